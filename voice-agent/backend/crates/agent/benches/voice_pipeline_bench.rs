@@ -121,6 +121,116 @@ fn bench_intent_detection(c: &mut Criterion) {
     group.finish();
 }
 
+// =============================================================================
+// Slot Extraction Benchmarks
+// =============================================================================
+
+fn bench_slot_extraction(c: &mut Criterion) {
+    use voice_agent_agent::{IntentDetector, SlotExtractor};
+
+    let mut group = c.benchmark_group("slot_extraction");
+    let utterance = "Mujhe 5 lakh ka gold loan chahiye, 50 gram 22 karat hai";
+
+    // Per-session setup: built-in patterns are cloned, not recompiled
+    group.bench_function("intent_detector_new", |b| b.iter(IntentDetector::new));
+
+    // Config patterns of an already-seen domain come from the pattern cache
+    let competitors = vec![
+        ("muthoot", "Muthoot Finance", r"(?i)\b(muthoot)\b"),
+        ("manappuram", "Manappuram", r"(?i)\b(manappuram)\b"),
+    ];
+    group.bench_function("intent_detector_with_competitors", |b| {
+        b.iter(|| {
+            let mut detector = IntentDetector::new();
+            detector.add_competitor_patterns(competitors.clone());
+            detector
+        })
+    });
+
+    // Per-turn extraction with the session's detector
+    let detector = IntentDetector::new();
+    group.bench_function("intent_detector_extract_slots", |b| {
+        b.iter(|| detector.extract_slots(utterance))
+    });
+
+    // Config-free extraction: fresh extractor vs the shared one
+    group.bench_function("extract_new_extractor", |b| {
+        b.iter(|| SlotExtractor::new().extract(utterance, "hi"))
+    });
+    group.bench_function("extract_shared_extractor", |b| {
+        b.iter(|| SlotExtractor::shared().extract(utterance, "hi"))
+    });
+
+    group.finish();
+}
+
+// =============================================================================
+// Keyword Matching Benchmarks
+// =============================================================================
+
+fn bench_keyword_matching(c: &mut Criterion) {
+    use regex::Regex;
+    use voice_agent_text_processing::KeywordMatcher;
+
+    let mut group = c.benchmark_group("keyword_matching");
+
+    // Thirty lenders with aliases, as in a large domain config
+    let lenders: Vec<(String, Vec<String>)> = (0..30)
+        .map(|i| {
+            let id = format!("lender{}", i);
+            let aliases = vec![
+                id.clone(),
+                format!("{} finance", id),
+                format!("{} gold loan", id),
+            ];
+            (id, aliases)
+        })
+        .collect();
+
+    // One regex per lender, the shape competitor_intent_patterns() generates
+    let regexes: Vec<(&str, Regex)> = lenders
+        .iter()
+        .map(|(id, aliases)| {
+            let alternatives: Vec<String> = aliases.iter().map(|a| regex::escape(a)).collect();
+            let pattern = format!(r"(?i)\b({})\b", alternatives.join("|"));
+            (id.as_str(), Regex::new(&pattern).unwrap())
+        })
+        .collect();
+    let matcher =
+        KeywordMatcher::whole_words(lenders.iter().map(|(id, aliases)| (id.as_str(), aliases)))
+            .unwrap();
+
+    // The last lender is the worst case for the per-regex loop
+    let short = "mera loan Lender29 Finance se hai".to_string();
+    let long = format!(
+        "{}aur abhi mera loan Lender29 Finance se chal raha hai",
+        "mujhe apne purane gold loan ke baare mein baat karni hai, byaj dar bahut zyada hai \
+         aur har mahine EMI bharna mushkil ho raha hai. "
+            .repeat(6)
+    );
+
+    for (name, text) in [("short", &short), ("long", &long)] {
+        group.throughput(Throughput::Bytes(text.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::new("regex_per_lender", name),
+            text,
+            |b, text| {
+                b.iter(|| {
+                    regexes
+                        .iter()
+                        .find(|(_, regex)| regex.is_match(text))
+                        .map(|(id, _)| *id)
+                })
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("aho_corasick", name), text, |b, text| {
+            b.iter(|| matcher.find(text).map(|found| found.label))
+        });
+    }
+
+    group.finish();
+}
+
 // =============================================================================
 // RAG Benchmarks
 // =============================================================================
@@ -358,6 +468,51 @@ fn bench_voice_session(c: &mut Criterion) {
     group.finish();
 }
 
+// =============================================================================
+// Noise Suppression Benchmarks
+// =============================================================================
+
+/// Latency each denoiser adds to an inbound frame
+///
+/// Build with `--features noise-suppression` (RNNoise) or `real-models`
+/// (ONNX denoiser); a backend that is not compiled in is benchmarked as the
+/// passthrough it falls back to, which the benchmark id shows.
+fn bench_noise_suppression(c: &mut Criterion) {
+    use voice_agent_config::{AudioConfig, DenoiserBackend};
+    use voice_agent_core::audio::{AudioFrame, Channels, SampleRate};
+    use voice_agent_pipeline::create_denoiser;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let mut group = c.benchmark_group("noise_suppression");
+
+    // 20ms of speech-like tone over hiss at 16kHz
+    let samples: Vec<f32> = (0..320)
+        .map(|i| (i as f32 * 0.1).sin() * 0.3 + if i % 2 == 0 { 0.02 } else { -0.02 })
+        .collect();
+    let frame = AudioFrame::new(samples, SampleRate::Hz16000, Channels::Mono, 0);
+
+    let off = AudioConfig {
+        noise_suppression: false,
+        ..Default::default()
+    };
+    let mut onnx = AudioConfig::default();
+    onnx.denoiser.backend = DenoiserBackend::Onnx;
+
+    for (backend, config) in [
+        ("off", off),
+        ("rnnoise", AudioConfig::default()),
+        ("onnx", onnx),
+    ] {
+        let denoiser = create_denoiser(&config);
+        group.bench_function(BenchmarkId::new(backend, denoiser.name()), |b| {
+            b.to_async(&rt)
+                .iter(|| async { denoiser.process(&frame, None).await })
+        });
+    }
+
+    group.finish();
+}
+
 // =============================================================================
 // Criterion Groups and Main
 // =============================================================================
@@ -367,11 +522,14 @@ criterion_group!(
     bench_audio_resampling,
     bench_vad,
     bench_intent_detection,
+    bench_slot_extraction,
+    bench_keyword_matching,
     bench_rag,
     bench_tools,
     bench_memory,
     bench_stage_manager,
     bench_voice_session,
+    bench_noise_suppression,
 );
 
 criterion_main!(benches);
@@ -0,0 +1,135 @@
+//! Abusive Speech Policy
+//!
+//! Decides how the agent reacts to abusive caller turns:
+//! - **Mild** profanity: answer as usual, with de-escalation guidance in the
+//!   prompt for that turn
+//! - **Severe** abuse: speak a warning instead of answering
+//! - After `escalate_after` abusive turns: hand the call to a human
+//!
+//! Detection itself lives in `voice_agent_text_processing::abuse`.
+
+use std::sync::Arc;
+use voice_agent_config::AbuseHandlingConfig;
+use voice_agent_text_processing::abuse::{
+    AbuseClassifier, AbuseDetection, AbuseDetector, AbuseSeverity,
+};
+
+/// Fallback when no warning template is configured
+const DEFAULT_WARNING: &str = "Please keep the conversation respectful so I can help you.";
+
+/// Fallback when no escalation message is configured
+const DEFAULT_ESCALATION: &str = "I'm connecting you to a member of our team.";
+
+/// How to respond to an abusive turn
+#[derive(Debug, Clone, PartialEq)]
+pub enum AbuseResponse {
+    /// Answer normally, with de-escalation guidance in the prompt
+    Deescalate,
+    /// Speak this warning instead of answering
+    Warn(String),
+    /// Speak this message and escalate to a human
+    Escalate(String),
+}
+
+/// Abusive speech policy shared by all sessions
+pub struct AbusePolicy {
+    detector: AbuseDetector,
+    config: AbuseHandlingConfig,
+}
+
+impl AbusePolicy {
+    /// Create a policy with the built-in lexicons plus configured terms
+    pub fn new(config: AbuseHandlingConfig) -> Self {
+        let detector =
+            AbuseDetector::with_terms(&config.extra_mild_terms, &config.extra_severe_terms);
+        Self { detector, config }
+    }
+
+    /// Plug in a classifier for abuse the lexicons miss
+    pub fn with_classifier(mut self, classifier: Arc<dyn AbuseClassifier>) -> Self {
+        self.detector = self
+            .detector
+            .with_classifier(classifier, self.config.classifier_threshold);
+        self
+    }
+
+    /// Screen a caller transcript
+    pub fn detect(&self, text: &str) -> AbuseDetection {
+        self.detector.detect(text)
+    }
+
+    /// Decide the response to an abusive turn
+    ///
+    /// `incidents` is the session's abusive-turn count including this one.
+    pub fn respond(
+        &self,
+        detection: &AbuseDetection,
+        incidents: u32,
+        language: &str,
+    ) -> AbuseResponse {
+        let threshold = self.config.escalate_after;
+        if threshold > 0 && incidents >= threshold {
+            let message = self
+                .config
+                .escalation_message(language)
+                .unwrap_or(DEFAULT_ESCALATION);
+            return AbuseResponse::Escalate(message.to_string());
+        }
+
+        if detection.severity == AbuseSeverity::Severe {
+            let warning = self.config.warning(language).unwrap_or(DEFAULT_WARNING);
+            return AbuseResponse::Warn(warning.to_string());
+        }
+
+        AbuseResponse::Deescalate
+    }
+
+    /// Prompt guidance for a turn after mild profanity
+    pub fn deescalation_prompt(&self) -> &str {
+        &self.config.deescalation_prompt
+    }
+
+    /// Abusive turns before escalation (0 = never)
+    pub fn escalate_after(&self) -> u32 {
+        self.config.escalate_after
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_ladder() {
+        let policy = AbusePolicy::new(AbuseHandlingConfig::default());
+
+        let mild = policy.detect("kya bakwas hai");
+        assert_eq!(policy.respond(&mild, 1, "en"), AbuseResponse::Deescalate);
+
+        let severe = policy.detect("chutiya bana rahe ho");
+        assert!(matches!(
+            policy.respond(&severe, 2, "hi"),
+            AbuseResponse::Warn(ref w) if w.contains("सम्मान")
+        ));
+
+        assert!(matches!(
+            policy.respond(&mild, 3, "en"),
+            AbuseResponse::Escalate(_)
+        ));
+    }
+
+    #[test]
+    fn test_escalation_disabled() {
+        let config = AbuseHandlingConfig {
+            escalate_after: 0,
+            ..Default::default()
+        };
+        let policy = AbusePolicy::new(config);
+
+        let severe = policy.detect("you bastard");
+        assert!(matches!(
+            policy.respond(&severe, 10, "ta"),
+            AbuseResponse::Warn(_)
+        ));
+    }
+}
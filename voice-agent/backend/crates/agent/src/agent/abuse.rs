@@ -0,0 +1,94 @@
+//! Abusive Speech Handling for DomainAgent
+//!
+//! Screens each caller turn with the shared [`AbusePolicy`], keeps the
+//! session's incident count and turns the policy's decision into a reply,
+//! prompt guidance or an escalation event.
+
+use std::sync::Arc;
+
+use super::DomainAgent;
+use crate::abuse_policy::{AbusePolicy, AbuseResponse};
+use crate::agent_config::AgentEvent;
+
+/// Per-session abuse tracking
+#[derive(Debug, Default)]
+pub(crate) struct AbuseState {
+    /// Abusive turns so far
+    pub(crate) incidents: u32,
+    /// Add de-escalation guidance to the current turn's prompt
+    pub(crate) deescalate: bool,
+}
+
+impl DomainAgent {
+    /// Share an abusive-speech policy with this session
+    pub fn set_abuse_policy(&self, policy: Arc<AbusePolicy>) {
+        *self.abuse_policy.write() = Some(policy);
+    }
+
+    /// Number of abusive turns in this session
+    pub fn abuse_incidents(&self) -> u32 {
+        self.abuse_state.read().incidents
+    }
+
+    /// Whether the caller has been abusive in this session
+    pub fn is_flagged_abusive(&self) -> bool {
+        self.abuse_incidents() > 0
+    }
+
+    /// Screen a caller turn
+    ///
+    /// Returns a reply to speak instead of answering (warning or handoff
+    /// message), or `None` to carry on with the turn.
+    pub(super) fn screen_abuse(&self, user_input: &str) -> Option<String> {
+        let policy = self.abuse_policy.read().clone()?;
+
+        let detection = policy.detect(user_input);
+        let incidents = {
+            let mut state = self.abuse_state.write();
+            // Guidance only applies to the turn that triggered it
+            state.deescalate = false;
+            if !detection.is_abusive() {
+                return None;
+            }
+            state.incidents += 1;
+            state.incidents
+        };
+
+        tracing::warn!(
+            severity = ?detection.severity,
+            terms = ?detection.matched_terms,
+            incidents = incidents,
+            "Abusive language detected"
+        );
+
+        match policy.respond(&detection, incidents, self.user_language.code()) {
+            AbuseResponse::Deescalate => {
+                self.abuse_state.write().deescalate = true;
+                None
+            },
+            AbuseResponse::Warn(warning) => Some(warning),
+            AbuseResponse::Escalate(message) => {
+                let _ = self.event_tx.send(AgentEvent::EscalationTriggered {
+                    trigger: format!(
+                        "AbusiveLanguage: {} incidents (threshold: {})",
+                        incidents,
+                        policy.escalate_after()
+                    ),
+                    recommendation: "EscalateNow: abusive caller".to_string(),
+                });
+                Some(message)
+            },
+        }
+    }
+
+    /// De-escalation guidance for the current turn's prompt, if any
+    pub(super) fn deescalation_guidance(&self) -> Option<String> {
+        if !self.abuse_state.read().deescalate {
+            return None;
+        }
+        self.abuse_policy
+            .read()
+            .as_ref()
+            .map(|policy| policy.deescalation_prompt().to_string())
+    }
+}
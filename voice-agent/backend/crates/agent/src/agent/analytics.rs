@@ -0,0 +1,77 @@
+//! Session Analytics for DomainAgent
+//!
+//! Counts what the analytics rollups need but the conversation does not
+//! already keep: successful tool calls (and their last output, for
+//! disposition rules) and caller barge-ins.
+
+use std::collections::HashMap;
+
+use voice_agent_core::DomainEvent;
+
+use super::DomainAgent;
+use crate::agent_config::AgentEvent;
+
+/// Per-session analytics counters
+#[derive(Debug, Default)]
+pub(crate) struct SessionStats {
+    /// Times the caller interrupted a response
+    pub(crate) interruptions: u32,
+    /// Tools that succeeded, in call order
+    pub(crate) tools_called: Vec<String>,
+    /// Last text output of each tool
+    pub(crate) tool_outputs: HashMap<String, String>,
+}
+
+impl DomainAgent {
+    /// Publish a tool result and count it if it succeeded
+    pub(super) fn report_tool_result(&self, name: &str, success: bool) {
+        if success {
+            self.session_stats
+                .write()
+                .tools_called
+                .push(name.to_string());
+        }
+        let _ = self.event_tx.send(AgentEvent::ToolResult {
+            name: name.to_string(),
+            success,
+        });
+        self.publish_event(DomainEvent::ToolExecuted {
+            tool: name.to_string(),
+            success,
+        });
+    }
+
+    /// Keep a tool's latest output
+    pub(super) fn record_tool_output(&self, name: &str, output: &str) {
+        self.session_stats
+            .write()
+            .tool_outputs
+            .insert(name.to_string(), output.to_string());
+    }
+
+    /// Times the caller barged in on a response
+    pub fn interruption_count(&self) -> u32 {
+        self.session_stats.read().interruptions
+    }
+
+    /// Tools that succeeded this session, in call order
+    pub fn tools_called(&self) -> Vec<String> {
+        self.session_stats.read().tools_called.clone()
+    }
+
+    /// Stages the conversation has been through, in order
+    pub fn stages_reached(&self) -> Vec<String> {
+        let history = self.conversation.stage_manager().history();
+        let mut stages = match history.first() {
+            Some(first) => vec![first.from.as_str().to_string()],
+            None => vec![self.stage().as_str().to_string()],
+        };
+        stages.extend(history.iter().map(|t| t.to.as_str().to_string()));
+        stages
+    }
+
+    /// Whether every required slot for the dialogue goal is filled
+    pub fn goal_reached(&self) -> bool {
+        self.dialogue_state.read().state().is_goal_complete()
+    }
+}
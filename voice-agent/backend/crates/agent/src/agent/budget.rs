@@ -0,0 +1,104 @@
+//! Session Budgets for DomainAgent
+//!
+//! Enforces the session's LLM token and duration budgets at the start of
+//! each caller turn. Past the wrap-up share of either budget the prompt
+//! asks the model to summarize and close; once one is spent the caller
+//! hears the configured wrap-up message instead of an answer, the
+//! conversation ends and `BudgetExceeded` is published for the audit trail.
+
+use voice_agent_config::SessionBudget;
+use voice_agent_core::DomainEvent;
+
+use super::DomainAgent;
+use crate::conversation::EndReason;
+
+/// Closing line when no wrap-up message is configured
+const DEFAULT_WRAP_UP: &str =
+    "We've reached the time available for this call. Thank you for your time.";
+
+/// A budget and how much of it is used
+struct BudgetUse {
+    name: &'static str,
+    limit: u64,
+    used: u64,
+}
+
+impl BudgetUse {
+    fn fraction(&self) -> f32 {
+        self.used as f32 / self.limit as f32
+    }
+}
+
+impl DomainAgent {
+    /// Limit this session's LLM tokens and duration
+    pub fn set_session_budget(&self, budget: SessionBudget) {
+        *self.budget.write() = Some(budget);
+    }
+
+    /// Budgets with a limit, and their use so far
+    fn budget_use(&self, budget: &SessionBudget) -> Vec<BudgetUse> {
+        [
+            BudgetUse {
+                name: "llm_tokens",
+                limit: budget.max_llm_tokens,
+                used: self.session_usage().llm_tokens(),
+            },
+            BudgetUse {
+                name: "session_duration",
+                limit: budget.max_duration_secs,
+                used: self.conversation.duration().as_secs(),
+            },
+        ]
+        .into_iter()
+        .filter(|b| b.limit > 0)
+        .collect()
+    }
+
+    /// Wrap-up message to speak instead of answering, if a budget is spent
+    pub(super) fn enforce_budget(&self) -> Option<String> {
+        let budget = self.budget.read().clone()?;
+        let spent = self
+            .budget_use(&budget)
+            .into_iter()
+            .find(|b| b.used >= b.limit)?;
+
+        tracing::warn!(
+            budget = spent.name,
+            limit = spent.limit,
+            used = spent.used,
+            "Session budget spent, wrapping up the call"
+        );
+        self.publish_event(DomainEvent::BudgetExceeded {
+            budget: spent.name.to_string(),
+            limit: spent.limit,
+            used: spent.used,
+        });
+
+        let message = [self.user_language.code(), "en"]
+            .into_iter()
+            .find_map(|language| budget.wrap_up_messages.get(language))
+            .cloned()
+            .unwrap_or_else(|| DEFAULT_WRAP_UP.to_string());
+        Some(message)
+    }
+
+    /// End the conversation after the wrap-up message was recorded
+    pub(super) fn end_for_budget(&self) {
+        self.conversation.end(EndReason::BudgetExhausted);
+    }
+
+    /// Prompt guidance once the session nears a budget
+    pub(super) fn budget_guidance(&self) -> Option<String> {
+        let budget = self.budget.read().clone()?;
+        self.budget_use(&budget)
+            .iter()
+            .any(|b| b.fraction() >= budget.wrap_up_fraction)
+            .then(|| {
+                "## Wrapping Up\n\
+                 This call is close to its time limit. Keep answers brief, \
+                 summarize what was agreed and the next step, and do not \
+                 start new topics."
+                    .to_string()
+            })
+    }
+}
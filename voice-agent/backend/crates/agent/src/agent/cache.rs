@@ -0,0 +1,91 @@
+//! Response Cache Methods for DomainAgent
+//!
+//! Thin wrappers over the shared [`ResponseCache`]: build the cache key from
+//! the current turn (session, language, intent, slots) and skip caching
+//! entirely when no cache is attached.
+
+use std::sync::Arc;
+
+use super::DomainAgent;
+use crate::conversation::ConversationContext;
+use crate::response_cache::{CacheQuery, ResponseCache};
+
+impl DomainAgent {
+    /// Share a response cache with this session
+    pub fn set_response_cache(&self, cache: Arc<ResponseCache>) {
+        *self.response_cache.write() = Some(cache);
+    }
+
+    fn cache_query<'a>(
+        &'a self,
+        intent: &'a crate::intent::DetectedIntent,
+        question: &'a str,
+    ) -> CacheQuery<'a> {
+        let mut slots: Vec<(String, String)> = intent
+            .slots
+            .iter()
+            .filter_map(|(k, v)| v.value.as_ref().map(|val| (k.clone(), val.clone())))
+            .collect();
+        slots.sort();
+
+        CacheQuery {
+            session_id: self.conversation.session_id(),
+            language: self.user_language.code(),
+            intent: &intent.intent,
+            slots,
+            personalized: self.personalization_ctx.read().customer_name.is_some(),
+            question,
+        }
+    }
+
+    /// Answer from an earlier turn or session for the same question
+    pub(super) fn cached_response(
+        &self,
+        intent: &crate::intent::DetectedIntent,
+        user_input: &str,
+    ) -> Option<String> {
+        let cache = self.response_cache.read().clone()?;
+        let hit = cache.lookup(&self.cache_query(intent, user_input))?;
+
+        tracing::debug!(
+            intent = %intent.intent,
+            similarity = hit.similarity,
+            scope = ?hit.scope,
+            "Answered from response cache"
+        );
+        Some(hit.response)
+    }
+
+    /// Remember an answer for later turns if it is deterministic
+    ///
+    /// `tool` is the tool whose result the answer was built from, if any.
+    pub(super) fn cache_response(
+        &self,
+        intent: &crate::intent::DetectedIntent,
+        user_input: &str,
+        response: &str,
+        tool: Option<&str>,
+    ) {
+        let Some(cache) = self.response_cache.read().clone() else {
+            return;
+        };
+        cache.store(&self.cache_query(intent, user_input), response, tool);
+    }
+
+    pub(super) fn cached_tool_result(
+        &self,
+        tool: &str,
+        args: &serde_json::Value,
+    ) -> Option<String> {
+        self.response_cache
+            .read()
+            .as_ref()?
+            .get_tool_result(tool, args)
+    }
+
+    pub(super) fn cache_tool_result(&self, tool: &str, args: &serde_json::Value, result: &str) {
+        if let Some(cache) = self.response_cache.read().as_ref() {
+            cache.put_tool_result(tool, args, result);
+        }
+    }
+}
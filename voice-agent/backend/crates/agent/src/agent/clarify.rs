@@ -0,0 +1,76 @@
+//! Intent Clarification for DomainAgent
+//!
+//! When the intent detector is unsure between its top two intents, the agent
+//! asks which one the customer meant instead of guessing. The question is
+//! tracked in the DST as the pending question, so the answer ("eligibility
+//! wala", "the second one") picks the intent and the same pair is never
+//! asked twice.
+
+use super::DomainAgent;
+use crate::dst::PendingQuestion;
+use crate::intent::DetectedIntent;
+
+impl DomainAgent {
+    /// Take the intent the customer picked in answer to a clarification
+    pub(super) fn apply_clarified_intent(
+        &self,
+        intent: &mut DetectedIntent,
+        name: &str,
+        confidence: f32,
+    ) {
+        if intent.intent == name {
+            intent.confidence = intent.confidence.max(confidence);
+            return;
+        }
+        tracing::debug!(
+            detected = %intent.intent,
+            clarified = name,
+            "Intent clarified by the customer"
+        );
+        let detected = (intent.intent.clone(), intent.confidence);
+        intent.alternatives.retain(|(alternative, _)| alternative != name);
+        intent.alternatives.insert(0, detected);
+        intent.intent = name.to_string();
+        intent.confidence = confidence;
+    }
+
+    /// Disambiguation question for a low-confidence intent, if one should
+    /// be asked instead of answering
+    ///
+    /// Records the question in the DST so the next turn's answer resolves
+    /// against it.
+    pub(super) fn clarify_intent(&self, intent: &DetectedIntent) -> Option<String> {
+        let view = self.domain_view.as_ref()?;
+        let intents = view.intents_config();
+        let (alternative, alternative_confidence) = intent.alternatives.first()?;
+        if !intents
+            .clarification
+            .is_ambiguous(intent.confidence, *alternative_confidence)
+        {
+            return None;
+        }
+
+        let mut dst = self.dialogue_state.write();
+        let max_per_session = intents.clarification.max_per_session;
+        if !dst.may_clarify(&intent.intent, alternative, max_per_session) {
+            return None;
+        }
+        let question = intents.clarification_question(
+            &intent.intent,
+            alternative,
+            self.user_language.code(),
+        )?;
+        let first = intents.get_intent(&intent.intent)?;
+        let second = intents.get_intent(alternative)?;
+        dst.ask_clarification(PendingQuestion::clarify_between([first, second]));
+
+        tracing::info!(
+            first = %intent.intent,
+            first_confidence = intent.confidence,
+            second = %alternative,
+            second_confidence = alternative_confidence,
+            "Asking the customer to clarify their intent"
+        );
+        Some(question)
+    }
+}
@@ -0,0 +1,52 @@
+//! Call Disposition for DomainAgent
+//!
+//! Gathers the session's signals for the shared [`DispositionClassifier`]
+//! and keeps the code it assigns once the call is over.
+
+use super::DomainAgent;
+use crate::disposition::{Disposition, DispositionClassifier, DispositionSignals};
+
+impl DomainAgent {
+    /// Signals the disposition rules look at
+    pub fn disposition_signals(&self) -> DispositionSignals {
+        let (tools_called, tool_outputs) = {
+            let stats = self.session_stats.read();
+            (stats.tools_called.clone(), stats.tool_outputs.clone())
+        };
+        let transcript = self
+            .conversation
+            .agentic_memory()
+            .get_all_turns()
+            .into_iter()
+            .map(|turn| (turn.role.as_str().to_string(), turn.content))
+            .collect();
+
+        DispositionSignals {
+            abusive: self.is_flagged_abusive(),
+            tools_called,
+            tool_outputs,
+            transcript,
+            goal: self.dialogue_goal(),
+            goal_complete: self.goal_reached(),
+        }
+    }
+
+    /// Classify the finished call and keep its disposition
+    pub async fn classify_disposition(&self, classifier: &DispositionClassifier) -> Disposition {
+        let llm = self.llm.read().clone();
+        let disposition = classifier.classify(&self.disposition_signals(), llm).await;
+        tracing::info!(
+            session_id = %self.conversation.session_id(),
+            code = %disposition.code,
+            source = ?disposition.source,
+            "Call disposition assigned"
+        );
+        *self.disposition.write() = Some(disposition.clone());
+        disposition
+    }
+
+    /// Disposition assigned when the call ended, if classified
+    pub fn disposition(&self) -> Option<Disposition> {
+        self.disposition.read().clone()
+    }
+}
@@ -0,0 +1,93 @@
+//! Domain Events for DomainAgent
+//!
+//! Publishes what the dialogue loop did to the shared `EventBus` (when one
+//! is attached): slots filled and goals reached by the DST, stage changes,
+//! tool results and their business outcomes (lead captured, appointment
+//! booked, escalation requested), and barge-ins reported by the pipeline.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+use voice_agent_core::{DomainEvent, EventBus};
+use voice_agent_tools::ESCALATION_TOOL;
+
+use super::DomainAgent;
+use crate::stage::ConversationStage;
+
+/// Slot values and goal completion before a DST update
+pub(super) struct DialogueSnapshot {
+    slots: HashMap<String, String>,
+    goal_complete: bool,
+}
+
+impl DomainAgent {
+    /// Publish this session's domain events to a shared bus
+    pub fn set_event_bus(&self, bus: EventBus) {
+        *self.event_bus.write() = Some(bus);
+    }
+
+    /// Publish an event if a bus is attached
+    pub(super) fn publish_event(&self, event: DomainEvent) {
+        if let Some(ref bus) = *self.event_bus.read() {
+            bus.publish(self.conversation.session_id(), event);
+        }
+    }
+
+    /// Capture the dialogue state to diff against, or `None` without a bus
+    pub(super) fn dialogue_snapshot(&self) -> Option<DialogueSnapshot> {
+        let attached = self.event_bus.read().is_some();
+        attached.then(|| DialogueSnapshot {
+            slots: self.customer_facts(),
+            goal_complete: self.goal_reached(),
+        })
+    }
+
+    /// Publish slots the DST filled or changed since `before`, and the goal
+    /// if it has just been reached
+    pub(super) fn publish_dialogue_changes(&self, before: DialogueSnapshot) {
+        let mut changed: Vec<(String, String)> = self
+            .customer_facts()
+            .into_iter()
+            .filter(|(slot, value)| before.slots.get(slot) != Some(value))
+            .collect();
+        changed.sort();
+        for (slot, value) in changed {
+            self.publish_event(DomainEvent::SlotFilled { slot, value });
+        }
+
+        if !before.goal_complete && self.goal_reached() {
+            let goal = self.dialogue_goal();
+            self.publish_event(DomainEvent::GoalReached { goal });
+        }
+    }
+
+    /// Publish a stage change since `before` (intent transition or advance)
+    pub(super) fn publish_stage_change(&self, before: ConversationStage) {
+        let stage = self.stage();
+        if stage != before {
+            self.publish_event(DomainEvent::StageChanged {
+                from: before.as_str().to_string(),
+                to: stage.as_str().to_string(),
+            });
+        }
+    }
+
+    /// Publish the business outcome of a tool that has just run
+    ///
+    /// Only fresh executions count: replayed or remembered results already
+    /// published theirs. Results reporting `"success": false` are skipped.
+    pub(super) fn publish_tool_outcome(&self, tool: &str, output: &str) {
+        let outcome: fn(Value) -> DomainEvent = match tool {
+            "capture_lead" => |details| DomainEvent::LeadCaptured { details },
+            "schedule_appointment" => |details| DomainEvent::AppointmentBooked { details },
+            ESCALATION_TOOL => |details| DomainEvent::EscalationRequested { details },
+            _ => return,
+        };
+        let details =
+            serde_json::from_str(output).unwrap_or_else(|_| Value::String(output.to_string()));
+        if details.get("success").and_then(Value::as_bool) == Some(false) {
+            return;
+        }
+        self.publish_event(outcome(details));
+    }
+}
@@ -0,0 +1,52 @@
+//! Few-Shot Examples for DomainAgent
+//!
+//! Picks the domain's annotated exchanges closest to the current turn (its
+//! intent and goal, then word overlap) and adds them to the prompt, so small
+//! models see how this kind of request is answered and which tool it calls.
+
+use voice_agent_llm::{PromptBuilder, SectionKind};
+
+use super::DomainAgent;
+
+impl DomainAgent {
+    /// Add the examples most relevant to this turn, if the domain has any
+    pub(super) fn with_examples(
+        &self,
+        builder: PromptBuilder,
+        english_input: &str,
+    ) -> PromptBuilder {
+        let Some(view) = self.domain_view.as_ref() else {
+            return builder;
+        };
+        let store = view.examples_config();
+        if store.is_empty() {
+            return builder;
+        }
+
+        let (intent, goal) = {
+            let dst = self.dialogue_state.read();
+            let intent = dst.state().primary_intent_value().map(str::to_string);
+            (intent, dst.goal_id().to_string())
+        };
+        let examples = store.retrieve(intent.as_deref(), Some(&goal), english_input);
+        if examples.is_empty() {
+            return builder;
+        }
+
+        tracing::debug!(
+            intent = ?intent,
+            goal = %goal,
+            examples = ?examples.iter().map(|e| e.id.as_str()).collect::<Vec<_>>(),
+            "Few-shot examples added to prompt"
+        );
+        let rendered: Vec<String> = examples.iter().map(|example| example.render()).collect();
+        builder.with_section(
+            SectionKind::Examples,
+            &format!(
+                "## Example Exchanges\nHow similar requests were handled. Follow the same \
+                 tool-call format; use the customer's own values, not these.\n\n{}",
+                rendered.join("\n\n")
+            ),
+        )
+    }
+}
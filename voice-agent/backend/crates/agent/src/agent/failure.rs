@@ -0,0 +1,45 @@
+//! Failed Turns for DomainAgent
+//!
+//! When a turn fails (LLM down, tool backend timing out, pipeline error) the
+//! caller should hear a short apology rather than silence. The phrase is
+//! chosen by the error's [`ErrorKind`] from the domain's `error_templates`
+//! (keyed by kind, then language). A kind without a template in the
+//! caller's language uses the generic `internal` one, then English, then a
+//! built-in phrase.
+
+use voice_agent_core::{ErrorClass, ErrorKind};
+
+use super::DomainAgent;
+
+impl DomainAgent {
+    /// What to tell the caller after a failed turn, in their language
+    ///
+    /// `None` for failures the caller should not hear about, such as a
+    /// session that has already ended or a background write.
+    pub fn failure_reply(&self, error: &impl ErrorClass) -> Option<String> {
+        let kind = error.kind();
+        if !error.is_user_visible() {
+            tracing::debug!(kind = %kind, "Failure not spoken to the caller");
+            return None;
+        }
+
+        let language = self.user_language.code();
+        let configured = self.domain_view.as_ref().and_then(|view| {
+            [language, "en"]
+                .into_iter()
+                .flat_map(|language| {
+                    [kind, ErrorKind::Internal].map(|key| (key.as_str(), language))
+                })
+                .find_map(|(key, language)| view.error_template(key, language))
+                .map(str::to_string)
+        });
+
+        tracing::info!(
+            kind = %kind,
+            retryable = error.is_retryable(),
+            configured = configured.is_some(),
+            "Turn failed, answering with an apology"
+        );
+        Some(configured.unwrap_or_else(|| kind.default_phrase().to_string()))
+    }
+}
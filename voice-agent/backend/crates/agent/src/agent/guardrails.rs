@@ -0,0 +1,69 @@
+//! Output Guardrail Methods for DomainAgent
+//!
+//! Runs LLM output through the shared [`Guardrails`] before it is spoken.
+//! Rules are matched against the English answer, before translation.
+
+use std::sync::Arc;
+
+use voice_agent_config::GuardrailAction;
+
+use super::DomainAgent;
+use crate::conversation::ConversationContext;
+use crate::guardrails::{GuardrailVerdict, Guardrails};
+
+/// LLM output after the guardrails ran
+pub(super) enum Guarded {
+    /// Speak this English text (unchanged or rewritten)
+    Allowed(String),
+    /// Speak this safe response instead; already in the user's language
+    Blocked(String),
+}
+
+impl DomainAgent {
+    /// Share output guardrails with this session
+    pub fn set_guardrails(&self, guardrails: Arc<Guardrails>) {
+        *self.guardrails.write() = Some(guardrails);
+    }
+
+    /// Check a complete answer
+    pub(super) fn guard_response(&self, english: String) -> Guarded {
+        match self.enforce_guardrails(&english) {
+            None => Guarded::Allowed(english),
+            Some((_, verdict)) if verdict.is_blocked() => Guarded::Blocked(verdict.text),
+            Some((_, verdict)) => Guarded::Allowed(verdict.text),
+        }
+    }
+
+    /// Check one sentence of a streamed answer
+    ///
+    /// Returns `None` when the sentence should be skipped: in rewrite mode an
+    /// unfixable sentence is dropped and the rest of the answer still plays.
+    pub(super) fn guard_sentence(&self, english: String) -> Option<Guarded> {
+        match self.enforce_guardrails(&english) {
+            None => Some(Guarded::Allowed(english)),
+            Some((_, verdict)) if !verdict.is_blocked() => Some(Guarded::Allowed(verdict.text)),
+            Some((GuardrailAction::Rewrite, _)) => None,
+            Some((_, verdict)) => Some(Guarded::Blocked(verdict.text)),
+        }
+    }
+
+    /// Safe response in the user's language, if guardrails are attached
+    pub(super) fn guardrail_safe_response(&self) -> Option<String> {
+        self.guardrails
+            .read()
+            .as_ref()
+            .map(|g| g.safe_response(self.user_language.code()).to_string())
+    }
+
+    /// Run the guardrails, returning the verdict only if they intervened
+    fn enforce_guardrails(&self, english: &str) -> Option<(GuardrailAction, GuardrailVerdict)> {
+        let guardrails = self.guardrails.read().clone()?;
+
+        let verdict = guardrails.enforce(english, self.user_language.code());
+        if verdict.is_clean() {
+            return None;
+        }
+        guardrails.record(self.conversation.session_id(), &verdict);
+        Some((guardrails.action(), verdict))
+    }
+}
@@ -0,0 +1,87 @@
+//! Human Handoff for DomainAgent
+//!
+//! Packages the session for the human agent taking over an escalated call
+//! (dialogue state, slots, recent turns, lead score) and applies the warm
+//! transfer signals the agent desk sends back.
+
+use serde_json::{json, Map, Value};
+
+use super::DomainAgent;
+use crate::agent_config::{AgentEvent, HandoffStatus};
+use crate::dst::DialogueStateTrait;
+
+impl DomainAgent {
+    /// Attach the session context to escalation tool arguments
+    pub(super) fn apply_handoff_context(&self, args: &mut Map<String, Value>) {
+        args.entry("session_id".to_string())
+            .or_insert_with(|| json!(self.conversation.session_id()));
+
+        let (dialogue_state, slots) = {
+            let dst = self.dialogue_state.read();
+            let state = dst.state();
+            let slots: Map<String, Value> = state
+                .filled_slots()
+                .into_iter()
+                .filter_map(|name| {
+                    state
+                        .get_slot_value(name)
+                        .map(|value| (name.to_string(), json!(value)))
+                })
+                .collect();
+            (state.to_context_string(), slots)
+        };
+
+        let recent_turns: Vec<Value> = self
+            .conversation
+            .get_messages()
+            .into_iter()
+            .map(|(role, content)| json!({ "role": role, "content": content }))
+            .collect();
+
+        let score = self.last_lead_score();
+        args.insert(
+            "handoff_context".to_string(),
+            json!({
+                "dialogue_state": dialogue_state,
+                "slots": slots,
+                "recent_turns": recent_turns,
+                "lead_score": score.as_ref().map(|s| s.total),
+                "lead_qualification": score.as_ref().map(|s| format!("{:?}", s.qualification)),
+                "stage": self.stage().as_str(),
+                "language": self.user_language.code(),
+            }),
+        );
+    }
+
+    /// Apply a warm transfer signal from the agent desk
+    ///
+    /// The bot stops answering once the human agent is connected and picks
+    /// the conversation back up if the transfer is cancelled or fails.
+    pub fn signal_handoff(
+        &self,
+        handoff_id: &str,
+        status: HandoffStatus,
+        agent_id: Option<String>,
+        transfer_to: Option<String>,
+    ) {
+        match status {
+            HandoffStatus::Connected => self.conversation.pause(),
+            HandoffStatus::Cancelled | HandoffStatus::Failed => self.conversation.resume(),
+            HandoffStatus::AgentAssigned | HandoffStatus::Ready => {},
+        }
+
+        tracing::info!(
+            session_id = %self.conversation.session_id(),
+            handoff_id,
+            status = status.as_str(),
+            agent_id = ?agent_id,
+            "Handoff signal"
+        );
+        let _ = self.event_tx.send(AgentEvent::Handoff {
+            handoff_id: handoff_id.to_string(),
+            status,
+            agent_id,
+            transfer_to,
+        });
+    }
+}
@@ -0,0 +1,339 @@
+//! Crash-Safe Session Journal for DomainAgent
+//!
+//! With a journal attached, every turn is written ahead to persistence as
+//! it completes: what the caller said, what the agent answered, the tools
+//! it ran and the dialogue state that changed (slots and stage, diffed
+//! against the previous turn). Writes happen off the response path and a
+//! failed batch is retried with the next turn's.
+//!
+//! After a backend restart, `recover_from_journal` replays a session's
+//! journal into a fresh agent, rebuilding the conversation, agentic memory,
+//! DST slots and remembered tool results, so the call continues where it
+//! was instead of starting over.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+
+use serde_json::{json, Value};
+use voice_agent_persistence::{JournalEntry, JournalEventKind, SessionJournal};
+
+use super::DomainAgent;
+use crate::conversation::ConversationContext;
+use crate::dst::{ChangeSource, DialogueStateTrait};
+use crate::intent::DetectedIntent;
+use crate::memory::{ConversationTurn, TurnRole};
+use crate::stage::{ConversationStage, TransitionReason};
+use crate::AgentError;
+
+/// A DST slot as journaled
+#[derive(Debug, Clone)]
+struct JournaledSlot {
+    value: String,
+    confidence: f32,
+    confirmed: bool,
+}
+
+impl JournaledSlot {
+    fn changed_from(&self, previous: Option<&JournaledSlot>) -> bool {
+        !previous.is_some_and(|p| p.value == self.value && p.confirmed == self.confirmed)
+    }
+}
+
+/// Journal attached to a session and what it has recorded so far
+#[derive(Default)]
+pub(crate) struct JournalState {
+    store: Option<Arc<dyn SessionJournal>>,
+    next_seq: i64,
+    /// Recorded but not yet written
+    pending: Vec<JournalEntry>,
+    /// Slot values as of the last journaled turn
+    slots: HashMap<String, JournaledSlot>,
+    /// Stage as of the last journaled turn
+    stage: Option<ConversationStage>,
+}
+
+impl JournalState {
+    fn record(&mut self, session_id: &str, kind: JournalEventKind, payload: Value) {
+        if self.store.is_none() {
+            return;
+        }
+        let entry = JournalEntry::new(session_id, self.next_seq, kind, &payload);
+        self.next_seq += 1;
+        self.pending.push(entry);
+    }
+
+    /// Store and pending entries to write, or `None` if there is nothing to do
+    fn take_batch(&mut self) -> Option<(Arc<dyn SessionJournal>, Vec<JournalEntry>)> {
+        let store = self.store.clone()?;
+        if self.pending.is_empty() {
+            return None;
+        }
+        Some((store, std::mem::take(&mut self.pending)))
+    }
+
+    /// Put a batch that failed to write back ahead of newer entries
+    fn requeue(&mut self, mut batch: Vec<JournalEntry>) {
+        batch.append(&mut self.pending);
+        self.pending = batch;
+    }
+}
+
+impl DomainAgent {
+    /// Write this session's turns ahead to a journal for crash recovery
+    pub fn set_journal(&self, journal: Arc<dyn SessionJournal>) {
+        self.journal.write().store = Some(journal);
+    }
+
+    /// Add the caller's turn to the conversation and the journal
+    pub(super) fn add_user_turn(&self, text: &str) -> Result<DetectedIntent, AgentError> {
+        let intent = self.conversation.add_user_turn(text)?;
+        self.start_usage_turn();
+        self.journal.write().record(
+            self.conversation.session_id(),
+            JournalEventKind::UserTurn,
+            json!({ "text": text }),
+        );
+        Ok(intent)
+    }
+
+    /// Add the agent's reply to the conversation and close the turn in the
+    /// journal with the dialogue state it changed
+    pub(super) fn add_assistant_turn(&self, text: &str) -> Result<(), AgentError> {
+        self.conversation.add_assistant_turn(text)?;
+        if self.journal.read().store.is_none() {
+            return Ok(());
+        }
+
+        let slots = self.slot_snapshot();
+        let stage = self.stage();
+        {
+            let session_id = self.conversation.session_id();
+            let mut journal = self.journal.write();
+            journal.record(
+                session_id,
+                JournalEventKind::AssistantTurn,
+                json!({ "text": text }),
+            );
+
+            let mut changed: Vec<(String, Value)> = slots
+                .iter()
+                .filter(|(slot, current)| current.changed_from(journal.slots.get(*slot)))
+                .map(|(slot, current)| {
+                    let payload = json!({
+                        "slot": slot,
+                        "value": current.value,
+                        "confidence": current.confidence,
+                        "confirmed": current.confirmed,
+                    });
+                    (slot.clone(), payload)
+                })
+                .chain(
+                    journal
+                        .slots
+                        .keys()
+                        .filter(|slot| !slots.contains_key(*slot))
+                        .map(|slot| (slot.clone(), json!({ "slot": slot, "value": null }))),
+                )
+                .collect();
+            changed.sort_by(|a, b| a.0.cmp(&b.0));
+            for (_, payload) in changed {
+                journal.record(session_id, JournalEventKind::SlotChanged, payload);
+            }
+            if journal.stage != Some(stage) {
+                journal.record(
+                    session_id,
+                    JournalEventKind::StageChanged,
+                    json!({ "stage": stage.as_str() }),
+                );
+            }
+            journal.slots = slots;
+            journal.stage = Some(stage);
+        }
+
+        self.flush_journal_in_background();
+        Ok(())
+    }
+
+    /// Journal a tool's result for this turn
+    pub(super) fn journal_tool_result(&self, tool: &str, arguments: &Value, result: &str) {
+        self.journal.write().record(
+            self.conversation.session_id(),
+            JournalEventKind::ToolResult,
+            json!({ "tool": tool, "arguments": arguments, "result": result }),
+        );
+    }
+
+    /// Write pending entries now
+    ///
+    /// On failure the entries stay pending for the next flush.
+    pub async fn flush_journal(&self) -> Result<(), AgentError> {
+        let Some((store, batch)) = self.journal.write().take_batch() else {
+            return Ok(());
+        };
+        if let Err(e) = store.append(&batch).await {
+            self.journal.write().requeue(batch);
+            return Err(AgentError::Memory(format!("journal write failed: {}", e)));
+        }
+        Ok(())
+    }
+
+    fn flush_journal_in_background(&self) {
+        let Some((store, batch)) = self.journal.write().take_batch() else {
+            return;
+        };
+
+        let journal = self.journal.clone();
+        let session_id = self.conversation.session_id().to_string();
+        tokio::spawn(async move {
+            if let Err(e) = store.append(&batch).await {
+                tracing::warn!(
+                    session_id = %session_id,
+                    entries = batch.len(),
+                    error = %e,
+                    "Journal write failed, retrying with the next turn"
+                );
+                journal.write().requeue(batch);
+            }
+        });
+    }
+
+    /// Rebuild this session from its journal after a restart
+    ///
+    /// Returns the number of conversation turns restored (0 if the session
+    /// has no journal or nothing was recorded).
+    pub async fn recover_from_journal(&self) -> Result<usize, AgentError> {
+        let Some(store) = self.journal.read().store.clone() else {
+            return Ok(0);
+        };
+        let entries = store
+            .entries(self.conversation.session_id())
+            .await
+            .map_err(|e| AgentError::Memory(format!("journal read failed: {}", e)))?;
+        Ok(self.replay_journal(&entries))
+    }
+
+    /// Apply journal entries to this (fresh) agent, returning the number of
+    /// conversation turns restored
+    pub(super) fn replay_journal(&self, entries: &[JournalEntry]) -> usize {
+        let memory = self.conversation.agentic_memory();
+        let mut turns = 0;
+
+        for entry in entries {
+            let payload = entry.payload();
+            let text = payload["text"].as_str().unwrap_or_default();
+            match entry.kind {
+                JournalEventKind::UserTurn => {
+                    if let Err(e) = self.conversation.add_user_turn(text) {
+                        tracing::warn!(seq = entry.seq, error = %e, "Journal replay stopped");
+                        break;
+                    }
+                    memory.add_turn(ConversationTurn::new(TurnRole::User, text));
+                    turns += 1;
+                },
+                JournalEventKind::AssistantTurn => {
+                    if let Err(e) = self.conversation.add_assistant_turn(text) {
+                        tracing::warn!(seq = entry.seq, error = %e, "Journal replay stopped");
+                        break;
+                    }
+                    memory.add_turn(ConversationTurn::new(TurnRole::Assistant, text));
+                    turns += 1;
+                },
+                JournalEventKind::SlotChanged => self.replay_slot(&payload),
+                JournalEventKind::StageChanged => {
+                    let stage = payload["stage"]
+                        .as_str()
+                        .and_then(ConversationStage::from_str);
+                    if let Some(stage) = stage {
+                        if let Err(e) = self
+                            .conversation
+                            .stage_manager()
+                            .transition(stage, TransitionReason::Manual)
+                        {
+                            tracing::debug!(error = %e, "Journaled stage not restored");
+                        }
+                    }
+                },
+                JournalEventKind::ToolResult => {
+                    let (Some(tool), Some(result)) =
+                        (payload["tool"].as_str(), payload["result"].as_str())
+                    else {
+                        continue;
+                    };
+                    let age = (chrono::Utc::now() - entry.recorded_at)
+                        .to_std()
+                        .unwrap_or_default();
+                    let stored_at = Instant::now().checked_sub(age).unwrap_or_else(Instant::now);
+                    self.restore_tool_result(tool, &payload["arguments"], result, stored_at);
+                    self.record_tool_output(tool, result);
+                },
+            }
+        }
+
+        let slots = self.slot_snapshot();
+        let mut journal = self.journal.write();
+        journal.next_seq = entries.last().map_or(0, |e| e.seq + 1);
+        journal.slots = slots;
+        journal.stage = Some(self.stage());
+        drop(journal);
+
+        tracing::info!(
+            session_id = %self.conversation.session_id(),
+            entries = entries.len(),
+            turns,
+            "Session restored from journal"
+        );
+        turns
+    }
+
+    /// Restore one journaled slot into the DST and core memory
+    fn replay_slot(&self, payload: &Value) {
+        let Some(slot) = payload["slot"].as_str() else {
+            return;
+        };
+        let mut dst = self.dialogue_state.write();
+        let Some(value) = payload["value"].as_str() else {
+            dst.clear_slot(slot);
+            return;
+        };
+
+        // The slot's confirmation policy sees the original confidence, so a
+        // value pending read-back is still read back
+        let confidence = payload["confidence"].as_f64().unwrap_or(1.0) as f32;
+        let turn = dst.history().len();
+        dst.update_slot(slot, value, confidence, ChangeSource::External, turn);
+        if payload["confirmed"].as_bool().unwrap_or(false) {
+            dst.confirm_slot(slot);
+        }
+        drop(dst);
+
+        let fact_key = self
+            .domain_view
+            .as_ref()
+            .map(|v| v.canonical_fact_key(slot))
+            .unwrap_or(slot);
+        let memory = self.conversation.agentic_memory();
+        if let Err(e) = memory.core_memory_append(fact_key, value) {
+            tracing::debug!(fact = fact_key, error = %e, "Skipped journaled fact");
+        }
+    }
+
+    /// Filled DST slots with their values and confirmation
+    fn slot_snapshot(&self) -> HashMap<String, JournaledSlot> {
+        let dst = self.dialogue_state.read();
+        let state = dst.state();
+        state
+            .filled_slots()
+            .into_iter()
+            .filter_map(|slot| {
+                let value = state.get_slot_with_confidence(slot)?;
+                let journaled = JournaledSlot {
+                    value: value.value.clone(),
+                    confidence: value.confidence,
+                    confirmed: value.confirmed,
+                };
+                Some((slot.to_string(), journaled))
+            })
+            .collect()
+    }
+}
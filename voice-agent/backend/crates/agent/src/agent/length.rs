@@ -0,0 +1,69 @@
+//! Response Length Methods for DomainAgent
+//!
+//! Keeps English answers within the shared [`ResponseGovernor`]'s
+//! spoken-duration budget, before the guardrails and translation run.
+
+use std::sync::Arc;
+
+use super::usage::estimate_prompt_tokens;
+use super::DomainAgent;
+use crate::response_governor::ResponseGovernor;
+
+impl DomainAgent {
+    /// Share the spoken-duration budget with this session
+    pub fn set_response_governor(&self, governor: Arc<ResponseGovernor>) {
+        *self.response_governor.write() = Some(governor);
+    }
+
+    /// Fit a complete answer into the budget
+    ///
+    /// With summarization on, the answer is compressed first; a summary that
+    /// fails or is still too long is cut at a sentence boundary.
+    pub(super) async fn govern_response(&self, english: String) -> String {
+        let Some(governor) = self.response_governor.read().clone() else {
+            return english;
+        };
+        if governor.fits(&english) {
+            return english;
+        }
+
+        let mut answer = english;
+        let llm = self.llm.read().clone();
+        if let (true, Some(llm)) = (governor.summarizes(), llm) {
+            let request = governor.compression_request(&answer);
+            let prompt_tokens = estimate_prompt_tokens(llm.as_ref(), &request);
+            let result = llm.generate(request).await;
+            if let Ok(summary) = &result {
+                self.record_llm_usage(
+                    llm.as_ref(),
+                    prompt_tokens,
+                    summary.usage.as_ref(),
+                    &summary.text,
+                );
+            }
+            match result {
+                Ok(summary) if !summary.text.trim().is_empty() => {
+                    answer = summary.text.trim().to_string();
+                },
+                Ok(_) => tracing::debug!("Empty compression pass, cutting the answer instead"),
+                Err(e) => tracing::warn!("Response compression failed: {}", e),
+            }
+        }
+
+        let governed = governor.truncate(&answer);
+        tracing::debug!(
+            seconds = governor.spoken_seconds(&answer),
+            kept_seconds = governor.spoken_seconds(&governed),
+            "Answer cut to the spoken-duration budget"
+        );
+        governed
+    }
+
+    /// Whether a streamed sentence may follow what was already spoken
+    pub(super) fn admits_sentence(&self, spoken: &str, sentence: &str) -> bool {
+        self.response_governor
+            .read()
+            .as_ref()
+            .map_or(true, |governor| governor.admits(spoken, sentence))
+    }
+}
@@ -0,0 +1,82 @@
+//! Line Quality Handling for DomainAgent
+//!
+//! The pipeline reports when the caller's line turns noisy, lossy or
+//! clipped. The session keeps the latest report for analytics, and while the
+//! line is degraded the prompt asks for short sentences and, when the last
+//! answer was heard with low STT confidence, for the agent to say the line is
+//! bad and ask for that answer again instead of guessing at it.
+
+use voice_agent_core::{AudioQuality, LineIssue};
+
+use super::DomainAgent;
+
+/// STT confidence below which an answer on a degraded line is not trusted
+const DEGRADED_MIN_CONFIDENCE: f32 = 0.75;
+
+/// Per-session line quality
+#[derive(Debug, Default)]
+pub(crate) struct LineState {
+    /// Latest report from the pipeline
+    pub(crate) quality: Option<AudioQuality>,
+    /// Times the line turned bad
+    pub(crate) degradations: u32,
+    /// STT confidence of the last transcript
+    pub(crate) last_confidence: Option<f32>,
+}
+
+impl DomainAgent {
+    /// Record a line quality report from the pipeline
+    pub fn set_line_quality(&self, quality: AudioQuality) {
+        let mut line = self.line_state.write();
+        let was_degraded = line.quality.is_some_and(|q| q.is_degraded());
+        if quality.is_degraded() && !was_degraded {
+            line.degradations += 1;
+            tracing::info!(
+                session_id = %self.conversation.session_id(),
+                issue = ?quality.issue,
+                snr_db = ?quality.snr_db,
+                "Caller line degraded"
+            );
+        }
+        line.quality = Some(quality);
+    }
+
+    /// Latest quality of the caller's line, if reported
+    pub fn line_quality(&self) -> Option<AudioQuality> {
+        self.line_state.read().quality
+    }
+
+    /// Times the caller's line turned bad in this session
+    pub fn line_degradations(&self) -> u32 {
+        self.line_state.read().degradations
+    }
+
+    /// Guidance for the prompt while the line is degraded
+    pub(super) fn line_quality_guidance(&self) -> Option<String> {
+        let line = self.line_state.read();
+        let issue = line.quality.and_then(|q| q.issue)?;
+        let problem = match issue {
+            LineIssue::Noise => "noisy",
+            LineIssue::PacketLoss => "breaking up",
+            LineIssue::Clipping => "distorted",
+        };
+        let mut guidance = format!(
+            "## Line Quality\n\
+             The customer's line is {}. Keep sentences short and confirm \
+             amounts, numbers and names by repeating them back.",
+            problem
+        );
+        if line
+            .last_confidence
+            .is_some_and(|confidence| confidence < DEGRADED_MIN_CONFIDENCE)
+        {
+            guidance.push_str(&format!(
+                "\nThe last answer was not heard clearly. Do not guess at it: \
+                 say the line is {} and ask the customer to repeat it, e.g. \
+                 \"The line is {}, could you repeat the amount?\"",
+                problem, problem
+            ));
+        }
+        Some(guidance)
+    }
+}
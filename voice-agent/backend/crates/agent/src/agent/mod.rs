@@ -14,15 +14,51 @@
 //! - `processing`: Core process() and process_stream() methods
 //! - `rag`: RAG and prefetch methods
 //! - `tools`: Tool calling logic
+//! - `tool_memory`: Tool results remembered for follow-up questions
+//! - `repair`: Dry run of LLM tool calls and repair of bad arguments
 //! - `response`: Response generation
+//! - `cache`: Response cache lookups
+//! - `examples`: Few-shot exchanges retrieved for the prompt
+//! - `guardrails`: Compliance checks on LLM output
+//! - `length`: Spoken-duration budget for LLM answers
+//! - `abuse`: Abusive speech handling on caller turns
+//! - `clarify`: Disambiguation questions for low-confidence intents
+//! - `line_quality`: Caller line quality reports and repeat requests
+//! - `objection`: Objection tracking and playbook guidance
+//! - `events`: Domain events published to the shared event bus
+//! - `failure`: What the caller hears when a turn fails
+//! - `journal`: Write-ahead journal of turns and recovery after a restart
+//! - `usage`: Per-turn token, speech, tool and translation accounting
+//! - `budget`: Session token and duration budgets and the wrap-up when spent
 
 // Submodules for focused functionality
+mod abuse;
+mod analytics;
+mod budget;
+mod cache;
+mod clarify;
+mod disposition;
+mod events;
+mod examples;
+mod failure;
+mod guardrails;
+mod handoff;
+mod journal;
+mod length;
+mod line_quality;
+mod objection;
 mod processing;
 mod rag;
+mod repair;
 mod response;
+mod supervisor;
+mod tool_memory;
 mod tools;
+mod translation;
+mod usage;
 
 use parking_lot::RwLock;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::broadcast;
 
@@ -31,29 +67,39 @@ use voice_agent_llm::{LlmFactory, SpeculativeExecutor};
 use voice_agent_core::LanguageModel;
 // P8 FIX: Import AgentDomainView for config-driven domain abstraction
 use voice_agent_config::domain::AgentDomainView;
-use voice_agent_tools::ToolRegistry;
+use voice_agent_tools::ToolExecutor;
 // P1 FIX: Import RAG components for retrieval-augmented generation
-use voice_agent_rag::{AgenticRetriever, SearchResult, VectorStore};
+use voice_agent_rag::{AgenticRetriever, KnowledgeBase, SearchResult, VectorStore};
 // P4 FIX: Import personalization engine for dynamic response adaptation
 use voice_agent_core::personalization::{PersonalizationContext, PersonalizationEngine};
 // P5 FIX: Import translator for Translate-Think-Translate pattern
-use voice_agent_core::{Language, Translator};
+use voice_agent_core::{Language, Speaker, Translator};
 use voice_agent_text_processing::translation::{
     CandleIndicTrans2Config, CandleIndicTrans2Translator,
 };
 
 use crate::conversation::{Conversation, ConversationContext, EndReason};
-use crate::dst::DialogueStateTracker;
+use crate::dst::{ChangeSource, DialogueStateTrait, DialogueStateTracker};
 use crate::lead_scoring::{LeadRecommendation, LeadScore, LeadScoringEngine};
 use crate::persuasion::{PersuasionEngine, PersuasionStrategy};
+use crate::abuse_policy::AbusePolicy;
+use crate::guardrails::Guardrails;
+use crate::response_governor::ResponseGovernor;
+use crate::response_cache::ResponseCache;
 use crate::stage::ConversationStage;
 use crate::AgentError;
 
 // Re-export config types for backwards compatibility
 pub use crate::agent_config::{
-    is_small_model, AgentConfig, AgentEvent, PersonaTraits, SmallModelConfig,
-    SpeculativeDecodingConfig, ToolDefaults,
+    is_small_model, AgentConfig, AgentEvent, ParallelToolConfig, PersonaTraits, SmallModelConfig,
+    SpeculativeDecodingConfig, ToolDefaults, ToolMemoryConfig, TranslateThinkConfig,
 };
+pub use supervisor::Whisper;
+
+/// Confidence for facts preloaded from prior sessions
+///
+/// Below the DST auto-confirm threshold so stale facts get re-confirmed.
+const PRELOADED_FACT_CONFIDENCE: f32 = 0.8;
 
 /// Prefetch cache entry
 #[derive(Debug, Clone)]
@@ -79,14 +125,45 @@ pub struct DomainAgent {
     pub(crate) config: AgentConfig,
     /// Phase 2: Uses ConversationContext trait for domain-agnostic conversation management
     pub(crate) conversation: Arc<dyn ConversationContext>,
-    pub(crate) tools: Arc<ToolRegistry>,
+    pub(crate) tools: Arc<dyn ToolExecutor>,
     /// P1 FIX: Now uses LanguageModel trait instead of LlmBackend for proper abstraction
-    pub(crate) llm: Option<Arc<dyn LanguageModel>>,
+    /// Replaceable after session creation (e.g. by a shared LLM router)
+    pub(crate) llm: RwLock<Option<Arc<dyn LanguageModel>>>,
     /// Phase 11: Agentic RAG retriever for multi-step retrieval with query rewriting
     /// Replaces simple HybridRetriever with iterative retrieval flow
     pub(crate) agentic_retriever: Option<Arc<AgenticRetriever>>,
     /// P1 FIX: Vector store for RAG search (optional, can be injected)
     pub(crate) vector_store: Option<Arc<VectorStore>>,
+    /// Ingested knowledge base (per-domain, with citations); set after session creation
+    pub(crate) knowledge_base: RwLock<Option<Arc<KnowledgeBase>>>,
+    /// Shared cache of deterministic tool and FAQ answers; set after session creation
+    pub(crate) response_cache: RwLock<Option<Arc<ResponseCache>>>,
+    /// Compliance guardrails on LLM output; set after session creation
+    pub(crate) guardrails: RwLock<Option<Arc<Guardrails>>>,
+    /// Spoken-duration budget for answers; set after session creation
+    pub(crate) response_governor: RwLock<Option<Arc<ResponseGovernor>>>,
+    /// Abusive-speech policy; set after session creation
+    pub(crate) abuse_policy: RwLock<Option<Arc<AbusePolicy>>>,
+    /// Abuse incidents and pending de-escalation for this session
+    pub(crate) abuse_state: RwLock<abuse::AbuseState>,
+    /// Caller line quality reported by the pipeline
+    pub(crate) line_state: RwLock<line_quality::LineState>,
+    /// Supervisor guidance added to every prompt (never spoken)
+    pub(crate) whispers: RwLock<Vec<supervisor::Whisper>>,
+    /// Tool calls and barge-ins counted for analytics
+    pub(crate) session_stats: RwLock<analytics::SessionStats>,
+    /// Read-only tool results kept for follow-up questions
+    pub(crate) tool_memory: RwLock<tool_memory::ToolMemory>,
+    /// Write-ahead journal of this session's turns; attached after session creation
+    pub(crate) journal: Arc<RwLock<journal::JournalState>>,
+    /// Tokens, speech and calls consumed per turn; store attached after session creation
+    pub(crate) usage: RwLock<usage::UsageState>,
+    /// LLM token and duration limits; set after session creation
+    pub(crate) budget: RwLock<Option<voice_agent_config::SessionBudget>>,
+    /// Disposition code assigned when the call ended
+    pub(crate) disposition: RwLock<Option<crate::disposition::Disposition>>,
+    /// Shared domain event bus; set after session creation
+    pub(crate) event_bus: RwLock<Option<voice_agent_core::EventBus>>,
     pub(crate) event_tx: broadcast::Sender<AgentEvent>,
     /// P2 FIX: Prefetch cache for VAD → RAG prefetch optimization
     pub(crate) prefetch_cache: RwLock<Option<PrefetchEntry>>,
@@ -276,9 +353,24 @@ impl DomainAgent {
             config,
             conversation,
             tools,
-            llm,
+            llm: RwLock::new(llm),
             agentic_retriever,
             vector_store: None,
+            knowledge_base: RwLock::new(None),
+            response_cache: RwLock::new(None),
+            guardrails: RwLock::new(None),
+            response_governor: RwLock::new(None),
+            abuse_policy: RwLock::new(None),
+            abuse_state: RwLock::new(abuse::AbuseState::default()),
+            line_state: RwLock::new(line_quality::LineState::default()),
+            whispers: RwLock::new(Vec::new()),
+            session_stats: RwLock::new(analytics::SessionStats::default()),
+            tool_memory: RwLock::new(tool_memory::ToolMemory::default()),
+            journal: Arc::new(RwLock::new(journal::JournalState::default())),
+            usage: RwLock::new(usage::UsageState::default()),
+            budget: RwLock::new(None),
+            disposition: RwLock::new(None),
+            event_bus: RwLock::new(None),
             event_tx,
             prefetch_cache: RwLock::new(None),
             personalization,
@@ -414,9 +506,24 @@ impl DomainAgent {
             config: config.clone(),
             conversation,
             tools,
-            llm: Some(llm),
+            llm: RwLock::new(Some(llm)),
             agentic_retriever,
             vector_store: None,
+            knowledge_base: RwLock::new(None),
+            response_cache: RwLock::new(None),
+            guardrails: RwLock::new(None),
+            response_governor: RwLock::new(None),
+            abuse_policy: RwLock::new(None),
+            abuse_state: RwLock::new(abuse::AbuseState::default()),
+            line_state: RwLock::new(line_quality::LineState::default()),
+            whispers: RwLock::new(Vec::new()),
+            session_stats: RwLock::new(analytics::SessionStats::default()),
+            tool_memory: RwLock::new(tool_memory::ToolMemory::default()),
+            journal: Arc::new(RwLock::new(journal::JournalState::default())),
+            usage: RwLock::new(usage::UsageState::default()),
+            budget: RwLock::new(None),
+            disposition: RwLock::new(None),
+            event_bus: RwLock::new(None),
             event_tx,
             prefetch_cache: RwLock::new(None),
             personalization,
@@ -499,9 +606,24 @@ impl DomainAgent {
             config: config.clone(),
             conversation,
             tools,
-            llm: None,
+            llm: RwLock::new(None),
             agentic_retriever,
             vector_store: None,
+            knowledge_base: RwLock::new(None),
+            response_cache: RwLock::new(None),
+            guardrails: RwLock::new(None),
+            response_governor: RwLock::new(None),
+            abuse_policy: RwLock::new(None),
+            abuse_state: RwLock::new(abuse::AbuseState::default()),
+            line_state: RwLock::new(line_quality::LineState::default()),
+            whispers: RwLock::new(Vec::new()),
+            session_stats: RwLock::new(analytics::SessionStats::default()),
+            tool_memory: RwLock::new(tool_memory::ToolMemory::default()),
+            journal: Arc::new(RwLock::new(journal::JournalState::default())),
+            usage: RwLock::new(usage::UsageState::default()),
+            budget: RwLock::new(None),
+            disposition: RwLock::new(None),
+            event_bus: RwLock::new(None),
             event_tx,
             prefetch_cache: RwLock::new(None),
             personalization,
@@ -522,8 +644,20 @@ impl DomainAgent {
         self
     }
 
+    /// Set the knowledge base used for cited RAG context
+    pub fn set_knowledge_base(&self, knowledge_base: Arc<KnowledgeBase>) {
+        *self.knowledge_base.write() = Some(knowledge_base);
+    }
+
+    /// Replace the language model used for responses and memory summarization
+    pub fn set_language_model(&self, llm: Arc<dyn LanguageModel>) {
+        self.conversation.memory().set_llm(llm.clone());
+        self.conversation.agentic_memory().set_llm(llm.clone());
+        *self.llm.write() = Some(llm);
+    }
+
     /// P0 FIX: Set custom tool registry (with persistence wired)
-    pub fn with_tools(mut self, tools: Arc<ToolRegistry>) -> Self {
+    pub fn with_tools(mut self, tools: Arc<dyn ToolExecutor>) -> Self {
         self.tools = tools;
         self
     }
@@ -589,6 +723,32 @@ impl DomainAgent {
         self.user_language
     }
 
+    /// Set who spoke the next utterance on a conference call
+    ///
+    /// `None` outside conference mode. The co-applicant's own name and phone
+    /// are kept apart from the customer's, and the reply is addressed to them.
+    pub fn set_speaker(&self, speaker: Option<Speaker>) {
+        self.dialogue_state.write().set_speaker(speaker);
+    }
+
+    /// Count the STT confidence of the transcript about to be processed
+    ///
+    /// After repeated badly heard turns the agent asks for answers on the
+    /// keypad; returns whether this transcript switched it over. On a
+    /// degraded line a low-confidence answer is asked for again.
+    pub fn record_stt_confidence(&self, confidence: f32) -> bool {
+        self.line_state.write().last_confidence = Some(confidence);
+        self.dialogue_state.write().hear(confidence)
+    }
+
+    /// Take a key (DTMF tone) the customer pressed
+    ///
+    /// Returns the answer it completes as text to process as the customer's
+    /// turn, or `None` while more keys are expected.
+    pub fn press_key(&self, key: char) -> Option<String> {
+        self.dialogue_state.write().press_key(key)
+    }
+
     /// Subscribe to agent events
     pub fn subscribe(&self) -> broadcast::Receiver<AgentEvent> {
         self.event_tx.subscribe()
@@ -604,6 +764,28 @@ impl DomainAgent {
         &self.conversation
     }
 
+    /// Trim the last response to what the caller heard before barging in
+    ///
+    /// `spoken` comes from the TTS playback position (`SpokenUpTo`), so the
+    /// next LLM turn doesn't assume the caller heard the whole response.
+    pub fn record_interruption(&self, spoken: &str) {
+        let spoken = spoken.trim();
+        let content = if spoken.is_empty() {
+            "[interrupted before speaking]".to_string()
+        } else {
+            format!("{} [interrupted]", spoken)
+        };
+
+        self.conversation.memory().amend_last_assistant(&content);
+        let amended = self
+            .conversation
+            .agentic_memory()
+            .amend_last_assistant_turn(&content);
+        self.session_stats.write().interruptions += 1;
+        self.publish_event(voice_agent_core::DomainEvent::BargeIn);
+        tracing::debug!(amended, spoken, "Recorded barge-in on agent response");
+    }
+
     /// P1 FIX: Get agent configuration
     pub fn config(&self) -> &AgentConfig {
         &self.config
@@ -705,6 +887,11 @@ impl DomainAgent {
         lead_scoring.calculate_score()
     }
 
+    /// Score from the most recent turn, without recalculating
+    pub fn last_lead_score(&self) -> Option<LeadScore> {
+        self.lead_scoring.read().last_score().cloned()
+    }
+
     /// Phase 10: Get lead signals (read-only)
     pub fn get_lead_signals(&self) -> crate::lead_scoring::LeadSignals {
         self.lead_scoring.read().signals().clone()
@@ -739,6 +926,119 @@ impl DomainAgent {
         lead_scoring.reset();
     }
 
+    /// Preload what we know about a customer from prior sessions
+    ///
+    /// Known facts become pending DST slots (the agent re-confirms rather than
+    /// assumes them) and human-block facts in core memory. Returning customers
+    /// also get a context note summarizing earlier sessions, so the LLM can
+    /// open with "welcome back" and pick up where the last call left off.
+    pub fn preload_customer(&self, identity: &voice_agent_core::CustomerIdentity) {
+        let memory = self.conversation.agentic_memory();
+
+        if let Some(ref name) = identity.name {
+            self.set_customer_name(name.clone());
+            memory.core.set_customer_name(name);
+        }
+        if let Some(ref language) = identity.preferred_language {
+            memory.core.set_customer_language(language);
+        }
+        if let Some(ref segment_id) = identity.segment {
+            self.set_segment_id(segment_id.clone());
+        }
+
+        {
+            let mut dst = self.dialogue_state.write();
+            for (slot, value) in &identity.facts {
+                dst.update_slot(slot, value, PRELOADED_FACT_CONFIDENCE, ChangeSource::External, 0);
+            }
+        }
+
+        for (slot, value) in &identity.facts {
+            let fact_key = self
+                .domain_view
+                .as_ref()
+                .map(|v| v.canonical_fact_key(slot.as_str()))
+                .unwrap_or(slot.as_str());
+            if let Err(e) = memory.core_memory_append(fact_key, value) {
+                tracing::debug!(fact = fact_key, error = %e, "Skipped preloaded customer fact");
+            }
+        }
+
+        let session_id = self.conversation.session_id();
+        if identity.is_returning(session_id) {
+            memory
+                .core
+                .add_context_note(&identity.prior_context_summary(session_id));
+        }
+
+        tracing::info!(
+            session_id = %session_id,
+            facts = identity.facts.len(),
+            prior_sessions = identity.session_count().saturating_sub(1),
+            "Preloaded customer identity"
+        );
+    }
+
+    /// Promote durable facts from this session into the customer's long-term memory
+    ///
+    /// Call when the session ends. Slot names are mapped to fact keys through
+    /// the domain's slot aliases.
+    pub fn consolidate_customer_memory(
+        &self,
+        customer_id: &str,
+    ) -> crate::memory::ConsolidationReport {
+        let canonical_key = |key: &str| match self.domain_view {
+            Some(ref view) => view.canonical_fact_key(key).to_string(),
+            None => key.to_string(),
+        };
+        self.conversation
+            .agentic_memory()
+            .consolidate_to_customer(customer_id, &canonical_key)
+    }
+
+    /// Load facts promoted in the customer's earlier sessions into core memory
+    pub async fn load_customer_memory(
+        &self,
+        customer_id: &str,
+        limit: usize,
+    ) -> Result<usize, AgentError> {
+        self.conversation
+            .agentic_memory()
+            .load_customer_memory(customer_id, limit)
+            .await
+    }
+
+    /// Facts collected in this session, keyed by DST slot name
+    ///
+    /// Used to update the customer identity when the session ends.
+    pub fn customer_facts(&self) -> HashMap<String, String> {
+        let dst = self.dialogue_state.read();
+        let state = dst.state();
+        state
+            .filled_slots()
+            .into_iter()
+            .filter_map(|slot| state.get_slot_value(slot).map(|v| (slot.to_string(), v)))
+            .collect()
+    }
+
+    /// Current conversation goal from DST
+    pub fn dialogue_goal(&self) -> String {
+        self.dialogue_state.read().goal_id().to_string()
+    }
+
+    /// Advance the conversation stage once its success criteria are met
+    ///
+    /// Slots filled by the DST count towards the stage's required info.
+    fn advance_stage(&self) {
+        let stage_manager = self.conversation.stage_manager();
+        for (slot, value) in self.customer_facts() {
+            stage_manager.record_info(&slot, &value);
+        }
+        if let Some(stage) = self.conversation.advance_stage() {
+            tracing::debug!(stage = ?stage, "Conversation stage advanced");
+        }
+    }
+
     /// End conversation
     pub fn end(&self, reason: EndReason) {
         self.conversation.end(reason);
@@ -821,28 +1121,6 @@ impl PersonalizableAgent for DomainAgent {
     }
 }
 
-/// P0-2 FIX: Find the position of a sentence boundary in text
-fn find_sentence_end(text: &str, terminators: &[char]) -> Option<usize> {
-    for (i, c) in text.char_indices() {
-        if terminators.contains(&c) {
-            let next_pos = i + c.len_utf8();
-            if next_pos >= text.len() {
-                return Some(i);
-            }
-
-            if let Some(next_char) = text[next_pos..].chars().next() {
-                if next_char.is_whitespace() || next_char == '\n' {
-                    return Some(i);
-                }
-                if c == '।' || c == '॥' {
-                    return Some(i);
-                }
-            }
-        }
-    }
-    None
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -938,6 +1216,89 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_record_interruption_trims_history() {
+        let agent = DomainAgent::without_llm("test-barge-in", AgentConfig::default());
+        agent.process("Hello").await.unwrap();
+
+        agent.record_interruption("Namaste, main ");
+
+        let turns = agent.conversation().agentic_memory().get_all_turns();
+        let last = turns.last().unwrap();
+        assert_eq!(last.content, "Namaste, main [interrupted]");
+        let messages = agent.conversation().memory().get_recent_messages();
+        assert_eq!(messages.last().unwrap().1, "Namaste, main [interrupted]");
+        assert_eq!(agent.interruption_count(), 1);
+        assert_eq!(agent.stages_reached().first().map(String::as_str), Some("greeting"));
+    }
+
+    #[test]
+    fn test_preload_returning_customer() {
+        let agent = DomainAgent::without_llm("call-2", AgentConfig::default());
+
+        let mut identity = voice_agent_core::CustomerIdentity::new("9876543210").unwrap();
+        identity.name = Some("Rahul".to_string());
+        identity.last_goal = Some("balance_transfer".to_string());
+        identity.merge_facts([("current_lender", "Muthoot")]);
+        identity.link_session("call-1", voice_agent_core::Channel::Voice);
+        identity.link_session("call-2", voice_agent_core::Channel::WhatsApp);
+
+        agent.preload_customer(&identity);
+
+        assert_eq!(
+            agent.personalization_context().customer_name.as_deref(),
+            Some("Rahul")
+        );
+        assert_eq!(
+            agent.customer_facts().get("current_lender").map(String::as_str),
+            Some("Muthoot")
+        );
+        // Preloaded facts must be re-confirmed with the customer
+        assert!(agent
+            .dialogue_state
+            .read()
+            .slots_needing_confirmation()
+            .contains(&"current_lender"));
+
+        let human = agent.conversation().agentic_memory().core.human_snapshot();
+        assert!(human
+            .context_notes
+            .iter()
+            .any(|n| n.contains("Last discussed: balance_transfer")));
+    }
+
+    #[tokio::test]
+    async fn test_recover_session_from_journal() {
+        let journal = Arc::new(voice_agent_persistence::InMemorySessionJournal::new());
+
+        let agent = DomainAgent::without_llm("call-1", AgentConfig::default());
+        agent.set_journal(journal.clone());
+        agent.process("Hello").await.unwrap();
+        agent.dialogue_state.write().update_slot(
+            "gold_weight",
+            "50",
+            0.95,
+            ChangeSource::UserUtterance,
+            1,
+        );
+        agent.process("Theek hai").await.unwrap();
+        // Let the background write finish, then write anything left
+        tokio::task::yield_now().await;
+        agent.flush_journal().await.unwrap();
+
+        // Same session ID in a new process
+        let restored = DomainAgent::without_llm("call-1", AgentConfig::default());
+        restored.set_journal(journal);
+        assert_eq!(restored.recover_from_journal().await.unwrap(), 4);
+
+        assert_eq!(restored.conversation().turn_count(), 4);
+        assert_eq!(restored.stage(), agent.stage());
+        let facts = restored.customer_facts();
+        assert_eq!(facts.get("gold_weight").map(String::as_str), Some("50"));
+        let turns = restored.conversation().agentic_memory().get_all_turns();
+        assert_eq!(turns.first().map(|t| t.content.as_str()), Some("Hello"));
+    }
+
     #[tokio::test]
     async fn test_prefetch_requires_rag_components() {
         let agent = DomainAgent::without_llm("test-prefetch", AgentConfig::default());
@@ -1065,4 +1426,309 @@ mod tests {
         assert!(config.use_extractive_compression);
         assert!(config.disable_llm_query_rewriting);
     }
+
+    /// Tags translations with the target language code
+    struct TaggingTranslator;
+
+    #[async_trait::async_trait]
+    impl Translator for TaggingTranslator {
+        async fn translate(
+            &self,
+            text: &str,
+            _from: Language,
+            to: Language,
+        ) -> voice_agent_core::Result<String> {
+            Ok(format!("<{}> {}", to.code(), text))
+        }
+
+        async fn detect_language(&self, text: &str) -> voice_agent_core::Result<Language> {
+            let devanagari = text.chars().any(|c| ('\u{0900}'..='\u{097F}').contains(&c));
+            Ok(if devanagari { Language::Hindi } else { Language::English })
+        }
+
+        fn translate_stream<'a>(
+            &'a self,
+            text_stream: std::pin::Pin<Box<dyn futures::Stream<Item = String> + Send + 'a>>,
+            _from: Language,
+            _to: Language,
+        ) -> std::pin::Pin<Box<dyn futures::Stream<Item = voice_agent_core::Result<String>> + Send + 'a>>
+        {
+            use futures::StreamExt;
+            Box::pin(text_stream.map(Ok))
+        }
+
+        fn supports_pair(&self, _from: Language, _to: Language) -> bool {
+            true
+        }
+
+        fn name(&self) -> &str {
+            "tagging"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_translate_think_translate_detects_turn_language() {
+        let agent = DomainAgent::without_llm("test-ttt", AgentConfig::default())
+            .with_translator(Arc::new(TaggingTranslator));
+
+        // English turns in an English session are not translated
+        let response = agent.process("Hello").await.unwrap();
+        assert!(!response.starts_with('<'), "got: {}", response);
+
+        // A Hindi turn is answered in Hindi
+        let response = agent.process("मुझे 5 लाख का लोन चाहिए").await.unwrap();
+        assert!(response.starts_with("<hi> "), "got: {}", response);
+    }
+
+    #[tokio::test]
+    async fn test_handoff_context_and_transfer_signal() {
+        use crate::agent_config::HandoffStatus;
+        use crate::conversation::ConversationState;
+
+        let agent = DomainAgent::new("test-handoff", AgentConfig::default(), test_domain_config());
+        let _ = agent.process("Hello").await.unwrap();
+
+        let mut args = serde_json::Map::new();
+        agent.apply_handoff_context(&mut args);
+        assert_eq!(args["session_id"], "test-handoff");
+        let turns = args["handoff_context"]["recent_turns"].as_array().unwrap();
+        assert!(!turns.is_empty());
+        assert_eq!(args["handoff_context"]["stage"], agent.stage().as_str());
+
+        let mut events = agent.subscribe();
+        agent.signal_handoff(
+            "ESC-1",
+            HandoffStatus::Connected,
+            Some("agent-3".into()),
+            None,
+        );
+        assert_eq!(agent.conversation().state(), ConversationState::Paused);
+        assert!(matches!(
+            events.recv().await.unwrap(),
+            AgentEvent::Handoff {
+                status: HandoffStatus::Connected,
+                ..
+            }
+        ));
+
+        agent.signal_handoff("ESC-1", HandoffStatus::Failed, None, None);
+        assert_eq!(agent.conversation().state(), ConversationState::Active);
+    }
+
+    #[tokio::test]
+    async fn test_whispers_reach_prompt_guidance_only() {
+        let agent = DomainAgent::new("test-whisper", AgentConfig::default(), test_domain_config());
+        assert!(agent.whisper_guidance().is_none());
+
+        agent.whisper("Mention the doorstep service", Some("sup-1".to_string()));
+        agent.whisper("   ", None);
+        for i in 0..6 {
+            agent.whisper(&format!("tip {}", i), None);
+        }
+
+        let whispers = agent.whispers();
+        assert_eq!(whispers.len(), 5);
+        assert_eq!(whispers[0].text, "tip 1");
+        let guidance = agent.whisper_guidance().unwrap();
+        assert!(guidance.contains("- tip 5"));
+        assert!(!guidance.contains("doorstep"));
+
+        agent.clear_whispers();
+        assert!(agent.whisper_guidance().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_degraded_line_asks_for_repeat() {
+        use voice_agent_core::{AudioQuality, LineIssue};
+
+        let agent = DomainAgent::new("test-line", AgentConfig::default(), test_domain_config());
+        let mut quality = AudioQuality {
+            snr_db: Some(8.0),
+            noise_floor_db: Some(-30.0),
+            clipping: 0.0,
+            packet_loss: 0.0,
+            issue: Some(LineIssue::Noise),
+        };
+        agent.set_line_quality(quality);
+        agent.record_stt_confidence(0.9);
+        let guidance = agent.line_quality_guidance().unwrap();
+        assert!(guidance.contains("noisy"));
+        assert!(!guidance.contains("repeat it"));
+
+        agent.record_stt_confidence(0.4);
+        let guidance = agent.line_quality_guidance().unwrap();
+        assert!(guidance.contains("could you repeat the amount?"));
+
+        quality.issue = None;
+        agent.set_line_quality(quality);
+        assert!(agent.line_quality_guidance().is_none());
+        assert_eq!(agent.line_degradations(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_spent_budget_wraps_up_call() {
+        let agent = DomainAgent::without_llm("test-budget", AgentConfig::default());
+        let mut budget = voice_agent_config::BudgetConfig::default().session_budget("default");
+        budget.max_llm_tokens = 1_000;
+        agent.set_session_budget(budget.clone());
+
+        let tokens = |llm_prompt_tokens| voice_agent_persistence::UsageCounts {
+            llm_prompt_tokens,
+            ..Default::default()
+        };
+        agent.usage.write().add(&tokens(900));
+        assert!(agent.budget_guidance().unwrap().contains("Wrapping Up"));
+        agent.process("Hello").await.unwrap();
+
+        agent.usage.write().add(&tokens(100));
+        let reply = agent.process("What about the rate?").await.unwrap();
+        assert_eq!(reply, budget.wrap_up_messages["en"]);
+        assert!(agent.process("Hello?").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_classify_disposition_from_session_signals() {
+        let agent = DomainAgent::without_llm("test-disposition", AgentConfig::default());
+        agent.process("Hello").await.unwrap();
+        assert!(agent.disposition().is_none());
+
+        let classifier = crate::disposition::DispositionClassifier::new(
+            voice_agent_config::DispositionConfig::default(),
+        );
+        let disposition = agent.classify_disposition(&classifier).await;
+        assert_eq!(disposition.code, "dropped");
+        assert_eq!(agent.disposition(), Some(disposition));
+    }
+
+    #[tokio::test]
+    async fn test_failure_reply_by_error_kind() {
+        let agent = DomainAgent::new("test-failure", AgentConfig::default(), test_domain_config());
+
+        assert_eq!(
+            agent.failure_reply(&AgentError::Timeout).as_deref(),
+            Some(voice_agent_core::ErrorKind::Timeout.default_phrase())
+        );
+        assert!(agent
+            .failure_reply(&AgentError::Conversation("Conversation has ended".into()))
+            .is_none());
+    }
+
+    /// Answers after the number of milliseconds in the tool name ("wait_300")
+    struct SlowTools;
+
+    #[async_trait::async_trait]
+    impl ToolExecutor for SlowTools {
+        async fn execute(
+            &self,
+            name: &str,
+            _arguments: serde_json::Value,
+        ) -> Result<voice_agent_tools::ToolOutput, voice_agent_tools::ToolError> {
+            let ms = name.trim_start_matches("wait_").parse().unwrap_or(0);
+            tokio::time::sleep(std::time::Duration::from_millis(ms)).await;
+            Ok(voice_agent_tools::ToolOutput::text(name))
+        }
+
+        fn list_tools(&self) -> Vec<voice_agent_tools::ToolSchema> {
+            Vec::new()
+        }
+
+        fn get_tool(&self, _name: &str) -> Option<voice_agent_tools::ToolSchema> {
+            None
+        }
+    }
+
+    #[tokio::test]
+    async fn test_llm_tool_calls_run_concurrently_in_call_order() {
+        let mut config = AgentConfig::default();
+        config.parallel_tools.turn_budget_ms = 600;
+        let agent =
+            DomainAgent::without_llm("test-parallel", config).with_tools(Arc::new(SlowTools));
+
+        let call = |name: &str| voice_agent_llm::ParsedToolCall {
+            name: name.to_string(),
+            arguments: serde_json::json!({}),
+            text_before: String::new(),
+            text_after: String::new(),
+        };
+        let calls = [
+            call("wait_300"),
+            call("wait_10"),
+            call("wait_5000"),
+            call("wait_300"),
+        ];
+
+        let started = std::time::Instant::now();
+        let results = agent.execute_llm_tool_calls(&calls).await;
+        let elapsed = started.elapsed();
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0], "Tool 'wait_300' result:\nwait_300");
+        assert_eq!(results[1], "Tool 'wait_10' result:\nwait_10");
+        assert!(
+            results[2].starts_with("Tool 'wait_5000' failed"),
+            "got: {}",
+            results[2]
+        );
+        assert_eq!(results[3], "Tool 'wait_300' result:\nwait_300");
+        // Sequential execution would need 300 + 10 + 600 + 300 ms
+        assert!(
+            elapsed < std::time::Duration::from_millis(900),
+            "took {:?}",
+            elapsed
+        );
+    }
+
+    /// Records the arguments of every call it executes
+    #[derive(Default)]
+    struct RecordingTools {
+        calls: parking_lot::Mutex<Vec<(String, serde_json::Value)>>,
+    }
+
+    #[async_trait::async_trait]
+    impl ToolExecutor for RecordingTools {
+        async fn execute(
+            &self,
+            name: &str,
+            arguments: serde_json::Value,
+        ) -> Result<voice_agent_tools::ToolOutput, voice_agent_tools::ToolError> {
+            self.calls.lock().push((name.to_string(), arguments));
+            Ok(voice_agent_tools::ToolOutput::text("{\"success\": true}"))
+        }
+
+        fn list_tools(&self) -> Vec<voice_agent_tools::ToolSchema> {
+            Vec::new()
+        }
+
+        fn get_tool(&self, _name: &str) -> Option<voice_agent_tools::ToolSchema> {
+            None
+        }
+    }
+
+    #[tokio::test]
+    async fn test_llm_lead_capture_carries_lead_score() {
+        let tools = Arc::new(RecordingTools::default());
+        let agent = DomainAgent::without_llm("test-llm-capture", AgentConfig::default())
+            .with_tools(tools.clone());
+        let score = agent.get_lead_score();
+
+        let call = voice_agent_llm::ParsedToolCall {
+            name: "capture_lead".to_string(),
+            arguments: serde_json::json!({ "customer_name": "Ramesh" }),
+            text_before: String::new(),
+            text_after: String::new(),
+        };
+        let result = agent.execute_llm_tool_call(&call).await;
+        assert!(result.starts_with("Tool 'capture_lead' result:"), "got: {}", result);
+
+        let calls = tools.calls.lock();
+        let (name, arguments) = &calls[0];
+        assert_eq!(name, "capture_lead");
+        assert_eq!(arguments["customer_name"], "Ramesh");
+        assert_eq!(arguments["lead_score"], serde_json::json!(score.total));
+        assert_eq!(
+            arguments["lead_qualification"],
+            format!("{:?}", score.qualification)
+        );
+        assert!(arguments.get("interest_level").is_some());
+    }
 }
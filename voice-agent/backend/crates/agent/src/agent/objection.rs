@@ -0,0 +1,63 @@
+//! Objection Handling for DomainAgent
+//!
+//! Each caller turn is classified against the domain's objection patterns
+//! (objections.yaml). A detected objection is recorded on the DST, which
+//! makes addressing it the next best action, and its playbook (acknowledge,
+//! reframe, evidence, call to action) goes into the prompt. The sales flow
+//! resumes on the first turn without an objection.
+
+use voice_agent_llm::{PromptBuilder, SectionKind};
+
+use super::DomainAgent;
+use crate::dst::DialogueStateTrait;
+
+impl DomainAgent {
+    /// Classify the caller's turn and record (or clear) its objection
+    pub(super) fn track_objection(&self, english_input: &str) {
+        let objection = self
+            .persuasion
+            .detect_objection(english_input, self.user_language);
+        if let Some(ref objection) = objection {
+            tracing::debug!(objection = %objection, "Objection detected");
+        }
+        self.dialogue_state
+            .write()
+            .state_mut()
+            .set_objection(objection);
+    }
+
+    /// Add the open objection's playbook and the next best action
+    pub(super) fn with_objection_guidance(&self, mut builder: PromptBuilder) -> PromptBuilder {
+        let (objection, instruction) = {
+            let dst = self.dialogue_state.read();
+            let action = dst.state().next_best_action();
+            let instruction = dst.instruction_for_action(&action, self.user_language.code());
+            (
+                dst.state().active_objection().map(String::from),
+                instruction,
+            )
+        };
+
+        let playbook = objection.and_then(|objection| {
+            self.persuasion
+                .get_response_by_id(&objection, self.user_language)
+        });
+        if let Some(response) = playbook {
+            let guidance = format!(
+                "## Objection Handling Guidance\n\
+                The customer has raised a concern. Address it before moving the conversation forward:\n\
+                1. **Acknowledge**: {}\n\
+                2. **Reframe**: {}\n\
+                3. **Evidence**: {}\n\
+                4. **Call to Action**: {}",
+                response.acknowledge, response.reframe, response.evidence, response.call_to_action
+            );
+            builder = builder.with_section(SectionKind::Guidance, &guidance);
+        }
+
+        builder.with_section(
+            SectionKind::Guidance,
+            &format!("## Next Best Action\n{}", instruction),
+        )
+    }
+}
@@ -4,67 +4,65 @@
 //! - process() - Main turn processing
 //! - process_stream() - Streaming turn processing
 //! - build_llm_request() - LLM request construction
+//!
+//! Streaming output is run through `StreamingToolCallParser` so text-format
+//! tool calls are dry-run and executed (or repaired, see `repair`) instead of
+//! spoken.
+//! Every answer is fitted to the spoken-duration budget and passes the output
+//! guardrails before it reaches the caller.
 
 use futures::StreamExt;
 
-use super::{find_sentence_end, DomainAgent};
+use super::guardrails::Guarded;
+use super::repair::ToolCallRepair;
+use super::usage::estimate_prompt_tokens;
+use super::DomainAgent;
 use crate::agent_config::AgentEvent;
 use crate::conversation::ConversationEvent;
 use crate::dst::DialogueStateTrait;
-use crate::lead_scoring::{EscalationTrigger, LeadRecommendation};
+use crate::lead_scoring::{DialogueSignals, EscalationTrigger, LeadRecommendation};
 use crate::memory::{ConversationTurn, TurnRole};
+use crate::stable_prefix::StablePrefixDetector;
 use crate::AgentError;
-use voice_agent_core::Language;
-use voice_agent_llm::{Message, PromptBuilder, Role};
+use voice_agent_core::{LlmTask, ToolDefinition};
+use voice_agent_llm::{
+    tool_call_reask_prompt, validate_tool_call, Message, ParsedToolCall, PromptBuilder, Role,
+    SectionKind, StreamingToolCallParser, ToolCallError, ToolStreamEvent,
+};
 use voice_agent_rag::QueryContext;
+use voice_agent_text_processing::disfluency;
 
 impl DomainAgent {
     /// Process user input and generate response
     ///
     /// P5 FIX: Implements Translate-Think-Translate pattern:
-    /// 1. If user language is not English, translate input to English
+    /// 1. If the turn is not in English, translate input to English
     /// 2. Process with LLM (which works best in English)
     /// 3. Translate response back to user's language
+    ///
+    /// Numbers and slot values are kept verbatim across both translations.
     pub async fn process(&self, user_input: &str) -> Result<String, AgentError> {
         // Emit thinking event
         let _ = self.event_tx.send(AgentEvent::Thinking);
 
-        // P5 FIX: Translate user input to English if needed
-        let english_input = if self.user_language != Language::English {
-            if let Some(ref translator) = self.translator {
-                match translator
-                    .translate(user_input, self.user_language, Language::English)
-                    .await
-                {
-                    Ok(translated) => {
-                        tracing::debug!(
-                            from = ?self.user_language,
-                            original = %user_input,
-                            translated = %translated,
-                            "Translated user input to English"
-                        );
-                        translated
-                    }
-                    Err(e) => {
-                        tracing::warn!(
-                            error = %e,
-                            "Translation failed, using original input"
-                        );
-                        user_input.to_string()
-                    }
-                }
-            } else {
-                user_input.to_string()
-            }
-        } else {
-            user_input.to_string()
-        };
+        let cleaned = self.clean_transcript(user_input);
+        let user_input = cleaned.as_str();
 
         // Add user turn and detect intent
-        let intent = self.conversation.add_user_turn(user_input)?;
+        let stage_before = self.stage();
+        let mut intent = self.add_user_turn(user_input)?;
+        // On a conference call the co-applicant's own details get their own slots
+        self.dialogue_state
+            .read()
+            .attribute_slots(&mut intent.slots);
+
+        // P5 FIX: Translate user input to English if needed
+        let turn = self.translate_input(user_input, &intent).await;
+        let english_input = turn.english_input.as_str();
+        self.track_objection(english_input);
 
         // Add to MemGPT-style agentic memory recall
-        let turn = ConversationTurn::new(TurnRole::User, user_input)
+        let memory_turn = ConversationTurn::new(TurnRole::User, user_input)
             .with_intents(vec![intent.intent.clone()])
             .with_entities(
                 intent
@@ -74,7 +72,7 @@ impl DomainAgent {
                     .collect(),
             )
             .with_stage(self.conversation.stage().display_name());
-        self.conversation.agentic_memory().add_turn(turn);
+        self.conversation.agentic_memory().add_turn(memory_turn);
 
         // Log memory state
         let stats = self.conversation.agentic_memory().get_stats();
@@ -130,12 +128,24 @@ impl DomainAgent {
         }
 
         // Phase 5: Update Dialogue State Tracker with detected intent
+        let dialogue_before = self.dialogue_snapshot();
         {
             let mut dst = self.dialogue_state.write();
+            // An answer to the last question ("haan", "the second one") is
+            // resolved before this turn's extraction
+            if dst.resolve_answer(user_input).is_none() {
+                dst.resolve_answer(english_input);
+            }
+            if let Some((name, confidence)) = dst.clarified_intent() {
+                self.apply_clarified_intent(&mut intent, name, confidence);
+            }
+            // Digits or letters of a number being dictated over several turns
+            dst.compose(user_input);
             dst.update(&intent);
 
             let turn = dst.history().len();
             dst.update_goal_from_intent(&intent.intent, turn);
+            dst.expect_answer();
 
             tracing::debug!(
                 primary_intent = ?dst.state().primary_intent(),
@@ -145,6 +155,14 @@ impl DomainAgent {
                 "Dialogue state updated"
             );
         }
+        if let Some(before) = dialogue_before {
+            self.publish_dialogue_changes(before);
+        }
+
+        // Move to the next stage once this one's success criteria are met,
+        // so the prompt below carries the new stage's guidance
+        self.advance_stage();
+        self.publish_stage_change(stage_before);
 
         // P4 FIX: Process input through personalization engine
         {
@@ -161,6 +179,8 @@ impl DomainAgent {
             let mut lead_scoring = self.lead_scoring.write();
 
             lead_scoring.update_urgency(user_input);
+            lead_scoring.update_sentiment(user_input);
+            lead_scoring.update_from_dialogue(&self.dialogue_signals());
 
             let slot_values: std::collections::HashMap<String, String> = intent
                 .slots
@@ -201,12 +221,42 @@ impl DomainAgent {
                 intent.clone(),
             )));
 
+        // A spent budget ends the call with a wrap-up instead of an answer
+        if let Some(reply) = self.enforce_budget() {
+            self.add_assistant_turn(&reply)?;
+            self.end_for_budget();
+            let _ = self.event_tx.send(AgentEvent::Response(reply.clone()));
+            return Ok(reply);
+        }
+
+        // Abusive turns get a warning or a handoff instead of an answer
+        if let Some(reply) = self.screen_abuse(user_input) {
+            self.add_assistant_turn(&reply)?;
+            let _ = self.event_tx.send(AgentEvent::Response(reply.clone()));
+            return Ok(reply);
+        }
+
+        // Unsure between two intents: ask which one instead of guessing
+        if let Some(question) = self.clarify_intent(&intent) {
+            self.add_assistant_turn(&question)?;
+            let _ = self.event_tx.send(AgentEvent::Response(question.clone()));
+            return Ok(question);
+        }
+
+        // Repeated questions (gold price, documents, branch timings) reuse an
+        // earlier answer and skip the tool and LLM calls
+        let cached_response = self.cached_response(&intent, user_input);
+
         // Check for tool calls based on intent
-        let tool_result = if self.config.tools_enabled {
-            self.maybe_call_tool(&intent).await?
+        let intent_tool = if self.config.tools_enabled && cached_response.is_none() {
+            self.resolve_intent_tool(&intent)
         } else {
             None
         };
+        let tool_result = match intent_tool {
+            Some(ref name) => self.call_intent_tool(name, &intent).await?,
+            None => None,
+        };
 
         // Phase 12: Auto-capture lead when we have contact info
         if self.config.tools_enabled {
@@ -226,44 +276,32 @@ impl DomainAgent {
             }
         }
 
-        // Build prompt for LLM
-        let english_response = self
-            .generate_response(&english_input, tool_result.as_deref())
-            .await?;
-
-        // P5 FIX: Translate response back to user's language if needed
-        let response = if self.user_language != Language::English {
-            if let Some(ref translator) = self.translator {
-                match translator
-                    .translate(&english_response, Language::English, self.user_language)
-                    .await
-                {
-                    Ok(translated) => {
-                        tracing::debug!(
-                            to = ?self.user_language,
-                            original = %english_response,
-                            translated = %translated,
-                            "Translated response to user language"
-                        );
-                        translated
-                    }
-                    Err(e) => {
-                        tracing::warn!(
-                            error = %e,
-                            "Response translation failed, using English response"
-                        );
-                        english_response
+        let response = match cached_response {
+            Some(response) => response,
+            None => {
+                // Build prompt for LLM
+                let english_response = self
+                    .generate_response(english_input, tool_result.as_deref())
+                    .await?;
+                let english_response = self.govern_response(english_response).await;
+
+                match self.guard_response(english_response) {
+                    // Safe responses are already localized and never cached
+                    Guarded::Blocked(safe_response) => safe_response,
+                    Guarded::Allowed(english_response) => {
+                        // P5 FIX: Translate response back to user's language if needed
+                        let response = self.translate_reply(&english_response, &turn).await;
+
+                        let answer_tool = tool_result.as_ref().and(intent_tool.as_deref());
+                        self.cache_response(&intent, user_input, &response, answer_tool);
+                        response
                     }
                 }
-            } else {
-                english_response
             }
-        } else {
-            english_response
         };
 
         // Add assistant turn
-        self.conversation.add_assistant_turn(&response)?;
+        self.add_assistant_turn(&response)?;
 
         // Add to MemGPT-style agentic memory recall
         let assistant_turn = ConversationTurn::new(TurnRole::Assistant, &response)
@@ -297,15 +335,22 @@ impl DomainAgent {
             }
         }
 
-        // Check agentic memory compaction
+        // Summarize evicted turns off the response path
         if self.conversation.agentic_memory().needs_compaction() {
-            let stats = self.conversation.agentic_memory().get_stats();
+            let memory = self.conversation.agentic_memory().clone();
+            let stats = memory.get_stats();
             tracing::debug!(
                 core_tokens = stats.core_tokens,
                 fifo_tokens = stats.fifo_tokens,
+                summary_tokens = stats.summary_tokens,
                 archival_count = stats.archival_count,
-                "Agentic memory high watermark exceeded"
+                "Scheduling agentic memory compaction"
             );
+            tokio::spawn(async move {
+                if let Err(e) = memory.compact().await {
+                    tracing::warn!("Agentic memory compaction failed: {}", e);
+                }
+            });
         }
 
         // Phase 10: Calculate lead score and emit events
@@ -393,22 +438,29 @@ impl DomainAgent {
         // Emit thinking event
         let _ = self.event_tx.send(AgentEvent::Thinking);
 
-        // P5 FIX: Translate user input to English if needed
-        let english_input = if self.user_language != Language::English {
-            if let Some(ref translator) = self.translator {
-                translator
-                    .translate(user_input, self.user_language, Language::English)
-                    .await
-                    .unwrap_or_else(|_| user_input.to_string())
-            } else {
-                user_input.to_string()
-            }
-        } else {
-            user_input.to_string()
-        };
+        let cleaned = self.clean_transcript(user_input);
+        let user_input = cleaned.as_str();
 
         // Add user turn and detect intent
-        let intent = self.conversation.add_user_turn(user_input)?;
+        let stage_before = self.stage();
+        let mut intent = self.add_user_turn(user_input)?;
+        // On a conference call the co-applicant's own details get their own slots
+        self.dialogue_state
+            .read()
+            .attribute_slots(&mut intent.slots);
+        {
+            let mut dst = self.dialogue_state.write();
+            if let Some((name, confidence)) = dst.resolve_clarification(user_input) {
+                self.apply_clarified_intent(&mut intent, name, confidence);
+            }
+        }
+        self.advance_stage();
+        self.publish_stage_change(stage_before);
+
+        // P5 FIX: Translate user input to English if needed
+        let turn = self.translate_input(user_input, &intent).await;
+        let english_input = turn.english_input.as_str();
+        self.track_objection(english_input);
 
         // P4 FIX: Process through personalization engine
         {
@@ -423,107 +475,310 @@ impl DomainAgent {
                 intent.clone(),
             )));
 
+        // Create output channel
+        let (tx, rx) = tokio::sync::mpsc::channel::<String>(32);
+
+        if let Some(reply) = self.enforce_budget() {
+            self.add_assistant_turn(&reply)?;
+            self.end_for_budget();
+            let _ = self.event_tx.send(AgentEvent::Response(reply.clone()));
+            let _ = tx.send(reply).await;
+            return Ok(rx);
+        }
+
+        // Abusive turns get a warning or a handoff instead of an answer
+        if let Some(reply) = self.screen_abuse(user_input) {
+            self.add_assistant_turn(&reply)?;
+            let _ = self.event_tx.send(AgentEvent::Response(reply.clone()));
+            let _ = tx.send(reply).await;
+            return Ok(rx);
+        }
+
+        if let Some(question) = self.clarify_intent(&intent) {
+            self.add_assistant_turn(&question)?;
+            let _ = self.event_tx.send(AgentEvent::Response(question.clone()));
+            let _ = tx.send(question).await;
+            return Ok(rx);
+        }
+
+        if let Some(response) = self.cached_response(&intent, user_input) {
+            self.add_assistant_turn(&response)?;
+            let _ = self.event_tx.send(AgentEvent::Response(response.clone()));
+            let _ = tx.send(response).await;
+            return Ok(rx);
+        }
+
         // Check for tool calls
-        let tool_result = if self.config.tools_enabled {
-            self.maybe_call_tool(&intent).await?
+        let intent_tool = if self.config.tools_enabled {
+            self.resolve_intent_tool(&intent)
         } else {
             None
         };
+        let tool_result = match intent_tool {
+            Some(ref name) => self.call_intent_tool(name, &intent).await?,
+            None => None,
+        };
+
+        // Offer tools in the prompt too; text-format tool calls are parsed
+        // out of the token stream below
+        let mut tool_defs = if self.config.tools_enabled {
+            self.enabled_tool_definitions()
+        } else {
+            Vec::new()
+        };
 
         // Build prompt
-        let prompt_request = self
-            .build_llm_request(&english_input, tool_result.as_deref())
+        let mut prompt_request = self
+            .build_llm_request_with_tools(english_input, tool_result.as_deref(), &tool_defs)
             .await?;
-
-        // Create output channel
-        let (tx, rx) = tokio::sync::mpsc::channel::<String>(32);
+        if !tool_defs.is_empty() {
+            prompt_request.task = Some(LlmTask::ToolCalling);
+        }
 
         // Check if LLM is available for streaming
-        if let Some(ref llm) = self.llm {
+        let llm = self.llm.read().clone();
+        if let Some(ref llm) = llm {
             if llm.is_available().await {
-                let mut stream = llm.generate_stream(prompt_request);
-
-                let translator = &self.translator;
-                let user_language = self.user_language;
-                let terminators = user_language.sentence_terminators();
+                // Clauses translated out of context read badly; a translated
+                // reply is still released a sentence at a time
+                let translated_reply =
+                    self.config.translate_think.enabled && turn.languages.translates_reply();
+                let mut detector = StablePrefixDetector::new(
+                    self.config.early_tts.clone(),
+                    self.user_language.sentence_terminators(),
+                    !translated_reply,
+                );
 
-                let mut buffer = String::new();
                 let mut full_response = String::new();
+                // What was actually sent, after the guardrails (English)
+                let mut spoken = String::new();
+                let mut blocked: Option<String> = None;
+                // Question for a tool argument the customer still has to give
+                let mut asked: Option<String> = None;
+                // The next sentence would have gone over the spoken-duration budget
+                let mut over_budget = false;
+                let mut reasked = false;
+                let mut receiver_dropped = false;
+                let mut llm_tool: Option<String> = None;
+
+                // One pass per LLM stream: the answer itself, plus at most one re-ask
+                // for malformed tool calls and one follow-up carrying their results
+                loop {
+                    // A later pass supersedes whatever the last one left unreleased
+                    detector.restart();
+                    let prompt_tokens = estimate_prompt_tokens(llm.as_ref(), &prompt_request);
+                    let mut generated = String::new();
+                    let mut stream = llm.generate_stream(prompt_request.clone());
+                    let mut parser = StreamingToolCallParser::new();
+                    let mut tool_calls: Vec<Result<ParsedToolCall, ToolCallError>> = Vec::new();
+                    let mut finished = false;
+
+                    while !finished {
+                        let events = match stream.next().await {
+                            Some(Ok(chunk)) => {
+                                finished = chunk.is_final;
+                                generated.push_str(&chunk.delta);
+                                let mut events = parser.feed(&chunk.delta);
+                                if finished {
+                                    events.extend(parser.finish());
+                                }
+                                events
+                            }
+                            Some(Err(e)) => {
+                                tracing::warn!("LLM stream error: {}", e);
+                                finished = true;
+                                parser.finish()
+                            }
+                            None => {
+                                finished = true;
+                                parser.finish()
+                            }
+                        };
 
-                while let Some(result) = stream.next().await {
-                    match result {
-                        Ok(chunk) => {
-                            buffer.push_str(&chunk.delta);
-                            full_response.push_str(&chunk.delta);
-
-                            while let Some(pos) = find_sentence_end(&buffer, terminators) {
-                                let sentence = buffer[..=pos].trim().to_string();
-                                buffer = buffer[pos + 1..].to_string();
-
-                                if sentence.is_empty() {
-                                    continue;
+                        for event in events {
+                            match event {
+                                // Text after a tool call is superseded by the follow-up
+                                ToolStreamEvent::Text(text) if tool_calls.is_empty() => {
+                                    detector.push(&text);
+                                    full_response.push_str(&text);
+                                }
+                                ToolStreamEvent::ToolCallStarted => {
+                                    tracing::debug!("Tool call detected in LLM stream");
+                                }
+                                ToolStreamEvent::ToolCall(call) => {
+                                    tool_calls.push(validate_tool_call(&call, &tool_defs));
                                 }
+                                ToolStreamEvent::Malformed { raw, error } => {
+                                    tool_calls.push(Err(ToolCallError::Malformed { raw, error }));
+                                }
+                                _ => {}
+                            }
+                        }
 
-                                let translated = if user_language != Language::English {
-                                    if let Some(ref t) = translator {
-                                        t.translate(&sentence, Language::English, user_language)
-                                            .await
-                                            .unwrap_or(sentence)
-                                    } else {
-                                        sentence
-                                    }
-                                } else {
-                                    sentence
-                                };
+                        while let Some(sentence) = detector.next_release() {
+                            if !self.admits_sentence(&spoken, &sentence) {
+                                over_budget = true;
+                                break;
+                            }
 
-                                if tx.send(translated).await.is_err() {
-                                    tracing::debug!("Stream receiver dropped");
+                            let sentence = match self.guard_sentence(sentence) {
+                                Some(Guarded::Allowed(sentence)) => sentence,
+                                Some(Guarded::Blocked(safe_response)) => {
+                                    if tx.send(safe_response.clone()).await.is_err() {
+                                        receiver_dropped = true;
+                                    }
+                                    blocked = Some(safe_response);
                                     break;
                                 }
+                                None => continue,
+                            };
+                            if !spoken.is_empty() {
+                                spoken.push(' ');
                             }
+                            spoken.push_str(&sentence);
 
-                            if chunk.is_final {
+                            let translated = self.translate_reply(&sentence, &turn).await;
+
+                            if tx.send(translated).await.is_err() {
+                                tracing::debug!("Stream receiver dropped");
+                                receiver_dropped = true;
                                 break;
                             }
                         }
-                        Err(e) => {
-                            tracing::warn!("LLM stream error: {}", e);
+
+                        if receiver_dropped || blocked.is_some() || over_budget {
                             break;
                         }
                     }
+                    self.record_llm_usage(llm.as_ref(), prompt_tokens, None, &generated);
+
+                    if receiver_dropped || blocked.is_some() || over_budget {
+                        break;
+                    }
+
+                    // Once a tool has run, the follow-up may not call another
+                    let model_retried = reasked || tool_defs.is_empty();
+                    let language = self.user_language.code();
+                    let mut calls = Vec::new();
+                    let mut errors = Vec::new();
+                    let mut question = None;
+                    for checked in tool_calls {
+                        match self.repair_tool_call(checked, model_retried, language) {
+                            ToolCallRepair::Execute(call) => calls.push(call),
+                            ToolCallRepair::AskModel(error) => errors.push(error),
+                            ToolCallRepair::AskCustomer(q) => {
+                                question = Some(q);
+                                break;
+                            },
+                            ToolCallRepair::Abandon => {},
+                        }
+                    }
+
+                    if let Some(question) = question {
+                        let _ = tx.send(question.clone()).await;
+                        asked = Some(question);
+                        break;
+                    }
+                    if !errors.is_empty() {
+                        let error = errors.join("\n");
+                        tracing::warn!(error = %error, "Unusable tool call in stream, re-asking");
+                        reasked = true;
+                        prompt_request
+                            .messages
+                            .push(Message::user(tool_call_reask_prompt(&error, &tool_defs)));
+                        continue;
+                    }
+                    let Some(first) = calls.first() else {
+                        break;
+                    };
+
+                    llm_tool = Some(first.name.clone());
+                    let results = self.execute_llm_tool_calls(&calls).await;
+                    // The follow-up answers from the results; no further tool calls
+                    tool_defs.clear();
+                    prompt_request = self
+                        .build_llm_request(english_input, Some(&results.join("\n\n")))
+                        .await?;
                 }
 
                 // Flush remaining buffer
-                if !buffer.trim().is_empty() {
-                    let sentence = buffer.trim().to_string();
-                    let translated = if user_language != Language::English {
-                        if let Some(ref t) = translator {
-                            t.translate(&sentence, Language::English, user_language)
-                                .await
-                                .unwrap_or(sentence)
-                        } else {
-                            sentence
+                let rest = detector.flush().filter(|rest| {
+                    !receiver_dropped
+                        && blocked.is_none()
+                        && asked.is_none()
+                        && !over_budget
+                        && self.admits_sentence(&spoken, rest)
+                });
+                if let Some(rest) = rest {
+                    match self.guard_sentence(rest) {
+                        Some(Guarded::Allowed(sentence)) => {
+                            if !spoken.is_empty() {
+                                spoken.push(' ');
+                            }
+                            spoken.push_str(&sentence);
+
+                            let translated = self.translate_reply(&sentence, &turn).await;
+                            let _ = tx.send(translated).await;
                         }
-                    } else {
-                        sentence
-                    };
-                    let _ = tx.send(translated).await;
+                        Some(Guarded::Blocked(safe_response)) => {
+                            let _ = tx.send(safe_response.clone()).await;
+                            blocked = Some(safe_response);
+                        }
+                        None => {}
+                    }
                 }
 
-                // Update conversation with full response
-                let final_response = if user_language != Language::English {
-                    if let Some(ref t) = translator {
-                        t.translate(&full_response, Language::English, user_language)
-                            .await
-                            .unwrap_or(full_response.clone())
+                // The guardrails dropped every sentence of the answer
+                if blocked.is_none()
+                    && asked.is_none()
+                    && spoken.is_empty()
+                    && !full_response.trim().is_empty()
+                    && !receiver_dropped
+                {
+                    if let Some(safe_response) = self.guardrail_safe_response() {
+                        let _ = tx.send(safe_response.clone()).await;
+                        blocked = Some(safe_response);
+                    }
+                }
+
+                // Update conversation with what the caller actually heard
+                let answer = if spoken.is_empty() {
+                    full_response.clone()
+                } else {
+                    spoken.clone()
+                };
+                let final_response = if let Some(ref safe_response) = blocked {
+                    safe_response.clone()
+                } else if let Some(ref question) = asked {
+                    // Configured in the caller's language already
+                    if spoken.is_empty() {
+                        question.clone()
                     } else {
-                        full_response.clone()
+                        format!("{} {}", self.translate_reply(&spoken, &turn).await, question)
                     }
+                } else if answer.trim().is_empty() {
+                    // Nothing speakable came back (e.g. only a broken tool call)
+                    let fallback = self.generate_mock_response(user_input, tool_result.as_deref());
+                    let _ = tx.send(fallback.clone()).await;
+                    fallback
                 } else {
-                    full_response.clone()
+                    self.translate_reply(&answer, &turn).await
                 };
 
-                if let Err(e) = self.conversation.add_assistant_turn(&final_response) {
+                // Safe responses and argument questions are already localized
+                // and never cached
+                if blocked.is_none()
+                    && asked.is_none()
+                    && !answer.trim().is_empty()
+                    && !receiver_dropped
+                {
+                    let answer_tool = llm_tool
+                        .as_deref()
+                        .or_else(|| tool_result.as_ref().and(intent_tool.as_deref()));
+                    self.cache_response(&intent, user_input, &final_response, answer_tool);
+                }
+
+                if let Err(e) = self.add_assistant_turn(&final_response) {
                     tracing::warn!("Failed to add assistant turn: {}", e);
                 }
 
@@ -535,7 +790,7 @@ impl DomainAgent {
 
         // Fallback: No LLM available
         let response = self.generate_mock_response(user_input, tool_result.as_deref());
-        self.conversation.add_assistant_turn(&response)?;
+        self.add_assistant_turn(&response)?;
         let _ = self.event_tx.send(AgentEvent::Response(response.clone()));
 
         let _ = tx.send(response).await;
@@ -548,6 +803,17 @@ impl DomainAgent {
         &self,
         english_input: &str,
         tool_result: Option<&str>,
+    ) -> Result<voice_agent_core::GenerateRequest, AgentError> {
+        self.build_llm_request_with_tools(english_input, tool_result, &[])
+            .await
+    }
+
+    /// Build LLM request with text-format tool definitions in the system prompt
+    pub(super) async fn build_llm_request_with_tools(
+        &self,
+        english_input: &str,
+        tool_result: Option<&str>,
+        tools: &[ToolDefinition],
     ) -> Result<voice_agent_core::GenerateRequest, AgentError> {
         let persona = self.config.persona.clone();
 
@@ -570,19 +836,46 @@ impl DomainAgent {
                  Configure domain YAML files for production use."
             );
             // Add minimal system message
-            builder = builder.with_context("You are a helpful assistant.");
+            builder = builder.with_section(SectionKind::System, "You are a helpful assistant.");
         }
 
+        builder = builder.with_tools(tools);
+
         // Add personalization instructions
         {
             let ctx = self.personalization_ctx.read();
             let instructions = self.personalization.generate_instructions(&ctx);
             if !instructions.is_empty() {
-                builder =
-                    builder.with_context(&format!("## Personalization Guidance\n{}", instructions));
+                builder = builder.with_section(
+                    SectionKind::Personalization,
+                    &format!("## Personalization Guidance\n{}", instructions),
+                );
             }
         }
 
+        // Keep the tone calm after the caller swore
+        if let Some(guidance) = self.deescalation_guidance() {
+            builder = builder.with_section(
+                SectionKind::Guidance,
+                &format!("## Tone\n{}", guidance),
+            );
+        }
+
+        // Coaching from a supervisor monitoring the call
+        if let Some(guidance) = self.whisper_guidance() {
+            builder = builder.with_section(SectionKind::Guidance, &guidance);
+        }
+
+        // Ask again rather than guess at answers from a bad line
+        if let Some(guidance) = self.line_quality_guidance() {
+            builder = builder.with_section(SectionKind::Guidance, &guidance);
+        }
+
+        // Close out a call that is nearly over budget
+        if let Some(guidance) = self.budget_guidance() {
+            builder = builder.with_section(SectionKind::Guidance, &guidance);
+        }
+
         // Add memory context with query-based archival retrieval
         let stage = self.conversation.stage();
         // P1.5 FIX: Use config-driven context budget, fall back to hardcoded defaults
@@ -622,7 +915,7 @@ impl DomainAgent {
         }
 
         if !context.is_empty() {
-            builder = builder.with_context(&context);
+            builder = builder.with_section(SectionKind::Memory, &context);
         }
 
         // Phase 5 + Phase 12: Add DST state context with goal tracking
@@ -640,7 +933,7 @@ impl DomainAgent {
                         dst.slots_needing_confirmation().join(", ")
                     }
                 );
-                builder = builder.with_context(&dst_section);
+                builder = builder.with_section(SectionKind::DialogueState, &dst_section);
             }
 
             let human_block = self.conversation.agentic_memory().core.human_snapshot();
@@ -651,12 +944,47 @@ impl DomainAgent {
                     .map(|(k, entry)| format!("- {}: {}", k, entry.value))
                     .collect::<Vec<_>>()
                     .join("\n");
-                builder =
-                    builder.with_context(&format!("## Customer Facts from Memory\n{}", facts_str));
+                builder = builder.with_section(
+                    SectionKind::CustomerProfile,
+                    &format!("## Customer Facts from Memory\n{}", facts_str),
+                );
             }
 
             let goal_id = dst.goal_id();
-            builder = builder.with_context(&format!("Current Goal: {}", goal_id));
+            builder = builder
+                .with_section(SectionKind::DialogueState, &format!("Current Goal: {}", goal_id));
+            if let Some(answer) = dst.answer_context() {
+                builder = builder.with_section(SectionKind::DialogueState, &answer);
+            }
+            if let Some(dictation) = dst.composition_context(self.user_language.code()) {
+                builder = builder.with_section(SectionKind::DialogueState, &dictation);
+            }
+            if let Some(held_back) = dst.held_back_context() {
+                builder = builder.with_section(SectionKind::DialogueState, &held_back);
+            }
+            if let Some(speaker) = dst.speaker_context() {
+                builder = builder.with_section(SectionKind::DialogueState, &speaker);
+            }
+            if let Some(keypad) = dst.keypad_context(self.user_language.code()) {
+                builder = builder.with_section(SectionKind::DialogueState, &keypad);
+            }
+
+            if let Some(resumed) = dst.state().resumed_goal() {
+                let topic = dst
+                    .slots_config()
+                    .get_goal(resumed)
+                    .map(|goal| goal.description.as_str())
+                    .unwrap_or(resumed);
+                builder = builder.with_section(
+                    SectionKind::Guidance,
+                    &format!(
+                        "## Resuming Earlier Question
+The customer's earlier question ({}) was put on hold. \
+                        Briefly tell them you are coming back to it, then continue with it.",
+                        topic
+                    ),
+                );
+            }
 
             tracing::debug!(
                 goal = %goal_id,
@@ -670,6 +998,15 @@ impl DomainAgent {
             let rag_fraction = stage.rag_context_fraction();
 
             if rag_fraction > 0.0 {
+                // Ingested product sheets / policies, cited by number
+                let kb_results = ((rag_fraction * 10.0).ceil() as usize).clamp(1, 5);
+                if let Some(knowledge) = self.knowledge_context(english_input, kb_results) {
+                    builder = builder.with_section(
+                        SectionKind::Knowledge,
+                        &format!("## Knowledge Base (cite sources as [n])\n{}", knowledge),
+                    );
+                }
+
                 if let (Some(agentic_retriever), Some(vector_store)) =
                     (&self.agentic_retriever, &self.vector_store)
                 {
@@ -720,8 +1057,10 @@ impl DomainAgent {
                             .map(|r| format!("- {}", r.content))
                             .collect::<Vec<_>>()
                             .join("\n");
-                        builder = builder
-                            .with_context(&format!("## Relevant Information\n{}", rag_context));
+                        builder = builder.with_section(
+                            SectionKind::Knowledge,
+                            &format!("## Relevant Information\n{}", rag_context),
+                        );
                     }
                 }
             }
@@ -729,35 +1068,20 @@ impl DomainAgent {
 
         // Add tool result
         if let Some(result) = tool_result {
-            builder = builder.with_context(&format!("## Tool Result\n{}", result));
+            builder = builder
+                .with_section(SectionKind::ToolResult, &format!("## Tool Result\n{}", result));
         }
 
-        // Add stage guidance from config if domain_view is available
-        if let Some(ref view) = self.domain_view {
-            let stage_name = self.conversation.stage().as_str();
-            if let Some(guidance) = view.stage_guidance(stage_name) {
-                builder = builder.with_stage_guidance_from_config(guidance, view.prompts_config());
-            }
-        }
+        // Results of earlier tool calls, for follow-up questions
+        builder = self.with_tool_memory(builder);
 
-        // Add persuasion guidance
-        if let Some(objection_response) = self
-            .persuasion
-            .handle_objection(english_input, self.user_language)
-        {
-            let guidance = format!(
-                "## Objection Handling Guidance\n\
-                1. **Acknowledge**: {}\n\
-                2. **Reframe**: {}\n\
-                3. **Evidence**: {}\n\
-                4. **Call to Action**: {}",
-                objection_response.acknowledge,
-                objection_response.reframe,
-                objection_response.evidence,
-                objection_response.call_to_action
-            );
-            builder = builder.with_context(&guidance);
-        }
+        // Annotated exchanges like this turn, for the tool-call format
+        builder = self.with_examples(builder, english_input);
+
+        builder = self.with_stage_guidance(builder);
+
+        // Objection playbook (when the caller raised one) and next best action
+        builder = self.with_objection_guidance(builder);
 
         // Add conversation history
         let history: Vec<Message> = self
@@ -795,4 +1119,69 @@ impl DomainAgent {
 
         Ok(builder.build_request_with_limit(effective_budget))
     }
+
+    /// Strip fillers, stutters and false starts from the ASR transcript
+    /// before intent and slot extraction, logging both forms
+    fn clean_transcript(&self, user_input: &str) -> String {
+        let cleaned = disfluency::clean(user_input, self.user_language.code());
+        if cleaned != user_input {
+            tracing::debug!(raw = %user_input, cleaned = %cleaned, "Disfluencies removed");
+        }
+        cleaned
+    }
+
+    /// Add the current stage's guidance: prompts config (keyed by stage
+    /// name), else the built-in text
+    pub(super) fn with_stage_guidance(&self, builder: PromptBuilder) -> PromptBuilder {
+        let stage = self.conversation.stage();
+        let prompts = self.domain_view.as_ref().map(|v| v.prompts_config());
+        match prompts {
+            Some(prompts) if prompts.get_stage_guidance(stage.as_str()).is_some() => {
+                builder.with_stage_guidance_from_config(stage.as_str(), prompts)
+            },
+            _ => builder.with_section(
+                SectionKind::Guidance,
+                &format!("## Current Stage Guidance\n{}", stage.guidance()),
+            ),
+        }
+    }
+
+    /// Collect dialogue-state inputs for lead scoring
+    ///
+    /// Slot names come from the domain's `scoring.dialogue` config.
+    fn dialogue_signals(&self) -> DialogueSignals {
+        let default_config;
+        let cfg = match self.domain_view.as_ref() {
+            Some(view) => &view.scoring_config().dialogue,
+            None => {
+                default_config = voice_agent_config::domain::DialogueScoringConfig::default();
+                &default_config
+            }
+        };
+        let parse_number = |value: String| value.replace(',', "").trim().parse::<f64>().ok();
+
+        let dst = self.dialogue_state.read();
+        let state = dst.state();
+
+        let loan_amount = state.get_slot_value(&cfg.amount_slot).and_then(parse_number);
+        let current_rate = state
+            .get_slot_value(&cfg.current_rate_slot)
+            .and_then(parse_number)
+            .or_else(|| {
+                let provider = state.get_slot_value(&cfg.current_provider_slot)?;
+                self.domain_view.as_ref()?.lender_rate(&provider.to_lowercase())
+            });
+        let offered_rate = match (self.domain_view.as_ref(), current_rate) {
+            (Some(view), Some(_)) => Some(view.our_rate_for_amount(loan_amount.unwrap_or(0.0))),
+            _ => None,
+        };
+
+        DialogueSignals {
+            urgency_level: state.get_slot_value(&cfg.urgency_slot),
+            loan_amount,
+            goal_completion: state.completion_for_goal(dst.goal_id()),
+            current_rate,
+            offered_rate,
+        }
+    }
 }
@@ -4,8 +4,9 @@
 //! - Prefetch on partial transcript
 //! - Background prefetch
 //! - Prefetch cache management
+//! - Knowledge base context with citations
 
-use voice_agent_rag::SearchResult;
+use voice_agent_rag::{format_knowledge_context, SearchResult};
 
 use super::{DomainAgent, PrefetchEntry};
 
@@ -186,4 +187,31 @@ impl DomainAgent {
     pub fn clear_prefetch_cache(&self) {
         *self.prefetch_cache.write() = None;
     }
+
+    /// Retrieve cited knowledge-base context for the prompt
+    ///
+    /// Searches the index of the agent's domain, preferring chunks in the
+    /// user's language. Returns `None` when no knowledge base is attached
+    /// or nothing relevant matched.
+    pub(crate) fn knowledge_context(&self, query: &str, max_results: usize) -> Option<String> {
+        let knowledge_base = self.knowledge_base.read().clone()?;
+        let domain = self.domain_view.as_ref()?.domain_id().to_string();
+
+        match knowledge_base.retrieve(&domain, query, Some(self.user_language.code()), max_results) {
+            Ok(hits) if !hits.is_empty() => {
+                tracing::debug!(
+                    domain = %domain,
+                    hits = hits.len(),
+                    top_score = hits[0].score,
+                    "Knowledge base context added"
+                );
+                Some(format_knowledge_context(&hits))
+            },
+            Ok(_) => None,
+            Err(e) => {
+                tracing::warn!(domain = %domain, error = %e, "Knowledge base retrieval failed");
+                None
+            },
+        }
+    }
 }
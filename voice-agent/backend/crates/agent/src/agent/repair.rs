@@ -0,0 +1,111 @@
+//! Tool-Call Repair for DomainAgent
+//!
+//! Every tool call the LLM proposes is dry-run against the tool's schema
+//! (required arguments, types, ranges, formats) before anything executes.
+//! A call that fails is repaired instead of run with bad values:
+//!
+//! - a required argument the customer has not given yet is asked of the
+//!   customer with the parameter's configured question; sending it back to
+//!   the model would only invite a made-up value
+//! - anything else goes back to the model once, with the exact errors
+//! - if the model's retry is still unusable, the customer is asked for the
+//!   first bad argument, or the turn is answered without the tool
+
+use voice_agent_llm::{ParsedToolCall, ToolCallError};
+
+use super::DomainAgent;
+use crate::dst::DialogueStateTrait;
+
+/// What to do with a proposed tool call after its dry run
+#[derive(Debug)]
+pub(super) enum ToolCallRepair {
+    /// The call is valid (arguments coerced); run it
+    Execute(ParsedToolCall),
+    /// Show the model what was wrong and let it try again
+    AskModel(String),
+    /// Ask the customer for a missing or invalid value
+    AskCustomer(String),
+    /// Answer without the tool
+    Abandon,
+}
+
+impl DomainAgent {
+    /// Decide how to handle a dry-run tool call
+    ///
+    /// `model_retried` is set once the model has already had its chance to
+    /// fix a call this turn. Questions for the customer are in `language`.
+    pub(super) fn repair_tool_call(
+        &self,
+        checked: Result<ParsedToolCall, ToolCallError>,
+        model_retried: bool,
+        language: &str,
+    ) -> ToolCallRepair {
+        let error = match checked {
+            Ok(call) => return ToolCallRepair::Execute(call),
+            Err(error) => error,
+        };
+
+        if let ToolCallError::InvalidArguments {
+            tool,
+            missing,
+            invalid,
+            ..
+        } = &error
+        {
+            let question = if model_retried {
+                missing
+                    .iter()
+                    .chain(invalid)
+                    .find_map(|argument| self.argument_question(tool, argument, language))
+            } else {
+                missing
+                    .iter()
+                    .filter(|argument| !self.dialogue_has_argument(tool, argument))
+                    .find_map(|argument| self.argument_question(tool, argument, language))
+            };
+            if let Some(question) = question {
+                tracing::info!(
+                    tool = %tool,
+                    missing = ?missing,
+                    invalid = ?invalid,
+                    "Tool call held back, asking the customer"
+                );
+                return ToolCallRepair::AskCustomer(question);
+            }
+        }
+
+        if model_retried {
+            tracing::warn!(error = %error, "Tool call still unusable, answering without it");
+            ToolCallRepair::Abandon
+        } else {
+            tracing::debug!(error = %error, "Tool call failed its dry run, re-asking the model");
+            ToolCallRepair::AskModel(error.to_string())
+        }
+    }
+
+    /// Configured question for a tool argument
+    fn argument_question(&self, tool: &str, argument: &str, language: &str) -> Option<String> {
+        self.domain_view
+            .as_ref()?
+            .tools_config()
+            .argument_question(tool, argument, language)
+            .map(str::to_string)
+    }
+
+    /// Whether a slot filled earlier in the call holds this argument, so the
+    /// model can take it from the conversation
+    fn dialogue_has_argument(&self, tool: &str, argument: &str) -> bool {
+        let dst = self.dialogue_state.read();
+        let filled = dst.state().filled_slots();
+        filled.iter().any(|slot| {
+            *slot == argument
+                || self.domain_view.as_ref().is_some_and(|view| {
+                    view.map_slot_to_argument(tool, slot) == argument
+                        || view
+                            .get_common_argument_mappings()
+                            .get(*slot)
+                            .is_some_and(|mapped| mapped == argument)
+                })
+        })
+    }
+}
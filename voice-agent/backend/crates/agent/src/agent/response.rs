@@ -5,13 +5,16 @@
 //! - Mock/fallback responses
 //! - Stage-aware response adaptation
 
+use super::repair::ToolCallRepair;
+use super::usage::estimate_prompt_tokens;
 use super::DomainAgent;
 use crate::stage::ConversationStage;
 use crate::AgentError;
-use voice_agent_core::{FinishReason, ToolDefinition};
-use voice_agent_llm::{Message, PromptBuilder, Role};
+use voice_agent_core::{FinishReason, TokenUsage, ToolDefinition};
+use voice_agent_llm::{
+    validate_tool_call, Message, ParsedToolCall, PromptBuilder, Role, SectionKind,
+};
 use voice_agent_rag::QueryContext;
-use voice_agent_tools::ToolExecutor;
 
 impl DomainAgent {
     /// Generate response using LLM
@@ -41,7 +44,7 @@ impl DomainAgent {
                 "No domain_view configured - using minimal system prompt. \
                  Configure domain YAML files for production use."
             );
-            builder = builder.with_context("You are a helpful assistant.");
+            builder = builder.with_section(SectionKind::System, "You are a helpful assistant.");
         }
 
         // P4 FIX: Add personalization instructions based on detected signals
@@ -50,10 +53,10 @@ impl DomainAgent {
             let ctx = self.personalization_ctx.read();
             let personalization_instructions = self.personalization.generate_instructions(&ctx);
             if !personalization_instructions.is_empty() {
-                builder = builder.with_context(&format!(
-                    "## Personalization Guidance\n{}",
-                    personalization_instructions
-                ));
+                builder = builder.with_section(
+                    SectionKind::Personalization,
+                    &format!("## Personalization Guidance\n{}", personalization_instructions),
+                );
                 tracing::trace!(
                     instructions_len = personalization_instructions.len(),
                     "Added personalization instructions to prompt"
@@ -72,7 +75,7 @@ impl DomainAgent {
             .unwrap_or_else(|| stage.context_budget_tokens());
         let context = self.conversation.get_context_for_query(user_input, context_budget);
         if !context.is_empty() {
-            builder = builder.with_context(&context);
+            builder = builder.with_section(SectionKind::Memory, &context);
         }
 
         // P1 FIX: Add RAG context if retriever and vector store are available
@@ -89,6 +92,15 @@ impl DomainAgent {
 
             // Skip RAG entirely for stages that don't need it (greeting, farewell)
             if rag_fraction > 0.0 {
+                // Ingested product sheets / policies, cited by number
+                let kb_results = ((rag_fraction * 10.0).ceil() as usize).clamp(1, 5);
+                if let Some(knowledge) = self.knowledge_context(user_input, kb_results) {
+                    builder = builder.with_section(
+                        SectionKind::Knowledge,
+                        &format!("## Knowledge Base (cite sources as [n])\n{}", knowledge),
+                    );
+                }
+
                 // Phase 11: Use AgenticRetriever for multi-step retrieval
                 if let (Some(agentic_retriever), Some(vector_store)) =
                     (&self.agentic_retriever, &self.vector_store)
@@ -147,8 +159,10 @@ impl DomainAgent {
                             .map(|r| format!("- {}", r.content))
                             .collect::<Vec<_>>()
                             .join("\n");
-                        builder = builder
-                            .with_context(&format!("## Relevant Information\n{}", rag_context));
+                        builder = builder.with_section(
+                            SectionKind::Knowledge,
+                            &format!("## Relevant Information\n{}", rag_context),
+                        );
 
                         tracing::debug!(
                             stage = ?stage,
@@ -168,39 +182,20 @@ impl DomainAgent {
 
         // Add tool result if available
         if let Some(result) = tool_result {
-            builder = builder.with_context(&format!("## Tool Result\n{}", result));
+            builder = builder
+                .with_section(SectionKind::ToolResult, &format!("## Tool Result\n{}", result));
         }
 
-        // Add stage guidance from config if domain_view is available
-        if let Some(ref view) = self.domain_view {
-            let stage_name = self.conversation.stage().as_str();
-            if let Some(guidance) = view.stage_guidance(stage_name) {
-                builder = builder.with_stage_guidance_from_config(guidance, view.prompts_config());
-            }
-        }
+        // Results of earlier tool calls, for follow-up questions
+        builder = self.with_tool_memory(builder);
 
-        // P0 FIX: Detect objections and add persuasion guidance to prompt
-        // Uses acknowledge-reframe-evidence pattern from PersuasionEngine
-        if let Some(objection_response) = self
-            .persuasion
-            .handle_objection(user_input, self.user_language)
-        {
-            let persuasion_guidance = format!(
-                "## Objection Handling Guidance\n\
-                The customer appears to have a concern. Use this framework:\n\
-                1. **Acknowledge**: {}\n\
-                2. **Reframe**: {}\n\
-                3. **Evidence**: {}\n\
-                4. **Call to Action**: {}",
-                objection_response.acknowledge,
-                objection_response.reframe,
-                objection_response.evidence,
-                objection_response.call_to_action
-            );
-            builder = builder.with_context(&persuasion_guidance);
+        // Annotated exchanges like this turn, for the tool-call format
+        builder = self.with_examples(builder, user_input);
 
-            tracing::debug!("Detected objection, adding persuasion guidance to prompt");
-        }
+        builder = self.with_stage_guidance(builder);
+
+        // Objection playbook (when the caller raised one) and next best action
+        builder = self.with_objection_guidance(builder);
 
         // Add conversation history
         let history: Vec<Message> = self
@@ -250,11 +245,7 @@ impl DomainAgent {
         // P1-2 FIX: Try speculative execution first if enabled and appropriate
         // Speculative doesn't support tool calling, so only use for non-tool responses
         let tool_defs: Vec<ToolDefinition> = if self.config.tools_enabled {
-            self.tools
-                .list_tools()
-                .iter()
-                .map(ToolDefinition::from_schema)
-                .collect()
+            self.enabled_tool_definitions()
         } else {
             Vec::new()
         };
@@ -282,6 +273,22 @@ impl DomainAgent {
                             tokens = result.generation.tokens,
                             "Speculative execution succeeded"
                         );
+                        if let Some(llm) = self.llm.read().clone() {
+                            let prompt_tokens = messages
+                                .iter()
+                                .map(|m| llm.estimate_tokens(&m.content) as u64)
+                                .sum();
+                            let reported = TokenUsage::new(
+                                prompt_tokens as u32,
+                                result.generation.tokens as u32,
+                            );
+                            self.record_llm_usage(
+                                llm.as_ref(),
+                                prompt_tokens,
+                                Some(&reported),
+                                &result.text,
+                            );
+                        }
                         return Ok(result.text);
                     }
                     Err(e) => {
@@ -302,7 +309,8 @@ impl DomainAgent {
         let request = self.build_llm_request(user_input, tool_result).await?;
 
         // Try to use LLM backend if available
-        if let Some(ref llm) = self.llm {
+        let llm = self.llm.read().clone();
+        if let Some(ref llm) = llm {
             // Check if LLM is available
             if llm.is_available().await {
                 tracing::debug!(
@@ -311,6 +319,7 @@ impl DomainAgent {
                     "Calling LLM with tool definitions"
                 );
 
+                let prompt_tokens = estimate_prompt_tokens(llm.as_ref(), &request);
                 // P0-2 FIX: Use generate_with_tools when tools are available
                 let result = if has_tools {
                     llm.generate_with_tools(request, &tool_defs).await
@@ -320,6 +329,12 @@ impl DomainAgent {
 
                 match result {
                     Ok(response) => {
+                        self.record_llm_usage(
+                            llm.as_ref(),
+                            prompt_tokens,
+                            response.usage.as_ref(),
+                            &response.text,
+                        );
                         // P1 FIX: Use GenerateResponse fields (LanguageModel trait)
                         let tokens = response
                             .usage
@@ -342,66 +357,54 @@ impl DomainAgent {
                                 "LLM requested tool calls"
                             );
 
-                            // Execute each tool call and collect results
-                            let mut tool_results = Vec::new();
+                            // Dry-run every call first; a question for the customer
+                            // ends the turn before anything runs
+                            let mut planned: Vec<Result<ParsedToolCall, String>> = Vec::new();
                             for tool_call in &response.tool_calls {
-                                let _ = self.event_tx.send(crate::agent_config::AgentEvent::ToolCall {
+                                // Convert HashMap arguments to serde_json::Value
+                                let proposed = ParsedToolCall {
                                     name: tool_call.name.clone(),
-                                });
+                                    arguments: serde_json::to_value(&tool_call.arguments)
+                                        .unwrap_or(serde_json::json!({})),
+                                    text_before: String::new(),
+                                    text_after: String::new(),
+                                };
+                                // A follow-up turn is the model's retry; the reply is
+                                // translated afterwards, so questions are in English
+                                let checked = validate_tool_call(&proposed, &tool_defs);
+                                let retried = tool_result.is_some();
+                                match self.repair_tool_call(checked, retried, "en") {
+                                    ToolCallRepair::Execute(call) => planned.push(Ok(call)),
+                                    ToolCallRepair::AskCustomer(question) => return Ok(question),
+                                    ToolCallRepair::AskModel(error) => planned.push(Err(format!(
+                                        "Tool '{}' not run: {}",
+                                        tool_call.name, error
+                                    ))),
+                                    ToolCallRepair::Abandon => {},
+                                }
+                            }
 
-                                // Convert HashMap arguments to serde_json::Value
-                                let args = serde_json::to_value(&tool_call.arguments)
-                                    .unwrap_or(serde_json::json!({}));
-
-                                match self.tools.execute(&tool_call.name, args).await {
-                                    Ok(output) => {
-                                        let _ = self.event_tx.send(
-                                            crate::agent_config::AgentEvent::ToolResult {
-                                                name: tool_call.name.clone(),
-                                                success: true,
-                                            },
-                                        );
-
-                                        // Extract text from output
-                                        let text = output
-                                            .content
-                                            .iter()
-                                            .filter_map(|c| match c {
-                                                voice_agent_tools::mcp::ContentBlock::Text {
-                                                    text,
-                                                } => Some(text.clone()),
-                                                _ => None,
-                                            })
-                                            .collect::<Vec<_>>()
-                                            .join("\n");
-
-                                        tool_results.push(format!(
-                                            "Tool '{}' result:\n{}",
-                                            tool_call.name, text
-                                        ));
-                                        tracing::debug!(
-                                            tool = %tool_call.name,
-                                            "Tool execution successful"
-                                        );
-                                    }
-                                    Err(e) => {
-                                        let _ = self.event_tx.send(
-                                            crate::agent_config::AgentEvent::ToolResult {
-                                                name: tool_call.name.clone(),
-                                                success: false,
-                                            },
-                                        );
-                                        tool_results.push(format!(
-                                            "Tool '{}' failed: {}",
-                                            tool_call.name, e
-                                        ));
-                                        tracing::warn!(
-                                            tool = %tool_call.name,
-                                            error = %e,
-                                            "Tool execution failed"
-                                        );
-                                    }
+                            // Run the valid calls together, keeping results in call order
+                            let calls: Vec<ParsedToolCall> = planned
+                                .iter()
+                                .filter_map(|p| p.as_ref().ok().cloned())
+                                .collect();
+                            let mut executed =
+                                self.execute_llm_tool_calls(&calls).await.into_iter();
+                            let tool_results: Vec<String> = planned
+                                .into_iter()
+                                .filter_map(|planned| match planned {
+                                    Ok(_) => executed.next(),
+                                    Err(not_run) => Some(not_run),
+                                })
+                                .collect();
+
+                            // Every call was dropped; answer with what the model said
+                            if tool_results.is_empty() {
+                                if response.text.trim().is_empty() {
+                                    return Ok(self.generate_mock_response(user_input, tool_result));
                                 }
+                                return Ok(response.text);
                             }
 
                             // Recursive call with tool results to get final response
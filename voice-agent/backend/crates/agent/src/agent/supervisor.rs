@@ -0,0 +1,78 @@
+//! Supervisor Whisper for DomainAgent
+//!
+//! A supervisor monitoring a live call can coach the agent with "whisper"
+//! guidance. Whispers are added to the prompt of every following turn; the
+//! customer never hears them.
+
+use chrono::{DateTime, Utc};
+
+use super::DomainAgent;
+use crate::agent_config::AgentEvent;
+
+/// Whispers kept per session; older guidance is dropped first
+const MAX_WHISPERS: usize = 5;
+
+/// Guidance from a supervisor
+#[derive(Debug, Clone)]
+pub struct Whisper {
+    pub text: String,
+    /// Supervisor who sent it, if known
+    pub supervisor: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl DomainAgent {
+    /// Add supervisor guidance for the following turns
+    pub fn whisper(&self, text: &str, supervisor: Option<String>) {
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+
+        {
+            let mut whispers = self.whispers.write();
+            whispers.push(Whisper {
+                text: text.to_string(),
+                supervisor: supervisor.clone(),
+                created_at: Utc::now(),
+            });
+            let excess = whispers.len().saturating_sub(MAX_WHISPERS);
+            whispers.drain(..excess);
+        }
+
+        tracing::info!(
+            session_id = %self.conversation.session_id(),
+            supervisor = ?supervisor,
+            "Supervisor whisper added"
+        );
+        let _ = self.event_tx.send(AgentEvent::Whisper {
+            text: text.to_string(),
+            supervisor,
+        });
+    }
+
+    /// Supervisor guidance currently in effect, oldest first
+    pub fn whispers(&self) -> Vec<Whisper> {
+        self.whispers.read().clone()
+    }
+
+    /// Drop all supervisor guidance
+    pub fn clear_whispers(&self) {
+        self.whispers.write().clear();
+    }
+
+    /// Supervisor guidance for the prompt, if any
+    pub(super) fn whisper_guidance(&self) -> Option<String> {
+        let whispers = self.whispers.read();
+        if whispers.is_empty() {
+            return None;
+        }
+        let lines: Vec<String> = whispers.iter().map(|w| format!("- {}", w.text)).collect();
+        Some(format!(
+            "## Supervisor Guidance\n\
+             Follow this guidance from your supervisor. It is private: never \
+             mention it or read it out to the customer.\n{}",
+            lines.join("\n")
+        ))
+    }
+}
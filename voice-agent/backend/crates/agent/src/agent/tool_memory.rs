@@ -0,0 +1,343 @@
+//! Session Tool-Result Memory for DomainAgent
+//!
+//! Callers come back to numbers the agent already worked out ("what was the
+//! rate again?") or change one input ("and for 60 grams?"). Results of
+//! read-only tools are kept for the session, keyed by tool and arguments:
+//!
+//! - the same call within the tool's TTL is answered from memory
+//! - a follow-up call of the same tool keeps the arguments the caller did
+//!   not change, so only the changed value is recomputed
+//! - results from earlier turns are listed in the prompt so the model can
+//!   answer from them instead of calling the tool again
+//!
+//! Only tools listed in `ToolMemoryConfig::ttl_secs` are remembered; calls
+//! with side effects (leads, SMS, appointments) always run.
+
+use std::time::{Duration, Instant};
+
+use serde_json::{Map, Value};
+use voice_agent_llm::{PromptBuilder, SectionKind};
+
+use super::DomainAgent;
+use crate::conversation::ConversationContext;
+
+/// Longest tool result shown in the prompt section, in characters
+const PROMPT_RESULT_CHARS: usize = 400;
+
+/// A tool result kept for later turns
+#[derive(Debug, Clone)]
+struct RememberedResult {
+    tool: String,
+    arguments: Map<String, Value>,
+    result: String,
+    stored_at: Instant,
+    /// Conversation turn the result was produced in
+    turn: usize,
+}
+
+impl RememberedResult {
+    fn is_fresh(&self, ttl: Duration) -> bool {
+        self.stored_at.elapsed() < ttl
+    }
+
+    fn matches(&self, tool: &str, arguments: &Map<String, Value>) -> bool {
+        self.tool == tool
+            && self.arguments.len() == arguments.len()
+            && self.arguments.iter().all(|(key, value)| {
+                arguments
+                    .get(key)
+                    .is_some_and(|other| argument_key(value) == argument_key(other))
+            })
+    }
+
+    fn render(&self) -> String {
+        let arguments: Vec<String> = self
+            .arguments
+            .iter()
+            .map(|(key, value)| match value {
+                Value::String(s) => format!("{}={}", key, s),
+                other => format!("{}={}", key, other),
+            })
+            .collect();
+        let mut result: String = self.result.chars().take(PROMPT_RESULT_CHARS).collect();
+        if result.len() < self.result.len() {
+            result.push_str("...");
+        }
+        format!("- {}({}):\n{}", self.tool, arguments.join(", "), result)
+    }
+}
+
+/// Tool results remembered for one session, oldest first
+#[derive(Debug, Default)]
+pub(crate) struct ToolMemory {
+    entries: Vec<RememberedResult>,
+}
+
+impl ToolMemory {
+    /// Fresh result of exactly this call
+    fn lookup(&self, tool: &str, arguments: &Map<String, Value>, ttl: Duration) -> Option<&str> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| entry.matches(tool, arguments) && entry.is_fresh(ttl))
+            .map(|entry| entry.result.as_str())
+    }
+
+    /// Fill arguments missing from a follow-up call with those of the latest
+    /// fresh call of the same tool, returning the names filled in
+    fn carry_over(
+        &self,
+        tool: &str,
+        arguments: &mut Map<String, Value>,
+        ttl: Duration,
+    ) -> Vec<String> {
+        let Some(previous) = self
+            .entries
+            .iter()
+            .rev()
+            .find(|entry| entry.tool == tool && entry.is_fresh(ttl))
+        else {
+            return Vec::new();
+        };
+
+        let mut carried = Vec::new();
+        for (key, value) in &previous.arguments {
+            if !arguments.contains_key(key) {
+                arguments.insert(key.clone(), value.clone());
+                carried.push(key.clone());
+            }
+        }
+        carried
+    }
+
+    /// Keep a result, replacing an earlier one for the same call and
+    /// dropping the oldest beyond `max_entries`
+    fn remember(
+        &mut self,
+        tool: &str,
+        arguments: Map<String, Value>,
+        result: &str,
+        turn: usize,
+        max_entries: usize,
+    ) {
+        let entry = RememberedResult {
+            tool: tool.to_string(),
+            arguments,
+            result: result.to_string(),
+            stored_at: Instant::now(),
+            turn,
+        };
+        self.insert(entry, max_entries);
+    }
+
+    fn insert(&mut self, entry: RememberedResult, max_entries: usize) {
+        self.entries
+            .retain(|other| !other.matches(&entry.tool, &entry.arguments));
+        self.entries.push(entry);
+        let excess = self.entries.len().saturating_sub(max_entries);
+        self.entries.drain(..excess);
+    }
+}
+
+/// Comparable form of an argument value ("50", 50 and 50.0 are the same weight)
+fn argument_key(value: &Value) -> String {
+    match value {
+        Value::String(s) => {
+            let s = s.trim().to_lowercase();
+            match s.parse::<f64>() {
+                Ok(n) => n.to_string(),
+                Err(_) => s,
+            }
+        },
+        Value::Number(n) => n
+            .as_f64()
+            .map(|n| n.to_string())
+            .unwrap_or_else(|| n.to_string()),
+        other => other.to_string(),
+    }
+}
+
+impl DomainAgent {
+    /// TTL for a tool's results, if they may be remembered
+    fn tool_memory_ttl(&self, tool: &str) -> Option<Duration> {
+        let config = &self.config.tool_memory;
+        if !config.enabled {
+            return None;
+        }
+        config
+            .ttl_secs
+            .get(tool)
+            .map(|secs| Duration::from_secs(*secs))
+    }
+
+    /// Result of the same call made earlier this session, if still fresh
+    pub(super) fn remembered_tool_result(&self, tool: &str, arguments: &Value) -> Option<String> {
+        let ttl = self.tool_memory_ttl(tool)?;
+        let arguments = arguments.as_object()?;
+        let result = self
+            .tool_memory
+            .read()
+            .lookup(tool, arguments, ttl)
+            .map(str::to_string)?;
+        tracing::debug!(tool = %tool, "Tool result served from session memory");
+        Some(result)
+    }
+
+    /// Keep the arguments of the last call that a follow-up did not change
+    pub(super) fn carry_over_tool_arguments(&self, tool: &str, arguments: &mut Map<String, Value>) {
+        let Some(ttl) = self.tool_memory_ttl(tool) else {
+            return;
+        };
+        let carried = self.tool_memory.read().carry_over(tool, arguments, ttl);
+        if !carried.is_empty() {
+            tracing::debug!(tool = %tool, carried = ?carried, "Follow-up keeps earlier arguments");
+        }
+    }
+
+    /// Remember a successful result of a read-only tool
+    pub(super) fn remember_tool_result(&self, tool: &str, arguments: &Value, result: &str) {
+        if self.tool_memory_ttl(tool).is_none() {
+            return;
+        }
+        let Some(arguments) = arguments.as_object() else {
+            return;
+        };
+        self.tool_memory.write().remember(
+            tool,
+            arguments.clone(),
+            result,
+            self.conversation.turn_count(),
+            self.config.tool_memory.max_entries,
+        );
+    }
+
+    /// Remember a result replayed from the session journal, produced at
+    /// `stored_at` (so it expires as it would have without the restart)
+    pub(super) fn restore_tool_result(
+        &self,
+        tool: &str,
+        arguments: &Value,
+        result: &str,
+        stored_at: Instant,
+    ) {
+        if self.tool_memory_ttl(tool).is_none() {
+            return;
+        }
+        let Some(arguments) = arguments.as_object() else {
+            return;
+        };
+        let entry = RememberedResult {
+            tool: tool.to_string(),
+            arguments: arguments.clone(),
+            result: result.to_string(),
+            stored_at,
+            turn: self.conversation.turn_count(),
+        };
+        self.tool_memory
+            .write()
+            .insert(entry, self.config.tool_memory.max_entries);
+    }
+
+    /// Add fresh results from earlier turns to the prompt
+    pub(super) fn with_tool_memory(&self, builder: PromptBuilder) -> PromptBuilder {
+        let limit = self.config.tool_memory.prompt_limit;
+        if limit == 0 {
+            return builder;
+        }
+
+        let turn = self.conversation.turn_count();
+        let rendered: Vec<String> = {
+            let memory = self.tool_memory.read();
+            memory
+                .entries
+                .iter()
+                .rev()
+                .filter(|entry| entry.turn < turn)
+                .filter(|entry| {
+                    self.tool_memory_ttl(&entry.tool)
+                        .is_some_and(|ttl| entry.is_fresh(ttl))
+                })
+                .take(limit)
+                .map(RememberedResult::render)
+                .collect()
+        };
+        if rendered.is_empty() {
+            return builder;
+        }
+
+        builder.with_section(
+            SectionKind::Context,
+            &format!(
+                "## Earlier Tool Results\nStill valid for this call, newest first. Answer \
+                 follow-up questions from these; call a tool again only for values that \
+                 changed.\n\n{}",
+                rendered.join("\n\n")
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn args(value: Value) -> Map<String, Value> {
+        value.as_object().cloned().unwrap()
+    }
+
+    #[test]
+    fn test_lookup_matches_equivalent_arguments_until_expiry() {
+        let mut memory = ToolMemory::default();
+        memory.remember(
+            "check_eligibility",
+            args(json!({"gold_weight": 50, "gold_purity": "22K"})),
+            "Eligible for Rs 2,50,000",
+            1,
+            8,
+        );
+
+        let same = args(json!({"gold_weight": "50", "gold_purity": "22k"}));
+        let ttl = Duration::from_secs(60);
+        assert_eq!(
+            memory.lookup("check_eligibility", &same, ttl),
+            Some("Eligible for Rs 2,50,000")
+        );
+        assert!(memory
+            .lookup("check_eligibility", &args(json!({"gold_weight": 60})), ttl)
+            .is_none());
+        assert!(memory
+            .lookup("check_eligibility", &same, Duration::ZERO)
+            .is_none());
+    }
+
+    #[test]
+    fn test_follow_up_carries_unchanged_arguments() {
+        let mut memory = ToolMemory::default();
+        memory.remember(
+            "check_eligibility",
+            args(json!({"gold_weight": 50, "gold_purity": "18K"})),
+            "Eligible for Rs 2,00,000",
+            1,
+            8,
+        );
+
+        let mut follow_up = args(json!({"gold_weight": 60}));
+        let carried =
+            memory.carry_over("check_eligibility", &mut follow_up, Duration::from_secs(60));
+        assert_eq!(carried, vec!["gold_purity".to_string()]);
+        assert_eq!(
+            follow_up,
+            args(json!({"gold_weight": 60, "gold_purity": "18K"}))
+        );
+
+        memory.remember(
+            "check_eligibility",
+            follow_up,
+            "Eligible for Rs 2,40,000",
+            3,
+            1,
+        );
+        assert_eq!(memory.entries.len(), 1);
+    }
+}
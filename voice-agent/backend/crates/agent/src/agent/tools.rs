@@ -4,6 +4,7 @@
 //! - Intent-based tool invocation
 //! - DST-enriched tool calls
 //! - Tool argument mapping and defaults
+//! - Concurrent execution of the LLM's tool calls within a turn budget
 //!
 //! # P20 FIX: Config-Driven Tool Resolution
 //!
@@ -17,24 +18,157 @@
 use super::DomainAgent;
 use crate::agent_config::AgentEvent;
 use crate::dst::DialogueStateTrait;
+use crate::lead_scoring::LeadQualification;
 use crate::AgentError;
-use voice_agent_tools::ToolExecutor;
+use futures::StreamExt;
+use tokio::time::{Duration, Instant};
+use voice_agent_core::ToolDefinition;
+use voice_agent_llm::ParsedToolCall;
+use voice_agent_tools::{ToolExecutor, ESCALATION_TOOL, OFFER_TOOLS};
 
 impl DomainAgent {
-    /// Maybe call a tool based on intent
+    /// Tool definitions offered to the LLM (registered and enabled for this session)
+    pub(super) fn enabled_tool_definitions(&self) -> Vec<ToolDefinition> {
+        self.tools
+            .list_tools()
+            .iter()
+            .filter(|schema| self.config.is_tool_enabled(&schema.name))
+            .map(ToolDefinition::from_schema)
+            .collect()
+    }
+
+    /// Execute a tool call the LLM emitted, returning the result for the follow-up prompt
+    pub(super) async fn execute_llm_tool_call(&self, call: &ParsedToolCall) -> String {
+        let unconfirmed = self.unconfirmed_tool_inputs(&call.name);
+        if !unconfirmed.is_empty() {
+            return format!(
+                "Tool '{}' not run: confirm {} with the customer first",
+                call.name,
+                unconfirmed.join(", ")
+            );
+        }
+
+        let _ = self.event_tx.send(AgentEvent::ToolCall {
+            name: call.name.clone(),
+        });
+
+        let mut arguments = call.arguments.clone();
+        if let Some(args) = arguments.as_object_mut() {
+            if call.name.contains("capture") {
+                self.apply_lead_score_arguments(args);
+            }
+            if call.name == ESCALATION_TOOL {
+                self.apply_handoff_context(args);
+            }
+            if OFFER_TOOLS.contains(&call.name.as_str()) {
+                self.apply_session_id(args);
+            }
+        }
+
+        if let Some(remembered) = self.remembered_tool_result(&call.name, &arguments) {
+            self.report_tool_result(&call.name, true);
+            self.record_tool_output(&call.name, &remembered);
+            return format!("Tool '{}' result:\n{}", call.name, remembered);
+        }
+
+        self.record_tool_usage();
+        let result = self
+            .tools
+            .execute_for_session(
+                self.conversation.session_id(),
+                &call.name,
+                arguments.clone(),
+            )
+            .await;
+
+        self.report_tool_result(&call.name, result.is_ok());
+
+        match result {
+            Ok(output) => {
+                let text = output
+                    .content
+                    .iter()
+                    .filter_map(|c| match c {
+                        voice_agent_tools::mcp::ContentBlock::Text { text } => Some(text.clone()),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                self.record_tool_output(&call.name, &text);
+                self.remember_tool_result(&call.name, &arguments, &text);
+                self.journal_tool_result(&call.name, &arguments, &text);
+                self.publish_tool_outcome(&call.name, &text);
+                tracing::debug!(tool = %call.name, "LLM tool call succeeded");
+                format!("Tool '{}' result:\n{}", call.name, text)
+            }
+            Err(e) => {
+                tracing::warn!(tool = %call.name, error = %e, "LLM tool call failed");
+                format!("Tool '{}' failed: {}", call.name, e)
+            }
+        }
+    }
+
+    /// Execute the LLM's tool calls concurrently, returning results in call order
+    ///
+    /// Calls share the turn's tool budget: each gets whatever is left of it
+    /// when it starts, and a call still running at the deadline is reported
+    /// as failed so the follow-up prompt can answer without it.
+    pub(super) async fn execute_llm_tool_calls(&self, calls: &[ParsedToolCall]) -> Vec<String> {
+        let config = &self.config.parallel_tools;
+        let concurrency = if config.enabled {
+            config.max_concurrent.max(1)
+        } else {
+            1
+        };
+        let deadline = Instant::now() + Duration::from_millis(config.turn_budget_ms);
+
+        if calls.len() > 1 {
+            tracing::debug!(
+                calls = calls.len(),
+                concurrency,
+                budget_ms = config.turn_budget_ms,
+                "Running LLM tool calls concurrently"
+            );
+        }
+
+        futures::stream::iter(calls)
+            .map(|call| async move {
+                let budget = deadline.saturating_duration_since(Instant::now());
+                match tokio::time::timeout(budget, self.execute_llm_tool_call(call)).await {
+                    Ok(result) => result,
+                    Err(_) => {
+                        tracing::warn!(
+                            tool = %call.name,
+                            budget_ms = config.turn_budget_ms,
+                            "LLM tool call ran past the turn budget"
+                        );
+                        self.report_tool_result(&call.name, false);
+                        format!(
+                            "Tool '{}' failed: no result within {} ms",
+                            call.name, config.turn_budget_ms
+                        )
+                    },
+                }
+            })
+            .buffered(concurrency)
+            .collect()
+            .await
+    }
+
+    /// Resolve the tool to call for an intent
     ///
     /// P20 FIX: Fully config-driven - NO hardcoded fallback mappings.
     /// All intent-to-tool mappings come from intent_tool_mappings.yaml.
-    pub(super) async fn maybe_call_tool(
+    pub(super) fn resolve_intent_tool(
         &self,
         intent: &crate::intent::DetectedIntent,
-    ) -> Result<Option<String>, AgentError> {
+    ) -> Option<String> {
         // Collect available slot names
         let available_slots: Vec<&str> = intent.slots.keys().map(|s| s.as_str()).collect();
 
         // P20 FIX: Config-driven intent-to-tool resolution ONLY
         // No hardcoded fallbacks - if config is missing, no tool is called
-        let tool_name = self.domain_view
+        self.domain_view
             .as_ref()
             .and_then(|view| {
                 if view.has_intent_mappings() {
@@ -58,99 +192,148 @@ impl DomainAgent {
                     );
                 }
                 None
-            });
-
-        if let Some(name) = tool_name {
-            let _ = self.event_tx.send(AgentEvent::ToolCall {
-                name: name.to_string(),
-            });
-
-            // Build arguments from slots
-            let mut args = serde_json::Map::new();
-            for (key, slot) in &intent.slots {
-                if let Some(ref value) = slot.value {
-                    args.insert(key.clone(), serde_json::json!(value));
+            })
+            .filter(|name| {
+                let enabled = self.config.is_tool_enabled(name);
+                if !enabled {
+                    tracing::debug!(tool = %name, "Tool disabled for this session - skipping");
                 }
+                enabled
+            })
+    }
+
+    /// Call the tool resolved for an intent, filling arguments from its slots
+    ///
+    /// Results of read-only tools are served from this session's tool memory,
+    /// then the response cache, when the same arguments were used recently.
+    pub(super) async fn call_intent_tool(
+        &self,
+        name: &str,
+        intent: &crate::intent::DetectedIntent,
+    ) -> Result<Option<String>, AgentError> {
+        if !self.unconfirmed_tool_inputs(name).is_empty() {
+            return Ok(None);
+        }
+
+        let _ = self.event_tx.send(AgentEvent::ToolCall {
+            name: name.to_string(),
+        });
+
+        // Build arguments from slots
+        let mut args = serde_json::Map::new();
+        for (key, slot) in &intent.slots {
+            if let Some(ref value) = slot.value {
+                args.insert(key.clone(), serde_json::json!(value));
             }
+        }
 
-            // P20 FIX: Config-driven tool defaults ONLY
-            // All defaults and argument mappings come from tools/schemas.yaml
-            if let Some(view) = self.domain_view.as_ref() {
-                // Apply argument name mappings from config
-                if let Some(arg_mapping) = view.get_argument_mapping(&name) {
-                    let keys: Vec<String> = args.keys().cloned().collect();
-                    for slot_name in keys {
-                        if let Some(arg_name) = arg_mapping.get(&slot_name) {
-                            if !args.contains_key(arg_name) {
-                                if let Some(value) = args.remove(&slot_name) {
-                                    args.insert(arg_name.clone(), value);
-                                }
+        // P20 FIX: Config-driven tool defaults ONLY
+        // All defaults and argument mappings come from tools/schemas.yaml
+        if let Some(view) = self.domain_view.as_ref() {
+            // Apply argument name mappings from config
+            if let Some(arg_mapping) = view.get_argument_mapping(name) {
+                let keys: Vec<String> = args.keys().cloned().collect();
+                for slot_name in keys {
+                    if let Some(arg_name) = arg_mapping.get(&slot_name) {
+                        if !args.contains_key(arg_name) {
+                            if let Some(value) = args.remove(&slot_name) {
+                                args.insert(arg_name.clone(), value);
                             }
                         }
                     }
                 }
+            }
 
-                // Apply defaults from config
-                if let Some(tool_defaults) = view.get_tool_defaults(&name) {
-                    for (arg_name, default_value) in tool_defaults {
-                        if !args.contains_key(arg_name) {
-                            args.insert(arg_name.clone(), default_value.clone());
-                        }
+            // Follow-ups ("and for 60 grams?") keep what the caller did not change
+            self.carry_over_tool_arguments(name, &mut args);
+
+            // Apply defaults from config
+            if let Some(tool_defaults) = view.get_tool_defaults(name) {
+                for (arg_name, default_value) in tool_defaults {
+                    if !args.contains_key(arg_name) {
+                        args.insert(arg_name.clone(), default_value.clone());
                     }
                 }
-            } else {
-                // P20 FIX: Log warning when domain view is not configured
-                tracing::warn!(
-                    tool = %name,
-                    "DomainView not configured - tool defaults not available. Check domain config."
-                );
             }
+        } else {
+            // P20 FIX: Log warning when domain view is not configured
+            tracing::warn!(
+                tool = %name,
+                "DomainView not configured - tool defaults not available. Check domain config."
+            );
+        }
 
-            // P20 FIX: Apply generic slot-to-argument mappings
-            // These are common mappings that don't depend on domain
-            self.apply_common_argument_mappings(&mut args);
+        // P20 FIX: Apply generic slot-to-argument mappings
+        // These are common mappings that don't depend on domain
+        self.apply_common_argument_mappings(&mut args);
 
-            // P20 FIX: Interest level default based on intent confidence
-            // This is a generic behavior, not domain-specific
-            if !args.contains_key("interest_level") && name.contains("capture") {
-                let level = if intent.confidence > 0.8 { "High" } else { "Medium" };
-                args.insert("interest_level".to_string(), serde_json::json!(level));
-            }
+        // Attach the current lead score to lead capture payloads
+        if name.contains("capture") {
+            self.apply_lead_score_arguments(&mut args);
+        }
 
-            let result = self
-                .tools
-                .execute(&name, serde_json::Value::Object(args))
-                .await;
-
-            let success = result.is_ok();
-            let _ = self.event_tx.send(AgentEvent::ToolResult {
-                name: name.to_string(),
-                success,
-            });
-
-            match result {
-                Ok(output) => {
-                    // Extract text from output
-                    let text = output
-                        .content
-                        .iter()
-                        .filter_map(|c| match c {
-                            voice_agent_tools::mcp::ContentBlock::Text { text } => {
-                                Some(text.clone())
-                            }
-                            _ => None,
-                        })
-                        .collect::<Vec<_>>()
-                        .join("\n");
-                    Ok(Some(text))
-                }
-                Err(e) => {
-                    tracing::warn!("Tool error: {}", e);
-                    Ok(None)
-                }
+        // Package the session for the human agent taking an escalation
+        if name == ESCALATION_TOOL {
+            self.apply_handoff_context(&mut args);
+        }
+
+        // Offers are tracked per session
+        if OFFER_TOOLS.contains(&name) {
+            self.apply_session_id(&mut args);
+        }
+
+        // P20 FIX: Interest level default based on intent confidence
+        // This is a generic behavior, not domain-specific
+        if !args.contains_key("interest_level") && name.contains("capture") {
+            let level = if intent.confidence > 0.8 { "High" } else { "Medium" };
+            args.insert("interest_level".to_string(), serde_json::json!(level));
+        }
+
+        let args = serde_json::Value::Object(args);
+        if let Some(cached) = self
+            .remembered_tool_result(name, &args)
+            .or_else(|| self.cached_tool_result(name, &args))
+        {
+            tracing::debug!(tool = %name, "Tool result served from cache");
+            self.report_tool_result(name, true);
+            self.record_tool_output(name, &cached);
+            return Ok(Some(cached));
+        }
+
+        self.record_tool_usage();
+        let result = self
+            .tools
+            .execute_for_session(self.conversation.session_id(), name, args.clone())
+            .await;
+
+        let success = result.is_ok();
+        self.report_tool_result(name, success);
+
+        match result {
+            Ok(output) => {
+                // Extract text from output
+                let text = output
+                    .content
+                    .iter()
+                    .filter_map(|c| match c {
+                        voice_agent_tools::mcp::ContentBlock::Text { text } => {
+                            Some(text.clone())
+                        }
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                self.record_tool_output(name, &text);
+                self.remember_tool_result(name, &args, &text);
+                self.journal_tool_result(name, &args, &text);
+                self.publish_tool_outcome(name, &text);
+                self.cache_tool_result(name, &args, &text);
+                Ok(Some(text))
+            }
+            Err(e) => {
+                tracing::warn!("Tool error: {}", e);
+                Ok(None)
             }
-        } else {
-            Ok(None)
         }
     }
 
@@ -160,6 +343,14 @@ impl DomainAgent {
         tool_name: &str,
         intent: &crate::intent::DetectedIntent,
     ) -> Result<Option<String>, AgentError> {
+        if !self.config.is_tool_enabled(tool_name) {
+            tracing::debug!(tool = %tool_name, "Tool disabled for this session - skipping");
+            return Ok(None);
+        }
+        if !self.unconfirmed_tool_inputs(tool_name).is_empty() {
+            return Ok(None);
+        }
+
         let _ = self.event_tx.send(AgentEvent::ToolCall {
             name: tool_name.to_string(),
         });
@@ -241,6 +432,8 @@ impl DomainAgent {
                 }
             }
 
+            self.carry_over_tool_arguments(tool_name, &mut args);
+
             // Apply defaults from config
             if let Some(tool_defaults) = view.get_tool_defaults(tool_name) {
                 for (arg_name, default_value) in tool_defaults {
@@ -260,6 +453,21 @@ impl DomainAgent {
         // P20 FIX: Apply generic slot-to-argument mappings
         self.apply_common_argument_mappings(&mut args);
 
+        // Attach the current lead score to lead capture payloads
+        if tool_name.contains("capture") {
+            self.apply_lead_score_arguments(&mut args);
+        }
+
+        // Package the session for the human agent taking an escalation
+        if tool_name == ESCALATION_TOOL {
+            self.apply_handoff_context(&mut args);
+        }
+
+        // Offers are tracked per session
+        if OFFER_TOOLS.contains(&tool_name) {
+            self.apply_session_id(&mut args);
+        }
+
         // P20 FIX: Interest level default (generic behavior)
         if tool_name.contains("capture") && !args.contains_key("interest_level") {
             // Default interest level to High for proactive capture
@@ -272,16 +480,21 @@ impl DomainAgent {
             "Calling tool proactively with DST state"
         );
 
+        let args = serde_json::Value::Object(args);
+        if let Some(remembered) = self.remembered_tool_result(tool_name, &args) {
+            self.report_tool_result(tool_name, true);
+            self.record_tool_output(tool_name, &remembered);
+            return Ok(Some(remembered));
+        }
+
+        self.record_tool_usage();
         let result = self
             .tools
-            .execute(tool_name, serde_json::Value::Object(args))
+            .execute_for_session(self.conversation.session_id(), tool_name, args.clone())
             .await;
 
         let success = result.is_ok();
-        let _ = self.event_tx.send(AgentEvent::ToolResult {
-            name: tool_name.to_string(),
-            success,
-        });
+        self.report_tool_result(tool_name, success);
 
         match result {
             Ok(output) => {
@@ -294,6 +507,10 @@ impl DomainAgent {
                     })
                     .collect::<Vec<_>>()
                     .join("\n");
+                self.record_tool_output(tool_name, &text);
+                self.remember_tool_result(tool_name, &args, &text);
+                self.journal_tool_result(tool_name, &args, &text);
+                self.publish_tool_outcome(tool_name, &text);
                 Ok(Some(text))
             }
             Err(e) => {
@@ -303,6 +520,45 @@ impl DomainAgent {
         }
     }
 
+    /// Slots the tool's confirmation policy still needs confirmed
+    ///
+    /// They are marked pending, so the response reads them back to the
+    /// customer instead of the tool running on an unconfirmed value.
+    fn unconfirmed_tool_inputs(&self, tool: &str) -> Vec<String> {
+        let unconfirmed = self.dialogue_state.write().slots_blocking_tool(tool);
+        if !unconfirmed.is_empty() {
+            tracing::debug!(tool, slots = ?unconfirmed, "Tool waiting for slot confirmation");
+        }
+        unconfirmed
+    }
+
+    /// Attach the session ID to tools that record per-session events
+    fn apply_session_id(&self, args: &mut serde_json::Map<String, serde_json::Value>) {
+        args.entry("session_id".to_string())
+            .or_insert_with(|| serde_json::json!(self.conversation.session_id()));
+    }
+
+    /// Add lead score, qualification and derived interest level to capture arguments
+    fn apply_lead_score_arguments(&self, args: &mut serde_json::Map<String, serde_json::Value>) {
+        let Some(score) = self.last_lead_score() else {
+            return;
+        };
+
+        args.insert("lead_score".to_string(), serde_json::json!(score.total));
+        args.insert(
+            "lead_qualification".to_string(),
+            serde_json::json!(format!("{:?}", score.qualification)),
+        );
+        if !args.contains_key("interest_level") {
+            let level = match score.qualification {
+                LeadQualification::Hot | LeadQualification::Qualified => "High",
+                LeadQualification::Warm => "Medium",
+                LeadQualification::Cold => "Low",
+            };
+            args.insert("interest_level".to_string(), serde_json::json!(level));
+        }
+    }
+
     /// Apply common slot-to-argument mappings
     ///
     /// P20 FIX: Uses config-driven common mappings when available.
@@ -0,0 +1,145 @@
+//! Translate-Think-Translate Methods for DomainAgent
+//!
+//! Wraps the session translator with per-turn language detection and
+//! placeholder protection (see [`crate::translate_think`]). A failed or
+//! lossy translation never blocks the turn: the input falls back to the
+//! original text and the answer to English.
+
+use voice_agent_core::Language;
+
+use super::DomainAgent;
+use crate::intent::DetectedIntent;
+use crate::translate_think::{ProtectedText, TurnLanguages};
+
+/// Translation state for one turn
+pub(super) struct ThinkTurn {
+    /// Input and reply languages
+    pub languages: TurnLanguages,
+    /// What the LLM reasons over
+    pub english_input: String,
+    /// Slot text, as the caller said it, to keep verbatim in the answer
+    slot_values: Vec<String>,
+}
+
+impl DomainAgent {
+    /// Translate the caller's input to English for the LLM
+    pub(super) async fn translate_input(
+        &self,
+        user_input: &str,
+        intent: &DetectedIntent,
+    ) -> ThinkTurn {
+        let settings = &self.config.translate_think;
+        let slot_values = intent.slot_spans.clone();
+
+        let translator = match self.translator {
+            Some(ref translator) if settings.enabled => translator,
+            _ => {
+                return ThinkTurn {
+                    languages: TurnLanguages::resolve(self.user_language, None),
+                    english_input: user_input.to_string(),
+                    slot_values,
+                }
+            },
+        };
+
+        let detected = if settings.detect_language {
+            translator.detect_language(user_input).await.ok()
+        } else {
+            None
+        };
+        let languages = TurnLanguages::resolve(self.user_language, detected);
+
+        let english_input = if languages.translates_input() {
+            match self
+                .translate_protected(user_input, languages.input, Language::English, &slot_values)
+                .await
+            {
+                Some(translated) => {
+                    tracing::debug!(
+                        from = ?languages.input,
+                        original = %user_input,
+                        translated = %translated,
+                        "Translated user input to English"
+                    );
+                    translated
+                },
+                None => user_input.to_string(),
+            }
+        } else {
+            user_input.to_string()
+        };
+
+        ThinkTurn {
+            languages,
+            english_input,
+            slot_values,
+        }
+    }
+
+    /// Translate an English answer (or one sentence of it) to the reply language
+    pub(super) async fn translate_reply(&self, english: &str, turn: &ThinkTurn) -> String {
+        if !turn.languages.translates_reply() || !self.config.translate_think.enabled {
+            return english.to_string();
+        }
+
+        match self
+            .translate_protected(
+                english,
+                Language::English,
+                turn.languages.reply,
+                &turn.slot_values,
+            )
+            .await
+        {
+            Some(translated) => {
+                tracing::debug!(
+                    to = ?turn.languages.reply,
+                    original = %english,
+                    translated = %translated,
+                    "Translated response to user language"
+                );
+                translated
+            },
+            None => english.to_string(),
+        }
+    }
+
+    /// Translate with numbers and slot values shielded by placeholders
+    ///
+    /// `None` when there is no translator, it fails, or it loses a value.
+    async fn translate_protected(
+        &self,
+        text: &str,
+        from: Language,
+        to: Language,
+        slot_values: &[String],
+    ) -> Option<String> {
+        let translator = self.translator.as_ref()?;
+        self.record_translation_usage();
+
+        if !self.config.translate_think.protect_values {
+            return translator
+                .translate(text, from, to)
+                .await
+                .map_err(|e| tracing::warn!(error = %e, "Translation failed"))
+                .ok();
+        }
+
+        let protected = ProtectedText::protect(text, slot_values);
+        let translated = translator
+            .translate(protected.text(), from, to)
+            .await
+            .map_err(|e| tracing::warn!(error = %e, "Translation failed"))
+            .ok()?;
+
+        let restored = protected.restore(&translated);
+        if restored.is_none() {
+            tracing::warn!(
+                translated = %translated,
+                values = ?protected.values(),
+                "Translation lost protected values, keeping original text"
+            );
+        }
+        restored
+    }
+}
@@ -0,0 +1,186 @@
+//! Per-Turn Usage Accounting for DomainAgent
+//!
+//! Counts what each turn consumes: LLM tokens (as reported by the backend,
+//! estimated when it reports none, e.g. while streaming), tool calls and
+//! translation calls, plus the STT seconds and TTS characters reported by
+//! the pipeline. Caller audio counts toward the turn it was transcribed
+//! for, and a turn's record is written when the next caller turn starts so
+//! it includes the speech of its reply. `flush_usage` writes the last turn
+//! when the session ends.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use voice_agent_core::{GenerateRequest, LanguageModel, TokenUsage};
+use voice_agent_persistence::{TurnUsage, UsageCounts, UsageStore};
+
+use super::DomainAgent;
+use crate::AgentError;
+
+/// Estimated prompt size of a request, for backends that report no usage
+pub(super) fn estimate_prompt_tokens(llm: &dyn LanguageModel, request: &GenerateRequest) -> u64 {
+    request
+        .messages
+        .iter()
+        .map(|m| llm.estimate_tokens(&m.content) as u64)
+        .sum()
+}
+
+/// Usage store attached to a session and the counts so far
+#[derive(Default)]
+pub(crate) struct UsageState {
+    store: Option<Arc<dyn UsageStore>>,
+    tenant: String,
+    /// Turn being counted; 0 until the caller first speaks
+    turn: u32,
+    current: UsageCounts,
+    /// Caller audio transcribed for a turn that has not started yet
+    pending_stt_seconds: f64,
+    /// Everything this session has consumed
+    total: UsageCounts,
+}
+
+impl UsageState {
+    pub(super) fn add(&mut self, counts: &UsageCounts) {
+        self.current.add(counts);
+        self.total.add(counts);
+    }
+
+    /// Close the current turn, returning its record if there is a store and
+    /// anything to record
+    fn close_turn(&mut self, session_id: &str) -> Option<(Arc<dyn UsageStore>, TurnUsage)> {
+        let counts = std::mem::take(&mut self.current);
+        let store = self.store.clone()?;
+        if counts.is_empty() {
+            return None;
+        }
+        let usage = TurnUsage {
+            session_id: session_id.to_string(),
+            tenant: self.tenant.clone(),
+            turn: self.turn,
+            recorded_at: Utc::now(),
+            counts,
+        };
+        Some((store, usage))
+    }
+}
+
+impl DomainAgent {
+    /// Persist this session's per-turn usage under `tenant`
+    pub fn set_usage_store(&self, store: Arc<dyn UsageStore>, tenant: impl Into<String>) {
+        let mut usage = self.usage.write();
+        usage.store = Some(store);
+        usage.tenant = tenant.into();
+    }
+
+    /// Tenant this session's usage is accounted to (empty if none was set)
+    pub fn usage_tenant(&self) -> String {
+        self.usage.read().tenant.clone()
+    }
+
+    /// Everything this session has consumed so far
+    pub fn session_usage(&self) -> UsageCounts {
+        self.usage.read().total
+    }
+
+    /// Count caller audio transcribed for the next turn
+    pub fn record_stt_seconds(&self, seconds: f64) {
+        if !seconds.is_finite() || seconds <= 0.0 {
+            return;
+        }
+        let mut usage = self.usage.write();
+        usage.pending_stt_seconds += seconds;
+        usage.total.stt_seconds += seconds;
+    }
+
+    /// Count text sent to TTS for the current turn
+    pub fn record_tts_characters(&self, text: &str) {
+        self.usage.write().add(&UsageCounts {
+            tts_characters: text.chars().count() as u64,
+            ..Default::default()
+        });
+    }
+
+    /// Count an LLM call, using the backend's token counts when it has them
+    ///
+    /// `prompt_estimate` comes from `estimate_prompt_tokens`, taken before
+    /// the request was handed to the backend.
+    pub(super) fn record_llm_usage(
+        &self,
+        llm: &dyn LanguageModel,
+        prompt_estimate: u64,
+        reported: Option<&TokenUsage>,
+        output: &str,
+    ) {
+        let (prompt, completion) = match reported {
+            Some(reported) => (
+                reported.prompt_tokens as u64,
+                reported.completion_tokens as u64,
+            ),
+            None => (prompt_estimate, llm.estimate_tokens(output) as u64),
+        };
+        self.usage.write().add(&UsageCounts {
+            llm_prompt_tokens: prompt,
+            llm_completion_tokens: completion,
+            ..Default::default()
+        });
+    }
+
+    /// Count a tool call
+    pub(super) fn record_tool_usage(&self) {
+        self.usage.write().add(&UsageCounts {
+            tool_calls: 1,
+            ..Default::default()
+        });
+    }
+
+    /// Count a machine translation call
+    pub(super) fn record_translation_usage(&self) {
+        self.usage.write().add(&UsageCounts {
+            translation_calls: 1,
+            ..Default::default()
+        });
+    }
+
+    /// Start counting a new caller turn, writing the previous one
+    pub(super) fn start_usage_turn(&self) {
+        let closed = {
+            let mut usage = self.usage.write();
+            let closed = usage.close_turn(self.conversation.session_id());
+            usage.turn += 1;
+            usage.current.stt_seconds = std::mem::take(&mut usage.pending_stt_seconds);
+            closed
+        };
+        let Some((store, record)) = closed else {
+            return;
+        };
+
+        tokio::spawn(async move {
+            if let Err(e) = store.record_turn(&record).await {
+                tracing::warn!(
+                    session_id = %record.session_id,
+                    turn = record.turn,
+                    error = %e,
+                    "Usage write failed"
+                );
+            }
+        });
+    }
+
+    /// Write the current turn's usage now, e.g. when the session ends
+    pub async fn flush_usage(&self) -> Result<(), AgentError> {
+        let closed = {
+            let mut usage = self.usage.write();
+            let pending = std::mem::take(&mut usage.pending_stt_seconds);
+            usage.current.stt_seconds += pending;
+            usage.close_turn(self.conversation.session_id())
+        };
+        let Some((store, record)) = closed else {
+            return Ok(());
+        };
+        store
+            .record_turn(&record)
+            .await
+            .map_err(|e| AgentError::Memory(format!("usage write failed: {}", e)))
+    }
+}
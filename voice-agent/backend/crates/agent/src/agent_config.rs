@@ -2,7 +2,9 @@
 //!
 //! Configuration structs for the DomainAgent.
 
-use voice_agent_config::PersonaConfig;
+use std::collections::HashMap;
+
+use voice_agent_config::{PersonaConfig, SessionOverrides};
 use voice_agent_llm::{LlmProviderConfig, SpeculativeConfig, SpeculativeMode};
 use voice_agent_rag::AgenticRagConfig;
 
@@ -23,6 +25,8 @@ pub struct AgentConfig {
     pub rag_enabled: bool,
     /// Enable tools
     pub tools_enabled: bool,
+    /// Per-session tool allow-list (None = every registered tool)
+    pub enabled_tools: Option<Vec<String>>,
     /// P1 FIX: Configurable tool defaults (no more hardcoded values)
     pub tool_defaults: ToolDefaults,
     /// P2 FIX: Context window size in tokens (for LLM prompt truncation)
@@ -39,6 +43,14 @@ pub struct AgentConfig {
     pub agentic_rag: AgenticRagConfig,
     /// Small model optimizations (auto-detected or manual)
     pub small_model: SmallModelConfig,
+    /// Translate-Think-Translate orchestration for non-English callers
+    pub translate_think: TranslateThinkConfig,
+    /// Per-session memory of read-only tool results
+    pub tool_memory: ToolMemoryConfig,
+    /// Concurrent execution of multiple LLM tool calls in one turn
+    pub parallel_tools: ParallelToolConfig,
+    /// Clause-level release of streamed answers to TTS
+    pub early_tts: EarlyTtsConfig,
 }
 
 impl Default for AgentConfig {
@@ -75,6 +87,7 @@ impl Default for AgentConfig {
             persona: PersonaConfig::default(),
             rag_enabled: true,
             tools_enabled: true,
+            enabled_tools: None,
             tool_defaults: ToolDefaults::default(),
             // Context window adjusted for small models (2500 vs 4096)
             // Research: Qwen2.5 Technical Report (arXiv:2412.15115)
@@ -92,6 +105,10 @@ impl Default for AgentConfig {
             agentic_rag,
             // Small model config (auto-detected)
             small_model,
+            translate_think: TranslateThinkConfig::default(),
+            tool_memory: ToolMemoryConfig::default(),
+            parallel_tools: ParallelToolConfig::default(),
+            early_tts: EarlyTtsConfig::default(),
         }
     }
 }
@@ -102,6 +119,36 @@ impl AgentConfig {
         &self.persona.name
     }
 
+    /// Whether a tool may be called in this session
+    pub fn is_tool_enabled(&self, tool: &str) -> bool {
+        self.tools_enabled
+            && self
+                .enabled_tools
+                .as_ref()
+                .map(|tools| tools.iter().any(|t| t == tool))
+                .unwrap_or(true)
+    }
+
+    /// Apply per-session overrides (language, persona, temperature, tools)
+    ///
+    /// TTS voice is not part of the agent config; the transport reads it
+    /// from the session's `SessionOverrides` directly.
+    pub fn with_overrides(mut self, overrides: &SessionOverrides) -> Self {
+        if let Some(language) = &overrides.language {
+            self.language = language.clone();
+        }
+        if let Some(persona) = &overrides.persona {
+            self.persona.name = persona.clone();
+        }
+        if let Some(temperature) = overrides.llm_temperature {
+            self.llm_provider.temperature = temperature;
+        }
+        if let Some(tools) = &overrides.enabled_tools {
+            self.enabled_tools = Some(tools.clone());
+        }
+        self
+    }
+
     /// Check if small model optimizations are enabled
     pub fn is_small_model(&self) -> bool {
         self.small_model.enabled
@@ -173,6 +220,117 @@ impl Default for ToolDefaults {
     }
 }
 
+/// Translate-Think-Translate configuration
+///
+/// Non-English input is translated to English for LLM reasoning and tool
+/// calls, and the answer is translated back to the caller's language.
+#[derive(Debug, Clone)]
+pub struct TranslateThinkConfig {
+    /// Translate around the LLM when a translator is available
+    pub enabled: bool,
+    /// Detect each turn's language instead of trusting the session language
+    pub detect_language: bool,
+    /// Shield numbers and slot values from the translator with placeholders
+    pub protect_values: bool,
+}
+
+impl Default for TranslateThinkConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            detect_language: true,
+            protect_values: true,
+        }
+    }
+}
+
+/// Session tool-result memory configuration
+///
+/// Results of the listed read-only tools are reused for repeated and
+/// follow-up questions within a call and shown to the LLM on later turns.
+#[derive(Debug, Clone)]
+pub struct ToolMemoryConfig {
+    /// Remember tool results at all
+    pub enabled: bool,
+    /// How long each tool's results stay valid; unlisted tools are never remembered
+    pub ttl_secs: HashMap<String, u64>,
+    /// Results kept per session (oldest dropped first)
+    pub max_entries: usize,
+    /// Earlier results shown in the prompt (0 = none)
+    pub prompt_limit: usize,
+}
+
+impl Default for ToolMemoryConfig {
+    fn default() -> Self {
+        let ttl_secs = [
+            ("get_price", 300),
+            ("check_slot_availability", 120),
+            ("check_eligibility", 1800),
+            ("check_top_up_eligibility", 1800),
+            ("calculate_savings", 1800),
+            ("compare_providers", 1800),
+            ("find_locations", 3600),
+            ("get_document_checklist", 3600),
+        ]
+        .into_iter()
+        .map(|(tool, secs)| (tool.to_string(), secs))
+        .collect();
+
+        Self {
+            enabled: true,
+            ttl_secs,
+            max_entries: 16,
+            prompt_limit: 4,
+        }
+    }
+}
+
+/// Parallel tool execution configuration
+///
+/// When the LLM asks for several tools in one turn (price and document
+/// checklist), they run concurrently instead of one after another.
+#[derive(Debug, Clone)]
+pub struct ParallelToolConfig {
+    /// Run independent calls concurrently (false = one at a time)
+    pub enabled: bool,
+    /// Calls in flight at once
+    pub max_concurrent: usize,
+    /// Time all of a turn's tool calls share (milliseconds)
+    pub turn_budget_ms: u64,
+}
+
+impl Default for ParallelToolConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_concurrent: 4,
+            turn_budget_ms: 8000,
+        }
+    }
+}
+
+/// Early TTS configuration
+///
+/// Streamed answers are handed to TTS clause by clause instead of sentence
+/// by sentence, so synthesis of a long sentence starts before the model has
+/// finished it (see `StablePrefixDetector`).
+#[derive(Debug, Clone)]
+pub struct EarlyTtsConfig {
+    /// Release clauses (false = whole sentences only)
+    pub enabled: bool,
+    /// Fewest words a released clause may have
+    pub min_clause_words: usize,
+}
+
+impl Default for EarlyTtsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_clause_words: 4,
+        }
+    }
+}
+
 /// P1-2 FIX: Speculative decoding configuration
 ///
 /// Configures the small (SLM) and large (LLM) models for speculative execution.
@@ -363,6 +521,58 @@ pub enum AgentEvent {
         trigger: String,
         recommendation: String,
     },
+    /// Warm transfer progress reported by the agent desk
+    Handoff {
+        handoff_id: String,
+        status: HandoffStatus,
+        agent_id: Option<String>,
+        /// Number or SIP URI the call is bridged to
+        transfer_to: Option<String>,
+    },
+    /// Supervisor guidance added to the prompt
+    Whisper {
+        text: String,
+        supervisor: Option<String>,
+    },
+}
+
+/// Warm transfer progress of an escalated call
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandoffStatus {
+    /// A human agent picked the handoff from the queue
+    AgentAssigned,
+    /// The agent has read the context and is ready to take the call
+    Ready,
+    /// The caller is talking to the agent; the bot stays silent
+    Connected,
+    /// The handoff was withdrawn; the bot carries on
+    Cancelled,
+    /// The transfer did not go through; the bot carries on
+    Failed,
+}
+
+impl HandoffStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HandoffStatus::AgentAssigned => "agent_assigned",
+            HandoffStatus::Ready => "ready",
+            HandoffStatus::Connected => "connected",
+            HandoffStatus::Cancelled => "cancelled",
+            HandoffStatus::Failed => "failed",
+        }
+    }
+
+    /// Parse a status sent by the agent desk
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "agent_assigned" => Some(HandoffStatus::AgentAssigned),
+            "ready" => Some(HandoffStatus::Ready),
+            "connected" => Some(HandoffStatus::Connected),
+            "cancelled" => Some(HandoffStatus::Cancelled),
+            "failed" => Some(HandoffStatus::Failed),
+            _ => None,
+        }
+    }
 }
 
 // Re-export for backwards compatibility
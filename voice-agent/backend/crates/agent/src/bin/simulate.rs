@@ -0,0 +1,159 @@
+//! simulate: run scripted conversation scenarios against the agent
+//!
+//! Loads a domain config, then drives a fresh `DomainAgent` through each
+//! scenario with a scripted LLM and reports failed expectations. Intended
+//! for CI, to catch DST and prompt regressions without audio or a model.
+//!
+//! ```text
+//! simulate [--config-dir DIR] [--domain ID] [--filter TEXT] [--tag TAG]
+//!          [--json] [PATH...]
+//! ```
+//!
+//! With no paths, `{config-dir}/domains/{domain}/scenarios/` is used. Without
+//! `--domain`, `DOMAIN_ID` is used if set, otherwise the default domain.
+//!
+//! Exit codes: 0 = all passed, 1 = a scenario failed, 2 = usage or load error.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Arc;
+
+use voice_agent_agent::simulator::{load_scenarios, Simulator};
+use voice_agent_agent::AgentConfig;
+use voice_agent_config::MasterDomainConfig;
+
+const USAGE: &str = "Usage: simulate [--config-dir DIR] [--domain ID] [--filter TEXT] \
+[--tag TAG] [--json] [PATH...]
+
+Options:
+  --config-dir DIR    Config root containing domains/ (default: config)
+  --domain ID         Domain to load (default: DOMAIN_ID or the default domain)
+  --filter TEXT       Only run scenarios whose name contains TEXT
+  --tag TAG           Only run scenarios with this tag
+  --json              Print reports as JSON";
+
+struct Args {
+    config_dir: PathBuf,
+    domain: Option<String>,
+    filter: Option<String>,
+    tag: Option<String>,
+    json: bool,
+    paths: Vec<PathBuf>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        config_dir: PathBuf::from("config"),
+        domain: None,
+        filter: None,
+        tag: None,
+        json: false,
+        paths: Vec::new(),
+    };
+
+    let mut iter = std::env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--config-dir" => {
+                let dir = iter.next().ok_or("--config-dir requires a value")?;
+                args.config_dir = PathBuf::from(dir);
+            },
+            "--domain" => args.domain = Some(iter.next().ok_or("--domain requires a value")?),
+            "--filter" => args.filter = Some(iter.next().ok_or("--filter requires a value")?),
+            "--tag" => args.tag = Some(iter.next().ok_or("--tag requires a value")?),
+            "--json" => args.json = true,
+            "-h" | "--help" => return Err(String::new()),
+            flag if flag.starts_with('-') => return Err(format!("Unknown option: {}", flag)),
+            path => args.paths.push(PathBuf::from(path)),
+        }
+    }
+
+    Ok(args)
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(msg) => {
+            if !msg.is_empty() {
+                eprintln!("{}\n", msg);
+            }
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        },
+    };
+
+    let loaded = match args.domain {
+        Some(ref domain) => MasterDomainConfig::load(domain, &args.config_dir),
+        None => MasterDomainConfig::load_from_env(&args.config_dir),
+    };
+    let domain_config = match loaded {
+        Ok(config) => Arc::new(config),
+        Err(e) => {
+            eprintln!("Failed to load domain config: {}", e);
+            return ExitCode::from(2);
+        },
+    };
+
+    let paths = if args.paths.is_empty() {
+        vec![args
+            .config_dir
+            .join("domains")
+            .join(&domain_config.domain_id)
+            .join("scenarios")]
+    } else {
+        args.paths.clone()
+    };
+
+    let mut scenarios = Vec::new();
+    for path in &paths {
+        match load_scenarios(path) {
+            Ok(loaded) => scenarios.extend(loaded),
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::from(2);
+            },
+        }
+    }
+    scenarios.retain(|s| {
+        args.filter
+            .as_ref()
+            .map_or(true, |f| s.name.contains(f.as_str()))
+            && args.tag.as_ref().map_or(true, |t| s.tags.contains(t))
+    });
+    if scenarios.is_empty() {
+        eprintln!("No scenarios to run");
+        return ExitCode::from(2);
+    }
+
+    let simulator = Simulator::new(domain_config, AgentConfig::default());
+    let reports = simulator.run_all(&scenarios).await;
+    let failed = reports.iter().filter(|r| !r.passed()).count();
+
+    if args.json {
+        match serde_json::to_string_pretty(&reports) {
+            Ok(json) => println!("{}", json),
+            Err(e) => eprintln!("Failed to serialize reports: {}", e),
+        }
+    } else {
+        for report in &reports {
+            let status = if report.passed() { "PASS" } else { "FAIL" };
+            println!("{} {} ({} turns)", status, report.name, report.turns);
+            for failure in &report.failures {
+                println!("  turn {}: {}", failure.turn, failure.message);
+            }
+        }
+        println!(
+            "{}/{} scenarios passed",
+            reports.len() - failed,
+            reports.len()
+        );
+    }
+
+    if failed > 0 {
+        ExitCode::from(1)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
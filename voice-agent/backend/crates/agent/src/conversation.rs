@@ -25,6 +25,7 @@ use crate::stage::{ConversationStage, StageManager, TransitionReason};
 use crate::AgentError;
 use voice_agent_config::domain::StagesConfig;
 use voice_agent_core::{Turn, TurnRole};
+use voice_agent_text_processing::Gazetteer;
 
 // =============================================================================
 // Phase 2: ConversationContext Trait (Domain-Agnostic Abstraction)
@@ -73,6 +74,10 @@ pub trait ConversationContext: Send + Sync {
     /// Transition to a new conversation stage
     fn transition_stage(&self, to: ConversationStage) -> Result<(), AgentError>;
 
+    /// Advance to the next stage if the current stage's success criteria
+    /// are met, returning the new stage
+    fn advance_stage(&self) -> Option<ConversationStage>;
+
     /// Get context string for the conversation
     fn get_context(&self) -> String;
 
@@ -205,6 +210,8 @@ pub enum EndReason {
     AgentEnded,
     Timeout,
     MaxDuration,
+    /// A session budget was spent
+    BudgetExhausted,
     Error(String),
 }
 
@@ -524,6 +531,16 @@ impl Conversation {
         let location_pattern = view.location_intent_pattern();
         intent_detector.set_location_pattern(&location_pattern);
 
+        // Near-miss lender, city and branch names ("mana puram") still fill
+        // their slots, at reduced confidence
+        for (slot, entries) in view.gazetteer_entries() {
+            let mut gazetteer = Gazetteer::new();
+            for (value, names) in entries {
+                gazetteer.insert(value, names);
+            }
+            intent_detector.add_gazetteer(slot, gazetteer);
+        }
+
         // P16 FIX: Store stages config for config-driven intent transitions
         let stages_config = Arc::new(view.stages_config().clone());
 
@@ -536,7 +553,11 @@ impl Conversation {
             start_time: Instant::now(),
             last_activity: Mutex::new(Instant::now()),
             state: Mutex::new(ConversationState::Active),
-            stage_manager: Arc::new(StageManager::new()),
+            // Success criteria per stage come from stages.yaml
+            stage_manager: Arc::new(StageManager::from_slots_config(
+                &stages_config,
+                view.slots_config(),
+            )),
             memory: Arc::new(ConversationMemory::new(config.memory)),
             agentic_memory: Arc::new(agentic_memory),
             intent_detector: Arc::new(intent_detector),
@@ -609,6 +630,7 @@ impl Conversation {
                 confidence: 0.0,
                 slots: std::collections::HashMap::new(),
                 alternatives: vec![],
+                slot_spans: vec![],
             }
         };
 
@@ -674,8 +696,18 @@ impl Conversation {
     }
 
     /// Transition to a new stage
+    ///
+    /// With a stages config only its listed transitions are allowed.
     pub fn transition_stage(&self, to: ConversationStage) -> Result<(), AgentError> {
         let from = self.stage();
+        if let Some(ref stages_config) = self.stages_config {
+            if to != from && !stages_config.is_valid_transition(from.as_str(), to.as_str()) {
+                return Err(AgentError::Stage(format!(
+                    "Invalid transition from {:?} to {:?}",
+                    from, to
+                )));
+            }
+        }
 
         match self
             .stage_manager
@@ -743,6 +775,31 @@ impl Conversation {
         }
     }
 
+    /// Advance to the configured `advance_to` stage once the current
+    /// stage's success criteria are met
+    ///
+    /// Returns the new stage, or `None` when the conversation stays put.
+    pub fn advance_stage(&self) -> Option<ConversationStage> {
+        let from = self.stage();
+        if !self.stage_manager.stage_completed() {
+            return None;
+        }
+        let to = self
+            .stages_config
+            .as_ref()?
+            .advance_target(from.as_str())
+            .and_then(ConversationStage::from_str)?;
+
+        self.stage_manager
+            .transition(to, TransitionReason::StageCompleted)
+            .ok()?;
+        tracing::debug!(from = ?from, to = ?to, "Stage success criteria met, advancing");
+        let _ = self
+            .event_tx
+            .send(ConversationEvent::StageChanged { from, to });
+        Some(to)
+    }
+
     /// Get memory context
     pub fn get_context(&self) -> String {
         self.memory.get_context()
@@ -988,6 +1045,10 @@ impl ConversationContext for Conversation {
         Conversation::transition_stage(self, to)
     }
 
+    fn advance_stage(&self) -> Option<ConversationStage> {
+        Conversation::advance_stage(self)
+    }
+
     fn get_context(&self) -> String {
         self.memory.get_context()
     }
@@ -1119,6 +1180,30 @@ mod tests {
         assert_eq!(conv.stage(), ConversationStage::Discovery);
     }
 
+    #[test]
+    fn test_stage_advances_on_success_criteria() {
+        let yaml = r#"
+stages:
+  greeting:
+    transitions: [discovery]
+    success_criteria:
+      min_turns: 1
+    advance_to: discovery
+  discovery:
+    transitions: [qualification]
+"#;
+        let mut conv = Conversation::new("test", ConversationConfig::default());
+        conv.stages_config = Some(Arc::new(serde_yaml::from_str(yaml).unwrap()));
+
+        assert_eq!(conv.advance_stage(), None);
+        conv.add_user_turn("Hello").unwrap();
+        assert_eq!(conv.advance_stage(), Some(ConversationStage::Discovery));
+
+        // Only transitions listed in the config are allowed
+        assert!(conv.transition_stage(ConversationStage::Closing).is_err());
+        assert_eq!(conv.stage(), ConversationStage::Discovery);
+    }
+
     #[test]
     fn test_fact_recording() {
         let conv = Conversation::new("test", ConversationConfig::default());
@@ -0,0 +1,286 @@
+//! Call Disposition Coding
+//!
+//! Tags a finished session with one of the configured disposition codes
+//! (interested-appointment, not-eligible, wrong-number, ...). Rules over the
+//! session's signals are tried first, in priority order; when none matches
+//! the LLM picks a code from the transcript.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use voice_agent_config::{DispositionConfig, DispositionRule};
+use voice_agent_core::{GenerateRequest, LanguageModel, LlmTask};
+
+/// How a disposition was decided
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DispositionSource {
+    /// A configured rule matched
+    Rule,
+    /// The LLM picked the code
+    Llm,
+    /// Nothing decided; the fallback code was used
+    Fallback,
+}
+
+/// Disposition assigned to a finished session
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Disposition {
+    pub code: String,
+    pub source: DispositionSource,
+}
+
+/// What a session did, as seen by the disposition rules
+#[derive(Debug, Clone, Default)]
+pub struct DispositionSignals {
+    pub abusive: bool,
+    /// Tools that succeeded
+    pub tools_called: Vec<String>,
+    /// Last text output of each tool
+    pub tool_outputs: HashMap<String, String>,
+    /// Conversation as (role, content), oldest first
+    pub transcript: Vec<(String, String)>,
+    /// Dialogue goal ID
+    pub goal: String,
+    pub goal_complete: bool,
+}
+
+impl DispositionSignals {
+    fn user_turns(&self) -> impl Iterator<Item = &str> {
+        self.transcript
+            .iter()
+            .filter(|(role, _)| role == "user")
+            .map(|(_, content)| content.as_str())
+    }
+
+    fn matches(&self, rule: &DispositionRule) -> bool {
+        if rule.is_empty() {
+            return false;
+        }
+        if rule.abusive && !self.abusive {
+            return false;
+        }
+        if !rule.tool_called.is_empty()
+            && !rule
+                .tool_called
+                .iter()
+                .any(|t| self.tools_called.contains(t))
+        {
+            return false;
+        }
+        if let Some(ref expected) = rule.tool_result {
+            let value = self
+                .tool_outputs
+                .get(&expected.tool)
+                .and_then(|output| serde_json::from_str::<serde_json::Value>(output).ok())
+                .and_then(|output| output.get(&expected.field).cloned());
+            if value.as_ref() != Some(&expected.equals) {
+                return false;
+            }
+        }
+        if !rule.phrases.is_empty() {
+            let said = self.user_turns().any(|turn| {
+                let turn = turn.to_lowercase();
+                rule.phrases
+                    .iter()
+                    .any(|p| turn.contains(&p.to_lowercase()))
+            });
+            if !said {
+                return false;
+            }
+        }
+        if let Some(max) = rule.max_user_turns {
+            if self.user_turns().count() > max {
+                return false;
+            }
+        }
+        if let Some(ref goal) = rule.goal_completed {
+            if !self.goal_complete || &self.goal != goal {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Disposition classifier shared by all sessions
+pub struct DispositionClassifier {
+    config: DispositionConfig,
+}
+
+impl DispositionClassifier {
+    pub fn new(config: DispositionConfig) -> Self {
+        Self { config }
+    }
+
+    /// First code whose rule matches, in priority order
+    pub fn classify_rules(&self, signals: &DispositionSignals) -> Option<String> {
+        self.config
+            .codes
+            .iter()
+            .find(|code| signals.matches(&code.when))
+            .map(|code| code.code.clone())
+    }
+
+    /// Classify a session, asking the LLM when no rule matches
+    pub async fn classify(
+        &self,
+        signals: &DispositionSignals,
+        llm: Option<Arc<dyn LanguageModel>>,
+    ) -> Disposition {
+        if let Some(code) = self.classify_rules(signals) {
+            return Disposition {
+                code,
+                source: DispositionSource::Rule,
+            };
+        }
+
+        let llm = llm.filter(|_| self.config.llm_fallback && !signals.transcript.is_empty());
+        if let Some(llm) = llm {
+            match llm.generate(self.llm_request(signals)).await {
+                Ok(response) => {
+                    if let Some(code) = self.parse_code(&response.text) {
+                        return Disposition {
+                            code,
+                            source: DispositionSource::Llm,
+                        };
+                    }
+                    tracing::debug!(answer = %response.text, "LLM gave no known disposition code");
+                },
+                Err(e) => tracing::warn!("LLM disposition failed: {}", e),
+            }
+        }
+
+        Disposition {
+            code: self.config.fallback_code.clone(),
+            source: DispositionSource::Fallback,
+        }
+    }
+
+    fn llm_request(&self, signals: &DispositionSignals) -> GenerateRequest {
+        let codes: Vec<String> = self
+            .config
+            .codes
+            .iter()
+            .map(|c| format!("- {}: {}", c.code, c.description))
+            .collect();
+        let skip = signals
+            .transcript
+            .len()
+            .saturating_sub(self.config.llm_turns);
+        let transcript: Vec<String> = signals.transcript[skip..]
+            .iter()
+            .map(|(role, content)| format!("{}: {}", role, content))
+            .collect();
+
+        let prompt = format!(
+            "Pick the disposition code that best describes how this call ended.\n\n\
+             Codes:\n{}\n\nTools used: {}\n\nTranscript:\n{}\n\n\
+             Answer with the code only.",
+            codes.join("\n"),
+            if signals.tools_called.is_empty() {
+                "none".to_string()
+            } else {
+                signals.tools_called.join(", ")
+            },
+            transcript.join("\n")
+        );
+        GenerateRequest::new("You label finished sales calls for reporting.")
+            .with_user_message(prompt)
+            .with_task(LlmTask::Extraction)
+    }
+
+    /// Find a configured code in the LLM's answer (longest match wins)
+    fn parse_code(&self, answer: &str) -> Option<String> {
+        let answer = answer.trim().to_lowercase();
+        let answer = answer.trim_matches(|c: char| !c.is_alphanumeric());
+        let mut codes: Vec<&str> = self.config.codes.iter().map(|c| c.code.as_str()).collect();
+        codes.sort_by_key(|c| std::cmp::Reverse(c.len()));
+        codes
+            .iter()
+            .find(|c| c.to_lowercase() == answer)
+            .or_else(|| codes.iter().find(|c| answer.contains(&c.to_lowercase())))
+            .map(|c| c.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signals(turns: &[&str]) -> DispositionSignals {
+        DispositionSignals {
+            transcript: turns
+                .iter()
+                .flat_map(|t| {
+                    [
+                        ("user".to_string(), t.to_string()),
+                        ("assistant".to_string(), "Okay".to_string()),
+                    ]
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rules_in_priority_order() {
+        let classifier = DispositionClassifier::new(DispositionConfig::default());
+
+        let mut booked = signals(&["I want a gold loan", "Book me for Monday"]);
+        booked.tools_called = vec!["schedule_appointment".to_string()];
+        assert_eq!(
+            classifier.classify_rules(&booked).as_deref(),
+            Some("interested-appointment")
+        );
+        booked.abusive = true;
+        assert_eq!(
+            classifier.classify_rules(&booked).as_deref(),
+            Some("abusive")
+        );
+
+        let mut rejected = signals(&["My gold is 5 grams", "What can I get?"]);
+        rejected.tool_outputs.insert(
+            "check_eligibility".to_string(),
+            r#"{"eligible": false, "max_loan_amount": 0}"#.to_string(),
+        );
+        assert_eq!(
+            classifier.classify_rules(&rejected).as_deref(),
+            Some("not-eligible")
+        );
+
+        let wrong = signals(&["Hello?", "Sorry, wrong number"]);
+        assert_eq!(
+            classifier.classify_rules(&wrong).as_deref(),
+            Some("wrong-number")
+        );
+        assert_eq!(
+            classifier.classify_rules(&signals(&["Hello?"])).as_deref(),
+            Some("dropped")
+        );
+
+        // Nothing matches and there is no LLM
+        let undecided = classifier
+            .classify(&signals(&["What is the rate?", "Okay, thanks"]), None)
+            .await;
+        assert_eq!(undecided.code, "unclassified");
+        assert_eq!(undecided.source, DispositionSource::Fallback);
+    }
+
+    #[test]
+    fn test_parse_llm_code() {
+        let classifier = DispositionClassifier::new(DispositionConfig::default());
+        assert_eq!(
+            classifier.parse_code(" Not-Interested.").as_deref(),
+            Some("not-interested")
+        );
+        assert_eq!(
+            classifier
+                .parse_code("The code is interested-callback")
+                .as_deref(),
+            Some("interested-callback")
+        );
+        assert!(classifier.parse_code("no idea").is_none());
+    }
+}
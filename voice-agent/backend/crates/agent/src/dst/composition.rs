@@ -0,0 +1,331 @@
+//! Spelled Value Composition
+//!
+//! Customers dictate phone numbers in chunks ("98763... haan, 43210") and
+//! spell a PAN letter by letter ("A for Apple, B, C..."), often over several
+//! turns. A [`CompositionBuffer`] collects the characters for the slot being
+//! asked for until its `spelled` format is complete, then checks the value:
+//! length, character classes, the slot's `validation` regex and checksum.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use voice_agent_config::domain::{SlotDefinition, SpelledChecksum, SpelledInput};
+use voice_agent_text_processing::IndianPIIPatterns;
+
+/// Spoken digits by value, in English and Hindi (space-separated)
+const DIGIT_WORDS: &[&str] = &[
+    "zero shunya sunya शून्य",
+    "one ek एक",
+    "two do दो",
+    "three teen तीन",
+    "four char chaar चार",
+    "five paanch panch पांच पाँच",
+    "six chhe chhah छह",
+    "seven saat सात",
+    "eight aath आठ",
+    "nine nau नौ",
+];
+
+/// Words repeating the digit that follows ("double nine" -> "99")
+const REPEATS: &[(&str, usize)] = &[("double", 2), ("triple", 3)];
+
+/// Confidence of a value composed from dictated characters
+pub const COMPOSED_CONFIDENCE: f32 = 0.9;
+
+/// Words introducing a spelled letter's example ("A for Apple", "B as in Bombay")
+const SPELLING_AIDS: &str = "for as in jaise";
+
+/// Characters of a spelled value received so far
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompositionBuffer {
+    /// Slot being dictated
+    pub slot: String,
+    /// Characters received, letters uppercased
+    pub received: String,
+}
+
+/// State of the value after a turn's characters are added
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Composition {
+    /// The value is complete and valid
+    Complete(String),
+    /// Part of the value; `remaining` more characters are needed
+    Partial { received: String, remaining: usize },
+    /// The dictated value does not fit the format (the buffer is emptied)
+    Invalid { received: String },
+}
+
+impl CompositionBuffer {
+    pub fn new(slot: &str) -> Self {
+        Self {
+            slot: slot.to_string(),
+            received: String::new(),
+        }
+    }
+
+    /// Add the characters dictated in `text`
+    ///
+    /// Returns `None` when the slot is not spelled or the turn dictates
+    /// nothing. A turn that would overflow the format is taken as starting
+    /// over, after dropping any configured prefix ("+91").
+    pub fn push(&mut self, text: &str, definition: &SlotDefinition) -> Option<Composition> {
+        let spec = definition.spelled.as_ref()?;
+        let classes: Vec<char> = spec.format.chars().collect();
+        let chars = spelled_chars(text, &classes)?;
+
+        let combined = format!("{}{}", self.received, chars);
+        let candidate = [combined.clone(), chars]
+            .into_iter()
+            .map(|value| strip_prefix(value, spec, classes.len()))
+            .find(|value| value.chars().count() <= classes.len());
+        self.received.clear();
+
+        let Some(value) = candidate.filter(|value| fits_classes(value, &classes)) else {
+            return Some(Composition::Invalid { received: combined });
+        };
+        let remaining = classes.len() - value.chars().count();
+        if remaining > 0 {
+            self.received = value.clone();
+            return Some(Composition::Partial {
+                received: value,
+                remaining,
+            });
+        }
+
+        if is_valid(&value, definition, spec) {
+            Some(Composition::Complete(value))
+        } else {
+            Some(Composition::Invalid { received: value })
+        }
+    }
+}
+
+/// Characters dictated in `text` that `classes` can hold
+///
+/// A lone single character counts only when it is the whole turn, so the "a"
+/// of "I have a question" or the "do" of "bata do" is not taken as spelled.
+fn spelled_chars(text: &str, classes: &[char]) -> Option<String> {
+    let letters = classes.iter().any(|c| matches!(c, 'A' | '*'));
+    let tokens: Vec<&str> = text
+        .split(|c: char| c.is_whitespace() || c.is_ascii_punctuation() || c == '।')
+        .filter(|t| !t.is_empty())
+        .collect();
+
+    let mut chars = String::new();
+    // Consecutive spelled tokens, and how many tokens made them
+    let mut run = (String::new(), 0);
+    let mut other_words = 0;
+    let mut repeat = 1;
+    let mut i = 0;
+    while i < tokens.len() {
+        let token = tokens[i];
+        let word = token.to_lowercase();
+        i += 1;
+
+        if let Some((_, times)) = REPEATS.iter().find(|(w, _)| *w == word) {
+            repeat = *times;
+            continue;
+        }
+        let spelled = if let Some(digits) = digits_of(token) {
+            digits
+        } else if let Some(digit) = DIGIT_WORDS.iter().position(|w| has_word(w, &word)) {
+            digit.to_string()
+        } else if letters && is_spelled_letters(token) {
+            // Skip "for Apple" / "as in Bombay" after a letter
+            let aids = tokens[i..]
+                .iter()
+                .take_while(|t| has_word(SPELLING_AIDS, &t.to_lowercase()))
+                .count();
+            if aids > 0 {
+                i = (i + aids + 1).min(tokens.len());
+            }
+            token.to_uppercase()
+        } else {
+            end_run(&mut chars, &mut run);
+            other_words += 1;
+            repeat = 1;
+            continue;
+        };
+        run.0.push_str(&spelled.repeat(repeat));
+        run.1 += 1;
+        repeat = 1;
+    }
+
+    if other_words == 0 {
+        chars.push_str(&run.0);
+    } else {
+        end_run(&mut chars, &mut run);
+    }
+    (!chars.is_empty()).then_some(chars)
+}
+
+/// Keep a run of spelled tokens unless it is a lone single character
+fn end_run(chars: &mut String, run: &mut (String, usize)) {
+    if run.1 > 1 || run.0.chars().count() > 1 {
+        chars.push_str(&run.0);
+    }
+    *run = (String::new(), 0);
+}
+
+/// ASCII or Devanagari digits, as ASCII
+fn digits_of(token: &str) -> Option<String> {
+    token
+        .chars()
+        .map(|c| match c {
+            '0'..='9' => Some(c),
+            '०'..='९' => char::from_digit(c as u32 - '०' as u32, 10),
+            _ => None,
+        })
+        .collect()
+}
+
+/// A letter, or a run the recogniser wrote in capitals ("ABCDE", "1234F")
+fn is_spelled_letters(token: &str) -> bool {
+    let capital = |c: char| c.is_ascii_uppercase() || c.is_ascii_digit();
+    let capitals = token.chars().all(capital);
+    match token.chars().count() {
+        1 => token.chars().all(|c| c.is_ascii_alphabetic()),
+        // Short capitals are more often acronyms (PAN, EMI, KYC)
+        2 | 3 => capitals && token.chars().any(|c| c.is_ascii_digit()),
+        _ => capitals,
+    }
+}
+
+fn has_word(words: &str, word: &str) -> bool {
+    words.split_whitespace().any(|w| w == word)
+}
+
+/// Drop the first configured prefix that brings `value` within `len`
+fn strip_prefix(value: String, spec: &SpelledInput, len: usize) -> String {
+    if value.chars().count() <= len {
+        return value;
+    }
+    let stripped = spec
+        .prefixes
+        .iter()
+        .filter_map(|prefix| value.strip_prefix(prefix.as_str()))
+        .find(|rest| rest.chars().count() <= len)
+        .map(String::from);
+    stripped.unwrap_or(value)
+}
+
+fn fits_classes(value: &str, classes: &[char]) -> bool {
+    value.chars().zip(classes).all(|(c, class)| match class {
+        '9' => c.is_ascii_digit(),
+        'A' => c.is_ascii_uppercase(),
+        _ => c.is_ascii_alphanumeric(),
+    })
+}
+
+/// The slot's validation regex and checksum, on a complete value
+fn is_valid(value: &str, definition: &SlotDefinition, spec: &SpelledInput) -> bool {
+    let pattern_ok = definition
+        .validation
+        .as_deref()
+        .and_then(|pattern| Regex::new(pattern).ok())
+        .map_or(true, |re| re.is_match(value));
+    let checksum_ok = match spec.checksum {
+        Some(SpelledChecksum::Pan) => IndianPIIPatterns::validate_pan(value),
+        Some(SpelledChecksum::Aadhaar) => IndianPIIPatterns::validate_aadhaar(value),
+        None => true,
+    };
+    pattern_ok && checksum_ok
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definition(yaml: &str) -> SlotDefinition {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    fn phone() -> SlotDefinition {
+        definition(
+            r#"
+type: string
+validation: "^[6-9]\\d{9}$"
+spelled:
+  format: "9999999999"
+  prefixes: ["91", "0"]
+"#,
+        )
+    }
+
+    fn pan() -> SlotDefinition {
+        definition(
+            r#"
+type: string
+spelled:
+  format: "AAAAA9999A"
+  checksum: pan
+"#,
+        )
+    }
+
+    fn complete(value: &str) -> Option<Composition> {
+        Some(Composition::Complete(value.to_string()))
+    }
+
+    #[test]
+    fn test_phone_in_chunks() {
+        let phone = phone();
+        let mut buffer = CompositionBuffer::new("phone_number");
+
+        let first = buffer.push("98763...", &phone);
+        let expected = Composition::Partial {
+            received: "98763".to_string(),
+            remaining: 5,
+        };
+        assert_eq!(first, Some(expected));
+        assert_eq!(buffer.push("kya karna hai", &phone), None);
+
+        let second = buffer.push("haan, 432 double one", &phone);
+        assert_eq!(second, complete("9876343211"));
+        assert!(buffer.received.is_empty());
+    }
+
+    #[test]
+    fn test_phone_prefix_and_restart() {
+        let phone = phone();
+        let mut buffer = CompositionBuffer::new("phone_number");
+
+        let full = buffer.push("+91 98763 43210", &phone);
+        assert_eq!(full, complete("9876343210"));
+
+        // Too long even without a prefix: the customer started over
+        buffer.push("98763", &phone);
+        let restarted = buffer.push("8888877777", &phone);
+        assert_eq!(restarted, complete("8888877777"));
+
+        // Fails the validation regex
+        let invalid = buffer.push("12345 67890", &phone);
+        assert!(matches!(invalid, Some(Composition::Invalid { .. })));
+    }
+
+    #[test]
+    fn test_pan_spelled_letters() {
+        let pan = pan();
+        let mut buffer = CompositionBuffer::new("pan_number");
+
+        let letters = buffer.push("A for Apple, B, C, P as in Pune, K", &pan);
+        let partial = matches!(letters, Some(Composition::Partial { remaining: 5, .. }));
+        assert!(partial);
+        let rest = buffer.push("1234 F", &pan);
+        assert_eq!(rest, complete("ABCPK1234F"));
+
+        // "X" is not a holder type
+        let invalid = buffer.push("ABCXK1234F", &pan);
+        assert!(matches!(invalid, Some(Composition::Invalid { .. })));
+        // A digit where a letter belongs
+        let misplaced = buffer.push("ABC1", &pan);
+        assert!(matches!(misplaced, Some(Composition::Invalid { .. })));
+    }
+
+    #[test]
+    fn test_stray_words_not_spelled() {
+        let pan = pan();
+        let mut buffer = CompositionBuffer::new("pan_number");
+        assert_eq!(buffer.push("I have a question", &pan), None);
+        assert_eq!(buffer.push("my pan is not with me", &pan), None);
+    }
+}
@@ -0,0 +1,108 @@
+//! Slot Confirmation Policy
+//!
+//! Decides which extracted values must be read back to the customer, using
+//! each slot's `confirmation` policy from slots.yaml (falling back to the
+//! tracker's confidence threshold), and recognises the customer's yes/no
+//! answer in English or Hindi.
+
+use voice_agent_config::domain::{ConfirmationPolicy, ConfirmationReplies};
+
+/// Customer's answer to a confirmation question
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmationReply {
+    Yes,
+    No,
+}
+
+/// Whether a value extracted with `confidence` must be confirmed
+///
+/// Slots without a policy (or without a threshold in it) are confirmed
+/// automatically at `default_threshold` or above.
+pub fn requires_confirmation(
+    policy: Option<&ConfirmationPolicy>,
+    confidence: f32,
+    default_threshold: f32,
+) -> bool {
+    match policy {
+        Some(policy) if policy.always => true,
+        Some(ConfirmationPolicy {
+            below_confidence: Some(threshold),
+            ..
+        }) => confidence < *threshold,
+        _ => confidence < default_threshold,
+    }
+}
+
+/// Detect a yes/no answer in the customer's turn
+///
+/// Words of every language count. A negation wins over agreement, so
+/// "sahi nahi hai" and "no, not right" are both `No`.
+pub fn detect_reply(text: &str, replies: &ConfirmationReplies) -> Option<ConfirmationReply> {
+    let tokens = tokenize(text);
+    let said = |words: &std::collections::HashMap<String, Vec<String>>| {
+        words
+            .values()
+            .flatten()
+            .any(|phrase| contains_phrase(&tokens, &tokenize(phrase)))
+    };
+
+    if said(&replies.no) {
+        Some(ConfirmationReply::No)
+    } else if said(&replies.yes) {
+        Some(ConfirmationReply::Yes)
+    } else {
+        None
+    }
+}
+
+/// Lowercased words, splitting on whitespace and punctuation (incl. the danda)
+pub(super) fn tokenize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| c.is_whitespace() || c.is_ascii_punctuation() || c == '।')
+        .filter(|t| !t.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+pub(super) fn contains_phrase(tokens: &[String], phrase: &[String]) -> bool {
+    !phrase.is_empty() && tokens.windows(phrase.len()).any(|window| window == phrase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_thresholds() {
+        assert!(!requires_confirmation(None, 0.95, 0.9));
+        assert!(requires_confirmation(None, 0.8, 0.9));
+
+        let always = ConfirmationPolicy {
+            always: true,
+            ..ConfirmationPolicy::default()
+        };
+        assert!(requires_confirmation(Some(&always), 1.0, 0.9));
+
+        let strict = ConfirmationPolicy {
+            below_confidence: Some(0.98),
+            ..ConfirmationPolicy::default()
+        };
+        assert!(requires_confirmation(Some(&strict), 0.95, 0.9));
+    }
+
+    #[test]
+    fn test_detect_reply() {
+        let replies = ConfirmationReplies::default();
+        for (text, expected) in [
+            ("Yes, that's it", Some(ConfirmationReply::Yes)),
+            ("haan ji", Some(ConfirmationReply::Yes)),
+            ("हाँ, सही है।", Some(ConfirmationReply::Yes)),
+            ("sahi nahi hai", Some(ConfirmationReply::No)),
+            ("No, it's 60 grams", Some(ConfirmationReply::No)),
+            // "know" and "notice" are not "no"/"not"
+            ("I know, notice it", None),
+        ] {
+            assert_eq!(detect_reply(text, &replies), expected, "{}", text);
+        }
+    }
+}
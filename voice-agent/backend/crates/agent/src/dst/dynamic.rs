@@ -9,7 +9,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use voice_agent_config::domain::{GoalDefinition, SlotDefinition, SlotsConfig};
+use voice_agent_config::domain::{GoalDefinition, GoalSwitchAction, SlotDefinition, SlotsConfig};
 
 use super::{DialogueStateTrait, NextBestAction, SlotValue, DEFAULT_GOAL};
 
@@ -40,6 +40,10 @@ pub struct DynamicDialogueState {
     /// Confirmed slots
     confirmed_slots: HashSet<String>,
 
+    /// Confirmed slots that newly extracted values may not overwrite
+    #[serde(default)]
+    locked_slots: HashSet<String>,
+
     /// Primary detected intent
     primary_intent: Option<String>,
 
@@ -58,6 +62,18 @@ pub struct DynamicDialogueState {
     /// Turn at which goal was set
     goal_set_turn: usize,
 
+    /// Unfinished goals the customer moved away from (most recent last)
+    #[serde(default)]
+    suspended_goals: Vec<String>,
+
+    /// Goal resumed from `suspended_goals` this turn
+    #[serde(default)]
+    resumed_goal: Option<String>,
+
+    /// Objection raised in the latest customer turn, not yet addressed
+    #[serde(default)]
+    active_objection: Option<String>,
+
     /// Slot configuration (not serialized - provided externally)
     #[serde(skip)]
     config: Option<Arc<SlotsConfig>>,
@@ -69,12 +85,16 @@ impl Default for DynamicDialogueState {
             slots: HashMap::new(),
             pending_slots: HashSet::new(),
             confirmed_slots: HashSet::new(),
+            locked_slots: HashSet::new(),
             primary_intent: None,
             intent_confidence: 0.0,
             secondary_intents: Vec::new(),
             conversation_goal: DEFAULT_GOAL.to_string(),
             goal_confirmed: false,
             goal_set_turn: 0,
+            suspended_goals: Vec::new(),
+            resumed_goal: None,
+            active_objection: None,
             config: None,
         }
     }
@@ -101,6 +121,29 @@ impl DynamicDialogueState {
         self.config = Some(config);
     }
 
+    /// Set (or clear) the objection raised in the latest customer turn
+    ///
+    /// While set, `next_best_action` is to handle it rather than continue
+    /// the goal.
+    pub fn set_objection(&mut self, objection: Option<String>) {
+        self.active_objection = objection;
+    }
+
+    /// Objection raised in the latest customer turn
+    pub fn active_objection(&self) -> Option<&str> {
+        self.active_objection.as_deref()
+    }
+
+    /// Protect a slot's value from being overwritten by later extractions
+    pub fn lock_slot(&mut self, slot_name: &str) {
+        self.locked_slots.insert(slot_name.to_string());
+    }
+
+    /// Whether the slot is locked (cleared by `clear_slot`)
+    pub fn is_slot_locked(&self, slot_name: &str) -> bool {
+        self.locked_slots.contains(slot_name)
+    }
+
     /// Get the slots configuration
     pub fn config(&self) -> Option<&SlotsConfig> {
         self.config.as_ref().map(|c| c.as_ref())
@@ -195,6 +238,76 @@ impl DynamicDialogueState {
         self.goal_set_turn
     }
 
+    /// Unfinished goals waiting to be resumed (most recent last)
+    pub fn suspended_goals(&self) -> &[String] {
+        &self.suspended_goals
+    }
+
+    /// Goal resumed this turn, so the agent can say it is coming back to it
+    pub fn resumed_goal(&self) -> Option<&str> {
+        self.resumed_goal.as_deref()
+    }
+
+    /// Forget the resumed goal once its turn is over
+    pub fn clear_resumed_goal(&mut self) {
+        self.resumed_goal = None;
+    }
+
+    /// Once the current goal is complete, return to the most recently
+    /// suspended goal that is still unfinished
+    pub fn resume_suspended_goal(&mut self, turn: usize) -> Option<&str> {
+        if self.conversation_goal == DEFAULT_GOAL || !self.is_goal_complete() {
+            return None;
+        }
+        while let Some(goal) = self.suspended_goals.pop() {
+            if !self.is_goal_id_complete(&goal) {
+                self.conversation_goal = goal.clone();
+                self.goal_confirmed = false;
+                self.goal_set_turn = turn;
+                self.resumed_goal = Some(goal);
+                return self.resumed_goal.as_deref();
+            }
+        }
+        None
+    }
+
+    /// Switch the current goal, suspending the old one unless it is
+    /// finished or a `goal_transitions` rule says to replace it
+    fn switch_goal(&mut self, goal_id: &str) {
+        if goal_id == self.conversation_goal {
+            return;
+        }
+        let previous = std::mem::replace(&mut self.conversation_goal, goal_id.to_string());
+        self.resumed_goal = None;
+        self.suspended_goals.retain(|g| g != goal_id);
+
+        let (action, max_suspended) = match self.config {
+            Some(ref config) => (
+                config.goal_switch_action(&previous, goal_id),
+                config.max_suspended_goals,
+            ),
+            None => (GoalSwitchAction::Suspend, 0),
+        };
+        if previous == DEFAULT_GOAL
+            || action == GoalSwitchAction::Replace
+            || self.is_goal_id_complete(&previous)
+        {
+            return;
+        }
+        self.suspended_goals.retain(|g| *g != previous);
+        self.suspended_goals.push(previous);
+        if self.suspended_goals.len() > max_suspended {
+            let excess = self.suspended_goals.len() - max_suspended;
+            self.suspended_goals.drain(..excess);
+        }
+    }
+
+    fn is_goal_id_complete(&self, goal_id: &str) -> bool {
+        self.required_slots_for_goal(goal_id)
+            .iter()
+            .all(|s| self.get_slot_value(s).is_some())
+    }
+
     /// Check if we have complete contact info
     pub fn has_complete_contact(&self) -> bool {
         self.slots.contains_key("customer_name") && self.slots.contains_key("phone_number")
@@ -272,7 +385,7 @@ impl DynamicDialogueState {
 
     /// Check if current goal is complete (all required slots filled)
     pub fn is_goal_complete(&self) -> bool {
-        self.missing_required_slots().is_empty()
+        self.is_goal_id_complete(&self.conversation_goal)
     }
 }
 
@@ -298,6 +411,7 @@ impl DialogueStateTrait for DynamicDialogueState {
         self.slots.remove(slot_name);
         self.pending_slots.remove(slot_name);
         self.confirmed_slots.remove(slot_name);
+        self.locked_slots.remove(slot_name);
     }
 
     fn filled_slots(&self) -> Vec<&str> {
@@ -334,13 +448,13 @@ impl DialogueStateTrait for DynamicDialogueState {
     fn set_goal(&mut self, goal_id: &str, turn: usize) {
         // Only update if it's a meaningful change (not downgrading to exploration)
         if goal_id != DEFAULT_GOAL || self.conversation_goal == DEFAULT_GOAL {
-            self.conversation_goal = goal_id.to_string();
+            self.switch_goal(goal_id);
             self.goal_set_turn = turn;
         }
     }
 
     fn confirm_goal(&mut self, goal_id: &str, turn: usize) {
-        self.conversation_goal = goal_id.to_string();
+        self.switch_goal(goal_id);
         self.goal_confirmed = true;
         self.goal_set_turn = turn;
     }
@@ -407,6 +521,12 @@ impl DialogueStateTrait for DynamicDialogueState {
 
         // Goal info
         output.push_str(&format!("# Current Goal: {}\n", self.conversation_goal));
+        if !self.suspended_goals.is_empty() {
+            output.push_str(&format!(
+                "# Suspended Goals: {}\n",
+                self.suspended_goals.join(", ")
+            ));
+        }
 
         // Missing slots
         let missing = self.missing_required_slots();
@@ -443,6 +563,11 @@ impl DialogueStateTrait for DynamicDialogueState {
     }
 
     fn next_best_action(&self) -> NextBestAction {
+        // An open objection comes before any step of the sales flow
+        if let Some(ref objection) = self.active_objection {
+            return NextBestAction::HandleObjection(objection.clone());
+        }
+
         // Check if we're in exploration mode
         if self.conversation_goal == DEFAULT_GOAL || self.conversation_goal.is_empty() {
             return NextBestAction::DiscoverIntent;
@@ -649,6 +774,59 @@ intent_mapping:
         );
     }
 
+    #[test]
+    fn test_objection_preempts_goal() {
+        let config = create_test_config();
+        let mut state = DynamicDialogueState::from_config(config);
+        state.set_goal("balance_transfer", 0);
+
+        state.set_objection(Some("need_time".to_string()));
+        assert_eq!(
+            state.next_best_action(),
+            NextBestAction::HandleObjection("need_time".to_string())
+        );
+
+        // Customer moved on: back to the goal's next step
+        state.set_objection(None);
+        let action = state.next_best_action();
+        assert!(matches!(action, NextBestAction::AskFor(_)));
+    }
+
+    #[test]
+    fn test_interleaved_goals_resume() {
+        let config = create_test_config();
+        let mut state = DynamicDialogueState::from_config(config);
+        state.set_goal("balance_transfer", 0);
+        state.set_slot_value("current_lender", "Muthoot", 0.9);
+
+        // Customer asks about eligibility before finishing the transfer
+        state.set_goal("eligibility_check", 1);
+        assert_eq!(state.suspended_goals(), ["balance_transfer"]);
+        assert_eq!(state.resume_suspended_goal(2), None);
+
+        state.set_slot_value("gold_weight", "40", 0.9);
+        assert_eq!(state.resume_suspended_goal(2), Some("balance_transfer"));
+        assert_eq!(state.goal_id(), "balance_transfer");
+        assert!(state.suspended_goals().is_empty());
+    }
+
+    #[test]
+    fn test_goal_replace_rule() {
+        let mut config = (*create_test_config()).clone();
+        config.goal_transitions = serde_yaml::from_str(
+            "[{ from: eligibility_check, to: balance_transfer, action: replace }]",
+        )
+        .unwrap();
+        let mut state = DynamicDialogueState::from_config(Arc::new(config));
+
+        state.set_goal("eligibility_check", 0);
+        state.set_goal("balance_transfer", 1);
+        assert!(state.suspended_goals().is_empty());
+
+        state.set_goal("lead_capture", 2);
+        assert_eq!(state.suspended_goals(), ["balance_transfer"]);
+    }
+
     #[test]
     fn test_goal_for_intent() {
         let config = create_test_config();
@@ -0,0 +1,270 @@
+//! Answer Interpretation
+//!
+//! Resolves a short customer answer against the question the agent asked
+//! last: a read-back of collected values ("haan", "nahin"), a choice between
+//! a slot's options ("the second one", "morning wala") or a yes/no offer
+//! ("shall I book a branch visit?") or which of two intents the customer
+//! meant ("eligibility wala"). Each interpretation carries a confidence,
+//! lower for long answers and positional picks.
+
+use serde::{Deserialize, Serialize};
+use voice_agent_config::domain::{ConfirmationReplies, IntentDefinition, SlotDefinition};
+
+use super::confirmation::{self, contains_phrase, tokenize, ConfirmationReply};
+
+/// Answers up to this many words are taken as a plain yes/no
+const SHORT_ANSWER_WORDS: usize = 4;
+
+/// Ordinal words by position, in English and Hindi (space-separated)
+const ORDINALS: &[&str] = &[
+    "first 1st pehla pehli pahla pahli पहला पहली",
+    "second 2nd doosra dusra doosri dusri दूसरा दूसरी",
+    "third 3rd teesra tisra teesri tisri तीसरा तीसरी",
+    "fourth 4th chautha chauthi चौथा चौथी",
+];
+
+/// Words picking the last option offered
+const LAST: &str = "last aakhri akhri आखिरी";
+
+/// Question awaiting the customer's answer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PendingQuestion {
+    /// Read-back of slot values awaiting confirmation
+    Confirm { slots: Vec<String> },
+    /// Choice between a slot's options, in the order they were offered
+    Choose {
+        slot: String,
+        options: Vec<AnswerOption>,
+    },
+    /// Yes/no question about an offer (e.g. "appointment")
+    YesNo { topic: String },
+    /// Which of two intents the customer meant, option IDs being intent names
+    Clarify { options: Vec<AnswerOption> },
+}
+
+impl PendingQuestion {
+    /// Choice between an enum slot's configured values
+    pub fn choose_from(slot: &str, definition: &SlotDefinition) -> Option<Self> {
+        let values = definition.values.as_ref().filter(|v| !v.is_empty())?;
+        let options = values
+            .iter()
+            .map(|value| {
+                let mut labels = vec![value.id.replace('_', " "), value.display.clone()];
+                labels.extend(value.short_code.iter().cloned());
+                labels.extend(value.patterns.iter().cloned());
+                AnswerOption {
+                    id: value.id.clone(),
+                    labels,
+                }
+            })
+            .collect();
+        Some(Self::Choose {
+            slot: slot.to_string(),
+            options,
+        })
+    }
+
+    /// Choice between two intents, named by their clarification phrases or
+    /// the words of their names ("balance", "transfer")
+    pub fn clarify_between(intents: [&IntentDefinition; 2]) -> Self {
+        let options = intents
+            .iter()
+            .map(|intent| {
+                let name = intent.name.replace('_', " ");
+                let mut labels: Vec<String> = intent.clarify_as.values().cloned().collect();
+                labels.extend(name.split_whitespace().map(str::to_string));
+                labels.push(name);
+                AnswerOption {
+                    id: intent.name.clone(),
+                    labels,
+                }
+            })
+            .collect();
+        Self::Clarify { options }
+    }
+}
+
+/// One option of a choice question
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnswerOption {
+    /// Value stored in the slot when chosen
+    pub id: String,
+    /// Words that name this option
+    pub labels: Vec<String>,
+}
+
+/// What the customer answered
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Answer {
+    Yes,
+    No,
+    /// ID of the chosen option
+    Option(String),
+}
+
+/// An answer with how sure the interpretation is (0.0-1.0)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interpretation {
+    pub answer: Answer,
+    pub confidence: f32,
+}
+
+/// Interpret `text` as an answer to `question`
+///
+/// Returns `None` when the text does not answer it (the customer changed the
+/// subject or was ambiguous).
+pub fn interpret(
+    text: &str,
+    question: &PendingQuestion,
+    replies: &ConfirmationReplies,
+) -> Option<Interpretation> {
+    let tokens = tokenize(text);
+    match question {
+        PendingQuestion::Confirm { .. } | PendingQuestion::YesNo { .. } => {
+            let answer = match confirmation::detect_reply(text, replies)? {
+                ConfirmationReply::Yes => Answer::Yes,
+                ConfirmationReply::No => Answer::No,
+            };
+            let confidence = if tokens.len() <= SHORT_ANSWER_WORDS {
+                0.95
+            } else {
+                0.75
+            };
+            Some(Interpretation { answer, confidence })
+        },
+        PendingQuestion::Choose { options, .. } | PendingQuestion::Clarify { options } => {
+            choose(&tokens, options)
+        },
+    }
+}
+
+/// Pick an option by name, else by position
+fn choose(tokens: &[String], options: &[AnswerOption]) -> Option<Interpretation> {
+    // Score each option by the longest of its labels the customer said
+    let scores: Vec<usize> = options
+        .iter()
+        .map(|option| {
+            option
+                .labels
+                .iter()
+                .map(|label| tokenize(label))
+                .filter(|label| contains_phrase(tokens, label))
+                .map(|label| label.iter().map(String::len).sum())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let best = scores.iter().copied().max().unwrap_or(0);
+    if best > 0 {
+        let mut named = options.iter().zip(&scores).filter(|(_, s)| **s == best);
+        let (option, _) = named.next()?;
+        // Two options named equally well ("12" is both morning and afternoon)
+        if named.next().is_some() {
+            return None;
+        }
+        let confidence = if scores.iter().filter(|&&s| s > 0).count() == 1 {
+            0.9
+        } else {
+            0.7
+        };
+        return Some(Interpretation {
+            answer: Answer::Option(option.id.clone()),
+            confidence,
+        });
+    }
+
+    let said = |words: &str| {
+        words
+            .split_whitespace()
+            .any(|w| tokens.iter().any(|t| t == w))
+    };
+    let position = ORDINALS
+        .iter()
+        .position(|words| said(words))
+        .or_else(|| said(LAST).then_some(options.len().saturating_sub(1)))?;
+    options.get(position).map(|option| Interpretation {
+        answer: Answer::Option(option.id.clone()),
+        confidence: 0.8,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time_question() -> PendingQuestion {
+        let option = |id: &str, labels: &[&str]| AnswerOption {
+            id: id.to_string(),
+            labels: labels.iter().map(|l| l.to_string()).collect(),
+        };
+        PendingQuestion::Choose {
+            slot: "preferred_time".to_string(),
+            options: vec![
+                option("morning", &["morning", "subah", "12"]),
+                option("afternoon", &["afternoon", "dopahar", "12"]),
+                option("evening", &["evening", "shaam"]),
+            ],
+        }
+    }
+
+    fn chosen(text: &str) -> Option<String> {
+        let replies = ConfirmationReplies::default();
+        match interpret(text, &time_question(), &replies)?.answer {
+            Answer::Option(id) => Some(id),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_option_by_name_or_position() {
+        assert_eq!(chosen("morning wala").as_deref(), Some("morning"));
+        assert_eq!(chosen("shaam ko").as_deref(), Some("evening"));
+        assert_eq!(chosen("the second one").as_deref(), Some("afternoon"));
+        assert_eq!(chosen("doosra wala").as_deref(), Some("afternoon"));
+        assert_eq!(chosen("last one please").as_deref(), Some("evening"));
+        // "12" names two options
+        assert_eq!(chosen("12 baje"), None);
+        assert_eq!(chosen("what is the interest rate"), None);
+    }
+
+    #[test]
+    fn test_yes_no_confidence() {
+        let replies = ConfirmationReplies::default();
+        let question = PendingQuestion::YesNo {
+            topic: "appointment".to_string(),
+        };
+
+        let short = interpret("haan ji", &question, &replies).unwrap();
+        assert_eq!(short.answer, Answer::Yes);
+        let long = interpret("yes but first tell me the rate today", &question, &replies);
+        assert!(long.unwrap().confidence < short.confidence);
+
+        let no = interpret("nahin", &question, &replies).unwrap();
+        assert_eq!(no.answer, Answer::No);
+    }
+
+    #[test]
+    fn test_clarify_between_intents() {
+        let intent = |name: &str, phrase: &str| IntentDefinition {
+            name: name.to_string(),
+            description: String::new(),
+            required_slots: vec![],
+            optional_slots: vec![],
+            examples: vec![],
+            clarify_as: [("en".to_string(), phrase.to_string())].into(),
+        };
+        let eligibility = intent("eligibility_check", "check your eligibility");
+        let transfer = intent("balance_transfer", "transfer an existing loan");
+        let question = PendingQuestion::clarify_between([&eligibility, &transfer]);
+        let replies = ConfirmationReplies::default();
+        let picked = |text: &str| match interpret(text, &question, &replies)?.answer {
+            Answer::Option(id) => Some(id),
+            _ => None,
+        };
+
+        assert_eq!(picked("eligibility").as_deref(), Some("eligibility_check"));
+        assert_eq!(picked("transfer my loan").as_deref(), Some("balance_transfer"));
+        assert_eq!(picked("first wala").as_deref(), Some("eligibility_check"));
+        assert_eq!(picked("what is the gold rate"), None);
+    }
+}
@@ -0,0 +1,159 @@
+//! Keypad Fallback
+//!
+//! When transcripts keep coming back with low STT confidence, answers are
+//! asked for on the phone keypad instead (see [`KeypadConfig`]). A
+//! [`Keypad`] counts the badly heard turns in a row to switch over, and
+//! collects the digits typed for the slot being asked for until the entry
+//! is ended with the terminator key or has all its digits.
+
+use voice_agent_config::domain::KeypadConfig;
+
+/// Confidence of a value typed on the keypad
+pub const KEYED_CONFIDENCE: f32 = 1.0;
+
+/// Keypad fallback state of a conversation
+#[derive(Debug, Clone, Default)]
+pub struct Keypad {
+    /// Badly heard turns in a row
+    misheard: usize,
+    /// Answers are asked for on the keypad
+    active: bool,
+    /// Slot being asked for, if it can be typed
+    asking: Option<String>,
+    /// Digits typed for it so far
+    entered: String,
+}
+
+/// What a key typed towards an entry amounts to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyEntry {
+    /// The entry is still being typed
+    Typing,
+    /// The entry is complete
+    Entered(String),
+    /// The digits typed so far were discarded
+    Cleared,
+}
+
+impl Keypad {
+    /// Count a transcript's STT confidence
+    ///
+    /// Switches to the keypad after `switch_after` badly heard turns in a
+    /// row, and back to speech on a well heard one. Returns whether this
+    /// turn switched to the keypad.
+    pub fn hear(&mut self, confidence: f32, config: &KeypadConfig) -> bool {
+        if !config.enabled {
+            return false;
+        }
+        if confidence >= config.low_confidence {
+            self.misheard = 0;
+            self.active = false;
+            return false;
+        }
+        self.misheard += 1;
+        let switched = !self.active && self.misheard >= config.switch_after;
+        self.active |= switched;
+        switched
+    }
+
+    /// Whether answers are asked for on the keypad
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Slot being asked for on the keypad
+    pub fn asking(&self) -> Option<&str> {
+        self.asking.as_deref()
+    }
+
+    /// Set the slot being asked for, dropping digits typed for another one
+    pub fn ask(&mut self, slot: Option<String>, config: &KeypadConfig) {
+        let slot = slot.filter(|slot| config.slots.contains_key(slot));
+        if slot != self.asking {
+            self.entered.clear();
+        }
+        self.asking = slot;
+    }
+
+    /// Add a key to the entry for the slot being asked for
+    ///
+    /// Returns `None` when no typed slot is asked for or the key is no digit.
+    pub fn type_key(&mut self, key: char, config: &KeypadConfig) -> Option<KeyEntry> {
+        let digits = config.slots.get(self.asking.as_ref()?)?.digits;
+        if key == config.clear {
+            self.entered.clear();
+            return Some(KeyEntry::Cleared);
+        }
+        if key == config.terminator && !self.entered.is_empty() {
+            return Some(KeyEntry::Entered(std::mem::take(&mut self.entered)));
+        }
+        if !key.is_ascii_digit() {
+            return None;
+        }
+        self.entered.push(key);
+        if self.entered.len() >= digits {
+            return Some(KeyEntry::Entered(std::mem::take(&mut self.entered)));
+        }
+        Some(KeyEntry::Typing)
+    }
+
+    /// Forget the switch and any digits typed
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use voice_agent_config::domain::KeypadEntry;
+
+    fn config() -> KeypadConfig {
+        let mut config = KeypadConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        let entry = KeypadEntry {
+            digits: 6,
+            prompt: Default::default(),
+        };
+        config.slots.insert("pincode".to_string(), entry);
+        config
+    }
+
+    #[test]
+    fn test_switches_after_misheard_turns() {
+        let config = config();
+        let mut keypad = Keypad::default();
+        assert!(!keypad.hear(0.3, &config));
+        assert!(keypad.hear(0.2, &config));
+        assert!(keypad.is_active());
+        assert!(!keypad.hear(0.1, &config));
+
+        // Heard well again: back to speech
+        keypad.hear(0.9, &config);
+        assert!(!keypad.is_active());
+    }
+
+    #[test]
+    fn test_digit_entry() {
+        let config = config();
+        let mut keypad = Keypad::default();
+        assert_eq!(keypad.type_key('4', &config), None);
+
+        keypad.ask(Some("pincode".to_string()), &config);
+        for key in "4000".chars() {
+            assert_eq!(keypad.type_key(key, &config), Some(KeyEntry::Typing));
+        }
+        assert_eq!(keypad.type_key('*', &config), Some(KeyEntry::Cleared));
+        for key in "40000".chars() {
+            keypad.type_key(key, &config);
+        }
+        let entry = keypad.type_key('1', &config);
+        assert_eq!(entry, Some(KeyEntry::Entered("400001".to_string())));
+
+        keypad.type_key('5', &config);
+        let entry = keypad.type_key('#', &config);
+        assert_eq!(entry, Some(KeyEntry::Entered("5".to_string())));
+    }
+}
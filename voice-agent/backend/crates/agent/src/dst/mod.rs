@@ -42,8 +42,12 @@
 //! tracker.update_slot("customer_name", "Rahul", 0.9, ChangeSource::UserUtterance, 0);
 //! ```
 
-pub mod slots;
+pub mod composition;
+pub mod confirmation;
 pub mod dynamic;
+pub mod interpretation;
+pub mod keypad;
+pub mod slots;
 
 // Core types from slots module
 pub use slots::{
@@ -54,6 +58,12 @@ pub use slots::{
 // Primary dialogue state implementation
 pub use dynamic::DynamicDialogueState;
 
+// Confirmations and answers to the agent's questions
+pub use composition::{Composition, CompositionBuffer};
+pub use confirmation::ConfirmationReply;
+pub use interpretation::{Answer, AnswerOption, Interpretation, PendingQuestion};
+pub use keypad::{KeyEntry, Keypad};
+
 
 // Re-export SlotExtractor from text_processing
 pub use voice_agent_text_processing::SlotExtractor;
@@ -62,8 +72,11 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use voice_agent_core::Speaker;
 use voice_agent_text_processing::intent::{DetectedIntent, Slot};
-use voice_agent_config::domain::AgentDomainView;
+use voice_agent_config::domain::{
+    AgentDomainView, ConfirmationPolicy, QuantityError, SlotQuantity,
+};
 
 // =============================================================================
 // DialogueStateTrait - The Abstraction
@@ -190,6 +203,15 @@ pub enum ChangeSource {
     SystemConfirmation,
     /// External data (CRM, etc.)
     External,
+    /// Typed on the phone keypad
+    Keypad,
+}
+
+impl ChangeSource {
+    /// Whether the value was extracted from what the customer said
+    fn is_extracted(self) -> bool {
+        matches!(self, Self::UserUtterance | Self::Correction)
+    }
 }
 
 /// Dialogue State Tracker
@@ -207,6 +229,25 @@ pub struct DialogueStateTracker {
     slots_config: Arc<voice_agent_config::domain::SlotsConfig>,
     /// Domain view for config-driven instructions (optional)
     domain_view: Option<Arc<AgentDomainView>>,
+    /// Question the last response asked
+    pending_question: Option<PendingQuestion>,
+    /// Answer resolved this turn, with the question it answered
+    last_answer: Option<(PendingQuestion, Interpretation)>,
+    /// Spelled value being dictated for the slot asked for
+    composition: Option<CompositionBuffer>,
+    /// Slot and state of the spelled value after this turn
+    last_composition: Option<(String, Composition)>,
+    /// Implausible quantities held back this turn, by slot
+    held_back: Vec<(String, QuantityError)>,
+    /// Quantities held back on the previous turn, now being questioned
+    questioned: Vec<(String, QuantityError)>,
+    /// Party speaking on a conference call (`None` outside conference mode)
+    speaker: Option<Speaker>,
+    /// Keypad fallback for a poor line
+    keypad: Keypad,
+    /// Intent pairs the customer was asked to choose between, so a pair is
+    /// never asked twice
+    clarified: Vec<[String; 2]>,
 }
 
 impl DialogueStateTracker {
@@ -221,6 +262,15 @@ impl DialogueStateTracker {
             config: DstConfig::default(),
             slots_config,
             domain_view: None,
+            pending_question: None,
+            last_answer: None,
+            composition: None,
+            last_composition: None,
+            held_back: Vec::new(),
+            questioned: Vec::new(),
+            speaker: None,
+            keypad: Keypad::default(),
+            clarified: Vec::new(),
         }
     }
 
@@ -236,6 +286,15 @@ impl DialogueStateTracker {
             config: dst_config,
             slots_config,
             domain_view: None,
+            pending_question: None,
+            last_answer: None,
+            composition: None,
+            last_composition: None,
+            held_back: Vec::new(),
+            questioned: Vec::new(),
+            speaker: None,
+            keypad: Keypad::default(),
+            clarified: Vec::new(),
         }
     }
 
@@ -248,6 +307,15 @@ impl DialogueStateTracker {
             config: DstConfig::default(),
             slots_config,
             domain_view: None,
+            pending_question: None,
+            last_answer: None,
+            composition: None,
+            last_composition: None,
+            held_back: Vec::new(),
+            questioned: Vec::new(),
+            speaker: None,
+            keypad: Keypad::default(),
+            clarified: Vec::new(),
         }
     }
 
@@ -265,6 +333,15 @@ impl DialogueStateTracker {
             config: dst_config,
             slots_config,
             domain_view: None,
+            pending_question: None,
+            last_answer: None,
+            composition: None,
+            last_composition: None,
+            held_back: Vec::new(),
+            questioned: Vec::new(),
+            speaker: None,
+            keypad: Keypad::default(),
+            clarified: Vec::new(),
         }
     }
 
@@ -281,6 +358,15 @@ impl DialogueStateTracker {
             config: dst_config,
             slots_config,
             domain_view: None,
+            pending_question: None,
+            last_answer: None,
+            composition: None,
+            last_composition: None,
+            held_back: Vec::new(),
+            questioned: Vec::new(),
+            speaker: None,
+            keypad: Keypad::default(),
+            clarified: Vec::new(),
         }
     }
 
@@ -318,14 +404,24 @@ impl DialogueStateTracker {
     /// Update state from detected intent
     pub fn update(&mut self, intent: &DetectedIntent) {
         let turn_index = self.history.len();
+        self.questioned = std::mem::take(&mut self.held_back);
+
+        // A goal finished on an earlier turn hands back to the one it interrupted
+        self.state.clear_resumed_goal();
+        if let Some(goal) = self.state.resume_suspended_goal(turn_index) {
+            tracing::debug!(goal = goal, "Resuming suspended goal");
+        }
+
+        let mut slots = intent.slots.clone();
+        self.attribute_slots(&mut slots);
 
         // Check for corrections first
         if self.config.enable_corrections {
-            self.detect_and_apply_corrections(&intent.slots, turn_index);
+            self.detect_and_apply_corrections(&slots, turn_index);
         }
 
         // Update from extracted slots
-        for (slot_name, slot) in &intent.slots {
+        for (slot_name, slot) in &slots {
             if slot.confidence >= self.config.min_slot_confidence {
                 if let Some(ref value) = slot.value {
                     self.update_slot(slot_name, value, slot.confidence, ChangeSource::UserUtterance, turn_index);
@@ -349,6 +445,10 @@ impl DialogueStateTracker {
         source: ChangeSource,
         turn_index: usize,
     ) {
+        let Some(value) = self.checked_value(slot_name, value, source) else {
+            return;
+        };
+        let value = value.as_str();
         let old_value = self.state.get_slot_value(slot_name);
 
         // Skip if value unchanged
@@ -356,6 +456,12 @@ impl DialogueStateTracker {
             return;
         }
 
+        // A confirmed value is not overwritten by a later extraction
+        if source.is_extracted() && self.state.is_slot_locked(slot_name) {
+            tracing::debug!(slot = slot_name, "Slot locked, extracted value ignored");
+            return;
+        }
+
         // Record change
         self.history.push(StateChange {
             timestamp: Utc::now(),
@@ -370,11 +476,15 @@ impl DialogueStateTracker {
         // Apply change to state
         self.state.set_slot_value(slot_name, value, confidence);
 
-        // Mark as pending confirmation if not auto-confirmed
-        if confidence < self.config.auto_confirm_confidence {
+        // Mark as pending confirmation if the slot's policy asks for it
+        if confirmation::requires_confirmation(
+            self.confirmation_policy(slot_name),
+            confidence,
+            self.config.auto_confirm_confidence,
+        ) {
             self.state.mark_pending(slot_name);
         } else {
-            self.state.mark_confirmed(slot_name);
+            self.mark_slot_confirmed(slot_name);
         }
 
         tracing::debug!(
@@ -386,9 +496,42 @@ impl DialogueStateTracker {
         );
     }
 
+    /// The value to store for `slot_name`, or `None` to hold it back
+    ///
+    /// Quantity slots store the value in their base unit ("2 lakh" becomes
+    /// "200000"). A value the customer said outside the slot's range is held
+    /// back so the next response questions it; said again, it is taken as meant.
+    fn checked_value(
+        &mut self,
+        slot_name: &str,
+        value: &str,
+        source: ChangeSource,
+    ) -> Option<String> {
+        let Some(definition) = self.slots_config.definition_for(slot_name) else {
+            return Some(value.to_string());
+        };
+        let error = match definition.check_quantity(value) {
+            Ok(Some(quantity)) => return Some(quantity.to_string()),
+            Ok(None) => return Some(value.to_string()),
+            Err(error) => error,
+        };
+
+        let questioned = (slot_name.to_string(), error);
+        let repeated = self.questioned.contains(&questioned);
+        match questioned.1 {
+            QuantityError::OutOfRange { value, .. } if repeated => Some(value.to_string()),
+            _ if !source.is_extracted() => Some(value.to_string()),
+            _ => {
+                tracing::debug!(slot = slot_name, error = %questioned.1, "Slot value held back");
+                self.held_back.push(questioned);
+                None
+            },
+        }
+    }
+
     /// Confirm a slot value
     pub fn confirm_slot(&mut self, slot_name: &str) {
-        self.state.mark_confirmed(slot_name);
+        self.mark_slot_confirmed(slot_name);
 
         self.history.push(StateChange {
             timestamp: Utc::now(),
@@ -461,13 +604,495 @@ impl DialogueStateTracker {
 
         for slot_name in pending {
             if let Some(slot_value) = self.state.get_slot_with_confidence(&slot_name) {
-                if slot_value.confidence >= self.config.auto_confirm_confidence {
-                    self.state.mark_confirmed(&slot_name);
+                if !confirmation::requires_confirmation(
+                    self.confirmation_policy(&slot_name),
+                    slot_value.confidence,
+                    self.config.auto_confirm_confidence,
+                ) {
+                    self.mark_slot_confirmed(&slot_name);
+                }
+            }
+        }
+    }
+
+    fn confirmation_policy(&self, slot_name: &str) -> Option<&ConfirmationPolicy> {
+        self.slots_config
+            .get_slot(slot_name)
+            .and_then(|def| def.confirmation.as_ref())
+    }
+
+    /// Mark confirmed, locking the slot when its policy says so
+    fn mark_slot_confirmed(&mut self, slot_name: &str) {
+        self.state.mark_confirmed(slot_name);
+        if self.confirmation_policy(slot_name).is_some_and(|p| p.lock) {
+            self.state.lock_slot(slot_name);
+        }
+    }
+
+    /// Record the question the next response asks, so the customer's
+    /// answer can be resolved against it
+    ///
+    /// Pending slots are read back first; otherwise the question follows
+    /// the next best action (a choice for enum slots, yes/no for an offer).
+    /// Asking for a spelled slot starts collecting its characters, kept
+    /// across turns until the value is complete or no longer asked for.
+    pub fn expect_answer(&mut self) {
+        let mut pending: Vec<String> = self.state.pending_slots().iter().cloned().collect();
+        pending.sort();
+        let mut asked_slot = None;
+        self.pending_question = if !pending.is_empty() {
+            Some(PendingQuestion::Confirm { slots: pending })
+        } else {
+            match self.state.next_best_action() {
+                NextBestAction::AskFor(slot) => {
+                    let question = self
+                        .slots_config
+                        .get_slot(&slot)
+                        .and_then(|def| PendingQuestion::choose_from(&slot, def));
+                    asked_slot = Some(slot);
+                    question
+                },
+                NextBestAction::OfferAppointment => Some(PendingQuestion::YesNo {
+                    topic: "appointment".to_string(),
+                }),
+                _ => None,
+            }
+        };
+
+        self.keypad
+            .ask(asked_slot.clone(), &self.slots_config.keypad);
+        let spelled = asked_slot.filter(|slot| {
+            let definition = self.slots_config.get_slot(slot);
+            definition.is_some_and(|def| def.spelled.is_some())
+        });
+        match spelled {
+            Some(slot) if self.composition.as_ref().is_some_and(|b| b.slot == slot) => {},
+            Some(slot) => self.composition = Some(CompositionBuffer::new(&slot)),
+            None => self.composition = None,
+        }
+    }
+
+    /// Question the customer is expected to answer
+    pub fn pending_question(&self) -> Option<&PendingQuestion> {
+        self.pending_question.as_ref()
+    }
+
+    /// Whether the customer may be asked to choose between `first` and
+    /// `second`
+    ///
+    /// Not when they were just answering a question, the pair was asked
+    /// before, or `max_per_session` questions have already been asked.
+    pub fn may_clarify(&self, first: &str, second: &str, max_per_session: usize) -> bool {
+        let asked = |pair: &[String; 2]| {
+            pair.iter().any(|i| i == first) && pair.iter().any(|i| i == second)
+        };
+        self.last_answer.is_none()
+            && self.clarified.len() < max_per_session
+            && !self.clarified.iter().any(asked)
+    }
+
+    /// Record a question asking which of two intents the customer meant, in
+    /// place of the question `expect_answer` chose
+    pub fn ask_clarification(&mut self, question: PendingQuestion) {
+        if let PendingQuestion::Clarify { options } = &question {
+            if let [first, second] = options.as_slice() {
+                self.clarified.push([first.id.clone(), second.id.clone()]);
+            }
+        }
+        self.pending_question = Some(question);
+    }
+
+    /// Intent the customer picked this turn in answer to a clarification
+    pub fn clarified_intent(&self) -> Option<(&str, f32)> {
+        match self.last_answer.as_ref()? {
+            (PendingQuestion::Clarify { .. }, interpretation) => match &interpretation.answer {
+                Answer::Option(intent) => Some((intent.as_str(), interpretation.confidence)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Resolve the turn only if it answers a clarification, leaving any
+    /// other pending question untouched
+    ///
+    /// A clarification the customer did not answer is dropped; their turn
+    /// is taken as it was detected.
+    pub fn resolve_clarification(&mut self, text: &str) -> Option<(&str, f32)> {
+        if !matches!(self.pending_question, Some(PendingQuestion::Clarify { .. })) {
+            self.last_answer = None;
+            return None;
+        }
+        if self.resolve_answer(text).is_none() {
+            self.pending_question = None;
+            return None;
+        }
+        self.clarified_intent()
+    }
+
+    /// Resolve the customer's turn against the pending question
+    ///
+    /// A confirmation "yes" confirms the read-back slots and "no" clears
+    /// them so they are asked again (a corrected value in the same turn is
+    /// then extracted as usual); a chosen option fills its slot with the
+    /// interpretation's confidence. Call before `update` so this turn's new
+    /// values are not swept up.
+    pub fn resolve_answer(&mut self, text: &str) -> Option<&Interpretation> {
+        self.last_answer = None;
+        let question = self.open_question()?;
+        let interpretation =
+            interpretation::interpret(text, &question, &self.slots_config.confirmation_replies)?;
+        self.apply_answer(question, interpretation);
+        self.last_answer.as_ref().map(|(_, answer)| answer)
+    }
+
+    /// The pending question, or a read-back of slots left pending without one
+    /// (e.g. by a held tool)
+    fn open_question(&self) -> Option<PendingQuestion> {
+        self.pending_question.clone().or_else(|| {
+            let mut slots: Vec<String> = self.state.pending_slots().iter().cloned().collect();
+            slots.sort();
+            (!slots.is_empty()).then_some(PendingQuestion::Confirm { slots })
+        })
+    }
+
+    fn apply_answer(&mut self, question: PendingQuestion, interpretation: Interpretation) {
+        match (&question, &interpretation.answer) {
+            (PendingQuestion::Confirm { slots }, Answer::Yes) => {
+                for slot_name in slots {
+                    self.confirm_slot(slot_name);
+                }
+            },
+            (PendingQuestion::Confirm { slots }, Answer::No) => {
+                for slot_name in slots {
+                    self.clear_slot(slot_name);
+                }
+            },
+            (PendingQuestion::Choose { slot, .. }, Answer::Option(value)) => {
+                let turn_index = self.history.len();
+                let (confidence, source) = (interpretation.confidence, ChangeSource::UserUtterance);
+                self.update_slot(slot, value, confidence, source, turn_index);
+            },
+            _ => {},
+        }
+        tracing::debug!(
+            question = ?question,
+            answer = ?interpretation.answer,
+            confidence = interpretation.confidence,
+            "Answer resolved"
+        );
+
+        self.pending_question = None;
+        self.last_answer = Some((question, interpretation));
+    }
+
+    /// Count the STT confidence of the customer's transcript
+    ///
+    /// Returns whether the line has turned poor enough to switch to the keypad.
+    pub fn hear(&mut self, confidence: f32) -> bool {
+        let switched = self.keypad.hear(confidence, &self.slots_config.keypad);
+        if switched {
+            tracing::info!(confidence, "Switching to keypad answers");
+        }
+        switched
+    }
+
+    /// Whether answers are asked for on the keypad
+    pub fn keypad_active(&self) -> bool {
+        self.keypad.is_active()
+    }
+
+    /// Take a key the customer pressed on the phone keypad
+    ///
+    /// The yes/no keys answer a read-back or offer and a position key picks
+    /// a choice; digits for the slot being asked for are collected until the
+    /// entry is complete, then fill it. Returns the answer as the text of the
+    /// customer's turn, or `None` while an entry is being typed or when the
+    /// key answers nothing being asked.
+    pub fn press_key(&mut self, key: char) -> Option<String> {
+        let config = self.slots_config.clone();
+        let keys = &config.keypad;
+        if let Some(question) = self.open_question() {
+            let answer = match &question {
+                PendingQuestion::Confirm { .. } | PendingQuestion::YesNo { .. } => {
+                    if key == keys.yes_no.yes_key {
+                        Some(Answer::Yes)
+                    } else if key == keys.yes_no.no_key {
+                        Some(Answer::No)
+                    } else {
+                        None
+                    }
+                },
+                PendingQuestion::Choose { options, .. } | PendingQuestion::Clarify { options } => {
+                    key.to_digit(10)
+                        .and_then(|position| options.get((position as usize).checked_sub(1)?))
+                        .map(|option| Answer::Option(option.id.clone()))
+                },
+            };
+            if let Some(answer) = answer {
+                let text = match answer {
+                    Answer::Yes => "yes".to_string(),
+                    Answer::No => "no".to_string(),
+                    Answer::Option(ref id) => id.replace('_', " "),
+                };
+                let confidence = keypad::KEYED_CONFIDENCE;
+                self.apply_answer(question, Interpretation { answer, confidence });
+                return Some(text);
+            }
+        }
+
+        let slot = self.keypad.asking()?.to_string();
+        match self.keypad.type_key(key, keys)? {
+            KeyEntry::Entered(value) => {
+                let turn_index = self.history.len();
+                let (confidence, source) = (keypad::KEYED_CONFIDENCE, ChangeSource::Keypad);
+                self.update_slot(&slot, &value, confidence, source, turn_index);
+                tracing::debug!(slot = %slot, "Slot typed on keypad");
+                Some(value)
+            },
+            KeyEntry::Typing | KeyEntry::Cleared => None,
+        }
+    }
+
+    /// How to ask for the next answer on the keypad, for the prompt
+    pub fn keypad_context(&self, language: &str) -> Option<String> {
+        if !self.keypad.is_active() {
+            return None;
+        }
+        let keypad = &self.slots_config.keypad;
+        let ask = match self.open_question() {
+            Some(PendingQuestion::Confirm { .. } | PendingQuestion::YesNo { .. }) => {
+                match keypad.yes_no_prompt(language) {
+                    Some(prompt) => prompt.to_string(),
+                    None => format!(
+                        "Press {} for yes or {} for no.",
+                        keypad.yes_no.yes_key, keypad.yes_no.no_key
+                    ),
                 }
+            },
+            Some(
+                PendingQuestion::Choose { options, .. } | PendingQuestion::Clarify { options },
+            ) => {
+                let keys: Vec<String> = options
+                    .iter()
+                    .take(9)
+                    .enumerate()
+                    .map(|(i, option)| format!("{} for {}", i + 1, option.id.replace('_', " ")))
+                    .collect();
+                format!("Press {}.", keys.join(", "))
+            },
+            None => keypad
+                .slot_prompt(self.keypad.asking()?, language)?
+                .to_string(),
+        };
+        Some(format!(
+            "The customer is hard to hear on this line. Ask for the answer on the \
+             phone keypad instead, for example: \"{}\"",
+            ask
+        ))
+    }
+
+    /// Add the characters the customer dictated to the spelled slot being
+    /// asked for
+    ///
+    /// A complete, valid value fills the slot (read back as its policy
+    /// says); a partial one is kept for the next turn. Call before `update`.
+    pub fn compose(&mut self, text: &str) -> Option<&Composition> {
+        self.last_composition = None;
+        let buffer = self.composition.as_mut()?;
+        let definition = self.slots_config.get_slot(&buffer.slot)?;
+        let outcome = buffer.push(text, definition)?;
+        let slot = buffer.slot.clone();
+
+        if let Composition::Complete(ref value) = outcome {
+            self.composition = None;
+            let turn_index = self.history.len();
+            let source = ChangeSource::UserUtterance;
+            let confidence = composition::COMPOSED_CONFIDENCE;
+            self.update_slot(&slot, value, confidence, source, turn_index);
+        }
+        let complete = matches!(outcome, Composition::Complete(_));
+        tracing::debug!(slot = %slot, complete, "Spelled value composed");
+
+        self.last_composition = Some((slot, outcome));
+        self.last_composition.as_ref().map(|(_, outcome)| outcome)
+    }
+
+    /// The spelled value still incomplete or invalid after this turn,
+    /// described for the prompt with the slot's re-prompt in `language`
+    pub fn composition_context(&self, language: &str) -> Option<String> {
+        let (slot, outcome) = self.last_composition.as_ref()?;
+        let name = slot.replace('_', " ");
+        match outcome {
+            Composition::Complete(_) => None,
+            Composition::Partial {
+                received,
+                remaining,
+            } => {
+                let mut context = format!(
+                    "Customer has dictated part of the {}: {} ({} more characters needed). \
+                     Ask only for the rest, not for the whole {} again.",
+                    name, received, remaining, name
+                );
+                let definition = self.slots_config.get_slot(slot);
+                let reprompt = definition
+                    .and_then(|def| def.spelled.as_ref())
+                    .and_then(|spec| spec.reprompt.get(language).or(spec.reprompt.get("en")));
+                if let Some(reprompt) = reprompt {
+                    let question = reprompt
+                        .replace("{received}", received)
+                        .replace("{remaining}", &remaining.to_string());
+                    context.push_str(&format!(" For example: \"{}\"", question));
+                }
+                Some(context)
+            },
+            Composition::Invalid { .. } => Some(format!(
+                "The {} the customer dictated is not valid. \
+                 Ask them to say it again from the start, slowly.",
+                name
+            )),
+        }
+    }
+
+    /// Set the party who spoke the utterance being processed
+    pub fn set_speaker(&mut self, speaker: Option<Speaker>) {
+        self.speaker = speaker;
+    }
+
+    /// Party speaking on a conference call
+    pub fn speaker(&self) -> Option<Speaker> {
+        self.speaker
+    }
+
+    /// Move details the co-applicant gave about themselves to their own slots
+    ///
+    /// Extraction does not know who spoke, so "mera naam Sunita hai" from the
+    /// co-applicant comes out as `customer_name`. Applying this twice is a no-op.
+    pub fn attribute_slots(&self, slots: &mut HashMap<String, Slot>) {
+        let Some(speaker) = self.speaker else {
+            return;
+        };
+        let moved: Vec<(String, String)> = slots
+            .keys()
+            .filter_map(|name| {
+                let target = self.slots_config.slot_for_speaker(name, speaker);
+                (target != name).then(|| (name.clone(), target.to_string()))
+            })
+            .collect();
+        for (name, target) in moved {
+            if let Some(mut slot) = slots.remove(&name) {
+                slot.name = target.clone();
+                slots.insert(target, slot);
             }
         }
     }
 
+    /// Who is speaking on a conference call, described for the prompt
+    pub fn speaker_context(&self) -> Option<String> {
+        let speaker = self.speaker?;
+        let name = self
+            .slots_config
+            .customer_name_slots
+            .iter()
+            .map(|slot| self.slots_config.slot_for_speaker(slot, speaker))
+            .find_map(|slot| self.state.get_slot_value(slot))
+            .map(|name| format!(" ({})", name))
+            .unwrap_or_default();
+        let (party, other) = match speaker {
+            Speaker::Customer => ("the customer", "the co-applicant"),
+            Speaker::CoApplicant => ("the co-applicant", "the customer"),
+        };
+        Some(format!(
+            "Conference call with the customer and a co-applicant. \
+             Speaking now: {}{}. Address your reply to them; details they give \
+             about themselves are theirs, not {}'s.",
+            party, name, other
+        ))
+    }
+
+    /// Values held back this turn as implausible, described for the prompt
+    pub fn held_back_context(&self) -> Option<String> {
+        if self.held_back.is_empty() {
+            return None;
+        }
+        let lines: Vec<String> = self
+            .held_back
+            .iter()
+            .map(|(slot, error)| {
+                let label = self.slots_config.get_slot_display_label(slot);
+                match error {
+                    QuantityError::OutOfRange { value, min, max } => {
+                        let kind = value.kind();
+                        let bound = |b: &Option<f64>| {
+                            b.map_or("-".to_string(), |b| SlotQuantity::new(kind, b).to_string())
+                        };
+                        format!(
+                            "- {}: heard {} {}, expected {} to {} {}",
+                            label,
+                            value,
+                            kind.unit(),
+                            bound(min),
+                            bound(max),
+                            kind.unit()
+                        )
+                    },
+                    QuantityError::Unreadable(raw) => {
+                        format!("- {}: could not read \"{}\"", label, raw)
+                    },
+                }
+            })
+            .collect();
+        Some(format!(
+            "Values not recorded because they look mis-heard:\n{}\n\
+             Read each back and ask the customer to confirm or correct it.",
+            lines.join("\n")
+        ))
+    }
+
+    /// The answer resolved this turn, described for the prompt
+    pub fn answer_context(&self) -> Option<String> {
+        let (question, interpretation) = self.last_answer.as_ref()?;
+        let answer = match interpretation.answer {
+            Answer::Yes => "yes".to_string(),
+            Answer::No => "no".to_string(),
+            Answer::Option(ref id) => id.replace('_', " "),
+        };
+        let asked = match question {
+            PendingQuestion::Confirm { slots } => format!("confirm {}", slots.join(", ")),
+            PendingQuestion::Choose { slot, .. } => format!("choose {}", slot.replace('_', " ")),
+            PendingQuestion::YesNo { topic } => format!("{}?", topic),
+            PendingQuestion::Clarify { options } => {
+                let intents: Vec<String> = options.iter().map(|o| o.id.replace('_', " ")).collect();
+                format!("did you mean {}", intents.join(" or "))
+            },
+        };
+        Some(format!(
+            "Customer answered your question ({}): {} (confidence {:.2})",
+            asked, answer, interpretation.confidence
+        ))
+    }
+
+    /// Slots that must be confirmed before `tool` runs but are not yet
+    ///
+    /// They are marked pending so the next response asks the customer.
+    pub fn slots_blocking_tool(&mut self, tool: &str) -> Vec<String> {
+        let mut blocking: Vec<String> = self
+            .slots_config
+            .slots_to_confirm_before(tool)
+            .into_iter()
+            .filter(|slot| {
+                self.state.get_slot_value(slot).is_some()
+                    && !self.state.confirmed_slots().contains(*slot)
+            })
+            .map(String::from)
+            .collect();
+        blocking.sort();
+        for slot_name in &blocking {
+            self.state.mark_pending(slot_name);
+        }
+        blocking
+    }
+
     /// Get slots that need confirmation
     pub fn slots_needing_confirmation(&self) -> Vec<&str> {
         self.state.pending_slots().iter().map(|s| s.as_str()).collect()
@@ -553,6 +1178,7 @@ impl DialogueStateTracker {
                 NextBestAction::DiscoverIntent => "discover_intent",
                 NextBestAction::OfferAppointment => "offer_appointment",
                 NextBestAction::CaptureLead => "capture_lead",
+                NextBestAction::HandleObjection(_) => "handle_objection",
                 _ => "",
             };
 
@@ -594,6 +1220,15 @@ impl DialogueStateTracker {
     pub fn reset(&mut self) {
         self.state = DynamicDialogueState::from_config(self.slots_config.clone());
         self.history.clear();
+        self.pending_question = None;
+        self.last_answer = None;
+        self.composition = None;
+        self.last_composition = None;
+        self.held_back.clear();
+        self.questioned.clear();
+        self.speaker = None;
+        self.keypad.reset();
+        self.clarified.clear();
     }
 }
 
@@ -780,6 +1415,13 @@ slots:
   phone_number:
     type: string
     description: "Phone number"
+    confirmation:
+      always: true
+      before_tools: [capture_lead]
+    spelled:
+      format: "9999999999"
+      reprompt:
+        en: "And the last {remaining} digits after {received}?"
   gold_weight:
     type: number
     description: "Asset weight in grams"
@@ -789,6 +1431,16 @@ slots:
   current_lender:
     type: string
     description: "Current lender"
+  preferred_time:
+    type: enum
+    description: "Preferred visit time"
+    values:
+      - id: morning
+        display: "Morning"
+        patterns: ["subah"]
+      - id: evening
+        display: "Evening"
+        patterns: ["shaam"]
 
 goals:
   exploration:
@@ -805,6 +1457,14 @@ goals:
     required_slots:
       - gold_weight
     completion_action: check_eligibility
+  branch_visit:
+    description: "Visit a branch"
+    required_slots:
+      - preferred_time
+  callback:
+    description: "Call the customer back"
+    required_slots:
+      - phone_number
 
 intent_mapping:
   balance_transfer:
@@ -894,6 +1554,234 @@ intent_mapping:
         assert!(tracker.state().confirmed_slots().contains(&"loan_amount".to_string()));
     }
 
+    #[test]
+    fn test_confirmation_policy_and_lock() {
+        let config = create_test_config();
+        let mut tracker = DialogueStateTracker::from_config(config);
+
+        // Always confirmed, however confident the extraction
+        let source = ChangeSource::UserUtterance;
+        tracker.update_slot("phone_number", "9876543210", 0.99, source, 0);
+        assert_eq!(tracker.slots_needing_confirmation(), vec!["phone_number"]);
+        let blocking = tracker.slots_blocking_tool("capture_lead");
+        assert_eq!(blocking, vec!["phone_number"]);
+
+        let answer = tracker.resolve_answer("haan ji").unwrap();
+        assert_eq!(answer.answer, Answer::Yes);
+        assert!(tracker.slots_blocking_tool("capture_lead").is_empty());
+
+        // Locked: a stray number later in the call does not replace it
+        tracker.update_slot("phone_number", "1234567890", 0.99, source, 1);
+        assert_eq!(
+            tracker.state().get_slot_value("phone_number"),
+            Some("9876543210".to_string())
+        );
+    }
+
+    #[test]
+    fn test_negative_reply_clears_pending() {
+        let config = create_test_config();
+        let mut tracker = DialogueStateTracker::from_config(config);
+
+        tracker.update_slot("loan_amount", "500000", 0.6, ChangeSource::UserUtterance, 0);
+        let answer = tracker.resolve_answer("nahi, galat hai").unwrap();
+        assert_eq!(answer.answer, Answer::No);
+        assert!(tracker.state().get_slot_value("loan_amount").is_none());
+        assert!(tracker.slots_needing_confirmation().is_empty());
+    }
+
+    #[test]
+    fn test_choice_answer_fills_slot() {
+        let config = create_test_config();
+        let mut tracker = DialogueStateTracker::from_config(config);
+        tracker.set_goal("branch_visit", 0);
+
+        tracker.expect_answer();
+        let question = tracker.pending_question();
+        assert!(matches!(question, Some(PendingQuestion::Choose { .. })));
+
+        let answer = tracker.resolve_answer("shaam wala").unwrap();
+        assert_eq!(answer.answer, Answer::Option("evening".to_string()));
+        assert_eq!(
+            tracker.state().get_slot_value("preferred_time"),
+            Some("evening".to_string())
+        );
+        assert!(tracker.pending_question().is_none());
+        assert!(tracker.answer_context().unwrap().contains("evening"));
+    }
+
+    #[test]
+    fn test_clarification_asked_once_per_pair() {
+        use voice_agent_config::domain::IntentDefinition;
+
+        let config = create_test_config();
+        let mut tracker = DialogueStateTracker::from_config(config);
+        let intent = |name: &str| IntentDefinition {
+            name: name.to_string(),
+            description: String::new(),
+            required_slots: vec![],
+            optional_slots: vec![],
+            examples: vec![],
+            clarify_as: HashMap::new(),
+        };
+        let (eligibility, transfer) = (intent("eligibility_check"), intent("balance_transfer"));
+
+        assert!(tracker.may_clarify("eligibility_check", "balance_transfer", 2));
+        tracker.ask_clarification(PendingQuestion::clarify_between([&eligibility, &transfer]));
+        let (picked, _) = tracker.resolve_clarification("balance transfer").unwrap();
+        assert_eq!(picked, "balance_transfer");
+        assert!(tracker.pending_question().is_none());
+
+        // The same pair, in either order, is not asked again
+        tracker.resolve_clarification("what is the rate");
+        assert!(!tracker.may_clarify("balance_transfer", "eligibility_check", 2));
+        assert!(tracker.may_clarify("eligibility_check", "interest_rate", 2));
+        assert!(!tracker.may_clarify("eligibility_check", "interest_rate", 1));
+    }
+
+    #[test]
+    fn test_phone_dictated_across_turns() {
+        let config = create_test_config();
+        let mut tracker = DialogueStateTracker::from_config(config);
+        tracker.set_goal("callback", 0);
+        tracker.expect_answer();
+
+        let outcome = tracker.compose("98763...");
+        let partial = matches!(outcome, Some(Composition::Partial { remaining: 5, .. }));
+        assert!(partial);
+        let context = tracker.composition_context("en").unwrap();
+        assert!(context.contains("And the last 5 digits after 98763?"));
+
+        // A turn without digits keeps what was dictated
+        tracker.expect_answer();
+        assert!(tracker.compose("ek minute").is_none());
+        tracker.expect_answer();
+        tracker.compose("haan, 43210");
+        let phone = tracker.state().get_slot_value("phone_number");
+        assert_eq!(phone.as_deref(), Some("9876343210"));
+        let pending = tracker.slots_needing_confirmation();
+        assert!(pending.contains(&"phone_number"));
+        assert!(tracker.composition_context("en").is_none());
+    }
+
+    #[test]
+    fn test_quantity_normalized_and_range_checked() {
+        let yaml = r#"
+slots:
+  asset_quantity:
+    type: number
+    quantity: weight_grams
+    min: 1
+    max: 2000
+  offer_amount:
+    type: number
+    quantity: money
+    aliases: [loan_amount]
+    min: 10000
+"#;
+        let config = Arc::new(serde_yaml::from_str(yaml).unwrap());
+        let mut tracker = DialogueStateTracker::from_config(config);
+        let source = ChangeSource::UserUtterance;
+
+        tracker.update_slot("loan_amount", "2.5 lakh", 0.9, source, 0);
+        let amount = tracker.state().get_slot_value("loan_amount");
+        assert_eq!(amount.as_deref(), Some("250000"));
+
+        let turn = |value: &str| DetectedIntent {
+            intent: "eligibility_check".to_string(),
+            confidence: 0.9,
+            slots: HashMap::from([(
+                "asset_quantity".to_string(),
+                Slot {
+                    name: "asset_quantity".to_string(),
+                    slot_type: voice_agent_text_processing::intent::SlotType::Number,
+                    value: Some(value.to_string()),
+                    confidence: 0.9,
+                },
+            )]),
+            alternatives: Vec::new(),
+            slot_spans: Vec::new(),
+        };
+
+        // Held back and questioned
+        tracker.update(&turn("5000"));
+        assert!(tracker.state().get_slot_value("asset_quantity").is_none());
+        let context = tracker.held_back_context().unwrap();
+        assert!(context.contains("heard 5000 grams, expected 1 to 2000 grams"));
+
+        // Said again, it is taken as meant
+        tracker.update(&turn("5000"));
+        let weight = tracker.state().get_slot_value("asset_quantity");
+        assert_eq!(weight.as_deref(), Some("5000"));
+        assert!(tracker.held_back_context().is_none());
+    }
+
+    #[test]
+    fn test_co_applicant_slots_attributed() {
+        let yaml = r#"
+customer_name_slots: [customer_name]
+co_applicant_slots:
+  customer_name: co_applicant_name
+"#;
+        let config = Arc::new(serde_yaml::from_str(yaml).unwrap());
+        let mut tracker = DialogueStateTracker::from_config(config);
+        let turn = |name: &str| DetectedIntent {
+            intent: "inquiry".to_string(),
+            confidence: 0.9,
+            slots: HashMap::from([(
+                "customer_name".to_string(),
+                Slot {
+                    name: "customer_name".to_string(),
+                    slot_type: voice_agent_text_processing::intent::SlotType::Text,
+                    value: Some(name.to_string()),
+                    confidence: 0.9,
+                },
+            )]),
+            alternatives: Vec::new(),
+            slot_spans: Vec::new(),
+        };
+
+        tracker.update(&turn("Ramesh"));
+        assert!(tracker.speaker_context().is_none());
+
+        tracker.set_speaker(Some(Speaker::CoApplicant));
+        tracker.update(&turn("Sunita"));
+        let name = |slot: &str| tracker.state().get_slot_value(slot);
+        assert_eq!(name("customer_name").as_deref(), Some("Ramesh"));
+        assert_eq!(name("co_applicant_name").as_deref(), Some("Sunita"));
+        let context = tracker.speaker_context().unwrap();
+        assert!(context.contains("Speaking now: the co-applicant (Sunita)"));
+    }
+
+    #[test]
+    fn test_keypad_answers_read_back() {
+        let yaml = r#"
+slots:
+  phone_number:
+    type: string
+    confirmation:
+      always: true
+keypad:
+  enabled: true
+  switch_after: 2
+"#;
+        let config = Arc::new(serde_yaml::from_str(yaml).unwrap());
+        let mut tracker = DialogueStateTracker::from_config(config);
+        let source = ChangeSource::UserUtterance;
+        tracker.update_slot("phone_number", "9876543210", 0.9, source, 0);
+        assert!(tracker.keypad_context("en").is_none());
+
+        assert!(!tracker.hear(0.3));
+        assert!(tracker.hear(0.4));
+        let context = tracker.keypad_context("en").unwrap();
+        assert!(context.contains("Press 1 for yes or 2 for no."));
+
+        // Not a yes/no key
+        assert_eq!(tracker.press_key('5'), None);
+        assert_eq!(tracker.press_key('1').as_deref(), Some("yes"));
+        assert!(tracker.confirmed_slots().contains(&"phone_number"));
+    }
+
     #[test]
     fn test_missing_slots_detection() {
         let config = create_test_config();
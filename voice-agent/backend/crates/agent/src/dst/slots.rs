@@ -54,6 +54,8 @@ pub enum NextBestAction {
     DiscoverIntent,
     /// Capture lead now
     CaptureLead,
+    /// Address the customer's objection (by objection ID) before moving on
+    HandleObjection(String),
 }
 
 impl NextBestAction {
@@ -66,6 +68,7 @@ impl NextBestAction {
             NextBestAction::ExplainProcess => "explain_process",
             NextBestAction::DiscoverIntent => "discover_intent",
             NextBestAction::CaptureLead => "capture_lead",
+            NextBestAction::HandleObjection(_) => "handle_objection",
         }
     }
 
@@ -74,6 +77,7 @@ impl NextBestAction {
         match self {
             NextBestAction::CallTool(tool) => Some(tool),
             NextBestAction::AskFor(slot) => Some(slot),
+            NextBestAction::HandleObjection(objection) => Some(objection),
             _ => None,
         }
     }
@@ -130,6 +134,12 @@ impl NextBestAction {
             NextBestAction::CaptureLead => {
                 "CAPTURE customer details for follow-up (name and phone)".to_string()
             }
+            NextBestAction::HandleObjection(objection) => {
+                format!(
+                    "ADDRESS the customer's {} concern first: acknowledge it and answer it before continuing",
+                    objection.replace('_', " ")
+                )
+            }
         }
     }
 }
@@ -269,6 +279,10 @@ mod tests {
         assert_eq!(NextBestAction::ExplainProcess.action_type(), "explain_process");
         assert_eq!(NextBestAction::DiscoverIntent.action_type(), "discover_intent");
         assert_eq!(NextBestAction::CaptureLead.action_type(), "capture_lead");
+        assert_eq!(
+            NextBestAction::HandleObjection("need_time".to_string()).action_type(),
+            "handle_objection"
+        );
     }
 
     #[test]
@@ -276,6 +290,10 @@ mod tests {
         assert_eq!(NextBestAction::CallTool("my_tool".to_string()).target(), Some("my_tool"));
         assert_eq!(NextBestAction::AskFor("my_slot".to_string()).target(), Some("my_slot"));
         assert_eq!(NextBestAction::DiscoverIntent.target(), None);
+        assert_eq!(
+            NextBestAction::HandleObjection("trust_issues".to_string()).target(),
+            Some("trust_issues")
+        );
     }
 
     #[test]
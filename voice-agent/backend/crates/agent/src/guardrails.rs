@@ -0,0 +1,439 @@
+//! Output Guardrails
+//!
+//! Post-generation compliance check on LLM output. The system prompt asks the
+//! model to avoid banned claims, but a prompt is not a control: this module
+//! scans every answer before it is spoken and enforces the domain's
+//! compliance.yaml rules.
+//!
+//! Detected:
+//! - **Forbidden phrases** ("guaranteed approval", "zero interest", ...)
+//! - **Rate promises** matching `rate_promise_patterns` ("you will get 9%")
+//! - **Competitor disparagement**: a competitor and a `disparaging_terms`
+//!   word in the same sentence
+//!
+//! With [`GuardrailAction::Rewrite`], forbidden phrases that have an
+//! auto-correction are replaced and any sentence still in violation is
+//! dropped. If nothing is left, or with [`GuardrailAction::Block`], the whole
+//! answer is replaced by the templated safe response for the caller's
+//! language. Every intervention is recorded in the audit log.
+
+use regex::{NoExpand, Regex};
+use std::collections::HashMap;
+use std::sync::Arc;
+use voice_agent_config::domain::ComplianceConfig;
+use voice_agent_config::GuardrailAction;
+use voice_agent_core::{ComplianceViolation, Severity, ViolationCategory};
+use voice_agent_persistence::AuditLogger;
+
+/// Sentence terminators (English + Devanagari danda)
+const SENTENCE_TERMINATORS: &[char] = &['.', '!', '?', '।'];
+
+struct ForbiddenRule {
+    pattern: Regex,
+    phrase: String,
+    replacement: Option<String>,
+}
+
+/// Result of running the guardrails over a response
+#[derive(Debug, Clone)]
+pub struct GuardrailVerdict {
+    /// Text to speak (original, rewritten, or the safe response)
+    pub text: String,
+    /// What was done; `None` when the response was clean
+    pub action: Option<GuardrailAction>,
+    /// Violations found in the original response
+    pub violations: Vec<ComplianceViolation>,
+}
+
+impl GuardrailVerdict {
+    fn pass(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            action: None,
+            violations: Vec::new(),
+        }
+    }
+
+    /// Whether the response went through unchanged
+    pub fn is_clean(&self) -> bool {
+        self.action.is_none()
+    }
+
+    /// Whether the response was replaced by the safe response
+    pub fn is_blocked(&self) -> bool {
+        self.action == Some(GuardrailAction::Block)
+    }
+}
+
+/// Compliance guardrails shared by all sessions
+pub struct Guardrails {
+    action: GuardrailAction,
+    forbidden: Vec<ForbiddenRule>,
+    rate_promises: Vec<Regex>,
+    competitors: Option<Regex>,
+    disparaging: Option<Regex>,
+    compliance: ComplianceConfig,
+    audit_logger: Option<Arc<AuditLogger>>,
+}
+
+impl Guardrails {
+    /// Build guardrails from the domain's compliance rules
+    pub fn new(compliance: &ComplianceConfig, action: GuardrailAction) -> Self {
+        let corrections: HashMap<String, &String> = compliance
+            .auto_corrections
+            .replacements
+            .iter()
+            .map(|(phrase, replacement)| (phrase.to_lowercase(), replacement))
+            .collect();
+
+        let forbidden = compliance
+            .forbidden_phrases
+            .iter()
+            .filter_map(|phrase| {
+                Regex::new(&format!(r"(?i)\b{}\b", regex::escape(phrase)))
+                    .ok()
+                    .map(|pattern| ForbiddenRule {
+                        pattern,
+                        phrase: phrase.clone(),
+                        replacement: corrections
+                            .get(&phrase.to_lowercase())
+                            .map(|r| r.to_string()),
+                    })
+            })
+            .collect();
+
+        let rate_promises = compliance
+            .rate_promise_patterns
+            .iter()
+            .filter_map(|pattern| match Regex::new(pattern) {
+                Ok(re) => Some(re),
+                Err(e) => {
+                    tracing::warn!(pattern = %pattern, error = %e, "Invalid rate promise pattern");
+                    None
+                },
+            })
+            .collect();
+
+        let rules = &compliance.competitor_rules;
+        let (competitors, disparaging) = if rules.allow_disparagement {
+            (None, None)
+        } else {
+            (
+                word_alternation(&rules.competitors),
+                word_alternation(&compliance.disparaging_terms),
+            )
+        };
+
+        Self {
+            action,
+            forbidden,
+            rate_promises,
+            competitors,
+            disparaging,
+            compliance: compliance.clone(),
+            audit_logger: None,
+        }
+    }
+
+    /// Record interventions in the audit log
+    pub fn with_audit_logger(mut self, logger: Arc<AuditLogger>) -> Self {
+        self.audit_logger = Some(logger);
+        self
+    }
+
+    /// Configured action for non-compliant responses
+    pub fn action(&self) -> GuardrailAction {
+        self.action
+    }
+
+    /// Safe response for a language
+    pub fn safe_response(&self, language: &str) -> &str {
+        self.compliance.get_safe_response(language)
+    }
+
+    /// Find all violations in `text`
+    pub fn scan(&self, text: &str) -> Vec<ComplianceViolation> {
+        let mut violations = Vec::new();
+
+        for rule in &self.forbidden {
+            if let Some(m) = rule.pattern.find(text) {
+                violations.push(
+                    ComplianceViolation::new(
+                        format!("FORBIDDEN_{}", rule.phrase.to_uppercase().replace(' ', "_")),
+                        format!("Forbidden phrase: '{}'", rule.phrase),
+                        ViolationCategory::MisleadingClaim,
+                        Severity::Critical,
+                    )
+                    .with_span(m.start(), m.end(), m.as_str()),
+                );
+            }
+        }
+
+        for pattern in &self.rate_promises {
+            if let Some(m) = pattern.find(text) {
+                violations.push(
+                    ComplianceViolation::new(
+                        "RATE_PROMISE",
+                        "Specific interest rate promised to the customer",
+                        ViolationCategory::UnauthorizedPromise,
+                        Severity::Critical,
+                    )
+                    .with_span(m.start(), m.end(), m.as_str()),
+                );
+            }
+        }
+
+        if let (Some(competitors), Some(disparaging)) = (&self.competitors, &self.disparaging) {
+            let mut offset = 0;
+            for sentence in split_sentences(text) {
+                let start = offset + text[offset..].find(sentence).unwrap_or(0);
+                offset = start + sentence.len();
+
+                if let Some(competitor) = competitors.find(sentence) {
+                    if disparaging.is_match(sentence) {
+                        violations.push(
+                            ComplianceViolation::new(
+                                "COMPETITOR_DISPARAGEMENT",
+                                format!("Disparaging mention of '{}'", competitor.as_str()),
+                                ViolationCategory::CompetitorDisparagement,
+                                Severity::Critical,
+                            )
+                            .with_span(start, offset, sentence),
+                        );
+                    }
+                }
+            }
+        }
+
+        violations
+    }
+
+    /// Check a response and rewrite or block it as configured
+    ///
+    /// `language` selects the safe response; rules are matched against
+    /// `text` as given (the agent passes its English output).
+    pub fn enforce(&self, text: &str, language: &str) -> GuardrailVerdict {
+        let violations = self.scan(text);
+        if violations.is_empty() {
+            return GuardrailVerdict::pass(text);
+        }
+
+        if self.action == GuardrailAction::Rewrite {
+            let kept: Vec<String> = split_sentences(text)
+                .into_iter()
+                .filter_map(|sentence| self.rewrite_sentence(sentence))
+                .collect();
+
+            if !kept.is_empty() {
+                return GuardrailVerdict {
+                    text: kept.join(" "),
+                    action: Some(GuardrailAction::Rewrite),
+                    violations,
+                };
+            }
+        }
+
+        GuardrailVerdict {
+            text: self.safe_response(language).to_string(),
+            action: Some(GuardrailAction::Block),
+            violations,
+        }
+    }
+
+    /// Log an intervention and write it to the audit log
+    pub fn record(&self, session_id: &str, verdict: &GuardrailVerdict) {
+        let Some(action) = verdict.action else {
+            return;
+        };
+
+        let rules: Vec<&str> = verdict
+            .violations
+            .iter()
+            .map(|v| v.rule_id.as_str())
+            .collect();
+        tracing::warn!(
+            session_id = %session_id,
+            action = action.as_str(),
+            rules = ?rules,
+            "Guardrails intercepted non-compliant response"
+        );
+
+        let Some(logger) = self.audit_logger.clone() else {
+            return;
+        };
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+
+        let session_id = session_id.to_string();
+        let violations = serde_json::to_value(&verdict.violations).unwrap_or_default();
+        handle.spawn(async move {
+            if let Err(e) = logger
+                .log_compliance_violation(&session_id, action.as_str(), violations)
+                .await
+            {
+                tracing::warn!(session_id = %session_id, error = %e, "Failed to audit guardrail violation");
+            }
+        });
+    }
+
+    /// Correct a sentence, or drop it if it can't be made compliant
+    fn rewrite_sentence(&self, sentence: &str) -> Option<String> {
+        let mut rewritten = sentence.to_string();
+        for rule in &self.forbidden {
+            if let Some(ref replacement) = rule.replacement {
+                rewritten = rule
+                    .pattern
+                    .replace_all(&rewritten, NoExpand(replacement))
+                    .into_owned();
+            }
+        }
+
+        self.scan(&rewritten).is_empty().then_some(rewritten)
+    }
+}
+
+/// Case-insensitive whole-word match for any of `words`
+fn word_alternation(words: &[String]) -> Option<Regex> {
+    let alternatives: Vec<String> = words
+        .iter()
+        .filter(|w| !w.trim().is_empty())
+        .map(|w| regex::escape(w.trim()))
+        .collect();
+    if alternatives.is_empty() {
+        return None;
+    }
+    Regex::new(&format!(r"(?i)\b(?:{})\b", alternatives.join("|"))).ok()
+}
+
+/// Split into trimmed sentences
+///
+/// A terminator only ends a sentence when followed by whitespace or the end
+/// of the text, so rates like "9.5%" stay intact.
+pub(crate) fn split_sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if !SENTENCE_TERMINATORS.contains(&c) {
+            continue;
+        }
+        let at_boundary = match chars.peek() {
+            Some((_, next)) => next.is_whitespace(),
+            None => true,
+        };
+        if at_boundary {
+            let end = i + c.len_utf8();
+            let sentence = text[start..end].trim();
+            if !sentence.is_empty() {
+                sentences.push(sentence);
+            }
+            start = end;
+        }
+    }
+
+    let rest = text[start..].trim();
+    if !rest.is_empty() {
+        sentences.push(rest);
+    }
+    sentences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compliance() -> ComplianceConfig {
+        let mut config = ComplianceConfig::default();
+        config.forbidden_phrases = vec!["guaranteed approval".to_string(), "fraud".to_string()];
+        config.auto_corrections.replacements.insert(
+            "guaranteed approval".to_string(),
+            "high approval rate".to_string(),
+        );
+        config.rate_promise_patterns =
+            vec![r"(?i)\byou\s*(will|'ll)\s+(get|pay)\b[^.!?]{0,30}\d+(\.\d+)?\s*%".to_string()];
+        config.competitor_rules.competitors = vec!["Muthoot".to_string()];
+        config.disparaging_terms = vec!["worst".to_string(), "avoid".to_string()];
+        config.safe_responses.insert(
+            "en".to_string(),
+            "Let me connect you with our team.".to_string(),
+        );
+        config.safe_responses.insert(
+            "hi".to_string(),
+            "Main aapko team se jodti hoon.".to_string(),
+        );
+        config
+    }
+
+    #[test]
+    fn test_clean_response_passes() {
+        let guardrails = Guardrails::new(&compliance(), GuardrailAction::Rewrite);
+        let text = "Our gold loan rate starts at 9.5% per annum. Muthoot is another lender.";
+
+        let verdict = guardrails.enforce(text, "en");
+        assert!(verdict.is_clean());
+        assert_eq!(verdict.text, text);
+    }
+
+    #[test]
+    fn test_rewrite_corrects_and_drops() {
+        let guardrails = Guardrails::new(&compliance(), GuardrailAction::Rewrite);
+        let text = "We offer guaranteed approval. You will get 8.5% for sure. \
+                    Visit any branch with your KYC.";
+
+        let verdict = guardrails.enforce(text, "en");
+        assert_eq!(verdict.action, Some(GuardrailAction::Rewrite));
+        assert_eq!(
+            verdict.text,
+            "We offer high approval rate. Visit any branch with your KYC."
+        );
+        assert!(verdict
+            .violations
+            .iter()
+            .any(|v| v.rule_id == "RATE_PROMISE"));
+        assert!(verdict
+            .violations
+            .iter()
+            .any(|v| v.rule_id == "FORBIDDEN_GUARANTEED_APPROVAL"));
+    }
+
+    #[test]
+    fn test_competitor_disparagement() {
+        let guardrails = Guardrails::new(&compliance(), GuardrailAction::Rewrite);
+        let text = "Avoid Muthoot, they are the worst. Our rates are transparent.";
+
+        let verdict = guardrails.enforce(text, "en");
+        assert!(verdict
+            .violations
+            .iter()
+            .any(|v| v.category == ViolationCategory::CompetitorDisparagement));
+        assert_eq!(verdict.text, "Our rates are transparent.");
+    }
+
+    #[test]
+    fn test_block_uses_safe_response() {
+        let guardrails = Guardrails::new(&compliance(), GuardrailAction::Block);
+        let verdict = guardrails.enforce("We offer guaranteed approval.", "hi");
+
+        assert!(verdict.is_blocked());
+        assert_eq!(verdict.text, "Main aapko team se jodti hoon.");
+    }
+
+    #[test]
+    fn test_rewrite_falls_back_to_block() {
+        let guardrails = Guardrails::new(&compliance(), GuardrailAction::Rewrite);
+        let verdict = guardrails.enforce("You'll pay 7% only.", "ta");
+
+        assert!(verdict.is_blocked());
+        assert_eq!(verdict.text, "Let me connect you with our team.");
+    }
+
+    #[test]
+    fn test_split_sentences_keeps_decimals() {
+        assert_eq!(
+            split_sentences("Rate is 9.5% today. Visit us! कल आइए।"),
+            vec!["Rate is 9.5% today.", "Visit us!", "कल आइए।"]
+        );
+    }
+}
@@ -5,6 +5,8 @@
 //! - MQL (Marketing Qualified Lead) vs SQL (Sales Qualified Lead) classification
 //! - Conversion probability estimation
 //! - Auto-escalation triggers
+//! - Dialogue-state signals (urgency slot, amount, goal completion,
+//!   sentiment, rate advantage) scored each turn via `update_from_dialogue()`
 //!
 //! # P20 FIX: Config-Driven Classification
 //!
@@ -19,6 +21,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use voice_agent_text_processing::SentimentAnalyzer;
 
 /// Lead qualification level
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -271,6 +274,15 @@ pub struct LeadSignals {
     pub expressed_disinterest: bool,
     pub mentioned_competitor_preference: bool,
     pub conversation_stalled_turns: u32,
+
+    // Dialogue-state signals (refreshed from DST each turn)
+    pub urgency_level: Option<String>,
+    pub loan_amount: Option<f64>,
+    pub goal_completion: f32,
+    /// Smoothed sentiment polarity (-1.0 to 1.0)
+    pub sentiment_polarity: f32,
+    /// Percentage points saved versus the customer's current rate
+    pub rate_advantage: Option<f64>,
 }
 
 /// Trust level indicator
@@ -361,6 +373,9 @@ pub struct ScoreBreakdown {
     pub information: u32,
     /// Intent strength score (0-25)
     pub intent: u32,
+    /// Dialogue-state score (0-25)
+    #[serde(default)]
+    pub dialogue: u32,
     /// Penalty from negative signals
     pub penalty: i32,
 }
@@ -398,6 +413,25 @@ pub struct LeadScoringEngine {
     /// P20 FIX: Optional scoring config for config-driven scoring values
     /// When set, uses urgency keywords, signal weights, etc. from config
     scoring_config: Option<std::sync::Arc<voice_agent_config::ScoringConfig>>,
+    /// Sentiment analyzer for per-turn polarity
+    sentiment: SentimentAnalyzer,
+    /// Most recent score (from the last `calculate_score()` call)
+    last_score: Option<LeadScore>,
+}
+
+/// Dialogue-state inputs for lead scoring, gathered by the agent each turn
+#[derive(Debug, Clone, Default)]
+pub struct DialogueSignals {
+    /// Urgency level id from the urgency slot (e.g., "immediate")
+    pub urgency_level: Option<String>,
+    /// Requested amount
+    pub loan_amount: Option<f64>,
+    /// Completion of the current goal (0.0-1.0)
+    pub goal_completion: f32,
+    /// Customer's current rate (percent), from slot or provider's typical rate
+    pub current_rate: Option<f64>,
+    /// Our rate for the requested amount (percent)
+    pub offered_rate: Option<f64>,
 }
 
 /// Configuration for lead scoring
@@ -420,6 +454,12 @@ pub struct ScoreWeights {
     pub engagement: f32,
     pub information: f32,
     pub intent: f32,
+    #[serde(default = "default_dialogue_weight")]
+    pub dialogue: f32,
+}
+
+fn default_dialogue_weight() -> f32 {
+    1.0
 }
 
 impl Default for LeadScoringConfig {
@@ -433,6 +473,7 @@ impl Default for LeadScoringConfig {
                 engagement: 1.0,
                 information: 1.0,
                 intent: 1.0,
+                dialogue: 1.0,
             },
         }
     }
@@ -448,6 +489,8 @@ impl LeadScoringEngine {
             classifier: None,
             classification_config: None,
             scoring_config: None,
+            sentiment: SentimentAnalyzer::new(),
+            last_score: None,
         }
     }
 
@@ -460,6 +503,8 @@ impl LeadScoringEngine {
             classifier: None,
             classification_config: None,
             scoring_config: None,
+            sentiment: SentimentAnalyzer::new(),
+            last_score: None,
         }
     }
 
@@ -482,6 +527,8 @@ impl LeadScoringEngine {
             classifier: Some(classifier),
             classification_config: None,
             scoring_config: None,
+            sentiment: SentimentAnalyzer::new(),
+            last_score: None,
         }
     }
 
@@ -496,6 +543,8 @@ impl LeadScoringEngine {
             classifier: None,
             classification_config: Some(classification_config),
             scoring_config: None,
+            sentiment: SentimentAnalyzer::new(),
+            last_score: None,
         }
     }
 
@@ -512,6 +561,8 @@ impl LeadScoringEngine {
             classifier: None,
             classification_config: None,
             scoring_config: Some(scoring_config),
+            sentiment: SentimentAnalyzer::new(),
+            last_score: None,
         }
     }
 
@@ -751,11 +802,42 @@ impl LeadScoringEngine {
         self.signals.conversation_stalled_turns = 0;
     }
 
+    /// Update sentiment polarity from a customer utterance
+    ///
+    /// Polarity is smoothed across turns so a single remark doesn't swing the score.
+    pub fn update_sentiment(&mut self, text: &str) {
+        let polarity = self.sentiment.analyze(text).polarity.clamp(-1.0, 1.0);
+        self.signals.sentiment_polarity = self.signals.sentiment_polarity * 0.5 + polarity * 0.5;
+    }
+
+    /// Update dialogue-state signals (called once per turn after DST update)
+    pub fn update_from_dialogue(&mut self, dialogue: &DialogueSignals) {
+        let signals = &mut self.signals;
+
+        if let Some(level) = &dialogue.urgency_level {
+            signals.urgency_level = Some(level.to_lowercase());
+        }
+        if let Some(amount) = dialogue.loan_amount {
+            signals.loan_amount = Some(amount);
+            signals.provided_loan_amount = true;
+        }
+        signals.goal_completion = dialogue.goal_completion.clamp(0.0, 1.0);
+        if let (Some(current), Some(offered)) = (dialogue.current_rate, dialogue.offered_rate) {
+            signals.rate_advantage = Some(current - offered);
+        }
+    }
+
+    /// Most recent score, if one has been calculated
+    pub fn last_score(&self) -> Option<&LeadScore> {
+        self.last_score.as_ref()
+    }
+
     /// Calculate current lead score
     ///
     /// P21 FIX: Uses config-driven scoring values when `scoring_config` is set.
     pub fn calculate_score(&mut self) -> LeadScore {
-        let breakdown = self.calculate_breakdown();
+        let mut breakdown = self.calculate_breakdown();
+        self.apply_dialogue_score(&mut breakdown);
         let total = self.calculate_total(&breakdown);
 
         // Track score history
@@ -775,7 +857,7 @@ impl LeadScoringEngine {
         let escalation_triggers = self.check_escalation_triggers();
         let recommendation = self.generate_recommendation(&qualification, &escalation_triggers);
 
-        LeadScore {
+        let score = LeadScore {
             total,
             qualification,
             classification,
@@ -783,7 +865,9 @@ impl LeadScoringEngine {
             breakdown,
             escalation_triggers,
             recommendation,
-        }
+        };
+        self.last_score = Some(score.clone());
+        score
     }
 
     /// P20 FIX: Classify lead using config-driven rules when available
@@ -912,6 +996,7 @@ impl LeadScoringEngine {
             engagement,
             information,
             intent,
+            dialogue: 0,
             penalty,
         }
     }
@@ -1012,18 +1097,67 @@ impl LeadScoringEngine {
             engagement,
             information,
             intent,
+            dialogue: 0,
             penalty,
         }
     }
 
+    /// Add the dialogue-state category and sentiment penalty to a breakdown
+    fn apply_dialogue_score(&self, breakdown: &mut ScoreBreakdown) {
+        let default_config;
+        let cfg = match &self.scoring_config {
+            Some(scoring_config) => &scoring_config.dialogue,
+            None => {
+                default_config = voice_agent_config::domain::DialogueScoringConfig::default();
+                &default_config
+            }
+        };
+        let signals = &self.signals;
+
+        let mut score = 0u32;
+        if let Some(level) = &signals.urgency_level {
+            score += cfg.urgency_score(level);
+        }
+        if let Some(amount) = signals.loan_amount {
+            score += cfg.amount_score(amount);
+        }
+        score += (signals.goal_completion * cfg.goal_completion_score as f32).round() as u32;
+        if signals.sentiment_polarity > 0.0 {
+            score += (signals.sentiment_polarity * cfg.positive_sentiment_score as f32).round() as u32;
+        }
+        if let Some(advantage) = signals.rate_advantage.filter(|a| *a > 0.0) {
+            let points = (advantage * cfg.rate_advantage_per_point as f64).round() as u32;
+            score += points.min(cfg.max_rate_advantage_score);
+        }
+        breakdown.dialogue = score.min(cfg.max_score);
+
+        if signals.sentiment_polarity < 0.0 {
+            // negative_sentiment_penalty is already negative in config
+            breakdown.penalty +=
+                (-signals.sentiment_polarity * cfg.negative_sentiment_penalty as f32).round() as i32;
+        }
+    }
+
     /// Calculate total score from breakdown
+    ///
+    /// Uses per-domain category weights from `scoring_config` when set.
     fn calculate_total(&self, breakdown: &ScoreBreakdown) -> u32 {
-        let weights = &self.config.weights;
+        let (urgency, engagement, information, intent, dialogue) = match &self.scoring_config {
+            Some(config) => {
+                let w = &config.weights;
+                (w.urgency, w.engagement, w.information, w.intent, w.dialogue)
+            }
+            None => {
+                let w = &self.config.weights;
+                (w.urgency, w.engagement, w.information, w.intent, w.dialogue)
+            }
+        };
 
-        let weighted_sum = (breakdown.urgency as f32 * weights.urgency)
-            + (breakdown.engagement as f32 * weights.engagement)
-            + (breakdown.information as f32 * weights.information)
-            + (breakdown.intent as f32 * weights.intent);
+        let weighted_sum = (breakdown.urgency as f32 * urgency)
+            + (breakdown.engagement as f32 * engagement)
+            + (breakdown.information as f32 * information)
+            + (breakdown.intent as f32 * intent)
+            + (breakdown.dialogue as f32 * dialogue);
 
         let total_with_penalty = weighted_sum as i32 + breakdown.penalty;
         total_with_penalty.max(0).min(100) as u32
@@ -1222,6 +1356,7 @@ impl LeadScoringEngine {
     pub fn reset(&mut self) {
         self.signals = LeadSignals::default();
         self.score_history.clear();
+        self.last_score = None;
     }
 }
 
@@ -1470,4 +1605,57 @@ mod tests {
         let trend = engine.score_trend();
         assert!(trend > 0, "Score trend should be positive");
     }
+
+    #[test]
+    fn test_dialogue_signals_score() {
+        let mut engine = LeadScoringEngine::new();
+        let baseline = engine.calculate_score().total;
+
+        engine.update_from_dialogue(&DialogueSignals {
+            urgency_level: Some("Immediate".to_string()),
+            loan_amount: Some(600_000.0),
+            goal_completion: 0.5,
+            current_rate: Some(18.0),
+            offered_rate: Some(10.0),
+        });
+        let score = engine.calculate_score();
+
+        // urgency 8 + amount 4 + goal 3 + rate advantage min(8, 5) = 20
+        assert_eq!(score.breakdown.dialogue, 20);
+        assert!(engine.signals().provided_loan_amount);
+        assert_eq!(engine.signals().rate_advantage, Some(8.0));
+        assert!(score.total > baseline);
+        assert_eq!(engine.last_score().map(|s| s.total), Some(score.total));
+    }
+
+    #[test]
+    fn test_dialogue_weights_from_config() {
+        let mut config = voice_agent_config::ScoringConfig::default();
+        config.weights.dialogue = 0.0;
+        let mut engine = LeadScoringEngine::with_scoring_config(std::sync::Arc::new(config));
+
+        engine.update_from_dialogue(&DialogueSignals {
+            urgency_level: Some("immediate".to_string()),
+            goal_completion: 1.0,
+            ..Default::default()
+        });
+        let score = engine.calculate_score();
+
+        assert!(score.breakdown.dialogue > 0);
+        // Category is computed but weighted out of the total
+        assert_eq!(score.total, 0);
+    }
+
+    #[test]
+    fn test_negative_sentiment_penalty() {
+        let mut engine = LeadScoringEngine::new();
+        engine.signals_mut().engagement_turns = 5;
+        let neutral = engine.calculate_score().total;
+
+        engine.signals_mut().sentiment_polarity = -1.0;
+        let score = engine.calculate_score();
+
+        assert_eq!(score.breakdown.penalty, -5);
+        assert!(score.total < neutral);
+    }
 }
@@ -32,6 +32,22 @@ pub mod fsm_adapter;
 pub mod dst;
 // Phase 10: Lead Scoring for Sales Conversion
 pub mod lead_scoring;
+// Semantic cache for deterministic tool and FAQ answers
+pub mod response_cache;
+// Post-generation compliance guardrails
+pub mod guardrails;
+// Spoken-duration budget for LLM answers
+pub mod response_governor;
+// Abusive speech policy for caller turns
+pub mod abuse_policy;
+// Disposition codes for finished calls
+pub mod disposition;
+// Translate-Think-Translate placeholder protection
+pub mod translate_think;
+// Clause-level release of streamed answers to TTS
+pub mod stable_prefix;
+// Scripted conversation simulator for regression scenarios
+pub mod simulator;
 
 // P1-2 FIX: Re-export intent module from text_processing for backward compatibility
 pub mod intent {
@@ -53,7 +69,9 @@ pub use memory::{CompressionLevel, CompressionMethod, CompressionStats};
 // Agentic memory types
 pub use memory::{
     AgenticMemory, AgenticMemoryConfig, ArchivalMemory, ArchivalMemoryConfig,
-    ConversationTurn, CoreMemory, MemoryNote, MemoryStats, MemoryType, RecallMemory, TurnRole,
+    ArchivalVectorBackend, ConsolidationConfig, ConsolidationReport, ConversationTurn,
+    CoreMemory, InMemoryArchivalBackend, MemoryNote, MemoryStats, MemoryType,
+    QdrantArchivalBackend, RecallMemory, ScyllaArchivalBackend, TurnRole,
 };
 pub use memory_legacy::{ConversationMemory, MemoryEntry};
 pub use stage::{
@@ -64,11 +82,22 @@ pub use voice_agent_text_processing::intent::{
     DetectedIntent, Intent, IntentDetector, Slot, SlotType,
 };
 // Primary agent export
-pub use agent::DomainAgent;
+pub use agent::{DomainAgent, Whisper};
+pub use abuse_policy::{AbusePolicy, AbuseResponse};
+pub use disposition::{Disposition, DispositionClassifier, DispositionSignals, DispositionSource};
+pub use guardrails::{GuardrailVerdict, Guardrails};
+pub use response_governor::ResponseGovernor;
+pub use response_cache::{CacheHit, CacheQuery, CacheScope, ResponseCache, ResponseCacheStats};
+pub use stable_prefix::StablePrefixDetector;
+pub use simulator::{
+    load_scenarios, Scenario, ScenarioReport, ScenarioTurn, ScriptedLanguageModel, Simulator,
+    TurnExpectation,
+};
 // P1-SRP: Export agent config types
 pub use agent_config::{
-    AgentConfig, AgentEvent, PersonaTraits, SmallModelConfig, SpeculativeDecodingConfig,
-    ToolDefaults, is_small_model,
+    AgentConfig, AgentEvent, EarlyTtsConfig, HandoffStatus, PersonaTraits, SmallModelConfig,
+    ParallelToolConfig, SpeculativeDecodingConfig, ToolDefaults, ToolMemoryConfig,
+    TranslateThinkConfig, is_small_model,
 };
 // Phase 2: PersuasionStrategy trait for domain-agnostic persuasion handling
 pub use persuasion::{
@@ -93,8 +122,9 @@ pub use dst::{
 };
 // Phase 10: Export Lead Scoring types
 pub use lead_scoring::{
-    EscalationTrigger, LeadClassification, LeadQualification, LeadRecommendation, LeadScore,
-    LeadScoringConfig, LeadScoringEngine, LeadSignals, ScoreBreakdown, ScoreWeights, TrustLevel,
+    DialogueSignals, EscalationTrigger, LeadClassification, LeadQualification, LeadRecommendation,
+    LeadScore, LeadScoringConfig, LeadScoringEngine, LeadSignals, ScoreBreakdown, ScoreWeights,
+    TrustLevel,
 };
 
 // Re-export transport types for convenience
@@ -148,17 +178,44 @@ pub enum AgentError {
     /// P1-2 FIX: Initialization errors (e.g., speculative executor setup)
     #[error("Initialization error: {0}")]
     Initialization(String),
+
+    #[error("Scenario error: {0}")]
+    Scenario(String),
+}
+
+impl voice_agent_core::ErrorClass for AgentError {
+    fn kind(&self) -> voice_agent_core::ErrorKind {
+        use voice_agent_core::ErrorKind;
+        match self {
+            AgentError::Conversation(_) => ErrorKind::SessionClosed,
+            AgentError::Intent(_) => ErrorKind::InvalidInput,
+            AgentError::Tool(_) | AgentError::Llm(_) | AgentError::Pipeline(_) => {
+                ErrorKind::Unavailable
+            },
+            AgentError::Timeout => ErrorKind::Timeout,
+            AgentError::Stage(_)
+            | AgentError::Memory(_)
+            | AgentError::Initialization(_)
+            | AgentError::Scenario(_) => ErrorKind::Internal,
+        }
+    }
 }
 
 impl From<voice_agent_pipeline::PipelineError> for AgentError {
     fn from(err: voice_agent_pipeline::PipelineError) -> Self {
-        AgentError::Pipeline(err.to_string())
+        match err {
+            voice_agent_pipeline::PipelineError::Timeout => AgentError::Timeout,
+            err => AgentError::Pipeline(err.to_string()),
+        }
     }
 }
 
 impl From<voice_agent_llm::LlmError> for AgentError {
     fn from(err: voice_agent_llm::LlmError) -> Self {
-        AgentError::Llm(err.to_string())
+        match err {
+            voice_agent_llm::LlmError::Timeout => AgentError::Timeout,
+            err => AgentError::Llm(err.to_string()),
+        }
     }
 }
 
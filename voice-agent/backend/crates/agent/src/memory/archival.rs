@@ -4,16 +4,28 @@
 //! Used for storing and retrieving information that doesn't fit in core memory.
 //!
 //! Key features:
-//! - Semantic search over stored memories
-//! - Automatic relevance scoring
+//! - Hybrid BM25 + dense vector search over stored memories
+//! - Embeddings generated on insert by a configurable ONNX embedder
+//! - Write-through to a durable vector backend (Qdrant / ScyllaDB)
 //! - Memory linking (A-MEM Zettelkasten style)
 //!
 //! Reference: MemGPT paper (arXiv:2310.08560), A-MEM paper (arXiv:2502.12110)
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use uuid::Uuid;
+use voice_agent_rag::{cosine_similarity, Embedder};
+
+use super::consolidation::PromotedFact;
+use super::vector_backend::ArchivalVectorBackend;
+use crate::AgentError;
+
+/// BM25 term frequency saturation
+const BM25_K1: f32 = 1.2;
+/// BM25 document length normalization
+const BM25_B: f32 = 0.75;
 
 /// Archival memory configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +40,13 @@ pub struct ArchivalMemoryConfig {
     pub enable_linking: bool,
     /// Collection name in vector store
     pub collection_name: String,
+    /// Weight of vector similarity in hybrid scoring (0.0 = BM25 only)
+    #[serde(default = "default_vector_weight")]
+    pub vector_weight: f32,
+}
+
+fn default_vector_weight() -> f32 {
+    0.6
 }
 
 impl Default for ArchivalMemoryConfig {
@@ -38,6 +57,7 @@ impl Default for ArchivalMemoryConfig {
             min_similarity: 0.5,
             enable_linking: true,
             collection_name: "agent_archival_memory".to_string(),
+            vector_weight: default_vector_weight(),
         }
     }
 }
@@ -74,6 +94,9 @@ pub struct MemoryNote {
     /// Embedding vector (populated by embedder)
     #[serde(skip)]
     pub embedding: Option<Vec<f32>>,
+    /// Promoted customer fact with provenance (customer namespace notes only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fact: Option<PromotedFact>,
 }
 
 impl MemoryNote {
@@ -98,6 +121,7 @@ impl MemoryNote {
             last_accessed: now,
             access_count: 0,
             embedding: None,
+            fact: None,
         }
     }
 
@@ -202,14 +226,20 @@ pub struct ArchivalSearchResult {
 
 /// Archival Memory Storage
 ///
-/// MemGPT-style archival storage with vector search capabilities.
-/// In production, this interfaces with Qdrant or similar vector DB.
+/// MemGPT-style archival storage with hybrid BM25 + vector search.
+/// Notes are kept in process as a working set; when a backend is set they
+/// are also written through to Qdrant or ScyllaDB and can be reloaded with
+/// `hydrate()` after a restart.
 pub struct ArchivalMemory {
     config: ArchivalMemoryConfig,
-    /// In-memory store (for testing/simple deployments)
+    /// In-process working set
     memories: parking_lot::RwLock<Vec<MemoryNote>>,
     /// Index by session ID for quick lookup
     session_index: parking_lot::RwLock<std::collections::HashMap<String, Vec<Uuid>>>,
+    /// Embedder for dense retrieval (None = BM25 only)
+    embedder: parking_lot::RwLock<Option<Arc<Embedder>>>,
+    /// Durable vector backend (None = in-process only)
+    backend: parking_lot::RwLock<Option<Arc<dyn ArchivalVectorBackend>>>,
 }
 
 impl ArchivalMemory {
@@ -219,9 +249,62 @@ impl ArchivalMemory {
             config,
             memories: parking_lot::RwLock::new(Vec::new()),
             session_index: parking_lot::RwLock::new(std::collections::HashMap::new()),
+            embedder: parking_lot::RwLock::new(None),
+            backend: parking_lot::RwLock::new(None),
         }
     }
 
+    /// Set the embedder used for dense retrieval
+    ///
+    /// Notes inserted afterwards are embedded on insert.
+    pub fn set_embedder(&self, embedder: Arc<Embedder>) {
+        *self.embedder.write() = Some(embedder);
+    }
+
+    /// Set the durable vector backend
+    ///
+    /// Requires an embedder; notes without embeddings are not written through.
+    pub fn set_backend(&self, backend: Arc<dyn ArchivalVectorBackend>) {
+        tracing::debug!(backend = backend.name(), "Archival memory backend attached");
+        *self.backend.write() = Some(backend);
+    }
+
+    /// Check if a durable backend is attached
+    pub fn has_backend(&self) -> bool {
+        self.backend.read().is_some()
+    }
+
+    /// Reload a session's notes from the backend (e.g. after a restart)
+    ///
+    /// Notes already in the working set are skipped. Returns the number loaded.
+    pub async fn hydrate(&self, session_id: &str, limit: usize) -> Result<usize, AgentError> {
+        let backend = self.backend.read().clone();
+        let Some(backend) = backend else {
+            return Ok(0);
+        };
+
+        let notes = backend.load(session_id, limit).await?;
+        let mut loaded = 0;
+        for mut note in notes {
+            if self.get(note.id).is_some() {
+                continue;
+            }
+            if note.embedding.is_none() {
+                note.embedding = self.embed(&note.text_for_embedding());
+            }
+            self.insert_local(note);
+            loaded += 1;
+        }
+
+        tracing::debug!(
+            session_id = %session_id,
+            loaded,
+            backend = backend.name(),
+            "Archival memory hydrated"
+        );
+        Ok(loaded)
+    }
+
     // =========================================================================
     // MemGPT-style Functions
     // =========================================================================
@@ -230,14 +313,45 @@ impl ArchivalMemory {
     ///
     /// MemGPT function: archival_memory_insert
     pub fn insert(&self, mut note: MemoryNote) -> Uuid {
-        let id = note.id;
-        let session_id = note.session_id.clone();
+        if note.embedding.is_none() {
+            note.embedding = self.embed(&note.text_for_embedding());
+        }
 
         // Auto-link if enabled
         if self.config.enable_linking {
             self.auto_link_memory(&mut note);
         }
 
+        self.write_through(&note);
+        self.insert_local(note)
+    }
+
+    /// Insert a note, or replace the stored note with the same ID
+    ///
+    /// Replacement keeps the existing links and position in the working set.
+    pub fn upsert(&self, mut note: MemoryNote) -> Uuid {
+        if self.get(note.id).is_none() {
+            return self.insert(note);
+        }
+        if note.embedding.is_none() {
+            note.embedding = self.embed(&note.text_for_embedding());
+        }
+
+        let id = note.id;
+        let mut memories = self.memories.write();
+        if let Some(existing) = memories.iter_mut().find(|n| n.id == id) {
+            note.links.extend(existing.links.iter().copied());
+            self.write_through(&note);
+            *existing = note;
+        }
+        id
+    }
+
+    /// Add a note to the working set without linking or write-through
+    fn insert_local(&self, note: MemoryNote) -> Uuid {
+        let id = note.id;
+        let session_id = note.session_id.clone();
+
         // Add to storage
         self.memories.write().push(note);
 
@@ -258,34 +372,89 @@ impl ArchivalMemory {
     ///
     /// MemGPT function: archival_memory_search
     ///
-    /// In production, this would call the vector database.
-    /// This implementation uses simple keyword matching for testing.
+    /// Hybrid search over the working set: BM25 blended with cosine
+    /// similarity when an embedder is set (see `vector_weight`).
     pub fn search(&self, query: &str, top_k: Option<usize>) -> Vec<ArchivalSearchResult> {
+        let top_k = top_k.unwrap_or(self.config.default_top_k);
+        let query_embedding = self.embed(query);
+        self.search_local(query, query_embedding.as_deref(), top_k)
+    }
+
+    /// Hybrid search including notes held only by the backend
+    ///
+    /// Notes evicted from the working set, or written by another instance,
+    /// are found through the backend's ANN search and re-scored with keyword
+    /// coverage. Falls back to `search()` without a backend.
+    pub async fn search_with_backend(
+        &self,
+        session_id: Option<&str>,
+        query: &str,
+        top_k: Option<usize>,
+    ) -> Vec<ArchivalSearchResult> {
+        let top_k = top_k.unwrap_or(self.config.default_top_k);
+        let query_embedding = self.embed(query);
+        let mut results = self.search_local(query, query_embedding.as_deref(), top_k);
+
+        let backend = self.backend.read().clone();
+        let (Some(backend), Some(embedding)) = (backend, query_embedding) else {
+            return results;
+        };
+
+        match backend.search(&embedding, top_k, session_id).await {
+            Ok(hits) => {
+                let seen: HashSet<Uuid> = results.iter().map(|r| r.note.id).collect();
+                for (note, similarity) in hits {
+                    if seen.contains(&note.id) {
+                        continue;
+                    }
+                    let lexical = self.compute_keyword_score(query, &note);
+                    let score = self.hybrid_score(lexical, Some(similarity));
+                    if score >= self.config.min_similarity {
+                        results.push(ArchivalSearchResult {
+                            note,
+                            score,
+                            via_link: false,
+                        });
+                    }
+                }
+                results.sort_by(|a, b| {
+                    b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal)
+                });
+                results.truncate(top_k);
+            },
+            Err(e) => {
+                tracing::warn!(backend = backend.name(), error = %e, "Archival backend search failed");
+            },
+        }
+
+        results
+    }
+
+    /// Search with embedding vector (pure dense retrieval over the working set)
+    pub fn search_by_embedding(
+        &self,
+        embedding: &[f32],
+        top_k: Option<usize>,
+    ) -> Vec<ArchivalSearchResult> {
         let top_k = top_k.unwrap_or(self.config.default_top_k);
         let memories = self.memories.read();
 
-        // Simple keyword-based scoring for testing
-        // In production, use vector similarity from Qdrant
         let mut results: Vec<ArchivalSearchResult> = memories
             .iter()
-            .map(|note| {
-                let score = self.compute_keyword_score(query, note);
-                ArchivalSearchResult {
+            .filter_map(|note| {
+                let score = cosine_similarity(embedding, note.embedding.as_deref()?);
+                Some(ArchivalSearchResult {
                     note: note.clone(),
                     score,
                     via_link: false,
-                }
+                })
             })
             .filter(|r| r.score >= self.config.min_similarity)
             .collect();
 
-        // Sort by score descending
         results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
-
-        // Take top_k
         results.truncate(top_k);
 
-        // Mark accessed
         drop(memories);
         for result in &results {
             self.mark_accessed(result.note.id);
@@ -294,18 +463,6 @@ impl ArchivalMemory {
         results
     }
 
-    /// Search with embedding vector (for production use)
-    pub fn search_by_embedding(
-        &self,
-        _embedding: &[f32],
-        top_k: Option<usize>,
-    ) -> Vec<ArchivalSearchResult> {
-        // In production, this would query Qdrant with the embedding vector
-        // For now, return empty (no embeddings stored in test mode)
-        let _ = top_k;
-        Vec::new()
-    }
-
     /// Search within a specific session
     pub fn search_session(
         &self,
@@ -322,27 +479,15 @@ impl ArchivalMemory {
         };
         drop(session_ids);
 
+        let query_embedding = self.embed(query);
         let memories = self.memories.read();
         let id_set: HashSet<Uuid> = memory_ids.into_iter().collect();
 
-        let mut results: Vec<ArchivalSearchResult> = memories
+        let notes: Vec<&MemoryNote> = memories
             .iter()
             .filter(|note| id_set.contains(&note.id))
-            .map(|note| {
-                let score = self.compute_keyword_score(query, note);
-                ArchivalSearchResult {
-                    note: note.clone(),
-                    score,
-                    via_link: false,
-                }
-            })
-            .filter(|r| r.score >= self.config.min_similarity)
             .collect();
-
-        results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
-        results.truncate(top_k);
-
-        results
+        self.rank(query, query_embedding.as_deref(), &notes, top_k)
     }
 
     /// Get memory by ID
@@ -372,9 +517,12 @@ impl ArchivalMemory {
         result
     }
 
-    /// Delete memory by ID
+    /// Delete memory by ID (also removed from the backend)
     pub fn delete(&self, id: Uuid) -> bool {
         let mut memories = self.memories.write();
+        if let Some(note) = memories.iter().find(|n| n.id == id) {
+            self.delete_through(note.clone());
+        }
         let initial_len = memories.len();
         memories.retain(|n| n.id != id);
 
@@ -392,6 +540,18 @@ impl ArchivalMemory {
         memories.len() < initial_len
     }
 
+    /// Get all notes for a session in insertion order
+    pub fn session_notes(&self, session_id: &str) -> Vec<MemoryNote> {
+        let ids = match self.session_index.read().get(session_id) {
+            Some(ids) => ids.clone(),
+            None => return Vec::new(),
+        };
+        let memories = self.memories.read();
+        ids.iter()
+            .filter_map(|id| memories.iter().find(|n| n.id == *id).cloned())
+            .collect()
+    }
+
     /// Clear all memories for a session
     pub fn clear_session(&self, session_id: &str) {
         let ids_to_remove: Vec<Uuid> = {
@@ -420,7 +580,126 @@ impl ArchivalMemory {
     // Private Helpers
     // =========================================================================
 
-    /// Compute simple keyword-based score (for testing)
+    /// Embed text with the configured embedder, if any
+    fn embed(&self, text: &str) -> Option<Vec<f32>> {
+        let embedder = self.embedder.read().clone()?;
+        match embedder.embed(text) {
+            Ok(embedding) => Some(embedding),
+            Err(e) => {
+                tracing::warn!(error = %e, "Archival memory embedding failed, using BM25 only");
+                None
+            },
+        }
+    }
+
+    /// Persist a note to the backend in the background
+    fn write_through(&self, note: &MemoryNote) {
+        let Some(backend) = self.backend.read().clone() else {
+            return;
+        };
+        let embedding = match note.embedding.clone() {
+            Some(embedding) => embedding,
+            None if !backend.requires_embeddings() => Vec::new(),
+            None => return,
+        };
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            tracing::debug!("No async runtime, archival note not persisted");
+            return;
+        };
+
+        let note = note.clone();
+        handle.spawn(async move {
+            if let Err(e) = backend.upsert(&note, &embedding).await {
+                tracing::warn!(id = %note.id, backend = backend.name(), error = %e, "Failed to persist archival note");
+            }
+        });
+    }
+
+    /// Remove a note from the backend in the background
+    fn delete_through(&self, note: MemoryNote) {
+        let backend = self.backend.read().clone();
+        let Some(backend) = backend else {
+            return;
+        };
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+
+        handle.spawn(async move {
+            if let Err(e) = backend.delete(&note).await {
+                tracing::warn!(id = %note.id, backend = backend.name(), error = %e, "Failed to delete archival note");
+            }
+        });
+    }
+
+    /// Hybrid search over the whole working set
+    fn search_local(
+        &self,
+        query: &str,
+        query_embedding: Option<&[f32]>,
+        top_k: usize,
+    ) -> Vec<ArchivalSearchResult> {
+        let memories = self.memories.read();
+        let notes: Vec<&MemoryNote> = memories.iter().collect();
+        let results = self.rank(query, query_embedding, &notes, top_k);
+
+        // Mark accessed
+        drop(memories);
+        for result in &results {
+            self.mark_accessed(result.note.id);
+        }
+
+        results
+    }
+
+    /// Score, filter and sort candidate notes
+    ///
+    /// Lexical relevance is BM25 (normalized to the best candidate) scaled
+    /// by query keyword coverage, so a weak match never scores 1.0 just for
+    /// being the best of a poor set.
+    fn rank(
+        &self,
+        query: &str,
+        query_embedding: Option<&[f32]>,
+        notes: &[&MemoryNote],
+        top_k: usize,
+    ) -> Vec<ArchivalSearchResult> {
+        let bm25 = bm25_scores(query, notes);
+
+        let mut results: Vec<ArchivalSearchResult> = notes
+            .iter()
+            .zip(bm25)
+            .map(|(note, bm25)| {
+                let lexical = bm25 * self.compute_keyword_score(query, note);
+                let similarity = query_embedding
+                    .zip(note.embedding.as_deref())
+                    .map(|(q, n)| cosine_similarity(q, n));
+                ArchivalSearchResult {
+                    note: (*note).clone(),
+                    score: self.hybrid_score(lexical, similarity),
+                    via_link: false,
+                }
+            })
+            .filter(|r| r.score >= self.config.min_similarity)
+            .collect();
+
+        results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        results.truncate(top_k);
+        results
+    }
+
+    /// Blend lexical and vector scores
+    fn hybrid_score(&self, lexical: f32, similarity: Option<f32>) -> f32 {
+        match similarity {
+            Some(similarity) => {
+                let w = self.config.vector_weight.clamp(0.0, 1.0);
+                w * similarity.max(0.0) + (1.0 - w) * lexical
+            },
+            None => lexical,
+        }
+    }
+
+    /// Fraction of query words found in the note (keyword hits count double)
     fn compute_keyword_score(&self, query: &str, note: &MemoryNote) -> f32 {
         let query_lower = query.to_lowercase();
         let query_words: HashSet<&str> = query_lower.split_whitespace().collect();
@@ -540,6 +819,66 @@ impl ArchivalMemory {
     }
 }
 
+/// Split text into lowercase terms
+///
+/// Splits on whitespace and ASCII punctuation only, so Devanagari vowel
+/// signs stay attached to their words.
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| c.is_whitespace() || c.is_ascii_punctuation())
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_lowercase())
+        .collect()
+}
+
+/// BM25 scores for each note, normalized so the best note scores 1.0
+fn bm25_scores(query: &str, notes: &[&MemoryNote]) -> Vec<f32> {
+    let query_terms: HashSet<String> = tokenize(query).into_iter().collect();
+    if query_terms.is_empty() || notes.is_empty() {
+        return vec![0.0; notes.len()];
+    }
+
+    let docs: Vec<Vec<String>> = notes
+        .iter()
+        .map(|note| {
+            let mut terms = tokenize(&note.content);
+            terms.extend(tokenize(&note.context_description));
+            for keyword in &note.keywords {
+                terms.extend(tokenize(keyword));
+            }
+            terms
+        })
+        .collect();
+
+    let n = docs.len() as f32;
+    let avg_len = (docs.iter().map(Vec::len).sum::<usize>() as f32 / n).max(1.0);
+    let idf: HashMap<&str, f32> = query_terms
+        .iter()
+        .map(|term| {
+            let df = docs.iter().filter(|d| d.contains(term)).count() as f32;
+            (term.as_str(), ((n - df + 0.5) / (df + 0.5) + 1.0).ln())
+        })
+        .collect();
+
+    let raw: Vec<f32> = docs
+        .iter()
+        .map(|doc| {
+            let len_norm = 1.0 - BM25_B + BM25_B * doc.len() as f32 / avg_len;
+            idf.iter()
+                .map(|(term, weight)| {
+                    let tf = doc.iter().filter(|t| t.as_str() == *term).count() as f32;
+                    weight * tf * (BM25_K1 + 1.0) / (tf + BM25_K1 * len_norm)
+                })
+                .sum::<f32>()
+        })
+        .collect();
+
+    let max = raw.iter().copied().fold(0.0f32, f32::max);
+    if max <= 0.0 {
+        return vec![0.0; notes.len()];
+    }
+    raw.into_iter().map(|score| score / max).collect()
+}
+
 impl Default for ArchivalMemory {
     fn default() -> Self {
         Self::new(ArchivalMemoryConfig::default())
@@ -659,4 +998,138 @@ mod tests {
 
         assert!(archival.len() <= 3);
     }
+
+    #[test]
+    fn test_bm25_scores() {
+        let gold = MemoryNote::new("s1", "Customer has gold jewellery", MemoryType::CustomerFact);
+        let loan = MemoryNote::new("s1", "Customer wants a loan", MemoryType::CustomerFact);
+        let both = MemoryNote::new("s1", "Customer wants gold loan", MemoryType::CustomerFact);
+
+        let scores = bm25_scores("gold loan", &[&gold, &loan, &both]);
+        assert_eq!(scores[2], 1.0);
+        assert!(scores[0] > 0.0 && scores[0] < 1.0);
+
+        // Notes without any query term score zero
+        let scores = bm25_scores("jewellery", &[&gold, &loan]);
+        assert_eq!(scores, vec![1.0, 0.0]);
+        assert_eq!(bm25_scores("", &[&gold]), vec![0.0]);
+    }
+
+    #[test]
+    fn test_hybrid_score_blends_vector_similarity() {
+        let archival = ArchivalMemory::new(ArchivalMemoryConfig {
+            vector_weight: 0.6,
+            ..Default::default()
+        });
+
+        assert_eq!(archival.hybrid_score(0.8, None), 0.8);
+        let blended = archival.hybrid_score(0.5, Some(1.0));
+        assert!((blended - 0.8).abs() < 1e-6);
+        // Negative similarity never drags the score below the lexical share
+        assert!((archival.hybrid_score(1.0, Some(-1.0)) - 0.4).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_search_by_embedding() {
+        let archival = ArchivalMemory::default();
+        let gold = MemoryNote::new("s1", "Gold weight 40 grams", MemoryType::CustomerFact)
+            .with_embedding(vec![1.0, 0.0]);
+        let rate = MemoryNote::new("s1", "Asked about rates", MemoryType::Event)
+            .with_embedding(vec![0.0, 1.0]);
+        let gold_id = archival.insert(gold);
+        archival.insert(rate);
+
+        let results = archival.search_by_embedding(&[0.9, 0.1], None);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].note.id, gold_id);
+        assert!((cosine_similarity(&[1.0, 0.0], &[1.0, 0.0]) - 1.0).abs() < 1e-6);
+        assert_eq!(cosine_similarity(&[1.0], &[1.0, 0.0]), 0.0);
+    }
+
+    /// Backend that keeps notes in a Vec (stands in for Qdrant/Scylla)
+    #[derive(Default)]
+    struct MockBackend {
+        notes: parking_lot::Mutex<Vec<MemoryNote>>,
+    }
+
+    #[async_trait::async_trait]
+    impl ArchivalVectorBackend for MockBackend {
+        fn name(&self) -> &'static str {
+            "mock"
+        }
+
+        async fn upsert(&self, note: &MemoryNote, embedding: &[f32]) -> Result<(), AgentError> {
+            let note = note.clone().with_embedding(embedding.to_vec());
+            self.notes.lock().push(note);
+            Ok(())
+        }
+
+        async fn search(
+            &self,
+            embedding: &[f32],
+            top_k: usize,
+            _session_id: Option<&str>,
+        ) -> Result<Vec<(MemoryNote, f32)>, AgentError> {
+            let mut hits: Vec<(MemoryNote, f32)> = self
+                .notes
+                .lock()
+                .iter()
+                .map(|n| {
+                    let score = cosine_similarity(embedding, n.embedding.as_deref().unwrap_or(&[]));
+                    (n.clone(), score)
+                })
+                .collect();
+            hits.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+            hits.truncate(top_k);
+            Ok(hits)
+        }
+
+        async fn delete(&self, note: &MemoryNote) -> Result<(), AgentError> {
+            self.notes.lock().retain(|n| n.id != note.id);
+            Ok(())
+        }
+
+        async fn load(&self, session_id: &str, limit: usize) -> Result<Vec<MemoryNote>, AgentError> {
+            Ok(self
+                .notes
+                .lock()
+                .iter()
+                .filter(|n| n.session_id == session_id)
+                .take(limit)
+                .map(|n| {
+                    let mut n = n.clone();
+                    n.embedding = None;
+                    n
+                })
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_write_through_and_hydrate_after_restart() {
+        let backend = Arc::new(MockBackend::default());
+
+        let archival = ArchivalMemory::default();
+        archival.set_backend(backend.clone());
+        let note = MemoryNote::new("s1", "Customer prefers Hindi", MemoryType::Preference)
+            .with_embedding(vec![0.2, 0.8]);
+        let id = archival.insert(note);
+
+        // Write-through runs on a spawned task
+        for _ in 0..50 {
+            if !backend.notes.lock().is_empty() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        }
+        assert_eq!(backend.notes.lock().len(), 1);
+
+        // A fresh instance (new process) reloads the session from the backend
+        let restarted = ArchivalMemory::default();
+        restarted.set_backend(backend.clone());
+        assert_eq!(restarted.hydrate("s1", 100).await.unwrap(), 1);
+        assert_eq!(restarted.hydrate("s1", 100).await.unwrap(), 0);
+        assert!(restarted.get(id).is_some());
+        assert_eq!(restarted.search("Hindi", None).len(), 1);
+    }
 }
@@ -0,0 +1,236 @@
+//! Context Compaction
+//!
+//! Keeps the main context within its token budget. When a turn pushes the
+//! context over the high watermark, the oldest FIFO turns are evicted to
+//! recall storage (still searchable) and folded into a running summary.
+//!
+//! Summaries form a hierarchy: each eviction adds a level-0 segment; when
+//! the segments outgrow their budget they are merged into one segment a
+//! level higher. Eviction runs synchronously with a rule-based or
+//! extractive summary; `AgenticMemory::compact` later re-summarizes the
+//! hierarchy with the LLM when one is set.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::recall::estimate_tokens;
+
+/// Prefix used by rule-based summaries
+const SUMMARY_PREFIX: &str = "Previous: ";
+/// Separator between facts in rule-based summaries
+const FACT_SEPARATOR: &str = " | ";
+
+/// A summary of a run of evicted turns
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SummarySegment {
+    /// Summary text
+    pub text: String,
+    /// 0 for turn summaries, +1 for each merge
+    pub level: u8,
+    /// Number of turns covered
+    pub turns: usize,
+    /// Estimated tokens
+    pub tokens: usize,
+}
+
+impl SummarySegment {
+    pub fn new(text: impl Into<String>, level: u8, turns: usize) -> Self {
+        let text = text.into();
+        let tokens = estimate_tokens(&text);
+        Self {
+            text,
+            level,
+            turns,
+            tokens,
+        }
+    }
+}
+
+/// Running summary of turns evicted from the context window
+#[derive(Debug, Clone, Default)]
+pub struct SummaryHierarchy {
+    segments: Vec<SummarySegment>,
+}
+
+impl SummaryHierarchy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a summary of newly evicted turns
+    pub fn push(&mut self, text: impl Into<String>, turns: usize) {
+        let segment = SummarySegment::new(text, 0, turns);
+        if !segment.text.trim().is_empty() {
+            self.segments.push(segment);
+        }
+    }
+
+    /// Segments, oldest first
+    pub fn segments(&self) -> &[SummarySegment] {
+        &self.segments
+    }
+
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Total estimated tokens of all segments
+    pub fn total_tokens(&self) -> usize {
+        self.segments.iter().map(|s| s.tokens).sum()
+    }
+
+    /// Total turns covered by all segments
+    pub fn total_turns(&self) -> usize {
+        self.segments.iter().map(|s| s.turns).sum()
+    }
+
+    /// Highest level among segments
+    pub fn max_level(&self) -> u8 {
+        self.segments.iter().map(|s| s.level).max().unwrap_or(0)
+    }
+
+    /// Replace all segments with a single higher-level summary
+    pub fn collapse(&mut self, text: impl Into<String>) {
+        if self.segments.is_empty() {
+            return;
+        }
+        let level = self.max_level().saturating_add(1);
+        let turns = self.total_turns();
+        self.segments = vec![SummarySegment::new(text, level, turns)];
+    }
+
+    /// Merge segments with the rule-based merger while over `budget` tokens
+    ///
+    /// Returns whether a merge happened.
+    pub fn merge_if_over(&mut self, budget: usize) -> bool {
+        if self.total_tokens() <= budget {
+            return false;
+        }
+        let texts: Vec<&str> = self.segments.iter().map(|s| s.text.as_str()).collect();
+        let merged = fit_to_budget(&merge_summaries(&texts), budget);
+        self.collapse(merged);
+        true
+    }
+
+    /// Format the summary for the LLM context
+    pub fn format_for_context(&self) -> String {
+        self.segments
+            .iter()
+            .map(|s| s.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub fn clear(&mut self) {
+        self.segments.clear();
+    }
+}
+
+/// Merge rule-based summaries, keeping the latest value per fact label
+///
+/// Rule-based summaries look like `Previous: Name: Ravi | Amount: 5 lakh`.
+/// Free-text summaries (e.g. from the LLM) are kept as separate facts.
+pub fn merge_summaries(summaries: &[&str]) -> String {
+    let mut order: Vec<String> = Vec::new();
+    let mut facts: HashMap<String, String> = HashMap::new();
+
+    for summary in summaries {
+        let body = summary
+            .trim()
+            .strip_prefix(SUMMARY_PREFIX)
+            .unwrap_or(summary.trim());
+        for fact in body
+            .split(FACT_SEPARATOR)
+            .map(str::trim)
+            .filter(|f| !f.is_empty())
+        {
+            let label = match fact.split_once(':') {
+                Some((label, _)) if label.len() <= 32 => label.trim().to_lowercase(),
+                _ => fact.to_lowercase(),
+            };
+            if !facts.contains_key(&label) {
+                order.push(label.clone());
+            }
+            facts.insert(label, fact.to_string());
+        }
+    }
+
+    let merged: Vec<&str> = order
+        .iter()
+        .filter_map(|label| facts.get(label).map(String::as_str))
+        .collect();
+    format!("{}{}", SUMMARY_PREFIX, merged.join(FACT_SEPARATOR))
+}
+
+/// Truncate text so its estimated tokens fit the budget
+///
+/// Cuts at a grapheme boundary, preferring the last whitespace.
+pub fn fit_to_budget(text: &str, max_tokens: usize) -> String {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    if estimate_tokens(text) <= max_tokens {
+        return text.to_string();
+    }
+
+    let graphemes: Vec<&str> = text.graphemes(true).collect();
+    let mut keep = graphemes.len();
+    loop {
+        let estimated = estimate_tokens(&graphemes[..keep].concat()).max(1);
+        if estimated <= max_tokens || keep == 0 {
+            break;
+        }
+        // Shrink proportionally, always making progress
+        keep = (keep * max_tokens / estimated).min(keep - 1);
+    }
+
+    let truncated = graphemes[..keep].concat();
+    match truncated.rfind(char::is_whitespace) {
+        Some(pos) if pos > truncated.len() / 2 => truncated[..pos].to_string(),
+        _ => truncated,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_summaries_keeps_latest_value() {
+        let merged = merge_summaries(&[
+            "Previous: Name: Ravi | Amount: 5 lakh",
+            "Previous: Amount: 7 lakh | City: Pune",
+        ]);
+        assert_eq!(merged, "Previous: Name: Ravi | Amount: 7 lakh | City: Pune");
+    }
+
+    #[test]
+    fn test_hierarchy_merges_over_budget() {
+        let mut hierarchy = SummaryHierarchy::new();
+        hierarchy.push("Previous: Name: Ravi | Amount: 5 lakh", 4);
+        hierarchy.push("Previous: Amount: 7 lakh", 2);
+        assert!(!hierarchy.merge_if_over(1000));
+        assert_eq!(hierarchy.len(), 2);
+
+        assert!(hierarchy.merge_if_over(5));
+        assert_eq!(hierarchy.len(), 1);
+        assert_eq!(hierarchy.segments()[0].level, 1);
+        assert_eq!(hierarchy.total_turns(), 6);
+        assert!(hierarchy.total_tokens() <= 5);
+    }
+
+    #[test]
+    fn test_fit_to_budget() {
+        let text = "the customer wants a loan against fifty grams of gold jewellery ".repeat(20);
+        let fitted = fit_to_budget(&text, 30);
+        assert!(estimate_tokens(&fitted) <= 30);
+        assert!(!fitted.is_empty());
+        assert_eq!(fit_to_budget("short", 30), "short");
+
+        let hindi = "नमस्ते मैं आपकी कैसे मदद कर सकता हूं ".repeat(20);
+        assert!(estimate_tokens(&fit_to_budget(&hindi, 10)) <= 10);
+    }
+}
@@ -0,0 +1,549 @@
+//! Cross-Session Memory Consolidation
+//!
+//! At session end, durable customer facts (name, city, holdings, language
+//! preference) are promoted from core memory and recall entities into a
+//! customer-scoped archival namespace. Each fact is stored as one archival
+//! note carrying its provenance. When a new value conflicts with a promoted
+//! one, the winner is decided by source priority, then confidence, then
+//! recency; the losing value is kept in the fact's history.
+//!
+//! The next session for the same customer hydrates the namespace and
+//! starts with these facts in core memory.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::archival::{MemoryNote, MemorySource, MemoryType};
+use super::core::{EntrySource, HumanBlock, MemoryBlockEntry};
+use super::recall::{ConversationTurn, TurnRole};
+
+/// Tag marking archival notes that hold a promoted customer fact
+pub const PROMOTED_FACT_TAG: &str = "promoted_fact";
+/// Fact key for the customer's name (stored outside `HumanBlock::facts`)
+pub const NAME_FACT_KEY: &str = "name";
+/// Fact key for the customer's preferred language
+pub const LANGUAGE_FACT_KEY: &str = "preferred_language";
+
+/// Confidence given to entities extracted from recall turns
+const RECALL_ENTITY_CONFIDENCE: f32 = 0.7;
+/// Confidence given to the session language (detected, not stated)
+const LANGUAGE_CONFIDENCE: f32 = 0.9;
+/// A newer value of equal source priority wins unless it is less
+/// confident than the promoted value by more than this margin
+const RECENCY_MARGIN: f32 = 0.1;
+
+/// Archival namespace holding a customer's promoted facts
+pub fn customer_namespace(customer_id: &str) -> String {
+    format!("customer:{}", customer_id)
+}
+
+/// Consolidation configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsolidationConfig {
+    /// Fact keys worth keeping across sessions (canonical fact keys)
+    pub durable_fact_keys: Vec<String>,
+    /// Minimum confidence for a fact to be promoted
+    pub min_confidence: f32,
+    /// Superseded values kept per fact
+    pub max_history: usize,
+}
+
+impl Default for ConsolidationConfig {
+    fn default() -> Self {
+        Self {
+            durable_fact_keys: [
+                NAME_FACT_KEY,
+                LANGUAGE_FACT_KEY,
+                "customer_name",
+                "location",
+                "city",
+                "asset_quantity",
+                "asset_quality",
+                "asset_type",
+                "current_provider",
+            ]
+            .iter()
+            .map(|k| k.to_string())
+            .collect(),
+            min_confidence: 0.6,
+            max_history: 5,
+        }
+    }
+}
+
+/// Where a promoted fact value came from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FactProvenance {
+    /// Session the value was observed in
+    pub session_id: String,
+    /// How the value was obtained
+    pub source: EntrySource,
+    /// Confidence at observation time (0.0 - 1.0)
+    pub confidence: f32,
+    /// When the value was observed
+    pub observed_at: DateTime<Utc>,
+}
+
+/// A value a promoted fact held before being replaced
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SupersededValue {
+    pub value: String,
+    pub provenance: FactProvenance,
+}
+
+/// A durable customer fact in the customer namespace
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PromotedFact {
+    /// Canonical fact key
+    pub key: String,
+    /// Current value
+    pub value: String,
+    /// Provenance of the current value
+    pub provenance: FactProvenance,
+    /// Earlier values, most recent first
+    #[serde(default)]
+    pub superseded: Vec<SupersededValue>,
+}
+
+impl PromotedFact {
+    pub fn new(
+        key: impl Into<String>,
+        value: impl Into<String>,
+        provenance: FactProvenance,
+    ) -> Self {
+        Self {
+            key: key.into(),
+            value: value.into(),
+            provenance,
+            superseded: Vec::new(),
+        }
+    }
+
+    /// Build the archival note for this fact in the customer's namespace
+    pub fn to_note(&self, customer_id: &str) -> MemoryNote {
+        let mut note = MemoryNote::new(
+            customer_namespace(customer_id),
+            format!("{}: {}", self.key, self.value),
+            MemoryType::CustomerFact,
+        )
+        .with_context("Customer fact")
+        .with_keywords(vec![self.key.clone(), self.value.clone()])
+        .with_tags(vec![
+            PROMOTED_FACT_TAG.to_string(),
+            format!("fact:{}", self.key),
+        ]);
+        note.source = match self.provenance.source {
+            EntrySource::External => MemorySource::External,
+            EntrySource::Inferred => MemorySource::Inferred,
+            EntrySource::System => MemorySource::System,
+            EntrySource::UserStated => MemorySource::Conversation,
+        };
+        note.fact = Some(self.clone());
+        note
+    }
+
+    /// Core memory entry for restoring this fact into a new session
+    pub fn to_entry(&self) -> MemoryBlockEntry {
+        MemoryBlockEntry::new(self.key.clone(), self.value.clone(), self.provenance.source)
+            .with_confidence(self.provenance.confidence)
+    }
+}
+
+/// Outcome of merging one observed value into the promoted facts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FactResolution {
+    /// No promoted value existed
+    Added,
+    /// Same value observed again (provenance refreshed)
+    Confirmed,
+    /// New value replaced the promoted one
+    Replaced,
+    /// Promoted value kept; the observed value lost
+    Kept,
+}
+
+/// Ranking of entry sources for conflict resolution
+fn source_priority(source: EntrySource) -> u8 {
+    match source {
+        EntrySource::UserStated => 3,
+        EntrySource::External => 2,
+        EntrySource::System => 1,
+        EntrySource::Inferred => 0,
+    }
+}
+
+fn same_value(a: &str, b: &str) -> bool {
+    a.trim().eq_ignore_ascii_case(b.trim())
+}
+
+/// Decide whether an observed value should replace the promoted one
+pub fn resolve_conflict(
+    existing: Option<&PromotedFact>,
+    value: &str,
+    provenance: &FactProvenance,
+) -> FactResolution {
+    let Some(existing) = existing else {
+        return FactResolution::Added;
+    };
+    if same_value(&existing.value, value) {
+        return FactResolution::Confirmed;
+    }
+
+    let new_priority = source_priority(provenance.source);
+    let old_priority = source_priority(existing.provenance.source);
+    let replaces = new_priority > old_priority
+        || (new_priority == old_priority
+            && provenance.confidence + RECENCY_MARGIN >= existing.provenance.confidence
+            && provenance.observed_at >= existing.provenance.observed_at);
+
+    if replaces {
+        FactResolution::Replaced
+    } else {
+        FactResolution::Kept
+    }
+}
+
+/// A fact observed during the session, eligible for promotion
+#[derive(Debug, Clone)]
+pub struct FactCandidate {
+    pub key: String,
+    pub value: String,
+    pub provenance: FactProvenance,
+}
+
+impl FactCandidate {
+    /// Ordering key: stronger source, then confidence, then recency
+    fn strength(&self) -> (u8, f32, DateTime<Utc>) {
+        (
+            source_priority(self.provenance.source),
+            self.provenance.confidence,
+            self.provenance.observed_at,
+        )
+    }
+}
+
+/// Summary of a consolidation run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConsolidationReport {
+    pub added: usize,
+    pub confirmed: usize,
+    pub replaced: usize,
+    pub kept: usize,
+}
+
+impl ConsolidationReport {
+    /// Number of facts written to the customer namespace
+    pub fn written(&self) -> usize {
+        self.added + self.confirmed + self.replaced
+    }
+}
+
+/// Promotes durable session facts into customer-scoped long-term memory
+pub struct MemoryConsolidator {
+    config: ConsolidationConfig,
+}
+
+impl MemoryConsolidator {
+    pub fn new(config: ConsolidationConfig) -> Self {
+        Self { config }
+    }
+
+    /// Check if a fact key is kept across sessions
+    pub fn is_durable(&self, key: &str) -> bool {
+        self.config.durable_fact_keys.iter().any(|k| k == key)
+    }
+
+    /// Collect promotion candidates from core memory and recall entities
+    ///
+    /// `canonical_key` maps slot names to fact keys (e.g. domain slot
+    /// aliases). Only the strongest observation per key is returned.
+    pub fn collect(
+        &self,
+        session_id: &str,
+        human: &HumanBlock,
+        turns: &[ConversationTurn],
+        canonical_key: &dyn Fn(&str) -> String,
+    ) -> Vec<FactCandidate> {
+        let now = Utc::now();
+        let provenance = |source, confidence, observed_at| FactProvenance {
+            session_id: session_id.to_string(),
+            source,
+            confidence,
+            observed_at,
+        };
+
+        let mut observed = Vec::new();
+        if let Some(ref name) = human.name {
+            observed.push(FactCandidate {
+                key: NAME_FACT_KEY.to_string(),
+                value: name.clone(),
+                provenance: provenance(EntrySource::UserStated, 1.0, now),
+            });
+        }
+        if let Some(ref language) = human.preferred_language {
+            observed.push(FactCandidate {
+                key: LANGUAGE_FACT_KEY.to_string(),
+                value: language.clone(),
+                provenance: provenance(EntrySource::Inferred, LANGUAGE_CONFIDENCE, now),
+            });
+        }
+        for entry in human.facts.values() {
+            observed.push(FactCandidate {
+                key: canonical_key(&entry.key),
+                value: entry.value.clone(),
+                provenance: provenance(entry.source, entry.confidence, entry.updated_at),
+            });
+        }
+        for turn in turns.iter().filter(|t| t.role == TurnRole::User) {
+            for (key, value) in &turn.entities {
+                observed.push(FactCandidate {
+                    key: canonical_key(key),
+                    value: value.clone(),
+                    provenance: provenance(
+                        EntrySource::Inferred,
+                        RECALL_ENTITY_CONFIDENCE,
+                        turn.timestamp,
+                    ),
+                });
+            }
+        }
+
+        let mut best: HashMap<String, FactCandidate> = HashMap::new();
+        for candidate in observed {
+            if !self.is_durable(&candidate.key)
+                || candidate.value.trim().is_empty()
+                || candidate.provenance.confidence < self.config.min_confidence
+            {
+                continue;
+            }
+            match best.get(&candidate.key) {
+                Some(current) if current.strength() >= candidate.strength() => {},
+                _ => {
+                    best.insert(candidate.key.clone(), candidate);
+                },
+            }
+        }
+
+        let mut candidates: Vec<FactCandidate> = best.into_values().collect();
+        candidates.sort_by(|a, b| a.key.cmp(&b.key));
+        candidates
+    }
+
+    /// Merge candidates into the promoted facts
+    ///
+    /// Returns the facts to write back (added, confirmed or replaced).
+    pub fn merge(
+        &self,
+        existing: &HashMap<String, PromotedFact>,
+        candidates: Vec<FactCandidate>,
+    ) -> (Vec<PromotedFact>, ConsolidationReport) {
+        let mut report = ConsolidationReport::default();
+        let mut changed = Vec::new();
+
+        for candidate in candidates {
+            let current = existing.get(&candidate.key);
+            match resolve_conflict(current, &candidate.value, &candidate.provenance) {
+                FactResolution::Added => {
+                    report.added += 1;
+                    changed.push(PromotedFact::new(
+                        candidate.key,
+                        candidate.value,
+                        candidate.provenance,
+                    ));
+                },
+                FactResolution::Confirmed => {
+                    report.confirmed += 1;
+                    let mut fact = current.cloned().expect("confirmed facts exist");
+                    let old = &fact.provenance;
+                    let source = if source_priority(candidate.provenance.source)
+                        >= source_priority(old.source)
+                    {
+                        candidate.provenance.source
+                    } else {
+                        old.source
+                    };
+                    fact.provenance = FactProvenance {
+                        source,
+                        confidence: candidate.provenance.confidence.max(old.confidence),
+                        ..candidate.provenance
+                    };
+                    changed.push(fact);
+                },
+                FactResolution::Replaced => {
+                    report.replaced += 1;
+                    let mut fact = current.cloned().expect("replaced facts exist");
+                    fact.superseded.insert(
+                        0,
+                        SupersededValue {
+                            value: std::mem::replace(&mut fact.value, candidate.value),
+                            provenance: std::mem::replace(
+                                &mut fact.provenance,
+                                candidate.provenance,
+                            ),
+                        },
+                    );
+                    fact.superseded.truncate(self.config.max_history);
+                    changed.push(fact);
+                },
+                FactResolution::Kept => {
+                    report.kept += 1;
+                    tracing::debug!(
+                        key = %candidate.key,
+                        "Kept promoted fact over weaker conflicting value"
+                    );
+                },
+            }
+        }
+
+        (changed, report)
+    }
+}
+
+impl Default for MemoryConsolidator {
+    fn default() -> Self {
+        Self::new(ConsolidationConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn provenance(source: EntrySource, confidence: f32, age_days: i64) -> FactProvenance {
+        FactProvenance {
+            session_id: format!("s-{}", age_days),
+            source,
+            confidence,
+            observed_at: Utc::now() - Duration::days(age_days),
+        }
+    }
+
+    #[test]
+    fn test_resolve_conflict() {
+        let promoted =
+            PromotedFact::new("city", "Pune", provenance(EntrySource::UserStated, 0.9, 10));
+
+        let stated = provenance(EntrySource::UserStated, 0.9, 0);
+        assert_eq!(
+            resolve_conflict(None, "Pune", &stated),
+            FactResolution::Added
+        );
+        assert_eq!(
+            resolve_conflict(Some(&promoted), " pune ", &stated),
+            FactResolution::Confirmed
+        );
+        assert_eq!(
+            resolve_conflict(Some(&promoted), "Mumbai", &stated),
+            FactResolution::Replaced
+        );
+
+        // A guess never overrides something the customer said
+        let inferred = provenance(EntrySource::Inferred, 1.0, 0);
+        assert_eq!(
+            resolve_conflict(Some(&promoted), "Mumbai", &inferred),
+            FactResolution::Kept
+        );
+
+        // Same source but much less confident
+        let unsure = provenance(EntrySource::UserStated, 0.6, 0);
+        assert_eq!(
+            resolve_conflict(Some(&promoted), "Mumbai", &unsure),
+            FactResolution::Kept
+        );
+    }
+
+    #[test]
+    fn test_collect_filters_and_canonicalizes() {
+        let consolidator = MemoryConsolidator::default();
+        let mut human = HumanBlock::new();
+        human.set_name("Ravi");
+        human.set_fact("gold_weight", "40 grams", EntrySource::UserStated);
+        human.set_fact("mood", "curious", EntrySource::Inferred);
+
+        let turns = vec![
+            ConversationTurn::new(TurnRole::User, "I live in Pune")
+                .with_entities(vec![("city".to_string(), "Pune".to_string())]),
+            ConversationTurn::new(TurnRole::Assistant, "Noted")
+                .with_entities(vec![("city".to_string(), "Delhi".to_string())]),
+        ];
+
+        let canonical = |key: &str| match key {
+            "gold_weight" => "asset_quantity".to_string(),
+            other => other.to_string(),
+        };
+        let candidates = consolidator.collect("s1", &human, &turns, &canonical);
+        let keys: Vec<&str> = candidates.iter().map(|c| c.key.as_str()).collect();
+        assert_eq!(keys, vec!["asset_quantity", "city", "name"]);
+
+        let city = candidates.iter().find(|c| c.key == "city").unwrap();
+        assert_eq!(city.value, "Pune");
+        assert_eq!(city.provenance.source, EntrySource::Inferred);
+    }
+
+    #[test]
+    fn test_merge_keeps_history() {
+        let consolidator = MemoryConsolidator::default();
+        let mut existing = HashMap::new();
+        existing.insert(
+            "city".to_string(),
+            PromotedFact::new("city", "Pune", provenance(EntrySource::UserStated, 0.9, 10)),
+        );
+        existing.insert(
+            "name".to_string(),
+            PromotedFact::new("name", "Ravi", provenance(EntrySource::UserStated, 1.0, 10)),
+        );
+
+        let candidates = vec![
+            FactCandidate {
+                key: "city".to_string(),
+                value: "Mumbai".to_string(),
+                provenance: provenance(EntrySource::UserStated, 1.0, 0),
+            },
+            FactCandidate {
+                key: "name".to_string(),
+                value: "Ravi".to_string(),
+                provenance: provenance(EntrySource::UserStated, 0.8, 0),
+            },
+            FactCandidate {
+                key: "asset_quantity".to_string(),
+                value: "40 grams".to_string(),
+                provenance: provenance(EntrySource::UserStated, 1.0, 0),
+            },
+        ];
+
+        let (changed, report) = consolidator.merge(&existing, candidates);
+        assert_eq!(
+            report,
+            ConsolidationReport {
+                added: 1,
+                confirmed: 1,
+                replaced: 1,
+                kept: 0
+            }
+        );
+
+        let city = changed.iter().find(|f| f.key == "city").unwrap();
+        assert_eq!(city.value, "Mumbai");
+        assert_eq!(city.superseded[0].value, "Pune");
+
+        // Confirmation refreshes provenance but keeps the higher confidence
+        let name = changed.iter().find(|f| f.key == "name").unwrap();
+        assert_eq!(name.provenance.confidence, 1.0);
+        assert_eq!(name.provenance.session_id, "s-0");
+    }
+
+    #[test]
+    fn test_fact_note_roundtrip() {
+        let fact = PromotedFact::new("city", "Pune", provenance(EntrySource::External, 0.8, 1));
+        let note = fact.to_note("9876543210");
+        assert_eq!(note.session_id, "customer:9876543210");
+        assert_eq!(note.source, MemorySource::External);
+        assert!(note.tags.contains(&PROMOTED_FACT_TAG.to_string()));
+
+        let json = serde_json::to_string(&note).unwrap();
+        let restored: MemoryNote = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.fact, Some(fact));
+    }
+}
@@ -442,6 +442,28 @@ impl CoreMemory {
         Ok(())
     }
 
+    /// Restore a fact carried over from an earlier session
+    ///
+    /// Keeps the entry's source and confidence instead of marking it as
+    /// user-stated.
+    pub fn restore_fact(&self, entry: MemoryBlockEntry) -> Result<(), CoreMemoryError> {
+        let mut human = self.human.write();
+
+        let new_size = human.char_count() + entry.key.len() + entry.value.len();
+        if new_size > self.config.human_block_limit {
+            return Err(CoreMemoryError::BlockSizeLimitExceeded {
+                block: "human".to_string(),
+                limit: self.config.human_block_limit,
+                requested: new_size,
+            });
+        }
+
+        // set_fact keeps the char count in sync; then keep the original provenance
+        human.set_fact(entry.key.clone(), entry.value.clone(), entry.source);
+        human.facts.insert(entry.key.clone(), entry);
+        Ok(())
+    }
+
     /// Replace in human block
     ///
     /// MemGPT function: core_memory_replace
@@ -31,19 +31,41 @@
 //! - `archival_memory_insert`: Store in long-term memory
 //! - `archival_memory_search`: Search long-term memory
 //! - `conversation_search`: Search conversation history
+//!
+//! ## Token Budget
+//!
+//! Adding a turn past `high_watermark_tokens` evicts the oldest FIFO turns
+//! to recall storage and folds them into a hierarchical running summary
+//! (see `compaction`) until the context is back under
+//! `low_watermark_tokens`. `get_context()` never exceeds
+//! `max_context_tokens`.
+//!
+//! ## Cross-Session Memory
+//!
+//! At session end durable customer facts are consolidated into a
+//! customer-scoped archival namespace (see `consolidation`) and loaded back
+//! into core memory when the customer calls again.
 
 pub mod archival;
+pub mod compaction;
 pub mod compressor;
+pub mod consolidation;
 pub mod core;
 pub mod recall;
+pub mod vector_backend;
 
 pub use archival::{
     ArchivalMemory, ArchivalMemoryConfig, ArchivalSearchResult, MemoryNote, MemorySource,
     MemoryType,
 };
+pub use compaction::{SummaryHierarchy, SummarySegment};
 pub use compressor::{
     ExtractiveCompressor, ExtractiveCompressorConfig, ExtractionStats, ScoredSentence,
 };
+pub use consolidation::{
+    customer_namespace, ConsolidationConfig, ConsolidationReport, FactProvenance,
+    MemoryConsolidator, PromotedFact,
+};
 pub use core::{
     CoreMemory, CoreMemoryConfig, CoreMemoryError, EntrySource, HumanBlock, MemoryBlockEntry,
     PersonaBlock,
@@ -51,12 +73,18 @@ pub use core::{
 pub use recall::{
     ConversationTurn, RecallMemory, RecallMemoryConfig, RecallSearchResult, TurnRole,
 };
+pub use vector_backend::{
+    ArchivalVectorBackend, InMemoryArchivalBackend, QdrantArchivalBackend, ScyllaArchivalBackend,
+};
 
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use uuid::Uuid;
-use voice_agent_core::{GenerateRequest, LanguageModel};
+use voice_agent_core::{GenerateRequest, LanguageModel, LlmTask};
+
+use consolidation::{LANGUAGE_FACT_KEY, NAME_FACT_KEY};
+use crate::AgentError;
 
 /// Unified memory configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -73,8 +101,14 @@ pub struct AgenticMemoryConfig {
     pub high_watermark_tokens: usize,
     /// Low watermark target after compaction
     pub low_watermark_tokens: usize,
-    /// Enable automatic summarization
+    /// Enable automatic compaction when a turn crosses the high watermark
     pub auto_summarize: bool,
+    /// Token budget for the running summary of evicted turns
+    #[serde(default = "default_summary_budget_tokens")]
+    pub summary_budget_tokens: usize,
+    /// Recent turns never evicted from the context window
+    #[serde(default = "default_min_fifo_turns")]
+    pub min_fifo_turns: usize,
     /// Use extractive compression instead of LLM summarization
     /// Recommended for small models (< 3B parameters)
     #[serde(default)]
@@ -82,6 +116,17 @@ pub struct AgenticMemoryConfig {
     /// Extractive compressor configuration (RECOMP-style)
     #[serde(default)]
     pub extractive: ExtractiveCompressorConfig,
+    /// Cross-session fact consolidation
+    #[serde(default)]
+    pub consolidation: ConsolidationConfig,
+}
+
+fn default_summary_budget_tokens() -> usize {
+    256
+}
+
+fn default_min_fifo_turns() -> usize {
+    2
 }
 
 impl Default for AgenticMemoryConfig {
@@ -94,8 +139,11 @@ impl Default for AgenticMemoryConfig {
             high_watermark_tokens: 3072,
             low_watermark_tokens: 2048,
             auto_summarize: true,
+            summary_budget_tokens: default_summary_budget_tokens(),
+            min_fifo_turns: default_min_fifo_turns(),
             use_extractive_compression: false, // Default to LLM, enable for small models
             extractive: ExtractiveCompressorConfig::default(),
+            consolidation: ConsolidationConfig::default(),
         }
     }
 }
@@ -111,6 +159,8 @@ pub struct MemoryStats {
     pub recall_total_tokens: usize,
     /// Archival memory count
     pub archival_count: usize,
+    /// Running summary tokens
+    pub summary_tokens: usize,
     /// Total estimated context tokens
     pub total_context_tokens: usize,
     /// Whether above high watermark
@@ -236,6 +286,10 @@ pub struct AgenticMemory {
     /// P19 FIX: Config-driven slot display labels (e.g., "gold_weight" -> "Gold Weight")
     /// Loaded from domain config, empty if no config provided
     slot_display_labels: std::collections::HashMap<String, String>,
+    /// Running summary of turns evicted from the context window
+    summaries: RwLock<SummaryHierarchy>,
+    /// Set while an async compaction is running
+    compacting: std::sync::atomic::AtomicBool,
 }
 
 impl AgenticMemory {
@@ -257,6 +311,8 @@ impl AgenticMemory {
             competitor_names: Vec::new(),
             // P19 FIX: Empty by default - use from_view() for config-driven display labels
             slot_display_labels: std::collections::HashMap::new(),
+            summaries: RwLock::new(SummaryHierarchy::new()),
+            compacting: std::sync::atomic::AtomicBool::new(false),
         }
     }
 
@@ -294,6 +350,8 @@ impl AgenticMemory {
             llm: RwLock::new(None),
             competitor_names,
             slot_display_labels,
+            summaries: RwLock::new(SummaryHierarchy::new()),
+            compacting: std::sync::atomic::AtomicBool::new(false),
         }
     }
 
@@ -372,19 +430,28 @@ impl AgenticMemory {
 
     /// Add a user turn
     pub fn add_user_turn(&self, content: &str) -> u64 {
-        let turn = ConversationTurn::new(TurnRole::User, content);
-        self.recall.add_turn(turn)
+        self.add_turn(ConversationTurn::new(TurnRole::User, content))
     }
 
     /// Add an assistant turn
     pub fn add_assistant_turn(&self, content: &str) -> u64 {
-        let turn = ConversationTurn::new(TurnRole::Assistant, content);
-        self.recall.add_turn(turn)
+        self.add_turn(ConversationTurn::new(TurnRole::Assistant, content))
+    }
+
+    /// Replace the most recent assistant turn (e.g. after barge-in)
+    pub fn amend_last_assistant_turn(&self, content: &str) -> bool {
+        self.recall.amend_last_assistant_turn(content)
     }
 
     /// Add a turn with metadata
+    ///
+    /// Enforces the token budget when `auto_summarize` is enabled.
     pub fn add_turn(&self, turn: ConversationTurn) -> u64 {
-        self.recall.add_turn(turn)
+        let id = self.recall.add_turn(turn);
+        if self.config.auto_summarize {
+            self.enforce_token_budget();
+        }
+        id
     }
 
     /// Get recent conversation (FIFO)
@@ -405,23 +472,56 @@ impl AgenticMemory {
     ///
     /// Returns the complete context including:
     /// 1. Core memory (persona + human blocks)
-    /// 2. FIFO recent turns
+    /// 2. Summary of turns evicted from the window
+    /// 3. FIFO recent turns
+    ///
+    /// Never exceeds `max_context_tokens`: the oldest turns are dropped
+    /// first, then the summary, and core memory is truncated last.
     pub fn get_context(&self) -> String {
-        let mut context = String::new();
-
-        // Core memory (always included)
-        context.push_str(&self.core.format_for_context());
-        context.push('\n');
+        let max_tokens = self.config.max_context_tokens;
+        let core = self.core.format_for_context();
+        let summary = self.summaries.read().format_for_context();
+        let fifo: Vec<String> = self
+            .recall
+            .get_fifo()
+            .iter()
+            .map(|t| t.format_for_context())
+            .collect();
 
-        // Recent conversation (FIFO)
-        let fifo_context = self.recall.format_fifo_for_context();
-        if !fifo_context.is_empty() {
-            context.push_str("## Recent Conversation\n");
-            context.push_str(&fifo_context);
+        let assemble = |summary: &str, turns: &[String]| {
+            let mut context = String::new();
+            context.push_str(&core);
             context.push('\n');
+            if !summary.is_empty() {
+                context.push_str("## Earlier Conversation\n");
+                context.push_str(summary);
+                context.push_str("\n\n");
+            }
+            if !turns.is_empty() {
+                context.push_str("## Recent Conversation\n");
+                context.push_str(&turns.join("\n"));
+                context.push('\n');
+            }
+            context
+        };
+
+        let mut start = 0;
+        loop {
+            let context = assemble(&summary, &fifo[start..]);
+            if recall::estimate_tokens(&context) <= max_tokens {
+                return context;
+            }
+            if start == fifo.len() {
+                break;
+            }
+            start += 1;
         }
 
-        context
+        let context = assemble("", &[]);
+        if recall::estimate_tokens(&context) <= max_tokens {
+            return context;
+        }
+        compaction::fit_to_budget(&context, max_tokens)
     }
 
     /// Get context with RAG results
@@ -502,14 +602,16 @@ impl AgenticMemory {
         let fifo_tokens = self.recall.fifo_tokens();
         let recall_total_tokens = self.recall.total_tokens();
         let archival_count = self.archival.len();
+        let summary_tokens = self.summaries.read().total_tokens();
 
-        let total_context_tokens = core_tokens + fifo_tokens;
+        let total_context_tokens = core_tokens + summary_tokens + fifo_tokens;
 
         MemoryStats {
             core_tokens,
             fifo_tokens,
             recall_total_tokens,
             archival_count,
+            summary_tokens,
             total_context_tokens,
             above_high_watermark: total_context_tokens > self.config.high_watermark_tokens,
             above_max_limit: total_context_tokens > self.config.max_context_tokens,
@@ -517,21 +619,142 @@ impl AgenticMemory {
     }
 
     /// Check if memory needs compaction
+    ///
+    /// True when over the high watermark or when evicted turns are waiting
+    /// to be summarized by `compact()`.
     pub fn needs_compaction(&self) -> bool {
-        self.get_stats().above_high_watermark
+        self.get_stats().above_high_watermark || self.recall.has_pending_summarization()
+    }
+
+    /// Get the running summary of turns evicted from the context window
+    pub fn running_summary(&self) -> SummaryHierarchy {
+        self.summaries.read().clone()
+    }
+
+    /// Bring the context back under the low watermark if over the high one
+    ///
+    /// Evicts the oldest FIFO turns to recall storage and folds them into
+    /// the running summary with a quick (non-LLM) summary. Returns the
+    /// number of turns evicted.
+    pub fn enforce_token_budget(&self) -> usize {
+        if !self.get_stats().above_high_watermark {
+            return 0;
+        }
+
+        let mut evicted = Vec::new();
+        while self.get_stats().total_context_tokens > self.config.low_watermark_tokens {
+            match self.recall.evict_from_context(self.config.min_fifo_turns) {
+                Some(turn) => evicted.push(turn),
+                None => break,
+            }
+        }
+
+        if !evicted.is_empty() {
+            let summary = self.quick_summary(&evicted);
+            let mut summaries = self.summaries.write();
+            summaries.push(summary, evicted.len());
+            summaries.merge_if_over(self.config.summary_budget_tokens);
+        }
+
+        let stats = self.get_stats();
+        tracing::debug!(
+            evicted = evicted.len(),
+            total_context_tokens = stats.total_context_tokens,
+            summary_tokens = stats.summary_tokens,
+            "Enforced context token budget"
+        );
+        evicted.len()
+    }
+
+    /// Summarize turns without the LLM (used on the synchronous path)
+    fn quick_summary(&self, turns: &[ConversationTurn]) -> String {
+        if self.config.use_extractive_compression {
+            self.extractive_compressor.compress(turns, None).0
+        } else {
+            self.rule_based_summary(turns)
+        }
+    }
+
+    /// Re-summarize the running summary with the LLM
+    ///
+    /// Collapses all segments into one higher-level summary. Without an
+    /// LLM, segments are merged by rule while over budget.
+    async fn resummarize(&self) {
+        let (texts, level) = {
+            let summaries = self.summaries.read();
+            if summaries.len() < 2 && summaries.total_tokens() <= self.config.summary_budget_tokens {
+                return;
+            }
+            let texts: Vec<String> = summaries.segments().iter().map(|s| s.text.clone()).collect();
+            (texts, summaries.max_level())
+        };
+
+        let llm = self.llm.read().clone();
+        let Some(llm) = llm.filter(|_| !self.config.use_extractive_compression) else {
+            self.summaries.write().merge_if_over(self.config.summary_budget_tokens);
+            return;
+        };
+
+        let prompt = format!(
+            r#"Merge these conversation summaries into one concise summary.
+Later summaries override earlier ones when they disagree.
+Keep key-value facts (e.g., "Name: Rahul, Amount: 5 lakh").
+
+Summaries:
+{}
+
+Merged Summary (max 100 words):"#,
+            texts.join("\n")
+        );
+        let request = GenerateRequest::new(
+            "You are a context compression assistant. Extract and preserve only essential information."
+        )
+        .with_user_message(prompt)
+        .with_task(LlmTask::Summarization);
+
+        match llm.generate(request).await {
+            Ok(response) => {
+                let merged = compaction::fit_to_budget(response.text.trim(), self.config.summary_budget_tokens);
+                let mut summaries = self.summaries.write();
+                // Only collapse if no eviction raced with the LLM call
+                if summaries.len() == texts.len() && summaries.max_level() == level {
+                    summaries.collapse(merged);
+                }
+            },
+            Err(e) => {
+                tracing::warn!("LLM re-summarization failed: {}", e);
+                self.summaries.write().merge_if_over(self.config.summary_budget_tokens);
+            },
+        }
     }
 
     /// Perform memory compaction
     ///
     /// This:
-    /// 1. Summarizes pending recall turns
-    /// 2. Moves summaries to archival storage
-    /// 3. Cleans up low-confidence facts
+    /// 1. Enforces the context token budget
+    /// 2. Summarizes pending recall turns into archival storage
+    /// 3. Re-summarizes the running summary hierarchically
+    ///
+    /// Concurrent calls return immediately while one is running.
     pub async fn compact(&self) -> Result<(), String> {
+        use std::sync::atomic::Ordering;
+
+        if self.compacting.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        let result = self.compact_inner().await;
+        self.compacting.store(false, Ordering::Release);
+        result
+    }
+
+    async fn compact_inner(&self) -> Result<(), String> {
+        self.enforce_token_budget();
+
         // Get pending turns for summarization
         let pending = self.recall.get_pending_summarization();
 
         if pending.is_empty() {
+            self.resummarize().await;
             return Ok(());
         }
 
@@ -550,6 +773,7 @@ impl AgenticMemory {
             "Compacted conversation turns into summary"
         );
 
+        self.resummarize().await;
         Ok(())
     }
 
@@ -621,7 +845,9 @@ Compressed Summary (max 100 words):"#,
 
         let request = GenerateRequest::new(
             "You are a context compression assistant. Extract and preserve only essential information."
-        ).with_user_message(prompt);
+        )
+        .with_user_message(prompt)
+        .with_task(LlmTask::Summarization);
 
         match llm.generate(request).await {
             Ok(response) => Ok(response.text.trim().to_string()),
@@ -974,11 +1200,123 @@ Compressed Summary (max 100 words):"#,
         tracing::debug!("Compression level set to {:?}", level);
     }
 
+    // =========================================================================
+    // Cross-Session Memory
+    // =========================================================================
+
+    /// Promote durable facts from this session into the customer's namespace
+    ///
+    /// Call at session end. `canonical_key` maps slot names to fact keys
+    /// (e.g. through the domain's slot aliases). Conflicts with previously
+    /// promoted values are resolved by source, confidence and recency.
+    pub fn consolidate_to_customer(
+        &self,
+        customer_id: &str,
+        canonical_key: &dyn Fn(&str) -> String,
+    ) -> ConsolidationReport {
+        let consolidator = MemoryConsolidator::new(self.config.consolidation.clone());
+        let namespace = customer_namespace(customer_id);
+
+        let mut note_ids = std::collections::HashMap::new();
+        let mut existing = std::collections::HashMap::new();
+        for note in self.archival.session_notes(&namespace) {
+            if let Some(fact) = note.fact {
+                note_ids.insert(fact.key.clone(), note.id);
+                existing.insert(fact.key.clone(), fact);
+            }
+        }
+
+        let candidates = consolidator.collect(
+            &self.session_id,
+            &self.core.human_snapshot(),
+            &self.recall.get_all(),
+            canonical_key,
+        );
+        let (changed, report) = consolidator.merge(&existing, candidates);
+
+        for fact in changed {
+            let mut note = fact.to_note(customer_id);
+            if let Some(id) = note_ids.get(&fact.key) {
+                note.id = *id;
+            }
+            self.archival.upsert(note);
+        }
+
+        tracing::info!(
+            session_id = %self.session_id,
+            customer_id = %customer_id,
+            added = report.added,
+            confirmed = report.confirmed,
+            replaced = report.replaced,
+            kept = report.kept,
+            "Consolidated session facts into customer memory"
+        );
+        report
+    }
+
+    /// Load a returning customer's promoted facts into this session
+    ///
+    /// Hydrates the customer namespace from the archival backend and copies
+    /// the facts into core memory. Facts already known in this session are
+    /// not overwritten. Returns the number of facts restored.
+    pub async fn load_customer_memory(
+        &self,
+        customer_id: &str,
+        limit: usize,
+    ) -> Result<usize, AgentError> {
+        let namespace = customer_namespace(customer_id);
+        self.archival.hydrate(&namespace, limit).await?;
+
+        let human = self.core.human_snapshot();
+        let mut restored = 0;
+        for fact in self
+            .archival
+            .session_notes(&namespace)
+            .into_iter()
+            .filter_map(|note| note.fact)
+        {
+            match fact.key.as_str() {
+                NAME_FACT_KEY => {
+                    if human.name.is_none() {
+                        self.core.set_customer_name(&fact.value);
+                        restored += 1;
+                    }
+                },
+                LANGUAGE_FACT_KEY => {
+                    if human.preferred_language.is_none() {
+                        self.core.set_customer_language(&fact.value);
+                        restored += 1;
+                    }
+                },
+                key => {
+                    if human.get_fact(key).is_some() {
+                        continue;
+                    }
+                    match self.core.restore_fact(fact.to_entry()) {
+                        Ok(()) => restored += 1,
+                        Err(e) => {
+                            tracing::debug!(key = %key, error = %e, "Skipped restoring customer fact");
+                        },
+                    }
+                },
+            }
+        }
+
+        tracing::debug!(
+            session_id = %self.session_id,
+            customer_id = %customer_id,
+            restored,
+            "Loaded customer memory"
+        );
+        Ok(restored)
+    }
+
     /// Clear all memory for this session
     pub fn clear(&self) {
         self.core.clear_human_block();
         self.core.clear_persona_goals();
         self.recall.clear();
+        self.summaries.write().clear();
         self.archival.clear_session(&self.session_id);
     }
 
@@ -986,6 +1324,7 @@ Compressed Summary (max 100 words):"#,
     pub fn reset(&self) {
         self.core.reset();
         self.recall.clear();
+        self.summaries.write().clear();
         self.archival.clear_session(&self.session_id);
     }
 }
@@ -1244,4 +1583,117 @@ mod tests {
         assert!(weight.is_some());
         assert!(weight.unwrap().contains("50"));
     }
+
+    #[tokio::test]
+    async fn test_customer_memory_carries_over_sessions() {
+        let backend = Arc::new(InMemoryArchivalBackend::new());
+        let identity = |key: &str| key.to_string();
+
+        let first = AgenticMemory::with_session("call-1");
+        first.archival.set_backend(backend.clone());
+        first.core.set_customer_name("Ravi");
+        first.core_memory_append("city", "Pune").unwrap();
+        first.core_memory_append("mood", "curious").unwrap();
+
+        let report = first.consolidate_to_customer("9876543210", &identity);
+        assert_eq!(report.added, 2);
+        for _ in 0..100 {
+            if backend.len() == 2 {
+                break;
+            }
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(backend.len(), 2);
+
+        let second = AgenticMemory::with_session("call-2");
+        second.archival.set_backend(backend.clone());
+        let restored = second.load_customer_memory("9876543210", 100).await.unwrap();
+        assert_eq!(restored, 2);
+
+        let human = second.core.human_snapshot();
+        assert_eq!(human.name.as_deref(), Some("Ravi"));
+        let city = human.get_fact("city").unwrap();
+        assert_eq!(city.value, "Pune");
+        assert_eq!(city.source, EntrySource::UserStated);
+        assert!(human.get_fact("mood").is_none());
+
+        // A later correction replaces the value in place and keeps history
+        second.core_memory_replace("city", "Pune", "Mumbai").unwrap();
+        let report = second.consolidate_to_customer("9876543210", &identity);
+        assert_eq!(report.replaced, 1);
+        let city_note = second
+            .archival
+            .session_notes(&customer_namespace("9876543210"))
+            .into_iter()
+            .find_map(|n| n.fact.filter(|f| f.key == "city"))
+            .unwrap();
+        assert_eq!(city_note.value, "Mumbai");
+        assert_eq!(city_note.superseded[0].value, "Pune");
+        assert_eq!(city_note.provenance.session_id, "call-2");
+    }
+
+    #[test]
+    fn test_auto_compaction_enforces_token_budget() {
+        let config = AgenticMemoryConfig {
+            max_context_tokens: 400,
+            high_watermark_tokens: 350,
+            low_watermark_tokens: 250,
+            summary_budget_tokens: 40,
+            recall: RecallMemoryConfig {
+                fifo_size: 20,
+                summarization_threshold: 100,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let memory = AgenticMemory::new(config, "budget-session");
+
+        for i in 0..30 {
+            memory.add_user_turn(&format!(
+                "I have {} grams of gold and want to know the interest rate for my loan request",
+                i + 10
+            ));
+            memory.add_assistant_turn(&format!(
+                "For {} grams we can offer a competitive rate with quick processing and no hidden fees",
+                i + 10
+            ));
+            let context = memory.get_context();
+            assert!(
+                recall::estimate_tokens(&context) <= 400,
+                "Context over budget after turn {}",
+                i
+            );
+        }
+
+        let stats = memory.get_stats();
+        assert!(stats.total_context_tokens <= 350);
+        assert!(memory.get_recent_turns().len() >= 2);
+
+        // Evicted turns are summarized and remain searchable in recall
+        let summary = memory.running_summary();
+        assert!(!summary.is_empty());
+        assert!(summary.total_tokens() <= 40);
+        assert!(memory.get_context().contains("## Earlier Conversation"));
+        assert!(!memory.conversation_search("10 grams", Some(3)).is_empty());
+        assert!(memory.needs_compaction());
+    }
+
+    #[tokio::test]
+    async fn test_compact_drains_evicted_turns() {
+        let config = AgenticMemoryConfig {
+            max_context_tokens: 300,
+            high_watermark_tokens: 250,
+            low_watermark_tokens: 150,
+            ..Default::default()
+        };
+        let memory = AgenticMemory::new(config, "compact-session");
+        for i in 0..12 {
+            memory.add_user_turn(&format!("My name is Ravi and I need a loan of {} lakh rupees for my shop", i + 1));
+        }
+        assert!(memory.recall.has_pending_summarization());
+
+        memory.compact().await.unwrap();
+        assert!(!memory.recall.has_pending_summarization());
+        assert!(memory.archival.len() > 0);
+    }
 }
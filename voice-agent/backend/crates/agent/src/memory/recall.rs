@@ -163,6 +163,8 @@ pub struct RecallMemory {
     next_id: RwLock<u64>,
     /// Turns pending summarization
     pending_summarization: RwLock<Vec<ConversationTurn>>,
+    /// Turns with an ID at or below this were evicted from the context window
+    context_floor: RwLock<u64>,
 }
 
 impl RecallMemory {
@@ -173,6 +175,7 @@ impl RecallMemory {
             turns: RwLock::new(VecDeque::new()),
             next_id: RwLock::new(1),
             pending_summarization: RwLock::new(Vec::new()),
+            context_floor: RwLock::new(0),
         }
     }
 
@@ -198,7 +201,7 @@ impl RecallMemory {
         // Enforce max size
         while turns.len() > self.config.max_turns {
             if let Some(old) = turns.pop_front() {
-                self.pending_summarization.write().push(old);
+                self.queue_for_summarization(old);
             }
         }
 
@@ -276,8 +279,24 @@ impl RecallMemory {
     /// Get recent FIFO turns (always included in context)
     pub fn get_fifo(&self) -> Vec<ConversationTurn> {
         let turns = self.turns.read();
-        let start = turns.len().saturating_sub(self.config.fifo_size);
-        turns.iter().skip(start).cloned().collect()
+        self.fifo_window(&turns).cloned().collect()
+    }
+
+    /// Evict the oldest FIFO turn from the context window
+    ///
+    /// The turn stays searchable in recall and is queued for summarization.
+    /// At least `keep` recent turns are always left in the window.
+    pub fn evict_from_context(&self, keep: usize) -> Option<ConversationTurn> {
+        let turns = self.turns.read();
+        let window: Vec<&ConversationTurn> = self.fifo_window(&turns).collect();
+        if window.len() <= keep {
+            return None;
+        }
+
+        let oldest = window[0].clone();
+        *self.context_floor.write() = oldest.id;
+        self.pending_summarization.write().push(oldest.clone());
+        Some(oldest)
     }
 
     /// Get all turns
@@ -285,6 +304,27 @@ impl RecallMemory {
         self.turns.read().iter().cloned().collect()
     }
 
+    /// Replace the content of the most recent assistant turn
+    ///
+    /// Used when barge-in cut a response short, so history only keeps what
+    /// the caller heard. Returns false if there is no assistant turn.
+    pub fn amend_last_assistant_turn(&self, content: &str) -> bool {
+        let mut turns = self.turns.write();
+        match turns
+            .iter_mut()
+            .rev()
+            .find(|t| t.role == TurnRole::Assistant)
+        {
+            Some(turn) => {
+                turn.content = content.to_string();
+                turn.estimated_tokens = estimate_tokens(content);
+                turn.embedding = None;
+                true
+            },
+            None => false,
+        }
+    }
+
     /// Get turn by ID
     pub fn get_turn(&self, id: u64) -> Option<ConversationTurn> {
         self.turns.read().iter().find(|t| t.id == id).cloned()
@@ -328,8 +368,7 @@ impl RecallMemory {
     /// Get FIFO token count
     pub fn fifo_tokens(&self) -> usize {
         let turns = self.turns.read();
-        let start = turns.len().saturating_sub(self.config.fifo_size);
-        turns.iter().skip(start).map(|t| t.estimated_tokens).sum()
+        self.fifo_window(&turns).map(|t| t.estimated_tokens).sum()
     }
 
     /// Format FIFO for LLM context
@@ -346,12 +385,30 @@ impl RecallMemory {
         self.turns.write().clear();
         self.pending_summarization.write().clear();
         *self.next_id.write() = 1;
+        *self.context_floor.write() = 0;
     }
 
     // =========================================================================
     // Private Helpers
     // =========================================================================
 
+    /// Last `fifo_size` turns that were not evicted from the context window
+    fn fifo_window<'a>(
+        &self,
+        turns: &'a VecDeque<ConversationTurn>,
+    ) -> impl Iterator<Item = &'a ConversationTurn> {
+        let floor = *self.context_floor.read();
+        let start = turns.len().saturating_sub(self.config.fifo_size);
+        turns.iter().skip(start).filter(move |t| t.id > floor)
+    }
+
+    /// Queue a dropped turn for summarization unless eviction already did
+    fn queue_for_summarization(&self, turn: ConversationTurn) {
+        if turn.id > *self.context_floor.read() {
+            self.pending_summarization.write().push(turn);
+        }
+    }
+
     /// Collect old turns for summarization
     fn collect_for_summarization(&self, turns: &mut VecDeque<ConversationTurn>) {
         let to_summarize = self.config.summarization_threshold - self.config.fifo_size;
@@ -360,10 +417,9 @@ impl RecallMemory {
             return;
         }
 
-        let mut pending = self.pending_summarization.write();
         for _ in 0..to_summarize.min(turns.len() - self.config.fifo_size) {
             if let Some(turn) = turns.pop_front() {
-                pending.push(turn);
+                self.queue_for_summarization(turn);
             }
         }
     }
@@ -376,7 +432,7 @@ impl Default for RecallMemory {
 }
 
 /// Estimate tokens for text (simple 4-chars-per-token estimate)
-fn estimate_tokens(text: &str) -> usize {
+pub(crate) fn estimate_tokens(text: &str) -> usize {
     use unicode_segmentation::UnicodeSegmentation;
 
     let grapheme_count = text.graphemes(true).count();
@@ -548,4 +604,57 @@ mod tests {
         let hindi_tokens = estimate_tokens(hindi);
         assert!(hindi_tokens > 0);
     }
+
+    #[test]
+    fn test_evict_from_context() {
+        let config = RecallMemoryConfig {
+            fifo_size: 4,
+            ..Default::default()
+        };
+        let recall = RecallMemory::new(config);
+        for i in 0..4 {
+            recall.add_turn(ConversationTurn::new(TurnRole::User, format!("Message {}", i)));
+        }
+
+        let evicted = recall.evict_from_context(2).unwrap();
+        assert!(evicted.content.contains("0"));
+        recall.evict_from_context(2).unwrap();
+        assert!(recall.evict_from_context(2).is_none());
+        assert_eq!(recall.get_fifo().len(), 2);
+
+        // Evicted turns stay searchable and are queued once
+        assert!(!recall.search("Message 0", Some(5)).is_empty());
+        assert_eq!(recall.get_pending_summarization().len(), 2);
+
+        // New turns refill the window up to fifo_size
+        for i in 4..8 {
+            recall.add_turn(ConversationTurn::new(TurnRole::User, format!("Message {}", i)));
+        }
+        assert_eq!(recall.get_fifo().len(), 4);
+    }
+
+    #[test]
+    fn test_amend_last_assistant_turn() {
+        let recall = RecallMemory::new(RecallMemoryConfig::default());
+        assert!(!recall.amend_last_assistant_turn("nothing yet"));
+
+        recall.add_turn(ConversationTurn::new(
+            TurnRole::Assistant,
+            "Namaste, how can I help?",
+        ));
+        recall.add_turn(ConversationTurn::new(TurnRole::User, "Gold loan rate?"));
+        recall.add_turn(ConversationTurn::new(
+            TurnRole::Assistant,
+            "Our rate starts at 9.5% and processing is free for balance transfers",
+        ));
+
+        assert!(recall.amend_last_assistant_turn("Our rate starts at 9.5%"));
+        let turns = recall.get_all();
+        assert_eq!(turns[2].content, "Our rate starts at 9.5%");
+        assert_eq!(
+            turns[2].estimated_tokens,
+            estimate_tokens("Our rate starts at 9.5%")
+        );
+        assert_eq!(turns[0].content, "Namaste, how can I help?");
+    }
 }
@@ -0,0 +1,484 @@
+//! Archival Memory Vector Backends
+//!
+//! Durable storage and nearest-neighbour search for archival memory notes.
+//! `ArchivalMemory` keeps a working set in process and writes through to a
+//! backend so notes survive restarts:
+//!
+//! - `InMemoryArchivalBackend`: process-wide store shared across sessions
+//!   (lost on restart)
+//! - `QdrantArchivalBackend`: Qdrant collection with native ANN search
+//! - `ScyllaArchivalBackend`: ScyllaDB table partitioned by session,
+//!   brute-force cosine scan within the session
+//!   (fallback for deployments without Qdrant)
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use voice_agent_persistence::{ArchivalRecord, ArchivalStore};
+use voice_agent_rag::vector_store::{Document, SearchFilter};
+use voice_agent_rag::{cosine_similarity, VectorStore};
+
+use super::archival::MemoryNote;
+use crate::AgentError;
+
+/// Payload key holding the serialized note
+const NOTE_PAYLOAD_KEY: &str = "note_json";
+/// Payload key holding the note's session ID (used for filtering)
+const SESSION_PAYLOAD_KEY: &str = "session_id";
+
+/// Vector backend for archival memory
+#[async_trait]
+pub trait ArchivalVectorBackend: Send + Sync {
+    /// Backend name for logging
+    fn name(&self) -> &'static str;
+
+    /// Whether notes need an embedding to be stored
+    ///
+    /// Notes without an embedding are not written to backends returning
+    /// `true`; others receive an empty slice.
+    fn requires_embeddings(&self) -> bool {
+        true
+    }
+
+    /// Insert or overwrite a note with its embedding
+    async fn upsert(&self, note: &MemoryNote, embedding: &[f32]) -> Result<(), AgentError>;
+
+    /// Nearest-neighbour search, optionally restricted to one session
+    ///
+    /// Returns notes with their cosine similarity, best first.
+    async fn search(
+        &self,
+        embedding: &[f32],
+        top_k: usize,
+        session_id: Option<&str>,
+    ) -> Result<Vec<(MemoryNote, f32)>, AgentError>;
+
+    /// Delete a note
+    async fn delete(&self, note: &MemoryNote) -> Result<(), AgentError>;
+
+    /// Load stored notes for a session (used to resume after restart)
+    ///
+    /// Notes may come back without embeddings; callers re-embed as needed.
+    async fn load(&self, session_id: &str, limit: usize) -> Result<Vec<MemoryNote>, AgentError>;
+}
+
+fn memory_err(e: impl std::fmt::Display) -> AgentError {
+    AgentError::Memory(e.to_string())
+}
+
+/// In-process archival storage shared across sessions
+///
+/// Lets customer-scoped memories carry over between calls without an
+/// external database. Contents are lost on restart; the oldest notes are
+/// dropped beyond `max_notes`.
+pub struct InMemoryArchivalBackend {
+    notes: parking_lot::RwLock<Vec<MemoryNote>>,
+    max_notes: usize,
+}
+
+impl InMemoryArchivalBackend {
+    /// Default number of notes kept
+    pub const DEFAULT_MAX_NOTES: usize = 50_000;
+
+    pub fn new() -> Self {
+        Self {
+            notes: parking_lot::RwLock::new(Vec::new()),
+            max_notes: Self::DEFAULT_MAX_NOTES,
+        }
+    }
+
+    /// Set the maximum number of notes kept
+    pub fn with_max_notes(mut self, max_notes: usize) -> Self {
+        self.max_notes = max_notes;
+        self
+    }
+
+    /// Number of stored notes
+    pub fn len(&self) -> usize {
+        self.notes.read().len()
+    }
+
+    /// Check if empty
+    pub fn is_empty(&self) -> bool {
+        self.notes.read().is_empty()
+    }
+}
+
+impl Default for InMemoryArchivalBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ArchivalVectorBackend for InMemoryArchivalBackend {
+    fn name(&self) -> &'static str {
+        "in_memory"
+    }
+
+    fn requires_embeddings(&self) -> bool {
+        false
+    }
+
+    async fn upsert(&self, note: &MemoryNote, embedding: &[f32]) -> Result<(), AgentError> {
+        let mut stored = note.clone();
+        stored.embedding = (!embedding.is_empty()).then(|| embedding.to_vec());
+
+        let mut notes = self.notes.write();
+        match notes.iter_mut().find(|n| n.id == note.id) {
+            Some(existing) => *existing = stored,
+            None => notes.push(stored),
+        }
+        if notes.len() > self.max_notes {
+            let excess = notes.len() - self.max_notes;
+            notes.drain(..excess);
+        }
+        Ok(())
+    }
+
+    async fn search(
+        &self,
+        embedding: &[f32],
+        top_k: usize,
+        session_id: Option<&str>,
+    ) -> Result<Vec<(MemoryNote, f32)>, AgentError> {
+        let notes = self.notes.read();
+        let mut scored: Vec<(MemoryNote, f32)> = notes
+            .iter()
+            .filter(|n| session_id.is_none() || session_id == Some(n.session_id.as_str()))
+            .filter_map(|n| {
+                let score = cosine_similarity(embedding, n.embedding.as_deref()?);
+                Some((n.clone(), score))
+            })
+            .collect();
+
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        scored.truncate(top_k);
+        Ok(scored)
+    }
+
+    async fn delete(&self, note: &MemoryNote) -> Result<(), AgentError> {
+        self.notes.write().retain(|n| n.id != note.id);
+        Ok(())
+    }
+
+    async fn load(&self, session_id: &str, limit: usize) -> Result<Vec<MemoryNote>, AgentError> {
+        Ok(self
+            .notes
+            .read()
+            .iter()
+            .filter(|n| n.session_id == session_id)
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+}
+
+/// Qdrant-backed archival storage
+///
+/// The full note is stored as JSON in the point payload so search results
+/// can be returned without a second lookup.
+pub struct QdrantArchivalBackend {
+    store: Arc<VectorStore>,
+}
+
+impl QdrantArchivalBackend {
+    /// Wrap a vector store whose collection is dedicated to archival memory
+    pub fn new(store: Arc<VectorStore>) -> Self {
+        Self { store }
+    }
+
+    fn parse_note(metadata: &HashMap<String, String>) -> Option<MemoryNote> {
+        let json = metadata.get(NOTE_PAYLOAD_KEY)?;
+        match serde_json::from_str(json) {
+            Ok(note) => Some(note),
+            Err(e) => {
+                tracing::warn!(error = %e, "Skipping unreadable archival note in Qdrant");
+                None
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl ArchivalVectorBackend for QdrantArchivalBackend {
+    fn name(&self) -> &'static str {
+        "qdrant"
+    }
+
+    async fn upsert(&self, note: &MemoryNote, embedding: &[f32]) -> Result<(), AgentError> {
+        let mut metadata = HashMap::new();
+        metadata.insert(
+            NOTE_PAYLOAD_KEY.to_string(),
+            serde_json::to_string(note).map_err(memory_err)?,
+        );
+        metadata.insert(SESSION_PAYLOAD_KEY.to_string(), note.session_id.clone());
+
+        let document = Document {
+            id: note.id.to_string(),
+            content: note.text_for_embedding(),
+            title: None,
+            category: Some(format!("{:?}", note.memory_type)),
+            language: None,
+            metadata,
+        };
+
+        self.store
+            .upsert(&[document], &[embedding.to_vec()])
+            .await
+            .map_err(memory_err)
+    }
+
+    async fn search(
+        &self,
+        embedding: &[f32],
+        top_k: usize,
+        session_id: Option<&str>,
+    ) -> Result<Vec<(MemoryNote, f32)>, AgentError> {
+        let filter = session_id.map(|s| SearchFilter::new().metadata(SESSION_PAYLOAD_KEY, s));
+        let results = self
+            .store
+            .search(embedding, top_k, filter)
+            .await
+            .map_err(memory_err)?;
+
+        Ok(results
+            .into_iter()
+            .filter_map(|r| Self::parse_note(&r.metadata).map(|note| (note, r.score)))
+            .collect())
+    }
+
+    async fn delete(&self, note: &MemoryNote) -> Result<(), AgentError> {
+        self.store
+            .delete(&[note.id.to_string()])
+            .await
+            .map_err(memory_err)
+    }
+
+    async fn load(&self, session_id: &str, limit: usize) -> Result<Vec<MemoryNote>, AgentError> {
+        let filter = SearchFilter::new().metadata(SESSION_PAYLOAD_KEY, session_id);
+        let results = self
+            .store
+            .scroll(Some(filter), limit)
+            .await
+            .map_err(memory_err)?;
+
+        Ok(results
+            .iter()
+            .filter_map(|r| Self::parse_note(&r.metadata))
+            .collect())
+    }
+}
+
+/// ScyllaDB-backed archival storage
+///
+/// Embeddings are stored alongside the note, partitioned by session; search
+/// scans up to `scan_limit` of the session's rows and ranks them by cosine
+/// similarity. Searching across sessions needs Qdrant.
+pub struct ScyllaArchivalBackend {
+    store: Arc<dyn ArchivalStore>,
+    collection: String,
+    scan_limit: usize,
+}
+
+impl ScyllaArchivalBackend {
+    /// Default number of rows scanned per search
+    pub const DEFAULT_SCAN_LIMIT: usize = 5000;
+
+    pub fn new(store: Arc<dyn ArchivalStore>, collection: impl Into<String>) -> Self {
+        Self {
+            store,
+            collection: collection.into(),
+            scan_limit: Self::DEFAULT_SCAN_LIMIT,
+        }
+    }
+
+    /// Set the maximum number of rows scanned per search
+    pub fn with_scan_limit(mut self, scan_limit: usize) -> Self {
+        self.scan_limit = scan_limit;
+        self
+    }
+
+    fn parse_record(record: ArchivalRecord) -> Option<MemoryNote> {
+        match serde_json::from_str::<MemoryNote>(&record.note_json) {
+            Ok(note) if record.embedding.is_empty() => Some(note),
+            Ok(note) => Some(note.with_embedding(record.embedding)),
+            Err(e) => {
+                tracing::warn!(id = %record.id, error = %e, "Skipping unreadable archival note in ScyllaDB");
+                None
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl ArchivalVectorBackend for ScyllaArchivalBackend {
+    fn name(&self) -> &'static str {
+        "scylla"
+    }
+
+    async fn upsert(&self, note: &MemoryNote, embedding: &[f32]) -> Result<(), AgentError> {
+        let record = ArchivalRecord {
+            id: note.id.to_string(),
+            session_id: note.session_id.clone(),
+            note_json: serde_json::to_string(note).map_err(memory_err)?,
+            embedding: embedding.to_vec(),
+            created_at: note.created_at,
+        };
+        self.store
+            .upsert(&self.collection, &record)
+            .await
+            .map_err(memory_err)
+    }
+
+    async fn search(
+        &self,
+        embedding: &[f32],
+        top_k: usize,
+        session_id: Option<&str>,
+    ) -> Result<Vec<(MemoryNote, f32)>, AgentError> {
+        let records = self
+            .store
+            .list(&self.collection, session_id, self.scan_limit)
+            .await
+            .map_err(memory_err)?;
+
+        let mut scored: Vec<(MemoryNote, f32)> = records
+            .into_iter()
+            .filter_map(Self::parse_record)
+            .filter_map(|note| {
+                let score = cosine_similarity(embedding, note.embedding.as_deref()?);
+                Some((note, score))
+            })
+            .collect();
+
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        scored.truncate(top_k);
+        Ok(scored)
+    }
+
+    async fn delete(&self, note: &MemoryNote) -> Result<(), AgentError> {
+        self.store
+            .delete(&self.collection, &note.session_id, &note.id.to_string())
+            .await
+            .map_err(memory_err)
+    }
+
+    async fn load(&self, session_id: &str, limit: usize) -> Result<Vec<MemoryNote>, AgentError> {
+        let records = self
+            .store
+            .list(&self.collection, Some(session_id), limit)
+            .await
+            .map_err(memory_err)?;
+
+        Ok(records.into_iter().filter_map(Self::parse_record).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::MemoryType;
+    use chrono::{DateTime, Utc};
+    use uuid::Uuid;
+    use voice_agent_persistence::PersistenceError;
+
+    /// In-memory stand-in for the Scylla table
+    #[derive(Default)]
+    struct MockArchivalStore {
+        records: parking_lot::Mutex<Vec<(String, ArchivalRecord)>>,
+    }
+
+    #[async_trait]
+    impl ArchivalStore for MockArchivalStore {
+        async fn upsert(
+            &self,
+            collection: &str,
+            record: &ArchivalRecord,
+        ) -> Result<(), PersistenceError> {
+            let mut records = self.records.lock();
+            records.retain(|(c, r)| !(c == collection && r.id == record.id));
+            records.push((collection.to_string(), record.clone()));
+            Ok(())
+        }
+
+        async fn delete(
+            &self,
+            collection: &str,
+            _session_id: &str,
+            id: &str,
+        ) -> Result<(), PersistenceError> {
+            self.records
+                .lock()
+                .retain(|(c, r)| !(c == collection && r.id == id));
+            Ok(())
+        }
+
+        async fn list(
+            &self,
+            collection: &str,
+            session_id: Option<&str>,
+            limit: usize,
+        ) -> Result<Vec<ArchivalRecord>, PersistenceError> {
+            Ok(self
+                .records
+                .lock()
+                .iter()
+                .filter(|(c, r)| {
+                    c == collection
+                        && (session_id.is_none() || session_id == Some(r.session_id.as_str()))
+                })
+                .map(|(_, r)| r.clone())
+                .take(limit)
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scylla_backend_roundtrip_and_search() {
+        let backend = ScyllaArchivalBackend::new(Arc::new(MockArchivalStore::default()), "test");
+
+        let gold = MemoryNote::new(
+            "s1",
+            "Customer has 40 grams of gold",
+            MemoryType::CustomerFact,
+        );
+        let rate = MemoryNote::new(
+            "s1",
+            "Customer asked about interest rate",
+            MemoryType::Event,
+        );
+        let other = MemoryNote::new("s2", "Other session note", MemoryType::Event);
+        backend.upsert(&gold, &[1.0, 0.0, 0.0]).await.unwrap();
+        backend.upsert(&rate, &[0.0, 1.0, 0.0]).await.unwrap();
+        backend.upsert(&other, &[1.0, 0.0, 0.0]).await.unwrap();
+
+        let results = backend
+            .search(&[0.9, 0.1, 0.0], 1, Some("s1"))
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0.id, gold.id);
+        assert!(results[0].1 > 0.9);
+
+        // Survives a "restart": notes reload with their embeddings
+        let loaded = backend.load("s1", 10).await.unwrap();
+        assert_eq!(loaded.len(), 2);
+        assert!(loaded.iter().all(|n| n.embedding.is_some()));
+
+        backend.delete(&gold).await.unwrap();
+        assert_eq!(backend.load("s1", 10).await.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_parse_record_rejects_bad_json() {
+        let record = ArchivalRecord {
+            id: Uuid::new_v4().to_string(),
+            session_id: "s1".to_string(),
+            note_json: "not json".to_string(),
+            embedding: vec![],
+            created_at: DateTime::<Utc>::from_timestamp_millis(0).unwrap(),
+        };
+        assert!(ScyllaArchivalBackend::parse_record(record).is_none());
+    }
+}
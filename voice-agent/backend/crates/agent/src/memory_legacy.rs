@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 
-use voice_agent_core::{GenerateRequest, LanguageModel, Turn, TurnRole};
+use voice_agent_core::{GenerateRequest, LanguageModel, LlmTask, Turn, TurnRole};
 
 // P2-3 FIX: Re-export MemoryConfig from config crate
 pub use voice_agent_config::MemoryConfig;
@@ -275,7 +275,8 @@ Summary:"#,
 
         // P1 FIX: Use GenerateRequest for LanguageModel trait
         let request = GenerateRequest::new("You are a helpful summarization assistant.")
-            .with_user_message(prompt);
+            .with_user_message(prompt)
+            .with_task(LlmTask::Summarization);
 
         // Call LLM for summarization
         match llm.generate(request).await {
@@ -388,6 +389,18 @@ Summary:"#,
         context
     }
 
+    /// Replace the most recent assistant entry still in working memory
+    pub fn amend_last_assistant(&self, content: &str) -> bool {
+        let mut working = self.working.write();
+        match working.iter_mut().rev().find(|e| e.role == "assistant") {
+            Some(entry) => {
+                entry.content = content.to_string();
+                true
+            },
+            None => false,
+        }
+    }
+
     /// Get recent conversation for LLM
     pub fn get_recent_messages(&self) -> Vec<(String, String)> {
         self.working
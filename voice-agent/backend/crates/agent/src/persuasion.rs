@@ -259,8 +259,16 @@ impl ObjectionDetector {
 
     /// Detect objection type from text
     ///
-    /// Returns the objection ID with highest pattern match score.
+    /// Patterns of every language are matched (Hindi words in English text
+    /// and vice versa). The objection with the most matching patterns wins;
+    /// ties go to matches in the caller's language, then to the longest
+    /// (most specific) matching pattern, so the result is deterministic.
     pub fn detect(&self, text: &str, language: Language) -> Option<String> {
+        self.classify(text, language).map(|(id, _)| id)
+    }
+
+    /// Detect the objection type with its match score (matching patterns)
+    pub fn classify(&self, text: &str, language: Language) -> Option<(String, usize)> {
         let lower = text.to_lowercase();
         let lang_key = match language {
             Language::English => "en",
@@ -268,31 +276,39 @@ impl ObjectionDetector {
             _ => "en",
         };
 
-        let mut best_match: Option<(String, usize)> = None;
-
+        // (matches, matches in caller's language, longest match, id)
+        let mut best: Option<(usize, usize, usize, &str)> = None;
         for (objection_id, lang_patterns) in &self.patterns {
-            // Check primary language
-            if let Some(patterns) = lang_patterns.get(lang_key) {
-                let matches = patterns.iter().filter(|p| lower.contains(&p.to_lowercase())).count();
-                if matches > 0 {
-                    if best_match.as_ref().map_or(true, |(_, count)| matches > *count) {
-                        best_match = Some((objection_id.clone(), matches));
+            let mut matches = 0;
+            let mut primary = 0;
+            let mut longest = 0;
+            for (lang, patterns) in lang_patterns {
+                for pattern in patterns {
+                    if lower.contains(&pattern.to_lowercase()) {
+                        matches += 1;
+                        primary += usize::from(lang == lang_key);
+                        longest = longest.max(pattern.chars().count());
                     }
                 }
             }
+            if matches == 0 {
+                continue;
+            }
 
-            // Also check all languages for cross-language detection (e.g., Hindi words in English text)
-            for (_, patterns) in lang_patterns {
-                let matches = patterns.iter().filter(|p| lower.contains(&p.to_lowercase())).count();
-                if matches > 0 {
-                    if best_match.as_ref().map_or(true, |(_, count)| matches > *count) {
-                        best_match = Some((objection_id.clone(), matches));
-                    }
-                }
+            let candidate = (matches, primary, longest, objection_id.as_str());
+            let better = match best {
+                None => true,
+                Some((m, p, l, id)) => (matches, primary, longest)
+                    .cmp(&(m, p, l))
+                    .then(id.cmp(objection_id))
+                    .is_gt(),
+            };
+            if better {
+                best = Some(candidate);
             }
         }
 
-        best_match.map(|(id, _)| id)
+        best.map(|(matches, _, _, id)| (id.to_string(), matches))
     }
 
     /// Get available objection types
@@ -678,6 +694,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_classifier_prefers_stronger_match() {
+        let objection = |patterns: &[&str]| {
+            let mut by_lang = HashMap::new();
+            by_lang.insert(
+                "en".to_string(),
+                patterns.iter().map(|p| p.to_string()).collect(),
+            );
+            by_lang
+        };
+        let mut patterns = HashMap::new();
+        patterns.insert("need_time".to_string(), objection(&["think", "later"]));
+        patterns.insert("trust_issues".to_string(), objection(&["trust"]));
+        patterns.insert("collateral_safety".to_string(), objection(&["safe"]));
+        patterns.insert("gold_security".to_string(), objection(&["my gold"]));
+        let detector = ObjectionDetector { patterns };
+
+        assert_eq!(
+            detector.classify("I trust you, but I'll think about it later", Language::English),
+            Some(("need_time".to_string(), 2))
+        );
+        // One match each: the longer pattern is more specific
+        assert_eq!(
+            detector.detect("is my gold safe", Language::English),
+            Some("gold_security".to_string())
+        );
+        assert_eq!(detector.detect("sounds good", Language::English), None);
+    }
+
     #[test]
     fn test_switch_savings_calculation() {
         let mut engine = PersuasionEngine::new();
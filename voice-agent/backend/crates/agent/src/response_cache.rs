@@ -0,0 +1,478 @@
+//! Response Cache
+//!
+//! Semantic cache for deterministic answers. Customers ask the same things
+//! over and over ("gold price today", "which documents", "branch timings");
+//! answering them again costs a tool call and an LLM round trip for an
+//! answer we already have.
+//!
+//! Two layers:
+//! - **Tool results**, keyed by tool name and arguments, for tools listed in
+//!   `tool_ttl_secs`. Other tools (lead capture, SMS, appointments) are never
+//!   cached.
+//! - **Answers**, keyed by language, intent, slot values and the question's
+//!   content words. Near-identical questions hit when their token overlap
+//!   reaches `similarity_threshold`. Answers that used personal context are
+//!   only reused within the same session; the rest are shared globally.
+//!
+//! When a tool's underlying data changes, `invalidate_tool` drops its cached
+//! results and every answer that was built from them.
+
+use parking_lot::RwLock;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use voice_agent_config::ResponseCacheConfig;
+
+/// Filler words ignored when comparing questions (English + Hinglish)
+const STOPWORDS: &[&str] = &[
+    "a", "an", "the", "is", "are", "am", "be", "what", "whats", "which", "please", "pls", "plz",
+    "tell", "me", "my", "i", "you", "your", "can", "could", "would", "will", "do", "does", "for",
+    "of", "to", "in", "on", "about", "and", "or", "it", "this", "that", "kya", "hai", "hain", "ka",
+    "ki", "ke", "ko", "mujhe", "batao", "bataiye", "aap", "ji", "hello", "hi", "ok", "okay",
+];
+
+/// Who may reuse a cached answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CacheScope {
+    /// Any session
+    Global,
+    /// Only the session that produced it
+    Session(String),
+}
+
+/// A question as seen by the cache
+#[derive(Debug, Clone)]
+pub struct CacheQuery<'a> {
+    /// Session asking the question
+    pub session_id: &'a str,
+    /// Language code of the answer
+    pub language: &'a str,
+    /// Detected intent
+    pub intent: &'a str,
+    /// Slot values the answer depends on, sorted by name
+    pub slots: Vec<(String, String)>,
+    /// Whether the answer may contain personal context (e.g. the customer's name)
+    pub personalized: bool,
+    /// Raw question text
+    pub question: &'a str,
+}
+
+impl CacheQuery<'_> {
+    fn scope(&self) -> CacheScope {
+        if self.personalized || !self.slots.is_empty() {
+            CacheScope::Session(self.session_id.to_string())
+        } else {
+            CacheScope::Global
+        }
+    }
+}
+
+/// A cached answer
+#[derive(Debug, Clone)]
+pub struct CacheHit {
+    /// Answer text, in the query's language
+    pub response: String,
+    /// Token overlap with the original question (1.0 = same content words)
+    pub similarity: f32,
+    /// Scope the answer was cached under
+    pub scope: CacheScope,
+}
+
+/// Cache counters
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ResponseCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub tool_hits: u64,
+    pub responses: usize,
+    pub tool_results: usize,
+}
+
+#[derive(Debug)]
+struct CachedResponse {
+    scope: CacheScope,
+    language: String,
+    intent: String,
+    slots: Vec<(String, String)>,
+    tokens: BTreeSet<String>,
+    response: String,
+    tool: Option<String>,
+    expires_at: Instant,
+}
+
+#[derive(Debug)]
+struct CachedToolResult {
+    tool: String,
+    result: String,
+    expires_at: Instant,
+}
+
+/// Semantic response and tool-result cache shared across sessions
+pub struct ResponseCache {
+    config: ResponseCacheConfig,
+    responses: RwLock<VecDeque<CachedResponse>>,
+    tool_results: RwLock<HashMap<String, CachedToolResult>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    tool_hits: AtomicU64,
+}
+
+impl ResponseCache {
+    /// Create a cache from config
+    pub fn new(config: ResponseCacheConfig) -> Self {
+        Self {
+            config,
+            responses: RwLock::new(VecDeque::new()),
+            tool_results: RwLock::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            tool_hits: AtomicU64::new(0),
+        }
+    }
+
+    /// Whether caching is enabled
+    pub fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    /// Whether results of this tool may be cached
+    pub fn is_cacheable_tool(&self, tool: &str) -> bool {
+        self.config.enabled && self.config.tool_ttl_secs.contains_key(tool)
+    }
+
+    fn tool_ttl(&self, tool: &str) -> Option<Duration> {
+        self.config
+            .tool_ttl_secs
+            .get(tool)
+            .map(|secs| Duration::from_secs(*secs))
+    }
+
+    fn tool_key(tool: &str, args: &serde_json::Value) -> String {
+        // serde_json objects are ordered by key, so equal arguments serialize equally
+        format!("{}:{}", tool, args)
+    }
+
+    /// Cached result of a tool call with these arguments
+    pub fn get_tool_result(&self, tool: &str, args: &serde_json::Value) -> Option<String> {
+        if !self.is_cacheable_tool(tool) {
+            return None;
+        }
+
+        let key = Self::tool_key(tool, args);
+        let results = self.tool_results.read();
+        let cached = results.get(&key)?;
+        if cached.expires_at <= Instant::now() {
+            return None;
+        }
+
+        self.tool_hits.fetch_add(1, Ordering::Relaxed);
+        Some(cached.result.clone())
+    }
+
+    /// Cache a tool result (ignored for tools without a configured TTL)
+    pub fn put_tool_result(&self, tool: &str, args: &serde_json::Value, result: &str) {
+        if !self.config.enabled {
+            return;
+        }
+        let Some(ttl) = self.tool_ttl(tool) else {
+            return;
+        };
+
+        let now = Instant::now();
+        let mut results = self.tool_results.write();
+        results.retain(|_, cached| cached.expires_at > now);
+        results.insert(
+            Self::tool_key(tool, args),
+            CachedToolResult {
+                tool: tool.to_string(),
+                result: result.to_string(),
+                expires_at: now + ttl,
+            },
+        );
+    }
+
+    /// Find a cached answer to the same or a near-identical question
+    pub fn lookup(&self, query: &CacheQuery<'_>) -> Option<CacheHit> {
+        if !self.config.enabled {
+            return None;
+        }
+
+        let tokens = question_tokens(query.question);
+        if tokens.is_empty() {
+            return None;
+        }
+
+        let now = Instant::now();
+        let responses = self.responses.read();
+        let best = responses
+            .iter()
+            .filter(|entry| entry.expires_at > now)
+            .filter(|entry| match entry.scope {
+                CacheScope::Global => true,
+                CacheScope::Session(ref id) => id == query.session_id,
+            })
+            .filter(|entry| {
+                entry.language == query.language
+                    && entry.intent == query.intent
+                    && entry.slots == query.slots
+            })
+            .map(|entry| (entry, jaccard(&entry.tokens, &tokens)))
+            .filter(|(_, similarity)| *similarity >= self.config.similarity_threshold)
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+
+        match best {
+            Some((entry, similarity)) => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Some(CacheHit {
+                    response: entry.response.clone(),
+                    similarity,
+                    scope: entry.scope.clone(),
+                })
+            },
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            },
+        }
+    }
+
+    /// Cache an answer if it is deterministic
+    ///
+    /// An answer is cacheable when it was built from a cacheable tool's
+    /// result, or when no tool ran and the intent is a configured FAQ intent.
+    /// Returns whether the answer was stored.
+    pub fn store(&self, query: &CacheQuery<'_>, response: &str, tool: Option<&str>) -> bool {
+        if !self.config.enabled || response.trim().is_empty() {
+            return false;
+        }
+
+        let cacheable = match tool {
+            Some(tool) => self.is_cacheable_tool(tool),
+            None => self.config.faq_intents.iter().any(|i| i == query.intent),
+        };
+        let tokens = question_tokens(query.question);
+        if !cacheable || tokens.is_empty() {
+            return false;
+        }
+
+        let scope = query.scope();
+        let mut ttl = Duration::from_secs(match scope {
+            CacheScope::Global => self.config.global_ttl_secs,
+            CacheScope::Session(_) => self.config.session_ttl_secs,
+        });
+        if let Some(tool_ttl) = tool.and_then(|t| self.tool_ttl(t)) {
+            ttl = ttl.min(tool_ttl);
+        }
+
+        let now = Instant::now();
+        let mut responses = self.responses.write();
+        responses.retain(|entry| {
+            entry.expires_at > now
+                && !(entry.scope == scope
+                    && entry.language == query.language
+                    && entry.intent == query.intent
+                    && entry.slots == query.slots
+                    && entry.tokens == tokens)
+        });
+        responses.push_back(CachedResponse {
+            scope,
+            language: query.language.to_string(),
+            intent: query.intent.to_string(),
+            slots: query.slots.clone(),
+            tokens,
+            response: response.to_string(),
+            tool: tool.map(str::to_string),
+            expires_at: now + ttl,
+        });
+        while responses.len() > self.config.max_entries {
+            responses.pop_front();
+        }
+
+        true
+    }
+
+    /// Drop a tool's cached results and every answer built from them
+    ///
+    /// Call when the tool's underlying data changes (price feed update,
+    /// branch list or document policy edits). Returns the entries removed.
+    pub fn invalidate_tool(&self, tool: &str) -> usize {
+        let mut removed = {
+            let mut results = self.tool_results.write();
+            let before = results.len();
+            results.retain(|_, cached| cached.tool != tool);
+            before - results.len()
+        };
+
+        let mut responses = self.responses.write();
+        let before = responses.len();
+        responses.retain(|entry| entry.tool.as_deref() != Some(tool));
+        removed += before - responses.len();
+
+        if removed > 0 {
+            tracing::info!(tool = %tool, removed, "Invalidated cached responses");
+        }
+        removed
+    }
+
+    /// Drop answers cached for one session
+    pub fn invalidate_session(&self, session_id: &str) {
+        self.responses.write().retain(
+            |entry| !matches!(entry.scope, CacheScope::Session(ref id) if id == session_id),
+        );
+    }
+
+    /// Drop everything
+    pub fn clear(&self) {
+        self.responses.write().clear();
+        self.tool_results.write().clear();
+    }
+
+    /// Current counters
+    pub fn stats(&self) -> ResponseCacheStats {
+        let responses = self.responses.read().len();
+        let tool_results = self.tool_results.read().len();
+        ResponseCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            tool_hits: self.tool_hits.load(Ordering::Relaxed),
+            responses,
+            tool_results,
+        }
+    }
+}
+
+/// Content words of a question, lowercased and without filler words
+pub fn question_tokens(text: &str) -> BTreeSet<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty() && !STOPWORDS.contains(word))
+        .map(str::to_string)
+        .collect()
+}
+
+fn jaccard(a: &BTreeSet<String>, b: &BTreeSet<String>) -> f32 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f32 / union as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache() -> ResponseCache {
+        let mut config = ResponseCacheConfig::default();
+        config.tool_ttl_secs.insert("get_price".to_string(), 300);
+        config.faq_intents.push("document_inquiry".to_string());
+        ResponseCache::new(config)
+    }
+
+    fn query<'a>(session_id: &'a str, intent: &'a str, question: &'a str) -> CacheQuery<'a> {
+        CacheQuery {
+            session_id,
+            language: "en",
+            intent,
+            slots: Vec::new(),
+            personalized: false,
+            question,
+        }
+    }
+
+    #[test]
+    fn test_near_identical_question_hits() {
+        let cache = cache();
+        let stored = cache.store(
+            &query("s1", "price_inquiry", "What is the gold price today?"),
+            "Gold is ₹7,200 per gram today.",
+            Some("get_price"),
+        );
+        assert!(stored);
+
+        let hit = cache
+            .lookup(&query("s2", "price_inquiry", "gold price today please"))
+            .expect("global answer should be shared");
+        assert_eq!(hit.response, "Gold is ₹7,200 per gram today.");
+        assert_eq!(hit.scope, CacheScope::Global);
+
+        assert!(cache
+            .lookup(&query("s2", "price_inquiry", "gold loan interest rate"))
+            .is_none());
+        assert_eq!(cache.stats().hits, 1);
+        assert_eq!(cache.stats().misses, 1);
+    }
+
+    #[test]
+    fn test_only_deterministic_answers_are_stored() {
+        let cache = cache();
+        assert!(cache.store(
+            &query("s1", "document_inquiry", "which documents do I need"),
+            "Bring your Aadhaar and PAN.",
+            None,
+        ));
+        assert!(!cache.store(
+            &query("s1", "eligibility_check", "am I eligible"),
+            "Yes, you are eligible.",
+            None,
+        ));
+        assert!(!cache.store(
+            &query("s1", "document_inquiry", "which documents do I need"),
+            "Lead captured.",
+            Some("capture_lead"),
+        ));
+    }
+
+    #[test]
+    fn test_personal_answers_stay_in_session() {
+        let cache = cache();
+        let mut personal = query("s1", "document_inquiry", "documents needed");
+        personal.personalized = true;
+        cache.store(&personal, "Rahul, bring your Aadhaar.", None);
+
+        assert!(cache
+            .lookup(&query("s1", "document_inquiry", "documents needed"))
+            .is_some());
+        assert!(cache
+            .lookup(&query("s2", "document_inquiry", "documents needed"))
+            .is_none());
+
+        cache.invalidate_session("s1");
+        assert!(cache
+            .lookup(&query("s1", "document_inquiry", "documents needed"))
+            .is_none());
+    }
+
+    #[test]
+    fn test_invalidate_tool() {
+        let cache = cache();
+        let args = serde_json::json!({"tier": "22K"});
+        cache.put_tool_result("get_price", &args, "₹7,200");
+        cache.put_tool_result("capture_lead", &args, "ok");
+        assert_eq!(
+            cache.get_tool_result("get_price", &args).as_deref(),
+            Some("₹7,200")
+        );
+        assert!(cache.get_tool_result("capture_lead", &args).is_none());
+
+        cache.store(
+            &query("s1", "price_inquiry", "gold price"),
+            "₹7,200 per gram",
+            Some("get_price"),
+        );
+        assert_eq!(cache.invalidate_tool("get_price"), 2);
+        assert!(cache.get_tool_result("get_price", &args).is_none());
+        assert!(cache
+            .lookup(&query("s1", "price_inquiry", "gold price"))
+            .is_none());
+    }
+
+    #[test]
+    fn test_question_tokens() {
+        let tokens = question_tokens("Gold ka rate kya hai?");
+        assert_eq!(
+            tokens.into_iter().collect::<Vec<_>>(),
+            vec!["gold".to_string(), "rate".to_string()]
+        );
+    }
+}
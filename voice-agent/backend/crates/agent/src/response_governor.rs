@@ -0,0 +1,167 @@
+//! Response Length Governor
+//!
+//! The system prompt asks for replies under 50 words, but the model doesn't
+//! always comply and a long answer is painful to sit through on a call. This
+//! module estimates how long an English answer takes to speak and keeps it
+//! within the configured budget:
+//!
+//! - Complete answers are cut after the last sentence that fits. With
+//!   `summarize` on, an overlong answer is first compressed by a
+//!   summarization pass and only cut if the summary is still too long.
+//! - Streamed answers are admitted sentence by sentence; the first sentence
+//!   that would go over budget ends the answer.
+//!
+//! The first sentence is always spoken, however long, so an answer is never
+//! cut to nothing.
+
+use voice_agent_config::ResponseLengthConfig;
+use voice_agent_core::{GenerateRequest, LlmTask};
+
+use crate::guardrails::split_sentences;
+
+/// Output tokens allowed per budgeted word in the summarization pass
+const TOKENS_PER_WORD: f32 = 2.0;
+
+/// Spoken-duration budget shared by all sessions
+pub struct ResponseGovernor {
+    config: ResponseLengthConfig,
+}
+
+impl ResponseGovernor {
+    pub fn new(config: ResponseLengthConfig) -> Self {
+        Self { config }
+    }
+
+    /// Most words an answer may have
+    pub fn word_budget(&self) -> usize {
+        ((self.config.max_spoken_seconds * self.config.words_per_minute / 60.0) as usize).max(1)
+    }
+
+    /// Estimated time to speak `text`
+    pub fn spoken_seconds(&self, text: &str) -> f32 {
+        word_count(text) as f32 * 60.0 / self.config.words_per_minute
+    }
+
+    /// Whether `text` fits the budget
+    pub fn fits(&self, text: &str) -> bool {
+        word_count(text) <= self.word_budget()
+    }
+
+    /// Whether a streamed `sentence` may follow what was already `spoken`
+    pub fn admits(&self, spoken: &str, sentence: &str) -> bool {
+        spoken.trim().is_empty() || word_count(spoken) + word_count(sentence) <= self.word_budget()
+    }
+
+    /// Whether overlong complete answers get a summarization pass
+    pub fn summarizes(&self) -> bool {
+        self.config.summarize
+    }
+
+    /// Keep the leading sentences of `text` that fit the budget
+    pub fn truncate(&self, text: &str) -> String {
+        let budget = self.word_budget();
+        let mut kept = String::new();
+        let mut words = 0;
+
+        for sentence in split_sentences(text) {
+            let count = word_count(sentence);
+            if !kept.is_empty() && words + count > budget {
+                break;
+            }
+            if !kept.is_empty() {
+                kept.push(' ');
+            }
+            kept.push_str(sentence);
+            words += count;
+        }
+        kept
+    }
+
+    /// Request compressing `text` into the budget for the summarization pass
+    pub fn compression_request(&self, text: &str) -> GenerateRequest {
+        let budget = self.word_budget();
+        let prompt = format!(
+            r#"Shorten this reply from a phone agent to at most {} words.
+Keep every number, amount, rate and instruction. Keep the same language and tone.
+Reply with the shortened text only.
+
+Reply:
+{}"#,
+            budget, text
+        );
+        GenerateRequest::new("You are an editor who shortens spoken replies without losing facts.")
+            .with_user_message(prompt)
+            .with_max_tokens((budget as f32 * TOKENS_PER_WORD).ceil() as u32)
+            .with_task(LlmTask::Summarization)
+    }
+}
+
+fn word_count(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn governor(seconds: f32) -> ResponseGovernor {
+        ResponseGovernor::new(ResponseLengthConfig {
+            max_spoken_seconds: seconds,
+            words_per_minute: 120.0,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_budget() {
+        let governor = governor(5.0);
+        assert_eq!(governor.word_budget(), 10);
+        assert_eq!(governor.spoken_seconds("one two three four five six"), 3.0);
+        assert!(governor.fits("The rate is 9.5% per annum."));
+        assert!(!governor.fits(&"word ".repeat(11)));
+    }
+
+    #[test]
+    fn test_truncate_at_sentence_boundary() {
+        let governor = governor(5.0);
+        let answer = "Your loan is approved. The rate is 9.5% per annum. Please visit the branch with your KYC documents.";
+        assert_eq!(
+            governor.truncate(answer),
+            "Your loan is approved. The rate is 9.5% per annum."
+        );
+
+        // A short answer is untouched
+        assert_eq!(governor.truncate("Yes."), "Yes.");
+
+        // The first sentence stays even when it alone is over budget
+        let long = format!("{}. Next.", "word ".repeat(15).trim());
+        assert_eq!(
+            governor.truncate(&long),
+            format!("{}.", "word ".repeat(15).trim())
+        );
+    }
+
+    #[test]
+    fn test_streamed_admission() {
+        let governor = governor(5.0);
+        assert!(governor.admits("", &"word ".repeat(20)));
+        assert!(governor.admits("Your loan is approved.", "The rate is low."));
+        assert!(!governor.admits(
+            "Your loan is approved. The rate is 9.5%.",
+            "Please visit the branch."
+        ));
+    }
+
+    #[test]
+    fn test_compression_request() {
+        let request = governor(5.0).compression_request("A long answer.");
+        assert_eq!(request.task, Some(LlmTask::Summarization));
+        assert_eq!(request.max_tokens, Some(20));
+        assert!(request
+            .messages
+            .last()
+            .unwrap()
+            .content
+            .contains("at most 10 words"));
+    }
+}
@@ -0,0 +1,490 @@
+//! Conversation Simulator
+//!
+//! Drives a `DomainAgent` through scripted conversations without audio, for
+//! end-to-end regression tests of intent detection, DST and tool use. Each
+//! scenario is a sequence of user utterances with per-turn expectations on
+//! slots, tool calls, goal and stage.
+//!
+//! The LLM is replaced by `ScriptedLanguageModel`, which answers from the
+//! script, so runs are deterministic and need no backend. Scenarios live in
+//! YAML files with a top-level `scenarios:` list:
+//!
+//! ```yaml
+//! scenarios:
+//!   - name: eligibility-happy-path
+//!     persona: Salaried customer with 50g of 22k jewellery
+//!     turns:
+//!       - user: I have 50 grams of 22 karat gold
+//!         expect:
+//!           slots:
+//!             gold_weight: "50"
+//!           tools_not_called: [schedule_appointment]
+//! ```
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::Stream;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use voice_agent_config::MasterDomainConfig;
+use voice_agent_core::{
+    GenerateRequest, GenerateResponse, LanguageModel, LlmTask, StreamChunk, ToolDefinition,
+};
+
+use crate::{AgentConfig, AgentError, DomainAgent};
+
+/// Reply used when neither the turn nor the scenario scripts one
+const DEFAULT_REPLY: &str = "Okay.";
+
+/// A scripted conversation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Scenario {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Who the caller is meant to be (documentation only)
+    #[serde(default)]
+    pub persona: Option<String>,
+    /// Session language; the agent config default when unset
+    #[serde(default)]
+    pub language: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Agent reply for turns that don't script their own
+    #[serde(default)]
+    pub llm_reply: Option<String>,
+    pub turns: Vec<ScenarioTurn>,
+}
+
+/// One caller utterance and what should hold after the agent handles it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioTurn {
+    pub user: String,
+    /// What the scripted LLM answers this turn
+    #[serde(default)]
+    pub llm_reply: Option<String>,
+    #[serde(default)]
+    pub expect: TurnExpectation,
+}
+
+/// Assertions checked after a turn; empty fields are not checked
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TurnExpectation {
+    /// Slots that must hold exactly these values
+    pub slots: HashMap<String, String>,
+    /// Slots that must hold some value
+    pub slots_filled: Vec<String>,
+    /// Slots that must still be empty
+    pub slots_absent: Vec<String>,
+    /// Tools that must succeed during this turn
+    pub tools_called: Vec<String>,
+    /// Tools that must not succeed during this turn
+    pub tools_not_called: Vec<String>,
+    /// Dialogue goal ID
+    pub goal: Option<String>,
+    pub goal_complete: Option<bool>,
+    /// Conversation stage, e.g. `discovery`
+    pub stage: Option<String>,
+    /// Case-insensitive fragments of the agent's response
+    pub response_contains: Vec<String>,
+    /// Case-insensitive fragments of the prompt sent to the LLM
+    pub prompt_contains: Vec<String>,
+}
+
+/// YAML file holding scenarios
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScenarioFile {
+    #[serde(default)]
+    pub scenarios: Vec<Scenario>,
+}
+
+/// Load scenarios from a YAML file, or from every `.yaml`/`.yml` file in a
+/// directory (sorted by name)
+pub fn load_scenarios(path: impl AsRef<Path>) -> Result<Vec<Scenario>, AgentError> {
+    let path = path.as_ref();
+    let files: Vec<PathBuf> = if path.is_dir() {
+        let entries = std::fs::read_dir(path)
+            .map_err(|e| AgentError::Scenario(format!("{}: {}", path.display(), e)))?;
+        let mut files: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|p| {
+                matches!(
+                    p.extension().and_then(|e| e.to_str()),
+                    Some("yaml") | Some("yml")
+                )
+            })
+            .collect();
+        files.sort();
+        files
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    let mut scenarios = Vec::new();
+    for file in files {
+        let text = std::fs::read_to_string(&file)
+            .map_err(|e| AgentError::Scenario(format!("{}: {}", file.display(), e)))?;
+        let parsed: ScenarioFile = serde_yaml::from_str(&text)
+            .map_err(|e| AgentError::Scenario(format!("{}: {}", file.display(), e)))?;
+        scenarios.extend(parsed.scenarios);
+    }
+    Ok(scenarios)
+}
+
+/// Deterministic LLM that answers from the scenario script
+///
+/// Dialogue and tool-calling requests get the scripted reply; background
+/// tasks (summaries, extraction) get the default reply. The last dialogue
+/// prompt is kept for `prompt_contains` checks.
+pub struct ScriptedLanguageModel {
+    default_reply: String,
+    next_reply: Mutex<Option<String>>,
+    last_prompt: Mutex<Option<String>>,
+}
+
+impl ScriptedLanguageModel {
+    pub fn new(default_reply: impl Into<String>) -> Self {
+        Self {
+            default_reply: default_reply.into(),
+            next_reply: Mutex::new(None),
+            last_prompt: Mutex::new(None),
+        }
+    }
+
+    /// Reply for the next dialogue request
+    pub fn script(&self, reply: impl Into<String>) {
+        *self.next_reply.lock() = Some(reply.into());
+    }
+
+    /// Prompt of the most recent dialogue request, messages joined by newlines
+    pub fn last_prompt(&self) -> Option<String> {
+        self.last_prompt.lock().clone()
+    }
+
+    /// Forget the last prompt before a new turn
+    pub fn clear_prompt(&self) {
+        *self.last_prompt.lock() = None;
+    }
+
+    fn respond(&self, request: &GenerateRequest) -> String {
+        let background = matches!(
+            request.task,
+            Some(LlmTask::Summarization) | Some(LlmTask::Extraction)
+        );
+        if background {
+            return self.default_reply.clone();
+        }
+
+        let prompt: Vec<&str> = request
+            .messages
+            .iter()
+            .map(|m| m.content.as_str())
+            .collect();
+        *self.last_prompt.lock() = Some(prompt.join("\n"));
+        self.next_reply
+            .lock()
+            .clone()
+            .unwrap_or_else(|| self.default_reply.clone())
+    }
+}
+
+#[async_trait]
+impl LanguageModel for ScriptedLanguageModel {
+    async fn generate(
+        &self,
+        request: GenerateRequest,
+    ) -> voice_agent_core::Result<GenerateResponse> {
+        Ok(GenerateResponse::text(self.respond(&request)))
+    }
+
+    fn generate_stream<'a>(
+        &'a self,
+        request: GenerateRequest,
+    ) -> Pin<Box<dyn Stream<Item = voice_agent_core::Result<StreamChunk>> + Send + 'a>> {
+        let reply = self.respond(&request);
+        Box::pin(futures::stream::iter(vec![Ok(StreamChunk::text(reply))]))
+    }
+
+    async fn generate_with_tools(
+        &self,
+        request: GenerateRequest,
+        _tools: &[ToolDefinition],
+    ) -> voice_agent_core::Result<GenerateResponse> {
+        self.generate(request).await
+    }
+
+    async fn is_available(&self) -> bool {
+        true
+    }
+
+    fn model_name(&self) -> &str {
+        "scripted"
+    }
+}
+
+/// A failed expectation
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TurnFailure {
+    /// 1-based turn number
+    pub turn: usize,
+    pub message: String,
+}
+
+/// Result of running one scenario
+#[derive(Debug, Clone, Serialize)]
+pub struct ScenarioReport {
+    pub name: String,
+    pub turns: usize,
+    pub failures: Vec<TurnFailure>,
+}
+
+impl ScenarioReport {
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Runs scenarios against fresh agents built from one domain config
+pub struct Simulator {
+    domain_config: Arc<MasterDomainConfig>,
+    agent_config: AgentConfig,
+}
+
+impl Simulator {
+    pub fn new(domain_config: Arc<MasterDomainConfig>, agent_config: AgentConfig) -> Self {
+        Self {
+            domain_config,
+            agent_config,
+        }
+    }
+
+    /// Run every scenario, one fresh agent each
+    pub async fn run_all(&self, scenarios: &[Scenario]) -> Vec<ScenarioReport> {
+        let mut reports = Vec::with_capacity(scenarios.len());
+        for scenario in scenarios {
+            reports.push(self.run(scenario).await);
+        }
+        reports
+    }
+
+    /// Run one scenario; stops at the first turn the agent fails to process
+    pub async fn run(&self, scenario: &Scenario) -> ScenarioReport {
+        let mut config = self.agent_config.clone();
+        // The speculative executor brings its own models and would bypass the script
+        config.speculative.enabled = false;
+        if let Some(ref language) = scenario.language {
+            config.language = language.clone();
+        }
+
+        let llm = Arc::new(ScriptedLanguageModel::new(DEFAULT_REPLY));
+        let agent = DomainAgent::new(
+            format!("sim-{}", scenario.name),
+            config,
+            self.domain_config.clone(),
+        );
+        agent.set_language_model(llm.clone());
+
+        let mut failures = Vec::new();
+        for (index, turn) in scenario.turns.iter().enumerate() {
+            let number = index + 1;
+            llm.clear_prompt();
+            llm.script(
+                turn.llm_reply
+                    .as_deref()
+                    .or(scenario.llm_reply.as_deref())
+                    .unwrap_or(DEFAULT_REPLY),
+            );
+
+            let tools_before = agent.tools_called().len();
+            let response = match agent.process(&turn.user).await {
+                Ok(response) => response,
+                Err(e) => {
+                    failures.push(TurnFailure {
+                        turn: number,
+                        message: format!("agent error: {}", e),
+                    });
+                    break;
+                },
+            };
+            let tools = agent.tools_called().split_off(tools_before);
+
+            for message in check_turn(&agent, &turn.expect, &response, &tools, &llm) {
+                failures.push(TurnFailure {
+                    turn: number,
+                    message,
+                });
+            }
+        }
+
+        ScenarioReport {
+            name: scenario.name.clone(),
+            turns: scenario.turns.len(),
+            failures,
+        }
+    }
+}
+
+/// Compare the agent's state after a turn with what was expected
+fn check_turn(
+    agent: &DomainAgent,
+    expect: &TurnExpectation,
+    response: &str,
+    tools: &[String],
+    llm: &ScriptedLanguageModel,
+) -> Vec<String> {
+    let mut failures = Vec::new();
+    let facts = agent.customer_facts();
+
+    let mut slots: Vec<_> = expect.slots.iter().collect();
+    slots.sort();
+    for (slot, expected) in slots {
+        match facts.get(slot) {
+            Some(actual) if actual == expected => {},
+            Some(actual) => failures.push(format!(
+                "slot {}: expected {:?}, got {:?}",
+                slot, expected, actual
+            )),
+            None => failures.push(format!(
+                "slot {}: expected {:?}, not filled",
+                slot, expected
+            )),
+        }
+    }
+    for slot in &expect.slots_filled {
+        if !facts.contains_key(slot) {
+            failures.push(format!("slot {}: expected a value, not filled", slot));
+        }
+    }
+    for slot in &expect.slots_absent {
+        if let Some(actual) = facts.get(slot) {
+            failures.push(format!("slot {}: expected empty, got {:?}", slot, actual));
+        }
+    }
+
+    for tool in &expect.tools_called {
+        if !tools.contains(tool) {
+            failures.push(format!(
+                "tool {} was not called (called: {:?})",
+                tool, tools
+            ));
+        }
+    }
+    for tool in &expect.tools_not_called {
+        if tools.contains(tool) {
+            failures.push(format!("tool {} was called", tool));
+        }
+    }
+
+    if let Some(ref goal) = expect.goal {
+        let actual = agent.dialogue_goal();
+        if &actual != goal {
+            failures.push(format!("goal: expected {:?}, got {:?}", goal, actual));
+        }
+    }
+    if let Some(complete) = expect.goal_complete {
+        if agent.goal_reached() != complete {
+            failures.push(format!("goal complete: expected {}", complete));
+        }
+    }
+    if let Some(ref stage) = expect.stage {
+        let actual = agent.stage();
+        if actual.as_str() != stage {
+            failures.push(format!(
+                "stage: expected {:?}, got {:?}",
+                stage,
+                actual.as_str()
+            ));
+        }
+    }
+
+    let lower = response.to_lowercase();
+    for fragment in &expect.response_contains {
+        if !lower.contains(&fragment.to_lowercase()) {
+            failures.push(format!(
+                "response does not contain {:?}: {:?}",
+                fragment, response
+            ));
+        }
+    }
+    if !expect.prompt_contains.is_empty() {
+        let prompt = llm.last_prompt().unwrap_or_default().to_lowercase();
+        for fragment in &expect.prompt_contains {
+            if !prompt.contains(&fragment.to_lowercase()) {
+                failures.push(format!("prompt does not contain {:?}", fragment));
+            }
+        }
+    }
+
+    failures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCENARIOS: &str = r#"
+scenarios:
+  - name: greeting
+    persona: Curious first-time caller
+    llm_reply: Hello! How can I help you today?
+    turns:
+      - user: Hello
+        expect:
+          response_contains: [help you]
+          tools_not_called: [schedule_appointment]
+      - user: What is a gold loan?
+        llm_reply: A gold loan is a loan against your gold jewellery.
+        expect:
+          response_contains: [gold jewellery]
+          prompt_contains: [what is a gold loan]
+"#;
+
+    #[test]
+    fn test_parse_scenarios() {
+        let file: ScenarioFile = serde_yaml::from_str(SCENARIOS).unwrap();
+        assert_eq!(file.scenarios.len(), 1);
+        let scenario = &file.scenarios[0];
+        assert_eq!(scenario.turns.len(), 2);
+        assert_eq!(scenario.turns[0].expect.response_contains, vec!["help you"]);
+        assert!(scenario.turns[0].llm_reply.is_none());
+        assert!(scenario.turns[1].expect.goal.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_scripted_model() {
+        let llm = ScriptedLanguageModel::new("default");
+        let summary = GenerateRequest::new("sys")
+            .with_user_message("summarise")
+            .with_task(LlmTask::Summarization);
+        assert_eq!(llm.generate(summary).await.unwrap().text, "default");
+        assert!(llm.last_prompt().is_none());
+
+        llm.script("scripted");
+        let dialogue = GenerateRequest::new("sys").with_user_message("Hi there");
+        assert_eq!(llm.generate(dialogue).await.unwrap().text, "scripted");
+        assert!(llm.last_prompt().unwrap().contains("Hi there"));
+    }
+
+    #[tokio::test]
+    async fn test_failed_expectations_are_reported() {
+        let simulator = Simulator::new(
+            Arc::new(MasterDomainConfig::default()),
+            AgentConfig::default(),
+        );
+        let mut file: ScenarioFile = serde_yaml::from_str(SCENARIOS).unwrap();
+        let report = simulator.run(&file.scenarios[0]).await;
+        assert!(report.passed(), "{:?}", report.failures);
+
+        file.scenarios[0].turns[0].expect.response_contains = vec!["goodbye".to_string()];
+        file.scenarios[0].turns[1].expect.tools_called = vec!["check_eligibility".to_string()];
+        let report = simulator.run(&file.scenarios[0]).await;
+        assert_eq!(report.failures.len(), 2);
+        assert_eq!(report.failures[0].turn, 1);
+        assert_eq!(report.failures[1].turn, 2);
+    }
+}
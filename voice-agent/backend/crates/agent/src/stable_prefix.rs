@@ -0,0 +1,304 @@
+//! Stable Prefix Detection
+//!
+//! Streamed answers used to reach TTS a sentence at a time, so the caller
+//! heard nothing until the model finished its first sentence. This module
+//! watches the LLM token stream and releases text as soon as it is safe to
+//! speak:
+//!
+//! - Sentences are released at their terminator. A terminator at the very
+//!   end of the stream so far waits for the next token, so `9.` of `9.5%`
+//!   is not spoken as a sentence.
+//! - Clauses are released at `,` `;` `:` or a dash followed by whitespace,
+//!   once they have `min_clause_words` words, so a long sentence starts
+//!   synthesizing while the rest of it is still being generated. Digit
+//!   grouping (`1,50,000`) has no whitespace after the comma and never splits.
+//! - A clause boundary is skipped while the text before it is unfinished:
+//!   an unclosed prosody tag (`<emphasis>` without `</emphasis>`, a `<`
+//!   without `>`), bracket or quote.
+//!
+//! When a pass is superseded (a re-ask after a malformed tool call, or the
+//! follow-up carrying tool results) `restart` drops the unreleased tail.
+//! While the new pass repeats what was already released it is suppressed
+//! rather than spoken twice; if it revises that text instead, the new pass
+//! is spoken from its start.
+
+use crate::agent_config::EarlyTtsConfig;
+
+/// Clause boundaries, when followed by whitespace
+const CLAUSE_BREAKS: &[char] = &[',', ';', ':', '—', '–'];
+
+/// Releases speakable prefixes of a streamed answer
+pub struct StablePrefixDetector {
+    config: EarlyTtsConfig,
+    terminators: &'static [char],
+    /// Release at clause boundaries as well as sentence ends
+    clauses: bool,
+    /// Received but not yet released
+    buffer: String,
+    /// Everything released, across passes
+    released: String,
+    /// Set after a restart until the new pass diverges or catches up
+    replay: Option<Replay>,
+}
+
+/// Progress of a restarted pass through the already-released text
+struct Replay {
+    /// Non-whitespace characters of the released text
+    expected: Vec<char>,
+    matched: usize,
+    /// The new pass's text so far, spoken if it turns out to be a revision
+    held: String,
+}
+
+impl StablePrefixDetector {
+    /// `clauses` is typically off when each release is machine translated:
+    /// a clause translated out of context reads worse than a sentence.
+    pub fn new(config: EarlyTtsConfig, terminators: &'static [char], clauses: bool) -> Self {
+        Self {
+            clauses: clauses && config.enabled,
+            config,
+            terminators,
+            buffer: String::new(),
+            released: String::new(),
+            replay: None,
+        }
+    }
+
+    /// Add streamed text
+    pub fn push(&mut self, text: &str) {
+        for c in text.chars() {
+            self.push_char(c);
+        }
+    }
+
+    fn push_char(&mut self, c: char) {
+        let Some(replay) = self.replay.as_mut() else {
+            self.buffer.push(c);
+            return;
+        };
+
+        replay.held.push(c);
+        if c.is_whitespace() {
+            return;
+        }
+        if replay.expected.get(replay.matched) == Some(&c) {
+            replay.matched += 1;
+            if replay.matched == replay.expected.len() {
+                // The repeat is complete; only what follows is new
+                self.replay = None;
+            }
+        } else {
+            let held = std::mem::take(&mut replay.held);
+            self.replay = None;
+            self.buffer.push_str(&held);
+        }
+    }
+
+    /// Next prefix that is safe to speak, if any
+    pub fn next_release(&mut self) -> Option<String> {
+        loop {
+            let end = self.release_point()?;
+            let chunk = self.buffer[..end].trim().to_string();
+            self.buffer.drain(..end);
+            if chunk.is_empty() {
+                continue;
+            }
+            if !self.released.is_empty() {
+                self.released.push(' ');
+            }
+            self.released.push_str(&chunk);
+            return Some(chunk);
+        }
+    }
+
+    /// Whatever remains once the stream has ended
+    ///
+    /// A restarted pass that only repeated released text yields nothing.
+    pub fn flush(&mut self) -> Option<String> {
+        self.replay = None;
+        let rest = std::mem::take(&mut self.buffer).trim().to_string();
+        if rest.is_empty() {
+            return None;
+        }
+        if !self.released.is_empty() {
+            self.released.push(' ');
+        }
+        self.released.push_str(&rest);
+        Some(rest)
+    }
+
+    /// Start a new pass that supersedes the current one
+    pub fn restart(&mut self) {
+        self.buffer.clear();
+        self.replay = (!self.released.is_empty()).then(|| Replay {
+            expected: self
+                .released
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect(),
+            matched: 0,
+            held: String::new(),
+        });
+    }
+
+    /// Byte offset just past the earliest safe boundary in the buffer
+    fn release_point(&self) -> Option<usize> {
+        let sentence_end = find_sentence_end(&self.buffer, self.terminators)
+            .map(|i| i + self.buffer[i..].chars().next().map_or(1, char::len_utf8));
+        if !self.clauses {
+            return sentence_end;
+        }
+
+        let limit = sentence_end.unwrap_or(self.buffer.len());
+        let text = &self.buffer[..limit];
+        text.char_indices()
+            .filter(|(_, c)| CLAUSE_BREAKS.contains(c))
+            .map(|(i, c)| i + c.len_utf8())
+            .find(|&end| {
+                let clause = &text[..end];
+                text[end..].starts_with(char::is_whitespace)
+                    && clause.split_whitespace().count() >= self.config.min_clause_words
+                    && is_settled(clause)
+            })
+            .or(sentence_end)
+    }
+}
+
+/// Find the position of a sentence terminator followed by whitespace
+///
+/// Danda and double danda end a sentence on their own; any other terminator
+/// at the end of the text waits for what follows it.
+pub(crate) fn find_sentence_end(text: &str, terminators: &[char]) -> Option<usize> {
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if !terminators.contains(&c) {
+            continue;
+        }
+        if c == '।' || c == '॥' {
+            return Some(i);
+        }
+        if chars.peek().is_some_and(|(_, next)| next.is_whitespace()) {
+            return Some(i);
+        }
+    }
+    None
+}
+
+/// Whether every bracket, quote and prosody tag opened in `text` is closed
+fn is_settled(text: &str) -> bool {
+    let mut brackets = 0i32;
+    let mut quoted = false;
+    let mut open_tags = 0i32;
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '(' | '[' => brackets += 1,
+            ')' | ']' => brackets -= 1,
+            '"' => quoted = !quoted,
+            '“' => quoted = true,
+            '”' => quoted = false,
+            '<' => {
+                let Some(close) = rest.find('>') else {
+                    return false;
+                };
+                let tag = &rest[1..close];
+                if tag.starts_with('/') {
+                    open_tags -= 1;
+                } else if !tag.ends_with('/') {
+                    open_tags += 1;
+                }
+                rest = &rest[close + 1..];
+                continue;
+            },
+            _ => {},
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+
+    brackets <= 0 && !quoted && open_tags <= 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TERMINATORS: &[char] = &['.', '?', '!', '।'];
+
+    fn detector() -> StablePrefixDetector {
+        StablePrefixDetector::new(EarlyTtsConfig::default(), TERMINATORS, true)
+    }
+
+    fn releases(detector: &mut StablePrefixDetector, tokens: &[&str]) -> Vec<String> {
+        let mut out = Vec::new();
+        for token in tokens {
+            detector.push(token);
+            while let Some(chunk) = detector.next_release() {
+                out.push(chunk);
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_releases_safe_clauses_early() {
+        let mut d = detector();
+        let out = releases(
+            &mut d,
+            &[
+                "For 50 grams of gold at ₹1,50,000 per",
+                " 20 grams, you can get",
+                " about ₹2,80,000, <emphasis>at 9",
+                ".5%, today</emphasis>",
+                " only. Ok",
+            ],
+        );
+        assert_eq!(
+            out,
+            vec![
+                "For 50 grams of gold at ₹1,50,000 per 20 grams,",
+                "you can get about ₹2,80,000,",
+                "<emphasis>at 9.5%, today</emphasis> only.",
+            ]
+        );
+        assert_eq!(d.flush().as_deref(), Some("Ok"));
+
+        // Sentence-only mode waits for the full stop
+        let mut d = StablePrefixDetector::new(EarlyTtsConfig::default(), TERMINATORS, false);
+        assert!(releases(&mut d, &["If you pledge fifty grams of gold, you"]).is_empty());
+        assert!(!is_settled("He said (roughly,"));
+        assert!(!is_settled("<emphasis>at 9.5%,"));
+        assert!(is_settled("<pause/> at 9.5%,"));
+    }
+
+    #[test]
+    fn test_restart_suppresses_repeated_prefix() {
+        let mut d = detector();
+        let out = releases(
+            &mut d,
+            &["Let me check the current gold rate, one moment", " please"],
+        );
+        assert_eq!(out, vec!["Let me check the current gold rate,"]);
+
+        // The follow-up repeats the released clause, then continues
+        d.restart();
+        let out = releases(
+            &mut d,
+            &[
+                "Let me check the current gold rate,",
+                " it is ₹7,200 a gram. ",
+            ],
+        );
+        assert_eq!(out, vec!["it is ₹7,200 a gram."]);
+
+        // A revision is spoken from its start
+        d.restart();
+        let out = releases(&mut d, &["Let me check the", " gold price instead. "]);
+        assert_eq!(out, vec!["Let me check the gold price instead."]);
+
+        // A pass that only repeats leaves nothing to flush
+        d.restart();
+        releases(&mut d, &["Let me check"]);
+        assert_eq!(d.flush(), None);
+    }
+}
@@ -0,0 +1,213 @@
+//! Translate-Think-Translate
+//!
+//! Small LLMs reason and call tools far more reliably in English, so a
+//! non-English turn is translated to English before the LLM and the answer
+//! is translated back to the caller's language.
+//!
+//! Machine translation is free to rewrite digits and transliterate names,
+//! which silently corrupts loan amounts, weights and lender names. Values
+//! that must survive verbatim are swapped for `[[n]]` placeholders before
+//! translation and restored afterwards; a translation that drops a
+//! placeholder is rejected rather than spoken.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use voice_agent_core::Language;
+
+/// Numbers with grouping or decimals ("5,00,000", "9.5", "२२")
+static NUMBER: Lazy<Regex> = Lazy::new(|| Regex::new(r"\d+(?:[,.]\d+)*").unwrap());
+
+/// Placeholder as it comes back from the translator, tolerating added spaces
+static PLACEHOLDER: Lazy<Regex> = Lazy::new(|| Regex::new(r"\[\s*\[\s*(\d+)\s*\]\s*\]").unwrap());
+
+/// Shortest slot value worth protecting; shorter ones are mostly noise
+const MIN_VALUE_CHARS: usize = 2;
+
+/// Languages for one turn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TurnLanguages {
+    /// Language the caller spoke this turn
+    pub input: Language,
+    /// Language to answer in
+    pub reply: Language,
+}
+
+impl TurnLanguages {
+    /// Resolve the turn's languages from the session language and, if
+    /// available, the language detected in the utterance
+    ///
+    /// Detected non-English input is answered in that language. Latin-script
+    /// input in a non-English session (often romanized Hindi) goes to the LLM
+    /// as is but is still answered in the session language.
+    pub fn resolve(session: Language, detected: Option<Language>) -> Self {
+        let input = detected.unwrap_or(session);
+        let reply = if input != Language::English {
+            input
+        } else {
+            session
+        };
+        Self { input, reply }
+    }
+
+    /// Whether the input needs translating to English
+    pub fn translates_input(&self) -> bool {
+        self.input != Language::English
+    }
+
+    /// Whether the English answer needs translating back
+    pub fn translates_reply(&self) -> bool {
+        self.reply != Language::English
+    }
+}
+
+/// Text with protected values replaced by placeholders
+#[derive(Debug, Clone)]
+pub struct ProtectedText {
+    text: String,
+    values: Vec<String>,
+}
+
+impl ProtectedText {
+    /// Replace numbers and the given slot text with `[[n]]` placeholders
+    ///
+    /// Slot text (the matched spans, not normalized slot values) is matched
+    /// verbatim, longest first; numbers are protected wherever they are not
+    /// already part of a slot span.
+    pub fn protect(text: &str, slot_values: &[String]) -> Self {
+        let mut spans: Vec<(usize, usize)> = Vec::new();
+        let overlaps = |spans: &[(usize, usize)], s: usize, e: usize| {
+            spans.iter().any(|&(a, b)| s < b && a < e)
+        };
+
+        let mut values: Vec<&str> = slot_values
+            .iter()
+            .map(|v| v.trim())
+            .filter(|v| v.chars().count() >= MIN_VALUE_CHARS)
+            .collect();
+        values.sort_by_key(|v| std::cmp::Reverse(v.len()));
+
+        for value in values {
+            for (start, matched) in text.match_indices(value) {
+                let end = start + matched.len();
+                if !overlaps(&spans, start, end) {
+                    spans.push((start, end));
+                }
+            }
+        }
+        for m in NUMBER.find_iter(text) {
+            if !overlaps(&spans, m.start(), m.end()) {
+                spans.push((m.start(), m.end()));
+            }
+        }
+        spans.sort_unstable();
+
+        let mut out = String::with_capacity(text.len());
+        let mut protected = Vec::with_capacity(spans.len());
+        let mut last = 0;
+        for (start, end) in spans {
+            out.push_str(&text[last..start]);
+            out.push_str(&format!("[[{}]]", protected.len()));
+            protected.push(text[start..end].to_string());
+            last = end;
+        }
+        out.push_str(&text[last..]);
+
+        Self {
+            text: out,
+            values: protected,
+        }
+    }
+
+    /// Text to send to the translator
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Protected values, in placeholder order
+    pub fn values(&self) -> &[String] {
+        &self.values
+    }
+
+    /// Whether anything was protected
+    pub fn is_protected(&self) -> bool {
+        !self.values.is_empty()
+    }
+
+    /// Put the protected values back into a translation
+    ///
+    /// Returns `None` if the translator dropped or invented a placeholder.
+    pub fn restore(&self, translated: &str) -> Option<String> {
+        let mut seen = vec![false; self.values.len()];
+        let mut valid = true;
+
+        let restored = PLACEHOLDER.replace_all(translated, |caps: &regex::Captures| {
+            match caps[1]
+                .parse::<usize>()
+                .ok()
+                .filter(|&i| i < self.values.len())
+            {
+                Some(i) => {
+                    seen[i] = true;
+                    self.values[i].clone()
+                },
+                None => {
+                    valid = false;
+                    String::new()
+                },
+            }
+        });
+
+        (valid && seen.iter().all(|&s| s)).then(|| restored.into_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turn_languages() {
+        let hindi_in_english_session =
+            TurnLanguages::resolve(Language::English, Some(Language::Hindi));
+        assert!(hindi_in_english_session.translates_input());
+        assert_eq!(hindi_in_english_session.reply, Language::Hindi);
+
+        let romanized_in_hindi_session =
+            TurnLanguages::resolve(Language::Hindi, Some(Language::English));
+        assert!(!romanized_in_hindi_session.translates_input());
+        assert_eq!(romanized_in_hindi_session.reply, Language::Hindi);
+
+        let english = TurnLanguages::resolve(Language::English, None);
+        assert!(!english.translates_input() && !english.translates_reply());
+    }
+
+    #[test]
+    fn test_protect_and_restore() {
+        let protected = ProtectedText::protect(
+            "मुझे Muthoot Finance से 5,00,000 का लोन 9.5% पर चाहिए",
+            &["Muthoot Finance".to_string()],
+        );
+        assert_eq!(protected.text(), "मुझे [[0]] से [[1]] का लोन [[2]]% पर चाहिए");
+        assert_eq!(protected.values(), ["Muthoot Finance", "5,00,000", "9.5"]);
+
+        // Translators reorder and pad placeholders
+        let restored = protected
+            .restore("I need a loan of [[1]] at [[ 2 ]]% from [ [0] ]")
+            .unwrap();
+        assert_eq!(
+            restored,
+            "I need a loan of 5,00,000 at 9.5% from Muthoot Finance"
+        );
+    }
+
+    #[test]
+    fn test_restore_rejects_lost_placeholders() {
+        let protected = ProtectedText::protect("Your loan of 50000 is approved", &[]);
+        assert!(protected.is_protected());
+        assert!(protected.restore("आपका लोन मंज़ूर है").is_none());
+        assert!(protected.restore("आपका [[0]] लोन [[7]] मंज़ूर है").is_none());
+        assert!(ProtectedText::protect("hello", &[])
+            .restore("नमस्ते")
+            .is_some());
+    }
+}
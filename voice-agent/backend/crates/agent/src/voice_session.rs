@@ -19,14 +19,21 @@ use std::time::{Duration, Instant};
 use tokio::sync::{broadcast, mpsc, RwLock};
 use tokio::time::interval;
 
+use voice_agent_config::AgentDomainView;
 use voice_agent_core::AudioFrame;
 use voice_agent_pipeline::{
     stt::{IndicConformerConfig, StreamingStt, SttConfig, SttEngine},
-    tts::{create_hindi_g2p, StreamingTts, TtsConfig, TtsEngine, TtsEvent},
+    tts::{
+        create_hindi_g2p, StreamingTts, SynthesisCache, TtsConfig, TtsEngine, TtsEvent,
+        VoiceRegistry,
+    },
     vad::{SileroConfig, SileroVad, VadResult, VadState},
 };
+use voice_agent_text_processing::translation::ScriptDetector;
 use voice_agent_transport::{SessionConfig, TransportEvent, TransportSession};
 
+use crate::translate_think::TurnLanguages;
+
 use crate::{AgentConfig, AgentError, AgentEvent, DomainAgent};
 
 /// Voice session configuration
@@ -40,6 +47,12 @@ pub struct VoiceSessionConfig {
     pub indicconformer: Option<IndicConformerConfig>,
     /// TTS configuration
     pub tts: TtsConfig,
+    /// TTS voice registry; `None` keeps the single configured voice
+    pub voices: Option<Arc<VoiceRegistry>>,
+    /// Per-session voice override (`SessionOverrides::tts_voice`)
+    pub voice_override: Option<String>,
+    /// Synthesis cache shared across sessions; `None` synthesizes every time
+    pub tts_cache: Option<Arc<SynthesisCache>>,
     /// Transport configuration
     pub transport: SessionConfig,
     /// VAD configuration (Silero)
@@ -79,6 +92,9 @@ impl Default for VoiceSessionConfig {
                 engine: TtsEngine::Piper,
                 ..Default::default()
             },
+            voices: None,
+            voice_override: None,
+            tts_cache: None,
             transport: SessionConfig::default(),
             vad: SileroConfig::default(),
             barge_in_enabled: true,
@@ -95,6 +111,13 @@ impl Default for VoiceSessionConfig {
 }
 
 impl VoiceSessionConfig {
+    /// Boost the domain's vocabulary and location names (cities, states,
+    /// branch localities from the location gazetteer) in speech recognition
+    pub fn with_domain_view(mut self, view: &AgentDomainView) -> Self {
+        self.stt_entities = view.stt_boost_terms();
+        self
+    }
+
     /// Get STT entities for entity boosting
     ///
     /// Returns config-driven entities if available, otherwise falls back
@@ -220,7 +243,11 @@ impl VoiceSession {
         }
 
         // Create TTS
-        let tts = Arc::new(StreamingTts::simple(config.tts.clone()));
+        let mut tts = StreamingTts::simple(config.tts.clone());
+        if let Some(cache) = config.tts_cache.clone() {
+            tts = tts.with_cache(cache);
+        }
+        let tts = Arc::new(tts);
 
         // Create VAD if enabled
         let vad = if config.use_silero_vad {
@@ -338,6 +365,8 @@ impl VoiceSession {
         let session_id = self.session_id.clone();
         let agent = Arc::clone(&self.agent);
         let tts = Arc::clone(&self.tts);
+        let voices = self.config.voices.clone();
+        let voice_override = self.config.voice_override.clone();
         let audio_out_tx = self.audio_out_tx.clone();
 
         tokio::spawn(async move {
@@ -435,8 +464,15 @@ impl VoiceSession {
                                     text: transcript.text.clone(),
                                 });
 
-                                // Process through agent
-                                if let Ok(response) = agent.process(&transcript.text).await {
+                                // Process through agent; a failed turn gets an apology
+                                let reply = match agent.process(&transcript.text).await {
+                                    Ok(response) => Some(response),
+                                    Err(e) => {
+                                        tracing::warn!(error = %e, "Agent turn failed");
+                                        agent.failure_reply(&e)
+                                    },
+                                };
+                                if let Some(response) = reply {
                                     let _ = event_tx.send(VoiceSessionEvent::Speaking {
                                         text: response.clone(),
                                     });
@@ -444,6 +480,14 @@ impl VoiceSession {
                                     // Synthesize and send audio
                                     *state.write().await = VoiceSessionState::Speaking;
 
+                                    select_voice(
+                                        voices.as_deref(),
+                                        voice_override.as_deref(),
+                                        &agent,
+                                        &tts,
+                                        &response,
+                                    );
+
                                     let g2p = create_hindi_g2p();
                                     if let Ok(_phonemes) = g2p.convert(&response) {
                                         let (tts_tx, mut tts_rx) = mpsc::channel::<TtsEvent>(10);
@@ -590,9 +634,22 @@ impl VoiceSession {
         let _ = self.event_tx.send(VoiceSessionEvent::FinalTranscript {
             text: transcript.text.clone(),
         });
-
-        // Process through agent
-        let response = self.agent.process(&transcript.text).await?;
+        let audio_ms = transcript
+            .end_time_ms
+            .saturating_sub(transcript.start_time_ms);
+        self.agent.record_stt_seconds(audio_ms as f64 / 1000.0);
+
+        // Process through agent; a failed turn gets an apology instead of silence
+        let response = match self.agent.process(&transcript.text).await {
+            Ok(response) => response,
+            Err(e) => match self.agent.failure_reply(&e) {
+                Some(apology) => {
+                    tracing::warn!(error = %e, "Agent turn failed");
+                    apology
+                },
+                None => return Err(e),
+            },
+        };
 
         // Speak response
         self.speak(&response).await?;
@@ -610,6 +667,7 @@ impl VoiceSession {
         let _ = self.event_tx.send(VoiceSessionEvent::Speaking {
             text: text.to_string(),
         });
+        self.agent.record_tts_characters(text);
 
         // Convert to phonemes for Indian language support
         let g2p = create_hindi_g2p();
@@ -617,6 +675,15 @@ impl VoiceSession {
             .convert(text)
             .map_err(|e| AgentError::Pipeline(e.to_string()))?;
 
+        // Pick the voice for the reply's language before synthesis starts
+        select_voice(
+            self.config.voices.as_deref(),
+            self.config.voice_override.as_deref(),
+            &self.agent,
+            &self.tts,
+            text,
+        );
+
         // Start TTS
         let (tts_tx, mut tts_rx) = mpsc::channel::<TtsEvent>(10);
         self.tts.start(text, tts_tx);
@@ -802,6 +869,33 @@ impl VoiceSession {
     }
 }
 
+/// Switch the TTS voice to match the reply about to be spoken
+///
+/// The reply language is read from its script, so a caller who switches to
+/// Tamil mid-call hears a Tamil voice; Latin-script replies keep the session
+/// language (Hinglish is still spoken by the Hindi voice).
+fn select_voice(
+    voices: Option<&VoiceRegistry>,
+    voice_override: Option<&str>,
+    agent: &DomainAgent,
+    tts: &StreamingTts,
+    text: &str,
+) {
+    let Some(voices) = voices else {
+        return;
+    };
+
+    let detected = ScriptDetector::new().detect(text);
+    let language = TurnLanguages::resolve(agent.user_language(), Some(detected)).reply;
+    match voices.apply(tts, agent.name(), language.code(), voice_override) {
+        Ok(Some(voice)) => {
+            tracing::info!(voice = %voice, language = ?language, "Switched TTS voice")
+        },
+        Ok(None) => {},
+        Err(e) => tracing::warn!(error = %e, "Failed to switch TTS voice, keeping current voice"),
+    }
+}
+
 /// Calculate RMS energy of audio samples
 fn calculate_energy(samples: &[f32]) -> f32 {
     if samples.is_empty() {
@@ -824,6 +918,61 @@ mod tests {
         assert_eq!(session.session_id(), "test-session");
     }
 
+    #[test]
+    fn test_stt_entities_include_locations() {
+        let domain = Arc::new(voice_agent_config::MasterDomainConfig::default());
+        let config = VoiceSessionConfig::default().with_domain_view(&AgentDomainView::new(domain));
+        let entities = config.get_stt_entities();
+        assert!(entities.contains(&"Bengaluru") && entities.contains(&"Maharashtra"));
+    }
+
+    #[test]
+    fn test_select_voice_follows_reply_language() {
+        let mut voices = voice_agent_config::VoicesConfig::default();
+        for (id, engine, language) in [
+            ("hi-female-1", "parler", "hi"),
+            ("ta-female-1", "parler", "ta"),
+        ] {
+            voices.voices.insert(
+                id.to_string(),
+                voice_agent_config::VoiceProfile {
+                    engine: engine.to_string(),
+                    model_path: None,
+                    reference_audio: None,
+                    language: language.to_string(),
+                    gender: Some("female".to_string()),
+                    style: None,
+                },
+            );
+        }
+        let registry = VoiceRegistry::new(voices);
+
+        let config = AgentConfig {
+            language: "hi".to_string(),
+            ..AgentConfig::default()
+        };
+        let agent = DomainAgent::without_llm("test-voice", config);
+        let tts = StreamingTts::simple(TtsConfig::default());
+
+        // Hinglish reply keeps the session's Hindi voice
+        select_voice(
+            Some(&registry),
+            None,
+            &agent,
+            &tts,
+            "Namaste, main Priya hoon",
+        );
+        assert_eq!(tts.voice_id().as_deref(), Some("hi-female-1"));
+
+        // Caller switched to Tamil
+        select_voice(Some(&registry), None, &agent, &tts, "வணக்கம், நான் உதவ முடியும்");
+        assert_eq!(tts.voice_id().as_deref(), Some("ta-female-1"));
+
+        // Override only applies to the language it speaks
+        select_voice(Some(&registry), Some("hi-female-1"), &agent, &tts, "नमस्ते");
+        assert_eq!(tts.voice_id().as_deref(), Some("hi-female-1"));
+    }
+
     #[tokio::test]
     async fn test_voice_session_state() {
         let session = VoiceSession::new("test", VoiceSessionConfig::default()).unwrap();
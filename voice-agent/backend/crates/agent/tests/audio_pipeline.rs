@@ -0,0 +1,423 @@
+//! Golden-path audio tests: fixture audio -> VAD -> STT -> agent -> TTS
+//!
+//! Fixtures are listed in `tests/fixtures/audio/fixtures.yaml`. Audio is fed
+//! in 10ms frames at real-time pace (turn detection runs on wall-clock
+//! silence), the final transcript goes through a `DomainAgent` backed by a
+//! scripted LLM, and the reply is streamed through the TTS processor chain.
+//!
+//! By default a `ScriptedSttBackend` recognises each fixture's transcript and
+//! TTS synthesises silence, so the tests check turn-taking, timing and
+//! barge-in without models. With `--features real-models` the pipeline loads
+//! the models under `models/` and recorded fixtures are checked against
+//! their transcripts by word error rate:
+//!
+//! ```text
+//! cargo test -p voice-agent-agent --features real-models --test audio_pipeline
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use tokio::sync::mpsc;
+use tokio::time::timeout;
+
+use voice_agent_agent::{AgentConfig, DomainAgent, ScriptedLanguageModel, TurnRole};
+use voice_agent_config::MasterDomainConfig;
+use voice_agent_core::{AudioFrame, Channels, Frame, SampleRate};
+use voice_agent_pipeline::{PipelineConfig, PipelineEvent, PipelineState, VoicePipeline};
+
+const SAMPLE_RATE: usize = 16_000;
+/// VAD frame size
+const FRAME_MS: usize = 10;
+const FRAME_SAMPLES: usize = SAMPLE_RATE * FRAME_MS / 1000;
+/// Final transcript to first synthesised audio
+const MAX_RESPONSE_LATENCY: Duration = Duration::from_millis(1000);
+/// Caller speech onset to barge-in while the agent is speaking
+const MAX_BARGE_IN_LATENCY: Duration = Duration::from_millis(600);
+
+#[derive(Debug, Deserialize)]
+struct FixtureManifest {
+    fixtures: Vec<Fixture>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct Fixture {
+    name: String,
+    transcript: String,
+    reply: String,
+    #[serde(default = "default_language")]
+    language: String,
+    /// Recorded 16 kHz mono WAV, relative to the manifest
+    #[serde(default)]
+    file: Option<String>,
+    /// Synthetic audio plan, used when `file` is unset
+    #[serde(default)]
+    segments: Vec<Segment>,
+    #[serde(default = "default_max_turn_latency_ms")]
+    max_turn_latency_ms: u64,
+    /// Word error rate allowed against a real STT model
+    #[serde(default = "default_max_wer")]
+    #[cfg_attr(not(feature = "real-models"), allow(dead_code))]
+    max_wer: f32,
+}
+
+/// One stretch of synthetic audio; exactly one field is set
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct Segment {
+    speech_ms: usize,
+    silence_ms: usize,
+}
+
+fn default_language() -> String {
+    "en".to_string()
+}
+
+fn default_max_turn_latency_ms() -> u64 {
+    1600
+}
+
+fn default_max_wer() -> f32 {
+    0.3
+}
+
+fn fixtures_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/audio")
+}
+
+fn backend_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..")
+}
+
+fn load_fixtures() -> Vec<Fixture> {
+    let path = fixtures_dir().join("fixtures.yaml");
+    let text = std::fs::read_to_string(&path).expect("fixture manifest should exist");
+    let manifest: FixtureManifest =
+        serde_yaml::from_str(&text).expect("fixture manifest should parse");
+    manifest.fixtures
+}
+
+/// Voiced-speech stand-in: a 140 Hz harmonic stack with a syllable-rate
+/// envelope, loud enough for the energy VAD throughout
+fn synthesize(segments: &[Segment]) -> Vec<f32> {
+    let mut samples = Vec::new();
+    for segment in segments {
+        let speech = segment.speech_ms * SAMPLE_RATE / 1000;
+        for i in 0..speech {
+            let t = i as f32 / SAMPLE_RATE as f32;
+            let envelope = 0.7 + 0.3 * (2.0 * std::f32::consts::PI * 4.0 * t).sin();
+            let voiced: f32 = (1..=4)
+                .map(|h| (2.0 * std::f32::consts::PI * 140.0 * h as f32 * t).sin() / h as f32)
+                .sum();
+            samples.push(0.3 * envelope * voiced);
+        }
+        samples.extend(std::iter::repeat(0.0).take(segment.silence_ms * SAMPLE_RATE / 1000));
+    }
+    samples
+}
+
+fn read_wav(path: &Path) -> Vec<f32> {
+    let mut reader =
+        hound::WavReader::open(path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+    let spec = reader.spec();
+    assert_eq!(
+        (spec.sample_rate, spec.channels),
+        (SAMPLE_RATE as u32, 1),
+        "{}: fixtures must be 16 kHz mono",
+        path.display()
+    );
+    match spec.sample_format {
+        hound::SampleFormat::Float => reader.samples::<f32>().map(|s| s.unwrap()).collect(),
+        hound::SampleFormat::Int => {
+            let scale = (1i64 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .map(|s| s.unwrap() as f32 / scale)
+                .collect()
+        },
+    }
+}
+
+/// Fixture audio, or `None` when a recorded fixture's file is missing
+fn fixture_audio(fixture: &Fixture) -> Option<Vec<f32>> {
+    match fixture.file {
+        Some(ref file) => {
+            let path = fixtures_dir().join(file);
+            if !path.exists() {
+                eprintln!("skipping {}: {} not found", fixture.name, path.display());
+                return None;
+            }
+            Some(read_wav(&path))
+        },
+        None => Some(synthesize(&fixture.segments)),
+    }
+}
+
+#[cfg(not(feature = "real-models"))]
+fn build_pipeline(fixture: &Fixture) -> VoicePipeline {
+    VoicePipeline::simple(PipelineConfig::default())
+        .expect("pipeline should build without models")
+        .with_stt(voice_agent_pipeline::ScriptedSttBackend::new(
+            fixture.language.clone(),
+            [fixture.transcript.clone()],
+        ))
+}
+
+#[cfg(feature = "real-models")]
+fn build_pipeline(fixture: &Fixture) -> VoicePipeline {
+    // Model paths in the pipeline are relative to the backend directory
+    std::env::set_current_dir(backend_dir()).expect("backend directory should exist");
+    let mut config = PipelineConfig::default();
+    config.stt.language = Some(fixture.language.clone());
+    VoicePipeline::with_indicconformer("models/stt/indicconformer", config)
+        .expect("IndicConformer model should load from models/stt/indicconformer")
+}
+
+fn build_agent(fixture: &Fixture) -> DomainAgent {
+    let domain = MasterDomainConfig::load("gold_loan", backend_dir().join("config"))
+        .expect("gold_loan domain config should load");
+    let agent = DomainAgent::new(
+        format!("audio-{}", fixture.name),
+        AgentConfig::default(),
+        Arc::new(domain),
+    );
+    agent.set_language_model(Arc::new(ScriptedLanguageModel::new(fixture.reply.clone())));
+    agent
+}
+
+/// What happened during one caller turn
+struct TurnOutcome {
+    transcript: String,
+    response: String,
+    /// End of speech to final transcript
+    turn_latency: Duration,
+    /// Final transcript to first synthesised audio
+    response_latency: Duration,
+    audio_frames: usize,
+}
+
+/// Feed audio at real-time pace; returns the final transcript and when it
+/// arrived, measured from the last voiced frame
+async fn listen(
+    pipeline: &VoicePipeline,
+    events: &mut tokio::sync::broadcast::Receiver<PipelineEvent>,
+    audio: &[f32],
+) -> Option<(String, Duration)> {
+    let mut ticker = tokio::time::interval(Duration::from_millis(FRAME_MS as u64));
+    let mut last_voiced = Instant::now();
+    for (sequence, chunk) in audio.chunks(FRAME_SAMPLES).enumerate() {
+        ticker.tick().await;
+        let frame = AudioFrame::new(
+            chunk.to_vec(),
+            SampleRate::Hz16000,
+            Channels::Mono,
+            sequence as u64,
+        );
+        if frame.energy_db > -40.0 {
+            last_voiced = Instant::now();
+        }
+        pipeline
+            .process_audio(frame)
+            .await
+            .expect("pipeline should accept audio");
+
+        while let Ok(event) = events.try_recv() {
+            if let PipelineEvent::FinalTranscript(transcript) = event {
+                return Some((transcript.text, last_voiced.elapsed()));
+            }
+        }
+    }
+    None
+}
+
+/// Run one fixture turn end to end
+async fn run_turn(pipeline: &VoicePipeline, agent: &DomainAgent, audio: &[f32]) -> TurnOutcome {
+    let mut events = pipeline.subscribe();
+    let (transcript, turn_latency) = listen(pipeline, &mut events, audio)
+        .await
+        .expect("turn should complete before the fixture ends");
+    let heard = Instant::now();
+
+    let response = agent
+        .process(&transcript)
+        .await
+        .expect("agent should answer");
+
+    let (tx, rx) = mpsc::channel::<String>(8);
+    let mut audio_rx = pipeline
+        .speak_streaming(rx, agent.user_language())
+        .await
+        .expect("TTS chain should start");
+    tx.send(response.clone()).await.unwrap();
+    drop(tx);
+
+    let mut response_latency = None;
+    let mut audio_frames = 0;
+    while let Ok(Some(frame)) = timeout(Duration::from_millis(500), audio_rx.recv()).await {
+        if let Frame::AudioOutput(_) = frame {
+            response_latency.get_or_insert_with(|| heard.elapsed());
+            audio_frames += 1;
+        }
+    }
+
+    TurnOutcome {
+        transcript,
+        response,
+        turn_latency,
+        response_latency: response_latency.unwrap_or(Duration::MAX),
+        audio_frames,
+    }
+}
+
+#[cfg(feature = "real-models")]
+fn word_error_rate(reference: &str, hypothesis: &str) -> f32 {
+    let reference: Vec<String> = reference
+        .split_whitespace()
+        .map(|w| w.to_lowercase())
+        .collect();
+    let hypothesis: Vec<String> = hypothesis
+        .split_whitespace()
+        .map(|w| w.to_lowercase())
+        .collect();
+    let mut row: Vec<usize> = (0..=hypothesis.len()).collect();
+    for (i, r) in reference.iter().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, h) in hypothesis.iter().enumerate() {
+            let substitution = previous + usize::from(r != h);
+            previous = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(previous + 1);
+        }
+    }
+    row[hypothesis.len()] as f32 / reference.len().max(1) as f32
+}
+
+#[cfg(not(feature = "real-models"))]
+fn check_transcript(fixture: &Fixture, transcript: &str) {
+    assert_eq!(
+        transcript, fixture.transcript,
+        "{}: transcript",
+        fixture.name
+    );
+}
+
+#[cfg(feature = "real-models")]
+fn check_transcript(fixture: &Fixture, transcript: &str) {
+    let wer = word_error_rate(&fixture.transcript, transcript);
+    assert!(
+        wer <= fixture.max_wer,
+        "{}: WER {:.2} for {:?} (expected {:?})",
+        fixture.name,
+        wer,
+        transcript,
+        fixture.transcript
+    );
+}
+
+#[tokio::test]
+async fn test_fixture_golden_path() {
+    let mut ran = 0;
+    for fixture in load_fixtures() {
+        // Synthetic tones are not speech to a real VAD/STT
+        if cfg!(feature = "real-models") && fixture.file.is_none() {
+            continue;
+        }
+        let Some(audio) = fixture_audio(&fixture) else {
+            continue;
+        };
+
+        let pipeline = build_pipeline(&fixture);
+        let agent = build_agent(&fixture);
+        let outcome = run_turn(&pipeline, &agent, &audio).await;
+
+        check_transcript(&fixture, &outcome.transcript);
+        assert!(
+            outcome.turn_latency <= Duration::from_millis(fixture.max_turn_latency_ms),
+            "{}: end of speech to final transcript took {:?}",
+            fixture.name,
+            outcome.turn_latency
+        );
+        assert!(
+            outcome.response_latency <= MAX_RESPONSE_LATENCY,
+            "{}: first audio after {:?}",
+            fixture.name,
+            outcome.response_latency
+        );
+        assert!(
+            !outcome.response.is_empty(),
+            "{}: empty response",
+            fixture.name
+        );
+        assert!(outcome.audio_frames > 0, "{}: no audio", fixture.name);
+
+        let turns = agent.conversation().agentic_memory().get_all_turns();
+        let user_turn = turns.iter().find(|t| t.role == TurnRole::User);
+        assert_eq!(
+            user_turn.map(|t| t.content.as_str()),
+            Some(outcome.transcript.as_str()),
+            "{}: agent should see the transcript",
+            fixture.name
+        );
+        ran += 1;
+    }
+
+    if ran == 0 {
+        eprintln!("no audio fixtures available for this configuration");
+    }
+}
+
+#[tokio::test]
+#[cfg_attr(
+    feature = "real-models",
+    ignore = "synthetic barge-in audio is not speech to a real VAD"
+)]
+async fn test_barge_in_while_speaking() {
+    let fixture = load_fixtures()
+        .into_iter()
+        .find(|f| f.file.is_none())
+        .expect("a synthetic fixture");
+    let pipeline = build_pipeline(&fixture);
+    let agent = build_agent(&fixture);
+
+    let audio = synthesize(&fixture.segments);
+    run_turn(&pipeline, &agent, &audio).await;
+    assert_eq!(pipeline.state(), PipelineState::Speaking);
+
+    // Caller talks over the reply
+    let mut events = pipeline.subscribe();
+    let interruption = synthesize(&[Segment {
+        speech_ms: 600,
+        silence_ms: 0,
+    }]);
+    let onset = Instant::now();
+    let mut barged_in = None;
+    let mut ticker = tokio::time::interval(Duration::from_millis(FRAME_MS as u64));
+    for (sequence, chunk) in interruption.chunks(FRAME_SAMPLES).enumerate() {
+        ticker.tick().await;
+        let frame = AudioFrame::new(
+            chunk.to_vec(),
+            SampleRate::Hz16000,
+            Channels::Mono,
+            sequence as u64,
+        );
+        pipeline.process_audio(frame).await.unwrap();
+        while let Ok(event) = events.try_recv() {
+            if let PipelineEvent::BargeIn { .. } = event {
+                barged_in.get_or_insert_with(|| onset.elapsed());
+            }
+        }
+        if barged_in.is_some() {
+            break;
+        }
+    }
+
+    let latency = barged_in.expect("talking over the agent should barge in");
+    assert!(
+        latency <= MAX_BARGE_IN_LATENCY,
+        "barge-in took {:?}",
+        latency
+    );
+    assert_eq!(pipeline.state(), PipelineState::Listening);
+}
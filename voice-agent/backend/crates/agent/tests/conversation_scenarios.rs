@@ -0,0 +1,37 @@
+//! Regression tests that run the domain's scripted conversation scenarios
+//!
+//! Every scenario under `config/domains/{domain}/scenarios/` must pass.
+//! Use the `simulate` binary to iterate on a single scenario.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use voice_agent_agent::{load_scenarios, AgentConfig, Simulator};
+use voice_agent_config::MasterDomainConfig;
+
+fn config_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../config")
+}
+
+#[tokio::test]
+async fn test_gold_loan_scenarios() {
+    let domain_config = MasterDomainConfig::load("gold_loan", config_dir())
+        .expect("gold_loan domain config should load");
+    let scenarios = load_scenarios(config_dir().join("domains/gold_loan/scenarios"))
+        .expect("scenario files should parse");
+    assert!(!scenarios.is_empty());
+
+    let simulator = Simulator::new(Arc::new(domain_config), AgentConfig::default());
+    let failed: Vec<String> = simulator
+        .run_all(&scenarios)
+        .await
+        .into_iter()
+        .filter(|r| !r.passed())
+        .map(|r| format!("{}: {:?}", r.name, r.failures))
+        .collect();
+    assert!(
+        failed.is_empty(),
+        "failed scenarios:\n{}",
+        failed.join("\n")
+    );
+}
@@ -758,7 +758,10 @@ async fn test_tool_registry_integration() {
     // Original 5: check_eligibility, calculate_savings, capture_lead, schedule_appointment, find_branches
     // P0 added 3: get_gold_price, escalate_to_human, send_sms
     // Phase 6 added 2: get_document_checklist, compare_lenders
-    assert_eq!(registry.len(), 10);
+    // Then check_top_up_eligibility for existing customers
+    // and check_slot_availability / hold_appointment_slot for branch visits,
+    // then schedule_callback for callback requests
+    assert_eq!(registry.len(), 14);
 
     // Test executing each tool type
     let eligibility_result = registry
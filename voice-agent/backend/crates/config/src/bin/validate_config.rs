@@ -0,0 +1,157 @@
+//! validate-config: check domain YAML for referential integrity
+//!
+//! Runs the same `ConfigValidator` the server uses at startup, without
+//! booting the server. Intended for CI and pre-deploy checks.
+//!
+//! ```text
+//! validate-config [--config-dir DIR] [--strict] [--no-warnings]
+//!                 [--languages en,hi] [DOMAIN_ID...]
+//! ```
+//!
+//! With no domain IDs, `DOMAIN_ID` is used if set, otherwise every directory
+//! under `{config-dir}/domains/` is validated.
+//!
+//! Exit codes: 0 = passed, 1 = validation failed, 2 = usage or load error.
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use voice_agent_config::{ConfigValidator, ValidationSeverity};
+
+const USAGE: &str = "Usage: validate-config [--config-dir DIR] [--strict] [--no-warnings] \
+[--languages en,hi] [DOMAIN_ID...]
+
+Options:
+  --config-dir DIR    Config root containing domains/ (default: config)
+  --strict            Fail on errors as well as critical errors
+  --no-warnings       Do not report warnings
+  --languages LIST    Comma-separated languages every prompt must provide
+                      (default: languages used by prompts.greetings)";
+
+struct Args {
+    config_dir: PathBuf,
+    strict: bool,
+    warnings: bool,
+    languages: Vec<String>,
+    domains: Vec<String>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        config_dir: PathBuf::from("config"),
+        strict: false,
+        warnings: true,
+        languages: Vec::new(),
+        domains: Vec::new(),
+    };
+
+    let mut iter = std::env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--config-dir" => {
+                let dir = iter.next().ok_or("--config-dir requires a value")?;
+                args.config_dir = PathBuf::from(dir);
+            }
+            "--strict" => args.strict = true,
+            "--no-warnings" => args.warnings = false,
+            "--languages" => {
+                let list = iter.next().ok_or("--languages requires a value")?;
+                args.languages = list
+                    .split(',')
+                    .map(|l| l.trim().to_string())
+                    .filter(|l| !l.is_empty())
+                    .collect();
+            }
+            "-h" | "--help" => return Err(String::new()),
+            flag if flag.starts_with('-') => return Err(format!("Unknown option: {}", flag)),
+            domain => args.domains.push(domain.to_string()),
+        }
+    }
+
+    Ok(args)
+}
+
+/// Domain IDs to validate when none are given on the command line
+fn discover_domains(config_dir: &Path) -> Vec<String> {
+    if let Ok(id) = std::env::var("DOMAIN_ID") {
+        if !id.is_empty() {
+            return vec![id];
+        }
+    }
+
+    let mut domains: Vec<String> = std::fs::read_dir(config_dir.join("domains"))
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .filter(|e| e.path().join("domain.yaml").exists())
+                .filter_map(|e| e.file_name().into_string().ok())
+                .collect()
+        })
+        .unwrap_or_default();
+    domains.sort();
+    domains
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(msg) => {
+            if !msg.is_empty() {
+                eprintln!("{}\n", msg);
+            }
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let domains = if args.domains.is_empty() {
+        discover_domains(&args.config_dir)
+    } else {
+        args.domains.clone()
+    };
+    if domains.is_empty() {
+        eprintln!(
+            "No domains found under {}",
+            args.config_dir.join("domains").display()
+        );
+        return ExitCode::from(2);
+    }
+
+    let validator = ConfigValidator::new()
+        .with_warnings(args.warnings)
+        .with_required_languages(args.languages.clone());
+
+    let mut failed = false;
+    for domain in &domains {
+        let result = match validator.validate_dir(domain, &args.config_dir) {
+            Ok(result) => result,
+            Err(e) => {
+                eprintln!("Domain '{}': failed to load: {}", domain, e);
+                return ExitCode::from(2);
+            }
+        };
+
+        let mut errors: Vec<_> = result.errors.iter().collect();
+        errors.sort_by(|a, b| b.severity.cmp(&a.severity).then_with(|| a.path().cmp(&b.path())));
+        for error in errors {
+            if error.severity == ValidationSeverity::Warning && !args.warnings {
+                continue;
+            }
+            println!("  {}", error);
+        }
+        println!("{}", result.summary());
+
+        let passed = if args.strict {
+            result.is_strict_ok()
+        } else {
+            result.is_ok()
+        };
+        failed |= !passed;
+    }
+
+    if failed {
+        ExitCode::from(1)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -29,6 +29,16 @@ pub mod endpoints {
         std::env::var("OLLAMA_URL").unwrap_or_else(|_| "http://localhost:11434".to_string())
     });
 
+    /// llama.cpp server OpenAI-compatible endpoint (env: LLAMA_CPP_URL)
+    pub static LLAMA_CPP_DEFAULT: Lazy<String> = Lazy::new(|| {
+        std::env::var("LLAMA_CPP_URL").unwrap_or_else(|_| "http://localhost:8080/v1".to_string())
+    });
+
+    /// vLLM OpenAI-compatible endpoint (env: VLLM_URL)
+    pub static VLLM_DEFAULT: Lazy<String> = Lazy::new(|| {
+        std::env::var("VLLM_URL").unwrap_or_else(|_| "http://localhost:8000/v1".to_string())
+    });
+
     /// Qdrant vector store endpoint (env: QDRANT_URL)
     pub static QDRANT_DEFAULT: Lazy<String> = Lazy::new(|| {
         std::env::var("QDRANT_URL").unwrap_or_else(|_| "http://127.0.0.1:6333".to_string())
@@ -18,6 +18,9 @@ pub struct BranchesConfig {
     /// Mobile/doorstep service configuration
     #[serde(default)]
     pub doorstep_service: DoorstepServiceConfig,
+    /// Branch visit slot capacity and hold settings
+    #[serde(default)]
+    pub appointments: AppointmentSlotConfig,
 }
 
 impl Default for BranchesConfig {
@@ -26,6 +29,7 @@ impl Default for BranchesConfig {
             branches: Vec::new(),
             defaults: BranchDefaults::default(),
             doorstep_service: DoorstepServiceConfig::default(),
+            appointments: AppointmentSlotConfig::default(),
         }
     }
 }
@@ -63,6 +67,42 @@ impl BranchesConfig {
         self.branches.iter().find(|b| b.branch_id == branch_id)
     }
 
+    /// Get service locations matching a free-text place
+    ///
+    /// Matches branch ID, area, name, pincode, city or address; branches
+    /// matched on ID, area or name come before city/address-only matches.
+    pub fn find_near(&self, location: &str) -> Vec<&BranchEntry> {
+        let needle = location.trim().to_lowercase();
+        if needle.is_empty() {
+            return Vec::new();
+        }
+
+        let mut close = Vec::new();
+        let mut wide = Vec::new();
+        for branch in self.branches.iter().filter(|b| b.service_available) {
+            if branch.branch_id.to_lowercase() == needle
+                || branch.area.to_lowercase().contains(&needle)
+                || branch.name.to_lowercase().contains(&needle)
+                || branch.pincode == needle
+            {
+                close.push(branch);
+            } else if branch.city.to_lowercase().contains(&needle)
+                || branch.address.to_lowercase().contains(&needle)
+            {
+                wide.push(branch);
+            }
+        }
+        close.extend(wide);
+        close
+    }
+
+    /// Visits a branch can take per time slot
+    pub fn slot_capacity(&self, branch_id: &str) -> u32 {
+        self.get_branch(branch_id)
+            .and_then(|b| b.slot_capacity)
+            .unwrap_or(self.appointments.slot_capacity)
+    }
+
     /// Get locations where service is available
     pub fn service_locations(&self) -> Vec<&BranchEntry> {
         self.branches
@@ -119,6 +159,9 @@ pub struct BranchEntry {
     /// List of available facilities/services
     #[serde(default)]
     pub facilities: Vec<String>,
+    /// Visits per time slot (overrides `appointments.slot_capacity`)
+    #[serde(default)]
+    pub slot_capacity: Option<u32>,
 }
 
 fn default_true() -> bool {
@@ -181,6 +224,67 @@ impl Default for DoorstepServiceConfig {
     }
 }
 
+/// Branch visit slot settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppointmentSlotConfig {
+    /// Visits per time slot at each branch
+    #[serde(default = "default_slot_capacity")]
+    pub slot_capacity: u32,
+    /// How long a slot offered during a call stays held, in seconds
+    #[serde(default = "default_hold_ttl_secs")]
+    pub hold_ttl_secs: u64,
+    /// Days ahead searched for free slots
+    #[serde(default = "default_search_days")]
+    pub search_days: u32,
+    /// Free slots offered per availability query
+    #[serde(default = "default_slots_offered")]
+    pub slots_offered: usize,
+    /// Days branches are closed (e.g. "Sunday")
+    #[serde(default)]
+    pub closed_days: Vec<String>,
+    /// Branch local time offset from UTC, used to skip slots already past today
+    #[serde(default)]
+    pub utc_offset_minutes: i32,
+}
+
+fn default_slot_capacity() -> u32 {
+    2
+}
+
+fn default_hold_ttl_secs() -> u64 {
+    600
+}
+
+fn default_search_days() -> u32 {
+    7
+}
+
+fn default_slots_offered() -> usize {
+    3
+}
+
+impl Default for AppointmentSlotConfig {
+    fn default() -> Self {
+        Self {
+            slot_capacity: default_slot_capacity(),
+            hold_ttl_secs: default_hold_ttl_secs(),
+            search_days: default_search_days(),
+            slots_offered: default_slots_offered(),
+            closed_days: Vec::new(),
+            utc_offset_minutes: 0,
+        }
+    }
+}
+
+impl AppointmentSlotConfig {
+    /// Whether branches are closed on a weekday ("Sun", "sunday", ...)
+    pub fn is_closed_day(&self, day: &str) -> bool {
+        let prefix = |name: &str| name.to_lowercase().chars().take(3).collect::<String>();
+        let day = prefix(day);
+        day.chars().count() == 3 && self.closed_days.iter().any(|closed| prefix(closed) == day)
+    }
+}
+
 /// Errors when loading location configuration
 #[derive(Debug)]
 pub enum BranchesConfigError {
@@ -263,6 +367,7 @@ branches:
                     service_available: true,
                     timing: "10-5".to_string(),
                     facilities: vec![],
+                    slot_capacity: None,
                 },
                 BranchEntry {
                     branch_id: "L2".to_string(),
@@ -275,10 +380,12 @@ branches:
                     service_available: true,
                     timing: "10-5".to_string(),
                     facilities: vec![],
+                    slot_capacity: None,
                 },
             ],
             defaults: BranchDefaults::default(),
             doorstep_service: DoorstepServiceConfig::default(),
+            appointments: AppointmentSlotConfig::default(),
         };
 
         let mumbai = config.find_by_city("mumbai");
@@ -301,6 +408,7 @@ branches:
                     service_available: true,
                     timing: "10-5".to_string(),
                     facilities: vec![],
+                    slot_capacity: None,
                 },
                 BranchEntry {
                     branch_id: "L2".to_string(),
@@ -313,14 +421,54 @@ branches:
                     service_available: false, // Service not available
                     timing: "10-5".to_string(),
                     facilities: vec![],
+                    slot_capacity: None,
                 },
             ],
             defaults: BranchDefaults::default(),
             doorstep_service: DoorstepServiceConfig::default(),
+            appointments: AppointmentSlotConfig::default(),
         };
 
         let service_locs = config.service_locations();
         assert_eq!(service_locs.len(), 1);
         assert_eq!(service_locs[0].branch_id, "L1");
     }
+
+    #[test]
+    fn test_find_near_and_slot_settings() {
+        let yaml = r#"
+branches:
+  - branch_id: "L1"
+    name: "Location 1"
+    city: "Mumbai"
+    area: "Bandra West"
+    address: "Hill Road"
+    phone: "1"
+  - branch_id: "L2"
+    name: "Location 2"
+    city: "Mumbai"
+    area: "Andheri West"
+    address: "S.V. Road"
+    phone: "2"
+    slot_capacity: 5
+appointments:
+  slot_capacity: 3
+  closed_days: ["Sunday"]
+"#;
+        let config: BranchesConfig = serde_yaml::from_str(yaml).unwrap();
+
+        let near = config.find_near("andheri");
+        assert_eq!(near.len(), 1);
+        assert_eq!(near[0].branch_id, "L2");
+        // City matches rank after area matches
+        let near = config.find_near("Mumbai");
+        assert_eq!(near.len(), 2);
+        assert!(config.find_near("  ").is_empty());
+
+        assert_eq!(config.slot_capacity("L2"), 5);
+        assert_eq!(config.slot_capacity("L1"), 3);
+        assert_eq!(config.appointments.hold_ttl_secs, 600);
+        assert!(config.appointments.is_closed_day("Sun"));
+        assert!(!config.appointments.is_closed_day("Mon"));
+    }
 }
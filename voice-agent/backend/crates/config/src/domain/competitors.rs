@@ -183,6 +183,9 @@ pub struct CompetitorEntry {
     pub weaknesses: Vec<String>,
     #[serde(default)]
     pub processing_time: String,
+    /// Foreclosure charge (% of outstanding) when switching away
+    #[serde(default)]
+    pub prepayment_penalty_percent: Option<f64>,
 }
 
 fn default_ltv() -> f64 {
@@ -214,6 +217,15 @@ pub struct CompetitorDefaults {
     pub local_lender_rate: f64,
     #[serde(default = "default_bank_rate")]
     pub bank_rate: f64,
+    /// Foreclosure charge (% of outstanding) for lenders without their own
+    #[serde(default)]
+    pub prepayment_penalty_percent: f64,
+    /// Stored competitor rates older than this are flagged as possibly outdated
+    #[serde(default = "default_rate_max_age_days")]
+    pub rate_max_age_days: u32,
+    /// Product key used to look up stored competitor rates (empty = any product)
+    #[serde(default)]
+    pub rate_product: String,
 }
 
 fn default_nbfc_rate() -> f64 {
@@ -228,12 +240,19 @@ fn default_bank_rate() -> f64 {
     11.0
 }
 
+fn default_rate_max_age_days() -> u32 {
+    30
+}
+
 impl Default for CompetitorDefaults {
     fn default() -> Self {
         Self {
             nbfc_rate: default_nbfc_rate(),
             local_lender_rate: default_local_rate(),
             bank_rate: default_bank_rate(),
+            prepayment_penalty_percent: 0.0,
+            rate_max_age_days: default_rate_max_age_days(),
+            rate_product: String::new(),
         }
     }
 }
@@ -301,6 +320,7 @@ comparison_points:
                 strengths: vec![],
                 weaknesses: vec![],
                 processing_time: "Same day".to_string(),
+                prepayment_penalty_percent: None,
             },
         );
 
@@ -57,6 +57,18 @@ pub struct ComplianceConfig {
     /// Key is language code (en, hi, mr, ta, etc.), value is the disclosure message
     #[serde(default)]
     pub ai_disclosures: HashMap<String, String>,
+
+    /// Regex patterns for specific rate promises ("you will get 9%")
+    #[serde(default)]
+    pub rate_promise_patterns: Vec<String>,
+
+    /// Words that make a competitor mention disparaging
+    #[serde(default)]
+    pub disparaging_terms: Vec<String>,
+
+    /// Templated safe responses by language, spoken when output is blocked
+    #[serde(default)]
+    pub safe_responses: HashMap<String, String>,
 }
 
 fn default_version() -> String {
@@ -270,6 +282,20 @@ impl ComplianceConfig {
         // Default message if nothing configured
         "This is an AI assistant. You can speak with a human agent at any time by saying 'speak to agent'."
     }
+
+    /// Get the safe response for a language
+    ///
+    /// Falls back to English, then to a built-in message.
+    pub fn get_safe_response(&self, language: &str) -> &str {
+        self.safe_responses
+            .get(language)
+            .or_else(|| self.safe_responses.get("en"))
+            .map(|s| s.as_str())
+            .unwrap_or(
+                "I'm sorry, I can't confirm that. Final terms depend on your gold valuation \
+                 and eligibility. Would you like me to connect you with our team?",
+            )
+    }
 }
 
 /// Errors during compliance config loading
@@ -321,4 +347,19 @@ mod tests {
         assert!(config.is_forbidden("GUARANTEED APPROVAL"));
         assert!(!config.is_forbidden("High approval rate"));
     }
+
+    #[test]
+    fn test_safe_response_fallback() {
+        let mut config = ComplianceConfig::default();
+        assert!(!config.get_safe_response("hi").is_empty());
+
+        config
+            .safe_responses
+            .insert("en".to_string(), "English safe".to_string());
+        config
+            .safe_responses
+            .insert("hi".to_string(), "Hindi safe".to_string());
+        assert_eq!(config.get_safe_response("hi"), "Hindi safe");
+        assert_eq!(config.get_safe_response("ta"), "English safe");
+    }
 }
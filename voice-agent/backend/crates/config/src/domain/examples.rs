@@ -0,0 +1,290 @@
+//! Few-Shot Example Configuration
+//!
+//! Annotated exchanges loaded from examples.yaml. Each exemplar is tagged
+//! with the intents and goals it demonstrates; the agent retrieves the most
+//! relevant few for the current turn and shows them in the prompt, so small
+//! models copy the tool-call format and argument names.
+//!
+//! ```yaml
+//! top_k: 3
+//! examples:
+//!   - id: eligibility_with_weight
+//!     intents: [eligibility_check]
+//!     goals: [eligibility_check]
+//!     user: "I have 40 grams of 22 karat gold, how much can I get?"
+//!     tool_call:
+//!       name: check_eligibility
+//!       arguments: { gold_weight_grams: 40, gold_purity: "22K" }
+//!     tool_result: "Eligible for up to ₹1,80,000"
+//!     assistant: "With 40 grams of 22 karat gold you can get up to ₹1,80,000."
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Root examples configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExamplesConfig {
+    /// Examples shown per turn
+    #[serde(default = "default_top_k")]
+    pub top_k: usize,
+    /// Annotated exchanges
+    #[serde(default)]
+    pub examples: Vec<Exemplar>,
+}
+
+fn default_top_k() -> usize {
+    3
+}
+
+impl Default for ExamplesConfig {
+    fn default() -> Self {
+        Self {
+            top_k: default_top_k(),
+            examples: Vec::new(),
+        }
+    }
+}
+
+/// One annotated exchange
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Exemplar {
+    /// Unique identifier
+    pub id: String,
+    /// Intents this exchange demonstrates
+    #[serde(default)]
+    pub intents: Vec<String>,
+    /// Goals this exchange demonstrates
+    #[serde(default)]
+    pub goals: Vec<String>,
+    /// What the customer says
+    pub user: String,
+    /// Tool the agent calls before answering, if any
+    #[serde(default)]
+    pub tool_call: Option<ExemplarToolCall>,
+    /// What the tool returned
+    #[serde(default)]
+    pub tool_result: Option<String>,
+    /// The agent's spoken answer
+    pub assistant: String,
+}
+
+/// Tool call in an exemplar
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExemplarToolCall {
+    pub name: String,
+    #[serde(default)]
+    pub arguments: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Score for an exemplar tagged with the turn's intent
+const INTENT_MATCH: f32 = 2.0;
+/// Score for an exemplar tagged with the current goal
+const GOAL_MATCH: f32 = 1.0;
+
+impl ExamplesConfig {
+    /// Load from a YAML file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ExamplesConfigError> {
+        let content = std::fs::read_to_string(path.as_ref()).map_err(|e| {
+            ExamplesConfigError::FileNotFound(path.as_ref().display().to_string(), e.to_string())
+        })?;
+
+        serde_yaml::from_str(&content).map_err(|e| ExamplesConfigError::ParseError(e.to_string()))
+    }
+
+    /// Number of exemplars
+    pub fn len(&self) -> usize {
+        self.examples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.examples.is_empty()
+    }
+
+    /// The `top_k` exemplars most relevant to a turn, best first
+    ///
+    /// Exemplars tagged with the turn's intent rank above those tagged with
+    /// the current goal; word overlap with `query` breaks ties and brings in
+    /// untagged exemplars. Exemplars sharing nothing with the turn are left
+    /// out.
+    pub fn retrieve(
+        &self,
+        intent: Option<&str>,
+        goal: Option<&str>,
+        query: &str,
+    ) -> Vec<&Exemplar> {
+        let query_words = words(query);
+        let tagged = |tags: &[String], value: Option<&str>| {
+            value.is_some_and(|value| tags.iter().any(|tag| tag == value))
+        };
+
+        let mut scored: Vec<(f32, &Exemplar)> = self
+            .examples
+            .iter()
+            .map(|example| {
+                let mut score = overlap(&query_words, &words(&example.user));
+                if tagged(&example.intents, intent) {
+                    score += INTENT_MATCH;
+                }
+                if tagged(&example.goals, goal) {
+                    score += GOAL_MATCH;
+                }
+                (score, example)
+            })
+            .filter(|(score, _)| *score > 0.0)
+            .collect();
+        scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+        scored
+            .into_iter()
+            .take(self.top_k)
+            .map(|(_, example)| example)
+            .collect()
+    }
+
+    /// Check that IDs are unique and every exchange is complete
+    pub fn validate(&self) -> Result<(), ExamplesConfigError> {
+        let mut ids = HashSet::new();
+        for example in &self.examples {
+            if !ids.insert(example.id.as_str()) {
+                return Err(ExamplesConfigError::DuplicateId(example.id.clone()));
+            }
+            let tool_unnamed = example
+                .tool_call
+                .as_ref()
+                .is_some_and(|call| call.name.trim().is_empty());
+            let unsaid = example.user.trim().is_empty() || example.assistant.trim().is_empty();
+            if unsaid || tool_unnamed {
+                return Err(ExamplesConfigError::Incomplete(example.id.clone()));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Exemplar {
+    /// The exchange as prompt text, tool call in the `[TOOL_CALL: ...]`
+    /// format the agent parses
+    pub fn render(&self) -> String {
+        let mut text = format!("Customer: {}\n", self.user);
+        if let Some(call) = &self.tool_call {
+            let arguments = serde_json::Value::Object(call.arguments.clone());
+            text.push_str(&format!(
+                "Assistant: [TOOL_CALL: {{\"name\": \"{}\", \"arguments\": {}}}]\n",
+                call.name, arguments
+            ));
+        }
+        if let Some(result) = &self.tool_result {
+            text.push_str(&format!("Tool result: {}\n", result));
+        }
+        text.push_str(&format!("Assistant: {}", self.assistant));
+        text
+    }
+}
+
+fn words(text: &str) -> HashSet<String> {
+    text.split_whitespace()
+        .map(|word| word.trim_matches(|c: char| c.is_ascii_punctuation() || c == '।'))
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Share of the exemplar's words the query also uses (0.0-1.0)
+fn overlap(query: &HashSet<String>, example: &HashSet<String>) -> f32 {
+    if example.is_empty() {
+        return 0.0;
+    }
+    query.intersection(example).count() as f32 / example.len() as f32
+}
+
+/// Errors when loading examples configuration
+#[derive(Debug)]
+pub enum ExamplesConfigError {
+    FileNotFound(String, String),
+    ParseError(String),
+    DuplicateId(String),
+    Incomplete(String),
+}
+
+impl std::fmt::Display for ExamplesConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FileNotFound(path, err) => {
+                write!(f, "Examples config not found at {}: {}", path, err)
+            },
+            Self::ParseError(err) => write!(f, "Failed to parse examples config: {}", err),
+            Self::DuplicateId(id) => write!(f, "Duplicate example id '{}'", id),
+            Self::Incomplete(id) => {
+                write!(f, "Example '{}' needs a user turn, an answer and a tool name", id)
+            },
+        }
+    }
+}
+
+impl std::error::Error for ExamplesConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> ExamplesConfig {
+        serde_yaml::from_str(
+            r#"
+top_k: 2
+examples:
+  - id: eligibility
+    intents: [eligibility_check]
+    goals: [eligibility_check]
+    user: "I have 40 grams of 22 karat gold, how much can I get?"
+    tool_call:
+      name: check_eligibility
+      arguments: { gold_weight_grams: 40, gold_purity: "22K" }
+    tool_result: "Eligible for up to 180000"
+    assistant: "You can get up to 1.8 lakh."
+  - id: transfer
+    intents: [balance_transfer]
+    goals: [balance_transfer]
+    user: "I pay 18 percent at Muthoot"
+    assistant: "Switching could save you money."
+  - id: branch
+    intents: [branch_inquiry]
+    user: "Where is your branch in Pune?"
+    assistant: "We have branches across Pune."
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_retrieve_by_intent_then_overlap() {
+        let config = config();
+        assert!(config.validate().is_ok());
+
+        let ids = |found: Vec<&Exemplar>| found.iter().map(|e| e.id.clone()).collect::<Vec<_>>();
+        let found = config.retrieve(Some("balance_transfer"), None, "where is the branch");
+        assert_eq!(ids(found), vec!["transfer", "branch"]);
+
+        let found = config.retrieve(None, Some("eligibility_check"), "hello");
+        assert_eq!(ids(found), vec!["eligibility"]);
+        assert!(config.retrieve(None, None, "namaste").is_empty());
+    }
+
+    #[test]
+    fn test_render_tool_call() {
+        let config = config();
+        let text = config.examples[0].render();
+        assert!(text.starts_with("Customer: I have 40 grams"));
+        assert!(text.contains(r#"[TOOL_CALL: {"name": "check_eligibility", "arguments": {"#));
+        assert!(text.contains(r#""gold_weight_grams":40"#));
+        assert!(text.contains("Tool result: Eligible for up to 180000\n"));
+        assert!(text.ends_with("Assistant: You can get up to 1.8 lakh."));
+    }
+
+    #[test]
+    fn test_validate_rejects_duplicates() {
+        let mut config = config();
+        config.examples[1].id = "eligibility".to_string();
+        assert!(matches!(config.validate(), Err(ExamplesConfigError::DuplicateId(_))));
+    }
+}
@@ -45,6 +45,10 @@ pub struct ExtractionPatternsConfig {
     /// Name exclusion list
     #[serde(default)]
     pub name_exclusions: Vec<String>,
+
+    /// Per-language extraction packs keyed by language code ("mr", "ta", "te")
+    #[serde(default)]
+    pub language_packs: HashMap<String, LanguagePackConfig>,
 }
 
 impl ExtractionPatternsConfig {
@@ -143,6 +147,13 @@ impl ExtractionPatternsConfig {
         }
     }
 
+    /// Get the extraction pack for a language ("ta" or "ta-IN")
+    pub fn language_pack(&self, lang: &str) -> Option<&LanguagePackConfig> {
+        self.language_packs
+            .get(lang)
+            .or_else(|| lang.split('-').next().and_then(|base| self.language_packs.get(base)))
+    }
+
     /// P21 FIX: Get repayment type patterns for a language
     /// Returns patterns compiled from config for repayment type detection
     pub fn repayment_patterns(&self, lang: &str) -> Vec<(&str, &str, f32)> {
@@ -806,6 +817,36 @@ pub struct ConfidenceBoostersConfig {
     pub rate: LanguageKeywords,
 }
 
+// =============================================================================
+// Language Pack Configuration
+// =============================================================================
+
+/// Slot extraction vocabulary for one language
+///
+/// Spoken number words and units are rewritten to digits and canonical unit
+/// names before the shared amount/weight patterns run, so "ஐந்து லட்சம்"
+/// (Tamil) reads "5 lakh".
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct LanguagePackConfig {
+    /// Number words and their values (e.g., "पाच": 5)
+    #[serde(default)]
+    pub number_words: HashMap<String, f64>,
+
+    /// Amount units: canonical unit ("lakh", "crore", "thousand") -> spoken forms
+    ///
+    /// Spoken forms match as prefixes so inflected forms ("லட்சத்துக்கு") count.
+    #[serde(default)]
+    pub amount_units: HashMap<String, Vec<String>>,
+
+    /// Weight units: canonical unit ("gram", "tola") -> spoken forms (prefixes)
+    #[serde(default)]
+    pub weight_units: HashMap<String, Vec<String>>,
+
+    /// Intent keywords: intent name -> keywords
+    #[serde(default)]
+    pub intent_keywords: HashMap<String, Vec<String>>,
+}
+
 // =============================================================================
 // Error Types
 // =============================================================================
@@ -843,6 +884,28 @@ mod tests {
         assert!(config.asset_quality.tiers.is_empty());
     }
 
+    #[test]
+    fn test_language_packs() {
+        let yaml = r#"
+language_packs:
+  ta:
+    number_words:
+      "ஐந்து": 5
+    amount_units:
+      lakh: ["லட்ச"]
+    weight_units:
+      gram: ["கிராம்"]
+    intent_keywords:
+      interest_rate_inquiry: ["வட்டி"]
+"#;
+        let config: ExtractionPatternsConfig = serde_yaml::from_str(yaml).unwrap();
+
+        let pack = config.language_pack("ta-IN").unwrap();
+        assert_eq!(pack.number_words.get("ஐந்து"), Some(&5.0));
+        assert_eq!(pack.amount_units["lakh"], vec!["லட்ச".to_string()]);
+        assert!(config.language_pack("te").is_none());
+    }
+
     #[test]
     fn test_currency_multiplier() {
         let config = ExtractionPatternsConfig::default();
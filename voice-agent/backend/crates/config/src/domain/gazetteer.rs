@@ -0,0 +1,180 @@
+//! Location Gazetteer Configuration
+//!
+//! States, cities and PIN code ranges loaded from gazetteer.yaml.
+//!
+//! At load time they are merged with the cities of extraction_patterns.yaml
+//! and the branch localities of tools/branches.yaml into one
+//! [`LocationGazetteer`], which slot extraction, the branch locator and STT
+//! entity boosting all read instead of keeping their own city lists.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use voice_agent_core::gazetteer::{City, Locality, LocationGazetteer};
+
+use super::branches::BranchEntry;
+use super::extraction_patterns::CityEntry;
+
+/// Root gazetteer configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct GazetteerConfig {
+    /// States and union territories served
+    #[serde(default)]
+    pub states: Vec<String>,
+
+    /// Cities with their state, aliases and PIN code ranges
+    #[serde(default)]
+    pub cities: Vec<City>,
+}
+
+impl GazetteerConfig {
+    /// Load from a YAML file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, GazetteerConfigError> {
+        let content = std::fs::read_to_string(path.as_ref()).map_err(|e| {
+            GazetteerConfigError::FileNotFound(path.as_ref().display().to_string(), e.to_string())
+        })?;
+
+        serde_yaml::from_str(&content).map_err(|e| GazetteerConfigError::ParseError(e.to_string()))
+    }
+
+    /// Check that every PIN code range holds 6-digit codes in order
+    pub fn validate(&self) -> Result<(), GazetteerConfigError> {
+        let pincodes = 100_000..=999_999;
+        for city in &self.cities {
+            let valid = city.pincodes.iter().all(|range| {
+                pincodes.contains(&range.from)
+                    && pincodes.contains(&range.to)
+                    && range.from <= range.to
+            });
+            if !valid {
+                return Err(GazetteerConfigError::InvalidPincodeRange(city.name.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Merge with the extraction pattern cities and branch localities
+    ///
+    /// Without any configured city, the built-in list of major Indian cities
+    /// is used, so lookups still work for a domain that only lists branches.
+    pub fn build(&self, cities: &[CityEntry], branches: &[BranchEntry]) -> LocationGazetteer {
+        let mut gazetteer = if self.cities.is_empty() && cities.is_empty() {
+            LocationGazetteer::builtin().clone()
+        } else {
+            LocationGazetteer::new()
+        };
+
+        for state in &self.states {
+            gazetteer.add_state(state);
+        }
+        for city in &self.cities {
+            gazetteer.add_city(city.clone());
+        }
+        for city in cities {
+            // Plain alternatives of the Hindi pattern are Devanagari names
+            let hindi = city.pattern_hi.iter().flat_map(|p| p.split('|'));
+            let plain = hindi.filter(|name| !name.contains('\\'));
+            let mut aliases = city.aliases.clone();
+            aliases.extend(plain.map(str::to_string));
+            gazetteer.add_city(City {
+                name: city.name.clone(),
+                aliases,
+                ..City::default()
+            });
+        }
+        for branch in branches {
+            gazetteer.add_locality(Locality {
+                name: branch.area.clone(),
+                city: branch.city.clone(),
+                branch_id: Some(branch.branch_id.clone()),
+                pincode: Some(branch.pincode.clone()).filter(|p| !p.is_empty()),
+            });
+        }
+        gazetteer
+    }
+}
+
+/// Errors when loading gazetteer configuration
+#[derive(Debug)]
+pub enum GazetteerConfigError {
+    FileNotFound(String, String),
+    ParseError(String),
+    InvalidPincodeRange(String),
+}
+
+impl std::fmt::Display for GazetteerConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FileNotFound(path, err) => {
+                write!(f, "Gazetteer config not found at {}: {}", path, err)
+            },
+            Self::ParseError(err) => write!(f, "Failed to parse gazetteer config: {}", err),
+            Self::InvalidPincodeRange(city) => {
+                write!(f, "City '{}' has an invalid PIN code range", city)
+            },
+        }
+    }
+}
+
+impl std::error::Error for GazetteerConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> GazetteerConfig {
+        serde_yaml::from_str(
+            r#"
+states: ["Karnataka", "Maharashtra"]
+cities:
+  - name: Bangalore
+    state: Karnataka
+    aliases: [Bengaluru]
+    pincodes:
+      - { from: 560001, to: 560117 }
+"#,
+        )
+        .unwrap()
+    }
+
+    fn branch(id: &str, city: &str, area: &str, pincode: &str) -> BranchEntry {
+        serde_yaml::from_str(&format!(
+            "{{branch_id: {}, name: {}, city: {}, area: {}, address: '', pincode: '{}', phone: ''}}",
+            id, area, city, area, pincode
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_build_merges_sources() {
+        let config = config();
+        assert!(config.validate().is_ok());
+
+        let pattern_city: CityEntry =
+            serde_yaml::from_str("{name: Mumbai, aliases: [Bombay], pattern_hi: 'मुंबई|बॉम्बे'}")
+                .unwrap();
+        let branches = [
+            branch("B1", "Bengaluru", "Koramangala", "560034"),
+            branch("B2", "Mumbai", "Powai", "400076"),
+        ];
+        let gazetteer = config.build(&[pattern_city], &branches);
+
+        assert_eq!(gazetteer.cities().len(), 2);
+        assert_eq!(gazetteer.state_of("bombay"), None);
+        assert!(gazetteer.city("मुंबई").is_some());
+        assert_eq!(gazetteer.localities_in("Bangalore").count(), 1);
+        let city = gazetteer
+            .city_for_pincode("400076")
+            .map(|c| c.name.as_str());
+        assert_eq!(city, Some("Mumbai"));
+    }
+
+    #[test]
+    fn test_builtin_fallback_and_validation() {
+        let gazetteer = GazetteerConfig::default().build(&[], &[]);
+        assert!(gazetteer.city("Chennai").is_some());
+
+        let mut config = config();
+        config.cities[0].pincodes[0].to = 56_011;
+        assert!(config.validate().is_err());
+    }
+}
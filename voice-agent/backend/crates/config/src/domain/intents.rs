@@ -2,8 +2,24 @@
 //!
 //! Defines config-driven intent definitions for the voice agent.
 //! Intents are loaded from domain config files instead of being hardcoded.
+//!
+//! When the detected intent's confidence is low, the agent can ask which of
+//! the top two intents the customer meant instead of guessing:
+//!
+//! ```yaml
+//! clarification:
+//!   enabled: true
+//!   below_confidence: 0.6
+//!   question:
+//!     en: "Did you want to {first} or {second}?"
+//! intents:
+//!   - name: eligibility_check
+//!     clarify_as:
+//!       en: "check your eligibility"
+//! ```
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::Path;
 
 /// Intents configuration loaded from intents.yaml
@@ -18,6 +34,9 @@ pub struct IntentsConfig {
     /// Minimum confidence threshold
     #[serde(default = "default_min_confidence")]
     pub min_confidence: f32,
+    /// Disambiguation between the top two intents when unsure
+    #[serde(default)]
+    pub clarification: ClarificationConfig,
 }
 
 fn default_intent() -> String {
@@ -34,6 +53,7 @@ impl Default for IntentsConfig {
             intents: Vec::new(),
             default_intent: default_intent(),
             min_confidence: default_min_confidence(),
+            clarification: ClarificationConfig::default(),
         }
     }
 }
@@ -71,6 +91,94 @@ impl IntentsConfig {
             .map(|i| i.name.as_str())
             .collect()
     }
+
+    /// Question asking whether the customer meant `first` or `second`, in
+    /// `language` (English fallback)
+    pub fn clarification_question(
+        &self,
+        first: &str,
+        second: &str,
+        language: &str,
+    ) -> Option<String> {
+        let (first, second) = (self.get_intent(first)?, self.get_intent(second)?);
+        let question = &self.clarification.question;
+        let (template, language) = match question.get(language) {
+            Some(template) => (template, language),
+            None => (question.get("en")?, "en"),
+        };
+        Some(
+            template
+                .replace("{first}", &first.clarify_phrase(language))
+                .replace("{second}", &second.clarify_phrase(language)),
+        )
+    }
+}
+
+/// Disambiguation policy for low-confidence intents
+///
+/// Below `below_confidence`, and with a runner-up scoring at least
+/// `min_alternative_confidence`, the agent asks which of the two the
+/// customer meant. Each pair is asked at most once per session, and at most
+/// `max_per_session` times in all, so the agent never loops on it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClarificationConfig {
+    /// Ask instead of guessing
+    #[serde(default)]
+    pub enabled: bool,
+    /// Intents detected with less confidence than this are clarified
+    #[serde(default = "default_clarify_below")]
+    pub below_confidence: f32,
+    /// Runner-ups scoring less than this are not worth offering
+    #[serde(default = "default_min_alternative_confidence")]
+    pub min_alternative_confidence: f32,
+    /// Clarification questions per session
+    #[serde(default = "default_max_clarifications")]
+    pub max_per_session: usize,
+    /// Question template by language, with `{first}` and `{second}`
+    /// replaced by each intent's `clarify_as` phrase
+    #[serde(default = "default_clarification_question")]
+    pub question: HashMap<String, String>,
+}
+
+fn default_clarify_below() -> f32 {
+    0.6
+}
+
+fn default_min_alternative_confidence() -> f32 {
+    0.3
+}
+
+fn default_max_clarifications() -> usize {
+    2
+}
+
+fn default_clarification_question() -> HashMap<String, String> {
+    HashMap::from([(
+        "en".to_string(),
+        "Did you want to {first} or {second}?".to_string(),
+    )])
+}
+
+impl Default for ClarificationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            below_confidence: default_clarify_below(),
+            min_alternative_confidence: default_min_alternative_confidence(),
+            max_per_session: default_max_clarifications(),
+            question: default_clarification_question(),
+        }
+    }
+}
+
+impl ClarificationConfig {
+    /// Whether an intent detected with `confidence`, and a runner-up with
+    /// `alternative`, should be clarified
+    pub fn is_ambiguous(&self, confidence: f32, alternative: f32) -> bool {
+        self.enabled
+            && confidence < self.below_confidence
+            && alternative >= self.min_alternative_confidence
+    }
 }
 
 /// Single intent definition
@@ -89,6 +197,9 @@ pub struct IntentDefinition {
     /// Example utterances for training/matching
     #[serde(default)]
     pub examples: Vec<String>,
+    /// Phrase naming this intent in a clarification question, by language
+    #[serde(default)]
+    pub clarify_as: HashMap<String, String>,
 }
 
 impl IntentDefinition {
@@ -105,6 +216,16 @@ impl IntentDefinition {
             .map(|s| s.as_str())
             .collect()
     }
+
+    /// Phrase naming this intent in `language`, falling back to English and
+    /// then to the intent name ("ask about balance transfer")
+    pub fn clarify_phrase(&self, language: &str) -> String {
+        self.clarify_as
+            .get(language)
+            .or_else(|| self.clarify_as.get("en"))
+            .cloned()
+            .unwrap_or_else(|| format!("ask about {}", self.name.replace('_', " ")))
+    }
 }
 
 /// Errors when loading intents configuration
@@ -165,10 +286,53 @@ min_confidence: 0.4
             required_slots: vec!["slot_a".to_string(), "slot_b".to_string()],
             optional_slots: vec![],
             examples: vec![],
+            clarify_as: HashMap::new(),
         };
 
         assert!(intent.has_required_slots(&["slot_a", "slot_b", "slot_c"]));
         assert!(!intent.has_required_slots(&["slot_a"]));
         assert!(!intent.has_required_slots(&[]));
     }
+
+    #[test]
+    fn test_clarification_question() {
+        let yaml = r#"
+clarification:
+  enabled: true
+  question:
+    en: "Did you want to {first} or {second}?"
+    hi: "Aap {first} chahte hain ya {second}?"
+intents:
+  - name: eligibility_check
+    description: "Check eligibility"
+    clarify_as:
+      en: "check your eligibility"
+      hi: "eligibility check karna"
+  - name: balance_transfer
+    description: "Transfer a loan"
+    clarify_as:
+      en: "transfer an existing loan"
+  - name: greeting
+    description: "Greeting"
+"#;
+        let config: IntentsConfig = serde_yaml::from_str(yaml).unwrap();
+        assert!(config.clarification.is_ambiguous(0.4, 0.35));
+        assert!(!config.clarification.is_ambiguous(0.8, 0.35));
+        assert!(!config.clarification.is_ambiguous(0.4, 0.1));
+
+        assert_eq!(
+            config
+                .clarification_question("eligibility_check", "balance_transfer", "en")
+                .as_deref(),
+            Some("Did you want to check your eligibility or transfer an existing loan?")
+        );
+        // Phrases fall back to English, then to the intent name
+        assert_eq!(
+            config
+                .clarification_question("eligibility_check", "greeting", "hi")
+                .as_deref(),
+            Some("Aap eligibility check karna chahte hain ya ask about greeting?")
+        );
+        assert_eq!(config.clarification_question("unknown", "greeting", "en"), None);
+    }
 }
@@ -0,0 +1,170 @@
+//! Keypad (DTMF) Fallback
+//!
+//! On a noisy line the same question can be mis-heard turn after turn. After
+//! `switch_after` transcripts in a row below `low_confidence`, the agent asks
+//! for answers on the phone keypad instead:
+//! - a slot listed under `slots` is typed as digits, ended with the
+//!   terminator key or once `digits` keys are in
+//! - a read-back or yes/no offer is answered with the `yes_no` keys
+//! - a choice between a slot's options is answered with its position (1-9)
+//!
+//! ```yaml
+//! keypad:
+//!   enabled: true
+//!   low_confidence: 0.5
+//!   switch_after: 2
+//!   slots:
+//!     phone_number:
+//!       digits: 10
+//!       prompt:
+//!         en: "Please type your mobile number, then press hash."
+//!   yes_no:
+//!     "yes": "1"
+//!     "no": "2"
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Keypad fallback configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeypadConfig {
+    /// Switch to the keypad when the line is poor
+    #[serde(default)]
+    pub enabled: bool,
+    /// Transcripts with less STT confidence than this count as mis-heard
+    #[serde(default = "default_low_confidence")]
+    pub low_confidence: f32,
+    /// Mis-heard turns in a row before switching to the keypad
+    #[serde(default = "default_switch_after")]
+    pub switch_after: usize,
+    /// Key ending a digit entry
+    #[serde(default = "default_terminator")]
+    pub terminator: char,
+    /// Key discarding the digits typed so far
+    #[serde(default = "default_clear")]
+    pub clear: char,
+    /// Slots typed as digits, by slot name
+    #[serde(default)]
+    pub slots: HashMap<String, KeypadEntry>,
+    /// Keys answering a read-back or yes/no question
+    #[serde(default)]
+    pub yes_no: KeypadYesNo,
+}
+
+fn default_low_confidence() -> f32 {
+    0.5
+}
+
+fn default_switch_after() -> usize {
+    2
+}
+
+fn default_terminator() -> char {
+    '#'
+}
+
+fn default_clear() -> char {
+    '*'
+}
+
+impl Default for KeypadConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            low_confidence: default_low_confidence(),
+            switch_after: default_switch_after(),
+            terminator: default_terminator(),
+            clear: default_clear(),
+            slots: HashMap::new(),
+            yes_no: KeypadYesNo::default(),
+        }
+    }
+}
+
+impl KeypadConfig {
+    /// Prompt asking for `slot` on the keypad in `language` (English fallback)
+    pub fn slot_prompt(&self, slot: &str, language: &str) -> Option<&str> {
+        let prompt = &self.slots.get(slot)?.prompt;
+        prompt
+            .get(language)
+            .or_else(|| prompt.get("en"))
+            .map(|p| p.as_str())
+    }
+
+    /// Prompt asking for a yes/no key in `language` (English fallback)
+    pub fn yes_no_prompt(&self, language: &str) -> Option<&str> {
+        let prompt = &self.yes_no.prompt;
+        prompt
+            .get(language)
+            .or_else(|| prompt.get("en"))
+            .map(|p| p.as_str())
+    }
+}
+
+/// A slot typed on the keypad
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeypadEntry {
+    /// Keys in a complete value; the entry ends on its own once reached
+    pub digits: usize,
+    /// What to ask, by language
+    #[serde(default)]
+    pub prompt: HashMap<String, String>,
+}
+
+/// Keys answering a yes/no question
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeypadYesNo {
+    #[serde(default = "default_yes_key", rename = "yes")]
+    pub yes_key: char,
+    #[serde(default = "default_no_key", rename = "no")]
+    pub no_key: char,
+    /// What to ask, by language
+    #[serde(default)]
+    pub prompt: HashMap<String, String>,
+}
+
+fn default_yes_key() -> char {
+    '1'
+}
+
+fn default_no_key() -> char {
+    '2'
+}
+
+impl Default for KeypadYesNo {
+    fn default() -> Self {
+        Self {
+            yes_key: default_yes_key(),
+            no_key: default_no_key(),
+            prompt: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keypad_deserialization() {
+        let yaml = r#"
+enabled: true
+slots:
+  phone_number:
+    digits: 10
+    prompt:
+      en: "Type your number"
+yes_no:
+  "yes": "9"
+"#;
+        let config: KeypadConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.switch_after, 2);
+        assert_eq!(config.terminator, '#');
+        assert_eq!(config.slots["phone_number"].digits, 10);
+        let prompt = config.slot_prompt("phone_number", "hi");
+        assert_eq!(prompt, Some("Type your number"));
+        assert_eq!((config.yes_no.yes_key, config.yes_no.no_key), ('9', '2'));
+        assert_eq!(config.yes_no_prompt("en"), None);
+    }
+}
@@ -0,0 +1,150 @@
+//! Pronunciation Lexicon Configuration
+//!
+//! Grapheme to phoneme overrides loaded from lexicon.yaml.
+//!
+//! The G2P rules mispronounce brand and place names ("Kotak", "Manappuram",
+//! "Andheri"). Each entry pins a word's phonemes for a language, and can give
+//! a respelling for TTS engines that read text rather than phonemes.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Root lexicon configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct LexiconConfig {
+    /// Entries per language code, keyed by lowercase word
+    #[serde(default)]
+    pub languages: HashMap<String, HashMap<String, LexiconEntry>>,
+}
+
+/// Pronunciation of one word
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LexiconEntry {
+    /// IPA phonemes replacing the G2P output
+    pub phonemes: String,
+    /// Spelling sent to text-input TTS engines instead of the word
+    #[serde(default)]
+    pub respell: Option<String>,
+}
+
+impl LexiconConfig {
+    /// Load from a YAML file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, LexiconConfigError> {
+        let content = std::fs::read_to_string(path.as_ref()).map_err(|e| {
+            LexiconConfigError::FileNotFound(path.as_ref().display().to_string(), e.to_string())
+        })?;
+
+        let config: Self = serde_yaml::from_str(&content)
+            .map_err(|e| LexiconConfigError::ParseError(e.to_string()))?;
+        Ok(config.normalized())
+    }
+
+    /// Lowercase words and base language codes so lookups are exact
+    pub fn normalized(self) -> Self {
+        let languages = self
+            .languages
+            .into_iter()
+            .map(|(language, entries)| {
+                let entries = entries
+                    .into_iter()
+                    .map(|(word, entry)| (word.trim().to_lowercase(), entry))
+                    .collect();
+                (base_language(&language).to_lowercase(), entries)
+            })
+            .collect();
+        Self { languages }
+    }
+
+    /// Entry for a word in a language ("hi-IN" uses the "hi" entries)
+    pub fn entry(&self, language: &str, word: &str) -> Option<&LexiconEntry> {
+        self.languages
+            .get(base_language(language))?
+            .get(word.to_lowercase().as_str())
+    }
+
+    /// Total entries across languages
+    pub fn len(&self) -> usize {
+        self.languages.values().map(HashMap::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Check that every entry has phonemes
+    pub fn validate(&self) -> Result<(), LexiconConfigError> {
+        for (language, entries) in &self.languages {
+            for (word, entry) in entries {
+                if entry.phonemes.trim().is_empty() {
+                    return Err(LexiconConfigError::EmptyPhonemes(
+                        language.clone(),
+                        word.clone(),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// "hi-IN" -> "hi"
+fn base_language(code: &str) -> &str {
+    code.split(['-', '_']).next().unwrap_or(code)
+}
+
+/// Errors when loading lexicon configuration
+#[derive(Debug)]
+pub enum LexiconConfigError {
+    FileNotFound(String, String),
+    ParseError(String),
+    EmptyPhonemes(String, String),
+}
+
+impl std::fmt::Display for LexiconConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FileNotFound(path, err) => {
+                write!(f, "Lexicon config not found at {}: {}", path, err)
+            },
+            Self::ParseError(err) => write!(f, "Failed to parse lexicon config: {}", err),
+            Self::EmptyPhonemes(language, word) => {
+                write!(f, "Lexicon entry '{}' ({}) has no phonemes", word, language)
+            },
+        }
+    }
+}
+
+impl std::error::Error for LexiconConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_is_case_and_region_insensitive() {
+        let config: LexiconConfig = serde_yaml::from_str(
+            r#"
+languages:
+  hi-IN:
+    Kotak:
+      phonemes: "koːʈək"
+      respell: "कोटक"
+  en:
+    andheri:
+      phonemes: "ənˈdeəri"
+"#,
+        )
+        .unwrap();
+        let config = config.normalized();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.len(), 2);
+
+        let entry = config.entry("hi", "KOTAK").unwrap();
+        assert_eq!(entry.phonemes, "koːʈək");
+        assert_eq!(entry.respell.as_deref(), Some("कोटक"));
+        assert!(config.entry("hi-IN", "kotak").is_some());
+        assert!(config.entry("en", "kotak").is_none());
+        assert!(config.entry("en-IN", "Andheri").is_some());
+    }
+}
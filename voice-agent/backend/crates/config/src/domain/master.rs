@@ -7,7 +7,9 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use voice_agent_core::LocationGazetteer;
 
 use crate::ConfigError;
 use super::branches::BranchesConfig;
@@ -15,11 +17,14 @@ use super::competitors::CompetitorsConfig;
 use super::documents::DocumentsConfig;
 use super::entities::EntitiesConfig;
 use super::features::FeaturesConfig;
+use super::gazetteer::GazetteerConfig;
 use super::goals::GoalsConfig;
 use super::intents::IntentsConfig;
 use super::objections::ObjectionsConfig;
+use super::offers::OffersConfig;
 use super::personas::PersonasConfig;
 use super::prompts::PromptsConfig;
+use super::rate_card::RateCardConfig;
 use super::scoring::ScoringConfig;
 use super::segments::SegmentsConfig;
 use super::signals::SignalsConfig;
@@ -100,6 +105,26 @@ pub struct DomainConstants {
     /// Variant factors (e.g., purity factors for gold: K24=1.0, K22=0.916)
     #[serde(default, alias = "purity_factors")]
     pub variant_factors: HashMap<String, f64>,
+    /// Top-up and renewal rules for existing loans
+    #[serde(default)]
+    pub top_up: TopUpConfig,
+}
+
+/// Top-up and renewal rules for existing loans
+///
+/// Zero values fall back to the new-loan equivalents (`ltv_percent`,
+/// `loan_limits.min`).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TopUpConfig {
+    /// LTV cap on today's collateral value across outstanding plus top-up
+    #[serde(default)]
+    pub max_ltv_percent: f64,
+    /// Smallest top-up amount disbursed
+    #[serde(default)]
+    pub min_amount: f64,
+    /// Tenures offered when renewing, in months
+    #[serde(default)]
+    pub renewal_tenures_months: Vec<u32>,
 }
 
 impl DomainConstants {
@@ -579,6 +604,9 @@ pub struct MasterDomainConfig {
     /// P22 FIX: Intent definitions (loaded from intents.yaml)
     #[serde(skip)]
     pub intents: IntentsConfig,
+    /// Annotated few-shot exchanges for prompts (loaded from examples.yaml)
+    #[serde(skip)]
+    pub examples: super::ExamplesConfig,
     /// P22 FIX: Full vocabulary with ASR boost, phonetic corrections (loaded from vocabulary.yaml)
     #[serde(skip)]
     pub vocabulary_full: FullVocabularyConfig,
@@ -591,6 +619,28 @@ pub struct MasterDomainConfig {
     /// P24 FIX: Persona configurations for tone/style (loaded from personas.yaml)
     #[serde(skip)]
     pub personas: PersonasConfig,
+    /// TTS voice registry and per-persona selection rules (loaded from voices.yaml)
+    #[serde(skip)]
+    pub voices: super::VoicesConfig,
+    /// Pronunciation overrides for G2P (loaded from lexicon.yaml)
+    #[serde(skip)]
+    pub lexicon: super::LexiconConfig,
+    /// lexicon.yaml the overrides came from, watched for edits
+    #[serde(skip)]
+    pub lexicon_path: Option<PathBuf>,
+    /// States, cities and PIN code ranges (loaded from gazetteer.yaml)
+    #[serde(skip)]
+    pub gazetteer: GazetteerConfig,
+    /// Cities and branch localities merged from gazetteer, extraction
+    /// patterns and branches; built once at load time
+    #[serde(skip)]
+    pub locations: Arc<LocationGazetteer>,
+    /// Versioned LTV and interest rate cards (loaded from rate_card.yaml)
+    #[serde(skip)]
+    pub rate_card: RateCardConfig,
+    /// Promotional offers and their eligibility (loaded from offers.yaml)
+    #[serde(skip)]
+    pub offers: OffersConfig,
     // P23 FIX: Removed raw_config field - was never accessed
     // Use typed config fields instead
 }
@@ -638,10 +688,18 @@ impl Default for MasterDomainConfig {
             adaptation: super::AdaptationConfig::default(),
             extraction_patterns: super::ExtractionPatternsConfig::default(),
             intents: IntentsConfig::default(),
+            examples: super::ExamplesConfig::default(),
             vocabulary_full: FullVocabularyConfig::default(),
             entities: EntitiesConfig::default(),
             signals: SignalsConfig::default(),
             personas: PersonasConfig::default(),
+            voices: super::VoicesConfig::default(),
+            lexicon: super::LexiconConfig::default(),
+            lexicon_path: None,
+            gazetteer: GazetteerConfig::default(),
+            locations: Arc::new(LocationGazetteer::builtin().clone()),
+            rate_card: RateCardConfig::default(),
+            offers: OffersConfig::default(),
             // P23 FIX: Removed raw_config - use typed config fields
         }
     }
@@ -1088,6 +1146,25 @@ impl MasterDomainConfig {
             tracing::debug!("No intents config found at {:?}", intents_path);
         }
 
+        // 22a. Load few-shot examples (optional)
+        let examples_path = config_dir.join(format!("domains/{}/examples.yaml", domain_id));
+        if examples_path.exists() {
+            match super::ExamplesConfig::load(&examples_path) {
+                Ok(examples) => {
+                    if let Err(e) = examples.validate() {
+                        tracing::warn!("Invalid examples config: {}", e);
+                    }
+                    tracing::info!(examples = examples.len(), "Loaded few-shot examples");
+                    config.examples = examples;
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to load examples config: {}", e);
+                }
+            }
+        } else {
+            tracing::debug!("No examples config found at {:?}", examples_path);
+        }
+
         // 23. P22 FIX: Load full vocabulary configuration (optional)
         let vocabulary_path = config_dir.join(format!("domains/{}/vocabulary.yaml", domain_id));
         if vocabulary_path.exists() {
@@ -1174,7 +1251,120 @@ impl MasterDomainConfig {
             tracing::debug!("No personas config found at {:?}", personas_path);
         }
 
-        // 27. P16 FIX: Apply variable substitution to all text configs
+        // 27. Load TTS voice registry (optional)
+        let voices_path = config_dir.join(format!("domains/{}/voices.yaml", domain_id));
+        if voices_path.exists() {
+            match super::VoicesConfig::load(&voices_path) {
+                Ok(voices) => {
+                    if let Err(e) = voices.validate() {
+                        tracing::warn!("Invalid voices config: {}", e);
+                    }
+                    tracing::info!(
+                        voices = voices.voices.len(),
+                        personas = voices.personas.len(),
+                        "Loaded voices configuration"
+                    );
+                    config.voices = voices;
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to load voices config: {}", e);
+                }
+            }
+        } else {
+            tracing::debug!("No voices config found at {:?}", voices_path);
+        }
+
+        // 27a. Load pronunciation lexicon (optional)
+        let lexicon_path = config_dir.join(format!("domains/{}/lexicon.yaml", domain_id));
+        if lexicon_path.exists() {
+            match super::LexiconConfig::load(&lexicon_path) {
+                Ok(lexicon) => {
+                    if let Err(e) = lexicon.validate() {
+                        tracing::warn!("Invalid lexicon config: {}", e);
+                    }
+                    tracing::info!(entries = lexicon.len(), "Loaded pronunciation lexicon");
+                    config.lexicon = lexicon;
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to load lexicon config: {}", e);
+                }
+            }
+            // Watched even if unreadable now, so a fixed file is picked up
+            config.lexicon_path = Some(lexicon_path);
+        } else {
+            tracing::debug!("No lexicon config found at {:?}", lexicon_path);
+        }
+
+        // 27b. Load the location gazetteer (optional) and merge in the
+        // extraction pattern cities and branch localities
+        let gazetteer_path = config_dir.join(format!("domains/{}/gazetteer.yaml", domain_id));
+        if gazetteer_path.exists() {
+            match GazetteerConfig::load(&gazetteer_path) {
+                Ok(gazetteer) => {
+                    if let Err(e) = gazetteer.validate() {
+                        tracing::warn!("Invalid gazetteer config: {}", e);
+                    }
+                    tracing::info!(
+                        states = gazetteer.states.len(),
+                        cities = gazetteer.cities.len(),
+                        "Loaded gazetteer configuration"
+                    );
+                    config.gazetteer = gazetteer;
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to load gazetteer config: {}", e);
+                }
+            }
+        } else {
+            tracing::debug!("No gazetteer config found at {:?}", gazetteer_path);
+        }
+        config.locations = Arc::new(config.gazetteer.build(
+            &config.extraction_patterns.locations.cities,
+            &config.branches.branches,
+        ));
+
+        // 27c. Load rate cards (optional; without them rates come from constants)
+        let rate_card_path = config_dir.join(format!("domains/{}/rate_card.yaml", domain_id));
+        if rate_card_path.exists() {
+            match RateCardConfig::load(&rate_card_path) {
+                Ok(rate_card) => {
+                    if let Err(e) = rate_card.validate() {
+                        tracing::warn!("Invalid rate card config: {}", e);
+                    }
+                    tracing::info!(
+                        cards = rate_card.cards.len(),
+                        "Loaded rate card configuration"
+                    );
+                    config.rate_card = rate_card;
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to load rate card config: {}", e);
+                }
+            }
+        } else {
+            tracing::debug!("No rate card config found at {:?}", rate_card_path);
+        }
+
+        // 27d. Load promotional offers (optional)
+        let offers_path = config_dir.join(format!("domains/{}/offers.yaml", domain_id));
+        if offers_path.exists() {
+            match OffersConfig::load(&offers_path) {
+                Ok(offers) => {
+                    if let Err(e) = offers.validate() {
+                        tracing::warn!("Invalid offers config: {}", e);
+                    }
+                    tracing::info!(offers = offers.offers.len(), "Loaded offers configuration");
+                    config.offers = offers;
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to load offers config: {}", e);
+                }
+            }
+        } else {
+            tracing::debug!("No offers config found at {:?}", offers_path);
+        }
+
+        // 28. P16 FIX: Apply variable substitution to all text configs
         // This allows YAML files to use {{variable_name}} placeholders
         // that are replaced with values from adaptation.yaml variables
         config.substitute_all_variables();
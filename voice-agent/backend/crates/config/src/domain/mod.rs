@@ -3,7 +3,7 @@
 //! Provides a layered configuration system:
 //! 1. Base config (config/base/defaults.yaml)
 //! 2. Domain config (config/domains/{domain}/domain.yaml)
-//! 3. Runtime overrides (per-session)
+//! 3. Runtime overrides (per-session, see `SessionOverrides` / `SessionDomainView`)
 //!
 //! Each crate accesses config through a specific "view" that translates
 //! raw config into crate-specific terminology.
@@ -15,14 +15,22 @@ mod compliance;
 mod competitors;
 mod documents;
 mod entities;
+mod examples;
 mod extraction_patterns;
 mod features;
+mod gazetteer;
 mod goals;
 mod intents;
+mod keypad;
+mod lexicon;
 mod master;
 mod objections;
+mod offers;
+mod overrides;
 mod personas;
 mod prompts;
+mod quantity;
+mod rate_card;
 mod scoring;
 mod segments;
 mod signals;
@@ -34,11 +42,15 @@ mod tools;
 mod validator;
 mod views;
 mod vocabulary;
+mod voices;
 
 pub use adaptation::{
     AdaptationConfig, AdaptationConfigError, SegmentAdaptation, SpecialProgram,
 };
-pub use branches::{BranchDefaults, BranchEntry, BranchesConfig, BranchesConfigError, DoorstepServiceConfig};
+pub use branches::{
+    AppointmentSlotConfig, BranchDefaults, BranchEntry, BranchesConfig, BranchesConfigError,
+    DoorstepServiceConfig,
+};
 pub use compliance::{
     AutoCorrections, ClaimRule, CompetitorRules as ComplianceCompetitorRules, ComplianceConfig,
     ComplianceConfigError, LanguageRules, RateRules, RegulatoryInfo, RequiredDisclosure,
@@ -50,14 +62,15 @@ pub use documents::{
 };
 pub use extraction_patterns::{
     AssetQualityConfig, AssetQualityTier, CityEntry, CompiledCityPattern, CompiledPurposePattern,
-    CompiledQualityTier, ExtractionPatternsConfig, ExtractionPatternsError, LocationsConfig,
-    PurposeCategory, PurposesConfig, UnitConversionsConfig, ValidationConfig,
+    CompiledQualityTier, ExtractionPatternsConfig, ExtractionPatternsError, LanguagePackConfig,
+    LocationsConfig, PurposeCategory, PurposesConfig, UnitConversionsConfig, ValidationConfig,
 };
 pub use competitors::{
     ComparisonPoint, CompetitorDefaults, CompetitorEntry, CompetitorsConfig,
     CompetitorsConfigError, RateRange,
 };
 pub use features::{FeatureDefinition, FeatureId, FeaturesConfig};
+pub use gazetteer::{GazetteerConfig, GazetteerConfigError};
 pub use goals::{
     ActionContext, ActionTemplate, ActionTemplatesConfig, GoalEntry, GoalsConfig, GoalsConfigError,
 };
@@ -65,7 +78,10 @@ pub use entities::{
     CompetitorTypeDefaults, CompetitorTypeDefinition, EntitiesConfig, EntitiesConfigError,
     EntityCategory, EntityTypeDefinition,
 };
-pub use intents::{IntentDefinition, IntentsConfig, IntentsConfigError};
+pub use examples::{ExamplesConfig, ExamplesConfigError, Exemplar, ExemplarToolCall};
+pub use intents::{ClarificationConfig, IntentDefinition, IntentsConfig, IntentsConfigError};
+pub use keypad::{KeypadConfig, KeypadEntry, KeypadYesNo};
+pub use lexicon::{LexiconConfig, LexiconConfigError, LexiconEntry};
 pub use master::{
     BrandConfig, ContextualRule, CurrencyConfig, DisplayUnit, DisplayUnitsConfig, DomainBoostConfig,
     DomainBoostTermEntry, DomainKeywordsConfig, EntityPatternConfig, IntentKeywordConfig,
@@ -81,10 +97,19 @@ pub use personas::{
     NameUsageConfig, PersonasConfig, PersonasConfigError, RangeGuideline,
     ResponseLengthGuidelines, ThresholdConfig, ToneConfig, UrgencyConfig,
 };
+pub use offers::{
+    Offer, OfferCheck, OfferContext, OfferEligibility, OffersConfig, OffersConfigError,
+};
+pub use overrides::{SessionDomainView, SessionOverrides, SessionOverridesError};
 pub use prompts::{PromptsConfig, PromptsConfigError};
+pub use quantity::{QuantityError, QuantityKind, SlotQuantity};
+pub use rate_card::{
+    effective_rate_card, RateCard, RateCardConfig, RateCardConfigError, RateSlab,
+    CONSTANTS_RATE_CARD_VERSION,
+};
 pub use scoring::{
-    CategoryWeights, ConversionMultipliers, EscalationConfig, QualificationThresholds,
-    ScoringConfig, ScoringConfigError, TrustScores,
+    AmountTier, CategoryWeights, ConversionMultipliers, DialogueScoringConfig, EscalationConfig,
+    QualificationThresholds, ScoringConfig, ScoringConfigError, TrustScores,
 };
 pub use signals::{
     EscalationTriggerDef, ScoringThreshold, SignalCategory, SignalDefinition as SignalDefConfig,
@@ -95,24 +120,27 @@ pub use segments::{
     SegmentsConfig, SegmentsConfigError,
 };
 pub use slots::{
-    EnumParsingConfig, EnumValue, GoalDefinition, NumericPatternRule, SlotDefinition, SlotType,
-    SlotsConfig, SlotsConfigError,
+    ConfirmationPolicy, ConfirmationReplies, EnumParsingConfig, EnumValue, GoalDefinition,
+    GoalSwitchAction, GoalTransitionRule, NumericPatternRule, SlotDefinition, SlotType,
+    SlotsConfig, SlotsConfigError, SpelledChecksum, SpelledInput,
 };
 pub use sms_templates::{SmsCategories, SmsConfig, SmsTemplatesConfig, SmsTemplatesConfigError};
 pub use stages::{
     StageDefinition, StageRequirements, StagesConfig, StagesConfigError, TransitionTrigger,
 };
 pub use tool_responses::{ToolResponsesConfig, ToolResponsesConfigError, ToolTemplates, TemplateVariant};
-pub use tools::{IntentToolMapping, IntentToolMappingsConfig, ToolDefinition, ToolParameter, ToolSchema, ToolSchemaMetadata, ToolsConfig, ToolsConfigError};
+pub use tools::{HttpToolConfig, IntentToolMapping, IntentToolMappingsConfig, ToolDefinition, ToolParameter, ToolSchema, ToolSchemaMetadata, ToolsConfig, ToolsConfigError};
 pub use views::{AgentDomainView, CompetitorInfo, LlmDomainView, MonthlySavings, ToolsDomainView};
 pub use vocabulary::{DomainTerm, FullVocabularyConfig, FullVocabularyConfigError};
+pub use voices::{PersonaVoiceRule, VoiceProfile, VoicesConfig, VoicesConfigError};
 
 // P13 FIX: Domain bridge for trait implementations
 pub use bridge::DomainBridge;
 
 // P5.2 FIX: Config validator for startup validation
 pub use validator::{
-    ConfigValidator, ValidationCategory, ValidationError, ValidationResult, ValidationSeverity,
+    validate_domain, ConfigValidator, ValidationCategory, ValidationError, ValidationResult,
+    ValidationSeverity,
 };
 
 // P13 FIX: DomainConfig and DomainConfigManager removed - use MasterDomainConfig + views
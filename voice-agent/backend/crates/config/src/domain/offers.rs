@@ -0,0 +1,411 @@
+//! Promotional Offers Configuration
+//!
+//! Campaign offers loaded from offers.yaml.
+//!
+//! Each offer runs from `valid_from` (until `valid_until`, when set) and has
+//! eligibility rules: new customers only, balance transfers from given
+//! lenders, a loan amount range and a city list. A rule left out applies to
+//! everyone. Offers are checked against what is known of the customer; a
+//! rule that cannot be checked yet leaves the offer undecided rather than
+//! ruled out, so the agent can ask before mentioning it.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::path::Path;
+
+/// What is known of the customer when offers are checked
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OfferContext {
+    /// Whether the customer is new to us
+    pub new_customer: Option<bool>,
+    /// Lender the loan would be transferred from (competitor id)
+    pub current_lender: Option<String>,
+    pub loan_amount: Option<f64>,
+    /// Canonical city name
+    pub city: Option<String>,
+}
+
+/// Who an offer is for
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OfferEligibility {
+    /// Only for customers new to us
+    #[serde(default)]
+    pub new_customers_only: bool,
+    /// Only for balance transfers from these lenders (competitor ids)
+    #[serde(default)]
+    pub balance_transfer_from: Vec<String>,
+    #[serde(default)]
+    pub min_amount: Option<f64>,
+    #[serde(default)]
+    pub max_amount: Option<f64>,
+    /// Only in these cities
+    #[serde(default)]
+    pub cities: Vec<String>,
+}
+
+/// Outcome of checking an offer against an `OfferContext`
+#[derive(Debug, Clone, PartialEq)]
+pub enum OfferCheck {
+    Eligible,
+    /// A rule rules the customer out
+    Ineligible(String),
+    /// No rule fails, but these context fields are needed to decide
+    Undecided(Vec<&'static str>),
+}
+
+impl OfferCheck {
+    pub fn is_eligible(&self) -> bool {
+        matches!(self, Self::Eligible)
+    }
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// A promotional offer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Offer {
+    /// Stable ID, used when tracking mentions and acceptance
+    pub id: String,
+    pub name: String,
+    /// What the agent tells the customer
+    pub pitch: String,
+    /// Conditions to read out or send on request
+    #[serde(default)]
+    pub terms: Option<String>,
+    /// Interest rate reduction in percentage points
+    #[serde(default)]
+    pub rate_discount: Option<f64>,
+    #[serde(default)]
+    pub processing_fee_waived: bool,
+    pub valid_from: NaiveDate,
+    /// Last day of the offer (none = open-ended)
+    #[serde(default)]
+    pub valid_until: Option<NaiveDate>,
+    /// Switch an offer off without removing it
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Higher priority offers are presented first
+    #[serde(default)]
+    pub priority: i32,
+    #[serde(default)]
+    pub eligibility: OfferEligibility,
+}
+
+impl Offer {
+    /// Whether the offer is enabled and running on `date`
+    pub fn is_active_on(&self, date: NaiveDate) -> bool {
+        self.enabled
+            && self.valid_from <= date
+            && self.valid_until.map_or(true, |until| date <= until)
+    }
+
+    /// Check the eligibility rules against what is known of the customer
+    pub fn check(&self, context: &OfferContext) -> OfferCheck {
+        let rules = &self.eligibility;
+        let mut missing = Vec::new();
+
+        if rules.new_customers_only {
+            match context.new_customer {
+                Some(false) => return OfferCheck::Ineligible("new customers only".to_string()),
+                Some(true) => {},
+                None => missing.push("new_customer"),
+            }
+        }
+
+        if !rules.balance_transfer_from.is_empty() {
+            match context.current_lender {
+                Some(ref lender) => {
+                    if !rules
+                        .balance_transfer_from
+                        .iter()
+                        .any(|l| l.eq_ignore_ascii_case(lender))
+                    {
+                        return OfferCheck::Ineligible(format!(
+                            "only for transfers from {}",
+                            rules.balance_transfer_from.join(", ")
+                        ));
+                    }
+                },
+                None => missing.push("current_lender"),
+            }
+        }
+
+        if rules.min_amount.is_some() || rules.max_amount.is_some() {
+            match context.loan_amount {
+                Some(amount) => {
+                    if rules.min_amount.is_some_and(|min| amount < min) {
+                        return OfferCheck::Ineligible(format!(
+                            "loan amount below {:.0}",
+                            rules.min_amount.unwrap_or_default()
+                        ));
+                    }
+                    if rules.max_amount.is_some_and(|max| amount > max) {
+                        return OfferCheck::Ineligible(format!(
+                            "loan amount above {:.0}",
+                            rules.max_amount.unwrap_or_default()
+                        ));
+                    }
+                },
+                None => missing.push("loan_amount"),
+            }
+        }
+
+        if !rules.cities.is_empty() {
+            match context.city {
+                Some(ref city) => {
+                    if !rules.cities.iter().any(|c| c.eq_ignore_ascii_case(city)) {
+                        return OfferCheck::Ineligible(format!(
+                            "only in {}",
+                            rules.cities.join(", ")
+                        ));
+                    }
+                },
+                None => missing.push("city"),
+            }
+        }
+
+        if missing.is_empty() {
+            OfferCheck::Eligible
+        } else {
+            OfferCheck::Undecided(missing)
+        }
+    }
+
+    /// Check the offer is usable
+    pub fn validate(&self) -> Result<(), OffersConfigError> {
+        let invalid = |reason: &str| {
+            Err(OffersConfigError::InvalidOffer(
+                self.id.clone(),
+                reason.to_string(),
+            ))
+        };
+
+        if self.id.trim().is_empty() {
+            return invalid("id is required");
+        }
+        if self.pitch.trim().is_empty() {
+            return invalid("pitch is required");
+        }
+        if self
+            .valid_until
+            .is_some_and(|until| until < self.valid_from)
+        {
+            return invalid("valid_until is before valid_from");
+        }
+        if self.rate_discount.is_some_and(|d| d <= 0.0) {
+            return invalid("rate_discount must be positive");
+        }
+        let rules = &self.eligibility;
+        if let (Some(min), Some(max)) = (rules.min_amount, rules.max_amount) {
+            if max < min {
+                return invalid("max_amount is below min_amount");
+            }
+        }
+        Ok(())
+    }
+}
+
+fn default_max_presented() -> usize {
+    2
+}
+
+/// Offers configuration (offers.yaml)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OffersConfig {
+    #[serde(default)]
+    pub offers: Vec<Offer>,
+    /// Most offers presented to a customer at once
+    #[serde(default = "default_max_presented")]
+    pub max_presented: usize,
+}
+
+impl Default for OffersConfig {
+    fn default() -> Self {
+        Self {
+            offers: Vec::new(),
+            max_presented: default_max_presented(),
+        }
+    }
+}
+
+impl OffersConfig {
+    /// Load from a YAML file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, OffersConfigError> {
+        let content = std::fs::read_to_string(path.as_ref()).map_err(|e| {
+            OffersConfigError::FileNotFound(path.as_ref().display().to_string(), e.to_string())
+        })?;
+
+        serde_yaml::from_str(&content).map_err(|e| OffersConfigError::ParseError(e.to_string()))
+    }
+
+    /// Check every offer, and that IDs are unique
+    pub fn validate(&self) -> Result<(), OffersConfigError> {
+        let mut ids = HashSet::new();
+        for offer in &self.offers {
+            offer.validate()?;
+            if !ids.insert(offer.id.as_str()) {
+                return Err(OffersConfigError::DuplicateId(offer.id.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Get an offer by ID
+    pub fn get(&self, id: &str) -> Option<&Offer> {
+        self.offers.iter().find(|o| o.id == id)
+    }
+
+    /// Offers running on `date`, highest priority first
+    pub fn active_on(&self, date: NaiveDate) -> Vec<&Offer> {
+        let mut active: Vec<&Offer> = self
+            .offers
+            .iter()
+            .filter(|o| o.is_active_on(date))
+            .collect();
+        active.sort_by_key(|offer| Reverse(offer.priority));
+        active
+    }
+
+    /// Offers running on `date` that the customer is not ruled out of,
+    /// highest priority first
+    pub fn evaluate(&self, context: &OfferContext, date: NaiveDate) -> Vec<(&Offer, OfferCheck)> {
+        self.active_on(date)
+            .into_iter()
+            .map(|offer| (offer, offer.check(context)))
+            .filter(|(_, check)| !matches!(check, OfferCheck::Ineligible(_)))
+            .collect()
+    }
+}
+
+/// Errors when loading offers configuration
+#[derive(Debug)]
+pub enum OffersConfigError {
+    FileNotFound(String, String),
+    ParseError(String),
+    InvalidOffer(String, String),
+    DuplicateId(String),
+}
+
+impl std::fmt::Display for OffersConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FileNotFound(path, err) => {
+                write!(f, "Offers config not found at {}: {}", path, err)
+            },
+            Self::ParseError(err) => write!(f, "Failed to parse offers config: {}", err),
+            Self::InvalidOffer(id, reason) => {
+                write!(f, "Offer '{}' is invalid: {}", id, reason)
+            },
+            Self::DuplicateId(id) => write!(f, "Offer '{}' is defined more than once", id),
+        }
+    }
+}
+
+impl std::error::Error for OffersConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offer(id: &str, priority: i32, eligibility: OfferEligibility) -> Offer {
+        Offer {
+            id: id.to_string(),
+            name: id.to_string(),
+            pitch: format!("{} pitch", id),
+            terms: None,
+            rate_discount: Some(0.5),
+            processing_fee_waived: false,
+            valid_from: NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(),
+            valid_until: NaiveDate::from_ymd_opt(2026, 12, 31),
+            enabled: true,
+            priority,
+            eligibility,
+        }
+    }
+
+    #[test]
+    fn test_offer_check() {
+        let switch = offer(
+            "switch",
+            0,
+            OfferEligibility {
+                new_customers_only: true,
+                balance_transfer_from: vec!["muthoot".to_string()],
+                min_amount: Some(100_000.0),
+                cities: vec!["Mumbai".to_string()],
+                ..Default::default()
+            },
+        );
+
+        let mut context = OfferContext::default();
+        assert_eq!(
+            switch.check(&context),
+            OfferCheck::Undecided(vec![
+                "new_customer",
+                "current_lender",
+                "loan_amount",
+                "city"
+            ])
+        );
+
+        context.new_customer = Some(true);
+        context.current_lender = Some("Muthoot".to_string());
+        context.loan_amount = Some(250_000.0);
+        assert_eq!(switch.check(&context), OfferCheck::Undecided(vec!["city"]));
+        context.city = Some("mumbai".to_string());
+        assert!(switch.check(&context).is_eligible());
+
+        // A failing rule decides even when other facts are missing
+        let context = OfferContext {
+            loan_amount: Some(50_000.0),
+            ..Default::default()
+        };
+        assert!(matches!(switch.check(&context), OfferCheck::Ineligible(_)));
+    }
+
+    #[test]
+    fn test_evaluate_orders_active_offers() {
+        let date = NaiveDate::from_ymd_opt(2026, 11, 1).unwrap();
+        let mut expired = offer("expired", 10, OfferEligibility::default());
+        expired.valid_until = NaiveDate::from_ymd_opt(2026, 10, 15);
+        let large = offer(
+            "large",
+            0,
+            OfferEligibility {
+                min_amount: Some(500_000.0),
+                ..Default::default()
+            },
+        );
+        let config = OffersConfig {
+            offers: vec![
+                offer("festive", 1, OfferEligibility::default()),
+                expired,
+                large,
+                offer("top", 5, OfferEligibility::default()),
+            ],
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+
+        let context = OfferContext {
+            loan_amount: Some(200_000.0),
+            ..Default::default()
+        };
+        let ids: Vec<&str> = config
+            .evaluate(&context, date)
+            .iter()
+            .map(|(o, _)| o.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["top", "festive"]);
+
+        let mut duplicate = config.clone();
+        duplicate
+            .offers
+            .push(offer("top", 0, OfferEligibility::default()));
+        assert!(duplicate.validate().is_err());
+    }
+}
@@ -0,0 +1,395 @@
+//! Per-Session Runtime Overrides
+//!
+//! Third layer of the hierarchical config (after base defaults and domain
+//! YAML). A `SessionOverrides` is attached when a session starts and is
+//! applied through `SessionDomainView`, which reads the shared
+//! `MasterDomainConfig` without ever mutating it — other sessions keep
+//! seeing the domain defaults.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use super::tools::ToolDefinition;
+use super::MasterDomainConfig;
+
+/// Typed overlay applied on top of the domain config for one session
+///
+/// Every field is optional; `None` means "use the domain default".
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SessionOverrides {
+    /// Conversation language (e.g., "hi", "en")
+    #[serde(default)]
+    pub language: Option<String>,
+    /// Agent persona name (replaces brand.agent_name in prompts and greetings)
+    #[serde(default)]
+    pub persona: Option<String>,
+    /// TTS voice ID
+    #[serde(default)]
+    pub tts_voice: Option<String>,
+    /// Speaking rate multiplier (e.g. 0.8 for callers who ask to slow down)
+    #[serde(default)]
+    pub tts_rate: Option<f32>,
+    /// Pitch adjustment in semitones
+    #[serde(default)]
+    pub tts_pitch: Option<f32>,
+    /// Silence between sentences (ms)
+    #[serde(default)]
+    pub tts_sentence_pause_ms: Option<u32>,
+    /// LLM sampling temperature
+    #[serde(default)]
+    pub llm_temperature: Option<f32>,
+    /// Allow-list of tool names; `None` keeps every configured tool enabled
+    #[serde(default)]
+    pub enabled_tools: Option<Vec<String>>,
+}
+
+impl SessionOverrides {
+    /// Whether no override is set
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Check overrides against the domain they will be applied to
+    pub fn validate(&self, config: &MasterDomainConfig) -> Result<(), SessionOverridesError> {
+        if let Some(temperature) = self.llm_temperature {
+            if !(0.0..=2.0).contains(&temperature) {
+                return Err(SessionOverridesError::InvalidTemperature(temperature));
+            }
+        }
+
+        if let Some(language) = &self.language {
+            // Domains without prompt languages accept any language
+            let supported: HashSet<&str> = config
+                .prompts
+                .language_styles
+                .keys()
+                .chain(config.prompts.greetings.keys())
+                .map(|s| s.as_str())
+                .collect();
+            if !supported.is_empty() && !supported.contains(language.as_str()) {
+                return Err(SessionOverridesError::UnsupportedLanguage(language.clone()));
+            }
+        }
+
+        if let Some(tools) = &self.enabled_tools {
+            for tool in tools {
+                if !config.tools.tools.contains_key(tool) {
+                    return Err(SessionOverridesError::UnknownTool(tool.clone()));
+                }
+            }
+        }
+
+        if let Some(persona) = &self.persona {
+            if persona.trim().is_empty() {
+                return Err(SessionOverridesError::EmptyPersona);
+            }
+        }
+
+        if let Some(rate) = self.tts_rate {
+            if !(0.5..=2.0).contains(&rate) {
+                return Err(SessionOverridesError::InvalidTtsRate(rate));
+            }
+        }
+        if let Some(pitch) = self.tts_pitch {
+            if !(-12.0..=12.0).contains(&pitch) {
+                return Err(SessionOverridesError::InvalidTtsPitch(pitch));
+            }
+        }
+        if let Some(pause) = self.tts_sentence_pause_ms {
+            if pause > 2000 {
+                return Err(SessionOverridesError::InvalidSentencePause(pause));
+            }
+        }
+
+        // Domains without a voice registry accept any voice ID
+        if let Some(voice) = &self.tts_voice {
+            if !config.voices.voices.is_empty() && config.voices.voice(voice).is_none() {
+                return Err(SessionOverridesError::UnknownVoice(voice.clone()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether a tool may be used in this session
+    pub fn is_tool_enabled(&self, tool: &str) -> bool {
+        self.enabled_tools
+            .as_ref()
+            .map(|tools| tools.iter().any(|t| t == tool))
+            .unwrap_or(true)
+    }
+}
+
+/// Errors when validating session overrides
+#[derive(Debug, Clone, PartialEq)]
+pub enum SessionOverridesError {
+    InvalidTemperature(f32),
+    UnsupportedLanguage(String),
+    UnknownTool(String),
+    EmptyPersona,
+    UnknownVoice(String),
+    InvalidTtsRate(f32),
+    InvalidTtsPitch(f32),
+    InvalidSentencePause(u32),
+}
+
+impl std::fmt::Display for SessionOverridesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidTemperature(t) => {
+                write!(f, "llm_temperature must be between 0.0 and 2.0, got {}", t)
+            }
+            Self::UnsupportedLanguage(lang) => {
+                write!(f, "Language '{}' is not configured for this domain", lang)
+            }
+            Self::UnknownTool(tool) => write!(f, "Unknown tool in enabled_tools: {}", tool),
+            Self::EmptyPersona => write!(f, "persona must not be empty"),
+            Self::UnknownVoice(voice) => write!(f, "Unknown TTS voice: {}", voice),
+            Self::InvalidTtsRate(rate) => {
+                write!(f, "tts_rate must be between 0.5 and 2.0, got {}", rate)
+            }
+            Self::InvalidTtsPitch(pitch) => {
+                write!(f, "tts_pitch must be between -12 and 12 semitones, got {}", pitch)
+            }
+            Self::InvalidSentencePause(ms) => {
+                write!(f, "tts_sentence_pause_ms must be at most 2000, got {}", ms)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SessionOverridesError {}
+
+/// Session-scoped view: domain config with `SessionOverrides` layered on top
+#[derive(Clone)]
+pub struct SessionDomainView {
+    config: Arc<MasterDomainConfig>,
+    overrides: SessionOverrides,
+}
+
+impl SessionDomainView {
+    pub fn new(config: Arc<MasterDomainConfig>, overrides: SessionOverrides) -> Self {
+        Self { config, overrides }
+    }
+
+    /// Same domain config with a different set of overrides
+    pub fn with_overrides(mut self, overrides: SessionOverrides) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    /// The overrides applied to this session
+    pub fn overrides(&self) -> &SessionOverrides {
+        &self.overrides
+    }
+
+    /// Underlying (shared, unmodified) domain config
+    pub fn config(&self) -> &MasterDomainConfig {
+        &self.config
+    }
+
+    /// Session language, falling back to the given default
+    pub fn language<'a>(&'a self, default: &'a str) -> &'a str {
+        self.overrides.language.as_deref().unwrap_or(default)
+    }
+
+    /// Agent name: persona override, else brand.agent_name
+    pub fn agent_name(&self) -> &str {
+        self.overrides
+            .persona
+            .as_deref()
+            .unwrap_or(&self.config.brand.agent_name)
+    }
+
+    /// TTS voice, falling back to the given default
+    pub fn tts_voice<'a>(&'a self, default: &'a str) -> &'a str {
+        self.overrides.tts_voice.as_deref().unwrap_or(default)
+    }
+
+    /// Voice for the persona in a language, honouring the session override
+    ///
+    /// Called again whenever the conversation language changes; see
+    /// `VoicesConfig::select` for the order rules are applied in.
+    pub fn voice_for(&self, language: &str) -> Option<&str> {
+        self.config
+            .voices
+            .select(self.agent_name(), language, self.overrides.tts_voice.as_deref())
+    }
+
+    /// LLM temperature, falling back to the given default
+    pub fn llm_temperature(&self, default: f32) -> f32 {
+        self.overrides.llm_temperature.unwrap_or(default)
+    }
+
+    /// Whether a tool is enabled in config and allowed for this session
+    pub fn is_tool_enabled(&self, tool: &str) -> bool {
+        let configured = self
+            .config
+            .tools
+            .get_tool(tool)
+            .is_some_and(|t| t.enabled.unwrap_or(true));
+        configured && self.overrides.is_tool_enabled(tool)
+    }
+
+    /// Tool definitions for the LLM, restricted to this session's tools
+    pub fn tool_definitions(&self) -> Vec<ToolDefinition> {
+        self.config
+            .tools
+            .to_tool_definitions()
+            .into_iter()
+            .filter(|t| self.overrides.is_tool_enabled(&t.name))
+            .collect()
+    }
+
+    /// Greeting in the session language with the session persona
+    pub fn greeting(&self, default_language: &str) -> String {
+        let template = self.config.prompts.get_greeting(self.language(default_language));
+        self.substitute_brand_placeholders(template)
+    }
+
+    /// Farewell in the session language
+    pub fn farewell(&self, default_language: &str) -> String {
+        let template = self.config.prompts.get_farewell(self.language(default_language));
+        self.substitute_brand_placeholders(template)
+    }
+
+    fn substitute_brand_placeholders(&self, text: &str) -> String {
+        let brand = &self.config.brand;
+        text.replace("{company_name}", &brand.company_name)
+            .replace("{bank_name}", &brand.company_name) // Legacy support
+            .replace("{agent_name}", self.agent_name())
+            .replace("{product_name}", &brand.product_name)
+            .replace("{helpline}", &brand.helpline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> Arc<MasterDomainConfig> {
+        let mut config = MasterDomainConfig::default();
+        config.brand.agent_name = "Priya".to_string();
+        config.brand.company_name = "Acme Bank".to_string();
+        config.prompts.greetings.insert("en".to_string(), "Hi, I'm {agent_name}".to_string());
+        config.prompts.greetings.insert("hi".to_string(), "Namaste, main {agent_name}".to_string());
+        for name in ["capture_lead", "find_locations"] {
+            let schema = serde_yaml::from_str(&format!("name: {}\ndescription: test", name)).unwrap();
+            config.tools.tools.insert(name.to_string(), schema);
+        }
+        Arc::new(config)
+    }
+
+    #[test]
+    fn test_overrides_deserialize() {
+        let overrides: SessionOverrides = serde_json::from_value(serde_json::json!({
+            "language": "hi",
+            "llm_temperature": 0.2,
+            "enabled_tools": ["capture_lead"]
+        }))
+        .unwrap();
+        assert_eq!(overrides.language.as_deref(), Some("hi"));
+        assert!(!overrides.is_empty());
+
+        let unknown = serde_json::from_value::<SessionOverrides>(serde_json::json!({"voice": "x"}));
+        assert!(unknown.is_err());
+    }
+
+    #[test]
+    fn test_validate() {
+        let config = test_config();
+        let ok = SessionOverrides {
+            language: Some("hi".to_string()),
+            enabled_tools: Some(vec!["capture_lead".to_string()]),
+            ..Default::default()
+        };
+        assert!(ok.validate(&config).is_ok());
+
+        let bad_lang = SessionOverrides { language: Some("fr".to_string()), ..Default::default() };
+        assert_eq!(
+            bad_lang.validate(&config),
+            Err(SessionOverridesError::UnsupportedLanguage("fr".to_string()))
+        );
+
+        let bad_tool = SessionOverrides {
+            enabled_tools: Some(vec!["missing".to_string()]),
+            ..Default::default()
+        };
+        assert!(matches!(bad_tool.validate(&config), Err(SessionOverridesError::UnknownTool(_))));
+
+        let bad_temp = SessionOverrides { llm_temperature: Some(3.5), ..Default::default() };
+        assert!(bad_temp.validate(&config).is_err());
+
+        let slow = SessionOverrides {
+            tts_rate: Some(0.8),
+            tts_pitch: Some(-2.0),
+            tts_sentence_pause_ms: Some(400),
+            ..Default::default()
+        };
+        assert!(slow.validate(&config).is_ok());
+
+        let too_slow = SessionOverrides { tts_rate: Some(0.2), ..Default::default() };
+        assert_eq!(too_slow.validate(&config), Err(SessionOverridesError::InvalidTtsRate(0.2)));
+    }
+
+    #[test]
+    fn test_voice_selection() {
+        let mut config = MasterDomainConfig::default();
+        config.brand.agent_name = "Priya".to_string();
+        config.voices = serde_yaml::from_str(
+            "voices:\n  hi-female-1: {engine: indicf5, language: hi, gender: female}\n  \
+             hi-male-1: {engine: indicf5, language: hi, gender: male}\n  \
+             ta-female-1: {engine: indicf5, language: ta, gender: female}\n\
+             personas:\n  Priya: {gender: female}\n",
+        )
+        .unwrap();
+        let config = Arc::new(config);
+
+        let view = SessionDomainView::new(config.clone(), SessionOverrides::default());
+        assert_eq!(view.voice_for("hi"), Some("hi-female-1"));
+        assert_eq!(view.voice_for("ta"), Some("ta-female-1"));
+
+        let overrides = SessionOverrides {
+            tts_voice: Some("hi-male-1".to_string()),
+            ..Default::default()
+        };
+        assert!(overrides.validate(&config).is_ok());
+        let view = SessionDomainView::new(config.clone(), overrides);
+        assert_eq!(view.voice_for("hi"), Some("hi-male-1"));
+        assert_eq!(view.voice_for("ta"), Some("ta-female-1"));
+
+        let unknown = SessionOverrides {
+            tts_voice: Some("missing".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            unknown.validate(&config),
+            Err(SessionOverridesError::UnknownVoice("missing".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_session_view_does_not_mutate_domain() {
+        let config = test_config();
+        let overrides = SessionOverrides {
+            language: Some("hi".to_string()),
+            persona: Some("Asha".to_string()),
+            enabled_tools: Some(vec!["capture_lead".to_string()]),
+            ..Default::default()
+        };
+        let view = SessionDomainView::new(config.clone(), overrides);
+
+        assert_eq!(view.greeting("en"), "Namaste, main Asha");
+        assert_eq!(view.tool_definitions().len(), 1);
+        assert!(!view.is_tool_enabled("find_locations"));
+        assert_eq!(view.llm_temperature(0.7), 0.7);
+
+        // Shared config and a default view are untouched
+        assert_eq!(config.brand.agent_name, "Priya");
+        let default_view = SessionDomainView::new(config, SessionOverrides::default());
+        assert_eq!(default_view.greeting("en"), "Hi, I'm Priya");
+        assert_eq!(default_view.tool_definitions().len(), 2);
+    }
+}
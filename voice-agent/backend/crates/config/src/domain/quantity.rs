@@ -0,0 +1,264 @@
+//! Typed Slot Quantities
+//!
+//! Number slots hold money, gold weight, interest rates and tenures, but the
+//! extracted value comes in whatever unit the customer used ("2.5 lakh",
+//! "5 tola", "2 saal"). A slot's `quantity` kind reads such a value as a
+//! [`SlotQuantity`] in the kind's base unit (rupees, grams, percent, months),
+//! which is then checked against the slot's `min` and `max`.
+//!
+//! ```yaml
+//! asset_quantity:
+//!   type: number
+//!   quantity: weight_grams
+//!   min: 1
+//!   max: 2000
+//!   unit_conversions:
+//!     tola: 11.66
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Kind of quantity a number slot holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuantityKind {
+    /// Amount in rupees
+    Money,
+    /// Weight in grams
+    WeightGrams,
+    /// Rate in percent
+    Percent,
+    /// Duration in months
+    Tenure,
+}
+
+impl QuantityKind {
+    /// Base unit the value is normalized to
+    pub fn unit(self) -> &'static str {
+        match self {
+            Self::Money => "rupees",
+            Self::WeightGrams => "grams",
+            Self::Percent => "percent",
+            Self::Tenure => "months",
+        }
+    }
+
+    /// Units understood without configuration, as "name name...|factor"
+    fn builtin_units(self) -> &'static [&'static str] {
+        match self {
+            Self::Money => &[
+                "crore crores cr करोड़|10000000",
+                "lakh lakhs lac lacs लाख|100000",
+                "thousand k hazar hazaar हज़ार हजार|1000",
+                "rupees rupee rs inr ₹ रुपये रुपए|1",
+            ],
+            Self::WeightGrams => &[
+                "kg kgs kilo kilos किलो|1000",
+                "tola tole तोला|11.66",
+                "grams gram gms gm g ग्राम|1",
+            ],
+            Self::Percent => &["percent pct % प्रतिशत|1"],
+            Self::Tenure => &[
+                "years year yrs yr saal sal साल वर्ष|12",
+                "months month mahine mahina महीने महीना|1",
+            ],
+        }
+    }
+
+    /// Factor to the base unit, configured conversions taking precedence
+    fn factor(self, unit: &str, conversions: Option<&HashMap<String, f64>>) -> Option<f64> {
+        if let Some(factor) = conversions.and_then(|c| c.get(unit)) {
+            return Some(*factor);
+        }
+        self.builtin_units().iter().find_map(|entry| {
+            let (names, factor) = entry.split_once('|')?;
+            names
+                .split_whitespace()
+                .any(|name| name == unit)
+                .then(|| factor.parse().ok())?
+        })
+    }
+}
+
+/// A number slot's value in its kind's base unit
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SlotQuantity {
+    /// Rupees
+    Money(f64),
+    /// Grams
+    WeightGrams(f64),
+    /// Percent
+    Percent(f64),
+    /// Whole months
+    Tenure(u32),
+}
+
+impl SlotQuantity {
+    /// Quantity of `kind` with `value` in the base unit
+    pub fn new(kind: QuantityKind, value: f64) -> Self {
+        match kind {
+            QuantityKind::Money => Self::Money(value),
+            QuantityKind::WeightGrams => Self::WeightGrams(value),
+            QuantityKind::Percent => Self::Percent(value),
+            QuantityKind::Tenure => Self::Tenure(value.round().max(0.0) as u32),
+        }
+    }
+
+    /// Read `raw` ("2.5 lakh", "₹2,50,000", "5 tola", "2 years") as `kind`
+    ///
+    /// The unit is the word after the number; `conversions` are the slot's
+    /// `unit_conversions`. A missing unit means the base unit. Returns `None`
+    /// without a number or with a unit of another kind ("5 grams" as money).
+    pub fn parse(
+        kind: QuantityKind,
+        raw: &str,
+        conversions: Option<&HashMap<String, f64>>,
+    ) -> Option<Self> {
+        let text = raw.to_lowercase().replace(',', "");
+        let text = text.trim().trim_start_matches(['₹', '$']);
+        let text = ["rs.", "rs", "inr"]
+            .iter()
+            .find_map(|prefix| text.strip_prefix(prefix))
+            .unwrap_or(text)
+            .trim_start();
+
+        let end = text
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(text.len());
+        let value: f64 = text[..end].parse().ok()?;
+        let rest = text[end..].trim_start();
+        let unit = if rest.starts_with('%') {
+            "%"
+        } else {
+            rest.split(|c: char| c.is_whitespace() || c == '.')
+                .next()
+                .unwrap_or_default()
+        };
+
+        let factor = if unit.is_empty() {
+            1.0
+        } else {
+            kind.factor(unit, conversions).or_else(|| {
+                // A following word that is no unit at all ("50000 only")
+                // leaves the value in the base unit
+                let other_kind = [
+                    QuantityKind::Money,
+                    QuantityKind::WeightGrams,
+                    QuantityKind::Percent,
+                    QuantityKind::Tenure,
+                ]
+                .iter()
+                .any(|k| k.factor(unit, None).is_some());
+                (!other_kind).then_some(1.0)
+            })?
+        };
+        Some(Self::new(kind, value * factor))
+    }
+
+    /// Kind of the quantity
+    pub fn kind(&self) -> QuantityKind {
+        match self {
+            Self::Money(_) => QuantityKind::Money,
+            Self::WeightGrams(_) => QuantityKind::WeightGrams,
+            Self::Percent(_) => QuantityKind::Percent,
+            Self::Tenure(_) => QuantityKind::Tenure,
+        }
+    }
+
+    /// Value in the base unit
+    pub fn value(&self) -> f64 {
+        match *self {
+            Self::Money(v) | Self::WeightGrams(v) | Self::Percent(v) => v,
+            Self::Tenure(months) => months as f64,
+        }
+    }
+
+    /// Whether the value lies within `min..=max` (either bound optional)
+    pub fn in_range(&self, min: Option<f64>, max: Option<f64>) -> bool {
+        let value = self.value();
+        min.map_or(true, |min| value >= min) && max.map_or(true, |max| value <= max)
+    }
+}
+
+/// The value as stored in the slot: base unit, no trailing zeros
+impl std::fmt::Display for SlotQuantity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = self.value();
+        if value.fract() == 0.0 {
+            write!(f, "{}", value as i64)
+        } else {
+            let rounded = format!("{:.2}", value);
+            write!(f, "{}", rounded.trim_end_matches('0').trim_end_matches('.'))
+        }
+    }
+}
+
+/// Why a slot value was not accepted as its quantity
+#[derive(Debug, Clone, PartialEq)]
+pub enum QuantityError {
+    /// The value has no number, or a unit of another kind
+    Unreadable(String),
+    /// The value is outside the slot's plausible range
+    OutOfRange {
+        value: SlotQuantity,
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+}
+
+impl std::fmt::Display for QuantityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unreadable(raw) => write!(f, "'{}' is not a quantity", raw),
+            Self::OutOfRange { value, min, max } => {
+                let bound = |b: &Option<f64>| b.map_or("-".to_string(), |b| b.to_string());
+                write!(
+                    f,
+                    "{} {} is outside {}..{}",
+                    value,
+                    value.kind().unit(),
+                    bound(min),
+                    bound(max)
+                )
+            },
+        }
+    }
+}
+
+impl std::error::Error for QuantityError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(kind: QuantityKind, raw: &str) -> Option<String> {
+        SlotQuantity::parse(kind, raw, None).map(|q| q.to_string())
+    }
+
+    #[test]
+    fn test_unit_normalization() {
+        use QuantityKind::*;
+        assert_eq!(parse(Money, "2.5 lakh"), Some("250000".to_string()));
+        assert_eq!(parse(Money, "Rs. 2,50,000"), Some("250000".to_string()));
+        assert_eq!(parse(Money, "500000"), Some("500000".to_string()));
+        assert_eq!(parse(WeightGrams, "5 tola"), Some("58.3".to_string()));
+        assert_eq!(parse(WeightGrams, "1.5 kg"), Some("1500".to_string()));
+        assert_eq!(parse(Percent, "12.5%"), Some("12.5".to_string()));
+        assert_eq!(parse(Tenure, "2 saal"), Some("24".to_string()));
+        // A unit of another kind
+        assert_eq!(parse(Money, "50 grams"), None);
+        assert_eq!(parse(Tenure, "abhi nahi"), None);
+    }
+
+    #[test]
+    fn test_configured_conversion_and_range() {
+        let conversions = HashMap::from([("tola".to_string(), 10.0)]);
+        let weight = SlotQuantity::parse(QuantityKind::WeightGrams, "5 tola", Some(&conversions));
+        assert_eq!(weight, Some(SlotQuantity::WeightGrams(50.0)));
+
+        let weight = weight.unwrap();
+        assert!(weight.in_range(Some(1.0), Some(2000.0)));
+        assert!(!weight.in_range(Some(100.0), None));
+    }
+}
@@ -0,0 +1,357 @@
+//! Rate Card Configuration
+//!
+//! Versioned LTV and interest rate cards loaded from rate_card.yaml.
+//!
+//! A card sets the LTV allowed per quality tier and the interest rate slabs
+//! by loan amount and tenure, and applies from its `effective_from` date
+//! (until `effective_until`, when set). On any day the card in force is the
+//! one that started most recently, so a new card can be added ahead of its
+//! start date without touching the current one.
+//!
+//! Domains without rate_card.yaml get a single card built from `constants`
+//! (`ltv_percent`, `interest_rates`, `processing_fee_percent`), so their
+//! figures do not change.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use super::master::DomainConstants;
+
+/// Version label of the card built from `constants`
+pub const CONSTANTS_RATE_CARD_VERSION: &str = "constants";
+
+/// One interest rate slab
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RateSlab {
+    /// Name shown to customers (e.g. "Premium")
+    #[serde(default)]
+    pub name: String,
+    /// Smallest loan amount covered
+    #[serde(default)]
+    pub min_amount: f64,
+    /// Largest loan amount covered (none = no upper limit)
+    #[serde(default)]
+    pub max_amount: Option<f64>,
+    /// Shortest tenure covered, in months
+    #[serde(default)]
+    pub min_tenure_months: u32,
+    /// Longest tenure covered, in months (none = no upper limit)
+    #[serde(default)]
+    pub max_tenure_months: Option<u32>,
+    /// Interest rate (% p.a.)
+    pub rate: f64,
+}
+
+impl RateSlab {
+    /// Whether the slab covers the amount and, when given, the tenure
+    pub fn covers(&self, amount: f64, tenure_months: Option<u32>) -> bool {
+        let amount_covered =
+            amount >= self.min_amount && self.max_amount.map_or(true, |max| amount <= max);
+        let tenure_covered = tenure_months.map_or(true, |tenure| {
+            tenure >= self.min_tenure_months
+                && self.max_tenure_months.map_or(true, |max| tenure <= max)
+        });
+        amount_covered && tenure_covered
+    }
+}
+
+/// A versioned rate card
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RateCard {
+    /// Version label (e.g. "2026-04"), unique across cards
+    pub version: String,
+    /// First day the card applies
+    pub effective_from: NaiveDate,
+    /// Last day the card applies, if it has an end
+    #[serde(default)]
+    pub effective_until: Option<NaiveDate>,
+    /// LTV (%) for tiers without their own entry
+    pub ltv_percent: f64,
+    /// LTV (%) by quality tier short code (e.g. "18K")
+    #[serde(default)]
+    pub ltv_by_tier: HashMap<String, f64>,
+    /// Rate slabs; the first one covering a loan applies
+    #[serde(default)]
+    pub slabs: Vec<RateSlab>,
+    /// Processing fee (% of the loan), else `constants.processing_fee_percent`
+    #[serde(default)]
+    pub processing_fee_percent: Option<f64>,
+}
+
+impl RateCard {
+    /// The card implied by `constants`, in force from the earliest date
+    pub fn from_constants(constants: &DomainConstants) -> Self {
+        let rates = &constants.interest_rates;
+        let mut slabs: Vec<RateSlab> = rates
+            .tiers
+            .iter()
+            .map(|tier| RateSlab {
+                name: tier.name.clone(),
+                min_amount: 0.0,
+                max_amount: tier.max_amount,
+                min_tenure_months: 0,
+                max_tenure_months: None,
+                rate: tier.rate,
+            })
+            .collect();
+        if slabs.is_empty() {
+            slabs.push(RateSlab {
+                name: String::new(),
+                min_amount: 0.0,
+                max_amount: None,
+                min_tenure_months: 0,
+                max_tenure_months: None,
+                rate: rates.base_rate,
+            });
+        }
+
+        Self {
+            version: CONSTANTS_RATE_CARD_VERSION.to_string(),
+            effective_from: NaiveDate::MIN,
+            effective_until: None,
+            ltv_percent: constants.ltv_percent,
+            ltv_by_tier: HashMap::new(),
+            slabs,
+            processing_fee_percent: Some(constants.processing_fee_percent),
+        }
+    }
+
+    /// Whether the card applies on `date`
+    pub fn is_effective_on(&self, date: NaiveDate) -> bool {
+        self.effective_from <= date && self.effective_until.map_or(true, |until| date <= until)
+    }
+
+    /// LTV (%) for a tier short code, or the card default
+    pub fn ltv_for(&self, tier: Option<&str>) -> f64 {
+        tier.and_then(|tier| {
+            self.ltv_by_tier
+                .iter()
+                .find(|(code, _)| code.eq_ignore_ascii_case(tier))
+                .map(|(_, ltv)| *ltv)
+        })
+        .unwrap_or(self.ltv_percent)
+    }
+
+    /// First slab covering the loan
+    pub fn slab_for(&self, amount: f64, tenure_months: Option<u32>) -> Option<&RateSlab> {
+        self.slabs
+            .iter()
+            .find(|slab| slab.covers(amount, tenure_months))
+    }
+
+    /// Interest rate (% p.a.) for the loan, if a slab covers it
+    pub fn rate_for(&self, amount: f64, tenure_months: Option<u32>) -> Option<f64> {
+        self.slab_for(amount, tenure_months).map(|slab| slab.rate)
+    }
+
+    /// Check the card's figures are usable
+    pub fn validate(&self) -> Result<(), RateCardConfigError> {
+        let invalid = |reason: String| {
+            Err(RateCardConfigError::InvalidCard(
+                self.version.clone(),
+                reason,
+            ))
+        };
+        let percent = |value: f64| value > 0.0 && value <= 100.0;
+
+        if self.version.trim().is_empty() {
+            return invalid("version is required".to_string());
+        }
+        if let Some(until) = self.effective_until {
+            if until < self.effective_from {
+                return invalid("effective_until is before effective_from".to_string());
+            }
+        }
+        if !percent(self.ltv_percent) {
+            return invalid(format!("ltv_percent {} is not in (0, 100]", self.ltv_percent));
+        }
+        if let Some((tier, ltv)) = self.ltv_by_tier.iter().find(|(_, ltv)| !percent(**ltv)) {
+            return invalid(format!("LTV {} for {} is not in (0, 100]", ltv, tier));
+        }
+        if self.slabs.is_empty() {
+            return invalid("at least one rate slab is required".to_string());
+        }
+        for slab in &self.slabs {
+            if !percent(slab.rate) {
+                return invalid(format!("rate {} is not in (0, 100]", slab.rate));
+            }
+            if slab.max_amount.is_some_and(|max| max < slab.min_amount) {
+                return invalid(format!("slab '{}' has max_amount below min_amount", slab.name));
+            }
+            if slab
+                .max_tenure_months
+                .is_some_and(|max| max < slab.min_tenure_months)
+            {
+                return invalid(format!(
+                    "slab '{}' has max_tenure_months below min_tenure_months",
+                    slab.name
+                ));
+            }
+        }
+        if let Some(fee) = self.processing_fee_percent {
+            if !(0.0..=100.0).contains(&fee) {
+                return invalid(format!("processing_fee_percent {} is not in [0, 100]", fee));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The card in force on `date`: of those covering it, the latest to start
+pub fn effective_rate_card(cards: &[RateCard], date: NaiveDate) -> Option<&RateCard> {
+    cards
+        .iter()
+        .filter(|card| card.is_effective_on(date))
+        .max_by_key(|card| card.effective_from)
+}
+
+/// Root rate card configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct RateCardConfig {
+    /// All card versions, past and upcoming
+    #[serde(default)]
+    pub cards: Vec<RateCard>,
+}
+
+impl RateCardConfig {
+    /// Load from a YAML file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, RateCardConfigError> {
+        let content = std::fs::read_to_string(path.as_ref()).map_err(|e| {
+            RateCardConfigError::FileNotFound(path.as_ref().display().to_string(), e.to_string())
+        })?;
+
+        serde_yaml::from_str(&content).map_err(|e| RateCardConfigError::ParseError(e.to_string()))
+    }
+
+    /// Check every card, and that versions are unique
+    pub fn validate(&self) -> Result<(), RateCardConfigError> {
+        let mut versions = HashSet::new();
+        for card in &self.cards {
+            card.validate()?;
+            if !versions.insert(card.version.as_str()) {
+                return Err(RateCardConfigError::DuplicateVersion(
+                    card.version.clone(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// The card in force on `date`, if any is configured
+    pub fn effective_on(&self, date: NaiveDate) -> Option<&RateCard> {
+        effective_rate_card(&self.cards, date)
+    }
+}
+
+/// Errors when loading rate card configuration
+#[derive(Debug)]
+pub enum RateCardConfigError {
+    FileNotFound(String, String),
+    ParseError(String),
+    InvalidCard(String, String),
+    DuplicateVersion(String),
+}
+
+impl std::fmt::Display for RateCardConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FileNotFound(path, err) => {
+                write!(f, "Rate card config not found at {}: {}", path, err)
+            },
+            Self::ParseError(err) => write!(f, "Failed to parse rate card config: {}", err),
+            Self::InvalidCard(version, reason) => {
+                write!(f, "Rate card '{}' is invalid: {}", version, reason)
+            },
+            Self::DuplicateVersion(version) => {
+                write!(f, "Rate card version '{}' is defined more than once", version)
+            },
+        }
+    }
+}
+
+impl std::error::Error for RateCardConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> RateCardConfig {
+        serde_yaml::from_str(
+            r#"
+cards:
+  - version: "2026-01"
+    effective_from: 2026-01-01
+    ltv_percent: 75
+    slabs:
+      - { name: Standard, rate: 11.5 }
+  - version: "2026-07"
+    effective_from: 2026-07-01
+    ltv_percent: 75
+    ltv_by_tier: { "18K": 70 }
+    slabs:
+      - { name: Short, max_amount: 100000, max_tenure_months: 6, rate: 10.5 }
+      - { name: Standard, max_amount: 100000, rate: 11.0 }
+      - { name: Premium, min_amount: 100000, rate: 9.75 }
+"#,
+        )
+        .unwrap()
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_effective_card_and_slabs() {
+        let config = config();
+        assert!(config.validate().is_ok());
+        assert!(config.effective_on(date("2025-12-31")).is_none());
+        assert_eq!(config.effective_on(date("2026-06-30")).unwrap().version, "2026-01");
+
+        let card = config.effective_on(date("2026-10-17")).unwrap();
+        assert_eq!(card.version, "2026-07");
+        assert_eq!(card.rate_for(50_000.0, Some(6)), Some(10.5));
+        assert_eq!(card.rate_for(50_000.0, Some(12)), Some(11.0));
+        assert_eq!(card.rate_for(50_000.0, None), Some(10.5));
+        assert_eq!(card.rate_for(500_000.0, Some(12)), Some(9.75));
+        assert_eq!(card.ltv_for(Some("18k")), 70.0);
+        assert_eq!(card.ltv_for(Some("22K")), 75.0);
+        assert_eq!(card.ltv_for(None), 75.0);
+
+        let mut duplicate = config.clone();
+        duplicate.cards[1].version = "2026-01".to_string();
+        assert!(matches!(
+            duplicate.validate(),
+            Err(RateCardConfigError::DuplicateVersion(_))
+        ));
+        let mut bad = config;
+        bad.cards[0].ltv_by_tier.insert("24K".to_string(), 120.0);
+        assert!(bad.validate().is_err());
+    }
+
+    #[test]
+    fn test_card_from_constants_matches_tiers() {
+        let constants: DomainConstants = serde_yaml::from_str(
+            r#"
+interest_rates:
+  tiers:
+    - { max_amount: 100000, rate: 11.5 }
+    - { max_amount: null, rate: 9.5 }
+  base_rate: 10.5
+ltv_percent: 75.0
+processing_fee_percent: 1.0
+"#,
+        )
+        .unwrap();
+        let card = RateCard::from_constants(&constants);
+
+        assert!(card.validate().is_ok());
+        assert!(card.is_effective_on(date("2026-10-17")));
+        assert_eq!(card.rate_for(100_000.0, Some(12)), Some(11.5));
+        assert_eq!(card.rate_for(100_001.0, None), Some(9.5));
+        assert_eq!(card.ltv_for(Some("22K")), 75.0);
+        assert_eq!(card.processing_fee_percent, Some(1.0));
+    }
+}
@@ -33,6 +33,9 @@ pub struct ScoringConfig {
     /// Intent strength scoring config
     #[serde(default)]
     pub intent: IntentScoringConfig,
+    /// Dialogue-state (DST) scoring config
+    #[serde(default)]
+    pub dialogue: DialogueScoringConfig,
     /// Penalty scores
     #[serde(default)]
     pub penalties: PenaltyConfig,
@@ -58,6 +61,7 @@ impl Default for ScoringConfig {
             engagement: EngagementScoringConfig::default(),
             information: InformationScoringConfig::default(),
             intent: IntentScoringConfig::default(),
+            dialogue: DialogueScoringConfig::default(),
             penalties: PenaltyConfig::default(),
             conversion_multipliers: ConversionMultipliers::default(),
             intent_signal_mappings: HashMap::new(),
@@ -194,6 +198,13 @@ pub struct CategoryWeights {
     pub engagement: f32,
     pub information: f32,
     pub intent: f32,
+    /// Weight for the dialogue-state category
+    #[serde(default = "default_weight")]
+    pub dialogue: f32,
+}
+
+fn default_weight() -> f32 {
+    1.0
 }
 
 impl Default for CategoryWeights {
@@ -203,6 +214,7 @@ impl Default for CategoryWeights {
             engagement: 1.0,
             information: 1.0,
             intent: 1.0,
+            dialogue: 1.0,
         }
     }
 }
@@ -326,6 +338,95 @@ impl Default for IntentScoringConfig {
     }
 }
 
+/// Dialogue-state scoring configuration
+///
+/// Scores signals read from the dialogue state tracker each turn: urgency
+/// slot, requested amount, goal completion, sentiment, and the rate
+/// advantage over the customer's current provider. Slot names are
+/// configurable so other domains can map their own slots.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DialogueScoringConfig {
+    pub max_score: u32,
+    /// Slot holding the urgency level (enum value id)
+    pub urgency_slot: String,
+    /// Slot holding the requested amount
+    pub amount_slot: String,
+    /// Slot holding the customer's current rate (percent)
+    pub current_rate_slot: String,
+    /// Slot holding the customer's current provider (used for its typical rate)
+    pub current_provider_slot: String,
+    /// Points per urgency level id
+    pub urgency_levels: HashMap<String, u32>,
+    /// Amount tiers; the highest matching tier applies
+    pub amount_tiers: Vec<AmountTier>,
+    /// Points at 100% goal completion (scaled linearly)
+    pub goal_completion_score: u32,
+    /// Points at fully positive sentiment (scaled by polarity)
+    pub positive_sentiment_score: u32,
+    /// Penalty at fully negative sentiment (scaled by polarity, negative value)
+    pub negative_sentiment_penalty: i32,
+    /// Points per percentage point the customer saves over their current rate
+    pub rate_advantage_per_point: u32,
+    pub max_rate_advantage_score: u32,
+}
+
+/// Amount tier for dialogue scoring
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AmountTier {
+    pub min_amount: f64,
+    pub score: u32,
+}
+
+impl Default for DialogueScoringConfig {
+    fn default() -> Self {
+        let mut urgency_levels = HashMap::new();
+        urgency_levels.insert("immediate".to_string(), 8);
+        urgency_levels.insert("soon".to_string(), 5);
+        urgency_levels.insert("planning".to_string(), 2);
+        urgency_levels.insert("exploring".to_string(), 0);
+
+        Self {
+            max_score: 25,
+            urgency_slot: "urgency".to_string(),
+            amount_slot: "offer_amount".to_string(),
+            current_rate_slot: "current_interest_rate".to_string(),
+            current_provider_slot: "current_lender".to_string(),
+            urgency_levels,
+            amount_tiers: vec![
+                AmountTier { min_amount: 100_000.0, score: 2 },
+                AmountTier { min_amount: 500_000.0, score: 4 },
+                AmountTier { min_amount: 1_000_000.0, score: 6 },
+            ],
+            goal_completion_score: 6,
+            positive_sentiment_score: 3,
+            negative_sentiment_penalty: -5,
+            rate_advantage_per_point: 1,
+            max_rate_advantage_score: 5,
+        }
+    }
+}
+
+impl DialogueScoringConfig {
+    /// Points for an urgency level id (unknown levels score 0)
+    pub fn urgency_score(&self, level: &str) -> u32 {
+        self.urgency_levels
+            .get(&level.to_lowercase())
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Points for a requested amount (highest tier the amount reaches)
+    pub fn amount_score(&self, amount: f64) -> u32 {
+        self.amount_tiers
+            .iter()
+            .filter(|tier| amount >= tier.min_amount)
+            .map(|tier| tier.score)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
 /// Penalty scoring configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PenaltyConfig {
@@ -481,4 +582,28 @@ urgency:
         assert_eq!(config.trust_score("high"), 15);
         assert_eq!(config.trust_score("invalid"), 0);
     }
+
+    #[test]
+    fn test_dialogue_scoring_defaults() {
+        let yaml = r#"
+weights:
+  urgency: 1.0
+  engagement: 1.0
+  information: 1.0
+  intent: 1.0
+dialogue:
+  amount_slot: loan_amount
+  urgency_levels:
+    immediate: 10
+"#;
+        let config: ScoringConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.weights.dialogue, 1.0);
+        assert_eq!(config.dialogue.amount_slot, "loan_amount");
+        assert_eq!(config.dialogue.urgency_score("Immediate"), 10);
+        assert_eq!(config.dialogue.urgency_score("soon"), 0);
+        // Unspecified fields keep defaults
+        assert_eq!(config.dialogue.goal_completion_score, 6);
+        assert_eq!(config.dialogue.amount_score(50_000.0), 0);
+        assert_eq!(config.dialogue.amount_score(750_000.0), 4);
+    }
 }
@@ -5,6 +5,10 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
+use voice_agent_core::Speaker;
+
+use super::keypad::KeypadConfig;
+use super::quantity::{QuantityError, QuantityKind, SlotQuantity};
 
 /// Slot schema loaded from slots.yaml
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,6 +30,26 @@ pub struct SlotsConfig {
     /// P16 FIX: Slots that should trigger customer name update (instead of fact storage)
     #[serde(default)]
     pub customer_name_slots: Vec<String>,
+    /// Slots that hold the co-applicant's own details on a conference call
+    /// e.g., {"customer_name": "co_applicant_name"}
+    #[serde(default)]
+    pub co_applicant_slots: HashMap<String, String>,
+    /// Rules for switching from one goal to another mid-conversation
+    #[serde(default)]
+    pub goal_transitions: Vec<GoalTransitionRule>,
+    /// Most goals kept suspended at once (the oldest is dropped beyond this)
+    #[serde(default = "default_max_suspended_goals")]
+    pub max_suspended_goals: usize,
+    /// Words that answer a confirmation question, by language
+    #[serde(default)]
+    pub confirmation_replies: ConfirmationReplies,
+    /// Answers on the phone keypad when speech keeps being mis-heard
+    #[serde(default)]
+    pub keypad: KeypadConfig,
+}
+
+fn default_max_suspended_goals() -> usize {
+    3
 }
 
 impl Default for SlotsConfig {
@@ -36,6 +60,11 @@ impl Default for SlotsConfig {
             intent_mapping: HashMap::new(),
             slot_aliases: HashMap::new(),
             customer_name_slots: vec!["customer_name".to_string(), "name".to_string()],
+            co_applicant_slots: HashMap::new(),
+            goal_transitions: Vec::new(),
+            max_suspended_goals: default_max_suspended_goals(),
+            confirmation_replies: ConfirmationReplies::default(),
+            keypad: KeypadConfig::default(),
         }
     }
 }
@@ -55,6 +84,29 @@ impl SlotsConfig {
         self.slots.get(name)
     }
 
+    /// Definition of a slot by name, its alias or a name it is extracted under
+    pub fn definition_for(&self, name: &str) -> Option<&SlotDefinition> {
+        self.get_slot(name)
+            .or_else(|| self.get_slot(self.canonical_fact_key(name)))
+            .or_else(|| {
+                let mut slots = self.slots.values();
+                slots.find(|def| def.aliases.iter().any(|alias| alias == name))
+            })
+    }
+
+    /// Slots whose confirmation policy requires them confirmed before `tool` runs
+    pub fn slots_to_confirm_before(&self, tool: &str) -> Vec<&str> {
+        self.slots
+            .iter()
+            .filter(|(_, def)| {
+                def.confirmation
+                    .as_ref()
+                    .is_some_and(|policy| policy.before_tools.iter().any(|t| t == tool))
+            })
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+
     /// Get a goal definition by name
     pub fn get_goal(&self, name: &str) -> Option<&GoalDefinition> {
         self.goals.get(name)
@@ -70,6 +122,19 @@ impl SlotsConfig {
         None
     }
 
+    /// What happens to goal `from` when the conversation switches to `to`
+    ///
+    /// The first matching rule wins (`"*"` matches any goal). Without one,
+    /// an unfinished goal is suspended so it can be resumed later.
+    pub fn goal_switch_action(&self, from: &str, to: &str) -> GoalSwitchAction {
+        let matches = |pattern: &str, goal: &str| pattern == "*" || pattern == goal;
+        self.goal_transitions
+            .iter()
+            .find(|rule| matches(&rule.from, from) && matches(&rule.to, to))
+            .map(|rule| rule.action)
+            .unwrap_or_default()
+    }
+
     /// Get extraction patterns for a slot
     pub fn extraction_patterns(&self, slot_name: &str, language: &str) -> Vec<&str> {
         self.slots
@@ -186,6 +251,21 @@ impl SlotsConfig {
         self.customer_name_slots.iter().any(|s| s == slot_name)
     }
 
+    /// Slot a value extracted from `speaker`'s words is recorded under
+    ///
+    /// The co-applicant's own name or phone goes to its co-applicant slot;
+    /// everything else is shared by the application.
+    pub fn slot_for_speaker<'a>(&'a self, slot_name: &'a str, speaker: Speaker) -> &'a str {
+        match speaker {
+            Speaker::Customer => slot_name,
+            Speaker::CoApplicant => self
+                .co_applicant_slots
+                .get(slot_name)
+                .map(|s| s.as_str())
+                .unwrap_or(slot_name),
+        }
+    }
+
     /// Get the canonical fact key for a slot, checking aliases first
     /// If no alias exists, returns the original slot name
     pub fn canonical_fact_key<'a>(&'a self, slot_name: &'a str) -> &'a str {
@@ -314,6 +394,166 @@ pub struct SlotDefinition {
     /// P20 FIX: Currency code (e.g., "INR" for offer_amount)
     #[serde(default)]
     pub currency: Option<String>,
+    /// When the value must be read back to the customer (none: confidence-based)
+    #[serde(default)]
+    pub confirmation: Option<ConfirmationPolicy>,
+    /// Format of a value the customer may dictate in pieces across turns
+    #[serde(default)]
+    pub spelled: Option<SpelledInput>,
+    /// Kind of quantity a number slot holds; its values are normalized to
+    /// the kind's base unit and must lie within `min`/`max`
+    #[serde(default)]
+    pub quantity: Option<QuantityKind>,
+    /// Other names the slot is extracted under (e.g. "loan_amount")
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+impl SlotDefinition {
+    /// Read `value` as the slot's quantity and check its range
+    ///
+    /// Returns `Ok(None)` for a slot without a `quantity` kind.
+    pub fn check_quantity(&self, value: &str) -> Result<Option<SlotQuantity>, QuantityError> {
+        let Some(kind) = self.quantity else {
+            return Ok(None);
+        };
+        let conversions = self.unit_conversions.as_ref();
+        let quantity = SlotQuantity::parse(kind, value, conversions)
+            .ok_or_else(|| QuantityError::Unreadable(value.to_string()))?;
+        if !quantity.in_range(self.min, self.max) {
+            return Err(QuantityError::OutOfRange {
+                value: quantity,
+                min: self.min,
+                max: self.max,
+            });
+        }
+        Ok(Some(quantity))
+    }
+}
+
+/// Value dictated digit by digit or letter by letter, possibly over several
+/// turns ("98763... haan, 43210")
+///
+/// ```yaml
+/// spelled:
+///   format: "AAAAA9999A"
+///   checksum: pan
+///   reprompt:
+///     en: "And the remaining {remaining} characters after {received}?"
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SpelledInput {
+    /// One class per character: `9` a digit, `A` a letter, `*` either
+    pub format: String,
+    /// Check applied to the complete value, on top of `validation`
+    #[serde(default)]
+    pub checksum: Option<SpelledChecksum>,
+    /// Prefixes dropped when the value would otherwise be too long (e.g. "91")
+    #[serde(default)]
+    pub prefixes: Vec<String>,
+    /// Question for the missing part by language, with `{received}` and
+    /// `{remaining}` placeholders
+    #[serde(default)]
+    pub reprompt: HashMap<String, String>,
+}
+
+/// Check-digit or structure check for a spelled value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpelledChecksum {
+    /// PAN structure, including the holder-type letter
+    Pan,
+    /// Aadhaar Verhoeff check digit
+    Aadhaar,
+}
+
+/// Per-slot confirmation policy
+///
+/// ```yaml
+/// confirmation:
+///   below_confidence: 0.95
+///   before_tools: [capture_lead]
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfirmationPolicy {
+    /// Confirm every value, whatever the extraction confidence
+    #[serde(default)]
+    pub always: bool,
+    /// Confirm values extracted with less confidence than this
+    #[serde(default)]
+    pub below_confidence: Option<f32>,
+    /// Tools that must not run until the value is confirmed
+    #[serde(default)]
+    pub before_tools: Vec<String>,
+    /// Ignore newly extracted values once the slot is confirmed
+    #[serde(default = "default_true")]
+    pub lock: bool,
+}
+
+impl Default for ConfirmationPolicy {
+    fn default() -> Self {
+        Self {
+            always: false,
+            below_confidence: None,
+            before_tools: Vec::new(),
+            lock: default_true(),
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Yes/no answers to "did I get that right?", keyed by language code
+///
+/// Words from every language are accepted, since callers code-mix.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfirmationReplies {
+    #[serde(default)]
+    pub yes: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    pub no: HashMap<String, Vec<String>>,
+}
+
+impl Default for ConfirmationReplies {
+    fn default() -> Self {
+        let words = |list: &[&str]| list.iter().map(|w| w.to_string()).collect::<Vec<_>>();
+        Self {
+            yes: HashMap::from([
+                (
+                    "en".to_string(),
+                    words(&["yes", "yeah", "yep", "correct", "right", "exactly", "sure"]),
+                ),
+                (
+                    "hi".to_string(),
+                    words(&[
+                        "haan",
+                        "haa",
+                        "ji",
+                        "sahi",
+                        "bilkul",
+                        "theek hai",
+                        "हाँ",
+                        "हां",
+                        "जी",
+                        "सही",
+                        "बिल्कुल",
+                    ]),
+                ),
+            ]),
+            no: HashMap::from([
+                (
+                    "en".to_string(),
+                    words(&["no", "nope", "not", "wrong", "incorrect"]),
+                ),
+                (
+                    "hi".to_string(),
+                    words(&["nahi", "nahin", "galat", "नहीं", "नही", "गलत"]),
+                ),
+            ]),
+        }
+    }
 }
 
 /// Slot type enumeration
@@ -421,6 +661,29 @@ pub struct GoalDefinition {
     pub completion_action: Option<String>,
 }
 
+/// Rule for switching goals, e.g. `{ from: eligibility_check, to: new_loan, action: replace }`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoalTransitionRule {
+    /// Goal being left (`"*"` for any)
+    pub from: String,
+    /// Goal being entered (`"*"` for any)
+    pub to: String,
+    /// What happens to the goal being left
+    #[serde(default)]
+    pub action: GoalSwitchAction,
+}
+
+/// What happens to the current goal when another one starts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum GoalSwitchAction {
+    /// Keep it to resume once the new goal is complete
+    #[default]
+    Suspend,
+    /// Drop it (the new goal supersedes it)
+    Replace,
+}
+
 /// Errors when loading slot configuration
 #[derive(Debug)]
 pub enum SlotsConfigError {
@@ -505,6 +768,33 @@ intent_mapping:
         assert_eq!(config.goal_for_intent("unknown"), None);
     }
 
+    #[test]
+    fn test_goal_switch_rules() {
+        let yaml = r#"
+goal_transitions:
+  - from: eligibility_check
+    to: new_loan
+    action: replace
+  - from: "*"
+    to: lead_capture
+    action: replace
+"#;
+        let config: SlotsConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.max_suspended_goals, 3);
+        assert_eq!(
+            config.goal_switch_action("eligibility_check", "new_loan"),
+            GoalSwitchAction::Replace
+        );
+        assert_eq!(
+            config.goal_switch_action("balance_transfer", "lead_capture"),
+            GoalSwitchAction::Replace
+        );
+        assert_eq!(
+            config.goal_switch_action("new_loan", "branch_visit"),
+            GoalSwitchAction::Suspend
+        );
+    }
+
     #[test]
     fn test_unit_conversion() {
         let yaml = r#"
@@ -520,4 +810,18 @@ slots:
         assert_eq!(config.unit_conversion("weight", "oz"), Some(31.1));
         assert_eq!(config.unit_conversion("weight", "unknown"), None);
     }
+
+    #[test]
+    fn test_slot_for_speaker() {
+        let yaml = r#"
+co_applicant_slots:
+  customer_name: co_applicant_name
+"#;
+        let config: SlotsConfig = serde_yaml::from_str(yaml).unwrap();
+        let slot = |name, speaker| config.slot_for_speaker(name, speaker);
+        assert_eq!(slot("customer_name", Speaker::Customer), "customer_name");
+        assert_eq!(slot("loan_amount", Speaker::CoApplicant), "loan_amount");
+        let co_applicant_name = slot("customer_name", Speaker::CoApplicant);
+        assert_eq!(co_applicant_name, "co_applicant_name");
+    }
 }
@@ -153,6 +153,17 @@ impl StagesConfig {
             .unwrap_or(0.0)
     }
 
+    /// Stage to advance to once `stage_id`'s success criteria are met
+    ///
+    /// `None` when the stage has no `advance_to` or it is not a valid
+    /// transition.
+    pub fn advance_target(&self, stage_id: &str) -> Option<&str> {
+        self.stages
+            .get(stage_id)
+            .and_then(|s| s.advance_to.as_deref())
+            .filter(|to| self.is_valid_transition(stage_id, to))
+    }
+
     /// P16 FIX: Get intent-based transition target
     ///
     /// Returns the target stage for a given intent and current stage, if defined.
@@ -216,9 +227,14 @@ pub struct StageDefinition {
     /// Valid next stages (transitions)
     #[serde(default)]
     pub transitions: Vec<String>,
-    /// Requirements to stay in or leave this stage
-    #[serde(default)]
+    /// Success criteria: what must happen before leaving this stage
+    #[serde(default, alias = "success_criteria")]
     pub requirements: StageRequirements,
+    /// Stage entered automatically once the success criteria are met
+    /// (must be one of `transitions`); without it the stage only changes
+    /// on intent transitions
+    #[serde(default)]
+    pub advance_to: Option<String>,
 }
 
 fn default_context_budget() -> usize {
@@ -333,6 +349,28 @@ stages:
         assert!(!config.is_valid_transition("c", "a"));
     }
 
+    #[test]
+    fn test_advance_target() {
+        let yaml = r#"
+stages:
+  greeting:
+    transitions: [discovery]
+    success_criteria:
+      min_turns: 1
+    advance_to: discovery
+  discovery:
+    transitions: [closing]
+    advance_to: farewell
+"#;
+        let config: StagesConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.advance_target("greeting"), Some("discovery"));
+        let greeting = config.get_stage("greeting").unwrap();
+        assert_eq!(greeting.requirements.min_turns, 1);
+        // Not a listed transition
+        assert_eq!(config.advance_target("discovery"), None);
+        assert_eq!(config.advance_target("closing"), None);
+    }
+
     #[test]
     fn test_transition_triggers() {
         let yaml = r#"
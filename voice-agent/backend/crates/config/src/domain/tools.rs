@@ -157,6 +157,20 @@ impl ToolsConfig {
         self.tools.get(name)
     }
 
+    /// Question asking the customer for a tool argument, falling back to English
+    pub fn argument_question(&self, tool: &str, argument: &str, language: &str) -> Option<&str> {
+        let param = self
+            .get_tool(tool)?
+            .parameters
+            .iter()
+            .find(|param| param.name == argument)?;
+        param
+            .ask
+            .get(language)
+            .or_else(|| param.ask.get("en"))
+            .map(|s| s.as_str())
+    }
+
     /// Get all tool names
     pub fn tool_names(&self) -> Vec<&str> {
         self.tools.keys().map(|s| s.as_str()).collect()
@@ -172,11 +186,17 @@ impl ToolsConfig {
         self.tools.values().map(|t| t.to_json_schema()).collect()
     }
 
+    /// Find the schema for a tool by name or alias
+    pub fn find_tool(&self, name: &str) -> Option<&ToolSchema> {
+        self.get_tool(name)
+            .or_else(|| self.tools.values().find(|t| t.matches_name(name)))
+    }
+
     /// Get tool names that are enabled
     pub fn enabled_tool_names(&self) -> Vec<&str> {
         self.tools
             .iter()
-            .filter(|(_, t)| t.enabled.unwrap_or(true))
+            .filter(|(_, t)| t.is_enabled())
             .map(|(name, _)| name.as_str())
             .collect()
     }
@@ -411,6 +431,33 @@ pub struct ToolSchema {
     /// P22 FIX: Tool metadata for factory use (loaded from config)
     #[serde(default)]
     pub metadata: Option<ToolSchemaMetadata>,
+    /// External HTTP endpoint backing this tool (no Rust implementation needed)
+    #[serde(default)]
+    pub http: Option<HttpToolConfig>,
+}
+
+/// Declarative HTTP-backed tool
+///
+/// The tool's arguments are sent as a JSON body (or as query parameters for
+/// GET). `${VAR}` in the URL and header values is expanded from the environment
+/// at call time so secrets stay out of the YAML.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HttpToolConfig {
+    /// Endpoint URL
+    pub url: String,
+    /// HTTP method (GET, POST, PUT, PATCH)
+    #[serde(default = "default_http_method")]
+    pub method: String,
+    /// Extra request headers
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    /// Dotted path into the JSON response to return (whole body if unset)
+    #[serde(default)]
+    pub response_path: Option<String>,
+}
+
+fn default_http_method() -> String {
+    "POST".to_string()
 }
 
 impl ToolSchema {
@@ -456,6 +503,9 @@ impl ToolSchema {
             if let Some(max) = param.max {
                 prop.insert("maximum".to_string(), serde_json::json!(max));
             }
+            if let Some(format) = &param.format {
+                prop.insert("format".to_string(), JsonValue::String(format.clone()));
+            }
 
             properties.insert(param.name.clone(), JsonValue::Object(prop));
 
@@ -506,6 +556,11 @@ impl ToolSchema {
                 prop.insert("maximum".to_string(), serde_json::json!(max));
             }
 
+            // Add named string format
+            if let Some(format) = &param.format {
+                prop.insert("format".to_string(), JsonValue::String(format.clone()));
+            }
+
             properties.insert(param.name.clone(), JsonValue::Object(prop));
 
             if param.required {
@@ -524,6 +579,11 @@ impl ToolSchema {
         })
     }
 
+    /// Whether the tool is enabled for this domain (default: true)
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or(true)
+    }
+
     // ====== P22 FIX: Metadata Accessor Methods ======
 
     /// Get display name (from metadata or fall back to name)
@@ -619,6 +679,13 @@ pub struct ToolParameter {
     /// Default value
     #[serde(default)]
     pub default: Option<String>,
+    /// Named string format checked before the tool runs (`phone`, `pincode`, `email`)
+    #[serde(default)]
+    pub format: Option<String>,
+    /// Question asking the customer for this value, by language, used when
+    /// the LLM proposes a call without a usable value
+    #[serde(default)]
+    pub ask: HashMap<String, String>,
 }
 
 impl ToolParameter {
@@ -628,6 +695,7 @@ impl ToolParameter {
     /// - Type mapping (string, number, integer, boolean)
     /// - Enum constraints
     /// - Numeric range constraints (min/max)
+    /// - Named string formats
     /// - Default values
     pub fn to_core_property_schema(&self) -> CorePropertySchema {
         // Create base schema based on type
@@ -655,6 +723,10 @@ impl ToolParameter {
             schema.maximum = Some(max);
         }
 
+        if let Some(ref format) = self.format {
+            schema = schema.with_format(format);
+        }
+
         // Add default value
         if let Some(ref default) = self.default {
             // Try to parse as appropriate type
@@ -711,6 +783,9 @@ tools:
         required: true
         min: 1.0
         max: 10000.0
+        ask:
+          en: "How many grams of gold do you have?"
+          hi: "Aapke paas kitne gram sona hai?"
       - name: gold_purity
         type: string
         description: "Purity level"
@@ -725,6 +800,16 @@ tools:
         assert_eq!(tool.parameters.len(), 2);
         assert!(tool.parameters[0].required);
         assert!(!tool.parameters[1].required);
+
+        assert_eq!(
+            config.argument_question("check_eligibility", "gold_weight", "hi"),
+            Some("Aapke paas kitne gram sona hai?")
+        );
+        assert_eq!(
+            config.argument_question("check_eligibility", "gold_weight", "ta"),
+            Some("How many grams of gold do you have?")
+        );
+        assert_eq!(config.argument_question("check_eligibility", "gold_purity", "en"), None);
     }
 
     #[test]
@@ -735,6 +820,7 @@ tools:
             enabled: None,
             category: Some("test".to_string()),
             metadata: None, // P23 FIX: Added missing field
+            http: None,
             parameters: vec![
                 ToolParameter {
                     name: "required_param".to_string(),
@@ -745,6 +831,8 @@ tools:
                     min: None,
                     max: None,
                     default: None,
+                    format: None,
+                    ask: HashMap::new(),
                 },
                 ToolParameter {
                     name: "optional_param".to_string(),
@@ -755,6 +843,8 @@ tools:
                     min: Some(0.0),
                     max: Some(100.0),
                     default: None,
+                    format: None,
+                    ask: HashMap::new(),
                 },
             ],
         };
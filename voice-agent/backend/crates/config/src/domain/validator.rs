@@ -6,6 +6,11 @@
 //! - Cross-reference validation (e.g., goals reference valid slots)
 //! - Value range validation
 //! - Schema completeness checks
+//! - Tool references (goals, intent mappings and examples point at defined tools)
+//! - Prompt language coverage (every template has every configured language)
+//!
+//! Also backs the `validate-config` binary, which runs the same checks
+//! outside the server so broken YAML is caught before deploy.
 //!
 //! # Example
 //!
@@ -13,12 +18,18 @@
 //! use voice_agent_config::domain::ConfigValidator;
 //!
 //! let validator = ConfigValidator::new();
-//! let result = validator.validate_domain(&domain_id, &config)?;
+//! let result = validator.validate(&domain_id, &config);
+//!
+//! // Or load + validate in one step
+//! let result = voice_agent_config::validate_domain("gold_loan", "config")?;
 //! ```
 
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::Path;
+
 use super::MasterDomainConfig;
 use super::slots::SlotType;
+use crate::ConfigError;
 
 /// Validation error with context
 #[derive(Debug, Clone)]
@@ -35,14 +46,16 @@ pub struct ValidationError {
     pub severity: ValidationSeverity,
 }
 
+impl ValidationError {
+    /// Full path to the offending entry, e.g. `goals.yaml/goals.balance_transfer.completion_tool`
+    pub fn path(&self) -> String {
+        format!("{}/{}", self.source, self.field.as_deref().unwrap_or("(root)"))
+    }
+}
+
 impl std::fmt::Display for ValidationError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let field_str = self.field.as_deref().unwrap_or("(root)");
-        write!(
-            f,
-            "[{:?}] {}/{}: {}",
-            self.severity, self.source, field_str, self.message
-        )
+        write!(f, "[{:?}] {}: {}", self.severity, self.path(), self.message)
     }
 }
 
@@ -132,11 +145,27 @@ impl ValidationResult {
         });
     }
 
+    /// Add a missing-variant error (e.g., a prompt without a Hindi translation)
+    pub fn add_missing_variant(&mut self, source: &str, field: &str, message: &str) {
+        self.errors.push(ValidationError {
+            category: ValidationCategory::SchemaMismatch,
+            source: source.to_string(),
+            field: Some(field.to_string()),
+            message: message.to_string(),
+            severity: ValidationSeverity::Error,
+        });
+    }
+
     /// Check if validation passed (no critical errors)
     pub fn is_ok(&self) -> bool {
         !self.errors.iter().any(|e| e.severity == ValidationSeverity::Critical)
     }
 
+    /// Check if validation passed with no errors at all (warnings allowed)
+    pub fn is_strict_ok(&self) -> bool {
+        !self.errors.iter().any(|e| e.severity >= ValidationSeverity::Error)
+    }
+
     /// Get only critical errors
     pub fn critical_errors(&self) -> Vec<&ValidationError> {
         self.errors
@@ -174,6 +203,9 @@ impl ValidationResult {
 pub struct ConfigValidator {
     /// Whether to include warnings
     include_warnings: bool,
+    /// Languages every multilingual template must provide.
+    /// When empty, derived from the languages used by `prompts.greetings`.
+    required_languages: Vec<String>,
 }
 
 impl Default for ConfigValidator {
@@ -187,6 +219,7 @@ impl ConfigValidator {
     pub fn new() -> Self {
         Self {
             include_warnings: true,
+            required_languages: Vec::new(),
         }
     }
 
@@ -196,6 +229,25 @@ impl ConfigValidator {
         self
     }
 
+    /// Set the languages every multilingual template must provide
+    pub fn with_required_languages(mut self, languages: Vec<String>) -> Self {
+        self.required_languages = languages;
+        self
+    }
+
+    /// Load a domain from disk and validate it
+    ///
+    /// Load failures (missing domain.yaml, malformed YAML) are returned as
+    /// `Err`; referential problems are reported in the `ValidationResult`.
+    pub fn validate_dir(
+        &self,
+        domain_id: &str,
+        config_dir: impl AsRef<Path>,
+    ) -> Result<ValidationResult, ConfigError> {
+        let config = MasterDomainConfig::load(domain_id, config_dir)?;
+        Ok(self.validate(domain_id, &config))
+    }
+
     /// Validate a domain configuration
     pub fn validate(&self, domain: &str, config: &MasterDomainConfig) -> ValidationResult {
         let mut result = ValidationResult::new(domain);
@@ -221,6 +273,18 @@ impl ConfigValidator {
         // 7. Cross-validate references
         self.validate_cross_references(config, &mut result);
 
+        // 8. Validate tool references
+        self.validate_tool_references(config, &mut result);
+
+        // 9. Validate prompt language variants
+        self.validate_prompt_languages(config, &mut result);
+
+        // 10. Validate rate cards
+        self.validate_rate_cards(config, &mut result);
+
+        // 11. Validate promotional offers
+        self.validate_offers(config, &mut result);
+
         result
     }
 
@@ -263,6 +327,53 @@ impl ConfigValidator {
                     }
                 }
             }
+
+            // Only number slots hold a quantity
+            if slot.quantity.is_some() && slot.slot_type != SlotType::Number {
+                result.add_reference_error(
+                    "slots.yaml",
+                    id,
+                    "Quantity kind set on a non-number slot",
+                );
+            }
+
+            // Spelled formats use 9 (digit), A (letter) and * (either)
+            if let Some(ref spelled) = slot.spelled {
+                let classes_ok = spelled.format.chars().all(|c| matches!(c, '9' | 'A' | '*'));
+                if spelled.format.is_empty() || !classes_ok {
+                    result.add_reference_error(
+                        "slots.yaml",
+                        id,
+                        &format!("Invalid spelled format: '{}'", spelled.format),
+                    );
+                }
+            }
+        }
+
+        // Co-applicant details must go to defined slots
+        for (slot, target) in &slots.co_applicant_slots {
+            if !slots.slots.contains_key(target) {
+                result.add_reference_error(
+                    "slots.yaml",
+                    &format!("co_applicant_slots.{}", slot),
+                    &format!("Unknown co-applicant slot: {}", target),
+                );
+            }
+        }
+
+        // Keypad entries must name defined slots and take at least one key
+        for (slot, entry) in &slots.keypad.slots {
+            let field = format!("keypad.slots.{}", slot);
+            if !slots.slots.contains_key(slot) {
+                result.add_reference_error("slots.yaml", &field, "Keypad entry for unknown slot");
+            }
+            if entry.digits == 0 {
+                result.add_reference_error("slots.yaml", &field, "Keypad entry needs digits > 0");
+            }
+        }
+        let yes_no = &slots.keypad.yes_no;
+        if yes_no.yes_key == yes_no.no_key {
+            result.add_reference_error("slots.yaml", "keypad.yes_no", "Same key for yes and no");
         }
     }
 
@@ -299,7 +410,7 @@ impl ConfigValidator {
                 if !stage_ids.contains(target_stage) {
                     result.add_reference_error(
                         "stages.yaml",
-                        id,
+                        &format!("stages.{}.transitions", id),
                         &format!("Transition references unknown stage: {}", target_stage),
                     );
                 }
@@ -421,7 +532,7 @@ impl ConfigValidator {
                 if !slot_ids.contains(slot) {
                     result.add_reference_error(
                         "goals.yaml",
-                        goal_id,
+                        &format!("goals.{}.required_slots", goal_id),
                         &format!("Goal references unknown required slot: {}", slot),
                     );
                 }
@@ -431,7 +542,7 @@ impl ConfigValidator {
                 if !slot_ids.contains(slot) {
                     result.add_reference_error(
                         "goals.yaml",
-                        goal_id,
+                        &format!("goals.{}.optional_slots", goal_id),
                         &format!("Goal references unknown optional slot: {}", slot),
                     );
                 }
@@ -457,6 +568,266 @@ impl ConfigValidator {
             }
         }
     }
+
+    /// Validate that goals, intent mappings and examples only reference defined tools
+    fn validate_tool_references(&self, config: &MasterDomainConfig, result: &mut ValidationResult) {
+        let tools = &config.tools;
+
+        // Without schemas.yaml every reference would be reported; that case
+        // is already visible as a missing tools config.
+        if tools.tools.is_empty() {
+            if self.include_warnings {
+                result.add_warning("tools/schemas.yaml", "tools", "No tools defined");
+            }
+            return;
+        }
+
+        let tool_ids: HashSet<&str> = tools.tools.keys().map(|s| s.as_str()).collect();
+
+        for (goal_id, goal) in &config.goals.goals {
+            if let Some(tool) = &goal.completion_tool {
+                if !tool_ids.contains(tool.as_str()) {
+                    result.add_reference_error(
+                        "goals.yaml",
+                        &format!("goals.{}.completion_tool", goal_id),
+                        &format!("Goal references unknown tool: {}", tool),
+                    );
+                }
+            }
+        }
+
+        for (intent, mapping) in &tools.intent_to_tool {
+            if !tool_ids.contains(mapping.tool.as_str()) {
+                result.add_reference_error(
+                    "intent_tool_mappings.yaml",
+                    &format!("intent_to_tool.{}.tool", intent),
+                    &format!("Intent mapping references unknown tool: {}", mapping.tool),
+                );
+            }
+            if let Some(fallback) = &mapping.fallback_tool {
+                if !tool_ids.contains(fallback.as_str()) {
+                    result.add_reference_error(
+                        "intent_tool_mappings.yaml",
+                        &format!("intent_to_tool.{}.fallback_tool", intent),
+                        &format!("Intent mapping references unknown fallback tool: {}", fallback),
+                    );
+                }
+            }
+        }
+
+        for tool in tools.tool_defaults.keys() {
+            if !tool_ids.contains(tool.as_str()) {
+                result.add_reference_error(
+                    "intent_tool_mappings.yaml",
+                    &format!("tool_defaults.{}", tool),
+                    &format!("Defaults declared for unknown tool: {}", tool),
+                );
+            }
+        }
+
+        for tool in tools.argument_mappings.keys() {
+            if !tool_ids.contains(tool.as_str()) {
+                result.add_reference_error(
+                    "intent_tool_mappings.yaml",
+                    &format!("argument_mappings.{}", tool),
+                    &format!("Argument mapping declared for unknown tool: {}", tool),
+                );
+            }
+        }
+
+        for example in &config.examples.examples {
+            if let Some(call) = &example.tool_call {
+                if !tool_ids.contains(call.name.as_str()) {
+                    result.add_reference_error(
+                        "examples.yaml",
+                        &format!("examples.{}.tool_call.name", example.id),
+                        &format!("Example calls unknown tool: {}", call.name),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Validate that every multilingual prompt template has all required languages
+    fn validate_prompt_languages(&self, config: &MasterDomainConfig, result: &mut ValidationResult) {
+        let prompts = &config.prompts;
+
+        let required: BTreeSet<&str> = if self.required_languages.is_empty() {
+            prompts.greetings.keys().map(|s| s.as_str()).collect()
+        } else {
+            self.required_languages.iter().map(|s| s.as_str()).collect()
+        };
+        if required.is_empty() {
+            return;
+        }
+
+        const SOURCE: &str = "prompts/system.yaml";
+        check_languages(result, SOURCE, "greetings", &prompts.greetings, &required);
+        check_languages(result, SOURCE, "farewells", &prompts.farewells, &required);
+
+        let nested = [
+            ("response_templates", &prompts.response_templates),
+            ("error_templates", &prompts.error_templates),
+            ("dst_instructions", &prompts.dst_instructions),
+            ("stage_fallback_responses", &prompts.stage_fallback_responses),
+        ];
+        for (section, templates) in nested {
+            for (key, variants) in templates {
+                check_languages(
+                    result,
+                    SOURCE,
+                    &format!("{}.{}", section, key),
+                    variants,
+                    &required,
+                );
+            }
+        }
+
+        for (goal_id, goal) in &config.goals.goals {
+            if let Some(slot_prompts) = &goal.slot_prompts {
+                for (slot, variants) in slot_prompts {
+                    check_languages(
+                        result,
+                        "goals.yaml",
+                        &format!("goals.{}.slot_prompts.{}", goal_id, slot),
+                        variants,
+                        &required,
+                    );
+                }
+            }
+        }
+    }
+    /// Validate rate cards: usable figures, unique versions, known tiers
+    fn validate_rate_cards(&self, config: &MasterDomainConfig, result: &mut ValidationResult) {
+        const SOURCE: &str = "rate_card.yaml";
+
+        let tier_codes: Vec<String> = config
+            .slots
+            .get_slot("asset_quality_tier")
+            .and_then(|slot| slot.values.as_ref())
+            .map(|values| values.iter().map(|v| v.short_code().to_string()).collect())
+            .unwrap_or_default();
+
+        let mut versions = HashSet::new();
+        for card in &config.rate_card.cards {
+            let field = format!("cards.{}", card.version);
+            if let Err(e) = card.validate() {
+                result.add_error(ValidationError {
+                    category: ValidationCategory::ValueOutOfRange,
+                    source: SOURCE.to_string(),
+                    field: Some(field.clone()),
+                    message: e.to_string(),
+                    severity: ValidationSeverity::Error,
+                });
+            }
+            if !versions.insert(card.version.as_str()) {
+                result.add_error(ValidationError {
+                    category: ValidationCategory::Duplicate,
+                    source: SOURCE.to_string(),
+                    field: Some(field.clone()),
+                    message: "Duplicate rate card version".to_string(),
+                    severity: ValidationSeverity::Error,
+                });
+            }
+
+            if tier_codes.is_empty() {
+                continue;
+            }
+            for tier in card.ltv_by_tier.keys() {
+                if !tier_codes
+                    .iter()
+                    .any(|code| code.eq_ignore_ascii_case(tier))
+                {
+                    result.add_reference_error(
+                        SOURCE,
+                        &format!("{}.ltv_by_tier.{}", field, tier),
+                        "References unknown quality tier",
+                    );
+                }
+            }
+        }
+    }
+
+    /// Validate offers: usable terms, unique IDs, known lenders and cities
+    fn validate_offers(&self, config: &MasterDomainConfig, result: &mut ValidationResult) {
+        const SOURCE: &str = "offers.yaml";
+
+        let mut ids = HashSet::new();
+        for offer in &config.offers.offers {
+            let field = format!("offers.{}", offer.id);
+            if let Err(e) = offer.validate() {
+                result.add_error(ValidationError {
+                    category: ValidationCategory::ValueOutOfRange,
+                    source: SOURCE.to_string(),
+                    field: Some(field.clone()),
+                    message: e.to_string(),
+                    severity: ValidationSeverity::Error,
+                });
+            }
+            if !ids.insert(offer.id.as_str()) {
+                result.add_error(ValidationError {
+                    category: ValidationCategory::Duplicate,
+                    source: SOURCE.to_string(),
+                    field: Some(field.clone()),
+                    message: "Duplicate offer ID".to_string(),
+                    severity: ValidationSeverity::Error,
+                });
+            }
+
+            for lender in &offer.eligibility.balance_transfer_from {
+                if config.competitors_config.get_competitor(lender).is_none() {
+                    result.add_reference_error(
+                        SOURCE,
+                        &format!("{}.eligibility.balance_transfer_from", field),
+                        &format!("References unknown competitor '{}'", lender),
+                    );
+                }
+            }
+            // The gazetteer may not list every city, so these only warn
+            for city in &offer.eligibility.cities {
+                if config.locations.city(city).is_none() {
+                    result.add_warning(
+                        SOURCE,
+                        &format!("{}.eligibility.cities", field),
+                        &format!("City '{}' is not in the gazetteer", city),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Load and validate a domain with the default validator settings
+pub fn validate_domain(
+    domain_id: &str,
+    config_dir: impl AsRef<Path>,
+) -> Result<ValidationResult, ConfigError> {
+    ConfigValidator::new().validate_dir(domain_id, config_dir)
+}
+
+/// Report each required language missing (or blank) in a language-keyed map
+fn check_languages(
+    result: &mut ValidationResult,
+    source: &str,
+    path: &str,
+    variants: &HashMap<String, String>,
+    required: &BTreeSet<&str>,
+) {
+    // An absent section is not a missing variant
+    if variants.is_empty() {
+        return;
+    }
+
+    for lang in required {
+        let present = variants.get(*lang).map(|v| !v.trim().is_empty()).unwrap_or(false);
+        if !present {
+            result.add_missing_variant(
+                source,
+                &format!("{}.{}", path, lang),
+                &format!("Missing '{}' language variant", lang),
+            );
+        }
+    }
 }
 
 #[cfg(test)]
@@ -493,6 +864,136 @@ mod tests {
         assert!(display.contains("References unknown slot"));
     }
 
+    fn tool_schema(name: &str) -> crate::domain::ToolSchema {
+        serde_yaml::from_str(&format!("name: {}\ndescription: test", name)).unwrap()
+    }
+
+    #[test]
+    fn test_unknown_completion_tool() {
+        let mut config = MasterDomainConfig::default();
+        config.tools.tools.insert("capture_lead".to_string(), tool_schema("capture_lead"));
+        config.goals.goals.insert(
+            "lead_capture".to_string(),
+            crate::domain::GoalEntry {
+                display_name: "Lead".to_string(),
+                completion_tool: Some("capture_lead".to_string()),
+                ..Default::default()
+            },
+        );
+        config.goals.goals.insert(
+            "savings".to_string(),
+            crate::domain::GoalEntry {
+                display_name: "Savings".to_string(),
+                completion_tool: Some("missing_tool".to_string()),
+                ..Default::default()
+            },
+        );
+
+        let result = ConfigValidator::new().validate("test", &config);
+        let paths: Vec<String> = result
+            .errors
+            .iter()
+            .filter(|e| e.category == ValidationCategory::InvalidReference)
+            .map(|e| e.path())
+            .collect();
+
+        assert!(paths.contains(&"goals.yaml/goals.savings.completion_tool".to_string()));
+        assert!(!paths.iter().any(|p| p.contains("lead_capture")));
+    }
+
+    #[test]
+    fn test_missing_prompt_language_variant() {
+        let mut config = MasterDomainConfig::default();
+        config.prompts.greetings.insert("en".to_string(), "Hello".to_string());
+        config.prompts.greetings.insert("hi".to_string(), "Namaste".to_string());
+        config.prompts.error_templates.insert(
+            "tool_error".to_string(),
+            [("en".to_string(), "Sorry".to_string())].into_iter().collect(),
+        );
+
+        let result = ConfigValidator::new().validate("test", &config);
+        let missing: Vec<String> = result
+            .errors
+            .iter()
+            .filter(|e| e.category == ValidationCategory::SchemaMismatch)
+            .map(|e| e.path())
+            .collect();
+
+        assert_eq!(missing, vec!["prompts/system.yaml/error_templates.tool_error.hi"]);
+        assert!(!result.is_strict_ok());
+    }
+
+    #[test]
+    fn test_required_languages_override() {
+        let mut config = MasterDomainConfig::default();
+        config.prompts.greetings.insert("en".to_string(), "Hello".to_string());
+
+        let result = ConfigValidator::new()
+            .with_required_languages(vec!["en".to_string(), "ta".to_string()])
+            .validate("test", &config);
+
+        assert!(result
+            .errors
+            .iter()
+            .any(|e| e.path() == "prompts/system.yaml/greetings.ta"));
+    }
+
+    #[test]
+    fn test_invalid_rate_card() {
+        let mut config = MasterDomainConfig::default();
+        let mut card = crate::domain::RateCard::from_constants(&config.constants);
+        card.version = "2026-07".to_string();
+        card.ltv_percent = 0.0;
+        config.rate_card.cards = vec![card.clone(), card];
+
+        let result = ConfigValidator::new().validate("test", &config);
+        let categories: Vec<ValidationCategory> = result
+            .errors
+            .iter()
+            .filter(|e| e.source == "rate_card.yaml")
+            .map(|e| e.category)
+            .collect();
+
+        assert!(categories.contains(&ValidationCategory::ValueOutOfRange));
+        assert!(categories.contains(&ValidationCategory::Duplicate));
+        assert!(!result.is_strict_ok());
+    }
+
+    #[test]
+    fn test_offer_with_unknown_lender() {
+        let mut config = MasterDomainConfig::default();
+        let offer: crate::domain::Offer = serde_yaml::from_str(
+            r#"
+id: switch
+name: Switch bonus
+pitch: Half a percent off when you move your loan to us
+valid_from: 2026-10-01
+eligibility:
+  balance_transfer_from: [no_such_lender]
+"#,
+        )
+        .unwrap();
+        config.offers.offers = vec![offer.clone(), offer];
+
+        let result = ConfigValidator::new().validate("test", &config);
+        let categories: Vec<ValidationCategory> = result
+            .errors
+            .iter()
+            .filter(|e| e.source == "offers.yaml")
+            .map(|e| e.category)
+            .collect();
+
+        assert!(categories.contains(&ValidationCategory::InvalidReference));
+        assert!(categories.contains(&ValidationCategory::Duplicate));
+        assert!(!result.is_strict_ok());
+    }
+
+    #[test]
+    fn test_validate_dir_missing_domain() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(validate_domain("nope", dir.path()).is_err());
+    }
+
     #[test]
     fn test_severity_ordering() {
         assert!(ValidationSeverity::Warning < ValidationSeverity::Error);
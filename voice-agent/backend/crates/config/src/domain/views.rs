@@ -3,13 +3,17 @@
 //! Each crate accesses domain configuration through a "view" that provides
 //! only the information that crate needs, in terminology appropriate for that crate.
 
+use chrono::{NaiveDate, Utc};
 use std::collections::HashMap;
 use std::sync::Arc;
+use voice_agent_core::LocationGazetteer;
 
-use super::branches::{BranchEntry, BranchesConfig};
+use super::branches::{AppointmentSlotConfig, BranchEntry, BranchesConfig};
 use super::competitors::{CompetitorEntry as ExtCompetitorEntry, CompetitorsConfig};
 use super::objections::{ObjectionResponse, ObjectionsConfig};
+use super::offers::{Offer, OfferCheck, OfferContext, OffersConfig};
 use super::prompts::PromptsConfig;
+use super::rate_card::RateCard;
 use super::scoring::{CategoryWeights, EscalationConfig, ScoringConfig};
 use super::segments::{SegmentDefinition, SegmentsConfig};
 use super::slots::{GoalDefinition, SlotDefinition, SlotsConfig};
@@ -20,6 +24,9 @@ use super::{
     MasterDomainConfig, MemoryCompressorConfig, CurrencyConfig,
 };
 
+/// Slot values with the names each is matched by
+type NamedValues<'a> = Vec<(&'a str, Vec<&'a str>)>;
+
 /// View for the agent crate
 /// Provides access to conversation stages, DST slots, scoring, objections
 pub struct AgentDomainView {
@@ -783,34 +790,74 @@ impl AgentDomainView {
 
     /// P2.1 FIX: Get location patterns for IntentDetector
     ///
-    /// Returns a combined regex pattern for all configured cities.
-    /// This replaces the hardcoded 9-city list with config-driven cities.
+    /// Returns a combined regex pattern for every city name and alias in the
+    /// location gazetteer (gazetteer.yaml, extraction patterns and branches).
     pub fn location_intent_pattern(&self) -> String {
         let city_names: Vec<String> = self
             .config
-            .extraction_patterns
             .locations
-            .cities
+            .cities()
             .iter()
-            .flat_map(|city| {
-                let mut names = vec![regex::escape(&city.name)];
-                names.extend(city.aliases.iter().map(|a| regex::escape(a)));
-                // Also include the pattern_en if it's a different simple name
-                if !city.pattern_en.is_empty() && !city.pattern_en.contains('|') {
-                    names.push(regex::escape(&city.pattern_en));
-                }
-                names
-            })
+            .flat_map(|city| city.names().map(regex::escape))
             .collect();
 
-        if city_names.is_empty() {
-            // Fallback to a minimal pattern if no cities configured
-            return r"(?i)\b(mumbai|delhi|bangalore)\b".to_string();
-        }
-
         format!(r"(?i)\b({})\b", city_names.join("|"))
     }
 
+    /// Cities, states and branch localities shared with slot extraction
+    pub fn location_gazetteer(&self) -> Arc<LocationGazetteer> {
+        Arc::clone(&self.config.locations)
+    }
+
+    /// Terms to boost in speech recognition: the domain vocabulary plus
+    /// city, state and locality names
+    pub fn stt_boost_terms(&self) -> Vec<String> {
+        let vocabulary = self.config.vocabulary_full.all_terms();
+        let locations = self.config.locations.boost_terms();
+        vocabulary
+            .into_iter()
+            .chain(locations)
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Names to fuzzy-match per slot, for IntentDetector::add_gazetteer()
+    ///
+    /// Returns (slot, [(value, names)]): competitors for `current_lender`,
+    /// cities for `location` and branch areas for `preferred_branch`.
+    pub fn gazetteer_entries(&self) -> Vec<(&str, NamedValues<'_>)> {
+        let lenders: NamedValues = self
+            .config
+            .competitors
+            .iter()
+            .map(|(id, entry)| {
+                let mut names = vec![id.as_str(), entry.display_name.as_str()];
+                names.extend(entry.aliases.iter().map(|s| s.as_str()));
+                (entry.display_name.as_str(), names)
+            })
+            .collect();
+        let cities: NamedValues = self
+            .config
+            .locations
+            .cities()
+            .iter()
+            .map(|city| (city.name.as_str(), city.names().collect()))
+            .collect();
+        let branches: NamedValues = self
+            .config
+            .branches
+            .branches
+            .iter()
+            .map(|branch| (branch.branch_id.as_str(), vec![branch.area.as_str()]))
+            .collect();
+
+        vec![
+            ("current_lender", lenders),
+            ("location", cities),
+            ("preferred_branch", branches),
+        ]
+    }
+
     // ====== P18 FIX: RAG Configuration (Domain-Agnostic) ======
 
     /// Get the RAG collection name for this domain.
@@ -969,6 +1016,11 @@ impl AgentDomainView {
         self.config.intents.min_confidence
     }
 
+    /// Annotated few-shot exchanges for prompts
+    pub fn examples_config(&self) -> &super::ExamplesConfig {
+        &self.config.examples
+    }
+
     // ====== P22 FIX: Full Vocabulary Configuration ======
 
     /// Get the full vocabulary configuration (ASR boost, phonetic corrections)
@@ -1242,13 +1294,98 @@ impl ToolsDomainView {
     }
 
     /// Get interest rate for eligibility calculations
+    /// (from the rate card in force today, else the constants tiers)
     pub fn get_rate_for_amount(&self, amount: f64) -> f64 {
-        self.config.get_rate_for_amount(amount)
+        self.configured_rate_card()
+            .and_then(|card| card.rate_for(amount, None))
+            .unwrap_or_else(|| self.config.get_rate_for_amount(amount))
     }
 
-    /// Get LTV percentage
+    /// Get LTV percentage (the rate card default when one is in force)
     pub fn ltv_percent(&self) -> f64 {
-        self.config.constants.ltv_percent
+        self.configured_rate_card()
+            .map(|card| card.ltv_percent)
+            .unwrap_or(self.config.constants.ltv_percent)
+    }
+
+    /// Rate cards from rate_card.yaml, past and upcoming
+    pub fn rate_cards(&self) -> &[RateCard] {
+        &self.config.rate_card.cards
+    }
+
+    /// The rate card in force on `date`, else the card implied by constants
+    pub fn rate_card_on(&self, date: NaiveDate) -> RateCard {
+        self.config
+            .rate_card
+            .effective_on(date)
+            .cloned()
+            .unwrap_or_else(|| RateCard::from_constants(&self.config.constants))
+    }
+
+    /// The configured rate card in force today, if any
+    fn configured_rate_card(&self) -> Option<&RateCard> {
+        self.config.rate_card.effective_on(Utc::now().date_naive())
+    }
+
+    /// Quality tier short code (e.g. "22K") for a tier id, display name,
+    /// short code or pattern
+    pub fn quality_tier_code(&self, variant: &str) -> Option<String> {
+        let wanted: String = variant.chars().filter(|c| !c.is_whitespace()).collect();
+        let same = |s: &str| {
+            s.chars()
+                .filter(|c| !c.is_whitespace())
+                .collect::<String>()
+                .eq_ignore_ascii_case(&wanted)
+        };
+        let values = self
+            .config
+            .slots
+            .get_slot("asset_quality_tier")?
+            .values
+            .as_ref()?;
+        values
+            .iter()
+            .find(|v| {
+                same(&v.id)
+                    || same(&v.display)
+                    || same(v.short_code())
+                    || v.patterns.iter().any(|p| same(p))
+            })
+            .map(|v| v.short_code().to_string())
+    }
+
+    // ====== Promotional Offers ======
+
+    /// Offers configuration (offers.yaml)
+    pub fn offers_config(&self) -> &OffersConfig {
+        &self.config.offers
+    }
+
+    /// Get an offer by ID
+    pub fn get_offer(&self, id: &str) -> Option<&Offer> {
+        self.config.offers.get(id)
+    }
+
+    /// Offers running on `date` that the customer is not ruled out of,
+    /// highest priority first
+    ///
+    /// The lender and city may be given as spoken (an alias or display
+    /// name); they are matched to competitor IDs and gazetteer city names.
+    pub fn offers_for(&self, context: &OfferContext, date: NaiveDate) -> Vec<(&Offer, OfferCheck)> {
+        let mut context = context.clone();
+        context.current_lender = context.current_lender.map(|lender| {
+            self.find_competitor_by_name(&lender)
+                .map(|(id, _)| id.to_string())
+                .unwrap_or(lender)
+        });
+        context.city = context.city.map(|city| {
+            self.config
+                .locations
+                .city(&city)
+                .map(|c| c.name.clone())
+                .unwrap_or(city)
+        });
+        self.config.offers.evaluate(&context, date)
     }
 
     /// Get variant/purity factor (e.g., K24=1.0, K22=0.916 for gold)
@@ -1283,9 +1420,11 @@ impl ToolsDomainView {
         self.config.constants.loan_limits.max
     }
 
-    /// Get processing fee percentage
+    /// Get processing fee percentage (the rate card's, when it sets one)
     pub fn processing_fee_percent(&self) -> f64 {
-        self.config.constants.processing_fee_percent
+        self.configured_rate_card()
+            .and_then(|card| card.processing_fee_percent)
+            .unwrap_or(self.config.constants.processing_fee_percent)
     }
 
     /// Get competitor info for savings calculations
@@ -1350,6 +1489,26 @@ impl ToolsDomainView {
         self.config.branches.defaults.max_results
     }
 
+    /// Find service branches matching a free-text place (area, city, ID)
+    pub fn find_branches_near(&self, location: &str) -> Vec<&BranchEntry> {
+        self.config.branches.find_near(location)
+    }
+
+    /// Cities, states and branch localities for resolving the caller's place
+    pub fn location_gazetteer(&self) -> Arc<LocationGazetteer> {
+        Arc::clone(&self.config.locations)
+    }
+
+    /// Branch visit slot capacity and hold settings
+    pub fn appointment_slots(&self) -> &AppointmentSlotConfig {
+        &self.config.branches.appointments
+    }
+
+    /// Visits a branch can take per time slot
+    pub fn branch_slot_capacity(&self, branch_id: &str) -> u32 {
+        self.config.branches.slot_capacity(branch_id)
+    }
+
     // ====== SMS Templates Configuration ======
 
     /// Get the full SMS templates configuration
@@ -1414,6 +1573,17 @@ impl ToolsDomainView {
         self.config.competitors_config.default_rate_for_type(competitor_type)
     }
 
+    /// Age in days after which stored competitor rates are flagged as outdated
+    pub fn competitor_rate_max_age_days(&self) -> u32 {
+        self.config.competitors_config.defaults.rate_max_age_days
+    }
+
+    /// Product key for stored competitor rates (None = any product)
+    pub fn competitor_rate_product(&self) -> Option<&str> {
+        let defaults = &self.config.competitors_config.defaults;
+        (!defaults.rate_product.is_empty()).then_some(defaults.rate_product.as_str())
+    }
+
     /// Get highlighted comparison points
     pub fn highlighted_comparison_points(&self) -> Vec<(&str, &str)> {
         self.config.competitors_config.highlighted_points()
@@ -1515,6 +1685,31 @@ impl ToolsDomainView {
         max_from_ltv.min(self.max_loan_amount())
     }
 
+    /// LTV cap for top-ups on an existing pledge (falls back to `ltv_percent`)
+    pub fn top_up_ltv_percent(&self) -> f64 {
+        let ltv = self.config.constants.top_up.max_ltv_percent;
+        if ltv > 0.0 {
+            ltv
+        } else {
+            self.ltv_percent()
+        }
+    }
+
+    /// Smallest top-up disbursed (falls back to the minimum loan amount)
+    pub fn top_up_min_amount(&self) -> f64 {
+        let min = self.config.constants.top_up.min_amount;
+        if min > 0.0 {
+            min
+        } else {
+            self.min_loan_amount()
+        }
+    }
+
+    /// Renewal tenures offered to existing customers, in months
+    pub fn renewal_tenures_months(&self) -> &[u32] {
+        &self.config.constants.top_up.renewal_tenures_months
+    }
+
     /// Get competitor rate by name (convenience method)
     /// Falls back to default NBFC rate if competitor not found
     pub fn get_competitor_rate(&self, lender: &str) -> f64 {
@@ -1526,6 +1721,25 @@ impl ToolsDomainView {
             .unwrap_or_else(|| self.default_competitor_rate("nbfc"))
     }
 
+    /// Foreclosure charge (% of outstanding) at a lender, else the configured default
+    pub fn competitor_prepayment_penalty(&self, lender: &str) -> f64 {
+        let competitors = &self.config.competitors_config;
+        competitors
+            .find_by_name(lender)
+            .and_then(|(_, entry)| entry.prepayment_penalty_percent)
+            .unwrap_or(competitors.defaults.prepayment_penalty_percent)
+    }
+
+    /// LTV offered by a lender, if known
+    pub fn competitor_ltv_percent(&self, lender: &str) -> Option<f64> {
+        self.config
+            .competitors_config
+            .find_by_name(lender)
+            .map(|(_, entry)| entry.ltv_percent)
+            .or_else(|| self.config.get_competitor(lender).map(|c| c.ltv_percent))
+            .filter(|ltv| *ltv > 0.0)
+    }
+
     /// Calculate monthly savings when switching from competitor
     /// Uses the competitor's rate and our tiered rate
     pub fn calculate_monthly_savings(&self, loan_amount: f64, current_rate: f64) -> f64 {
@@ -1544,6 +1758,13 @@ impl ToolsDomainView {
     /// Returns the tier name (e.g., "Standard", "Premium", "Elite")
     /// If tier has no name, derives from tier index
     pub fn get_rate_tier_name(&self, amount: f64) -> &str {
+        if let Some(slab) = self
+            .configured_rate_card()
+            .and_then(|card| card.slab_for(amount, None))
+            .filter(|slab| !slab.name.is_empty())
+        {
+            return &slab.name;
+        }
         for (idx, tier) in self.config.constants.interest_rates.tiers.iter().enumerate() {
             let threshold = tier.max_amount.unwrap_or(f64::MAX);
             if amount <= threshold {
@@ -2001,4 +2222,33 @@ mod tests {
         assert_eq!(format_amount(10000000.0), "1.0 Cr");
         assert_eq!(format_amount(25000000.0), "2.5 Cr");
     }
+
+    #[test]
+    fn test_rate_card_overrides_constants() {
+        let mut config = MasterDomainConfig::default();
+        config.constants.ltv_percent = 75.0;
+        config.constants.processing_fee_percent = 1.0;
+        let view = ToolsDomainView::new(Arc::new(config.clone()));
+        assert_eq!(
+            view.rate_card_on(Utc::now().date_naive()).version,
+            "constants"
+        );
+        assert_eq!(view.ltv_percent(), 75.0);
+
+        let mut card = RateCard::from_constants(&config.constants);
+        card.version = "2026-01".to_string();
+        card.effective_from = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        card.ltv_percent = 72.0;
+        card.slabs[0].name = "Flat".to_string();
+        card.slabs[0].rate = 12.0;
+        config.rate_card.cards.push(card);
+        let view = ToolsDomainView::new(Arc::new(config));
+
+        assert_eq!(view.ltv_percent(), 72.0);
+        assert_eq!(view.get_rate_for_amount(50_000.0), 12.0);
+        assert_eq!(view.get_rate_tier_name(50_000.0), "Flat");
+        assert_eq!(view.processing_fee_percent(), 1.0);
+        let before = NaiveDate::from_ymd_opt(2025, 12, 31).unwrap();
+        assert_eq!(view.rate_card_on(before).ltv_percent, 75.0);
+    }
 }
@@ -0,0 +1,288 @@
+//! TTS Voice Configuration
+//!
+//! Voice registry and selection rules loaded from voices.yaml.
+//!
+//! Each voice maps an ID to the engine and model that synthesize it, plus the
+//! language it speaks and descriptive attributes (gender, style). Selection
+//! rules pick a voice for a persona and language, so "Priya" speaks with a
+//! Hindi female voice in Hindi and switches to a Tamil voice when the caller
+//! moves to Tamil.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Root voices configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct VoicesConfig {
+    /// Voice registry keyed by voice ID
+    #[serde(default)]
+    pub voices: HashMap<String, VoiceProfile>,
+
+    /// Per-persona selection rules keyed by persona name
+    #[serde(default)]
+    pub personas: HashMap<String, PersonaVoiceRule>,
+
+    /// Default voice per language code
+    #[serde(default)]
+    pub language_defaults: HashMap<String, String>,
+
+    /// Voice used when nothing else matches
+    #[serde(default)]
+    pub default_voice: Option<String>,
+}
+
+/// One registered voice
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoiceProfile {
+    /// TTS engine ("piper", "indicf5", "parler")
+    pub engine: String,
+    /// Model file or directory
+    #[serde(default)]
+    pub model_path: Option<PathBuf>,
+    /// Reference audio for voice cloning engines (IndicF5)
+    #[serde(default)]
+    pub reference_audio: Option<PathBuf>,
+    /// Language code the voice speaks ("hi", "ta", "en")
+    pub language: String,
+    /// Voice gender ("female", "male", "neutral")
+    #[serde(default)]
+    pub gender: Option<String>,
+    /// Speaking style ("warm", "formal", "energetic")
+    #[serde(default)]
+    pub style: Option<String>,
+}
+
+/// Voice selection rule for a persona
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PersonaVoiceRule {
+    /// Explicit voice per language code
+    #[serde(default)]
+    pub voices: HashMap<String, String>,
+    /// Preferred gender for languages without an explicit voice
+    #[serde(default)]
+    pub gender: Option<String>,
+    /// Preferred style for languages without an explicit voice
+    #[serde(default)]
+    pub style: Option<String>,
+}
+
+impl VoicesConfig {
+    /// Load from a YAML file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, VoicesConfigError> {
+        let content = std::fs::read_to_string(path.as_ref()).map_err(|e| {
+            VoicesConfigError::FileNotFound(path.as_ref().display().to_string(), e.to_string())
+        })?;
+
+        serde_yaml::from_str(&content).map_err(|e| VoicesConfigError::ParseError(e.to_string()))
+    }
+
+    /// Look up a registered voice
+    pub fn voice(&self, id: &str) -> Option<&VoiceProfile> {
+        self.voices.get(id)
+    }
+
+    /// Pick the voice for a persona speaking a language
+    ///
+    /// In order: the session override (if registered and it speaks the
+    /// language), the persona's explicit voice, a voice matching the persona's
+    /// gender/style, the language default, any voice for the language, then
+    /// `default_voice`. An override in another language is skipped so a
+    /// language switch never leaves a voice reading a script it cannot speak.
+    pub fn select<'a>(
+        &'a self,
+        persona: &str,
+        language: &str,
+        override_voice: Option<&'a str>,
+    ) -> Option<&'a str> {
+        let language = base_language(language);
+
+        if let Some(id) = override_voice {
+            match self.voices.get_key_value(id) {
+                Some((id, voice)) if voice.speaks(language) => return Some(id.as_str()),
+                Some(_) => {},
+                None if self.voices.is_empty() => return Some(id),
+                None => tracing::warn!(voice = id, "Unknown TTS voice override, ignoring"),
+            }
+        }
+
+        let rule = self.personas.get(persona);
+        if let Some(id) = rule.and_then(|r| r.voices.get(language)) {
+            return Some(id.as_str());
+        }
+
+        let mut candidates: Vec<(&String, &VoiceProfile)> = self
+            .voices
+            .iter()
+            .filter(|(_, voice)| voice.speaks(language))
+            .collect();
+        candidates.sort_by(|a, b| a.0.cmp(b.0));
+
+        if let Some(rule) = rule {
+            let preferred = candidates.iter().find(|(_, voice)| {
+                matches_preference(voice.gender.as_deref(), rule.gender.as_deref())
+                    && matches_preference(voice.style.as_deref(), rule.style.as_deref())
+            });
+            if let Some((id, _)) = preferred {
+                return Some(id.as_str());
+            }
+        }
+
+        self.language_defaults
+            .get(language)
+            .or_else(|| candidates.first().map(|(id, _)| *id))
+            .or(self.default_voice.as_ref())
+            .map(String::as_str)
+    }
+
+    /// Check that every rule references a registered voice
+    pub fn validate(&self) -> Result<(), VoicesConfigError> {
+        let referenced = self
+            .personas
+            .values()
+            .flat_map(|rule| rule.voices.values())
+            .chain(self.language_defaults.values())
+            .chain(self.default_voice.iter());
+
+        for id in referenced {
+            if !self.voices.contains_key(id) {
+                return Err(VoicesConfigError::UnknownVoice(id.clone()));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl VoiceProfile {
+    /// Whether the voice speaks a language ("hi" matches "hi-IN")
+    pub fn speaks(&self, language: &str) -> bool {
+        base_language(&self.language).eq_ignore_ascii_case(base_language(language))
+    }
+}
+
+/// "hi-IN" -> "hi"
+fn base_language(code: &str) -> &str {
+    code.split(['-', '_']).next().unwrap_or(code)
+}
+
+/// A missing preference matches anything
+fn matches_preference(value: Option<&str>, preferred: Option<&str>) -> bool {
+    match preferred {
+        Some(preferred) => value.is_some_and(|v| v.eq_ignore_ascii_case(preferred)),
+        None => true,
+    }
+}
+
+/// Errors when loading voices configuration
+#[derive(Debug)]
+pub enum VoicesConfigError {
+    FileNotFound(String, String),
+    ParseError(String),
+    UnknownVoice(String),
+}
+
+impl std::fmt::Display for VoicesConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FileNotFound(path, err) => {
+                write!(f, "Voices config not found at {}: {}", path, err)
+            },
+            Self::ParseError(err) => write!(f, "Failed to parse voices config: {}", err),
+            Self::UnknownVoice(id) => write!(f, "Voice '{}' is not in the voice registry", id),
+        }
+    }
+}
+
+impl std::error::Error for VoicesConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> VoicesConfig {
+        serde_yaml::from_str(
+            r#"
+voices:
+  hi-female-priya:
+    engine: indicf5
+    language: hi
+    gender: female
+    style: warm
+  hi-male-1:
+    engine: indicf5
+    language: hi
+    gender: male
+  ta-female-1:
+    engine: indicf5
+    language: ta
+    gender: female
+  ta-male-1:
+    engine: indicf5
+    language: ta
+    gender: male
+  en-female-1:
+    engine: piper
+    language: en-IN
+    gender: female
+personas:
+  Priya:
+    voices:
+      hi: hi-female-priya
+    gender: female
+language_defaults:
+  ta: ta-male-1
+default_voice: en-female-1
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_persona_selection() {
+        let config = config();
+        assert!(config.validate().is_ok());
+
+        assert_eq!(config.select("Priya", "hi", None), Some("hi-female-priya"));
+        // No explicit Tamil voice: gender preference beats the language default
+        assert_eq!(config.select("Priya", "ta-IN", None), Some("ta-female-1"));
+        // Unknown persona uses the language default
+        assert_eq!(config.select("Arjun", "ta", None), Some("ta-male-1"));
+        assert_eq!(config.select("Arjun", "en", None), Some("en-female-1"));
+        assert_eq!(config.select("Arjun", "bn", None), Some("en-female-1"));
+    }
+
+    #[test]
+    fn test_override_selection() {
+        let config = config();
+        assert_eq!(
+            config.select("Priya", "hi", Some("hi-male-1")),
+            Some("hi-male-1")
+        );
+        // Override in another language or unknown voice is skipped
+        assert_eq!(
+            config.select("Priya", "ta", Some("hi-male-1")),
+            Some("ta-female-1")
+        );
+        assert_eq!(
+            config.select("Priya", "hi", Some("missing")),
+            Some("hi-female-priya")
+        );
+        // Without a registry the override is passed through
+        assert_eq!(
+            VoicesConfig::default().select("Priya", "hi", Some("x")),
+            Some("x")
+        );
+    }
+
+    #[test]
+    fn test_validate_unknown_voice() {
+        let mut config = config();
+        config
+            .language_defaults
+            .insert("mr".into(), "mr-female-1".into());
+        assert!(matches!(
+            config.validate(),
+            Err(VoicesConfigError::UnknownVoice(id)) if id == "mr-female-1"
+        ));
+    }
+}
@@ -29,32 +29,49 @@ pub mod pipeline;
 pub mod settings;
 
 pub use agent::{AgentConfig, MemoryConfig, PersonaConfig};
-pub use pipeline::PipelineConfig;
+pub use pipeline::{
+    AudioConfig, DenoiserBackend, DenoiserConfig, EndOfTurnPolicy, ModelPrecision,
+    ModelWorkersConfig, PipelineConfig, TtsCacheConfig, WakePhraseConfig, WakeWordConfig,
+    WorkerPoolConfig,
+};
 pub use settings::{
-    load_settings, AuthConfig, PersistenceConfig, RagConfig, RateLimitConfig, RuntimeEnvironment,
-    ServerConfig, Settings, TurnServerConfig,
+    load_settings, AbuseHandlingConfig, AdmissionConfig, AnalyticsConfig, ArchivalBackendKind, ArchivalStoreConfig, AuthConfig, BudgetConfig, CodeSwitchConfig, CodeSwitchLevel, CrmConfig,
+    CrmConnectorKind, DegradationConfig, DeliveryGuarantee, DialerConfig, DispositionCode, DispositionConfig, DispositionRule, DraftDecodingConfig, EventEncoding, EventSinkConfig, EventSinkKind, GuardrailAction, GuardrailsConfig, HandoffConfig, HandoffQueueKind, KnowledgeConfig, LlmBackendEntry, LlmRouterConfig, ModelRegistryConfig, OutboxConfig, PersistenceBackend, PersistenceConfig, PriceAlertConfig, PipelineComponent, RagConfig, RateLimitConfig,
+    ResponseCacheConfig, ResponseLengthConfig, RuntimeEnvironment,
+    ScyllaConsistency, ScyllaPoolConfig, ServerConfig, SessionBudget, Settings, SmsConfirmationConfig, SpeculativeRetryConfig, SqlPersistenceConfig, TenantBudget, ToolExecutionConfig, ToolPolicyConfig, ToolResultMatch, TurnServerConfig, UsageConfig,
+    WebhookConfig, WebhookEndpoint, WEBHOOK_EVENTS,
 };
 
 // P13 FIX: Domain configuration via MasterDomainConfig + views
 pub use domain::{
     MasterDomainConfig,
     // Sub-config types
-    BranchDefaults, BranchEntry, BranchesConfig,
+    AppointmentSlotConfig, BranchDefaults, BranchEntry, BranchesConfig,
     ComparisonPoint, CompetitorDefaults, CompetitorEntry,
     CompetitorsConfig, NumericThreshold, ObjectionDefinition, ObjectionResponse, ObjectionsConfig,
     PromptsConfig, QualificationThresholds, ScoringConfig, SegmentDefinition, SegmentDetection,
     SegmentsConfig, SlotDefinition, SlotsConfig, SmsTemplatesConfig, StageDefinition, StagesConfig,
-    ToolParameter, ToolSchema, ToolsConfig,
+    HttpToolConfig, ToolParameter, ToolSchema, ToolsConfig,
     // Goals and action templates (domain-agnostic action instructions)
     ActionContext, ActionTemplate, ActionTemplatesConfig, GoalEntry, GoalsConfig,
     // View types
     AgentDomainView, CompetitorInfo, LlmDomainView, MonthlySavings, ToolsDomainView,
+    // Per-session runtime overrides layered over the domain views
+    SessionDomainView, SessionOverrides, SessionOverridesError,
     // P21 FIX: Domain bridge for trait-based factory methods
     DomainBridge,
     // P21 FIX: Extraction patterns for domain-agnostic slot extraction
-    ExtractionPatternsConfig,
+    ExtractionPatternsConfig, LanguagePackConfig,
+    // TTS voice registry and selection rules
+    VoiceProfile, VoicesConfig,
+    // Pronunciation overrides for G2P and TTS
+    LexiconConfig, LexiconEntry,
+    // Versioned LTV and interest rate cards
+    effective_rate_card, RateCard, RateCardConfig, RateCardConfigError, RateSlab,
+    // Promotional offers and campaign eligibility
+    Offer, OfferCheck, OfferContext, OfferEligibility, OffersConfig, OffersConfigError,
     // P23 FIX: Config validator for startup validation
-    ConfigValidator, ValidationResult, ValidationSeverity,
+    validate_domain, ConfigValidator, ValidationError, ValidationResult, ValidationSeverity,
 };
 
 use thiserror::Error;
@@ -32,6 +32,14 @@ pub struct PipelineConfig {
     /// Audio configuration
     #[serde(default)]
     pub audio: AudioConfig,
+
+    /// Shared STT/TTS worker pools
+    #[serde(default)]
+    pub workers: ModelWorkersConfig,
+
+    /// Wake word gate for always-listening kiosks
+    #[serde(default)]
+    pub wake_word: WakeWordConfig,
 }
 
 fn default_latency_budget() -> u64 {
@@ -48,6 +56,174 @@ impl Default for PipelineConfig {
             tts: TtsConfig::default(),
             barge_in: BargeInConfig::default(),
             audio: AudioConfig::default(),
+            workers: ModelWorkersConfig::default(),
+            wake_word: WakeWordConfig::default(),
+        }
+    }
+}
+
+/// Shared model worker pools, one per engine
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelWorkersConfig {
+    /// Utterance transcription pool
+    #[serde(default = "default_stt_worker_pool")]
+    pub stt: WorkerPoolConfig,
+
+    /// Sentence synthesis pool
+    #[serde(default)]
+    pub tts: WorkerPoolConfig,
+}
+
+/// Model worker pool with cross-session batching
+///
+/// Sessions submit requests to one pool per engine instead of owning a
+/// locked model instance each. Requests of similar size from different
+/// sessions are batched together; sessions are served round-robin, earliest
+/// deadline first, so one long reply cannot starve another caller.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkerPoolConfig {
+    /// Route requests through the shared pool
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Batches run concurrently (model instances)
+    #[serde(default = "default_pool_workers")]
+    pub workers: usize,
+
+    /// Most requests per batch
+    #[serde(default = "default_pool_max_batch_size")]
+    pub max_batch_size: usize,
+
+    /// How long a partial batch waits for more requests (ms)
+    #[serde(default = "default_pool_max_batch_wait_ms")]
+    pub max_batch_wait_ms: u64,
+
+    /// Per-request latency target, queueing included (ms); requests closest
+    /// to missing it are scheduled first
+    #[serde(default = "default_pool_latency_slo_ms")]
+    pub latency_slo_ms: u64,
+
+    /// Requests one session may have queued before submissions are refused
+    #[serde(default = "default_pool_max_queue_per_session")]
+    pub max_queue_per_session: usize,
+}
+
+fn default_pool_workers() -> usize {
+    1
+}
+fn default_pool_max_batch_size() -> usize {
+    4
+}
+fn default_pool_max_batch_wait_ms() -> u64 {
+    10
+}
+fn default_pool_latency_slo_ms() -> u64 {
+    400
+}
+fn default_pool_max_queue_per_session() -> usize {
+    16
+}
+fn default_stt_worker_pool() -> WorkerPoolConfig {
+    WorkerPoolConfig {
+        workers: 2,
+        latency_slo_ms: 300,
+        ..Default::default()
+    }
+}
+
+impl Default for ModelWorkersConfig {
+    fn default() -> Self {
+        Self {
+            stt: default_stt_worker_pool(),
+            tts: WorkerPoolConfig::default(),
+        }
+    }
+}
+
+impl Default for WorkerPoolConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            workers: default_pool_workers(),
+            max_batch_size: default_pool_max_batch_size(),
+            max_batch_wait_ms: default_pool_max_batch_wait_ms(),
+            latency_slo_ms: default_pool_latency_slo_ms(),
+            max_queue_per_session: default_pool_max_queue_per_session(),
+        }
+    }
+}
+
+/// Wake word configuration
+///
+/// Kiosk devices listen all the time but only start a session once a
+/// customer says an activation phrase ("Hello Kotak"). Each phrase has its
+/// own small keyword-spotting model, scored on a sliding window of audio.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WakeWordConfig {
+    /// Gate kiosk audio on the wake word
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Activation phrases and their models
+    #[serde(default)]
+    pub phrases: Vec<WakePhraseConfig>,
+
+    /// Audio scored per window (ms)
+    #[serde(default = "default_wake_window_ms")]
+    pub window_ms: u32,
+
+    /// Window advance between scores (ms)
+    #[serde(default = "default_wake_hop_ms")]
+    pub hop_ms: u32,
+
+    /// Windows in a row above threshold before the phrase counts as said
+    #[serde(default = "default_wake_min_consecutive")]
+    pub min_consecutive: usize,
+
+    /// Quiet period after a wake or a session end before waking again (ms)
+    #[serde(default = "default_wake_cooldown_ms")]
+    pub cooldown_ms: u64,
+}
+
+/// One activation phrase
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WakePhraseConfig {
+    /// Phrase as reported on wake
+    pub phrase: String,
+
+    /// Keyword spotter model (ONNX)
+    pub model_path: String,
+
+    /// Score above which a window matches (0.0 - 1.0)
+    #[serde(default = "default_wake_threshold")]
+    pub threshold: f32,
+}
+
+fn default_wake_window_ms() -> u32 {
+    1000
+}
+fn default_wake_hop_ms() -> u32 {
+    250
+}
+fn default_wake_min_consecutive() -> usize {
+    2
+}
+fn default_wake_cooldown_ms() -> u64 {
+    3000
+}
+fn default_wake_threshold() -> f32 {
+    0.5
+}
+
+impl Default for WakeWordConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            phrases: Vec::new(),
+            window_ms: default_wake_window_ms(),
+            hop_ms: default_wake_hop_ms(),
+            min_consecutive: default_wake_min_consecutive(),
+            cooldown_ms: default_wake_cooldown_ms(),
         }
     }
 }
@@ -138,6 +314,10 @@ pub struct TurnDetectionConfig {
     /// History turns to include in context
     #[serde(default = "default_history_turns")]
     pub history_turns: usize,
+
+    /// End-of-turn prediction policy
+    #[serde(default)]
+    pub end_of_turn: EndOfTurnPolicy,
 }
 
 fn default_min_semantic_silence() -> u64 {
@@ -165,6 +345,57 @@ impl Default for TurnDetectionConfig {
             end_token_threshold: default_end_token_threshold(),
             max_seq_len: default_max_seq_len(),
             history_turns: default_history_turns(),
+            end_of_turn: EndOfTurnPolicy::default(),
+        }
+    }
+}
+
+/// End-of-turn prediction policy
+///
+/// Lexical completeness and prosody decide how much silence to wait for:
+/// a finished question with falling pitch is answered after `min_silence_ms`,
+/// a trailing "aur..." waits up to `max_silence_ms`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndOfTurnPolicy {
+    /// Use the predictor instead of fixed silence thresholds
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Silence required when the turn is certainly complete (ms)
+    #[serde(default = "default_eot_min_silence")]
+    pub min_silence_ms: u64,
+
+    /// Silence after which the turn always ends (ms)
+    #[serde(default = "default_max_silence")]
+    pub max_silence_ms: u64,
+
+    /// Weight of lexical completeness from partial transcripts
+    #[serde(default = "default_lexical_weight")]
+    pub lexical_weight: f32,
+
+    /// Weight of prosody cues (pitch contour, energy decay)
+    #[serde(default = "default_prosody_weight")]
+    pub prosody_weight: f32,
+}
+
+fn default_eot_min_silence() -> u64 {
+    200
+}
+fn default_lexical_weight() -> f32 {
+    0.65
+}
+fn default_prosody_weight() -> f32 {
+    0.35
+}
+
+impl Default for EndOfTurnPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_silence_ms: default_eot_min_silence(),
+            max_silence_ms: default_max_silence(),
+            lexical_weight: default_lexical_weight(),
+            prosody_weight: default_prosody_weight(),
         }
     }
 }
@@ -269,6 +500,26 @@ pub struct TtsConfig {
     /// Maximum queue depth
     #[serde(default = "default_queue_depth")]
     pub max_queue_depth: usize,
+
+    /// Synthesized audio cache for repeated utterances
+    #[serde(default)]
+    pub cache: TtsCacheConfig,
+
+    /// Publish viseme and gesture cues for avatar clients
+    #[serde(default)]
+    pub avatar_cues: bool,
+
+    /// Silence between sentences of a response (ms)
+    #[serde(default)]
+    pub sentence_pause_ms: u32,
+
+    /// Sentences synthesized ahead of playback (0 = one at a time)
+    #[serde(default = "default_lookahead_sentences")]
+    pub lookahead_sentences: usize,
+
+    /// Weight precision of the candle TTS model (int8/int4 for CPU-only hosts)
+    #[serde(default)]
+    pub quantization: ModelPrecision,
 }
 
 fn default_voice() -> String {
@@ -289,6 +540,9 @@ fn default_crossfade() -> u32 {
 fn default_queue_depth() -> usize {
     5
 }
+fn default_lookahead_sentences() -> usize {
+    2
+}
 
 impl Default for TtsConfig {
     fn default() -> Self {
@@ -300,10 +554,71 @@ impl Default for TtsConfig {
             chunk_mode: default_chunk_mode(),
             crossfade_ms: default_crossfade(),
             max_queue_depth: default_queue_depth(),
+            cache: TtsCacheConfig::default(),
+            avatar_cues: false,
+            sentence_pause_ms: 0,
+            lookahead_sentences: default_lookahead_sentences(),
+            quantization: ModelPrecision::default(),
+        }
+    }
+}
+
+/// TTS synthesis cache configuration
+///
+/// Greetings, disclosures and slot prompts repeat across calls; caching their
+/// audio by (engine, voice, text) skips synthesis entirely on a hit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TtsCacheConfig {
+    /// Enable the cache
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Maximum utterances kept in memory
+    #[serde(default = "default_tts_cache_entries")]
+    pub max_entries: usize,
+
+    /// Memory budget for cached audio (MB)
+    #[serde(default = "default_tts_cache_memory_mb")]
+    pub max_memory_mb: usize,
+
+    /// Directory for the persistent disk layer (disabled when unset)
+    #[serde(default)]
+    pub disk_dir: Option<String>,
+}
+
+fn default_tts_cache_entries() -> usize {
+    512
+}
+fn default_tts_cache_memory_mb() -> usize {
+    64
+}
+
+impl Default for TtsCacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_entries: default_tts_cache_entries(),
+            max_memory_mb: default_tts_cache_memory_mb(),
+            disk_dir: None,
         }
     }
 }
 
+/// Weight precision for in-process (candle) models
+///
+/// `int8`/`int4` store the large linear layers in Q8_0/Q4_0 blocks and keep
+/// activations in F32, trading a little output quality for CPU latency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ModelPrecision {
+    #[default]
+    F32,
+    F16,
+    Bf16,
+    Int8,
+    Int4,
+}
+
 /// TTS chunking mode
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -417,14 +732,59 @@ pub struct AudioConfig {
 
     /// Enable noise suppression
     ///
-    /// P2 FIX: Currently a placeholder flag. Actual NS implementation requires
-    /// a signal processing library (e.g., rnnoise, webrtc-audio-processing).
-    /// When implemented, this will:
-    /// - Reduce background noise before STT
-    /// - Improve transcription accuracy in noisy environments
-    /// - Work with energy-based VAD for better speech detection
+    /// Runs the `denoiser` over inbound audio before VAD and STT. Worth the
+    /// added latency for branch and roadside callers; quiet deployments can
+    /// turn it off.
     #[serde(default = "default_true")]
     pub noise_suppression: bool,
+
+    /// Denoiser used when `noise_suppression` is on
+    #[serde(default)]
+    pub denoiser: DenoiserConfig,
+}
+
+/// Denoiser implementations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum DenoiserBackend {
+    /// RNNoise (nnnoiseless, `noise-suppression` feature)
+    #[default]
+    Rnnoise,
+    /// Small ONNX denoiser model (`onnx` feature)
+    Onnx,
+}
+
+/// Inbound audio denoiser
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DenoiserConfig {
+    /// Implementation to run
+    #[serde(default)]
+    pub backend: DenoiserBackend,
+
+    /// Model for the ONNX backend
+    #[serde(default = "default_denoiser_model_path")]
+    pub model_path: String,
+
+    /// Added latency per frame (ms) above which a warning is logged
+    #[serde(default = "default_denoiser_latency_budget")]
+    pub latency_budget_ms: f32,
+}
+
+fn default_denoiser_model_path() -> String {
+    "models/denoiser/denoiser.onnx".to_string()
+}
+fn default_denoiser_latency_budget() -> f32 {
+    5.0
+}
+
+impl Default for DenoiserConfig {
+    fn default() -> Self {
+        Self {
+            backend: DenoiserBackend::default(),
+            model_path: default_denoiser_model_path(),
+            latency_budget_ms: default_denoiser_latency_budget(),
+        }
+    }
 }
 
 fn default_input_sample_rate() -> u32 {
@@ -445,6 +805,7 @@ impl Default for AudioConfig {
             jitter_buffer_ms: default_jitter_buffer(),
             echo_cancellation: true,
             noise_suppression: true,
+            denoiser: DenoiserConfig::default(),
         }
     }
 }
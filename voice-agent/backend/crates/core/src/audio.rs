@@ -1,9 +1,10 @@
 //! Audio frame types and utilities
 
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+use crate::samples::Samples;
+
 /// Supported audio sample rates
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub enum SampleRate {
@@ -83,11 +84,12 @@ impl Channels {
 
 /// Audio frame with metadata
 ///
-/// Internally stores samples as f32 for processing efficiency.
+/// Internally stores samples as f32 for processing efficiency. Samples are
+/// shared, so cloning or splitting a frame does not copy audio.
 #[derive(Clone)]
 pub struct AudioFrame {
     /// Raw audio samples (f32, normalized to [-1.0, 1.0])
-    pub samples: Arc<[f32]>,
+    pub samples: Samples,
     /// Sample rate
     pub sample_rate: SampleRate,
     /// Number of channels
@@ -123,19 +125,22 @@ impl std::fmt::Debug for AudioFrame {
 
 impl AudioFrame {
     /// Create a new audio frame from f32 samples
+    ///
+    /// Takes a `Vec<f32>` (moved, not copied) or an existing [`Samples`].
     pub fn new(
-        samples: Vec<f32>,
+        samples: impl Into<Samples>,
         sample_rate: SampleRate,
         channels: Channels,
         sequence: u64,
     ) -> Self {
+        let samples = samples.into();
         let duration = Duration::from_secs_f64(
             samples.len() as f64 / (sample_rate.as_u32() as f64 * channels.count() as f64),
         );
         let energy_db = Self::calculate_energy_db(&samples);
 
         Self {
-            samples: samples.into(),
+            samples,
             sample_rate,
             channels,
             sequence,
@@ -149,7 +154,7 @@ impl AudioFrame {
 
     /// Create audio frame with explicit timestamp
     pub fn with_timestamp(
-        samples: Vec<f32>,
+        samples: impl Into<Samples>,
         sample_rate: SampleRate,
         channels: Channels,
         sequence: u64,
@@ -319,21 +324,23 @@ impl AudioFrame {
     }
 
     /// Split frame into smaller chunks
+    ///
+    /// Chunks are views into this frame's samples; no audio is copied.
     pub fn split(&self, chunk_samples: usize) -> Vec<AudioFrame> {
-        let mut chunks = Vec::new();
-        let mut seq = self.sequence;
-
-        for chunk in self.samples.chunks(chunk_samples) {
-            chunks.push(AudioFrame::new(
-                chunk.to_vec(),
-                self.sample_rate,
-                self.channels,
-                seq,
-            ));
-            seq += 1;
-        }
-
-        chunks
+        let chunk_samples = chunk_samples.max(1);
+        (0..self.samples.len())
+            .step_by(chunk_samples)
+            .enumerate()
+            .map(|(i, start)| {
+                let end = (start + chunk_samples).min(self.samples.len());
+                AudioFrame::new(
+                    self.samples.slice(start..end),
+                    self.sample_rate,
+                    self.channels,
+                    self.sequence + i as u64,
+                )
+            })
+            .collect()
     }
 }
 
@@ -460,6 +467,21 @@ mod tests {
         assert!(loud.energy_db > -10.0);
     }
 
+    #[test]
+    fn test_split_shares_samples() {
+        let samples: Vec<f32> = (0..500).map(|i| i as f32 / 500.0).collect();
+        let frame = AudioFrame::new(samples, SampleRate::Hz16000, Channels::Mono, 7);
+
+        let chunks = frame.split(160);
+        assert_eq!(chunks.len(), 4);
+        assert_eq!(chunks[3].samples.len(), 20);
+        assert_eq!(chunks[1].samples[0], frame.samples[160]);
+        assert_eq!(chunks[3].sequence, 10);
+        assert!(chunks
+            .iter()
+            .all(|chunk| chunk.samples.shares_buffer(&frame.samples)));
+    }
+
     #[test]
     fn test_audio_buffer() {
         let mut buffer =
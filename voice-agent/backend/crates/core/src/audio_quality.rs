@@ -0,0 +1,256 @@
+//! Line audio quality
+//!
+//! [`AudioQualityAnalyzer`] watches the caller's inbound audio and estimates
+//! how usable the line is:
+//! - SNR: a noise floor tracked from the quietest frames (falls at once,
+//!   rises slowly) against the average level of frames well above it
+//! - Clipping: share of samples at full scale
+//! - Packet loss: share of frames the jitter buffer had to conceal
+//!
+//! Each measure is averaged over roughly `window_frames` frames. A line
+//! past any limit reports a [`LineIssue`], which the pipeline uses to
+//! tighten speech detection and the agent to ask for repeats.
+
+use serde::{Deserialize, Serialize};
+
+use crate::jitter::JitterStats;
+
+/// Frames quieter than this are digital silence and say nothing of noise
+const SILENCE_DB: f32 = -90.0;
+
+/// Audio quality analyzer configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AudioQualityConfig {
+    /// Frames each measure is averaged over
+    pub window_frames: usize,
+    /// SNR below which the line counts as noisy (dB)
+    pub noisy_snr_db: f32,
+    /// Share of clipped samples above which the line counts as clipping
+    pub max_clipping: f32,
+    /// Share of concealed frames above which the line counts as lossy
+    pub max_packet_loss: f32,
+    /// How far above the noise floor a frame must be to count as speech (dB)
+    pub speech_margin_db: f32,
+    /// How fast the noise floor may rise (dB per frame)
+    pub floor_rise_db: f32,
+}
+
+impl Default for AudioQualityConfig {
+    fn default() -> Self {
+        Self {
+            // 2s at 20ms frames
+            window_frames: 100,
+            noisy_snr_db: 15.0,
+            max_clipping: 0.01,
+            max_packet_loss: 0.05,
+            speech_margin_db: 6.0,
+            // 1dB per second at 20ms frames
+            floor_rise_db: 0.02,
+        }
+    }
+}
+
+/// What makes a line hard to understand
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineIssue {
+    /// Background noise close to the caller's voice
+    Noise,
+    /// Audio dropping out on the network
+    PacketLoss,
+    /// Caller too loud for the microphone
+    Clipping,
+}
+
+impl LineIssue {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LineIssue::Noise => "noise",
+            LineIssue::PacketLoss => "packet_loss",
+            LineIssue::Clipping => "clipping",
+        }
+    }
+}
+
+impl std::fmt::Display for LineIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Snapshot of a line's audio quality
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AudioQuality {
+    /// Estimated SNR (dB), once speech has been heard
+    pub snr_db: Option<f32>,
+    /// Noise floor (dB)
+    pub noise_floor_db: Option<f32>,
+    /// Share of samples clipped
+    pub clipping: f32,
+    /// Share of frames lost
+    pub packet_loss: f32,
+    /// Worst problem on the line, if any
+    pub issue: Option<LineIssue>,
+}
+
+impl AudioQuality {
+    /// Whether the line is hard to understand
+    pub fn is_degraded(&self) -> bool {
+        self.issue.is_some()
+    }
+}
+
+/// Estimates the audio quality of one inbound line
+#[derive(Debug, Default)]
+pub struct AudioQualityAnalyzer {
+    config: AudioQualityConfig,
+    noise_floor_db: Option<f32>,
+    speech_db: Option<f32>,
+    clipping: f32,
+    packet_loss: f32,
+    /// Jitter buffer counts at the last look
+    received: u64,
+    concealed: u64,
+}
+
+impl AudioQualityAnalyzer {
+    /// Create an audio quality analyzer
+    pub fn new(config: AudioQualityConfig) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+
+    /// Weight of one frame in the running averages
+    fn alpha(&self) -> f32 {
+        1.0 / self.config.window_frames.max(1) as f32
+    }
+
+    /// Measure a frame of raw (not noise suppressed) audio
+    pub fn observe(&mut self, samples: &[f32]) {
+        if samples.is_empty() {
+            return;
+        }
+        let alpha = self.alpha();
+
+        let clipped = samples.iter().filter(|s| s.abs() >= 0.99).count();
+        let clipping = clipped as f32 / samples.len() as f32;
+        self.clipping += (clipping - self.clipping) * alpha;
+
+        let energy = samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32;
+        let energy_db = 10.0 * energy.max(1e-10).log10();
+        if energy_db <= SILENCE_DB {
+            return;
+        }
+        let floor = match self.noise_floor_db {
+            Some(floor) => energy_db.min(floor + self.config.floor_rise_db),
+            None => energy_db,
+        };
+        self.noise_floor_db = Some(floor);
+        if energy_db >= floor + self.config.speech_margin_db {
+            let speech = self.speech_db.unwrap_or(energy_db);
+            self.speech_db = Some(speech + (energy_db - speech) * alpha);
+        }
+    }
+
+    /// Measure loss from a jitter buffer's running counts
+    pub fn observe_jitter(&mut self, stats: &JitterStats) {
+        let received = stats.received.saturating_sub(self.received);
+        let concealed = stats.concealed.saturating_sub(self.concealed);
+        self.received = stats.received;
+        self.concealed = stats.concealed;
+        let frames = received + concealed;
+        if frames == 0 {
+            return;
+        }
+        let loss = concealed as f32 / frames as f32;
+        let weight = (frames as f32 * self.alpha()).min(1.0);
+        self.packet_loss += (loss - self.packet_loss) * weight;
+    }
+
+    /// Current quality of the line
+    pub fn report(&self) -> AudioQuality {
+        let snr_db = self
+            .speech_db
+            .zip(self.noise_floor_db)
+            .map(|(speech, floor)| speech - floor);
+        let issue = if snr_db.is_some_and(|snr| snr < self.config.noisy_snr_db) {
+            Some(LineIssue::Noise)
+        } else if self.packet_loss > self.config.max_packet_loss {
+            Some(LineIssue::PacketLoss)
+        } else if self.clipping > self.config.max_clipping {
+            Some(LineIssue::Clipping)
+        } else {
+            None
+        };
+        AudioQuality {
+            snr_db,
+            noise_floor_db: self.noise_floor_db,
+            clipping: self.clipping,
+            packet_loss: self.packet_loss,
+            issue,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 20ms of a tone at `level`, over noise at `noise`
+    fn frame(level: f32, noise: f32) -> Vec<f32> {
+        (0..320)
+            .map(|i| {
+                let tone = level * (i as f32 * 0.3).sin();
+                let hiss = noise * if i % 2 == 0 { 1.0 } else { -1.0 };
+                tone + hiss
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_snr_separates_clean_and_noisy_lines() {
+        let mut clean = AudioQualityAnalyzer::new(AudioQualityConfig::default());
+        let mut noisy = AudioQualityAnalyzer::new(AudioQualityConfig::default());
+        assert_eq!(clean.report().snr_db, None);
+
+        for i in 0..300 {
+            let speaking = (i / 25) % 2 == 1;
+            let level = if speaking { 0.3 } else { 0.0 };
+            clean.observe(&frame(level, 0.003));
+            noisy.observe(&frame(level, 0.08));
+        }
+
+        let report = clean.report();
+        assert!(report.snr_db.unwrap() > 30.0);
+        assert_eq!(report.issue, None);
+        let report = noisy.report();
+        assert!(report.snr_db.unwrap() < 15.0);
+        assert_eq!(report.issue, Some(LineIssue::Noise));
+    }
+
+    #[test]
+    fn test_clipping_and_packet_loss() {
+        let mut analyzer = AudioQualityAnalyzer::new(AudioQualityConfig::default());
+        for _ in 0..100 {
+            analyzer.observe(&vec![1.0; 320]);
+        }
+        assert_eq!(analyzer.report().issue, Some(LineIssue::Clipping));
+
+        let mut stats = JitterStats {
+            received: 90,
+            concealed: 10,
+            ..Default::default()
+        };
+        analyzer.observe_jitter(&stats);
+        let report = analyzer.report();
+        assert!((report.packet_loss - 0.1).abs() < 1e-6);
+        assert_eq!(report.issue, Some(LineIssue::PacketLoss));
+
+        // Loss stops: counted from the new frames only
+        stats.received += 200;
+        analyzer.observe_jitter(&stats);
+        assert_eq!(analyzer.report().packet_loss, 0.0);
+    }
+}
@@ -0,0 +1,84 @@
+//! Avatar cues
+//!
+//! Timing events for a lip-synced web avatar, published alongside the
+//! agent's audio. Times are offsets (ms) from the start of the spoken
+//! response, so the client schedules them against its own playback clock.
+
+use serde::{Deserialize, Serialize};
+
+/// Mouth shape (the common 15-viseme set)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Viseme {
+    /// Mouth closed, at rest
+    Sil,
+    /// p, b, m
+    Pp,
+    /// f, v
+    Ff,
+    /// th
+    Th,
+    /// t, d (dental and retroflex)
+    Dd,
+    /// k, g, h
+    Kk,
+    /// ch, j, sh
+    Ch,
+    /// s, z
+    Ss,
+    /// n, l
+    Nn,
+    /// r
+    Rr,
+    /// a
+    Aa,
+    /// e
+    E,
+    /// i, y
+    I,
+    /// o
+    O,
+    /// u
+    U,
+}
+
+/// Mouth shape held for a span of the response
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VisemeCue {
+    pub viseme: Viseme,
+    pub start_ms: u64,
+    pub duration_ms: u64,
+}
+
+/// Body language for a moment in the response
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Gesture {
+    /// Greeting the customer
+    Greet,
+    /// Presenting an amount, rate or other figure
+    PresentNumber,
+}
+
+/// Gesture starting at a word of the response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GestureCue {
+    pub gesture: Gesture,
+    pub at_ms: u64,
+    /// Word that triggered it
+    pub word: String,
+}
+
+/// Cues for one synthesized chunk of the response
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AvatarCues {
+    pub visemes: Vec<VisemeCue>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub gestures: Vec<GestureCue>,
+}
+
+impl AvatarCues {
+    pub fn is_empty(&self) -> bool {
+        self.visemes.is_empty() && self.gestures.is_empty()
+    }
+}
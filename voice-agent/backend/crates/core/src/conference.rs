@@ -0,0 +1,235 @@
+//! Conference calls
+//!
+//! Gold loans are often taken jointly, with the spouse on the call as
+//! co-applicant. In a conference session each party speaks on their own
+//! inbound channel:
+//! - [`ConferenceMixer`] mixes the two channels frame by frame into the one
+//!   stream the pipeline transcribes, and marks the party speaking in each
+//!   frame (one channel clearly louder than the other)
+//! - [`SpeakerTally`] counts those marks over an utterance, so its transcript
+//!   is attributed to whoever spoke most of it
+//!
+//! A channel that falls behind by more than `max_lag_frames` (muted or
+//! dropped) is mixed as silence, so one party never stalls the other.
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Party on a call
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Speaker {
+    /// The applicant the call is with
+    #[default]
+    Customer,
+    /// A second applicant joining the call (usually the spouse)
+    CoApplicant,
+}
+
+impl Speaker {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Speaker::Customer => "customer",
+            Speaker::CoApplicant => "co_applicant",
+        }
+    }
+}
+
+impl std::fmt::Display for Speaker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Conference mixer configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConferenceMixerConfig {
+    /// RMS level above which a channel counts as speech
+    pub speech_rms: f32,
+    /// How many times louder one channel must be to mark its speaker
+    pub dominance: f32,
+    /// Frames one channel may run ahead before the other is mixed as silence
+    pub max_lag_frames: usize,
+}
+
+impl Default for ConferenceMixerConfig {
+    fn default() -> Self {
+        Self {
+            speech_rms: 0.02,
+            dominance: 2.0,
+            // 100ms at 20ms frames
+            max_lag_frames: 5,
+        }
+    }
+}
+
+/// Frame released by the conference mixer
+#[derive(Debug, Clone, PartialEq)]
+pub struct MixedFrame {
+    /// Sum of both channels, clipped to [-1, 1]
+    pub samples: Vec<f32>,
+    /// Party clearly speaking in this frame, if any
+    pub speaker: Option<Speaker>,
+}
+
+/// Mixes the customer and co-applicant channels of a conference session
+#[derive(Debug, Default)]
+pub struct ConferenceMixer {
+    config: ConferenceMixerConfig,
+    customer: VecDeque<Vec<f32>>,
+    co_applicant: VecDeque<Vec<f32>>,
+}
+
+impl ConferenceMixer {
+    /// Create a conference mixer
+    pub fn new(config: ConferenceMixerConfig) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+
+    /// Add a frame received on `speaker`'s channel
+    pub fn push(&mut self, speaker: Speaker, samples: Vec<f32>) {
+        match speaker {
+            Speaker::Customer => self.customer.push_back(samples),
+            Speaker::CoApplicant => self.co_applicant.push_back(samples),
+        }
+    }
+
+    /// Release every frame that can be mixed
+    ///
+    /// Frames are paired in arrival order; a channel more than
+    /// `max_lag_frames` ahead is mixed with silence for the other.
+    pub fn drain(&mut self) -> Vec<MixedFrame> {
+        let mut mixed = Vec::new();
+        loop {
+            let ahead = self.customer.len().abs_diff(self.co_applicant.len());
+            let both = !self.customer.is_empty() && !self.co_applicant.is_empty();
+            if !both && ahead <= self.config.max_lag_frames {
+                break;
+            }
+            let customer = self.customer.pop_front().unwrap_or_default();
+            let co_applicant = self.co_applicant.pop_front().unwrap_or_default();
+            mixed.push(self.mix(&customer, &co_applicant));
+        }
+        mixed
+    }
+
+    /// Frames held waiting for the other channel
+    pub fn depth(&self) -> usize {
+        self.customer.len().max(self.co_applicant.len())
+    }
+
+    fn mix(&self, customer: &[f32], co_applicant: &[f32]) -> MixedFrame {
+        let len = customer.len().max(co_applicant.len());
+        let at = |channel: &[f32], i: usize| channel.get(i).copied().unwrap_or(0.0);
+        let samples = (0..len)
+            .map(|i| (at(customer, i) + at(co_applicant, i)).clamp(-1.0, 1.0))
+            .collect();
+
+        let (customer_rms, co_applicant_rms) = (rms(customer), rms(co_applicant));
+        let speaks = |rms: f32, other: f32| {
+            rms >= self.config.speech_rms && rms >= other * self.config.dominance
+        };
+        let speaker = if speaks(customer_rms, co_applicant_rms) {
+            Some(Speaker::Customer)
+        } else if speaks(co_applicant_rms, customer_rms) {
+            Some(Speaker::CoApplicant)
+        } else {
+            None
+        };
+        MixedFrame { samples, speaker }
+    }
+}
+
+fn rms(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let energy: f32 = samples.iter().map(|s| s * s).sum();
+    (energy / samples.len() as f32).sqrt()
+}
+
+/// Counts the frames each party spoke over an utterance
+#[derive(Debug, Clone, Default)]
+pub struct SpeakerTally {
+    customer: usize,
+    co_applicant: usize,
+}
+
+impl SpeakerTally {
+    /// Count a mixed frame's speaker
+    pub fn record(&mut self, speaker: Option<Speaker>) {
+        match speaker {
+            Some(Speaker::Customer) => self.customer += 1,
+            Some(Speaker::CoApplicant) => self.co_applicant += 1,
+            None => {},
+        }
+    }
+
+    /// Party that spoke most since the last call, the customer on a tie
+    ///
+    /// Returns `None` when nobody clearly spoke, and starts a new count.
+    pub fn take(&mut self) -> Option<Speaker> {
+        let tally = std::mem::take(self);
+        match (tally.customer, tally.co_applicant) {
+            (0, 0) => None,
+            (customer, co_applicant) if co_applicant > customer => Some(Speaker::CoApplicant),
+            _ => Some(Speaker::Customer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mixes_and_marks_speaker() {
+        let mut mixer = ConferenceMixer::new(ConferenceMixerConfig::default());
+        mixer.push(Speaker::Customer, vec![0.5; 4]);
+        assert!(mixer.drain().is_empty());
+
+        mixer.push(Speaker::CoApplicant, vec![0.25; 4]);
+        let frames = mixer.drain();
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].samples, vec![0.75; 4]);
+        assert_eq!(frames[0].speaker, Some(Speaker::Customer));
+
+        // Both talking at once: nobody is marked
+        mixer.push(Speaker::Customer, vec![0.5; 4]);
+        mixer.push(Speaker::CoApplicant, vec![0.75; 4]);
+        let frames = mixer.drain();
+        assert_eq!(frames[0].samples, vec![1.0; 4]);
+        assert_eq!(frames[0].speaker, None);
+    }
+
+    #[test]
+    fn test_lagging_channel_mixed_as_silence() {
+        let config = ConferenceMixerConfig::default();
+        let max_lag = config.max_lag_frames;
+        let mut mixer = ConferenceMixer::new(config);
+        for _ in 0..=max_lag {
+            mixer.push(Speaker::CoApplicant, vec![0.3; 4]);
+        }
+
+        let frames = mixer.drain();
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].speaker, Some(Speaker::CoApplicant));
+        assert_eq!(mixer.depth(), max_lag);
+    }
+
+    #[test]
+    fn test_tally_attributes_utterance() {
+        let mut tally = SpeakerTally::default();
+        assert_eq!(tally.take(), None);
+
+        for speaker in [Some(Speaker::CoApplicant), None, Some(Speaker::CoApplicant)] {
+            tally.record(speaker);
+        }
+        tally.record(Some(Speaker::Customer));
+        assert_eq!(tally.take(), Some(Speaker::CoApplicant));
+        assert_eq!(tally.take(), None);
+    }
+}
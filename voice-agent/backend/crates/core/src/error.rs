@@ -250,3 +250,206 @@ impl From<&ToolError> for ErrorCode {
         }
     }
 }
+
+/// What kind of failure an error is, whichever crate it came from
+///
+/// Decides whether an operation is retried and what the caller hears when
+/// their turn fails (see `error_templates` in the domain's prompts, keyed by
+/// [`ErrorKind::as_str`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorKind {
+    /// A backend (tool service, LLM, database, model) failed or is unreachable
+    Unavailable,
+    /// A backend did not answer in time
+    Timeout,
+    /// Too many requests; back off before retrying
+    RateLimited,
+    /// The input or arguments were rejected
+    InvalidInput,
+    /// What was asked for does not exist
+    NotFound,
+    /// Credentials or permissions were rejected
+    Unauthorized,
+    /// The conversation is paused, ended or timed out
+    SessionClosed,
+    /// A bug or misconfiguration on our side
+    Internal,
+}
+
+impl ErrorKind {
+    /// Whether the same operation may succeed if tried again
+    pub fn is_retryable(self) -> bool {
+        matches!(self, Self::Unavailable | Self::Timeout | Self::RateLimited)
+    }
+
+    /// Stable name, used as the `error_templates` key and metrics label
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Unavailable => "unavailable",
+            Self::Timeout => "timeout",
+            Self::RateLimited => "rate_limited",
+            Self::InvalidInput => "invalid_input",
+            Self::NotFound => "not_found",
+            Self::Unauthorized => "unauthorized",
+            Self::SessionClosed => "session_closed",
+            Self::Internal => "internal",
+        }
+    }
+
+    /// English phrase for the caller when the domain configures none
+    pub fn default_phrase(self) -> &'static str {
+        match self {
+            Self::Unavailable | Self::Timeout | Self::RateLimited => {
+                "I'm having trouble checking that right now. Could we try again in a moment?"
+            },
+            Self::InvalidInput => "I couldn't use those details. Could you say them once more?",
+            Self::NotFound => "I couldn't find that in our records. Could you check the details?",
+            Self::Unauthorized | Self::SessionClosed | Self::Internal => {
+                "Sorry, something went wrong on my side. Could you please repeat that?"
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Classification every crate's error type provides
+pub trait ErrorClass {
+    /// What kind of failure this is
+    fn kind(&self) -> ErrorKind;
+
+    /// Whether the same operation may succeed if tried again
+    fn is_retryable(&self) -> bool {
+        self.kind().is_retryable()
+    }
+
+    /// Whether the failure cost the caller an answer and should be spoken
+    /// about; background failures (persistence, audit) are only logged
+    fn is_user_visible(&self) -> bool {
+        self.kind() != ErrorKind::SessionClosed
+    }
+}
+
+impl ErrorClass for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::Audio(e) => e.kind(),
+            Error::Pipeline(e) => e.kind(),
+            Error::Model(e) => e.kind(),
+            Error::Tool(e) => e.kind(),
+            Error::Agent(e) => e.kind(),
+            Error::Llm(_) | Error::Rag(_) | Error::Io(_) => ErrorKind::Unavailable,
+            Error::TextProcessing(_)
+            | Error::Config(_)
+            | Error::Serialization(_)
+            | Error::Other(_) => ErrorKind::Internal,
+        }
+    }
+}
+
+impl ErrorClass for AudioError {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            AudioError::InvalidFormat(_) | AudioError::UnsupportedSampleRate(_) => {
+                ErrorKind::InvalidInput
+            },
+            AudioError::BufferOverflow | AudioError::Codec(_) | AudioError::Resampling(_) => {
+                ErrorKind::Internal
+            },
+        }
+    }
+}
+
+impl ErrorClass for PipelineError {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            PipelineError::Timeout(_) => ErrorKind::Timeout,
+            PipelineError::NotInitialized => ErrorKind::Internal,
+            PipelineError::Vad(_)
+            | PipelineError::Stt(_)
+            | PipelineError::Tts(_)
+            | PipelineError::TurnDetection(_)
+            | PipelineError::ChannelClosed
+            | PipelineError::Audio(_)
+            | PipelineError::Io(_)
+            | PipelineError::Model(_) => ErrorKind::Unavailable,
+        }
+    }
+}
+
+impl ErrorClass for ModelError {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            ModelError::Inference(_) | ModelError::OnnxRuntime(_) => ErrorKind::Unavailable,
+            ModelError::NotFound(_)
+            | ModelError::LoadError(_)
+            | ModelError::Tokenization(_)
+            | ModelError::ShapeMismatch { .. } => ErrorKind::Internal,
+        }
+    }
+}
+
+impl ErrorClass for ToolError {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            ToolError::NotFound(_) => ErrorKind::NotFound,
+            ToolError::InvalidInput(_) => ErrorKind::InvalidInput,
+            ToolError::ExecutionFailed(_) => ErrorKind::Unavailable,
+            ToolError::Timeout => ErrorKind::Timeout,
+            ToolError::RateLimited => ErrorKind::RateLimited,
+            ToolError::Unauthorized => ErrorKind::Unauthorized,
+            ToolError::Internal(_) => ErrorKind::Internal,
+        }
+    }
+}
+
+/// MCP tool errors: backend failures (internal and custom codes) are transient
+impl ErrorClass for crate::traits::ToolError {
+    fn kind(&self) -> ErrorKind {
+        use crate::traits::ErrorCode;
+        match self.code {
+            ErrorCode::ParseError | ErrorCode::InvalidRequest | ErrorCode::InvalidParams => {
+                ErrorKind::InvalidInput
+            },
+            ErrorCode::MethodNotFound => ErrorKind::NotFound,
+            ErrorCode::InternalError | ErrorCode::Custom(_) => ErrorKind::Unavailable,
+        }
+    }
+}
+
+impl ErrorClass for AgentError {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            AgentError::LlmGeneration(_) | AgentError::NoResponse => ErrorKind::Unavailable,
+            AgentError::InvalidStageTransition { .. }
+            | AgentError::ContextOverflow(..)
+            | AgentError::Memory(_) => ErrorKind::Internal,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_kinds_decide_retry_and_visibility() {
+        let timeout = Error::Pipeline(PipelineError::Timeout(500));
+        assert_eq!(timeout.kind(), ErrorKind::Timeout);
+        assert!(timeout.is_retryable());
+        assert!(timeout.is_user_visible());
+
+        let bad_params = crate::traits::ToolError::invalid_params("weight must be positive");
+        assert_eq!(bad_params.kind(), ErrorKind::InvalidInput);
+        assert!(!bad_params.is_retryable());
+        assert!(crate::traits::ToolError::internal("backend down").is_retryable());
+
+        assert_eq!(Error::config("missing key").kind(), ErrorKind::Internal);
+        assert_eq!(ErrorKind::RateLimited.as_str(), "rate_limited");
+    }
+}
@@ -0,0 +1,238 @@
+//! Domain event bus
+//!
+//! The dialogue loop publishes typed events (session started, slot filled,
+//! goal reached, tool executed, lead captured, ...) to an `EventBus`;
+//! cross-cutting features such as audit, analytics, CRM sync, webhooks and
+//! metrics attach as `EventSubscriber`s instead of being called from the
+//! loop. Publishing never blocks: each subscriber runs in its own task, and
+//! one that falls behind skips the events it missed rather than slowing the
+//! conversation.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+/// Events buffered per subscriber before the slowest starts skipping
+pub const DEFAULT_EVENT_CAPACITY: usize = 1024;
+
+/// Something that happened in a conversation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DomainEvent {
+    /// Session created and wired to the agent
+    SessionStarted { language: String },
+    /// Conversation ended
+    SessionEnded { reason: String, duration_secs: u64 },
+    /// Dialogue state tracker filled (or changed) a slot
+    SlotFilled { slot: String, value: String },
+    /// All required slots of the active goal are filled
+    GoalReached { goal: String },
+    /// Conversation moved to another stage (greeting, discovery, ...)
+    StageChanged { from: String, to: String },
+    /// A tool finished executing
+    ToolExecuted { tool: String, success: bool },
+    /// Caller interrupted the agent's speech
+    BargeIn,
+    /// A session budget (`llm_tokens`, `session_duration`) was spent and enforced
+    BudgetExceeded {
+        budget: String,
+        limit: u64,
+        used: u64,
+    },
+    /// Lead capture succeeded; `details` is the tool's JSON result
+    LeadCaptured { details: serde_json::Value },
+    /// An appointment was booked; `details` is the tool's JSON result
+    AppointmentBooked { details: serde_json::Value },
+    /// The caller was escalated to a human; `details` is the tool's JSON result
+    EscalationRequested { details: serde_json::Value },
+}
+
+impl DomainEvent {
+    /// Stable name, e.g. for metric labels
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::SessionStarted { .. } => "session_started",
+            Self::SessionEnded { .. } => "session_ended",
+            Self::SlotFilled { .. } => "slot_filled",
+            Self::GoalReached { .. } => "goal_reached",
+            Self::StageChanged { .. } => "stage_changed",
+            Self::ToolExecuted { .. } => "tool_executed",
+            Self::BargeIn => "barge_in",
+            Self::BudgetExceeded { .. } => "budget_exceeded",
+            Self::LeadCaptured { .. } => "lead_captured",
+            Self::AppointmentBooked { .. } => "appointment_booked",
+            Self::EscalationRequested { .. } => "escalation_requested",
+        }
+    }
+}
+
+/// An event with the session it belongs to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventEnvelope {
+    pub session_id: String,
+    pub occurred_at: DateTime<Utc>,
+    pub event: DomainEvent,
+}
+
+/// Consumer of domain events
+#[async_trait]
+pub trait EventSubscriber: Send + Sync {
+    /// Name used in logs
+    fn name(&self) -> &str;
+
+    /// Whether this subscriber wants the event (all events by default)
+    fn accepts(&self, event: &DomainEvent) -> bool {
+        let _ = event;
+        true
+    }
+
+    /// Handle one event; errors are the subscriber's to log
+    async fn handle(&self, envelope: &EventEnvelope);
+}
+
+/// In-process publish/subscribe for domain events
+///
+/// Cheap to clone; all clones publish to the same subscribers.
+#[derive(Clone)]
+pub struct EventBus {
+    tx: broadcast::Sender<Arc<EventEnvelope>>,
+}
+
+impl EventBus {
+    pub fn new(capacity: usize) -> Self {
+        let (tx, _) = broadcast::channel(capacity.max(1));
+        Self { tx }
+    }
+
+    /// Publish an event; a no-op when nothing is subscribed
+    pub fn publish(&self, session_id: &str, event: DomainEvent) {
+        let _ = self.tx.send(Arc::new(EventEnvelope {
+            session_id: session_id.to_string(),
+            occurred_at: Utc::now(),
+            event,
+        }));
+    }
+
+    /// Raw receiver, for consumers that drive their own loop
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<EventEnvelope>> {
+        self.tx.subscribe()
+    }
+
+    /// Run a subscriber in the background until the bus is dropped
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn attach(&self, subscriber: Arc<dyn EventSubscriber>) -> tokio::task::JoinHandle<()> {
+        let mut rx = self.tx.subscribe();
+        tokio::spawn(async move {
+            loop {
+                match rx.recv().await {
+                    Ok(envelope) => {
+                        if subscriber.accepts(&envelope.event) {
+                            subscriber.handle(&envelope).await;
+                        }
+                    },
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!(
+                            subscriber = subscriber.name(),
+                            skipped,
+                            "Event subscriber fell behind, events skipped"
+                        );
+                    },
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+
+    /// Number of active receivers (attached subscribers included)
+    pub fn subscriber_count(&self) -> usize {
+        self.tx.receiver_count()
+    }
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new(DEFAULT_EVENT_CAPACITY)
+    }
+}
+
+impl std::fmt::Debug for EventBus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventBus")
+            .field("subscribers", &self.subscriber_count())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use tokio::sync::mpsc;
+
+    /// Forwards accepted events to a channel
+    struct ToolEvents {
+        tx: mpsc::UnboundedSender<EventEnvelope>,
+        seen: Mutex<usize>,
+    }
+
+    #[async_trait]
+    impl EventSubscriber for ToolEvents {
+        fn name(&self) -> &str {
+            "tool_events"
+        }
+
+        fn accepts(&self, event: &DomainEvent) -> bool {
+            matches!(event, DomainEvent::ToolExecuted { .. })
+        }
+
+        async fn handle(&self, envelope: &EventEnvelope) {
+            *self.seen.lock().unwrap() += 1;
+            let _ = self.tx.send(envelope.clone());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscriber_receives_accepted_events() {
+        let bus = EventBus::default();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let subscriber = Arc::new(ToolEvents {
+            tx,
+            seen: Mutex::new(0),
+        });
+        bus.attach(subscriber.clone());
+
+        bus.publish(
+            "s1",
+            DomainEvent::SlotFilled {
+                slot: "city".into(),
+                value: "Mumbai".into(),
+            },
+        );
+        bus.publish(
+            "s1",
+            DomainEvent::ToolExecuted {
+                tool: "capture_lead".into(),
+                success: true,
+            },
+        );
+
+        let envelope = rx.recv().await.unwrap();
+        assert_eq!(envelope.session_id, "s1");
+        assert_eq!(envelope.event.name(), "tool_executed");
+        assert_eq!(*subscriber.seen.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_publish_without_subscribers() {
+        let bus = EventBus::new(4);
+        bus.publish("s1", DomainEvent::BargeIn);
+        assert_eq!(bus.subscriber_count(), 0);
+
+        let mut rx = bus.subscribe();
+        bus.publish("s1", DomainEvent::BargeIn);
+        assert_eq!(rx.try_recv().unwrap().event, DomainEvent::BargeIn);
+    }
+}
@@ -0,0 +1,402 @@
+//! Location Gazetteer
+//!
+//! Cities with their states, aliases and PIN code ranges, plus the
+//! localities branches serve. One instance is built per domain at startup
+//! (from gazetteer.yaml, extraction patterns and branch data) and shared by
+//! slot extraction, the branch locator and STT entity boosting, so a city
+//! list lives in exactly one place.
+//!
+//! Lookups are case-insensitive: exact by name or alias, by prefix for
+//! partial input, fuzzy for ASR near misses, and by PIN code.
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use super::{Gazetteer, GazetteerMatch};
+
+/// Major Indian cities, used when a domain configures none
+///
+/// One city per line: `name|state|aliases|pincode ranges`, lists comma-separated.
+const BUILTIN_CITIES: &str = "\
+Mumbai|Maharashtra|Bombay,Mumbay|400001-400107
+Delhi|Delhi|New Delhi,Dilli|110001-110097
+Bangalore|Karnataka|Bengaluru,Bangaluru|560001-560117
+Chennai|Tamil Nadu|Madras|600001-600130
+Hyderabad|Telangana||500001-500098
+Kolkata|West Bengal|Calcutta|700001-700160
+Pune|Maharashtra|Poona|411001-411062
+Ahmedabad|Gujarat|Amdavad|380001-380063
+Jaipur|Rajasthan||302001-302040
+Surat|Gujarat||395001-395023
+Lucknow|Uttar Pradesh||226001-226031
+Kanpur|Uttar Pradesh||208001-208027
+Nagpur|Maharashtra||440001-440037
+Indore|Madhya Pradesh||452001-452020
+Thane|Maharashtra||400601-400615
+Bhopal|Madhya Pradesh||462001-462046
+Visakhapatnam|Andhra Pradesh|Vizag|530001-530053
+Patna|Bihar||800001-800030
+Vadodara|Gujarat|Baroda|390001-390025
+Ghaziabad|Uttar Pradesh||201001-201017
+Ludhiana|Punjab||141001-141017
+Agra|Uttar Pradesh||282001-282010
+Nashik|Maharashtra|Nasik|422001-422013
+Faridabad|Haryana||121001-121010
+Meerut|Uttar Pradesh||250001-250005
+Rajkot|Gujarat||360001-360007
+Kalyan|Maharashtra||421301-421306
+Vasai|Maharashtra||401201-401210
+Varanasi|Uttar Pradesh|Banaras,Benares|221001-221011
+Aurangabad|Maharashtra|Sambhajinagar|431001-431010
+Dhanbad|Jharkhand||826001-826004
+Amritsar|Punjab||143001-143006
+Allahabad|Uttar Pradesh|Prayagraj|211001-211019
+Ranchi|Jharkhand||834001-834012
+Gwalior|Madhya Pradesh||474001-474012
+Jodhpur|Rajasthan||342001-342015
+Coimbatore|Tamil Nadu|Kovai|641001-641050
+Vijayawada|Andhra Pradesh|Bezawada|520001-520015
+Madurai|Tamil Nadu||625001-625022
+Raipur|Chhattisgarh||492001-492015
+Kota|Rajasthan||324001-324010";
+
+static BUILTIN: Lazy<LocationGazetteer> = Lazy::new(|| {
+    let mut gazetteer = LocationGazetteer::new();
+    for line in BUILTIN_CITIES.lines() {
+        let mut fields = line.split('|');
+        let mut next_list = || {
+            fields
+                .next()
+                .unwrap_or_default()
+                .split(',')
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+        let (name, state, aliases, ranges) = (next_list(), next_list(), next_list(), next_list());
+        gazetteer.add_city(City {
+            name: name.concat(),
+            state: state.into_iter().next(),
+            aliases,
+            pincodes: ranges
+                .iter()
+                .filter_map(|r| PincodeRange::parse(r))
+                .collect(),
+        });
+    }
+    gazetteer
+});
+
+/// Inclusive range of 6-digit PIN codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PincodeRange {
+    pub from: u32,
+    pub to: u32,
+}
+
+impl PincodeRange {
+    /// Parse "560001-560117" or a single "560001"
+    pub fn parse(text: &str) -> Option<Self> {
+        let (from, to) = text.split_once('-').unwrap_or((text, text));
+        Some(Self {
+            from: from.trim().parse().ok()?,
+            to: to.trim().parse().ok()?,
+        })
+    }
+
+    pub fn contains(&self, pincode: u32) -> bool {
+        (self.from..=self.to).contains(&pincode)
+    }
+}
+
+/// A city, with its state, other names and PIN codes
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct City {
+    pub name: String,
+    #[serde(default)]
+    pub state: Option<String>,
+    /// Other names and spellings ("Bombay", "Bengaluru")
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    #[serde(default)]
+    pub pincodes: Vec<PincodeRange>,
+}
+
+impl City {
+    fn is_named(&self, name: &str) -> bool {
+        self.names().any(|n| n.eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Name followed by the aliases
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.name.as_str()).chain(self.aliases.iter().map(String::as_str))
+    }
+}
+
+/// An area of a city served by a branch
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Locality {
+    /// Area name ("Andheri West")
+    pub name: String,
+    /// Canonical name of the city
+    pub city: String,
+    #[serde(default)]
+    pub branch_id: Option<String>,
+    #[serde(default)]
+    pub pincode: Option<String>,
+}
+
+/// Cities, states and branch localities of a domain
+#[derive(Debug, Clone, Default)]
+pub struct LocationGazetteer {
+    states: Vec<String>,
+    cities: Vec<City>,
+    localities: Vec<Locality>,
+    /// City names and aliases, valued by city name
+    city_index: Gazetteer,
+    /// Locality names, valued by locality name
+    locality_index: Gazetteer,
+}
+
+impl LocationGazetteer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Process-wide gazetteer of major Indian cities
+    pub fn builtin() -> &'static LocationGazetteer {
+        &BUILTIN
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cities.is_empty() && self.localities.is_empty()
+    }
+
+    pub fn add_state(&mut self, name: &str) {
+        let name = name.trim();
+        if !name.is_empty() && !self.states.iter().any(|s| s.eq_ignore_ascii_case(name)) {
+            self.states.push(name.to_string());
+        }
+    }
+
+    /// Add a city, merging its aliases and PIN codes into a known one
+    pub fn add_city(&mut self, city: City) {
+        if let Some(state) = &city.state {
+            self.add_state(state);
+        }
+        let Some(known) = self.cities.iter_mut().find(|c| c.is_named(&city.name)) else {
+            self.city_index.insert(&city.name, city.names());
+            self.cities.push(city);
+            return;
+        };
+        let new_names: Vec<String> = city
+            .names()
+            .filter(|name| !known.is_named(name))
+            .map(str::to_string)
+            .collect();
+        self.city_index.insert(&known.name, &new_names);
+        known.aliases.extend(new_names);
+        known.state = known.state.take().or(city.state);
+        for range in city.pincodes {
+            if !known.pincodes.contains(&range) {
+                known.pincodes.push(range);
+            }
+        }
+    }
+
+    /// Add a branch locality; its city is added if unknown
+    pub fn add_locality(&mut self, mut locality: Locality) {
+        match self.city(&locality.city) {
+            Some(city) => locality.city = city.name.clone(),
+            None => self.add_city(City {
+                name: locality.city.clone(),
+                ..City::default()
+            }),
+        }
+        let duplicate = self
+            .localities
+            .iter()
+            .any(|l| l.name.eq_ignore_ascii_case(&locality.name) && l.city == locality.city);
+        if !duplicate {
+            self.locality_index.insert(&locality.name, [&locality.name]);
+            self.localities.push(locality);
+        }
+    }
+
+    pub fn states(&self) -> &[String] {
+        &self.states
+    }
+
+    pub fn cities(&self) -> &[City] {
+        &self.cities
+    }
+
+    pub fn localities(&self) -> &[Locality] {
+        &self.localities
+    }
+
+    /// City known by `name` or one of its aliases
+    pub fn city(&self, name: &str) -> Option<&City> {
+        self.cities.iter().find(|c| c.is_named(name))
+    }
+
+    /// Cities whose name or an alias starts with `prefix` ("bang" -> Bangalore)
+    pub fn cities_with_prefix(&self, prefix: &str) -> Vec<&City> {
+        let prefix = prefix.trim().to_lowercase();
+        if prefix.is_empty() {
+            return Vec::new();
+        }
+        self.cities
+            .iter()
+            .filter(|c| c.names().any(|n| n.to_lowercase().starts_with(&prefix)))
+            .collect()
+    }
+
+    /// City named in an utterance, tolerating ASR misspellings
+    pub fn find_city(&self, text: &str) -> Option<GazetteerMatch<'_>> {
+        self.city_index.find(text)
+    }
+
+    /// Locality named in an utterance, with the match similarity
+    pub fn find_locality(&self, text: &str) -> Option<(&Locality, f32)> {
+        let found = self.locality_index.find(text)?;
+        let locality = self.localities.iter().find(|l| l.name == found.value)?;
+        Some((locality, found.similarity))
+    }
+
+    /// City of a 6-digit PIN code, by branch locality first, then by range
+    pub fn city_for_pincode(&self, pincode: &str) -> Option<&City> {
+        let pincode = pincode.trim();
+        if pincode.len() != 6 {
+            return None;
+        }
+        let code: u32 = pincode.parse().ok()?;
+        if let Some(locality) = self
+            .localities
+            .iter()
+            .find(|l| l.pincode.as_deref() == Some(pincode))
+        {
+            return self.city(&locality.city);
+        }
+        self.cities
+            .iter()
+            .find(|c| c.pincodes.iter().any(|range| range.contains(code)))
+    }
+
+    /// State of the city known by `city`
+    pub fn state_of(&self, city: &str) -> Option<&str> {
+        self.city(city)?.state.as_deref()
+    }
+
+    /// Localities in the city known by `city`
+    pub fn localities_in<'a>(&'a self, city: &str) -> impl Iterator<Item = &'a Locality> {
+        let name = self.city(city).map(|c| c.name.clone());
+        self.localities
+            .iter()
+            .filter(move |l| name.as_deref() == Some(l.city.as_str()))
+    }
+
+    /// Names worth boosting in speech recognition: cities, aliases,
+    /// states and localities, without duplicates
+    pub fn boost_terms(&self) -> Vec<&str> {
+        let cities = self.cities.iter().flat_map(City::names);
+        let states = self.states.iter().map(String::as_str);
+        let localities = self.localities.iter().map(|l| l.name.as_str());
+
+        let mut terms: Vec<&str> = Vec::new();
+        for term in cities.chain(states).chain(localities) {
+            if !terms.iter().any(|t| t.eq_ignore_ascii_case(term)) {
+                terms.push(term);
+            }
+        }
+        terms
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gazetteer() -> LocationGazetteer {
+        let mut gazetteer = LocationGazetteer::new();
+        gazetteer.add_city(City {
+            name: "Bangalore".to_string(),
+            state: Some("Karnataka".to_string()),
+            aliases: vec!["Bengaluru".to_string()],
+            pincodes: vec![PincodeRange::parse("560001-560117").unwrap()],
+        });
+        gazetteer.add_locality(Locality {
+            name: "Koramangala".to_string(),
+            city: "bengaluru".to_string(),
+            branch_id: Some("KMBL010".to_string()),
+            pincode: Some("560034".to_string()),
+        });
+        gazetteer.add_locality(Locality {
+            name: "Andheri West".to_string(),
+            city: "Mumbai".to_string(),
+            branch_id: None,
+            pincode: Some("400058".to_string()),
+        });
+        gazetteer
+    }
+
+    #[test]
+    fn test_lookups() {
+        let gazetteer = gazetteer();
+        assert_eq!(gazetteer.state_of("bengaluru"), Some("Karnataka"));
+        // Adding a locality in an unknown city adds the city
+        assert!(gazetteer.city("Mumbai").is_some());
+
+        let names = |cities: Vec<&City>| -> Vec<String> {
+            cities.into_iter().map(|c| c.name.clone()).collect()
+        };
+        assert_eq!(names(gazetteer.cities_with_prefix("beng")), ["Bangalore"]);
+        assert!(gazetteer.cities_with_prefix("").is_empty());
+
+        let koramangala: Vec<_> = gazetteer.localities_in("Bangalore").collect();
+        assert_eq!(koramangala.len(), 1);
+        assert_eq!(koramangala[0].city, "Bangalore");
+    }
+
+    #[test]
+    fn test_pincodes() {
+        let gazetteer = gazetteer();
+        let city = |pin: &str| gazetteer.city_for_pincode(pin).map(|c| c.name.as_str());
+        assert_eq!(city("560034"), Some("Bangalore"));
+        assert_eq!(city("560100"), Some("Bangalore"));
+        assert_eq!(city("400058"), Some("Mumbai"));
+        assert_eq!(city("999999"), None);
+        assert_eq!(city("5600"), None);
+    }
+
+    #[test]
+    fn test_fuzzy_and_merge() {
+        let mut gazetteer = gazetteer();
+        let found = gazetteer
+            .find_city("main bengluru se hoon")
+            .map(|m| m.value);
+        assert_eq!(found, Some("Bangalore"));
+        let (locality, _) = gazetteer.find_locality("kormangala branch").unwrap();
+        assert_eq!(locality.name, "Koramangala");
+
+        gazetteer.add_city(City {
+            name: "Bangalore".to_string(),
+            aliases: vec!["Bangaluru".to_string(), "Bengaluru".to_string()],
+            ..City::default()
+        });
+        assert_eq!(gazetteer.cities().len(), 2);
+        assert_eq!(gazetteer.city("bangaluru").unwrap().aliases.len(), 2);
+
+        let terms = gazetteer.boost_terms();
+        assert!(terms.contains(&"Karnataka") && terms.contains(&"Koramangala"));
+    }
+
+    #[test]
+    fn test_builtin() {
+        let builtin = LocationGazetteer::builtin();
+        assert!(builtin.cities().len() >= 40);
+        assert_eq!(builtin.state_of("Bombay"), Some("Maharashtra"));
+        let city = builtin.city_for_pincode("110001").map(|c| c.name.as_str());
+        assert_eq!(city, Some("Delhi"));
+    }
+}
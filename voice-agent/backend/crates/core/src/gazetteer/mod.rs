@@ -0,0 +1,257 @@
+//! ASR-Tolerant Gazetteer Lookup
+//!
+//! Exact name patterns miss near-miss transcriptions: "Manappuram" comes out
+//! as "mana puram", "Muthoot" as "mutut", "Vijayawada" as "vijaywada". A
+//! [`Gazetteer`] holds the names of lenders, cities or branches and finds
+//! the closest one in an utterance:
+//!
+//! - word n-grams are joined without spaces, so split words still match;
+//! - similarity is the normalized edit distance, taken on the raw spelling
+//!   and on a Soundex-style phonetic folding of Indic transliterations
+//!   ("th" -> "t", "oo" -> "u", doubled letters collapsed);
+//! - the best match above [`MIN_SIMILARITY`] wins, with its similarity so
+//!   callers can lower the slot confidence accordingly.
+//!
+//! [`LocationGazetteer`] builds on it for cities, states, PIN codes and
+//! branch localities.
+
+mod location;
+
+pub use location::{City, Locality, LocationGazetteer, PincodeRange};
+
+/// Matches below this similarity (0.0-1.0) are ignored
+pub const MIN_SIMILARITY: f32 = 0.8;
+
+/// Shorter words and names are only matched exactly; they collide with
+/// everything ("kotak" is one edit from "Kota")
+const MIN_FUZZY_LEN: usize = 5;
+
+/// Longest word n-gram tried against the names
+const MAX_NGRAM: usize = 3;
+
+/// A phonetic match is never as certain as the spelling
+const PHONETIC_WEIGHT: f32 = 0.95;
+
+/// Transliteration variants folded to one spelling, in order
+const FOLDS: &[(&str, &str)] = &[
+    ("ph", "f"),
+    ("bh", "b"),
+    ("kh", "k"),
+    ("gh", "g"),
+    ("th", "t"),
+    ("dh", "d"),
+    ("sh", "s"),
+    ("ch", "c"),
+    ("ck", "k"),
+    ("aa", "a"),
+    ("ee", "i"),
+    ("oo", "u"),
+    ("w", "v"),
+    ("z", "j"),
+    ("q", "k"),
+    ("y", "i"),
+];
+
+/// A name known under one value, compared in two spellings
+#[derive(Debug, Clone)]
+struct Form {
+    /// Lowercased, without spaces or punctuation
+    squashed: String,
+    phonetic: String,
+}
+
+impl Form {
+    fn new(text: &str) -> Self {
+        let squashed = squash(text);
+        let phonetic = phonetic_key(&squashed);
+        Self { squashed, phonetic }
+    }
+}
+
+/// Name found in an utterance
+#[derive(Debug, Clone, PartialEq)]
+pub struct GazetteerMatch<'a> {
+    /// Value of the matched entry (e.g. the canonical lender name)
+    pub value: &'a str,
+    /// 1.0 for an exact match, lower for near misses
+    pub similarity: f32,
+}
+
+/// Names to find in utterances, each mapped to a slot value
+#[derive(Debug, Clone, Default)]
+pub struct Gazetteer {
+    entries: Vec<(String, Vec<Form>)>,
+}
+
+impl Gazetteer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `value`, known by any of `names`
+    pub fn insert<I>(&mut self, value: &str, names: I)
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let forms: Vec<Form> = names
+            .into_iter()
+            .map(|name| Form::new(name.as_ref()))
+            .filter(|form| !form.squashed.is_empty())
+            .collect();
+        if !forms.is_empty() {
+            self.entries.push((value.to_string(), forms));
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Closest name in `text`, if similar enough
+    pub fn find(&self, text: &str) -> Option<GazetteerMatch<'_>> {
+        let words: Vec<String> = text
+            .split(|c: char| c.is_whitespace() || c.is_ascii_punctuation() || c == '।')
+            .filter(|w| !w.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+
+        let mut best: Option<GazetteerMatch> = None;
+        for n in 1..=MAX_NGRAM.min(words.len()) {
+            for window in words.windows(n) {
+                let candidate = Form::new(&window.concat());
+                for (value, forms) in &self.entries {
+                    let similarity = forms
+                        .iter()
+                        .map(|form| similarity(&candidate, form))
+                        .fold(0.0, f32::max);
+                    if similarity >= MIN_SIMILARITY
+                        && best.as_ref().map_or(true, |b| similarity > b.similarity)
+                    {
+                        let value = value.as_str();
+                        best = Some(GazetteerMatch { value, similarity });
+                    }
+                }
+            }
+        }
+        best
+    }
+}
+
+/// Similarity of a candidate to a name, on spelling and on sound
+fn similarity(candidate: &Form, name: &Form) -> f32 {
+    if candidate.squashed == name.squashed {
+        return 1.0;
+    }
+    let length = |form: &Form| form.squashed.chars().count();
+    if length(candidate).min(length(name)) < MIN_FUZZY_LEN {
+        return 0.0;
+    }
+    let spelled = ratio(&candidate.squashed, &name.squashed);
+    let sounded = ratio(&candidate.phonetic, &name.phonetic) * PHONETIC_WEIGHT;
+    spelled.max(sounded)
+}
+
+/// 1.0 minus the edit distance relative to the longer string
+fn ratio(a: &str, b: &str) -> f32 {
+    let longest = a.chars().count().max(b.chars().count());
+    if longest == 0 {
+        return 0.0;
+    }
+    1.0 - levenshtein(a, b) as f32 / longest as f32
+}
+
+/// Edit distance in characters, over two rows
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != *cb);
+            curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+/// Lowercase letters and digits only ("Mana Puram" -> "manapuram")
+fn squash(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Fold transliteration variants and collapse doubled letters
+fn phonetic_key(squashed: &str) -> String {
+    let fold = |text: String, (from, to): &(&str, &str)| text.replace(from, to);
+    let folded = FOLDS.iter().fold(squashed.to_string(), fold);
+    let mut chars: Vec<char> = folded.chars().collect();
+    chars.dedup();
+    chars.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lenders() -> Gazetteer {
+        let mut gazetteer = Gazetteer::new();
+        gazetteer.insert("Manappuram Finance", ["manappuram", "Manappuram Finance"]);
+        gazetteer.insert("Muthoot Finance", ["muthoot", "Muthoot Finance"]);
+        gazetteer.insert("IIFL Finance", ["iifl"]);
+        gazetteer
+    }
+
+    #[test]
+    fn test_near_misses_found() {
+        let lenders = lenders();
+        for (text, expected) in [
+            ("mera loan mana puram mein hai", "Manappuram Finance"),
+            ("I have it with mannapuram", "Manappuram Finance"),
+            ("mutthut finance se liya tha", "Muthoot Finance"),
+            ("IIFL se", "IIFL Finance"),
+        ] {
+            let found = lenders.find(text).map(|m| m.value);
+            assert_eq!(found, Some(expected), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_similarity_reflects_distance() {
+        let lenders = lenders();
+        let exact = lenders.find("muthoot").unwrap();
+        assert_eq!(exact.similarity, 1.0);
+        let near = lenders.find("muthut").unwrap();
+        assert!(near.similarity < 1.0 && near.similarity >= MIN_SIMILARITY);
+    }
+
+    #[test]
+    fn test_unrelated_words_ignored() {
+        let lenders = lenders();
+        assert_eq!(lenders.find("mujhe gold loan chahiye"), None);
+        // Short words are only matched exactly
+        assert_eq!(lenders.find("iffl"), None);
+    }
+
+    #[test]
+    fn test_cities() {
+        let mut cities = Gazetteer::new();
+        cities.insert("Vijayawada", ["Vijayawada"]);
+        cities.insert("Thiruvananthapuram", ["Thiruvananthapuram", "Trivandrum"]);
+        cities.insert("Kota", ["Kota"]);
+
+        let found = cities.find("main vijaywada se hoon").map(|m| m.value);
+        assert_eq!(found, Some("Vijayawada"));
+        let found = cities.find("tiruvanantapuram branch").map(|m| m.value);
+        assert_eq!(found, Some("Thiruvananthapuram"));
+        assert_eq!(cities.find("kotak se loan hai"), None);
+    }
+}
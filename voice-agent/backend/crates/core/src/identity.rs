@@ -0,0 +1,242 @@
+//! Cross-channel customer identity
+//!
+//! Customers are identified by their normalized phone number so that a voice
+//! call, a WhatsApp chat and a repeat call next week all resolve to the same
+//! identity. The identity carries facts learned in prior sessions, which the
+//! agent preloads into memory and dialogue state at session start.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Maximum session IDs remembered per customer (oldest dropped first)
+pub const MAX_LINKED_SESSIONS: usize = 50;
+
+/// Channel a session arrived on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum Channel {
+    /// Voice call (WebSocket / WebRTC / PTT)
+    #[default]
+    Voice,
+    /// WhatsApp chat
+    #[serde(rename = "whatsapp")]
+    WhatsApp,
+    /// Web chat / HTTP API
+    Web,
+}
+
+impl Channel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Voice => "voice",
+            Self::WhatsApp => "whatsapp",
+            Self::Web => "web",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "voice" => Some(Self::Voice),
+            "whatsapp" => Some(Self::WhatsApp),
+            "web" => Some(Self::Web),
+            _ => None,
+        }
+    }
+}
+
+/// Normalize a phone number to its 10-digit Indian mobile form
+///
+/// Strips formatting and the `+91` / `91` / `0` prefixes. Returns `None`
+/// when the result is not a valid 10-digit mobile number (must start 6-9).
+pub fn normalize_phone(raw: &str) -> Option<String> {
+    let digits: String = raw.chars().filter(|c| c.is_ascii_digit()).collect();
+    let national = match digits.len() {
+        10 => digits.as_str(),
+        11 if digits.starts_with('0') => &digits[1..],
+        12 if digits.starts_with("91") => &digits[2..],
+        _ => return None,
+    };
+
+    match national.chars().next() {
+        Some('6'..='9') => Some(national.to_string()),
+        _ => None,
+    }
+}
+
+/// A customer known across sessions and channels
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerIdentity {
+    /// Normalized phone number (see `normalize_phone`)
+    pub customer_id: String,
+    /// Customer name, if ever stated
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Last used language (ISO 639-1)
+    #[serde(default)]
+    pub preferred_language: Option<String>,
+    /// Config-driven segment ID
+    #[serde(default)]
+    pub segment: Option<String>,
+    /// Facts learned in prior sessions, keyed by DST slot name
+    #[serde(default)]
+    pub facts: HashMap<String, String>,
+    /// Conversation goal of the most recent session
+    #[serde(default)]
+    pub last_goal: Option<String>,
+    /// Channel of the most recent session
+    #[serde(default)]
+    pub last_channel: Option<Channel>,
+    /// Linked session IDs, oldest first
+    #[serde(default)]
+    pub session_ids: Vec<String>,
+    pub first_seen: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+}
+
+impl CustomerIdentity {
+    /// Create a new identity from a raw phone number
+    ///
+    /// Returns `None` if the phone number cannot be normalized.
+    pub fn new(phone: &str) -> Option<Self> {
+        let customer_id = normalize_phone(phone)?;
+        let now = Utc::now();
+        Some(Self {
+            customer_id,
+            name: None,
+            preferred_language: None,
+            segment: None,
+            facts: HashMap::new(),
+            last_goal: None,
+            last_channel: None,
+            session_ids: Vec::new(),
+            first_seen: now,
+            last_seen: now,
+        })
+    }
+
+    /// Link a session to this identity
+    ///
+    /// Re-linking the same session only refreshes `last_seen`.
+    pub fn link_session(&mut self, session_id: &str, channel: Channel) {
+        if !self.session_ids.iter().any(|id| id == session_id) {
+            self.session_ids.push(session_id.to_string());
+            if self.session_ids.len() > MAX_LINKED_SESSIONS {
+                let excess = self.session_ids.len() - MAX_LINKED_SESSIONS;
+                self.session_ids.drain(..excess);
+            }
+        }
+        self.last_channel = Some(channel);
+        self.last_seen = Utc::now();
+    }
+
+    /// Merge facts from a finished session (newer values win)
+    pub fn merge_facts<I, K, V>(&mut self, facts: I)
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        for (key, value) in facts {
+            let value = value.into();
+            if !value.trim().is_empty() {
+                self.facts.insert(key.into(), value);
+            }
+        }
+    }
+
+    /// Whether this customer has sessions other than `current_session_id`
+    pub fn is_returning(&self, current_session_id: &str) -> bool {
+        self.session_ids.iter().any(|id| id != current_session_id)
+    }
+
+    /// Number of linked sessions
+    pub fn session_count(&self) -> usize {
+        self.session_ids.len()
+    }
+
+    /// Short summary of prior interactions for the agent's memory
+    ///
+    /// e.g. "Returning customer (3 prior sessions, last via whatsapp on 2024-01-15).
+    /// Last discussed: balance_transfer. Known: current_lender=Muthoot"
+    pub fn prior_context_summary(&self, current_session_id: &str) -> String {
+        let prior = self
+            .session_ids
+            .iter()
+            .filter(|id| *id != current_session_id)
+            .count();
+
+        let mut summary = format!(
+            "Returning customer ({} prior session{}",
+            prior,
+            if prior == 1 { "" } else { "s" }
+        );
+        if let Some(channel) = self.last_channel {
+            summary.push_str(&format!(", last via {}", channel.as_str()));
+        }
+        summary.push_str(&format!(" on {}).", self.last_seen.format("%Y-%m-%d")));
+
+        if let Some(ref goal) = self.last_goal {
+            summary.push_str(&format!(" Last discussed: {}.", goal));
+        }
+
+        if !self.facts.is_empty() {
+            let mut facts: Vec<_> = self.facts.iter().collect();
+            facts.sort_by(|a, b| a.0.cmp(b.0));
+            let known: Vec<String> = facts.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+            summary.push_str(&format!(" Known: {}", known.join(", ")));
+        }
+
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_phone() {
+        assert_eq!(normalize_phone("9876543210").as_deref(), Some("9876543210"));
+        assert_eq!(normalize_phone("+91 98765-43210").as_deref(), Some("9876543210"));
+        assert_eq!(normalize_phone("919876543210").as_deref(), Some("9876543210"));
+        assert_eq!(normalize_phone("09876543210").as_deref(), Some("9876543210"));
+        assert!(normalize_phone("12345").is_none());
+        assert!(normalize_phone("1234567890").is_none());
+    }
+
+    #[test]
+    fn test_link_session_across_channels() {
+        let mut identity = CustomerIdentity::new("+91 9876543210").unwrap();
+        identity.link_session("s1", Channel::Voice);
+        identity.link_session("s2", Channel::WhatsApp);
+        identity.link_session("s2", Channel::WhatsApp);
+
+        assert_eq!(identity.session_count(), 2);
+        assert_eq!(identity.last_channel, Some(Channel::WhatsApp));
+        assert!(identity.is_returning("s2"));
+        assert!(!CustomerIdentity::new("9876543210").unwrap().is_returning("s1"));
+    }
+
+    #[test]
+    fn test_prior_context_summary() {
+        let mut identity = CustomerIdentity::new("9876543210").unwrap();
+        identity.link_session("s1", Channel::Voice);
+        identity.last_goal = Some("balance_transfer".to_string());
+        identity.merge_facts([("current_lender", "Muthoot"), ("location", " ")]);
+
+        let summary = identity.prior_context_summary("s2");
+        assert!(summary.starts_with("Returning customer (1 prior session, last via voice"));
+        assert!(summary.contains("Last discussed: balance_transfer."));
+        assert!(summary.contains("current_lender=Muthoot"));
+        assert!(!identity.facts.contains_key("location"));
+    }
+
+    #[test]
+    fn test_channel_serde() {
+        assert_eq!(serde_json::to_string(&Channel::WhatsApp).unwrap(), "\"whatsapp\"");
+        let channel: Channel = serde_json::from_str("\"whatsapp\"").unwrap();
+        assert_eq!(channel, Channel::WhatsApp);
+        assert_eq!(Channel::parse("WhatsApp"), Some(Channel::WhatsApp));
+    }
+}
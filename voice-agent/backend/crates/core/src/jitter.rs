@@ -0,0 +1,278 @@
+//! Inbound audio jitter buffer
+//!
+//! Reorders sequence-numbered audio frames, conceals short gaps and resyncs
+//! past long ones, so a brief network drop neither shifts STT feature
+//! windows nor the frame timeline that barge-in alignment relies on:
+//! - In-order frames pass straight through (no added latency)
+//! - A missing frame is waited for until `target_depth` later frames arrive
+//! - Gaps up to `max_conceal_frames` are filled with a fading copy of the
+//!   last frame, keeping one output frame per sequence number
+//! - Longer gaps skip ahead and report a [`JitterOutput::Resync`]
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Jitter buffer configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JitterBufferConfig {
+    /// Frames to hold behind a gap before giving up on the missing frame
+    pub target_depth: usize,
+    /// Longest gap (in frames) filled by concealment instead of a resync
+    pub max_conceal_frames: u64,
+}
+
+impl Default for JitterBufferConfig {
+    fn default() -> Self {
+        Self {
+            target_depth: 3,
+            // 100ms at 20ms frames
+            max_conceal_frames: 5,
+        }
+    }
+}
+
+/// Frame released by the jitter buffer
+#[derive(Debug, Clone, PartialEq)]
+pub enum JitterOutput {
+    /// Frame received from the network
+    Frame { sequence: u64, samples: Vec<f32> },
+    /// Synthesized stand-in for a lost frame
+    Concealed { sequence: u64, samples: Vec<f32> },
+    /// Gap too long to conceal; playback resumed at `resumed_at`
+    Resync { expected: u64, resumed_at: u64 },
+}
+
+/// Jitter buffer statistics
+#[derive(Debug, Clone, Default)]
+pub struct JitterStats {
+    pub received: u64,
+    /// Arrived after their slot was concealed or skipped
+    pub late: u64,
+    pub duplicates: u64,
+    pub concealed: u64,
+    pub resyncs: u64,
+}
+
+/// Reordering jitter buffer for one inbound audio stream
+#[derive(Debug, Default)]
+pub struct JitterBuffer {
+    config: JitterBufferConfig,
+    pending: BTreeMap<u64, Vec<f32>>,
+    /// Next sequence number to release (`None` until the first frame)
+    next: Option<u64>,
+    /// Last released frame, the basis for concealment
+    last: Vec<f32>,
+    /// Consecutive concealed frames (drives the fade)
+    conceal_run: i32,
+    stats: JitterStats,
+}
+
+impl JitterBuffer {
+    /// Create a jitter buffer
+    pub fn new(config: JitterBufferConfig) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+
+    /// Add a frame; returns `false` if it was late or a duplicate
+    pub fn push(&mut self, sequence: u64, samples: Vec<f32>) -> bool {
+        let next = *self.next.get_or_insert(sequence);
+        if sequence < next {
+            self.stats.late += 1;
+            return false;
+        }
+        if self.pending.contains_key(&sequence) {
+            self.stats.duplicates += 1;
+            return false;
+        }
+
+        self.pending.insert(sequence, samples);
+        self.stats.received += 1;
+        true
+    }
+
+    /// Add a frame from a source without sequence numbers (assumed in order)
+    pub fn push_unsequenced(&mut self, samples: Vec<f32>) -> u64 {
+        let sequence = self
+            .pending
+            .keys()
+            .next_back()
+            .map(|last| last + 1)
+            .or(self.next)
+            .unwrap_or(0);
+        self.push(sequence, samples);
+        sequence
+    }
+
+    /// Release every frame that is ready, in sequence order
+    pub fn drain(&mut self) -> Vec<JitterOutput> {
+        let mut out = Vec::new();
+
+        while let Some(next) = self.next {
+            if let Some(samples) = self.pending.remove(&next) {
+                self.last.clone_from(&samples);
+                self.conceal_run = 0;
+                out.push(JitterOutput::Frame {
+                    sequence: next,
+                    samples,
+                });
+                self.next = Some(next + 1);
+                continue;
+            }
+
+            // Gap: wait for the missing frame until enough later ones queue up
+            let Some(&earliest) = self.pending.keys().next() else {
+                break;
+            };
+            if self.pending.len() < self.config.target_depth {
+                break;
+            }
+
+            let missing = earliest - next;
+            if missing <= self.config.max_conceal_frames {
+                for sequence in next..earliest {
+                    out.push(JitterOutput::Concealed {
+                        sequence,
+                        samples: self.conceal(),
+                    });
+                }
+            } else {
+                self.stats.resyncs += 1;
+                self.last.clear();
+                out.push(JitterOutput::Resync {
+                    expected: next,
+                    resumed_at: earliest,
+                });
+            }
+            self.next = Some(earliest);
+        }
+
+        out
+    }
+
+    /// Restart at `next_sequence`, dropping anything queued
+    ///
+    /// Used when the peer announces a new numbering after reconnecting.
+    pub fn resync(&mut self, next_sequence: u64) {
+        self.pending.clear();
+        self.next = Some(next_sequence);
+        self.last.clear();
+        self.conceal_run = 0;
+        self.stats.resyncs += 1;
+    }
+
+    /// Next sequence number expected
+    pub fn next_sequence(&self) -> Option<u64> {
+        self.next
+    }
+
+    /// Frames waiting behind a gap
+    pub fn depth(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn stats(&self) -> &JitterStats {
+        &self.stats
+    }
+
+    /// Last frame at halving gain per consecutive loss, so repeats fade out
+    fn conceal(&mut self) -> Vec<f32> {
+        self.conceal_run += 1;
+        self.stats.concealed += 1;
+        let gain = 0.5f32.powi(self.conceal_run);
+        self.last.iter().map(|s| s * gain).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequences(out: &[JitterOutput]) -> Vec<u64> {
+        out.iter()
+            .filter_map(|o| match o {
+                JitterOutput::Frame { sequence, .. } | JitterOutput::Concealed { sequence, .. } => {
+                    Some(*sequence)
+                },
+                JitterOutput::Resync { .. } => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_reorders_without_concealing() {
+        let mut buffer = JitterBuffer::new(JitterBufferConfig::default());
+        buffer.push(10, vec![0.1; 4]);
+        assert_eq!(sequences(&buffer.drain()), vec![10]);
+
+        // 12 arrives before 11: held, then both released in order
+        buffer.push(12, vec![0.3; 4]);
+        assert!(buffer.drain().is_empty());
+        buffer.push(11, vec![0.2; 4]);
+        assert_eq!(sequences(&buffer.drain()), vec![11, 12]);
+
+        assert!(!buffer.push(11, vec![0.2; 4]));
+        assert_eq!(buffer.stats().late, 1);
+        assert_eq!(buffer.stats().concealed, 0);
+    }
+
+    #[test]
+    fn test_conceals_short_gap() {
+        let mut buffer = JitterBuffer::new(JitterBufferConfig::default());
+        buffer.push(0, vec![0.8; 4]);
+        buffer.drain();
+
+        // 1 and 2 lost; 3 frames queue up behind the gap
+        for sequence in 3..6 {
+            buffer.push(sequence, vec![0.4; 4]);
+        }
+        let out = buffer.drain();
+        assert_eq!(sequences(&out), vec![1, 2, 3, 4, 5]);
+        assert_eq!(
+            out[0],
+            JitterOutput::Concealed {
+                sequence: 1,
+                samples: vec![0.4; 4]
+            }
+        );
+        assert_eq!(
+            out[1],
+            JitterOutput::Concealed {
+                sequence: 2,
+                samples: vec![0.2; 4]
+            }
+        );
+
+        // The lost frame turning up late is dropped
+        assert!(!buffer.push(2, vec![0.0; 4]));
+    }
+
+    #[test]
+    fn test_resync_after_long_drop() {
+        let mut buffer = JitterBuffer::new(JitterBufferConfig::default());
+        buffer.push(0, vec![0.5; 4]);
+        buffer.drain();
+
+        for sequence in 50..53 {
+            buffer.push(sequence, vec![0.5; 4]);
+        }
+        let out = buffer.drain();
+        assert_eq!(
+            out[0],
+            JitterOutput::Resync {
+                expected: 1,
+                resumed_at: 50
+            }
+        );
+        assert_eq!(sequences(&out), vec![50, 51, 52]);
+
+        // Peer reconnects and restarts numbering
+        buffer.resync(0);
+        assert_eq!(buffer.push_unsequenced(vec![0.5; 4]), 0);
+        assert_eq!(buffer.push_unsequenced(vec![0.5; 4]), 1);
+        assert_eq!(sequences(&buffer.drain()), vec![0, 1]);
+        assert_eq!(buffer.stats().resyncs, 2);
+    }
+}
@@ -7,18 +7,32 @@
 //! - Text processing types (PII, compliance)
 //! - Error types
 //! - Conversation types
+//! - Gazetteers (fuzzy name lookup, cities and branch localities)
+//! - Conference calls (mixing customer and co-applicant channels)
+//! - Line audio quality (SNR, clipping, packet loss)
+//! - Avatar cues (visemes and gestures for lip-synced clients)
+//! - Latency statistics (percentiles for benchmarks and load tests)
 
 // Existing modules
 pub mod audio;
+pub mod audio_quality;
+pub mod avatar;
+pub mod conference;
 pub mod conversation;
 pub mod customer;
 pub mod error;
+pub mod events;
+pub mod identity;
+pub mod jitter;
+pub mod samples;
+pub mod stats;
 pub mod transcript;
 
 // New modules (Phase 1)
 pub mod compliance;
 pub mod domain;
 pub mod domain_context;
+pub mod gazetteer;
 pub mod language;
 pub mod llm_types;
 pub mod pii;
@@ -33,12 +47,19 @@ pub mod financial;
 
 // Re-exports from existing modules
 pub use audio::{AudioEncoding, AudioFrame, Channels, SampleRate};
+pub use audio_quality::{AudioQuality, AudioQualityAnalyzer, AudioQualityConfig, LineIssue};
+pub use avatar::{AvatarCues, Gesture, GestureCue, Viseme, VisemeCue};
+pub use conference::{ConferenceMixer, ConferenceMixerConfig, MixedFrame, Speaker, SpeakerTally};
 pub use conversation::{ConversationStage, Turn, TurnRole};
 pub use customer::{
     CompanyRelationship, CustomerProfile, CustomerSegment, SegmentDetector,
     SegmentId as CustomerSegmentId,  // Re-export for clarity
 };
-pub use error::{Error, Result};
+pub use error::{Error, ErrorClass, ErrorKind, Result};
+pub use events::{DomainEvent, EventBus, EventEnvelope, EventSubscriber};
+pub use identity::{normalize_phone, Channel, CustomerIdentity};
+pub use jitter::{JitterBuffer, JitterBufferConfig, JitterOutput, JitterStats};
+pub use samples::{SamplePool, SamplePoolStats, Samples};
 pub use transcript::{TranscriptResult, WordTimestamp};
 
 // Re-exports from new modules
@@ -47,10 +68,11 @@ pub use compliance::{
     Severity, SuggestedRewrite, ViolationCategory,
 };
 pub use domain_context::{Abbreviation, DomainContext};
+pub use gazetteer::{City, Gazetteer, GazetteerMatch, Locality, LocationGazetteer, PincodeRange};
 pub use language::{Language, Script};
 pub use llm_types::{
-    FinishReason, GenerateRequest, GenerateResponse, Message, Role, StreamChunk, TokenUsage,
-    ToolCall, ToolDefinition,
+    ConstraintSupport, FinishReason, GenerateRequest, GenerateResponse, LlmTask, Message,
+    OutputConstraint, Role, StreamChunk, TokenUsage, ToolCall, ToolDefinition,
 };
 pub use pii::{DetectionMethod, PIIEntity, PIISeverity, PIIType, RedactionStrategy};
 pub use voice_config::{VoiceConfig, VoiceGender, VoiceInfo};
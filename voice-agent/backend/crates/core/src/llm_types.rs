@@ -34,6 +34,12 @@ pub struct GenerateRequest {
     /// Presence penalty (-2.0 to 2.0)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub presence_penalty: Option<f32>,
+    /// Guided decoding constraint (honoured only by backends that support it)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constraint: Option<OutputConstraint>,
+    /// Kind of work this request does (used by routers to pick a backend)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task: Option<LlmTask>,
 }
 
 impl Default for GenerateRequest {
@@ -48,6 +54,8 @@ impl Default for GenerateRequest {
             model: None,
             frequency_penalty: None,
             presence_penalty: None,
+            constraint: None,
+            task: None,
         }
     }
 }
@@ -96,6 +104,140 @@ impl GenerateRequest {
         self.model = Some(model.into());
         self
     }
+
+    /// Constrain the output format (JSON, JSON Schema or GBNF grammar)
+    pub fn with_constraint(mut self, constraint: OutputConstraint) -> Self {
+        self.constraint = Some(constraint);
+        self
+    }
+
+    /// Tag the request with the kind of work it does
+    pub fn with_task(mut self, task: LlmTask) -> Self {
+        self.task = Some(task);
+        self
+    }
+}
+
+/// Kind of work an LLM request does
+///
+/// Lets a router send cheap background work (summaries, extraction) to a
+/// smaller model while keeping customer-facing turns on the best one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LlmTask {
+    /// Customer-facing conversation turn
+    #[default]
+    Dialogue,
+    /// Turn where tools are offered to the model
+    ToolCalling,
+    /// Memory summarization and compaction
+    Summarization,
+    /// Structured extraction, rewriting and correction
+    Extraction,
+}
+
+impl LlmTask {
+    /// All task kinds
+    pub const ALL: [LlmTask; 4] = [
+        LlmTask::Dialogue,
+        LlmTask::ToolCalling,
+        LlmTask::Summarization,
+        LlmTask::Extraction,
+    ];
+
+    /// Config/metrics name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LlmTask::Dialogue => "dialogue",
+            LlmTask::ToolCalling => "tool_calling",
+            LlmTask::Summarization => "summarization",
+            LlmTask::Extraction => "extraction",
+        }
+    }
+
+    /// Parse a config name
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "dialogue" | "dialog" | "chat" => Some(LlmTask::Dialogue),
+            "tool_calling" | "tools" => Some(LlmTask::ToolCalling),
+            "summarization" | "summary" => Some(LlmTask::Summarization),
+            "extraction" => Some(LlmTask::Extraction),
+            _ => None,
+        }
+    }
+}
+
+/// Constraint on generated output for guided (grammar-constrained) decoding
+///
+/// Backends that support it mask logits so the output is guaranteed to
+/// match; others ignore it and callers must validate the text themselves.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OutputConstraint {
+    /// Any syntactically valid JSON value
+    Json,
+    /// JSON matching a JSON Schema
+    JsonSchema {
+        /// Schema name (required by some APIs)
+        name: String,
+        schema: serde_json::Value,
+    },
+    /// GBNF grammar (llama.cpp format)
+    Grammar { gbnf: String },
+}
+
+impl OutputConstraint {
+    /// Create a JSON Schema constraint
+    pub fn json_schema(name: impl Into<String>, schema: serde_json::Value) -> Self {
+        Self::JsonSchema {
+            name: name.into(),
+            schema,
+        }
+    }
+
+    /// Create a GBNF grammar constraint
+    pub fn grammar(gbnf: impl Into<String>) -> Self {
+        Self::Grammar { gbnf: gbnf.into() }
+    }
+
+    /// Whether the constrained output is JSON
+    pub fn is_json(&self) -> bool {
+        matches!(self, Self::Json | Self::JsonSchema { .. })
+    }
+}
+
+/// Which output constraints a backend can enforce during decoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ConstraintSupport {
+    /// Free-form JSON mode
+    pub json: bool,
+    /// JSON Schema guided decoding
+    pub json_schema: bool,
+    /// GBNF grammars
+    pub grammar: bool,
+}
+
+impl ConstraintSupport {
+    /// No guided decoding
+    pub const NONE: Self = Self {
+        json: false,
+        json_schema: false,
+        grammar: false,
+    };
+
+    /// Whether any kind of guided decoding is available
+    pub fn any(&self) -> bool {
+        self.json || self.json_schema || self.grammar
+    }
+
+    /// Whether this constraint can be enforced as-is
+    pub fn supports(&self, constraint: &OutputConstraint) -> bool {
+        match constraint {
+            OutputConstraint::Json => self.json,
+            OutputConstraint::JsonSchema { .. } => self.json_schema,
+            OutputConstraint::Grammar { .. } => self.grammar,
+        }
+    }
 }
 
 /// Chat message
@@ -390,4 +532,34 @@ mod tests {
         let final_chunk = StreamChunk::final_chunk(FinishReason::Stop);
         assert!(final_chunk.is_final);
     }
+
+    #[test]
+    fn test_llm_task() {
+        let req = GenerateRequest::new("sys").with_task(LlmTask::Summarization);
+        assert_eq!(req.task, Some(LlmTask::Summarization));
+        assert_eq!(GenerateRequest::default().task, None);
+
+        for task in LlmTask::ALL {
+            assert_eq!(LlmTask::parse(task.as_str()), Some(task));
+        }
+        assert_eq!(LlmTask::parse("tool-calling"), Some(LlmTask::ToolCalling));
+        assert_eq!(LlmTask::parse("translation"), None);
+    }
+
+    #[test]
+    fn test_constraint_support() {
+        let schema = OutputConstraint::json_schema("slot", serde_json::json!({"type": "object"}));
+        let req = GenerateRequest::new("sys").with_constraint(schema.clone());
+        assert_eq!(req.constraint, Some(schema.clone()));
+        assert!(schema.is_json());
+
+        let ollama_like = ConstraintSupport {
+            json: true,
+            json_schema: true,
+            grammar: false,
+        };
+        assert!(ollama_like.supports(&schema));
+        assert!(!ollama_like.supports(&OutputConstraint::grammar("root ::= \"yes\"")));
+        assert!(!ConstraintSupport::NONE.any());
+    }
 }
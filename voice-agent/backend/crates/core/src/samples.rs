@@ -0,0 +1,340 @@
+//! Shared audio sample buffers
+//!
+//! Audio moves through VAD, STT, the processor chain and the transport
+//! several times per frame. [`Samples`] is a reference-counted view into an
+//! immutable sample buffer: cloning and slicing share the allocation
+//! instead of copying it, and a `Vec<f32>` converts without a copy.
+//!
+//! [`SamplePool`] recycles the backing allocations: a buffer frozen through
+//! a pool returns its `Vec` to the pool when the last `Samples` referencing
+//! it is dropped, so steady-state streaming stops allocating per chunk.
+
+use std::ops::{Bound, Deref, RangeBounds};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+
+/// Default number of idle buffers a pool keeps
+const DEFAULT_POOL_BUFFERS: usize = 64;
+
+/// Buffers larger than this (10s at 48kHz) are not kept for reuse
+const MAX_POOLED_CAPACITY: usize = 480_000;
+
+struct Storage {
+    samples: Vec<f32>,
+    pool: Option<Weak<PoolShared>>,
+}
+
+impl Drop for Storage {
+    fn drop(&mut self) {
+        if let Some(pool) = self.pool.as_ref().and_then(Weak::upgrade) {
+            pool.recycle(std::mem::take(&mut self.samples));
+        }
+    }
+}
+
+/// Reference-counted, sliceable view of audio samples
+#[derive(Clone)]
+pub struct Samples {
+    storage: Arc<Storage>,
+    start: usize,
+    end: usize,
+}
+
+impl Samples {
+    /// Empty buffer
+    pub fn empty() -> Self {
+        Vec::new().into()
+    }
+
+    /// Zero-copy view of `range` (relative to this view)
+    ///
+    /// # Panics
+    /// If the range is out of bounds, like slice indexing.
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Included(&s) => s,
+            Bound::Excluded(&s) => s + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&e) => e + 1,
+            Bound::Excluded(&e) => e,
+            Bound::Unbounded => len,
+        };
+        assert!(
+            start <= end && end <= len,
+            "slice {}..{} out of range for {} samples",
+            start,
+            end,
+            len
+        );
+
+        Self {
+            storage: self.storage.clone(),
+            start: self.start + start,
+            end: self.start + end,
+        }
+    }
+
+    /// Owned copy of the samples, reusing the allocation when unshared
+    pub fn into_vec(self) -> Vec<f32> {
+        let (start, end) = (self.start, self.end);
+        match Arc::try_unwrap(self.storage) {
+            Ok(mut storage) => {
+                let mut samples = std::mem::take(&mut storage.samples);
+                samples.truncate(end);
+                samples.drain(..start);
+                samples
+            },
+            Err(storage) => storage.samples[start..end].to_vec(),
+        }
+    }
+
+    /// Whether two views share one allocation
+    pub fn shares_buffer(&self, other: &Samples) -> bool {
+        Arc::ptr_eq(&self.storage, &other.storage)
+    }
+}
+
+impl Deref for Samples {
+    type Target = [f32];
+
+    fn deref(&self) -> &[f32] {
+        &self.storage.samples[self.start..self.end]
+    }
+}
+
+impl AsRef<[f32]> for Samples {
+    fn as_ref(&self) -> &[f32] {
+        self
+    }
+}
+
+impl From<Vec<f32>> for Samples {
+    fn from(samples: Vec<f32>) -> Self {
+        let end = samples.len();
+        Self {
+            storage: Arc::new(Storage {
+                samples,
+                pool: None,
+            }),
+            start: 0,
+            end,
+        }
+    }
+}
+
+impl From<&[f32]> for Samples {
+    fn from(samples: &[f32]) -> Self {
+        samples.to_vec().into()
+    }
+}
+
+impl Default for Samples {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl PartialEq for Samples {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl std::fmt::Debug for Samples {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Samples").field("len", &self.len()).finish()
+    }
+}
+
+/// Sample pool statistics
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SamplePoolStats {
+    /// Buffers served from the pool
+    pub reused: u64,
+    /// Buffers freshly allocated
+    pub allocated: u64,
+    /// Idle buffers currently held
+    pub idle: usize,
+}
+
+struct PoolShared {
+    free: Mutex<Vec<Vec<f32>>>,
+    max_buffers: usize,
+    reused: AtomicU64,
+    allocated: AtomicU64,
+}
+
+impl PoolShared {
+    fn recycle(&self, mut samples: Vec<f32>) {
+        if samples.capacity() == 0 || samples.capacity() > MAX_POOLED_CAPACITY {
+            return;
+        }
+        samples.clear();
+        let mut free = self.free.lock().unwrap_or_else(|e| e.into_inner());
+        if free.len() < self.max_buffers {
+            free.push(samples);
+        }
+    }
+}
+
+/// Pool of reusable sample allocations
+///
+/// Cheap to clone; clones share the same buffers.
+#[derive(Clone)]
+pub struct SamplePool {
+    shared: Arc<PoolShared>,
+}
+
+impl SamplePool {
+    /// Pool keeping at most `max_buffers` idle buffers
+    pub fn new(max_buffers: usize) -> Self {
+        Self {
+            shared: Arc::new(PoolShared {
+                free: Mutex::new(Vec::new()),
+                max_buffers,
+                reused: AtomicU64::new(0),
+                allocated: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    /// Empty buffer with room for at least `capacity` samples
+    pub fn take(&self, capacity: usize) -> Vec<f32> {
+        let reused = {
+            let mut free = self.shared.free.lock().unwrap_or_else(|e| e.into_inner());
+            free.iter()
+                .position(|buffer| buffer.capacity() >= capacity)
+                .map(|index| free.swap_remove(index))
+                .or_else(|| free.pop())
+        };
+
+        match reused {
+            Some(mut buffer) => {
+                self.shared.reused.fetch_add(1, Ordering::Relaxed);
+                buffer.reserve(capacity);
+                buffer
+            },
+            None => {
+                self.shared.allocated.fetch_add(1, Ordering::Relaxed);
+                Vec::with_capacity(capacity)
+            },
+        }
+    }
+
+    /// Share `samples`; the allocation returns to this pool once unreferenced
+    pub fn freeze(&self, samples: Vec<f32>) -> Samples {
+        let end = samples.len();
+        Samples {
+            storage: Arc::new(Storage {
+                samples,
+                pool: Some(Arc::downgrade(&self.shared)),
+            }),
+            start: 0,
+            end,
+        }
+    }
+
+    /// Copy `samples` into a pooled buffer
+    pub fn copy_from(&self, samples: &[f32]) -> Samples {
+        let mut buffer = self.take(samples.len());
+        buffer.extend_from_slice(samples);
+        self.freeze(buffer)
+    }
+
+    /// Return a buffer that was never frozen
+    pub fn recycle(&self, samples: Vec<f32>) {
+        self.shared.recycle(samples);
+    }
+
+    pub fn stats(&self) -> SamplePoolStats {
+        SamplePoolStats {
+            reused: self.shared.reused.load(Ordering::Relaxed),
+            allocated: self.shared.allocated.load(Ordering::Relaxed),
+            idle: self
+                .shared
+                .free
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .len(),
+        }
+    }
+}
+
+impl Default for SamplePool {
+    fn default() -> Self {
+        Self::new(DEFAULT_POOL_BUFFERS)
+    }
+}
+
+impl std::fmt::Debug for SamplePool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SamplePool")
+            .field("max_buffers", &self.shared.max_buffers)
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slices_share_allocation() {
+        let samples: Samples = vec![0.0, 0.1, 0.2, 0.3, 0.4].into();
+        let middle = samples.slice(1..4);
+        assert_eq!(&*middle, &[0.1, 0.2, 0.3]);
+        assert!(middle.shares_buffer(&samples));
+
+        let inner = middle.slice(1..);
+        assert_eq!(&*inner, &[0.2, 0.3]);
+        assert_eq!(inner.clone(), inner);
+
+        // Unshared views hand back their allocation
+        drop((samples, middle));
+        assert_eq!(inner.into_vec(), vec![0.2, 0.3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_out_of_range() {
+        let samples: Samples = vec![0.0; 4].into();
+        let _ = samples.slice(2..5);
+    }
+
+    #[test]
+    fn test_pool_recycles_after_last_reference() {
+        let pool = SamplePool::new(2);
+        let frozen = pool.copy_from(&[0.5; 160]);
+        let view = frozen.slice(..80);
+        drop(frozen);
+        assert_eq!(pool.stats().idle, 0, "still referenced by the slice");
+
+        drop(view);
+        assert_eq!(pool.stats().idle, 1);
+
+        let buffer = pool.take(160);
+        assert!(buffer.is_empty());
+        assert!(buffer.capacity() >= 160);
+        assert_eq!(
+            pool.stats(),
+            SamplePoolStats {
+                reused: 1,
+                allocated: 1,
+                idle: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_pool_bounded() {
+        let pool = SamplePool::new(1);
+        pool.recycle(vec![0.0; 10]);
+        pool.recycle(vec![0.0; 10]);
+        pool.recycle(Vec::with_capacity(MAX_POOLED_CAPACITY + 1));
+        assert_eq!(pool.stats().idle, 1);
+    }
+}
@@ -1,6 +1,8 @@
 //! Language Model traits
 
-use crate::{GenerateRequest, GenerateResponse, Result, StreamChunk, ToolDefinition};
+use crate::{
+    ConstraintSupport, GenerateRequest, GenerateResponse, Result, StreamChunk, ToolDefinition,
+};
 use async_trait::async_trait;
 use futures::Stream;
 use std::pin::Pin;
@@ -68,6 +70,14 @@ pub trait LanguageModel: Send + Sync + 'static {
     /// Get model name for logging
     fn model_name(&self) -> &str;
 
+    /// Output constraints this model can enforce during decoding
+    ///
+    /// `GenerateRequest::constraint` is ignored by models without support,
+    /// so callers should still validate the output.
+    fn constraint_support(&self) -> ConstraintSupport {
+        ConstraintSupport::NONE
+    }
+
     /// Get context window size in tokens
     fn context_size(&self) -> usize {
         4096 // Default, implementations should override
@@ -102,8 +102,8 @@ pub use fsm::{
 };
 // P3 FIX: Export Tool trait and types
 pub use tool::{
-    validate_property, ContentBlock, ErrorCode, InputSchema, PropertySchema, Tool, ToolError,
-    ToolInput, ToolOutput, ToolSchema,
+    normalize_format, validate_property, ContentBlock, ErrorCode, InputSchema, PropertySchema,
+    Tool, ToolError, ToolInput, ToolOutput, ToolSchema,
 };
 // P13 FIX: Export ToolFactory trait for domain-agnostic tool creation
 pub use tool_factory::{ToolFactory, ToolFactoryError, ToolFactoryRegistry, ToolMetadata};
@@ -1,11 +1,12 @@
 //! Pipeline processing traits
 
 use crate::transcript::TranscriptResult;
-use crate::{AudioFrame, Language, Result};
+use crate::{AudioFrame, AvatarCues, Language, Result, SamplePool};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
+use tokio::sync::mpsc;
 
 /// Frame types that flow through the pipeline
 ///
@@ -47,6 +48,17 @@ pub enum Frame {
         transcript: Option<String>,
     },
 
+    /// Agent speech cut short by barge-in
+    SpokenUpTo {
+        /// Text the caller actually heard before playback stopped
+        text: String,
+        /// Playback position where audio stopped
+        audio_position_ms: u64,
+    },
+
+    /// Avatar cues for the audio output that follows
+    AvatarCues(AvatarCues),
+
     /// Voice activity detected (speech started)
     VoiceStart,
 
@@ -174,6 +186,8 @@ impl Frame {
             Frame::Sentence { .. } => "sentence",
             Frame::AudioOutput(_) => "audio_output",
             Frame::BargeIn { .. } => "barge_in",
+            Frame::SpokenUpTo { .. } => "spoken_up_to",
+            Frame::AvatarCues(_) => "avatar_cues",
             Frame::VoiceStart => "voice_start",
             Frame::VoiceEnd { .. } => "voice_end",
             Frame::EndOfStream => "end_of_stream",
@@ -203,6 +217,11 @@ pub struct ProcessorContext {
     pub language: Option<Language>,
     /// Custom metadata
     pub metadata: HashMap<String, serde_json::Value>,
+    /// Reusable audio buffers shared by the processors of a chain
+    pub sample_pool: SamplePool,
+    /// Downstream channel for frames produced after `process` returned,
+    /// set by a running chain
+    pub output: Option<mpsc::Sender<Frame>>,
     /// Processor-specific state
     state: HashMap<String, serde_json::Value>,
 }
@@ -320,6 +320,9 @@ pub struct PropertySchema {
     /// Maximum value (for number/integer)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub maximum: Option<f64>,
+    /// Named string format (`phone`, `pincode`, `email`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
 }
 
 impl PropertySchema {
@@ -332,6 +335,7 @@ impl PropertySchema {
             enum_values: None,
             minimum: None,
             maximum: None,
+            format: None,
         }
     }
 
@@ -344,6 +348,7 @@ impl PropertySchema {
             enum_values: None,
             minimum: None,
             maximum: None,
+            format: None,
         }
     }
 
@@ -356,6 +361,7 @@ impl PropertySchema {
             enum_values: None,
             minimum: None,
             maximum: None,
+            format: None,
         }
     }
 
@@ -368,6 +374,7 @@ impl PropertySchema {
             enum_values: None,
             minimum: None,
             maximum: None,
+            format: None,
         }
     }
 
@@ -380,6 +387,7 @@ impl PropertySchema {
             enum_values: Some(values),
             minimum: None,
             maximum: None,
+            format: None,
         }
     }
 
@@ -395,6 +403,12 @@ impl PropertySchema {
         self.maximum = Some(max);
         self
     }
+
+    /// Add a named string format constraint
+    pub fn with_format(mut self, format: impl Into<String>) -> Self {
+        self.format = Some(format.into());
+        self
+    }
 }
 
 /// Tool trait for MCP-compatible tool implementations
@@ -516,9 +530,65 @@ pub fn validate_property(
         }
     }
 
+    // Check string format
+    if let (Some(format), Some(s)) = (&schema.format, value.as_str()) {
+        if normalize_format(format, s).is_none() {
+            return Err(ToolError::invalid_params(format!(
+                "Field '{}' must be a valid {}, got '{}'",
+                name, format, s
+            )));
+        }
+    }
+
     Ok(())
 }
 
+/// Canonical form of `value` for a named string format, `None` if invalid
+///
+/// - `phone`: 10-digit Indian mobile number; spaces, dashes, a leading `0`
+///   or `+91` are stripped
+/// - `pincode`: 6-digit postal code
+/// - `email`: `name@domain.tld`, lowercased
+///
+/// Unknown formats accept any value unchanged.
+pub fn normalize_format(format: &str, value: &str) -> Option<String> {
+    let value = value.trim();
+    match format {
+        "phone" => {
+            let separator = |c: char| matches!(c, ' ' | '-' | '+' | '(' | ')');
+            if value.chars().any(|c| !c.is_ascii_digit() && !separator(c)) {
+                return None;
+            }
+            let digits: String = value.chars().filter(char::is_ascii_digit).collect();
+            let local = match digits.len() {
+                10 => digits.as_str(),
+                11 if digits.starts_with('0') => &digits[1..],
+                12 if digits.starts_with("91") => &digits[2..],
+                _ => return None,
+            };
+            local
+                .starts_with(['6', '7', '8', '9'])
+                .then(|| local.to_string())
+        },
+        "pincode" => {
+            let digits: String = value.chars().filter(|c| !c.is_whitespace()).collect();
+            (digits.len() == 6
+                && digits.chars().all(|c| c.is_ascii_digit())
+                && !digits.starts_with('0'))
+            .then_some(digits)
+        },
+        "email" => {
+            let (local, domain) = value.split_once('@')?;
+            let valid = !local.is_empty()
+                && !value.contains(char::is_whitespace)
+                && domain.split('.').count() >= 2
+                && domain.split('.').all(|part| !part.is_empty());
+            valid.then(|| value.to_lowercase())
+        },
+        _ => Some(value.to_string()),
+    }
+}
+
 /// Get a human-readable type name for a JSON value
 fn json_type_name(value: &Value) -> &'static str {
     match value {
@@ -591,6 +661,30 @@ mod tests {
         assert!(validate_property("field", &json!(15.0), &schema).is_err());
     }
 
+    #[test]
+    fn test_validate_property_format() {
+        let schema = PropertySchema::string("test").with_format("phone");
+        assert!(validate_property("phone", &json!("98765 43210"), &schema).is_ok());
+        assert!(validate_property("phone", &json!("12345"), &schema).is_err());
+
+        let normalized = |format, value| normalize_format(format, value);
+        assert_eq!(
+            normalized("phone", "+91 98765-43210").as_deref(),
+            Some("9876543210")
+        );
+        assert_eq!(
+            normalized("phone", "09876543210").as_deref(),
+            Some("9876543210")
+        );
+        assert_eq!(normalized("phone", "1234567890"), None);
+        assert_eq!(normalized("pincode", "400 053").as_deref(), Some("400053"));
+        assert_eq!(
+            normalized("email", "Priya@Example.com").as_deref(),
+            Some("priya@example.com")
+        );
+        assert_eq!(normalized("email", "priya@example"), None);
+    }
+
     #[test]
     fn test_error_code_serialization() {
         let code: i32 = ErrorCode::InvalidParams.into();
@@ -11,10 +11,13 @@ use tokio::sync::mpsc;
 
 use voice_agent_core::{
     llm_types::{FinishReason as CoreFinishReason, TokenUsage},
-    Error, GenerateRequest, GenerateResponse, LanguageModel, Result, StreamChunk, ToolDefinition,
+    ConstraintSupport, Error, GenerateRequest, GenerateResponse, LanguageModel, Result,
+    StreamChunk, ToolDefinition,
 };
 
 use crate::backend::{FinishReason as BackendFinishReason, LlmBackend};
+use crate::constraint::{resolve_constraint, tool_call_constraint};
+use crate::tool_parser::{extract_tool_call, tool_call_reask_prompt};
 
 /// Adapter that wraps an LlmBackend to implement the core LanguageModel trait.
 ///
@@ -93,9 +96,21 @@ impl LanguageModel for LanguageModelAdapter {
         let messages = Self::convert_messages(&request);
         let model = self.model_name.clone();
 
-        self.backend
-            .generate(&messages)
-            .await
+        // Use guided decoding when the backend can enforce the constraint
+        let constraint = request
+            .constraint
+            .as_ref()
+            .and_then(|c| resolve_constraint(c, self.backend.constraint_support()));
+        if request.constraint.is_some() && constraint.is_none() {
+            tracing::debug!(model = %model, "Backend cannot enforce output constraint, generating unconstrained");
+        }
+
+        let result = match constraint {
+            Some(ref constraint) => self.backend.generate_constrained(&messages, constraint).await,
+            None => self.backend.generate(&messages).await,
+        };
+
+        result
             .map(|result| GenerateResponse {
                 text: result.text,
                 finish_reason: Self::convert_finish_reason(result.finish_reason),
@@ -185,45 +200,77 @@ impl LanguageModel for LanguageModelAdapter {
 
         let model = self.model_name.clone();
         let tool_count = tools.len();
+        let map_err = |e: crate::LlmError| {
+            Error::Llm(format!(
+                "generate_with_tools failed (model={}, tools={}): {}",
+                model, tool_count, e
+            ))
+        };
+
+        let mut result = self.backend.generate(&messages).await.map_err(map_err)?;
+        let mut extraction = extract_tool_call(&result.text, tools);
+
+        // Small models often emit malformed tool calls; ask once for a corrected one,
+        // grammar-constrained to a valid call when the backend supports it
+        if let Err(error) = &extraction.call {
+            tracing::warn!(model = %model, error = %error, "Malformed tool call, re-asking");
+            messages.push(crate::prompt::Message::assistant(result.text.clone()));
+            messages.push(crate::prompt::Message::user(tool_call_reask_prompt(
+                error, tools,
+            )));
+            let constraint = resolve_constraint(
+                &tool_call_constraint(tools),
+                self.backend.constraint_support(),
+            );
+            result = match constraint {
+                Some(ref constraint) => {
+                    let mut result = self
+                        .backend
+                        .generate_constrained(&messages, constraint)
+                        .await
+                        .map_err(map_err)?;
+                    // Constrained output is the bare call JSON; wrap it in the text format
+                    result.text = format!("[TOOL_CALL: {}]", result.text.trim());
+                    result
+                },
+                None => self.backend.generate(&messages).await.map_err(map_err)?,
+            };
+            extraction = extract_tool_call(&result.text, tools);
+        }
 
-        self.backend
-            .generate(&messages)
-            .await
-            .map(|result| {
-                // Parse tool calls from response text
-                let tool_calls: Vec<voice_agent_core::llm_types::ToolCall> =
-                    crate::prompt::parse_tool_call(&result.text)
-                        .map(|tc| voice_agent_core::llm_types::ToolCall {
-                            id: uuid::Uuid::new_v4().to_string(),
-                            name: tc.name,
-                            arguments: tc
-                                .arguments
-                                .as_object()
-                                .map(|o| o.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
-                                .unwrap_or_default(),
-                        })
-                        .into_iter()
-                        .collect();
-
-                let finish_reason = if !tool_calls.is_empty() {
-                    CoreFinishReason::ToolCalls
-                } else {
-                    Self::convert_finish_reason(result.finish_reason)
-                };
-
-                GenerateResponse {
-                    text: result.text,
-                    finish_reason,
-                    usage: Some(TokenUsage::new(0, result.tokens as u32)),
-                    tool_calls,
-                }
-            })
-            .map_err(|e| {
-                Error::Llm(format!(
-                    "generate_with_tools failed (model={}, tools={}): {}",
-                    model, tool_count, e
-                ))
-            })
+        let tool_calls: Vec<voice_agent_core::llm_types::ToolCall> = match extraction.call {
+            Ok(call) => call
+                .map(|tc| voice_agent_core::llm_types::ToolCall {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    name: tc.name,
+                    arguments: tc
+                        .arguments
+                        .as_object()
+                        .map(|o| o.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+                        .unwrap_or_default(),
+                })
+                .into_iter()
+                .collect(),
+            Err(error) => {
+                tracing::warn!(model = %model, error = %error, "Tool call still malformed after re-ask, answering without tools");
+                // Never surface broken tool-call markup as the response
+                result.text = extraction.text;
+                Vec::new()
+            },
+        };
+
+        let finish_reason = if !tool_calls.is_empty() {
+            CoreFinishReason::ToolCalls
+        } else {
+            Self::convert_finish_reason(result.finish_reason)
+        };
+
+        Ok(GenerateResponse {
+            text: result.text,
+            finish_reason,
+            usage: Some(TokenUsage::new(0, result.tokens as u32)),
+            tool_calls,
+        })
     }
 
     async fn is_available(&self) -> bool {
@@ -234,6 +281,10 @@ impl LanguageModel for LanguageModelAdapter {
         &self.model_name
     }
 
+    fn constraint_support(&self) -> ConstraintSupport {
+        self.backend.constraint_support()
+    }
+
     fn context_size(&self) -> usize {
         // Default context size, could be made configurable
         4096
@@ -251,24 +302,45 @@ mod tests {
     // Mock backend for testing
     struct MockBackend {
         response: String,
+        reask_response: Option<String>,
+        constrained_response: Option<String>,
     }
 
     impl MockBackend {
         fn new(response: &str) -> Self {
             Self {
                 response: response.to_string(),
+                reask_response: None,
+                constrained_response: None,
             }
         }
+
+        fn with_constrained(mut self, response: &str) -> Self {
+            self.constrained_response = Some(response.to_string());
+            self
+        }
+
+        fn with_reask(mut self, response: &str) -> Self {
+            self.reask_response = Some(response.to_string());
+            self
+        }
     }
 
     #[async_trait]
     impl LlmBackend for MockBackend {
         async fn generate(
             &self,
-            _messages: &[crate::prompt::Message],
+            messages: &[crate::prompt::Message],
         ) -> std::result::Result<crate::backend::GenerationResult, crate::LlmError> {
+            let is_reask = messages
+                .last()
+                .is_some_and(|m| m.content.contains("previous tool call"));
+            let text = match (&self.reask_response, is_reask) {
+                (Some(reask), true) => reask.clone(),
+                _ => self.response.clone(),
+            };
             Ok(crate::backend::GenerationResult {
-                text: self.response.clone(),
+                text,
                 tokens: 10,
                 time_to_first_token_ms: 50,
                 total_time_ms: 100,
@@ -305,6 +377,23 @@ mod tests {
         fn model_name(&self) -> &str {
             "mock-model"
         }
+
+        fn constraint_support(&self) -> ConstraintSupport {
+            ConstraintSupport {
+                json_schema: self.constrained_response.is_some(),
+                ..ConstraintSupport::NONE
+            }
+        }
+
+        async fn generate_constrained(
+            &self,
+            messages: &[crate::prompt::Message],
+            _constraint: &voice_agent_core::OutputConstraint,
+        ) -> std::result::Result<crate::backend::GenerationResult, crate::LlmError> {
+            let mut result = self.generate(messages).await?;
+            result.text = self.constrained_response.clone().unwrap_or_default();
+            Ok(result)
+        }
     }
 
     #[tokio::test]
@@ -332,4 +421,63 @@ mod tests {
         let adapter = LanguageModelAdapter::new(backend);
         assert_eq!(adapter.model_name(), "mock-model");
     }
+
+    #[tokio::test]
+    async fn test_generate_with_tools_reasks_on_malformed_call() {
+        let tools = vec![crate::prompt::ToolBuilder::new("get_rates", "Get rates")
+            .param("amount", "number", "Loan amount", true)
+            .build()];
+        let request = GenerateRequest::new("You are helpful").with_user_message("Rates?");
+
+        let backend = MockBackend::new(r#"[TOOL_CALL: {"name": "get_rates", "arguments": {"amount": "lots"}}]"#)
+            .with_reask(r#"[TOOL_CALL: {"name": "get_rates", "arguments": {"amount": 500000}}]"#);
+        let response = LanguageModelAdapter::new(backend)
+            .generate_with_tools(request.clone(), &tools)
+            .await
+            .unwrap();
+        assert_eq!(response.finish_reason, CoreFinishReason::ToolCalls);
+        assert_eq!(response.tool_calls[0].arguments["amount"], 500000);
+
+        // Still malformed after re-ask: fall back to plain text without markup
+        let backend = MockBackend::new("Let me check. [TOOL_CALL: {broken");
+        let response = LanguageModelAdapter::new(backend)
+            .generate_with_tools(request, &tools)
+            .await
+            .unwrap();
+        assert!(response.tool_calls.is_empty());
+        assert_eq!(response.text, "Let me check.");
+    }
+
+    #[tokio::test]
+    async fn test_constrained_generation() {
+        let request = GenerateRequest::new("Extract").with_constraint(
+            voice_agent_core::OutputConstraint::json_schema("slot", serde_json::json!({"type": "object"})),
+        );
+
+        // Unsupported: constraint ignored
+        let response = LanguageModelAdapter::new(MockBackend::new("free text"))
+            .generate(request.clone())
+            .await
+            .unwrap();
+        assert_eq!(response.text, "free text");
+
+        let adapter = LanguageModelAdapter::new(
+            MockBackend::new("free text").with_constrained(r#"{"confirmed": true}"#),
+        );
+        assert!(adapter.constraint_support().json_schema);
+        let response = adapter.generate(request).await.unwrap();
+        assert_eq!(response.text, r#"{"confirmed": true}"#);
+
+        // Re-ask for a malformed tool call is constrained to a valid call
+        let tools = vec![crate::prompt::ToolBuilder::new("get_rates", "Get rates")
+            .param("amount", "number", "Loan amount", true)
+            .build()];
+        let backend = MockBackend::new("[TOOL_CALL: {name: get_rates}]")
+            .with_constrained(r#"{"name": "get_rates", "arguments": {"amount": 100000}}"#);
+        let response = LanguageModelAdapter::new(backend)
+            .generate_with_tools(GenerateRequest::new("sys").with_user_message("rates"), &tools)
+            .await
+            .unwrap();
+        assert_eq!(response.tool_calls[0].arguments["amount"], 100000);
+    }
 }
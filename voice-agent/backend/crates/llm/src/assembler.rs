@@ -0,0 +1,556 @@
+//! Prompt Assembly under a Token Budget
+//!
+//! The prompt is built from typed sections (system prompt, dialogue state,
+//! memory, RAG, history, ...). Each section has a priority and a truncation
+//! strategy. When the prompt exceeds its budget, the lowest-priority
+//! sections are trimmed first: history drops its oldest messages, memory is
+//! summarized extractively, RAG is cut, and optional guidance is dropped.
+//!
+//! Every assembly produces an [`AssemblyReport`] describing what was kept,
+//! trimmed or dropped, for debugging prompt regressions.
+
+use std::fmt;
+
+use crate::prompt::{Message, Role};
+
+/// Estimate tokens for a piece of text
+///
+/// Devanagari-heavy text tokenizes at roughly 2 graphemes per token,
+/// Latin text at roughly 4.
+pub fn estimate_text_tokens(content: &str) -> usize {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let grapheme_count = content.graphemes(true).count();
+    let devanagari_count = content
+        .chars()
+        .filter(|c| ('\u{0900}'..='\u{097F}').contains(c))
+        .count();
+
+    if devanagari_count > grapheme_count / 3 {
+        grapheme_count.max(1) / 2
+    } else {
+        grapheme_count.max(1) / 4
+    }
+}
+
+/// What a prompt section contains
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SectionKind {
+    /// Persona and system instructions
+    System,
+    /// Tool definitions for text-based tool calling
+    Tools,
+    /// Result of a tool call this turn
+    ToolResult,
+    /// Slots collected by dialogue state tracking
+    DialogueState,
+    /// Current stage guidance and objection handling
+    Guidance,
+    /// Customer profile
+    CustomerProfile,
+    /// Retrieved knowledge (RAG)
+    Knowledge,
+    /// Agentic memory (core facts, summaries, archival recall)
+    Memory,
+    /// Personalization hints
+    Personalization,
+    /// Few-shot exchanges retrieved for this turn
+    Examples,
+    /// Untyped context
+    Context,
+    /// Prior conversation messages
+    History,
+    /// Current user message
+    User,
+}
+
+impl SectionKind {
+    /// Default priority (higher is kept longer)
+    pub fn default_priority(self) -> u8 {
+        match self {
+            Self::System | Self::User => 100,
+            Self::ToolResult => 90,
+            Self::DialogueState => 80,
+            Self::Tools => 75,
+            Self::Guidance => 70,
+            Self::CustomerProfile => 65,
+            Self::Knowledge => 60,
+            Self::Context => 55,
+            Self::Memory => 50,
+            Self::Examples => 45,
+            Self::History => 40,
+            Self::Personalization => 30,
+        }
+    }
+
+    /// Default truncation strategy
+    pub fn default_strategy(self) -> TruncationStrategy {
+        match self {
+            Self::System | Self::User | Self::ToolResult | Self::Tools => TruncationStrategy::Never,
+            Self::DialogueState | Self::Memory | Self::CustomerProfile => {
+                TruncationStrategy::Summarize
+            },
+            Self::Knowledge | Self::Context | Self::Examples => TruncationStrategy::TruncateTail,
+            Self::History => TruncationStrategy::DropOldest,
+            Self::Guidance | Self::Personalization => TruncationStrategy::Drop,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::System => "system",
+            Self::Tools => "tools",
+            Self::ToolResult => "tool_result",
+            Self::DialogueState => "dialogue_state",
+            Self::Guidance => "guidance",
+            Self::CustomerProfile => "customer_profile",
+            Self::Knowledge => "knowledge",
+            Self::Memory => "memory",
+            Self::Personalization => "personalization",
+            Self::Examples => "examples",
+            Self::Context => "context",
+            Self::History => "history",
+            Self::User => "user",
+        }
+    }
+}
+
+/// How a section shrinks when the prompt is over budget
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TruncationStrategy {
+    /// Always kept whole
+    Never,
+    /// Removed entirely
+    Drop,
+    /// Text cut from the end
+    TruncateTail,
+    /// Headings and the first sentence of each paragraph/bullet are kept
+    Summarize,
+    /// Oldest messages removed first (history)
+    DropOldest,
+}
+
+/// A typed block of the prompt
+#[derive(Debug, Clone)]
+pub struct PromptSection {
+    pub kind: SectionKind,
+    pub priority: u8,
+    pub strategy: TruncationStrategy,
+    /// Sections shrunk below this are dropped instead
+    pub min_tokens: usize,
+    pub messages: Vec<Message>,
+}
+
+impl PromptSection {
+    /// A single system message section with the kind's defaults
+    pub fn system(kind: SectionKind, content: impl Into<String>) -> Self {
+        Self::from_messages(kind, vec![Message::system(content)])
+    }
+
+    pub fn from_messages(kind: SectionKind, messages: Vec<Message>) -> Self {
+        Self {
+            kind,
+            priority: kind.default_priority(),
+            strategy: kind.default_strategy(),
+            min_tokens: 16,
+            messages,
+        }
+    }
+
+    pub fn with_priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn with_strategy(mut self, strategy: TruncationStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    pub fn tokens(&self) -> usize {
+        self.messages
+            .iter()
+            .map(|m| estimate_text_tokens(&m.content))
+            .sum()
+    }
+}
+
+/// What happened to a section during assembly
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SectionAction {
+    Kept,
+    Truncated,
+    Summarized,
+    /// Oldest history messages removed
+    Trimmed {
+        dropped_messages: usize,
+    },
+    Dropped,
+}
+
+/// Per-section assembly outcome
+#[derive(Debug, Clone)]
+pub struct SectionReport {
+    pub kind: SectionKind,
+    pub priority: u8,
+    pub original_tokens: usize,
+    pub final_tokens: usize,
+    pub action: SectionAction,
+}
+
+/// Outcome of a prompt assembly
+#[derive(Debug, Clone)]
+pub struct AssemblyReport {
+    pub budget: usize,
+    pub original_tokens: usize,
+    pub final_tokens: usize,
+    /// Sections in prompt order
+    pub sections: Vec<SectionReport>,
+}
+
+impl AssemblyReport {
+    /// Whether anything was trimmed or dropped
+    pub fn was_trimmed(&self) -> bool {
+        self.sections
+            .iter()
+            .any(|s| s.action != SectionAction::Kept)
+    }
+
+    /// Whether the prompt still exceeds the budget (only untruncatable sections left)
+    pub fn over_budget(&self) -> bool {
+        self.final_tokens > self.budget
+    }
+}
+
+impl fmt::Display for AssemblyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{} tokens (from {})",
+            self.final_tokens, self.budget, self.original_tokens
+        )?;
+        for section in &self.sections {
+            write!(
+                f,
+                "; {}[p{}] {}->{}",
+                section.kind.as_str(),
+                section.priority,
+                section.original_tokens,
+                section.final_tokens
+            )?;
+            match section.action {
+                SectionAction::Kept => {},
+                SectionAction::Truncated => write!(f, " truncated")?,
+                SectionAction::Summarized => write!(f, " summarized")?,
+                SectionAction::Trimmed { dropped_messages } => {
+                    write!(f, " dropped {} msgs", dropped_messages)?
+                },
+                SectionAction::Dropped => write!(f, " dropped")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Assembles typed sections into messages within a token budget
+#[derive(Debug, Clone, Default)]
+pub struct PromptAssembler {
+    sections: Vec<PromptSection>,
+}
+
+impl PromptAssembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a section (prompt order is insertion order)
+    pub fn push(&mut self, section: PromptSection) {
+        if section.messages.iter().any(|m| !m.content.is_empty()) {
+            self.sections.push(section);
+        }
+    }
+
+    pub fn with_section(mut self, section: PromptSection) -> Self {
+        self.push(section);
+        self
+    }
+
+    pub fn sections(&self) -> &[PromptSection] {
+        &self.sections
+    }
+
+    /// Total estimated tokens before trimming
+    pub fn tokens(&self) -> usize {
+        self.sections.iter().map(PromptSection::tokens).sum()
+    }
+
+    /// Messages without enforcing any budget
+    pub fn into_messages(self) -> Vec<Message> {
+        self.sections.into_iter().flat_map(|s| s.messages).collect()
+    }
+
+    /// Trim sections to fit `budget` tokens and flatten to messages
+    ///
+    /// Sections are trimmed lowest priority first; among equal priorities,
+    /// the later section goes first. Sections with `Never` are kept even if
+    /// the budget cannot be met (see [`AssemblyReport::over_budget`]).
+    pub fn assemble(self, budget: usize) -> (Vec<Message>, AssemblyReport) {
+        let mut sections = self.sections;
+        let mut reports: Vec<SectionReport> = sections
+            .iter()
+            .map(|s| {
+                let tokens = s.tokens();
+                SectionReport {
+                    kind: s.kind,
+                    priority: s.priority,
+                    original_tokens: tokens,
+                    final_tokens: tokens,
+                    action: SectionAction::Kept,
+                }
+            })
+            .collect();
+        let original_tokens: usize = reports.iter().map(|r| r.original_tokens).sum();
+
+        let mut order: Vec<usize> = (0..sections.len())
+            .filter(|&i| sections[i].strategy != TruncationStrategy::Never)
+            .collect();
+        order.sort_by(|&a, &b| {
+            sections[a]
+                .priority
+                .cmp(&sections[b].priority)
+                .then(b.cmp(&a))
+        });
+
+        let mut total = original_tokens;
+        for i in order {
+            if total <= budget {
+                break;
+            }
+            let overflow = total - budget;
+            let before = reports[i].final_tokens;
+            let action = shrink(&mut sections[i], overflow);
+            let after = sections[i].tokens();
+            reports[i].final_tokens = after;
+            reports[i].action = action;
+            total = total - before + after;
+        }
+
+        let report = AssemblyReport {
+            budget,
+            original_tokens,
+            final_tokens: total,
+            sections: reports,
+        };
+        let messages = sections
+            .into_iter()
+            .flat_map(|s| s.messages)
+            .filter(|m| !(m.role == Role::System && m.content.is_empty()))
+            .collect();
+        (messages, report)
+    }
+}
+
+/// Shrink a section by at least `overflow` tokens if its strategy allows
+fn shrink(section: &mut PromptSection, overflow: usize) -> SectionAction {
+    let tokens = section.tokens();
+    let target = tokens.saturating_sub(overflow);
+
+    match section.strategy {
+        TruncationStrategy::Never => SectionAction::Kept,
+        TruncationStrategy::Drop => {
+            section.messages.clear();
+            SectionAction::Dropped
+        },
+        TruncationStrategy::DropOldest => {
+            let mut dropped = 0;
+            let mut remaining = tokens;
+            while remaining > target && !section.messages.is_empty() {
+                let message = section.messages.remove(0);
+                remaining -= estimate_text_tokens(&message.content).min(remaining);
+                dropped += 1;
+            }
+            if section.messages.is_empty() {
+                SectionAction::Dropped
+            } else {
+                SectionAction::Trimmed {
+                    dropped_messages: dropped,
+                }
+            }
+        },
+        TruncationStrategy::TruncateTail | TruncationStrategy::Summarize => {
+            if target < section.min_tokens {
+                section.messages.clear();
+                return SectionAction::Dropped;
+            }
+            let summarize = section.strategy == TruncationStrategy::Summarize;
+            let text = section
+                .messages
+                .iter()
+                .map(|m| m.content.as_str())
+                .collect::<Vec<_>>()
+                .join("\n\n");
+            let condensed = if summarize {
+                let summary = summarize_extractive(&text);
+                if estimate_text_tokens(&summary) <= target {
+                    summary
+                } else {
+                    truncate_to_tokens(&summary, target)
+                }
+            } else {
+                truncate_to_tokens(&text, target)
+            };
+            let role = section
+                .messages
+                .first()
+                .map(|m| m.role)
+                .unwrap_or(Role::System);
+            section.messages = vec![Message {
+                role,
+                content: condensed,
+                name: None,
+                tool_call_id: None,
+            }];
+            if summarize {
+                SectionAction::Summarized
+            } else {
+                SectionAction::Truncated
+            }
+        },
+    }
+}
+
+/// Keep headings, `key: value` lines and the first sentence of other lines
+fn summarize_extractive(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            if line.starts_with('#') || line.len() <= 80 {
+                return line.to_string();
+            }
+            match line.find(['.', '।', '?', '!']) {
+                Some(end) => {
+                    let end = end + line[end..].chars().next().map_or(1, char::len_utf8);
+                    line[..end].to_string()
+                },
+                None => line.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Cut text so its estimated tokens fit, at a grapheme boundary
+fn truncate_to_tokens(text: &str, max_tokens: usize) -> String {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    if estimate_text_tokens(text) <= max_tokens {
+        return text.to_string();
+    }
+    // One token is left for the ellipsis
+    let limit = max_tokens.saturating_sub(1);
+    let graphemes: Vec<&str> = text.graphemes(true).collect();
+    let mut keep = graphemes.len();
+    while keep > 0 {
+        let estimated = estimate_text_tokens(&graphemes[..keep].concat()).max(1);
+        if estimated <= limit {
+            break;
+        }
+        keep = (keep * limit / estimated).min(keep - 1);
+    }
+    let mut truncated = graphemes[..keep].concat();
+    if let Some(pos) = truncated.rfind('\n').filter(|&p| p > truncated.len() / 2) {
+        truncated.truncate(pos);
+    }
+    if !truncated.is_empty() {
+        truncated.push('…');
+    }
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(words: usize) -> String {
+        "gold loan rate ".repeat(words)
+    }
+
+    #[test]
+    fn test_within_budget_keeps_everything() {
+        let (messages, report) = PromptAssembler::new()
+            .with_section(PromptSection::system(
+                SectionKind::System,
+                "You are an agent.",
+            ))
+            .with_section(PromptSection::system(
+                SectionKind::Knowledge,
+                "Rate is 9.5%",
+            ))
+            .with_section(PromptSection::from_messages(
+                SectionKind::User,
+                vec![Message::user("Rate?")],
+            ))
+            .assemble(1000);
+        assert_eq!(messages.len(), 3);
+        assert!(!report.was_trimmed());
+    }
+
+    #[test]
+    fn test_lowest_priority_trimmed_first() {
+        let history = (0..10).map(|_| Message::user(text(20))).collect();
+        let (messages, report) = PromptAssembler::new()
+            .with_section(PromptSection::system(SectionKind::System, text(40)))
+            .with_section(PromptSection::system(
+                SectionKind::Personalization,
+                text(40),
+            ))
+            .with_section(PromptSection::system(SectionKind::Knowledge, text(100)))
+            .with_section(PromptSection::from_messages(SectionKind::History, history))
+            .with_section(PromptSection::from_messages(
+                SectionKind::User,
+                vec![Message::user("Rate?")],
+            ))
+            .assemble(400);
+
+        assert!(report.final_tokens <= 400, "{}", report);
+        let action = |kind| {
+            report
+                .sections
+                .iter()
+                .find(|s| s.kind == kind)
+                .unwrap()
+                .action
+        };
+        assert_eq!(action(SectionKind::Personalization), SectionAction::Dropped);
+        assert!(matches!(
+            action(SectionKind::History),
+            SectionAction::Trimmed { .. } | SectionAction::Dropped
+        ));
+        assert_eq!(action(SectionKind::System), SectionAction::Kept);
+        assert_eq!(messages.last().unwrap().content, "Rate?");
+    }
+
+    #[test]
+    fn test_never_sections_survive_over_budget() {
+        let (messages, report) = PromptAssembler::new()
+            .with_section(PromptSection::system(SectionKind::System, text(100)))
+            .with_section(PromptSection::system(SectionKind::Memory, text(100)))
+            .assemble(50);
+        assert_eq!(messages.len(), 1);
+        assert!(report.over_budget());
+        assert!(report.to_string().contains("memory[p50]"));
+    }
+
+    #[test]
+    fn test_summarize_keeps_first_sentences() {
+        let long = format!(
+            "## Memory\n{} First point ends here. {}",
+            "Customer said a lot about their loan needs and rates.",
+            text(30)
+        );
+        let summary = summarize_extractive(&long);
+        assert!(summary.starts_with("## Memory\n"));
+        assert!(summary.ends_with("rates."));
+    }
+}
@@ -15,11 +15,15 @@ use async_trait::async_trait;
 use parking_lot::Mutex;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
 // P1 FIX: Use centralized constants
 use voice_agent_config::constants::endpoints;
+use voice_agent_config::DraftDecodingConfig;
+
+use voice_agent_core::{ConstraintSupport, OutputConstraint};
 
 use crate::prompt::Message;
 use crate::LlmError;
@@ -119,6 +123,24 @@ pub trait LlmBackend: Send + Sync {
     /// Get model name
     fn model_name(&self) -> &str;
 
+    /// Output constraints this backend can enforce during decoding
+    fn constraint_support(&self) -> ConstraintSupport {
+        ConstraintSupport::NONE
+    }
+
+    /// Generate with guided decoding
+    ///
+    /// The default ignores the constraint. Pass only constraints the backend
+    /// reports in `constraint_support` (see `constraint::resolve_constraint`).
+    async fn generate_constrained(
+        &self,
+        messages: &[Message],
+        constraint: &OutputConstraint,
+    ) -> Result<GenerationResult, LlmError> {
+        let _ = constraint;
+        self.generate(messages).await
+    }
+
     /// Estimate tokens
     ///
     /// P0 FIX: Improved token estimation for multilingual content.
@@ -220,6 +242,25 @@ impl OllamaBackend {
         &self,
         messages: &[Message],
         context: Option<&[i64]>,
+    ) -> Result<GenerationResult, LlmError> {
+        self.generate_with_format(messages, context, None).await
+    }
+
+    /// Map a constraint to Ollama's `format` field ("json" or a JSON schema)
+    fn format_for(constraint: &OutputConstraint) -> Option<serde_json::Value> {
+        match constraint {
+            OutputConstraint::Json => Some(serde_json::Value::String("json".to_string())),
+            OutputConstraint::JsonSchema { schema, .. } => Some(schema.clone()),
+            OutputConstraint::Grammar { .. } => None,
+        }
+    }
+
+    /// Non-streaming chat with optional structured output `format`
+    async fn generate_with_format(
+        &self,
+        messages: &[Message],
+        context: Option<&[i64]>,
+        format: Option<serde_json::Value>,
     ) -> Result<GenerationResult, LlmError> {
         let start = std::time::Instant::now();
 
@@ -235,6 +276,7 @@ impl OllamaBackend {
             keep_alive: Some(self.config.keep_alive.clone()),
             context: context.map(|c| c.to_vec()),
             think: Some(false), // Disable extended thinking for faster responses
+            format,
         };
 
         // Retry loop with exponential backoff
@@ -374,6 +416,7 @@ impl LlmBackend for OllamaBackend {
             keep_alive: Some(self.config.keep_alive.clone()),
             context: cached_context,
             think: Some(false), // Disable extended thinking for faster responses
+            format: None,
         };
 
         let response = self
@@ -469,6 +512,24 @@ impl LlmBackend for OllamaBackend {
     fn model_name(&self) -> &str {
         &self.config.model
     }
+
+    fn constraint_support(&self) -> ConstraintSupport {
+        // `format` takes "json" or a JSON schema; Ollama has no GBNF support
+        ConstraintSupport {
+            json: true,
+            json_schema: true,
+            grammar: false,
+        }
+    }
+
+    async fn generate_constrained(
+        &self,
+        messages: &[Message],
+        constraint: &OutputConstraint,
+    ) -> Result<GenerationResult, LlmError> {
+        self.generate_with_format(messages, None, Self::format_for(constraint))
+            .await
+    }
 }
 
 // Ollama API types
@@ -488,6 +549,9 @@ struct OllamaChatRequest {
     /// Disable extended thinking for models like qwen3/deepseek-r1
     #[serde(skip_serializing_if = "Option::is_none")]
     think: Option<bool>,
+    /// Structured output: "json" or a JSON schema
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -566,6 +630,10 @@ pub struct OpenAIConfig {
     pub organization: Option<String>,
     /// Azure API version (Azure specific)
     pub api_version: Option<String>,
+    /// Guided decoding the server supports (`response_format`, llama.cpp `grammar`)
+    pub constraint_support: ConstraintSupport,
+    /// Speculative decoding against the server's draft model (llama.cpp)
+    pub draft_decoding: Option<DraftDecodingConfig>,
 }
 
 impl Default for OpenAIConfig {
@@ -581,6 +649,12 @@ impl Default for OpenAIConfig {
             stream: true,
             organization: None,
             api_version: None,
+            constraint_support: ConstraintSupport {
+                json: true,
+                json_schema: true,
+                grammar: false,
+            },
+            draft_decoding: None,
         }
     }
 }
@@ -612,14 +686,69 @@ impl OpenAIConfig {
     }
 
     /// Create config for local OpenAI-compatible server (vLLM, Ollama, etc.)
+    ///
+    /// Assumes llama.cpp-style `grammar` support; override with
+    /// `with_constraint_support` for servers without it.
     pub fn local(endpoint: impl Into<String>, model: impl Into<String>) -> Self {
         Self {
             endpoint: endpoint.into(),
             api_key: "not-needed".to_string(),
             model: model.into(),
+            constraint_support: ConstraintSupport {
+                json: true,
+                json_schema: true,
+                grammar: true,
+            },
             ..Default::default()
         }
     }
+
+    /// Set the guided decoding capabilities of the server
+    pub fn with_constraint_support(mut self, support: ConstraintSupport) -> Self {
+        self.constraint_support = support;
+        self
+    }
+
+    /// Draft tokens with the llama.cpp server's draft model (`--model-draft`)
+    pub fn with_draft_decoding(mut self, draft: DraftDecodingConfig) -> Self {
+        self.draft_decoding = Some(draft);
+        self
+    }
+}
+
+/// Draft tokens proposed and accepted by the server since the backend was
+/// created
+///
+/// A low acceptance rate means the draft model disagrees with the target
+/// too often to save time; lower `max_draft_tokens` or pick a closer draft.
+#[derive(Debug, Default)]
+pub struct DraftStats {
+    drafted: AtomicU64,
+    accepted: AtomicU64,
+}
+
+impl DraftStats {
+    fn record(&self, timings: &LlamaCppTimings) {
+        self.drafted.fetch_add(timings.draft_n, Ordering::Relaxed);
+        self.accepted
+            .fetch_add(timings.draft_n_accepted, Ordering::Relaxed);
+    }
+
+    pub fn drafted(&self) -> u64 {
+        self.drafted.load(Ordering::Relaxed)
+    }
+
+    pub fn accepted(&self) -> u64 {
+        self.accepted.load(Ordering::Relaxed)
+    }
+
+    /// Accepted over drafted tokens (None before any draft)
+    pub fn acceptance_rate(&self) -> Option<f32> {
+        match self.drafted() {
+            0 => None,
+            drafted => Some(self.accepted() as f32 / drafted as f32),
+        }
+    }
 }
 
 /// OpenAI-compatible backend
@@ -633,6 +762,7 @@ impl OpenAIConfig {
 pub struct OpenAIBackend {
     config: OpenAIConfig,
     client: Client,
+    draft_stats: DraftStats,
 }
 
 impl OpenAIBackend {
@@ -649,7 +779,38 @@ impl OpenAIBackend {
             .build()
             .map_err(|e| LlmError::Network(e.to_string()))?;
 
-        Ok(Self { config, client })
+        Ok(Self {
+            config,
+            client,
+            draft_stats: DraftStats::default(),
+        })
+    }
+
+    /// Draft acceptance so far (zero unless draft decoding is configured)
+    pub fn draft_stats(&self) -> &DraftStats {
+        &self.draft_stats
+    }
+
+    /// Per-request draft parameters, when draft decoding is configured
+    fn speculative_params(&self) -> Option<SpeculativeParams> {
+        self.config
+            .draft_decoding
+            .as_ref()
+            .map(SpeculativeParams::from)
+    }
+
+    /// Count the draft tokens reported in a llama.cpp response
+    fn record_timings(&self, timings: Option<&LlamaCppTimings>) {
+        let Some(timings) = timings.filter(|t| t.draft_n > 0) else {
+            return;
+        };
+        self.draft_stats.record(timings);
+        tracing::debug!(
+            model = %self.config.model,
+            drafted = timings.draft_n,
+            accepted = timings.draft_n_accepted,
+            "Draft tokens verified"
+        );
     }
 
     /// Get the full API URL for chat completions
@@ -705,9 +866,25 @@ impl OpenAIBackend {
     }
 }
 
-#[async_trait]
-impl LlmBackend for OpenAIBackend {
-    async fn generate(&self, messages: &[Message]) -> Result<GenerationResult, LlmError> {
+impl OpenAIBackend {
+    /// Map a JSON constraint to the `response_format` field
+    fn response_format(constraint: &OutputConstraint) -> Option<serde_json::Value> {
+        match constraint {
+            OutputConstraint::Json => Some(serde_json::json!({ "type": "json_object" })),
+            OutputConstraint::JsonSchema { name, schema } => Some(serde_json::json!({
+                "type": "json_schema",
+                "json_schema": { "name": name, "schema": schema, "strict": false },
+            })),
+            OutputConstraint::Grammar { .. } => None,
+        }
+    }
+
+    /// Non-streaming chat completion with optional guided decoding
+    async fn complete(
+        &self,
+        messages: &[Message],
+        constraint: Option<&OutputConstraint>,
+    ) -> Result<GenerationResult, LlmError> {
         let start = std::time::Instant::now();
 
         let openai_messages: Vec<OpenAIMessage> = messages
@@ -730,6 +907,12 @@ impl LlmBackend for OpenAIBackend {
             temperature: Some(self.config.temperature),
             top_p: Some(self.config.top_p),
             stream: Some(false),
+            response_format: constraint.and_then(Self::response_format),
+            grammar: match constraint {
+                Some(OutputConstraint::Grammar { gbnf }) => Some(gbnf.clone()),
+                _ => None,
+            },
+            speculative: self.speculative_params(),
         };
 
         let response = self
@@ -750,6 +933,7 @@ impl LlmBackend for OpenAIBackend {
             .json()
             .await
             .map_err(|e| LlmError::InvalidResponse(e.to_string()))?;
+        self.record_timings(response.timings.as_ref());
 
         let choice = response
             .choices
@@ -777,6 +961,13 @@ impl LlmBackend for OpenAIBackend {
             context: None, // OpenAI doesn't expose KV cache
         })
     }
+}
+
+#[async_trait]
+impl LlmBackend for OpenAIBackend {
+    async fn generate(&self, messages: &[Message]) -> Result<GenerationResult, LlmError> {
+        self.complete(messages, None).await
+    }
 
     async fn generate_stream(
         &self,
@@ -808,6 +999,9 @@ impl LlmBackend for OpenAIBackend {
             temperature: Some(self.config.temperature),
             top_p: Some(self.config.top_p),
             stream: Some(true),
+            response_format: None,
+            grammar: None,
+            speculative: self.speculative_params(),
         };
 
         let response = self
@@ -845,6 +1039,8 @@ impl LlmBackend for OpenAIBackend {
 
                 if let Some(json_str) = line.strip_prefix("data: ") {
                     if let Ok(chunk) = serde_json::from_str::<OpenAIStreamChunk>(json_str) {
+                        // llama.cpp reports timings on the final chunk
+                        self.record_timings(chunk.timings.as_ref());
                         if let Some(choice) = chunk.choices.first() {
                             if let Some(ref delta) = choice.delta {
                                 if let Some(ref content) = delta.content {
@@ -900,6 +1096,18 @@ impl LlmBackend for OpenAIBackend {
     fn model_name(&self) -> &str {
         &self.config.model
     }
+
+    fn constraint_support(&self) -> ConstraintSupport {
+        self.config.constraint_support
+    }
+
+    async fn generate_constrained(
+        &self,
+        messages: &[Message],
+        constraint: &OutputConstraint,
+    ) -> Result<GenerationResult, LlmError> {
+        self.complete(messages, Some(constraint)).await
+    }
 }
 
 // OpenAI API types
@@ -915,6 +1123,44 @@ struct OpenAIChatRequest {
     top_p: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     stream: Option<bool>,
+    /// JSON mode / JSON schema structured output
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<serde_json::Value>,
+    /// GBNF grammar (llama.cpp server extension)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    grammar: Option<String>,
+    /// Draft decoding parameters (llama.cpp server extension)
+    #[serde(flatten)]
+    speculative: Option<SpeculativeParams>,
+}
+
+#[derive(Debug, Serialize)]
+struct SpeculativeParams {
+    #[serde(rename = "speculative.n_max")]
+    n_max: usize,
+    #[serde(rename = "speculative.n_min")]
+    n_min: usize,
+    #[serde(rename = "speculative.p_min")]
+    p_min: f32,
+}
+
+impl From<&DraftDecodingConfig> for SpeculativeParams {
+    fn from(draft: &DraftDecodingConfig) -> Self {
+        Self {
+            n_max: draft.max_draft_tokens,
+            n_min: draft.min_draft_tokens,
+            p_min: draft.min_draft_probability,
+        }
+    }
+}
+
+/// llama.cpp server timings (only the draft counters are used)
+#[derive(Debug, Default, Deserialize)]
+struct LlamaCppTimings {
+    #[serde(default)]
+    draft_n: u64,
+    #[serde(default)]
+    draft_n_accepted: u64,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -927,6 +1173,8 @@ struct OpenAIMessage {
 struct OpenAIChatResponse {
     choices: Vec<OpenAIChoice>,
     usage: Option<OpenAIUsage>,
+    #[serde(default)]
+    timings: Option<LlamaCppTimings>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -947,6 +1195,8 @@ struct OpenAIUsage {
 #[derive(Debug, Deserialize)]
 struct OpenAIStreamChunk {
     choices: Vec<OpenAIStreamChoice>,
+    #[serde(default)]
+    timings: Option<LlamaCppTimings>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -1015,6 +1265,7 @@ mod tests {
             keep_alive: Some("5m".to_string()),
             context: Some(vec![1, 2, 3]),
             think: Some(false),
+            format: None,
         };
 
         let json = serde_json::to_string(&request).unwrap();
@@ -1111,11 +1362,63 @@ mod tests {
             temperature: Some(0.7),
             top_p: Some(0.9),
             stream: Some(false),
+            response_format: None,
+            grammar: None,
+            speculative: None,
         };
 
         let json = serde_json::to_string(&request).unwrap();
+        assert!(!json.contains("speculative"));
         assert!(json.contains("gpt-4"));
         assert!(json.contains("Hello"));
         assert!(json.contains("max_tokens"));
     }
+
+    #[test]
+    fn test_openai_constrained_request_fields() {
+        let schema = OutputConstraint::json_schema("slot", serde_json::json!({"type": "object"}));
+        let format = OpenAIBackend::response_format(&schema).unwrap();
+        assert_eq!(format["type"], "json_schema");
+        assert_eq!(format["json_schema"]["name"], "slot");
+        assert!(OpenAIBackend::response_format(&OutputConstraint::grammar("root ::= \"x\"")).is_none());
+
+        assert_eq!(
+            OllamaBackend::format_for(&OutputConstraint::Json),
+            Some(serde_json::json!("json"))
+        );
+        assert!(OpenAIConfig::local("http://localhost:8080/v1", "qwen").constraint_support.grammar);
+        assert!(!OpenAIConfig::default().constraint_support.grammar);
+    }
+
+    #[test]
+    fn test_draft_decoding_request_and_stats() {
+        let config = OpenAIConfig::local("http://localhost:8080/v1", "qwen")
+            .with_draft_decoding(DraftDecodingConfig::default());
+        let backend = OpenAIBackend::new(config).unwrap();
+        let request = serde_json::to_value(OpenAIChatRequest {
+            model: "qwen".to_string(),
+            messages: vec![],
+            max_tokens: None,
+            temperature: None,
+            top_p: None,
+            stream: Some(true),
+            response_format: None,
+            grammar: None,
+            speculative: backend.speculative_params(),
+        })
+        .unwrap();
+        assert_eq!(request["speculative.n_max"], 16);
+        assert_eq!(request["speculative.n_min"], 0);
+
+        assert_eq!(backend.draft_stats().acceptance_rate(), None);
+        let response: OpenAIChatResponse = serde_json::from_str(
+            r#"{"choices": [{"message": {"role": "assistant", "content": "Namaste"},
+                "finish_reason": "stop"}],
+                "timings": {"predicted_n": 40, "draft_n": 32, "draft_n_accepted": 24}}"#,
+        )
+        .unwrap();
+        backend.record_timings(response.timings.as_ref());
+        assert_eq!(backend.draft_stats().drafted(), 32);
+        assert_eq!(backend.draft_stats().acceptance_rate(), Some(0.75));
+    }
 }
@@ -0,0 +1,346 @@
+//! Grammar-Constrained Decoding
+//!
+//! Small models often produce tool arguments or slot confirmations that
+//! fail to parse. Backends with guided decoding (llama.cpp GBNF grammars,
+//! Ollama/OpenAI JSON schema) can enforce the output format. This module:
+//!
+//! - builds constraints for tool calls and slot confirmations
+//! - converts JSON Schema to GBNF for grammar-only backends
+//! - picks the best constraint a backend can enforce
+
+use std::collections::HashSet;
+
+use serde_json::{json, Value};
+use voice_agent_core::{ConstraintSupport, OutputConstraint};
+
+use crate::prompt::ToolDefinition;
+use crate::LlmError;
+
+/// GBNF rules for generic JSON values
+const JSON_PRIMITIVES: &str = r#"ws ::= ([ \t\n] ws)?
+string ::= "\"" ( [^"\\\x7F\x00-\x1F] | "\\" ( ["\\/bfnrt] | "u" [0-9a-fA-F] [0-9a-fA-F] [0-9a-fA-F] [0-9a-fA-F] ) )* "\""
+number ::= "-"? ( [0-9] | [1-9] [0-9]* ) ( "." [0-9]+ )? ( [eE] [-+]? [0-9]+ )?
+integer ::= "-"? ( [0-9] | [1-9] [0-9]* )
+boolean ::= "true" | "false"
+null ::= "null"
+value ::= object | array | string | number | boolean | null
+object ::= "{" ws ( string ws ":" ws value ws ( "," ws string ws ":" ws value ws )* )? "}"
+array ::= "[" ws ( value ws ( "," ws value ws )* )? "]""#;
+
+/// Constraint forcing a single tool call `{"name": ..., "arguments": {...}}`
+pub fn tool_call_constraint(tools: &[ToolDefinition]) -> OutputConstraint {
+    let variants: Vec<Value> = tools
+        .iter()
+        .map(|tool| {
+            json!({
+                "type": "object",
+                "properties": {
+                    "name": { "const": tool.name },
+                    "arguments": tool.parameters,
+                },
+                "required": ["name", "arguments"],
+            })
+        })
+        .collect();
+
+    OutputConstraint::json_schema("tool_call", json!({ "oneOf": variants }))
+}
+
+/// Constraint for confirming a collected slot value
+///
+/// Output is `{"confirmed": bool, "value": string | null}` where `value`
+/// carries a corrected value when the customer did not confirm. With
+/// `allowed_values` the correction is restricted to those options.
+pub fn slot_confirmation_constraint(slot: &str, allowed_values: &[String]) -> OutputConstraint {
+    let value_schema = if allowed_values.is_empty() {
+        json!({ "type": ["string", "null"] })
+    } else {
+        let mut options: Vec<Value> = allowed_values.iter().map(|v| json!(v)).collect();
+        options.push(Value::Null);
+        json!({ "enum": options })
+    };
+
+    OutputConstraint::json_schema(
+        format!("{}_confirmation", slot),
+        json!({
+            "type": "object",
+            "properties": {
+                "confirmed": { "type": "boolean" },
+                "value": value_schema,
+            },
+            "required": ["confirmed", "value"],
+        }),
+    )
+}
+
+/// Pick the best constraint a backend can enforce
+///
+/// Falls back from JSON Schema to an equivalent GBNF grammar, then to
+/// plain JSON mode. Returns `None` when the backend cannot constrain the
+/// output at all.
+pub fn resolve_constraint(
+    constraint: &OutputConstraint,
+    support: ConstraintSupport,
+) -> Option<OutputConstraint> {
+    if support.supports(constraint) {
+        return Some(constraint.clone());
+    }
+
+    match constraint {
+        OutputConstraint::JsonSchema { schema, .. } => {
+            if support.grammar {
+                match json_schema_to_gbnf(schema) {
+                    Ok(gbnf) => return Some(OutputConstraint::grammar(gbnf)),
+                    Err(e) => tracing::debug!(error = %e, "Schema not convertible to GBNF"),
+                }
+            }
+            support.json.then_some(OutputConstraint::Json)
+        },
+        OutputConstraint::Json if support.grammar => Some(OutputConstraint::grammar(format!(
+            "root ::= object\n{}",
+            JSON_PRIMITIVES
+        ))),
+        _ => None,
+    }
+}
+
+/// Convert a JSON Schema to a GBNF grammar
+///
+/// Supports `type` (including type arrays), `properties`/`required`,
+/// `items`, `enum`, `const`, `oneOf` and `anyOf`. Object properties are
+/// emitted required-first in a fixed order, which guided decoding needs
+/// anyway. Other keywords (`minimum`, `pattern`, ...) are ignored.
+pub fn json_schema_to_gbnf(schema: &Value) -> Result<String, LlmError> {
+    let mut builder = GbnfBuilder::default();
+    let root = builder.visit(schema, "root")?;
+    // Nested rules are added before their parent; the root rule goes first
+    match builder.rules.iter().position(|(name, _)| *name == root) {
+        Some(pos) if root == "root" => {
+            let rule = builder.rules.remove(pos);
+            builder.rules.insert(0, rule);
+        },
+        _ => builder.rules.insert(0, ("root".to_string(), root)),
+    }
+
+    let mut grammar = String::new();
+    for (name, body) in &builder.rules {
+        grammar.push_str(&format!("{} ::= {}\n", name, body));
+    }
+    grammar.push_str(JSON_PRIMITIVES);
+    Ok(grammar)
+}
+
+#[derive(Default)]
+struct GbnfBuilder {
+    rules: Vec<(String, String)>,
+    names: HashSet<String>,
+}
+
+impl GbnfBuilder {
+    /// Returns an expression (usually a rule name) matching `schema`
+    fn visit(&mut self, schema: &Value, name: &str) -> Result<String, LlmError> {
+        if let Some(value) = schema.get("const") {
+            return Ok(literal(value));
+        }
+
+        if let Some(options) = schema.get("enum").and_then(Value::as_array) {
+            let alternatives: Vec<String> = options.iter().map(literal).collect();
+            return Ok(self.add_rule(name, alternatives.join(" | ")));
+        }
+
+        for key in ["oneOf", "anyOf"] {
+            if let Some(variants) = schema.get(key).and_then(Value::as_array) {
+                let alternatives = variants
+                    .iter()
+                    .enumerate()
+                    .map(|(i, variant)| self.visit(variant, &format!("{}-{}", name, i)))
+                    .collect::<Result<Vec<_>, _>>()?;
+                return Ok(self.add_rule(name, alternatives.join(" | ")));
+            }
+        }
+
+        match schema.get("type") {
+            Some(Value::String(kind)) => self.visit_type(kind, schema, name),
+            Some(Value::Array(kinds)) => {
+                let alternatives = kinds
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(|kind| self.visit_type(kind, schema, &format!("{}-{}", name, kind)))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(self.add_rule(name, alternatives.join(" | ")))
+            },
+            None if schema.get("properties").is_some() => self.visit_type("object", schema, name),
+            None => Ok("value".to_string()),
+            Some(other) => Err(LlmError::Configuration(format!(
+                "unsupported schema type at {}: {}",
+                name, other
+            ))),
+        }
+    }
+
+    fn visit_type(&mut self, kind: &str, schema: &Value, name: &str) -> Result<String, LlmError> {
+        match kind {
+            "string" | "number" | "integer" | "boolean" | "null" => Ok(kind.to_string()),
+            "array" => match schema.get("items") {
+                Some(items) => {
+                    let item = self.visit(items, &format!("{}-item", name))?;
+                    Ok(self.add_rule(
+                        name,
+                        format!(
+                            "\"[\" ws ( {item} ws ( \",\" ws {item} ws )* )? \"]\"",
+                            item = item
+                        ),
+                    ))
+                },
+                None => Ok("array".to_string()),
+            },
+            "object" => self.visit_object(schema, name),
+            other => Err(LlmError::Configuration(format!(
+                "unsupported schema type at {}: {}",
+                name, other
+            ))),
+        }
+    }
+
+    fn visit_object(&mut self, schema: &Value, name: &str) -> Result<String, LlmError> {
+        let properties = match schema.get("properties").and_then(Value::as_object) {
+            Some(properties) if !properties.is_empty() => properties,
+            _ => return Ok("object".to_string()),
+        };
+        let required: Vec<&str> = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|r| r.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+
+        let mut required_pairs = Vec::new();
+        let mut optional_pairs = Vec::new();
+        for key in required.iter().filter(|key| properties.contains_key(**key)) {
+            required_pairs.push(self.pair(key, &properties[*key], name)?);
+        }
+        for (key, property) in properties {
+            if !required.contains(&key.as_str()) {
+                optional_pairs.push(self.pair(key, property, name)?);
+            }
+        }
+
+        let body = if required_pairs.is_empty() {
+            let pair = self.add_rule(&format!("{}-kv", name), optional_pairs.join(" | "));
+            format!(
+                "\"{{\" ws ( {pair} ws ( \",\" ws {pair} ws )* )? \"}}\"",
+                pair = pair
+            )
+        } else {
+            let mut body = format!("\"{{\" ws {} ws", required_pairs.join(" ws \",\" ws "));
+            for pair in &optional_pairs {
+                body.push_str(&format!(" ( \",\" ws {} ws )?", pair));
+            }
+            body.push_str(" \"}\"");
+            body
+        };
+        Ok(self.add_rule(name, body))
+    }
+
+    fn pair(&mut self, key: &str, schema: &Value, parent: &str) -> Result<String, LlmError> {
+        let value = self.visit(schema, &format!("{}-{}", parent, key))?;
+        Ok(format!("{} ws \":\" ws {}", literal(&json!(key)), value))
+    }
+
+    fn add_rule(&mut self, name: &str, body: String) -> String {
+        let base: String = name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+        let mut unique = base.clone();
+        let mut n = 1;
+        while !self.names.insert(unique.clone()) {
+            n += 1;
+            unique = format!("{}{}", base, n);
+        }
+        self.rules.push((unique.clone(), body));
+        unique
+    }
+}
+
+/// GBNF literal matching the JSON encoding of `value`
+fn literal(value: &Value) -> String {
+    let json = value.to_string();
+    format!("\"{}\"", json.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prompt::ToolBuilder;
+
+    #[test]
+    fn test_object_schema_to_gbnf() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "amount": { "type": "number" },
+                "scheme": { "enum": ["standard", "bullet"] },
+            },
+            "required": ["amount"],
+        });
+        let gbnf = json_schema_to_gbnf(&schema).unwrap();
+
+        assert!(gbnf.starts_with(
+            r#"root ::= "{" ws "\"amount\"" ws ":" ws number ws ( "," ws "\"scheme\"" ws ":" ws root-scheme ws )? "}""#
+        ));
+        assert!(gbnf.contains(r#"root-scheme ::= "\"standard\"" | "\"bullet\"""#));
+        assert!(gbnf.contains("string ::="));
+    }
+
+    #[test]
+    fn test_primitive_root_and_unique_names() {
+        let gbnf = json_schema_to_gbnf(&json!({ "type": "string" })).unwrap();
+        assert!(gbnf.starts_with("root ::= string\n"));
+
+        let mut builder = GbnfBuilder::default();
+        assert_eq!(builder.add_rule("root-a_b", String::new()), "root-a-b");
+        assert_eq!(builder.add_rule("root-a-b", String::new()), "root-a-b2");
+    }
+
+    #[test]
+    fn test_tool_call_constraint_grammar() {
+        let tools = vec![ToolBuilder::new("get_rates", "Rates")
+            .param("amount", "number", "Loan amount", true)
+            .build()];
+        let OutputConstraint::JsonSchema { schema, .. } = tool_call_constraint(&tools) else {
+            panic!("expected JSON schema");
+        };
+        let gbnf = json_schema_to_gbnf(&schema).unwrap();
+        assert!(gbnf.contains(r#""\"name\"" ws ":" ws "\"get_rates\"""#));
+        assert!(gbnf.contains("root ::= root-0"));
+    }
+
+    #[test]
+    fn test_resolve_constraint_fallbacks() {
+        let schema = slot_confirmation_constraint("loan_amount", &[]);
+        let grammar_only = ConstraintSupport {
+            grammar: true,
+            ..ConstraintSupport::NONE
+        };
+        let json_only = ConstraintSupport {
+            json: true,
+            ..ConstraintSupport::NONE
+        };
+
+        assert!(matches!(
+            resolve_constraint(&schema, grammar_only),
+            Some(OutputConstraint::Grammar { .. })
+        ));
+        assert_eq!(
+            resolve_constraint(&schema, json_only),
+            Some(OutputConstraint::Json)
+        );
+        assert_eq!(resolve_constraint(&schema, ConstraintSupport::NONE), None);
+    }
+}
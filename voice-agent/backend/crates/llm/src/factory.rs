@@ -7,6 +7,10 @@
 //! - **Claude**: Native tool_use support, best for complex tool calling
 //! - **Ollama**: Local models with text-based tool injection
 //! - **OpenAI**: GPT-4, GPT-3.5, Azure OpenAI
+//! - **llama.cpp / vLLM**: self-hosted models behind an OpenAI-compatible server
+//!
+//! Several providers can be combined behind an [`LlmRouter`] with
+//! [`LlmFactory::create_router`].
 //!
 //! ## Example
 //! ```ignore
@@ -16,13 +20,15 @@
 //! ```
 
 use std::sync::Arc;
-use voice_agent_config::constants::endpoints;
-use voice_agent_core::{llm_types::ToolDefinition, LanguageModel};
+use std::time::Duration;
+use voice_agent_config::{constants::endpoints, DraftDecodingConfig, LlmRouterConfig};
+use voice_agent_core::{llm_types::ToolDefinition, ConstraintSupport, LanguageModel, LlmTask};
 
 use crate::{
     adapter::LanguageModelAdapter,
     backend::{LlmBackend, LlmConfig, OllamaBackend, OpenAIBackend, OpenAIConfig},
     claude::{ClaudeBackend, ClaudeConfig},
+    router::{LlmRouter, RouterConfig},
     LlmError,
 };
 
@@ -38,6 +44,10 @@ pub enum LlmProvider {
     OpenAI,
     /// Azure OpenAI - Azure-hosted GPT models
     AzureOpenAI,
+    /// llama.cpp server (local GGUF models, GBNF grammar support)
+    LlamaCpp,
+    /// vLLM server (JSON schema guided decoding)
+    Vllm,
 }
 
 impl LlmProvider {
@@ -47,6 +57,8 @@ impl LlmProvider {
             "ollama" | "local" => Some(LlmProvider::Ollama),
             "openai" | "gpt" => Some(LlmProvider::OpenAI),
             "azure" | "azure-openai" => Some(LlmProvider::AzureOpenAI),
+            "llama_cpp" | "llama.cpp" | "llamacpp" => Some(LlmProvider::LlamaCpp),
+            "vllm" => Some(LlmProvider::Vllm),
             _ => None,
         }
     }
@@ -73,6 +85,8 @@ pub struct LlmProviderConfig {
     pub azure_api_version: Option<String>,
     /// Organization ID (for OpenAI only)
     pub organization: Option<String>,
+    /// Speculative decoding with the server's draft model (llama.cpp only)
+    pub draft_decoding: Option<DraftDecodingConfig>,
 }
 
 impl Default for LlmProviderConfig {
@@ -87,6 +101,7 @@ impl Default for LlmProviderConfig {
             streaming: true,
             azure_api_version: None,
             organization: None,
+            draft_decoding: None,
         }
     }
 }
@@ -169,6 +184,40 @@ impl LlmProviderConfig {
         self.endpoint = Some(endpoint.into());
         self
     }
+
+    /// Set API key
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Enable speculative decoding (llama.cpp server started with a draft model)
+    pub fn with_draft_decoding(mut self, draft: DraftDecodingConfig) -> Self {
+        self.draft_decoding = Some(draft);
+        self
+    }
+
+    /// Create llama.cpp server config
+    pub fn llama_cpp(model: impl Into<String>) -> Self {
+        Self {
+            provider: LlmProvider::LlamaCpp,
+            api_key: None,
+            endpoint: Some(endpoints::LLAMA_CPP_DEFAULT.to_string()),
+            model: model.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Create vLLM server config
+    pub fn vllm(model: impl Into<String>) -> Self {
+        Self {
+            provider: LlmProvider::Vllm,
+            api_key: None,
+            endpoint: Some(endpoints::VLLM_DEFAULT.to_string()),
+            model: model.into(),
+            ..Default::default()
+        }
+    }
 }
 
 /// Factory for creating LLM backends
@@ -249,6 +298,11 @@ impl LlmFactory {
                 let backend = OpenAIBackend::new(azure_config)?;
                 Ok(Arc::new(LanguageModelAdapter::new(backend)))
             },
+
+            LlmProvider::LlamaCpp | LlmProvider::Vllm => {
+                let backend = Self::self_hosted_backend(config)?;
+                Ok(Arc::new(LanguageModelAdapter::new(backend)))
+            },
         }
     }
 
@@ -313,7 +367,122 @@ impl LlmFactory {
 
                 Ok(Arc::new(OpenAIBackend::new(openai_config)?))
             },
+
+            LlmProvider::LlamaCpp | LlmProvider::Vllm => {
+                Ok(Arc::new(Self::self_hosted_backend(config)?))
+            },
+        }
+    }
+
+    /// OpenAI-compatible backend for a self-hosted llama.cpp or vLLM server
+    ///
+    /// Local models run out of process: llama.cpp's `llama-server` serves
+    /// GGUF models with GBNF grammars, vLLM serves HF models with JSON
+    /// schema guided decoding but no GBNF.
+    fn self_hosted_backend(
+        config: &LlmProviderConfig,
+    ) -> std::result::Result<OpenAIBackend, LlmError> {
+        let (default_endpoint, grammar) = match config.provider {
+            LlmProvider::Vllm => (endpoints::VLLM_DEFAULT.to_string(), false),
+            _ => (endpoints::LLAMA_CPP_DEFAULT.to_string(), true),
+        };
+        let endpoint = config.endpoint.clone().unwrap_or(default_endpoint);
+
+        let mut openai_config = OpenAIConfig::local(endpoint, &config.model)
+            .with_constraint_support(ConstraintSupport {
+                json: true,
+                json_schema: true,
+                grammar,
+            });
+        if let Some(ref api_key) = config.api_key {
+            openai_config.api_key = api_key.clone();
+        }
+        openai_config.max_tokens = config.max_tokens;
+        openai_config.temperature = config.temperature;
+        openai_config.stream = config.streaming;
+        if let Some(ref draft) = config.draft_decoding {
+            if config.provider == LlmProvider::LlamaCpp {
+                openai_config.draft_decoding = Some(draft.clone());
+            } else {
+                tracing::warn!(
+                    model = %config.model,
+                    "Ignoring draft_decoding: vLLM takes --speculative-config at launch"
+                );
+            }
+        }
+
+        OpenAIBackend::new(openai_config)
+    }
+
+    /// Build an LLM router from config
+    ///
+    /// Backends that cannot be constructed (e.g. a missing API key) are
+    /// skipped with a warning; it is an error if none remain.
+    pub fn create_router(config: &LlmRouterConfig) -> std::result::Result<LlmRouter, LlmError> {
+        let mut router = LlmRouter::new(RouterConfig {
+            failure_threshold: config.failure_threshold,
+            cooldown: Duration::from_secs(config.cooldown_secs),
+            health_check_interval: Duration::from_secs(config.health_check_interval_secs),
+        });
+
+        for entry in &config.backends {
+            let provider = LlmProvider::from_str(&entry.provider).ok_or_else(|| {
+                LlmError::Configuration(format!(
+                    "backend '{}' has unknown provider '{}'",
+                    entry.name, entry.provider
+                ))
+            })?;
+
+            let provider_config = LlmProviderConfig {
+                provider,
+                api_key: entry.api_key.clone(),
+                endpoint: entry.endpoint.clone(),
+                model: entry.model.clone(),
+                max_tokens: entry.max_tokens,
+                temperature: entry.temperature,
+                draft_decoding: entry.draft_decoding.clone(),
+                ..Default::default()
+            };
+
+            match Self::create(&provider_config) {
+                Ok(model) => {
+                    tracing::info!(
+                        backend = %entry.name,
+                        provider = %entry.provider,
+                        model = %entry.model,
+                        "Registered LLM backend"
+                    );
+                    router = router.with_backend(&entry.name, model, entry.cost_per_1k_tokens);
+                },
+                Err(e) => {
+                    tracing::warn!(backend = %entry.name, error = %e, "Skipping LLM backend");
+                },
+            }
+        }
+
+        if router.backend_names().is_empty() {
+            return Err(LlmError::Configuration(
+                "LLM router has no usable backends".to_string(),
+            ));
         }
+
+        for (task, names) in &config.routes {
+            let task = LlmTask::parse(task).ok_or_else(|| {
+                LlmError::Configuration(format!("unknown LLM task '{}'", task))
+            })?;
+            // Routes may name backends that were skipped above
+            let registered = router.backend_names();
+            let available: Vec<&str> = names
+                .iter()
+                .map(String::as_str)
+                .filter(|name| registered.contains(name))
+                .collect();
+            if !available.is_empty() {
+                router = router.with_route(task, &available)?;
+            }
+        }
+
+        Ok(router)
     }
 
     /// Get the default provider from environment
@@ -505,6 +674,11 @@ mod tests {
             LlmProvider::from_str("azure"),
             Some(LlmProvider::AzureOpenAI)
         );
+        assert_eq!(
+            LlmProvider::from_str("llama.cpp"),
+            Some(LlmProvider::LlamaCpp)
+        );
+        assert_eq!(LlmProvider::from_str("vllm"), Some(LlmProvider::Vllm));
         assert_eq!(LlmProvider::from_str("unknown"), None);
     }
 
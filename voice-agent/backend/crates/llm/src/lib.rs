@@ -3,14 +3,22 @@
 //! Features:
 //! - Multiple backend support (Ollama, Claude, OpenAI)
 //! - Native tool calling (Claude tool_use, text-based for Ollama)
+//! - Streaming tool-call parsing with JSON repair and schema validation
+//! - Grammar-constrained decoding (GBNF / JSON schema) where the backend supports it
+//! - Multi-backend routing by task with health checks and failover
 //! - Speculative execution (SLM-first, race parallel, hybrid streaming)
+//! - Speculative decoding with a draft model on llama.cpp servers
 //! - Streaming token generation
-//! - Context management
+//! - Context management (prompt assembly under a token budget)
 
+pub mod assembler;
 pub mod backend;
+pub mod constraint;
 pub mod prompt;
+pub mod router;
 pub mod speculative;
 pub mod streaming;
+pub mod tool_parser;
 // P0 FIX: Adapter bridging LlmBackend to core::LanguageModel
 pub mod adapter;
 // P0-3a: Claude backend with native tool_use support
@@ -18,9 +26,16 @@ pub mod claude;
 // P0-3c: LLM factory with provider abstraction
 pub mod factory;
 
+pub use assembler::{
+    estimate_text_tokens, AssemblyReport, PromptAssembler, PromptSection, SectionAction,
+    SectionKind, SectionReport, TruncationStrategy,
+};
+pub use constraint::{
+    json_schema_to_gbnf, resolve_constraint, slot_confirmation_constraint, tool_call_constraint,
+};
 pub use backend::{
-    FinishReason, GenerationResult, LlmBackend, LlmConfig, OllamaBackend, OpenAIBackend,
-    OpenAIConfig,
+    DraftStats, FinishReason, GenerationResult, LlmBackend, LlmConfig, OllamaBackend,
+    OpenAIBackend, OpenAIConfig,
 };
 // P0 FIX: Export adapter for clean dependency injection
 pub use adapter::LanguageModelAdapter;
@@ -34,8 +49,13 @@ pub use prompt::{
     parse_tool_call, BrandConfig, BrandDefaults, Message, ParsedToolCall, PersonaConfig,
     ProductFacts, PromptBuilder, ResponseTemplates, Role, ToolBuilder, ToolDefinition,
 };
+pub use router::{BackendStats, LlmRouter, RouterConfig};
 pub use speculative::{SpeculativeConfig, SpeculativeExecutor, SpeculativeMode, SpeculativeResult};
 pub use streaming::{GenerationEvent, StreamingGenerator, TokenStream};
+pub use tool_parser::{
+    parse_tool_json, repair_json, tool_call_reask_prompt, validate_tool_call,
+    StreamingToolCallParser, ToolCallError, ToolStreamEvent,
+};
 
 use thiserror::Error;
 
@@ -73,6 +93,22 @@ impl From<reqwest::Error> for LlmError {
     }
 }
 
+impl voice_agent_core::ErrorClass for LlmError {
+    fn kind(&self) -> voice_agent_core::ErrorKind {
+        use voice_agent_core::ErrorKind;
+        match self {
+            LlmError::Generation(_)
+            | LlmError::Api(_)
+            | LlmError::Network(_)
+            | LlmError::InvalidResponse(_) => ErrorKind::Unavailable,
+            LlmError::Timeout => ErrorKind::Timeout,
+            LlmError::ContextTooLong(..)
+            | LlmError::ModelNotFound(_)
+            | LlmError::Configuration(_) => ErrorKind::Internal,
+        }
+    }
+}
+
 impl From<LlmError> for voice_agent_core::Error {
     fn from(err: LlmError) -> Self {
         voice_agent_core::Error::Llm(err.to_string())
@@ -14,6 +14,9 @@
 
 use std::sync::OnceLock;
 
+use crate::assembler::{estimate_text_tokens, AssemblyReport, PromptAssembler, PromptSection, SectionKind};
+use crate::tool_parser::{StreamingToolCallParser, ToolStreamEvent};
+
 /// P19 FIX: Brand defaults loaded from domain config YAML at app startup.
 /// This allows deprecated methods to still be domain-agnostic.
 /// Generic placeholders are used until init() is called with domain config.
@@ -193,6 +196,16 @@ impl ToolBuilder {
         self
     }
 
+    /// Add a named format constraint (`phone`, `pincode`, `email`) to a string parameter
+    pub fn string_format(mut self, name: &str, format: &str) -> Self {
+        if let Some(prop) = self.properties.get_mut(name) {
+            if let Some(obj) = prop.as_object_mut() {
+                obj.insert("format".to_string(), serde_json::json!(format));
+            }
+        }
+        self
+    }
+
     /// Build the ToolDefinition
     pub fn build(self) -> ToolDefinition {
         let parameters = serde_json::json!({
@@ -216,36 +229,26 @@ impl ToolBuilder {
 /// P4 FIX: Parse tool call from LLM response
 ///
 /// Extracts tool calls in the format: `[TOOL_CALL: {"name": "...", "arguments": {...}}]`
+/// (or `<tool_call>...</tool_call>`), repairing malformed JSON where possible.
+/// See [`crate::tool_parser`] for the streaming variant.
 pub fn parse_tool_call(response: &str) -> Option<ParsedToolCall> {
-    // Look for the tool call pattern
-    let start_marker = "[TOOL_CALL:";
-    let end_marker = "]";
-
-    let start_idx = response.find(start_marker)?;
-    let json_start = start_idx + start_marker.len();
-
-    // Find matching end bracket
-    let remaining = &response[json_start..];
-    let end_idx = remaining.find(end_marker)?;
-    let json_str = remaining[..end_idx].trim();
-
-    // Parse the JSON
-    let value: serde_json::Value = serde_json::from_str(json_str).ok()?;
-    let name = value.get("name")?.as_str()?.to_string();
-    let arguments = value
-        .get("arguments")
-        .cloned()
-        .unwrap_or(serde_json::Value::Object(serde_json::Map::new()));
-
-    // Extract the text before and after the tool call
-    let text_before = response[..start_idx].trim().to_string();
-    let text_after = response[json_start + end_idx + 1..].trim().to_string();
-
-    Some(ParsedToolCall {
-        name,
-        arguments,
-        text_before,
-        text_after,
+    let mut parser = StreamingToolCallParser::new();
+    let mut events = parser.feed(response);
+    events.extend(parser.finish());
+
+    let mut call: Option<ParsedToolCall> = None;
+    let mut text_after = String::new();
+    for event in events {
+        match event {
+            ToolStreamEvent::ToolCall(parsed) if call.is_none() => call = Some(parsed),
+            ToolStreamEvent::Text(text) if call.is_some() => text_after.push_str(&text),
+            _ => {},
+        }
+    }
+
+    call.map(|mut call| {
+        call.text_after = text_after.trim().to_string();
+        call
     })
 }
 
@@ -293,8 +296,11 @@ impl Default for ProductFacts {
 }
 
 /// Prompt builder for voice agent (domain-agnostic)
+///
+/// Each `with_*` call adds a typed section; `build_with_limit` trims the
+/// lowest-priority sections first (see [`PromptAssembler`]).
 pub struct PromptBuilder {
-    messages: Vec<Message>,
+    sections: PromptAssembler,
     persona: PersonaConfig,
     /// P13 FIX: Config-driven product facts
     product_facts: ProductFacts,
@@ -316,7 +322,7 @@ impl PromptBuilder {
     /// Create a new prompt builder
     pub fn new() -> Self {
         Self {
-            messages: Vec::new(),
+            sections: PromptAssembler::new(),
             persona: PersonaConfig::default(),
             product_facts: ProductFacts::default(),
         }
@@ -370,7 +376,8 @@ impl PromptBuilder {
             &brand.helpline,
         );
 
-        self.messages.push(Message::system(system));
+        self.sections
+            .push(PromptSection::system(SectionKind::System, system));
         self
     }
 
@@ -405,7 +412,19 @@ impl PromptBuilder {
                 "## Relevant Information\n{}\n\nUse this information to answer the customer's question if relevant.",
                 context
             );
-            self.messages.push(Message::system(context_msg));
+            self.sections
+                .push(PromptSection::system(SectionKind::Context, context_msg));
+        }
+        self
+    }
+
+    /// Add a typed context section (content is used as-is)
+    ///
+    /// The kind decides the section's priority and how it is trimmed when
+    /// the prompt is over budget.
+    pub fn with_section(mut self, kind: SectionKind, content: &str) -> Self {
+        if !content.is_empty() {
+            self.sections.push(PromptSection::system(kind, content));
         }
         self
     }
@@ -431,20 +450,27 @@ impl PromptBuilder {
 
         if !profile_parts.is_empty() {
             let profile = format!("## Customer Profile\n{}", profile_parts.join("\n"));
-            self.messages.push(Message::system(profile));
+            self.sections
+                .push(PromptSection::system(SectionKind::CustomerProfile, profile));
         }
         self
     }
 
     /// Add conversation history
     pub fn with_history(mut self, history: &[Message]) -> Self {
-        self.messages.extend(history.iter().cloned());
+        self.sections.push(PromptSection::from_messages(
+            SectionKind::History,
+            history.to_vec(),
+        ));
         self
     }
 
     /// Add current user message
     pub fn user_message(mut self, message: &str) -> Self {
-        self.messages.push(Message::user(message));
+        self.sections.push(PromptSection::from_messages(
+            SectionKind::User,
+            vec![Message::user(message)],
+        ));
         self
     }
 
@@ -456,14 +482,13 @@ impl PromptBuilder {
     ) -> Self {
         if let Some(guidance) = prompts_config.get_stage_guidance(stage) {
             let wrapper = prompts_config.build_stage_guidance(guidance);
-            if !wrapper.is_empty() {
-                self.messages.push(Message::system(wrapper));
+            let content = if !wrapper.is_empty() {
+                wrapper
             } else {
-                self.messages.push(Message::system(format!(
-                    "## Current Stage Guidance\n{}",
-                    guidance
-                )));
-            }
+                format!("## Current Stage Guidance\n{}", guidance)
+            };
+            self.sections
+                .push(PromptSection::system(SectionKind::Guidance, content));
         }
         self
     }
@@ -549,13 +574,14 @@ Available tools:
             "\nOnly use tools when the customer's request requires specific calculations or data lookup. For general conversation, respond naturally without tools."
         );
 
-        self.messages.push(Message::system(tool_prompt));
+        self.sections
+            .push(PromptSection::system(SectionKind::Tools, tool_prompt));
         self
     }
 
     /// Build final message list
     pub fn build(self) -> Vec<Message> {
-        self.messages
+        self.sections.into_messages()
     }
 
     /// P1 FIX: Build as GenerateRequest for core::LanguageModel trait
@@ -564,7 +590,8 @@ Available tools:
     /// can be used with the LanguageModel trait from voice_agent_core.
     pub fn build_request(self) -> voice_agent_core::GenerateRequest {
         let core_messages: Vec<voice_agent_core::llm_types::Message> = self
-            .messages
+            .sections
+            .into_messages()
             .into_iter()
             .map(Self::convert_message_to_core)
             .collect();
@@ -609,108 +636,55 @@ Available tools:
 
     /// Internal helper for build_with_limit (also used by build_request_with_limit)
     fn build_with_limit_internal(self, max_tokens: usize) -> Vec<Message> {
-        let current_tokens = self.estimate_tokens();
-
-        if current_tokens <= max_tokens {
-            return self.messages;
-        }
-
-        // Separate system messages (keep all) from conversation history
-        let (system_msgs, conv_msgs): (Vec<_>, Vec<_>) = self
-            .messages
-            .into_iter()
-            .partition(|m| matches!(m.role, Role::System));
-
-        let system_tokens: usize = system_msgs
-            .iter()
-            .map(|m| Self::estimate_single_message_tokens(&m.content))
-            .sum();
-
-        let available_tokens = max_tokens.saturating_sub(system_tokens);
-
-        // Keep recent messages that fit within limit
-        let mut kept_msgs: Vec<Message> = Vec::new();
-        let mut used_tokens = 0;
+        self.assemble(max_tokens).0
+    }
 
-        for msg in conv_msgs.into_iter().rev() {
-            let msg_tokens = Self::estimate_single_message_tokens(&msg.content);
-            if used_tokens + msg_tokens <= available_tokens {
-                kept_msgs.push(msg);
-                used_tokens += msg_tokens;
-            } else {
-                break;
-            }
+    /// Assemble within a token budget, returning the assembly report
+    ///
+    /// Sections are trimmed lowest priority first: personalization is
+    /// dropped, then history loses its oldest messages, memory is
+    /// summarized and RAG context is cut. The system prompt, tools, tool
+    /// results and user message are never trimmed.
+    pub fn assemble(self, max_tokens: usize) -> (Vec<Message>, AssemblyReport) {
+        let (messages, report) = self.sections.assemble(max_tokens);
+
+        if report.over_budget() {
+            tracing::warn!(report = %report, "Prompt exceeds budget after trimming");
+        } else if report.was_trimmed() {
+            tracing::debug!(report = %report, "Prompt trimmed to budget");
+        } else {
+            tracing::trace!(report = %report, "Prompt assembled");
         }
 
-        kept_msgs.reverse();
-
-        // Combine: system messages first, then kept conversation
-        let mut result = system_msgs;
-        result.extend(kept_msgs);
-
-        tracing::debug!(
-            "Context truncated: {} -> {} tokens ({} messages kept)",
-            current_tokens,
-            system_tokens + used_tokens,
-            result.len()
-        );
-
-        result
+        (messages, report)
     }
 
     /// Build with context window limit
     ///
-    /// P0 FIX: Truncates conversation history to fit within token limit.
-    /// Preserves system prompt and most recent messages, removing oldest
-    /// non-system messages first.
+    /// P0 FIX: Trims sections to fit within the token limit, lowest
+    /// priority first (see [`PromptBuilder::assemble`]).
     pub fn build_with_limit(self, max_tokens: usize) -> Vec<Message> {
         self.build_with_limit_internal(max_tokens)
     }
 
-    /// Estimate tokens for a single message content
-    fn estimate_single_message_tokens(content: &str) -> usize {
-        use unicode_segmentation::UnicodeSegmentation;
-
-        let grapheme_count = content.graphemes(true).count();
-        let devanagari_count = content
-            .chars()
-            .filter(|c| ('\u{0900}'..='\u{097F}').contains(c))
-            .count();
-
-        if devanagari_count > grapheme_count / 3 {
-            grapheme_count.max(1) / 2
-        } else {
-            grapheme_count.max(1) / 4
-        }
-    }
-
     /// Get message count
     pub fn message_count(&self) -> usize {
-        self.messages.len()
+        self.sections
+            .sections()
+            .iter()
+            .map(|s| s.messages.len())
+            .sum()
     }
 
     /// Estimate token count
     ///
     /// P0 FIX: Improved estimation for Hindi/Devanagari text
     pub fn estimate_tokens(&self) -> usize {
-        use unicode_segmentation::UnicodeSegmentation;
-
-        self.messages
+        self.sections
+            .sections()
             .iter()
-            .map(|m| {
-                let grapheme_count = m.content.graphemes(true).count();
-                let devanagari_count = m
-                    .content
-                    .chars()
-                    .filter(|c| ('\u{0900}'..='\u{097F}').contains(c))
-                    .count();
-
-                if devanagari_count > grapheme_count / 3 {
-                    grapheme_count.max(1) / 2
-                } else {
-                    grapheme_count.max(1) / 4
-                }
-            })
+            .flat_map(|s| &s.messages)
+            .map(|m| estimate_text_tokens(&m.content))
             .sum()
     }
 }
@@ -880,6 +854,25 @@ mod tests {
         assert_eq!(messages[0].role, Role::System);
     }
 
+    #[test]
+    fn test_build_with_limit_trims_history_before_state() {
+        let history: Vec<Message> = (0..20)
+            .map(|i| Message::user(format!("Turn {} about my gold loan amount and rate", i)))
+            .collect();
+        let (messages, report) = PromptBuilder::new()
+            .with_section(SectionKind::DialogueState, "## Customer Details\nloan_amount: 5 lakh")
+            .with_history(&history)
+            .user_message("What is my EMI?")
+            .assemble(60);
+
+        assert!(report.final_tokens <= 60);
+        assert!(messages[0].content.contains("loan_amount: 5 lakh"));
+        assert_eq!(messages.last().unwrap().content, "What is my EMI?");
+        // Most recent history survives
+        assert!(messages.iter().any(|m| m.content.starts_with("Turn 19 ")));
+        assert!(!messages.iter().any(|m| m.content.starts_with("Turn 0 ")));
+    }
+
     #[test]
     fn test_templates() {
         // P0 FIX: Test non-deprecated response templates
@@ -1016,6 +1009,15 @@ mod tests {
         assert_eq!(parsed.text_after, "I'll wait for the results.");
     }
 
+    #[test]
+    fn test_parse_tool_call_nested_brackets_and_repair() {
+        let response = r#"[TOOL_CALL: {"name": "compare_lenders", "arguments": {"lenders": ["a", "b"],}}]"#;
+
+        let parsed = parse_tool_call(response).expect("Should parse repaired tool call");
+        assert_eq!(parsed.name, "compare_lenders");
+        assert_eq!(parsed.arguments["lenders"][1], "b");
+    }
+
     #[test]
     fn test_with_tools() {
         // P16 FIX: Tools created via ToolBuilder instead of hardcoded gold_loan_tools()
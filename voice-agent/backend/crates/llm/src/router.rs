@@ -0,0 +1,644 @@
+//! LLM Router
+//!
+//! Fronts several `LanguageModel` backends (llama.cpp, vLLM, Ollama,
+//! OpenAI-compatible HTTP, Claude) behind a single `LanguageModel`.
+//!
+//! - Requests are routed by [`LlmTask`]: each task has an ordered list of
+//!   backends, unrouted tasks use registration order.
+//! - A backend that fails `failure_threshold` times in a row is skipped for
+//!   `cooldown`, then retried (half-open). Background health checks bring
+//!   recovered backends back early.
+//! - Per-backend request, failure, latency, token and cost counters are kept
+//!   for the metrics endpoint.
+//!
+//! ## Example
+//! ```ignore
+//! let router = LlmRouter::new(RouterConfig::default())
+//!     .with_backend("local", llama_cpp, 0.0)
+//!     .with_backend("cloud", openai, 0.5)
+//!     .with_route(LlmTask::Summarization, &["local"])?;
+//! ```
+
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use futures::{Future, Stream, StreamExt};
+use parking_lot::Mutex;
+use serde::Serialize;
+use voice_agent_core::{
+    llm_types::ToolDefinition, ConstraintSupport, Error, GenerateRequest, GenerateResponse,
+    LanguageModel, LlmTask, Result, StreamChunk,
+};
+
+use crate::LlmError;
+
+/// Router health and failover settings
+#[derive(Debug, Clone)]
+pub struct RouterConfig {
+    /// Consecutive failures before a backend is taken out of rotation
+    pub failure_threshold: u32,
+    /// How long an unhealthy backend is skipped before being retried
+    pub cooldown: Duration,
+    /// Interval between background health checks
+    pub health_check_interval: Duration,
+}
+
+impl Default for RouterConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 3,
+            cooldown: Duration::from_secs(30),
+            health_check_interval: Duration::from_secs(15),
+        }
+    }
+}
+
+/// Snapshot of one backend's health and usage
+#[derive(Debug, Clone, Serialize)]
+pub struct BackendStats {
+    /// Registered backend name
+    pub name: String,
+    /// Model served by the backend
+    pub model: String,
+    /// Whether the backend is currently in rotation
+    pub healthy: bool,
+    /// Completed requests (successes and failures)
+    pub requests: u64,
+    /// Failed requests
+    pub failures: u64,
+    /// Mean latency of successful requests
+    pub avg_latency_ms: f64,
+    /// Tokens consumed (prompt + completion, estimated when not reported)
+    pub tokens: u64,
+    /// Estimated spend from `cost_per_1k_tokens`
+    pub estimated_cost: f64,
+}
+
+#[derive(Debug, Default)]
+struct BackendState {
+    consecutive_failures: u32,
+    unhealthy_until: Option<Instant>,
+    requests: u64,
+    failures: u64,
+    latency_ms_total: u64,
+    successes: u64,
+    tokens: u64,
+    cost: f64,
+}
+
+struct RoutedBackend {
+    name: String,
+    model: Arc<dyn LanguageModel>,
+    cost_per_1k_tokens: f64,
+    state: Mutex<BackendState>,
+}
+
+impl BackendState {
+    fn in_rotation(&self, now: Instant) -> bool {
+        match self.unhealthy_until {
+            Some(until) => now >= until,
+            None => true,
+        }
+    }
+}
+
+impl RoutedBackend {
+    fn is_healthy(&self, now: Instant) -> bool {
+        self.state.lock().in_rotation(now)
+    }
+}
+
+/// Routes requests across multiple LLM backends with failover
+pub struct LlmRouter {
+    backends: Vec<RoutedBackend>,
+    routes: HashMap<LlmTask, Vec<usize>>,
+    config: RouterConfig,
+}
+
+impl LlmRouter {
+    /// Create an empty router
+    pub fn new(config: RouterConfig) -> Self {
+        Self {
+            backends: Vec::new(),
+            routes: HashMap::new(),
+            config,
+        }
+    }
+
+    /// Register a backend; registration order is the default fallback order
+    pub fn with_backend(
+        mut self,
+        name: impl Into<String>,
+        model: Arc<dyn LanguageModel>,
+        cost_per_1k_tokens: f64,
+    ) -> Self {
+        self.backends.push(RoutedBackend {
+            name: name.into(),
+            model,
+            cost_per_1k_tokens,
+            state: Mutex::new(BackendState::default()),
+        });
+        self
+    }
+
+    /// Route a task to the named backends, tried in order
+    pub fn with_route(
+        mut self,
+        task: LlmTask,
+        backends: &[&str],
+    ) -> std::result::Result<Self, LlmError> {
+        let indices = backends
+            .iter()
+            .map(|name| {
+                self.index_of(name).ok_or_else(|| {
+                    LlmError::Configuration(format!(
+                        "route '{}' references unknown backend '{}'",
+                        task.as_str(),
+                        name
+                    ))
+                })
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        self.routes.insert(task, indices);
+        Ok(self)
+    }
+
+    /// Registered backend names in registration order
+    pub fn backend_names(&self) -> Vec<&str> {
+        self.backends.iter().map(|b| b.name.as_str()).collect()
+    }
+
+    /// Health check interval from the router config
+    pub fn health_check_interval(&self) -> Duration {
+        self.config.health_check_interval
+    }
+
+    fn index_of(&self, name: &str) -> Option<usize> {
+        self.backends.iter().position(|b| b.name == name)
+    }
+
+    /// Backends to try for a task: healthy ones in route order, then the
+    /// unhealthy ones as a last resort so an outage never blocks every request
+    fn candidates(&self, task: LlmTask) -> Vec<&RoutedBackend> {
+        let ordered: Vec<&RoutedBackend> = match self.routes.get(&task) {
+            Some(indices) => indices.iter().map(|&i| &self.backends[i]).collect(),
+            None => self.backends.iter().collect(),
+        };
+
+        let now = Instant::now();
+        let (mut healthy, unhealthy): (Vec<_>, Vec<_>) =
+            ordered.into_iter().partition(|b| b.is_healthy(now));
+        healthy.extend(unhealthy);
+        healthy
+    }
+
+    fn record_success(&self, backend: &RoutedBackend, latency: Duration, tokens: u64) {
+        let mut state = backend.state.lock();
+        if state.unhealthy_until.is_some() {
+            tracing::info!(backend = %backend.name, "LLM backend recovered");
+        }
+        state.consecutive_failures = 0;
+        state.unhealthy_until = None;
+        state.requests += 1;
+        state.successes += 1;
+        state.latency_ms_total += latency.as_millis() as u64;
+        state.tokens += tokens;
+        state.cost += tokens as f64 / 1000.0 * backend.cost_per_1k_tokens;
+    }
+
+    fn record_failure(&self, backend: &RoutedBackend, task: LlmTask, error: &Error) {
+        let mut state = backend.state.lock();
+        state.requests += 1;
+        state.failures += 1;
+        state.consecutive_failures += 1;
+
+        if state.consecutive_failures >= self.config.failure_threshold {
+            state.unhealthy_until = Some(Instant::now() + self.config.cooldown);
+            tracing::warn!(
+                backend = %backend.name,
+                task = task.as_str(),
+                failures = state.consecutive_failures,
+                error = %error,
+                "LLM backend marked unhealthy"
+            );
+        } else {
+            tracing::debug!(
+                backend = %backend.name,
+                task = task.as_str(),
+                error = %error,
+                "LLM backend request failed, trying next"
+            );
+        }
+    }
+
+    fn tokens_used(backend: &RoutedBackend, response: &GenerateResponse) -> u64 {
+        response
+            .usage
+            .as_ref()
+            .map(|usage| usage.total_tokens as u64)
+            .unwrap_or_else(|| backend.model.estimate_tokens(&response.text) as u64)
+    }
+
+    /// Try each candidate backend in turn until one succeeds
+    async fn run<'s, F, Fut>(&'s self, task: LlmTask, mut call: F) -> Result<GenerateResponse>
+    where
+        F: FnMut(&'s RoutedBackend) -> Fut + Send,
+        Fut: Future<Output = Result<GenerateResponse>> + Send,
+    {
+        let mut last_error = None;
+
+        for backend in self.candidates(task) {
+            let start = Instant::now();
+            match call(backend).await {
+                Ok(response) => {
+                    let tokens = Self::tokens_used(backend, &response);
+                    self.record_success(backend, start.elapsed(), tokens);
+                    return Ok(response);
+                },
+                Err(e) => {
+                    self.record_failure(backend, task, &e);
+                    last_error = Some(e);
+                },
+            }
+        }
+
+        Err(Self::exhausted(task, last_error))
+    }
+
+    fn exhausted(task: LlmTask, last_error: Option<Error>) -> Error {
+        match last_error {
+            Some(e) => Error::Llm(format!(
+                "all LLM backends failed for {}: {}",
+                task.as_str(),
+                e
+            )),
+            None => Error::Llm("no LLM backends registered".to_string()),
+        }
+    }
+
+    /// Probe every backend and update its health
+    pub async fn check_health(&self) {
+        for backend in &self.backends {
+            let available = backend.model.is_available().await;
+            let mut state = backend.state.lock();
+
+            if available {
+                if state.unhealthy_until.take().is_some() {
+                    tracing::info!(backend = %backend.name, "LLM backend passed health check");
+                }
+                state.consecutive_failures = 0;
+            } else {
+                if state.unhealthy_until.is_none() {
+                    tracing::warn!(backend = %backend.name, "LLM backend failed health check");
+                }
+                state.unhealthy_until = Some(Instant::now() + self.config.cooldown);
+            }
+        }
+    }
+
+    /// Run `check_health` on the configured interval until the router is dropped
+    pub fn spawn_health_checks(self: &Arc<Self>) -> tokio::task::JoinHandle<()> {
+        let router = Arc::downgrade(self);
+        let interval = self.config.health_check_interval;
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                let Some(router) = router.upgrade() else {
+                    break;
+                };
+                router.check_health().await;
+            }
+        })
+    }
+
+    /// Per-backend health and usage snapshot
+    pub fn stats(&self) -> Vec<BackendStats> {
+        let now = Instant::now();
+        self.backends
+            .iter()
+            .map(|backend| {
+                let state = backend.state.lock();
+                BackendStats {
+                    name: backend.name.clone(),
+                    model: backend.model.model_name().to_string(),
+                    healthy: state.in_rotation(now),
+                    requests: state.requests,
+                    failures: state.failures,
+                    avg_latency_ms: if state.successes > 0 {
+                        state.latency_ms_total as f64 / state.successes as f64
+                    } else {
+                        0.0
+                    },
+                    tokens: state.tokens,
+                    estimated_cost: state.cost,
+                }
+            })
+            .collect()
+    }
+
+    fn primary(&self) -> Option<&RoutedBackend> {
+        self.candidates(LlmTask::Dialogue).into_iter().next()
+    }
+}
+
+#[async_trait]
+impl LanguageModel for LlmRouter {
+    async fn generate(&self, request: GenerateRequest) -> Result<GenerateResponse> {
+        let task = request.task.unwrap_or_default();
+        self.run(task, |backend| backend.model.generate(request.clone()))
+            .await
+    }
+
+    fn generate_stream<'a>(
+        &'a self,
+        request: GenerateRequest,
+    ) -> Pin<Box<dyn Stream<Item = Result<StreamChunk>> + Send + 'a>> {
+        let task = request.task.unwrap_or_default();
+
+        Box::pin(async_stream::stream! {
+            let mut last_error = None;
+            let mut served = false;
+
+            for backend in self.candidates(task) {
+                let start = Instant::now();
+                let mut stream = backend.model.generate_stream(request.clone());
+
+                // Fail over only until the first chunk; after that the text is
+                // already on its way to the caller
+                match stream.next().await {
+                    Some(Ok(first)) => {
+                        served = true;
+                        let mut text = first.delta.clone();
+                        yield Ok(first);
+
+                        let mut failed = None;
+                        while let Some(item) = stream.next().await {
+                            match item {
+                                Ok(chunk) => {
+                                    text.push_str(&chunk.delta);
+                                    yield Ok(chunk);
+                                },
+                                Err(e) => {
+                                    failed = Some(e);
+                                    break;
+                                },
+                            }
+                        }
+
+                        match failed {
+                            None => {
+                                let tokens = backend.model.estimate_tokens(&text) as u64;
+                                self.record_success(backend, start.elapsed(), tokens);
+                            },
+                            Some(e) => {
+                                self.record_failure(backend, task, &e);
+                                yield Err(e);
+                            },
+                        }
+                        break;
+                    },
+                    Some(Err(e)) => {
+                        self.record_failure(backend, task, &e);
+                        last_error = Some(e);
+                    },
+                    None => {
+                        let e = Error::Llm(format!("{} returned an empty stream", backend.name));
+                        self.record_failure(backend, task, &e);
+                        last_error = Some(e);
+                    },
+                }
+            }
+
+            if !served {
+                yield Err(Self::exhausted(task, last_error));
+            }
+        })
+    }
+
+    async fn generate_with_tools(
+        &self,
+        request: GenerateRequest,
+        tools: &[ToolDefinition],
+    ) -> Result<GenerateResponse> {
+        let task = match request.task {
+            Some(task) => task,
+            None if tools.is_empty() => LlmTask::Dialogue,
+            None => LlmTask::ToolCalling,
+        };
+        self.run(task, |backend| {
+            backend.model.generate_with_tools(request.clone(), tools)
+        })
+        .await
+    }
+
+    async fn is_available(&self) -> bool {
+        for backend in &self.backends {
+            if backend.model.is_available().await {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn model_name(&self) -> &str {
+        self.primary()
+            .map(|backend| backend.model.model_name())
+            .unwrap_or("router")
+    }
+
+    fn constraint_support(&self) -> ConstraintSupport {
+        self.primary()
+            .map(|backend| backend.model.constraint_support())
+            .unwrap_or(ConstraintSupport::NONE)
+    }
+
+    fn context_size(&self) -> usize {
+        // A request may fail over to any backend, so budget for the smallest
+        self.backends
+            .iter()
+            .map(|backend| backend.model.context_size())
+            .min()
+            .unwrap_or(4096)
+    }
+
+    fn estimate_tokens(&self, text: &str) -> usize {
+        match self.primary() {
+            Some(backend) => backend.model.estimate_tokens(text),
+            None => text.len() / 4,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use voice_agent_core::llm_types::{FinishReason, TokenUsage};
+
+    struct FakeModel {
+        name: String,
+        fail: AtomicBool,
+        calls: AtomicUsize,
+    }
+
+    impl FakeModel {
+        fn new(name: &str, fail: bool) -> Arc<Self> {
+            Arc::new(Self {
+                name: name.to_string(),
+                fail: AtomicBool::new(fail),
+                calls: AtomicUsize::new(0),
+            })
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(Ordering::SeqCst)
+        }
+
+        fn respond(&self) -> Result<GenerateResponse> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.fail.load(Ordering::SeqCst) {
+                Err(Error::Llm(format!("{} down", self.name)))
+            } else {
+                Ok(GenerateResponse {
+                    text: format!("from {}", self.name),
+                    finish_reason: FinishReason::Stop,
+                    usage: Some(TokenUsage::new(900, 100)),
+                    tool_calls: Vec::new(),
+                })
+            }
+        }
+    }
+
+    #[async_trait]
+    impl LanguageModel for FakeModel {
+        async fn generate(&self, _request: GenerateRequest) -> Result<GenerateResponse> {
+            self.respond()
+        }
+
+        fn generate_stream<'a>(
+            &'a self,
+            _request: GenerateRequest,
+        ) -> Pin<Box<dyn Stream<Item = Result<StreamChunk>> + Send + 'a>> {
+            let result = self.respond();
+            Box::pin(futures::stream::iter(match result {
+                Ok(response) => vec![Ok(StreamChunk::text(response.text))],
+                Err(e) => vec![Err(e)],
+            }))
+        }
+
+        async fn generate_with_tools(
+            &self,
+            request: GenerateRequest,
+            _tools: &[ToolDefinition],
+        ) -> Result<GenerateResponse> {
+            self.generate(request).await
+        }
+
+        async fn is_available(&self) -> bool {
+            !self.fail.load(Ordering::SeqCst)
+        }
+
+        fn model_name(&self) -> &str {
+            &self.name
+        }
+    }
+
+    fn config(failure_threshold: u32) -> RouterConfig {
+        RouterConfig {
+            failure_threshold,
+            cooldown: Duration::from_secs(60),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failover_to_next_backend() {
+        let primary = FakeModel::new("primary", true);
+        let secondary = FakeModel::new("secondary", false);
+        let router = LlmRouter::new(config(3))
+            .with_backend("primary", primary.clone(), 0.0)
+            .with_backend("secondary", secondary.clone(), 2.0);
+
+        let response = router.generate(GenerateRequest::new("sys")).await.unwrap();
+        assert_eq!(response.text, "from secondary");
+        assert_eq!(primary.calls(), 1);
+
+        let stats = router.stats();
+        assert_eq!(stats[0].failures, 1);
+        assert!(stats[0].healthy);
+        assert_eq!(stats[1].requests, 1);
+        assert_eq!(stats[1].tokens, 1000);
+        assert!((stats[1].estimated_cost - 2.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_routes_by_task() {
+        let large = FakeModel::new("large", false);
+        let small = FakeModel::new("small", false);
+        let router = LlmRouter::new(config(3))
+            .with_backend("large", large.clone(), 1.0)
+            .with_backend("small", small.clone(), 0.1)
+            .with_route(LlmTask::Summarization, &["small", "large"])
+            .unwrap();
+
+        let summary = GenerateRequest::new("sys").with_task(LlmTask::Summarization);
+        assert_eq!(router.generate(summary).await.unwrap().text, "from small");
+        let turn = GenerateRequest::new("sys");
+        assert_eq!(router.generate(turn).await.unwrap().text, "from large");
+
+        assert!(LlmRouter::new(config(3))
+            .with_route(LlmTask::Dialogue, &["missing"])
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_unhealthy_backend_is_skipped_until_recovered() {
+        let flaky = FakeModel::new("flaky", true);
+        let stable = FakeModel::new("stable", false);
+        let router = LlmRouter::new(config(2))
+            .with_backend("flaky", flaky.clone(), 0.0)
+            .with_backend("stable", stable.clone(), 0.0);
+
+        for _ in 0..2 {
+            router.generate(GenerateRequest::new("sys")).await.unwrap();
+        }
+        assert!(!router.stats()[0].healthy);
+
+        router.generate(GenerateRequest::new("sys")).await.unwrap();
+        assert_eq!(flaky.calls(), 2, "unhealthy backend should be skipped");
+
+        flaky.fail.store(false, Ordering::SeqCst);
+        router.check_health().await;
+        assert!(router.stats()[0].healthy);
+        let response = router.generate(GenerateRequest::new("sys")).await.unwrap();
+        assert_eq!(response.text, "from flaky");
+    }
+
+    #[tokio::test]
+    async fn test_stream_failover_and_exhaustion() {
+        let down = FakeModel::new("down", true);
+        let up = FakeModel::new("up", false);
+        let router = LlmRouter::new(config(3))
+            .with_backend("down", down.clone(), 0.0)
+            .with_backend("up", up, 0.0);
+
+        let chunks: Vec<_> = router
+            .generate_stream(GenerateRequest::new("sys"))
+            .collect()
+            .await;
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].as_ref().unwrap().delta, "from up");
+
+        let router = LlmRouter::new(config(3)).with_backend("down", down, 0.0);
+        let err = router
+            .generate(GenerateRequest::new("sys"))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("all LLM backends failed"));
+    }
+}
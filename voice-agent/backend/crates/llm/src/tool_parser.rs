@@ -0,0 +1,887 @@
+//! Tool Call Parsing for Text-Based Tool Calling
+//!
+//! Small local models emit tool calls as text (`[TOOL_CALL: {...}]` or
+//! `<tool_call>{...}</tool_call>`) and often get the JSON wrong. This module:
+//!
+//! - detects tool-call intents incrementally while tokens stream in, so
+//!   the agent never speaks a half-emitted call
+//! - repairs common JSON errors (trailing commas, unquoted keys, single
+//!   quotes, Python literals, missing closing braces)
+//! - validates and coerces arguments against the tool's JSON schema
+//!   (types, enums, numeric ranges and named string formats)
+//! - builds a re-ask prompt when a call cannot be used
+
+use serde_json::{Map, Value};
+use thiserror::Error;
+use voice_agent_core::traits::normalize_format;
+
+use crate::prompt::{ParsedToolCall, ToolDefinition};
+
+/// Opening markers and the text that closes them
+const MARKERS: &[(&str, &str)] = &[("[TOOL_CALL:", "]"), ("<tool_call>", "</tool_call>")];
+
+/// Events produced while parsing streamed LLM output
+#[derive(Debug, Clone)]
+pub enum ToolStreamEvent {
+    /// Plain text, safe to speak
+    Text(String),
+    /// A tool-call marker was seen; arguments are still streaming
+    ToolCallStarted,
+    /// A complete (possibly repaired) tool call
+    ToolCall(ParsedToolCall),
+    /// A tool call that could not be parsed even after repair
+    Malformed { raw: String, error: String },
+}
+
+/// Incremental parser over streamed LLM output
+///
+/// Text that might be the start of a marker is held back until it is
+/// disambiguated, so `Text` events never contain tool-call syntax.
+#[derive(Debug, Default)]
+pub struct StreamingToolCallParser {
+    /// Text received but not yet emitted
+    pending: String,
+    /// All text emitted so far (becomes `text_before`)
+    emitted: String,
+    call: Option<CallState>,
+    /// Close marker still expected after a completed call
+    skip_close: Option<&'static str>,
+}
+
+#[derive(Debug)]
+struct CallState {
+    close: &'static str,
+    raw: String,
+    scanner: JsonScanner,
+}
+
+impl StreamingToolCallParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether a tool call is currently being received
+    pub fn in_tool_call(&self) -> bool {
+        self.call.is_some()
+    }
+
+    /// Feed a streamed delta
+    pub fn feed(&mut self, delta: &str) -> Vec<ToolStreamEvent> {
+        self.pending.push_str(delta);
+        let mut events = Vec::new();
+        self.drain(&mut events, false);
+        events
+    }
+
+    /// Flush at end of stream
+    ///
+    /// An unterminated tool call is repaired if possible, otherwise
+    /// reported as `Malformed`.
+    pub fn finish(&mut self) -> Vec<ToolStreamEvent> {
+        let mut events = Vec::new();
+        self.drain(&mut events, true);
+        if let Some(call) = self.call.take() {
+            events.push(self.complete_call(call.raw));
+        }
+        if !self.pending.is_empty() {
+            let text = std::mem::take(&mut self.pending);
+            self.emit_text(text, &mut events);
+        }
+        events
+    }
+
+    fn drain(&mut self, events: &mut Vec<ToolStreamEvent>, at_end: bool) {
+        loop {
+            if let Some(close) = self.skip_close {
+                let trimmed = self.pending.trim_start();
+                if let Some(rest) = trimmed.strip_prefix(close) {
+                    self.pending = rest.to_string();
+                    self.skip_close = None;
+                } else if close.starts_with(trimmed) && !at_end {
+                    // Could still be the close marker
+                    return;
+                } else {
+                    self.skip_close = None;
+                }
+            }
+
+            if let Some(mut call) = self.call.take() {
+                let mut consumed = self.pending.len();
+                let mut done = false;
+                for (i, c) in self.pending.char_indices() {
+                    match call.scanner.push(c) {
+                        ScanState::Continue => call.raw.push(c),
+                        ScanState::Complete => {
+                            call.raw.push(c);
+                            consumed = i + c.len_utf8();
+                            done = true;
+                            self.skip_close = Some(call.close);
+                            break;
+                        },
+                        ScanState::Interrupted => {
+                            // Close marker (or stray bracket) before the JSON closed
+                            consumed = i;
+                            done = true;
+                            self.skip_close = Some(call.close);
+                            break;
+                        },
+                    }
+                }
+                self.pending.drain(..consumed);
+                if done {
+                    events.push(self.complete_call(call.raw));
+                    continue;
+                }
+                self.call = Some(call);
+                return;
+            }
+
+            match find_marker(&self.pending) {
+                Some((idx, open, close)) => {
+                    let text: String = self.pending[..idx].to_string();
+                    self.emit_text(text, events);
+                    self.pending.drain(..idx + open.len());
+                    self.call = Some(CallState {
+                        close,
+                        raw: String::new(),
+                        scanner: JsonScanner::default(),
+                    });
+                    events.push(ToolStreamEvent::ToolCallStarted);
+                },
+                None => {
+                    let hold = if at_end {
+                        0
+                    } else {
+                        partial_marker_len(&self.pending)
+                    };
+                    let emit_to = self.pending.len() - hold;
+                    let text: String = self.pending.drain(..emit_to).collect();
+                    self.emit_text(text, events);
+                    return;
+                },
+            }
+        }
+    }
+
+    fn emit_text(&mut self, text: String, events: &mut Vec<ToolStreamEvent>) {
+        if text.is_empty() {
+            return;
+        }
+        self.emitted.push_str(&text);
+        events.push(ToolStreamEvent::Text(text));
+    }
+
+    fn complete_call(&self, raw: String) -> ToolStreamEvent {
+        match parse_tool_json(&raw) {
+            Ok((name, arguments)) => ToolStreamEvent::ToolCall(ParsedToolCall {
+                name,
+                arguments,
+                text_before: self.emitted.trim().to_string(),
+                text_after: String::new(),
+            }),
+            Err(error) => ToolStreamEvent::Malformed {
+                raw: raw.trim().to_string(),
+                error,
+            },
+        }
+    }
+}
+
+/// Find the earliest complete marker
+fn find_marker(text: &str) -> Option<(usize, &'static str, &'static str)> {
+    MARKERS
+        .iter()
+        .filter_map(|&(open, close)| text.find(open).map(|idx| (idx, open, close)))
+        .min_by_key(|&(idx, _, _)| idx)
+}
+
+/// Length of the longest suffix of `text` that is a proper prefix of a marker
+fn partial_marker_len(text: &str) -> usize {
+    MARKERS
+        .iter()
+        .flat_map(|&(open, _)| (1..open.len()).rev().find(|&n| text.ends_with(&open[..n])))
+        .max()
+        .unwrap_or(0)
+}
+
+enum ScanState {
+    Continue,
+    Complete,
+    Interrupted,
+}
+
+/// Tracks JSON nesting to find where a streamed object ends
+#[derive(Debug, Default)]
+struct JsonScanner {
+    stack: Vec<char>,
+    started: bool,
+    in_string: Option<char>,
+    escaped: bool,
+}
+
+impl JsonScanner {
+    fn push(&mut self, c: char) -> ScanState {
+        if let Some(quote) = self.in_string {
+            if self.escaped {
+                self.escaped = false;
+            } else if c == '\\' {
+                self.escaped = true;
+            } else if c == quote {
+                self.in_string = None;
+            }
+            return ScanState::Continue;
+        }
+
+        match c {
+            '"' | '\'' if self.started => self.in_string = Some(c),
+            '{' | '[' => {
+                self.started = true;
+                self.stack.push(c);
+            },
+            '}' | ']' if self.started => {
+                let expected = if c == '}' { '{' } else { '[' };
+                if self.stack.last() != Some(&expected) {
+                    return ScanState::Interrupted;
+                }
+                self.stack.pop();
+                if self.stack.is_empty() {
+                    return ScanState::Complete;
+                }
+            },
+            ']' => return ScanState::Interrupted,
+            '<' => return ScanState::Interrupted,
+            _ => {},
+        }
+        ScanState::Continue
+    }
+}
+
+/// Parse a tool call JSON object, repairing it if needed
+///
+/// Accepts `{"name", "arguments"}` (also `parameters`/`args`, `tool`) and
+/// the OpenAI `{"function": {"name", "arguments": "<json>"}}` shape.
+pub fn parse_tool_json(raw: &str) -> Result<(String, Value), String> {
+    let value = parse_lenient(raw)?;
+    let object = match value {
+        Value::Object(map) => map,
+        Value::Array(mut items) if !items.is_empty() => match items.remove(0) {
+            Value::Object(map) => map,
+            _ => return Err("tool call is not a JSON object".to_string()),
+        },
+        _ => return Err("tool call is not a JSON object".to_string()),
+    };
+    let object = match object.get("function").and_then(Value::as_object).cloned() {
+        Some(function) => function,
+        None => object,
+    };
+
+    let name = ["name", "tool", "tool_name"]
+        .iter()
+        .find_map(|key| object.get(*key).and_then(Value::as_str))
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .ok_or_else(|| "tool call has no \"name\"".to_string())?
+        .to_string();
+
+    let arguments = ["arguments", "parameters", "args", "input"]
+        .iter()
+        .find_map(|key| object.get(*key))
+        .cloned()
+        .unwrap_or_else(|| Value::Object(Map::new()));
+    let arguments = match arguments {
+        Value::String(s) if s.trim().is_empty() => Value::Object(Map::new()),
+        Value::String(s) => parse_lenient(&s)?,
+        Value::Null => Value::Object(Map::new()),
+        other => other,
+    };
+    if !arguments.is_object() {
+        return Err(format!("arguments for '{}' must be a JSON object", name));
+    }
+
+    Ok((name, arguments))
+}
+
+fn parse_lenient(raw: &str) -> Result<Value, String> {
+    serde_json::from_str(raw.trim()).or_else(|_| {
+        let repaired = repair_json(raw);
+        serde_json::from_str(&repaired).map_err(|e| format!("invalid JSON ({}): {}", e, repaired))
+    })
+}
+
+/// Repair common JSON mistakes made by small models
+///
+/// Handles code fences, single-quoted strings, unquoted keys and bare-word
+/// values, Python literals (`True`, `None`), trailing commas, unterminated
+/// strings and missing closing brackets. Text after the top-level value is
+/// dropped.
+pub fn repair_json(raw: &str) -> String {
+    let text = raw.trim();
+    let text = text
+        .strip_prefix("```json")
+        .or_else(|| text.strip_prefix("```"))
+        .unwrap_or(text);
+    let text = text.strip_suffix("```").unwrap_or(text).trim();
+    let text = match text.find(['{', '[']) {
+        Some(start) => &text[start..],
+        None => text,
+    };
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len() + 8);
+    let mut stack: Vec<char> = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '"' | '\'' => {
+                let (string, next) = read_string(&chars, i);
+                out.push_str(&string);
+                i = next;
+                continue;
+            },
+            '{' | '[' => {
+                stack.push(c);
+                out.push(c);
+            },
+            '}' | ']' => {
+                trim_trailing_comma(&mut out);
+                let expected = if c == '}' { '{' } else { '[' };
+                // Close anything left open inside this container
+                while let Some(&open) = stack.last() {
+                    if open == expected {
+                        break;
+                    }
+                    out.push(if open == '{' { '}' } else { ']' });
+                    stack.pop();
+                }
+                if stack.pop().is_some() {
+                    out.push(c);
+                }
+                if stack.is_empty() {
+                    return out;
+                }
+            },
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                let mut j = i;
+                while j < chars.len() && chars[j].is_whitespace() {
+                    j += 1;
+                }
+                let is_key = chars.get(j) == Some(&':');
+                if is_key {
+                    out.push_str(&format!("\"{}\"", word));
+                } else {
+                    match word.as_str() {
+                        "true" | "True" => out.push_str("true"),
+                        "false" | "False" => out.push_str("false"),
+                        "null" | "None" | "none" => out.push_str("null"),
+                        _ => out.push_str(&Value::String(word).to_string()),
+                    }
+                }
+                continue;
+            },
+            _ => out.push(c),
+        }
+        i += 1;
+    }
+
+    trim_trailing_comma(&mut out);
+    if out.trim_end().ends_with(':') {
+        out.push_str(" null");
+    }
+    while let Some(open) = stack.pop() {
+        out.push(if open == '{' { '}' } else { ']' });
+    }
+    out
+}
+
+/// Read a string starting at `start`, returning it double-quoted
+fn read_string(chars: &[char], start: usize) -> (String, usize) {
+    let quote = chars[start];
+    let mut out = String::from('"');
+    let mut i = start + 1;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '\\' if i + 1 < chars.len() => {
+                let next = chars[i + 1];
+                if next == '\'' {
+                    out.push('\'');
+                } else {
+                    out.push('\\');
+                    out.push(next);
+                }
+                i += 2;
+                continue;
+            },
+            c if c == quote => {
+                out.push('"');
+                return (out, i + 1);
+            },
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            _ => out.push(c),
+        }
+        i += 1;
+    }
+    // Unterminated string
+    out.push('"');
+    (out, i)
+}
+
+fn trim_trailing_comma(out: &mut String) {
+    let trimmed = out.trim_end();
+    if trimmed.ends_with(',') {
+        let len = trimmed.len() - 1;
+        out.truncate(len);
+    }
+}
+
+/// Why a parsed tool call cannot be executed
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ToolCallError {
+    #[error("unknown tool '{name}' (available: {})", available.join(", "))]
+    UnknownTool {
+        name: String,
+        available: Vec<String>,
+    },
+
+    #[error("invalid arguments for '{tool}': {}", errors.join("; "))]
+    InvalidArguments {
+        tool: String,
+        errors: Vec<String>,
+        /// Required arguments the call left out
+        missing: Vec<String>,
+        /// Arguments present but unusable (wrong type, out of range, bad format)
+        invalid: Vec<String>,
+    },
+
+    #[error("{error} in `{raw}`")]
+    Malformed { raw: String, error: String },
+}
+
+/// Validate a parsed call against the available tools
+///
+/// Tool names match case-insensitively with `-`/`_` treated alike.
+/// Arguments are coerced where unambiguous (`"5"` for a number, `"yes"`
+/// for a boolean, a `+91` phone number to its 10 digits); unknown arguments
+/// are dropped. Nothing is executed, so this doubles as the dry run before a
+/// call goes to the tool.
+pub fn validate_tool_call(
+    call: &ParsedToolCall,
+    tools: &[ToolDefinition],
+) -> Result<ParsedToolCall, ToolCallError> {
+    let normalize = |s: &str| s.trim().to_lowercase().replace('-', "_");
+    let tool = tools
+        .iter()
+        .find(|t| t.name == call.name)
+        .or_else(|| {
+            tools
+                .iter()
+                .find(|t| normalize(&t.name) == normalize(&call.name))
+        })
+        .ok_or_else(|| ToolCallError::UnknownTool {
+            name: call.name.clone(),
+            available: tools.iter().map(|t| t.name.clone()).collect(),
+        })?;
+
+    let mut errors = Vec::new();
+    let empty = Map::new();
+    let arguments = call.arguments.as_object().unwrap_or(&empty);
+    let properties = tool.parameters.get("properties").and_then(Value::as_object);
+    let required: Vec<&str> = tool
+        .parameters
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|r| r.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+
+    let mut coerced = Map::new();
+    let mut invalid = Vec::new();
+    for (key, value) in arguments {
+        match properties.and_then(|p| p.get(key)) {
+            Some(schema) => match coerce_value(value, schema) {
+                Ok(value) => {
+                    coerced.insert(key.clone(), value);
+                },
+                Err(e) => {
+                    errors.push(format!("{}: {}", key, e));
+                    invalid.push(key.clone());
+                },
+            },
+            // Without a schema, keep everything; otherwise drop extras
+            None if properties.is_none() => {
+                coerced.insert(key.clone(), value.clone());
+            },
+            None => {
+                tracing::debug!(tool = %tool.name, argument = %key, "Dropping unknown tool argument");
+            },
+        }
+    }
+    let mut missing = Vec::new();
+    for key in &required {
+        if !coerced.contains_key(*key) && !invalid.iter().any(|k| k == key) {
+            errors.push(format!("{}: required", key));
+            missing.push(key.to_string());
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(ToolCallError::InvalidArguments {
+            tool: tool.name.clone(),
+            errors,
+            missing,
+            invalid,
+        });
+    }
+
+    Ok(ParsedToolCall {
+        name: tool.name.clone(),
+        arguments: Value::Object(coerced),
+        text_before: call.text_before.clone(),
+        text_after: call.text_after.clone(),
+    })
+}
+
+fn coerce_value(value: &Value, schema: &Value) -> Result<Value, String> {
+    let value = coerce_type(value, schema)?;
+
+    if let Some(number) = value.as_f64() {
+        if let Some(min) = schema.get("minimum").and_then(Value::as_f64) {
+            if number < min {
+                return Err(format!("must be at least {}, got {}", min, number));
+            }
+        }
+        if let Some(max) = schema.get("maximum").and_then(Value::as_f64) {
+            if number > max {
+                return Err(format!("must be at most {}, got {}", max, number));
+            }
+        }
+    }
+
+    match (schema.get("format").and_then(Value::as_str), value.as_str()) {
+        (Some(format), Some(s)) => normalize_format(format, s)
+            .map(Value::String)
+            .ok_or_else(|| format!("not a valid {}: \"{}\"", format, s)),
+        _ => Ok(value),
+    }
+}
+
+fn coerce_type(value: &Value, schema: &Value) -> Result<Value, String> {
+    if let Some(options) = schema.get("enum").and_then(Value::as_array) {
+        if options.contains(value) {
+            return Ok(value.clone());
+        }
+        // Case-insensitive enum match for strings
+        if let Some(s) = value.as_str() {
+            if let Some(option) = options
+                .iter()
+                .find(|o| o.as_str().is_some_and(|o| o.eq_ignore_ascii_case(s.trim())))
+            {
+                return Ok(option.clone());
+            }
+        }
+        return Err(format!("must be one of {}", Value::Array(options.clone())));
+    }
+
+    let expected = schema.get("type").and_then(Value::as_str).unwrap_or("any");
+    match (expected, value) {
+        ("number", Value::Number(_))
+        | ("string", Value::String(_))
+        | ("boolean", Value::Bool(_)) => Ok(value.clone()),
+        ("integer", Value::Number(n)) => match n.as_i64() {
+            Some(_) => Ok(value.clone()),
+            None => match n.as_f64() {
+                Some(f) if f.fract() == 0.0 => Ok(Value::from(f as i64)),
+                _ => Err("expected an integer".to_string()),
+            },
+        },
+        ("number" | "integer", Value::String(s)) => {
+            let cleaned: String = s
+                .chars()
+                .filter(|c| !matches!(c, ',' | '₹' | ' ' | '%'))
+                .collect();
+            match cleaned.parse::<f64>() {
+                Ok(f) if expected == "integer" && f.fract() == 0.0 => Ok(Value::from(f as i64)),
+                Ok(f) if expected == "number" => Ok(serde_json::Number::from_f64(f)
+                    .map(Value::Number)
+                    .unwrap_or(Value::Null)),
+                _ => Err(format!("expected a {}, got \"{}\"", expected, s)),
+            }
+        },
+        ("string", Value::Number(n)) => Ok(Value::String(n.to_string())),
+        ("boolean", Value::String(s)) => match s.trim().to_lowercase().as_str() {
+            "true" | "yes" | "y" | "1" | "haan" => Ok(Value::Bool(true)),
+            "false" | "no" | "n" | "0" | "nahi" => Ok(Value::Bool(false)),
+            _ => Err(format!("expected a boolean, got \"{}\"", s)),
+        },
+        ("array", Value::Array(_)) | ("object", Value::Object(_)) | ("any", _) => Ok(value.clone()),
+        ("array", other) => Ok(Value::Array(vec![other.clone()])),
+        (expected, other) => Err(format!("expected {}, got {}", expected, other)),
+    }
+}
+
+/// Result of extracting a tool call from a complete response
+#[derive(Debug, Clone)]
+pub struct ToolCallExtraction {
+    /// Response text with any tool-call markup removed
+    pub text: String,
+    /// The validated call, `None` if the response had no tool call, or the
+    /// reason a call was present but unusable
+    pub call: Result<Option<ParsedToolCall>, String>,
+}
+
+/// Extract and validate the first tool call in a complete response
+pub fn extract_tool_call(response: &str, tools: &[ToolDefinition]) -> ToolCallExtraction {
+    let mut parser = StreamingToolCallParser::new();
+    let mut events = parser.feed(response);
+    events.extend(parser.finish());
+
+    let mut text = String::new();
+    let mut call = Ok(None);
+    for event in events {
+        match event {
+            ToolStreamEvent::Text(t) => text.push_str(&t),
+            ToolStreamEvent::ToolCall(parsed) if matches!(call, Ok(None)) => {
+                call = validate_tool_call(&parsed, tools)
+                    .map(Some)
+                    .map_err(|e| e.to_string());
+            },
+            ToolStreamEvent::Malformed { raw, error } if matches!(call, Ok(None)) => {
+                call = Err(format!("{} in `{}`", error, raw));
+            },
+            _ => {},
+        }
+    }
+
+    ToolCallExtraction {
+        text: text.trim().to_string(),
+        call,
+    }
+}
+
+/// Prompt asking the model to re-emit a failed tool call
+pub fn tool_call_reask_prompt(error: &str, tools: &[ToolDefinition]) -> String {
+    let names: Vec<&str> = tools.iter().map(|t| t.name.as_str()).collect();
+    format!(
+        "Your previous tool call could not be used: {}.\n\
+         If a tool is needed, reply with exactly one tool call and nothing else, in this format:\n\
+         [TOOL_CALL: {{\"name\": \"tool_name\", \"arguments\": {{\"param\": \"value\"}}}}]\n\
+         Use valid JSON with double-quoted keys and no trailing commas. Available tools: {}.\n\
+         If no tool is needed, answer the customer directly.",
+        error,
+        names.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prompt::ToolBuilder;
+
+    fn collect(parser: &mut StreamingToolCallParser, deltas: &[&str]) -> Vec<ToolStreamEvent> {
+        let mut events: Vec<ToolStreamEvent> = deltas.iter().flat_map(|d| parser.feed(d)).collect();
+        events.extend(parser.finish());
+        events
+    }
+
+    fn text_of(events: &[ToolStreamEvent]) -> String {
+        events
+            .iter()
+            .filter_map(|e| match e {
+                ToolStreamEvent::Text(t) => Some(t.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_streaming_detects_split_marker() {
+        let mut parser = StreamingToolCallParser::new();
+        let events = collect(
+            &mut parser,
+            &[
+                "Let me check. [TOOL",
+                "_CALL: {\"name\": \"check_eligibility\", \"argu",
+                "ments\": {\"weight\": 50}}] Done",
+            ],
+        );
+        assert_eq!(text_of(&events), "Let me check.  Done");
+        let call = events
+            .iter()
+            .find_map(|e| match e {
+                ToolStreamEvent::ToolCall(c) => Some(c),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(call.name, "check_eligibility");
+        assert_eq!(call.arguments["weight"], 50);
+        assert_eq!(call.text_before, "Let me check.");
+        assert!(events
+            .iter()
+            .any(|e| matches!(e, ToolStreamEvent::ToolCallStarted)));
+    }
+
+    #[test]
+    fn test_streaming_repairs_unterminated_call() {
+        let mut parser = StreamingToolCallParser::new();
+        let events = collect(
+            &mut parser,
+            &["<tool_call>{name: 'get_rates', arguments: {amount: 500000,}"],
+        );
+        let call = events
+            .iter()
+            .find_map(|e| match e {
+                ToolStreamEvent::ToolCall(c) => Some(c),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(call.name, "get_rates");
+        assert_eq!(call.arguments["amount"], 500000);
+    }
+
+    #[test]
+    fn test_streaming_plain_text_passes_through() {
+        let mut parser = StreamingToolCallParser::new();
+        let events = collect(
+            &mut parser,
+            &["Rates start at 9.5% [see", " branch] today."],
+        );
+        assert_eq!(text_of(&events), "Rates start at 9.5% [see branch] today.");
+    }
+
+    #[test]
+    fn test_repair_json() {
+        assert_eq!(
+            repair_json("{'name': 'x', 'arguments': {'a': True, 'b': None,},}"),
+            r#"{"name": "x", "arguments": {"a": true, "b": null}}"#
+        );
+        assert_eq!(
+            repair_json("```json\n{\"a\": [1, 2,\n```"),
+            "{\"a\": [1, 2]}"
+        );
+        assert_eq!(repair_json("{\"a\": 1} trailing text"), "{\"a\": 1}");
+    }
+
+    #[test]
+    fn test_parse_openai_function_shape() {
+        let (name, args) = parse_tool_json(
+            r#"{"function": {"name": "get_rates", "arguments": "{\"amount\": 5}"}}"#,
+        )
+        .unwrap();
+        assert_eq!(name, "get_rates");
+        assert_eq!(args["amount"], 5);
+        assert!(parse_tool_json(r#"{"arguments": {}}"#).is_err());
+    }
+
+    #[test]
+    fn test_validate_coerces_and_reports() {
+        let tools = vec![ToolBuilder::new("calculate_emi", "EMI")
+            .param("amount", "number", "Loan amount", true)
+            .param("tenure_months", "integer", "Tenure", true)
+            .param("scheme", "string", "Repayment scheme", false)
+            .string_enum("scheme", &["standard", "bullet"])
+            .build()];
+
+        let call = ParsedToolCall {
+            name: "Calculate-EMI".to_string(),
+            arguments: serde_json::json!({"amount": "5,00,000", "tenure_months": 12.0, "scheme": "Bullet", "extra": 1}),
+            text_before: String::new(),
+            text_after: String::new(),
+        };
+        let valid = validate_tool_call(&call, &tools).unwrap();
+        assert_eq!(valid.name, "calculate_emi");
+        assert_eq!(valid.arguments["amount"], 500000.0);
+        assert_eq!(valid.arguments["tenure_months"], 12);
+        assert_eq!(valid.arguments["scheme"], "bullet");
+        assert!(valid.arguments.get("extra").is_none());
+
+        let missing = ParsedToolCall {
+            arguments: serde_json::json!({"amount": "lots"}),
+            ..call.clone()
+        };
+        let err = validate_tool_call(&missing, &tools).unwrap_err();
+        assert!(err.to_string().contains("amount: expected a number"));
+        assert!(err.to_string().contains("tenure_months: required"));
+        let ToolCallError::InvalidArguments {
+            missing, invalid, ..
+        } = err
+        else {
+            panic!("expected invalid arguments");
+        };
+        assert_eq!(missing, vec!["tenure_months"]);
+        assert_eq!(invalid, vec!["amount"]);
+
+        let unknown = ParsedToolCall {
+            name: "book_flight".to_string(),
+            ..call
+        };
+        assert!(matches!(
+            validate_tool_call(&unknown, &tools),
+            Err(ToolCallError::UnknownTool { .. })
+        ));
+        assert!(tool_call_reask_prompt("bad json", &tools).contains("calculate_emi"));
+    }
+
+    #[test]
+    fn test_validate_checks_ranges_and_formats() {
+        let tools = vec![ToolBuilder::new("schedule_callback", "Callback")
+            .param("phone", "string", "Mobile number", true)
+            .string_format("phone", "phone")
+            .param("weight", "number", "Gold weight", false)
+            .number_range("weight", Some(1.0), Some(10000.0))
+            .build()];
+        let call = |arguments: Value| ParsedToolCall {
+            name: "schedule_callback".to_string(),
+            arguments,
+            text_before: String::new(),
+            text_after: String::new(),
+        };
+
+        let valid = validate_tool_call(
+            &call(serde_json::json!({"phone": "+91 98765 43210"})),
+            &tools,
+        )
+        .unwrap();
+        assert_eq!(valid.arguments["phone"], "9876543210");
+
+        let err = validate_tool_call(
+            &call(serde_json::json!({"phone": "12345", "weight": 0})),
+            &tools,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("phone: not a valid phone"));
+        assert!(err.to_string().contains("weight: must be at least 1"));
+        assert!(matches!(
+            err,
+            ToolCallError::InvalidArguments { ref invalid, ref missing, .. }
+                if invalid.len() == 2 && missing.is_empty()
+        ));
+    }
+
+    #[test]
+    fn test_extract_tool_call() {
+        let tools = vec![ToolBuilder::new("get_rates", "Rates")
+            .param("amount", "number", "Loan amount", true)
+            .build()];
+
+        let ok = extract_tool_call(
+            "One moment. [TOOL_CALL: {\"name\": \"get_rates\", \"arguments\": {amount: 5}}]",
+            &tools,
+        );
+        assert_eq!(ok.text, "One moment.");
+        assert_eq!(ok.call.unwrap().unwrap().arguments["amount"], 5);
+
+        let none = extract_tool_call("Rates start at 9.5%.", &tools);
+        assert!(matches!(none.call, Ok(None)));
+
+        let bad = extract_tool_call("[TOOL_CALL: not json at all]", &tools);
+        assert!(bad.call.is_err());
+        assert!(bad.text.is_empty());
+    }
+}
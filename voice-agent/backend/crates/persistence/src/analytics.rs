@@ -0,0 +1,610 @@
+//! Conversation analytics
+//!
+//! Each finished session is recorded as a `SessionOutcome` (stages reached,
+//! drop-off stage, goal, tools called, duration, language, interruptions).
+//! A rollup job aggregates a day's outcomes into `DailyRollup` rows, one per
+//! language plus an `all` row, and funnel reports are read from the rollups.
+
+use crate::{PersistenceError, ScyllaClient};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use tokio::sync::RwLock;
+
+/// Language key of the rollup row covering every language
+pub const ALL_LANGUAGES: &str = "all";
+
+/// Longest range a rollup query may span
+const MAX_QUERY_DAYS: i64 = 366;
+
+/// How one session went
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionOutcome {
+    pub session_id: String,
+    pub started_at: DateTime<Utc>,
+    pub duration_secs: u64,
+    /// Language code the caller spoke
+    pub language: String,
+    /// Stages the conversation reached, in order
+    pub stages_reached: Vec<String>,
+    /// Stage the conversation ended in (the drop-off stage)
+    pub final_stage: String,
+    /// Dialogue goal, if one was identified
+    pub goal: Option<String>,
+    /// Whether every required slot for the goal was collected
+    pub goal_reached: bool,
+    /// Tools that ran successfully (a tool may appear more than once)
+    pub tools_called: Vec<String>,
+    /// Times the caller barged in on a response
+    pub interruptions: u32,
+    pub turns: u32,
+}
+
+impl SessionOutcome {
+    /// Day (UTC) the outcome is counted under
+    pub fn day(&self) -> NaiveDate {
+        self.started_at.date_naive()
+    }
+}
+
+/// Aggregated outcomes for one day and language
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DailyRollup {
+    pub day: NaiveDate,
+    /// Language code, or `all`
+    pub language: String,
+    pub sessions: u64,
+    pub goals_reached: u64,
+    pub total_duration_secs: u64,
+    pub interruptions: u64,
+    /// Sessions that reached each stage
+    pub stage_reached: BTreeMap<String, u64>,
+    /// Sessions that ended in each stage
+    pub drop_off: BTreeMap<String, u64>,
+    /// Sessions that called each tool at least once
+    pub tool_sessions: BTreeMap<String, u64>,
+    /// Total successful calls per tool
+    pub tool_calls: BTreeMap<String, u64>,
+}
+
+impl DailyRollup {
+    pub fn new(day: NaiveDate, language: impl Into<String>) -> Self {
+        Self {
+            day,
+            language: language.into(),
+            sessions: 0,
+            goals_reached: 0,
+            total_duration_secs: 0,
+            interruptions: 0,
+            stage_reached: BTreeMap::new(),
+            drop_off: BTreeMap::new(),
+            tool_sessions: BTreeMap::new(),
+            tool_calls: BTreeMap::new(),
+        }
+    }
+
+    /// Aggregate a day's outcomes into per-language rows and an `all` row
+    pub fn aggregate(day: NaiveDate, outcomes: &[SessionOutcome]) -> Vec<DailyRollup> {
+        let mut total = DailyRollup::new(day, ALL_LANGUAGES);
+        let mut by_language: BTreeMap<String, DailyRollup> = BTreeMap::new();
+        for outcome in outcomes.iter().filter(|o| o.day() == day) {
+            total.add(outcome);
+            by_language
+                .entry(outcome.language.clone())
+                .or_insert_with(|| DailyRollup::new(day, outcome.language.clone()))
+                .add(outcome);
+        }
+
+        let mut rollups = vec![total];
+        rollups.extend(by_language.into_values());
+        rollups
+    }
+
+    /// Count one session
+    pub fn add(&mut self, outcome: &SessionOutcome) {
+        self.sessions += 1;
+        self.goals_reached += outcome.goal_reached as u64;
+        self.total_duration_secs += outcome.duration_secs;
+        self.interruptions += outcome.interruptions as u64;
+
+        let mut stages: Vec<&String> = outcome.stages_reached.iter().collect();
+        stages.sort();
+        stages.dedup();
+        for stage in stages {
+            *self.stage_reached.entry(stage.clone()).or_default() += 1;
+        }
+        *self
+            .drop_off
+            .entry(outcome.final_stage.clone())
+            .or_default() += 1;
+
+        let mut tools: Vec<&String> = outcome.tools_called.iter().collect();
+        for tool in &tools {
+            *self.tool_calls.entry((*tool).clone()).or_default() += 1;
+        }
+        tools.sort();
+        tools.dedup();
+        for tool in tools {
+            *self.tool_sessions.entry(tool.clone()).or_default() += 1;
+        }
+    }
+
+    /// Add another rollup's counts (e.g. to cover a date range)
+    pub fn merge(&mut self, other: &DailyRollup) {
+        self.sessions += other.sessions;
+        self.goals_reached += other.goals_reached;
+        self.total_duration_secs += other.total_duration_secs;
+        self.interruptions += other.interruptions;
+        for (counts, other_counts) in [
+            (&mut self.stage_reached, &other.stage_reached),
+            (&mut self.drop_off, &other.drop_off),
+            (&mut self.tool_sessions, &other.tool_sessions),
+            (&mut self.tool_calls, &other.tool_calls),
+        ] {
+            for (key, count) in other_counts {
+                *counts.entry(key.clone()).or_default() += count;
+            }
+        }
+    }
+
+    /// Sessions that reached a funnel step: a stage, else a tool
+    pub fn step_sessions(&self, step: &str) -> u64 {
+        self.stage_reached
+            .get(step)
+            .or_else(|| self.tool_sessions.get(step))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Average session length in seconds
+    pub fn average_duration_secs(&self) -> f64 {
+        if self.sessions == 0 {
+            return 0.0;
+        }
+        self.total_duration_secs as f64 / self.sessions as f64
+    }
+}
+
+/// One step of a conversion funnel
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FunnelStep {
+    /// Stage or tool name
+    pub step: String,
+    pub sessions: u64,
+    /// Share of the previous step's sessions that reached this one
+    pub conversion_from_previous: f64,
+    /// Share of all sessions that reached this one
+    pub conversion_from_start: f64,
+}
+
+/// Build a funnel from rollups covering a date range
+pub fn funnel(rollups: &[DailyRollup], steps: &[String]) -> Vec<FunnelStep> {
+    let mut total = DailyRollup::new(NaiveDate::MIN, ALL_LANGUAGES);
+    for rollup in rollups {
+        total.merge(rollup);
+    }
+
+    let ratio = |part: u64, whole: u64| {
+        if whole == 0 {
+            0.0
+        } else {
+            part as f64 / whole as f64
+        }
+    };
+    let mut previous = total.sessions;
+    steps
+        .iter()
+        .map(|step| {
+            let sessions = total.step_sessions(step);
+            let result = FunnelStep {
+                step: step.clone(),
+                sessions,
+                conversion_from_previous: ratio(sessions, previous),
+                conversion_from_start: ratio(sessions, total.sessions),
+            };
+            previous = sessions;
+            result
+        })
+        .collect()
+}
+
+/// Check a query range and list its days
+pub fn days_in_range(from: NaiveDate, to: NaiveDate) -> Result<Vec<NaiveDate>, PersistenceError> {
+    if to < from {
+        return Err(PersistenceError::InvalidData(
+            "range end is before its start".to_string(),
+        ));
+    }
+    if (to - from).num_days() >= MAX_QUERY_DAYS {
+        return Err(PersistenceError::InvalidData(format!(
+            "range cannot exceed {} days",
+            MAX_QUERY_DAYS
+        )));
+    }
+    Ok(from.iter_days().take_while(|d| *d <= to).collect())
+}
+
+/// Analytics store trait
+#[async_trait]
+pub trait AnalyticsStore: Send + Sync {
+    /// Record a finished session (replaces an earlier record for it)
+    async fn record_outcome(&self, outcome: &SessionOutcome) -> Result<(), PersistenceError>;
+
+    /// Outcomes of sessions that started on `day`
+    async fn outcomes_for_day(
+        &self,
+        day: NaiveDate,
+    ) -> Result<Vec<SessionOutcome>, PersistenceError>;
+
+    /// Insert or replace a rollup row
+    async fn upsert_rollup(&self, rollup: &DailyRollup) -> Result<(), PersistenceError>;
+
+    /// Rollups for a date range (inclusive) and language (`all` for totals)
+    async fn rollups(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        language: &str,
+    ) -> Result<Vec<DailyRollup>, PersistenceError>;
+}
+
+/// Re-aggregate one day's outcomes into its rollup rows
+pub async fn roll_up_day(
+    store: &dyn AnalyticsStore,
+    day: NaiveDate,
+) -> Result<usize, PersistenceError> {
+    let outcomes = store.outcomes_for_day(day).await?;
+    let rollups = DailyRollup::aggregate(day, &outcomes);
+    for rollup in &rollups {
+        store.upsert_rollup(rollup).await?;
+    }
+    Ok(outcomes.len())
+}
+
+/// ScyllaDB implementation of the analytics store
+#[derive(Clone)]
+pub struct ScyllaAnalyticsStore {
+    client: ScyllaClient,
+}
+
+impl ScyllaAnalyticsStore {
+    pub fn new(client: ScyllaClient) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl AnalyticsStore for ScyllaAnalyticsStore {
+    async fn record_outcome(&self, outcome: &SessionOutcome) -> Result<(), PersistenceError> {
+        let query = format!(
+            "INSERT INTO {}.session_outcomes (
+                day, session_id, started_at, duration_secs, language, stages_json,
+                final_stage, goal, goal_reached, tools_json, interruptions, turns
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            self.client.keyspace()
+        );
+
+        self.client
+            .execute(
+                query,
+                (
+                    outcome.day().to_string(),
+                    &outcome.session_id,
+                    outcome.started_at.timestamp_millis(),
+                    outcome.duration_secs as i64,
+                    &outcome.language,
+                    serde_json::to_string(&outcome.stages_reached)?,
+                    &outcome.final_stage,
+                    &outcome.goal,
+                    outcome.goal_reached,
+                    serde_json::to_string(&outcome.tools_called)?,
+                    outcome.interruptions as i32,
+                    outcome.turns as i32,
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn outcomes_for_day(
+        &self,
+        day: NaiveDate,
+    ) -> Result<Vec<SessionOutcome>, PersistenceError> {
+        let query = format!(
+            "SELECT session_id, started_at, duration_secs, language, stages_json,
+                    final_stage, goal, goal_reached, tools_json, interruptions, turns
+             FROM {}.session_outcomes WHERE day = ?",
+            self.client.keyspace()
+        );
+
+        let result = self.client.execute(query, (day.to_string(),)).await?;
+
+        let mut outcomes = Vec::new();
+        for row in result.rows.unwrap_or_default() {
+            let (
+                session_id,
+                started_at,
+                duration_secs,
+                language,
+                stages_json,
+                final_stage,
+                goal,
+                goal_reached,
+                tools_json,
+                interruptions,
+                turns,
+            ): (
+                String,
+                i64,
+                i64,
+                Option<String>,
+                Option<String>,
+                Option<String>,
+                Option<String>,
+                Option<bool>,
+                Option<String>,
+                Option<i32>,
+                Option<i32>,
+            ) = row
+                .into_typed()
+                .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+
+            outcomes.push(SessionOutcome {
+                session_id,
+                started_at: DateTime::from_timestamp_millis(started_at).unwrap_or_else(Utc::now),
+                duration_secs: duration_secs.max(0) as u64,
+                language: language.unwrap_or_default(),
+                stages_reached: match stages_json {
+                    Some(json) => serde_json::from_str(&json)?,
+                    None => Vec::new(),
+                },
+                final_stage: final_stage.unwrap_or_default(),
+                goal,
+                goal_reached: goal_reached.unwrap_or(false),
+                tools_called: match tools_json {
+                    Some(json) => serde_json::from_str(&json)?,
+                    None => Vec::new(),
+                },
+                interruptions: interruptions.unwrap_or(0).max(0) as u32,
+                turns: turns.unwrap_or(0).max(0) as u32,
+            });
+        }
+
+        Ok(outcomes)
+    }
+
+    async fn upsert_rollup(&self, rollup: &DailyRollup) -> Result<(), PersistenceError> {
+        let query = format!(
+            "INSERT INTO {}.analytics_daily (
+                day, language, sessions, goals_reached, total_duration_secs, interruptions,
+                stage_reached_json, drop_off_json, tool_sessions_json, tool_calls_json,
+                updated_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            self.client.keyspace()
+        );
+
+        self.client
+            .execute(
+                query,
+                (
+                    rollup.day.to_string(),
+                    &rollup.language,
+                    rollup.sessions as i64,
+                    rollup.goals_reached as i64,
+                    rollup.total_duration_secs as i64,
+                    rollup.interruptions as i64,
+                    serde_json::to_string(&rollup.stage_reached)?,
+                    serde_json::to_string(&rollup.drop_off)?,
+                    serde_json::to_string(&rollup.tool_sessions)?,
+                    serde_json::to_string(&rollup.tool_calls)?,
+                    Utc::now().timestamp_millis(),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn rollups(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        language: &str,
+    ) -> Result<Vec<DailyRollup>, PersistenceError> {
+        let query = format!(
+            "SELECT sessions, goals_reached, total_duration_secs, interruptions,
+                    stage_reached_json, drop_off_json, tool_sessions_json, tool_calls_json
+             FROM {}.analytics_daily WHERE day = ? AND language = ?",
+            self.client.keyspace()
+        );
+
+        let counts = |json: Option<String>| -> Result<BTreeMap<String, u64>, PersistenceError> {
+            Ok(match json {
+                Some(json) => serde_json::from_str(&json)?,
+                None => BTreeMap::new(),
+            })
+        };
+
+        let mut rollups = Vec::new();
+        for day in days_in_range(from, to)? {
+            let result = self
+                .client
+                .execute(query.clone(), (day.to_string(), language))
+                .await?;
+
+            let Some(row) = result.rows.unwrap_or_default().into_iter().next() else {
+                continue;
+            };
+            let (
+                sessions,
+                goals_reached,
+                total_duration_secs,
+                interruptions,
+                stage_reached_json,
+                drop_off_json,
+                tool_sessions_json,
+                tool_calls_json,
+            ): (
+                i64,
+                i64,
+                i64,
+                i64,
+                Option<String>,
+                Option<String>,
+                Option<String>,
+                Option<String>,
+            ) = row
+                .into_typed()
+                .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+
+            rollups.push(DailyRollup {
+                day,
+                language: language.to_string(),
+                sessions: sessions.max(0) as u64,
+                goals_reached: goals_reached.max(0) as u64,
+                total_duration_secs: total_duration_secs.max(0) as u64,
+                interruptions: interruptions.max(0) as u64,
+                stage_reached: counts(stage_reached_json)?,
+                drop_off: counts(drop_off_json)?,
+                tool_sessions: counts(tool_sessions_json)?,
+                tool_calls: counts(tool_calls_json)?,
+            });
+        }
+
+        Ok(rollups)
+    }
+}
+
+/// In-memory analytics store
+///
+/// Used when ScyllaDB is not configured; nothing survives restarts.
+#[derive(Default)]
+pub struct InMemoryAnalyticsStore {
+    outcomes: RwLock<HashMap<String, SessionOutcome>>,
+    rollups: RwLock<HashMap<(NaiveDate, String), DailyRollup>>,
+}
+
+impl InMemoryAnalyticsStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl AnalyticsStore for InMemoryAnalyticsStore {
+    async fn record_outcome(&self, outcome: &SessionOutcome) -> Result<(), PersistenceError> {
+        self.outcomes
+            .write()
+            .await
+            .insert(outcome.session_id.clone(), outcome.clone());
+        Ok(())
+    }
+
+    async fn outcomes_for_day(
+        &self,
+        day: NaiveDate,
+    ) -> Result<Vec<SessionOutcome>, PersistenceError> {
+        Ok(self
+            .outcomes
+            .read()
+            .await
+            .values()
+            .filter(|o| o.day() == day)
+            .cloned()
+            .collect())
+    }
+
+    async fn upsert_rollup(&self, rollup: &DailyRollup) -> Result<(), PersistenceError> {
+        self.rollups
+            .write()
+            .await
+            .insert((rollup.day, rollup.language.clone()), rollup.clone());
+        Ok(())
+    }
+
+    async fn rollups(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        language: &str,
+    ) -> Result<Vec<DailyRollup>, PersistenceError> {
+        let rollups = self.rollups.read().await;
+        Ok(days_in_range(from, to)?
+            .into_iter()
+            .filter_map(|day| rollups.get(&(day, language.to_string())).cloned())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(id: &str, language: &str, stages: &[&str], tools: &[&str]) -> SessionOutcome {
+        SessionOutcome {
+            session_id: id.to_string(),
+            started_at: DateTime::parse_from_rfc3339("2025-01-15T10:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+            duration_secs: 120,
+            language: language.to_string(),
+            stages_reached: stages.iter().map(|s| s.to_string()).collect(),
+            final_stage: stages.last().unwrap().to_string(),
+            goal: None,
+            goal_reached: !tools.is_empty(),
+            tools_called: tools.iter().map(|s| s.to_string()).collect(),
+            interruptions: 1,
+            turns: 6,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rollup_and_funnel() {
+        let store = InMemoryAnalyticsStore::new();
+        let day = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+        for o in [
+            outcome("a", "hi", &["greeting", "discovery"], &[]),
+            outcome(
+                "b",
+                "hi",
+                &["greeting", "discovery", "presentation"],
+                &["check_eligibility", "check_eligibility"],
+            ),
+            outcome(
+                "c",
+                "en",
+                &["greeting", "discovery", "presentation", "closing"],
+                &["schedule_appointment"],
+            ),
+            outcome("d", "en", &["greeting"], &[]),
+        ] {
+            store.record_outcome(&o).await.unwrap();
+        }
+
+        assert_eq!(roll_up_day(&store, day).await.unwrap(), 4);
+        let all = store.rollups(day, day, ALL_LANGUAGES).await.unwrap();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].sessions, 4);
+        assert_eq!(all[0].goals_reached, 2);
+        assert_eq!(all[0].drop_off["discovery"], 1);
+        assert_eq!(all[0].tool_calls["check_eligibility"], 2);
+        assert_eq!(all[0].tool_sessions["check_eligibility"], 1);
+        let hindi = store.rollups(day, day, "hi").await.unwrap();
+        assert_eq!(hindi[0].sessions, 2);
+
+        let steps: Vec<String> = ["discovery", "presentation", "schedule_appointment"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let report = funnel(&all, &steps);
+        assert_eq!(report[0].sessions, 3);
+        assert_eq!(report[1].sessions, 2);
+        assert_eq!(report[2].sessions, 1);
+        assert!((report[0].conversion_from_start - 0.75).abs() < 1e-9);
+        assert!((report[2].conversion_from_previous - 0.5).abs() < 1e-9);
+
+        assert!(days_in_range(day, day.pred_opt().unwrap()).is_err());
+    }
+}
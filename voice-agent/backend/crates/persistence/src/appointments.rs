@@ -15,6 +15,8 @@ pub enum AppointmentStatus {
     Cancelled,
     Completed,
     NoShow,
+    /// Customer replied RESCHEDULE to the confirmation SMS
+    RescheduleRequested,
 }
 
 impl AppointmentStatus {
@@ -25,6 +27,7 @@ impl AppointmentStatus {
             Self::Cancelled => "cancelled",
             Self::Completed => "completed",
             Self::NoShow => "no_show",
+            Self::RescheduleRequested => "reschedule_requested",
         }
     }
 
@@ -35,6 +38,7 @@ impl AppointmentStatus {
             "cancelled" => Self::Cancelled,
             "completed" => Self::Completed,
             "no_show" => Self::NoShow,
+            "reschedule_requested" => Self::RescheduleRequested,
             _ => Self::Scheduled,
         }
     }
@@ -86,6 +90,21 @@ impl Appointment {
             notes: None,
         }
     }
+
+    /// Code the customer quotes when replying to the confirmation SMS
+    ///
+    /// Derived from the appointment ID, so nothing extra is stored.
+    pub fn confirmation_code(&self) -> String {
+        self.appointment_id.simple().to_string()[..8].to_uppercase()
+    }
+
+    /// Whether the appointment still lies ahead and can be confirmed or cancelled
+    pub fn is_open(&self, today: NaiveDate) -> bool {
+        matches!(
+            self.status,
+            AppointmentStatus::Scheduled | AppointmentStatus::Confirmed
+        ) && self.appointment_date >= today
+    }
 }
 
 /// Appointment store trait
@@ -143,8 +162,7 @@ impl AppointmentStore for ScyllaAppointmentStore {
         );
 
         self.client
-            .session()
-            .query_unpaged(
+            .execute(
                 query,
                 (
                     &appointment.customer_phone,
@@ -190,11 +208,7 @@ impl AppointmentStore for ScyllaAppointmentStore {
             self.client.keyspace()
         );
 
-        let result = self
-            .client
-            .session()
-            .query_unpaged(query, (phone, appointment_id))
-            .await?;
+        let result = self.client.execute(query, (phone, appointment_id)).await?;
 
         if let Some(rows) = result.rows {
             if let Some(row) = rows.into_iter().next() {
@@ -218,8 +232,7 @@ impl AppointmentStore for ScyllaAppointmentStore {
         );
 
         self.client
-            .session()
-            .query_unpaged(
+            .execute(
                 query,
                 (
                     status.as_str(),
@@ -252,8 +265,7 @@ impl AppointmentStore for ScyllaAppointmentStore {
         );
 
         self.client
-            .session()
-            .query_unpaged(
+            .execute(
                 query,
                 (sms_id, Utc::now().timestamp_millis(), phone, appointment_id),
             )
@@ -276,11 +288,7 @@ impl AppointmentStore for ScyllaAppointmentStore {
             self.client.keyspace()
         );
 
-        let result = self
-            .client
-            .session()
-            .query_unpaged(query, (phone, limit))
-            .await?;
+        let result = self.client.execute(query, (phone, limit)).await?;
 
         let mut appointments = Vec::new();
         if let Some(rows) = result.rows {
@@ -387,5 +395,9 @@ mod tests {
             AppointmentStatus::Confirmed
         );
         assert_eq!(AppointmentStatus::Confirmed.as_str(), "confirmed");
+        assert_eq!(
+            AppointmentStatus::from_str("reschedule_requested"),
+            AppointmentStatus::RescheduleRequested
+        );
     }
 }
@@ -0,0 +1,169 @@
+//! Archival memory persistence using ScyllaDB
+//!
+//! Stores agent archival memory notes together with their embeddings so
+//! long-term memory survives restarts. Used as the vector backend when
+//! Qdrant is not deployed; similarity search is a brute-force scan over
+//! the stored embeddings. Notes are partitioned by session, so the scan
+//! covers one session's notes.
+
+use crate::{PersistenceError, ScyllaClient};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// A stored archival memory note
+#[derive(Debug, Clone)]
+pub struct ArchivalRecord {
+    /// Note ID (UUID string)
+    pub id: String,
+    /// Session the note belongs to
+    pub session_id: String,
+    /// Serialized note (owned by the agent crate)
+    pub note_json: String,
+    /// Dense embedding of the note text
+    pub embedding: Vec<f32>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Archival memory store trait
+#[async_trait]
+pub trait ArchivalStore: Send + Sync {
+    /// Insert or overwrite a note
+    async fn upsert(
+        &self,
+        collection: &str,
+        record: &ArchivalRecord,
+    ) -> Result<(), PersistenceError>;
+
+    /// Delete a note
+    async fn delete(
+        &self,
+        collection: &str,
+        session_id: &str,
+        id: &str,
+    ) -> Result<(), PersistenceError>;
+
+    /// List notes for a session, or across all sessions when `session_id` is `None`
+    ///
+    /// Stores partitioned by session may reject listing across sessions.
+    async fn list(
+        &self,
+        collection: &str,
+        session_id: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<ArchivalRecord>, PersistenceError>;
+}
+
+/// ScyllaDB implementation of archival store
+#[derive(Clone)]
+pub struct ScyllaArchivalStore {
+    client: ScyllaClient,
+}
+
+impl ScyllaArchivalStore {
+    pub fn new(client: ScyllaClient) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl ArchivalStore for ScyllaArchivalStore {
+    async fn upsert(
+        &self,
+        collection: &str,
+        record: &ArchivalRecord,
+    ) -> Result<(), PersistenceError> {
+        let query = format!(
+            "INSERT INTO {}.archival_memories (
+                collection, session_id, id, note_json, embedding, created_at
+            ) VALUES (?, ?, ?, ?, ?, ?)",
+            self.client.keyspace()
+        );
+
+        self.client
+            .execute(
+                query,
+                (
+                    collection,
+                    &record.session_id,
+                    &record.id,
+                    &record.note_json,
+                    &record.embedding,
+                    record.created_at.timestamp_millis(),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        collection: &str,
+        session_id: &str,
+        id: &str,
+    ) -> Result<(), PersistenceError> {
+        let query = format!(
+            "DELETE FROM {}.archival_memories
+             WHERE collection = ? AND session_id = ? AND id = ?",
+            self.client.keyspace()
+        );
+
+        self.client
+            .execute(query, (collection, session_id, id))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn list(
+        &self,
+        collection: &str,
+        session_id: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<ArchivalRecord>, PersistenceError> {
+        // Listing across sessions would scan every partition
+        let Some(session_id) = session_id else {
+            return Err(PersistenceError::InvalidData(
+                "archival notes are stored per session; session_id is required".to_string(),
+            ));
+        };
+
+        let limit = limit.min(i32::MAX as usize) as i32;
+        let query = format!(
+            "SELECT id, session_id, note_json, embedding, created_at
+             FROM {}.archival_memories
+             WHERE collection = ? AND session_id = ? LIMIT ?",
+            self.client.keyspace()
+        );
+        let result = self
+            .client
+            .execute(query, (collection, session_id, limit))
+            .await?;
+
+        let mut records = Vec::new();
+        if let Some(rows) = result.rows {
+            for row in rows {
+                let (id, session_id, note_json, embedding, created_at): (
+                    String,
+                    String,
+                    String,
+                    Option<Vec<f32>>,
+                    i64,
+                ) = row
+                    .into_typed()
+                    .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+
+                records.push(ArchivalRecord {
+                    id,
+                    session_id,
+                    note_json,
+                    embedding: embedding.unwrap_or_default(),
+                    created_at: DateTime::from_timestamp_millis(created_at)
+                        .unwrap_or_else(Utc::now),
+                });
+            }
+        }
+
+        Ok(records)
+    }
+}
@@ -51,6 +51,10 @@ pub enum AuditEventType {
     StageTransition,
     /// Data was exported
     DataExported,
+    /// A session or customer budget was enforced
+    BudgetEnforced,
+    /// An operator changed data through the admin API
+    AdminAction,
 }
 
 impl AuditEventType {
@@ -71,6 +75,8 @@ impl AuditEventType {
             Self::ToolExecuted => "tool_executed",
             Self::StageTransition => "stage_transition",
             Self::DataExported => "data_exported",
+            Self::BudgetEnforced => "budget_enforced",
+            Self::AdminAction => "admin_action",
         }
     }
 
@@ -91,6 +97,8 @@ impl AuditEventType {
             "tool_executed" => Self::ToolExecuted,
             "stage_transition" => Self::StageTransition,
             "data_exported" => Self::DataExported,
+            "budget_enforced" => Self::BudgetEnforced,
+            "admin_action" => Self::AdminAction,
             _ => Self::ComplianceCheckPerformed, // Default
         }
     }
@@ -127,6 +135,9 @@ impl AuditOutcome {
     }
 }
 
+/// Chain that admin API changes are logged under
+pub const ADMIN_CHAIN: &str = "admin";
+
 /// Actor who performed the action
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Actor {
@@ -155,6 +166,15 @@ impl Actor {
         }
     }
 
+    /// An operator using the admin API; entries chain under `ADMIN_CHAIN`
+    pub fn admin() -> Self {
+        Self {
+            actor_type: "admin".to_string(),
+            actor_id: "admin-api".to_string(),
+            session_id: Some(ADMIN_CHAIN.to_string()),
+        }
+    }
+
     pub fn user(session_id: &str, phone: Option<&str>) -> Self {
         Self {
             actor_type: "user".to_string(),
@@ -363,8 +383,7 @@ impl AuditLog for ScyllaAuditLog {
         );
 
         self.client
-            .session()
-            .query_unpaged(
+            .execute(
                 query,
                 (
                     &date,
@@ -409,7 +428,7 @@ impl AuditLog for ScyllaAuditLog {
             self.client.keyspace()
         );
 
-        let result = self.client.session().query_unpaged(cql, (limit,)).await?;
+        let result = self.client.execute(cql, (limit,)).await?;
 
         let mut entries = Vec::new();
         if let Some(rows) = result.rows {
@@ -477,11 +496,7 @@ impl AuditLog for ScyllaAuditLog {
             self.client.keyspace()
         );
 
-        let result = self
-            .client
-            .session()
-            .query_unpaged(query, (session_id,))
-            .await?;
+        let result = self.client.execute(query, (session_id,)).await?;
 
         if let Some(rows) = result.rows {
             if let Some(row) = rows.into_iter().next() {
@@ -507,11 +522,7 @@ impl AuditLog for ScyllaAuditLog {
             self.client.keyspace()
         );
 
-        let result = self
-            .client
-            .session()
-            .query_unpaged(query, (session_id,))
-            .await?;
+        let result = self.client.execute(query, (session_id,)).await?;
 
         let mut expected_previous = Self::genesis_hash();
 
@@ -739,6 +750,91 @@ impl AuditLogger {
         self.log.log(entry).await
     }
 
+    /// Log a compliance violation caught in agent output
+    ///
+    /// `action` is what the guardrails did with the response
+    /// ("rewrite" or "block").
+    pub async fn log_compliance_violation(
+        &self,
+        session_id: &str,
+        action: &str,
+        violations: serde_json::Value,
+    ) -> Result<(), PersistenceError> {
+        let previous_hash = self.log.get_latest_hash(session_id).await?;
+
+        let entry = AuditEntry::new(
+            AuditEventType::ComplianceViolationDetected,
+            Actor::agent(session_id),
+            "response",
+            session_id,
+            format!("{}_response", action),
+            AuditOutcome::Success,
+            serde_json::json!({
+                "action": action,
+                "violations": violations,
+            }),
+            previous_hash,
+        );
+
+        self.log.log(entry).await
+    }
+
+    /// Log a budget enforcement
+    ///
+    /// `budget` names what ran out (`llm_tokens`, `session_duration`,
+    /// `sms_per_day`); `action` is what was done about it.
+    pub async fn log_budget_enforcement(
+        &self,
+        session_id: &str,
+        budget: &str,
+        limit: u64,
+        used: u64,
+        action: &str,
+    ) -> Result<(), PersistenceError> {
+        let previous_hash = self.log.get_latest_hash(session_id).await?;
+
+        let entry = AuditEntry::new(
+            AuditEventType::BudgetEnforced,
+            Actor::system(),
+            "budget",
+            budget,
+            action,
+            AuditOutcome::Success,
+            serde_json::json!({
+                "budget": budget,
+                "limit": limit,
+                "used": used,
+            }),
+            previous_hash,
+        );
+
+        self.log.log(entry).await
+    }
+
+    /// Log a change made through the admin API
+    pub async fn log_admin_action(
+        &self,
+        resource_type: &str,
+        resource_id: &str,
+        action: &str,
+        details: serde_json::Value,
+    ) -> Result<(), PersistenceError> {
+        let previous_hash = self.log.get_latest_hash(ADMIN_CHAIN).await?;
+
+        let entry = AuditEntry::new(
+            AuditEventType::AdminAction,
+            Actor::admin(),
+            resource_type,
+            resource_id,
+            action,
+            AuditOutcome::Success,
+            details,
+            previous_hash,
+        );
+
+        self.log.log(entry).await
+    }
+
     /// Log human escalation request
     pub async fn log_escalation(
         &self,
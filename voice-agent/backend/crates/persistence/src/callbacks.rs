@@ -0,0 +1,495 @@
+//! Callback requests
+//!
+//! A callback is a promise to phone the customer back within a time window
+//! ("call me tomorrow after 6pm"). Unlike branch appointments it has no
+//! branch or slot: it is assigned to a human agent and handed to the
+//! outbound dialer once the window opens, and its status records what
+//! happened to it.
+
+use crate::{PersistenceError, ScyllaClient};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Callback status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CallbackStatus {
+    /// Waiting for its window, not yet assigned
+    Pending,
+    /// Assigned to a human agent
+    Assigned,
+    /// Handed to the outbound dialer
+    Dialing,
+    /// Customer was reached
+    Completed,
+    /// Dial attempt went unanswered; retried while the window is open
+    NoAnswer,
+    /// Window closed before the customer was reached
+    Expired,
+    /// Cancelled by the customer or an agent
+    Cancelled,
+}
+
+impl CallbackStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Assigned => "assigned",
+            Self::Dialing => "dialing",
+            Self::Completed => "completed",
+            Self::NoAnswer => "no_answer",
+            Self::Expired => "expired",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "pending" => Self::Pending,
+            "assigned" => Self::Assigned,
+            "dialing" => Self::Dialing,
+            "completed" => Self::Completed,
+            "no_answer" => Self::NoAnswer,
+            "expired" => Self::Expired,
+            "cancelled" => Self::Cancelled,
+            _ => Self::Pending,
+        }
+    }
+
+    /// Whether the callback is closed
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Completed | Self::Expired | Self::Cancelled)
+    }
+
+    /// Whether the lifecycle allows moving to `next`
+    pub fn can_transition_to(&self, next: CallbackStatus) -> bool {
+        use CallbackStatus::*;
+        match self {
+            Pending => matches!(next, Assigned | Dialing | Expired | Cancelled),
+            Assigned => matches!(next, Pending | Assigned | Dialing | Expired | Cancelled),
+            Dialing => matches!(next, Completed | NoAnswer | Cancelled),
+            NoAnswer => matches!(next, Assigned | Dialing | Expired | Cancelled),
+            Completed | Expired | Cancelled => false,
+        }
+    }
+}
+
+/// A requested callback
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Callback {
+    pub callback_id: Uuid,
+    pub session_id: Option<String>,
+    pub customer_phone: String,
+    pub customer_name: Option<String>,
+    /// Earliest time the customer wants to be called
+    pub window_start: DateTime<Utc>,
+    /// Latest time the customer wants to be called
+    pub window_end: DateTime<Utc>,
+    /// What the customer said, e.g. "tomorrow after 6pm"
+    pub requested_time: Option<String>,
+    /// Human agent who owns the callback
+    pub assigned_agent: Option<String>,
+    pub status: CallbackStatus,
+    /// Dial attempts made so far
+    pub attempts: i32,
+    /// Earliest time for the next dial attempt after a missed call
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    /// Call ID returned by the dialer for the latest attempt
+    pub dialer_call_id: Option<String>,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Callback {
+    pub fn new(
+        customer_phone: &str,
+        window_start: DateTime<Utc>,
+        window_end: DateTime<Utc>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            callback_id: Uuid::new_v4(),
+            session_id: None,
+            customer_phone: customer_phone.to_string(),
+            customer_name: None,
+            window_start,
+            window_end,
+            requested_time: None,
+            assigned_agent: None,
+            status: CallbackStatus::Pending,
+            attempts: 0,
+            next_attempt_at: None,
+            dialer_call_id: None,
+            notes: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Move to `next`, rejecting transitions the lifecycle does not allow
+    pub fn transition(&mut self, next: CallbackStatus) -> Result<(), PersistenceError> {
+        if !self.status.can_transition_to(next) {
+            return Err(PersistenceError::InvalidData(format!(
+                "callback cannot move from {} to {}",
+                self.status.as_str(),
+                next.as_str()
+            )));
+        }
+        self.status = next;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Assign to a human agent
+    pub fn assign(&mut self, agent_id: &str) -> Result<(), PersistenceError> {
+        self.transition(CallbackStatus::Assigned)?;
+        self.assigned_agent = Some(agent_id.to_string());
+        Ok(())
+    }
+
+    /// Whether the dialer should call now
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        matches!(
+            self.status,
+            CallbackStatus::Pending | CallbackStatus::Assigned | CallbackStatus::NoAnswer
+        ) && self.window_start <= now
+            && now < self.window_end
+            && self.next_attempt_at.map_or(true, |at| at <= now)
+    }
+
+    /// Whether the window closed without the customer being reached
+    pub fn is_missed(&self, now: DateTime<Utc>) -> bool {
+        !self.status.is_terminal()
+            && self.status != CallbackStatus::Dialing
+            && now >= self.window_end
+    }
+}
+
+/// Callback store trait
+#[async_trait]
+pub trait CallbackStore: Send + Sync {
+    /// Store a new callback
+    async fn create(&self, callback: &Callback) -> Result<(), PersistenceError>;
+    /// Get a callback by ID
+    async fn get(&self, callback_id: Uuid) -> Result<Option<Callback>, PersistenceError>;
+    /// Overwrite a callback after a status or assignment change
+    async fn update(&self, callback: &Callback) -> Result<(), PersistenceError>;
+    /// All callbacks not yet completed, expired or cancelled, earliest window first
+    async fn list_open(&self) -> Result<Vec<Callback>, PersistenceError>;
+}
+
+/// Partition of `open_callbacks` holding every open callback ID
+const OPEN_QUEUE: &str = "open";
+
+/// ScyllaDB implementation of the callback store
+#[derive(Clone)]
+pub struct ScyllaCallbackStore {
+    client: ScyllaClient,
+}
+
+/// Columns of a `callbacks` row, in `SELECT` order
+type CallbackRow = (
+    Uuid,
+    Option<String>,
+    String,
+    Option<String>,
+    i64,
+    i64,
+    Option<String>,
+    Option<String>,
+    String,
+    i32,
+    Option<i64>,
+    Option<String>,
+    Option<String>,
+    i64,
+    i64,
+);
+
+impl ScyllaCallbackStore {
+    pub fn new(client: ScyllaClient) -> Self {
+        Self { client }
+    }
+
+    async fn write(&self, callback: &Callback) -> Result<(), PersistenceError> {
+        let query = format!(
+            "INSERT INTO {}.callbacks (
+                callback_id, session_id, customer_phone, customer_name,
+                window_start, window_end, requested_time, assigned_agent,
+                status, attempts, next_attempt_at, dialer_call_id, notes,
+                created_at, updated_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            self.client.keyspace()
+        );
+
+        self.client
+            .execute(
+                query,
+                (
+                    callback.callback_id,
+                    &callback.session_id,
+                    &callback.customer_phone,
+                    &callback.customer_name,
+                    callback.window_start.timestamp_millis(),
+                    callback.window_end.timestamp_millis(),
+                    &callback.requested_time,
+                    &callback.assigned_agent,
+                    callback.status.as_str(),
+                    callback.attempts,
+                    callback.next_attempt_at.map(|t| t.timestamp_millis()),
+                    &callback.dialer_call_id,
+                    &callback.notes,
+                    callback.created_at.timestamp_millis(),
+                    callback.updated_at.timestamp_millis(),
+                ),
+            )
+            .await?;
+
+        // Keep the open index in step with the status
+        let index_query = if callback.status.is_terminal() {
+            format!(
+                "DELETE FROM {}.open_callbacks WHERE queue = ? AND callback_id = ?",
+                self.client.keyspace()
+            )
+        } else {
+            format!(
+                "INSERT INTO {}.open_callbacks (queue, callback_id) VALUES (?, ?)",
+                self.client.keyspace()
+            )
+        };
+        self.client
+            .execute(index_query, (OPEN_QUEUE, callback.callback_id))
+            .await?;
+
+        Ok(())
+    }
+
+    fn row_to_callback(
+        &self,
+        row: scylla::frame::response::result::Row,
+    ) -> Result<Callback, PersistenceError> {
+        let (
+            callback_id,
+            session_id,
+            customer_phone,
+            customer_name,
+            window_start,
+            window_end,
+            requested_time,
+            assigned_agent,
+            status,
+            attempts,
+            next_attempt_at,
+            dialer_call_id,
+            notes,
+            created_at,
+            updated_at,
+        ): CallbackRow = row
+            .into_typed()
+            .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+
+        let timestamp =
+            |millis: i64| DateTime::from_timestamp_millis(millis).unwrap_or_else(Utc::now);
+        Ok(Callback {
+            callback_id,
+            session_id,
+            customer_phone,
+            customer_name,
+            window_start: timestamp(window_start),
+            window_end: timestamp(window_end),
+            requested_time,
+            assigned_agent,
+            status: CallbackStatus::parse(&status),
+            attempts,
+            next_attempt_at: next_attempt_at.map(timestamp),
+            dialer_call_id,
+            notes,
+            created_at: timestamp(created_at),
+            updated_at: timestamp(updated_at),
+        })
+    }
+}
+
+#[async_trait]
+impl CallbackStore for ScyllaCallbackStore {
+    async fn create(&self, callback: &Callback) -> Result<(), PersistenceError> {
+        self.write(callback).await?;
+
+        tracing::info!(
+            callback_id = %callback.callback_id,
+            customer_phone = %callback.customer_phone,
+            window_start = %callback.window_start,
+            "Callback created in ScyllaDB"
+        );
+
+        Ok(())
+    }
+
+    async fn get(&self, callback_id: Uuid) -> Result<Option<Callback>, PersistenceError> {
+        let query = format!(
+            "SELECT callback_id, session_id, customer_phone, customer_name,
+                    window_start, window_end, requested_time, assigned_agent,
+                    status, attempts, next_attempt_at, dialer_call_id, notes,
+                    created_at, updated_at
+             FROM {}.callbacks WHERE callback_id = ?",
+            self.client.keyspace()
+        );
+
+        let result = self.client.execute(query, (callback_id,)).await?;
+
+        match result.rows.and_then(|rows| rows.into_iter().next()) {
+            Some(row) => Ok(Some(self.row_to_callback(row)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn update(&self, callback: &Callback) -> Result<(), PersistenceError> {
+        self.write(callback).await?;
+
+        tracing::info!(
+            callback_id = %callback.callback_id,
+            status = callback.status.as_str(),
+            agent = ?callback.assigned_agent,
+            "Callback updated"
+        );
+
+        Ok(())
+    }
+
+    async fn list_open(&self) -> Result<Vec<Callback>, PersistenceError> {
+        let query = format!(
+            "SELECT callback_id FROM {}.open_callbacks WHERE queue = ?",
+            self.client.keyspace()
+        );
+
+        let result = self.client.execute(query, (OPEN_QUEUE,)).await?;
+
+        let mut callbacks = Vec::new();
+        for row in result.rows.unwrap_or_default() {
+            let (callback_id,): (Uuid,) = row
+                .into_typed()
+                .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+            if let Some(callback) = self.get(callback_id).await? {
+                callbacks.push(callback);
+            }
+        }
+        callbacks.sort_by_key(|c| c.window_start);
+
+        Ok(callbacks)
+    }
+}
+
+/// In-memory callback store
+///
+/// Used when ScyllaDB is not configured; callbacks do not survive restarts.
+#[derive(Default)]
+pub struct InMemoryCallbackStore {
+    callbacks: RwLock<HashMap<Uuid, Callback>>,
+}
+
+impl InMemoryCallbackStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CallbackStore for InMemoryCallbackStore {
+    async fn create(&self, callback: &Callback) -> Result<(), PersistenceError> {
+        self.callbacks
+            .write()
+            .await
+            .insert(callback.callback_id, callback.clone());
+        Ok(())
+    }
+
+    async fn get(&self, callback_id: Uuid) -> Result<Option<Callback>, PersistenceError> {
+        Ok(self.callbacks.read().await.get(&callback_id).cloned())
+    }
+
+    async fn update(&self, callback: &Callback) -> Result<(), PersistenceError> {
+        self.create(callback).await
+    }
+
+    async fn list_open(&self) -> Result<Vec<Callback>, PersistenceError> {
+        let mut open: Vec<Callback> = self
+            .callbacks
+            .read()
+            .await
+            .values()
+            .filter(|c| !c.status.is_terminal())
+            .cloned()
+            .collect();
+        open.sort_by_key(|c| c.window_start);
+        Ok(open)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_status_lifecycle() {
+        let now = Utc::now();
+        let mut callback = Callback::new("9876543210", now, now + Duration::hours(2));
+        assert!(callback.is_due(now));
+
+        callback.assign("agent-7").unwrap();
+        assert_eq!(callback.assigned_agent.as_deref(), Some("agent-7"));
+        callback.transition(CallbackStatus::Dialing).unwrap();
+        assert!(!callback.is_due(now));
+        callback.transition(CallbackStatus::NoAnswer).unwrap();
+        assert!(callback.is_due(now));
+        callback.transition(CallbackStatus::Dialing).unwrap();
+        callback.transition(CallbackStatus::Completed).unwrap();
+
+        assert!(callback.transition(CallbackStatus::Pending).is_err());
+        assert_eq!(CallbackStatus::parse("no_answer"), CallbackStatus::NoAnswer);
+    }
+
+    #[test]
+    fn test_due_and_missed_windows() {
+        let now = Utc::now();
+        let mut callback = Callback::new(
+            "9876543210",
+            now + Duration::hours(1),
+            now + Duration::hours(2),
+        );
+        assert!(!callback.is_due(now));
+        assert!(callback.is_due(now + Duration::minutes(90)));
+        assert!(callback.is_missed(now + Duration::hours(2)));
+
+        callback.next_attempt_at = Some(now + Duration::minutes(100));
+        assert!(!callback.is_due(now + Duration::minutes(90)));
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_list_open() {
+        let store = InMemoryCallbackStore::new();
+        let now = Utc::now();
+        let later = Callback::new("1", now + Duration::hours(3), now + Duration::hours(4));
+        let sooner = Callback::new("2", now + Duration::hours(1), now + Duration::hours(2));
+        store.create(&later).await.unwrap();
+        store.create(&sooner).await.unwrap();
+
+        let open = store.list_open().await.unwrap();
+        assert_eq!(open.len(), 2);
+        assert_eq!(open[0].customer_phone, "2");
+
+        let mut cancelled = later.clone();
+        cancelled.transition(CallbackStatus::Cancelled).unwrap();
+        store.update(&cancelled).await.unwrap();
+        assert_eq!(store.list_open().await.unwrap().len(), 1);
+        assert_eq!(
+            store.get(later.callback_id).await.unwrap().unwrap().status,
+            CallbackStatus::Cancelled
+        );
+    }
+}
@@ -1,9 +1,33 @@
 //! ScyllaDB client and connection management
+//!
+//! Store queries go through [`ScyllaClient::execute`]: each distinct CQL
+//! string is prepared once and cached, carries the configured read or write
+//! consistency, and records its latency in the
+//! `voice_agent_scylla_query_duration_seconds` histogram.
 
 use crate::error::PersistenceError;
 use crate::schema;
-use scylla::{Session, SessionBuilder};
-use std::sync::Arc;
+use scylla::prepared_statement::PreparedStatement;
+use scylla::serialize::row::SerializeRow;
+use scylla::speculative_execution::SimpleSpeculativeExecutionPolicy;
+use scylla::transport::session::PoolSize;
+use scylla::transport::ExecutionProfile;
+use scylla::{QueryResult, Session, SessionBuilder};
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+pub use scylla::statement::Consistency;
+
+/// Speculative retry for reads
+#[derive(Debug, Clone, Copy)]
+pub struct SpeculativeRetry {
+    /// Extra attempts sent while the first is still pending
+    pub max_retries: usize,
+    /// Delay before each extra attempt
+    pub delay: Duration,
+}
 
 /// ScyllaDB configuration
 #[derive(Debug, Clone)]
@@ -11,6 +35,16 @@ pub struct ScyllaConfig {
     pub hosts: Vec<String>,
     pub keyspace: String,
     pub replication_factor: u8,
+    /// Consistency for SELECT statements
+    pub read_consistency: Consistency,
+    /// Consistency for INSERT/UPDATE/DELETE statements
+    pub write_consistency: Consistency,
+    /// Speculative retry for reads (`None` = off)
+    pub speculative_retry: Option<SpeculativeRetry>,
+    /// Connections per shard (per node on clusters without shard awareness)
+    pub connections_per_shard: usize,
+    /// Most prepared statements cached by the client
+    pub prepared_cache_size: usize,
 }
 
 impl Default for ScyllaConfig {
@@ -28,6 +62,11 @@ impl Default for ScyllaConfig {
             hosts,
             keyspace,
             replication_factor: 1,
+            read_consistency: Consistency::LocalQuorum,
+            write_consistency: Consistency::LocalQuorum,
+            speculative_retry: None,
+            connections_per_shard: 1,
+            prepared_cache_size: 512,
         }
     }
 }
@@ -37,21 +76,45 @@ impl Default for ScyllaConfig {
 pub struct ScyllaClient {
     session: Arc<Session>,
     config: ScyllaConfig,
+    /// Prepared statements by CQL text
+    prepared: Arc<RwLock<HashMap<String, PreparedStatement>>>,
 }
 
 impl ScyllaClient {
     /// Connect to ScyllaDB cluster
     pub async fn connect(config: ScyllaConfig) -> Result<Self, PersistenceError> {
-        tracing::info!(hosts = ?config.hosts, keyspace = %config.keyspace, "Connecting to ScyllaDB");
+        tracing::info!(
+            hosts = ?config.hosts,
+            keyspace = %config.keyspace,
+            connections_per_shard = config.connections_per_shard,
+            "Connecting to ScyllaDB"
+        );
+
+        // Reads are the only statements marked idempotent, so speculative
+        // attempts never duplicate a write
+        let mut profile = ExecutionProfile::builder().consistency(config.read_consistency);
+        if let Some(retry) = config.speculative_retry {
+            profile = profile.speculative_execution_policy(Some(Arc::new(
+                SimpleSpeculativeExecutionPolicy {
+                    max_retry_count: retry.max_retries,
+                    retry_interval: retry.delay,
+                },
+            )));
+        }
+        let pool_size =
+            NonZeroUsize::new(config.connections_per_shard).unwrap_or(NonZeroUsize::MIN);
 
         let session = SessionBuilder::new()
             .known_nodes(&config.hosts)
+            .pool_size(PoolSize::PerShard(pool_size))
+            .default_execution_profile_handle(profile.build().into_handle())
             .build()
             .await?;
 
         let client = Self {
             session: Arc::new(session),
             config,
+            prepared: Arc::new(RwLock::new(HashMap::new())),
         };
 
         Ok(client)
@@ -70,6 +133,64 @@ impl ScyllaClient {
         Ok(())
     }
 
+    /// Prepared statement for `cql`, prepared on first use
+    pub async fn prepare(&self, cql: &str) -> Result<PreparedStatement, PersistenceError> {
+        let cached = self
+            .prepared
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(cql)
+            .cloned();
+        if let Some(statement) = cached {
+            return Ok(statement);
+        }
+
+        let mut statement = self.session.prepare(cql).await?;
+        if statement_kind(cql) == "select" {
+            statement.set_consistency(self.config.read_consistency);
+            statement.set_is_idempotent(true);
+        } else {
+            statement.set_consistency(self.config.write_consistency);
+        }
+
+        let mut prepared = self.prepared.write().unwrap_or_else(|e| e.into_inner());
+        if prepared.len() < self.config.prepared_cache_size {
+            prepared.insert(cql.to_string(), statement.clone());
+        } else {
+            tracing::debug!(cql, "Prepared statement cache full");
+        }
+        Ok(statement)
+    }
+
+    /// Run a statement through the prepared statement cache
+    pub async fn execute(
+        &self,
+        cql: impl AsRef<str>,
+        values: impl SerializeRow,
+    ) -> Result<QueryResult, PersistenceError> {
+        let cql = cql.as_ref();
+        let statement = self.prepare(cql).await?;
+
+        let started = Instant::now();
+        let result = self.session.execute_unpaged(&statement, values).await;
+        metrics::histogram!(
+            "voice_agent_scylla_query_duration_seconds",
+            "op" => statement_kind(cql),
+            "status" => if result.is_ok() { "ok" } else { "error" }
+        )
+        .record(started.elapsed().as_secs_f64());
+
+        Ok(result?)
+    }
+
+    /// Number of cached prepared statements
+    pub fn prepared_statements(&self) -> usize {
+        self.prepared
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .len()
+    }
+
     /// Get the underlying session
     pub fn session(&self) -> &Session {
         &self.session
@@ -80,3 +201,34 @@ impl ScyllaClient {
         &self.config.keyspace
     }
 }
+
+/// Statement verb, used as the latency histogram label
+fn statement_kind(cql: &str) -> &'static str {
+    let verb = cql.split_whitespace().next().unwrap_or_default();
+    ["select", "insert", "update", "delete"]
+        .into_iter()
+        .find(|kind| verb.eq_ignore_ascii_case(kind))
+        .unwrap_or("other")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_statement_kind() {
+        assert_eq!(statement_kind("SELECT * FROM ks.sessions"), "select");
+        assert_eq!(statement_kind("  insert INTO ks.sms VALUES (?)"), "insert");
+        assert_eq!(statement_kind("BEGIN BATCH ..."), "other");
+        assert_eq!(statement_kind(""), "other");
+    }
+
+    #[test]
+    fn test_default_config() {
+        let config = ScyllaConfig::default();
+        assert_eq!(config.read_consistency, Consistency::LocalQuorum);
+        assert_eq!(config.write_consistency, Consistency::LocalQuorum);
+        assert!(config.speculative_retry.is_none());
+        assert_eq!(config.connections_per_shard, 1);
+    }
+}
@@ -0,0 +1,394 @@
+//! Competitor rate table
+//!
+//! Admin-maintained lender rates per product. These override the indicative
+//! figures in competitors.yaml, and each row records when it was last updated
+//! so comparisons can warn callers when the numbers may be out of date.
+
+use crate::slots::lwt_applied;
+use crate::{PersistenceError, ScyllaClient};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+
+/// A lender's rates for one product
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompetitorRate {
+    /// Lender ID (matches the competitors.yaml key where one exists)
+    pub lender_id: String,
+    /// Display name of the lender
+    pub lender_name: String,
+    /// Product the rates apply to (e.g. "gold_loan")
+    pub product: String,
+    /// Lowest advertised rate (% p.a.)
+    pub rate_min: f64,
+    /// Highest advertised rate (% p.a.)
+    pub rate_max: f64,
+    /// Rate most customers actually get, if known (% p.a.)
+    #[serde(default)]
+    pub typical_rate: Option<f64>,
+    /// Processing fee as a percentage of the loan amount
+    #[serde(default)]
+    pub processing_fee_percent: Option<f64>,
+    /// Where the figures came from (e.g. "website", "mystery_shopping")
+    #[serde(default)]
+    pub source: Option<String>,
+    /// When the figures were last verified
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CompetitorRate {
+    /// Create a rate entry stamped with the current time
+    pub fn new(
+        lender_id: impl Into<String>,
+        lender_name: impl Into<String>,
+        product: impl Into<String>,
+        rate_min: f64,
+        rate_max: f64,
+    ) -> Self {
+        Self {
+            lender_id: lender_id.into(),
+            lender_name: lender_name.into(),
+            product: product.into(),
+            rate_min,
+            rate_max,
+            typical_rate: None,
+            processing_fee_percent: None,
+            source: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// Set the typical rate
+    pub fn with_typical_rate(mut self, rate: f64) -> Self {
+        self.typical_rate = Some(rate);
+        self
+    }
+
+    /// Set the processing fee
+    pub fn with_processing_fee(mut self, percent: f64) -> Self {
+        self.processing_fee_percent = Some(percent);
+        self
+    }
+
+    /// Rate to compare against: the typical rate, else the middle of the range
+    pub fn comparison_rate(&self) -> f64 {
+        self.typical_rate
+            .unwrap_or((self.rate_min + self.rate_max) / 2.0)
+    }
+
+    /// Whole days since the figures were last updated
+    pub fn age_days(&self, now: DateTime<Utc>) -> i64 {
+        (now - self.updated_at).num_days().max(0)
+    }
+
+    /// Whether the figures are older than `max_age_days`
+    pub fn is_stale(&self, max_age_days: u32, now: DateTime<Utc>) -> bool {
+        self.age_days(now) > max_age_days as i64
+    }
+
+    /// Check the entry is usable before storing it
+    pub fn validate(&self) -> Result<(), PersistenceError> {
+        let invalid = |msg: &str| Err(PersistenceError::InvalidData(msg.to_string()));
+        if self.lender_id.trim().is_empty() || self.product.trim().is_empty() {
+            return invalid("lender_id and product are required");
+        }
+        let in_range = |rate: f64| (0.0..=100.0).contains(&rate);
+        if !in_range(self.rate_min) || !in_range(self.rate_max) {
+            return invalid("rates must be between 0 and 100");
+        }
+        if self.rate_min > self.rate_max {
+            return invalid("rate_min cannot exceed rate_max");
+        }
+        if let Some(typical) = self.typical_rate {
+            if typical < self.rate_min || typical > self.rate_max {
+                return invalid("typical_rate must be within the rate range");
+            }
+        }
+        if let Some(fee) = self.processing_fee_percent {
+            if !in_range(fee) {
+                return invalid("processing_fee_percent must be between 0 and 100");
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Competitor rate store trait
+#[async_trait]
+pub trait CompetitorRateStore: Send + Sync {
+    /// List stored rates, optionally for one product only
+    async fn list(&self, product: Option<&str>) -> Result<Vec<CompetitorRate>, PersistenceError>;
+
+    /// Insert or replace a lender's rates for a product
+    async fn upsert(&self, rate: &CompetitorRate) -> Result<(), PersistenceError>;
+
+    /// Remove a lender's rates for a product; returns whether a row existed
+    async fn remove(&self, product: &str, lender_id: &str) -> Result<bool, PersistenceError>;
+}
+
+/// ScyllaDB implementation of the competitor rate store
+#[derive(Clone)]
+pub struct ScyllaCompetitorRateStore {
+    client: ScyllaClient,
+}
+
+impl ScyllaCompetitorRateStore {
+    pub fn new(client: ScyllaClient) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl CompetitorRateStore for ScyllaCompetitorRateStore {
+    async fn list(&self, product: Option<&str>) -> Result<Vec<CompetitorRate>, PersistenceError> {
+        let columns = "product, lender_id, lender_name, rate_min, rate_max, typical_rate,
+                       processing_fee_percent, source, updated_at";
+        let result = match product {
+            Some(product) => {
+                let query = format!(
+                    "SELECT {} FROM {}.competitor_rates WHERE product = ?",
+                    columns,
+                    self.client.keyspace()
+                );
+                self.client.execute(query, (product,)).await?
+            },
+            None => {
+                let query = format!(
+                    "SELECT {} FROM {}.competitor_rates",
+                    columns,
+                    self.client.keyspace()
+                );
+                self.client.execute(query, &[]).await?
+            },
+        };
+
+        let mut rates = Vec::new();
+        for row in result.rows.unwrap_or_default() {
+            let (
+                product,
+                lender_id,
+                lender_name,
+                rate_min,
+                rate_max,
+                typical_rate,
+                processing_fee_percent,
+                source,
+                updated_at,
+            ): (
+                String,
+                String,
+                Option<String>,
+                f64,
+                f64,
+                Option<f64>,
+                Option<f64>,
+                Option<String>,
+                i64,
+            ) = row
+                .into_typed()
+                .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+
+            rates.push(CompetitorRate {
+                lender_name: lender_name.unwrap_or_else(|| lender_id.clone()),
+                lender_id,
+                product,
+                rate_min,
+                rate_max,
+                typical_rate,
+                processing_fee_percent,
+                source,
+                updated_at: DateTime::from_timestamp_millis(updated_at).unwrap_or_else(Utc::now),
+            });
+        }
+
+        Ok(rates)
+    }
+
+    async fn upsert(&self, rate: &CompetitorRate) -> Result<(), PersistenceError> {
+        rate.validate()?;
+
+        let query = format!(
+            "INSERT INTO {}.competitor_rates (
+                product, lender_id, lender_name, rate_min, rate_max, typical_rate,
+                processing_fee_percent, source, updated_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            self.client.keyspace()
+        );
+
+        self.client
+            .execute(
+                query,
+                (
+                    &rate.product,
+                    &rate.lender_id,
+                    &rate.lender_name,
+                    rate.rate_min,
+                    rate.rate_max,
+                    rate.typical_rate,
+                    rate.processing_fee_percent,
+                    &rate.source,
+                    rate.updated_at.timestamp_millis(),
+                ),
+            )
+            .await?;
+
+        tracing::info!(
+            lender_id = %rate.lender_id,
+            product = %rate.product,
+            rate_min = rate.rate_min,
+            rate_max = rate.rate_max,
+            "Competitor rate updated"
+        );
+
+        Ok(())
+    }
+
+    async fn remove(&self, product: &str, lender_id: &str) -> Result<bool, PersistenceError> {
+        let query = format!(
+            "DELETE FROM {}.competitor_rates WHERE product = ? AND lender_id = ? IF EXISTS",
+            self.client.keyspace()
+        );
+
+        let result = self.client.execute(query, (product, lender_id)).await?;
+
+        Ok(lwt_applied(result.rows))
+    }
+}
+
+/// In-memory competitor rate store
+///
+/// Used when ScyllaDB is not configured; updates do not survive restarts.
+#[derive(Default)]
+pub struct InMemoryCompetitorRateStore {
+    rates: RwLock<HashMap<(String, String), CompetitorRate>>,
+}
+
+impl InMemoryCompetitorRateStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CompetitorRateStore for InMemoryCompetitorRateStore {
+    async fn list(&self, product: Option<&str>) -> Result<Vec<CompetitorRate>, PersistenceError> {
+        let rates = self.rates.read().await;
+        let mut listed: Vec<CompetitorRate> = rates
+            .values()
+            .filter(|rate| product.map_or(true, |p| rate.product == p))
+            .cloned()
+            .collect();
+        listed.sort_by(|a, b| (&a.product, &a.lender_id).cmp(&(&b.product, &b.lender_id)));
+        Ok(listed)
+    }
+
+    async fn upsert(&self, rate: &CompetitorRate) -> Result<(), PersistenceError> {
+        rate.validate()?;
+        self.rates
+            .write()
+            .await
+            .insert((rate.product.clone(), rate.lender_id.clone()), rate.clone());
+        Ok(())
+    }
+
+    async fn remove(&self, product: &str, lender_id: &str) -> Result<bool, PersistenceError> {
+        Ok(self
+            .rates
+            .write()
+            .await
+            .remove(&(product.to_string(), lender_id.to_string()))
+            .is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_validation_and_staleness() {
+        let rate = CompetitorRate::new("muthoot", "Muthoot Finance", "gold_loan", 10.5, 24.0);
+        assert!(rate.validate().is_ok());
+        assert!((rate.comparison_rate() - 17.25).abs() < 0.001);
+        assert!((rate.clone().with_typical_rate(12.0).comparison_rate() - 12.0).abs() < 0.001);
+
+        assert!(rate.clone().with_typical_rate(30.0).validate().is_err());
+        assert!(CompetitorRate::new("x", "X", "gold_loan", 20.0, 10.0)
+            .validate()
+            .is_err());
+        assert!(CompetitorRate::new("", "X", "gold_loan", 10.0, 12.0)
+            .validate()
+            .is_err());
+
+        let now = Utc::now();
+        let mut old = rate;
+        old.updated_at = now - Duration::days(45);
+        assert_eq!(old.age_days(now), 45);
+        assert!(old.is_stale(30, now));
+        assert!(!old.is_stale(60, now));
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_store_upsert_list_remove() {
+        let store = InMemoryCompetitorRateStore::new();
+        store
+            .upsert(&CompetitorRate::new(
+                "muthoot",
+                "Muthoot",
+                "gold_loan",
+                10.5,
+                24.0,
+            ))
+            .await
+            .unwrap();
+        store
+            .upsert(&CompetitorRate::new(
+                "iifl",
+                "IIFL",
+                "gold_loan",
+                9.24,
+                24.0,
+            ))
+            .await
+            .unwrap();
+        store
+            .upsert(&CompetitorRate::new(
+                "muthoot",
+                "Muthoot",
+                "personal_loan",
+                14.0,
+                22.0,
+            ))
+            .await
+            .unwrap();
+
+        // Upsert replaces the existing row
+        store
+            .upsert(
+                &CompetitorRate::new("muthoot", "Muthoot", "gold_loan", 11.0, 22.0)
+                    .with_processing_fee(1.0),
+            )
+            .await
+            .unwrap();
+
+        let gold = store.list(Some("gold_loan")).await.unwrap();
+        assert_eq!(gold.len(), 2);
+        let muthoot = gold.iter().find(|r| r.lender_id == "muthoot").unwrap();
+        assert!((muthoot.rate_min - 11.0).abs() < 0.001);
+        assert_eq!(muthoot.processing_fee_percent, Some(1.0));
+        assert_eq!(store.list(None).await.unwrap().len(), 3);
+
+        // Invalid rows are rejected
+        assert!(store
+            .upsert(&CompetitorRate::new("x", "X", "gold_loan", 30.0, 20.0))
+            .await
+            .is_err());
+
+        assert!(store.remove("gold_loan", "iifl").await.unwrap());
+        assert!(!store.remove("gold_loan", "iifl").await.unwrap());
+        assert_eq!(store.list(Some("gold_loan")).await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,237 @@
+//! CRM lead delivery tracking using ScyllaDB
+//!
+//! Each captured lead is delivered to the external CRM asynchronously.
+//! Deliveries are keyed by an idempotency key so that retries and duplicate
+//! captures never create the same lead twice.
+
+use crate::{PersistenceError, ScyllaClient};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// CRM delivery status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrmDeliveryStatus {
+    /// Queued, not yet attempted
+    Pending,
+    /// Last attempt failed, will be retried
+    Retrying,
+    /// Accepted by the CRM
+    Delivered,
+    /// Gave up after exhausting retries (or non-retryable error)
+    Failed,
+}
+
+impl CrmDeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Retrying => "retrying",
+            Self::Delivered => "delivered",
+            Self::Failed => "failed",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "pending" => Self::Pending,
+            "retrying" => Self::Retrying,
+            "delivered" => Self::Delivered,
+            "failed" => Self::Failed,
+            _ => Self::Pending,
+        }
+    }
+
+    /// Whether no further attempts will be made
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Delivered | Self::Failed)
+    }
+}
+
+/// Delivery record for one lead
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrmDelivery {
+    pub idempotency_key: String,
+    /// Connector name (salesforce, hubspot, webhook)
+    pub connector: String,
+    pub customer_phone: String,
+    /// Lead payload as sent to the connector (JSON)
+    pub payload_json: String,
+    pub status: CrmDeliveryStatus,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    /// Lead ID assigned by the CRM once delivered
+    pub external_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CrmDelivery {
+    pub fn new(
+        idempotency_key: &str,
+        connector: &str,
+        customer_phone: &str,
+        payload_json: String,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            idempotency_key: idempotency_key.to_string(),
+            connector: connector.to_string(),
+            customer_phone: customer_phone.to_string(),
+            payload_json,
+            status: CrmDeliveryStatus::Pending,
+            attempts: 0,
+            last_error: None,
+            external_id: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// CRM delivery store trait
+#[async_trait]
+pub trait CrmDeliveryStore: Send + Sync {
+    /// Insert or overwrite a delivery record
+    async fn upsert(&self, delivery: &CrmDelivery) -> Result<(), PersistenceError>;
+    /// Get a delivery by idempotency key
+    async fn get(&self, idempotency_key: &str) -> Result<Option<CrmDelivery>, PersistenceError>;
+}
+
+/// ScyllaDB implementation of CRM delivery store
+#[derive(Clone)]
+pub struct ScyllaCrmDeliveryStore {
+    client: ScyllaClient,
+}
+
+impl ScyllaCrmDeliveryStore {
+    pub fn new(client: ScyllaClient) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl CrmDeliveryStore for ScyllaCrmDeliveryStore {
+    async fn upsert(&self, delivery: &CrmDelivery) -> Result<(), PersistenceError> {
+        let query = format!(
+            "INSERT INTO {}.crm_deliveries (
+                idempotency_key, connector, customer_phone, payload_json,
+                status, attempts, last_error, external_id,
+                created_at, updated_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            self.client.keyspace()
+        );
+
+        self.client
+            .execute(
+                query,
+                (
+                    &delivery.idempotency_key,
+                    &delivery.connector,
+                    &delivery.customer_phone,
+                    &delivery.payload_json,
+                    delivery.status.as_str(),
+                    delivery.attempts,
+                    &delivery.last_error,
+                    &delivery.external_id,
+                    delivery.created_at.timestamp_millis(),
+                    delivery.updated_at.timestamp_millis(),
+                ),
+            )
+            .await?;
+
+        tracing::debug!(
+            idempotency_key = %delivery.idempotency_key,
+            status = delivery.status.as_str(),
+            attempts = delivery.attempts,
+            "CRM delivery status persisted"
+        );
+
+        Ok(())
+    }
+
+    async fn get(&self, idempotency_key: &str) -> Result<Option<CrmDelivery>, PersistenceError> {
+        let query = format!(
+            "SELECT idempotency_key, connector, customer_phone, payload_json,
+                    status, attempts, last_error, external_id,
+                    created_at, updated_at
+             FROM {}.crm_deliveries WHERE idempotency_key = ?",
+            self.client.keyspace()
+        );
+
+        let result = self.client.execute(query, (idempotency_key,)).await?;
+
+        if let Some(rows) = result.rows {
+            if let Some(row) = rows.into_iter().next() {
+                let (
+                    idempotency_key,
+                    connector,
+                    customer_phone,
+                    payload_json,
+                    status,
+                    attempts,
+                    last_error,
+                    external_id,
+                    created_at,
+                    updated_at,
+                ): (
+                    String,
+                    String,
+                    String,
+                    String,
+                    String,
+                    i32,
+                    Option<String>,
+                    Option<String>,
+                    i64,
+                    i64,
+                ) = row
+                    .into_typed()
+                    .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+
+                return Ok(Some(CrmDelivery {
+                    idempotency_key,
+                    connector,
+                    customer_phone,
+                    payload_json,
+                    status: CrmDeliveryStatus::parse(&status),
+                    attempts,
+                    last_error,
+                    external_id,
+                    created_at: DateTime::from_timestamp_millis(created_at)
+                        .unwrap_or_else(Utc::now),
+                    updated_at: DateTime::from_timestamp_millis(updated_at)
+                        .unwrap_or_else(Utc::now),
+                }));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delivery_new() {
+        let delivery =
+            CrmDelivery::new("9876543210-20240115", "webhook", "9876543210", "{}".into());
+        assert_eq!(delivery.status, CrmDeliveryStatus::Pending);
+        assert_eq!(delivery.attempts, 0);
+        assert!(delivery.external_id.is_none());
+    }
+
+    #[test]
+    fn test_status_conversion() {
+        assert_eq!(
+            CrmDeliveryStatus::parse("delivered"),
+            CrmDeliveryStatus::Delivered
+        );
+        assert_eq!(CrmDeliveryStatus::Retrying.as_str(), "retrying");
+        assert!(CrmDeliveryStatus::Failed.is_terminal());
+        assert!(!CrmDeliveryStatus::Retrying.is_terminal());
+    }
+}
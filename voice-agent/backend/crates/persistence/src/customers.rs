@@ -0,0 +1,244 @@
+//! Customer identity persistence using ScyllaDB
+//!
+//! Identities are keyed by normalized phone number and link sessions across
+//! channels (voice, WhatsApp, repeat calls).
+
+use crate::{PersistenceError, ScyllaClient};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+use voice_agent_core::{normalize_phone, Channel, CustomerIdentity};
+
+/// Customer identity store trait
+#[async_trait]
+pub trait CustomerIdentityStore: Send + Sync {
+    /// Get an identity by customer ID (normalized phone)
+    async fn get(&self, customer_id: &str) -> Result<Option<CustomerIdentity>, PersistenceError>;
+
+    /// Insert or overwrite an identity
+    async fn upsert(&self, identity: &CustomerIdentity) -> Result<(), PersistenceError>;
+
+    /// Resolve a phone number to an identity and link the session to it
+    ///
+    /// Creates the identity on first contact. Returns `Ok(None)` if the phone
+    /// number cannot be normalized.
+    async fn resolve(
+        &self,
+        phone: &str,
+        session_id: &str,
+        channel: Channel,
+    ) -> Result<Option<CustomerIdentity>, PersistenceError> {
+        let Some(customer_id) = normalize_phone(phone) else {
+            return Ok(None);
+        };
+
+        let mut identity = match self.get(&customer_id).await? {
+            Some(identity) => identity,
+            None => match CustomerIdentity::new(&customer_id) {
+                Some(identity) => identity,
+                None => return Ok(None),
+            },
+        };
+        identity.link_session(session_id, channel);
+        self.upsert(&identity).await?;
+
+        tracing::debug!(
+            session_id = %session_id,
+            channel = channel.as_str(),
+            sessions = identity.session_count(),
+            "Customer identity resolved"
+        );
+
+        Ok(Some(identity))
+    }
+}
+
+/// ScyllaDB implementation of customer identity store
+#[derive(Clone)]
+pub struct ScyllaCustomerIdentityStore {
+    client: ScyllaClient,
+}
+
+impl ScyllaCustomerIdentityStore {
+    pub fn new(client: ScyllaClient) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl CustomerIdentityStore for ScyllaCustomerIdentityStore {
+    async fn get(&self, customer_id: &str) -> Result<Option<CustomerIdentity>, PersistenceError> {
+        let query = format!(
+            "SELECT customer_id, name, preferred_language, segment,
+                    facts_json, last_goal, last_channel, session_ids_json,
+                    first_seen, last_seen
+             FROM {}.customer_identities WHERE customer_id = ?",
+            self.client.keyspace()
+        );
+
+        let result = self.client.execute(query, (customer_id,)).await?;
+
+        if let Some(rows) = result.rows {
+            if let Some(row) = rows.into_iter().next() {
+                let (
+                    customer_id,
+                    name,
+                    preferred_language,
+                    segment,
+                    facts_json,
+                    last_goal,
+                    last_channel,
+                    session_ids_json,
+                    first_seen,
+                    last_seen,
+                ): (
+                    String,
+                    Option<String>,
+                    Option<String>,
+                    Option<String>,
+                    Option<String>,
+                    Option<String>,
+                    Option<String>,
+                    Option<String>,
+                    i64,
+                    i64,
+                ) = row
+                    .into_typed()
+                    .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+
+                let facts: HashMap<String, String> = match facts_json {
+                    Some(json) => serde_json::from_str(&json)?,
+                    None => HashMap::new(),
+                };
+                let session_ids: Vec<String> = match session_ids_json {
+                    Some(json) => serde_json::from_str(&json)?,
+                    None => Vec::new(),
+                };
+
+                return Ok(Some(CustomerIdentity {
+                    customer_id,
+                    name,
+                    preferred_language,
+                    segment,
+                    facts,
+                    last_goal,
+                    last_channel: last_channel.as_deref().and_then(Channel::parse),
+                    session_ids,
+                    first_seen: DateTime::from_timestamp_millis(first_seen)
+                        .unwrap_or_else(Utc::now),
+                    last_seen: DateTime::from_timestamp_millis(last_seen)
+                        .unwrap_or_else(Utc::now),
+                }));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn upsert(&self, identity: &CustomerIdentity) -> Result<(), PersistenceError> {
+        let query = format!(
+            "INSERT INTO {}.customer_identities (
+                customer_id, name, preferred_language, segment,
+                facts_json, last_goal, last_channel, session_ids_json,
+                first_seen, last_seen
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            self.client.keyspace()
+        );
+
+        let facts_json = serde_json::to_string(&identity.facts)?;
+        let session_ids_json = serde_json::to_string(&identity.session_ids)?;
+
+        self.client
+            .execute(
+                query,
+                (
+                    &identity.customer_id,
+                    &identity.name,
+                    &identity.preferred_language,
+                    &identity.segment,
+                    facts_json,
+                    &identity.last_goal,
+                    identity.last_channel.map(|c| c.as_str()),
+                    session_ids_json,
+                    identity.first_seen.timestamp_millis(),
+                    identity.last_seen.timestamp_millis(),
+                ),
+            )
+            .await?;
+
+        tracing::debug!(
+            sessions = identity.session_count(),
+            facts = identity.facts.len(),
+            "Customer identity persisted"
+        );
+
+        Ok(())
+    }
+}
+
+/// In-memory customer identity store
+///
+/// Used when ScyllaDB is not configured; identities do not survive restarts.
+#[derive(Default)]
+pub struct InMemoryCustomerIdentityStore {
+    identities: RwLock<HashMap<String, CustomerIdentity>>,
+}
+
+impl InMemoryCustomerIdentityStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CustomerIdentityStore for InMemoryCustomerIdentityStore {
+    async fn get(&self, customer_id: &str) -> Result<Option<CustomerIdentity>, PersistenceError> {
+        Ok(self.identities.read().await.get(customer_id).cloned())
+    }
+
+    async fn upsert(&self, identity: &CustomerIdentity) -> Result<(), PersistenceError> {
+        self.identities
+            .write()
+            .await
+            .insert(identity.customer_id.clone(), identity.clone());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_resolve_links_sessions_across_channels() {
+        let store = InMemoryCustomerIdentityStore::new();
+
+        let first = store
+            .resolve("9876543210", "call-1", Channel::Voice)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!first.is_returning("call-1"));
+
+        let second = store
+            .resolve("+91 98765 43210", "chat-1", Channel::WhatsApp)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(second.customer_id, "9876543210");
+        assert!(second.is_returning("chat-1"));
+        assert_eq!(second.session_ids, vec!["call-1", "chat-1"]);
+        assert_eq!(second.last_channel, Some(Channel::WhatsApp));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_rejects_invalid_phone() {
+        let store = InMemoryCustomerIdentityStore::new();
+        assert!(store
+            .resolve("12345", "call-1", Channel::Voice)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
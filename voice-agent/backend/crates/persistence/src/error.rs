@@ -4,10 +4,10 @@ use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum PersistenceError {
-    #[error("ScyllaDB connection error: {0}")]
+    #[error("Database connection error: {0}")]
     Connection(String),
 
-    #[error("ScyllaDB query error: {0}")]
+    #[error("Database query error: {0}")]
     Query(String),
 
     #[error("Serialization error: {0}")]
@@ -21,6 +21,29 @@ pub enum PersistenceError {
 
     #[error("Invalid data: {0}")]
     InvalidData(String),
+
+    #[error("Quota exceeded: {0}")]
+    QuotaExceeded(String),
+}
+
+/// Store failures never cost the caller an answer on their own; they are
+/// logged, and whatever needed the data degrades on its own terms
+impl voice_agent_core::ErrorClass for PersistenceError {
+    fn kind(&self) -> voice_agent_core::ErrorKind {
+        use voice_agent_core::ErrorKind;
+        match self {
+            PersistenceError::Connection(_) | PersistenceError::Query(_) => ErrorKind::Unavailable,
+            PersistenceError::SessionNotFound(_) => ErrorKind::NotFound,
+            PersistenceError::QuotaExceeded(_) => ErrorKind::RateLimited,
+            PersistenceError::Serialization(_)
+            | PersistenceError::SchemaError(_)
+            | PersistenceError::InvalidData(_) => ErrorKind::Internal,
+        }
+    }
+
+    fn is_user_visible(&self) -> bool {
+        false
+    }
 }
 
 impl From<scylla::transport::errors::NewSessionError> for PersistenceError {
@@ -34,3 +57,20 @@ impl From<scylla::transport::errors::QueryError> for PersistenceError {
         PersistenceError::Query(e.to_string())
     }
 }
+
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+impl From<sqlx::Error> for PersistenceError {
+    fn from(e: sqlx::Error) -> Self {
+        match e {
+            sqlx::Error::Configuration(_)
+            | sqlx::Error::Io(_)
+            | sqlx::Error::Tls(_)
+            | sqlx::Error::PoolTimedOut
+            | sqlx::Error::PoolClosed => PersistenceError::Connection(e.to_string()),
+            sqlx::Error::ColumnDecode { .. } | sqlx::Error::Decode(_) => {
+                PersistenceError::InvalidData(e.to_string())
+            },
+            _ => PersistenceError::Query(e.to_string()),
+        }
+    }
+}
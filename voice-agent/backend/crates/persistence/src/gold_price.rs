@@ -3,12 +3,13 @@
 //! Provides realistic asset price simulation with:
 //! - Daily fluctuation within configurable bounds
 //! - Price caching in ScyllaDB
-//! - Historical price tracking
+//! - Historical price tracking, rolled up into hourly or daily buckets
+//! - Trend statistics (change and volatility) over the history
 //! - Dynamic tier support (any number of tiers with config-driven names)
 
 use crate::{PersistenceError, ScyllaClient};
 use async_trait::async_trait;
-use chrono::{DateTime, NaiveDate, Timelike, Utc};
+use chrono::{DateTime, Duration, NaiveDate, Timelike, Utc};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -87,6 +88,164 @@ pub struct TierDefinition {
     pub description: String,
 }
 
+/// Width of the buckets price history is rolled up into
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PriceBucket {
+    Hourly,
+    Daily,
+}
+
+impl PriceBucket {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PriceBucket::Hourly => "hourly",
+            PriceBucket::Daily => "daily",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "hourly" => PriceBucket::Hourly,
+            _ => PriceBucket::Daily,
+        }
+    }
+
+    /// Start of the bucket containing `at`
+    fn start(&self, at: DateTime<Utc>) -> DateTime<Utc> {
+        let hour = match self {
+            PriceBucket::Hourly => at.hour(),
+            PriceBucket::Daily => 0,
+        };
+        at.date_naive()
+            .and_hms_opt(hour, 0, 0)
+            .map(|t| t.and_utc())
+            .unwrap_or(at)
+    }
+}
+
+/// Prices seen during one hour or day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PricePoint {
+    /// Start of the bucket
+    pub at: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    /// Stored prices rolled into this bucket
+    pub samples: usize,
+}
+
+/// Roll stored prices up into buckets, for one tier or the base price
+///
+/// Prices are placed by their `updated_at`; the result is oldest first.
+pub fn bucket_prices(
+    prices: &[AssetPrice],
+    bucket: PriceBucket,
+    tier: Option<&str>,
+) -> Vec<PricePoint> {
+    let mut sorted: Vec<&AssetPrice> = prices.iter().collect();
+    sorted.sort_by_key(|p| p.updated_at);
+
+    let mut points: Vec<PricePoint> = Vec::new();
+    for price in sorted {
+        let value = match tier {
+            Some(tier) => price.price_for_tier(tier),
+            None => price.base_price_per_unit,
+        };
+        let at = bucket.start(price.updated_at);
+        match points.last_mut() {
+            Some(point) if point.at == at => {
+                point.high = point.high.max(value);
+                point.low = point.low.min(value);
+                point.close = value;
+                point.samples += 1;
+            },
+            _ => points.push(PricePoint {
+                at,
+                open: value,
+                high: value,
+                low: value,
+                close: value,
+                samples: 1,
+            }),
+        }
+    }
+    points
+}
+
+/// Price movement across a run of history buckets
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PriceTrend {
+    /// First and last bucket
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    /// Close of the first bucket
+    pub start_price: f64,
+    /// Close of the last bucket
+    pub end_price: f64,
+    pub change: f64,
+    pub change_percent: f64,
+    pub high: f64,
+    pub low: f64,
+    /// Standard deviation of bucket-to-bucket returns, in percent
+    pub volatility_percent: f64,
+    pub points: usize,
+}
+
+impl PriceTrend {
+    /// Trend over `points` (oldest first); needs at least two buckets
+    pub fn from_points(points: &[PricePoint]) -> Option<Self> {
+        let (first, last) = match points {
+            [first, .., last] => (first, last),
+            _ => return None,
+        };
+        if first.close <= 0.0 {
+            return None;
+        }
+
+        let returns: Vec<f64> = points
+            .windows(2)
+            .filter(|w| w[0].close > 0.0)
+            .map(|w| (w[1].close - w[0].close) / w[0].close * 100.0)
+            .collect();
+        let volatility_percent = if returns.len() > 1 {
+            let mean = returns.iter().sum::<f64>() / returns.len() as f64;
+            let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>()
+                / (returns.len() - 1) as f64;
+            variance.sqrt()
+        } else {
+            0.0
+        };
+
+        let change = last.close - first.close;
+        Some(Self {
+            from: first.at,
+            to: last.at,
+            start_price: first.close,
+            end_price: last.close,
+            change,
+            change_percent: change / first.close * 100.0,
+            high: points.iter().map(|p| p.high).fold(f64::MIN, f64::max),
+            low: points.iter().map(|p| p.low).fold(f64::MAX, f64::min),
+            volatility_percent,
+            points: points.len(),
+        })
+    }
+
+    /// "up", "down", or "flat" when the change is within `flat_percent`
+    pub fn direction(&self, flat_percent: f64) -> &'static str {
+        if self.change_percent.abs() <= flat_percent {
+            "flat"
+        } else if self.change > 0.0 {
+            "up"
+        } else {
+            "down"
+        }
+    }
+}
+
 /// Asset price service trait (domain-agnostic interface)
 #[async_trait]
 pub trait AssetPriceService: Send + Sync {
@@ -101,9 +260,84 @@ pub trait AssetPriceService: Send + Sync {
 
     /// Force refresh the price (even if cache is valid)
     async fn refresh_price(&self) -> Result<AssetPrice, PersistenceError>;
+
+    /// Price history from `from` to `to` (inclusive), oldest first, for one
+    /// tier or the base price
+    ///
+    /// The default builds on `get_historical_price`, so it only has one price
+    /// per day to bucket.
+    async fn get_history(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        bucket: PriceBucket,
+        tier: Option<&str>,
+    ) -> Result<Vec<PricePoint>, PersistenceError> {
+        let mut prices = Vec::new();
+        for date in from.iter_days().take_while(|d| *d <= to) {
+            if let Some(price) = self.get_historical_price(date).await? {
+                prices.push(price);
+            }
+        }
+        Ok(bucket_prices(&prices, bucket, tier))
+    }
+
+    /// Daily trend over the last `days` days up to today
+    ///
+    /// `None` when there is not enough history to compare.
+    async fn get_trend(
+        &self,
+        days: u32,
+        tier: Option<&str>,
+    ) -> Result<Option<PriceTrend>, PersistenceError> {
+        let to = Utc::now().date_naive();
+        let from = to - Duration::days(days as i64);
+        let points = self.get_history(from, to, PriceBucket::Daily, tier).await?;
+        Ok(PriceTrend::from_points(&points))
+    }
 }
 
 
+/// Simulated price within ±`fluctuation_percent` of `base_price`
+pub(crate) fn simulate_price(
+    base_price: f64,
+    tiers: &[TierDefinition],
+    fluctuation_percent: f64,
+) -> AssetPrice {
+    let mut rng = rand::thread_rng();
+
+    // Generate fluctuation: -fluctuation_percent% to +fluctuation_percent%
+    let fluctuation = (rng.gen::<f64>() - 0.5) * 2.0 * (fluctuation_percent / 100.0);
+    let base_with_fluctuation = base_price * (1.0 + fluctuation);
+
+    let mut price = AssetPrice::new(base_with_fluctuation, "simulated");
+
+    // Calculate price for each tier
+    for tier in tiers {
+        let tier_price = base_with_fluctuation * tier.factor;
+        price.tier_prices.insert(tier.code.clone(), tier_price);
+    }
+
+    // Set base to the standard tier if available (typically the most common)
+    if let Some(standard_price) = price.tier_prices.get("22K").or_else(|| {
+        // Find tier with factor closest to 0.9 as "standard"
+        tiers
+            .iter()
+            .filter(|t| t.factor > 0.8 && t.factor < 1.0)
+            .min_by(|a, b| {
+                (a.factor - 0.9)
+                    .abs()
+                    .partial_cmp(&(b.factor - 0.9).abs())
+                    .unwrap()
+            })
+            .and_then(|t| price.tier_prices.get(&t.code))
+    }) {
+        price.base_price_per_unit = *standard_price;
+    }
+
+    price
+}
+
 /// Simulated asset price service with configurable tiers
 #[derive(Clone)]
 pub struct SimulatedAssetPriceService {
@@ -180,38 +414,7 @@ impl SimulatedAssetPriceService {
 
     /// Generate a simulated price with realistic fluctuation
     fn generate_price(&self) -> AssetPrice {
-        let mut rng = rand::thread_rng();
-
-        // Generate fluctuation: -fluctuation_percent% to +fluctuation_percent%
-        let fluctuation = (rng.gen::<f64>() - 0.5) * 2.0 * (self.fluctuation_percent / 100.0);
-        let base_with_fluctuation = self.base_price * (1.0 + fluctuation);
-
-        let mut price = AssetPrice::new(base_with_fluctuation, "simulated");
-
-        // Calculate price for each tier
-        for tier in &self.tiers {
-            let tier_price = base_with_fluctuation * tier.factor;
-            price.tier_prices.insert(tier.code.clone(), tier_price);
-        }
-
-        // Set base to the standard tier if available (typically the most common)
-        if let Some(standard_price) = price.tier_prices.get("22K").or_else(|| {
-            // Find tier with factor closest to 0.9 as "standard"
-            self.tiers
-                .iter()
-                .filter(|t| t.factor > 0.8 && t.factor < 1.0)
-                .min_by(|a, b| {
-                    (a.factor - 0.9)
-                        .abs()
-                        .partial_cmp(&(b.factor - 0.9).abs())
-                        .unwrap()
-                })
-                .and_then(|t| price.tier_prices.get(&t.code))
-        }) {
-            price.base_price_per_unit = *standard_price;
-        }
-
-        price
+        simulate_price(self.base_price, &self.tiers, self.fluctuation_percent)
     }
 
     /// Get cached price from ScyllaDB
@@ -223,7 +426,7 @@ impl SimulatedAssetPriceService {
             self.client.keyspace()
         );
 
-        let result = self.client.session().query_unpaged(query, &[]).await?;
+        let result = self.client.execute(query, &[]).await?;
 
         if let Some(rows) = result.rows {
             if let Some(row) = rows.into_iter().next() {
@@ -259,8 +462,7 @@ impl SimulatedAssetPriceService {
         );
 
         self.client
-            .session()
-            .query_unpaged(
+            .execute(
                 query,
                 (
                     price.base_price_per_unit,
@@ -291,8 +493,7 @@ impl SimulatedAssetPriceService {
         );
 
         self.client
-            .session()
-            .query_unpaged(
+            .execute(
                 query,
                 (
                     date.to_string(),
@@ -350,11 +551,7 @@ impl AssetPriceService for SimulatedAssetPriceService {
             self.client.keyspace()
         );
 
-        let result = self
-            .client
-            .session()
-            .query_unpaged(query, (date.to_string(),))
-            .await?;
+        let result = self.client.execute(query, (date.to_string(),)).await?;
 
         if let Some(rows) = result.rows {
             if let Some(row) = rows.into_iter().next() {
@@ -377,6 +574,47 @@ impl AssetPriceService for SimulatedAssetPriceService {
         Ok(None)
     }
 
+    async fn get_history(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        bucket: PriceBucket,
+        tier: Option<&str>,
+    ) -> Result<Vec<PricePoint>, PersistenceError> {
+        // History is partitioned by date, so read one day at a time
+        let query = format!(
+            "SELECT base_price, tier_prices_json, source, created_at
+             FROM {}.asset_prices WHERE date = ?",
+            self.client.keyspace()
+        );
+
+        let mut prices = Vec::new();
+        for date in from.iter_days().take_while(|d| *d <= to) {
+            let result = self
+                .client
+                .execute(query.clone(), (date.to_string(),))
+                .await?;
+            for row in result.rows.unwrap_or_default() {
+                let (base_price, tier_prices_json, source, created_at): (f64, String, String, i64) =
+                    row.into_typed()
+                        .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+
+                let tier_prices: HashMap<String, f64> = serde_json::from_str(&tier_prices_json)
+                    .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+
+                prices.push(AssetPrice {
+                    base_price_per_unit: base_price,
+                    tier_prices,
+                    source,
+                    updated_at: DateTime::from_timestamp_millis(created_at)
+                        .unwrap_or_else(Utc::now),
+                });
+            }
+        }
+
+        Ok(bucket_prices(&prices, bucket, tier))
+    }
+
     async fn refresh_price(&self) -> Result<AssetPrice, PersistenceError> {
         let price = self.generate_price();
         self.update_cache(&price).await?;
@@ -451,4 +689,60 @@ mod tests {
         assert!(codes.contains(&"C"));
         assert_eq!(codes.len(), 3);
     }
+
+    fn priced_at(base: f64, at: &str) -> AssetPrice {
+        let mut price = AssetPrice::new(base, "test").with_tier("22K", base * 0.916);
+        price.updated_at = DateTime::parse_from_rfc3339(at)
+            .unwrap()
+            .with_timezone(&Utc);
+        price
+    }
+
+    #[test]
+    fn test_bucket_prices_rolls_up_hours_into_days() {
+        let prices = vec![
+            priced_at(7100.0, "2026-10-02T15:10:00Z"),
+            priced_at(7000.0, "2026-10-01T09:05:00Z"),
+            priced_at(7200.0, "2026-10-01T13:40:00Z"),
+            priced_at(7050.0, "2026-10-01T18:20:00Z"),
+        ];
+
+        let hourly = bucket_prices(&prices, PriceBucket::Hourly, None);
+        assert_eq!(hourly.len(), 4);
+        assert_eq!(hourly[0].at.to_rfc3339(), "2026-10-01T09:00:00+00:00");
+
+        let daily = bucket_prices(&prices, PriceBucket::Daily, None);
+        assert_eq!(daily.len(), 2);
+        assert_eq!(daily[0].at.to_rfc3339(), "2026-10-01T00:00:00+00:00");
+        assert_eq!(
+            (daily[0].open, daily[0].high, daily[0].low, daily[0].close),
+            (7000.0, 7200.0, 7000.0, 7050.0)
+        );
+        assert_eq!(daily[0].samples, 3);
+
+        let tiered = bucket_prices(&prices, PriceBucket::Daily, Some("22K"));
+        assert!((tiered[1].close - 7100.0 * 0.916).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_price_trend_change_and_volatility() {
+        let prices = vec![
+            priced_at(7000.0, "2026-10-01T12:00:00Z"),
+            priced_at(7070.0, "2026-10-02T12:00:00Z"),
+            priced_at(7000.3, "2026-10-03T12:00:00Z"),
+        ];
+        let points = bucket_prices(&prices, PriceBucket::Daily, None);
+        let trend = PriceTrend::from_points(&points).unwrap();
+
+        assert!((trend.change - 0.3).abs() < 1e-6);
+        assert_eq!(trend.direction(0.25), "flat");
+        assert_eq!((trend.high, trend.low, trend.points), (7070.0, 7000.0, 3));
+        // Returns of +1% and about -0.99%
+        assert!((trend.volatility_percent - 1.4042).abs() < 0.001);
+
+        assert!(PriceTrend::from_points(&points[..1]).is_none());
+        let rising = PriceTrend::from_points(&points[..2]).unwrap();
+        assert_eq!(rising.direction(0.25), "up");
+        assert!((rising.change_percent - 1.0).abs() < 1e-9);
+    }
 }
@@ -0,0 +1,280 @@
+//! Write-ahead journal of conversation turns
+//!
+//! Every turn the agent appends what happened (caller and agent utterances,
+//! slots the dialogue state tracker filled or changed, stage moves and tool
+//! results) to the session's journal before the turn is considered done. If
+//! the backend dies mid-call, the next process replays the journal to
+//! rebuild the conversation, agent memory and dialogue state instead of
+//! starting the caller over.
+//!
+//! Entries are numbered per session; replay orders by sequence number, so
+//! batches may land out of order.
+
+use crate::{PersistenceError, ScyllaClient};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+
+/// What a journal entry records
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalEventKind {
+    /// Caller utterance (`{"text"}`)
+    UserTurn,
+    /// Agent reply (`{"text"}`)
+    AssistantTurn,
+    /// Dialogue slot filled, changed or cleared (`{"slot", "value"}`)
+    SlotChanged,
+    /// Conversation stage moved (`{"stage"}`)
+    StageChanged,
+    /// Tool executed (`{"tool", "arguments", "result"}`)
+    ToolResult,
+}
+
+impl JournalEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::UserTurn => "user_turn",
+            Self::AssistantTurn => "assistant_turn",
+            Self::SlotChanged => "slot_changed",
+            Self::StageChanged => "stage_changed",
+            Self::ToolResult => "tool_result",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "user_turn" => Some(Self::UserTurn),
+            "assistant_turn" => Some(Self::AssistantTurn),
+            "slot_changed" => Some(Self::SlotChanged),
+            "stage_changed" => Some(Self::StageChanged),
+            "tool_result" => Some(Self::ToolResult),
+            _ => None,
+        }
+    }
+}
+
+/// One journaled event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub session_id: String,
+    /// Position in the session's journal, from 0
+    pub seq: i64,
+    pub kind: JournalEventKind,
+    /// Event payload (JSON), shaped per kind
+    pub payload_json: String,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl JournalEntry {
+    pub fn new(
+        session_id: &str,
+        seq: i64,
+        kind: JournalEventKind,
+        payload: &serde_json::Value,
+    ) -> Self {
+        Self {
+            session_id: session_id.to_string(),
+            seq,
+            kind,
+            payload_json: payload.to_string(),
+            recorded_at: Utc::now(),
+        }
+    }
+
+    /// Parsed payload (`Null` if it is not valid JSON)
+    pub fn payload(&self) -> serde_json::Value {
+        serde_json::from_str(&self.payload_json).unwrap_or_default()
+    }
+}
+
+/// Session journal trait
+#[async_trait]
+pub trait SessionJournal: Send + Sync {
+    /// Append a batch of entries (already numbered by the caller)
+    async fn append(&self, entries: &[JournalEntry]) -> Result<(), PersistenceError>;
+
+    /// All entries of a session, in sequence order
+    async fn entries(&self, session_id: &str) -> Result<Vec<JournalEntry>, PersistenceError>;
+
+    /// Drop a session's journal once the call has ended normally
+    async fn truncate(&self, session_id: &str) -> Result<(), PersistenceError>;
+}
+
+/// ScyllaDB implementation of the session journal
+///
+/// Entries expire with the table's TTL, so journals of calls that never
+/// ended cleanly do not pile up.
+#[derive(Clone)]
+pub struct ScyllaSessionJournal {
+    client: ScyllaClient,
+}
+
+impl ScyllaSessionJournal {
+    pub fn new(client: ScyllaClient) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl SessionJournal for ScyllaSessionJournal {
+    async fn append(&self, entries: &[JournalEntry]) -> Result<(), PersistenceError> {
+        let query = format!(
+            "INSERT INTO {}.session_journal (
+                session_id, seq, kind, payload_json, recorded_at
+            ) VALUES (?, ?, ?, ?, ?)",
+            self.client.keyspace()
+        );
+
+        for entry in entries {
+            self.client
+                .execute(
+                    query.clone(),
+                    (
+                        &entry.session_id,
+                        entry.seq,
+                        entry.kind.as_str(),
+                        &entry.payload_json,
+                        entry.recorded_at.timestamp_millis(),
+                    ),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn entries(&self, session_id: &str) -> Result<Vec<JournalEntry>, PersistenceError> {
+        let query = format!(
+            "SELECT session_id, seq, kind, payload_json, recorded_at
+             FROM {}.session_journal WHERE session_id = ?",
+            self.client.keyspace()
+        );
+
+        let result = self.client.execute(query, (session_id,)).await?;
+
+        let mut entries = Vec::new();
+        for row in result.rows.unwrap_or_default() {
+            let (session_id, seq, kind, payload_json, recorded_at): (
+                String,
+                i64,
+                String,
+                String,
+                i64,
+            ) = row
+                .into_typed()
+                .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+            let Some(kind) = JournalEventKind::parse(&kind) else {
+                tracing::warn!(
+                    session_id = %session_id,
+                    seq,
+                    kind = %kind,
+                    "Skipping unknown journal entry"
+                );
+                continue;
+            };
+            entries.push(JournalEntry {
+                session_id,
+                seq,
+                kind,
+                payload_json,
+                recorded_at: DateTime::from_timestamp_millis(recorded_at).unwrap_or_else(Utc::now),
+            });
+        }
+        entries.sort_by_key(|e| e.seq);
+
+        Ok(entries)
+    }
+
+    async fn truncate(&self, session_id: &str) -> Result<(), PersistenceError> {
+        let query = format!(
+            "DELETE FROM {}.session_journal WHERE session_id = ?",
+            self.client.keyspace()
+        );
+        self.client.execute(query, (session_id,)).await?;
+        Ok(())
+    }
+}
+
+/// In-memory session journal
+///
+/// Used when persistence is not configured; journals do not survive restarts.
+#[derive(Default)]
+pub struct InMemorySessionJournal {
+    sessions: RwLock<HashMap<String, Vec<JournalEntry>>>,
+}
+
+impl InMemorySessionJournal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl SessionJournal for InMemorySessionJournal {
+    async fn append(&self, entries: &[JournalEntry]) -> Result<(), PersistenceError> {
+        let mut sessions = self.sessions.write().await;
+        for entry in entries {
+            sessions
+                .entry(entry.session_id.clone())
+                .or_default()
+                .push(entry.clone());
+        }
+        Ok(())
+    }
+
+    async fn entries(&self, session_id: &str) -> Result<Vec<JournalEntry>, PersistenceError> {
+        let mut entries = self
+            .sessions
+            .read()
+            .await
+            .get(session_id)
+            .cloned()
+            .unwrap_or_default();
+        entries.sort_by_key(|e| e.seq);
+        Ok(entries)
+    }
+
+    async fn truncate(&self, session_id: &str) -> Result<(), PersistenceError> {
+        self.sessions.write().await.remove(session_id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_in_memory_entries_in_sequence_order() {
+        let journal = InMemorySessionJournal::new();
+        let text = |text: &str| json!({ "text": text });
+        let slot = json!({"slot": "gold_weight", "value": "50"});
+        let later = [
+            JournalEntry::new("s1", 2, JournalEventKind::SlotChanged, &slot),
+            JournalEntry::new("s1", 3, JournalEventKind::AssistantTurn, &text("Noted")),
+        ];
+        let first = [
+            JournalEntry::new("s1", 0, JournalEventKind::UserTurn, &text("50 grams")),
+            JournalEntry::new("s2", 0, JournalEventKind::UserTurn, &text("hello")),
+        ];
+        journal.append(&later).await.unwrap();
+        journal.append(&first).await.unwrap();
+
+        let entries = journal.entries("s1").await.unwrap();
+        let seqs: Vec<i64> = entries.iter().map(|e| e.seq).collect();
+        assert_eq!(seqs, vec![0, 2, 3]);
+        assert_eq!(entries[1].payload()["value"], "50");
+        assert_eq!(
+            JournalEventKind::parse(entries[2].kind.as_str()),
+            Some(JournalEventKind::AssistantTurn)
+        );
+
+        journal.truncate("s1").await.unwrap();
+        assert!(journal.entries("s1").await.unwrap().is_empty());
+        assert_eq!(journal.entries("s2").await.unwrap().len(), 1);
+    }
+}
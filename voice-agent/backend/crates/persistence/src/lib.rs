@@ -1,32 +1,112 @@
 //! ScyllaDB persistence layer for voice-agent-rust
 //!
+//! SQLite and Postgres are supported as alternative backends behind the
+//! `sqlite` and `postgres` features (see [`sql`] and [`init_sql`]).
+//!
 //! Provides persistent storage for:
 //! - Sessions (replaces Redis stub)
 //! - SMS messages (simulated, persisted for audit)
 //! - Gold prices (simulated with realistic fluctuation)
 //! - Appointments
+//! - Callback requests
+//! - Branch slot capacity and holds
+//! - Competitor rates (admin-maintained)
+//! - CRM lead delivery status
+//! - Outbox of tool side-effects awaiting delivery
+//! - Write-ahead journal of conversation turns (crash recovery)
+//! - Customer identities (cross-channel)
+//! - Agent archival memory (notes + embeddings)
+//! - Conversation analytics (session outcomes + daily rollups)
+//! - Per-turn usage accounting (daily rollups per tenant)
+//! - Webhook delivery log
+//! - Price alert subscriptions
+//! - Published rate cards (LTV and interest rates)
+//! - Promotional offer mentions and acceptance per session
 //! - Audit logging (P0 FIX: RBI compliance)
 
+pub mod analytics;
 pub mod appointments;
+pub mod archival;
 pub mod audit;
+pub mod callbacks;
 pub mod client;
+pub mod competitor_rates;
+pub mod crm_delivery;
+pub mod customers;
 pub mod error;
 pub mod gold_price;
+pub mod journal;
+pub mod offers;
+pub mod outbox;
+pub mod price_alerts;
+pub mod rate_cards;
 pub mod schema;
 pub mod sessions;
+pub mod slots;
 pub mod sms;
+pub mod usage;
+pub mod webhooks;
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+pub mod sql;
 
+pub use analytics::{
+    AnalyticsStore, DailyRollup, FunnelStep, InMemoryAnalyticsStore, ScyllaAnalyticsStore,
+    SessionOutcome,
+};
 pub use appointments::{Appointment, AppointmentStatus, AppointmentStore, ScyllaAppointmentStore};
+pub use archival::{ArchivalRecord, ArchivalStore, ScyllaArchivalStore};
 pub use audit::{
     Actor, AuditEntry, AuditEventType, AuditLog, AuditLogger, AuditOutcome, AuditQuery,
     ScyllaAuditLog,
 };
-pub use client::{ScyllaClient, ScyllaConfig};
+pub use callbacks::{
+    Callback, CallbackStatus, CallbackStore, InMemoryCallbackStore, ScyllaCallbackStore,
+};
+pub use client::{Consistency, ScyllaClient, ScyllaConfig, SpeculativeRetry};
+pub use competitor_rates::{
+    CompetitorRate, CompetitorRateStore, InMemoryCompetitorRateStore, ScyllaCompetitorRateStore,
+};
+pub use crm_delivery::{CrmDelivery, CrmDeliveryStatus, CrmDeliveryStore, ScyllaCrmDeliveryStore};
+pub use customers::{
+    CustomerIdentityStore, InMemoryCustomerIdentityStore, ScyllaCustomerIdentityStore,
+};
 pub use error::PersistenceError;
 // Asset price types (domain-agnostic)
-pub use gold_price::{AssetPrice, AssetPriceService, SimulatedAssetPriceService, TierDefinition};
+pub use gold_price::{
+    bucket_prices, AssetPrice, AssetPriceService, PriceBucket, PricePoint, PriceTrend,
+    SimulatedAssetPriceService, TierDefinition,
+};
+pub use journal::{
+    InMemorySessionJournal, JournalEntry, JournalEventKind, ScyllaSessionJournal, SessionJournal,
+};
+pub use offers::{
+    InMemoryOfferEventStore, OfferEvent, OfferEventKind, OfferEventStore, OfferStats,
+    ScyllaOfferEventStore,
+};
+pub use outbox::{
+    InMemoryOutboxStore, OutboxEntry, OutboxKind, OutboxStatus, OutboxStore, ScyllaOutboxStore,
+};
+pub use price_alerts::{
+    AlertDirection, InMemoryPriceAlertStore, PriceAlert, PriceAlertStatus, PriceAlertStore,
+    ScyllaPriceAlertStore,
+};
+pub use rate_cards::{
+    InMemoryRateCardStore, PublishedRateCard, RateCardStore, ScyllaRateCardStore,
+};
 pub use sessions::{ScyllaSessionStore, SessionData, SessionStore};
-pub use sms::{SimulatedSmsService, SmsMessage, SmsService, SmsStatus, SmsType};
+pub use slots::{
+    InMemorySlotStore, ScyllaSlotStore, SlotAvailability, SlotConfirmation, SlotHold, SlotStore,
+};
+pub use sms::{SimulatedSmsService, SmsMessage, SmsResult, SmsService, SmsStatus, SmsType};
+pub use usage::{
+    DailyUsage, InMemoryUsageStore, ScyllaUsageStore, TurnUsage, UsageCounts, UsageStore,
+};
+pub use webhooks::{
+    InMemoryWebhookDeliveryStore, ScyllaWebhookDeliveryStore, WebhookDelivery,
+    WebhookDeliveryStatus, WebhookDeliveryStore,
+};
+
+use std::sync::Arc;
 
 /// Initialize the persistence layer with ScyllaDB and domain-specific tiers
 ///
@@ -43,23 +123,111 @@ pub async fn init(
     client.ensure_schema().await?;
 
     Ok(PersistenceLayer {
-        sessions: ScyllaSessionStore::new(client.clone()),
-        sms: SimulatedSmsService::new(client.clone()),
-        asset_price: SimulatedAssetPriceService::new(client.clone(), base_price, tiers),
-        appointments: ScyllaAppointmentStore::new(client.clone()),
-        audit: ScyllaAuditLog::new(client),
+        sessions: Arc::new(ScyllaSessionStore::new(client.clone())),
+        sms: Arc::new(SimulatedSmsService::new(client.clone())),
+        asset_price: Arc::new(SimulatedAssetPriceService::new(
+            client.clone(),
+            base_price,
+            tiers,
+        )),
+        appointments: Arc::new(ScyllaAppointmentStore::new(client.clone())),
+        slots: Arc::new(ScyllaSlotStore::new(client.clone())),
+        callbacks: Arc::new(ScyllaCallbackStore::new(client.clone())),
+        competitor_rates: Arc::new(ScyllaCompetitorRateStore::new(client.clone())),
+        crm_deliveries: Arc::new(ScyllaCrmDeliveryStore::new(client.clone())),
+        outbox: Arc::new(ScyllaOutboxStore::new(client.clone())),
+        journal: Arc::new(ScyllaSessionJournal::new(client.clone())),
+        customers: Arc::new(ScyllaCustomerIdentityStore::new(client.clone())),
+        archival: Arc::new(ScyllaArchivalStore::new(client.clone())),
+        analytics: Arc::new(ScyllaAnalyticsStore::new(client.clone())),
+        usage: Arc::new(ScyllaUsageStore::new(client.clone())),
+        webhook_deliveries: Arc::new(ScyllaWebhookDeliveryStore::new(client.clone())),
+        price_alerts: Arc::new(ScyllaPriceAlertStore::new(client.clone())),
+        rate_cards: Arc::new(ScyllaRateCardStore::new(client.clone())),
+        offer_events: Arc::new(ScyllaOfferEventStore::new(client.clone())),
+        audit: Arc::new(ScyllaAuditLog::new(client)),
     })
 }
 
+/// Initialize the persistence layer with SQLite or Postgres
+///
+/// The backend is chosen from the URL scheme in `config`; tables are created
+/// on first start. Arguments otherwise match [`init`].
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+pub async fn init_sql(
+    config: sql::SqlConfig,
+    base_price: f64,
+    tiers: Vec<TierDefinition>,
+) -> Result<PersistenceLayer, PersistenceError> {
+    let client = sql::SqlClient::connect(&config).await?;
+    client.ensure_schema().await?;
+
+    Ok(PersistenceLayer {
+        sessions: Arc::new(sql::SqlSessionStore::new(client.clone())),
+        sms: Arc::new(sql::SqlSmsService::new(client.clone())),
+        asset_price: Arc::new(sql::SqlAssetPriceService::new(
+            client.clone(),
+            base_price,
+            tiers,
+        )),
+        appointments: Arc::new(sql::SqlAppointmentStore::new(client.clone())),
+        slots: Arc::new(sql::SqlSlotStore::new(client.clone())),
+        callbacks: Arc::new(sql::SqlCallbackStore::new(client.clone())),
+        competitor_rates: Arc::new(sql::SqlCompetitorRateStore::new(client.clone())),
+        crm_deliveries: Arc::new(sql::SqlCrmDeliveryStore::new(client.clone())),
+        outbox: Arc::new(sql::SqlOutboxStore::new(client.clone())),
+        journal: Arc::new(sql::SqlSessionJournal::new(client.clone())),
+        customers: Arc::new(sql::SqlCustomerIdentityStore::new(client.clone())),
+        archival: Arc::new(sql::SqlArchivalStore::new(client.clone())),
+        analytics: Arc::new(sql::SqlAnalyticsStore::new(client.clone())),
+        usage: Arc::new(sql::SqlUsageStore::new(client.clone())),
+        webhook_deliveries: Arc::new(sql::SqlWebhookDeliveryStore::new(client.clone())),
+        price_alerts: Arc::new(sql::SqlPriceAlertStore::new(client.clone())),
+        rate_cards: Arc::new(sql::SqlRateCardStore::new(client.clone())),
+        offer_events: Arc::new(sql::SqlOfferEventStore::new(client.clone())),
+        audit: Arc::new(sql::SqlAuditLog::new(client)),
+    })
+}
 
 /// Combined persistence layer with all services
+///
+/// Services are trait objects so the same layer is returned for every
+/// backend.
 pub struct PersistenceLayer {
-    pub sessions: ScyllaSessionStore,
-    pub sms: SimulatedSmsService,
+    pub sessions: Arc<dyn SessionStore>,
+    pub sms: Arc<dyn SmsService>,
     /// Asset price service with config-driven tier support
-    pub asset_price: SimulatedAssetPriceService,
-    pub appointments: ScyllaAppointmentStore,
+    pub asset_price: Arc<dyn AssetPriceService>,
+    pub appointments: Arc<dyn AppointmentStore>,
+    /// Branch slot capacity and holds
+    pub slots: Arc<dyn SlotStore>,
+    /// Callback requests
+    pub callbacks: Arc<dyn CallbackStore>,
+    /// Admin-maintained competitor rates
+    pub competitor_rates: Arc<dyn CompetitorRateStore>,
+    /// CRM lead delivery status tracking
+    pub crm_deliveries: Arc<dyn CrmDeliveryStore>,
+    /// Tool side-effects awaiting delivery
+    pub outbox: Arc<dyn OutboxStore>,
+    /// Per-turn conversation journal for crash recovery
+    pub journal: Arc<dyn SessionJournal>,
+    /// Cross-channel customer identities
+    pub customers: Arc<dyn CustomerIdentityStore>,
+    /// Agent archival memory
+    pub archival: Arc<dyn ArchivalStore>,
+    /// Session outcomes and daily rollups
+    pub analytics: Arc<dyn AnalyticsStore>,
+    /// Per-turn usage and daily rollups per tenant
+    pub usage: Arc<dyn UsageStore>,
+    /// Webhook delivery log
+    pub webhook_deliveries: Arc<dyn WebhookDeliveryStore>,
+    /// Customer price alert subscriptions
+    pub price_alerts: Arc<dyn PriceAlertStore>,
+    /// Published rate cards
+    pub rate_cards: Arc<dyn RateCardStore>,
+    /// Offer mentions and acceptance per session
+    pub offer_events: Arc<dyn OfferEventStore>,
     /// Audit logging for compliance
-    pub audit: ScyllaAuditLog,
+    pub audit: Arc<dyn AuditLog>,
 }
 
@@ -0,0 +1,347 @@
+//! Promotional offer tracking
+//!
+//! Records which offers were mentioned to each session and whether the
+//! customer accepted or declined them. An event is kept once per session,
+//! offer and kind, so repeating an offer in a call is not counted twice.
+//! Campaign reports (`offer_report`) count sessions per offer over a date
+//! range.
+
+use crate::analytics::days_in_range;
+use crate::slots::lwt_applied;
+use crate::{PersistenceError, ScyllaClient};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use tokio::sync::RwLock;
+
+/// What happened to an offer in a session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OfferEventKind {
+    /// The agent presented the offer
+    Mentioned,
+    Accepted,
+    Declined,
+}
+
+impl OfferEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Mentioned => "mentioned",
+            Self::Accepted => "accepted",
+            Self::Declined => "declined",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "accepted" => Self::Accepted,
+            "declined" => Self::Declined,
+            _ => Self::Mentioned,
+        }
+    }
+}
+
+/// One offer event in a session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OfferEvent {
+    pub session_id: String,
+    pub offer_id: String,
+    pub kind: OfferEventKind,
+    pub created_at: DateTime<Utc>,
+}
+
+impl OfferEvent {
+    /// Create an event stamped with the current time
+    pub fn new(
+        session_id: impl Into<String>,
+        offer_id: impl Into<String>,
+        kind: OfferEventKind,
+    ) -> Self {
+        Self {
+            session_id: session_id.into(),
+            offer_id: offer_id.into(),
+            kind,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Day (UTC) the event is reported under
+    pub fn day(&self) -> NaiveDate {
+        self.created_at.date_naive()
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), PersistenceError> {
+        if self.session_id.trim().is_empty() || self.offer_id.trim().is_empty() {
+            return Err(PersistenceError::InvalidData(
+                "session_id and offer_id are required".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Sessions per outcome for one offer
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OfferStats {
+    pub offer_id: String,
+    pub mentioned: u64,
+    pub accepted: u64,
+    pub declined: u64,
+}
+
+impl OfferStats {
+    /// Count events per offer, by offer ID
+    pub fn aggregate(events: &[OfferEvent]) -> Vec<OfferStats> {
+        let mut seen = HashSet::new();
+        let mut stats: BTreeMap<&str, OfferStats> = BTreeMap::new();
+        for event in events {
+            if !seen.insert((&event.session_id, &event.offer_id, event.kind)) {
+                continue;
+            }
+            let entry = stats
+                .entry(event.offer_id.as_str())
+                .or_insert_with(|| OfferStats {
+                    offer_id: event.offer_id.clone(),
+                    ..Default::default()
+                });
+            match event.kind {
+                OfferEventKind::Mentioned => entry.mentioned += 1,
+                OfferEventKind::Accepted => entry.accepted += 1,
+                OfferEventKind::Declined => entry.declined += 1,
+            }
+        }
+        stats.into_values().collect()
+    }
+
+    /// Share of sessions the offer was mentioned in that accepted it
+    pub fn acceptance_rate(&self) -> f64 {
+        if self.mentioned == 0 {
+            return 0.0;
+        }
+        self.accepted as f64 / self.mentioned as f64
+    }
+}
+
+/// Offer event store trait
+#[async_trait]
+pub trait OfferEventStore: Send + Sync {
+    /// Record an event; returns false if the session already has it
+    async fn record(&self, event: &OfferEvent) -> Result<bool, PersistenceError>;
+
+    /// Events of one session, oldest first
+    async fn session_events(&self, session_id: &str) -> Result<Vec<OfferEvent>, PersistenceError>;
+
+    /// Events recorded on `day`
+    async fn events_for_day(&self, day: NaiveDate) -> Result<Vec<OfferEvent>, PersistenceError>;
+}
+
+/// Per-offer session counts for a date range (inclusive)
+pub async fn offer_report(
+    store: &dyn OfferEventStore,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<OfferStats>, PersistenceError> {
+    let mut events = Vec::new();
+    for day in days_in_range(from, to)? {
+        events.extend(store.events_for_day(day).await?);
+    }
+    Ok(OfferStats::aggregate(&events))
+}
+
+/// ScyllaDB implementation of the offer event store
+///
+/// Events are written per session first (a lightweight transaction keeps
+/// one per session, offer and kind) and then to a table keyed by day for
+/// reports.
+#[derive(Clone)]
+pub struct ScyllaOfferEventStore {
+    client: ScyllaClient,
+}
+
+impl ScyllaOfferEventStore {
+    pub fn new(client: ScyllaClient) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl OfferEventStore for ScyllaOfferEventStore {
+    async fn record(&self, event: &OfferEvent) -> Result<bool, PersistenceError> {
+        event.validate()?;
+
+        let query = format!(
+            "INSERT INTO {}.offer_events_by_session (
+                session_id, offer_id, kind, day, created_at
+            ) VALUES (?, ?, ?, ?, ?) IF NOT EXISTS",
+            self.client.keyspace()
+        );
+        let result = self
+            .client
+            .execute(
+                query,
+                (
+                    &event.session_id,
+                    &event.offer_id,
+                    event.kind.as_str(),
+                    event.day().to_string(),
+                    event.created_at.timestamp_millis(),
+                ),
+            )
+            .await?;
+        if !lwt_applied(result.rows) {
+            return Ok(false);
+        }
+
+        let query = format!(
+            "INSERT INTO {}.offer_events (
+                day, offer_id, session_id, kind, created_at
+            ) VALUES (?, ?, ?, ?, ?)",
+            self.client.keyspace()
+        );
+        self.client
+            .execute(
+                query,
+                (
+                    event.day().to_string(),
+                    &event.offer_id,
+                    &event.session_id,
+                    event.kind.as_str(),
+                    event.created_at.timestamp_millis(),
+                ),
+            )
+            .await?;
+
+        Ok(true)
+    }
+
+    async fn session_events(&self, session_id: &str) -> Result<Vec<OfferEvent>, PersistenceError> {
+        let query = format!(
+            "SELECT session_id, offer_id, kind, created_at
+             FROM {}.offer_events_by_session WHERE session_id = ?",
+            self.client.keyspace()
+        );
+        let result = self.client.execute(query, (session_id,)).await?;
+
+        let mut events = rows_to_events(result.rows)?;
+        events.sort_by_key(|e| e.created_at);
+        Ok(events)
+    }
+
+    async fn events_for_day(&self, day: NaiveDate) -> Result<Vec<OfferEvent>, PersistenceError> {
+        let query = format!(
+            "SELECT session_id, offer_id, kind, created_at
+             FROM {}.offer_events WHERE day = ?",
+            self.client.keyspace()
+        );
+        let result = self.client.execute(query, (day.to_string(),)).await?;
+
+        rows_to_events(result.rows)
+    }
+}
+
+fn rows_to_events(
+    rows: Option<Vec<scylla::frame::response::result::Row>>,
+) -> Result<Vec<OfferEvent>, PersistenceError> {
+    let mut events = Vec::new();
+    for row in rows.unwrap_or_default() {
+        let (session_id, offer_id, kind, created_at): (String, String, String, i64) = row
+            .into_typed()
+            .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+
+        events.push(OfferEvent {
+            session_id,
+            offer_id,
+            kind: OfferEventKind::parse(&kind),
+            created_at: DateTime::from_timestamp_millis(created_at).unwrap_or_else(Utc::now),
+        });
+    }
+    Ok(events)
+}
+
+/// In-memory offer event store
+///
+/// Used when ScyllaDB is not configured; events do not survive restarts.
+#[derive(Default)]
+pub struct InMemoryOfferEventStore {
+    events: RwLock<HashMap<(String, String, OfferEventKind), OfferEvent>>,
+}
+
+impl InMemoryOfferEventStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl OfferEventStore for InMemoryOfferEventStore {
+    async fn record(&self, event: &OfferEvent) -> Result<bool, PersistenceError> {
+        event.validate()?;
+        let mut events = self.events.write().await;
+        let key = (event.session_id.clone(), event.offer_id.clone(), event.kind);
+        if events.contains_key(&key) {
+            return Ok(false);
+        }
+        events.insert(key, event.clone());
+        Ok(true)
+    }
+
+    async fn session_events(&self, session_id: &str) -> Result<Vec<OfferEvent>, PersistenceError> {
+        let events = self.events.read().await;
+        let mut listed: Vec<OfferEvent> = events
+            .values()
+            .filter(|e| e.session_id == session_id)
+            .cloned()
+            .collect();
+        listed.sort_by_key(|e| e.created_at);
+        Ok(listed)
+    }
+
+    async fn events_for_day(&self, day: NaiveDate) -> Result<Vec<OfferEvent>, PersistenceError> {
+        let events = self.events.read().await;
+        Ok(events
+            .values()
+            .filter(|e| e.day() == day)
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_offer_report_counts_sessions_once() {
+        let store = InMemoryOfferEventStore::new();
+        let record = |session: &str, offer: &str, kind| {
+            let event = OfferEvent::new(session, offer, kind);
+            let store = &store;
+            async move { store.record(&event).await.unwrap() }
+        };
+
+        assert!(record("s1", "switch", OfferEventKind::Mentioned).await);
+        assert!(!record("s1", "switch", OfferEventKind::Mentioned).await);
+        assert!(record("s1", "switch", OfferEventKind::Accepted).await);
+        assert!(record("s2", "switch", OfferEventKind::Mentioned).await);
+        assert!(record("s2", "festive", OfferEventKind::Mentioned).await);
+        assert!(record("s2", "festive", OfferEventKind::Declined).await);
+        assert!(store
+            .record(&OfferEvent::new("", "switch", OfferEventKind::Mentioned))
+            .await
+            .is_err());
+
+        let session = store.session_events("s1").await.unwrap();
+        assert_eq!(session.len(), 2);
+
+        let today = Utc::now().date_naive();
+        let report = offer_report(&store, today, today).await.unwrap();
+        assert_eq!(report.len(), 2);
+        assert_eq!(report[0].offer_id, "festive");
+        assert_eq!(report[0].declined, 1);
+        let switch = &report[1];
+        assert_eq!((switch.mentioned, switch.accepted), (2, 1));
+        assert_eq!(switch.acceptance_rate(), 0.5);
+    }
+}
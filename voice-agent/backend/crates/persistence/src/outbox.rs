@@ -0,0 +1,395 @@
+//! Outbox for side-effecting tool actions
+//!
+//! SMS sends, CRM lead pushes and appointment bookings made mid-conversation
+//! are first written here and only then delivered by a background
+//! dispatcher, which retries with backoff and marks each entry delivered.
+//! A crash between the tool call and the external send therefore delays the
+//! action instead of losing it (at-least-once delivery).
+
+use crate::{PersistenceError, ScyllaClient};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+
+/// Kind of side-effect an outbox entry carries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutboxKind {
+    /// SMS to the customer
+    Sms,
+    /// Lead pushed to the CRM
+    CrmLead,
+    /// Appointment booked in the calendar
+    Appointment,
+}
+
+impl OutboxKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Sms => "sms",
+            Self::CrmLead => "crm_lead",
+            Self::Appointment => "appointment",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "crm_lead" => Self::CrmLead,
+            "appointment" => Self::Appointment,
+            _ => Self::Sms,
+        }
+    }
+}
+
+/// Outbox entry status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutboxStatus {
+    /// Recorded, not yet attempted
+    Pending,
+    /// Last attempt failed, will be retried
+    Retrying,
+    /// Accepted by the target
+    Delivered,
+    /// Gave up after exhausting retries (or non-retryable error)
+    Failed,
+}
+
+impl OutboxStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Retrying => "retrying",
+            Self::Delivered => "delivered",
+            Self::Failed => "failed",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "retrying" => Self::Retrying,
+            "delivered" => Self::Delivered,
+            "failed" => Self::Failed,
+            _ => Self::Pending,
+        }
+    }
+
+    /// Whether no further attempts will be made
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Delivered | Self::Failed)
+    }
+}
+
+/// One recorded side-effect
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    /// Idempotency key; recording the same key twice keeps one entry
+    pub idempotency_key: String,
+    pub kind: OutboxKind,
+    pub session_id: Option<String>,
+    /// Action payload (JSON), interpreted by the dispatcher per kind
+    pub payload_json: String,
+    pub status: OutboxStatus,
+    /// Delivery attempts made so far
+    pub attempts: i32,
+    /// Earliest time for the next attempt
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+    /// ID assigned by the target once delivered
+    pub external_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl OutboxEntry {
+    pub fn new(
+        idempotency_key: &str,
+        kind: OutboxKind,
+        session_id: Option<&str>,
+        payload_json: String,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            idempotency_key: idempotency_key.to_string(),
+            kind,
+            session_id: session_id.map(String::from),
+            payload_json,
+            status: OutboxStatus::Pending,
+            attempts: 0,
+            next_attempt_at: now,
+            last_error: None,
+            external_id: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Whether the dispatcher should attempt delivery now
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        !self.status.is_terminal() && self.next_attempt_at <= now
+    }
+}
+
+/// Outbox store trait
+#[async_trait]
+pub trait OutboxStore: Send + Sync {
+    /// Record a new entry
+    async fn create(&self, entry: &OutboxEntry) -> Result<(), PersistenceError>;
+    /// Get an entry by idempotency key
+    async fn get(&self, idempotency_key: &str) -> Result<Option<OutboxEntry>, PersistenceError>;
+    /// Overwrite an entry after a delivery attempt
+    async fn update(&self, entry: &OutboxEntry) -> Result<(), PersistenceError>;
+    /// All entries not yet delivered or failed, earliest next attempt first
+    async fn list_pending(&self) -> Result<Vec<OutboxEntry>, PersistenceError>;
+}
+
+/// Partition of `pending_outbox` holding every pending entry key
+const PENDING_QUEUE: &str = "pending";
+
+/// ScyllaDB implementation of the outbox store
+#[derive(Clone)]
+pub struct ScyllaOutboxStore {
+    client: ScyllaClient,
+}
+
+impl ScyllaOutboxStore {
+    pub fn new(client: ScyllaClient) -> Self {
+        Self { client }
+    }
+
+    async fn write(&self, entry: &OutboxEntry) -> Result<(), PersistenceError> {
+        let query = format!(
+            "INSERT INTO {}.outbox (
+                idempotency_key, kind, session_id, payload_json, status, attempts,
+                next_attempt_at, last_error, external_id, created_at, updated_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            self.client.keyspace()
+        );
+
+        self.client
+            .execute(
+                query,
+                (
+                    &entry.idempotency_key,
+                    entry.kind.as_str(),
+                    &entry.session_id,
+                    &entry.payload_json,
+                    entry.status.as_str(),
+                    entry.attempts,
+                    entry.next_attempt_at.timestamp_millis(),
+                    &entry.last_error,
+                    &entry.external_id,
+                    entry.created_at.timestamp_millis(),
+                    entry.updated_at.timestamp_millis(),
+                ),
+            )
+            .await?;
+
+        // Keep the pending index in step with the status
+        let index_query = if entry.status.is_terminal() {
+            format!(
+                "DELETE FROM {}.pending_outbox WHERE queue = ? AND idempotency_key = ?",
+                self.client.keyspace()
+            )
+        } else {
+            format!(
+                "INSERT INTO {}.pending_outbox (queue, idempotency_key) VALUES (?, ?)",
+                self.client.keyspace()
+            )
+        };
+        self.client
+            .execute(index_query, (PENDING_QUEUE, &entry.idempotency_key))
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OutboxStore for ScyllaOutboxStore {
+    async fn create(&self, entry: &OutboxEntry) -> Result<(), PersistenceError> {
+        self.write(entry).await?;
+
+        tracing::debug!(
+            idempotency_key = %entry.idempotency_key,
+            kind = entry.kind.as_str(),
+            "Outbox entry recorded in ScyllaDB"
+        );
+
+        Ok(())
+    }
+
+    async fn get(&self, idempotency_key: &str) -> Result<Option<OutboxEntry>, PersistenceError> {
+        let query = format!(
+            "SELECT idempotency_key, kind, session_id, payload_json, status, attempts,
+                    next_attempt_at, last_error, external_id, created_at, updated_at
+             FROM {}.outbox WHERE idempotency_key = ?",
+            self.client.keyspace()
+        );
+
+        let result = self.client.execute(query, (idempotency_key,)).await?;
+
+        let Some(row) = result.rows.and_then(|rows| rows.into_iter().next()) else {
+            return Ok(None);
+        };
+        let (
+            idempotency_key,
+            kind,
+            session_id,
+            payload_json,
+            status,
+            attempts,
+            next_attempt_at,
+            last_error,
+            external_id,
+            created_at,
+            updated_at,
+        ): (
+            String,
+            String,
+            Option<String>,
+            String,
+            String,
+            i32,
+            i64,
+            Option<String>,
+            Option<String>,
+            i64,
+            i64,
+        ) = row
+            .into_typed()
+            .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+
+        let timestamp =
+            |millis: i64| DateTime::from_timestamp_millis(millis).unwrap_or_else(Utc::now);
+        Ok(Some(OutboxEntry {
+            idempotency_key,
+            kind: OutboxKind::parse(&kind),
+            session_id,
+            payload_json,
+            status: OutboxStatus::parse(&status),
+            attempts,
+            next_attempt_at: timestamp(next_attempt_at),
+            last_error,
+            external_id,
+            created_at: timestamp(created_at),
+            updated_at: timestamp(updated_at),
+        }))
+    }
+
+    async fn update(&self, entry: &OutboxEntry) -> Result<(), PersistenceError> {
+        self.write(entry).await
+    }
+
+    async fn list_pending(&self) -> Result<Vec<OutboxEntry>, PersistenceError> {
+        let query = format!(
+            "SELECT idempotency_key FROM {}.pending_outbox WHERE queue = ?",
+            self.client.keyspace()
+        );
+
+        let result = self.client.execute(query, (PENDING_QUEUE,)).await?;
+
+        let mut entries = Vec::new();
+        for row in result.rows.unwrap_or_default() {
+            let (idempotency_key,): (String,) = row
+                .into_typed()
+                .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+            if let Some(entry) = self.get(&idempotency_key).await? {
+                entries.push(entry);
+            }
+        }
+        entries.sort_by_key(|e| e.next_attempt_at);
+
+        Ok(entries)
+    }
+}
+
+/// In-memory outbox store
+///
+/// Used when persistence is not configured; entries do not survive restarts.
+#[derive(Default)]
+pub struct InMemoryOutboxStore {
+    entries: RwLock<HashMap<String, OutboxEntry>>,
+}
+
+impl InMemoryOutboxStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl OutboxStore for InMemoryOutboxStore {
+    async fn create(&self, entry: &OutboxEntry) -> Result<(), PersistenceError> {
+        self.entries
+            .write()
+            .await
+            .insert(entry.idempotency_key.clone(), entry.clone());
+        Ok(())
+    }
+
+    async fn get(&self, idempotency_key: &str) -> Result<Option<OutboxEntry>, PersistenceError> {
+        Ok(self.entries.read().await.get(idempotency_key).cloned())
+    }
+
+    async fn update(&self, entry: &OutboxEntry) -> Result<(), PersistenceError> {
+        self.create(entry).await
+    }
+
+    async fn list_pending(&self) -> Result<Vec<OutboxEntry>, PersistenceError> {
+        let mut pending: Vec<OutboxEntry> = self
+            .entries
+            .read()
+            .await
+            .values()
+            .filter(|e| !e.status.is_terminal())
+            .cloned()
+            .collect();
+        pending.sort_by_key(|e| e.next_attempt_at);
+        Ok(pending)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_due_until_terminal() {
+        let mut entry = OutboxEntry::new("sms-1", OutboxKind::Sms, Some("s1"), "{}".to_string());
+        let now = entry.next_attempt_at;
+        assert!(entry.is_due(now));
+
+        entry.status = OutboxStatus::Retrying;
+        entry.next_attempt_at = now + Duration::seconds(30);
+        assert!(!entry.is_due(now));
+        assert!(entry.is_due(now + Duration::seconds(30)));
+
+        entry.status = OutboxStatus::Delivered;
+        assert!(!entry.is_due(now + Duration::hours(1)));
+        assert_eq!(OutboxKind::parse("crm_lead"), OutboxKind::CrmLead);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_list_pending() {
+        let store = InMemoryOutboxStore::new();
+        let now = Utc::now();
+        let mut later = OutboxEntry::new("a", OutboxKind::CrmLead, None, "{}".to_string());
+        later.next_attempt_at = now + Duration::minutes(5);
+        let sooner = OutboxEntry::new("b", OutboxKind::Sms, None, "{}".to_string());
+        store.create(&later).await.unwrap();
+        store.create(&sooner).await.unwrap();
+
+        let pending = store.list_pending().await.unwrap();
+        assert_eq!(pending.len(), 2);
+        assert_eq!(pending[0].idempotency_key, "b");
+
+        let mut delivered = sooner.clone();
+        delivered.status = OutboxStatus::Delivered;
+        store.update(&delivered).await.unwrap();
+        assert_eq!(store.list_pending().await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,452 @@
+//! Price alert subscriptions
+//!
+//! A price alert is a customer's request to be told by SMS when the asset
+//! price crosses a threshold ("tell me when gold crosses ₹7600"). Alerts
+//! stay active until the price crosses the threshold in the requested
+//! direction, the customer cancels, or the alert expires.
+
+use crate::{PersistenceError, ScyllaClient};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Which way the price has to move to trigger the alert
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertDirection {
+    /// Trigger when the price reaches or rises above the threshold
+    Above,
+    /// Trigger when the price falls to or below the threshold
+    Below,
+}
+
+impl AlertDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Above => "above",
+            Self::Below => "below",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "below" => Self::Below,
+            _ => Self::Above,
+        }
+    }
+}
+
+/// Price alert status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceAlertStatus {
+    /// Watching the price
+    Active,
+    /// Threshold crossed and the customer notified
+    Triggered,
+    /// Expired before the threshold was crossed
+    Expired,
+    /// Cancelled by the customer or an agent
+    Cancelled,
+}
+
+impl PriceAlertStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Triggered => "triggered",
+            Self::Expired => "expired",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "triggered" => Self::Triggered,
+            "expired" => Self::Expired,
+            "cancelled" => Self::Cancelled,
+            _ => Self::Active,
+        }
+    }
+
+    /// Whether the alert is closed
+    pub fn is_terminal(&self) -> bool {
+        !matches!(self, Self::Active)
+    }
+}
+
+/// A customer's price alert subscription
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceAlert {
+    pub alert_id: Uuid,
+    pub session_id: Option<String>,
+    pub customer_phone: String,
+    pub customer_name: Option<String>,
+    /// Tier code the threshold applies to (e.g. "22K"); base price if unset
+    pub tier: Option<String>,
+    /// Price per unit that triggers the alert
+    pub threshold: f64,
+    pub direction: AlertDirection,
+    pub status: PriceAlertStatus,
+    pub expires_at: DateTime<Utc>,
+    pub triggered_at: Option<DateTime<Utc>>,
+    /// Price seen when the alert triggered
+    pub triggered_price: Option<f64>,
+    /// Notification SMS
+    pub sms_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PriceAlert {
+    pub fn new(
+        customer_phone: &str,
+        threshold: f64,
+        direction: AlertDirection,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            alert_id: Uuid::new_v4(),
+            session_id: None,
+            customer_phone: customer_phone.to_string(),
+            customer_name: None,
+            tier: None,
+            threshold,
+            direction,
+            status: PriceAlertStatus::Active,
+            expires_at,
+            triggered_at: None,
+            triggered_price: None,
+            sms_id: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Whether `price` is on the far side of the threshold
+    pub fn is_crossed(&self, price: f64) -> bool {
+        match self.direction {
+            AlertDirection::Above => price >= self.threshold,
+            AlertDirection::Below => price <= self.threshold,
+        }
+    }
+
+    /// Whether the alert ran out before triggering
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.status == PriceAlertStatus::Active && now >= self.expires_at
+    }
+
+    /// Whether `other` watches the same price for the same customer
+    pub fn same_subscription(&self, other: &PriceAlert) -> bool {
+        self.customer_phone == other.customer_phone
+            && self.tier == other.tier
+            && self.direction == other.direction
+            && (self.threshold - other.threshold).abs() < 0.01
+    }
+}
+
+/// Price alert store trait
+#[async_trait]
+pub trait PriceAlertStore: Send + Sync {
+    /// Store a new alert
+    async fn create(&self, alert: &PriceAlert) -> Result<(), PersistenceError>;
+    /// Get an alert by ID
+    async fn get(&self, alert_id: Uuid) -> Result<Option<PriceAlert>, PersistenceError>;
+    /// Overwrite an alert after a status change
+    async fn update(&self, alert: &PriceAlert) -> Result<(), PersistenceError>;
+    /// All active alerts, oldest first
+    async fn list_active(&self) -> Result<Vec<PriceAlert>, PersistenceError>;
+}
+
+/// Partition of `active_price_alerts` holding every active alert ID
+const ACTIVE_QUEUE: &str = "active";
+
+/// ScyllaDB implementation of the price alert store
+#[derive(Clone)]
+pub struct ScyllaPriceAlertStore {
+    client: ScyllaClient,
+}
+
+/// Columns of a `price_alerts` row, in `SELECT` order
+type PriceAlertRow = (
+    Uuid,
+    Option<String>,
+    String,
+    Option<String>,
+    Option<String>,
+    f64,
+    String,
+    String,
+    i64,
+    Option<i64>,
+    Option<f64>,
+    Option<Uuid>,
+    i64,
+    i64,
+);
+
+impl ScyllaPriceAlertStore {
+    pub fn new(client: ScyllaClient) -> Self {
+        Self { client }
+    }
+
+    async fn write(&self, alert: &PriceAlert) -> Result<(), PersistenceError> {
+        let query = format!(
+            "INSERT INTO {}.price_alerts (
+                alert_id, session_id, customer_phone, customer_name, tier,
+                threshold, direction, status, expires_at, triggered_at,
+                triggered_price, sms_id, created_at, updated_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            self.client.keyspace()
+        );
+
+        self.client
+            .execute(
+                query,
+                (
+                    alert.alert_id,
+                    &alert.session_id,
+                    &alert.customer_phone,
+                    &alert.customer_name,
+                    &alert.tier,
+                    alert.threshold,
+                    alert.direction.as_str(),
+                    alert.status.as_str(),
+                    alert.expires_at.timestamp_millis(),
+                    alert.triggered_at.map(|t| t.timestamp_millis()),
+                    alert.triggered_price,
+                    alert.sms_id,
+                    alert.created_at.timestamp_millis(),
+                    alert.updated_at.timestamp_millis(),
+                ),
+            )
+            .await?;
+
+        // Keep the active index in step with the status
+        let index_query = if alert.status.is_terminal() {
+            format!(
+                "DELETE FROM {}.active_price_alerts WHERE queue = ? AND alert_id = ?",
+                self.client.keyspace()
+            )
+        } else {
+            format!(
+                "INSERT INTO {}.active_price_alerts (queue, alert_id) VALUES (?, ?)",
+                self.client.keyspace()
+            )
+        };
+        self.client
+            .execute(index_query, (ACTIVE_QUEUE, alert.alert_id))
+            .await?;
+
+        Ok(())
+    }
+
+    fn row_to_alert(
+        &self,
+        row: scylla::frame::response::result::Row,
+    ) -> Result<PriceAlert, PersistenceError> {
+        let (
+            alert_id,
+            session_id,
+            customer_phone,
+            customer_name,
+            tier,
+            threshold,
+            direction,
+            status,
+            expires_at,
+            triggered_at,
+            triggered_price,
+            sms_id,
+            created_at,
+            updated_at,
+        ): PriceAlertRow = row
+            .into_typed()
+            .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+
+        let timestamp =
+            |millis: i64| DateTime::from_timestamp_millis(millis).unwrap_or_else(Utc::now);
+        Ok(PriceAlert {
+            alert_id,
+            session_id,
+            customer_phone,
+            customer_name,
+            tier,
+            threshold,
+            direction: AlertDirection::parse(&direction),
+            status: PriceAlertStatus::parse(&status),
+            expires_at: timestamp(expires_at),
+            triggered_at: triggered_at.map(timestamp),
+            triggered_price,
+            sms_id,
+            created_at: timestamp(created_at),
+            updated_at: timestamp(updated_at),
+        })
+    }
+}
+
+#[async_trait]
+impl PriceAlertStore for ScyllaPriceAlertStore {
+    async fn create(&self, alert: &PriceAlert) -> Result<(), PersistenceError> {
+        self.write(alert).await?;
+
+        tracing::info!(
+            alert_id = %alert.alert_id,
+            customer_phone = %alert.customer_phone,
+            threshold = alert.threshold,
+            direction = alert.direction.as_str(),
+            "Price alert created in ScyllaDB"
+        );
+
+        Ok(())
+    }
+
+    async fn get(&self, alert_id: Uuid) -> Result<Option<PriceAlert>, PersistenceError> {
+        let query = format!(
+            "SELECT alert_id, session_id, customer_phone, customer_name, tier,
+                    threshold, direction, status, expires_at, triggered_at,
+                    triggered_price, sms_id, created_at, updated_at
+             FROM {}.price_alerts WHERE alert_id = ?",
+            self.client.keyspace()
+        );
+
+        let result = self.client.execute(query, (alert_id,)).await?;
+
+        match result.rows.and_then(|rows| rows.into_iter().next()) {
+            Some(row) => Ok(Some(self.row_to_alert(row)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn update(&self, alert: &PriceAlert) -> Result<(), PersistenceError> {
+        self.write(alert).await?;
+
+        tracing::info!(
+            alert_id = %alert.alert_id,
+            status = alert.status.as_str(),
+            "Price alert updated"
+        );
+
+        Ok(())
+    }
+
+    async fn list_active(&self) -> Result<Vec<PriceAlert>, PersistenceError> {
+        let query = format!(
+            "SELECT alert_id FROM {}.active_price_alerts WHERE queue = ?",
+            self.client.keyspace()
+        );
+
+        let result = self.client.execute(query, (ACTIVE_QUEUE,)).await?;
+
+        let mut alerts = Vec::new();
+        for row in result.rows.unwrap_or_default() {
+            let (alert_id,): (Uuid,) = row
+                .into_typed()
+                .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+            if let Some(alert) = self.get(alert_id).await? {
+                alerts.push(alert);
+            }
+        }
+        alerts.sort_by_key(|a| a.created_at);
+
+        Ok(alerts)
+    }
+}
+
+/// In-memory price alert store
+///
+/// Used when ScyllaDB is not configured; alerts do not survive restarts.
+#[derive(Default)]
+pub struct InMemoryPriceAlertStore {
+    alerts: RwLock<HashMap<Uuid, PriceAlert>>,
+}
+
+impl InMemoryPriceAlertStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl PriceAlertStore for InMemoryPriceAlertStore {
+    async fn create(&self, alert: &PriceAlert) -> Result<(), PersistenceError> {
+        self.alerts
+            .write()
+            .await
+            .insert(alert.alert_id, alert.clone());
+        Ok(())
+    }
+
+    async fn get(&self, alert_id: Uuid) -> Result<Option<PriceAlert>, PersistenceError> {
+        Ok(self.alerts.read().await.get(&alert_id).cloned())
+    }
+
+    async fn update(&self, alert: &PriceAlert) -> Result<(), PersistenceError> {
+        self.create(alert).await
+    }
+
+    async fn list_active(&self) -> Result<Vec<PriceAlert>, PersistenceError> {
+        let mut active: Vec<PriceAlert> = self
+            .alerts
+            .read()
+            .await
+            .values()
+            .filter(|a| !a.status.is_terminal())
+            .cloned()
+            .collect();
+        active.sort_by_key(|a| a.created_at);
+        Ok(active)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_crossing_and_expiry() {
+        let now = Utc::now();
+        let above = PriceAlert::new("9876543210", 7600.0, AlertDirection::Above, now);
+        assert!(!above.is_crossed(7599.0));
+        assert!(above.is_crossed(7600.0));
+        assert!(above.is_expired(now));
+
+        let below = PriceAlert::new(
+            "9876543210",
+            7000.0,
+            AlertDirection::Below,
+            now + Duration::days(1),
+        );
+        assert!(below.is_crossed(6950.0));
+        assert!(!below.is_crossed(7100.0));
+        assert!(!below.is_expired(now));
+        assert_eq!(AlertDirection::parse("below"), AlertDirection::Below);
+        assert_eq!(
+            PriceAlertStatus::parse("triggered"),
+            PriceAlertStatus::Triggered
+        );
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_list_active() {
+        let store = InMemoryPriceAlertStore::new();
+        let expires = Utc::now() + Duration::days(30);
+        let first = PriceAlert::new("1", 7600.0, AlertDirection::Above, expires);
+        let second = PriceAlert::new("2", 7000.0, AlertDirection::Below, expires);
+        store.create(&first).await.unwrap();
+        store.create(&second).await.unwrap();
+        assert_eq!(store.list_active().await.unwrap().len(), 2);
+
+        let mut triggered = first.clone();
+        triggered.status = PriceAlertStatus::Triggered;
+        store.update(&triggered).await.unwrap();
+        let active = store.list_active().await.unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].customer_phone, "2");
+    }
+}
@@ -0,0 +1,239 @@
+//! Published rate cards
+//!
+//! Rate cards published by admins, per product. A card is stored as the JSON
+//! the config crate parses (`RateCard`), alongside its version and start date
+//! so callers can order cards without parsing them. Versions are immutable:
+//! publishing an existing version is refused, and a change of rates is a new
+//! version with a later `effective_from`.
+
+use crate::slots::lwt_applied;
+use crate::{PersistenceError, ScyllaClient};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+
+/// One published rate card version
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublishedRateCard {
+    /// Product the card applies to (e.g. "gold_loan")
+    pub product: String,
+    /// Version label, unique per product
+    pub version: String,
+    /// First day the card applies
+    pub effective_from: NaiveDate,
+    /// The card itself, as JSON
+    pub card_json: String,
+    /// Who published the card
+    #[serde(default)]
+    pub published_by: Option<String>,
+    pub published_at: DateTime<Utc>,
+}
+
+impl PublishedRateCard {
+    /// Create a record stamped with the current time
+    pub fn new(
+        product: impl Into<String>,
+        version: impl Into<String>,
+        effective_from: NaiveDate,
+        card_json: impl Into<String>,
+    ) -> Self {
+        Self {
+            product: product.into(),
+            version: version.into(),
+            effective_from,
+            card_json: card_json.into(),
+            published_by: None,
+            published_at: Utc::now(),
+        }
+    }
+
+    /// Set who published the card
+    pub fn with_published_by(mut self, by: impl Into<String>) -> Self {
+        self.published_by = Some(by.into());
+        self
+    }
+
+    /// Check the record is usable before storing it
+    pub fn validate(&self) -> Result<(), PersistenceError> {
+        if self.product.trim().is_empty() || self.version.trim().is_empty() {
+            return Err(PersistenceError::InvalidData(
+                "product and version are required".to_string(),
+            ));
+        }
+        serde_json::from_str::<serde_json::Value>(&self.card_json)?;
+        Ok(())
+    }
+}
+
+/// Rate card store trait
+#[async_trait]
+pub trait RateCardStore: Send + Sync {
+    /// Published cards for a product, oldest `effective_from` first
+    async fn list(&self, product: &str) -> Result<Vec<PublishedRateCard>, PersistenceError>;
+
+    /// Store a new version; returns false if the version already exists
+    async fn publish(&self, card: &PublishedRateCard) -> Result<bool, PersistenceError>;
+}
+
+/// ScyllaDB implementation of the rate card store
+#[derive(Clone)]
+pub struct ScyllaRateCardStore {
+    client: ScyllaClient,
+}
+
+impl ScyllaRateCardStore {
+    pub fn new(client: ScyllaClient) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl RateCardStore for ScyllaRateCardStore {
+    async fn list(&self, product: &str) -> Result<Vec<PublishedRateCard>, PersistenceError> {
+        let query = format!(
+            "SELECT product, version, effective_from, card_json, published_by, published_at
+             FROM {}.rate_cards WHERE product = ?",
+            self.client.keyspace()
+        );
+        let result = self.client.execute(query, (product,)).await?;
+
+        let mut cards = Vec::new();
+        for row in result.rows.unwrap_or_default() {
+            let (product, version, effective_from, card_json, published_by, published_at): (
+                String,
+                String,
+                String,
+                String,
+                Option<String>,
+                i64,
+            ) = row
+                .into_typed()
+                .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+
+            cards.push(PublishedRateCard {
+                product,
+                version,
+                effective_from: NaiveDate::parse_from_str(&effective_from, "%Y-%m-%d")
+                    .map_err(|e| PersistenceError::InvalidData(e.to_string()))?,
+                card_json,
+                published_by,
+                published_at: DateTime::from_timestamp_millis(published_at)
+                    .unwrap_or_else(Utc::now),
+            });
+        }
+        cards.sort_by_key(|c| c.effective_from);
+
+        Ok(cards)
+    }
+
+    async fn publish(&self, card: &PublishedRateCard) -> Result<bool, PersistenceError> {
+        card.validate()?;
+
+        let query = format!(
+            "INSERT INTO {}.rate_cards (
+                product, version, effective_from, card_json, published_by, published_at
+            ) VALUES (?, ?, ?, ?, ?, ?) IF NOT EXISTS",
+            self.client.keyspace()
+        );
+
+        let result = self
+            .client
+            .execute(
+                query,
+                (
+                    &card.product,
+                    &card.version,
+                    card.effective_from.to_string(),
+                    &card.card_json,
+                    &card.published_by,
+                    card.published_at.timestamp_millis(),
+                ),
+            )
+            .await?;
+
+        let applied = lwt_applied(result.rows);
+        if applied {
+            tracing::info!(
+                product = %card.product,
+                version = %card.version,
+                effective_from = %card.effective_from,
+                "Rate card published"
+            );
+        }
+
+        Ok(applied)
+    }
+}
+
+/// In-memory rate card store
+///
+/// Used when ScyllaDB is not configured; published cards do not survive
+/// restarts.
+#[derive(Default)]
+pub struct InMemoryRateCardStore {
+    cards: RwLock<HashMap<(String, String), PublishedRateCard>>,
+}
+
+impl InMemoryRateCardStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl RateCardStore for InMemoryRateCardStore {
+    async fn list(&self, product: &str) -> Result<Vec<PublishedRateCard>, PersistenceError> {
+        let cards = self.cards.read().await;
+        let mut listed: Vec<PublishedRateCard> = cards
+            .values()
+            .filter(|card| card.product == product)
+            .cloned()
+            .collect();
+        listed.sort_by_key(|c| c.effective_from);
+        Ok(listed)
+    }
+
+    async fn publish(&self, card: &PublishedRateCard) -> Result<bool, PersistenceError> {
+        card.validate()?;
+        let mut cards = self.cards.write().await;
+        let key = (card.product.clone(), card.version.clone());
+        if cards.contains_key(&key) {
+            return Ok(false);
+        }
+        cards.insert(key, card.clone());
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_in_memory_store_publish_is_once_per_version() {
+        let store = InMemoryRateCardStore::new();
+        let date = |d: u32| NaiveDate::from_ymd_opt(2026, 7, d).unwrap();
+
+        let later = PublishedRateCard::new("gold_loan", "2026-07b", date(15), "{}");
+        let first = PublishedRateCard::new("gold_loan", "2026-07a", date(1), "{}")
+            .with_published_by("ops");
+        assert!(store.publish(&later).await.unwrap());
+        assert!(store.publish(&first).await.unwrap());
+        assert!(!store
+            .publish(&PublishedRateCard::new("gold_loan", "2026-07a", date(2), "{}"))
+            .await
+            .unwrap());
+        assert!(store
+            .publish(&PublishedRateCard::new("gold_loan", "x", date(1), "not json"))
+            .await
+            .is_err());
+
+        let cards = store.list("gold_loan").await.unwrap();
+        let versions: Vec<&str> = cards.iter().map(|c| c.version.as_str()).collect();
+        assert_eq!(versions, vec!["2026-07a", "2026-07b"]);
+        assert_eq!(cards[0].effective_from, date(1));
+        assert!(store.list("personal_loan").await.unwrap().is_empty());
+    }
+}
@@ -1,4 +1,4 @@
-//! ScyllaDB schema creation
+//! Schema creation for ScyllaDB and the SQL backends
 
 use crate::error::PersistenceError;
 use scylla::Session;
@@ -40,6 +40,8 @@ pub async fn create_tables(session: &Session, keyspace: &str) -> Result<(), Pers
             turn_count INT,
             memory_json TEXT,
             metadata_json TEXT,
+            lead_score INT,
+            lead_qualification TEXT,
             PRIMARY KEY (session_id)
         ) WITH default_time_to_live = 86400
     "#,
@@ -53,6 +55,16 @@ pub async fn create_tables(session: &Session, keyspace: &str) -> Result<(), Pers
             PersistenceError::SchemaError(format!("Failed to create sessions table: {}", e))
         })?;
 
+    // Lead score columns for sessions tables created before they existed.
+    // Fails harmlessly when the columns are already present.
+    let sessions_lead_columns = format!(
+        "ALTER TABLE {}.sessions ADD (lead_score INT, lead_qualification TEXT)",
+        keyspace
+    );
+    if let Err(e) = session.query_unpaged(sessions_lead_columns, &[]).await {
+        tracing::debug!(error = %e, "Sessions lead score columns not added (likely already present)");
+    }
+
     // SMS messages table (for simulation audit trail)
     let sms_table = format!(
         r#"
@@ -128,6 +140,54 @@ pub async fn create_tables(session: &Session, keyspace: &str) -> Result<(), Pers
             ))
         })?;
 
+    // Asset price history, one row per hour, with tier prices as JSON
+    let asset_prices_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.asset_prices (
+            date TEXT,
+            hour INT,
+            base_price DOUBLE,
+            tier_prices_json TEXT,
+            source TEXT,
+            created_at BIGINT,
+            PRIMARY KEY ((date), hour)
+        ) WITH CLUSTERING ORDER BY (hour DESC)
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(asset_prices_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!("Failed to create asset_prices table: {}", e))
+        })?;
+
+    // Latest asset price (single row)
+    let asset_latest_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.asset_price_latest (
+            singleton INT,
+            base_price DOUBLE,
+            tier_prices_json TEXT,
+            updated_at BIGINT,
+            source TEXT,
+            PRIMARY KEY (singleton)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(asset_latest_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!(
+                "Failed to create asset_price_latest table: {}",
+                e
+            ))
+        })?;
+
     // Appointments table
     let appointments_table = format!(
         r#"
@@ -159,6 +219,306 @@ pub async fn create_tables(session: &Session, keyspace: &str) -> Result<(), Pers
             PersistenceError::SchemaError(format!("Failed to create appointments table: {}", e))
         })?;
 
+    // Branch slot capacity calendar (capacity NULL = configured default)
+    let slot_capacity_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.slot_capacity (
+            branch_id TEXT,
+            slot_date TEXT,
+            slot_time TEXT,
+            capacity INT,
+            booked INT,
+            PRIMARY KEY ((branch_id, slot_date), slot_time)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(slot_capacity_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!("Failed to create slot_capacity table: {}", e))
+        })?;
+
+    // Slot holds by slot (rows written with a TTL so abandoned holds expire)
+    let slot_holds_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.slot_holds (
+            branch_id TEXT,
+            slot_date TEXT,
+            slot_time TEXT,
+            hold_id UUID,
+            session_id TEXT,
+            expires_at BIGINT,
+            PRIMARY KEY ((branch_id, slot_date), slot_time, hold_id)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(slot_holds_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!("Failed to create slot_holds table: {}", e))
+        })?;
+
+    // Slot holds by ID (lookup on confirm/release)
+    let slot_holds_by_id_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.slot_holds_by_id (
+            hold_id UUID,
+            branch_id TEXT,
+            slot_date TEXT,
+            slot_time TEXT,
+            session_id TEXT,
+            expires_at BIGINT,
+            PRIMARY KEY (hold_id)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(slot_holds_by_id_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!("Failed to create slot_holds_by_id table: {}", e))
+        })?;
+
+    // CRM lead delivery tracking (idempotency + retry status)
+    let crm_deliveries_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.crm_deliveries (
+            idempotency_key TEXT,
+            connector TEXT,
+            customer_phone TEXT,
+            payload_json TEXT,
+            status TEXT,
+            attempts INT,
+            last_error TEXT,
+            external_id TEXT,
+            created_at TIMESTAMP,
+            updated_at TIMESTAMP,
+            PRIMARY KEY (idempotency_key)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(crm_deliveries_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!("Failed to create crm_deliveries table: {}", e))
+        })?;
+
+    // Callback requests (time window, agent assignment, dialer status)
+    let callbacks_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.callbacks (
+            callback_id UUID,
+            session_id TEXT,
+            customer_phone TEXT,
+            customer_name TEXT,
+            window_start BIGINT,
+            window_end BIGINT,
+            requested_time TEXT,
+            assigned_agent TEXT,
+            status TEXT,
+            attempts INT,
+            next_attempt_at BIGINT,
+            dialer_call_id TEXT,
+            notes TEXT,
+            created_at BIGINT,
+            updated_at BIGINT,
+            PRIMARY KEY (callback_id)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(callbacks_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!("Failed to create callbacks table: {}", e))
+        })?;
+
+    // Index of callbacks still open, scanned by the dialer
+    let open_callbacks_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.open_callbacks (
+            queue TEXT,
+            callback_id UUID,
+            PRIMARY KEY ((queue), callback_id)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(open_callbacks_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!("Failed to create open_callbacks table: {}", e))
+        })?;
+
+    // Outbox of tool side-effects awaiting delivery (SMS, CRM leads, appointments)
+    let outbox_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.outbox (
+            idempotency_key TEXT,
+            kind TEXT,
+            session_id TEXT,
+            payload_json TEXT,
+            status TEXT,
+            attempts INT,
+            next_attempt_at BIGINT,
+            last_error TEXT,
+            external_id TEXT,
+            created_at BIGINT,
+            updated_at BIGINT,
+            PRIMARY KEY (idempotency_key)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(outbox_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!("Failed to create outbox table: {}", e))
+        })?;
+
+    // Index of outbox entries not yet delivered, scanned by the dispatcher
+    let pending_outbox_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.pending_outbox (
+            queue TEXT,
+            idempotency_key TEXT,
+            PRIMARY KEY ((queue), idempotency_key)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(pending_outbox_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!("Failed to create pending_outbox table: {}", e))
+        })?;
+
+    // Write-ahead journal of conversation turns, replayed after a crash.
+    // Expires with the sessions it belongs to.
+    let session_journal_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.session_journal (
+            session_id TEXT,
+            seq BIGINT,
+            kind TEXT,
+            payload_json TEXT,
+            recorded_at BIGINT,
+            PRIMARY KEY ((session_id), seq)
+        ) WITH CLUSTERING ORDER BY (seq ASC)
+        AND default_time_to_live = 86400
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(session_journal_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!("Failed to create session_journal table: {}", e))
+        })?;
+
+    // Admin-maintained competitor rates (overrides competitors.yaml)
+    let competitor_rates_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.competitor_rates (
+            product TEXT,
+            lender_id TEXT,
+            lender_name TEXT,
+            rate_min DOUBLE,
+            rate_max DOUBLE,
+            typical_rate DOUBLE,
+            processing_fee_percent DOUBLE,
+            source TEXT,
+            updated_at BIGINT,
+            PRIMARY KEY ((product), lender_id)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(competitor_rates_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!("Failed to create competitor_rates table: {}", e))
+        })?;
+
+    // Customer identities (cross-channel, keyed by normalized phone)
+    let customer_identities_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.customer_identities (
+            customer_id TEXT,
+            name TEXT,
+            preferred_language TEXT,
+            segment TEXT,
+            facts_json TEXT,
+            last_goal TEXT,
+            last_channel TEXT,
+            session_ids_json TEXT,
+            first_seen TIMESTAMP,
+            last_seen TIMESTAMP,
+            PRIMARY KEY (customer_id)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(customer_identities_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!(
+                "Failed to create customer_identities table: {}",
+                e
+            ))
+        })?;
+
+    // Archival memory notes with embeddings (Qdrant-less vector fallback),
+    // one partition per session so search scans a bounded set of notes
+    let archival_memories_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.archival_memories (
+            collection TEXT,
+            session_id TEXT,
+            id TEXT,
+            note_json TEXT,
+            embedding LIST<FLOAT>,
+            created_at TIMESTAMP,
+            PRIMARY KEY ((collection, session_id), id)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(archival_memories_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!(
+                "Failed to create archival_memories table: {}",
+                e
+            ))
+        })?;
+
     // P0 FIX: Audit log table for RBI compliance
     // Required for regulatory auditing of all financial conversations
     // 7 year retention as per RBI guidelines (220752000 seconds)
@@ -193,6 +553,802 @@ pub async fn create_tables(session: &Session, keyspace: &str) -> Result<(), Pers
             PersistenceError::SchemaError(format!("Failed to create audit_log table: {}", e))
         })?;
 
+    // Per-session outcomes, aggregated by the analytics rollup job
+    let session_outcomes_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.session_outcomes (
+            day TEXT,
+            session_id TEXT,
+            started_at BIGINT,
+            duration_secs BIGINT,
+            language TEXT,
+            stages_json TEXT,
+            final_stage TEXT,
+            goal TEXT,
+            goal_reached BOOLEAN,
+            tools_json TEXT,
+            interruptions INT,
+            turns INT,
+            PRIMARY KEY ((day), session_id)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(session_outcomes_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!("Failed to create session_outcomes table: {}", e))
+        })?;
+
+    // Daily analytics rollups, one row per language plus "all"
+    let analytics_daily_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.analytics_daily (
+            day TEXT,
+            language TEXT,
+            sessions BIGINT,
+            goals_reached BIGINT,
+            total_duration_secs BIGINT,
+            interruptions BIGINT,
+            stage_reached_json TEXT,
+            drop_off_json TEXT,
+            tool_sessions_json TEXT,
+            tool_calls_json TEXT,
+            updated_at BIGINT,
+            PRIMARY KEY ((day), language)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(analytics_daily_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!("Failed to create analytics_daily table: {}", e))
+        })?;
+
+    // Per-turn usage, by session and by day (for the usage rollup job)
+    for (table, key) in [
+        ("turn_usage", "(session_id), turn"),
+        ("turn_usage_by_day", "(day), session_id, turn"),
+    ] {
+        let turn_usage_table = format!(
+            r#"
+            CREATE TABLE IF NOT EXISTS {}.{} (
+                day TEXT,
+                session_id TEXT,
+                turn INT,
+                tenant TEXT,
+                recorded_at BIGINT,
+                llm_prompt_tokens BIGINT,
+                llm_completion_tokens BIGINT,
+                stt_seconds DOUBLE,
+                tts_characters BIGINT,
+                tool_calls BIGINT,
+                translation_calls BIGINT,
+                PRIMARY KEY ({})
+            )
+        "#,
+            keyspace, table, key
+        );
+
+        session
+            .query_unpaged(turn_usage_table, &[])
+            .await
+            .map_err(|e| {
+                PersistenceError::SchemaError(format!("Failed to create {} table: {}", table, e))
+            })?;
+    }
+
+    // Daily usage rollups, one row per tenant plus "all"
+    let usage_daily_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.usage_daily (
+            day TEXT,
+            tenant TEXT,
+            sessions BIGINT,
+            turns BIGINT,
+            llm_prompt_tokens BIGINT,
+            llm_completion_tokens BIGINT,
+            stt_seconds DOUBLE,
+            tts_characters BIGINT,
+            tool_calls BIGINT,
+            translation_calls BIGINT,
+            updated_at BIGINT,
+            PRIMARY KEY ((day), tenant)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(usage_daily_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!("Failed to create usage_daily table: {}", e))
+        })?;
+
+    // Webhook delivery log, by day
+    let webhook_deliveries_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.webhook_deliveries (
+            day TEXT,
+            delivery_id UUID,
+            endpoint TEXT,
+            event_type TEXT,
+            event_id TEXT,
+            session_id TEXT,
+            payload_json TEXT,
+            status TEXT,
+            attempts INT,
+            response_status INT,
+            last_error TEXT,
+            created_at BIGINT,
+            updated_at BIGINT,
+            PRIMARY KEY ((day), delivery_id)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(webhook_deliveries_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!(
+                "Failed to create webhook_deliveries table: {}",
+                e
+            ))
+        })?;
+
+    // Customer price alert subscriptions
+    let price_alerts_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.price_alerts (
+            alert_id UUID,
+            session_id TEXT,
+            customer_phone TEXT,
+            customer_name TEXT,
+            tier TEXT,
+            threshold DOUBLE,
+            direction TEXT,
+            status TEXT,
+            expires_at BIGINT,
+            triggered_at BIGINT,
+            triggered_price DOUBLE,
+            sms_id UUID,
+            created_at BIGINT,
+            updated_at BIGINT,
+            PRIMARY KEY (alert_id)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(price_alerts_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!("Failed to create price_alerts table: {}", e))
+        })?;
+
+    // Index of alerts still active, scanned by the price alert monitor
+    let active_price_alerts_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.active_price_alerts (
+            queue TEXT,
+            alert_id UUID,
+            PRIMARY KEY ((queue), alert_id)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(active_price_alerts_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!(
+                "Failed to create active_price_alerts table: {}",
+                e
+            ))
+        })?;
+
+    // Published rate cards (immutable versions per product)
+    let rate_cards_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.rate_cards (
+            product TEXT,
+            version TEXT,
+            effective_from TEXT,
+            card_json TEXT,
+            published_by TEXT,
+            published_at BIGINT,
+            PRIMARY KEY ((product), version)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(rate_cards_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!("Failed to create rate_cards table: {}", e))
+        })?;
+
+    // Offer events per session (one per session, offer and kind)
+    let offer_events_by_session_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.offer_events_by_session (
+            session_id TEXT,
+            offer_id TEXT,
+            kind TEXT,
+            day TEXT,
+            created_at BIGINT,
+            PRIMARY KEY ((session_id), offer_id, kind)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(offer_events_by_session_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!(
+                "Failed to create offer_events_by_session table: {}",
+                e
+            ))
+        })?;
+
+    // Offer events by day, for campaign reports
+    let offer_events_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.offer_events (
+            day TEXT,
+            offer_id TEXT,
+            session_id TEXT,
+            kind TEXT,
+            created_at BIGINT,
+            PRIMARY KEY ((day), offer_id, session_id, kind)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(offer_events_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!("Failed to create offer_events table: {}", e))
+        })?;
+
     tracing::info!("All tables created successfully");
     Ok(())
 }
+
+/// SQL dialect of a relational backend
+///
+/// The SQL stores keep the ScyllaDB column layout, with timestamps as epoch
+/// milliseconds and UUIDs as text, so both backends hold the same data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqlDialect {
+    Sqlite,
+    Postgres,
+}
+
+impl SqlDialect {
+    /// Dialect of a connection URL
+    pub fn from_url(url: &str) -> Option<Self> {
+        if url.starts_with("sqlite:") {
+            Some(Self::Sqlite)
+        } else if url.starts_with("postgres://") || url.starts_with("postgresql://") {
+            Some(Self::Postgres)
+        } else {
+            None
+        }
+    }
+
+    fn type_name(&self, ty: SqlType) -> &'static str {
+        match (self, ty) {
+            (_, SqlType::Text) => "TEXT",
+            (_, SqlType::BigInt) => "BIGINT",
+            (_, SqlType::Boolean) => "BOOLEAN",
+            (Self::Sqlite, SqlType::Double) => "REAL",
+            (Self::Postgres, SqlType::Double) => "DOUBLE PRECISION",
+            (Self::Sqlite, SqlType::Bytes) => "BLOB",
+            (Self::Postgres, SqlType::Bytes) => "BYTEA",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum SqlType {
+    Text,
+    BigInt,
+    Double,
+    Boolean,
+    Bytes,
+}
+
+struct SqlTable {
+    name: &'static str,
+    columns: &'static [(&'static str, SqlType)],
+    primary_key: &'static [&'static str],
+    /// Secondary indexes, by column list
+    indexes: &'static [&'static [&'static str]],
+}
+
+use SqlType::{BigInt, Boolean, Bytes, Double, Text};
+
+const SQL_TABLES: &[SqlTable] = &[
+    SqlTable {
+        name: "sessions",
+        columns: &[
+            ("session_id", Text),
+            ("created_at", BigInt),
+            ("updated_at", BigInt),
+            ("expires_at", BigInt),
+            ("customer_phone", Text),
+            ("customer_name", Text),
+            ("customer_segment", Text),
+            ("language", Text),
+            ("conversation_stage", Text),
+            ("turn_count", BigInt),
+            ("memory_json", Text),
+            ("metadata_json", Text),
+            ("lead_score", BigInt),
+            ("lead_qualification", Text),
+        ],
+        primary_key: &["session_id"],
+        indexes: &[&["expires_at"]],
+    },
+    SqlTable {
+        name: "sms_messages",
+        columns: &[
+            ("phone_number", Text),
+            ("message_id", Text),
+            ("session_id", Text),
+            ("message_text", Text),
+            ("message_type", Text),
+            ("status", Text),
+            ("created_at", BigInt),
+            ("sent_at", BigInt),
+            ("metadata_json", Text),
+        ],
+        primary_key: &["phone_number", "message_id"],
+        indexes: &[],
+    },
+    SqlTable {
+        name: "asset_prices",
+        columns: &[
+            ("date", Text),
+            ("hour", BigInt),
+            ("base_price", Double),
+            ("tier_prices_json", Text),
+            ("source", Text),
+            ("created_at", BigInt),
+        ],
+        primary_key: &["date", "hour"],
+        indexes: &[],
+    },
+    SqlTable {
+        name: "asset_price_latest",
+        columns: &[
+            ("singleton", BigInt),
+            ("base_price", Double),
+            ("tier_prices_json", Text),
+            ("updated_at", BigInt),
+            ("source", Text),
+        ],
+        primary_key: &["singleton"],
+        indexes: &[],
+    },
+    SqlTable {
+        name: "appointments",
+        columns: &[
+            ("customer_phone", Text),
+            ("appointment_id", Text),
+            ("session_id", Text),
+            ("customer_name", Text),
+            ("branch_id", Text),
+            ("branch_name", Text),
+            ("branch_address", Text),
+            ("appointment_date", Text),
+            ("appointment_time", Text),
+            ("status", Text),
+            ("created_at", BigInt),
+            ("updated_at", BigInt),
+            ("confirmation_sms_id", Text),
+            ("notes", Text),
+        ],
+        primary_key: &["customer_phone", "appointment_id"],
+        indexes: &[&["appointment_date"]],
+    },
+    SqlTable {
+        name: "slot_capacity",
+        columns: &[
+            ("branch_id", Text),
+            ("slot_date", Text),
+            ("slot_time", Text),
+            ("capacity", BigInt),
+            ("booked", BigInt),
+        ],
+        primary_key: &["branch_id", "slot_date", "slot_time"],
+        indexes: &[],
+    },
+    SqlTable {
+        name: "slot_holds",
+        columns: &[
+            ("hold_id", Text),
+            ("branch_id", Text),
+            ("slot_date", Text),
+            ("slot_time", Text),
+            ("session_id", Text),
+            ("expires_at", BigInt),
+        ],
+        primary_key: &["hold_id"],
+        indexes: &[&["branch_id", "slot_date"]],
+    },
+    SqlTable {
+        name: "crm_deliveries",
+        columns: &[
+            ("idempotency_key", Text),
+            ("connector", Text),
+            ("customer_phone", Text),
+            ("payload_json", Text),
+            ("status", Text),
+            ("attempts", BigInt),
+            ("last_error", Text),
+            ("external_id", Text),
+            ("created_at", BigInt),
+            ("updated_at", BigInt),
+        ],
+        primary_key: &["idempotency_key"],
+        indexes: &[],
+    },
+    SqlTable {
+        name: "callbacks",
+        columns: &[
+            ("callback_id", Text),
+            ("session_id", Text),
+            ("customer_phone", Text),
+            ("customer_name", Text),
+            ("window_start", BigInt),
+            ("window_end", BigInt),
+            ("requested_time", Text),
+            ("assigned_agent", Text),
+            ("status", Text),
+            ("attempts", BigInt),
+            ("next_attempt_at", BigInt),
+            ("dialer_call_id", Text),
+            ("notes", Text),
+            ("created_at", BigInt),
+            ("updated_at", BigInt),
+        ],
+        primary_key: &["callback_id"],
+        indexes: &[&["status"]],
+    },
+    SqlTable {
+        name: "outbox",
+        columns: &[
+            ("idempotency_key", Text),
+            ("kind", Text),
+            ("session_id", Text),
+            ("payload_json", Text),
+            ("status", Text),
+            ("attempts", BigInt),
+            ("next_attempt_at", BigInt),
+            ("last_error", Text),
+            ("external_id", Text),
+            ("created_at", BigInt),
+            ("updated_at", BigInt),
+        ],
+        primary_key: &["idempotency_key"],
+        indexes: &[&["status", "next_attempt_at"]],
+    },
+    SqlTable {
+        name: "session_journal",
+        columns: &[
+            ("session_id", Text),
+            ("seq", BigInt),
+            ("kind", Text),
+            ("payload_json", Text),
+            ("recorded_at", BigInt),
+        ],
+        primary_key: &["session_id", "seq"],
+        indexes: &[],
+    },
+    SqlTable {
+        name: "competitor_rates",
+        columns: &[
+            ("product", Text),
+            ("lender_id", Text),
+            ("lender_name", Text),
+            ("rate_min", Double),
+            ("rate_max", Double),
+            ("typical_rate", Double),
+            ("processing_fee_percent", Double),
+            ("source", Text),
+            ("updated_at", BigInt),
+        ],
+        primary_key: &["product", "lender_id"],
+        indexes: &[],
+    },
+    SqlTable {
+        name: "customer_identities",
+        columns: &[
+            ("customer_id", Text),
+            ("name", Text),
+            ("preferred_language", Text),
+            ("segment", Text),
+            ("facts_json", Text),
+            ("last_goal", Text),
+            ("last_channel", Text),
+            ("session_ids_json", Text),
+            ("first_seen", BigInt),
+            ("last_seen", BigInt),
+        ],
+        primary_key: &["customer_id"],
+        indexes: &[],
+    },
+    SqlTable {
+        name: "archival_memories",
+        columns: &[
+            ("collection", Text),
+            ("session_id", Text),
+            ("id", Text),
+            ("note_json", Text),
+            ("embedding", Bytes),
+            ("created_at", BigInt),
+        ],
+        primary_key: &["collection", "session_id", "id"],
+        indexes: &[],
+    },
+    SqlTable {
+        name: "audit_log",
+        columns: &[
+            ("id", Text),
+            ("partition_date", Text),
+            ("session_id", Text),
+            ("timestamp", BigInt),
+            ("event_type", Text),
+            ("actor_type", Text),
+            ("actor_id", Text),
+            ("resource_type", Text),
+            ("resource_id", Text),
+            ("action", Text),
+            ("outcome", Text),
+            ("details", Text),
+            ("previous_hash", Text),
+            ("hash", Text),
+        ],
+        primary_key: &["id"],
+        indexes: &[&["session_id", "timestamp"]],
+    },
+    SqlTable {
+        name: "session_outcomes",
+        columns: &[
+            ("day", Text),
+            ("session_id", Text),
+            ("started_at", BigInt),
+            ("duration_secs", BigInt),
+            ("language", Text),
+            ("stages_json", Text),
+            ("final_stage", Text),
+            ("goal", Text),
+            ("goal_reached", Boolean),
+            ("tools_json", Text),
+            ("interruptions", BigInt),
+            ("turns", BigInt),
+        ],
+        primary_key: &["day", "session_id"],
+        indexes: &[],
+    },
+    SqlTable {
+        name: "analytics_daily",
+        columns: &[
+            ("day", Text),
+            ("language", Text),
+            ("sessions", BigInt),
+            ("goals_reached", BigInt),
+            ("total_duration_secs", BigInt),
+            ("interruptions", BigInt),
+            ("stage_reached_json", Text),
+            ("drop_off_json", Text),
+            ("tool_sessions_json", Text),
+            ("tool_calls_json", Text),
+            ("updated_at", BigInt),
+        ],
+        primary_key: &["day", "language"],
+        indexes: &[],
+    },
+    SqlTable {
+        name: "turn_usage",
+        columns: &[
+            ("day", Text),
+            ("session_id", Text),
+            ("turn", BigInt),
+            ("tenant", Text),
+            ("recorded_at", BigInt),
+            ("llm_prompt_tokens", BigInt),
+            ("llm_completion_tokens", BigInt),
+            ("stt_seconds", Double),
+            ("tts_characters", BigInt),
+            ("tool_calls", BigInt),
+            ("translation_calls", BigInt),
+        ],
+        primary_key: &["session_id", "turn"],
+        indexes: &[&["day"]],
+    },
+    SqlTable {
+        name: "usage_daily",
+        columns: &[
+            ("day", Text),
+            ("tenant", Text),
+            ("sessions", BigInt),
+            ("turns", BigInt),
+            ("llm_prompt_tokens", BigInt),
+            ("llm_completion_tokens", BigInt),
+            ("stt_seconds", Double),
+            ("tts_characters", BigInt),
+            ("tool_calls", BigInt),
+            ("translation_calls", BigInt),
+            ("updated_at", BigInt),
+        ],
+        primary_key: &["day", "tenant"],
+        indexes: &[],
+    },
+    SqlTable {
+        name: "webhook_deliveries",
+        columns: &[
+            ("delivery_id", Text),
+            ("day", Text),
+            ("endpoint", Text),
+            ("event_type", Text),
+            ("event_id", Text),
+            ("session_id", Text),
+            ("payload_json", Text),
+            ("status", Text),
+            ("attempts", BigInt),
+            ("response_status", BigInt),
+            ("last_error", Text),
+            ("created_at", BigInt),
+            ("updated_at", BigInt),
+        ],
+        primary_key: &["delivery_id"],
+        indexes: &[&["day"]],
+    },
+    SqlTable {
+        name: "price_alerts",
+        columns: &[
+            ("alert_id", Text),
+            ("session_id", Text),
+            ("customer_phone", Text),
+            ("customer_name", Text),
+            ("tier", Text),
+            ("threshold", Double),
+            ("direction", Text),
+            ("status", Text),
+            ("expires_at", BigInt),
+            ("triggered_at", BigInt),
+            ("triggered_price", Double),
+            ("sms_id", Text),
+            ("created_at", BigInt),
+            ("updated_at", BigInt),
+        ],
+        primary_key: &["alert_id"],
+        indexes: &[&["status"]],
+    },
+    SqlTable {
+        name: "rate_cards",
+        columns: &[
+            ("product", Text),
+            ("version", Text),
+            ("effective_from", Text),
+            ("card_json", Text),
+            ("published_by", Text),
+            ("published_at", BigInt),
+        ],
+        primary_key: &["product", "version"],
+        indexes: &[],
+    },
+    SqlTable {
+        name: "offer_events",
+        columns: &[
+            ("session_id", Text),
+            ("offer_id", Text),
+            ("kind", Text),
+            ("day", Text),
+            ("created_at", BigInt),
+        ],
+        primary_key: &["session_id", "offer_id", "kind"],
+        indexes: &[&["day"]],
+    },
+];
+
+/// DDL for every SQL table and index, in creation order
+///
+/// Statements are idempotent (`IF NOT EXISTS`) and run on every startup.
+pub fn sql_tables(dialect: SqlDialect) -> Vec<String> {
+    let mut statements = Vec::new();
+    for table in SQL_TABLES {
+        let mut columns: Vec<String> = table
+            .columns
+            .iter()
+            .map(|(name, ty)| {
+                let not_null = if table.primary_key.contains(name) {
+                    " NOT NULL"
+                } else {
+                    ""
+                };
+                format!("{} {}{}", name, dialect.type_name(*ty), not_null)
+            })
+            .collect();
+        columns.push(format!("PRIMARY KEY ({})", table.primary_key.join(", ")));
+
+        statements.push(format!(
+            "CREATE TABLE IF NOT EXISTS {} ({})",
+            table.name,
+            columns.join(", ")
+        ));
+        for index in table.indexes {
+            statements.push(format!(
+                "CREATE INDEX IF NOT EXISTS idx_{}_{} ON {} ({})",
+                table.name,
+                index.join("_"),
+                table.name,
+                index.join(", ")
+            ));
+        }
+    }
+    statements
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dialect_from_url() {
+        assert_eq!(
+            SqlDialect::from_url("sqlite://voice_agent.db?mode=rwc"),
+            Some(SqlDialect::Sqlite)
+        );
+        assert_eq!(
+            SqlDialect::from_url("sqlite::memory:"),
+            Some(SqlDialect::Sqlite)
+        );
+        assert_eq!(
+            SqlDialect::from_url("postgresql://localhost/voice_agent"),
+            Some(SqlDialect::Postgres)
+        );
+        assert_eq!(SqlDialect::from_url("mysql://localhost/voice_agent"), None);
+    }
+
+    #[test]
+    fn test_sql_tables_per_dialect() {
+        let sqlite = sql_tables(SqlDialect::Sqlite);
+        let postgres = sql_tables(SqlDialect::Postgres);
+        assert_eq!(sqlite.len(), postgres.len());
+
+        let rates = |ddl: &[String]| {
+            ddl.iter()
+                .find(|s| s.contains("TABLE IF NOT EXISTS competitor_rates "))
+                .cloned()
+                .unwrap()
+        };
+        assert!(rates(&sqlite).contains("rate_min REAL"));
+        assert!(rates(&postgres).contains("rate_min DOUBLE PRECISION"));
+        assert!(rates(&postgres).contains("product TEXT NOT NULL"));
+        assert!(rates(&postgres).ends_with("PRIMARY KEY (product, lender_id))"));
+
+        let holds_index = "CREATE INDEX IF NOT EXISTS idx_slot_holds_branch_id_slot_date \
+                           ON slot_holds (branch_id, slot_date)";
+        assert!(sqlite.iter().any(|s| s == holds_index));
+    }
+}
@@ -20,6 +20,12 @@ pub struct SessionData {
     pub turn_count: i32,
     pub memory_json: Option<String>,
     pub metadata_json: Option<String>,
+    /// Latest lead score (0-100)
+    #[serde(default)]
+    pub lead_score: Option<i32>,
+    /// Latest lead qualification (Cold/Warm/Hot/Qualified)
+    #[serde(default)]
+    pub lead_qualification: Option<String>,
 }
 
 impl SessionData {
@@ -38,6 +44,8 @@ impl SessionData {
             turn_count: 0,
             memory_json: None,
             metadata_json: None,
+            lead_score: None,
+            lead_qualification: None,
         }
     }
 }
@@ -51,6 +59,8 @@ pub trait SessionStore: Send + Sync {
     async fn delete(&self, session_id: &str) -> Result<(), PersistenceError>;
     async fn touch(&self, session_id: &str) -> Result<(), PersistenceError>;
     async fn list_active(&self, limit: i32) -> Result<Vec<SessionData>, PersistenceError>;
+    /// Delete sessions past their expiry, returning how many were removed
+    async fn purge_expired(&self) -> Result<u64, PersistenceError>;
 }
 
 /// ScyllaDB implementation of session store
@@ -73,14 +83,13 @@ impl SessionStore for ScyllaSessionStore {
                 session_id, created_at, updated_at, expires_at,
                 customer_phone, customer_name, customer_segment,
                 language, conversation_stage, turn_count,
-                memory_json, metadata_json
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                memory_json, metadata_json, lead_score, lead_qualification
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             self.client.keyspace()
         );
 
         self.client
-            .session()
-            .query_unpaged(
+            .execute(
                 query,
                 (
                     &session.session_id,
@@ -95,6 +104,8 @@ impl SessionStore for ScyllaSessionStore {
                     session.turn_count,
                     &session.memory_json,
                     &session.metadata_json,
+                    session.lead_score,
+                    &session.lead_qualification,
                 ),
             )
             .await?;
@@ -108,16 +119,12 @@ impl SessionStore for ScyllaSessionStore {
             "SELECT session_id, created_at, updated_at, expires_at,
                     customer_phone, customer_name, customer_segment,
                     language, conversation_stage, turn_count,
-                    memory_json, metadata_json
+                    memory_json, metadata_json, lead_score, lead_qualification
              FROM {}.sessions WHERE session_id = ?",
             self.client.keyspace()
         );
 
-        let result = self
-            .client
-            .session()
-            .query_unpaged(query, (session_id,))
-            .await?;
+        let result = self.client.execute(query, (session_id,)).await?;
 
         if let Some(rows) = result.rows {
             if let Some(row) = rows.into_iter().next() {
@@ -134,6 +141,8 @@ impl SessionStore for ScyllaSessionStore {
                     turn_count,
                     memory_json,
                     metadata_json,
+                    lead_score,
+                    lead_qualification,
                 ): (
                     String,
                     i64,
@@ -147,6 +156,8 @@ impl SessionStore for ScyllaSessionStore {
                     i32,
                     Option<String>,
                     Option<String>,
+                    Option<i32>,
+                    Option<String>,
                 ) = row
                     .into_typed()
                     .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
@@ -167,6 +178,8 @@ impl SessionStore for ScyllaSessionStore {
                     turn_count,
                     memory_json,
                     metadata_json,
+                    lead_score,
+                    lead_qualification,
                 }));
             }
         }
@@ -185,14 +198,15 @@ impl SessionStore for ScyllaSessionStore {
                 conversation_stage = ?,
                 turn_count = ?,
                 memory_json = ?,
-                metadata_json = ?
+                metadata_json = ?,
+                lead_score = ?,
+                lead_qualification = ?
              WHERE session_id = ?",
             self.client.keyspace()
         );
 
         self.client
-            .session()
-            .query_unpaged(
+            .execute(
                 query,
                 (
                     Utc::now().timestamp_millis(),
@@ -204,6 +218,8 @@ impl SessionStore for ScyllaSessionStore {
                     session.turn_count,
                     &session.memory_json,
                     &session.metadata_json,
+                    session.lead_score,
+                    &session.lead_qualification,
                     &session.session_id,
                 ),
             )
@@ -219,10 +235,7 @@ impl SessionStore for ScyllaSessionStore {
             self.client.keyspace()
         );
 
-        self.client
-            .session()
-            .query_unpaged(query, (session_id,))
-            .await?;
+        self.client.execute(query, (session_id,)).await?;
         tracing::debug!(session_id = %session_id, "Session deleted from ScyllaDB");
         Ok(())
     }
@@ -237,8 +250,7 @@ impl SessionStore for ScyllaSessionStore {
         let expires = now + Duration::hours(24);
 
         self.client
-            .session()
-            .query_unpaged(
+            .execute(
                 query,
                 (
                     now.timestamp_millis(),
@@ -257,12 +269,12 @@ impl SessionStore for ScyllaSessionStore {
             "SELECT session_id, created_at, updated_at, expires_at,
                     customer_phone, customer_name, customer_segment,
                     language, conversation_stage, turn_count,
-                    memory_json, metadata_json
+                    memory_json, metadata_json, lead_score, lead_qualification
              FROM {}.sessions LIMIT ?",
             self.client.keyspace()
         );
 
-        let result = self.client.session().query_unpaged(query, (limit,)).await?;
+        let result = self.client.execute(query, (limit,)).await?;
 
         let mut sessions = Vec::new();
         if let Some(rows) = result.rows {
@@ -280,6 +292,8 @@ impl SessionStore for ScyllaSessionStore {
                     turn_count,
                     memory_json,
                     metadata_json,
+                    lead_score,
+                    lead_qualification,
                 ): (
                     String,
                     i64,
@@ -293,6 +307,8 @@ impl SessionStore for ScyllaSessionStore {
                     i32,
                     Option<String>,
                     Option<String>,
+                    Option<i32>,
+                    Option<String>,
                 ) = row
                     .into_typed()
                     .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
@@ -313,12 +329,19 @@ impl SessionStore for ScyllaSessionStore {
                     turn_count,
                     memory_json,
                     metadata_json,
+                    lead_score,
+                    lead_qualification,
                 });
             }
         }
 
         Ok(sessions)
     }
+
+    async fn purge_expired(&self) -> Result<u64, PersistenceError> {
+        // The table TTL already drops expired rows
+        Ok(0)
+    }
 }
 
 #[cfg(test)]
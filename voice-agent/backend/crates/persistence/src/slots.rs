@@ -0,0 +1,794 @@
+//! Branch visit slot capacity using ScyllaDB
+//!
+//! Each branch has a capacity calendar keyed by date and time slot. A slot
+//! offered during a call is held for the session until the hold expires;
+//! confirming the hold books the visit and uses up one unit of capacity.
+//! Holds count against availability so two callers are never offered the
+//! last place in the same slot.
+
+use crate::{PersistenceError, ScyllaClient};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use scylla::frame::response::result::{CqlValue, Row};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Compare-and-set attempts before a booking or capacity change gives up
+/// under contention
+const MAX_BOOKING_ATTEMPTS: usize = 5;
+
+/// Capacity and usage of one branch time slot
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SlotAvailability {
+    pub branch_id: String,
+    pub date: NaiveDate,
+    pub time: String,
+    pub capacity: u32,
+    pub booked: u32,
+    /// Unexpired holds from ongoing conversations
+    pub held: u32,
+}
+
+impl SlotAvailability {
+    /// Places still open to a new caller
+    pub fn remaining(&self) -> u32 {
+        self.capacity.saturating_sub(self.booked + self.held)
+    }
+}
+
+/// A slot reserved for a session while the booking is completed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SlotHold {
+    pub hold_id: Uuid,
+    pub branch_id: String,
+    pub date: NaiveDate,
+    pub time: String,
+    pub session_id: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl SlotHold {
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now()
+    }
+}
+
+/// Result of confirming a hold
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SlotConfirmation {
+    /// Visit booked; capacity decremented
+    Confirmed(SlotHold),
+    /// Hold unknown or expired
+    Expired,
+    /// Slot filled up before the hold was confirmed
+    Full,
+}
+
+/// Slot capacity store trait
+#[async_trait]
+pub trait SlotStore: Send + Sync {
+    /// Availability of the given times at a branch on a date
+    ///
+    /// `default_capacity` applies to slots without a stored capacity override.
+    async fn availability(
+        &self,
+        branch_id: &str,
+        date: NaiveDate,
+        times: &[String],
+        default_capacity: u32,
+    ) -> Result<Vec<SlotAvailability>, PersistenceError>;
+
+    /// Override the capacity of one slot (extra staff, half days, holidays)
+    async fn set_capacity(
+        &self,
+        branch_id: &str,
+        date: NaiveDate,
+        time: &str,
+        capacity: u32,
+    ) -> Result<(), PersistenceError>;
+
+    /// Hold a slot for a session; `Ok(None)` if the slot has no room left
+    async fn hold(
+        &self,
+        branch_id: &str,
+        date: NaiveDate,
+        time: &str,
+        session_id: &str,
+        default_capacity: u32,
+        ttl: Duration,
+    ) -> Result<Option<SlotHold>, PersistenceError>;
+
+    /// Book a held slot, decrementing its capacity
+    async fn confirm(
+        &self,
+        hold_id: Uuid,
+        default_capacity: u32,
+    ) -> Result<SlotConfirmation, PersistenceError>;
+
+    /// Give up a hold before it expires
+    async fn release(&self, hold_id: Uuid) -> Result<(), PersistenceError>;
+}
+
+/// ScyllaDB implementation of slot store
+///
+/// Bookings and capacity changes are compare-and-set updates so capacity is
+/// never exceeded. Holds are written with a TTL and disappear on their own
+/// when abandoned; a hold is re-checked after it is written and dropped if a
+/// concurrent one took the last place.
+#[derive(Clone)]
+pub struct ScyllaSlotStore {
+    client: ScyllaClient,
+}
+
+impl ScyllaSlotStore {
+    pub fn new(client: ScyllaClient) -> Self {
+        Self { client }
+    }
+
+    /// Stored capacity override and booked count per slot time
+    async fn slot_usage(
+        &self,
+        branch_id: &str,
+        date: NaiveDate,
+    ) -> Result<HashMap<String, (Option<i32>, i32)>, PersistenceError> {
+        let query = format!(
+            "SELECT slot_time, capacity, booked FROM {}.slot_capacity
+             WHERE branch_id = ? AND slot_date = ?",
+            self.client.keyspace()
+        );
+
+        let result = self
+            .client
+            .execute(query, (branch_id, date.to_string()))
+            .await?;
+
+        let mut usage = HashMap::new();
+        if let Some(rows) = result.rows {
+            for row in rows {
+                let (time, capacity, booked): (String, Option<i32>, Option<i32>) = row
+                    .into_typed()
+                    .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+                usage.insert(time, (capacity, booked.unwrap_or(0)));
+            }
+        }
+
+        Ok(usage)
+    }
+
+    /// Unexpired holds per slot time
+    async fn active_holds(
+        &self,
+        branch_id: &str,
+        date: NaiveDate,
+    ) -> Result<HashMap<String, u32>, PersistenceError> {
+        let query = format!(
+            "SELECT slot_time, expires_at FROM {}.slot_holds
+             WHERE branch_id = ? AND slot_date = ?",
+            self.client.keyspace()
+        );
+
+        let result = self
+            .client
+            .execute(query, (branch_id, date.to_string()))
+            .await?;
+
+        let now = Utc::now().timestamp_millis();
+        let mut holds = HashMap::new();
+        if let Some(rows) = result.rows {
+            for row in rows {
+                let (time, expires_at): (String, i64) = row
+                    .into_typed()
+                    .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+                if expires_at > now {
+                    *holds.entry(time).or_insert(0) += 1;
+                }
+            }
+        }
+
+        Ok(holds)
+    }
+
+    /// Unexpired holds on one slot, as (expires_at millis, hold_id)
+    async fn slot_holds(
+        &self,
+        branch_id: &str,
+        date: NaiveDate,
+        time: &str,
+    ) -> Result<Vec<(i64, Uuid)>, PersistenceError> {
+        let query = format!(
+            "SELECT expires_at, hold_id FROM {}.slot_holds
+             WHERE branch_id = ? AND slot_date = ? AND slot_time = ?",
+            self.client.keyspace()
+        );
+
+        let result = self
+            .client
+            .execute(query, (branch_id, date.to_string(), time))
+            .await?;
+
+        let now = Utc::now().timestamp_millis();
+        let mut holds = Vec::new();
+        for row in result.rows.unwrap_or_default() {
+            let hold: (i64, Uuid) = row
+                .into_typed()
+                .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+            if hold.0 > now {
+                holds.push(hold);
+            }
+        }
+
+        Ok(holds)
+    }
+
+    async fn get_hold(&self, hold_id: Uuid) -> Result<Option<SlotHold>, PersistenceError> {
+        let query = format!(
+            "SELECT branch_id, slot_date, slot_time, session_id, expires_at
+             FROM {}.slot_holds_by_id WHERE hold_id = ?",
+            self.client.keyspace()
+        );
+
+        let result = self.client.execute(query, (hold_id,)).await?;
+
+        if let Some(rows) = result.rows {
+            if let Some(row) = rows.into_iter().next() {
+                let (branch_id, date, time, session_id, expires_at): (
+                    String,
+                    String,
+                    String,
+                    String,
+                    i64,
+                ) = row
+                    .into_typed()
+                    .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+
+                let date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+                    .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+                return Ok(Some(SlotHold {
+                    hold_id,
+                    branch_id,
+                    date,
+                    time,
+                    session_id,
+                    expires_at: DateTime::from_timestamp_millis(expires_at)
+                        .unwrap_or_else(Utc::now),
+                }));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Delete a hold from both hold tables
+    async fn delete_hold(&self, hold: &SlotHold) -> Result<(), PersistenceError> {
+        let by_slot = format!(
+            "DELETE FROM {}.slot_holds
+             WHERE branch_id = ? AND slot_date = ? AND slot_time = ? AND hold_id = ?",
+            self.client.keyspace()
+        );
+        self.client
+            .execute(
+                by_slot,
+                (
+                    &hold.branch_id,
+                    hold.date.to_string(),
+                    &hold.time,
+                    hold.hold_id,
+                ),
+            )
+            .await?;
+
+        let by_id = format!(
+            "DELETE FROM {}.slot_holds_by_id WHERE hold_id = ?",
+            self.client.keyspace()
+        );
+        self.client.execute(by_id, (hold.hold_id,)).await?;
+
+        Ok(())
+    }
+}
+
+/// Capacity override from the calendar, or the configured default
+fn stored_capacity(capacity: Option<i32>, default_capacity: u32) -> u32 {
+    capacity
+        .map(|c| c.max(0) as u32)
+        .unwrap_or(default_capacity)
+}
+
+/// Whether a lightweight transaction was applied (first `[applied]` column)
+pub(crate) fn lwt_applied(rows: Option<Vec<Row>>) -> bool {
+    rows.and_then(|rows| rows.into_iter().next())
+        .and_then(|row| row.columns.into_iter().next().flatten())
+        .map(|value| matches!(value, CqlValue::Boolean(true)))
+        .unwrap_or(false)
+}
+
+#[async_trait]
+impl SlotStore for ScyllaSlotStore {
+    async fn availability(
+        &self,
+        branch_id: &str,
+        date: NaiveDate,
+        times: &[String],
+        default_capacity: u32,
+    ) -> Result<Vec<SlotAvailability>, PersistenceError> {
+        let usage = self.slot_usage(branch_id, date).await?;
+        let holds = self.active_holds(branch_id, date).await?;
+
+        Ok(times
+            .iter()
+            .map(|time| {
+                let (capacity, booked) = usage.get(time).copied().unwrap_or((None, 0));
+                SlotAvailability {
+                    branch_id: branch_id.to_string(),
+                    date,
+                    time: time.clone(),
+                    capacity: stored_capacity(capacity, default_capacity),
+                    booked: booked.max(0) as u32,
+                    held: holds.get(time).copied().unwrap_or(0),
+                }
+            })
+            .collect())
+    }
+
+    async fn set_capacity(
+        &self,
+        branch_id: &str,
+        date: NaiveDate,
+        time: &str,
+        capacity: u32,
+    ) -> Result<(), PersistenceError> {
+        // Bookings update this row with lightweight transactions, so the
+        // override must be one too; plain writes would race with them
+        let date_str = date.to_string();
+        let init = format!(
+            "INSERT INTO {}.slot_capacity (branch_id, slot_date, slot_time, booked)
+             VALUES (?, ?, ?, 0) IF NOT EXISTS",
+            self.client.keyspace()
+        );
+        self.client
+            .execute(init, (branch_id, &date_str, time))
+            .await?;
+
+        let update = format!(
+            "UPDATE {}.slot_capacity SET capacity = ?
+             WHERE branch_id = ? AND slot_date = ? AND slot_time = ? IF capacity = ?",
+            self.client.keyspace()
+        );
+        for _ in 0..MAX_BOOKING_ATTEMPTS {
+            let usage = self.slot_usage(branch_id, date).await?;
+            let current = usage.get(time).and_then(|(capacity, _)| *capacity);
+
+            let result = self
+                .client
+                .execute(
+                    update.clone(),
+                    (capacity as i32, branch_id, &date_str, time, current),
+                )
+                .await?;
+            if lwt_applied(result.rows) {
+                tracing::info!(
+                    branch_id = %branch_id,
+                    date = %date,
+                    time = %time,
+                    capacity,
+                    "Slot capacity overridden"
+                );
+                return Ok(());
+            }
+        }
+
+        Err(PersistenceError::Query(format!(
+            "capacity update for {} {} {} kept conflicting",
+            branch_id, date, time
+        )))
+    }
+
+    async fn hold(
+        &self,
+        branch_id: &str,
+        date: NaiveDate,
+        time: &str,
+        session_id: &str,
+        default_capacity: u32,
+        ttl: Duration,
+    ) -> Result<Option<SlotHold>, PersistenceError> {
+        let slot = self
+            .availability(branch_id, date, &[time.to_string()], default_capacity)
+            .await?;
+        if slot.first().map(|s| s.remaining()).unwrap_or(0) == 0 {
+            return Ok(None);
+        }
+
+        let hold = SlotHold {
+            hold_id: Uuid::new_v4(),
+            branch_id: branch_id.to_string(),
+            date,
+            time: time.to_string(),
+            session_id: session_id.to_string(),
+            expires_at: Utc::now() + ttl,
+        };
+        let ttl_secs = ttl.num_seconds().max(1) as i32;
+
+        let by_slot = format!(
+            "INSERT INTO {}.slot_holds (
+                branch_id, slot_date, slot_time, hold_id, session_id, expires_at
+            ) VALUES (?, ?, ?, ?, ?, ?) USING TTL ?",
+            self.client.keyspace()
+        );
+        self.client
+            .execute(
+                by_slot,
+                (
+                    &hold.branch_id,
+                    hold.date.to_string(),
+                    &hold.time,
+                    hold.hold_id,
+                    &hold.session_id,
+                    hold.expires_at.timestamp_millis(),
+                    ttl_secs,
+                ),
+            )
+            .await?;
+
+        let by_id = format!(
+            "INSERT INTO {}.slot_holds_by_id (
+                hold_id, branch_id, slot_date, slot_time, session_id, expires_at
+            ) VALUES (?, ?, ?, ?, ?, ?) USING TTL ?",
+            self.client.keyspace()
+        );
+        self.client
+            .execute(
+                by_id,
+                (
+                    hold.hold_id,
+                    &hold.branch_id,
+                    hold.date.to_string(),
+                    &hold.time,
+                    &hold.session_id,
+                    hold.expires_at.timestamp_millis(),
+                    ttl_secs,
+                ),
+            )
+            .await?;
+
+        // Another session may have held the last place between the check
+        // above and the insert. Every session sees the same holds on re-read,
+        // so keeping only the earliest ones (by expiry, i.e. by hold time for
+        // equal TTLs) leaves exactly one winner.
+        let (capacity, booked) = self
+            .slot_usage(branch_id, date)
+            .await?
+            .get(time)
+            .copied()
+            .unwrap_or((None, 0));
+        let capacity = stored_capacity(capacity, default_capacity);
+        let ours = (hold.expires_at.timestamp_millis(), hold.hold_id);
+        let ahead = self
+            .slot_holds(branch_id, date, time)
+            .await?
+            .into_iter()
+            .filter(|other| *other < ours)
+            .count() as u32;
+        if booked.max(0) as u32 + ahead >= capacity {
+            self.delete_hold(&hold).await?;
+            tracing::debug!(
+                branch_id = %branch_id,
+                date = %date,
+                time = %time,
+                "Slot taken by a concurrent hold"
+            );
+            return Ok(None);
+        }
+
+        tracing::debug!(
+            hold_id = %hold.hold_id,
+            branch_id = %branch_id,
+            date = %date,
+            time = %time,
+            "Slot held"
+        );
+
+        Ok(Some(hold))
+    }
+
+    async fn confirm(
+        &self,
+        hold_id: Uuid,
+        default_capacity: u32,
+    ) -> Result<SlotConfirmation, PersistenceError> {
+        let Some(hold) = self.get_hold(hold_id).await? else {
+            return Ok(SlotConfirmation::Expired);
+        };
+        if hold.is_expired() {
+            return Ok(SlotConfirmation::Expired);
+        }
+
+        let date = hold.date.to_string();
+        let init = format!(
+            "INSERT INTO {}.slot_capacity (branch_id, slot_date, slot_time, booked)
+             VALUES (?, ?, ?, 0) IF NOT EXISTS",
+            self.client.keyspace()
+        );
+        self.client
+            .execute(init, (&hold.branch_id, &date, &hold.time))
+            .await?;
+
+        let book = format!(
+            "UPDATE {}.slot_capacity SET booked = ?
+             WHERE branch_id = ? AND slot_date = ? AND slot_time = ? IF booked = ?",
+            self.client.keyspace()
+        );
+        for _ in 0..MAX_BOOKING_ATTEMPTS {
+            let usage = self.slot_usage(&hold.branch_id, hold.date).await?;
+            let (capacity, booked) = usage.get(&hold.time).copied().unwrap_or((None, 0));
+            let capacity = stored_capacity(capacity, default_capacity);
+            if booked.max(0) as u32 >= capacity {
+                self.delete_hold(&hold).await?;
+                return Ok(SlotConfirmation::Full);
+            }
+
+            let result = self
+                .client
+                .execute(
+                    book.clone(),
+                    (booked + 1, &hold.branch_id, &date, &hold.time, booked),
+                )
+                .await?;
+            if lwt_applied(result.rows) {
+                self.delete_hold(&hold).await?;
+                tracing::info!(
+                    hold_id = %hold_id,
+                    branch_id = %hold.branch_id,
+                    date = %hold.date,
+                    time = %hold.time,
+                    booked = booked + 1,
+                    "Slot booked"
+                );
+                return Ok(SlotConfirmation::Confirmed(hold));
+            }
+        }
+
+        Err(PersistenceError::Query(format!(
+            "slot booking for hold {} kept conflicting",
+            hold_id
+        )))
+    }
+
+    async fn release(&self, hold_id: Uuid) -> Result<(), PersistenceError> {
+        if let Some(hold) = self.get_hold(hold_id).await? {
+            self.delete_hold(&hold).await?;
+        }
+        Ok(())
+    }
+}
+
+type SlotKey = (String, NaiveDate, String);
+
+#[derive(Default)]
+struct SlotState {
+    capacity: HashMap<SlotKey, u32>,
+    booked: HashMap<SlotKey, u32>,
+    holds: HashMap<Uuid, SlotHold>,
+}
+
+impl SlotState {
+    fn availability(&self, key: &SlotKey, default_capacity: u32) -> SlotAvailability {
+        SlotAvailability {
+            branch_id: key.0.clone(),
+            date: key.1,
+            time: key.2.clone(),
+            capacity: self.capacity.get(key).copied().unwrap_or(default_capacity),
+            booked: self.booked.get(key).copied().unwrap_or(0),
+            held: self
+                .holds
+                .values()
+                .filter(|h| !h.is_expired())
+                .filter(|h| h.branch_id == key.0 && h.date == key.1 && h.time == key.2)
+                .count() as u32,
+        }
+    }
+}
+
+/// In-memory slot store
+///
+/// Used when ScyllaDB is not configured; bookings do not survive restarts.
+#[derive(Default)]
+pub struct InMemorySlotStore {
+    state: RwLock<SlotState>,
+}
+
+impl InMemorySlotStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl SlotStore for InMemorySlotStore {
+    async fn availability(
+        &self,
+        branch_id: &str,
+        date: NaiveDate,
+        times: &[String],
+        default_capacity: u32,
+    ) -> Result<Vec<SlotAvailability>, PersistenceError> {
+        let state = self.state.read().await;
+        Ok(times
+            .iter()
+            .map(|time| {
+                let key = (branch_id.to_string(), date, time.clone());
+                state.availability(&key, default_capacity)
+            })
+            .collect())
+    }
+
+    async fn set_capacity(
+        &self,
+        branch_id: &str,
+        date: NaiveDate,
+        time: &str,
+        capacity: u32,
+    ) -> Result<(), PersistenceError> {
+        self.state
+            .write()
+            .await
+            .capacity
+            .insert((branch_id.to_string(), date, time.to_string()), capacity);
+        Ok(())
+    }
+
+    async fn hold(
+        &self,
+        branch_id: &str,
+        date: NaiveDate,
+        time: &str,
+        session_id: &str,
+        default_capacity: u32,
+        ttl: Duration,
+    ) -> Result<Option<SlotHold>, PersistenceError> {
+        let mut state = self.state.write().await;
+        state.holds.retain(|_, h| !h.is_expired());
+
+        let key = (branch_id.to_string(), date, time.to_string());
+        if state.availability(&key, default_capacity).remaining() == 0 {
+            return Ok(None);
+        }
+
+        let hold = SlotHold {
+            hold_id: Uuid::new_v4(),
+            branch_id: branch_id.to_string(),
+            date,
+            time: time.to_string(),
+            session_id: session_id.to_string(),
+            expires_at: Utc::now() + ttl,
+        };
+        state.holds.insert(hold.hold_id, hold.clone());
+        Ok(Some(hold))
+    }
+
+    async fn confirm(
+        &self,
+        hold_id: Uuid,
+        default_capacity: u32,
+    ) -> Result<SlotConfirmation, PersistenceError> {
+        let mut state = self.state.write().await;
+        let Some(hold) = state.holds.remove(&hold_id) else {
+            return Ok(SlotConfirmation::Expired);
+        };
+        if hold.is_expired() {
+            return Ok(SlotConfirmation::Expired);
+        }
+
+        let key = (hold.branch_id.clone(), hold.date, hold.time.clone());
+        let capacity = state
+            .capacity
+            .get(&key)
+            .copied()
+            .unwrap_or(default_capacity);
+        let booked = state.booked.entry(key).or_insert(0);
+        if *booked >= capacity {
+            return Ok(SlotConfirmation::Full);
+        }
+        *booked += 1;
+
+        Ok(SlotConfirmation::Confirmed(hold))
+    }
+
+    async fn release(&self, hold_id: Uuid) -> Result<(), PersistenceError> {
+        self.state.write().await.holds.remove(&hold_id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2030, 1, 15).unwrap()
+    }
+
+    fn times() -> Vec<String> {
+        vec!["10:00 AM".to_string(), "11:00 AM".to_string()]
+    }
+
+    #[tokio::test]
+    async fn test_holds_count_against_availability() {
+        let store = InMemorySlotStore::new();
+        let ttl = Duration::minutes(10);
+
+        let first = store
+            .hold("B1", date(), "10:00 AM", "call-1", 2, ttl)
+            .await
+            .unwrap();
+        assert!(first.is_some());
+        store
+            .hold("B1", date(), "10:00 AM", "call-2", 2, ttl)
+            .await
+            .unwrap()
+            .unwrap();
+        // Both places held
+        assert!(store
+            .hold("B1", date(), "10:00 AM", "call-3", 2, ttl)
+            .await
+            .unwrap()
+            .is_none());
+
+        let slots = store.availability("B1", date(), &times(), 2).await.unwrap();
+        assert_eq!(slots[0].held, 2);
+        assert_eq!(slots[0].remaining(), 0);
+        assert_eq!(slots[1].remaining(), 2);
+
+        // Releasing frees the place again
+        store.release(first.unwrap().hold_id).await.unwrap();
+        let slots = store.availability("B1", date(), &times(), 2).await.unwrap();
+        assert_eq!(slots[0].remaining(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_confirm_decrements_capacity() {
+        let store = InMemorySlotStore::new();
+        store
+            .set_capacity("B1", date(), "11:00 AM", 1)
+            .await
+            .unwrap();
+
+        let hold = store
+            .hold("B1", date(), "11:00 AM", "call-1", 2, Duration::minutes(10))
+            .await
+            .unwrap()
+            .unwrap();
+        let confirmation = store.confirm(hold.hold_id, 2).await.unwrap();
+        assert!(matches!(confirmation, SlotConfirmation::Confirmed(_)));
+
+        let slots = store.availability("B1", date(), &times(), 2).await.unwrap();
+        assert_eq!(slots[1].capacity, 1);
+        assert_eq!(slots[1].booked, 1);
+        assert_eq!(slots[1].remaining(), 0);
+
+        // A hold can only be confirmed once
+        assert_eq!(
+            store.confirm(hold.hold_id, 2).await.unwrap(),
+            SlotConfirmation::Expired
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expired_hold_is_not_confirmed() {
+        let store = InMemorySlotStore::new();
+        let hold = store
+            .hold("B1", date(), "10:00 AM", "call-1", 2, Duration::seconds(-1))
+            .await
+            .unwrap()
+            .unwrap();
+
+        let slots = store.availability("B1", date(), &times(), 2).await.unwrap();
+        assert_eq!(slots[0].held, 0);
+        assert_eq!(
+            store.confirm(hold.hold_id, 2).await.unwrap(),
+            SlotConfirmation::Expired
+        );
+    }
+}
@@ -19,6 +19,7 @@ pub enum SmsType {
     Welcome,
     Promotional,
     Otp,
+    PriceAlert,
 }
 
 impl SmsType {
@@ -30,6 +31,20 @@ impl SmsType {
             Self::Welcome => "welcome",
             Self::Promotional => "promotional",
             Self::Otp => "otp",
+            Self::PriceAlert => "price_alert",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "appointment_confirmation" => Self::AppointmentConfirmation,
+            "appointment_reminder" => Self::AppointmentReminder,
+            "follow_up" => Self::FollowUp,
+            "welcome" => Self::Welcome,
+            "promotional" => Self::Promotional,
+            "otp" => Self::Otp,
+            "price_alert" => Self::PriceAlert,
+            _ => Self::FollowUp,
         }
     }
 }
@@ -53,6 +68,16 @@ impl SmsStatus {
             Self::Failed => "failed",
         }
     }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "queued" => Self::Queued,
+            "simulated_sent" => Self::SimulatedSent,
+            "delivered" => Self::Delivered,
+            "failed" => Self::Failed,
+            _ => Self::SimulatedSent,
+        }
+    }
 }
 
 /// SMS message record
@@ -206,8 +231,7 @@ impl SmsService for SimulatedSmsService {
         );
 
         self.client
-            .session()
-            .query_unpaged(
+            .execute(
                 query,
                 (
                     phone,
@@ -256,11 +280,7 @@ impl SmsService for SimulatedSmsService {
             self.client.keyspace()
         );
 
-        let result = self
-            .client
-            .session()
-            .query_unpaged(query, (phone, limit))
-            .await?;
+        let result = self.client.execute(query, (phone, limit)).await?;
 
         let mut messages = Vec::new();
         if let Some(rows) = result.rows {
@@ -294,22 +314,8 @@ impl SmsService for SimulatedSmsService {
                     phone_number,
                     session_id,
                     message_text,
-                    message_type: match message_type.as_str() {
-                        "appointment_confirmation" => SmsType::AppointmentConfirmation,
-                        "appointment_reminder" => SmsType::AppointmentReminder,
-                        "follow_up" => SmsType::FollowUp,
-                        "welcome" => SmsType::Welcome,
-                        "promotional" => SmsType::Promotional,
-                        "otp" => SmsType::Otp,
-                        _ => SmsType::FollowUp,
-                    },
-                    status: match status.as_str() {
-                        "queued" => SmsStatus::Queued,
-                        "simulated_sent" => SmsStatus::SimulatedSent,
-                        "delivered" => SmsStatus::Delivered,
-                        "failed" => SmsStatus::Failed,
-                        _ => SmsStatus::SimulatedSent,
-                    },
+                    message_type: SmsType::parse(&message_type),
+                    status: SmsStatus::parse(&status),
                     created_at: DateTime::from_timestamp_millis(created_at)
                         .unwrap_or_else(Utc::now),
                     sent_at: sent_at.and_then(DateTime::from_timestamp_millis),
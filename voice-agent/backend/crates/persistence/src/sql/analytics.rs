@@ -0,0 +1,194 @@
+//! Conversation analytics using SQLite/Postgres
+
+use super::{parse_date, timestamp, SqlClient};
+use crate::analytics::days_in_range;
+use crate::{AnalyticsStore, DailyRollup, PersistenceError, SessionOutcome};
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use sqlx::any::AnyRow;
+use sqlx::Row;
+use std::collections::BTreeMap;
+
+/// SQL implementation of the analytics store
+#[derive(Clone)]
+pub struct SqlAnalyticsStore {
+    client: SqlClient,
+}
+
+impl SqlAnalyticsStore {
+    pub fn new(client: SqlClient) -> Self {
+        Self { client }
+    }
+}
+
+fn json_list(json: Option<String>) -> Result<Vec<String>, PersistenceError> {
+    Ok(match json {
+        Some(json) => serde_json::from_str(&json)?,
+        None => Vec::new(),
+    })
+}
+
+fn json_counts(json: Option<String>) -> Result<BTreeMap<String, u64>, PersistenceError> {
+    Ok(match json {
+        Some(json) => serde_json::from_str(&json)?,
+        None => BTreeMap::new(),
+    })
+}
+
+fn row_to_outcome(row: &AnyRow) -> Result<SessionOutcome, PersistenceError> {
+    let duration_secs: i64 = row.try_get("duration_secs")?;
+    let language: Option<String> = row.try_get("language")?;
+    let final_stage: Option<String> = row.try_get("final_stage")?;
+    let goal_reached: Option<bool> = row.try_get("goal_reached")?;
+    let interruptions: Option<i64> = row.try_get("interruptions")?;
+    let turns: Option<i64> = row.try_get("turns")?;
+
+    Ok(SessionOutcome {
+        session_id: row.try_get("session_id")?,
+        started_at: timestamp(row.try_get("started_at")?),
+        duration_secs: duration_secs.max(0) as u64,
+        language: language.unwrap_or_default(),
+        stages_reached: json_list(row.try_get("stages_json")?)?,
+        final_stage: final_stage.unwrap_or_default(),
+        goal: row.try_get("goal")?,
+        goal_reached: goal_reached.unwrap_or(false),
+        tools_called: json_list(row.try_get("tools_json")?)?,
+        interruptions: interruptions.unwrap_or(0).max(0) as u32,
+        turns: turns.unwrap_or(0).max(0) as u32,
+    })
+}
+
+fn row_to_rollup(row: &AnyRow) -> Result<DailyRollup, PersistenceError> {
+    let count = |column: &str| -> Result<u64, PersistenceError> {
+        let value: i64 = row.try_get(column)?;
+        Ok(value.max(0) as u64)
+    };
+
+    Ok(DailyRollup {
+        day: parse_date(row.try_get("day")?)?,
+        language: row.try_get("language")?,
+        sessions: count("sessions")?,
+        goals_reached: count("goals_reached")?,
+        total_duration_secs: count("total_duration_secs")?,
+        interruptions: count("interruptions")?,
+        stage_reached: json_counts(row.try_get("stage_reached_json")?)?,
+        drop_off: json_counts(row.try_get("drop_off_json")?)?,
+        tool_sessions: json_counts(row.try_get("tool_sessions_json")?)?,
+        tool_calls: json_counts(row.try_get("tool_calls_json")?)?,
+    })
+}
+
+#[async_trait]
+impl AnalyticsStore for SqlAnalyticsStore {
+    async fn record_outcome(&self, outcome: &SessionOutcome) -> Result<(), PersistenceError> {
+        sqlx::query(
+            "INSERT INTO session_outcomes (
+                day, session_id, started_at, duration_secs, language, stages_json,
+                final_stage, goal, goal_reached, tools_json, interruptions, turns
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            ON CONFLICT (day, session_id) DO UPDATE SET
+                started_at = excluded.started_at,
+                duration_secs = excluded.duration_secs,
+                language = excluded.language,
+                stages_json = excluded.stages_json,
+                final_stage = excluded.final_stage,
+                goal = excluded.goal,
+                goal_reached = excluded.goal_reached,
+                tools_json = excluded.tools_json,
+                interruptions = excluded.interruptions,
+                turns = excluded.turns",
+        )
+        .bind(outcome.day().to_string())
+        .bind(&outcome.session_id)
+        .bind(outcome.started_at.timestamp_millis())
+        .bind(outcome.duration_secs as i64)
+        .bind(&outcome.language)
+        .bind(serde_json::to_string(&outcome.stages_reached)?)
+        .bind(&outcome.final_stage)
+        .bind(&outcome.goal)
+        .bind(outcome.goal_reached)
+        .bind(serde_json::to_string(&outcome.tools_called)?)
+        .bind(outcome.interruptions as i64)
+        .bind(outcome.turns as i64)
+        .execute(self.client.pool())
+        .await?;
+
+        Ok(())
+    }
+
+    async fn outcomes_for_day(
+        &self,
+        day: NaiveDate,
+    ) -> Result<Vec<SessionOutcome>, PersistenceError> {
+        let rows = sqlx::query(
+            "SELECT session_id, started_at, duration_secs, language, stages_json,
+                    final_stage, goal, goal_reached, tools_json, interruptions, turns
+             FROM session_outcomes WHERE day = $1",
+        )
+        .bind(day.to_string())
+        .fetch_all(self.client.pool())
+        .await?;
+
+        rows.iter().map(row_to_outcome).collect()
+    }
+
+    async fn upsert_rollup(&self, rollup: &DailyRollup) -> Result<(), PersistenceError> {
+        sqlx::query(
+            "INSERT INTO analytics_daily (
+                day, language, sessions, goals_reached, total_duration_secs, interruptions,
+                stage_reached_json, drop_off_json, tool_sessions_json, tool_calls_json,
+                updated_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            ON CONFLICT (day, language) DO UPDATE SET
+                sessions = excluded.sessions,
+                goals_reached = excluded.goals_reached,
+                total_duration_secs = excluded.total_duration_secs,
+                interruptions = excluded.interruptions,
+                stage_reached_json = excluded.stage_reached_json,
+                drop_off_json = excluded.drop_off_json,
+                tool_sessions_json = excluded.tool_sessions_json,
+                tool_calls_json = excluded.tool_calls_json,
+                updated_at = excluded.updated_at",
+        )
+        .bind(rollup.day.to_string())
+        .bind(&rollup.language)
+        .bind(rollup.sessions as i64)
+        .bind(rollup.goals_reached as i64)
+        .bind(rollup.total_duration_secs as i64)
+        .bind(rollup.interruptions as i64)
+        .bind(serde_json::to_string(&rollup.stage_reached)?)
+        .bind(serde_json::to_string(&rollup.drop_off)?)
+        .bind(serde_json::to_string(&rollup.tool_sessions)?)
+        .bind(serde_json::to_string(&rollup.tool_calls)?)
+        .bind(Utc::now().timestamp_millis())
+        .execute(self.client.pool())
+        .await?;
+
+        Ok(())
+    }
+
+    async fn rollups(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        language: &str,
+    ) -> Result<Vec<DailyRollup>, PersistenceError> {
+        // Validates the range; ISO dates sort as text so one query covers it
+        days_in_range(from, to)?;
+
+        let rows = sqlx::query(
+            "SELECT day, language, sessions, goals_reached, total_duration_secs, interruptions,
+                    stage_reached_json, drop_off_json, tool_sessions_json, tool_calls_json
+             FROM analytics_daily
+             WHERE day >= $1 AND day <= $2 AND language = $3
+             ORDER BY day",
+        )
+        .bind(from.to_string())
+        .bind(to.to_string())
+        .bind(language)
+        .fetch_all(self.client.pool())
+        .await?;
+
+        rows.iter().map(row_to_rollup).collect()
+    }
+}
@@ -0,0 +1,184 @@
+//! Appointment persistence using SQLite/Postgres
+
+use super::{parse_date, parse_uuid, timestamp, SqlClient};
+use crate::{Appointment, AppointmentStatus, AppointmentStore, PersistenceError};
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use sqlx::any::AnyRow;
+use sqlx::Row;
+use uuid::Uuid;
+
+const COLUMNS: &str = "customer_phone, appointment_id, session_id, customer_name,
+    branch_id, branch_name, branch_address,
+    appointment_date, appointment_time, status,
+    created_at, updated_at, confirmation_sms_id, notes";
+
+/// SQL implementation of appointment store
+#[derive(Clone)]
+pub struct SqlAppointmentStore {
+    client: SqlClient,
+}
+
+impl SqlAppointmentStore {
+    pub fn new(client: SqlClient) -> Self {
+        Self { client }
+    }
+}
+
+fn row_to_appointment(row: &AnyRow) -> Result<Appointment, PersistenceError> {
+    let confirmation_sms_id: Option<&str> = row.try_get("confirmation_sms_id")?;
+    Ok(Appointment {
+        appointment_id: parse_uuid(row.try_get("appointment_id")?)?,
+        session_id: row.try_get("session_id")?,
+        customer_phone: row.try_get("customer_phone")?,
+        customer_name: row.try_get("customer_name")?,
+        branch_id: row.try_get("branch_id")?,
+        branch_name: row.try_get("branch_name")?,
+        branch_address: row.try_get("branch_address")?,
+        appointment_date: parse_date(row.try_get("appointment_date")?)?,
+        appointment_time: row.try_get("appointment_time")?,
+        status: AppointmentStatus::from_str(row.try_get("status")?),
+        created_at: timestamp(row.try_get("created_at")?),
+        updated_at: timestamp(row.try_get("updated_at")?),
+        confirmation_sms_id: confirmation_sms_id.map(parse_uuid).transpose()?,
+        notes: row.try_get("notes")?,
+    })
+}
+
+#[async_trait]
+impl AppointmentStore for SqlAppointmentStore {
+    async fn create(&self, appointment: &Appointment) -> Result<(), PersistenceError> {
+        let query = format!(
+            "INSERT INTO appointments ({}) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)",
+            COLUMNS
+        );
+
+        sqlx::query(&query)
+            .bind(&appointment.customer_phone)
+            .bind(appointment.appointment_id.to_string())
+            .bind(&appointment.session_id)
+            .bind(&appointment.customer_name)
+            .bind(&appointment.branch_id)
+            .bind(&appointment.branch_name)
+            .bind(&appointment.branch_address)
+            .bind(appointment.appointment_date.to_string())
+            .bind(&appointment.appointment_time)
+            .bind(appointment.status.as_str())
+            .bind(appointment.created_at.timestamp_millis())
+            .bind(appointment.updated_at.timestamp_millis())
+            .bind(appointment.confirmation_sms_id.map(|id| id.to_string()))
+            .bind(&appointment.notes)
+            .execute(self.client.pool())
+            .await?;
+
+        tracing::info!(
+            appointment_id = %appointment.appointment_id,
+            customer_phone = %appointment.customer_phone,
+            branch = %appointment.branch_name,
+            date = %appointment.appointment_date,
+            "Appointment created in SQL store"
+        );
+
+        Ok(())
+    }
+
+    async fn get(
+        &self,
+        phone: &str,
+        appointment_id: Uuid,
+    ) -> Result<Option<Appointment>, PersistenceError> {
+        let query = format!(
+            "SELECT {} FROM appointments WHERE customer_phone = $1 AND appointment_id = $2",
+            COLUMNS
+        );
+
+        let row = sqlx::query(&query)
+            .bind(phone)
+            .bind(appointment_id.to_string())
+            .fetch_optional(self.client.pool())
+            .await?;
+
+        row.as_ref().map(row_to_appointment).transpose()
+    }
+
+    async fn update_status(
+        &self,
+        phone: &str,
+        appointment_id: Uuid,
+        status: AppointmentStatus,
+    ) -> Result<(), PersistenceError> {
+        sqlx::query(
+            "UPDATE appointments SET status = $1, updated_at = $2
+             WHERE customer_phone = $3 AND appointment_id = $4",
+        )
+        .bind(status.as_str())
+        .bind(Utc::now().timestamp_millis())
+        .bind(phone)
+        .bind(appointment_id.to_string())
+        .execute(self.client.pool())
+        .await?;
+
+        tracing::info!(
+            appointment_id = %appointment_id,
+            status = ?status,
+            "Appointment status updated"
+        );
+
+        Ok(())
+    }
+
+    async fn set_confirmation_sms(
+        &self,
+        phone: &str,
+        appointment_id: Uuid,
+        sms_id: Uuid,
+    ) -> Result<(), PersistenceError> {
+        sqlx::query(
+            "UPDATE appointments SET confirmation_sms_id = $1, updated_at = $2
+             WHERE customer_phone = $3 AND appointment_id = $4",
+        )
+        .bind(sms_id.to_string())
+        .bind(Utc::now().timestamp_millis())
+        .bind(phone)
+        .bind(appointment_id.to_string())
+        .execute(self.client.pool())
+        .await?;
+
+        Ok(())
+    }
+
+    async fn list_for_customer(
+        &self,
+        phone: &str,
+        limit: i32,
+    ) -> Result<Vec<Appointment>, PersistenceError> {
+        let query = format!(
+            "SELECT {} FROM appointments WHERE customer_phone = $1
+             ORDER BY created_at DESC LIMIT $2",
+            COLUMNS
+        );
+
+        let rows = sqlx::query(&query)
+            .bind(phone)
+            .bind(limit as i64)
+            .fetch_all(self.client.pool())
+            .await?;
+
+        rows.iter().map(row_to_appointment).collect()
+    }
+
+    async fn list_for_date(&self, date: NaiveDate) -> Result<Vec<Appointment>, PersistenceError> {
+        let query = format!(
+            "SELECT {} FROM appointments WHERE appointment_date = $1
+             ORDER BY appointment_time",
+            COLUMNS
+        );
+
+        let rows = sqlx::query(&query)
+            .bind(date.to_string())
+            .fetch_all(self.client.pool())
+            .await?;
+
+        rows.iter().map(row_to_appointment).collect()
+    }
+}
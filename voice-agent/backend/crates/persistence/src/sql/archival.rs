@@ -0,0 +1,142 @@
+//! Archival memory persistence using SQLite/Postgres
+
+use super::{timestamp, SqlClient};
+use crate::{ArchivalRecord, ArchivalStore, PersistenceError};
+use async_trait::async_trait;
+use sqlx::any::AnyRow;
+use sqlx::Row;
+
+/// SQL implementation of archival store
+///
+/// Embeddings are stored as little-endian `f32` bytes.
+#[derive(Clone)]
+pub struct SqlArchivalStore {
+    client: SqlClient,
+}
+
+impl SqlArchivalStore {
+    pub fn new(client: SqlClient) -> Self {
+        Self { client }
+    }
+}
+
+fn encode_embedding(embedding: &[f32]) -> Vec<u8> {
+    embedding.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+fn decode_embedding(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect()
+}
+
+fn row_to_record(row: &AnyRow) -> Result<ArchivalRecord, PersistenceError> {
+    let embedding: Option<Vec<u8>> = row.try_get("embedding")?;
+    Ok(ArchivalRecord {
+        id: row.try_get("id")?,
+        session_id: row.try_get("session_id")?,
+        note_json: row.try_get("note_json")?,
+        embedding: embedding
+            .as_deref()
+            .map(decode_embedding)
+            .unwrap_or_default(),
+        created_at: timestamp(row.try_get("created_at")?),
+    })
+}
+
+#[async_trait]
+impl ArchivalStore for SqlArchivalStore {
+    async fn upsert(
+        &self,
+        collection: &str,
+        record: &ArchivalRecord,
+    ) -> Result<(), PersistenceError> {
+        sqlx::query(
+            "INSERT INTO archival_memories (
+                collection, session_id, id, note_json, embedding, created_at
+            ) VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (collection, session_id, id) DO UPDATE SET
+                note_json = excluded.note_json,
+                embedding = excluded.embedding,
+                created_at = excluded.created_at",
+        )
+        .bind(collection)
+        .bind(&record.session_id)
+        .bind(&record.id)
+        .bind(&record.note_json)
+        .bind(encode_embedding(&record.embedding))
+        .bind(record.created_at.timestamp_millis())
+        .execute(self.client.pool())
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        collection: &str,
+        session_id: &str,
+        id: &str,
+    ) -> Result<(), PersistenceError> {
+        sqlx::query(
+            "DELETE FROM archival_memories
+             WHERE collection = $1 AND session_id = $2 AND id = $3",
+        )
+        .bind(collection)
+        .bind(session_id)
+        .bind(id)
+        .execute(self.client.pool())
+        .await?;
+
+        Ok(())
+    }
+
+    async fn list(
+        &self,
+        collection: &str,
+        session_id: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<ArchivalRecord>, PersistenceError> {
+        let limit = limit.min(i64::MAX as usize) as i64;
+        let rows = match session_id {
+            Some(session_id) => {
+                sqlx::query(
+                    "SELECT id, session_id, note_json, embedding, created_at
+                     FROM archival_memories
+                     WHERE collection = $1 AND session_id = $2 LIMIT $3",
+                )
+                .bind(collection)
+                .bind(session_id)
+                .bind(limit)
+                .fetch_all(self.client.pool())
+                .await?
+            },
+            None => {
+                sqlx::query(
+                    "SELECT id, session_id, note_json, embedding, created_at
+                     FROM archival_memories
+                     WHERE collection = $1 LIMIT $2",
+                )
+                .bind(collection)
+                .bind(limit)
+                .fetch_all(self.client.pool())
+                .await?
+            },
+        };
+
+        rows.iter().map(row_to_record).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embedding_round_trip() {
+        let embedding = vec![0.25, -1.5, 3.0e-7];
+        assert_eq!(decode_embedding(&encode_embedding(&embedding)), embedding);
+        assert!(decode_embedding(&[]).is_empty());
+    }
+}
@@ -0,0 +1,193 @@
+//! Audit log using SQLite/Postgres
+
+use super::{parse_uuid, timestamp, SqlClient};
+use crate::{
+    Actor, AuditEntry, AuditEventType, AuditLog, AuditOutcome, AuditQuery, PersistenceError,
+    ScyllaAuditLog,
+};
+use async_trait::async_trait;
+use sqlx::any::AnyRow;
+use sqlx::Row;
+
+const COLUMNS: &str = "id, session_id, timestamp, event_type, actor_type, actor_id,
+    resource_type, resource_id, action, outcome, details, previous_hash, hash";
+
+/// SQL implementation of the audit log
+///
+/// Unlike the ScyllaDB table, every `AuditQuery` filter is applied.
+#[derive(Clone)]
+pub struct SqlAuditLog {
+    client: SqlClient,
+}
+
+impl SqlAuditLog {
+    pub fn new(client: SqlClient) -> Self {
+        Self { client }
+    }
+}
+
+/// Bind value for a dynamically built filter
+enum Param {
+    Text(String),
+    Millis(i64),
+}
+
+fn row_to_entry(row: &AnyRow) -> Result<AuditEntry, PersistenceError> {
+    let event_type: String = row.try_get("event_type")?;
+    let outcome: String = row.try_get("outcome")?;
+    let details: String = row.try_get("details")?;
+
+    Ok(AuditEntry {
+        id: parse_uuid(row.try_get("id")?)?,
+        timestamp: timestamp(row.try_get("timestamp")?),
+        event_type: AuditEventType::from_str(&event_type),
+        actor: Actor {
+            actor_type: row.try_get("actor_type")?,
+            actor_id: row.try_get("actor_id")?,
+            session_id: Some(row.try_get("session_id")?),
+        },
+        resource_type: row.try_get("resource_type")?,
+        resource_id: row.try_get("resource_id")?,
+        action: row.try_get("action")?,
+        outcome: AuditOutcome::from_str(&outcome),
+        details: serde_json::from_str(&details).unwrap_or(serde_json::Value::Null),
+        previous_hash: row.try_get("previous_hash")?,
+        hash: row.try_get("hash")?,
+    })
+}
+
+#[async_trait]
+impl AuditLog for SqlAuditLog {
+    async fn log(&self, entry: AuditEntry) -> Result<(), PersistenceError> {
+        let session_id = entry.actor.session_id.as_deref().unwrap_or("system");
+
+        sqlx::query(
+            "INSERT INTO audit_log (
+                id, partition_date, session_id, timestamp, event_type,
+                actor_type, actor_id, resource_type, resource_id,
+                action, outcome, details, previous_hash, hash
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)",
+        )
+        .bind(entry.id.to_string())
+        .bind(entry.timestamp.format("%Y-%m-%d").to_string())
+        .bind(session_id)
+        .bind(entry.timestamp.timestamp_millis())
+        .bind(entry.event_type.as_str())
+        .bind(&entry.actor.actor_type)
+        .bind(&entry.actor.actor_id)
+        .bind(&entry.resource_type)
+        .bind(&entry.resource_id)
+        .bind(&entry.action)
+        .bind(entry.outcome.as_str())
+        .bind(entry.details.to_string())
+        .bind(&entry.previous_hash)
+        .bind(&entry.hash)
+        .execute(self.client.pool())
+        .await?;
+
+        tracing::debug!(
+            event_type = entry.event_type.as_str(),
+            resource_id = %entry.resource_id,
+            hash = %entry.hash,
+            "Audit entry logged"
+        );
+
+        Ok(())
+    }
+
+    async fn query(&self, query: AuditQuery) -> Result<Vec<AuditEntry>, PersistenceError> {
+        let mut params = Vec::new();
+        let mut filters = Vec::new();
+        let mut filter = |clause: &str, param: Param| {
+            params.push(param);
+            filters.push(format!("{} ${}", clause, params.len()));
+        };
+        if let Some(session_id) = query.session_id {
+            filter("session_id =", Param::Text(session_id));
+        }
+        if let Some(event_type) = query.event_type {
+            filter("event_type =", Param::Text(event_type.as_str().to_string()));
+        }
+        if let Some(resource_type) = query.resource_type {
+            filter("resource_type =", Param::Text(resource_type));
+        }
+        if let Some(resource_id) = query.resource_id {
+            filter("resource_id =", Param::Text(resource_id));
+        }
+        if let Some(from) = query.from {
+            filter("timestamp >=", Param::Millis(from.timestamp_millis()));
+        }
+        if let Some(to) = query.to {
+            filter("timestamp <=", Param::Millis(to.timestamp_millis()));
+        }
+
+        let where_clause = if filters.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", filters.join(" AND "))
+        };
+        let sql = format!(
+            "SELECT {} FROM audit_log {} ORDER BY timestamp DESC LIMIT ${}",
+            COLUMNS,
+            where_clause,
+            params.len() + 1
+        );
+
+        let mut statement = sqlx::query(&sql);
+        for param in params {
+            statement = match param {
+                Param::Text(value) => statement.bind(value),
+                Param::Millis(value) => statement.bind(value),
+            };
+        }
+        let rows = statement
+            .bind(query.limit.unwrap_or(100) as i64)
+            .fetch_all(self.client.pool())
+            .await?;
+
+        rows.iter().map(row_to_entry).collect()
+    }
+
+    async fn get_latest_hash(&self, session_id: &str) -> Result<String, PersistenceError> {
+        let row = sqlx::query(
+            "SELECT hash FROM audit_log WHERE session_id = $1 ORDER BY timestamp DESC LIMIT 1",
+        )
+        .bind(session_id)
+        .fetch_optional(self.client.pool())
+        .await?;
+
+        match row {
+            Some(row) => Ok(row.try_get("hash")?),
+            // No previous entries - return genesis hash
+            None => Ok(ScyllaAuditLog::genesis_hash()),
+        }
+    }
+
+    async fn verify_chain(&self, session_id: &str) -> Result<bool, PersistenceError> {
+        let sql = format!(
+            "SELECT {} FROM audit_log WHERE session_id = $1 ORDER BY timestamp ASC",
+            COLUMNS
+        );
+        let rows = sqlx::query(&sql)
+            .bind(session_id)
+            .fetch_all(self.client.pool())
+            .await?;
+
+        let mut expected_previous = ScyllaAuditLog::genesis_hash();
+        for row in &rows {
+            let entry = row_to_entry(row)?;
+            if !entry.verify_chain(&expected_previous) {
+                tracing::error!(
+                    entry_id = %entry.id,
+                    expected = %expected_previous,
+                    actual = %entry.previous_hash,
+                    "Audit chain verification failed"
+                );
+                return Ok(false);
+            }
+            expected_previous = entry.hash;
+        }
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,149 @@
+//! Callback requests using SQLite/Postgres
+
+use super::{parse_uuid, timestamp, SqlClient};
+use crate::{Callback, CallbackStatus, CallbackStore, PersistenceError};
+use async_trait::async_trait;
+use sqlx::any::AnyRow;
+use sqlx::Row;
+use uuid::Uuid;
+
+const COLUMNS: &str = "callback_id, session_id, customer_phone, customer_name,
+    window_start, window_end, requested_time, assigned_agent,
+    status, attempts, next_attempt_at, dialer_call_id, notes,
+    created_at, updated_at";
+
+/// SQL implementation of the callback store
+///
+/// Open callbacks are found through the status index instead of a separate
+/// open-queue table.
+#[derive(Clone)]
+pub struct SqlCallbackStore {
+    client: SqlClient,
+}
+
+impl SqlCallbackStore {
+    pub fn new(client: SqlClient) -> Self {
+        Self { client }
+    }
+
+    async fn write(&self, callback: &Callback) -> Result<(), PersistenceError> {
+        let query = format!(
+            "INSERT INTO callbacks ({}) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+             ON CONFLICT (callback_id) DO UPDATE SET
+                session_id = excluded.session_id,
+                customer_phone = excluded.customer_phone,
+                customer_name = excluded.customer_name,
+                window_start = excluded.window_start,
+                window_end = excluded.window_end,
+                requested_time = excluded.requested_time,
+                assigned_agent = excluded.assigned_agent,
+                status = excluded.status,
+                attempts = excluded.attempts,
+                next_attempt_at = excluded.next_attempt_at,
+                dialer_call_id = excluded.dialer_call_id,
+                notes = excluded.notes,
+                created_at = excluded.created_at,
+                updated_at = excluded.updated_at",
+            COLUMNS
+        );
+
+        sqlx::query(&query)
+            .bind(callback.callback_id.to_string())
+            .bind(&callback.session_id)
+            .bind(&callback.customer_phone)
+            .bind(&callback.customer_name)
+            .bind(callback.window_start.timestamp_millis())
+            .bind(callback.window_end.timestamp_millis())
+            .bind(&callback.requested_time)
+            .bind(&callback.assigned_agent)
+            .bind(callback.status.as_str())
+            .bind(callback.attempts as i64)
+            .bind(callback.next_attempt_at.map(|t| t.timestamp_millis()))
+            .bind(&callback.dialer_call_id)
+            .bind(&callback.notes)
+            .bind(callback.created_at.timestamp_millis())
+            .bind(callback.updated_at.timestamp_millis())
+            .execute(self.client.pool())
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn row_to_callback(row: &AnyRow) -> Result<Callback, PersistenceError> {
+    Ok(Callback {
+        callback_id: parse_uuid(row.try_get("callback_id")?)?,
+        session_id: row.try_get("session_id")?,
+        customer_phone: row.try_get("customer_phone")?,
+        customer_name: row.try_get("customer_name")?,
+        window_start: timestamp(row.try_get("window_start")?),
+        window_end: timestamp(row.try_get("window_end")?),
+        requested_time: row.try_get("requested_time")?,
+        assigned_agent: row.try_get("assigned_agent")?,
+        status: CallbackStatus::parse(row.try_get("status")?),
+        attempts: row.try_get::<i64, _>("attempts")? as i32,
+        next_attempt_at: row
+            .try_get::<Option<i64>, _>("next_attempt_at")?
+            .map(timestamp),
+        dialer_call_id: row.try_get("dialer_call_id")?,
+        notes: row.try_get("notes")?,
+        created_at: timestamp(row.try_get("created_at")?),
+        updated_at: timestamp(row.try_get("updated_at")?),
+    })
+}
+
+#[async_trait]
+impl CallbackStore for SqlCallbackStore {
+    async fn create(&self, callback: &Callback) -> Result<(), PersistenceError> {
+        self.write(callback).await?;
+
+        tracing::info!(
+            callback_id = %callback.callback_id,
+            customer_phone = %callback.customer_phone,
+            window_start = %callback.window_start,
+            "Callback created in SQL store"
+        );
+
+        Ok(())
+    }
+
+    async fn get(&self, callback_id: Uuid) -> Result<Option<Callback>, PersistenceError> {
+        let query = format!("SELECT {} FROM callbacks WHERE callback_id = $1", COLUMNS);
+
+        let row = sqlx::query(&query)
+            .bind(callback_id.to_string())
+            .fetch_optional(self.client.pool())
+            .await?;
+
+        row.as_ref().map(row_to_callback).transpose()
+    }
+
+    async fn update(&self, callback: &Callback) -> Result<(), PersistenceError> {
+        self.write(callback).await?;
+
+        tracing::info!(
+            callback_id = %callback.callback_id,
+            status = callback.status.as_str(),
+            agent = ?callback.assigned_agent,
+            "Callback updated"
+        );
+
+        Ok(())
+    }
+
+    async fn list_open(&self) -> Result<Vec<Callback>, PersistenceError> {
+        let query = format!(
+            "SELECT {} FROM callbacks WHERE status NOT IN ($1, $2, $3) ORDER BY window_start",
+            COLUMNS
+        );
+
+        let rows = sqlx::query(&query)
+            .bind(CallbackStatus::Completed.as_str())
+            .bind(CallbackStatus::Expired.as_str())
+            .bind(CallbackStatus::Cancelled.as_str())
+            .fetch_all(self.client.pool())
+            .await?;
+
+        rows.iter().map(row_to_callback).collect()
+    }
+}
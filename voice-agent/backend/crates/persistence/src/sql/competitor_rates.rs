@@ -0,0 +1,116 @@
+//! Competitor rate table using SQLite/Postgres
+
+use super::{timestamp, SqlClient};
+use crate::{CompetitorRate, CompetitorRateStore, PersistenceError};
+use async_trait::async_trait;
+use sqlx::any::AnyRow;
+use sqlx::Row;
+
+const COLUMNS: &str = "product, lender_id, lender_name, rate_min, rate_max, typical_rate,
+    processing_fee_percent, source, updated_at";
+
+/// SQL implementation of the competitor rate store
+#[derive(Clone)]
+pub struct SqlCompetitorRateStore {
+    client: SqlClient,
+}
+
+impl SqlCompetitorRateStore {
+    pub fn new(client: SqlClient) -> Self {
+        Self { client }
+    }
+}
+
+fn row_to_rate(row: &AnyRow) -> Result<CompetitorRate, PersistenceError> {
+    let lender_id: String = row.try_get("lender_id")?;
+    let lender_name: Option<String> = row.try_get("lender_name")?;
+    Ok(CompetitorRate {
+        lender_name: lender_name.unwrap_or_else(|| lender_id.clone()),
+        lender_id,
+        product: row.try_get("product")?,
+        rate_min: row.try_get("rate_min")?,
+        rate_max: row.try_get("rate_max")?,
+        typical_rate: row.try_get("typical_rate")?,
+        processing_fee_percent: row.try_get("processing_fee_percent")?,
+        source: row.try_get("source")?,
+        updated_at: timestamp(row.try_get("updated_at")?),
+    })
+}
+
+#[async_trait]
+impl CompetitorRateStore for SqlCompetitorRateStore {
+    async fn list(&self, product: Option<&str>) -> Result<Vec<CompetitorRate>, PersistenceError> {
+        let rows = match product {
+            Some(product) => {
+                let query = format!(
+                    "SELECT {} FROM competitor_rates WHERE product = $1 ORDER BY lender_id",
+                    COLUMNS
+                );
+                sqlx::query(&query)
+                    .bind(product)
+                    .fetch_all(self.client.pool())
+                    .await?
+            },
+            None => {
+                let query = format!(
+                    "SELECT {} FROM competitor_rates ORDER BY product, lender_id",
+                    COLUMNS
+                );
+                sqlx::query(&query).fetch_all(self.client.pool()).await?
+            },
+        };
+
+        rows.iter().map(row_to_rate).collect()
+    }
+
+    async fn upsert(&self, rate: &CompetitorRate) -> Result<(), PersistenceError> {
+        rate.validate()?;
+
+        let query = format!(
+            "INSERT INTO competitor_rates ({}) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+             ON CONFLICT (product, lender_id) DO UPDATE SET
+                lender_name = excluded.lender_name,
+                rate_min = excluded.rate_min,
+                rate_max = excluded.rate_max,
+                typical_rate = excluded.typical_rate,
+                processing_fee_percent = excluded.processing_fee_percent,
+                source = excluded.source,
+                updated_at = excluded.updated_at",
+            COLUMNS
+        );
+
+        sqlx::query(&query)
+            .bind(&rate.product)
+            .bind(&rate.lender_id)
+            .bind(&rate.lender_name)
+            .bind(rate.rate_min)
+            .bind(rate.rate_max)
+            .bind(rate.typical_rate)
+            .bind(rate.processing_fee_percent)
+            .bind(&rate.source)
+            .bind(rate.updated_at.timestamp_millis())
+            .execute(self.client.pool())
+            .await?;
+
+        tracing::info!(
+            lender_id = %rate.lender_id,
+            product = %rate.product,
+            rate_min = rate.rate_min,
+            rate_max = rate.rate_max,
+            "Competitor rate updated"
+        );
+
+        Ok(())
+    }
+
+    async fn remove(&self, product: &str, lender_id: &str) -> Result<bool, PersistenceError> {
+        let result =
+            sqlx::query("DELETE FROM competitor_rates WHERE product = $1 AND lender_id = $2")
+                .bind(product)
+                .bind(lender_id)
+                .execute(self.client.pool())
+                .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
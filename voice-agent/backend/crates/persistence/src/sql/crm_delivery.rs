@@ -0,0 +1,92 @@
+//! CRM lead delivery tracking using SQLite/Postgres
+
+use super::{timestamp, SqlClient};
+use crate::{CrmDelivery, CrmDeliveryStatus, CrmDeliveryStore, PersistenceError};
+use async_trait::async_trait;
+use sqlx::any::AnyRow;
+use sqlx::Row;
+
+/// SQL implementation of CRM delivery store
+#[derive(Clone)]
+pub struct SqlCrmDeliveryStore {
+    client: SqlClient,
+}
+
+impl SqlCrmDeliveryStore {
+    pub fn new(client: SqlClient) -> Self {
+        Self { client }
+    }
+}
+
+fn row_to_delivery(row: &AnyRow) -> Result<CrmDelivery, PersistenceError> {
+    Ok(CrmDelivery {
+        idempotency_key: row.try_get("idempotency_key")?,
+        connector: row.try_get("connector")?,
+        customer_phone: row.try_get("customer_phone")?,
+        payload_json: row.try_get("payload_json")?,
+        status: CrmDeliveryStatus::parse(row.try_get("status")?),
+        attempts: row.try_get::<i64, _>("attempts")? as i32,
+        last_error: row.try_get("last_error")?,
+        external_id: row.try_get("external_id")?,
+        created_at: timestamp(row.try_get("created_at")?),
+        updated_at: timestamp(row.try_get("updated_at")?),
+    })
+}
+
+#[async_trait]
+impl CrmDeliveryStore for SqlCrmDeliveryStore {
+    async fn upsert(&self, delivery: &CrmDelivery) -> Result<(), PersistenceError> {
+        sqlx::query(
+            "INSERT INTO crm_deliveries (
+                idempotency_key, connector, customer_phone, payload_json,
+                status, attempts, last_error, external_id,
+                created_at, updated_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (idempotency_key) DO UPDATE SET
+                connector = excluded.connector,
+                customer_phone = excluded.customer_phone,
+                payload_json = excluded.payload_json,
+                status = excluded.status,
+                attempts = excluded.attempts,
+                last_error = excluded.last_error,
+                external_id = excluded.external_id,
+                created_at = excluded.created_at,
+                updated_at = excluded.updated_at",
+        )
+        .bind(&delivery.idempotency_key)
+        .bind(&delivery.connector)
+        .bind(&delivery.customer_phone)
+        .bind(&delivery.payload_json)
+        .bind(delivery.status.as_str())
+        .bind(delivery.attempts as i64)
+        .bind(&delivery.last_error)
+        .bind(&delivery.external_id)
+        .bind(delivery.created_at.timestamp_millis())
+        .bind(delivery.updated_at.timestamp_millis())
+        .execute(self.client.pool())
+        .await?;
+
+        tracing::debug!(
+            idempotency_key = %delivery.idempotency_key,
+            status = delivery.status.as_str(),
+            attempts = delivery.attempts,
+            "CRM delivery status persisted"
+        );
+
+        Ok(())
+    }
+
+    async fn get(&self, idempotency_key: &str) -> Result<Option<CrmDelivery>, PersistenceError> {
+        let row = sqlx::query(
+            "SELECT idempotency_key, connector, customer_phone, payload_json,
+                    status, attempts, last_error, external_id,
+                    created_at, updated_at
+             FROM crm_deliveries WHERE idempotency_key = $1",
+        )
+        .bind(idempotency_key)
+        .fetch_optional(self.client.pool())
+        .await?;
+
+        row.as_ref().map(row_to_delivery).transpose()
+    }
+}
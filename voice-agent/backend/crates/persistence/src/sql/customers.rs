@@ -0,0 +1,109 @@
+//! Customer identity persistence using SQLite/Postgres
+
+use super::{timestamp, SqlClient};
+use crate::{CustomerIdentityStore, PersistenceError};
+use async_trait::async_trait;
+use sqlx::any::AnyRow;
+use sqlx::Row;
+use std::collections::HashMap;
+use voice_agent_core::{Channel, CustomerIdentity};
+
+/// SQL implementation of customer identity store
+#[derive(Clone)]
+pub struct SqlCustomerIdentityStore {
+    client: SqlClient,
+}
+
+impl SqlCustomerIdentityStore {
+    pub fn new(client: SqlClient) -> Self {
+        Self { client }
+    }
+}
+
+fn row_to_identity(row: &AnyRow) -> Result<CustomerIdentity, PersistenceError> {
+    let facts_json: Option<String> = row.try_get("facts_json")?;
+    let session_ids_json: Option<String> = row.try_get("session_ids_json")?;
+    let last_channel: Option<String> = row.try_get("last_channel")?;
+
+    let facts: HashMap<String, String> = match facts_json {
+        Some(json) => serde_json::from_str(&json)?,
+        None => HashMap::new(),
+    };
+    let session_ids: Vec<String> = match session_ids_json {
+        Some(json) => serde_json::from_str(&json)?,
+        None => Vec::new(),
+    };
+
+    Ok(CustomerIdentity {
+        customer_id: row.try_get("customer_id")?,
+        name: row.try_get("name")?,
+        preferred_language: row.try_get("preferred_language")?,
+        segment: row.try_get("segment")?,
+        facts,
+        last_goal: row.try_get("last_goal")?,
+        last_channel: last_channel.as_deref().and_then(Channel::parse),
+        session_ids,
+        first_seen: timestamp(row.try_get("first_seen")?),
+        last_seen: timestamp(row.try_get("last_seen")?),
+    })
+}
+
+#[async_trait]
+impl CustomerIdentityStore for SqlCustomerIdentityStore {
+    async fn get(&self, customer_id: &str) -> Result<Option<CustomerIdentity>, PersistenceError> {
+        let row = sqlx::query(
+            "SELECT customer_id, name, preferred_language, segment,
+                    facts_json, last_goal, last_channel, session_ids_json,
+                    first_seen, last_seen
+             FROM customer_identities WHERE customer_id = $1",
+        )
+        .bind(customer_id)
+        .fetch_optional(self.client.pool())
+        .await?;
+
+        row.as_ref().map(row_to_identity).transpose()
+    }
+
+    async fn upsert(&self, identity: &CustomerIdentity) -> Result<(), PersistenceError> {
+        let facts_json = serde_json::to_string(&identity.facts)?;
+        let session_ids_json = serde_json::to_string(&identity.session_ids)?;
+
+        sqlx::query(
+            "INSERT INTO customer_identities (
+                customer_id, name, preferred_language, segment,
+                facts_json, last_goal, last_channel, session_ids_json,
+                first_seen, last_seen
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (customer_id) DO UPDATE SET
+                name = excluded.name,
+                preferred_language = excluded.preferred_language,
+                segment = excluded.segment,
+                facts_json = excluded.facts_json,
+                last_goal = excluded.last_goal,
+                last_channel = excluded.last_channel,
+                session_ids_json = excluded.session_ids_json,
+                first_seen = excluded.first_seen,
+                last_seen = excluded.last_seen",
+        )
+        .bind(&identity.customer_id)
+        .bind(&identity.name)
+        .bind(&identity.preferred_language)
+        .bind(&identity.segment)
+        .bind(facts_json)
+        .bind(&identity.last_goal)
+        .bind(identity.last_channel.map(|c| c.as_str()))
+        .bind(session_ids_json)
+        .bind(identity.first_seen.timestamp_millis())
+        .bind(identity.last_seen.timestamp_millis())
+        .execute(self.client.pool())
+        .await?;
+
+        tracing::debug!(
+            sessions = identity.session_count(),
+            facts = identity.facts.len(),
+            "Customer identity persisted"
+        );
+
+        Ok(())
+    }
+}
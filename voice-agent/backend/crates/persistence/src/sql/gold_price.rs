@@ -0,0 +1,183 @@
+//! Simulated asset prices with SQLite/Postgres persistence
+
+use super::{timestamp, SqlClient};
+use crate::gold_price::simulate_price;
+use crate::{
+    bucket_prices, AssetPrice, AssetPriceService, PersistenceError, PriceBucket, PricePoint,
+    TierDefinition,
+};
+use async_trait::async_trait;
+use chrono::{NaiveDate, Timelike, Utc};
+use sqlx::any::AnyRow;
+use sqlx::Row;
+use std::collections::HashMap;
+
+/// Simulated asset price service that persists to SQL
+///
+/// Same simulation as [`crate::SimulatedAssetPriceService`].
+#[derive(Clone)]
+pub struct SqlAssetPriceService {
+    client: SqlClient,
+    base_price: f64,
+    tiers: Vec<TierDefinition>,
+    fluctuation_percent: f64,
+    cache_ttl_seconds: i64,
+}
+
+impl SqlAssetPriceService {
+    pub fn new(client: SqlClient, base_price: f64, tiers: Vec<TierDefinition>) -> Self {
+        Self {
+            client,
+            base_price,
+            tiers,
+            fluctuation_percent: 2.0, // ±2% daily fluctuation
+            cache_ttl_seconds: 300,   // 5 minute cache
+        }
+    }
+
+    /// Set custom fluctuation percentage
+    pub fn with_fluctuation(mut self, percent: f64) -> Self {
+        self.fluctuation_percent = percent;
+        self
+    }
+
+    /// Set custom cache TTL
+    pub fn with_cache_ttl(mut self, seconds: i64) -> Self {
+        self.cache_ttl_seconds = seconds;
+        self
+    }
+
+    async fn get_cached_price(&self) -> Result<Option<AssetPrice>, PersistenceError> {
+        let row = sqlx::query(
+            "SELECT base_price, tier_prices_json, updated_at AS at, source
+             FROM asset_price_latest WHERE singleton = 1",
+        )
+        .fetch_optional(self.client.pool())
+        .await?;
+
+        row.as_ref().map(row_to_price).transpose()
+    }
+
+    async fn store_price(&self, price: &AssetPrice) -> Result<(), PersistenceError> {
+        let tier_prices_json = serde_json::to_string(&price.tier_prices)?;
+        let now = Utc::now();
+
+        let mut tx = self.client.pool().begin().await?;
+        sqlx::query(
+            "INSERT INTO asset_price_latest (
+                singleton, base_price, tier_prices_json, updated_at, source
+            ) VALUES (1, $1, $2, $3, $4)
+            ON CONFLICT (singleton) DO UPDATE SET
+                base_price = excluded.base_price,
+                tier_prices_json = excluded.tier_prices_json,
+                updated_at = excluded.updated_at,
+                source = excluded.source",
+        )
+        .bind(price.base_price_per_unit)
+        .bind(&tier_prices_json)
+        .bind(price.updated_at.timestamp_millis())
+        .bind(&price.source)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "INSERT INTO asset_prices (
+                date, hour, base_price, tier_prices_json, source, created_at
+            ) VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (date, hour) DO UPDATE SET
+                base_price = excluded.base_price,
+                tier_prices_json = excluded.tier_prices_json,
+                source = excluded.source,
+                created_at = excluded.created_at",
+        )
+        .bind(now.date_naive().to_string())
+        .bind(now.hour() as i64)
+        .bind(price.base_price_per_unit)
+        .bind(&tier_prices_json)
+        .bind(&price.source)
+        .bind(now.timestamp_millis())
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
+
+fn row_to_price(row: &AnyRow) -> Result<AssetPrice, PersistenceError> {
+    let tier_prices_json: String = row.try_get("tier_prices_json")?;
+    let tier_prices: HashMap<String, f64> = serde_json::from_str(&tier_prices_json)?;
+
+    Ok(AssetPrice {
+        base_price_per_unit: row.try_get("base_price")?,
+        tier_prices,
+        source: row.try_get("source")?,
+        updated_at: timestamp(row.try_get("at")?),
+    })
+}
+
+#[async_trait]
+impl AssetPriceService for SqlAssetPriceService {
+    async fn get_current_price(&self) -> Result<AssetPrice, PersistenceError> {
+        if let Some(cached) = self.get_cached_price().await? {
+            let age = Utc::now() - cached.updated_at;
+            if age.num_seconds() < self.cache_ttl_seconds {
+                return Ok(cached);
+            }
+        }
+
+        self.refresh_price().await
+    }
+
+    async fn get_historical_price(
+        &self,
+        date: NaiveDate,
+    ) -> Result<Option<AssetPrice>, PersistenceError> {
+        let row = sqlx::query(
+            "SELECT base_price, tier_prices_json, source, created_at AS at
+             FROM asset_prices WHERE date = $1 ORDER BY hour DESC LIMIT 1",
+        )
+        .bind(date.to_string())
+        .fetch_optional(self.client.pool())
+        .await?;
+
+        row.as_ref().map(row_to_price).transpose()
+    }
+
+    async fn get_history(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        bucket: PriceBucket,
+        tier: Option<&str>,
+    ) -> Result<Vec<PricePoint>, PersistenceError> {
+        // ISO dates compare correctly as text
+        let rows = sqlx::query(
+            "SELECT base_price, tier_prices_json, source, created_at AS at
+             FROM asset_prices WHERE date >= $1 AND date <= $2 ORDER BY date, hour",
+        )
+        .bind(from.to_string())
+        .bind(to.to_string())
+        .fetch_all(self.client.pool())
+        .await?;
+
+        let prices = rows
+            .iter()
+            .map(row_to_price)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(bucket_prices(&prices, bucket, tier))
+    }
+
+    async fn refresh_price(&self) -> Result<AssetPrice, PersistenceError> {
+        let price = simulate_price(self.base_price, &self.tiers, self.fluctuation_percent);
+        self.store_price(&price).await?;
+
+        tracing::info!(
+            base_price = price.base_price_per_unit,
+            tier_count = price.tier_prices.len(),
+            "Generated new simulated asset price"
+        );
+
+        Ok(price)
+    }
+}
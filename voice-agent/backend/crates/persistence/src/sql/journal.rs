@@ -0,0 +1,82 @@
+//! Session journal using SQLite/Postgres
+
+use super::{timestamp, SqlClient};
+use crate::{JournalEntry, JournalEventKind, PersistenceError, SessionJournal};
+use async_trait::async_trait;
+use sqlx::any::AnyRow;
+use sqlx::Row;
+
+/// SQL implementation of the session journal
+#[derive(Clone)]
+pub struct SqlSessionJournal {
+    client: SqlClient,
+}
+
+impl SqlSessionJournal {
+    pub fn new(client: SqlClient) -> Self {
+        Self { client }
+    }
+}
+
+/// Entry for a row, or `None` for an event kind this build does not know
+fn row_to_entry(row: &AnyRow) -> Result<Option<JournalEntry>, PersistenceError> {
+    let kind: String = row.try_get("kind")?;
+    let Some(kind) = JournalEventKind::parse(&kind) else {
+        return Ok(None);
+    };
+    Ok(Some(JournalEntry {
+        session_id: row.try_get("session_id")?,
+        seq: row.try_get("seq")?,
+        kind,
+        payload_json: row.try_get("payload_json")?,
+        recorded_at: timestamp(row.try_get("recorded_at")?),
+    }))
+}
+
+#[async_trait]
+impl SessionJournal for SqlSessionJournal {
+    async fn append(&self, entries: &[JournalEntry]) -> Result<(), PersistenceError> {
+        let mut tx = self.client.pool().begin().await?;
+        for entry in entries {
+            sqlx::query(
+                "INSERT INTO session_journal (
+                    session_id, seq, kind, payload_json, recorded_at
+                ) VALUES ($1, $2, $3, $4, $5)
+                ON CONFLICT (session_id, seq) DO NOTHING",
+            )
+            .bind(&entry.session_id)
+            .bind(entry.seq)
+            .bind(entry.kind.as_str())
+            .bind(&entry.payload_json)
+            .bind(entry.recorded_at.timestamp_millis())
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn entries(&self, session_id: &str) -> Result<Vec<JournalEntry>, PersistenceError> {
+        let rows = sqlx::query(
+            "SELECT session_id, seq, kind, payload_json, recorded_at
+             FROM session_journal WHERE session_id = $1 ORDER BY seq",
+        )
+        .bind(session_id)
+        .fetch_all(self.client.pool())
+        .await?;
+
+        let mut entries = Vec::with_capacity(rows.len());
+        for row in &rows {
+            entries.extend(row_to_entry(row)?);
+        }
+        Ok(entries)
+    }
+
+    async fn truncate(&self, session_id: &str) -> Result<(), PersistenceError> {
+        sqlx::query("DELETE FROM session_journal WHERE session_id = $1")
+            .bind(session_id)
+            .execute(self.client.pool())
+            .await?;
+        Ok(())
+    }
+}
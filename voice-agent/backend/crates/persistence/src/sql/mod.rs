@@ -0,0 +1,211 @@
+//! SQLite/Postgres persistence backend
+//!
+//! Implements every store trait on top of sqlx for deployments that do not
+//! run ScyllaDB: SQLite for a single node, Postgres for managed cloud
+//! databases. Enabled by the `sqlite` and `postgres` cargo features.
+//!
+//! Queries are written once against sqlx's `Any` driver with `$N`
+//! placeholders, which both databases accept; only the DDL differs (see
+//! [`crate::schema::sql_tables`]). Timestamps are stored as epoch
+//! milliseconds and UUIDs as text, matching the ScyllaDB tables.
+
+pub mod analytics;
+pub mod appointments;
+pub mod archival;
+pub mod audit;
+pub mod callbacks;
+pub mod competitor_rates;
+pub mod crm_delivery;
+pub mod customers;
+pub mod gold_price;
+pub mod journal;
+pub mod offers;
+pub mod outbox;
+pub mod price_alerts;
+pub mod rate_cards;
+pub mod sessions;
+pub mod slots;
+pub mod sms;
+pub mod usage;
+pub mod webhooks;
+
+pub use analytics::SqlAnalyticsStore;
+pub use appointments::SqlAppointmentStore;
+pub use archival::SqlArchivalStore;
+pub use audit::SqlAuditLog;
+pub use callbacks::SqlCallbackStore;
+pub use competitor_rates::SqlCompetitorRateStore;
+pub use crm_delivery::SqlCrmDeliveryStore;
+pub use customers::SqlCustomerIdentityStore;
+pub use gold_price::SqlAssetPriceService;
+pub use journal::SqlSessionJournal;
+pub use offers::SqlOfferEventStore;
+pub use outbox::SqlOutboxStore;
+pub use price_alerts::SqlPriceAlertStore;
+pub use rate_cards::SqlRateCardStore;
+pub use sessions::SqlSessionStore;
+pub use slots::SqlSlotStore;
+pub use sms::SqlSmsService;
+pub use usage::SqlUsageStore;
+pub use webhooks::SqlWebhookDeliveryStore;
+
+use crate::schema::{self, SqlDialect};
+use crate::PersistenceError;
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::any::{AnyPool, AnyPoolOptions};
+use uuid::Uuid;
+
+/// SQLite/Postgres configuration
+#[derive(Debug, Clone)]
+pub struct SqlConfig {
+    /// Connection URL (`sqlite://...` or `postgres://...`)
+    pub url: String,
+    pub max_connections: u32,
+}
+
+impl Default for SqlConfig {
+    fn default() -> Self {
+        Self {
+            url: std::env::var("DATABASE_URL")
+                .unwrap_or_else(|_| "sqlite://voice_agent.db?mode=rwc".to_string()),
+            max_connections: 10,
+        }
+    }
+}
+
+/// SQLite/Postgres connection pool
+#[derive(Clone)]
+pub struct SqlClient {
+    pool: AnyPool,
+    dialect: SqlDialect,
+}
+
+impl SqlClient {
+    /// Connect to the database named by the URL
+    pub async fn connect(config: &SqlConfig) -> Result<Self, PersistenceError> {
+        let dialect = SqlDialect::from_url(&config.url).ok_or_else(|| {
+            PersistenceError::Connection(format!("unsupported database URL: {}", config.url))
+        })?;
+        tracing::info!(dialect = ?dialect, "Connecting to SQL database");
+
+        sqlx::any::install_default_drivers();
+        let pool = AnyPoolOptions::new()
+            .max_connections(config.max_connections.max(1))
+            .connect(&config.url)
+            .await?;
+
+        Ok(Self { pool, dialect })
+    }
+
+    /// Ensure tables and indexes exist
+    pub async fn ensure_schema(&self) -> Result<(), PersistenceError> {
+        for ddl in schema::sql_tables(self.dialect) {
+            sqlx::query(&ddl)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| PersistenceError::SchemaError(format!("{}: {}", ddl, e)))?;
+        }
+        tracing::info!(dialect = ?self.dialect, "SQL schema ensured");
+        Ok(())
+    }
+
+    /// Get the connection pool
+    pub fn pool(&self) -> &AnyPool {
+        &self.pool
+    }
+
+    pub fn dialect(&self) -> SqlDialect {
+        self.dialect
+    }
+}
+
+/// Epoch milliseconds to a timestamp, like the ScyllaDB stores
+pub(crate) fn timestamp(millis: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp_millis(millis).unwrap_or_else(Utc::now)
+}
+
+pub(crate) fn parse_uuid(value: &str) -> Result<Uuid, PersistenceError> {
+    Uuid::parse_str(value).map_err(|e| PersistenceError::InvalidData(e.to_string()))
+}
+
+pub(crate) fn parse_date(value: &str) -> Result<NaiveDate, PersistenceError> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|e| PersistenceError::InvalidData(e.to_string()))
+}
+
+#[cfg(all(test, feature = "sqlite"))]
+mod tests {
+    use super::*;
+    use crate::{SessionData, SessionStore, SlotConfirmation, SlotStore};
+
+    async fn memory_client() -> SqlClient {
+        let client = SqlClient::connect(&SqlConfig {
+            url: "sqlite::memory:".to_string(),
+            max_connections: 1,
+        })
+        .await
+        .unwrap();
+        client.ensure_schema().await.unwrap();
+        client
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_session_round_trip() {
+        let store = SqlSessionStore::new(memory_client().await);
+        let mut session = SessionData::new("sql-1");
+        session.customer_phone = Some("9876543210".to_string());
+        store.create(&session).await.unwrap();
+
+        session.turn_count = 3;
+        store.update(&session).await.unwrap();
+        let loaded = store.get("sql-1").await.unwrap().unwrap();
+        assert_eq!(loaded.turn_count, 3);
+        assert_eq!(loaded.customer_phone.as_deref(), Some("9876543210"));
+        assert_eq!(store.list_active(10).await.unwrap().len(), 1);
+
+        store.delete("sql-1").await.unwrap();
+        assert!(store.get("sql-1").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_purges_expired_sessions() {
+        let store = SqlSessionStore::new(memory_client().await);
+        let mut expired = SessionData::new("sql-old");
+        expired.expires_at = chrono::Utc::now() - chrono::Duration::hours(1);
+        store.create(&expired).await.unwrap();
+        store.create(&SessionData::new("sql-new")).await.unwrap();
+
+        assert_eq!(store.purge_expired().await.unwrap(), 1);
+        assert!(store.get("sql-old").await.unwrap().is_none());
+        assert!(store.get("sql-new").await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_slot_capacity() {
+        let store = SqlSlotStore::new(memory_client().await);
+        let date = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let ttl = chrono::Duration::minutes(5);
+
+        let first = store
+            .hold("b1", date, "10:00", "s1", 1, ttl)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(store
+            .hold("b1", date, "10:00", "s2", 1, ttl)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(matches!(
+            store.confirm(first.hold_id, 1).await.unwrap(),
+            SlotConfirmation::Confirmed(_)
+        ));
+
+        let slots = store
+            .availability("b1", date, &["10:00".to_string()], 1)
+            .await
+            .unwrap();
+        assert_eq!(slots[0].booked, 1);
+        assert_eq!(slots[0].remaining(), 0);
+    }
+}
@@ -0,0 +1,78 @@
+//! Offer events using SQLite/Postgres
+
+use super::{timestamp, SqlClient};
+use crate::{OfferEvent, OfferEventKind, OfferEventStore, PersistenceError};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::any::AnyRow;
+use sqlx::Row;
+
+const COLUMNS: &str = "session_id, offer_id, kind, day, created_at";
+
+/// SQL implementation of the offer event store
+#[derive(Clone)]
+pub struct SqlOfferEventStore {
+    client: SqlClient,
+}
+
+impl SqlOfferEventStore {
+    pub fn new(client: SqlClient) -> Self {
+        Self { client }
+    }
+}
+
+fn row_to_event(row: &AnyRow) -> Result<OfferEvent, PersistenceError> {
+    let kind: String = row.try_get("kind")?;
+    Ok(OfferEvent {
+        session_id: row.try_get("session_id")?,
+        offer_id: row.try_get("offer_id")?,
+        kind: OfferEventKind::parse(&kind),
+        created_at: timestamp(row.try_get("created_at")?),
+    })
+}
+
+#[async_trait]
+impl OfferEventStore for SqlOfferEventStore {
+    async fn record(&self, event: &OfferEvent) -> Result<bool, PersistenceError> {
+        event.validate()?;
+
+        let query = format!(
+            "INSERT INTO offer_events ({}) VALUES ($1, $2, $3, $4, $5)
+             ON CONFLICT (session_id, offer_id, kind) DO NOTHING",
+            COLUMNS
+        );
+        let result = sqlx::query(&query)
+            .bind(&event.session_id)
+            .bind(&event.offer_id)
+            .bind(event.kind.as_str())
+            .bind(event.day().to_string())
+            .bind(event.created_at.timestamp_millis())
+            .execute(self.client.pool())
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn session_events(&self, session_id: &str) -> Result<Vec<OfferEvent>, PersistenceError> {
+        let query = format!(
+            "SELECT {} FROM offer_events WHERE session_id = $1 ORDER BY created_at",
+            COLUMNS
+        );
+        let rows = sqlx::query(&query)
+            .bind(session_id)
+            .fetch_all(self.client.pool())
+            .await?;
+
+        rows.iter().map(row_to_event).collect()
+    }
+
+    async fn events_for_day(&self, day: NaiveDate) -> Result<Vec<OfferEvent>, PersistenceError> {
+        let query = format!("SELECT {} FROM offer_events WHERE day = $1", COLUMNS);
+        let rows = sqlx::query(&query)
+            .bind(day.to_string())
+            .fetch_all(self.client.pool())
+            .await?;
+
+        rows.iter().map(row_to_event).collect()
+    }
+}
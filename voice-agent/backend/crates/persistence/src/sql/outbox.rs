@@ -0,0 +1,121 @@
+//! Outbox of tool side-effects using SQLite/Postgres
+
+use super::{timestamp, SqlClient};
+use crate::{OutboxEntry, OutboxKind, OutboxStatus, OutboxStore, PersistenceError};
+use async_trait::async_trait;
+use sqlx::any::AnyRow;
+use sqlx::Row;
+
+const COLUMNS: &str = "idempotency_key, kind, session_id, payload_json, status, attempts,
+    next_attempt_at, last_error, external_id, created_at, updated_at";
+
+/// SQL implementation of the outbox store
+///
+/// Pending entries are found through the status index instead of a separate
+/// pending-queue table.
+#[derive(Clone)]
+pub struct SqlOutboxStore {
+    client: SqlClient,
+}
+
+impl SqlOutboxStore {
+    pub fn new(client: SqlClient) -> Self {
+        Self { client }
+    }
+
+    async fn write(&self, entry: &OutboxEntry) -> Result<(), PersistenceError> {
+        let query = format!(
+            "INSERT INTO outbox ({}) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+             ON CONFLICT (idempotency_key) DO UPDATE SET
+                kind = excluded.kind,
+                session_id = excluded.session_id,
+                payload_json = excluded.payload_json,
+                status = excluded.status,
+                attempts = excluded.attempts,
+                next_attempt_at = excluded.next_attempt_at,
+                last_error = excluded.last_error,
+                external_id = excluded.external_id,
+                created_at = excluded.created_at,
+                updated_at = excluded.updated_at",
+            COLUMNS
+        );
+
+        sqlx::query(&query)
+            .bind(&entry.idempotency_key)
+            .bind(entry.kind.as_str())
+            .bind(&entry.session_id)
+            .bind(&entry.payload_json)
+            .bind(entry.status.as_str())
+            .bind(entry.attempts as i64)
+            .bind(entry.next_attempt_at.timestamp_millis())
+            .bind(&entry.last_error)
+            .bind(&entry.external_id)
+            .bind(entry.created_at.timestamp_millis())
+            .bind(entry.updated_at.timestamp_millis())
+            .execute(self.client.pool())
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn row_to_entry(row: &AnyRow) -> Result<OutboxEntry, PersistenceError> {
+    Ok(OutboxEntry {
+        idempotency_key: row.try_get("idempotency_key")?,
+        kind: OutboxKind::parse(row.try_get("kind")?),
+        session_id: row.try_get("session_id")?,
+        payload_json: row.try_get("payload_json")?,
+        status: OutboxStatus::parse(row.try_get("status")?),
+        attempts: row.try_get::<i64, _>("attempts")? as i32,
+        next_attempt_at: timestamp(row.try_get("next_attempt_at")?),
+        last_error: row.try_get("last_error")?,
+        external_id: row.try_get("external_id")?,
+        created_at: timestamp(row.try_get("created_at")?),
+        updated_at: timestamp(row.try_get("updated_at")?),
+    })
+}
+
+#[async_trait]
+impl OutboxStore for SqlOutboxStore {
+    async fn create(&self, entry: &OutboxEntry) -> Result<(), PersistenceError> {
+        self.write(entry).await?;
+
+        tracing::debug!(
+            idempotency_key = %entry.idempotency_key,
+            kind = entry.kind.as_str(),
+            "Outbox entry recorded in SQL store"
+        );
+
+        Ok(())
+    }
+
+    async fn get(&self, idempotency_key: &str) -> Result<Option<OutboxEntry>, PersistenceError> {
+        let query = format!("SELECT {} FROM outbox WHERE idempotency_key = $1", COLUMNS);
+
+        let row = sqlx::query(&query)
+            .bind(idempotency_key)
+            .fetch_optional(self.client.pool())
+            .await?;
+
+        row.as_ref().map(row_to_entry).transpose()
+    }
+
+    async fn update(&self, entry: &OutboxEntry) -> Result<(), PersistenceError> {
+        self.write(entry).await
+    }
+
+    async fn list_pending(&self) -> Result<Vec<OutboxEntry>, PersistenceError> {
+        let query = format!(
+            "SELECT {} FROM outbox WHERE status IN ($1, $2) ORDER BY next_attempt_at",
+            COLUMNS
+        );
+
+        let rows = sqlx::query(&query)
+            .bind(OutboxStatus::Pending.as_str())
+            .bind(OutboxStatus::Retrying.as_str())
+            .fetch_all(self.client.pool())
+            .await?;
+
+        rows.iter().map(row_to_entry).collect()
+    }
+}
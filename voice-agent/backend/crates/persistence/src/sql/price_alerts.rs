@@ -0,0 +1,144 @@
+//! Price alert subscriptions using SQLite/Postgres
+
+use super::{parse_uuid, timestamp, SqlClient};
+use crate::{AlertDirection, PersistenceError, PriceAlert, PriceAlertStatus, PriceAlertStore};
+use async_trait::async_trait;
+use sqlx::any::AnyRow;
+use sqlx::Row;
+use uuid::Uuid;
+
+const COLUMNS: &str = "alert_id, session_id, customer_phone, customer_name, tier,
+    threshold, direction, status, expires_at, triggered_at,
+    triggered_price, sms_id, created_at, updated_at";
+
+/// SQL implementation of the price alert store
+///
+/// Active alerts are found through the status index instead of a separate
+/// active-queue table.
+#[derive(Clone)]
+pub struct SqlPriceAlertStore {
+    client: SqlClient,
+}
+
+impl SqlPriceAlertStore {
+    pub fn new(client: SqlClient) -> Self {
+        Self { client }
+    }
+
+    async fn write(&self, alert: &PriceAlert) -> Result<(), PersistenceError> {
+        let query = format!(
+            "INSERT INTO price_alerts ({}) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+             ON CONFLICT (alert_id) DO UPDATE SET
+                session_id = excluded.session_id,
+                customer_phone = excluded.customer_phone,
+                customer_name = excluded.customer_name,
+                tier = excluded.tier,
+                threshold = excluded.threshold,
+                direction = excluded.direction,
+                status = excluded.status,
+                expires_at = excluded.expires_at,
+                triggered_at = excluded.triggered_at,
+                triggered_price = excluded.triggered_price,
+                sms_id = excluded.sms_id,
+                created_at = excluded.created_at,
+                updated_at = excluded.updated_at",
+            COLUMNS
+        );
+
+        sqlx::query(&query)
+            .bind(alert.alert_id.to_string())
+            .bind(&alert.session_id)
+            .bind(&alert.customer_phone)
+            .bind(&alert.customer_name)
+            .bind(&alert.tier)
+            .bind(alert.threshold)
+            .bind(alert.direction.as_str())
+            .bind(alert.status.as_str())
+            .bind(alert.expires_at.timestamp_millis())
+            .bind(alert.triggered_at.map(|t| t.timestamp_millis()))
+            .bind(alert.triggered_price)
+            .bind(alert.sms_id.map(|id| id.to_string()))
+            .bind(alert.created_at.timestamp_millis())
+            .bind(alert.updated_at.timestamp_millis())
+            .execute(self.client.pool())
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn row_to_alert(row: &AnyRow) -> Result<PriceAlert, PersistenceError> {
+    let sms_id: Option<&str> = row.try_get("sms_id")?;
+    Ok(PriceAlert {
+        alert_id: parse_uuid(row.try_get("alert_id")?)?,
+        session_id: row.try_get("session_id")?,
+        customer_phone: row.try_get("customer_phone")?,
+        customer_name: row.try_get("customer_name")?,
+        tier: row.try_get("tier")?,
+        threshold: row.try_get("threshold")?,
+        direction: AlertDirection::parse(row.try_get("direction")?),
+        status: PriceAlertStatus::parse(row.try_get("status")?),
+        expires_at: timestamp(row.try_get("expires_at")?),
+        triggered_at: row
+            .try_get::<Option<i64>, _>("triggered_at")?
+            .map(timestamp),
+        triggered_price: row.try_get("triggered_price")?,
+        sms_id: sms_id.map(parse_uuid).transpose()?,
+        created_at: timestamp(row.try_get("created_at")?),
+        updated_at: timestamp(row.try_get("updated_at")?),
+    })
+}
+
+#[async_trait]
+impl PriceAlertStore for SqlPriceAlertStore {
+    async fn create(&self, alert: &PriceAlert) -> Result<(), PersistenceError> {
+        self.write(alert).await?;
+
+        tracing::info!(
+            alert_id = %alert.alert_id,
+            customer_phone = %alert.customer_phone,
+            threshold = alert.threshold,
+            direction = alert.direction.as_str(),
+            "Price alert created in SQL store"
+        );
+
+        Ok(())
+    }
+
+    async fn get(&self, alert_id: Uuid) -> Result<Option<PriceAlert>, PersistenceError> {
+        let query = format!("SELECT {} FROM price_alerts WHERE alert_id = $1", COLUMNS);
+
+        let row = sqlx::query(&query)
+            .bind(alert_id.to_string())
+            .fetch_optional(self.client.pool())
+            .await?;
+
+        row.as_ref().map(row_to_alert).transpose()
+    }
+
+    async fn update(&self, alert: &PriceAlert) -> Result<(), PersistenceError> {
+        self.write(alert).await?;
+
+        tracing::info!(
+            alert_id = %alert.alert_id,
+            status = alert.status.as_str(),
+            "Price alert updated"
+        );
+
+        Ok(())
+    }
+
+    async fn list_active(&self) -> Result<Vec<PriceAlert>, PersistenceError> {
+        let query = format!(
+            "SELECT {} FROM price_alerts WHERE status = $1 ORDER BY created_at",
+            COLUMNS
+        );
+
+        let rows = sqlx::query(&query)
+            .bind(PriceAlertStatus::Active.as_str())
+            .fetch_all(self.client.pool())
+            .await?;
+
+        rows.iter().map(row_to_alert).collect()
+    }
+}
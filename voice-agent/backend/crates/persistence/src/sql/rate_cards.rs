@@ -0,0 +1,81 @@
+//! Published rate cards using SQLite/Postgres
+
+use super::{parse_date, timestamp, SqlClient};
+use crate::{PersistenceError, PublishedRateCard, RateCardStore};
+use async_trait::async_trait;
+use sqlx::any::AnyRow;
+use sqlx::Row;
+
+const COLUMNS: &str = "product, version, effective_from, card_json, published_by, published_at";
+
+/// SQL implementation of the rate card store
+#[derive(Clone)]
+pub struct SqlRateCardStore {
+    client: SqlClient,
+}
+
+impl SqlRateCardStore {
+    pub fn new(client: SqlClient) -> Self {
+        Self { client }
+    }
+}
+
+fn row_to_card(row: &AnyRow) -> Result<PublishedRateCard, PersistenceError> {
+    let effective_from: String = row.try_get("effective_from")?;
+    Ok(PublishedRateCard {
+        product: row.try_get("product")?,
+        version: row.try_get("version")?,
+        effective_from: parse_date(&effective_from)?,
+        card_json: row.try_get("card_json")?,
+        published_by: row.try_get("published_by")?,
+        published_at: timestamp(row.try_get("published_at")?),
+    })
+}
+
+#[async_trait]
+impl RateCardStore for SqlRateCardStore {
+    async fn list(&self, product: &str) -> Result<Vec<PublishedRateCard>, PersistenceError> {
+        let query = format!(
+            "SELECT {} FROM rate_cards WHERE product = $1 ORDER BY effective_from, version",
+            COLUMNS
+        );
+        let rows = sqlx::query(&query)
+            .bind(product)
+            .fetch_all(self.client.pool())
+            .await?;
+
+        rows.iter().map(row_to_card).collect()
+    }
+
+    async fn publish(&self, card: &PublishedRateCard) -> Result<bool, PersistenceError> {
+        card.validate()?;
+
+        let query = format!(
+            "INSERT INTO rate_cards ({}) VALUES ($1, $2, $3, $4, $5, $6)
+             ON CONFLICT (product, version) DO NOTHING",
+            COLUMNS
+        );
+
+        let result = sqlx::query(&query)
+            .bind(&card.product)
+            .bind(&card.version)
+            .bind(card.effective_from.to_string())
+            .bind(&card.card_json)
+            .bind(&card.published_by)
+            .bind(card.published_at.timestamp_millis())
+            .execute(self.client.pool())
+            .await?;
+
+        let applied = result.rows_affected() > 0;
+        if applied {
+            tracing::info!(
+                product = %card.product,
+                version = %card.version,
+                effective_from = %card.effective_from,
+                "Rate card published"
+            );
+        }
+
+        Ok(applied)
+    }
+}
@@ -0,0 +1,185 @@
+//! Session persistence using SQLite/Postgres
+
+use super::{timestamp, SqlClient};
+use crate::{PersistenceError, SessionData, SessionStore};
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use sqlx::any::AnyRow;
+use sqlx::Row;
+
+const COLUMNS: &str = "session_id, created_at, updated_at, expires_at,
+    customer_phone, customer_name, customer_segment,
+    language, conversation_stage, turn_count,
+    memory_json, metadata_json, lead_score, lead_qualification";
+
+/// SQL implementation of session store
+#[derive(Clone)]
+pub struct SqlSessionStore {
+    client: SqlClient,
+}
+
+impl SqlSessionStore {
+    pub fn new(client: SqlClient) -> Self {
+        Self { client }
+    }
+
+    fn row_to_session(row: &AnyRow) -> Result<SessionData, PersistenceError> {
+        Ok(SessionData {
+            session_id: row.try_get("session_id")?,
+            created_at: timestamp(row.try_get("created_at")?),
+            updated_at: timestamp(row.try_get("updated_at")?),
+            expires_at: timestamp(row.try_get("expires_at")?),
+            customer_phone: row.try_get("customer_phone")?,
+            customer_name: row.try_get("customer_name")?,
+            customer_segment: row.try_get("customer_segment")?,
+            language: row.try_get("language")?,
+            conversation_stage: row.try_get("conversation_stage")?,
+            turn_count: row.try_get::<i64, _>("turn_count")? as i32,
+            memory_json: row.try_get("memory_json")?,
+            metadata_json: row.try_get("metadata_json")?,
+            lead_score: row
+                .try_get::<Option<i64>, _>("lead_score")?
+                .map(|score| score as i32),
+            lead_qualification: row.try_get("lead_qualification")?,
+        })
+    }
+}
+
+#[async_trait]
+impl SessionStore for SqlSessionStore {
+    async fn create(&self, session: &SessionData) -> Result<(), PersistenceError> {
+        // Upsert, matching ScyllaDB INSERT semantics
+        let query = format!(
+            "INSERT INTO sessions ({}) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+             ON CONFLICT (session_id) DO UPDATE SET
+                created_at = excluded.created_at,
+                updated_at = excluded.updated_at,
+                expires_at = excluded.expires_at,
+                customer_phone = excluded.customer_phone,
+                customer_name = excluded.customer_name,
+                customer_segment = excluded.customer_segment,
+                language = excluded.language,
+                conversation_stage = excluded.conversation_stage,
+                turn_count = excluded.turn_count,
+                memory_json = excluded.memory_json,
+                metadata_json = excluded.metadata_json,
+                lead_score = excluded.lead_score,
+                lead_qualification = excluded.lead_qualification",
+            COLUMNS
+        );
+
+        sqlx::query(&query)
+            .bind(&session.session_id)
+            .bind(session.created_at.timestamp_millis())
+            .bind(session.updated_at.timestamp_millis())
+            .bind(session.expires_at.timestamp_millis())
+            .bind(&session.customer_phone)
+            .bind(&session.customer_name)
+            .bind(&session.customer_segment)
+            .bind(&session.language)
+            .bind(&session.conversation_stage)
+            .bind(session.turn_count as i64)
+            .bind(&session.memory_json)
+            .bind(&session.metadata_json)
+            .bind(session.lead_score.map(|score| score as i64))
+            .bind(&session.lead_qualification)
+            .execute(self.client.pool())
+            .await?;
+
+        tracing::debug!(session_id = %session.session_id, "Session created in SQL store");
+        Ok(())
+    }
+
+    async fn get(&self, session_id: &str) -> Result<Option<SessionData>, PersistenceError> {
+        let query = format!("SELECT {} FROM sessions WHERE session_id = $1", COLUMNS);
+
+        let row = sqlx::query(&query)
+            .bind(session_id)
+            .fetch_optional(self.client.pool())
+            .await?;
+
+        row.as_ref().map(Self::row_to_session).transpose()
+    }
+
+    async fn update(&self, session: &SessionData) -> Result<(), PersistenceError> {
+        sqlx::query(
+            "UPDATE sessions SET
+                updated_at = $1,
+                customer_phone = $2,
+                customer_name = $3,
+                customer_segment = $4,
+                language = $5,
+                conversation_stage = $6,
+                turn_count = $7,
+                memory_json = $8,
+                metadata_json = $9,
+                lead_score = $10,
+                lead_qualification = $11
+             WHERE session_id = $12",
+        )
+        .bind(Utc::now().timestamp_millis())
+        .bind(&session.customer_phone)
+        .bind(&session.customer_name)
+        .bind(&session.customer_segment)
+        .bind(&session.language)
+        .bind(&session.conversation_stage)
+        .bind(session.turn_count as i64)
+        .bind(&session.memory_json)
+        .bind(&session.metadata_json)
+        .bind(session.lead_score.map(|score| score as i64))
+        .bind(&session.lead_qualification)
+        .bind(&session.session_id)
+        .execute(self.client.pool())
+        .await?;
+
+        tracing::debug!(session_id = %session.session_id, "Session updated in SQL store");
+        Ok(())
+    }
+
+    async fn delete(&self, session_id: &str) -> Result<(), PersistenceError> {
+        sqlx::query("DELETE FROM sessions WHERE session_id = $1")
+            .bind(session_id)
+            .execute(self.client.pool())
+            .await?;
+        Ok(())
+    }
+
+    async fn touch(&self, session_id: &str) -> Result<(), PersistenceError> {
+        let now = Utc::now();
+        let expires = now + Duration::hours(24);
+
+        sqlx::query("UPDATE sessions SET updated_at = $1, expires_at = $2 WHERE session_id = $3")
+            .bind(now.timestamp_millis())
+            .bind(expires.timestamp_millis())
+            .bind(session_id)
+            .execute(self.client.pool())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn list_active(&self, limit: i32) -> Result<Vec<SessionData>, PersistenceError> {
+        // No TTL in SQL: expired rows stay until deleted, so filter them here
+        let query = format!(
+            "SELECT {} FROM sessions WHERE expires_at > $1 ORDER BY updated_at DESC LIMIT $2",
+            COLUMNS
+        );
+
+        let rows = sqlx::query(&query)
+            .bind(Utc::now().timestamp_millis())
+            .bind(limit as i64)
+            .fetch_all(self.client.pool())
+            .await?;
+
+        rows.iter().map(Self::row_to_session).collect()
+    }
+
+    async fn purge_expired(&self) -> Result<u64, PersistenceError> {
+        let result = sqlx::query("DELETE FROM sessions WHERE expires_at <= $1")
+            .bind(Utc::now().timestamp_millis())
+            .execute(self.client.pool())
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
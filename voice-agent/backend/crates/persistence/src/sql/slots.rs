@@ -0,0 +1,260 @@
+//! Branch visit slot capacity using SQLite/Postgres
+
+use super::{parse_date, timestamp, SqlClient};
+use crate::{PersistenceError, SlotAvailability, SlotConfirmation, SlotHold, SlotStore};
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDate, Utc};
+use sqlx::Row;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// SQL implementation of slot store
+///
+/// Bookings are a single conditional `UPDATE`, so capacity is never
+/// exceeded. Expired holds are ignored when counting and removed whenever a
+/// new hold is taken.
+#[derive(Clone)]
+pub struct SqlSlotStore {
+    client: SqlClient,
+}
+
+impl SqlSlotStore {
+    pub fn new(client: SqlClient) -> Self {
+        Self { client }
+    }
+
+    async fn get_hold(&self, hold_id: Uuid) -> Result<Option<SlotHold>, PersistenceError> {
+        let row = sqlx::query(
+            "SELECT branch_id, slot_date, slot_time, session_id, expires_at
+             FROM slot_holds WHERE hold_id = $1",
+        )
+        .bind(hold_id.to_string())
+        .fetch_optional(self.client.pool())
+        .await?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        Ok(Some(SlotHold {
+            hold_id,
+            branch_id: row.try_get("branch_id")?,
+            date: parse_date(row.try_get("slot_date")?)?,
+            time: row.try_get("slot_time")?,
+            session_id: row.try_get("session_id")?,
+            expires_at: timestamp(row.try_get("expires_at")?),
+        }))
+    }
+
+    async fn delete_hold(&self, hold_id: Uuid) -> Result<(), PersistenceError> {
+        sqlx::query("DELETE FROM slot_holds WHERE hold_id = $1")
+            .bind(hold_id.to_string())
+            .execute(self.client.pool())
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SlotStore for SqlSlotStore {
+    async fn availability(
+        &self,
+        branch_id: &str,
+        date: NaiveDate,
+        times: &[String],
+        default_capacity: u32,
+    ) -> Result<Vec<SlotAvailability>, PersistenceError> {
+        let date_key = date.to_string();
+
+        let rows = sqlx::query(
+            "SELECT slot_time, capacity, booked FROM slot_capacity
+             WHERE branch_id = $1 AND slot_date = $2",
+        )
+        .bind(branch_id)
+        .bind(&date_key)
+        .fetch_all(self.client.pool())
+        .await?;
+        let mut usage: HashMap<String, (Option<i64>, i64)> = HashMap::new();
+        for row in rows {
+            let booked: Option<i64> = row.try_get("booked")?;
+            usage.insert(
+                row.try_get("slot_time")?,
+                (row.try_get("capacity")?, booked.unwrap_or(0)),
+            );
+        }
+
+        let rows = sqlx::query(
+            "SELECT slot_time FROM slot_holds
+             WHERE branch_id = $1 AND slot_date = $2 AND expires_at > $3",
+        )
+        .bind(branch_id)
+        .bind(&date_key)
+        .bind(Utc::now().timestamp_millis())
+        .fetch_all(self.client.pool())
+        .await?;
+        let mut holds: HashMap<String, u32> = HashMap::new();
+        for row in rows {
+            *holds.entry(row.try_get("slot_time")?).or_insert(0) += 1;
+        }
+
+        Ok(times
+            .iter()
+            .map(|time| {
+                let (capacity, booked) = usage.get(time).copied().unwrap_or((None, 0));
+                SlotAvailability {
+                    branch_id: branch_id.to_string(),
+                    date,
+                    time: time.clone(),
+                    capacity: capacity
+                        .map(|c| c.max(0) as u32)
+                        .unwrap_or(default_capacity),
+                    booked: booked.max(0) as u32,
+                    held: holds.get(time).copied().unwrap_or(0),
+                }
+            })
+            .collect())
+    }
+
+    async fn set_capacity(
+        &self,
+        branch_id: &str,
+        date: NaiveDate,
+        time: &str,
+        capacity: u32,
+    ) -> Result<(), PersistenceError> {
+        sqlx::query(
+            "INSERT INTO slot_capacity (branch_id, slot_date, slot_time, capacity, booked)
+             VALUES ($1, $2, $3, $4, 0)
+             ON CONFLICT (branch_id, slot_date, slot_time) DO UPDATE SET
+                capacity = excluded.capacity",
+        )
+        .bind(branch_id)
+        .bind(date.to_string())
+        .bind(time)
+        .bind(capacity as i64)
+        .execute(self.client.pool())
+        .await?;
+
+        tracing::info!(
+            branch_id = %branch_id,
+            date = %date,
+            time = %time,
+            capacity,
+            "Slot capacity overridden"
+        );
+
+        Ok(())
+    }
+
+    async fn hold(
+        &self,
+        branch_id: &str,
+        date: NaiveDate,
+        time: &str,
+        session_id: &str,
+        default_capacity: u32,
+        ttl: Duration,
+    ) -> Result<Option<SlotHold>, PersistenceError> {
+        let now = Utc::now();
+        sqlx::query("DELETE FROM slot_holds WHERE expires_at <= $1")
+            .bind(now.timestamp_millis())
+            .execute(self.client.pool())
+            .await?;
+
+        let slot = self
+            .availability(branch_id, date, &[time.to_string()], default_capacity)
+            .await?;
+        if slot.first().map(|s| s.remaining()).unwrap_or(0) == 0 {
+            return Ok(None);
+        }
+
+        let hold = SlotHold {
+            hold_id: Uuid::new_v4(),
+            branch_id: branch_id.to_string(),
+            date,
+            time: time.to_string(),
+            session_id: session_id.to_string(),
+            expires_at: now + ttl,
+        };
+
+        sqlx::query(
+            "INSERT INTO slot_holds (
+                hold_id, branch_id, slot_date, slot_time, session_id, expires_at
+            ) VALUES ($1, $2, $3, $4, $5, $6)",
+        )
+        .bind(hold.hold_id.to_string())
+        .bind(&hold.branch_id)
+        .bind(hold.date.to_string())
+        .bind(&hold.time)
+        .bind(&hold.session_id)
+        .bind(hold.expires_at.timestamp_millis())
+        .execute(self.client.pool())
+        .await?;
+
+        tracing::debug!(
+            hold_id = %hold.hold_id,
+            branch_id = %branch_id,
+            date = %date,
+            time = %time,
+            "Slot held"
+        );
+
+        Ok(Some(hold))
+    }
+
+    async fn confirm(
+        &self,
+        hold_id: Uuid,
+        default_capacity: u32,
+    ) -> Result<SlotConfirmation, PersistenceError> {
+        let Some(hold) = self.get_hold(hold_id).await? else {
+            return Ok(SlotConfirmation::Expired);
+        };
+        if hold.is_expired() {
+            self.delete_hold(hold_id).await?;
+            return Ok(SlotConfirmation::Expired);
+        }
+
+        let date = hold.date.to_string();
+        sqlx::query(
+            "INSERT INTO slot_capacity (branch_id, slot_date, slot_time, booked)
+             VALUES ($1, $2, $3, 0)
+             ON CONFLICT (branch_id, slot_date, slot_time) DO NOTHING",
+        )
+        .bind(&hold.branch_id)
+        .bind(&date)
+        .bind(&hold.time)
+        .execute(self.client.pool())
+        .await?;
+
+        let booked = sqlx::query(
+            "UPDATE slot_capacity SET booked = booked + 1
+             WHERE branch_id = $1 AND slot_date = $2 AND slot_time = $3
+               AND booked < COALESCE(capacity, $4)",
+        )
+        .bind(&hold.branch_id)
+        .bind(&date)
+        .bind(&hold.time)
+        .bind(default_capacity as i64)
+        .execute(self.client.pool())
+        .await?
+        .rows_affected()
+            > 0;
+        self.delete_hold(hold_id).await?;
+
+        if !booked {
+            return Ok(SlotConfirmation::Full);
+        }
+        tracing::info!(
+            hold_id = %hold_id,
+            branch_id = %hold.branch_id,
+            date = %hold.date,
+            time = %hold.time,
+            "Slot booked"
+        );
+        Ok(SlotConfirmation::Confirmed(hold))
+    }
+
+    async fn release(&self, hold_id: Uuid) -> Result<(), PersistenceError> {
+        self.delete_hold(hold_id).await
+    }
+}
@@ -0,0 +1,112 @@
+//! Simulated SMS service with SQLite/Postgres persistence
+
+use super::{parse_uuid, timestamp, SqlClient};
+use crate::sms::SmsResult;
+use crate::{PersistenceError, SmsMessage, SmsService, SmsStatus, SmsType};
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::any::AnyRow;
+use sqlx::Row;
+use uuid::Uuid;
+
+/// Simulated SMS service that persists to SQL
+///
+/// Messages are not sent; see [`crate::SimulatedSmsService`].
+#[derive(Clone)]
+pub struct SqlSmsService {
+    client: SqlClient,
+}
+
+impl SqlSmsService {
+    pub fn new(client: SqlClient) -> Self {
+        Self { client }
+    }
+}
+
+fn row_to_message(row: &AnyRow) -> Result<SmsMessage, PersistenceError> {
+    let metadata_json: Option<String> = row.try_get("metadata_json")?;
+    Ok(SmsMessage {
+        message_id: parse_uuid(row.try_get("message_id")?)?,
+        phone_number: row.try_get("phone_number")?,
+        session_id: row.try_get("session_id")?,
+        message_text: row.try_get("message_text")?,
+        message_type: SmsType::parse(row.try_get("message_type")?),
+        status: SmsStatus::parse(row.try_get("status")?),
+        created_at: timestamp(row.try_get("created_at")?),
+        sent_at: row.try_get::<Option<i64>, _>("sent_at")?.map(timestamp),
+        metadata: metadata_json.and_then(|s| serde_json::from_str(&s).ok()),
+    })
+}
+
+#[async_trait]
+impl SmsService for SqlSmsService {
+    async fn send_sms(
+        &self,
+        phone: &str,
+        message: &str,
+        msg_type: SmsType,
+        session_id: Option<&str>,
+    ) -> Result<SmsResult, PersistenceError> {
+        let message_id = Uuid::new_v4();
+        let now = Utc::now();
+
+        sqlx::query(
+            "INSERT INTO sms_messages (
+                phone_number, message_id, session_id, message_text,
+                message_type, status, created_at, sent_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+        )
+        .bind(phone)
+        .bind(message_id.to_string())
+        .bind(session_id)
+        .bind(message)
+        .bind(msg_type.as_str())
+        .bind(SmsStatus::SimulatedSent.as_str())
+        .bind(now.timestamp_millis())
+        .bind(now.timestamp_millis())
+        .execute(self.client.pool())
+        .await?;
+
+        tracing::info!(
+            phone = %phone,
+            message_id = %message_id,
+            msg_type = ?msg_type,
+            "SMS simulated and persisted to SQL store"
+        );
+
+        Ok(SmsResult {
+            message_id,
+            status: SmsStatus::SimulatedSent,
+            sent_at: now,
+            simulated: true,
+        })
+    }
+
+    async fn get_messages_for_phone(
+        &self,
+        phone: &str,
+        limit: i32,
+    ) -> Result<Vec<SmsMessage>, PersistenceError> {
+        let rows = sqlx::query(
+            "SELECT phone_number, message_id, session_id, message_text,
+                    message_type, status, created_at, sent_at, metadata_json
+             FROM sms_messages WHERE phone_number = $1
+             ORDER BY created_at DESC LIMIT $2",
+        )
+        .bind(phone)
+        .bind(limit as i64)
+        .fetch_all(self.client.pool())
+        .await?;
+
+        rows.iter().map(row_to_message).collect()
+    }
+
+    async fn get_message(
+        &self,
+        phone: &str,
+        message_id: Uuid,
+    ) -> Result<Option<SmsMessage>, PersistenceError> {
+        let messages = self.get_messages_for_phone(phone, 100).await?;
+        Ok(messages.into_iter().find(|m| m.message_id == message_id))
+    }
+}
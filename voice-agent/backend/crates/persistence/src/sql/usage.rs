@@ -0,0 +1,188 @@
+//! Usage accounting using SQLite/Postgres
+
+use super::{parse_date, timestamp, SqlClient};
+use crate::analytics::days_in_range;
+use crate::{DailyUsage, PersistenceError, TurnUsage, UsageCounts, UsageStore};
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use sqlx::any::AnyRow;
+use sqlx::Row;
+
+/// SQL implementation of the usage store
+#[derive(Clone)]
+pub struct SqlUsageStore {
+    client: SqlClient,
+}
+
+impl SqlUsageStore {
+    pub fn new(client: SqlClient) -> Self {
+        Self { client }
+    }
+}
+
+fn row_to_counts(row: &AnyRow) -> Result<UsageCounts, PersistenceError> {
+    let count = |column: &str| -> Result<u64, PersistenceError> {
+        let value: Option<i64> = row.try_get(column)?;
+        Ok(value.unwrap_or(0).max(0) as u64)
+    };
+    let stt_seconds: Option<f64> = row.try_get("stt_seconds")?;
+
+    Ok(UsageCounts {
+        llm_prompt_tokens: count("llm_prompt_tokens")?,
+        llm_completion_tokens: count("llm_completion_tokens")?,
+        stt_seconds: stt_seconds.unwrap_or(0.0).max(0.0),
+        tts_characters: count("tts_characters")?,
+        tool_calls: count("tool_calls")?,
+        translation_calls: count("translation_calls")?,
+    })
+}
+
+fn row_to_turn(row: &AnyRow) -> Result<TurnUsage, PersistenceError> {
+    let turn: i64 = row.try_get("turn")?;
+    let tenant: Option<String> = row.try_get("tenant")?;
+
+    Ok(TurnUsage {
+        session_id: row.try_get("session_id")?,
+        tenant: tenant.unwrap_or_default(),
+        turn: turn.max(0) as u32,
+        recorded_at: timestamp(row.try_get("recorded_at")?),
+        counts: row_to_counts(row)?,
+    })
+}
+
+fn row_to_daily(row: &AnyRow) -> Result<DailyUsage, PersistenceError> {
+    let sessions: i64 = row.try_get("sessions")?;
+    let turns: i64 = row.try_get("turns")?;
+
+    Ok(DailyUsage {
+        day: parse_date(row.try_get("day")?)?,
+        tenant: row.try_get("tenant")?,
+        sessions: sessions.max(0) as u64,
+        turns: turns.max(0) as u64,
+        counts: row_to_counts(row)?,
+    })
+}
+
+#[async_trait]
+impl UsageStore for SqlUsageStore {
+    async fn record_turn(&self, usage: &TurnUsage) -> Result<(), PersistenceError> {
+        sqlx::query(
+            "INSERT INTO turn_usage (
+                day, session_id, turn, tenant, recorded_at, llm_prompt_tokens,
+                llm_completion_tokens, stt_seconds, tts_characters, tool_calls, translation_calls
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            ON CONFLICT (session_id, turn) DO UPDATE SET
+                day = excluded.day,
+                tenant = excluded.tenant,
+                recorded_at = excluded.recorded_at,
+                llm_prompt_tokens = excluded.llm_prompt_tokens,
+                llm_completion_tokens = excluded.llm_completion_tokens,
+                stt_seconds = excluded.stt_seconds,
+                tts_characters = excluded.tts_characters,
+                tool_calls = excluded.tool_calls,
+                translation_calls = excluded.translation_calls",
+        )
+        .bind(usage.day().to_string())
+        .bind(&usage.session_id)
+        .bind(usage.turn as i64)
+        .bind(&usage.tenant)
+        .bind(usage.recorded_at.timestamp_millis())
+        .bind(usage.counts.llm_prompt_tokens as i64)
+        .bind(usage.counts.llm_completion_tokens as i64)
+        .bind(usage.counts.stt_seconds)
+        .bind(usage.counts.tts_characters as i64)
+        .bind(usage.counts.tool_calls as i64)
+        .bind(usage.counts.translation_calls as i64)
+        .execute(self.client.pool())
+        .await?;
+
+        Ok(())
+    }
+
+    async fn session_usage(&self, session_id: &str) -> Result<Vec<TurnUsage>, PersistenceError> {
+        let rows = sqlx::query(
+            "SELECT session_id, turn, tenant, recorded_at, llm_prompt_tokens,
+                    llm_completion_tokens, stt_seconds, tts_characters, tool_calls,
+                    translation_calls
+             FROM turn_usage WHERE session_id = $1 ORDER BY turn",
+        )
+        .bind(session_id)
+        .fetch_all(self.client.pool())
+        .await?;
+
+        rows.iter().map(row_to_turn).collect()
+    }
+
+    async fn turns_for_day(&self, day: NaiveDate) -> Result<Vec<TurnUsage>, PersistenceError> {
+        let rows = sqlx::query(
+            "SELECT session_id, turn, tenant, recorded_at, llm_prompt_tokens,
+                    llm_completion_tokens, stt_seconds, tts_characters, tool_calls,
+                    translation_calls
+             FROM turn_usage WHERE day = $1",
+        )
+        .bind(day.to_string())
+        .fetch_all(self.client.pool())
+        .await?;
+
+        rows.iter().map(row_to_turn).collect()
+    }
+
+    async fn upsert_daily(&self, usage: &DailyUsage) -> Result<(), PersistenceError> {
+        sqlx::query(
+            "INSERT INTO usage_daily (
+                day, tenant, sessions, turns, llm_prompt_tokens, llm_completion_tokens,
+                stt_seconds, tts_characters, tool_calls, translation_calls, updated_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            ON CONFLICT (day, tenant) DO UPDATE SET
+                sessions = excluded.sessions,
+                turns = excluded.turns,
+                llm_prompt_tokens = excluded.llm_prompt_tokens,
+                llm_completion_tokens = excluded.llm_completion_tokens,
+                stt_seconds = excluded.stt_seconds,
+                tts_characters = excluded.tts_characters,
+                tool_calls = excluded.tool_calls,
+                translation_calls = excluded.translation_calls,
+                updated_at = excluded.updated_at",
+        )
+        .bind(usage.day.to_string())
+        .bind(&usage.tenant)
+        .bind(usage.sessions as i64)
+        .bind(usage.turns as i64)
+        .bind(usage.counts.llm_prompt_tokens as i64)
+        .bind(usage.counts.llm_completion_tokens as i64)
+        .bind(usage.counts.stt_seconds)
+        .bind(usage.counts.tts_characters as i64)
+        .bind(usage.counts.tool_calls as i64)
+        .bind(usage.counts.translation_calls as i64)
+        .bind(Utc::now().timestamp_millis())
+        .execute(self.client.pool())
+        .await?;
+
+        Ok(())
+    }
+
+    async fn daily_usage(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        tenant: &str,
+    ) -> Result<Vec<DailyUsage>, PersistenceError> {
+        // Validates the range; ISO dates sort as text so one query covers it
+        days_in_range(from, to)?;
+
+        let rows = sqlx::query(
+            "SELECT day, tenant, sessions, turns, llm_prompt_tokens, llm_completion_tokens,
+                    stt_seconds, tts_characters, tool_calls, translation_calls
+             FROM usage_daily
+             WHERE day >= $1 AND day <= $2 AND tenant = $3
+             ORDER BY day",
+        )
+        .bind(from.to_string())
+        .bind(to.to_string())
+        .bind(tenant)
+        .fetch_all(self.client.pool())
+        .await?;
+
+        rows.iter().map(row_to_daily).collect()
+    }
+}
@@ -0,0 +1,103 @@
+//! Webhook delivery log using SQLite/Postgres
+
+use super::{parse_uuid, timestamp, SqlClient};
+use crate::{PersistenceError, WebhookDelivery, WebhookDeliveryStatus, WebhookDeliveryStore};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::any::AnyRow;
+use sqlx::Row;
+
+/// SQL implementation of the webhook delivery log
+#[derive(Clone)]
+pub struct SqlWebhookDeliveryStore {
+    client: SqlClient,
+}
+
+impl SqlWebhookDeliveryStore {
+    pub fn new(client: SqlClient) -> Self {
+        Self { client }
+    }
+}
+
+fn row_to_delivery(row: &AnyRow) -> Result<WebhookDelivery, PersistenceError> {
+    let delivery_id: String = row.try_get("delivery_id")?;
+    let response_status: Option<i64> = row.try_get("response_status")?;
+
+    Ok(WebhookDelivery {
+        delivery_id: parse_uuid(&delivery_id)?,
+        endpoint: row.try_get("endpoint")?,
+        event_type: row.try_get("event_type")?,
+        event_id: row.try_get("event_id")?,
+        session_id: row.try_get("session_id")?,
+        payload_json: row.try_get("payload_json")?,
+        status: WebhookDeliveryStatus::parse(row.try_get("status")?),
+        attempts: row.try_get::<i64, _>("attempts")? as i32,
+        response_status: response_status.map(|s| s as i32),
+        last_error: row.try_get("last_error")?,
+        created_at: timestamp(row.try_get("created_at")?),
+        updated_at: timestamp(row.try_get("updated_at")?),
+    })
+}
+
+#[async_trait]
+impl WebhookDeliveryStore for SqlWebhookDeliveryStore {
+    async fn upsert(&self, delivery: &WebhookDelivery) -> Result<(), PersistenceError> {
+        sqlx::query(
+            "INSERT INTO webhook_deliveries (
+                delivery_id, day, endpoint, event_type, event_id, session_id,
+                payload_json, status, attempts, response_status, last_error,
+                created_at, updated_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            ON CONFLICT (delivery_id) DO UPDATE SET
+                status = excluded.status,
+                attempts = excluded.attempts,
+                response_status = excluded.response_status,
+                last_error = excluded.last_error,
+                updated_at = excluded.updated_at",
+        )
+        .bind(delivery.delivery_id.to_string())
+        .bind(delivery.day().to_string())
+        .bind(&delivery.endpoint)
+        .bind(&delivery.event_type)
+        .bind(&delivery.event_id)
+        .bind(&delivery.session_id)
+        .bind(&delivery.payload_json)
+        .bind(delivery.status.as_str())
+        .bind(delivery.attempts as i64)
+        .bind(delivery.response_status.map(|s| s as i64))
+        .bind(&delivery.last_error)
+        .bind(delivery.created_at.timestamp_millis())
+        .bind(delivery.updated_at.timestamp_millis())
+        .execute(self.client.pool())
+        .await?;
+
+        tracing::debug!(
+            delivery_id = %delivery.delivery_id,
+            status = delivery.status.as_str(),
+            attempts = delivery.attempts,
+            "Webhook delivery status persisted"
+        );
+
+        Ok(())
+    }
+
+    async fn list_for_day(
+        &self,
+        day: NaiveDate,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, PersistenceError> {
+        let rows = sqlx::query(
+            "SELECT delivery_id, endpoint, event_type, event_id, session_id,
+                    payload_json, status, attempts, response_status, last_error,
+                    created_at, updated_at
+             FROM webhook_deliveries WHERE day = $1
+             ORDER BY created_at DESC LIMIT $2",
+        )
+        .bind(day.to_string())
+        .bind(limit as i64)
+        .fetch_all(self.client.pool())
+        .await?;
+
+        rows.iter().map(row_to_delivery).collect()
+    }
+}
@@ -0,0 +1,527 @@
+//! Usage accounting
+//!
+//! Each conversation turn is recorded as a `TurnUsage`: LLM tokens in and
+//! out, seconds of caller audio transcribed, characters sent to TTS, tool
+//! calls and translation calls. Turns are kept per session and tagged with
+//! the tenant the session is billed to. A rollup job sums a day's turns into
+//! `DailyUsage` rows, one per tenant plus an `all` row, which operators read
+//! for unit economics and quotas are checked against.
+
+use crate::analytics::days_in_range;
+use crate::{PersistenceError, ScyllaClient};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use tokio::sync::RwLock;
+
+/// Tenant key of the usage row covering every tenant
+pub const ALL_TENANTS: &str = "all";
+
+/// What a turn, session or day consumed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct UsageCounts {
+    /// LLM prompt tokens, across every call of the turn
+    pub llm_prompt_tokens: u64,
+    /// LLM completion tokens
+    pub llm_completion_tokens: u64,
+    /// Caller audio transcribed (seconds)
+    pub stt_seconds: f64,
+    /// Characters sent to TTS
+    pub tts_characters: u64,
+    /// Tool executions
+    pub tool_calls: u64,
+    /// Calls to the translator
+    pub translation_calls: u64,
+}
+
+impl UsageCounts {
+    /// Whether nothing was consumed
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// LLM tokens in and out
+    pub fn llm_tokens(&self) -> u64 {
+        self.llm_prompt_tokens + self.llm_completion_tokens
+    }
+
+    /// Add another set of counts
+    pub fn add(&mut self, other: &UsageCounts) {
+        self.llm_prompt_tokens += other.llm_prompt_tokens;
+        self.llm_completion_tokens += other.llm_completion_tokens;
+        self.stt_seconds += other.stt_seconds;
+        self.tts_characters += other.tts_characters;
+        self.tool_calls += other.tool_calls;
+        self.translation_calls += other.translation_calls;
+    }
+}
+
+/// What one turn of a session consumed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TurnUsage {
+    pub session_id: String,
+    pub tenant: String,
+    /// Turn number within the session (0 = before the caller first spoke)
+    pub turn: u32,
+    pub recorded_at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub counts: UsageCounts,
+}
+
+impl TurnUsage {
+    /// Day (UTC) the turn is counted under
+    pub fn day(&self) -> NaiveDate {
+        self.recorded_at.date_naive()
+    }
+}
+
+/// Aggregated usage for one day and tenant
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DailyUsage {
+    pub day: NaiveDate,
+    /// Tenant, or `all`
+    pub tenant: String,
+    /// Sessions with at least one turn that day
+    pub sessions: u64,
+    pub turns: u64,
+    #[serde(flatten)]
+    pub counts: UsageCounts,
+}
+
+impl DailyUsage {
+    pub fn new(day: NaiveDate, tenant: impl Into<String>) -> Self {
+        Self {
+            day,
+            tenant: tenant.into(),
+            sessions: 0,
+            turns: 0,
+            counts: UsageCounts::default(),
+        }
+    }
+
+    /// Aggregate a day's turns into per-tenant rows and an `all` row
+    pub fn aggregate(day: NaiveDate, turns: &[TurnUsage]) -> Vec<DailyUsage> {
+        let mut total = DailyUsage::new(day, ALL_TENANTS);
+        let mut by_tenant: BTreeMap<String, DailyUsage> = BTreeMap::new();
+        let mut sessions: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+        for turn in turns.iter().filter(|t| t.day() == day) {
+            total.add(turn);
+            by_tenant
+                .entry(turn.tenant.clone())
+                .or_insert_with(|| DailyUsage::new(day, turn.tenant.clone()))
+                .add(turn);
+            sessions
+                .entry(&turn.tenant)
+                .or_default()
+                .insert(&turn.session_id);
+        }
+
+        for (tenant, ids) in &sessions {
+            if let Some(usage) = by_tenant.get_mut(*tenant) {
+                usage.sessions = ids.len() as u64;
+            }
+            total.sessions += ids.len() as u64;
+        }
+
+        let mut rows = vec![total];
+        rows.extend(by_tenant.into_values());
+        rows
+    }
+
+    /// Count one turn (sessions are counted by `aggregate`)
+    pub fn add(&mut self, turn: &TurnUsage) {
+        self.turns += 1;
+        self.counts.add(&turn.counts);
+    }
+
+    /// Add another row's counts (e.g. to cover a date range)
+    pub fn merge(&mut self, other: &DailyUsage) {
+        self.sessions += other.sessions;
+        self.turns += other.turns;
+        self.counts.add(&other.counts);
+    }
+}
+
+/// Usage store trait
+#[async_trait]
+pub trait UsageStore: Send + Sync {
+    /// Record a turn's usage (replaces an earlier record of the same turn)
+    async fn record_turn(&self, usage: &TurnUsage) -> Result<(), PersistenceError>;
+
+    /// Every recorded turn of a session, in turn order
+    async fn session_usage(&self, session_id: &str) -> Result<Vec<TurnUsage>, PersistenceError>;
+
+    /// Turns recorded on `day`
+    async fn turns_for_day(&self, day: NaiveDate) -> Result<Vec<TurnUsage>, PersistenceError>;
+
+    /// Insert or replace a daily usage row
+    async fn upsert_daily(&self, usage: &DailyUsage) -> Result<(), PersistenceError>;
+
+    /// Daily rows for a date range (inclusive) and tenant (`all` for totals)
+    async fn daily_usage(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        tenant: &str,
+    ) -> Result<Vec<DailyUsage>, PersistenceError>;
+}
+
+/// Re-aggregate one day's turns into its daily usage rows
+pub async fn roll_up_usage_day(
+    store: &dyn UsageStore,
+    day: NaiveDate,
+) -> Result<usize, PersistenceError> {
+    let turns = store.turns_for_day(day).await?;
+    for usage in DailyUsage::aggregate(day, &turns) {
+        store.upsert_daily(&usage).await?;
+    }
+    Ok(turns.len())
+}
+
+/// ScyllaDB implementation of the usage store
+///
+/// Turns are written twice: by session for session lookups and by day for
+/// the rollup job.
+#[derive(Clone)]
+pub struct ScyllaUsageStore {
+    client: ScyllaClient,
+}
+
+/// Columns of a turn usage row, in `SELECT` order
+type TurnUsageRow = (
+    String,
+    i32,
+    Option<String>,
+    i64,
+    Option<i64>,
+    Option<i64>,
+    Option<f64>,
+    Option<i64>,
+    Option<i64>,
+    Option<i64>,
+);
+
+impl ScyllaUsageStore {
+    pub fn new(client: ScyllaClient) -> Self {
+        Self { client }
+    }
+
+    fn rows_to_turns(
+        rows: Vec<scylla::frame::response::result::Row>,
+    ) -> Result<Vec<TurnUsage>, PersistenceError> {
+        let mut turns = Vec::new();
+        for row in rows {
+            let (
+                session_id,
+                turn,
+                tenant,
+                recorded_at,
+                llm_prompt_tokens,
+                llm_completion_tokens,
+                stt_seconds,
+                tts_characters,
+                tool_calls,
+                translation_calls,
+            ): TurnUsageRow = row
+                .into_typed()
+                .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+
+            let count = |value: Option<i64>| value.unwrap_or(0).max(0) as u64;
+            turns.push(TurnUsage {
+                session_id,
+                tenant: tenant.unwrap_or_default(),
+                turn: turn.max(0) as u32,
+                recorded_at: DateTime::from_timestamp_millis(recorded_at).unwrap_or_else(Utc::now),
+                counts: UsageCounts {
+                    llm_prompt_tokens: count(llm_prompt_tokens),
+                    llm_completion_tokens: count(llm_completion_tokens),
+                    stt_seconds: stt_seconds.unwrap_or(0.0).max(0.0),
+                    tts_characters: count(tts_characters),
+                    tool_calls: count(tool_calls),
+                    translation_calls: count(translation_calls),
+                },
+            });
+        }
+        Ok(turns)
+    }
+}
+
+const TURN_COLUMNS: &str = "session_id, turn, tenant, recorded_at, llm_prompt_tokens, \
+     llm_completion_tokens, stt_seconds, tts_characters, tool_calls, translation_calls";
+
+#[async_trait]
+impl UsageStore for ScyllaUsageStore {
+    async fn record_turn(&self, usage: &TurnUsage) -> Result<(), PersistenceError> {
+        let counts = &usage.counts;
+        for table in ["turn_usage", "turn_usage_by_day"] {
+            let query = format!(
+                "INSERT INTO {}.{} (day, {}) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                self.client.keyspace(),
+                table,
+                TURN_COLUMNS
+            );
+            self.client
+                .execute(
+                    query,
+                    (
+                        usage.day().to_string(),
+                        &usage.session_id,
+                        usage.turn as i32,
+                        &usage.tenant,
+                        usage.recorded_at.timestamp_millis(),
+                        counts.llm_prompt_tokens as i64,
+                        counts.llm_completion_tokens as i64,
+                        counts.stt_seconds,
+                        counts.tts_characters as i64,
+                        counts.tool_calls as i64,
+                        counts.translation_calls as i64,
+                    ),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn session_usage(&self, session_id: &str) -> Result<Vec<TurnUsage>, PersistenceError> {
+        let query = format!(
+            "SELECT {} FROM {}.turn_usage WHERE session_id = ?",
+            TURN_COLUMNS,
+            self.client.keyspace()
+        );
+        let result = self.client.execute(query, (session_id,)).await?;
+        Self::rows_to_turns(result.rows.unwrap_or_default())
+    }
+
+    async fn turns_for_day(&self, day: NaiveDate) -> Result<Vec<TurnUsage>, PersistenceError> {
+        let query = format!(
+            "SELECT {} FROM {}.turn_usage_by_day WHERE day = ?",
+            TURN_COLUMNS,
+            self.client.keyspace()
+        );
+        let result = self.client.execute(query, (day.to_string(),)).await?;
+        Self::rows_to_turns(result.rows.unwrap_or_default())
+    }
+
+    async fn upsert_daily(&self, usage: &DailyUsage) -> Result<(), PersistenceError> {
+        let query = format!(
+            "INSERT INTO {}.usage_daily (
+                day, tenant, sessions, turns, llm_prompt_tokens, llm_completion_tokens,
+                stt_seconds, tts_characters, tool_calls, translation_calls, updated_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            self.client.keyspace()
+        );
+
+        self.client
+            .execute(
+                query,
+                (
+                    usage.day.to_string(),
+                    &usage.tenant,
+                    usage.sessions as i64,
+                    usage.turns as i64,
+                    usage.counts.llm_prompt_tokens as i64,
+                    usage.counts.llm_completion_tokens as i64,
+                    usage.counts.stt_seconds,
+                    usage.counts.tts_characters as i64,
+                    usage.counts.tool_calls as i64,
+                    usage.counts.translation_calls as i64,
+                    Utc::now().timestamp_millis(),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn daily_usage(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        tenant: &str,
+    ) -> Result<Vec<DailyUsage>, PersistenceError> {
+        let query = format!(
+            "SELECT sessions, turns, llm_prompt_tokens, llm_completion_tokens, stt_seconds,
+                    tts_characters, tool_calls, translation_calls
+             FROM {}.usage_daily WHERE day = ? AND tenant = ?",
+            self.client.keyspace()
+        );
+
+        let mut rows = Vec::new();
+        for day in days_in_range(from, to)? {
+            let result = self
+                .client
+                .execute(query.clone(), (day.to_string(), tenant))
+                .await?;
+
+            let Some(row) = result.rows.unwrap_or_default().into_iter().next() else {
+                continue;
+            };
+            let (
+                sessions,
+                turns,
+                llm_prompt_tokens,
+                llm_completion_tokens,
+                stt_seconds,
+                tts_characters,
+                tool_calls,
+                translation_calls,
+            ): (i64, i64, i64, i64, f64, i64, i64, i64) = row
+                .into_typed()
+                .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+
+            rows.push(DailyUsage {
+                day,
+                tenant: tenant.to_string(),
+                sessions: sessions.max(0) as u64,
+                turns: turns.max(0) as u64,
+                counts: UsageCounts {
+                    llm_prompt_tokens: llm_prompt_tokens.max(0) as u64,
+                    llm_completion_tokens: llm_completion_tokens.max(0) as u64,
+                    stt_seconds: stt_seconds.max(0.0),
+                    tts_characters: tts_characters.max(0) as u64,
+                    tool_calls: tool_calls.max(0) as u64,
+                    translation_calls: translation_calls.max(0) as u64,
+                },
+            });
+        }
+
+        Ok(rows)
+    }
+}
+
+/// In-memory usage store
+///
+/// Used when ScyllaDB is not configured; nothing survives restarts.
+#[derive(Default)]
+pub struct InMemoryUsageStore {
+    turns: RwLock<HashMap<(String, u32), TurnUsage>>,
+    daily: RwLock<HashMap<(NaiveDate, String), DailyUsage>>,
+}
+
+impl InMemoryUsageStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl UsageStore for InMemoryUsageStore {
+    async fn record_turn(&self, usage: &TurnUsage) -> Result<(), PersistenceError> {
+        self.turns
+            .write()
+            .await
+            .insert((usage.session_id.clone(), usage.turn), usage.clone());
+        Ok(())
+    }
+
+    async fn session_usage(&self, session_id: &str) -> Result<Vec<TurnUsage>, PersistenceError> {
+        let mut turns: Vec<TurnUsage> = self
+            .turns
+            .read()
+            .await
+            .values()
+            .filter(|t| t.session_id == session_id)
+            .cloned()
+            .collect();
+        turns.sort_by_key(|t| t.turn);
+        Ok(turns)
+    }
+
+    async fn turns_for_day(&self, day: NaiveDate) -> Result<Vec<TurnUsage>, PersistenceError> {
+        Ok(self
+            .turns
+            .read()
+            .await
+            .values()
+            .filter(|t| t.day() == day)
+            .cloned()
+            .collect())
+    }
+
+    async fn upsert_daily(&self, usage: &DailyUsage) -> Result<(), PersistenceError> {
+        self.daily
+            .write()
+            .await
+            .insert((usage.day, usage.tenant.clone()), usage.clone());
+        Ok(())
+    }
+
+    async fn daily_usage(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        tenant: &str,
+    ) -> Result<Vec<DailyUsage>, PersistenceError> {
+        let daily = self.daily.read().await;
+        Ok(days_in_range(from, to)?
+            .into_iter()
+            .filter_map(|day| daily.get(&(day, tenant.to_string())).cloned())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn turn(session_id: &str, tenant: &str, turn: u32, prompt_tokens: u64) -> TurnUsage {
+        TurnUsage {
+            session_id: session_id.to_string(),
+            tenant: tenant.to_string(),
+            turn,
+            recorded_at: DateTime::parse_from_rfc3339("2025-01-15T10:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+            counts: UsageCounts {
+                llm_prompt_tokens: prompt_tokens,
+                llm_completion_tokens: 40,
+                stt_seconds: 2.5,
+                tts_characters: 120,
+                tool_calls: 1,
+                translation_calls: 2,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_daily_usage_per_tenant() {
+        let store = InMemoryUsageStore::new();
+        let day = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+        for usage in [
+            turn("a", "acme", 0, 300),
+            turn("a", "acme", 1, 500),
+            turn("b", "acme", 1, 400),
+            turn("c", "globex", 1, 200),
+        ] {
+            store.record_turn(&usage).await.unwrap();
+        }
+        // Re-recording a turn replaces it
+        store.record_turn(&turn("a", "acme", 1, 600)).await.unwrap();
+
+        let session = store.session_usage("a").await.unwrap();
+        assert_eq!(
+            session.iter().map(|t| t.turn).collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+        assert_eq!(session[1].counts.llm_prompt_tokens, 600);
+
+        assert_eq!(roll_up_usage_day(&store, day).await.unwrap(), 4);
+        let all = store.daily_usage(day, day, ALL_TENANTS).await.unwrap();
+        assert_eq!(all[0].sessions, 3);
+        assert_eq!(all[0].turns, 4);
+        assert_eq!(all[0].counts.llm_prompt_tokens, 1500);
+        assert_eq!(all[0].counts.llm_tokens(), 1660);
+        assert!((all[0].counts.stt_seconds - 10.0).abs() < 1e-9);
+
+        let acme = store.daily_usage(day, day, "acme").await.unwrap();
+        assert_eq!(acme[0].sessions, 2);
+        assert_eq!(acme[0].counts.translation_calls, 6);
+        assert!(store
+            .daily_usage(day, day, "initech")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,309 @@
+//! Webhook delivery log
+//!
+//! Every business event sent to a webhook endpoint is recorded as a
+//! `WebhookDelivery`, updated after each attempt with the HTTP status or
+//! error, so operators can see what a bank's endpoint received and which
+//! notifications failed. Deliveries are listed per day, newest first.
+
+use crate::{PersistenceError, ScyllaClient};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Webhook delivery status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    /// Queued, not yet attempted
+    Pending,
+    /// Last attempt failed, will be retried
+    Retrying,
+    /// Endpoint answered with a 2xx status
+    Delivered,
+    /// Gave up after exhausting retries (or a non-retryable response)
+    Failed,
+}
+
+impl WebhookDeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Retrying => "retrying",
+            Self::Delivered => "delivered",
+            Self::Failed => "failed",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "retrying" => Self::Retrying,
+            "delivered" => Self::Delivered,
+            "failed" => Self::Failed,
+            _ => Self::Pending,
+        }
+    }
+}
+
+/// One event sent (or being sent) to one endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    pub delivery_id: Uuid,
+    /// Endpoint name from the webhook config
+    pub endpoint: String,
+    /// Business event, e.g. `lead_captured`
+    pub event_type: String,
+    /// Event ID carried in the payload, shared by every endpoint's delivery
+    pub event_id: String,
+    pub session_id: String,
+    /// Body as sent (JSON)
+    pub payload_json: String,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    /// HTTP status of the last response
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WebhookDelivery {
+    pub fn new(
+        endpoint: &str,
+        event_type: &str,
+        event_id: &str,
+        session_id: &str,
+        payload_json: String,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            delivery_id: Uuid::new_v4(),
+            endpoint: endpoint.to_string(),
+            event_type: event_type.to_string(),
+            event_id: event_id.to_string(),
+            session_id: session_id.to_string(),
+            payload_json,
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            response_status: None,
+            last_error: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Day (UTC) the delivery is listed under
+    pub fn day(&self) -> NaiveDate {
+        self.created_at.date_naive()
+    }
+}
+
+/// Webhook delivery log trait
+#[async_trait]
+pub trait WebhookDeliveryStore: Send + Sync {
+    /// Insert or overwrite a delivery record
+    async fn upsert(&self, delivery: &WebhookDelivery) -> Result<(), PersistenceError>;
+
+    /// Deliveries created on `day`, newest first
+    async fn list_for_day(
+        &self,
+        day: NaiveDate,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, PersistenceError>;
+}
+
+/// ScyllaDB implementation of the webhook delivery log
+#[derive(Clone)]
+pub struct ScyllaWebhookDeliveryStore {
+    client: ScyllaClient,
+}
+
+impl ScyllaWebhookDeliveryStore {
+    pub fn new(client: ScyllaClient) -> Self {
+        Self { client }
+    }
+}
+
+const DELIVERY_COLUMNS: &str = "delivery_id, endpoint, event_type, event_id, session_id, \
+     payload_json, status, attempts, response_status, last_error, created_at, updated_at";
+
+#[async_trait]
+impl WebhookDeliveryStore for ScyllaWebhookDeliveryStore {
+    async fn upsert(&self, delivery: &WebhookDelivery) -> Result<(), PersistenceError> {
+        let query = format!(
+            "INSERT INTO {}.webhook_deliveries (day, {}) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            self.client.keyspace(),
+            DELIVERY_COLUMNS
+        );
+
+        self.client
+            .execute(
+                query,
+                (
+                    delivery.day().to_string(),
+                    delivery.delivery_id,
+                    &delivery.endpoint,
+                    &delivery.event_type,
+                    &delivery.event_id,
+                    &delivery.session_id,
+                    &delivery.payload_json,
+                    delivery.status.as_str(),
+                    delivery.attempts,
+                    delivery.response_status,
+                    &delivery.last_error,
+                    delivery.created_at.timestamp_millis(),
+                    delivery.updated_at.timestamp_millis(),
+                ),
+            )
+            .await?;
+
+        tracing::debug!(
+            delivery_id = %delivery.delivery_id,
+            status = delivery.status.as_str(),
+            attempts = delivery.attempts,
+            "Webhook delivery status persisted"
+        );
+
+        Ok(())
+    }
+
+    async fn list_for_day(
+        &self,
+        day: NaiveDate,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, PersistenceError> {
+        let query = format!(
+            "SELECT {} FROM {}.webhook_deliveries WHERE day = ?",
+            DELIVERY_COLUMNS,
+            self.client.keyspace()
+        );
+        let result = self.client.execute(query, (day.to_string(),)).await?;
+
+        let mut deliveries = Vec::new();
+        for row in result.rows.unwrap_or_default() {
+            let (
+                delivery_id,
+                endpoint,
+                event_type,
+                event_id,
+                session_id,
+                payload_json,
+                status,
+                attempts,
+                response_status,
+                last_error,
+                created_at,
+                updated_at,
+            ): (
+                Uuid,
+                String,
+                String,
+                String,
+                String,
+                String,
+                String,
+                i32,
+                Option<i32>,
+                Option<String>,
+                i64,
+                i64,
+            ) = row
+                .into_typed()
+                .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+
+            deliveries.push(WebhookDelivery {
+                delivery_id,
+                endpoint,
+                event_type,
+                event_id,
+                session_id,
+                payload_json,
+                status: WebhookDeliveryStatus::parse(&status),
+                attempts,
+                response_status,
+                last_error,
+                created_at: DateTime::from_timestamp_millis(created_at).unwrap_or_else(Utc::now),
+                updated_at: DateTime::from_timestamp_millis(updated_at).unwrap_or_else(Utc::now),
+            });
+        }
+
+        deliveries.sort_by_key(|d| Reverse(d.created_at));
+        deliveries.truncate(limit);
+        Ok(deliveries)
+    }
+}
+
+/// In-memory webhook delivery log
+///
+/// Used when persistence is not configured; nothing survives restarts.
+#[derive(Default)]
+pub struct InMemoryWebhookDeliveryStore {
+    deliveries: RwLock<HashMap<Uuid, WebhookDelivery>>,
+}
+
+impl InMemoryWebhookDeliveryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryStore for InMemoryWebhookDeliveryStore {
+    async fn upsert(&self, delivery: &WebhookDelivery) -> Result<(), PersistenceError> {
+        self.deliveries
+            .write()
+            .await
+            .insert(delivery.delivery_id, delivery.clone());
+        Ok(())
+    }
+
+    async fn list_for_day(
+        &self,
+        day: NaiveDate,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, PersistenceError> {
+        let mut deliveries: Vec<WebhookDelivery> = self
+            .deliveries
+            .read()
+            .await
+            .values()
+            .filter(|d| d.day() == day)
+            .cloned()
+            .collect();
+        deliveries.sort_by_key(|d| Reverse(d.created_at));
+        deliveries.truncate(limit);
+        Ok(deliveries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[tokio::test]
+    async fn test_in_memory_deliveries_newest_first() {
+        let store = InMemoryWebhookDeliveryStore::new();
+        let at = |hour| Utc.with_ymd_and_hms(2025, 3, 10, hour, 0, 0).unwrap();
+        let mut first = WebhookDelivery::new("bank", "lead_captured", "e1", "s1", "{}".into());
+        first.created_at = at(9);
+        let mut second = WebhookDelivery::new("bank", "call_completed", "e2", "s1", "{}".into());
+        second.created_at = at(10);
+        store.upsert(&first).await.unwrap();
+        store.upsert(&second).await.unwrap();
+
+        second.status = WebhookDeliveryStatus::Delivered;
+        second.attempts = 1;
+        store.upsert(&second).await.unwrap();
+
+        let listed = store.list_for_day(second.day(), 10).await.unwrap();
+        assert_eq!(listed.len(), 2);
+        assert_eq!(listed[0].event_id, "e2");
+        assert_eq!(listed[0].status, WebhookDeliveryStatus::Delivered);
+        assert_eq!(store.list_for_day(second.day(), 1).await.unwrap().len(), 1);
+    }
+}
@@ -621,6 +621,121 @@ pub fn create_noise_suppressor(sample_rate: u32) -> Box<dyn AudioProcessor> {
     }
 }
 
+/// Noise suppression with a small ONNX denoiser model
+///
+/// The model maps a frame of noisy audio `input` [1, samples] to the clean
+/// frame `output` [1, samples] at the pipeline's input sample rate, so no
+/// resampling or buffering is added on top of the model's own run time.
+#[cfg(feature = "onnx")]
+pub struct OnnxDenoiserProcessor {
+    session: Mutex<ort::session::Session>,
+}
+
+#[cfg(feature = "onnx")]
+impl OnnxDenoiserProcessor {
+    /// Load a denoiser model
+    pub fn new(model_path: impl AsRef<std::path::Path>) -> Result<Self, PipelineError> {
+        use ort::session::{builder::GraphOptimizationLevel, Session};
+
+        let session = Session::builder()
+            .map_err(|e| PipelineError::Model(e.to_string()))?
+            .with_optimization_level(GraphOptimizationLevel::Level3)
+            .map_err(|e| PipelineError::Model(e.to_string()))?
+            .with_intra_threads(1)
+            .map_err(|e| PipelineError::Model(e.to_string()))?
+            .commit_from_file(model_path)
+            .map_err(|e| PipelineError::Model(e.to_string()))?;
+        tracing::info!("ONNX denoiser initialized");
+        Ok(Self {
+            session: Mutex::new(session),
+        })
+    }
+
+    fn denoise(&self, samples: &[f32]) -> Result<Vec<f32>, PipelineError> {
+        let input = ndarray::Array2::from_shape_vec((1, samples.len()), samples.to_vec())
+            .map_err(|e| PipelineError::Model(e.to_string()))?;
+        let input_tensor = ort::value::Tensor::from_array(input)
+            .map_err(|e| PipelineError::Model(e.to_string()))?;
+
+        let mut session = self.session.lock();
+        let outputs = session
+            .run(ort::inputs!["input" => input_tensor])
+            .map_err(|e| PipelineError::Model(e.to_string()))?;
+        let (_, clean) = outputs
+            .get("output")
+            .ok_or_else(|| PipelineError::Model("Missing output tensor".to_string()))?
+            .try_extract_tensor::<f32>()
+            .map_err(|e| PipelineError::Model(e.to_string()))?;
+        if clean.len() != samples.len() {
+            return Err(PipelineError::Model(format!(
+                "Denoiser returned {} samples for {}",
+                clean.len(),
+                samples.len()
+            )));
+        }
+        Ok(clean.to_vec())
+    }
+}
+
+#[cfg(feature = "onnx")]
+#[async_trait]
+impl AudioProcessor for OnnxDenoiserProcessor {
+    async fn process(
+        &self,
+        input: &AudioFrame,
+        _reference: Option<&AudioFrame>,
+    ) -> CoreResult<AudioFrame> {
+        if input.samples.is_empty() {
+            return Ok(input.clone());
+        }
+        let samples = self.denoise(&input.samples)?;
+        Ok(AudioFrame::new(
+            samples,
+            input.sample_rate,
+            input.channels,
+            input.sequence,
+        ))
+    }
+
+    fn name(&self) -> &str {
+        "onnx-denoiser"
+    }
+
+    fn reset(&self) {}
+}
+
+/// Create the inbound denoiser a deployment is configured for
+///
+/// Passthrough when `noise_suppression` is off, or when the chosen backend
+/// is not compiled in or fails to load, so a call is never lost to it.
+pub fn create_denoiser(config: &voice_agent_config::AudioConfig) -> Box<dyn AudioProcessor> {
+    use voice_agent_config::DenoiserBackend;
+
+    if !config.noise_suppression {
+        return Box::new(PassthroughAudioProcessor::with_name("passthrough-no-ns"));
+    }
+    match config.denoiser.backend {
+        DenoiserBackend::Rnnoise => create_noise_suppressor(config.input_sample_rate),
+        #[cfg(feature = "onnx")]
+        DenoiserBackend::Onnx => match OnnxDenoiserProcessor::new(&config.denoiser.model_path) {
+            Ok(denoiser) => Box::new(denoiser),
+            Err(e) => {
+                tracing::warn!(
+                    model_path = %config.denoiser.model_path,
+                    error = %e,
+                    "Failed to load ONNX denoiser, using passthrough"
+                );
+                Box::new(PassthroughAudioProcessor::with_name("passthrough-no-ns"))
+            },
+        },
+        #[cfg(not(feature = "onnx"))]
+        DenoiserBackend::Onnx => {
+            tracing::warn!("onnx feature not enabled, using passthrough denoiser");
+            Box::new(PassthroughAudioProcessor::with_name("passthrough-no-ns"))
+        },
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -652,4 +767,13 @@ mod tests {
         let result = processor.process(&frame, None).await.unwrap();
         assert_eq!(result.samples, frame.samples);
     }
+
+    #[test]
+    fn test_denoiser_off_is_passthrough() {
+        let config = voice_agent_config::AudioConfig {
+            noise_suppression: false,
+            ..Default::default()
+        };
+        assert_eq!(create_denoiser(&config).name(), "passthrough-no-ns");
+    }
 }
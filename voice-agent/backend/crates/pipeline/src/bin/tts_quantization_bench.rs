@@ -0,0 +1,325 @@
+//! tts-quantization-bench: IndicF5 latency and quality per weight precision
+//!
+//! Loads the IndicF5 model once per precision on CPU, synthesizes a fixed
+//! set of sentences with the same flow-matching seed, and reports load time,
+//! per-sentence latency, real-time factor and distortion against the F32
+//! rendering of the same sentence (see `compare_audio`). Use it to choose
+//! `pipeline.tts.quantization` for a CPU-only deployment.
+//!
+//! ```text
+//! tts-quantization-bench --model PATH [--reference WAV]
+//!                        [--modes f32,f16,bf16,int8,int4] [--runs N]
+//!                        [--steps N] [--seed N] [--json]
+//! ```
+//!
+//! Exit codes: 0 = every mode ran, 1 = a mode failed to load or
+//! synthesize, 2 = usage error.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Instant;
+
+use candle_core::Device;
+use serde::Serialize;
+
+use voice_agent_core::stats::percentile;
+use voice_agent_pipeline::tts::candle::FlowMatchingConfig;
+use voice_agent_pipeline::{
+    compare_audio, AudioDistortion, IndicF5Config, IndicF5Model, TtsQuantization,
+};
+
+const USAGE: &str = "Usage: tts-quantization-bench --model PATH [--reference WAV] \
+[--modes f32,f16,bf16,int8,int4] [--runs N] [--steps N] [--seed N] [--json]
+
+Options:
+  --model PATH      IndicF5 SafeTensors weights
+  --reference WAV   Reference voice (24kHz mono; default: 1s of silence)
+  --modes LIST      Precisions to compare (default: f32,f16,bf16,int8,int4)
+  --runs N          Timed runs per sentence after a warm-up (default: 3)
+  --steps N         Flow-matching steps (default: model default)
+  --seed N          Flow-matching noise seed (default: 42)
+  --json            Print the report as JSON";
+
+const MODES: [TtsQuantization; 5] = [
+    TtsQuantization::F32,
+    TtsQuantization::F16,
+    TtsQuantization::BF16,
+    TtsQuantization::Int8,
+    TtsQuantization::Int4,
+];
+
+/// Sentences typical of agent responses, short to long
+const SENTENCES: &[&str] = &[
+    "नमस्ते, मैं आपकी कैसे मदद कर सकती हूँ?",
+    "आपके पचास ग्राम सोने पर लगभग दो लाख रुपये का लोन मिल सकता है।",
+    "ब्याज दर नौ दशमलव पाँच प्रतिशत सालाना है, और आप कभी भी बिना किसी शुल्क के लोन चुका सकते हैं।",
+];
+
+struct Args {
+    model: PathBuf,
+    reference: Option<PathBuf>,
+    modes: Vec<TtsQuantization>,
+    runs: usize,
+    steps: Option<usize>,
+    seed: u64,
+    json: bool,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut model = None;
+    let mut args = Args {
+        model: PathBuf::new(),
+        reference: None,
+        modes: MODES.to_vec(),
+        runs: 3,
+        steps: None,
+        seed: 42,
+        json: false,
+    };
+
+    fn value(iter: &mut impl Iterator<Item = String>, flag: &str) -> Result<String, String> {
+        iter.next().ok_or(format!("{} requires a value", flag))
+    }
+    fn number<T: std::str::FromStr>(raw: String, flag: &str) -> Result<T, String> {
+        raw.parse()
+            .map_err(|_| format!("{} expects a number, got '{}'", flag, raw))
+    }
+
+    let mut iter = std::env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        let flag = arg.as_str();
+        match flag {
+            "--model" => model = Some(PathBuf::from(value(&mut iter, flag)?)),
+            "--reference" => args.reference = Some(PathBuf::from(value(&mut iter, flag)?)),
+            "--modes" => {
+                args.modes = value(&mut iter, flag)?
+                    .split(',')
+                    .map(|name| {
+                        let name = name.trim();
+                        MODES
+                            .into_iter()
+                            .find(|mode| mode.as_str() == name)
+                            .ok_or(format!("Unknown mode: {}", name))
+                    })
+                    .collect::<Result<_, _>>()?;
+            },
+            "--runs" => args.runs = number(value(&mut iter, flag)?, flag)?,
+            "--steps" => args.steps = Some(number(value(&mut iter, flag)?, flag)?),
+            "--seed" => args.seed = number(value(&mut iter, flag)?, flag)?,
+            "--json" => args.json = true,
+            "--help" | "-h" => return Err(USAGE.to_string()),
+            other => return Err(format!("Unknown option: {}\n\n{}", other, USAGE)),
+        }
+    }
+
+    args.model = model.ok_or(format!("--model is required\n\n{}", USAGE))?;
+    if args.runs == 0 {
+        return Err("--runs must be at least 1".to_string());
+    }
+    Ok(args)
+}
+
+/// 24kHz mono reference voice, or one second of silence
+fn load_reference(path: Option<&PathBuf>) -> Result<Vec<f32>, String> {
+    let Some(path) = path else {
+        return Ok(vec![0.0; 24000]);
+    };
+    let mut reader = hound::WavReader::open(path)
+        .map_err(|e| format!("failed to open {}: {}", path.display(), e))?;
+    let spec = reader.spec();
+    let samples: Result<Vec<f32>, _> = match spec.sample_format {
+        hound::SampleFormat::Float => reader.samples::<f32>().collect(),
+        hound::SampleFormat::Int => {
+            let scale = (1u32 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .map(|s| s.map(|s| s as f32 / scale))
+                .collect()
+        },
+    };
+    let samples = samples.map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+    // Keep the first channel
+    Ok(samples
+        .into_iter()
+        .step_by(spec.channels.max(1) as usize)
+        .collect())
+}
+
+#[derive(Debug, Serialize)]
+struct ModeReport {
+    mode: &'static str,
+    estimated_memory_mb: usize,
+    load_ms: f64,
+    mean_latency_ms: f64,
+    p95_latency_ms: f64,
+    /// Synthesis time over audio duration (< 1 is faster than real time)
+    real_time_factor: f64,
+    /// Mean over sentences; None for F32 or when F32 was not run
+    snr_db: Option<f32>,
+    log_spectral_distance_db: Option<f32>,
+    length_ratio: Option<f32>,
+}
+
+fn elapsed_ms(since: Instant) -> f64 {
+    since.elapsed().as_secs_f64() * 1000.0
+}
+
+/// Load one precision and time every sentence; returns the report and the
+/// audio of the first run per sentence
+fn run_mode(
+    args: &Args,
+    mode: TtsQuantization,
+    reference: &[f32],
+    baseline: Option<&[Vec<f32>]>,
+) -> Result<(ModeReport, Vec<Vec<f32>>), String> {
+    let config = IndicF5Config::indicf5_hindi().with_quantization(mode);
+    let estimated_memory_mb = config.estimated_memory() / 1024 / 1024;
+    let sample_rate = config.sample_rate as f64;
+
+    let started = Instant::now();
+    let mut model = IndicF5Model::load_with_config(&args.model, None, config, Device::Cpu)
+        .map_err(|e| format!("{}: failed to load: {}", mode.as_str(), e))?;
+    let load_ms = elapsed_ms(started);
+    model.set_noise_seed(Some(args.seed));
+    if let Some(steps) = args.steps {
+        model.set_inference_params(steps, FlowMatchingConfig::default().cfg_strength);
+    }
+
+    let synthesize = |text: &str| {
+        model
+            .synthesize(text, reference)
+            .map_err(|e| format!("{}: synthesis failed: {}", mode.as_str(), e))
+    };
+
+    let mut latencies = Vec::new();
+    let mut audio_secs = 0.0;
+    let mut outputs = Vec::new();
+    for sentence in SENTENCES {
+        // Warm-up run; its output is the one compared across modes
+        let audio = synthesize(sentence)?;
+        for _ in 0..args.runs {
+            let started = Instant::now();
+            synthesize(sentence)?;
+            latencies.push(elapsed_ms(started));
+            audio_secs += audio.len() as f64 / sample_rate;
+        }
+        outputs.push(audio);
+    }
+
+    let total_ms: f64 = latencies.iter().sum();
+    latencies.sort_by(|a, b| a.total_cmp(b));
+    let mut report = ModeReport {
+        mode: mode.as_str(),
+        estimated_memory_mb,
+        load_ms,
+        mean_latency_ms: total_ms / latencies.len() as f64,
+        p95_latency_ms: percentile(&latencies, 0.95),
+        real_time_factor: if audio_secs > 0.0 {
+            total_ms / 1000.0 / audio_secs
+        } else {
+            0.0
+        },
+        snr_db: None,
+        log_spectral_distance_db: None,
+        length_ratio: None,
+    };
+
+    if let Some(baseline) = baseline {
+        let distortions: Vec<_> = baseline
+            .iter()
+            .zip(&outputs)
+            .map(|(reference, candidate)| compare_audio(reference, candidate))
+            .collect();
+        let mean = |metric: fn(&AudioDistortion) -> f32| {
+            Some(distortions.iter().map(metric).sum::<f32>() / distortions.len() as f32)
+        };
+        report.snr_db = mean(|d| d.snr_db);
+        report.log_spectral_distance_db = mean(|d| d.log_spectral_distance_db);
+        report.length_ratio = mean(|d| d.length_ratio);
+    }
+    Ok((report, outputs))
+}
+
+fn print_report(reports: &[ModeReport]) {
+    let optional = |value: Option<f32>| match value {
+        Some(v) if v.is_infinite() => "inf".to_string(),
+        Some(v) => format!("{:.2}", v),
+        None => "-".to_string(),
+    };
+    println!(
+        "{:<6} {:>8} {:>9} {:>10} {:>10} {:>6} {:>8} {:>8} {:>7}",
+        "mode", "mem_mb", "load_ms", "mean_ms", "p95_ms", "rtf", "snr_db", "lsd_db", "len"
+    );
+    for r in reports {
+        println!(
+            "{:<6} {:>8} {:>9.0} {:>10.1} {:>10.1} {:>6.2} {:>8} {:>8} {:>7}",
+            r.mode,
+            r.estimated_memory_mb,
+            r.load_ms,
+            r.mean_latency_ms,
+            r.p95_latency_ms,
+            r.real_time_factor,
+            optional(r.snr_db),
+            optional(r.log_spectral_distance_db),
+            optional(r.length_ratio),
+        );
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::from(2);
+        },
+    };
+    let reference = match load_reference(args.reference.as_ref()) {
+        Ok(reference) => reference,
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::from(2);
+        },
+    };
+
+    // F32 runs first so every other mode is compared against it
+    let mut modes = args.modes.clone();
+    modes.sort_by_key(|mode| *mode != TtsQuantization::F32);
+
+    let mut reports = Vec::new();
+    let mut baseline: Option<Vec<Vec<f32>>> = None;
+    let mut failed = false;
+    for mode in modes {
+        let compare_to = baseline.as_deref().filter(|_| mode != TtsQuantization::F32);
+        match run_mode(&args, mode, &reference, compare_to) {
+            Ok((report, outputs)) => {
+                if mode == TtsQuantization::F32 {
+                    baseline = Some(outputs);
+                }
+                if !args.json {
+                    eprintln!("{}: done", report.mode);
+                }
+                reports.push(report);
+            },
+            Err(message) => {
+                eprintln!("{}", message);
+                failed = true;
+            },
+        }
+    }
+
+    if args.json {
+        match serde_json::to_string_pretty(&reports) {
+            Ok(json) => println!("{}", json),
+            Err(e) => eprintln!("failed to serialize report: {}", e),
+        }
+    } else {
+        print_report(&reports);
+    }
+
+    if failed {
+        ExitCode::from(1)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -8,6 +8,8 @@
 //! - Barge-in handling
 //! - Frame processors (SentenceDetector, InterruptHandler)
 //! - Channel-based processor chains
+//! - Inbound noise suppression (RNNoise or an ONNX denoiser)
+//! - Wake word gating for kiosk deployments
 
 pub mod adapters;
 pub mod orchestrator;
@@ -16,13 +18,16 @@ pub mod stt;
 pub mod tts;
 pub mod turn_detection;
 pub mod vad;
+pub mod wake_word;
+pub mod workers;
 
 // VAD exports
 pub use vad::{VadConfig, VadResult, VadState, VoiceActivityDetector};
 
 // Turn detection exports
 pub use turn_detection::{
-    HybridTurnDetector, SemanticTurnDetector, TurnDetectionConfig, TurnDetectionResult, TurnState,
+    EndOfTurnPrediction, EndOfTurnPredictor, HybridTurnDetector, SemanticTurnDetector,
+    TurnDecision, TurnDetectionConfig, TurnDetectionResult, TurnState,
 };
 
 // STT exports
@@ -30,15 +35,30 @@ pub use stt::{DecoderConfig, EnhancedDecoder, StreamingStt, SttConfig, SttEngine
 // P2 FIX: Export STT backend types and factory
 pub use stt::{
     create_indicconformer, create_stt_backend, IndicConformerBackend, IndicConformerConfig,
-    SttBackend, StubSttBackend,
+    ScriptedSttBackend, SttBackend, StubSttBackend,
 };
 
 // TTS exports
-pub use tts::{ChunkStrategy, StreamingTts, TtsConfig, TtsEngine, TtsEvent, WordChunker};
+pub use tts::{
+    compare_audio, AudioDistortion, ChunkStrategy, PronunciationLexicon, ProsodySupport,
+    SpeechStyle, StreamingTts, SynthesisCache, TextNormalizer, TtsConfig, TtsEngine, TtsEvent,
+    VoiceRegistry, WordChunker,
+};
 // P1-3 FIX: Export TTS backend types and factory
 pub use tts::{create_tts_backend, StubTtsBackend, TtsBackend};
 #[cfg(feature = "candle")]
-pub use tts::{IndicF5Backend, IndicF5Config, IndicF5Model};
+pub use tts::{IndicF5Backend, IndicF5Config, IndicF5Model, TtsQuantization};
+
+// Wake word exports
+#[cfg(feature = "onnx")]
+pub use wake_word::OnnxSpotter;
+pub use wake_word::{KeywordSpotter, WakeWordDetector, WakeWordMatch};
+
+// Shared model worker pool exports
+pub use workers::{
+    BatchEngine, PoolClient, PooledSttBackend, PooledTtsBackend, SttBatchEngine, TtsBatchEngine,
+    WorkerPool, WorkerPoolStats,
+};
 
 // Orchestrator exports
 pub use orchestrator::{
@@ -72,7 +92,11 @@ pub use processors::{
 // P3 FIX: Trait adapter exports - bridge internal STT/TTS with core traits
 #[cfg(feature = "noise-suppression")]
 pub use adapters::NoiseSuppressorProcessor;
+#[cfg(feature = "onnx")]
+pub use adapters::OnnxDenoiserProcessor;
 pub use adapters::{
+    // Inbound denoiser chosen by `pipeline.audio`
+    create_denoiser,
     // P2-1 FIX: Noise suppression processor
     create_noise_suppressor,
     create_passthrough_processor,
@@ -143,3 +167,21 @@ impl From<PipelineError> for voice_agent_core::Error {
         voice_agent_core::Error::Pipeline(core_err)
     }
 }
+
+impl voice_agent_core::ErrorClass for PipelineError {
+    fn kind(&self) -> voice_agent_core::ErrorKind {
+        use voice_agent_core::ErrorKind;
+        match self {
+            PipelineError::Timeout => ErrorKind::Timeout,
+            PipelineError::NotInitialized => ErrorKind::Internal,
+            PipelineError::Vad(_)
+            | PipelineError::TurnDetection(_)
+            | PipelineError::Stt(_)
+            | PipelineError::Tts(_)
+            | PipelineError::Model(_)
+            | PipelineError::ChannelClosed
+            | PipelineError::Audio(_)
+            | PipelineError::Io(_) => ErrorKind::Unavailable,
+        }
+    }
+}
@@ -12,17 +12,25 @@
 use futures::StreamExt;
 use parking_lot::Mutex;
 use std::sync::Arc;
-use std::time::Instant;
-use tokio::sync::{broadcast, mpsc};
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, mpsc, Mutex as AsyncMutex};
 
 use crate::stt::{IndicConformerConfig, IndicConformerStt, StreamingStt, SttBackend, SttConfig};
-use crate::tts::{StreamingTts, TtsConfig, TtsEvent};
-use crate::turn_detection::{HybridTurnDetector, TurnDetectionConfig, TurnDetectionResult};
+use crate::tts::{
+    PronunciationLexicon, SpeechStyle, StreamingTts, SynthesisCache, TtsConfig, TtsEvent,
+};
+use crate::turn_detection::{
+    EndOfTurnPrediction, HybridTurnDetector, TurnDecision, TurnDetectionConfig, TurnDetectionResult,
+};
 use crate::vad::{SileroConfig, SileroVad, VadConfig, VadEngine, VadState, VoiceActivityDetector};
+use crate::workers::{
+    PooledSttBackend, PooledTtsBackend, SttBatchEngine, TtsBatchEngine, WorkerPool,
+};
 use crate::PipelineError;
 use voice_agent_core::{
-    AudioFrame, AudioProcessor, ControlFrame, Frame, GenerateRequest, Language, LanguageModel,
-    ProcessorContext, TextProcessor, TranscriptResult,
+    AudioFrame, AudioProcessor, AudioQuality, AudioQualityAnalyzer, AudioQualityConfig,
+    AvatarCues, ControlFrame, Frame, GenerateRequest, JitterStats, Language, LanguageModel,
+    LineIssue, ProcessorContext, Samples, TextProcessor, TranscriptResult,
 };
 
 // P1 FIX: Import processors for streaming LLM → TTS pipeline
@@ -38,6 +46,8 @@ pub enum PipelineEvent {
     VadStateChanged(VadState),
     /// Turn state changed
     TurnStateChanged(TurnDetectionResult),
+    /// End-of-turn predictor decided the caller has finished speaking
+    EndOfTurn(EndOfTurnPrediction),
     /// Partial transcript available
     PartialTranscript(TranscriptResult),
     /// Final transcript available
@@ -49,7 +59,7 @@ pub enum PipelineEvent {
     },
     /// TTS audio chunk ready
     TtsAudio {
-        samples: Arc<[f32]>,
+        samples: Samples,
         text: String,
         is_final: bool,
     },
@@ -58,6 +68,18 @@ pub enum PipelineEvent {
         /// Word index where user interrupted
         at_word: usize,
     },
+    /// Text of the interrupted response the caller actually heard
+    SpokenUpTo {
+        text: String,
+        /// Playback position where audio stopped
+        position_ms: u64,
+    },
+    /// Viseme and gesture cues for the TTS audio that follows
+    AvatarCues(AvatarCues),
+    /// Key pressed on the caller's phone keypad (DTMF: 0-9, *, #, A-D)
+    Dtmf { key: char },
+    /// The line became hard to understand, or clear again
+    AudioQuality(AudioQuality),
     /// Error occurred
     Error(String),
 }
@@ -81,6 +103,18 @@ pub struct PipelineConfig {
     pub processors: ProcessorChainConfig,
     /// P0-3 FIX: LLM configuration for automatic response generation
     pub llm: LlmConfig,
+    /// Shared synthesis cache for repeated utterances (greetings, disclosures)
+    pub tts_cache: Option<Arc<SynthesisCache>>,
+    /// Shared pronunciation overrides for brand and place names
+    pub lexicon: Option<Arc<PronunciationLexicon>>,
+    /// Shared STT worker pool (None = this session loads its own model)
+    pub stt_pool: Option<Arc<WorkerPool<SttBatchEngine>>>,
+    /// Shared TTS worker pool (None = this session loads its own model)
+    pub tts_pool: Option<Arc<WorkerPool<TtsBatchEngine>>>,
+    /// Line quality limits; speech detection is stricter on a noisy line
+    pub audio_quality: AudioQualityConfig,
+    /// Average latency the noise suppressor may add per frame (ms)
+    pub denoise_budget_ms: f32,
 }
 
 /// P0-3 FIX: LLM configuration for the pipeline
@@ -149,6 +183,12 @@ impl Default for PipelineConfig {
             latency_budget_ms: 500,
             processors: ProcessorChainConfig::default(),
             llm: LlmConfig::default(),
+            tts_cache: None,
+            lexicon: None,
+            stt_pool: None,
+            tts_pool: None,
+            audio_quality: AudioQualityConfig::default(),
+            denoise_budget_ms: 5.0,
         }
     }
 }
@@ -208,8 +248,8 @@ pub struct VoicePipeline {
     config: PipelineConfig,
     vad: Arc<dyn VadEngine>,
     turn_detector: Arc<HybridTurnDetector>,
-    /// STT backend (StreamingStt or IndicConformerStt)
-    stt: Arc<Mutex<dyn SttBackend + Send>>,
+    /// STT backend (StreamingStt or IndicConformerStt); held across `finalize`
+    stt: Arc<AsyncMutex<dyn SttBackend + Send>>,
     tts: Arc<StreamingTts>,
     state: Mutex<PipelineState>,
     /// Event broadcaster
@@ -218,6 +258,10 @@ pub struct VoicePipeline {
     barge_in_speech_ms: Mutex<u32>,
     /// Last audio timestamp
     last_audio_time: Mutex<Instant>,
+    /// Inbound line quality, measured on raw audio
+    quality: Mutex<AudioQualityAnalyzer>,
+    /// Line issue last reported
+    line_issue: Mutex<Option<LineIssue>>,
     /// P1 FIX: Processor chain for streaming LLM → TTS
     /// Contains: SentenceDetector → TtsProcessor → InterruptHandler
     processor_chain: Option<ProcessorChain>,
@@ -229,6 +273,8 @@ pub struct VoicePipeline {
     text_processor: Option<Arc<dyn TextProcessor>>,
     /// P2 FIX: Noise suppressor for cleaning audio before VAD/STT
     noise_suppressor: Option<Arc<dyn AudioProcessor>>,
+    /// Running average of the latency noise suppression adds (ms)
+    denoise_ms: Mutex<Option<f32>>,
 }
 
 impl VoicePipeline {
@@ -268,9 +314,21 @@ impl VoicePipeline {
         };
 
         let turn_detector = Arc::new(HybridTurnDetector::new(config.turn_detection.clone()));
-        let stt: Arc<Mutex<dyn SttBackend + Send>> =
-            Arc::new(Mutex::new(StreamingStt::simple(config.stt.clone())));
-        let tts = Arc::new(StreamingTts::simple(config.tts.clone()));
+        let stt: Arc<AsyncMutex<dyn SttBackend + Send>> = match config.stt_pool {
+            Some(ref pool) => Arc::new(AsyncMutex::new(PooledSttBackend::new(pool))),
+            None => Arc::new(AsyncMutex::new(StreamingStt::simple(config.stt.clone()))),
+        };
+        let mut tts = match config.tts_pool {
+            Some(ref pool) => StreamingTts::with_backend(
+                Arc::new(PooledTtsBackend::new(pool)),
+                config.tts.clone(),
+            ),
+            None => StreamingTts::simple(config.tts.clone()),
+        };
+        if let Some(lexicon) = config.lexicon.clone() {
+            tts = tts.with_lexicon(lexicon);
+        }
+        let tts = Arc::new(tts);
 
         // Use larger capacity to avoid lagging slow receivers
         let (event_tx, _) = broadcast::channel(1000);
@@ -282,6 +340,7 @@ impl VoicePipeline {
             None
         };
 
+        let quality = AudioQualityAnalyzer::new(config.audio_quality.clone());
         Ok(Self {
             config,
             vad,
@@ -292,11 +351,14 @@ impl VoicePipeline {
             event_tx,
             barge_in_speech_ms: Mutex::new(0),
             last_audio_time: Mutex::new(Instant::now()),
+            quality: Mutex::new(quality),
+            line_issue: Mutex::new(None),
             processor_chain,
             llm: None, // P0-3 FIX: LLM not set by default, use with_llm()
             pending_transcript: Mutex::new(None),
             text_processor: None, // P0 FIX: Not set by default, use with_text_processor()
             noise_suppressor: None, // P2 FIX: Not set by default, use with_noise_suppressor()
+            denoise_ms: Mutex::new(None),
         })
     }
 
@@ -355,49 +417,41 @@ impl VoicePipeline {
 
         let turn_detector = Arc::new(HybridTurnDetector::new(config.turn_detection.clone()));
 
-        // Create IndicConformer STT with ONNX models
-        let indicconformer_config = IndicConformerConfig {
-            language: config.stt.language.clone().unwrap_or_else(|| "hi".to_string()),
-            sample_rate: config.stt.sample_rate,
-            chunk_ms: config.stt.chunk_ms,
-            enable_partials: config.stt.enable_partials,
-            partial_interval: config.stt.partial_interval,
-            decoder: config.stt.decoder.clone(),
-            ..Default::default()
+        // Create IndicConformer STT with ONNX models, unless sessions share a pool
+        let stt: Arc<AsyncMutex<dyn SttBackend + Send>> = match config.stt_pool {
+            Some(ref pool) => Arc::new(AsyncMutex::new(PooledSttBackend::new(pool))),
+            None => {
+                let stt =
+                    IndicConformerStt::new(model_dir, Self::indicconformer_config(&config.stt))?;
+                Arc::new(AsyncMutex::new(stt))
+            },
         };
 
-        let stt = IndicConformerStt::new(model_dir, indicconformer_config)?;
-        let stt: Arc<Mutex<dyn SttBackend + Send>> = Arc::new(Mutex::new(stt));
-
-        // P0 FIX: Configure TTS with IndicF5 model if available
-        // IndicF5 uses SafeTensors format, model directory contains model.safetensors
-        let tts_model_path = std::path::Path::new("models/tts/IndicF5");
-        let tts_reference_path = std::path::Path::new("models/tts/IndicF5/samples/namaste.wav");
-
-        let tts_config = if tts_model_path.exists() {
-            if tts_reference_path.exists() {
-                tracing::info!("Configuring TTS with IndicF5 model and reference audio");
-                TtsConfig::indicf5_with_reference(tts_model_path, tts_reference_path)
-            } else {
-                tracing::info!("Configuring TTS with IndicF5 model (no reference audio)");
-                TtsConfig::indicf5(tts_model_path)
-            }
-        } else {
-            tracing::warn!("IndicF5 TTS model not found at {}, using default TTS config", tts_model_path.display());
-            config.tts.clone()
-        };
+        let tts_config = Self::indicf5_tts_config(&config.tts);
 
         // P0 FIX: Use from_config to load real TTS model, fallback to simple (silence) on error
-        let tts = match StreamingTts::from_config(tts_config.clone()) {
-            Ok(tts) => {
-                tracing::info!("TTS model loaded successfully");
-                Arc::new(tts)
-            }
-            Err(e) => {
-                tracing::warn!("Failed to load TTS model: {}, using silence TTS", e);
-                Arc::new(StreamingTts::simple(tts_config))
-            }
+        let mut tts = match config.tts_pool {
+            Some(ref pool) => {
+                StreamingTts::with_backend(Arc::new(PooledTtsBackend::new(pool)), tts_config)
+            },
+            None => match StreamingTts::from_config(tts_config.clone()) {
+                Ok(tts) => {
+                    tracing::info!("TTS model loaded successfully");
+                    tts
+                },
+                Err(e) => {
+                    tracing::warn!("Failed to load TTS model: {}, using silence TTS", e);
+                    StreamingTts::simple(tts_config)
+                },
+            },
         };
+        if let Some(cache) = config.tts_cache.clone() {
+            tts = tts.with_cache(cache);
+        }
+        if let Some(lexicon) = config.lexicon.clone() {
+            tts = tts.with_lexicon(lexicon);
+        }
+        let tts = Arc::new(tts);
 
         // Use larger capacity to avoid lagging slow receivers
         let (event_tx, _) = broadcast::channel(1000);
@@ -413,6 +467,7 @@ impl VoicePipeline {
             "Created VoicePipeline with IndicConformer STT (ONNX enabled)"
         );
 
+        let quality = AudioQualityAnalyzer::new(config.audio_quality.clone());
         Ok(Self {
             config,
             vad,
@@ -423,14 +478,62 @@ impl VoicePipeline {
             event_tx,
             barge_in_speech_ms: Mutex::new(0),
             last_audio_time: Mutex::new(Instant::now()),
+            quality: Mutex::new(quality),
+            line_issue: Mutex::new(None),
             processor_chain,
             llm: None,
             pending_transcript: Mutex::new(None),
             text_processor: None,
             noise_suppressor: None,
+            denoise_ms: Mutex::new(None),
         })
     }
 
+    /// IndicConformer settings derived from the pipeline STT config
+    pub fn indicconformer_config(stt: &SttConfig) -> IndicConformerConfig {
+        IndicConformerConfig {
+            language: stt.language.clone().unwrap_or_else(|| "hi".to_string()),
+            sample_rate: stt.sample_rate,
+            chunk_ms: stt.chunk_ms,
+            enable_partials: stt.enable_partials,
+            partial_interval: stt.partial_interval,
+            decoder: stt.decoder.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// TTS config for the IndicF5 model if present, else `fallback`
+    pub fn indicf5_tts_config(fallback: &TtsConfig) -> TtsConfig {
+        // P0 FIX: Configure TTS with IndicF5 model if available
+        // IndicF5 uses SafeTensors format, model directory contains model.safetensors
+        let tts_model_path = std::path::Path::new("models/tts/IndicF5");
+        let tts_reference_path = std::path::Path::new("models/tts/IndicF5/samples/namaste.wav");
+
+        if tts_model_path.exists() {
+            let config = if tts_reference_path.exists() {
+                tracing::info!("Configuring TTS with IndicF5 model and reference audio");
+                TtsConfig::indicf5_with_reference(tts_model_path, tts_reference_path)
+            } else {
+                tracing::info!("Configuring TTS with IndicF5 model (no reference audio)");
+                TtsConfig::indicf5(tts_model_path)
+            };
+            // Pacing and weight precision are per deployment, not per engine
+            TtsConfig {
+                speaking_rate: fallback.speaking_rate,
+                pitch: fallback.pitch,
+                sentence_pause_ms: fallback.sentence_pause_ms,
+                quantization: fallback.quantization,
+                ..config
+            }
+        } else {
+            tracing::warn!(
+                "IndicF5 TTS model not found at {}, using default TTS config",
+                tts_model_path.display()
+            );
+            fallback.clone()
+        }
+    }
+
     /// P0-3 FIX: Set the LLM for automatic response generation
     ///
     /// When set, the pipeline will automatically call the LLM when a
@@ -452,6 +555,14 @@ impl VoicePipeline {
         self.llm.is_some()
     }
 
+    /// Replace the STT backend
+    ///
+    /// Used by tests to run fixture audio through a `ScriptedSttBackend`.
+    pub fn with_stt(mut self, stt: impl SttBackend + 'static) -> Self {
+        self.stt = Arc::new(AsyncMutex::new(stt));
+        self
+    }
+
     /// P0 FIX: Set the text processor for pre-LLM processing
     ///
     /// When set, transcripts are processed through grammar correction,
@@ -577,12 +688,27 @@ impl VoicePipeline {
                 tokio::spawn(async move {
                     let mut output_rx = output_rx;
                     while let Some(frame) = output_rx.recv().await {
-                        if let Frame::AudioOutput(audio) = frame {
-                            let _ = pipeline_event_tx.send(PipelineEvent::TtsAudio {
-                                samples: audio.samples.into(),
-                                text: String::new(), // Word text not available in this path
-                                is_final: false,
-                            });
+                        match frame {
+                            Frame::AudioOutput(audio) => {
+                                let _ = pipeline_event_tx.send(PipelineEvent::TtsAudio {
+                                    samples: audio.samples,
+                                    text: String::new(), // Word text not available in this path
+                                    is_final: false,
+                                });
+                            },
+                            Frame::SpokenUpTo {
+                                text,
+                                audio_position_ms,
+                            } => {
+                                let _ = pipeline_event_tx.send(PipelineEvent::SpokenUpTo {
+                                    text,
+                                    position_ms: audio_position_ms,
+                                });
+                            },
+                            Frame::AvatarCues(cues) => {
+                                let _ = pipeline_event_tx.send(PipelineEvent::AvatarCues(cues));
+                            },
+                            _ => {},
                         }
                     }
                 })
@@ -732,8 +858,13 @@ impl VoicePipeline {
             );
         }
 
+        // Line quality is judged on the raw audio, before noise suppression
+        let raw_energy_db = frame.energy_db;
+        self.observe_quality(&frame);
+
         // P2 FIX: Apply noise suppression before VAD/STT if configured
         if let Some(ns) = &self.noise_suppressor {
+            let started = Instant::now();
             frame = ns
                 .process(&frame, None)
                 .await
@@ -742,6 +873,7 @@ impl VoicePipeline {
                     e
                 })
                 .unwrap_or(frame);
+            self.record_denoise_latency(started.elapsed(), ns.name());
         }
 
         // 1. Run VAD
@@ -766,7 +898,9 @@ impl VoicePipeline {
 
         // 2. Check for barge-in if speaking
         if *self.state.lock() == PipelineState::Speaking
-            && self.check_barge_in(&frame, vad_state).await?
+            && self
+                .check_barge_in(&frame, raw_energy_db, vad_state)
+                .await?
         {
             return Ok(());
         }
@@ -780,7 +914,9 @@ impl VoicePipeline {
                 // Energy gate: Don't trigger on very quiet audio (likely muted mic or noise)
                 // Real speech typically has energy > -45 dB
                 const MIN_SPEECH_ENERGY_DB: f32 = -45.0;
-                let has_enough_energy = frame.energy_db > MIN_SPEECH_ENERGY_DB;
+                // On a noisy line speech must also stand out from the noise
+                let has_enough_energy = frame.energy_db > MIN_SPEECH_ENERGY_DB
+                    && self.stands_out_from_noise(raw_energy_db);
 
                 if (vad_state == VadState::Speech || vad_state == VadState::SpeechStart) && has_enough_energy {
                     tracing::info!(
@@ -789,7 +925,7 @@ impl VoicePipeline {
                         "Pipeline: Idle -> Listening (speech detected)"
                     );
                     *self.state.lock() = PipelineState::Listening;
-                    self.stt.lock().reset();
+                    self.stt.lock().await.reset();
                 } else if vad_state == VadState::Speech || vad_state == VadState::SpeechStart {
                     tracing::debug!(
                         vad_state = ?vad_state,
@@ -825,7 +961,7 @@ impl VoicePipeline {
                         max = MAX_LISTENING_FRAMES,
                         "Pipeline: Max listening timeout, forcing turn completion"
                     );
-                    let final_transcript = self.stt.lock().finalize_sync();
+                    let final_transcript = self.finalize_transcript().await;
                     tracing::info!(
                         text = %final_transcript.text,
                         confidence = format!("{:.2}", final_transcript.confidence),
@@ -838,13 +974,16 @@ impl VoicePipeline {
                     return Ok(());
                 }
 
+                // Prosody cues for the end-of-turn predictor
+                self.turn_detector.observe_audio(&frame, vad_state);
+
                 // Feed audio to STT
                 // Note: True parallelization with spawn_blocking isn't possible because
                 // ort::Session contains raw pointers that aren't Send. The ONNX runtime
                 // handles threading internally, so this is acceptable for now.
                 let samples_len = frame.samples.len();
                 let stt_start = std::time::Instant::now();
-                let stt_result = self.stt.lock().process(&frame.samples);
+                let stt_result = self.stt.lock().await.process(&frame.samples);
                 let stt_time = stt_start.elapsed();
 
                 // DIAGNOSTIC: Log STT processing time periodically
@@ -887,7 +1026,8 @@ impl VoicePipeline {
 
                         // Check for turn completion
                         if turn_result.is_turn_complete {
-                            let final_transcript = self.stt.lock().finalize_sync();
+                            self.emit_end_of_turn(&turn_result);
+                            let final_transcript = self.finalize_transcript().await;
                             tracing::info!(
                                 text = %final_transcript.text,
                                 confidence = format!("{:.2}", final_transcript.confidence),
@@ -924,7 +1064,8 @@ impl VoicePipeline {
                         // P0-3 FIX: Check for turn completion even without partial transcript
                         // This handles cases where speech ends before we get any partial text
                         if turn_result.is_turn_complete {
-                            let final_transcript = self.stt.lock().finalize_sync();
+                            self.emit_end_of_turn(&turn_result);
+                            let final_transcript = self.finalize_transcript().await;
                             tracing::info!(
                                 text = %final_transcript.text,
                                 confidence = format!("{:.2}", final_transcript.confidence),
@@ -984,10 +1125,38 @@ impl VoicePipeline {
         Ok(())
     }
 
+    /// Final transcript for the utterance; pooled backends are awaited here
+    async fn finalize_transcript(&self) -> TranscriptResult {
+        self.stt.lock().await.finalize().await.unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "STT finalize failed");
+            TranscriptResult::default()
+        })
+    }
+
+    /// Tell the conversation controller the predictor decided to respond
+    fn emit_end_of_turn(&self, turn_result: &TurnDetectionResult) {
+        if let Some(ref prediction) = turn_result.end_of_turn {
+            if prediction.decision == TurnDecision::Respond {
+                tracing::debug!(
+                    probability = format!("{:.2}", prediction.probability),
+                    lexical = format!("{:.2}", prediction.lexical_score),
+                    prosody = format!("{:.2}", prediction.prosody_score),
+                    silence_ms = prediction.silence.as_millis() as u64,
+                    required_ms = prediction.required_silence.as_millis() as u64,
+                    "Pipeline: End of turn predicted"
+                );
+                let _ = self
+                    .event_tx
+                    .send(PipelineEvent::EndOfTurn(prediction.clone()));
+            }
+        }
+    }
+
     /// Check for barge-in during TTS
     async fn check_barge_in(
         &self,
         frame: &AudioFrame,
+        raw_energy_db: f32,
         vad_state: VadState,
     ) -> Result<bool, PipelineError> {
         if !self.config.barge_in.enabled {
@@ -1000,13 +1169,21 @@ impl VoicePipeline {
 
         // Check if user is speaking
         let is_speech = vad_state == VadState::Speech || vad_state == VadState::SpeechStart;
-        let sufficient_energy = frame.energy_db >= self.config.barge_in.min_energy_db;
+        let sufficient_energy = frame.energy_db >= self.config.barge_in.min_energy_db
+            && self.stands_out_from_noise(raw_energy_db);
 
         if is_speech && sufficient_energy {
-            let mut speech_ms = self.barge_in_speech_ms.lock();
-            *speech_ms += self.config.vad.frame_ms;
+            let triggered = {
+                let mut speech_ms = self.barge_in_speech_ms.lock();
+                *speech_ms += self.config.vad.frame_ms;
+                let triggered = *speech_ms >= self.config.barge_in.min_speech_ms;
+                if triggered {
+                    *speech_ms = 0;
+                }
+                triggered
+            };
 
-            if *speech_ms >= self.config.barge_in.min_speech_ms {
+            if triggered {
                 // Barge-in triggered!
                 let word_index = self.tts.current_word_index();
 
@@ -1020,11 +1197,10 @@ impl VoicePipeline {
 
                 // Switch to listening
                 *self.state.lock() = PipelineState::Listening;
-                *speech_ms = 0;
 
                 // Reset turn detector
                 self.turn_detector.reset();
-                self.stt.lock().reset();
+                self.stt.lock().await.reset();
 
                 return Ok(true);
             }
@@ -1035,6 +1211,115 @@ impl VoicePipeline {
         Ok(false)
     }
 
+    /// Measure a raw frame's line quality
+    fn observe_quality(&self, frame: &AudioFrame) {
+        let report = {
+            let mut quality = self.quality.lock();
+            quality.observe(&frame.samples);
+            quality.report()
+        };
+        self.report_quality(report);
+    }
+
+    /// Count frames the inbound jitter buffer concealed as packet loss
+    pub fn record_jitter(&self, stats: &JitterStats) {
+        let report = {
+            let mut quality = self.quality.lock();
+            quality.observe_jitter(stats);
+            quality.report()
+        };
+        self.report_quality(report);
+    }
+
+    /// Emit [`PipelineEvent::AudioQuality`] when the line's issue changes
+    fn report_quality(&self, report: AudioQuality) {
+        let mut line_issue = self.line_issue.lock();
+        if *line_issue == report.issue {
+            return;
+        }
+        *line_issue = report.issue;
+        tracing::info!(
+            issue = ?report.issue,
+            snr_db = ?report.snr_db,
+            packet_loss = format!("{:.2}", report.packet_loss),
+            clipping = format!("{:.3}", report.clipping),
+            "Pipeline: line quality changed"
+        );
+        let _ = self.event_tx.send(PipelineEvent::AudioQuality(report));
+    }
+
+    /// Current quality of the inbound line
+    pub fn audio_quality(&self) -> AudioQuality {
+        self.quality.lock().report()
+    }
+
+    /// Fold one frame's noise suppression time into the running average
+    ///
+    /// Warns when the average goes over `denoise_budget_ms`, e.g. an ONNX
+    /// denoiser too heavy for the host.
+    fn record_denoise_latency(&self, elapsed: Duration, denoiser: &str) {
+        const ALPHA: f32 = 0.05;
+        let ms = elapsed.as_secs_f32() * 1000.0;
+        let mut average = self.denoise_ms.lock();
+        let previous = *average;
+        let current = previous.map_or(ms, |avg| avg + (ms - avg) * ALPHA);
+        *average = Some(current);
+
+        let budget = self.config.denoise_budget_ms;
+        if current > budget && previous.map_or(true, |avg| avg <= budget) {
+            tracing::warn!(
+                denoiser,
+                latency_ms = format!("{:.2}", current),
+                budget_ms = budget,
+                "Pipeline: noise suppression over its latency budget"
+            );
+        }
+    }
+
+    /// Average latency noise suppression adds per frame (ms), once measured
+    pub fn denoise_latency_ms(&self) -> Option<f32> {
+        *self.denoise_ms.lock()
+    }
+
+    /// Whether a frame is loud enough over the line noise to be speech
+    ///
+    /// Only noisy lines are checked: there speech must be
+    /// `NOISY_SPEECH_MARGIN_DB` above the noise floor (raw energy) to start
+    /// a turn or cut the agent off.
+    fn stands_out_from_noise(&self, raw_energy_db: f32) -> bool {
+        const NOISY_SPEECH_MARGIN_DB: f32 = 10.0;
+        if *self.line_issue.lock() != Some(LineIssue::Noise) {
+            return true;
+        }
+        match self.quality.lock().report().noise_floor_db {
+            Some(floor) => raw_energy_db >= floor + NOISY_SPEECH_MARGIN_DB,
+            None => true,
+        }
+    }
+
+    /// Take a key pressed on the caller's phone keypad (DTMF tone)
+    ///
+    /// A key pressed while the agent is speaking cuts it off, like speaking
+    /// over it. The key is passed on as [`PipelineEvent::Dtmf`].
+    pub fn process_dtmf(&self, key: char) -> Result<(), PipelineError> {
+        let key = key.to_ascii_uppercase();
+        if !(key.is_ascii_digit() || matches!(key, '*' | '#' | 'A'..='D')) {
+            return Err(PipelineError::Audio(format!("Not a DTMF key: {:?}", key)));
+        }
+
+        if self.state() == PipelineState::Speaking {
+            let word_index = self.tts.current_word_index();
+            self.tts.barge_in();
+            let _ = self.event_tx.send(PipelineEvent::BargeIn {
+                at_word: word_index,
+            });
+            *self.state.lock() = PipelineState::Listening;
+        }
+
+        let _ = self.event_tx.send(PipelineEvent::Dtmf { key });
+        Ok(())
+    }
+
     /// Start speaking a response
     pub async fn speak(&self, text: &str) -> Result<(), PipelineError> {
         // Set state
@@ -1163,6 +1448,11 @@ impl VoicePipeline {
         self.processor_chain.is_some()
     }
 
+    /// Change speaking rate, pitch and sentence pause for this session
+    pub fn set_speech_style(&self, style: SpeechStyle) {
+        self.tts.set_style(style);
+    }
+
     /// Get current pipeline state
     pub fn state(&self) -> PipelineState {
         *self.state.lock()
@@ -1182,11 +1472,11 @@ impl VoicePipeline {
     }
 
     /// Reset pipeline
-    pub fn reset(&self) {
+    pub async fn reset(&self) {
         *self.state.lock() = PipelineState::Idle;
         self.vad.reset();
         self.turn_detector.reset();
-        self.stt.lock().reset();
+        self.stt.lock().await.reset();
         self.tts.reset();
         *self.barge_in_speech_ms.lock() = 0;
     }
@@ -1227,7 +1517,21 @@ mod tests {
     #[tokio::test]
     async fn test_pipeline_reset() {
         let pipeline = VoicePipeline::simple(PipelineConfig::default()).unwrap();
-        pipeline.reset();
+        pipeline.reset().await;
         assert_eq!(pipeline.state(), PipelineState::Idle);
     }
+
+    #[tokio::test]
+    async fn test_dtmf_forwarded() {
+        let pipeline = VoicePipeline::simple(PipelineConfig::default()).unwrap();
+        let mut events = pipeline.subscribe();
+
+        pipeline.process_dtmf('#').unwrap();
+        assert!(pipeline.process_dtmf('x').is_err());
+        match events.try_recv() {
+            Ok(PipelineEvent::Dtmf { key }) => assert_eq!(key, '#'),
+            other => panic!("expected a DTMF event, got {:?}", other),
+        }
+        assert!(events.try_recv().is_err());
+    }
 }
@@ -5,7 +5,7 @@
 
 use std::sync::Arc;
 use tokio::sync::mpsc;
-use voice_agent_core::{Frame, FrameProcessor, ProcessorContext, Result};
+use voice_agent_core::{Frame, FrameProcessor, ProcessorContext, Result, SamplePool};
 
 /// Channel capacity for inter-processor communication
 const DEFAULT_CHANNEL_CAPACITY: usize = 64;
@@ -18,6 +18,8 @@ pub struct ProcessorChain {
     processors: Vec<Arc<dyn FrameProcessor>>,
     /// Channel capacity
     channel_capacity: usize,
+    /// Audio buffers handed to processors through their context
+    sample_pool: SamplePool,
 }
 
 impl ProcessorChain {
@@ -27,6 +29,7 @@ impl ProcessorChain {
             name: name.into(),
             processors: Vec::new(),
             channel_capacity: DEFAULT_CHANNEL_CAPACITY,
+            sample_pool: SamplePool::default(),
         }
     }
 
@@ -62,6 +65,11 @@ impl ProcessorChain {
         self.processors.is_empty()
     }
 
+    /// Audio buffer pool shared by this chain's processors
+    pub fn sample_pool(&self) -> &SamplePool {
+        &self.sample_pool
+    }
+
     /// Process a single frame through the chain synchronously
     ///
     /// This processes frames one by one through each processor.
@@ -71,6 +79,7 @@ impl ProcessorChain {
         frame: Frame,
         context: &mut ProcessorContext,
     ) -> Result<Vec<Frame>> {
+        context.sample_pool = self.sample_pool.clone();
         let mut frames = vec![frame];
 
         for processor in &self.processors {
@@ -93,8 +102,9 @@ impl ProcessorChain {
     /// Returns the input sender and output receiver.
     pub fn run(
         &self,
-        initial_context: ProcessorContext,
+        mut initial_context: ProcessorContext,
     ) -> (mpsc::Sender<Frame>, mpsc::Receiver<Frame>) {
+        initial_context.sample_pool = self.sample_pool.clone();
         let (input_tx, input_rx) = mpsc::channel::<Frame>(self.channel_capacity);
 
         if self.processors.is_empty() {
@@ -126,6 +136,7 @@ impl ProcessorChain {
 
             let rx = current_rx;
             let tx = next_tx.clone();
+            context.output = Some(next_tx.clone());
             let processor_name = processor.name().to_string();
 
             // Spawn processor task
@@ -268,6 +279,12 @@ impl ProcessorChainBuilder {
         self
     }
 
+    /// Share an audio buffer pool (e.g. across the chains of many sessions)
+    pub fn sample_pool(mut self, pool: SamplePool) -> Self {
+        self.chain.sample_pool = pool;
+        self
+    }
+
     /// Build the chain
     pub fn build(self) -> ProcessorChain {
         self.chain
@@ -492,6 +509,61 @@ mod tests {
         assert!(sentence_count >= 1);
     }
 
+    /// Re-emits input audio in a buffer from the chain's pool
+    struct PooledCopy;
+
+    #[async_trait::async_trait]
+    impl FrameProcessor for PooledCopy {
+        async fn process(
+            &self,
+            frame: Frame,
+            context: &mut ProcessorContext,
+        ) -> Result<Vec<Frame>> {
+            match frame {
+                Frame::AudioInput(mut audio) => {
+                    audio.samples = context.sample_pool.copy_from(&audio.samples);
+                    Ok(vec![Frame::AudioOutput(audio)])
+                },
+                other => Ok(vec![other]),
+            }
+        }
+
+        fn name(&self) -> &'static str {
+            "pooled_copy"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_processors_reuse_chain_buffers() {
+        use voice_agent_core::{AudioFrame, Channels, SampleRate};
+
+        let pool = SamplePool::new(4);
+        let chain = ProcessorChain::builder("pooled")
+            .processor(PooledCopy)
+            .sample_pool(pool.clone())
+            .build();
+        let mut ctx = ProcessorContext::default();
+
+        for sequence in 0..3 {
+            let frame = AudioFrame::new(
+                vec![0.1; 320],
+                SampleRate::Hz16000,
+                Channels::Mono,
+                sequence,
+            );
+            let frames = chain
+                .process_one(Frame::AudioInput(frame), &mut ctx)
+                .await
+                .unwrap();
+            assert!(matches!(&frames[0], Frame::AudioOutput(audio) if audio.samples.len() == 320));
+            // Output dropped here returns its buffer to the pool
+        }
+
+        let stats = chain.sample_pool().stats();
+        assert_eq!(stats.allocated, 1);
+        assert_eq!(stats.reused, 2);
+    }
+
     #[tokio::test]
     async fn test_builder() {
         let chain = ProcessorChain::builder("builder_test")
@@ -0,0 +1,310 @@
+//! Barge-in safe TTS chunk scheduler
+//!
+//! Sits between TTS synthesis and audio output:
+//! - Crossfades consecutive chunks so word boundaries don't click
+//! - Holds back a short tail of the last chunk so an interruption can fade
+//!   out instead of cutting the waveform mid-cycle
+//! - Tracks the playback position and maps it back to the text that was
+//!   actually heard, so dialogue history only keeps what the caller heard
+
+use std::time::{Duration, Instant};
+
+/// Sample range of one scheduled chunk on the playback timeline
+#[derive(Debug, Clone)]
+struct ChunkSpan {
+    start: u64,
+    end: u64,
+    text: String,
+}
+
+/// Result of interrupting playback
+#[derive(Debug, Clone, Default)]
+pub struct Interruption {
+    /// Faded-out tail to emit before stopping (may be empty)
+    pub fade_out: Vec<f32>,
+    /// Playback position where speech stopped
+    pub position_ms: u64,
+    /// Text heard up to that position
+    pub spoken_text: String,
+}
+
+/// Chunk scheduler for one agent response
+#[derive(Debug)]
+pub struct ChunkScheduler {
+    sample_rate: u32,
+    crossfade: usize,
+    fade_out: usize,
+    /// Samples held back from the last chunk
+    tail: Vec<f32>,
+    /// Samples placed on the timeline (including the held tail)
+    scheduled: u64,
+    spans: Vec<ChunkSpan>,
+    /// When the first samples were released for playback
+    started: Option<Instant>,
+    /// Position fixed by an interruption
+    stopped_at: Option<u64>,
+}
+
+impl ChunkScheduler {
+    /// Create a scheduler
+    pub fn new(sample_rate: u32, crossfade_ms: u32, fade_out_ms: u32) -> Self {
+        Self {
+            sample_rate,
+            crossfade: ms_to_samples(sample_rate, crossfade_ms as u64),
+            fade_out: ms_to_samples(sample_rate, fade_out_ms as u64),
+            tail: Vec::new(),
+            scheduled: 0,
+            spans: Vec::new(),
+            started: None,
+            stopped_at: None,
+        }
+    }
+
+    /// Schedule a synthesized chunk, returning the samples ready to play
+    ///
+    /// The start of `samples` is crossfaded with the held tail of the previous
+    /// chunk. Unless `is_final`, the end is held back for the next chunk (or a
+    /// fade-out on interruption).
+    pub fn push(&mut self, samples: &[f32], text: &str, is_final: bool) -> Vec<f32> {
+        if self.stopped_at.is_some() {
+            return Vec::new();
+        }
+
+        // Only overlap when both sides are long enough to spare the samples
+        let overlap = if samples.len() >= self.tail.len() * 2 {
+            self.crossfade.min(self.tail.len())
+        } else {
+            0
+        };
+
+        let mut out = std::mem::take(&mut self.tail);
+        let keep = out.len() - overlap;
+        for (i, sample) in samples[..overlap].iter().enumerate() {
+            let t = (i + 1) as f32 / (overlap + 1) as f32;
+            out[keep + i] = out[keep + i] * (1.0 - t) + sample * t;
+        }
+        out.extend_from_slice(&samples[overlap..]);
+
+        let start = self.scheduled - overlap as u64;
+        self.spans.push(ChunkSpan {
+            start,
+            end: start + samples.len() as u64,
+            text: text.to_string(),
+        });
+        self.scheduled = start + samples.len() as u64;
+
+        if !is_final {
+            let hold = self.crossfade.max(self.fade_out).min(samples.len() / 2);
+            self.tail = out.split_off(out.len() - hold);
+        }
+
+        if !out.is_empty() && self.started.is_none() {
+            self.started = Some(Instant::now());
+        }
+        out
+    }
+
+    /// Silence between two sentences, none before the first one
+    pub fn pause(&mut self, ms: u32) -> Vec<f32> {
+        if ms == 0 || self.spans.is_empty() {
+            return Vec::new();
+        }
+        let silence = vec![0.0; ms_to_samples(self.sample_rate, ms as u64)];
+        self.push(&silence, "", true)
+    }
+
+    /// Release the held tail (end of response without interruption)
+    pub fn flush(&mut self) -> Vec<f32> {
+        if self.stopped_at.is_some() {
+            return Vec::new();
+        }
+        std::mem::take(&mut self.tail)
+    }
+
+    /// Stop playback at the current position
+    pub fn interrupt(&mut self) -> Interruption {
+        let elapsed = self.started.map(|t| t.elapsed()).unwrap_or_default();
+        self.interrupt_after(elapsed)
+    }
+
+    /// Stop playback as if `elapsed` has passed since the first samples
+    pub fn interrupt_after(&mut self, elapsed: Duration) -> Interruption {
+        if let Some(position) = self.stopped_at {
+            return Interruption {
+                fade_out: Vec::new(),
+                position_ms: self.samples_to_ms(position),
+                spoken_text: self.spoken_text_at(position),
+            };
+        }
+
+        // Measured before the tail is taken, which counts as unreleased
+        let played = self.position_after(elapsed);
+
+        let mut fade_out = std::mem::take(&mut self.tail);
+        fade_out.truncate(self.fade_out);
+        let len = fade_out.len();
+        for (i, sample) in fade_out.iter_mut().enumerate() {
+            *sample *= 1.0 - (i + 1) as f32 / len as f32;
+        }
+
+        // The fade tail is heard too
+        let position = played + len as u64;
+        self.stopped_at = Some(position);
+
+        Interruption {
+            fade_out,
+            position_ms: self.samples_to_ms(position),
+            spoken_text: self.spoken_text_at(position),
+        }
+    }
+
+    /// Where the last scheduled chunk plays (start, end in ms)
+    pub fn last_chunk_ms(&self) -> Option<(u64, u64)> {
+        self.spans
+            .last()
+            .map(|span| (self.samples_to_ms(span.start), self.samples_to_ms(span.end)))
+    }
+
+    /// Current playback position (ms), assuming real-time playback
+    pub fn position_ms(&self) -> u64 {
+        self.samples_to_ms(self.position())
+    }
+
+    /// Text heard so far
+    pub fn spoken_text(&self) -> String {
+        self.spoken_text_at(self.position())
+    }
+
+    /// Whether playback was interrupted
+    pub fn is_interrupted(&self) -> bool {
+        self.stopped_at.is_some()
+    }
+
+    /// Clear state for the next response
+    pub fn reset(&mut self) {
+        self.tail.clear();
+        self.scheduled = 0;
+        self.spans.clear();
+        self.started = None;
+        self.stopped_at = None;
+    }
+
+    fn position(&self) -> u64 {
+        match self.stopped_at {
+            Some(position) => position,
+            None => self.position_after(self.started.map(|t| t.elapsed()).unwrap_or_default()),
+        }
+    }
+
+    /// Samples released for playback so far
+    fn released(&self) -> u64 {
+        self.scheduled - self.tail.len() as u64
+    }
+
+    /// Playback can't run ahead of the samples released to it
+    fn position_after(&self, elapsed: Duration) -> u64 {
+        let played = (elapsed.as_secs_f64() * self.sample_rate as f64) as u64;
+        played.min(self.released())
+    }
+
+    /// Full chunks before `position` plus the words of the partly played one
+    fn spoken_text_at(&self, position: u64) -> String {
+        let mut words: Vec<&str> = Vec::new();
+        for span in &self.spans {
+            if span.end <= position {
+                words.extend(span.text.split_whitespace());
+            } else if span.start < position {
+                let chunk: Vec<&str> = span.text.split_whitespace().collect();
+                let heard = (position - span.start) as f64 / (span.end - span.start) as f64;
+                let count = (chunk.len() as f64 * heard).floor() as usize;
+                words.extend(&chunk[..count]);
+            }
+        }
+        words.join(" ")
+    }
+
+    fn samples_to_ms(&self, samples: u64) -> u64 {
+        samples * 1000 / self.sample_rate.max(1) as u64
+    }
+}
+
+fn ms_to_samples(sample_rate: u32, ms: u64) -> usize {
+    (sample_rate as u64 * ms / 1000) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 1000; // 1 sample per ms keeps the arithmetic readable
+
+    #[test]
+    fn test_crossfade_between_chunks() {
+        let mut scheduler = ChunkScheduler::new(RATE, 10, 10);
+
+        let first = scheduler.push(&[1.0; 100], "aapka loan", false);
+        // Tail held back for the crossfade
+        assert_eq!(first.len(), 90);
+
+        let second = scheduler.push(&[0.0; 100], "approve hai", true);
+        // Held tail overlaps the new chunk's start: 10 + 100 - 10
+        assert_eq!(second.len(), 100);
+        // Ramps from the old chunk toward the new one without a jump
+        assert!(second[0] > 0.8 && second[0] < 1.0);
+        assert!(second[9] < 0.2);
+        assert!(second[..10].windows(2).all(|w| w[0] >= w[1]));
+        assert_eq!(scheduler.flush().len(), 0);
+    }
+
+    #[test]
+    fn test_interrupt_fades_out_and_reports_spoken_text() {
+        let mut scheduler = ChunkScheduler::new(RATE, 10, 20);
+        scheduler.push(&[0.5; 200], "Gold loan ki", false);
+        scheduler.push(&[0.5; 200], "byaj dar nau percent hai", false);
+
+        // Caller speaks 300ms into playback: first chunk and half the second
+        let interruption = scheduler.interrupt_after(Duration::from_millis(280));
+        assert_eq!(interruption.fade_out.len(), 20);
+        assert_eq!(*interruption.fade_out.last().unwrap(), 0.0);
+        assert!(interruption.fade_out.windows(2).all(|w| w[0] >= w[1]));
+        assert_eq!(interruption.position_ms, 300);
+        assert_eq!(interruption.spoken_text, "Gold loan ki byaj dar");
+
+        // Later chunks are dropped and the position stays put
+        assert!(scheduler.push(&[0.5; 100], "aur", true).is_empty());
+        let again = scheduler.interrupt_after(Duration::from_secs(5));
+        assert!(again.fade_out.is_empty());
+        assert_eq!(again.position_ms, 300);
+    }
+
+    #[test]
+    fn test_position_capped_at_released_audio() {
+        let mut scheduler = ChunkScheduler::new(RATE, 10, 10);
+        scheduler.push(&[0.5; 100], "namaste ji", false);
+
+        // Synthesis stalled: only 90 samples were released
+        let interruption = scheduler.interrupt_after(Duration::from_secs(2));
+        assert_eq!(interruption.position_ms, 100);
+        assert_eq!(interruption.spoken_text, "namaste ji");
+
+        scheduler.reset();
+        assert!(!scheduler.is_interrupted());
+        assert_eq!(scheduler.spoken_text(), "");
+    }
+
+    #[test]
+    fn test_pause_between_sentences() {
+        let mut scheduler = ChunkScheduler::new(RATE, 10, 10);
+        assert!(scheduler.pause(200).is_empty());
+
+        scheduler.push(&[0.5; 100], "namaste", true);
+        let pause = scheduler.pause(200);
+        assert_eq!(pause.len(), 200);
+        assert!(pause.iter().all(|&s| s == 0.0));
+        assert_eq!(scheduler.last_chunk_ms(), Some((100, 300)));
+
+        // Silence is heard but adds no words
+        let interruption = scheduler.interrupt_after(Duration::from_millis(250));
+        assert_eq!(interruption.spoken_text, "namaste");
+    }
+}
@@ -161,12 +161,15 @@ impl InterruptHandler {
 
             HandlerState::PendingInterrupt => {
                 // In pending, we block audio output but allow other frames
-                !matches!(frame, Frame::AudioOutput(_))
+                !matches!(frame, Frame::AudioOutput(_) | Frame::AvatarCues(_))
             },
 
             HandlerState::Interrupted => {
                 // When interrupted, block TTS audio
-                !matches!(frame, Frame::AudioOutput(_) | Frame::Sentence { .. })
+                !matches!(
+                    frame,
+                    Frame::AudioOutput(_) | Frame::AvatarCues(_) | Frame::Sentence { .. }
+                )
             },
         }
     }
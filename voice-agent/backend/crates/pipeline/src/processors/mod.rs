@@ -3,15 +3,18 @@
 //! This module contains FrameProcessor implementations for:
 //! - SentenceDetector: Detects sentence boundaries from LLM chunks
 //! - TtsProcessor: Converts sentences to audio via streaming TTS
+//! - ChunkScheduler: Crossfade/fade-out and playback position for TTS output
 //! - InterruptHandler: Handles barge-in with configurable modes
 //! - ProcessorChain: Channel-based chain connecting processors
 
 mod chain;
+mod chunk_scheduler;
 mod interrupt_handler;
 mod sentence_detector;
 mod tts_processor;
 
 pub use chain::{ProcessorChain, ProcessorChainBuilder};
+pub use chunk_scheduler::{ChunkScheduler, Interruption};
 // P2-2 FIX: Export generic processors for external use
 pub use chain::{FilterProcessor, MapProcessor, PassthroughProcessor};
 pub use interrupt_handler::{InterruptHandler, InterruptHandlerConfig, InterruptMode};
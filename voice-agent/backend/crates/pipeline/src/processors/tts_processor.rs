@@ -2,27 +2,52 @@
 //!
 //! Bridges Frame::Sentence to Frame::AudioOutput via StreamingTts.
 //! Wires the SentenceDetector output directly to TTS synthesis.
+//!
+//! Chunks pass through a [`ChunkScheduler`] that crossfades word boundaries
+//! and fades out on barge-in. An interruption emits `Frame::SpokenUpTo` with
+//! the text the caller actually heard.
+//!
+//! With `avatar_cues`, each chunk's audio is preceded by a `Frame::AvatarCues`
+//! placed on the same playback timeline.
+//!
+//! Consecutive sentences are separated by the TTS style's sentence pause.
+//!
+//! With `parallel_synthesis`, sentences are synthesized ahead of playback:
+//! up to `max_queue_size` sentences run concurrently while earlier ones are
+//! still playing, and their audio is sent downstream in order through the
+//! chain's output channel. Flush and end of stream wait for the queue, and
+//! barge-in cancels it.
 
 use async_trait::async_trait;
 use parking_lot::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
 
-use voice_agent_core::{Frame, FrameProcessor, Language, ProcessorContext, Result};
+use voice_agent_core::{Frame, FrameProcessor, Language, ProcessorContext, Result, SamplePool};
 
-use crate::tts::{StreamingTts, TtsConfig, TtsEvent};
+use super::chunk_scheduler::ChunkScheduler;
+use crate::tts::{AvatarTimeline, StreamingTts, TtsConfig, TtsEvent};
+use crate::PipelineError;
 
 /// TTS processor configuration
 #[derive(Debug, Clone)]
 pub struct TtsProcessorConfig {
     /// TTS configuration
     pub tts: TtsConfig,
-    /// Enable parallel synthesis (queue sentences)
+    /// Synthesize upcoming sentences while earlier ones play
     pub parallel_synthesis: bool,
-    /// Maximum queued sentences
+    /// Maximum sentences synthesized ahead of playback
     pub max_queue_size: usize,
     /// Sample rate for output audio
     pub sample_rate: u32,
+    /// Crossfade between consecutive chunks (ms)
+    pub crossfade_ms: u32,
+    /// Fade-out applied when barge-in stops playback (ms)
+    pub fade_out_ms: u32,
+    /// Emit viseme and gesture cues for an avatar client
+    pub avatar_cues: bool,
 }
 
 impl Default for TtsProcessorConfig {
@@ -32,6 +57,9 @@ impl Default for TtsProcessorConfig {
             parallel_synthesis: false,
             max_queue_size: 5,
             sample_rate: 22050,
+            crossfade_ms: 10,
+            fade_out_ms: 30,
+            avatar_cues: false,
         }
     }
 }
@@ -46,29 +74,136 @@ pub struct TtsProcessor {
     active: Mutex<bool>,
     /// Barge-in requested
     barge_in: Mutex<bool>,
+    /// Turns synthesized chunks into output frames
+    playback: Arc<Playback>,
+    /// Sentences synthesizing ahead of playback
+    lookahead: Mutex<Option<Lookahead>>,
+}
+
+/// Output side of the processor, shared with the lookahead emitter
+struct Playback {
+    /// Crossfade, fade-out and playback position for the current response
+    scheduler: Mutex<ChunkScheduler>,
+    /// Buffers for output frames, taken from the chain's context
+    sample_pool: Mutex<SamplePool>,
+    /// Avatar cue timeline, when enabled
+    avatar: Option<AvatarTimeline>,
+}
+
+/// Sentence synthesizing on a blocking thread
+struct QueuedSentence {
+    index: usize,
+    /// Silence to play before it (ms)
+    pause_ms: u32,
+    job: JoinHandle<std::result::Result<Vec<TtsEvent>, PipelineError>>,
+}
+
+/// Queue of sentences synthesized ahead of playback
+struct Lookahead {
+    jobs: mpsc::Sender<QueuedSentence>,
+    /// Stops running jobs between chunks and drops queued ones
+    cancelled: Arc<AtomicBool>,
+    emitter: JoinHandle<()>,
+}
+
+impl Playback {
+    /// Stop playback: fade-out tail, barge-in position and heard text
+    ///
+    /// Repeated calls for the same response only repeat the barge-in frame.
+    fn interrupt_frames(&self, sequence: u64) -> Vec<Frame> {
+        let mut scheduler = self.scheduler.lock();
+        let first = !scheduler.is_interrupted();
+        let interruption = scheduler.interrupt();
+
+        let mut frames = Vec::new();
+        if !interruption.fade_out.is_empty() {
+            frames.push(self.audio_frame(interruption.fade_out, sequence));
+        }
+        frames.push(Frame::BargeIn {
+            audio_position_ms: interruption.position_ms,
+            transcript: None,
+        });
+        if first {
+            tracing::debug!(
+                position_ms = interruption.position_ms,
+                spoken = %interruption.spoken_text,
+                "TTS interrupted"
+            );
+            frames.push(Frame::SpokenUpTo {
+                text: interruption.spoken_text,
+                audio_position_ms: interruption.position_ms,
+            });
+        }
+        frames
+    }
+
+    fn audio_frame(&self, samples: Vec<f32>, sequence: u64) -> Frame {
+        let samples = self.sample_pool.lock().freeze(samples);
+        Frame::AudioOutput(voice_agent_core::AudioFrame::new(
+            samples,
+            voice_agent_core::SampleRate::Hz16000, // Will be resampled if needed
+            voice_agent_core::Channels::Mono,
+            sequence,
+        ))
+    }
+
+    /// Schedule a synthesized chunk and append its frames
+    fn push_chunk(&self, samples: &[f32], text: &str, is_final: bool, frames: &mut Vec<Frame>) {
+        let (ready, span) = {
+            let mut scheduler = self.scheduler.lock();
+            let ready = scheduler.push(samples, text, is_final);
+            (ready, scheduler.last_chunk_ms())
+        };
+        if let (Some(avatar), Some((start_ms, end_ms))) = (&self.avatar, span) {
+            let cues = avatar.cues(text, start_ms, end_ms - start_ms);
+            frames.push(Frame::AvatarCues(cues));
+        }
+        if !ready.is_empty() {
+            frames.push(self.audio_frame(ready, frames.len() as u64));
+        }
+    }
+
+    /// Append the pause that separates a sentence from the previous one
+    fn start_sentence(&self, pause_ms: u32, frames: &mut Vec<Frame>) {
+        let silence = self.scheduler.lock().pause(pause_ms);
+        if !silence.is_empty() {
+            frames.push(self.audio_frame(silence, frames.len() as u64));
+        }
+    }
+
+    /// Append the audio held back for crossfading at the end of a sentence
+    fn finish_sentence(&self, frames: &mut Vec<Frame>) {
+        let tail = self.scheduler.lock().flush();
+        if !tail.is_empty() {
+            frames.push(self.audio_frame(tail, frames.len() as u64));
+        }
+    }
 }
 
 impl TtsProcessor {
     /// Create a new TTS processor
     pub fn new(config: TtsProcessorConfig) -> Self {
         let tts = Arc::new(StreamingTts::simple(config.tts.clone()));
-        Self {
-            config,
-            tts,
-            current_sentence: Mutex::new(0),
-            active: Mutex::new(false),
-            barge_in: Mutex::new(false),
-        }
+        Self::with_tts(config, tts)
     }
 
     /// Create with a shared TTS instance
     pub fn with_tts(config: TtsProcessorConfig, tts: Arc<StreamingTts>) -> Self {
+        let scheduler =
+            ChunkScheduler::new(tts.sample_rate(), config.crossfade_ms, config.fade_out_ms);
+        let avatar = config.avatar_cues.then(AvatarTimeline::new);
         Self {
             config,
             tts,
             current_sentence: Mutex::new(0),
             active: Mutex::new(false),
             barge_in: Mutex::new(false),
+            playback: Arc::new(Playback {
+                scheduler: Mutex::new(scheduler),
+                sample_pool: Mutex::new(SamplePool::default()),
+                avatar,
+            }),
+            lookahead: Mutex::new(None),
         }
     }
 
@@ -81,10 +216,7 @@ impl TtsProcessor {
     ) -> Result<Vec<Frame>> {
         // Check for barge-in before starting
         if *self.barge_in.lock() {
-            return Ok(vec![Frame::BargeIn {
-                audio_position_ms: 0,
-                transcript: None,
-            }]);
+            return Ok(self.playback.interrupt_frames(0));
         }
 
         *self.active.lock() = true;
@@ -97,16 +229,15 @@ impl TtsProcessor {
         self.tts.start(text, tx);
 
         let mut frames = Vec::new();
+        self.playback
+            .start_sentence(self.tts.style().sentence_pause_ms, &mut frames);
 
         // Process TTS events synchronously by polling
         loop {
             // Check for barge-in during synthesis
             if *self.barge_in.lock() {
                 self.tts.barge_in();
-                frames.push(Frame::BargeIn {
-                    audio_position_ms: frames.len() as u64 * 20, // Approximate ms position
-                    transcript: None,
-                });
+                frames.extend(self.playback.interrupt_frames(frames.len() as u64));
                 break;
             }
 
@@ -118,12 +249,8 @@ impl TtsProcessor {
                     is_final,
                     word_indices,
                 })) => {
-                    frames.push(Frame::AudioOutput(voice_agent_core::AudioFrame::new(
-                        samples.to_vec(),
-                        voice_agent_core::SampleRate::Hz16000, // Will be resampled if needed
-                        voice_agent_core::Channels::Mono,
-                        frames.len() as u64,
-                    )));
+                    self.playback
+                        .push_chunk(&samples, &chunk_text, is_final, &mut frames);
 
                     tracing::trace!(
                         sentence = sentence_index,
@@ -139,13 +266,12 @@ impl TtsProcessor {
                 },
                 Ok(Some(TtsEvent::Complete)) => {
                     tracing::debug!(sentence = sentence_index, "TTS synthesis complete");
+                    self.playback.finish_sentence(&mut frames);
                     break;
                 },
                 Ok(Some(TtsEvent::BargedIn { word_index })) => {
-                    frames.push(Frame::BargeIn {
-                        audio_position_ms: word_index as u64 * 100, // Approximate word to ms
-                        transcript: None,
-                    });
+                    tracing::trace!(sentence = sentence_index, word_index, "TTS barged in");
+                    frames.extend(self.playback.interrupt_frames(frames.len() as u64));
                     break;
                 },
                 Ok(Some(TtsEvent::Error(e))) => {
@@ -173,11 +299,8 @@ impl TtsProcessor {
                 match event {
                     TtsEvent::Started => {},
                     TtsEvent::Complete => break,
-                    TtsEvent::BargedIn { word_index } => {
-                        frames.push(Frame::BargeIn {
-                            audio_position_ms: word_index as u64 * 100,
-                            transcript: None,
-                        });
+                    TtsEvent::BargedIn { .. } => {
+                        frames.extend(self.playback.interrupt_frames(frames.len() as u64));
                         break;
                     },
                     TtsEvent::Error(e) => {
@@ -194,10 +317,78 @@ impl TtsProcessor {
         Ok(frames)
     }
 
+    /// Start synthesizing a sentence ahead of playback
+    ///
+    /// Waits while `max_queue_size` sentences are already queued. The audio
+    /// is sent to `output` once every earlier sentence has been sent.
+    async fn queue_sentence(
+        &self,
+        text: String,
+        sentence_index: usize,
+        output: &mpsc::Sender<Frame>,
+    ) -> Result<Vec<Frame>> {
+        if *self.barge_in.lock() {
+            return Ok(self.playback.interrupt_frames(0));
+        }
+        *self.current_sentence.lock() = sentence_index;
+
+        let (jobs, cancelled) = {
+            let mut lookahead = self.lookahead.lock();
+            let lookahead = lookahead.get_or_insert_with(|| self.start_lookahead(output.clone()));
+            (lookahead.jobs.clone(), lookahead.cancelled.clone())
+        };
+
+        let tts = self.tts.clone();
+        let queued = QueuedSentence {
+            index: sentence_index,
+            pause_ms: tts.style().sentence_pause_ms,
+            job: tokio::task::spawn_blocking(move || tts.synthesize_sentence(&text, &cancelled)),
+        };
+        if let Err(mpsc::error::SendError(queued)) = jobs.send(queued).await {
+            // Cancelled by barge-in or reset while waiting for room
+            queued.job.abort();
+        }
+        Ok(Vec::new())
+    }
+
+    fn start_lookahead(&self, output: mpsc::Sender<Frame>) -> Lookahead {
+        let (jobs, queue) = mpsc::channel(self.config.max_queue_size.max(1));
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let emitter = tokio::spawn(emit_lookahead(
+            self.playback.clone(),
+            queue,
+            cancelled.clone(),
+            output,
+        ));
+        Lookahead {
+            jobs,
+            cancelled,
+            emitter,
+        }
+    }
+
+    /// Wait until every queued sentence has been sent downstream
+    async fn drain_lookahead(&self) {
+        let lookahead = self.lookahead.lock().take();
+        if let Some(Lookahead { jobs, emitter, .. }) = lookahead {
+            drop(jobs);
+            let _ = emitter.await;
+        }
+    }
+
+    /// Drop sentences synthesized ahead that were not sent yet
+    fn cancel_lookahead(&self) {
+        if let Some(lookahead) = self.lookahead.lock().take() {
+            lookahead.cancelled.store(true, Ordering::Relaxed);
+            lookahead.emitter.abort();
+        }
+    }
+
     /// Request barge-in (stop synthesis)
     pub fn barge_in(&self) {
         *self.barge_in.lock() = true;
         self.tts.barge_in();
+        self.cancel_lookahead();
     }
 
     /// Check if currently synthesizing
@@ -210,18 +401,94 @@ impl TtsProcessor {
         *self.current_sentence.lock()
     }
 
+    /// Playback position in the current response (ms)
+    pub fn playback_position_ms(&self) -> u64 {
+        self.playback.scheduler.lock().position_ms()
+    }
+
+    /// Text of the current response heard so far
+    pub fn spoken_text(&self) -> String {
+        self.playback.scheduler.lock().spoken_text()
+    }
+
     /// Reset processor state
     pub fn reset(&self) {
+        self.cancel_lookahead();
         *self.current_sentence.lock() = 0;
         *self.active.lock() = false;
         *self.barge_in.lock() = false;
+        // Rebuilt so a voice switch picks up the new sample rate
+        *self.playback.scheduler.lock() = ChunkScheduler::new(
+            self.tts.sample_rate(),
+            self.config.crossfade_ms,
+            self.config.fade_out_ms,
+        );
         self.tts.reset();
     }
 }
 
+/// Send queued sentences downstream in order as their synthesis finishes
+async fn emit_lookahead(
+    playback: Arc<Playback>,
+    mut queue: mpsc::Receiver<QueuedSentence>,
+    cancelled: Arc<AtomicBool>,
+    output: mpsc::Sender<Frame>,
+) {
+    while let Some(QueuedSentence {
+        index,
+        pause_ms,
+        job,
+    }) = queue.recv().await
+    {
+        let events = match job.await {
+            Ok(Ok(events)) => events,
+            Ok(Err(e)) => {
+                tracing::error!(sentence = index, error = %e, "TTS lookahead synthesis failed");
+                let error = Frame::Error {
+                    stage: "tts_processor".to_string(),
+                    message: e.to_string(),
+                    recoverable: true,
+                };
+                if output.send(error).await.is_err() {
+                    return;
+                }
+                continue;
+            },
+            Err(_) => continue,
+        };
+        if cancelled.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let mut frames = Vec::new();
+        playback.start_sentence(pause_ms, &mut frames);
+        for event in events {
+            if let TtsEvent::Audio {
+                samples,
+                text,
+                is_final,
+                ..
+            } = event
+            {
+                playback.push_chunk(&samples, &text, is_final, &mut frames);
+            }
+        }
+        playback.finish_sentence(&mut frames);
+        tracing::debug!(sentence = index, "TTS lookahead sentence sent");
+
+        for frame in frames {
+            if output.send(frame).await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
 #[async_trait]
 impl FrameProcessor for TtsProcessor {
-    async fn process(&self, frame: Frame, _context: &mut ProcessorContext) -> Result<Vec<Frame>> {
+    async fn process(&self, frame: Frame, context: &mut ProcessorContext) -> Result<Vec<Frame>> {
+        *self.playback.sample_pool.lock() = context.sample_pool.clone();
+
         match frame {
             Frame::Sentence {
                 text,
@@ -235,6 +502,13 @@ impl FrameProcessor for TtsProcessor {
                     "Processing sentence for TTS"
                 );
 
+                // Synthesize ahead when the chain can take frames later
+                if self.config.parallel_synthesis {
+                    if let Some(ref output) = context.output {
+                        return self.queue_sentence(text, index, output).await;
+                    }
+                }
+
                 // Synthesize the sentence
                 let audio_frames = self.synthesize_sentence(&text, language, index).await?;
 
@@ -248,6 +522,7 @@ impl FrameProcessor for TtsProcessor {
 
             Frame::Control(voice_agent_core::ControlFrame::Flush) => {
                 // On flush, finish any pending synthesis
+                self.drain_lookahead().await;
                 if self.is_active() {
                     self.tts.finalize_text();
                 }
@@ -262,6 +537,7 @@ impl FrameProcessor for TtsProcessor {
 
             Frame::EndOfStream => {
                 // Finish any pending synthesis
+                self.drain_lookahead().await;
                 if self.is_active() {
                     self.tts.finalize_text();
                 }
@@ -403,4 +679,117 @@ mod tests {
         // Should produce barge-in frame
         assert!(frames.iter().any(|f| matches!(f, Frame::BargeIn { .. })));
     }
+
+    #[tokio::test]
+    async fn test_barge_in_reports_spoken_text_once() {
+        let processor = create_processor();
+        let mut ctx = ProcessorContext::default();
+        let sentence = |index| Frame::Sentence {
+            text: "Aapka gold loan approve ho gaya hai.".to_string(),
+            language: Language::English,
+            index,
+        };
+
+        let first = processor.process(sentence(0), &mut ctx).await.unwrap();
+        assert!(first.iter().any(|f| matches!(f, Frame::AudioOutput(_))));
+
+        processor.barge_in();
+        let second = processor.process(sentence(1), &mut ctx).await.unwrap();
+        let spoken: Vec<&String> = second
+            .iter()
+            .filter_map(|f| match f {
+                Frame::SpokenUpTo { text, .. } => Some(text),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(spoken.len(), 1);
+        assert!("Aapka gold loan approve ho gaya hai.".starts_with(spoken[0].as_str()));
+        assert!(!second.iter().any(|f| matches!(f, Frame::AudioOutput(_))));
+
+        // Later sentences of the interrupted response don't report again
+        let third = processor.process(sentence(2), &mut ctx).await.unwrap();
+        assert!(!third.iter().any(|f| matches!(f, Frame::SpokenUpTo { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_lookahead_sends_sentences_in_order_before_flush() {
+        let processor = TtsProcessor::new(TtsProcessorConfig {
+            parallel_synthesis: true,
+            max_queue_size: 2,
+            ..Default::default()
+        });
+        let (tx, mut rx) = mpsc::channel(256);
+        let mut ctx = ProcessorContext::default();
+        ctx.output = Some(tx);
+        let sentences = ["Namaste.", "Aapka loan approve ho gaya hai.", "Dhanyavaad."];
+
+        for (index, text) in sentences.iter().enumerate() {
+            let frames = processor
+                .process(
+                    Frame::Sentence {
+                        text: text.to_string(),
+                        language: Language::Hindi,
+                        index,
+                    },
+                    &mut ctx,
+                )
+                .await
+                .unwrap();
+            assert!(frames.is_empty(), "audio goes through the output channel");
+        }
+
+        let flushed = processor
+            .process(
+                Frame::Control(voice_agent_core::ControlFrame::Flush),
+                &mut ctx,
+            )
+            .await
+            .unwrap();
+        assert!(matches!(
+            flushed[..],
+            [Frame::Control(voice_agent_core::ControlFrame::Flush)]
+        ));
+
+        // Everything was sent before the flush passed through
+        let mut audio = 0;
+        while let Ok(frame) = rx.try_recv() {
+            assert!(matches!(frame, Frame::AudioOutput(_)));
+            audio += 1;
+        }
+        assert!(audio >= sentences.len());
+    }
+
+    #[tokio::test]
+    async fn test_lookahead_cancelled_by_barge_in() {
+        let processor = TtsProcessor::new(TtsProcessorConfig {
+            parallel_synthesis: true,
+            ..Default::default()
+        });
+        let (tx, _rx) = mpsc::channel(256);
+        let mut ctx = ProcessorContext::default();
+        ctx.output = Some(tx);
+        let sentence = |index| Frame::Sentence {
+            text: "Gold rate aaj kya hai?".to_string(),
+            language: Language::Hindi,
+            index,
+        };
+
+        processor.process(sentence(0), &mut ctx).await.unwrap();
+        processor
+            .process(
+                Frame::BargeIn {
+                    audio_position_ms: 0,
+                    transcript: None,
+                },
+                &mut ctx,
+            )
+            .await
+            .unwrap();
+        assert!(processor.lookahead.lock().is_none());
+
+        // Later sentences of the response are not queued
+        let frames = processor.process(sentence(1), &mut ctx).await.unwrap();
+        assert!(frames.iter().any(|f| matches!(f, Frame::BargeIn { .. })));
+        assert!(processor.lookahead.lock().is_none());
+    }
 }
@@ -22,6 +22,7 @@ pub use streaming::{StreamingStt, SttConfig, SttEngine};
 pub use vocab::{load_domain_vocab, load_vocabulary, Vocabulary};
 
 use crate::PipelineError;
+use std::collections::VecDeque;
 use std::sync::Arc;
 use voice_agent_core::TranscriptResult;
 
@@ -173,6 +174,93 @@ impl SttBackend for StubSttBackend {
     }
 }
 
+/// STT backend that "recognises" queued transcripts, for pipeline tests
+///
+/// Each utterance is recognised as the next transcript in the queue once it
+/// has been fed any audio; `finalize` hands it out and moves to the next.
+/// Lets VAD, turn detection and everything downstream run on fixture audio
+/// without an STT model.
+pub struct ScriptedSttBackend {
+    language: String,
+    transcripts: VecDeque<String>,
+    partial: Option<TranscriptResult>,
+    samples: usize,
+}
+
+impl ScriptedSttBackend {
+    pub fn new<I, S>(language: impl Into<String>, transcripts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            language: language.into(),
+            transcripts: transcripts.into_iter().map(Into::into).collect(),
+            partial: None,
+            samples: 0,
+        }
+    }
+
+    fn result(&self, text: String, is_final: bool) -> TranscriptResult {
+        TranscriptResult {
+            text,
+            is_final,
+            confidence: 1.0,
+            start_time_ms: 0,
+            end_time_ms: 0,
+            language: Some(self.language.clone()),
+            words: vec![],
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SttBackend for ScriptedSttBackend {
+    async fn process_chunk(
+        &mut self,
+        audio: &[f32],
+    ) -> Result<Option<TranscriptResult>, PipelineError> {
+        self.process(audio)
+    }
+
+    async fn finalize(&mut self) -> Result<TranscriptResult, PipelineError> {
+        Ok(self.finalize_sync())
+    }
+
+    fn reset(&mut self) {
+        self.partial = None;
+        self.samples = 0;
+    }
+
+    fn partial(&self) -> Option<&TranscriptResult> {
+        self.partial.as_ref()
+    }
+
+    fn process(&mut self, audio: &[f32]) -> Result<Option<TranscriptResult>, PipelineError> {
+        self.samples += audio.len();
+        if self.partial.is_some() || self.samples == 0 {
+            return Ok(None);
+        }
+        let Some(text) = self.transcripts.front().cloned() else {
+            return Ok(None);
+        };
+        let partial = self.result(text, false);
+        self.partial = Some(partial.clone());
+        Ok(Some(partial))
+    }
+
+    fn finalize_sync(&mut self) -> TranscriptResult {
+        let text = if self.samples > 0 {
+            self.transcripts.pop_front().unwrap_or_default()
+        } else {
+            String::new()
+        };
+        let result = self.result(text, true);
+        self.reset();
+        result
+    }
+}
+
 // ============================================================================
 // P0-2 FIX: Factory function for creating backends
 // ============================================================================
@@ -0,0 +1,241 @@
+//! Avatar Cues from Synthesized Chunks
+//!
+//! Lip sync without a second inference pass: each synthesized chunk's text
+//! goes through the Hindi/Hinglish G2P, and the phonemes are spread over
+//! the chunk's audio in proportion to their length (vowels hold the mouth
+//! longer than consonants). Each phoneme maps to a [`Viseme`]; repeats are
+//! merged into one cue.
+//!
+//! Gesture cues are placed on greeting words and on figures (digits, ₹,
+//! lakh/crore), at the word's share of the chunk.
+
+use voice_agent_core::{AvatarCues, Gesture, GestureCue, Viseme, VisemeCue};
+
+use super::g2p::{G2pConfig, HindiG2p};
+
+/// Relative time a vowel holds the mouth shape
+const VOWEL_WEIGHT: f32 = 1.0;
+/// Relative time of a consonant
+const CONSONANT_WEIGHT: f32 = 0.6;
+/// Relative time of the gap between words
+const BOUNDARY_WEIGHT: f32 = 0.4;
+
+const GREETINGS: &[&str] = &[
+    "namaste",
+    "namaskar",
+    "hello",
+    "hi",
+    "welcome",
+    "swagat",
+    "नमस्ते",
+    "नमस्कार",
+    "स्वागत",
+];
+const MAGNITUDES: &[&str] = &[
+    "hazaar",
+    "hazar",
+    "thousand",
+    "lakh",
+    "lakhs",
+    "crore",
+    "crores",
+    "percent",
+    "हज़ार",
+    "हजार",
+    "लाख",
+    "करोड़",
+    "प्रतिशत",
+];
+
+/// Derives avatar cues from the text and length of synthesized chunks
+pub struct AvatarTimeline {
+    g2p: HindiG2p,
+}
+
+impl Default for AvatarTimeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AvatarTimeline {
+    pub fn new() -> Self {
+        Self {
+            g2p: HindiG2p::new(G2pConfig {
+                add_silence: false,
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Cues for a chunk of `text` played from `start_ms` for `duration_ms`
+    pub fn cues(&self, text: &str, start_ms: u64, duration_ms: u64) -> AvatarCues {
+        AvatarCues {
+            visemes: self.visemes(text, start_ms, duration_ms),
+            gestures: gestures(text, start_ms, duration_ms),
+        }
+    }
+
+    fn visemes(&self, text: &str, start_ms: u64, duration_ms: u64) -> Vec<VisemeCue> {
+        let phonemes = match self.g2p.convert(text) {
+            Ok(phonemes) => phonemes,
+            Err(e) => {
+                tracing::debug!(error = %e, "G2P failed, no visemes for chunk");
+                return Vec::new();
+            },
+        };
+
+        // One entry per sound: G2P symbols may hold a consonant and a vowel
+        let mut shapes: Vec<(Viseme, f32)> = Vec::new();
+        for phoneme in &phonemes {
+            if phoneme.symbol.trim().is_empty() {
+                shapes.push((Viseme::Sil, BOUNDARY_WEIGHT * phoneme.duration));
+                continue;
+            }
+            for c in phoneme.symbol.chars() {
+                if let Some(viseme) = viseme_for(c) {
+                    let weight = if is_vowel(viseme) {
+                        VOWEL_WEIGHT
+                    } else {
+                        CONSONANT_WEIGHT
+                    };
+                    shapes.push((viseme, weight * phoneme.duration));
+                }
+            }
+        }
+        let total: f32 = shapes.iter().map(|(_, weight)| weight).sum();
+        if total <= 0.0 || duration_ms == 0 {
+            return Vec::new();
+        }
+
+        let mut cues: Vec<VisemeCue> = Vec::new();
+        let mut elapsed = 0.0f32;
+        for (viseme, weight) in shapes {
+            let from = start_ms + (elapsed / total * duration_ms as f32) as u64;
+            elapsed += weight;
+            let to = start_ms + (elapsed / total * duration_ms as f32) as u64;
+            match cues.last_mut() {
+                Some(last) if last.viseme == viseme => last.duration_ms = to - last.start_ms,
+                _ if to > from => cues.push(VisemeCue {
+                    viseme,
+                    start_ms: from,
+                    duration_ms: to - from,
+                }),
+                _ => {},
+            }
+        }
+        cues
+    }
+}
+
+/// Gesture cues on greeting words and figures
+fn gestures(text: &str, start_ms: u64, duration_ms: u64) -> Vec<GestureCue> {
+    let length = text.chars().count().max(1) as u64;
+    let mut cues = Vec::new();
+    let mut in_figure = false;
+    let mut offset = 0;
+    for token in text.split_inclusive(char::is_whitespace) {
+        let at_ms = start_ms + duration_ms * offset / length;
+        offset += token.chars().count() as u64;
+
+        let word = token
+            .trim()
+            .trim_matches(|c: char| c.is_ascii_punctuation() || c == '।')
+            .to_lowercase();
+        if word.is_empty() {
+            continue;
+        }
+        let figure = word.contains(|c: char| c.is_ascii_digit() || is_devanagari_digit(c))
+            || word.contains('₹')
+            || MAGNITUDES.contains(&word.as_str());
+        let gesture = if GREETINGS.contains(&word.as_str()) {
+            Some(Gesture::Greet)
+        } else if figure && !in_figure {
+            // "5 lakh 20 hazaar" is presented once
+            Some(Gesture::PresentNumber)
+        } else {
+            None
+        };
+        in_figure = figure;
+        if let Some(gesture) = gesture {
+            cues.push(GestureCue {
+                gesture,
+                at_ms,
+                word,
+            });
+        }
+    }
+    cues
+}
+
+fn is_devanagari_digit(c: char) -> bool {
+    ('\u{0966}'..='\u{096F}').contains(&c)
+}
+
+fn is_vowel(viseme: Viseme) -> bool {
+    matches!(
+        viseme,
+        Viseme::Aa | Viseme::E | Viseme::I | Viseme::O | Viseme::U
+    )
+}
+
+/// Mouth shape of an IPA sound; length, aspiration and stress marks have none
+fn viseme_for(c: char) -> Option<Viseme> {
+    let viseme = match c {
+        'p' | 'b' | 'm' => Viseme::Pp,
+        'f' | 'v' | 'ʋ' | 'w' => Viseme::Ff,
+        'θ' | 'ð' => Viseme::Th,
+        't' | 'd' | 'ʈ' | 'ɖ' | 'ɽ' | 'ɾ' => Viseme::Dd,
+        'k' | 'g' | 'ɡ' | 'ŋ' | 'x' | 'ɣ' | 'q' | 'h' | 'ɦ' => Viseme::Kk,
+        'ʃ' | 'ʒ' | 'ç' => Viseme::Ch,
+        's' | 'z' | 'ʂ' => Viseme::Ss,
+        'n' | 'ɳ' | 'ɲ' | 'l' => Viseme::Nn,
+        'r' | 'ɹ' => Viseme::Rr,
+        'a' | 'ɑ' | 'æ' | 'ʌ' | 'ə' | 'ɐ' => Viseme::Aa,
+        'e' | 'ɛ' | 'ɜ' => Viseme::E,
+        'i' | 'ɪ' | 'j' | 'y' => Viseme::I,
+        'o' | 'ɔ' | 'ɒ' => Viseme::O,
+        'u' | 'ʊ' => Viseme::U,
+        _ => return None,
+    };
+    Some(viseme)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visemes_cover_chunk() {
+        let timeline = AvatarTimeline::new();
+        let cues = timeline.cues("namaste", 1000, 700);
+
+        let visemes: Vec<Viseme> = cues.visemes.iter().map(|cue| cue.viseme).collect();
+        assert_eq!(visemes.first(), Some(&Viseme::Nn));
+        assert!(visemes.contains(&Viseme::Pp));
+        // Merged: no shape follows itself
+        assert!(visemes.windows(2).all(|pair| pair[0] != pair[1]));
+
+        // Back to back across the chunk
+        assert_eq!(cues.visemes[0].start_ms, 1000);
+        for pair in cues.visemes.windows(2) {
+            assert_eq!(pair[0].start_ms + pair[0].duration_ms, pair[1].start_ms);
+        }
+        let last = cues.visemes.last().unwrap();
+        assert!(last.start_ms + last.duration_ms <= 1700);
+        assert!(last.start_ms + last.duration_ms >= 1690);
+    }
+
+    #[test]
+    fn test_gestures_on_greeting_and_figures() {
+        let text = "Namaste! Aapko 5 lakh 20 hazaar ka loan mil sakta hai";
+        let cues = gestures(text, 0, 1000);
+
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[0].gesture, Gesture::Greet);
+        assert_eq!(cues[0].at_ms, 0);
+        assert_eq!(cues[1].gesture, Gesture::PresentNumber);
+        assert_eq!(cues[1].word, "5");
+        assert!(cues[1].at_ms > 200 && cues[1].at_ms < 400);
+    }
+}
@@ -0,0 +1,376 @@
+//! TTS Synthesis Cache
+//!
+//! Greetings, compliance disclosures and slot prompts are spoken on every
+//! call. The cache stores their synthesized PCM keyed by (engine, voice,
+//! sample rate, text) so a repeat is served without touching the backend.
+//!
+//! Two layers:
+//! - Memory: LRU bounded by entry count and total sample bytes
+//! - Disk (optional): one file per utterance, survives restarts and is
+//!   promoted into memory on first hit
+//!
+//! Keys use the text after normalization and markup lowering, so
+//! "₹5,000" and "five thousand rupees" share an entry.
+
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use voice_agent_config::TtsCacheConfig;
+use voice_agent_core::Samples;
+
+/// Disk entry header magic
+const MAGIC: &[u8; 4] = b"VTC1";
+
+/// Cache statistics
+#[derive(Debug, Default)]
+pub struct SynthesisCacheStats {
+    /// Served from memory
+    pub hits: AtomicU64,
+    /// Served from disk (also counted in `hits`)
+    pub disk_hits: AtomicU64,
+    pub misses: AtomicU64,
+    pub evictions: AtomicU64,
+}
+
+impl SynthesisCacheStats {
+    pub fn hit_rate(&self) -> f64 {
+        let hits = self.hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
+        let total = hits + misses;
+        if total == 0 {
+            0.0
+        } else {
+            hits as f64 / total as f64
+        }
+    }
+}
+
+/// Cache key for one synthesized utterance
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SynthesisKey {
+    pub engine: String,
+    pub voice: String,
+    pub sample_rate: u32,
+    pub text: String,
+}
+
+impl SynthesisKey {
+    pub fn new(
+        engine: impl Into<String>,
+        voice: impl Into<String>,
+        sample_rate: u32,
+        text: &str,
+    ) -> Self {
+        Self {
+            engine: engine.into(),
+            voice: voice.into(),
+            sample_rate,
+            // Chunk boundaries can leave stray whitespace around the same words
+            text: text.split_whitespace().collect::<Vec<_>>().join(" "),
+        }
+    }
+
+    /// Serialized form stored in disk headers
+    fn encode(&self) -> String {
+        format!(
+            "{}\u{1f}{}\u{1f}{}\u{1f}{}",
+            self.engine, self.voice, self.sample_rate, self.text
+        )
+    }
+
+    /// Stable file name (FNV-1a; std hashers are not stable across releases)
+    fn file_name(&self) -> String {
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for byte in self.encode().bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+        format!("{:016x}.pcm", hash)
+    }
+}
+
+struct CacheEntry {
+    samples: Samples,
+    last_used: u64,
+}
+
+struct MemoryLayer {
+    entries: HashMap<SynthesisKey, CacheEntry>,
+    bytes: usize,
+    tick: u64,
+}
+
+/// LRU memory + optional disk cache of synthesized audio
+pub struct SynthesisCache {
+    max_entries: usize,
+    max_bytes: usize,
+    disk_dir: Option<PathBuf>,
+    memory: Mutex<MemoryLayer>,
+    /// Cache statistics
+    pub stats: SynthesisCacheStats,
+}
+
+impl std::fmt::Debug for SynthesisCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SynthesisCache")
+            .field("entries", &self.len())
+            .field("max_entries", &self.max_entries)
+            .field("disk_dir", &self.disk_dir)
+            .finish()
+    }
+}
+
+impl SynthesisCache {
+    /// Create a cache; `None` when caching is disabled
+    pub fn from_config(config: &TtsCacheConfig) -> Option<Self> {
+        if !config.enabled || config.max_entries == 0 {
+            return None;
+        }
+
+        let cache = Self::new(config.max_entries, config.max_memory_mb * 1024 * 1024);
+        Some(match config.disk_dir {
+            Some(ref dir) => cache.with_disk(dir),
+            None => cache,
+        })
+    }
+
+    /// Memory-only cache
+    pub fn new(max_entries: usize, max_bytes: usize) -> Self {
+        Self {
+            max_entries,
+            max_bytes,
+            disk_dir: None,
+            memory: Mutex::new(MemoryLayer {
+                entries: HashMap::new(),
+                bytes: 0,
+                tick: 0,
+            }),
+            stats: SynthesisCacheStats::default(),
+        }
+    }
+
+    /// Add a disk layer rooted at `dir`
+    ///
+    /// Falls back to memory-only if the directory cannot be created.
+    pub fn with_disk(mut self, dir: impl AsRef<Path>) -> Self {
+        let dir = dir.as_ref();
+        match std::fs::create_dir_all(dir) {
+            Ok(()) => self.disk_dir = Some(dir.to_path_buf()),
+            Err(e) => tracing::warn!(
+                dir = %dir.display(),
+                "TTS cache directory unavailable, using memory only: {}",
+                e
+            ),
+        }
+        self
+    }
+
+    /// Cached audio for `key`, checking memory then disk
+    pub fn get(&self, key: &SynthesisKey) -> Option<Samples> {
+        {
+            let mut memory = self.memory.lock();
+            memory.tick += 1;
+            let tick = memory.tick;
+            if let Some(entry) = memory.entries.get_mut(key) {
+                entry.last_used = tick;
+                self.stats.hits.fetch_add(1, Ordering::Relaxed);
+                return Some(entry.samples.clone());
+            }
+        }
+
+        if let Some(samples) = self.read_disk(key) {
+            self.stats.hits.fetch_add(1, Ordering::Relaxed);
+            self.stats.disk_hits.fetch_add(1, Ordering::Relaxed);
+            let samples: Samples = samples.into();
+            self.insert_memory(key.clone(), samples.clone());
+            return Some(samples);
+        }
+
+        self.stats.misses.fetch_add(1, Ordering::Relaxed);
+        None
+    }
+
+    /// Store synthesized audio
+    pub fn insert(&self, key: SynthesisKey, samples: &[f32]) {
+        self.insert_shared(key, samples.into());
+    }
+
+    /// Store synthesized audio without copying it into the memory layer
+    pub fn insert_shared(&self, key: SynthesisKey, samples: Samples) {
+        if samples.is_empty() {
+            return;
+        }
+        self.write_disk(&key, &samples);
+        self.insert_memory(key, samples);
+    }
+
+    /// Entries held in memory
+    pub fn len(&self) -> usize {
+        self.memory.lock().entries.len()
+    }
+
+    /// Whether the memory layer is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop every memory entry (disk entries are kept)
+    pub fn clear(&self) {
+        let mut memory = self.memory.lock();
+        memory.entries.clear();
+        memory.bytes = 0;
+    }
+
+    fn insert_memory(&self, key: SynthesisKey, samples: Samples) {
+        let size = samples.len() * std::mem::size_of::<f32>();
+        if size > self.max_bytes {
+            return;
+        }
+
+        let mut memory = self.memory.lock();
+        memory.tick += 1;
+        let entry = CacheEntry {
+            samples,
+            last_used: memory.tick,
+        };
+        if let Some(old) = memory.entries.insert(key, entry) {
+            memory.bytes -= old.samples.len() * std::mem::size_of::<f32>();
+        }
+        memory.bytes += size;
+
+        while memory.entries.len() > self.max_entries || memory.bytes > self.max_bytes {
+            let oldest = memory
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            let Some(oldest) = oldest else {
+                break;
+            };
+            if let Some(evicted) = memory.entries.remove(&oldest) {
+                memory.bytes -= evicted.samples.len() * std::mem::size_of::<f32>();
+                self.stats.evictions.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Layout: magic, key length (u32 LE), key bytes, f32 LE samples
+    fn write_disk(&self, key: &SynthesisKey, samples: &[f32]) {
+        let Some(ref dir) = self.disk_dir else {
+            return;
+        };
+
+        let encoded = key.encode();
+        let mut bytes = Vec::with_capacity(8 + encoded.len() + samples.len() * 4);
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&(encoded.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(encoded.as_bytes());
+        for sample in samples {
+            bytes.extend_from_slice(&sample.to_le_bytes());
+        }
+
+        // Write then rename so a concurrent reader never sees a partial file
+        let path = dir.join(key.file_name());
+        let tmp = path.with_extension(format!("tmp{}", std::process::id()));
+        let result = std::fs::File::create(&tmp)
+            .and_then(|mut file| file.write_all(&bytes))
+            .and_then(|_| std::fs::rename(&tmp, &path));
+        if let Err(e) = result {
+            tracing::warn!(path = %path.display(), "Failed to write TTS cache entry: {}", e);
+            let _ = std::fs::remove_file(&tmp);
+        }
+    }
+
+    fn read_disk(&self, key: &SynthesisKey) -> Option<Vec<f32>> {
+        let path = self.disk_dir.as_ref()?.join(key.file_name());
+        let mut bytes = Vec::new();
+        std::fs::File::open(&path)
+            .and_then(|mut file| file.read_to_end(&mut bytes))
+            .ok()?;
+
+        if bytes.len() < 8 || !bytes.starts_with(MAGIC) {
+            return None;
+        }
+        let key_len = u32::from_le_bytes(bytes[4..8].try_into().ok()?) as usize;
+        let stored_key = bytes.get(8..8 + key_len)?;
+        // Hash collision or stale file from another key
+        if stored_key != key.encode().as_bytes() {
+            return None;
+        }
+
+        let pcm = &bytes[8 + key_len..];
+        if pcm.len() % 4 != 0 {
+            return None;
+        }
+        Some(
+            pcm.chunks_exact(4)
+                .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(text: &str) -> SynthesisKey {
+        SynthesisKey::new("piper", "hi-female-1", 22050, text)
+    }
+
+    #[test]
+    fn test_lru_eviction() {
+        let cache = SynthesisCache::new(2, usize::MAX);
+        cache.insert(key("namaste"), &[0.1; 10]);
+        cache.insert(key("dhanyavaad"), &[0.2; 10]);
+
+        // Touch the first entry so the second becomes least recently used
+        assert!(cache.get(&key("namaste")).is_some());
+        cache.insert(key("swagat hai"), &[0.3; 10]);
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&key("namaste")).is_some());
+        assert!(cache.get(&key("dhanyavaad")).is_none());
+        assert_eq!(cache.stats.evictions.load(Ordering::Relaxed), 1);
+
+        // Whitespace differences share an entry; other voices do not
+        assert!(cache.get(&key("  swagat   hai ")).is_some());
+        let other_voice = SynthesisKey::new("piper", "en-female-1", 22050, "swagat hai");
+        assert!(cache.get(&other_voice).is_none());
+    }
+
+    #[test]
+    fn test_memory_budget() {
+        // 40 bytes fits ten samples
+        let cache = SynthesisCache::new(10, 40);
+        cache.insert(key("a"), &[0.0; 6]);
+        cache.insert(key("b"), &[0.0; 6]);
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(&key("b")).is_some());
+
+        // Larger than the whole budget: never cached
+        cache.insert(key("c"), &[0.0; 20]);
+        assert!(cache.get(&key("c")).is_none());
+    }
+
+    #[test]
+    fn test_disk_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let samples = [0.25f32, -0.5, 1.0];
+
+        let cache = SynthesisCache::new(8, usize::MAX).with_disk(dir.path());
+        cache.insert(key("aapka loan approve ho gaya"), &samples);
+
+        // A fresh cache (e.g. after restart) is served from disk
+        let restarted = SynthesisCache::new(8, usize::MAX).with_disk(dir.path());
+        let cached = restarted.get(&key("aapka loan approve ho gaya")).unwrap();
+        assert_eq!(&*cached, &samples);
+        assert_eq!(restarted.stats.disk_hits.load(Ordering::Relaxed), 1);
+        assert_eq!(restarted.len(), 1);
+
+        assert!(restarted.get(&key("something else")).is_none());
+    }
+}
@@ -3,7 +3,7 @@
 //! Defines hyperparameters for the F5-TTS architecture.
 
 /// Quantization mode for TTS inference
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum TtsQuantization {
     /// Full precision (FP32) - most accurate
     #[default]
@@ -12,24 +12,70 @@ pub enum TtsQuantization {
     F16,
     /// Brain float (BF16) - good balance of range and precision
     BF16,
+    /// 8-bit weights (Q8_0) for the large linear layers, F32 activations
+    Int8,
+    /// 4-bit weights (Q4_0) for the large linear layers, F32 activations
+    Int4,
 }
 
 impl TtsQuantization {
     /// Get the Candle DType for this quantization mode
+    ///
+    /// Integer modes load and compute in F32; only the quantized linear
+    /// weights are stored in blocks.
     #[cfg(feature = "candle")]
     pub fn to_dtype(&self) -> candle_core::DType {
         match self {
-            TtsQuantization::F32 => candle_core::DType::F32,
+            TtsQuantization::F32 | TtsQuantization::Int8 | TtsQuantization::Int4 => {
+                candle_core::DType::F32
+            },
             TtsQuantization::F16 => candle_core::DType::F16,
             TtsQuantization::BF16 => candle_core::DType::BF16,
         }
     }
 
+    /// Block format for quantized linear weights (None = float weights)
+    #[cfg(feature = "candle")]
+    pub fn ggml_dtype(&self) -> Option<candle_core::quantized::GgmlDType> {
+        match self {
+            TtsQuantization::Int8 => Some(candle_core::quantized::GgmlDType::Q8_0),
+            TtsQuantization::Int4 => Some(candle_core::quantized::GgmlDType::Q4_0),
+            _ => None,
+        }
+    }
+
     /// Memory reduction factor compared to F32
+    ///
+    /// Q8_0/Q4_0 blocks carry an F16 scale per 32 weights.
     pub fn memory_factor(&self) -> f32 {
         match self {
             TtsQuantization::F32 => 1.0,
             TtsQuantization::F16 | TtsQuantization::BF16 => 0.5,
+            TtsQuantization::Int8 => 8.5 / 32.0,
+            TtsQuantization::Int4 => 4.5 / 32.0,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TtsQuantization::F32 => "f32",
+            TtsQuantization::F16 => "f16",
+            TtsQuantization::BF16 => "bf16",
+            TtsQuantization::Int8 => "int8",
+            TtsQuantization::Int4 => "int4",
+        }
+    }
+}
+
+impl From<voice_agent_config::ModelPrecision> for TtsQuantization {
+    fn from(precision: voice_agent_config::ModelPrecision) -> Self {
+        use voice_agent_config::ModelPrecision;
+        match precision {
+            ModelPrecision::F32 => TtsQuantization::F32,
+            ModelPrecision::F16 => TtsQuantization::F16,
+            ModelPrecision::Bf16 => TtsQuantization::BF16,
+            ModelPrecision::Int8 => TtsQuantization::Int8,
+            ModelPrecision::Int4 => TtsQuantization::Int4,
         }
     }
 }
@@ -193,6 +239,12 @@ impl IndicF5Config {
         self
     }
 
+    /// Use the given quantization mode on this config
+    pub fn with_quantization(mut self, quantization: TtsQuantization) -> Self {
+        self.quantization = quantization;
+        self
+    }
+
     /// Compute intermediate dimension for feedforward
     pub fn ff_dim(&self) -> usize {
         (self.dim as f32 * self.ff_mult) as usize
@@ -207,11 +259,7 @@ impl IndicF5Config {
     pub fn estimated_memory(&self) -> usize {
         // Rough estimate: params * bytes_per_param
         let params = self.dim * self.dim * self.depth * 12; // Rough transformer param count
-        let bytes_per_param = match self.quantization {
-            TtsQuantization::F32 => 4,
-            TtsQuantization::F16 | TtsQuantization::BF16 => 2,
-        };
-        params * bytes_per_param
+        (params as f32 * 4.0 * self.quantization.memory_factor()) as usize
     }
 }
 
@@ -275,6 +323,10 @@ pub struct FlowMatchingConfig {
 
     /// Maximum timestep
     pub t_max: f32,
+
+    /// Seed for the initial noise (None = fresh noise per call); fixed for
+    /// reproducible comparisons such as quantization benchmarks
+    pub noise_seed: Option<u64>,
 }
 
 impl Default for FlowMatchingConfig {
@@ -285,6 +337,7 @@ impl Default for FlowMatchingConfig {
             sway_coef: -1.0,
             t_min: 0.0,
             t_max: 1.0,
+            noise_seed: None,
         }
     }
 }
@@ -298,6 +351,7 @@ impl FlowMatchingConfig {
             sway_coef: -1.0,
             t_min: 0.0,
             t_max: 1.0,
+            noise_seed: None,
         }
     }
 
@@ -309,6 +363,7 @@ impl FlowMatchingConfig {
             sway_coef: -1.0,
             t_min: 0.0,
             t_max: 1.0,
+            noise_seed: None,
         }
     }
 }
@@ -331,6 +386,19 @@ mod tests {
         assert_eq!(config.ff_dim(), 4096); // 1024 * 4.0
     }
 
+    #[test]
+    fn test_quantized_memory_estimate() {
+        let f32_bytes = IndicF5Config::indicf5_hindi().estimated_memory();
+        let int8 = IndicF5Config::indicf5_hindi().with_quantization(TtsQuantization::Int8);
+        let int4 = IndicF5Config::indicf5_hindi().with_quantization(TtsQuantization::Int4);
+        assert!(int8.estimated_memory() < f32_bytes / 3);
+        assert!(int4.estimated_memory() < int8.estimated_memory());
+        assert_eq!(
+            TtsQuantization::from(voice_agent_config::ModelPrecision::Int4),
+            TtsQuantization::Int4
+        );
+    }
+
     #[test]
     fn test_frames_for_duration() {
         let config = IndicF5Config::default();
@@ -45,10 +45,12 @@ impl DiTBlock {
             config.max_seq_len,
             config.rope_base,
             vb.pp("attn"),
-        )?;
+        )?
+        .quantize(config.quantization)?;
         let ff_norm =
             super::modules::LayerNorm::new(config.dim, config.layer_norm_eps, vb.pp("ff_norm"))?;
-        let ff = FeedForward::new(config.dim, config.ff_mult, config.dropout, vb.pp("ff"))?;
+        let ff = FeedForward::new(config.dim, config.ff_mult, config.dropout, vb.pp("ff"))?
+            .quantize(config.quantization)?;
 
         Ok(Self {
             attn_norm,
@@ -68,10 +70,12 @@ impl DiTBlock {
             config.max_seq_len,
             config.rope_base,
             vb.pp("attn"),
-        )?;
+        )?
+        .quantize(config.quantization)?;
         let ff_norm =
             super::modules::LayerNorm::load(config.dim, config.layer_norm_eps, vb.pp("ff_norm"))?;
-        let ff = FeedForward::load(config.dim, config.ff_mult, config.dropout, vb.pp("ff"))?;
+        let ff = FeedForward::load(config.dim, config.ff_mult, config.dropout, vb.pp("ff"))?
+            .quantize(config.quantization)?;
 
         Ok(Self {
             attn_norm,
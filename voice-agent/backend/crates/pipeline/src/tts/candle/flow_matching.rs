@@ -81,7 +81,7 @@ impl FlowMatcher {
         let n_mels = model.config().n_mels;
 
         // Initialize with noise at t=1
-        let mut x = Tensor::randn(0.0f32, 1.0f32, (batch_size, target_len, n_mels), device)?;
+        let mut x = self.noise((batch_size, target_len, n_mels), device)?;
 
         // Get timesteps
         let timesteps = self.get_timesteps(device)?;
@@ -140,7 +140,7 @@ impl FlowMatcher {
         let n_mels = model.config().n_mels;
 
         // Initialize with noise where mask=1, reference where mask=0
-        let noise = Tensor::randn(0.0f32, 1.0f32, (batch_size, total_len, n_mels), device)?;
+        let noise = self.noise((batch_size, total_len, n_mels), device)?;
 
         // Expand mask for broadcasting: [batch, len, 1]
         let mask_expanded = mask.unsqueeze(2)?;
@@ -201,6 +201,36 @@ impl FlowMatcher {
         Ok(x)
     }
 
+    /// Standard normal noise, reproducible when `noise_seed` is set
+    ///
+    /// Candle cannot seed its CPU generator, so seeded noise is drawn from
+    /// SplitMix64 with a Box-Muller transform instead.
+    fn noise(&self, shape: (usize, usize, usize), device: &Device) -> Result<Tensor> {
+        let Some(seed) = self.config.noise_seed else {
+            return Tensor::randn(0.0f32, 1.0f32, shape, device);
+        };
+
+        let mut state = seed;
+        let mut uniform = move || {
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            // 53 high bits -> (0, 1]
+            (((z ^ (z >> 31)) >> 11) as f64 + 1.0) / (1u64 << 53) as f64
+        };
+        let len = shape.0 * shape.1 * shape.2;
+        let mut values = Vec::with_capacity(len + 1);
+        while values.len() < len {
+            let radius = (-2.0 * uniform().ln()).sqrt();
+            let angle = 2.0 * std::f64::consts::PI * uniform();
+            values.push((radius * angle.cos()) as f32);
+            values.push((radius * angle.sin()) as f32);
+        }
+        values.truncate(len);
+        Tensor::from_vec(values, shape, device)
+    }
+
     /// Apply classifier-free guidance
     ///
     /// v_guided = v_cond + cfg_strength * (v_cond - v_uncond)
@@ -234,6 +264,11 @@ impl FlowMatcher {
         &self.config
     }
 
+    /// Fix the initial noise for reproducible output (None = fresh noise)
+    pub fn set_noise_seed(&mut self, seed: Option<u64>) {
+        self.config.noise_seed = seed;
+    }
+
     /// Set number of integration steps
     pub fn set_num_steps(&mut self, num_steps: usize) {
         self.config.num_steps = num_steps;
@@ -306,4 +341,26 @@ mod tests {
             assert!((t_mid - expected).abs() < 1e-6);
         }
     }
+
+    #[cfg(feature = "candle")]
+    #[test]
+    fn test_seeded_noise_is_reproducible() {
+        let mut matcher = FlowMatcher::new(FlowMatchingConfig::default());
+        matcher.set_noise_seed(Some(7));
+        let shape = (1, 50, 4);
+        let a: Vec<f32> = matcher
+            .noise(shape, &Device::Cpu)
+            .and_then(|t| t.flatten_all()?.to_vec1())
+            .unwrap();
+        let b: Vec<f32> = matcher
+            .noise(shape, &Device::Cpu)
+            .and_then(|t| t.flatten_all()?.to_vec1())
+            .unwrap();
+        assert_eq!(a, b);
+
+        let mean = a.iter().sum::<f32>() / a.len() as f32;
+        let var = a.iter().map(|x| (x - mean).powi(2)).sum::<f32>() / a.len() as f32;
+        assert!(mean.abs() < 0.2, "mean {}", mean);
+        assert!((var - 1.0).abs() < 0.3, "variance {}", var);
+    }
 }
@@ -310,6 +310,11 @@ impl IndicF5Model {
         self.flow_matcher.set_num_steps(num_steps);
         self.flow_matcher.set_cfg_strength(cfg_strength);
     }
+
+    /// Fix the flow-matching noise so repeated calls give the same audio
+    pub fn set_noise_seed(&mut self, seed: Option<u64>) {
+        self.flow_matcher.set_noise_seed(seed);
+    }
 }
 
 /// Vocabulary for IndicF5
@@ -9,6 +9,11 @@ use candle_core::{DType, Device, Module, Result, Tensor, D};
 #[cfg(feature = "candle")]
 use candle_nn::{linear, linear_no_bias, Linear, VarBuilder};
 
+#[cfg(feature = "candle")]
+use super::super::config::TtsQuantization;
+#[cfg(feature = "candle")]
+use super::quantized::QLinear;
+
 /// Rotary Position Embeddings (RoPE)
 ///
 /// Applies rotation to query and key vectors based on position,
@@ -105,10 +110,10 @@ impl RotaryEmbedding {
 /// Multi-Head Self-Attention with optional RoPE
 #[cfg(feature = "candle")]
 pub struct SelfAttention {
-    q_proj: Linear,
-    k_proj: Linear,
-    v_proj: Linear,
-    out_proj: Linear,
+    q_proj: QLinear,
+    k_proj: QLinear,
+    v_proj: QLinear,
+    out_proj: QLinear,
     num_heads: usize,
     head_dim: usize,
     scale: f32,
@@ -144,10 +149,10 @@ impl SelfAttention {
         };
 
         Ok(Self {
-            q_proj,
-            k_proj,
-            v_proj,
-            out_proj,
+            q_proj: QLinear::Dense(q_proj),
+            k_proj: QLinear::Dense(k_proj),
+            v_proj: QLinear::Dense(v_proj),
+            out_proj: QLinear::Dense(out_proj),
             num_heads,
             head_dim,
             scale: (head_dim as f32).powf(-0.5),
@@ -167,6 +172,17 @@ impl SelfAttention {
         Self::new(dim, num_heads, use_rope, max_seq_len, rope_base, vb)
     }
 
+    /// Quantize the Q/K/V and output projections
+    pub fn quantize(self, quantization: TtsQuantization) -> Result<Self> {
+        Ok(Self {
+            q_proj: self.q_proj.quantize(quantization)?,
+            k_proj: self.k_proj.quantize(quantization)?,
+            v_proj: self.v_proj.quantize(quantization)?,
+            out_proj: self.out_proj.quantize(quantization)?,
+            ..self
+        })
+    }
+
     /// Forward pass
     ///
     /// Args:
@@ -9,13 +9,18 @@ use candle_core::{Module, Result, Tensor};
 #[cfg(feature = "candle")]
 use candle_nn::{linear, Linear, VarBuilder};
 
+#[cfg(feature = "candle")]
+use super::super::config::TtsQuantization;
+#[cfg(feature = "candle")]
+use super::quantized::QLinear;
+
 /// Standard Feedforward Network with GELU activation
 ///
 /// Architecture: Linear -> GELU -> Linear
 #[cfg(feature = "candle")]
 pub struct FeedForward {
-    fc1: Linear,
-    fc2: Linear,
+    fc1: QLinear,
+    fc2: QLinear,
     dropout: f32,
 }
 
@@ -26,12 +31,25 @@ impl FeedForward {
         let fc1 = linear(dim, hidden_dim, vb.pp("fc1"))?;
         let fc2 = linear(hidden_dim, dim, vb.pp("fc2"))?;
 
-        Ok(Self { fc1, fc2, dropout })
+        Ok(Self {
+            fc1: QLinear::Dense(fc1),
+            fc2: QLinear::Dense(fc2),
+            dropout,
+        })
     }
 
     pub fn load(dim: usize, mult: f32, dropout: f32, vb: VarBuilder) -> Result<Self> {
         Self::new(dim, mult, dropout, vb)
     }
+
+    /// Quantize both projections
+    pub fn quantize(self, quantization: TtsQuantization) -> Result<Self> {
+        Ok(Self {
+            fc1: self.fc1.quantize(quantization)?,
+            fc2: self.fc2.quantize(quantization)?,
+            ..self
+        })
+    }
 }
 
 #[cfg(feature = "candle")]
@@ -7,12 +7,14 @@
 //! - **feedforward**: MLP with GELU/SwiGLU activation
 //! - **conv**: ConvNeXt V2 blocks and convolutional position embedding
 //! - **embedding**: Text, mel, and time embeddings
+//! - **quantized**: Int8/Int4 linear layers for CPU inference
 
 pub mod attention;
 pub mod conv;
 pub mod embedding;
 pub mod feedforward;
 pub mod norm;
+pub mod quantized;
 
 // Re-export commonly used types
 pub use attention::{QKVProjection, RotaryEmbedding, SelfAttention};
@@ -22,6 +24,8 @@ pub use embedding::{
 };
 pub use feedforward::{Dropout, FeedForward, GatedFeedForward};
 pub use norm::{AdaLayerNorm, AdaLayerNormOutput, LayerNorm, RMSNorm};
+#[cfg(feature = "candle")]
+pub use quantized::QLinear;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,118 @@
+//! Quantized Linear Layers for IndicF5
+//!
+//! Int8/Int4 inference keeps activations in F32 and stores the weights of
+//! the large linear layers (attention projections and feedforward) in GGML
+//! block formats (Q8_0/Q4_0), quantized at load time from the SafeTensors
+//! weights. Matmuls then run on the quantized blocks, which is what makes
+//! CPU-only synthesis faster; norms, convolutions and embeddings stay F32.
+//!
+//! A layer whose input width is not a multiple of the block size (32) is
+//! left unquantized.
+
+#[cfg(feature = "candle")]
+use candle_core::quantized::{QMatMul, QTensor};
+#[cfg(feature = "candle")]
+use candle_core::{DType, Module, Result, Tensor};
+#[cfg(feature = "candle")]
+use candle_nn::Linear;
+
+#[cfg(feature = "candle")]
+use super::super::config::TtsQuantization;
+
+/// Linear layer with optionally quantized weights
+#[cfg(feature = "candle")]
+pub enum QLinear {
+    Dense(Linear),
+    Quantized {
+        weight: QMatMul,
+        bias: Option<Tensor>,
+    },
+}
+
+#[cfg(feature = "candle")]
+impl QLinear {
+    /// Quantize a loaded layer's weights for `quantization` (float modes
+    /// keep the layer as is)
+    pub fn from_linear(linear: Linear, quantization: TtsQuantization) -> Result<Self> {
+        let Some(dtype) = quantization.ggml_dtype() else {
+            return Ok(Self::Dense(linear));
+        };
+        let (_, in_dim) = linear.weight().dims2()?;
+        if in_dim % dtype.block_size() != 0 {
+            return Ok(Self::Dense(linear));
+        }
+
+        let weight = QTensor::quantize(&linear.weight().to_dtype(DType::F32)?, dtype)?;
+        Ok(Self::Quantized {
+            weight: QMatMul::from_qtensor(weight)?,
+            bias: linear.bias().cloned(),
+        })
+    }
+
+    /// Quantize a dense layer in place of itself (already quantized layers
+    /// are returned unchanged)
+    pub fn quantize(self, quantization: TtsQuantization) -> Result<Self> {
+        match self {
+            Self::Dense(linear) => Self::from_linear(linear, quantization),
+            quantized => Ok(quantized),
+        }
+    }
+
+    pub fn is_quantized(&self) -> bool {
+        matches!(self, Self::Quantized { .. })
+    }
+}
+
+#[cfg(feature = "candle")]
+impl Module for QLinear {
+    fn forward(&self, x: &Tensor) -> Result<Tensor> {
+        match self {
+            Self::Dense(linear) => linear.forward(x),
+            Self::Quantized { weight, bias } => {
+                let x = weight.forward(x)?;
+                match bias {
+                    Some(bias) => x.broadcast_add(bias),
+                    None => Ok(x),
+                }
+            },
+        }
+    }
+}
+
+#[cfg(all(test, feature = "candle"))]
+mod tests {
+    use super::*;
+    use candle_core::Device;
+
+    fn layer(in_dim: usize, out_dim: usize) -> Linear {
+        let device = Device::Cpu;
+        let weight = Tensor::randn(0.0f32, 0.1, (out_dim, in_dim), &device).unwrap();
+        let bias = Tensor::randn(0.0f32, 0.1, out_dim, &device).unwrap();
+        Linear::new(weight, Some(bias))
+    }
+
+    #[test]
+    fn test_int8_output_close_to_dense() {
+        let dense = layer(64, 16);
+        let x = Tensor::randn(0.0f32, 1.0, (2, 5, 64), &Device::Cpu).unwrap();
+        let expected = dense.forward(&x).unwrap();
+
+        let quantized = QLinear::from_linear(dense, TtsQuantization::Int8).unwrap();
+        assert!(quantized.is_quantized());
+        let actual = quantized.forward(&x).unwrap();
+        assert_eq!(actual.dims(), expected.dims());
+
+        let error: f32 = (actual - &expected)
+            .and_then(|diff| diff.abs()?.flatten_all()?.max(0)?.to_scalar())
+            .unwrap();
+        assert!(error < 0.05, "max error {}", error);
+    }
+
+    #[test]
+    fn test_unaligned_layer_stays_dense() {
+        let quantized = QLinear::from_linear(layer(100, 16), TtsQuantization::Int4).unwrap();
+        assert!(!quantized.is_quantized());
+        let float = QLinear::from_linear(layer(64, 16), TtsQuantization::F16).unwrap();
+        assert!(!float.is_quantized());
+    }
+}
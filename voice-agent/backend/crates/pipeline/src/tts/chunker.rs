@@ -2,6 +2,8 @@
 //!
 //! Splits text into speakable chunks for early emission.
 
+use super::markup;
+
 /// Chunk output from the chunker
 #[derive(Debug, Clone)]
 pub struct TextChunk {
@@ -296,9 +298,10 @@ impl WordChunker {
         self.next_phrase()
     }
 
-    /// Check if word is a pause point
+    /// Check if word is a pause point (silence tokens from markup count)
     fn is_pause_point(&self, word: &str) -> bool {
-        word.ends_with(',')
+        markup::silence_ms(word).is_some()
+            || word.ends_with(',')
             || word.ends_with('.')
             || word.ends_with('!')
             || word.ends_with('?')
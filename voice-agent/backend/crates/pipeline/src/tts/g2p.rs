@@ -3,12 +3,16 @@
 //! Converts text to phoneme sequences for TTS models.
 //! Supports:
 //! - Devanagari script (Hindi)
-//! - Romanized Hindi (transliteration)
+//! - Romanized Hindi (detected and transliterated to Devanagari, see `hinglish`)
 //! - English words (IPA-based)
 //! - Code-mixed Hinglish text
+//! - Pronunciation lexicon overrides (see [`PronunciationLexicon`])
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
+use super::hinglish;
+use super::lexicon::PronunciationLexicon;
 use crate::PipelineError;
 
 /// Phoneme for TTS
@@ -100,6 +104,8 @@ pub struct HindiG2p {
     roman_to_devanagari: HashMap<&'static str, &'static str>,
     /// Common English words phonemes (for code-mixed text)
     english_phonemes: HashMap<&'static str, &'static str>,
+    /// User overrides, checked before any rule
+    lexicon: Option<Arc<PronunciationLexicon>>,
 }
 
 impl HindiG2p {
@@ -112,6 +118,7 @@ impl HindiG2p {
             matras: HashMap::new(),
             roman_to_devanagari: HashMap::new(),
             english_phonemes: HashMap::new(),
+            lexicon: None,
         };
         g2p.init_mappings();
         g2p
@@ -243,8 +250,26 @@ impl HindiG2p {
         }
     }
 
+    /// Use pronunciation overrides from a lexicon
+    pub fn with_lexicon(mut self, lexicon: Arc<PronunciationLexicon>) -> Self {
+        self.lexicon = Some(lexicon);
+        self
+    }
+
+    /// Lexicon languages to search, in order
+    fn lexicon_languages(&self) -> &'static [&'static str] {
+        match self.config.language {
+            Language::Hindi => &["hi"],
+            Language::English => &["en"],
+            Language::Hinglish => &["hi", "en"],
+        }
+    }
+
     /// Convert text to phonemes
     pub fn convert(&self, text: &str) -> Result<Vec<Phoneme>, PipelineError> {
+        if let Some(ref lexicon) = self.lexicon {
+            lexicon.reload_if_changed();
+        }
         let mut phonemes = Vec::new();
 
         if self.config.add_silence {
@@ -271,6 +296,12 @@ impl HindiG2p {
 
     /// Convert a single word to phonemes
     fn word_to_phonemes(&self, word: &str) -> Result<Vec<Phoneme>, PipelineError> {
+        if let Some(ref lexicon) = self.lexicon {
+            if let Some(entry) = lexicon.lookup(self.lexicon_languages(), word) {
+                return Ok(self.ipa_to_phonemes(&entry.phonemes));
+            }
+        }
+
         let word_lower = word.to_lowercase();
 
         // Check if it's a known English word
@@ -283,6 +314,12 @@ impl HindiG2p {
             return self.devanagari_to_phonemes(word);
         }
 
+        // Romanized Hindi ("paise", "byaaj") reads with the Devanagari rules
+        let bare = word_lower.trim_matches(|c: char| c.is_ascii_punctuation());
+        if self.config.language != Language::English && hinglish::is_romanized_hindi(bare) {
+            return self.devanagari_to_phonemes(&hinglish::to_devanagari(bare));
+        }
+
         // Try Roman Hindi transliteration
         if self.config.transliteration_fallback {
             return self.roman_hindi_to_phonemes(&word_lower);
@@ -570,4 +607,54 @@ mod tests {
         let s = g2p.phonemes_to_string(&phonemes);
         assert!(!s.is_empty());
     }
+
+    #[test]
+    fn test_lexicon_override() {
+        use voice_agent_config::{LexiconConfig, LexiconEntry};
+
+        let entry = LexiconEntry {
+            phonemes: "koːʈək".to_string(),
+            respell: None,
+        };
+        let config = LexiconConfig {
+            languages: HashMap::from([(
+                "hi".to_string(),
+                HashMap::from([("kotak".to_string(), entry)]),
+            )]),
+        };
+        let g2p = HindiG2p::new(G2pConfig {
+            add_silence: false,
+            ..Default::default()
+        })
+        .with_lexicon(Arc::new(PronunciationLexicon::new(config)));
+
+        let phonemes = g2p.convert("Kotak").unwrap();
+        assert_eq!(g2p.phonemes_to_string(&phonemes), "koːʈək");
+        // Other words still follow the rules
+        assert!(!g2p.convert("andheri").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_romanized_hindi_uses_devanagari_rules() {
+        let g2p = HindiG2p::new(G2pConfig {
+            add_silence: false,
+            ..Default::default()
+        });
+        let roman = g2p.convert("byaaj").unwrap();
+        let devanagari = g2p.convert("ब्याज्").unwrap();
+        assert_eq!(
+            g2p.phonemes_to_string(&roman),
+            g2p.phonemes_to_string(&devanagari)
+        );
+        assert_eq!(g2p.phonemes_to_string(&roman), "bjaːdʒ");
+
+        // English mode keeps letter rules
+        let english = HindiG2p::new(G2pConfig {
+            language: Language::English,
+            add_silence: false,
+            ..Default::default()
+        });
+        let phonemes = english.convert("byaaj").unwrap();
+        assert_ne!(english.phonemes_to_string(&phonemes), "bjaːdʒ");
+    }
 }
@@ -0,0 +1,192 @@
+//! Romanized Hindi in Latin Script
+//!
+//! LLM replies mix English with Hindi typed in Latin letters ("aapko paise
+//! kab chahiye", "byaaj dar"). Read with letter rules those words come out
+//! anglicized, so the G2P first decides whether a Latin token is romanized
+//! Hindi and, if so, transliterates it to Devanagari and uses the Devanagari
+//! rules.
+//!
+//! Detection is a word list of common Hinglish words plus spelling cues that
+//! rarely occur in English (doubled "aa", aspirates like "bh"/"kh", verb
+//! endings like "-iye"/"-enge"). Transliteration follows the informal
+//! spelling people actually type: a final "a" is long ("paisa" → पैसा), a
+//! final "n" after a vowel is nasal ("hain" → हैं).
+
+/// Common Hinglish words that spelling cues alone would miss
+#[rustfmt::skip]
+const HINGLISH_WORDS: &[&str] = &[
+    // Pronouns, verbs and particles
+    "hai", "hain", "ho", "hoon", "hun", "tha", "thi", "kya", "kyun", "kyon", "kaise",
+    "kitna", "kitni", "kitne", "kab", "kahan", "yahan", "wahan", "aap", "aapka", "aapki",
+    "aapko", "mera", "meri", "mere", "mujhe", "hum", "hamara", "hamare", "humko", "tum",
+    "nahi", "nahin", "haan", "ji", "ka", "ki", "ke", "ko", "se", "mein", "par", "aur", "ya",
+    "bhi", "toh", "abhi", "phir", "chahiye", "karna", "karo", "karein", "kijiye", "dijiye",
+    "batao", "bataiye", "sakte", "sakta", "sakti", "milega", "milegi", "raha", "rahi", "rahe",
+    // Money and gold loan words
+    "paisa", "paise", "rupaye", "rupaiya", "byaaj", "byaj", "sona", "sone", "gehne", "zevar",
+    "karz", "karza", "kist", "kisht", "mahina", "mahine", "saal", "din", "lakh", "hazaar",
+    "hazar", "dar", "jama", "udhaar", "bachat",
+    // Everyday words
+    "ghar", "kaam", "samay", "jaldi", "theek", "thik", "accha", "achha", "acha", "bahut",
+    "kam", "zyada", "jyada", "sasta", "mehenga", "wala", "wali", "wale", "dhanyavaad",
+    "dhanyawad", "shukriya", "namaste", "namaskar",
+];
+
+/// Spellings that mark a word as romanized Hindi
+const HINDI_CLUSTERS: &[&str] = &["aa", "bh", "dh", "kh", "gh", "jh", "chh"];
+const HINDI_ENDINGS: &[&str] = &[
+    "iye", "aiye", "enge", "ega", "egi", "ogi", "oge", "wala", "wali", "wale", "iyon",
+];
+/// Spellings that mark a word as English
+const ENGLISH_ENDINGS: &[&str] = &["tion", "sion", "ing", "ment", "ness", "ly", "ity", "ous"];
+
+/// Whether a Latin-script word is romanized Hindi
+pub fn is_romanized_hindi(word: &str) -> bool {
+    if word.is_empty() || !word.chars().all(|c| c.is_ascii_alphabetic()) {
+        return false;
+    }
+    let word = word.to_ascii_lowercase();
+    if HINGLISH_WORDS.contains(&word.as_str()) {
+        return true;
+    }
+    if ENGLISH_ENDINGS.iter().any(|end| word.ends_with(end)) {
+        return false;
+    }
+    HINDI_CLUSTERS.iter().any(|cluster| word.contains(cluster))
+        || HINDI_ENDINGS.iter().any(|end| word.ends_with(end))
+}
+
+/// Consonants, longest spelling first
+const CONSONANTS: &[(&str, &str)] = &[
+    ("chh", "छ"),
+    ("ksh", "क्ष"),
+    ("kh", "ख"),
+    ("gh", "घ"),
+    ("ch", "च"),
+    ("jh", "झ"),
+    ("th", "थ"),
+    ("dh", "ध"),
+    ("ph", "फ"),
+    ("bh", "भ"),
+    ("sh", "श"),
+    ("k", "क"),
+    ("g", "ग"),
+    ("c", "क"),
+    ("j", "ज"),
+    ("t", "त"),
+    ("d", "द"),
+    ("n", "न"),
+    ("p", "प"),
+    ("f", "\u{095E}"),
+    ("b", "ब"),
+    ("m", "म"),
+    ("y", "य"),
+    ("r", "र"),
+    ("l", "ल"),
+    ("v", "व"),
+    ("w", "व"),
+    ("s", "स"),
+    ("h", "ह"),
+    ("z", "\u{095B}"),
+    ("q", "\u{0958}"),
+    ("x", "क्स"),
+];
+
+/// Vowels as (spelling, independent letter, vowel sign), longest first
+const VOWELS: &[(&str, &str, &str)] = &[
+    ("aa", "आ", "\u{093E}"),
+    ("ai", "ऐ", "\u{0948}"),
+    ("au", "औ", "\u{094C}"),
+    ("ee", "ई", "\u{0940}"),
+    ("ii", "ई", "\u{0940}"),
+    ("ei", "ए", "\u{0947}"),
+    ("oo", "ऊ", "\u{0942}"),
+    ("uu", "ऊ", "\u{0942}"),
+    ("a", "अ", ""),
+    ("i", "इ", "\u{093F}"),
+    ("u", "उ", "\u{0941}"),
+    ("e", "ए", "\u{0947}"),
+    ("o", "ओ", "\u{094B}"),
+];
+
+const VIRAMA: &str = "\u{094D}";
+const ANUSVARA: &str = "\u{0902}";
+
+/// Transliterate a romanized Hindi word to Devanagari
+///
+/// Consonant clusters and a final consonant get a virama, so the Devanagari
+/// rules add no inherent vowel the speaker would not say.
+pub fn to_devanagari(word: &str) -> String {
+    let word = word.to_ascii_lowercase();
+    let mut out = String::with_capacity(word.len() * 3);
+    let mut rest = word.as_str();
+    let mut after_consonant = false;
+
+    while !rest.is_empty() {
+        if let Some(&(spelling, letter, sign)) = VOWELS.iter().find(|v| rest.starts_with(v.0)) {
+            let last = rest.len() == spelling.len();
+            let (letter, sign) = match spelling {
+                // Final short vowels are spoken long in informal spelling
+                "a" if last && !out.is_empty() => ("आ", "\u{093E}"),
+                "i" if last && !out.is_empty() => ("ई", "\u{0940}"),
+                _ => (letter, sign),
+            };
+            out.push_str(if after_consonant { sign } else { letter });
+            after_consonant = false;
+            rest = &rest[spelling.len()..];
+            continue;
+        }
+
+        // "hain", "mein": a final n after a vowel nasalizes it
+        if rest == "n" && !after_consonant && !out.is_empty() {
+            out.push_str(ANUSVARA);
+            break;
+        }
+
+        match CONSONANTS.iter().find(|c| rest.starts_with(c.0)) {
+            Some(&(spelling, letter)) => {
+                if after_consonant {
+                    out.push_str(VIRAMA);
+                }
+                out.push_str(letter);
+                after_consonant = true;
+                rest = &rest[spelling.len()..];
+            },
+            None => {
+                let skip = rest.chars().next().map_or(1, char::len_utf8);
+                rest = &rest[skip..];
+            },
+        }
+    }
+
+    if after_consonant {
+        out.push_str(VIRAMA);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detection() {
+        for word in ["paise", "Byaaj", "chahiye", "bhugtaan", "dijiye", "milenge"] {
+            assert!(is_romanized_hindi(word), "{word}");
+        }
+        for word in ["loan", "eligibility", "branch", "processing", "khaki1", ""] {
+            assert!(!is_romanized_hindi(word), "{word}");
+        }
+    }
+
+    #[test]
+    fn test_transliteration() {
+        assert_eq!(to_devanagari("paise"), "पैसे");
+        assert_eq!(to_devanagari("byaaj"), "ब्याज्");
+        assert_eq!(to_devanagari("sona"), "सोना");
+        assert_eq!(to_devanagari("kya"), "क्या");
+        assert_eq!(to_devanagari("hain"), "हैं");
+        assert_eq!(to_devanagari("nahi"), "नही");
+        assert_eq!(to_devanagari("aap"), "आप्");
+    }
+}
@@ -0,0 +1,236 @@
+//! Pronunciation Lexicon
+//!
+//! User-editable pronunciation overrides (lexicon.yaml) shared by every
+//! session. [`HindiG2p`](super::HindiG2p) uses an entry's phonemes instead of
+//! its rules; [`StreamingTts`](super::StreamingTts) swaps in the entry's
+//! respelling before synthesis, for engines that read text.
+//!
+//! When built from a file, the lexicon re-reads it once it changes on disk
+//! (checked at most every [`RELOAD_CHECK_INTERVAL`]), so a fixed name is
+//! spoken correctly from the next utterance on.
+
+use parking_lot::{Mutex, RwLock};
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime};
+
+use voice_agent_config::{LexiconConfig, LexiconEntry};
+
+/// Minimum time between checks of the lexicon file
+pub const RELOAD_CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Pronunciation overrides, optionally backed by a watched file
+pub struct PronunciationLexicon {
+    config: RwLock<LexiconConfig>,
+    source: Option<LexiconSource>,
+}
+
+/// Watched lexicon file
+struct LexiconSource {
+    path: PathBuf,
+    /// Modification time of the loaded version, and when it was last checked
+    state: Mutex<(Option<SystemTime>, Instant)>,
+}
+
+impl std::fmt::Debug for PronunciationLexicon {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PronunciationLexicon")
+            .field("entries", &self.config.read().len())
+            .field("path", &self.source.as_ref().map(|s| &s.path))
+            .finish()
+    }
+}
+
+impl PronunciationLexicon {
+    /// Lexicon with fixed entries
+    pub fn new(config: LexiconConfig) -> Self {
+        Self {
+            config: RwLock::new(config.normalized()),
+            source: None,
+        }
+    }
+
+    /// Reload `config` from `path` whenever the file changes
+    pub fn watching(config: LexiconConfig, path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let modified = modified_time(&path);
+        Self {
+            config: RwLock::new(config.normalized()),
+            source: Some(LexiconSource {
+                path,
+                state: Mutex::new((modified, Instant::now())),
+            }),
+        }
+    }
+
+    /// Re-read the file if it changed since it was loaded
+    ///
+    /// Returns true when new entries were loaded. A file that fails to parse
+    /// is logged and the previous entries stay in use.
+    pub fn reload_if_changed(&self) -> bool {
+        let Some(ref source) = self.source else {
+            return false;
+        };
+        let modified = {
+            let mut state = source.state.lock();
+            if state.1.elapsed() < RELOAD_CHECK_INTERVAL {
+                return false;
+            }
+            state.1 = Instant::now();
+            let modified = modified_time(&source.path);
+            if modified == state.0 {
+                return false;
+            }
+            state.0 = modified;
+            modified
+        };
+        if modified.is_none() {
+            return false;
+        }
+
+        match LexiconConfig::load(&source.path) {
+            Ok(config) => {
+                tracing::info!(
+                    path = %source.path.display(),
+                    entries = config.len(),
+                    "Reloaded pronunciation lexicon"
+                );
+                *self.config.write() = config;
+                true
+            },
+            Err(e) => {
+                tracing::warn!(error = %e, "Lexicon reload failed, keeping previous entries");
+                false
+            },
+        }
+    }
+
+    /// Override for `word` in the first of `languages` that has one
+    pub fn lookup(&self, languages: &[&str], word: &str) -> Option<LexiconEntry> {
+        let word = trim_word(word);
+        if word.is_empty() {
+            return None;
+        }
+        let config = self.config.read();
+        languages
+            .iter()
+            .find_map(|language| config.entry(language, word))
+            .cloned()
+    }
+
+    /// Replace words that have a respelling in `language`
+    ///
+    /// Punctuation around a word and the spacing between words are kept.
+    pub fn respell(&self, language: &str, text: &str) -> String {
+        let config = self.config.read();
+        if config.is_empty() {
+            return text.to_string();
+        }
+
+        let mut out = String::with_capacity(text.len());
+        for token in text.split_inclusive(char::is_whitespace) {
+            let word = trim_word(token);
+            let respelling = (!word.is_empty())
+                .then(|| config.entry(language, word))
+                .flatten()
+                .and_then(|entry| entry.respell.as_deref());
+            match respelling {
+                Some(respelling) => {
+                    let start = token.find(word).unwrap_or(0);
+                    out.push_str(&token[..start]);
+                    out.push_str(respelling);
+                    out.push_str(&token[start + word.len()..]);
+                },
+                None => out.push_str(token),
+            }
+        }
+        out
+    }
+
+    /// Number of entries across languages
+    pub fn len(&self) -> usize {
+        self.config.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Word without surrounding punctuation or whitespace
+fn trim_word(token: &str) -> &str {
+    token.trim_matches(|c: char| !c.is_alphanumeric())
+}
+
+fn modified_time(path: &std::path::Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    const LEXICON: &str = r#"
+languages:
+  hi:
+    kotak:
+      phonemes: "koːʈək"
+      respell: "कोटक"
+    andheri:
+      phonemes: "əndʱeːɾiː"
+"#;
+
+    fn lexicon_file(yaml: &str) -> tempfile::NamedTempFile {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(yaml.as_bytes()).unwrap();
+        file
+    }
+
+    #[test]
+    fn test_lookup_and_respell() {
+        let file = lexicon_file(LEXICON);
+        let lexicon = PronunciationLexicon::new(LexiconConfig::load(file.path()).unwrap());
+
+        let entry = lexicon.lookup(&["hi", "en"], "Kotak,").unwrap();
+        assert_eq!(entry.phonemes, "koːʈək");
+        assert!(lexicon.lookup(&["en"], "kotak").is_none());
+
+        // Only words with a respelling change; punctuation stays
+        assert_eq!(
+            lexicon.respell("hi-IN", "Kotak, Andheri branch!"),
+            "कोटक, Andheri branch!"
+        );
+        assert_eq!(lexicon.respell("en", "Kotak"), "Kotak");
+    }
+
+    #[test]
+    fn test_reload_when_file_changes() {
+        let file = lexicon_file(LEXICON);
+        let config = LexiconConfig::load(file.path()).unwrap();
+        let lexicon = PronunciationLexicon::watching(config, file.path());
+        assert_eq!(lexicon.len(), 2);
+        let expire_check = || {
+            lexicon.source.as_ref().unwrap().state.lock().1 -= RELOAD_CHECK_INTERVAL;
+        };
+
+        // Unchanged file: nothing to do
+        expire_check();
+        assert!(!lexicon.reload_if_changed());
+
+        std::fs::write(
+            file.path(),
+            "languages:\n  hi:\n    thane:\n      phonemes: \"ʈʰaːɳeː\"\n",
+        )
+        .unwrap();
+        file.as_file()
+            .set_modified(SystemTime::now() + Duration::from_secs(60))
+            .unwrap();
+
+        // Throttled until the check interval passes
+        assert!(!lexicon.reload_if_changed());
+        expire_check();
+        assert!(lexicon.reload_if_changed());
+        assert_eq!(lexicon.len(), 1);
+        assert!(lexicon.lookup(&["hi"], "thane").is_some());
+    }
+}
@@ -0,0 +1,543 @@
+//! Prosody Markup
+//!
+//! Lightweight SSML-like markup accepted by [`StreamingTts`](super::StreamingTts):
+//!
+//! - `<pause/>`, `<pause ms="500"/>` (SSML `<break time="500ms"/>` also works): silence
+//! - `<emphasis>...</emphasis>`: stressed words
+//! - `<say-as type="currency|phone|digits|cardinal">...</say-as>`: spoken form
+//!
+//! `say-as` is rendered to words while parsing, in the script of the text, so
+//! "₹5,00,000" is spoken as "five lakh rupees" and phone numbers digit by
+//! digit. Pauses and emphasis
+//! are lowered per backend (see [`ProsodySupport`]): engines that honor
+//! punctuation get commas, the rest get silence spliced between synthesis
+//! calls. Anything that is not a recognized tag is spoken as text, so
+//! "EMI < 5000" is safe.
+
+use super::normalize::{SpokenLanguage, TextNormalizer};
+
+/// Pause length for a bare `<pause/>`
+pub const DEFAULT_PAUSE_MS: u32 = 300;
+
+/// Longest pause honored; longer requests are clamped
+pub const MAX_PAUSE_MS: u32 = 3000;
+
+/// Pause placed around emphasized words
+const EMPHASIS_PAUSE_MS: u32 = 150;
+
+/// Longest pause lowered to punctuation on engines that honor it
+const PUNCTUATION_PAUSE_MS: u32 = 400;
+
+/// Starts a silence token in lowered text (private use, never spoken)
+const SILENCE_MARK: char = '\u{E000}';
+
+/// Parsed markup
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarkupSegment {
+    /// Text to speak (`say-as` already rendered)
+    Text { text: String, emphasis: bool },
+    /// Silence
+    Pause { ms: u32 },
+}
+
+/// How a backend renders emphasis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmphasisStyle {
+    /// Short pauses around the emphasized words
+    #[default]
+    Pauses,
+    /// Speak emphasized words like any other
+    Plain,
+}
+
+/// Prosody a backend renders natively
+///
+/// Markup the engine cannot render is lowered to something it can.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ProsodySupport {
+    /// Engine renders commas and full stops as natural pauses, so short
+    /// pauses become punctuation instead of splitting the synthesis call
+    pub punctuation_pauses: bool,
+    /// How emphasis is lowered
+    pub emphasis: EmphasisStyle,
+    /// Engine is conditioned on speaking rate (no time-stretching needed)
+    pub rate: bool,
+    /// Engine is conditioned on pitch (no pitch-shifting needed)
+    pub pitch: bool,
+}
+
+/// Lowered text split for synthesis
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum SpeechPart {
+    Text(String),
+    Silence(u32),
+}
+
+/// Recognized tag
+enum Tag {
+    Pause(u32),
+    EmphasisOpen,
+    EmphasisClose,
+    SayAsOpen(String),
+    SayAsClose,
+}
+
+/// Whether the text may contain markup
+pub fn has_markup(text: &str) -> bool {
+    text.contains('<')
+}
+
+/// Parse markup into segments
+pub fn parse(text: &str) -> Vec<MarkupSegment> {
+    let spoken = SpokenLanguage::detect(text);
+    let mut segments = Vec::new();
+    let mut emphasis = 0usize;
+    let mut rest = text;
+
+    while let Some(lt) = rest.find('<') {
+        push_text(&mut segments, &rest[..lt], emphasis > 0);
+        rest = &rest[lt..];
+
+        match parse_tag(rest) {
+            Some((Tag::Pause(ms), len)) => {
+                if ms > 0 {
+                    segments.push(MarkupSegment::Pause { ms });
+                }
+                rest = &rest[len..];
+            },
+            Some((Tag::EmphasisOpen, len)) => {
+                emphasis += 1;
+                rest = &rest[len..];
+            },
+            Some((Tag::EmphasisClose, len)) => {
+                emphasis = emphasis.saturating_sub(1);
+                rest = &rest[len..];
+            },
+            Some((Tag::SayAsOpen(kind), len)) => {
+                let body = &rest[len..];
+                let (content, consumed) = match find_say_as_close(body) {
+                    Some((start, end)) => (&body[..start], len + end),
+                    None => (body, rest.len()),
+                };
+                push_text(&mut segments, &say_as(&kind, content, spoken), emphasis > 0);
+                rest = &rest[consumed..];
+            },
+            // Stray closing tag
+            Some((Tag::SayAsClose, len)) => rest = &rest[len..],
+            None => {
+                push_text(&mut segments, "<", emphasis > 0);
+                rest = &rest[1..];
+            },
+        }
+    }
+    push_text(&mut segments, rest, emphasis > 0);
+
+    segments
+}
+
+/// Text with markup removed and `say-as` rendered, e.g. for transcripts
+pub fn plain_text(text: &str) -> String {
+    if !has_markup(text) {
+        return text.to_string();
+    }
+
+    let mut out = String::new();
+    for segment in parse(text) {
+        match segment {
+            MarkupSegment::Text { text, .. } => out.push_str(&text),
+            MarkupSegment::Pause { .. } => out.push(' '),
+        }
+    }
+    out.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Lower segments to text the backend can synthesize
+///
+/// Pauses that cannot be punctuation become silence tokens, which
+/// `StreamingTts` turns into silent samples between synthesis calls.
+pub fn lower(segments: &[MarkupSegment], support: ProsodySupport) -> String {
+    let mut out = String::new();
+
+    for (i, segment) in segments.iter().enumerate() {
+        match segment {
+            MarkupSegment::Text {
+                text,
+                emphasis: true,
+            } if support.emphasis == EmphasisStyle::Pauses => {
+                push_pause(&mut out, EMPHASIS_PAUSE_MS, support);
+                out.push_str(text.trim());
+                // Punctuation right after the emphasis already pauses
+                let punctuated = matches!(
+                    segments.get(i + 1),
+                    Some(MarkupSegment::Text { text, .. }) if text.starts_with(is_pause_punctuation)
+                );
+                if !punctuated {
+                    push_pause(&mut out, EMPHASIS_PAUSE_MS, support);
+                }
+            },
+            MarkupSegment::Text { text, .. } => out.push_str(text),
+            MarkupSegment::Pause { ms } => push_pause(&mut out, *ms, support),
+        }
+    }
+
+    out
+}
+
+/// Whether lowered text contains silence tokens
+pub(super) fn has_silence(text: &str) -> bool {
+    text.contains(SILENCE_MARK)
+}
+
+/// Silence length if the word is a silence token
+pub(super) fn silence_ms(word: &str) -> Option<u32> {
+    word.strip_prefix(SILENCE_MARK)?.parse().ok()
+}
+
+/// Split lowered text into speech and silence
+pub(super) fn split_silences(text: &str) -> Vec<SpeechPart> {
+    let mut parts = Vec::new();
+    let mut words: Vec<&str> = Vec::new();
+
+    for word in text.split_whitespace() {
+        match silence_ms(word) {
+            Some(ms) => {
+                if !words.is_empty() {
+                    parts.push(SpeechPart::Text(words.join(" ")));
+                    words.clear();
+                }
+                parts.push(SpeechPart::Silence(ms));
+            },
+            None => words.push(word),
+        }
+    }
+    if !words.is_empty() {
+        parts.push(SpeechPart::Text(words.join(" ")));
+    }
+
+    parts
+}
+
+/// Lowered text without silence tokens
+pub(super) fn strip_silences(text: &str) -> String {
+    if !has_silence(text) {
+        return text.to_string();
+    }
+    text.split_whitespace()
+        .filter(|word| silence_ms(word).is_none())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Length of the prefix that can be parsed without cutting a tag in half
+///
+/// Used when text is streamed in pieces: an unterminated tag or an
+/// `<emphasis>`/`<say-as>` still waiting for its closing tag is held back.
+pub(super) fn complete_prefix_len(text: &str) -> usize {
+    let mut open: Vec<usize> = Vec::new();
+    let mut pos = 0;
+
+    while let Some(lt) = text[pos..].find('<').map(|i| pos + i) {
+        match parse_tag(&text[lt..]) {
+            Some((tag, len)) => {
+                match tag {
+                    Tag::EmphasisOpen | Tag::SayAsOpen(_) => open.push(lt),
+                    Tag::EmphasisClose | Tag::SayAsClose => {
+                        open.pop();
+                    },
+                    Tag::Pause(_) => {},
+                }
+                pos = lt + len;
+            },
+            None if !text[lt..].contains('>') && could_be_tag(&text[lt + 1..]) => {
+                return open.first().copied().unwrap_or(lt);
+            },
+            None => pos = lt + 1,
+        }
+    }
+
+    open.first().copied().unwrap_or(text.len())
+}
+
+fn could_be_tag(rest: &str) -> bool {
+    match rest.chars().next() {
+        Some(c) => c.is_ascii_alphabetic() || c == '/',
+        None => true,
+    }
+}
+
+fn push_text(segments: &mut Vec<MarkupSegment>, text: &str, emphasis: bool) {
+    if text.is_empty() {
+        return;
+    }
+    if let Some(MarkupSegment::Text {
+        text: last,
+        emphasis: last_emphasis,
+    }) = segments.last_mut()
+    {
+        if *last_emphasis == emphasis {
+            last.push_str(text);
+            return;
+        }
+    }
+    segments.push(MarkupSegment::Text {
+        text: text.to_string(),
+        emphasis,
+    });
+}
+
+fn push_pause(out: &mut String, ms: u32, support: ProsodySupport) {
+    let spoken_len = out.trim_end().len();
+
+    if support.punctuation_pauses && ms <= PUNCTUATION_PAUSE_MS && spoken_len > 0 {
+        out.truncate(spoken_len);
+        if !out.ends_with(is_pause_punctuation) {
+            out.push(',');
+        }
+        out.push(' ');
+    } else {
+        if !out.is_empty() && !out.ends_with(char::is_whitespace) {
+            out.push(' ');
+        }
+        out.push(SILENCE_MARK);
+        out.push_str(&ms.to_string());
+        out.push(' ');
+    }
+}
+
+fn is_pause_punctuation(c: char) -> bool {
+    matches!(c, ',' | '.' | '!' | '?' | ';' | ':' | '।' | '…')
+}
+
+/// Parse a tag at the start of `s` (which begins with '<')
+///
+/// Returns `None` for anything that is not a well-formed, recognized tag.
+fn parse_tag(s: &str) -> Option<(Tag, usize)> {
+    let end = s.find('>')?;
+    let inner = s[1..end].trim();
+    let (closing, inner) = match inner.strip_prefix('/') {
+        Some(rest) => (true, rest.trim_start()),
+        None => (false, inner),
+    };
+    let inner = inner.strip_suffix('/').unwrap_or(inner).trim_end();
+    let (name, attributes) = inner.split_once(char::is_whitespace).unwrap_or((inner, ""));
+    let attributes = parse_attributes(attributes)?;
+    let attribute = |key: &str| {
+        attributes
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .map(|(_, v)| v.as_str())
+    };
+
+    let tag = match (name.to_ascii_lowercase().as_str(), closing) {
+        // A stray `</pause>` is recognized and dropped
+        ("pause" | "break", true) => Tag::Pause(0),
+        ("pause" | "break", false) => {
+            let ms = match (attribute("ms"), attribute("time")) {
+                (Some(ms), _) => ms.trim().parse().ok()?,
+                (None, Some(time)) => parse_duration_ms(time)?,
+                (None, None) => DEFAULT_PAUSE_MS,
+            };
+            Tag::Pause(ms.min(MAX_PAUSE_MS))
+        },
+        ("emphasis", false) => Tag::EmphasisOpen,
+        ("emphasis", true) => Tag::EmphasisClose,
+        ("say-as", false) => Tag::SayAsOpen(
+            attribute("type")
+                .or_else(|| attribute("interpret-as"))
+                .unwrap_or_default()
+                .to_string(),
+        ),
+        ("say-as", true) => Tag::SayAsClose,
+        _ => return None,
+    };
+
+    Some((tag, end + 1))
+}
+
+/// `key="value"` pairs; `None` if malformed
+fn parse_attributes(mut s: &str) -> Option<Vec<(String, String)>> {
+    let mut attributes = Vec::new();
+
+    loop {
+        s = s.trim_start();
+        if s.is_empty() {
+            return Some(attributes);
+        }
+        let (key, rest) = s.split_once('=')?;
+        let rest = rest.trim_start();
+        let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let (value, rest) = rest[1..].split_once(quote)?;
+        attributes.push((key.trim().to_string(), value.to_string()));
+        s = rest;
+    }
+}
+
+/// "500ms", "1.5s" or "500"
+fn parse_duration_ms(time: &str) -> Option<u32> {
+    let time = time.trim();
+    if let Some(ms) = time.strip_suffix("ms") {
+        ms.trim().parse().ok()
+    } else if let Some(secs) = time.strip_suffix('s') {
+        let secs: f32 = secs.trim().parse().ok()?;
+        (secs >= 0.0).then(|| (secs * 1000.0).round() as u32)
+    } else {
+        time.parse().ok()
+    }
+}
+
+/// Byte range of the next `</say-as>` in `body`
+fn find_say_as_close(body: &str) -> Option<(usize, usize)> {
+    let mut pos = 0;
+    while let Some(lt) = body[pos..].find('<').map(|i| pos + i) {
+        if let Some((Tag::SayAsClose, len)) = parse_tag(&body[lt..]) {
+            return Some((lt, lt + len));
+        }
+        pos = lt + 1;
+    }
+    None
+}
+
+/// Render `say-as` content; unparseable content is spoken as is
+fn say_as(kind: &str, content: &str, spoken: SpokenLanguage) -> String {
+    let rendered = match kind.to_ascii_lowercase().as_str() {
+        "currency" | "money" => spoken.currency(content),
+        "phone" | "telephone" | "digits" => spoken.digits(content),
+        "cardinal" | "number" => spoken.decimal(content.trim()),
+        "date" => Some(TextNormalizer::new(Some(spoken)).normalize(content)),
+        _ => None,
+    };
+    rendered.unwrap_or_else(|| content.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(text: &str, emphasis: bool) -> MarkupSegment {
+        MarkupSegment::Text {
+            text: text.to_string(),
+            emphasis,
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let segments = parse(
+            r#"Your loan of <say-as type="currency">₹5,00,000</say-as> is <emphasis>approved</emphasis>.<pause ms="500"/>Call <say-as type="phone">1800 123 4567</say-as>"#,
+        );
+        assert_eq!(
+            segments,
+            vec![
+                text("Your loan of five lakh rupees is ", false),
+                text("approved", true),
+                text(".", false),
+                MarkupSegment::Pause { ms: 500 },
+                text(
+                    "Call one eight zero zero, one two three, four five six seven",
+                    false
+                ),
+            ]
+        );
+
+        // Unknown tags and stray brackets are spoken as text
+        assert_eq!(
+            parse("EMI < 5000 <b>only</b>"),
+            vec![text("EMI < 5000 <b>only</b>", false)]
+        );
+        assert_eq!(
+            parse(r#"<break time="1.5s"/><pause/><pause ms="99999"/>"#),
+            vec![
+                MarkupSegment::Pause { ms: 1500 },
+                MarkupSegment::Pause {
+                    ms: DEFAULT_PAUSE_MS
+                },
+                MarkupSegment::Pause { ms: MAX_PAUSE_MS },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_say_as() {
+        let en = SpokenLanguage::English;
+        assert_eq!(say_as("currency", "₹1", en), "one rupee");
+        assert_eq!(
+            say_as("currency", "₹2.5 crore", en),
+            "two point five crore rupees"
+        );
+        assert_eq!(
+            say_as("cardinal", "12,34,56,789", en),
+            "twelve crore thirty four lakh fifty six thousand seven hundred eighty nine"
+        );
+        assert_eq!(
+            say_as("date", "12/01/2025", en),
+            "twelfth January twenty twenty five"
+        );
+        // Unparseable content is left alone
+        assert_eq!(say_as("currency", "a lot", en), "a lot");
+        assert_eq!(say_as("ordinal", "3", en), "3");
+
+        // Rendered in the script of the surrounding text
+        assert_eq!(
+            parse(r#"आपको <say-as type="currency">₹5,00,000</say-as> मिलेंगे"#),
+            vec![text("आपको पाँच लाख रुपये मिलेंगे", false)]
+        );
+    }
+
+    #[test]
+    fn test_lowering() {
+        let segments = parse("Rate is <emphasis>9.5 percent</emphasis> only.<pause/> Thanks");
+
+        let silence = lower(&segments, ProsodySupport::default());
+        assert_eq!(
+            split_silences(&silence),
+            vec![
+                SpeechPart::Text("Rate is".into()),
+                SpeechPart::Silence(EMPHASIS_PAUSE_MS),
+                SpeechPart::Text("9.5 percent".into()),
+                SpeechPart::Silence(EMPHASIS_PAUSE_MS),
+                SpeechPart::Text("only.".into()),
+                SpeechPart::Silence(DEFAULT_PAUSE_MS),
+                SpeechPart::Text("Thanks".into()),
+            ]
+        );
+        assert_eq!(strip_silences(&silence), "Rate is 9.5 percent only. Thanks");
+
+        let punctuation = lower(
+            &segments,
+            ProsodySupport {
+                punctuation_pauses: true,
+                emphasis: EmphasisStyle::Pauses,
+                ..Default::default()
+            },
+        );
+        assert!(!has_silence(&punctuation));
+        assert_eq!(
+            punctuation.split_whitespace().collect::<Vec<_>>().join(" "),
+            "Rate is, 9.5 percent, only. Thanks"
+        );
+
+        let plain = lower(
+            &parse("<emphasis>Now</emphasis> <pause ms=\"1000\"/>"),
+            ProsodySupport {
+                punctuation_pauses: true,
+                emphasis: EmphasisStyle::Plain,
+                ..Default::default()
+            },
+        );
+        assert_eq!(split_silences(&plain).len(), 2);
+        assert_eq!(plain_text("a<pause/>b"), "a b");
+    }
+
+    #[test]
+    fn test_complete_prefix_len() {
+        assert_eq!(complete_prefix_len("plain text"), 10);
+        assert_eq!(complete_prefix_len("x < 5 and y"), 11);
+        assert_eq!(complete_prefix_len("Hello <pau"), 6);
+        assert_eq!(
+            complete_prefix_len(r#"Pay <say-as type="currency">₹5,00"#),
+            4
+        );
+        let closed = r#"Pay <say-as type="currency">₹5</say-as> now"#;
+        assert_eq!(complete_prefix_len(closed), closed.len());
+    }
+}
@@ -5,7 +5,15 @@
 //! - Barge-in aware (can stop mid-word)
 //! - Multiple backend support (Piper, IndicF5, Parler)
 //! - Hindi/Hinglish G2P conversion
-//! - Native Candle-based IndicF5 model (optional)
+//! - Pronunciation lexicon overrides, reloaded when edited
+//! - Viseme and gesture cues for avatar clients
+//! - Prosody markup (`<pause/>`, `<emphasis>`, `<say-as>`) lowered per backend
+//! - Number, ₹ amount, date and phone verbalization (English/Hindi)
+//! - Speaking rate, pitch and sentence pauses, time-stretched for engines
+//!   that can't be conditioned on them
+//! - Native Candle-based IndicF5 model (optional), with Int8/Int4 weights
+//!   for CPU-only hosts
+//! - Quality comparison between renderings (quantization benchmarks)
 //!
 //! ## P0-1 FIX: Engine Routing
 //!
@@ -14,9 +22,18 @@
 //! - `TtsEngine::Piper` uses ONNX-based Piper
 //! - `TtsEngine::ParlerTts` uses ONNX-based ParlerTts
 
+mod avatar;
+mod cache;
 mod chunker;
 mod g2p;
+mod hinglish;
+mod lexicon;
+pub mod markup;
+mod normalize;
+mod quality;
 mod streaming;
+mod stretch;
+mod voices;
 
 /// Candle-based TTS implementations (native Rust with SafeTensors)
 #[cfg(feature = "candle")]
@@ -29,18 +46,27 @@ pub mod candle {
     pub struct IndicF5Config;
 }
 
+pub use avatar::AvatarTimeline;
+pub use cache::{SynthesisCache, SynthesisCacheStats, SynthesisKey};
 pub use chunker::{ChunkStrategy, WordChunker};
 pub use g2p::{create_hindi_g2p, G2pConfig, HindiG2p, Language, Phoneme};
-pub use streaming::{StreamingTts, TtsConfig, TtsEngine, TtsEvent};
+pub use lexicon::PronunciationLexicon;
+pub use markup::{EmphasisStyle, MarkupSegment, ProsodySupport};
+pub use normalize::{SpokenLanguage, TextNormalizer};
+pub use quality::{compare_audio, AudioDistortion};
+pub(crate) use streaming::load_reference_audio;
+pub use streaming::{SpeechStyle, StreamingTts, TtsConfig, TtsEngine, TtsEvent};
+pub use voices::VoiceRegistry;
 
 // P1-3 FIX: Re-export IndicF5 model types from candle module
 // TtsBackend, StubTtsBackend, IndicF5Backend, and create_tts_backend
 // are already public as they're defined in this module
 #[cfg(feature = "candle")]
-pub use candle::{IndicF5Config, IndicF5Model};
+pub use candle::{IndicF5Config, IndicF5Model, TtsQuantization};
 
 use crate::PipelineError;
 use std::sync::Arc;
+use voice_agent_config::ModelPrecision;
 
 /// TTS backend trait
 #[async_trait::async_trait]
@@ -48,11 +74,43 @@ pub trait TtsBackend: Send + Sync {
     /// Synthesize text to audio
     async fn synthesize(&self, text: &str) -> Result<Vec<f32>, PipelineError>;
 
+    /// Synthesize several texts, one result per text in order
+    ///
+    /// Used by the shared worker pool. Backends that can run a padded batch
+    /// in one forward pass override this; the default synthesizes in turn.
+    async fn synthesize_batch(&self, texts: &[String]) -> Vec<Result<Vec<f32>, PipelineError>> {
+        let mut results = Vec::with_capacity(texts.len());
+        for text in texts {
+            results.push(self.synthesize(text).await);
+        }
+        results
+    }
+
     /// Get sample rate
     fn sample_rate(&self) -> u32;
 
     /// Supports streaming word-by-word?
     fn supports_streaming(&self) -> bool;
+
+    /// Prosody markup the engine renders natively
+    ///
+    /// Defaults to nothing native: pauses and emphasis become silence.
+    fn prosody_support(&self) -> ProsodySupport {
+        ProsodySupport::default()
+    }
+
+    /// Synthesize at a speaking rate and pitch
+    ///
+    /// Called for engines whose `prosody_support` reports `rate` or `pitch`;
+    /// the parts the engine doesn't render are applied to its output.
+    async fn synthesize_styled(
+        &self,
+        text: &str,
+        style: SpeechStyle,
+    ) -> Result<Vec<f32>, PipelineError> {
+        let _ = style;
+        self.synthesize(text).await
+    }
 }
 
 // ============================================================================
@@ -75,23 +133,32 @@ impl IndicF5Backend {
     /// # Arguments
     /// * `model_path` - Path to the SafeTensors model file
     /// * `reference_audio` - Reference audio samples for voice cloning (24kHz)
+    /// * `quantization` - Weight precision (Int8/Int4 for CPU-only hosts)
     pub fn new(
         model_path: impl AsRef<std::path::Path>,
         reference_audio: Vec<f32>,
+        quantization: candle::TtsQuantization,
     ) -> Result<Self, PipelineError> {
         use candle_core::Device;
 
         // Use CPU by default, can be extended to support CUDA
         let device = Device::Cpu;
 
-        let model = candle::IndicF5Model::load(model_path, None::<&std::path::Path>, device)
-            .map_err(|e| PipelineError::Model(format!("Failed to load IndicF5: {}", e)))?;
+        let config = candle::IndicF5Config::indicf5_hindi().with_quantization(quantization);
+        let model = candle::IndicF5Model::load_with_config(
+            model_path.as_ref(),
+            None,
+            config,
+            device,
+        )
+        .map_err(|e| PipelineError::Model(format!("Failed to load IndicF5: {}", e)))?;
 
         let sample_rate = model.config().sample_rate;
 
         tracing::info!(
-            "IndicF5 TTS backend loaded successfully (sample_rate={})",
-            sample_rate
+            "IndicF5 TTS backend loaded successfully (sample_rate={}, quantization={})",
+            sample_rate,
+            quantization.as_str()
         );
 
         Ok(Self {
@@ -104,10 +171,11 @@ impl IndicF5Backend {
     /// Create with default reference audio (silence - for testing)
     pub fn new_with_default_reference(
         model_path: impl AsRef<std::path::Path>,
+        quantization: candle::TtsQuantization,
     ) -> Result<Self, PipelineError> {
         // 1 second of silence at 24kHz as default reference
         let reference_audio = vec![0.0f32; 24000];
-        Self::new(model_path, reference_audio)
+        Self::new(model_path, reference_audio, quantization)
     }
 
     /// Set reference audio for voice cloning
@@ -146,6 +214,15 @@ impl TtsBackend for IndicF5Backend {
     fn supports_streaming(&self) -> bool {
         true // IndicF5 supports streaming via synthesize_streaming
     }
+
+    fn prosody_support(&self) -> ProsodySupport {
+        // Flow-matching model follows punctuation, so short pauses stay inline
+        ProsodySupport {
+            punctuation_pauses: true,
+            emphasis: EmphasisStyle::Pauses,
+            ..Default::default()
+        }
+    }
 }
 
 /// Stub backend when no model is loaded (returns silence)
@@ -187,11 +264,13 @@ impl TtsBackend for StubTtsBackend {
 /// * `engine` - Which TTS engine to use
 /// * `model_path` - Path to the model file/directory
 /// * `reference_audio` - Optional reference audio for voice cloning (IndicF5)
+/// * `quantization` - Weight precision for candle models (IndicF5)
 #[allow(unused_variables)] // model_path/reference_audio unused for stub backends
 pub fn create_tts_backend(
     engine: TtsEngine,
     model_path: Option<&std::path::Path>,
     reference_audio: Option<Vec<f32>>,
+    quantization: ModelPrecision,
 ) -> Result<Arc<dyn TtsBackend>, PipelineError> {
     match engine {
         TtsEngine::IndicF5 => {
@@ -201,10 +280,11 @@ pub fn create_tts_backend(
                     PipelineError::Model("IndicF5 requires model_path".to_string())
                 })?;
 
+                let quantization = candle::TtsQuantization::from(quantization);
                 let backend = if let Some(ref_audio) = reference_audio {
-                    IndicF5Backend::new(path, ref_audio)?
+                    IndicF5Backend::new(path, ref_audio, quantization)?
                 } else {
-                    IndicF5Backend::new_with_default_reference(path)?
+                    IndicF5Backend::new_with_default_reference(path, quantization)?
                 };
 
                 Ok(Arc::new(backend))
@@ -0,0 +1,764 @@
+//! Text Normalization for TTS
+//!
+//! Expands ₹ amounts, dates, percentages, phone numbers and plain numbers
+//! into words before synthesis. TTS engines and G2P read raw digit strings
+//! poorly: "₹5,00,000" comes out as a garble of digits and commas instead of
+//! "five lakh rupees". Amounts use Indian grouping (thousand, lakh, crore).
+//!
+//! Words are produced in English or Hindi (Devanagari), following the script
+//! of the text unless a language is fixed, so a Hindi voice says
+//! "पाँच लाख रुपये". Runs in `StreamingTts` before chunking, so every backend
+//! gets the same spoken form.
+
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use std::borrow::Cow;
+
+/// 2025-01-05
+static DATE_ISO: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b([0-9]{4})-([0-9]{1,2})-([0-9]{1,2})\b").unwrap());
+
+/// 05/01/2025, 5-1-25, 05.01.2025 (day first, as written in India)
+static DATE_DMY: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b([0-9]{1,2})[/.-]([0-9]{1,2})[/.-]([0-9]{4}|[0-9]{2})\b").unwrap());
+
+/// Indian mobile numbers with optional +91 or 0 prefix
+static MOBILE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(\+91[\s-]?|\b0|\b)([6-9][0-9]{4})[\s-]?([0-9]{5})\b").unwrap());
+
+/// Toll-free numbers (1800 123 4567)
+static TOLL_FREE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b(1800)[\s-]?([0-9]{3})[\s-]?([0-9]{4})\b").unwrap());
+
+/// ₹5,00,000 / Rs. 2.5 lakh / INR 50k / ₹5000/-
+static CURRENCY: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)(?:₹|\brs\.?|\binr)\s?([0-9]+(?:,[0-9]+)*(?:\.[0-9]+)?)(?:\s?(lakhs?|crores?|thousand|k)\b)?(?:/-)?",
+    )
+    .unwrap()
+});
+
+/// 9.5%
+static PERCENT: Lazy<Regex> = Lazy::new(|| Regex::new(r"([0-9]+(?:\.[0-9]+)?)\s?%").unwrap());
+
+/// 50g, 12.5 grams
+static GRAMS: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\b([0-9]+(?:\.[0-9]+)?)\s?(?:grams?|gms?|g)\b").unwrap());
+
+/// 22K gold
+static KARAT: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\b(14|18|20|22|24)\s?(?:k|kt|karat|carat)\b").unwrap());
+
+/// Any remaining number
+static NUMBER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b[0-9]+(?:,[0-9]+)*(?:\.[0-9]+)?\b").unwrap());
+
+/// Digit strings at least this long are read digit by digit (account numbers)
+const DIGIT_BY_DIGIT_LEN: usize = 10;
+
+const EN_ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const EN_TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// Hindi numbers below 100 are irregular and have to be listed
+const HI_BELOW_HUNDRED: [&str; 100] = [
+    "शून्य",
+    "एक",
+    "दो",
+    "तीन",
+    "चार",
+    "पाँच",
+    "छह",
+    "सात",
+    "आठ",
+    "नौ",
+    "दस",
+    "ग्यारह",
+    "बारह",
+    "तेरह",
+    "चौदह",
+    "पंद्रह",
+    "सोलह",
+    "सत्रह",
+    "अठारह",
+    "उन्नीस",
+    "बीस",
+    "इक्कीस",
+    "बाईस",
+    "तेईस",
+    "चौबीस",
+    "पच्चीस",
+    "छब्बीस",
+    "सत्ताईस",
+    "अट्ठाईस",
+    "उनतीस",
+    "तीस",
+    "इकतीस",
+    "बत्तीस",
+    "तैंतीस",
+    "चौंतीस",
+    "पैंतीस",
+    "छत्तीस",
+    "सैंतीस",
+    "अड़तीस",
+    "उनतालीस",
+    "चालीस",
+    "इकतालीस",
+    "बयालीस",
+    "तैंतालीस",
+    "चवालीस",
+    "पैंतालीस",
+    "छियालीस",
+    "सैंतालीस",
+    "अड़तालीस",
+    "उनचास",
+    "पचास",
+    "इक्यावन",
+    "बावन",
+    "तिरपन",
+    "चौवन",
+    "पचपन",
+    "छप्पन",
+    "सत्तावन",
+    "अट्ठावन",
+    "उनसठ",
+    "साठ",
+    "इकसठ",
+    "बासठ",
+    "तिरसठ",
+    "चौंसठ",
+    "पैंसठ",
+    "छियासठ",
+    "सड़सठ",
+    "अड़सठ",
+    "उनहत्तर",
+    "सत्तर",
+    "इकहत्तर",
+    "बहत्तर",
+    "तिहत्तर",
+    "चौहत्तर",
+    "पचहत्तर",
+    "छिहत्तर",
+    "सतहत्तर",
+    "अठहत्तर",
+    "उन्यासी",
+    "अस्सी",
+    "इक्यासी",
+    "बयासी",
+    "तिरासी",
+    "चौरासी",
+    "पचासी",
+    "छियासी",
+    "सत्तासी",
+    "अट्ठासी",
+    "नवासी",
+    "नब्बे",
+    "इक्यानबे",
+    "बानबे",
+    "तिरानबे",
+    "चौरानबे",
+    "पचानबे",
+    "छियानबे",
+    "सत्तानबे",
+    "अट्ठानबे",
+    "निन्यानबे",
+];
+
+/// Words a spoken form needs besides the numbers themselves
+struct Vocabulary {
+    /// crore, lakh, thousand, hundred
+    scales: [&'static str; 4],
+    months: [&'static str; 12],
+    point: &'static str,
+    percent: &'static str,
+    rupee: &'static str,
+    rupees: &'static str,
+    paise: &'static str,
+    and: &'static str,
+    plus: &'static str,
+    gram: &'static str,
+    grams: &'static str,
+    karat: &'static str,
+}
+
+const ENGLISH: Vocabulary = Vocabulary {
+    scales: ["crore", "lakh", "thousand", "hundred"],
+    months: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    point: "point",
+    percent: "percent",
+    rupee: "rupee",
+    rupees: "rupees",
+    paise: "paise",
+    and: "and",
+    plus: "plus",
+    gram: "gram",
+    grams: "grams",
+    karat: "karat",
+};
+
+const HINDI: Vocabulary = Vocabulary {
+    scales: ["करोड़", "लाख", "हज़ार", "सौ"],
+    months: [
+        "जनवरी",
+        "फ़रवरी",
+        "मार्च",
+        "अप्रैल",
+        "मई",
+        "जून",
+        "जुलाई",
+        "अगस्त",
+        "सितंबर",
+        "अक्टूबर",
+        "नवंबर",
+        "दिसंबर",
+    ],
+    point: "दशमलव",
+    percent: "प्रतिशत",
+    rupee: "रुपया",
+    rupees: "रुपये",
+    paise: "पैसे",
+    and: "और",
+    plus: "प्लस",
+    gram: "ग्राम",
+    grams: "ग्राम",
+    karat: "कैरेट",
+};
+
+/// Language numbers are spoken in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpokenLanguage {
+    /// English words with Indian grouping ("five lakh rupees")
+    English,
+    /// Devanagari Hindi words ("पाँच लाख रुपये")
+    Hindi,
+}
+
+impl SpokenLanguage {
+    /// Hindi for text containing Devanagari, English otherwise
+    pub fn detect(text: &str) -> Self {
+        if text.chars().any(|c| ('\u{0900}'..='\u{097F}').contains(&c)) {
+            Self::Hindi
+        } else {
+            Self::English
+        }
+    }
+
+    fn vocabulary(self) -> &'static Vocabulary {
+        match self {
+            Self::English => &ENGLISH,
+            Self::Hindi => &HINDI,
+        }
+    }
+
+    /// Number in words using Indian grouping (thousand, lakh, crore)
+    pub fn number(self, n: u64) -> String {
+        if n == 0 {
+            return self.below_hundred(0);
+        }
+
+        let scales = self.vocabulary().scales;
+        let mut parts = Vec::new();
+        let crore = n / 10_000_000;
+        if crore > 0 {
+            parts.push(format!("{} {}", self.number(crore), scales[0]));
+        }
+        for (value, scale) in [
+            ((n / 100_000) % 100, scales[1]),
+            ((n / 1000) % 100, scales[2]),
+            ((n / 100) % 10, scales[3]),
+        ] {
+            if value > 0 {
+                parts.push(format!("{} {}", self.below_hundred(value), scale));
+            }
+        }
+        if n % 100 > 0 {
+            parts.push(self.below_hundred(n % 100));
+        }
+
+        parts.join(" ")
+    }
+
+    fn below_hundred(self, n: u64) -> String {
+        let n = n as usize;
+        match self {
+            Self::Hindi => HI_BELOW_HUNDRED[n].to_string(),
+            Self::English => match (n / 10, n % 10) {
+                (0 | 1, _) => EN_ONES[n].to_string(),
+                (tens, 0) => EN_TENS[tens].to_string(),
+                (tens, ones) => format!("{} {}", EN_TENS[tens], EN_ONES[ones]),
+            },
+        }
+    }
+
+    /// "5,00,000" or "9.5" in words; long digit strings digit by digit
+    ///
+    /// `None` if the text is not a well-formed number.
+    pub fn decimal(self, text: &str) -> Option<String> {
+        let (whole, fraction) = text.split_once('.').unwrap_or((text, ""));
+        if !fraction.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        if !whole.contains(',')
+            && fraction.is_empty()
+            && (whole.len() >= DIGIT_BY_DIGIT_LEN || (whole.len() > 1 && whole.starts_with('0')))
+        {
+            return self.digits(whole);
+        }
+
+        let mut words = self.number(parse_grouped(whole)?);
+        if !fraction.is_empty() {
+            words.push(' ');
+            words.push_str(self.vocabulary().point);
+            words.push(' ');
+            words.push_str(&self.digits(fraction)?);
+        }
+        Some(words)
+    }
+
+    /// Digit by digit, keeping the text's grouping as short pauses
+    ///
+    /// "+91 98765 43210" -> "plus nine one, nine eight seven six five, four three two one zero"
+    pub fn digits(self, text: &str) -> Option<String> {
+        let mut groups: Vec<Vec<String>> = vec![Vec::new()];
+
+        for c in text.trim().chars() {
+            match c {
+                '0'..='9' => groups
+                    .last_mut()?
+                    .push(self.below_hundred(c as u64 - '0' as u64)),
+                '+' => groups.last_mut()?.push(self.vocabulary().plus.to_string()),
+                ' ' | '-' | '.' | '(' | ')' => {
+                    if !groups.last()?.is_empty() {
+                        groups.push(Vec::new());
+                    }
+                },
+                _ => return None,
+            }
+        }
+
+        let spoken: Vec<String> = groups
+            .into_iter()
+            .filter(|group| !group.is_empty())
+            .map(|group| group.join(" "))
+            .collect();
+        (!spoken.is_empty()).then(|| spoken.join(", "))
+    }
+
+    /// "₹5,00,000" -> "five lakh rupees", "Rs 2.5 lakh" -> "two point five lakh rupees"
+    pub fn currency(self, text: &str) -> Option<String> {
+        let mut amount = text.trim();
+        for prefix in ["₹", "INR", "Rs.", "Rs", "rs.", "rs"] {
+            if let Some(rest) = amount.strip_prefix(prefix) {
+                amount = rest.trim_start();
+                break;
+            }
+        }
+        let amount = amount.trim_end_matches("/-").trim();
+
+        let (number, scale) = match amount.rsplit_once(char::is_whitespace) {
+            Some((number, scale)) => (number.trim(), Some(scale)),
+            None => (amount, None),
+        };
+        self.amount(number, scale)
+    }
+
+    /// Amount plus optional scale word ("lakh", "crore", "k") in rupees
+    fn amount(self, number: &str, scale: Option<&str>) -> Option<String> {
+        let vocabulary = self.vocabulary();
+
+        if let Some(scale) = scale {
+            let scale = match scale.to_ascii_lowercase().trim_end_matches('s') {
+                "crore" => vocabulary.scales[0],
+                "lakh" => vocabulary.scales[1],
+                "thousand" | "k" => vocabulary.scales[2],
+                _ => return None,
+            };
+            return Some(format!(
+                "{} {} {}",
+                self.decimal(number)?,
+                scale,
+                vocabulary.rupees
+            ));
+        }
+
+        let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+        let whole = parse_grouped(whole)?;
+        let paise: u64 = match fraction.len() {
+            0 => 0,
+            1 => fraction.parse::<u64>().ok()? * 10,
+            2 => fraction.parse().ok()?,
+            _ => return None,
+        };
+
+        let unit = if whole == 1 {
+            vocabulary.rupee
+        } else {
+            vocabulary.rupees
+        };
+        let mut words = format!("{} {}", self.number(whole), unit);
+        if paise > 0 {
+            words.push_str(&format!(
+                " {} {} {}",
+                vocabulary.and,
+                self.number(paise),
+                vocabulary.paise
+            ));
+        }
+        Some(words)
+    }
+
+    /// Spoken date; `None` for an impossible day or month
+    pub fn date(self, day: u32, month: u32, year: u32) -> Option<String> {
+        if !(1..=31).contains(&day) || !(1..=12).contains(&month) {
+            return None;
+        }
+        let year = if year < 100 { 2000 + year } else { year };
+        let month = self.vocabulary().months[month as usize - 1];
+
+        Some(match self {
+            // "पाँच जनवरी दो हज़ार पच्चीस"
+            Self::Hindi => format!(
+                "{} {} {}",
+                self.number(day as u64),
+                month,
+                self.number(year as u64)
+            ),
+            // "fifth January twenty twenty five"
+            Self::English => format!(
+                "{} {} {}",
+                ordinal(&self.number(day as u64)),
+                month,
+                english_year(year as u64)
+            ),
+        })
+    }
+}
+
+/// English years are read in pairs ("twenty twenty five", "nineteen oh five")
+fn english_year(year: u64) -> String {
+    let en = SpokenLanguage::English;
+    match (year / 100, year % 100) {
+        (20, 0..=9) => en.number(year),
+        (century, 0) => format!("{} hundred", en.below_hundred(century)),
+        (century, rest) if rest < 10 => format!(
+            "{} oh {}",
+            en.below_hundred(century),
+            en.below_hundred(rest)
+        ),
+        (century, rest) => format!("{} {}", en.below_hundred(century), en.below_hundred(rest)),
+    }
+}
+
+/// English ordinal from cardinal words ("twenty one" -> "twenty first")
+fn ordinal(words: &str) -> String {
+    let (head, last) = words.rsplit_once(' ').unwrap_or(("", words));
+    let last = match last {
+        "one" => "first".to_string(),
+        "two" => "second".to_string(),
+        "three" => "third".to_string(),
+        "five" => "fifth".to_string(),
+        "eight" => "eighth".to_string(),
+        "nine" => "ninth".to_string(),
+        "twelve" => "twelfth".to_string(),
+        word if word.ends_with('y') => format!("{}ieth", &word[..word.len() - 1]),
+        word => format!("{}th", word),
+    };
+
+    if head.is_empty() {
+        last
+    } else {
+        format!("{} {}", head, last)
+    }
+}
+
+/// Parse "500000", "5,00,000" (Indian) or "500,000" (international)
+///
+/// Commas anywhere else mean this is not one number (e.g. a list "1,2,3").
+fn parse_grouped(text: &str) -> Option<u64> {
+    if text.contains(',') {
+        let groups: Vec<&str> = text.split(',').collect();
+        let (first, rest) = groups.split_first()?;
+        let (last, middle) = rest.split_last()?;
+        let middle_len = middle.first().map_or(last.len(), |group| group.len());
+        let well_formed = (1..=3).contains(&first.len())
+            && last.len() == 3
+            && (middle_len == 2 || middle_len == 3)
+            && middle.iter().all(|group| group.len() == middle_len);
+        if !well_formed {
+            return None;
+        }
+    }
+    text.replace(',', "").parse().ok()
+}
+
+/// Devanagari digits (०-९) to ASCII so one set of patterns handles both
+fn ascii_digits(text: &str) -> Cow<'_, str> {
+    if !text.chars().any(is_devanagari_digit) {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(
+        text.chars()
+            .map(|c| {
+                if is_devanagari_digit(c) {
+                    char::from(b'0' + (c as u32 - 0x0966) as u8)
+                } else {
+                    c
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Length of streamed text that can be normalized without cutting a number
+///
+/// Holds back a trailing partial word and the run of words with digits
+/// before it, since "Call 98765 " may continue with "43210".
+pub(super) fn stable_prefix_len(text: &str) -> usize {
+    let mut words = Vec::new();
+    let mut offset = 0;
+    for piece in text.split_inclusive(char::is_whitespace) {
+        let word = piece.trim_end();
+        if !word.is_empty() {
+            words.push((offset, word, word.len() < piece.len()));
+        }
+        offset += piece.len();
+    }
+
+    let mut stable = text.len();
+    for (start, word, complete) in words.into_iter().rev() {
+        let numeric = word
+            .chars()
+            .any(|c| c.is_ascii_digit() || is_devanagari_digit(c))
+            || matches!(word, "₹" | "Rs" | "Rs." | "INR");
+        if complete && !numeric {
+            break;
+        }
+        stable = start;
+    }
+    stable
+}
+
+fn is_devanagari_digit(c: char) -> bool {
+    ('\u{0966}'..='\u{096F}').contains(&c)
+}
+
+/// Replace matches with their spoken form, leaving unparseable ones as is
+fn replace(pattern: &Regex, text: &str, render: impl Fn(&Captures) -> Option<String>) -> String {
+    pattern
+        .replace_all(text, |caps: &Captures| {
+            render(caps).unwrap_or_else(|| caps[0].to_string())
+        })
+        .into_owned()
+}
+
+fn number_at(caps: &Captures, group: usize) -> u32 {
+    caps[group].parse().unwrap_or(0)
+}
+
+/// TTS text normalizer
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TextNormalizer {
+    /// Fixed spoken language; `None` follows the script of each text
+    language: Option<SpokenLanguage>,
+}
+
+impl TextNormalizer {
+    /// Create a normalizer; `None` picks the language from the text's script
+    pub fn new(language: Option<SpokenLanguage>) -> Self {
+        Self { language }
+    }
+
+    /// Expand numbers, amounts, dates, percentages and phone numbers
+    pub fn normalize(&self, text: &str) -> String {
+        let text = ascii_digits(text);
+        if !text.bytes().any(|b| b.is_ascii_digit()) {
+            return text.into_owned();
+        }
+        let spoken = self
+            .language
+            .unwrap_or_else(|| SpokenLanguage::detect(&text));
+        let vocabulary = spoken.vocabulary();
+
+        let text = replace(&DATE_ISO, &text, |caps| {
+            spoken.date(number_at(caps, 3), number_at(caps, 2), number_at(caps, 1))
+        });
+        let text = replace(&DATE_DMY, &text, |caps| {
+            spoken.date(number_at(caps, 1), number_at(caps, 2), number_at(caps, 3))
+        });
+        let text = replace(&TOLL_FREE, &text, |caps| {
+            let groups = [&caps[1], &caps[2], &caps[3]];
+            let parts: Option<Vec<String>> = groups.iter().map(|g| spoken.digits(g)).collect();
+            Some(parts?.join(", "))
+        });
+        let text = replace(&MOBILE, &text, |caps| {
+            let groups = [caps[1].trim_end_matches([' ', '-']), &caps[2], &caps[3]];
+            let parts: Option<Vec<String>> = groups
+                .iter()
+                .filter(|g| !g.is_empty())
+                .map(|g| spoken.digits(g))
+                .collect();
+            Some(parts?.join(", "))
+        });
+        let text = replace(&CURRENCY, &text, |caps| {
+            spoken.amount(&caps[1], caps.get(2).map(|m| m.as_str()))
+        });
+        let text = replace(&PERCENT, &text, |caps| {
+            Some(format!(
+                "{} {}",
+                spoken.decimal(&caps[1])?,
+                vocabulary.percent
+            ))
+        });
+        let text = replace(&GRAMS, &text, |caps| {
+            let unit = if &caps[1] == "1" {
+                vocabulary.gram
+            } else {
+                vocabulary.grams
+            };
+            Some(format!("{} {}", spoken.decimal(&caps[1])?, unit))
+        });
+        let text = replace(&KARAT, &text, |caps| {
+            Some(format!(
+                "{} {}",
+                spoken.decimal(&caps[1])?,
+                vocabulary.karat
+            ))
+        });
+        replace(&NUMBER, &text, |caps| spoken.decimal(&caps[0]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn english(text: &str) -> String {
+        TextNormalizer::new(Some(SpokenLanguage::English)).normalize(text)
+    }
+
+    #[test]
+    fn test_numbers() {
+        let en = SpokenLanguage::English;
+        assert_eq!(en.number(0), "zero");
+        assert_eq!(
+            en.number(123_456_789),
+            "twelve crore thirty four lakh fifty six thousand seven hundred eighty nine"
+        );
+        assert_eq!(en.decimal("5,00,000").unwrap(), "five lakh");
+        assert_eq!(en.decimal("500,000").unwrap(), "five lakh");
+        assert_eq!(en.decimal("9.75").unwrap(), "nine point seven five");
+        assert!(en.decimal("1,2,3").is_none());
+
+        let hi = SpokenLanguage::Hindi;
+        assert_eq!(hi.number(250_000), "दो लाख पचास हज़ार");
+        assert_eq!(hi.number(10_500_000), "एक करोड़ पाँच लाख");
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            english("Loan of ₹5,00,000 approved"),
+            "Loan of five lakh rupees approved"
+        );
+        assert_eq!(
+            english("Pay Rs. 1,250.50 now"),
+            "Pay one thousand two hundred fifty rupees and fifty paise now"
+        );
+        assert_eq!(english("INR 2.5 lakh"), "two point five lakh rupees");
+        assert_eq!(english("₹1/-"), "one rupee");
+        assert_eq!(
+            TextNormalizer::default().normalize("आपको ₹5,00,000 मिलेंगे"),
+            "आपको पाँच लाख रुपये मिलेंगे"
+        );
+    }
+
+    #[test]
+    fn test_dates_percent_and_units() {
+        assert_eq!(
+            english("Due on 05/01/2025"),
+            "Due on fifth January twenty twenty five"
+        );
+        assert_eq!(
+            english("2024-03-21"),
+            "twenty first March twenty twenty four"
+        );
+        assert_eq!(
+            english("Due 31/13/2025"),
+            "Due thirty one/thirteen/two thousand twenty five"
+        );
+        assert_eq!(english("rate 9.5%"), "rate nine point five percent");
+        assert_eq!(
+            english("50g of 22K gold"),
+            "fifty grams of twenty two karat gold"
+        );
+        assert_eq!(
+            TextNormalizer::default().normalize("ब्याज दर ९.५% है, तारीख 5/1/25"),
+            "ब्याज दर नौ दशमलव पाँच प्रतिशत है, तारीख पाँच जनवरी दो हज़ार पच्चीस"
+        );
+    }
+
+    #[test]
+    fn test_phone_numbers() {
+        assert_eq!(
+            english("Call +91 98765 43210"),
+            "Call plus nine one, nine eight seven six five, four three two one zero"
+        );
+        assert_eq!(
+            english("or 1800-123-4567."),
+            "or one eight zero zero, one two three, four five six seven."
+        );
+        // Long digit strings that are not phone numbers are still read digit by digit
+        assert_eq!(
+            english("account 12345678901"),
+            "account one two three four five six seven eight nine zero one"
+        );
+        // Alphanumeric codes are left to the engine
+        assert_eq!(english("ref GL12345"), "ref GL12345");
+    }
+
+    #[test]
+    fn test_stable_prefix_len() {
+        assert_eq!(stable_prefix_len("Hello there "), 12);
+        assert_eq!(stable_prefix_len("Hello the"), 6);
+        assert_eq!(stable_prefix_len("Call 98765 "), 5);
+        assert_eq!(stable_prefix_len("Loan of ₹ 5,00,"), 8);
+        assert_eq!(stable_prefix_len("Call 98765 43210 now "), 21);
+    }
+}
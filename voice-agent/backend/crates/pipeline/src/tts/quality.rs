@@ -0,0 +1,151 @@
+//! Synthesis Quality Comparison
+//!
+//! Objective distance between a reference rendering (e.g. F32 weights) and
+//! a candidate (e.g. Int8 weights) of the same text with the same seed.
+//! Waveform SNR is strict: a small phase drift lowers it even when the two
+//! sound alike. Log-spectral distance ignores phase and tracks audible
+//! differences better; under ~2 dB is generally hard to hear.
+
+use realfft::RealFftPlanner;
+
+/// STFT frame size for the spectral distance
+const FRAME_SIZE: usize = 1024;
+/// STFT hop size
+const HOP_SIZE: usize = 256;
+/// Floor added to power spectra before taking logs
+const POWER_FLOOR: f32 = 1e-10;
+
+/// How far a candidate rendering is from the reference
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AudioDistortion {
+    /// Waveform signal-to-noise ratio over the overlapping samples (dB,
+    /// infinite when identical)
+    pub snr_db: f32,
+    /// Mean log-spectral distance per STFT frame (dB, 0 when identical)
+    pub log_spectral_distance_db: f32,
+    /// Candidate length over reference length
+    pub length_ratio: f32,
+}
+
+/// Compare `candidate` against `reference`
+pub fn compare_audio(reference: &[f32], candidate: &[f32]) -> AudioDistortion {
+    let len = reference.len().min(candidate.len());
+    let (reference_trimmed, candidate_trimmed) = (&reference[..len], &candidate[..len]);
+
+    let signal: f64 = reference_trimmed.iter().map(|&x| (x as f64).powi(2)).sum();
+    let noise: f64 = reference_trimmed
+        .iter()
+        .zip(candidate_trimmed)
+        .map(|(&r, &c)| (r as f64 - c as f64).powi(2))
+        .sum();
+    let snr_db = if noise == 0.0 {
+        f32::INFINITY
+    } else {
+        (10.0 * (signal.max(f64::MIN_POSITIVE) / noise).log10()) as f32
+    };
+
+    let length_ratio = if reference.is_empty() {
+        0.0
+    } else {
+        candidate.len() as f32 / reference.len() as f32
+    };
+
+    AudioDistortion {
+        snr_db,
+        log_spectral_distance_db: log_spectral_distance(reference_trimmed, candidate_trimmed),
+        length_ratio,
+    }
+}
+
+/// Mean over frames of the RMS difference between log power spectra (dB)
+fn log_spectral_distance(reference: &[f32], candidate: &[f32]) -> f32 {
+    let reference = power_spectra(reference);
+    let candidate = power_spectra(candidate);
+    if reference.is_empty() {
+        return 0.0;
+    }
+
+    let total: f32 = reference
+        .iter()
+        .zip(&candidate)
+        .map(|(r, c)| {
+            let squared: f32 = r
+                .iter()
+                .zip(c)
+                .map(|(&pr, &pc)| {
+                    let diff =
+                        10.0 * (pr + POWER_FLOOR).log10() - 10.0 * (pc + POWER_FLOOR).log10();
+                    diff * diff
+                })
+                .sum();
+            (squared / r.len() as f32).sqrt()
+        })
+        .sum();
+    total / reference.len() as f32
+}
+
+/// Hann-windowed power spectrum of each full frame
+fn power_spectra(samples: &[f32]) -> Vec<Vec<f32>> {
+    if samples.len() < FRAME_SIZE {
+        return Vec::new();
+    }
+
+    let fft = RealFftPlanner::<f32>::new().plan_fft_forward(FRAME_SIZE);
+    let window: Vec<f32> = (0..FRAME_SIZE)
+        .map(|i| 0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / FRAME_SIZE as f32).cos())
+        .collect();
+    let mut input = fft.make_input_vec();
+    let mut output = fft.make_output_vec();
+
+    (0..=(samples.len() - FRAME_SIZE) / HOP_SIZE)
+        .map(|frame| {
+            let start = frame * HOP_SIZE;
+            for (i, slot) in input.iter_mut().enumerate() {
+                *slot = samples[start + i] * window[i];
+            }
+            // Frame and buffer sizes always match the plan
+            let _ = fft.process(&mut input, &mut output);
+            output.iter().map(|bin| bin.norm_sqr()).collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tone(freq: f32, len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| 0.5 * (2.0 * std::f32::consts::PI * freq * i as f32 / 24000.0).sin())
+            .collect()
+    }
+
+    #[test]
+    fn test_identical_audio() {
+        let audio = tone(220.0, 24000);
+        let distortion = compare_audio(&audio, &audio);
+        assert!(distortion.snr_db.is_infinite());
+        assert_eq!(distortion.log_spectral_distance_db, 0.0);
+        assert_eq!(distortion.length_ratio, 1.0);
+    }
+
+    #[test]
+    fn test_distortion_grows_with_noise() {
+        let reference = tone(220.0, 24000);
+        let with_noise = |amplitude: f32| -> Vec<f32> {
+            reference
+                .iter()
+                .enumerate()
+                .map(|(i, &x)| x + amplitude * ((i * 7919 % 101) as f32 / 50.0 - 1.0))
+                .collect()
+        };
+
+        let slight = compare_audio(&reference, &with_noise(0.001));
+        let heavy = compare_audio(&reference, &with_noise(0.05));
+        assert!(slight.snr_db > heavy.snr_db);
+        assert!(slight.log_spectral_distance_db < heavy.log_spectral_distance_db);
+
+        let truncated = compare_audio(&reference, &reference[..12000]);
+        assert_eq!(truncated.length_ratio, 0.5);
+    }
+}
@@ -8,10 +8,13 @@
 //! - Use `StreamingTts::with_backend()` for production with real TTS
 //! - Use `StreamingTts::simple()` for testing with silence output
 
-use parking_lot::Mutex;
+use parking_lot::{Mutex, RwLock};
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
 use tokio::sync::mpsc;
+use voice_agent_config::ModelPrecision;
+use voice_agent_core::Samples;
 
 #[cfg(feature = "onnx")]
 use ndarray::Array2;
@@ -20,10 +23,18 @@ use ort::session::{builder::GraphOptimizationLevel, Session};
 #[cfg(feature = "onnx")]
 use ort::value::Tensor;
 
+use super::cache::{SynthesisCache, SynthesisKey};
 use super::chunker::{ChunkStrategy, ChunkerConfig, TextChunk, WordChunker};
+use super::lexicon::PronunciationLexicon;
+use super::markup::{self, MarkupSegment, ProsodySupport, SpeechPart};
+use super::normalize::{self, TextNormalizer};
+use super::stretch;
 use super::{create_tts_backend, TtsBackend};
 use crate::PipelineError;
 
+/// Voice language assumed until one is set
+const DEFAULT_LANGUAGE: &str = "hi";
+
 /// TTS engine selection
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TtsEngine {
@@ -44,18 +55,24 @@ pub struct TtsConfig {
     pub sample_rate: u32,
     /// Voice/speaker ID
     pub voice_id: Option<String>,
-    /// Speaking rate (1.0 = normal)
+    /// Speaking rate (1.0 = normal, 0.8 = 25% longer)
     pub speaking_rate: f32,
-    /// Pitch adjustment (1.0 = normal)
+    /// Pitch factor (1.0 = normal, 2.0 = an octave up)
     pub pitch: f32,
+    /// Silence between consecutive sentences of a response (ms)
+    pub sentence_pause_ms: u32,
     /// Chunking strategy
     pub chunk_strategy: ChunkStrategy,
-    /// Enable prosody hints
+    /// Enable prosody hints (pause/emphasis/say-as markup, see `markup`)
     pub prosody_hints: bool,
+    /// Expand numbers, ₹ amounts, dates and phone numbers into words
+    pub normalize_text: bool,
     /// P0-1 FIX: Path to the TTS model (required for IndicF5, Piper, etc.)
     pub model_path: Option<std::path::PathBuf>,
     /// P0-1 FIX: Path to reference audio for voice cloning (IndicF5)
     pub reference_audio_path: Option<std::path::PathBuf>,
+    /// Weight precision for candle models (IndicF5)
+    pub quantization: ModelPrecision,
 }
 
 impl Default for TtsConfig {
@@ -66,10 +83,13 @@ impl Default for TtsConfig {
             voice_id: None,
             speaking_rate: 1.0,
             pitch: 1.0,
+            sentence_pause_ms: 0,
             chunk_strategy: ChunkStrategy::Adaptive,
             prosody_hints: true,
+            normalize_text: true,
             model_path: None,
             reference_audio_path: None,
+            quantization: ModelPrecision::default(),
         }
     }
 }
@@ -98,6 +118,68 @@ impl TtsConfig {
             ..Default::default()
         }
     }
+
+    /// Speaking rate, pitch and sentence pause from this config
+    pub fn speech_style(&self) -> SpeechStyle {
+        SpeechStyle {
+            rate: self.speaking_rate,
+            pitch: self.pitch,
+            sentence_pause_ms: self.sentence_pause_ms,
+        }
+    }
+}
+
+/// How a session's speech is paced and pitched
+///
+/// Engines conditioned on rate or pitch (see `ProsodySupport`) receive it
+/// through `TtsBackend::synthesize_styled`; other engines' audio is
+/// time-stretched and pitch-shifted after synthesis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpeechStyle {
+    /// Speaking rate (1.0 = normal)
+    pub rate: f32,
+    /// Pitch factor (1.0 = normal)
+    pub pitch: f32,
+    /// Silence between consecutive sentences (ms)
+    pub sentence_pause_ms: u32,
+}
+
+impl Default for SpeechStyle {
+    fn default() -> Self {
+        Self {
+            rate: 1.0,
+            pitch: 1.0,
+            sentence_pause_ms: 0,
+        }
+    }
+}
+
+impl SpeechStyle {
+    /// Slowest and fastest rate that stays natural
+    pub const RATE_RANGE: (f32, f32) = (0.5, 2.0);
+    /// Lowest and highest pitch factor (an octave either way)
+    pub const PITCH_RANGE: (f32, f32) = (0.5, 2.0);
+    /// Longest pause between sentences (ms)
+    pub const MAX_SENTENCE_PAUSE_MS: u32 = 2000;
+
+    /// Pitch factor for a shift in semitones
+    pub fn pitch_from_semitones(semitones: f32) -> f32 {
+        2f32.powf(semitones / 12.0)
+    }
+
+    /// Limit values to the supported ranges
+    pub fn clamped(self) -> Self {
+        Self {
+            rate: self.rate.clamp(Self::RATE_RANGE.0, Self::RATE_RANGE.1),
+            pitch: self.pitch.clamp(Self::PITCH_RANGE.0, Self::PITCH_RANGE.1),
+            sentence_pause_ms: self.sentence_pause_ms.min(Self::MAX_SENTENCE_PAUSE_MS),
+        }
+    }
+
+    /// Rate and pitch leave the audio unchanged
+    pub fn is_neutral(&self) -> bool {
+        self.rate == 1.0 && self.pitch == 1.0
+    }
 }
 
 /// TTS event for streaming output
@@ -106,7 +188,7 @@ pub enum TtsEvent {
     /// Audio chunk ready
     Audio {
         /// Audio samples
-        samples: Arc<[f32]>,
+        samples: Samples,
         /// Text that was synthesized
         text: String,
         /// Word indices
@@ -132,16 +214,30 @@ pub struct StreamingTts {
     /// ONNX session (None for simple/testing mode) - legacy, prefer backend
     #[cfg(feature = "onnx")]
     session: Option<Mutex<Session>>,
-    /// P0-1 FIX: TTS backend for actual synthesis
-    backend: Option<Arc<dyn TtsBackend>>,
+    /// P0-1 FIX: TTS backend for actual synthesis (swapped on voice change)
+    backend: RwLock<Option<Arc<dyn TtsBackend>>>,
+    /// Active voice ID
+    voice_id: Mutex<Option<String>>,
+    /// Output sample rate of the active backend
+    sample_rate: AtomicU32,
     config: TtsConfig,
     chunker: Mutex<WordChunker>,
+    /// Streamed text held back until its markup tags and last word are complete
+    pending_text: Mutex<String>,
     /// Is currently synthesizing?
     synthesizing: Mutex<bool>,
     /// Barge-in requested?
     barge_in: Mutex<bool>,
     /// Current word index
     current_word: Mutex<usize>,
+    /// Shared cache of synthesized utterances
+    cache: Option<Arc<SynthesisCache>>,
+    /// Pronunciation overrides; respellings replace words before synthesis
+    lexicon: Option<Arc<PronunciationLexicon>>,
+    /// Language code of the active voice, selects lexicon respellings
+    language: Mutex<String>,
+    /// Speaking rate, pitch and sentence pause for this session
+    style: Mutex<SpeechStyle>,
 }
 
 impl StreamingTts {
@@ -164,12 +260,19 @@ impl StreamingTts {
 
         Ok(Self {
             session: Some(Mutex::new(session)),
-            backend: None,
+            backend: RwLock::new(None),
+            voice_id: Mutex::new(config.voice_id.clone()),
+            style: Mutex::new(config.speech_style().clamped()),
+            sample_rate: AtomicU32::new(config.sample_rate),
             config,
             chunker: Mutex::new(WordChunker::new(chunker_config)),
+            pending_text: Mutex::new(String::new()),
             synthesizing: Mutex::new(false),
             barge_in: Mutex::new(false),
             current_word: Mutex::new(0),
+            cache: None,
+            lexicon: None,
+            language: Mutex::new(DEFAULT_LANGUAGE.to_string()),
         })
     }
 
@@ -196,12 +299,19 @@ impl StreamingTts {
         Self {
             #[cfg(feature = "onnx")]
             session: None,
-            backend: Some(backend),
+            backend: RwLock::new(Some(backend)),
+            voice_id: Mutex::new(config.voice_id.clone()),
+            style: Mutex::new(config.speech_style().clamped()),
+            sample_rate: AtomicU32::new(sample_rate),
             config,
             chunker: Mutex::new(WordChunker::new(chunker_config)),
+            pending_text: Mutex::new(String::new()),
             synthesizing: Mutex::new(false),
             barge_in: Mutex::new(false),
             current_word: Mutex::new(0),
+            cache: None,
+            lexicon: None,
+            language: Mutex::new(DEFAULT_LANGUAGE.to_string()),
         }
     }
 
@@ -216,8 +326,12 @@ impl StreamingTts {
             None
         };
 
-        let backend =
-            create_tts_backend(config.engine, config.model_path.as_deref(), reference_audio)?;
+        let backend = create_tts_backend(
+            config.engine,
+            config.model_path.as_deref(),
+            reference_audio,
+            config.quantization,
+        )?;
 
         Ok(Self::with_backend(backend, config))
     }
@@ -232,20 +346,40 @@ impl StreamingTts {
         Self {
             #[cfg(feature = "onnx")]
             session: None, // No model - will use stub synthesis
-            backend: None,
+            backend: RwLock::new(None),
+            voice_id: Mutex::new(config.voice_id.clone()),
+            style: Mutex::new(config.speech_style().clamped()),
+            sample_rate: AtomicU32::new(config.sample_rate),
             config,
             chunker: Mutex::new(WordChunker::new(chunker_config)),
+            pending_text: Mutex::new(String::new()),
             synthesizing: Mutex::new(false),
             barge_in: Mutex::new(false),
             current_word: Mutex::new(0),
+            cache: None,
+            lexicon: None,
+            language: Mutex::new(DEFAULT_LANGUAGE.to_string()),
         }
     }
 
+    /// Serve repeated utterances from a synthesis cache
+    pub fn with_cache(mut self, cache: Arc<SynthesisCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Apply pronunciation overrides from a lexicon
+    pub fn with_lexicon(mut self, lexicon: Arc<PronunciationLexicon>) -> Self {
+        self.lexicon = Some(lexicon);
+        self
+    }
+
     /// Start streaming synthesis
     pub fn start(&self, text: &str, tx: mpsc::Sender<TtsEvent>) {
+        self.pending_text.lock().clear();
         let mut chunker = self.chunker.lock();
         chunker.reset();
-        chunker.add_text(text);
+        chunker.add_text(&self.prepare_text(text));
         chunker.finalize();
 
         *self.synthesizing.lock() = true;
@@ -283,8 +417,8 @@ impl StreamingTts {
                 }
 
                 Ok(Some(TtsEvent::Audio {
-                    samples: audio.into(),
-                    text: text_chunk.text,
+                    samples: audio,
+                    text: markup::strip_silences(&text_chunk.text),
                     word_indices: text_chunk.word_indices,
                     is_final: text_chunk.is_final,
                 }))
@@ -296,24 +430,52 @@ impl StreamingTts {
         }
     }
 
+    /// Synthesize a whole sentence into `TtsEvent::Audio` chunks
+    ///
+    /// Uses its own chunker and leaves the streaming state alone, so several
+    /// sentences can be synthesized at once. Stops between chunks once
+    /// `cancelled` is set. Blocks on the backend; call it from a blocking
+    /// task.
+    pub fn synthesize_sentence(
+        &self,
+        text: &str,
+        cancelled: &AtomicBool,
+    ) -> Result<Vec<TtsEvent>, PipelineError> {
+        let mut chunker = WordChunker::new(ChunkerConfig {
+            strategy: self.config.chunk_strategy,
+            ..Default::default()
+        });
+        chunker.add_text(&self.prepare_text(text));
+        chunker.finalize();
+
+        let mut events = Vec::new();
+        while let Some(chunk) = chunker.next_chunk() {
+            if cancelled.load(Ordering::Relaxed) {
+                break;
+            }
+            events.push(TtsEvent::Audio {
+                samples: self.synthesize_chunk(&chunk)?,
+                text: markup::strip_silences(&chunk.text),
+                word_indices: chunk.word_indices,
+                is_final: chunk.is_final,
+            });
+        }
+        Ok(events)
+    }
+
     /// Synthesize a single chunk
     ///
     /// P0-1 FIX: Now routes to the configured backend if available
     #[cfg(feature = "onnx")]
-    fn synthesize_chunk(&self, chunk: &TextChunk) -> Result<Vec<f32>, PipelineError> {
-        // P0-1 FIX: Use backend if available (preferred path)
-        if let Some(ref backend) = self.backend {
-            // Backend synthesis is async, but we're in a sync context
-            // Use block_in_place to safely run async code from within tokio runtime
-            let text = chunk.text.clone();
-            let backend = backend.clone();
-
-            // block_in_place allows blocking in async context by moving thread to blocking pool
-            let audio = tokio::task::block_in_place(|| {
-                tokio::runtime::Handle::current().block_on(backend.synthesize(&text))
-            })?;
+    fn synthesize_chunk(&self, chunk: &TextChunk) -> Result<Samples, PipelineError> {
+        if markup::has_silence(&chunk.text) {
+            return self.synthesize_with_silences(chunk);
+        }
 
-            return Ok(audio);
+        // P0-1 FIX: Use backend if available (preferred path)
+        let backend = self.backend.read().clone();
+        if let Some(backend) = backend {
+            return self.synthesize_cached(backend.as_ref(), &chunk.text);
         }
 
         // Legacy ONNX path: If no backend but ONNX session exists, use it
@@ -321,8 +483,8 @@ impl StreamingTts {
             Some(s) => s,
             None => {
                 // Return silence of appropriate length (sample_rate samples per second)
-                let duration_samples = chunk.text.len() * (self.config.sample_rate as usize / 20); // ~50ms per char
-                return Ok(vec![0.0f32; duration_samples]);
+                let duration_samples = chunk.text.len() * (self.sample_rate() as usize / 20); // ~50ms per char
+                return Ok(vec![0.0f32; duration_samples].into());
             },
         };
 
@@ -334,7 +496,9 @@ impl StreamingTts {
         let input_lengths = Array2::from_shape_vec((1, 1), vec![chunk.text.len() as i64])
             .map_err(|e| PipelineError::Tts(e.to_string()))?;
 
-        let scales = Array2::from_shape_vec((1, 3), vec![0.667, self.config.speaking_rate, 0.8])
+        // Length scale is the inverse of the speaking rate
+        let style = self.style();
+        let scales = Array2::from_shape_vec((1, 3), vec![0.667, 1.0 / style.rate, 0.8])
             .map_err(|e| PipelineError::Tts(e.to_string()))?;
 
         let mut session = session_mutex.lock();
@@ -350,30 +514,168 @@ impl StreamingTts {
             .try_extract_array::<f32>()
             .map_err(|e| PipelineError::Model(e.to_string()))?;
 
-        Ok(audio.iter().copied().collect())
+        let audio: Vec<f32> = audio.iter().copied().collect();
+        Ok(stretch::pitch_shift(&audio, style.pitch, self.sample_rate()).into())
     }
 
     /// Synthesize a single chunk (stub when ONNX disabled)
     ///
     /// P0-1 FIX: Now routes to the configured backend if available
     #[cfg(not(feature = "onnx"))]
-    fn synthesize_chunk(&self, chunk: &TextChunk) -> Result<Vec<f32>, PipelineError> {
-        // P0-1 FIX: Use backend if available
-        if let Some(ref backend) = self.backend {
-            let text = chunk.text.clone();
-            let backend = backend.clone();
-
-            // block_in_place allows blocking in async context by moving thread to blocking pool
-            let audio = tokio::task::block_in_place(|| {
-                tokio::runtime::Handle::current().block_on(backend.synthesize(&text))
-            })?;
+    fn synthesize_chunk(&self, chunk: &TextChunk) -> Result<Samples, PipelineError> {
+        if markup::has_silence(&chunk.text) {
+            return self.synthesize_with_silences(chunk);
+        }
 
-            return Ok(audio);
+        // P0-1 FIX: Use backend if available
+        let backend = self.backend.read().clone();
+        if let Some(backend) = backend {
+            return self.synthesize_cached(backend.as_ref(), &chunk.text);
         }
 
         // Return silence of appropriate length (22050 samples per second)
         let duration_samples = chunk.text.len() * 2000; // ~50ms per char
-        Ok(vec![0.0f32; duration_samples])
+        Ok(vec![0.0f32; duration_samples].into())
+    }
+
+    /// Synthesize with the backend, serving repeats from the cache
+    fn synthesize_cached(
+        &self,
+        backend: &dyn TtsBackend,
+        text: &str,
+    ) -> Result<Samples, PipelineError> {
+        let style = self.style();
+        let key = self.cache.as_ref().map(|_| {
+            let mut voice = self.voice_id().unwrap_or_default();
+            if !style.is_neutral() {
+                voice = format!("{}@{}x{}", voice, style.rate, style.pitch);
+            }
+            SynthesisKey::new(
+                format!("{:?}", self.config.engine),
+                voice,
+                backend.sample_rate(),
+                text,
+            )
+        });
+        if let (Some(cache), Some(key)) = (&self.cache, &key) {
+            if let Some(samples) = cache.get(key) {
+                return Ok(samples);
+            }
+        }
+
+        // Backend synthesis is async, but we're in a sync context
+        // block_in_place allows blocking in async context by moving thread to blocking pool
+        let support = backend.prosody_support();
+        let conditioned = (support.rate || support.pitch) && !style.is_neutral();
+        let audio = tokio::task::block_in_place(|| {
+            let synthesis = async {
+                if conditioned {
+                    backend.synthesize_styled(text, style).await
+                } else {
+                    backend.synthesize(text).await
+                }
+            };
+            tokio::runtime::Handle::current().block_on(synthesis)
+        })?;
+        let audio: Samples = apply_style(audio, style, support, backend.sample_rate()).into();
+
+        if let (Some(cache), Some(key)) = (&self.cache, key) {
+            cache.insert_shared(key, audio.clone());
+        }
+        Ok(audio)
+    }
+
+    /// Synthesize a chunk containing pauses, splicing in silent samples
+    fn synthesize_with_silences(&self, chunk: &TextChunk) -> Result<Samples, PipelineError> {
+        let mut audio = Vec::new();
+        for part in markup::split_silences(&chunk.text) {
+            match part {
+                SpeechPart::Text(text) => {
+                    let part = TextChunk {
+                        text,
+                        ..chunk.clone()
+                    };
+                    audio.extend_from_slice(&self.synthesize_chunk(&part)?);
+                },
+                SpeechPart::Silence(ms) => {
+                    let samples = self.sample_rate() as usize * ms as usize / 1000;
+                    audio.resize(audio.len() + samples, 0.0);
+                },
+            }
+        }
+        Ok(audio.into())
+    }
+
+    /// Prosody the active backend renders natively
+    pub fn prosody_support(&self) -> ProsodySupport {
+        self.backend
+            .read()
+            .as_ref()
+            .map(|backend| backend.prosody_support())
+            .unwrap_or_default()
+    }
+
+    /// Normalize text and lower markup for the active backend
+    fn prepare_text(&self, text: &str) -> String {
+        let normalizer = TextNormalizer::default();
+        let normalize = |text: &str| {
+            let text = if self.config.normalize_text {
+                normalizer.normalize(text)
+            } else {
+                text.to_string()
+            };
+            self.respell(&text)
+        };
+
+        if !self.config.prosody_hints || !markup::has_markup(text) {
+            return normalize(text);
+        }
+
+        // Normalize after parsing so tag attributes (ms="500") stay intact
+        let segments: Vec<MarkupSegment> = markup::parse(text)
+            .into_iter()
+            .map(|segment| match segment {
+                MarkupSegment::Text { text, emphasis } => MarkupSegment::Text {
+                    text: normalize(&text),
+                    emphasis,
+                },
+                pause => pause,
+            })
+            .collect();
+        markup::lower(&segments, self.prosody_support())
+    }
+
+    /// Speaking rate, pitch and sentence pause in use
+    pub fn style(&self) -> SpeechStyle {
+        *self.style.lock()
+    }
+
+    /// Change speaking rate, pitch or sentence pause from the next chunk on
+    ///
+    /// Values outside the supported ranges are clamped.
+    pub fn set_style(&self, style: SpeechStyle) {
+        *self.style.lock() = style.clamped();
+    }
+
+    /// Replace words with their lexicon respelling for the voice's language
+    fn respell(&self, text: &str) -> String {
+        let Some(ref lexicon) = self.lexicon else {
+            return text.to_string();
+        };
+        lexicon.reload_if_changed();
+        lexicon.respell(&self.language.lock(), text)
+    }
+
+    /// Length of the streamed text that can be prepared now
+    fn stream_ready_len(&self, text: &str) -> usize {
+        let mut ready = text.len();
+        if self.config.prosody_hints {
+            ready = markup::complete_prefix_len(text);
+        }
+        if self.config.normalize_text {
+            ready = ready.min(normalize::stable_prefix_len(text));
+        }
+        ready
     }
 
     /// Request barge-in (stop synthesis)
@@ -392,19 +694,36 @@ impl StreamingTts {
     }
 
     /// Add more text (for streaming input)
+    ///
+    /// Markup and words split across calls are held back until complete.
     pub fn add_text(&self, text: &str) {
+        let ready = if self.config.prosody_hints || self.config.normalize_text {
+            let mut pending = self.pending_text.lock();
+            pending.push_str(text);
+            let complete = self.stream_ready_len(&pending);
+            let ready: String = pending.drain(..complete).collect();
+            self.prepare_text(&ready)
+        } else {
+            text.to_string()
+        };
+
         let mut chunker = self.chunker.lock();
-        chunker.add_text(text);
+        chunker.add_text(&ready);
     }
 
     /// Finalize text input
     pub fn finalize_text(&self) {
+        let pending = std::mem::take(&mut *self.pending_text.lock());
+        let ready = self.prepare_text(&pending);
+
         let mut chunker = self.chunker.lock();
+        chunker.add_text(&ready);
         chunker.finalize();
     }
 
     /// Reset TTS state
     pub fn reset(&self) {
+        self.pending_text.lock().clear();
         let mut chunker = self.chunker.lock();
         chunker.reset();
         *self.synthesizing.lock() = false;
@@ -414,7 +733,33 @@ impl StreamingTts {
 
     /// Get sample rate
     pub fn sample_rate(&self) -> u32 {
-        self.config.sample_rate
+        self.sample_rate.load(Ordering::Relaxed)
+    }
+
+    /// Active voice ID, if one was configured or selected
+    pub fn voice_id(&self) -> Option<String> {
+        self.voice_id.lock().clone()
+    }
+
+    /// Language code of the active voice ("hi", "ta", "en")
+    pub fn language(&self) -> String {
+        self.language.lock().clone()
+    }
+
+    /// Set the language the active voice speaks
+    pub fn set_language(&self, language: impl Into<String>) {
+        *self.language.lock() = language.into();
+    }
+
+    /// Switch to another voice
+    ///
+    /// Takes effect from the next synthesized chunk, so callers switch between
+    /// utterances (e.g. when the caller changes language).
+    pub fn switch_voice(&self, voice_id: impl Into<String>, backend: Arc<dyn TtsBackend>) {
+        self.sample_rate
+            .store(backend.sample_rate(), Ordering::Relaxed);
+        *self.backend.write() = Some(backend);
+        *self.voice_id.lock() = Some(voice_id.into());
     }
 }
 
@@ -427,11 +772,15 @@ impl TtsBackend for StreamingTts {
             is_final: true,
             can_pause: true,
         };
-        self.synthesize_chunk(&chunk)
+        self.synthesize_chunk(&chunk).map(Samples::into_vec)
     }
 
     fn sample_rate(&self) -> u32 {
-        self.config.sample_rate
+        StreamingTts::sample_rate(self)
+    }
+
+    fn prosody_support(&self) -> ProsodySupport {
+        StreamingTts::prosody_support(self)
     }
 
     fn supports_streaming(&self) -> bool {
@@ -443,10 +792,29 @@ impl TtsBackend for StreamingTts {
 // P0-1 FIX: Helper functions
 // ============================================================================
 
+/// Stretch and shift audio for the parts of `style` the engine didn't render
+fn apply_style(
+    audio: Vec<f32>,
+    style: SpeechStyle,
+    support: ProsodySupport,
+    sample_rate: u32,
+) -> Vec<f32> {
+    let audio = if support.rate {
+        audio
+    } else {
+        stretch::time_stretch(&audio, style.rate, sample_rate)
+    };
+    if support.pitch {
+        audio
+    } else {
+        stretch::pitch_shift(&audio, style.pitch, sample_rate)
+    }
+}
+
 /// Load reference audio from a WAV file
 ///
 /// Returns the audio samples as f32 normalized to [-1.0, 1.0]
-fn load_reference_audio(path: &std::path::Path) -> Result<Vec<f32>, PipelineError> {
+pub(crate) fn load_reference_audio(path: &std::path::Path) -> Result<Vec<f32>, PipelineError> {
     use hound::WavReader;
 
     let reader = WavReader::open(path)
@@ -519,6 +887,91 @@ mod tests {
         assert!(matches!(event, Some(TtsEvent::BargedIn { .. })));
     }
 
+    #[test]
+    fn test_prosody_markup() {
+        let tts = StreamingTts::simple(TtsConfig {
+            chunk_strategy: ChunkStrategy::SingleWord,
+            ..Default::default()
+        });
+        let (tx, _rx) = mpsc::channel(10);
+
+        tts.start(
+            r#"Loan <say-as type="currency">₹5,00,000</say-as><pause ms="200"/>"#,
+            tx,
+        );
+
+        let mut words = Vec::new();
+        let mut silence = None;
+        while let Some(TtsEvent::Audio { samples, text, .. }) = tts.process_next().unwrap() {
+            if text.is_empty() {
+                silence = Some(samples.len());
+            } else {
+                words.push(text);
+            }
+        }
+        assert_eq!(words, ["Loan", "five", "lakh", "rupees"]);
+        assert_eq!(silence, Some(22050 / 5));
+    }
+
+    #[test]
+    fn test_lexicon_respelling() {
+        use std::collections::HashMap;
+        use voice_agent_config::{LexiconConfig, LexiconEntry};
+
+        let entry = LexiconEntry {
+            phonemes: "koːʈək".to_string(),
+            respell: Some("कोटक".to_string()),
+        };
+        let lexicon = LexiconConfig {
+            languages: HashMap::from([(
+                "hi".to_string(),
+                HashMap::from([("kotak".to_string(), entry)]),
+            )]),
+        };
+        let tts = StreamingTts::simple(TtsConfig {
+            chunk_strategy: ChunkStrategy::SingleWord,
+            ..Default::default()
+        })
+        .with_lexicon(Arc::new(PronunciationLexicon::new(lexicon)));
+
+        let spoken = |tts: &StreamingTts| {
+            let (tx, _rx) = mpsc::channel(10);
+            tts.start("Kotak bank", tx);
+            let mut words = Vec::new();
+            while let Some(TtsEvent::Audio { text, .. }) = tts.process_next().unwrap() {
+                words.push(text);
+            }
+            words
+        };
+        assert_eq!(spoken(&tts), ["कोटक", "bank"]);
+
+        // Respellings follow the voice's language
+        tts.set_language("ta");
+        assert_eq!(spoken(&tts), ["Kotak", "bank"]);
+    }
+
+    #[test]
+    fn test_streamed_markup_held_until_complete() {
+        let tts = StreamingTts::simple(TtsConfig::default());
+        let (tx, _rx) = mpsc::channel(10);
+
+        tts.start("", tx);
+        tts.add_text("Call <say-as type=\"phone\">1800");
+        assert!(!tts.pending_text.lock().is_empty());
+        tts.add_text(" 123</say-as> now ");
+        assert!(tts.pending_text.lock().is_empty());
+        tts.finalize_text();
+
+        let mut spoken = Vec::new();
+        while let Some(TtsEvent::Audio { text, .. }) = tts.process_next().unwrap() {
+            spoken.push(text);
+        }
+        assert_eq!(
+            spoken.join(" "),
+            "Call one eight zero zero, one two three now"
+        );
+    }
+
     #[test]
     fn test_reset() {
         let tts = StreamingTts::simple(TtsConfig::default());
@@ -529,4 +982,121 @@ mod tests {
 
         assert!(!tts.is_synthesizing());
     }
+
+    /// Backend that counts synthesis calls
+    struct CountingBackend(std::sync::atomic::AtomicUsize);
+
+    #[async_trait::async_trait]
+    impl TtsBackend for CountingBackend {
+        async fn synthesize(&self, text: &str) -> Result<Vec<f32>, PipelineError> {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            Ok(vec![0.5; text.len()])
+        }
+
+        fn sample_rate(&self) -> u32 {
+            16000
+        }
+
+        fn supports_streaming(&self) -> bool {
+            false
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_synthesis_cache_hit() {
+        let backend = Arc::new(CountingBackend(Default::default()));
+        let cache = Arc::new(SynthesisCache::new(16, usize::MAX));
+        let tts = StreamingTts::with_backend(backend.clone(), TtsConfig::default())
+            .with_cache(cache.clone());
+
+        let first = TtsBackend::synthesize(&tts, "Namaste, welcome")
+            .await
+            .unwrap();
+        let second = TtsBackend::synthesize(&tts, "Namaste, welcome")
+            .await
+            .unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(backend.0.load(Ordering::Relaxed), 1);
+        assert_eq!(cache.stats.hits.load(Ordering::Relaxed), 1);
+    }
+
+    /// One second of audio; optionally conditioned on speaking rate
+    struct RateBackend {
+        conditioned: bool,
+        styled_calls: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl TtsBackend for RateBackend {
+        async fn synthesize(&self, _text: &str) -> Result<Vec<f32>, PipelineError> {
+            Ok((0..16000).map(|i| (i as f32 * 0.08).sin()).collect())
+        }
+
+        async fn synthesize_styled(
+            &self,
+            text: &str,
+            _style: SpeechStyle,
+        ) -> Result<Vec<f32>, PipelineError> {
+            self.styled_calls.fetch_add(1, Ordering::Relaxed);
+            self.synthesize(text).await
+        }
+
+        fn sample_rate(&self) -> u32 {
+            16000
+        }
+
+        fn supports_streaming(&self) -> bool {
+            false
+        }
+
+        fn prosody_support(&self) -> ProsodySupport {
+            ProsodySupport {
+                rate: self.conditioned,
+                ..Default::default()
+            }
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_speech_style_per_backend() {
+        let slow = SpeechStyle {
+            rate: 0.8,
+            ..Default::default()
+        };
+
+        // Plain engine: audio is time-stretched
+        let plain = Arc::new(RateBackend {
+            conditioned: false,
+            styled_calls: Default::default(),
+        });
+        let tts = StreamingTts::with_backend(plain.clone(), TtsConfig::default());
+        tts.set_style(slow);
+        let audio = TtsBackend::synthesize(&tts, "Dheere boliye").await.unwrap();
+        assert_eq!(audio.len(), 20000);
+        assert_eq!(plain.styled_calls.load(Ordering::Relaxed), 0);
+
+        // Conditioned engine renders the rate itself
+        let conditioned = Arc::new(RateBackend {
+            conditioned: true,
+            styled_calls: Default::default(),
+        });
+        let tts = StreamingTts::with_backend(conditioned.clone(), TtsConfig::default());
+        tts.set_style(slow);
+        let audio = TtsBackend::synthesize(&tts, "Dheere boliye").await.unwrap();
+        assert_eq!(audio.len(), 16000);
+        assert_eq!(conditioned.styled_calls.load(Ordering::Relaxed), 1);
+
+        // Out-of-range values are clamped
+        tts.set_style(SpeechStyle {
+            rate: 0.1,
+            pitch: 1.0,
+            sentence_pause_ms: 10_000,
+        });
+        assert_eq!(tts.style().rate, SpeechStyle::RATE_RANGE.0);
+        assert_eq!(
+            tts.style().sentence_pause_ms,
+            SpeechStyle::MAX_SENTENCE_PAUSE_MS
+        );
+    }
 }
@@ -0,0 +1,150 @@
+//! Time-Stretching and Pitch-Shifting
+//!
+//! Speaking rate and pitch for engines that can't be conditioned on them.
+//! Rate uses WSOLA (waveform similarity overlap-add): windowed frames are
+//! taken from the input at the new rate, each shifted within a small
+//! tolerance to line up with the previous frame's waveform, so pitch is kept
+//! and joins don't phase. Pitch stretches by the pitch factor and resamples
+//! back to the original length.
+
+/// Analysis frame length
+const FRAME_MS: usize = 30;
+/// How far a frame may move to match the previous one
+const TOLERANCE_MS: usize = 8;
+/// Factors this close to 1.0 leave the audio untouched
+const NEUTRAL_EPSILON: f32 = 0.01;
+
+/// Change duration by `1 / rate` keeping pitch (rate 0.8 is 25% longer)
+pub fn time_stretch(samples: &[f32], rate: f32, sample_rate: u32) -> Vec<f32> {
+    let frame = (sample_rate as usize * FRAME_MS / 1000).max(16);
+    if (rate - 1.0).abs() < NEUTRAL_EPSILON || rate <= 0.0 || samples.len() < frame * 2 {
+        return samples.to_vec();
+    }
+
+    let hop = frame / 2;
+    let tolerance = sample_rate as usize * TOLERANCE_MS / 1000;
+    let window: Vec<f32> = (0..frame)
+        .map(|i| 0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / frame as f32).cos())
+        .collect();
+
+    let out_len = (samples.len() as f64 / rate as f64).round() as usize;
+    let last_start = samples.len() - frame;
+    let mut out = vec![0.0f32; out_len + frame];
+    let mut weight = vec![0.0f32; out_len + frame];
+
+    let mut previous = 0usize;
+    let mut out_pos = 0usize;
+    while out_pos < out_len {
+        let start = if out_pos == 0 {
+            0
+        } else {
+            let nominal = ((out_pos as f64 * rate as f64) as usize).min(last_start);
+            // Continue the previous frame's waveform as closely as possible
+            let natural = (previous + hop).min(last_start);
+            let target = &samples[natural..natural + hop];
+            let low = nominal.saturating_sub(tolerance);
+            let high = (nominal + tolerance).min(last_start);
+            (low..=high)
+                .max_by(|&a, &b| {
+                    correlation(&samples[a..a + hop], target)
+                        .total_cmp(&correlation(&samples[b..b + hop], target))
+                })
+                .unwrap_or(nominal)
+        };
+
+        for i in 0..frame {
+            out[out_pos + i] += samples[start + i] * window[i];
+            weight[out_pos + i] += window[i];
+        }
+        previous = start;
+        out_pos += hop;
+    }
+
+    out.truncate(out_len);
+    for (sample, w) in out.iter_mut().zip(&weight) {
+        if *w > 1e-3 {
+            *sample /= w;
+        }
+    }
+    out
+}
+
+/// Scale pitch by `ratio` keeping duration (2.0 is an octave up)
+pub fn pitch_shift(samples: &[f32], ratio: f32, sample_rate: u32) -> Vec<f32> {
+    if (ratio - 1.0).abs() < NEUTRAL_EPSILON || ratio <= 0.0 {
+        return samples.to_vec();
+    }
+    let stretched = time_stretch(samples, 1.0 / ratio, sample_rate);
+    resample(&stretched, ratio as f64, samples.len())
+}
+
+/// Read `input` at `step` samples per output sample, linearly interpolated
+fn resample(input: &[f32], step: f64, len: usize) -> Vec<f32> {
+    if input.is_empty() {
+        return Vec::new();
+    }
+    (0..len)
+        .map(|i| {
+            let position = i as f64 * step;
+            let index = position as usize;
+            match (input.get(index), input.get(index + 1)) {
+                (Some(&a), Some(&b)) => {
+                    let t = (position - index as f64) as f32;
+                    a + (b - a) * t
+                },
+                (Some(&a), None) => a,
+                _ => 0.0,
+            }
+        })
+        .collect()
+}
+
+fn correlation(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 16000;
+
+    fn tone(hz: f32, seconds: f32) -> Vec<f32> {
+        let len = (RATE as f32 * seconds) as usize;
+        (0..len)
+            .map(|i| (2.0 * std::f32::consts::PI * hz * i as f32 / RATE as f32).sin())
+            .collect()
+    }
+
+    /// Frequency estimated from zero crossings
+    fn frequency(samples: &[f32]) -> f32 {
+        let crossings = samples
+            .windows(2)
+            .filter(|w| (w[0] < 0.0) != (w[1] < 0.0))
+            .count();
+        crossings as f32 / 2.0 / (samples.len() as f32 / RATE as f32)
+    }
+
+    #[test]
+    fn test_time_stretch_keeps_pitch() {
+        let input = tone(200.0, 1.0);
+
+        let slow = time_stretch(&input, 0.8, RATE);
+        assert_eq!(slow.len(), 20000);
+        assert!((frequency(&slow) - 200.0).abs() < 10.0);
+
+        let fast = time_stretch(&input, 1.25, RATE);
+        assert_eq!(fast.len(), 12800);
+        assert!((frequency(&fast) - 200.0).abs() < 10.0);
+
+        assert_eq!(time_stretch(&input, 1.0, RATE), input);
+    }
+
+    #[test]
+    fn test_pitch_shift_keeps_duration() {
+        let input = tone(200.0, 1.0);
+        let higher = pitch_shift(&input, 1.5, RATE);
+        assert_eq!(higher.len(), input.len());
+        assert!((frequency(&higher) - 300.0).abs() < 15.0);
+    }
+}
@@ -0,0 +1,206 @@
+//! TTS Voice Registry
+//!
+//! Resolves voice IDs from the domain voice registry (voices.yaml) to TTS
+//! backends and switches a [`StreamingTts`] to the voice the selection rules
+//! pick for the persona and language. Backends are loaded on first use and
+//! shared by every session that selects the same voice.
+
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use voice_agent_config::VoicesConfig;
+
+use super::streaming::load_reference_audio;
+use super::{create_tts_backend, StreamingTts, TtsBackend, TtsConfig, TtsEngine};
+use crate::PipelineError;
+
+/// Voice registry with lazily loaded backends
+pub struct VoiceRegistry {
+    config: VoicesConfig,
+    backends: Mutex<HashMap<String, Arc<dyn TtsBackend>>>,
+}
+
+impl std::fmt::Debug for VoiceRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VoiceRegistry")
+            .field("voices", &self.config.voices.len())
+            .field("loaded", &self.backends.lock().len())
+            .finish()
+    }
+}
+
+impl VoiceRegistry {
+    /// Create a registry from domain voice config
+    pub fn new(config: VoicesConfig) -> Self {
+        Self {
+            config,
+            backends: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Voice config the registry was built from
+    pub fn config(&self) -> &VoicesConfig {
+        &self.config
+    }
+
+    /// Pick a voice; see `VoicesConfig::select`
+    pub fn select<'a>(
+        &'a self,
+        persona: &str,
+        language: &str,
+        override_voice: Option<&'a str>,
+    ) -> Option<&'a str> {
+        self.config.select(persona, language, override_voice)
+    }
+
+    /// TTS config for a registered voice
+    pub fn tts_config(&self, voice_id: &str) -> Option<TtsConfig> {
+        let voice = self.config.voice(voice_id)?;
+        let engine = parse_engine(&voice.engine)?;
+        let sample_rate = match engine {
+            TtsEngine::Piper => 22050,
+            TtsEngine::IndicF5 | TtsEngine::ParlerTts => 24000,
+        };
+
+        Some(TtsConfig {
+            engine,
+            sample_rate,
+            voice_id: Some(voice_id.to_string()),
+            model_path: voice.model_path.clone(),
+            reference_audio_path: voice.reference_audio.clone(),
+            ..Default::default()
+        })
+    }
+
+    /// Backend for a voice, loading it on first use
+    pub fn backend(&self, voice_id: &str) -> Result<Arc<dyn TtsBackend>, PipelineError> {
+        if let Some(backend) = self.backends.lock().get(voice_id) {
+            return Ok(backend.clone());
+        }
+
+        let config = self.tts_config(voice_id).ok_or_else(|| {
+            PipelineError::Tts(format!("Unknown or misconfigured voice: {}", voice_id))
+        })?;
+        let reference_audio = match config.reference_audio_path {
+            Some(ref path) => Some(load_reference_audio(path)?),
+            None => None,
+        };
+        let backend = create_tts_backend(
+            config.engine,
+            config.model_path.as_deref(),
+            reference_audio,
+            config.quantization,
+        )?;
+
+        tracing::info!(voice = voice_id, engine = ?config.engine, "Loaded TTS voice");
+        self.backends
+            .lock()
+            .insert(voice_id.to_string(), backend.clone());
+        Ok(backend)
+    }
+
+    /// Switch `tts` to the selected voice if it is not already active
+    ///
+    /// Returns the new voice ID when a switch happened.
+    pub fn apply(
+        &self,
+        tts: &StreamingTts,
+        persona: &str,
+        language: &str,
+        override_voice: Option<&str>,
+    ) -> Result<Option<String>, PipelineError> {
+        let Some(voice_id) = self.select(persona, language, override_voice) else {
+            return Ok(None);
+        };
+        tts.set_language(language);
+        if tts.voice_id().as_deref() == Some(voice_id) {
+            return Ok(None);
+        }
+
+        let backend = self.backend(voice_id)?;
+        tracing::debug!(voice = voice_id, language, persona, "Switching TTS voice");
+        tts.switch_voice(voice_id, backend);
+        Ok(Some(voice_id.to_string()))
+    }
+}
+
+/// Engine name from voices.yaml
+fn parse_engine(name: &str) -> Option<TtsEngine> {
+    match name.to_ascii_lowercase().replace(['-', '_'], "").as_str() {
+        "piper" => Some(TtsEngine::Piper),
+        "indicf5" => Some(TtsEngine::IndicF5),
+        "parler" | "parlertts" => Some(TtsEngine::ParlerTts),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use voice_agent_config::VoiceProfile;
+
+    fn voice(engine: &str, language: &str) -> VoiceProfile {
+        VoiceProfile {
+            engine: engine.to_string(),
+            model_path: None,
+            reference_audio: None,
+            language: language.to_string(),
+            gender: Some("female".to_string()),
+            style: None,
+        }
+    }
+
+    fn registry() -> VoiceRegistry {
+        let mut config = VoicesConfig::default();
+        for (id, engine, language) in [
+            ("hi-female-1", "parler", "hi"),
+            ("en-female-1", "piper", "en"),
+            ("ta-female-1", "Parler-TTS", "ta"),
+            ("broken", "unknown", "hi"),
+        ] {
+            config
+                .voices
+                .insert(id.to_string(), voice(engine, language));
+        }
+        config
+            .language_defaults
+            .insert("hi".to_string(), "hi-female-1".to_string());
+        VoiceRegistry::new(config)
+    }
+
+    #[test]
+    fn test_tts_config() {
+        let registry = registry();
+        let config = registry.tts_config("en-female-1").unwrap();
+        assert_eq!(config.engine, TtsEngine::Piper);
+        assert_eq!(config.voice_id.as_deref(), Some("en-female-1"));
+        assert_eq!(
+            registry.tts_config("ta-female-1").unwrap().engine,
+            TtsEngine::ParlerTts
+        );
+        assert!(registry.tts_config("broken").is_none());
+        assert!(registry.backend("missing").is_err());
+    }
+
+    #[test]
+    fn test_switch_on_language_change() {
+        let registry = registry();
+        let tts = StreamingTts::simple(TtsConfig::default());
+        assert_eq!(tts.voice_id(), None);
+
+        let switched = registry.apply(&tts, "Priya", "hi", None).unwrap();
+        assert_eq!(switched.as_deref(), Some("hi-female-1"));
+        assert_eq!(tts.sample_rate(), 24000);
+
+        // Same language: no switch
+        assert_eq!(registry.apply(&tts, "Priya", "hi-IN", None).unwrap(), None);
+
+        // Caller moves to English
+        let switched = registry.apply(&tts, "Priya", "en", None).unwrap();
+        assert_eq!(switched.as_deref(), Some("en-female-1"));
+        assert_eq!(tts.voice_id().as_deref(), Some("en-female-1"));
+        assert_eq!(tts.sample_rate(), 22050);
+        assert_eq!(tts.language(), "en");
+    }
+}
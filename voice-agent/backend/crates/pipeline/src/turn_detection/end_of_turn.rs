@@ -0,0 +1,361 @@
+//! End-of-Turn Predictor
+//!
+//! Replaces a fixed silence timeout with a required silence that shrinks as
+//! evidence of a finished turn grows:
+//! - Lexical: completeness class of the latest partial transcript
+//! - Prosody: falling pitch and decaying energy over the last voiced frames
+//!
+//! `required = max - (max - min) * completeness`, so "Rate kya hai?" with a
+//! falling contour is answered after ~min silence while "mujhe loan chahiye
+//! aur..." waits for up to max silence.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use voice_agent_config::EndOfTurnPolicy;
+
+use super::semantic::CompletenessClass;
+
+/// Speech frames kept for prosody analysis (~250ms at 10ms frames)
+const PROSODY_WINDOW: usize = 25;
+
+/// Frames treated as the utterance ending
+const ENDING_FRAMES: usize = 3;
+
+/// Pitch search range (Hz)
+const MIN_PITCH_HZ: u32 = 70;
+const MAX_PITCH_HZ: u32 = 400;
+
+/// Normalized autocorrelation above which a frame counts as voiced
+const VOICING_THRESHOLD: f32 = 0.5;
+
+/// Relative pitch change across the window treated as a contour
+const PITCH_SLOPE: f32 = 0.05;
+
+/// Whether to keep waiting or respond now
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurnDecision {
+    /// Caller is likely to continue
+    Wait,
+    /// Caller has finished; respond
+    Respond,
+}
+
+/// End-of-turn prediction for the current pause
+#[derive(Debug, Clone)]
+pub struct EndOfTurnPrediction {
+    /// Combined turn completeness (0.0 - 1.0)
+    pub probability: f32,
+    /// Lexical completeness of the partial transcript (0.0 - 1.0)
+    pub lexical_score: f32,
+    /// Prosodic finality of the last speech (0.0 - 1.0)
+    pub prosody_score: f32,
+    /// Silence observed so far
+    pub silence: Duration,
+    /// Silence the policy requires at this completeness
+    pub required_silence: Duration,
+    /// Resulting decision
+    pub decision: TurnDecision,
+}
+
+/// Prosody features of one speech frame
+#[derive(Debug, Clone, Copy)]
+struct FrameProsody {
+    energy_db: f32,
+    pitch_hz: Option<f32>,
+}
+
+/// Tracks pitch and energy over the most recent speech frames
+#[derive(Debug, Default)]
+pub struct ProsodyTracker {
+    frames: VecDeque<FrameProsody>,
+    /// Trailing speech samples; 10ms frames are too short to hold two
+    /// periods of a low voice, so pitch is estimated over this window
+    recent: Vec<f32>,
+}
+
+impl ProsodyTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add one speech frame
+    pub fn push(&mut self, samples: &[f32], sample_rate: u32) {
+        if samples.is_empty() {
+            return;
+        }
+        let window = 2 * (sample_rate / MIN_PITCH_HZ) as usize;
+        self.recent.extend_from_slice(samples);
+        if self.recent.len() > window {
+            self.recent.drain(..self.recent.len() - window);
+        }
+
+        let energy = samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32;
+        let frame = FrameProsody {
+            energy_db: 10.0 * energy.max(1e-10).log10(),
+            pitch_hz: estimate_pitch(&self.recent, sample_rate),
+        };
+
+        if self.frames.len() == PROSODY_WINDOW {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(frame);
+    }
+
+    /// Finality score: high for falling pitch and decaying energy
+    ///
+    /// 0.5 (neutral) until enough frames have been seen.
+    pub fn score(&self) -> f32 {
+        if self.frames.len() <= ENDING_FRAMES {
+            return 0.5;
+        }
+
+        let mut score: f32 = 0.5;
+
+        if let Some(slope) = self.pitch_slope() {
+            score += if slope < -PITCH_SLOPE {
+                // Declarative fall
+                0.3
+            } else if slope > PITCH_SLOPE {
+                // Question rise also ends a turn, less reliably
+                0.15
+            } else {
+                // Level pitch: mid-list or hesitation
+                -0.15
+            };
+        }
+
+        let split = self.frames.len() - ENDING_FRAMES;
+        let earlier = mean(self.frames.iter().take(split).map(|f| f.energy_db));
+        let ending = mean(self.frames.iter().skip(split).map(|f| f.energy_db));
+        let decay = earlier - ending;
+        if decay > 3.0 {
+            score += 0.2;
+        } else if decay.abs() < 1.0 {
+            // Sustained energy up to the pause suggests a cut-off
+            score -= 0.1;
+        }
+
+        score.clamp(0.0, 1.0)
+    }
+
+    /// Clear for the next utterance
+    pub fn reset(&mut self) {
+        self.frames.clear();
+        self.recent.clear();
+    }
+
+    /// Relative pitch change between the first and second half of voiced frames
+    fn pitch_slope(&self) -> Option<f32> {
+        let pitches: Vec<f32> = self.frames.iter().filter_map(|f| f.pitch_hz).collect();
+        if pitches.len() < 4 {
+            return None;
+        }
+        let half = pitches.len() / 2;
+        let first = mean(pitches[..half].iter().copied());
+        let second = mean(pitches[half..].iter().copied());
+        Some((second - first) / first)
+    }
+}
+
+/// Combines silence, prosody and lexical completeness under a policy
+#[derive(Debug)]
+pub struct EndOfTurnPredictor {
+    policy: EndOfTurnPolicy,
+    prosody: ProsodyTracker,
+}
+
+impl EndOfTurnPredictor {
+    pub fn new(policy: EndOfTurnPolicy) -> Self {
+        Self {
+            policy,
+            prosody: ProsodyTracker::new(),
+        }
+    }
+
+    /// Feed a speech frame for prosody analysis
+    pub fn observe(&mut self, samples: &[f32], sample_rate: u32) {
+        self.prosody.push(samples, sample_rate);
+    }
+
+    /// Predict whether the turn has ended after `silence`
+    ///
+    /// `lexical` is the completeness class and confidence of the latest
+    /// partial transcript, if any.
+    pub fn predict(
+        &self,
+        silence: Duration,
+        lexical: Option<(CompletenessClass, f32)>,
+    ) -> EndOfTurnPrediction {
+        let lexical_score = lexical_score(lexical);
+        let prosody_score = self.prosody.score();
+
+        let total_weight = self.policy.lexical_weight + self.policy.prosody_weight;
+        let probability = if total_weight > 0.0 {
+            (self.policy.lexical_weight * lexical_score
+                + self.policy.prosody_weight * prosody_score)
+                / total_weight
+        } else {
+            0.5
+        };
+
+        let min = self.policy.min_silence_ms.min(self.policy.max_silence_ms) as f32;
+        let max = self.policy.max_silence_ms as f32;
+        let required_silence = Duration::from_millis((max - (max - min) * probability) as u64);
+
+        let decision = if silence >= required_silence {
+            TurnDecision::Respond
+        } else {
+            TurnDecision::Wait
+        };
+
+        EndOfTurnPrediction {
+            probability,
+            lexical_score,
+            prosody_score,
+            silence,
+            required_silence,
+            decision,
+        }
+    }
+
+    /// Clear prosody history for the next turn
+    pub fn reset(&mut self) {
+        self.prosody.reset();
+    }
+}
+
+/// Map a completeness class to a 0-1 score
+fn lexical_score(lexical: Option<(CompletenessClass, f32)>) -> f32 {
+    let Some((class, confidence)) = lexical else {
+        return 0.5;
+    };
+    let confidence = confidence.clamp(0.0, 1.0);
+    match class {
+        CompletenessClass::Complete | CompletenessClass::Question => 0.5 + 0.5 * confidence,
+        CompletenessClass::PossiblyComplete => 0.6,
+        CompletenessClass::Backchannel => 0.4,
+        CompletenessClass::Incomplete => 0.5 - 0.5 * confidence,
+    }
+}
+
+/// Autocorrelation pitch estimate; `None` for unvoiced or too-short input
+///
+/// Needs two periods of the lowest pitch. Takes the shortest lag that peaks
+/// near the best correlation, so multiples of the period don't halve the
+/// estimate.
+fn estimate_pitch(samples: &[f32], sample_rate: u32) -> Option<f32> {
+    let min_lag = (sample_rate / MAX_PITCH_HZ).max(1) as usize;
+    let max_lag = (sample_rate / MIN_PITCH_HZ) as usize;
+    if samples.len() < 2 * max_lag {
+        return None;
+    }
+
+    let correlations: Vec<f32> = (min_lag - 1..=max_lag + 1)
+        .map(|lag| {
+            let (head, tail) = (&samples[..samples.len() - lag], &samples[lag..]);
+            let cross: f32 = head.iter().zip(tail).map(|(a, b)| a * b).sum();
+            let energy =
+                head.iter().map(|s| s * s).sum::<f32>() * tail.iter().map(|s| s * s).sum::<f32>();
+            if energy <= f32::EPSILON {
+                0.0
+            } else {
+                cross / energy.sqrt()
+            }
+        })
+        .collect();
+
+    let best = correlations.iter().copied().fold(0.0f32, f32::max);
+    if best <= VOICING_THRESHOLD {
+        return None;
+    }
+
+    // correlations[i] is lag min_lag - 1 + i
+    (1..correlations.len() - 1)
+        .find(|&i| {
+            correlations[i] >= 0.9 * best
+                && correlations[i] >= correlations[i - 1]
+                && correlations[i] >= correlations[i + 1]
+        })
+        .map(|i| sample_rate as f32 / (min_lag - 1 + i) as f32)
+}
+
+fn mean(values: impl Iterator<Item = f32>) -> f32 {
+    let (sum, count) = values.fold((0.0, 0usize), |(sum, count), v| (sum + v, count + 1));
+    if count == 0 {
+        0.0
+    } else {
+        sum / count as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 16000;
+
+    /// Continuous-phase sine split into 10ms frames
+    struct Voice {
+        phase: f32,
+    }
+
+    impl Voice {
+        fn frame(&mut self, hz: f32, amplitude: f32) -> Vec<f32> {
+            (0..RATE as usize / 100)
+                .map(|_| {
+                    self.phase += 2.0 * std::f32::consts::PI * hz / RATE as f32;
+                    amplitude * self.phase.sin()
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_pitch_estimate() {
+        let mut voice = Voice { phase: 0.0 };
+        let samples: Vec<f32> = (0..4).flat_map(|_| voice.frame(120.0, 0.5)).collect();
+        let pitch = estimate_pitch(&samples, RATE).unwrap();
+        assert!((pitch - 120.0).abs() < 3.0, "pitch {}", pitch);
+        assert!(estimate_pitch(&[0.0; 640], RATE).is_none());
+        // One 10ms frame is too short for low voices
+        assert!(estimate_pitch(&samples[..160], RATE).is_none());
+    }
+
+    #[test]
+    fn test_falling_contour_scores_higher_than_flat() {
+        let mut falling = ProsodyTracker::new();
+        let mut flat = ProsodyTracker::new();
+        let (mut a, mut b) = (Voice { phase: 0.0 }, Voice { phase: 0.0 });
+        for i in 0..25 {
+            falling.push(&a.frame(220.0 - 3.0 * i as f32, 0.5 * 0.9f32.powi(i)), RATE);
+            flat.push(&b.frame(180.0, 0.5), RATE);
+        }
+
+        assert!(falling.score() > 0.8, "falling {}", falling.score());
+        assert!(flat.score() < 0.4, "flat {}", flat.score());
+    }
+
+    #[test]
+    fn test_complete_text_responds_sooner() {
+        let predictor = EndOfTurnPredictor::new(EndOfTurnPolicy::default());
+        let silence = Duration::from_millis(500);
+
+        let complete = predictor.predict(silence, Some((CompletenessClass::Question, 0.9)));
+        let incomplete = predictor.predict(silence, Some((CompletenessClass::Incomplete, 0.9)));
+
+        assert!(complete.required_silence < incomplete.required_silence);
+        assert_eq!(complete.decision, TurnDecision::Respond);
+        assert_eq!(incomplete.decision, TurnDecision::Wait);
+    }
+
+    #[test]
+    fn test_max_silence_always_responds() {
+        let policy = EndOfTurnPolicy::default();
+        let max = Duration::from_millis(policy.max_silence_ms);
+        let predictor = EndOfTurnPredictor::new(policy);
+
+        let prediction = predictor.predict(max, Some((CompletenessClass::Incomplete, 1.0)));
+        assert_eq!(prediction.decision, TurnDecision::Respond);
+    }
+}
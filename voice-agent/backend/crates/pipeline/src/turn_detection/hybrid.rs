@@ -6,9 +6,12 @@
 use parking_lot::Mutex;
 use std::time::{Duration, Instant};
 
+use super::end_of_turn::{EndOfTurnPrediction, EndOfTurnPredictor, TurnDecision};
 use super::semantic::{CompletenessClass, SemanticConfig, SemanticTurnDetector};
 use crate::vad::VadState;
 use crate::PipelineError;
+use voice_agent_config::EndOfTurnPolicy;
+use voice_agent_core::AudioFrame;
 
 /// Turn detection state
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -41,6 +44,8 @@ pub struct TurnDetectionResult {
     pub silence_duration: Duration,
     /// Dynamic silence threshold being used
     pub silence_threshold: Duration,
+    /// End-of-turn prediction while evaluating a pause
+    pub end_of_turn: Option<EndOfTurnPrediction>,
 }
 
 /// Configuration for hybrid turn detection
@@ -60,6 +65,8 @@ pub struct TurnDetectionConfig {
     pub semantic_config: SemanticConfig,
     /// Weight for semantic vs VAD decision
     pub semantic_weight: f32,
+    /// End-of-turn predictor policy (replaces the dynamic threshold when enabled)
+    pub end_of_turn: EndOfTurnPolicy,
 }
 
 impl Default for TurnDetectionConfig {
@@ -77,6 +84,7 @@ impl Default for TurnDetectionConfig {
             semantic_enabled: true,
             semantic_config: SemanticConfig::default(),
             semantic_weight: SEMANTIC_WEIGHT,
+            end_of_turn: EndOfTurnPolicy::default(),
         }
     }
 }
@@ -90,6 +98,7 @@ struct InternalState {
     last_semantic_class: Option<CompletenessClass>,
     last_semantic_confidence: f32,
     dynamic_threshold: Duration,
+    end_of_turn: Option<EndOfTurnPredictor>,
 }
 
 /// Hybrid Turn Detector
@@ -117,6 +126,7 @@ impl HybridTurnDetector {
                 last_semantic_class: None,
                 last_semantic_confidence: 0.0,
                 dynamic_threshold: Duration::from_millis(config.base_silence_ms as u64),
+                end_of_turn: Self::predictor(&config),
             }),
             config,
             semantic,
@@ -134,12 +144,30 @@ impl HybridTurnDetector {
                 last_semantic_class: None,
                 last_semantic_confidence: 0.0,
                 dynamic_threshold: Duration::from_millis(config.base_silence_ms as u64),
+                end_of_turn: Self::predictor(&config),
             }),
             config,
             semantic: Some(semantic),
         }
     }
 
+    fn predictor(config: &TurnDetectionConfig) -> Option<EndOfTurnPredictor> {
+        config
+            .end_of_turn
+            .enabled
+            .then(|| EndOfTurnPredictor::new(config.end_of_turn.clone()))
+    }
+
+    /// Feed an audio frame for prosody analysis (speech frames only)
+    pub fn observe_audio(&self, frame: &AudioFrame, vad_state: VadState) {
+        if !matches!(vad_state, VadState::Speech | VadState::SpeechStart) {
+            return;
+        }
+        if let Some(ref mut predictor) = self.internal.lock().end_of_turn {
+            predictor.observe(&frame.samples, frame.sample_rate.as_u32());
+        }
+    }
+
     /// Process VAD result and optional transcript update
     pub fn process(
         &self,
@@ -172,6 +200,8 @@ impl HybridTurnDetector {
             }
         }
 
+        let mut prediction = None;
+
         // State machine transitions
         let (new_state, is_turn_complete) = match (internal.state, vad_state) {
             // Idle -> UserSpeaking when speech starts
@@ -212,9 +242,22 @@ impl HybridTurnDetector {
 
                 let min_speech = Duration::from_millis(self.config.min_speech_ms as u64);
 
+                if let Some(ref predictor) = internal.end_of_turn {
+                    let lexical = internal
+                        .last_semantic_class
+                        .map(|class| (class, internal.last_semantic_confidence));
+                    prediction = Some(predictor.predict(silence_duration, lexical));
+                }
+
                 if speech_duration < min_speech {
                     // Not enough speech, keep waiting
                     (TurnState::Evaluating, false)
+                } else if let Some(ref prediction) = prediction {
+                    if prediction.decision == TurnDecision::Respond {
+                        (TurnState::TurnComplete, true)
+                    } else {
+                        (TurnState::Evaluating, false)
+                    }
                 } else if silence_duration >= internal.dynamic_threshold {
                     // Turn complete
                     (TurnState::TurnComplete, true)
@@ -268,7 +311,11 @@ impl HybridTurnDetector {
             semantic_class: internal.last_semantic_class,
             confidence,
             silence_duration,
-            silence_threshold: internal.dynamic_threshold,
+            silence_threshold: prediction
+                .as_ref()
+                .map(|p| p.required_silence)
+                .unwrap_or(internal.dynamic_threshold),
+            end_of_turn: prediction,
         })
     }
 
@@ -310,6 +357,9 @@ impl HybridTurnDetector {
         internal.last_semantic_class = None;
         internal.last_semantic_confidence = 0.0;
         internal.dynamic_threshold = Duration::from_millis(self.config.base_silence_ms as u64);
+        if let Some(ref mut predictor) = internal.end_of_turn {
+            predictor.reset();
+        }
 
         if let Some(ref semantic) = self.semantic {
             semantic.reset();
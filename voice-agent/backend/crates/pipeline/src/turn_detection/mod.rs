@@ -2,9 +2,12 @@
 //!
 //! Combines VAD-based silence detection with semantic completeness analysis.
 //! Architecture: Silence detector + Lightweight transformer classifier
+//! + End-of-turn predictor (silence, prosody, lexical completeness)
 
+mod end_of_turn;
 mod hybrid;
 mod semantic;
 
+pub use end_of_turn::{EndOfTurnPrediction, EndOfTurnPredictor, ProsodyTracker, TurnDecision};
 pub use hybrid::{HybridTurnDetector, TurnDetectionConfig, TurnDetectionResult, TurnState};
 pub use semantic::SemanticTurnDetector;
@@ -0,0 +1,345 @@
+//! Wake Word Detection
+//!
+//! Always-listening kiosk devices only start a session once a customer says
+//! an activation phrase. Each phrase in [`WakeWordConfig`] has a small ONNX
+//! keyword spotter scoring a sliding window of 16kHz audio (`window_ms`,
+//! advanced by `hop_ms`). The phrase counts as said after `min_consecutive`
+//! windows in a row score above its threshold.
+//!
+//! After a wake, and again when the session it started ends, the detector
+//! stays quiet for `cooldown_ms` so the tail of the phrase (or the agent's
+//! own goodbye) does not wake it a second time.
+//!
+//! Spotters are ONNX models ([`OnnxSpotter`], `onnx` feature); without the
+//! feature [`WakeWordDetector::new`] fails, and other [`KeywordSpotter`]s
+//! can be plugged in with [`WakeWordDetector::with_spotters`].
+
+use parking_lot::Mutex;
+use std::time::{Duration, Instant};
+use voice_agent_config::WakeWordConfig;
+use voice_agent_core::AudioFrame;
+
+use crate::PipelineError;
+
+#[cfg(feature = "onnx")]
+use ort::{session::builder::GraphOptimizationLevel, session::Session, value::Tensor};
+
+/// Sample rate the spotters expect
+const SAMPLE_RATE: usize = 16000;
+
+/// An activation phrase heard in the audio
+#[derive(Debug, Clone, PartialEq)]
+pub struct WakeWordMatch {
+    /// Phrase as configured
+    pub phrase: String,
+    /// Spotter score of the window that completed the match
+    pub score: f32,
+}
+
+/// Turns per-window scores into wakes: consecutive hits and cooldown
+#[derive(Debug)]
+struct WakeGate {
+    min_consecutive: usize,
+    cooldown: Duration,
+    /// Windows in a row above threshold, per phrase
+    hits: Vec<usize>,
+    quiet_until: Option<Instant>,
+}
+
+impl WakeGate {
+    fn new(config: &WakeWordConfig) -> Self {
+        Self {
+            min_consecutive: config.min_consecutive.max(1),
+            cooldown: Duration::from_millis(config.cooldown_ms),
+            hits: vec![0; config.phrases.len()],
+            quiet_until: None,
+        }
+    }
+
+    fn is_quiet(&self, now: Instant) -> bool {
+        self.quiet_until.is_some_and(|until| now < until)
+    }
+
+    /// Count one window's result for a phrase; returns whether it wakes
+    fn observe(&mut self, phrase: usize, hit: bool, now: Instant) -> bool {
+        if self.is_quiet(now) {
+            return false;
+        }
+        if !hit {
+            self.hits[phrase] = 0;
+            return false;
+        }
+        self.hits[phrase] += 1;
+        if self.hits[phrase] < self.min_consecutive {
+            return false;
+        }
+        self.rearm(now);
+        true
+    }
+
+    /// Start a cooldown and forget partial matches
+    fn rearm(&mut self, now: Instant) {
+        self.hits.iter_mut().for_each(|hits| *hits = 0);
+        self.quiet_until = Some(now + self.cooldown);
+    }
+}
+
+/// Scores a window of audio for one phrase
+pub trait KeywordSpotter: Send + Sync {
+    /// Probability (0.0 - 1.0) that the window contains the phrase
+    fn score(&self, window: &[f32]) -> Result<f32, PipelineError>;
+}
+
+/// ONNX keyword spotter
+///
+/// Takes `input` [1, window] and returns the phrase probability as `output`.
+#[cfg(feature = "onnx")]
+pub struct OnnxSpotter {
+    session: Mutex<Session>,
+}
+
+#[cfg(feature = "onnx")]
+impl OnnxSpotter {
+    /// Load a spotter model
+    pub fn new(model_path: impl AsRef<std::path::Path>) -> Result<Self, PipelineError> {
+        let session = Session::builder()
+            .map_err(|e| PipelineError::Model(e.to_string()))?
+            .with_optimization_level(GraphOptimizationLevel::Level3)
+            .map_err(|e| PipelineError::Model(e.to_string()))?
+            .with_intra_threads(1)
+            .map_err(|e| PipelineError::Model(e.to_string()))?
+            .commit_from_file(model_path)
+            .map_err(|e| PipelineError::Model(e.to_string()))?;
+        Ok(Self {
+            session: Mutex::new(session),
+        })
+    }
+}
+
+#[cfg(feature = "onnx")]
+impl KeywordSpotter for OnnxSpotter {
+    fn score(&self, window: &[f32]) -> Result<f32, PipelineError> {
+        let input = ndarray::Array2::from_shape_vec((1, window.len()), window.to_vec())
+            .map_err(|e| PipelineError::Model(e.to_string()))?;
+        let input_tensor =
+            Tensor::from_array(input).map_err(|e| PipelineError::Model(e.to_string()))?;
+
+        let mut session = self.session.lock();
+        let outputs = session
+            .run(ort::inputs!["input" => input_tensor])
+            .map_err(|e| PipelineError::Model(e.to_string()))?;
+        let (_, scores) = outputs
+            .get("output")
+            .ok_or_else(|| PipelineError::Model("Missing output tensor".to_string()))?
+            .try_extract_tensor::<f32>()
+            .map_err(|e| PipelineError::Model(e.to_string()))?;
+        Ok(scores.first().copied().unwrap_or(0.0))
+    }
+}
+
+/// Mutable detector state
+struct WakeWordState {
+    gate: WakeGate,
+    /// Last `window` samples heard
+    window: Vec<f32>,
+    /// Samples heard since the last score
+    since_score: usize,
+}
+
+/// Keyword spotter gating kiosk audio on activation phrases
+pub struct WakeWordDetector {
+    /// One spotter per configured phrase, in order
+    spotters: Vec<Box<dyn KeywordSpotter>>,
+    config: WakeWordConfig,
+    window_samples: usize,
+    hop_samples: usize,
+    state: Mutex<WakeWordState>,
+}
+
+impl WakeWordDetector {
+    /// Load an ONNX spotter for every configured phrase
+    #[cfg(feature = "onnx")]
+    pub fn new(config: WakeWordConfig) -> Result<Self, PipelineError> {
+        let spotters = config
+            .phrases
+            .iter()
+            .map(|phrase| {
+                OnnxSpotter::new(&phrase.model_path)
+                    .map(|spotter| Box::new(spotter) as Box<dyn KeywordSpotter>)
+                    .map_err(|e| {
+                        PipelineError::Model(format!("wake word '{}': {}", phrase.phrase, e))
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::with_spotters(config, spotters)
+    }
+
+    /// Wake word detection needs the ONNX spotter models
+    #[cfg(not(feature = "onnx"))]
+    pub fn new(_config: WakeWordConfig) -> Result<Self, PipelineError> {
+        Err(PipelineError::Model(
+            "wake word detection requires the onnx feature".to_string(),
+        ))
+    }
+
+    /// Create a detector over the given spotters, one per configured phrase
+    pub fn with_spotters(
+        config: WakeWordConfig,
+        spotters: Vec<Box<dyn KeywordSpotter>>,
+    ) -> Result<Self, PipelineError> {
+        if config.phrases.is_empty() || spotters.len() != config.phrases.len() {
+            return Err(PipelineError::Model(format!(
+                "{} wake word spotters for {} phrases",
+                spotters.len(),
+                config.phrases.len()
+            )));
+        }
+        let window_samples = (config.window_ms as usize * SAMPLE_RATE / 1000).max(1);
+        let hop_samples = (config.hop_ms as usize * SAMPLE_RATE / 1000).clamp(1, window_samples);
+        Ok(Self {
+            spotters,
+            state: Mutex::new(WakeWordState {
+                gate: WakeGate::new(&config),
+                window: Vec::with_capacity(window_samples),
+                since_score: 0,
+            }),
+            config,
+            window_samples,
+            hop_samples,
+        })
+    }
+
+    /// Feed a frame of 16kHz audio
+    ///
+    /// Returns the phrase heard once a match completes; frames arriving
+    /// during a cooldown are dropped.
+    pub fn process(&self, frame: &AudioFrame) -> Result<Option<WakeWordMatch>, PipelineError> {
+        let now = Instant::now();
+        let mut state = self.state.lock();
+        if state.gate.is_quiet(now) {
+            state.window.clear();
+            state.since_score = 0;
+            return Ok(None);
+        }
+
+        state.window.extend_from_slice(&frame.samples);
+        state.since_score += frame.samples.len();
+        let excess = state.window.len().saturating_sub(self.window_samples);
+        state.window.drain(..excess);
+        if state.window.len() < self.window_samples || state.since_score < self.hop_samples {
+            return Ok(None);
+        }
+        state.since_score = 0;
+
+        for (i, (phrase, spotter)) in self.config.phrases.iter().zip(&self.spotters).enumerate() {
+            let score = spotter.score(&state.window)?;
+            if state.gate.observe(i, score >= phrase.threshold, now) {
+                state.window.clear();
+                return Ok(Some(WakeWordMatch {
+                    phrase: phrase.phrase.clone(),
+                    score,
+                }));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Start a cooldown, e.g. when the session a wake started has ended
+    pub fn rearm(&self) {
+        let mut state = self.state.lock();
+        state.gate.rearm(Instant::now());
+        state.window.clear();
+        state.since_score = 0;
+    }
+
+    /// Phrases listened for
+    pub fn phrases(&self) -> impl Iterator<Item = &str> {
+        self.config.phrases.iter().map(|p| p.phrase.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use voice_agent_config::WakePhraseConfig;
+
+    fn config() -> WakeWordConfig {
+        WakeWordConfig {
+            enabled: true,
+            phrases: vec![WakePhraseConfig {
+                phrase: "Hello Kotak".to_string(),
+                model_path: "models/wake_word/hello_kotak.onnx".to_string(),
+                threshold: 0.6,
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_gate_needs_consecutive_hits() {
+        let mut gate = WakeGate::new(&config());
+        let now = Instant::now();
+        assert!(!gate.observe(0, true, now));
+        assert!(!gate.observe(0, false, now));
+        assert!(!gate.observe(0, true, now));
+        assert!(gate.observe(0, true, now));
+    }
+
+    #[test]
+    fn test_gate_cooldown() {
+        let config = config();
+        let cooldown = Duration::from_millis(config.cooldown_ms);
+        let mut gate = WakeGate::new(&config);
+        let now = Instant::now();
+        gate.observe(0, true, now);
+        assert!(gate.observe(0, true, now));
+
+        // Quiet until the cooldown has passed, whatever is heard
+        for _ in 0..config.min_consecutive {
+            assert!(!gate.observe(0, true, now + cooldown / 2));
+        }
+        let later = now + cooldown;
+        assert!(!gate.observe(0, true, later));
+        assert!(gate.observe(0, true, later));
+
+        // A session ending starts another cooldown
+        gate.rearm(later);
+        assert!(gate.is_quiet(later + cooldown / 2));
+    }
+
+    /// Spotter hearing the phrase in any loud window
+    struct LoudSpotter;
+
+    impl KeywordSpotter for LoudSpotter {
+        fn score(&self, window: &[f32]) -> Result<f32, PipelineError> {
+            Ok(window.iter().fold(0.0f32, |max, s| max.max(s.abs())))
+        }
+    }
+
+    #[test]
+    fn test_detector_wakes_on_phrase() {
+        use voice_agent_core::{Channels, SampleRate};
+
+        let detector =
+            WakeWordDetector::with_spotters(config(), vec![Box::new(LoudSpotter)]).unwrap();
+        let frame =
+            |level: f32| AudioFrame::new(vec![level; 4000], SampleRate::Hz16000, Channels::Mono, 0);
+
+        // Quiet audio fills the 1s window without waking
+        for _ in 0..4 {
+            assert_eq!(detector.process(&frame(0.1)).unwrap(), None);
+        }
+        // Two windows in a row with the phrase
+        assert_eq!(detector.process(&frame(0.9)).unwrap(), None);
+        let heard = detector.process(&frame(0.9)).unwrap().unwrap();
+        assert_eq!(heard.phrase, "Hello Kotak");
+
+        // Cooling down: said again straight away, it is not heard
+        for _ in 0..8 {
+            assert_eq!(detector.process(&frame(0.9)).unwrap(), None);
+        }
+
+        assert!(WakeWordDetector::with_spotters(config(), Vec::new()).is_err());
+    }
+}
@@ -0,0 +1,529 @@
+//! Shared Model Worker Pools
+//!
+//! By default every session loads and locks its own STT and TTS models, so
+//! N concurrent calls run N independent single-item inferences. A
+//! `WorkerPool` fronts one engine shared by all sessions instead:
+//!
+//! - Requests are queued per session (bounded, FIFO within a session)
+//! - The dispatcher lingers up to `max_batch_wait_ms` for more work, then
+//!   forms a batch of compatible requests (same `batch_key`)
+//! - The batch kind is chosen by the most urgent request; the batch is
+//!   filled round-robin, one request per session per round, so a session
+//!   streaming many sentences cannot starve the others
+//! - At most `workers` batches run at once; under load batches grow
+//!   toward `max_batch_size` while workers are busy
+//!
+//! Each request carries a deadline of enqueue time + `latency_slo_ms`.
+//! Deadlines order the queue heads and completed requests past their
+//! deadline are counted as SLO misses.
+
+mod stt;
+mod tts;
+
+pub use stt::{PooledSttBackend, SttBatchEngine};
+pub use tts::{PooledTtsBackend, TtsBatchEngine};
+
+use parking_lot::Mutex;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{oneshot, Notify, Semaphore};
+
+use voice_agent_config::WorkerPoolConfig;
+
+use crate::PipelineError;
+
+/// Model engine that can run several requests in one call
+#[async_trait::async_trait]
+pub trait BatchEngine: Send + Sync + 'static {
+    type Input: Send + 'static;
+    type Output: Send + 'static;
+
+    /// Requests with equal keys may share a batch (e.g. a length bucket)
+    fn batch_key(&self, _input: &Self::Input) -> u64 {
+        0
+    }
+
+    /// Run a batch; returns one result per input, in input order
+    async fn run_batch(&self, inputs: Vec<Self::Input>)
+        -> Vec<Result<Self::Output, PipelineError>>;
+}
+
+/// Point-in-time pool statistics
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WorkerPoolStats {
+    pub submitted: u64,
+    pub completed: u64,
+    pub failed: u64,
+    /// Rejected because the session's queue was full
+    pub rejected: u64,
+    pub batches: u64,
+    /// Requests completed after their latency SLO
+    pub slo_misses: u64,
+    /// Requests waiting for a batch
+    pub queued: usize,
+    /// Mean requests per batch
+    pub avg_batch_size: f64,
+}
+
+#[derive(Default)]
+struct Counters {
+    submitted: AtomicU64,
+    completed: AtomicU64,
+    failed: AtomicU64,
+    rejected: AtomicU64,
+    batches: AtomicU64,
+    batched: AtomicU64,
+    slo_misses: AtomicU64,
+}
+
+struct Job<E: BatchEngine> {
+    input: E::Input,
+    key: u64,
+    enqueued: Instant,
+    deadline: Instant,
+    reply: oneshot::Sender<Result<E::Output, PipelineError>>,
+}
+
+struct Queues<E: BatchEngine> {
+    by_client: HashMap<u64, VecDeque<Job<E>>>,
+    queued: usize,
+    closed: bool,
+}
+
+impl<E: BatchEngine> Queues<E> {
+    /// Enqueue time of the oldest waiting request
+    fn oldest(&self) -> Option<Instant> {
+        self.by_client
+            .values()
+            .filter_map(|queue| queue.front().map(|job| job.enqueued))
+            .min()
+    }
+
+    /// Take the next batch: most urgent kind first, one job per client per round
+    fn take_batch(&mut self, max: usize) -> Vec<Job<E>> {
+        let Some(key) = self
+            .by_client
+            .values()
+            .filter_map(VecDeque::front)
+            .min_by_key(|job| job.deadline)
+            .map(|job| job.key)
+        else {
+            return Vec::new();
+        };
+
+        let mut batch = Vec::new();
+        while batch.len() < max {
+            let mut heads: Vec<(Instant, u64)> = self
+                .by_client
+                .iter()
+                .filter_map(|(&client, queue)| {
+                    queue
+                        .front()
+                        .filter(|job| job.key == key)
+                        .map(|job| (job.deadline, client))
+                })
+                .collect();
+            if heads.is_empty() {
+                break;
+            }
+            heads.sort_unstable();
+
+            for (_, client) in heads.into_iter().take(max - batch.len()) {
+                if let Some(job) = self
+                    .by_client
+                    .get_mut(&client)
+                    .and_then(VecDeque::pop_front)
+                {
+                    batch.push(job);
+                }
+            }
+        }
+
+        self.by_client.retain(|_, queue| !queue.is_empty());
+        self.queued -= batch.len();
+        batch
+    }
+}
+
+struct Shared<E: BatchEngine> {
+    engine: Arc<E>,
+    config: WorkerPoolConfig,
+    queues: Mutex<Queues<E>>,
+    notify: Notify,
+    counters: Counters,
+}
+
+impl<E: BatchEngine> Shared<E> {
+    async fn dispatch(self: Arc<Self>) {
+        let workers = Arc::new(Semaphore::new(self.config.workers.max(1)));
+        let max_batch = self.config.max_batch_size.max(1);
+        let max_wait = Duration::from_millis(self.config.max_batch_wait_ms);
+
+        loop {
+            // Wait for the first request
+            let oldest = loop {
+                {
+                    let queues = self.queues.lock();
+                    if queues.closed {
+                        return;
+                    }
+                    if let Some(oldest) = queues.oldest() {
+                        break oldest;
+                    }
+                }
+                self.notify.notified().await;
+            };
+
+            // Linger so requests from other sessions can join the batch
+            let fill_by = oldest + max_wait;
+            loop {
+                {
+                    let queues = self.queues.lock();
+                    if queues.closed {
+                        return;
+                    }
+                    if queues.queued >= max_batch || Instant::now() >= fill_by {
+                        break;
+                    }
+                }
+                tokio::select! {
+                    _ = self.notify.notified() => {}
+                    _ = tokio::time::sleep_until(fill_by.into()) => {}
+                }
+            }
+
+            let Ok(permit) = workers.clone().acquire_owned().await else {
+                return;
+            };
+            let batch = self.queues.lock().take_batch(max_batch);
+            if batch.is_empty() {
+                continue;
+            }
+
+            let shared = self.clone();
+            tokio::spawn(async move {
+                let _permit = permit;
+                shared.run(batch).await;
+            });
+        }
+    }
+
+    async fn run(&self, batch: Vec<Job<E>>) {
+        let size = batch.len();
+        let mut inputs = Vec::with_capacity(size);
+        let mut replies = Vec::with_capacity(size);
+        for job in batch {
+            inputs.push(job.input);
+            replies.push((job.reply, job.deadline));
+        }
+
+        let mut results = self.engine.run_batch(inputs).await.into_iter();
+        let finished = Instant::now();
+
+        self.counters.batches.fetch_add(1, Ordering::Relaxed);
+        self.counters
+            .batched
+            .fetch_add(size as u64, Ordering::Relaxed);
+        for (reply, deadline) in replies {
+            let result = results.next().unwrap_or_else(|| {
+                Err(PipelineError::Model(
+                    "batch engine returned fewer results than inputs".to_string(),
+                ))
+            });
+            if finished > deadline {
+                self.counters.slo_misses.fetch_add(1, Ordering::Relaxed);
+            }
+            let counter = if result.is_ok() {
+                &self.counters.completed
+            } else {
+                &self.counters.failed
+            };
+            counter.fetch_add(1, Ordering::Relaxed);
+            let _ = reply.send(result);
+        }
+    }
+}
+
+/// Batching worker pool shared by all sessions
+pub struct WorkerPool<E: BatchEngine> {
+    shared: Arc<Shared<E>>,
+    next_client: AtomicU64,
+}
+
+impl<E: BatchEngine> std::fmt::Debug for WorkerPool<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WorkerPool")
+            .field("workers", &self.shared.config.workers)
+            .field("max_batch_size", &self.shared.config.max_batch_size)
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+impl<E: BatchEngine> WorkerPool<E> {
+    /// Create a pool and spawn its dispatcher (requires a Tokio runtime)
+    pub fn new(engine: Arc<E>, config: WorkerPoolConfig) -> Arc<Self> {
+        let shared = Arc::new(Shared {
+            engine,
+            config,
+            queues: Mutex::new(Queues {
+                by_client: HashMap::new(),
+                queued: 0,
+                closed: false,
+            }),
+            notify: Notify::new(),
+            counters: Counters::default(),
+        });
+        tokio::spawn(shared.clone().dispatch());
+
+        Arc::new(Self {
+            shared,
+            next_client: AtomicU64::new(0),
+        })
+    }
+
+    /// Handle for one session; each client gets its own fair-share queue
+    pub fn client(self: &Arc<Self>) -> PoolClient<E> {
+        PoolClient {
+            pool: self.clone(),
+            id: self.next_client.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+
+    pub fn engine(&self) -> &Arc<E> {
+        &self.shared.engine
+    }
+
+    pub fn config(&self) -> &WorkerPoolConfig {
+        &self.shared.config
+    }
+
+    pub fn stats(&self) -> WorkerPoolStats {
+        let counters = &self.shared.counters;
+        let batches = counters.batches.load(Ordering::Relaxed);
+        let batched = counters.batched.load(Ordering::Relaxed);
+        WorkerPoolStats {
+            submitted: counters.submitted.load(Ordering::Relaxed),
+            completed: counters.completed.load(Ordering::Relaxed),
+            failed: counters.failed.load(Ordering::Relaxed),
+            rejected: counters.rejected.load(Ordering::Relaxed),
+            batches,
+            slo_misses: counters.slo_misses.load(Ordering::Relaxed),
+            queued: self.shared.queues.lock().queued,
+            avg_batch_size: if batches == 0 {
+                0.0
+            } else {
+                batched as f64 / batches as f64
+            },
+        }
+    }
+
+    fn enqueue(
+        &self,
+        client: u64,
+        input: E::Input,
+    ) -> Result<oneshot::Receiver<Result<E::Output, PipelineError>>, PipelineError> {
+        let key = self.shared.engine.batch_key(&input);
+        let (reply, rx) = oneshot::channel();
+        let enqueued = Instant::now();
+        let deadline = enqueued + Duration::from_millis(self.shared.config.latency_slo_ms);
+
+        {
+            let mut queues = self.shared.queues.lock();
+            if queues.closed {
+                return Err(PipelineError::ChannelClosed);
+            }
+            let queue = queues.by_client.entry(client).or_default();
+            if queue.len() >= self.shared.config.max_queue_per_session {
+                self.shared
+                    .counters
+                    .rejected
+                    .fetch_add(1, Ordering::Relaxed);
+                return Err(PipelineError::Model(format!(
+                    "worker queue full ({} pending for this session)",
+                    queue.len()
+                )));
+            }
+            queue.push_back(Job {
+                input,
+                key,
+                enqueued,
+                deadline,
+                reply,
+            });
+            queues.queued += 1;
+        }
+
+        self.shared
+            .counters
+            .submitted
+            .fetch_add(1, Ordering::Relaxed);
+        self.shared.notify.notify_one();
+        Ok(rx)
+    }
+}
+
+impl<E: BatchEngine> Drop for WorkerPool<E> {
+    fn drop(&mut self) {
+        // Pending requests see their reply sender dropped (ChannelClosed)
+        let mut queues = self.shared.queues.lock();
+        queues.closed = true;
+        queues.by_client.clear();
+        queues.queued = 0;
+        drop(queues);
+        self.shared.notify.notify_one();
+    }
+}
+
+/// One session's handle to a shared pool
+pub struct PoolClient<E: BatchEngine> {
+    pool: Arc<WorkerPool<E>>,
+    id: u64,
+}
+
+impl<E: BatchEngine> PoolClient<E> {
+    /// Queue a request and wait for its batch to complete
+    pub async fn submit(&self, input: E::Input) -> Result<E::Output, PipelineError> {
+        let rx = self.pool.enqueue(self.id, input)?;
+        rx.await.map_err(|_| PipelineError::ChannelClosed)?
+    }
+
+    pub fn pool(&self) -> &Arc<WorkerPool<E>> {
+        &self.pool
+    }
+}
+
+impl<E: BatchEngine> Drop for PoolClient<E> {
+    fn drop(&mut self) {
+        // Requests from a finished session are not worth running
+        let mut queues = self.pool.shared.queues.lock();
+        if let Some(queue) = queues.by_client.remove(&self.id) {
+            queues.queued -= queue.len();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Records batch compositions; output is the input doubled
+    #[derive(Default)]
+    struct Recorder {
+        batches: Mutex<Vec<Vec<(u64, u32)>>>,
+        delay_ms: u64,
+    }
+
+    #[async_trait::async_trait]
+    impl BatchEngine for Recorder {
+        /// (session tag, value)
+        type Input = (u64, u32);
+        type Output = u32;
+
+        fn batch_key(&self, input: &Self::Input) -> u64 {
+            (input.1 >= 100) as u64
+        }
+
+        async fn run_batch(&self, inputs: Vec<Self::Input>) -> Vec<Result<u32, PipelineError>> {
+            tokio::time::sleep(Duration::from_millis(self.delay_ms)).await;
+            self.batches.lock().push(inputs.clone());
+            inputs.into_iter().map(|(_, value)| Ok(value * 2)).collect()
+        }
+    }
+
+    fn config(workers: usize, max_batch_size: usize) -> WorkerPoolConfig {
+        WorkerPoolConfig {
+            enabled: true,
+            workers,
+            max_batch_size,
+            max_batch_wait_ms: 20,
+            latency_slo_ms: 1000,
+            max_queue_per_session: 4,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batches_across_sessions() {
+        let engine = Arc::new(Recorder::default());
+        let pool = WorkerPool::new(engine.clone(), config(1, 8));
+
+        let clients: Vec<_> = (0..4).map(|_| pool.client()).collect();
+        let results = futures::future::join_all(
+            clients
+                .iter()
+                .enumerate()
+                .map(|(i, client)| client.submit((i as u64, i as u32))),
+        )
+        .await;
+
+        let values: Vec<u32> = results.into_iter().map(Result::unwrap).collect();
+        assert_eq!(values, vec![0, 2, 4, 6]);
+        // All four arrived within the linger window and ran together
+        assert_eq!(engine.batches.lock().len(), 1);
+
+        let stats = pool.stats();
+        assert_eq!(stats.submitted, 4);
+        assert_eq!(stats.completed, 4);
+        assert_eq!(stats.batches, 1);
+        assert_eq!(stats.avg_batch_size, 4.0);
+        assert_eq!(stats.slo_misses, 0);
+    }
+
+    #[tokio::test]
+    async fn test_round_robin_fairness_and_keys() {
+        let engine = Arc::new(Recorder::default());
+        let pool = WorkerPool::new(engine.clone(), config(1, 3));
+        let busy = pool.client();
+        let quiet = pool.client();
+
+        // The busy session queues three short requests before the quiet one
+        // and one long request that cannot share a batch with them
+        let submits = futures::future::join_all(vec![
+            busy.submit((0, 1)),
+            busy.submit((0, 2)),
+            busy.submit((0, 3)),
+            quiet.submit((1, 4)),
+            quiet.submit((1, 200)),
+        ])
+        .await;
+        assert!(submits.iter().all(Result::is_ok));
+
+        let batches = engine.batches.lock().clone();
+        // First batch interleaves sessions instead of draining the busy one
+        assert_eq!(batches[0], vec![(0, 1), (1, 4), (0, 2)]);
+        assert!(batches.iter().all(|batch| {
+            batch.iter().all(|input| input.1 >= 100) || batch.iter().all(|input| input.1 < 100)
+        }));
+        // Per-session order is preserved
+        let busy_order: Vec<u32> = batches
+            .iter()
+            .flatten()
+            .filter(|input| input.0 == 0)
+            .map(|input| input.1)
+            .collect();
+        assert_eq!(busy_order, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_queue_cap_and_slo_misses() {
+        let engine = Arc::new(Recorder {
+            delay_ms: 30,
+            ..Default::default()
+        });
+        let mut cfg = config(1, 1);
+        cfg.max_queue_per_session = 1;
+        cfg.latency_slo_ms = 10;
+        let pool = WorkerPool::new(engine, cfg);
+        let client = pool.client();
+
+        let (first, second) = tokio::join!(client.submit((0, 1)), client.submit((0, 2)));
+        assert_eq!(first.unwrap(), 2);
+        assert!(matches!(second, Err(PipelineError::Model(_))));
+
+        let stats = pool.stats();
+        assert_eq!(stats.rejected, 1);
+        assert_eq!(stats.slo_misses, 1);
+    }
+}
@@ -0,0 +1,183 @@
+//! Pooled STT
+//!
+//! A fixed set of STT model instances shared by all sessions. Sessions
+//! buffer the caller's audio and submit the whole utterance at end of
+//! turn; utterances of similar duration are batched onto one instance.
+//! Pooled sessions get final transcripts only (no partials) in exchange
+//! for not holding a model per call.
+
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::sync::{Mutex, MutexGuard};
+use voice_agent_core::TranscriptResult;
+
+use super::{BatchEngine, PoolClient, WorkerPool};
+use crate::stt::{IndicConformerConfig, IndicConformerStt, SttBackend};
+use crate::PipelineError;
+
+/// Seconds of audio per duration bucket
+const DURATION_BUCKET_SECS: usize = 2;
+
+/// Batch engine over a set of STT model instances
+pub struct SttBatchEngine {
+    instances: Vec<Mutex<Box<dyn SttBackend>>>,
+    sample_rate: u32,
+    next: AtomicUsize,
+}
+
+impl SttBatchEngine {
+    /// Wrap pre-loaded instances; batches run on whichever is free
+    pub fn new(
+        instances: Vec<Box<dyn SttBackend>>,
+        sample_rate: u32,
+    ) -> Result<Self, PipelineError> {
+        if instances.is_empty() {
+            return Err(PipelineError::NotInitialized);
+        }
+        Ok(Self {
+            instances: instances.into_iter().map(Mutex::new).collect(),
+            sample_rate,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    /// Load `count` IndicConformer instances from `model_dir`
+    pub fn indicconformer(
+        model_dir: impl AsRef<Path>,
+        config: IndicConformerConfig,
+        count: usize,
+    ) -> Result<Self, PipelineError> {
+        let sample_rate = config.sample_rate.as_u32();
+        let instances = (0..count.max(1))
+            .map(|_| {
+                IndicConformerStt::new(model_dir.as_ref(), config.clone())
+                    .map(|stt| Box::new(stt) as Box<dyn SttBackend>)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(instances, sample_rate)
+    }
+
+    pub fn instances(&self) -> usize {
+        self.instances.len()
+    }
+
+    /// Lock a free instance, or wait on the next one in rotation
+    async fn checkout(&self) -> MutexGuard<'_, Box<dyn SttBackend>> {
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+        let count = self.instances.len();
+        for offset in 0..count {
+            if let Ok(guard) = self.instances[(start + offset) % count].try_lock() {
+                return guard;
+            }
+        }
+        self.instances[start % count].lock().await
+    }
+}
+
+#[async_trait::async_trait]
+impl BatchEngine for SttBatchEngine {
+    type Input = Vec<f32>;
+    type Output = TranscriptResult;
+
+    fn batch_key(&self, audio: &Vec<f32>) -> u64 {
+        let bucket = self.sample_rate as usize * DURATION_BUCKET_SECS;
+        (audio.len() / bucket.max(1)) as u64
+    }
+
+    async fn run_batch(
+        &self,
+        utterances: Vec<Vec<f32>>,
+    ) -> Vec<Result<TranscriptResult, PipelineError>> {
+        let mut model = self.checkout().await;
+        let mut results = Vec::with_capacity(utterances.len());
+        for audio in utterances {
+            model.reset();
+            let result = match model.process_chunk(&audio).await {
+                Ok(_) => model.finalize().await,
+                Err(e) => Err(e),
+            };
+            results.push(result);
+        }
+        model.reset();
+        results
+    }
+}
+
+/// Per-session STT backend that transcribes through a shared pool
+pub struct PooledSttBackend {
+    client: PoolClient<SttBatchEngine>,
+    buffer: Vec<f32>,
+}
+
+impl PooledSttBackend {
+    pub fn new(pool: &Arc<WorkerPool<SttBatchEngine>>) -> Self {
+        Self {
+            client: pool.client(),
+            buffer: Vec::new(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SttBackend for PooledSttBackend {
+    async fn process_chunk(
+        &mut self,
+        audio: &[f32],
+    ) -> Result<Option<TranscriptResult>, PipelineError> {
+        self.buffer.extend_from_slice(audio);
+        Ok(None)
+    }
+
+    async fn finalize(&mut self) -> Result<TranscriptResult, PipelineError> {
+        let audio = std::mem::take(&mut self.buffer);
+        if audio.is_empty() {
+            return Ok(TranscriptResult {
+                is_final: true,
+                ..Default::default()
+            });
+        }
+        self.client.submit(audio).await
+    }
+
+    fn reset(&mut self) {
+        self.buffer.clear();
+    }
+
+    fn partial(&self) -> Option<&TranscriptResult> {
+        None
+    }
+
+    fn process(&mut self, audio: &[f32]) -> Result<Option<TranscriptResult>, PipelineError> {
+        self.buffer.extend_from_slice(audio);
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stt::ScriptedSttBackend;
+    use voice_agent_config::WorkerPoolConfig;
+
+    // Default (current_thread) runtime: the pool dispatcher must be able to
+    // run while the session waits on its transcript
+    #[tokio::test]
+    async fn test_pooled_finalize_on_current_thread_runtime() {
+        let scripted = ScriptedSttBackend::new("hi", ["gold loan chahiye"]);
+        let engine = SttBatchEngine::new(vec![Box::new(scripted)], 16000).unwrap();
+        let pool = WorkerPool::new(
+            Arc::new(engine),
+            WorkerPoolConfig {
+                enabled: true,
+                ..Default::default()
+            },
+        );
+        let mut stt = PooledSttBackend::new(&pool);
+
+        assert!(stt.process(&[0.1; 1600]).unwrap().is_none());
+        let transcript = stt.finalize().await.unwrap();
+        assert_eq!(transcript.text, "gold loan chahiye");
+        assert_eq!(pool.stats().completed, 1);
+    }
+}
@@ -0,0 +1,129 @@
+//! Pooled TTS
+//!
+//! One TTS backend shared by all sessions. Sentences from concurrent
+//! responses are grouped by length so padded batches waste little compute;
+//! `TtsBackend::synthesize_batch` decides how a batch is actually run.
+
+use std::sync::Arc;
+
+use super::{BatchEngine, PoolClient, WorkerPool};
+use crate::tts::{create_tts_backend, load_reference_audio, ProsodySupport, TtsBackend, TtsConfig};
+use crate::PipelineError;
+
+/// Characters per length bucket
+const LENGTH_BUCKET_CHARS: usize = 32;
+
+/// Batch engine over a shared TTS backend
+pub struct TtsBatchEngine {
+    backend: Arc<dyn TtsBackend>,
+}
+
+impl TtsBatchEngine {
+    pub fn new(backend: Arc<dyn TtsBackend>) -> Self {
+        Self { backend }
+    }
+
+    /// Load the backend selected by `config.engine`
+    pub fn from_config(config: &TtsConfig) -> Result<Self, PipelineError> {
+        let reference_audio = match config.reference_audio_path {
+            Some(ref path) => Some(load_reference_audio(path)?),
+            None => None,
+        };
+        let backend = create_tts_backend(
+            config.engine,
+            config.model_path.as_deref(),
+            reference_audio,
+            config.quantization,
+        )?;
+        Ok(Self::new(backend))
+    }
+
+    pub fn backend(&self) -> &Arc<dyn TtsBackend> {
+        &self.backend
+    }
+}
+
+#[async_trait::async_trait]
+impl BatchEngine for TtsBatchEngine {
+    type Input = String;
+    type Output = Vec<f32>;
+
+    fn batch_key(&self, text: &String) -> u64 {
+        (text.chars().count() / LENGTH_BUCKET_CHARS) as u64
+    }
+
+    async fn run_batch(&self, texts: Vec<String>) -> Vec<Result<Vec<f32>, PipelineError>> {
+        self.backend.synthesize_batch(&texts).await
+    }
+}
+
+/// Per-session TTS backend that synthesizes through a shared pool
+///
+/// Plugs into `StreamingTts::with_backend`, so chunking, caching and
+/// barge-in behave exactly as with a dedicated backend.
+pub struct PooledTtsBackend {
+    client: PoolClient<TtsBatchEngine>,
+}
+
+impl PooledTtsBackend {
+    pub fn new(pool: &Arc<WorkerPool<TtsBatchEngine>>) -> Self {
+        Self {
+            client: pool.client(),
+        }
+    }
+
+    fn inner(&self) -> &Arc<dyn TtsBackend> {
+        self.client.pool().engine().backend()
+    }
+}
+
+#[async_trait::async_trait]
+impl TtsBackend for PooledTtsBackend {
+    async fn synthesize(&self, text: &str) -> Result<Vec<f32>, PipelineError> {
+        self.client.submit(text.to_string()).await
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.inner().sample_rate()
+    }
+
+    fn supports_streaming(&self) -> bool {
+        self.inner().supports_streaming()
+    }
+
+    fn prosody_support(&self) -> ProsodySupport {
+        // Batches carry plain text, so rate and pitch are applied afterwards
+        ProsodySupport {
+            rate: false,
+            pitch: false,
+            ..self.inner().prosody_support()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tts::StubTtsBackend;
+    use voice_agent_config::WorkerPoolConfig;
+
+    #[tokio::test]
+    async fn test_pooled_backend_matches_direct() {
+        let stub: Arc<dyn TtsBackend> = Arc::new(StubTtsBackend::new(16000));
+        let pool = WorkerPool::new(
+            Arc::new(TtsBatchEngine::new(stub.clone())),
+            WorkerPoolConfig {
+                enabled: true,
+                ..Default::default()
+            },
+        );
+        let first = PooledTtsBackend::new(&pool);
+        let second = PooledTtsBackend::new(&pool);
+        assert_eq!(first.sample_rate(), 16000);
+
+        let (a, b) = tokio::join!(first.synthesize("namaste"), second.synthesize("dhanyavaad"));
+        assert_eq!(a.unwrap(), stub.synthesize("namaste").await.unwrap());
+        assert_eq!(b.unwrap().len(), "dhanyavaad".len() * 800);
+        assert_eq!(pool.stats().batches, 1);
+    }
+}
@@ -173,7 +173,12 @@ impl SemanticChunker {
             };
 
             let context = if context.len() > self.config.max_context_prefix_len {
-                format!("{}...", &context[..self.config.max_context_prefix_len - 3])
+                // Cut on a char boundary so Devanagari titles don't panic
+                let mut end = self.config.max_context_prefix_len.saturating_sub(3);
+                while !context.is_char_boundary(end) {
+                    end -= 1;
+                }
+                format!("{}...", &context[..end])
             } else {
                 context
             };
@@ -0,0 +1,800 @@
+//! Knowledge Ingestion
+//!
+//! Turns product sheets and policy documents into citable chunks for the
+//! [`KnowledgeBase`]: parse (Markdown, HTML, PDF, plain text, or the
+//! YAML/JSON `KnowledgeFile` format), split into sections, chunk, embed and
+//! index per domain.
+//!
+//! Directory layout: files directly under the knowledge root belong to the
+//! root domain; each subdirectory is its own domain. A language suffix in
+//! the file name (`rates.hi.md`) sets the document language.
+//!
+//! PDF parsing requires the `pdf` feature.
+
+use std::path::{Path, PathBuf};
+
+use crate::chunker::{ChunkConfig, SemanticChunker};
+use crate::knowledge_base::{Citation, KnowledgeBase, KnowledgeChunk};
+use crate::knowledge_loader::KnowledgeFile;
+use crate::RagError;
+
+/// Supported source formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentFormat {
+    Markdown,
+    Html,
+    Pdf,
+    Text,
+    /// YAML/JSON `KnowledgeFile` (`documents:` list)
+    Structured,
+}
+
+impl DocumentFormat {
+    /// Detect the format from a file extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "md" | "markdown" => Some(Self::Markdown),
+            "html" | "htm" => Some(Self::Html),
+            "pdf" => Some(Self::Pdf),
+            "txt" => Some(Self::Text),
+            "yaml" | "yml" | "json" => Some(Self::Structured),
+            _ => None,
+        }
+    }
+}
+
+/// A titled span of a document
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentSection {
+    /// Heading the section falls under
+    pub heading: Option<String>,
+    /// Plain text
+    pub text: String,
+    /// Page number (PDF only, 1-based)
+    pub page: Option<u32>,
+}
+
+/// A document parsed into plain-text sections
+#[derive(Debug, Clone)]
+pub struct ParsedDocument {
+    pub id: String,
+    pub title: String,
+    /// File path or URL
+    pub source: String,
+    pub language: String,
+    pub format: DocumentFormat,
+    pub sections: Vec<DocumentSection>,
+}
+
+/// Ingestion configuration
+#[derive(Debug, Clone)]
+pub struct IngestionConfig {
+    /// Chunking parameters
+    pub chunk: ChunkConfig,
+    /// Language for documents that do not declare one
+    pub default_language: String,
+}
+
+impl Default for IngestionConfig {
+    fn default() -> Self {
+        Self {
+            chunk: ChunkConfig::default(),
+            default_language: "en".to_string(),
+        }
+    }
+}
+
+/// Outcome of an ingestion run
+#[derive(Debug, Default)]
+pub struct IngestionReport {
+    /// Documents indexed
+    pub documents: usize,
+    /// Chunks indexed
+    pub chunks: usize,
+    /// Files that could not be ingested, with the reason
+    pub failed: Vec<(PathBuf, String)>,
+}
+
+/// Parses, chunks and indexes documents into a knowledge base
+pub struct IngestionPipeline {
+    config: IngestionConfig,
+    chunker: SemanticChunker,
+}
+
+impl IngestionPipeline {
+    pub fn new(config: IngestionConfig) -> Self {
+        let chunker = SemanticChunker::new(config.chunk.clone());
+        Self { config, chunker }
+    }
+
+    /// Split a parsed document into citable chunks
+    pub fn chunk_document(&self, document: &ParsedDocument) -> Vec<KnowledgeChunk> {
+        let mut chunks = Vec::new();
+        for section in &document.sections {
+            let heading = section.heading.as_deref().unwrap_or(&document.title);
+            for chunk in self
+                .chunker
+                .chunk_with_context(&section.text, heading, None)
+            {
+                let chunk_index = chunks.len();
+                chunks.push(KnowledgeChunk {
+                    id: format!("{}#{}", document.id, chunk_index),
+                    language: document.language.clone(),
+                    text: chunk.text_with_context(),
+                    citation: Citation {
+                        document_id: document.id.clone(),
+                        title: document.title.clone(),
+                        source: document.source.clone(),
+                        section: section.heading.clone(),
+                        page: section.page,
+                        chunk_index,
+                    },
+                });
+            }
+        }
+        chunks
+    }
+
+    /// Parse and index raw document bytes
+    ///
+    /// Returns the number of documents and chunks indexed.
+    pub fn ingest_bytes(
+        &self,
+        kb: &KnowledgeBase,
+        domain: &str,
+        id: &str,
+        source: &str,
+        bytes: &[u8],
+        format: DocumentFormat,
+    ) -> Result<(usize, usize), RagError> {
+        let language =
+            language_from_source(source).unwrap_or_else(|| self.config.default_language.clone());
+        let documents = match format {
+            DocumentFormat::Structured => parse_knowledge_file(source, bytes, &language)?,
+            _ => vec![parse_document(id, source, bytes, format, &language)?],
+        };
+
+        let mut chunk_count = 0;
+        for document in &documents {
+            chunk_count += kb.add_chunks(domain, self.chunk_document(document))?;
+        }
+        Ok((documents.len(), chunk_count))
+    }
+
+    /// Ingest a single file into a domain
+    pub fn ingest_file(
+        &self,
+        kb: &KnowledgeBase,
+        domain: &str,
+        path: &Path,
+    ) -> Result<(usize, usize), RagError> {
+        let format = DocumentFormat::from_path(path).ok_or_else(|| {
+            RagError::Index(format!("Unsupported document type: {}", path.display()))
+        })?;
+        let bytes = std::fs::read(path)
+            .map_err(|e| RagError::Index(format!("Failed to read {}: {}", path.display(), e)))?;
+        let id = document_id(domain, path);
+        self.ingest_bytes(kb, domain, &id, &path.to_string_lossy(), &bytes, format)
+    }
+
+    /// Ingest a knowledge directory
+    ///
+    /// Files at the top level go to `root_domain`; each subdirectory is
+    /// indexed as a domain named after it. Unreadable or unparseable files
+    /// are reported and skipped.
+    pub fn ingest_directory(
+        &self,
+        kb: &KnowledgeBase,
+        root: &Path,
+        root_domain: &str,
+    ) -> Result<IngestionReport, RagError> {
+        let mut report = IngestionReport::default();
+        if !root.exists() {
+            tracing::warn!(path = %root.display(), "Knowledge directory does not exist");
+            return Ok(report);
+        }
+
+        let entries = std::fs::read_dir(root)
+            .map_err(|e| RagError::Index(format!("Failed to read directory: {}", e)))?;
+        let mut paths: Vec<PathBuf> = entries.filter_map(|e| e.ok().map(|e| e.path())).collect();
+        paths.sort();
+
+        for path in paths {
+            if path.is_dir() {
+                let Some(domain) = path.file_name().and_then(|n| n.to_str()) else {
+                    continue;
+                };
+                let mut files = Vec::new();
+                collect_files(&path, &mut files);
+                for file in files {
+                    self.ingest_into(kb, domain, &file, &mut report);
+                }
+            } else {
+                self.ingest_into(kb, root_domain, &path, &mut report);
+            }
+        }
+
+        tracing::info!(
+            directory = %root.display(),
+            documents = report.documents,
+            chunks = report.chunks,
+            failed = report.failed.len(),
+            domains = ?kb.domains(),
+            "Knowledge ingestion complete"
+        );
+        Ok(report)
+    }
+
+    fn ingest_into(
+        &self,
+        kb: &KnowledgeBase,
+        domain: &str,
+        path: &Path,
+        report: &mut IngestionReport,
+    ) {
+        if DocumentFormat::from_path(path).is_none() || is_manifest(path) {
+            return;
+        }
+        match self.ingest_file(kb, domain, path) {
+            Ok((documents, chunks)) => {
+                tracing::debug!(
+                    file = %path.display(),
+                    domain = %domain,
+                    documents,
+                    chunks,
+                    "Ingested knowledge file"
+                );
+                report.documents += documents;
+                report.chunks += chunks;
+            },
+            Err(e) => {
+                tracing::warn!(file = %path.display(), error = %e, "Failed to ingest knowledge file");
+                report.failed.push((path.to_path_buf(), e.to_string()));
+            },
+        }
+    }
+}
+
+/// Parse a single document into sections
+pub fn parse_document(
+    id: &str,
+    source: &str,
+    bytes: &[u8],
+    format: DocumentFormat,
+    default_language: &str,
+) -> Result<ParsedDocument, RagError> {
+    let fallback_title = Path::new(source)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .map(|s| s.split('.').next().unwrap_or(s).replace(['_', '-'], " "))
+        .unwrap_or_else(|| id.to_string());
+
+    let (title, language, sections) = match format {
+        DocumentFormat::Markdown => parse_markdown(&decode_utf8(bytes)),
+        DocumentFormat::Html => parse_html(&decode_utf8(bytes)),
+        DocumentFormat::Pdf => (None, None, parse_pdf(bytes)?),
+        DocumentFormat::Text => (
+            None,
+            None,
+            vec![DocumentSection {
+                heading: None,
+                text: decode_utf8(bytes).trim().to_string(),
+                page: None,
+            }],
+        ),
+        DocumentFormat::Structured => {
+            return Err(RagError::Index(
+                "Structured knowledge files hold several documents; use parse_knowledge_file"
+                    .to_string(),
+            ))
+        },
+    };
+
+    Ok(ParsedDocument {
+        id: id.to_string(),
+        title: title.unwrap_or(fallback_title),
+        source: source.to_string(),
+        language: language.unwrap_or_else(|| default_language.to_string()),
+        format,
+        sections: sections
+            .into_iter()
+            .filter(|s| !s.text.trim().is_empty())
+            .collect(),
+    })
+}
+
+/// Parse a YAML/JSON `KnowledgeFile` into one document per entry
+pub fn parse_knowledge_file(
+    source: &str,
+    bytes: &[u8],
+    default_language: &str,
+) -> Result<Vec<ParsedDocument>, RagError> {
+    let content = decode_utf8(bytes);
+    let file: KnowledgeFile = if source.to_ascii_lowercase().ends_with(".json") {
+        serde_json::from_str(&content)
+            .map_err(|e| RagError::Index(format!("JSON parse error: {}", e)))?
+    } else {
+        serde_yaml::from_str(&content)
+            .map_err(|e| RagError::Index(format!("YAML parse error: {}", e)))?
+    };
+
+    Ok(file
+        .documents
+        .into_iter()
+        .map(|doc| {
+            let language = if doc.language.is_empty() {
+                default_language.to_string()
+            } else {
+                doc.language
+            };
+            ParsedDocument {
+                id: doc.id,
+                title: doc.title,
+                source: source.to_string(),
+                language,
+                format: DocumentFormat::Structured,
+                sections: vec![DocumentSection {
+                    heading: doc.category,
+                    text: doc.content,
+                    page: None,
+                }],
+            }
+        })
+        .collect())
+}
+
+fn decode_utf8(bytes: &[u8]) -> String {
+    let text = String::from_utf8_lossy(bytes);
+    text.strip_prefix('\u{feff}').unwrap_or(&text).to_string()
+}
+
+/// Stable document ID: domain plus path relative to the domain directory
+fn document_id(domain: &str, path: &Path) -> String {
+    let stem = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("document");
+    let stem = stem.rsplit_once('.').map(|(s, _)| s).unwrap_or(stem);
+    format!("{}/{}", domain, stem)
+}
+
+/// Language code from a `name.<lang>.<ext>` file name
+fn language_from_source(source: &str) -> Option<String> {
+    let name = Path::new(source).file_name()?.to_str()?;
+    let mut parts = name.rsplit('.');
+    parts.next()?;
+    let candidate = parts.next()?;
+    parts.next()?;
+    let is_code =
+        (2..=3).contains(&candidate.len()) && candidate.chars().all(|c| c.is_ascii_lowercase());
+    is_code.then(|| candidate.to_string())
+}
+
+/// The knowledge manifest describes files; it is not itself knowledge
+fn is_manifest(path: &Path) -> bool {
+    path.file_stem()
+        .and_then(|s| s.to_str())
+        .is_some_and(|s| s.eq_ignore_ascii_case("manifest"))
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let mut paths: Vec<PathBuf> = entries.filter_map(|e| e.ok().map(|e| e.path())).collect();
+    paths.sort();
+    for path in paths {
+        if path.is_dir() {
+            collect_files(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
+}
+
+type ParsedParts = (Option<String>, Option<String>, Vec<DocumentSection>);
+
+/// Markdown: front matter (`title:`, `language:`), `#` headings as sections
+fn parse_markdown(text: &str) -> ParsedParts {
+    let mut title = None;
+    let mut language = None;
+    let mut body = text;
+
+    if let Some(rest) = text.strip_prefix("---\n") {
+        if let Some(end) = rest.find("\n---") {
+            for line in rest[..end].lines() {
+                if let Some((key, value)) = line.split_once(':') {
+                    let value = value.trim().trim_matches('"').trim_matches('\'');
+                    match key.trim() {
+                        "title" => title = Some(value.to_string()),
+                        "language" | "lang" => language = Some(value.to_string()),
+                        _ => {},
+                    }
+                }
+            }
+            body = rest[end + 4..].trim_start_matches(['-', '\n']);
+        }
+    }
+
+    let mut sections = Vec::new();
+    let mut heading: Option<String> = None;
+    let mut current = String::new();
+    let mut in_code = false;
+
+    for line in body.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if !in_code && trimmed.starts_with('#') {
+            let level = trimmed.chars().take_while(|c| *c == '#').count();
+            let text = trimmed[level..].trim();
+            if level <= 6 && !text.is_empty() {
+                push_section(&mut sections, heading.take(), &mut current, None);
+                let text = strip_markdown_inline(text);
+                if level == 1 && title.is_none() {
+                    title = Some(text.clone());
+                }
+                heading = Some(text);
+                continue;
+            }
+        }
+        // Table separator rows carry no content
+        if trimmed.starts_with('|') && trimmed.chars().all(|c| matches!(c, '|' | '-' | ':' | ' ')) {
+            continue;
+        }
+        let line = if in_code {
+            line.to_string()
+        } else {
+            strip_markdown_inline(trimmed.trim_matches('|'))
+        };
+        current.push_str(&line);
+        current.push('\n');
+    }
+    push_section(&mut sections, heading, &mut current, None);
+
+    (title, language, sections)
+}
+
+/// Remove emphasis, inline code and link syntax, keeping the visible text
+fn strip_markdown_inline(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let chars: Vec<char> = text.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '*' | '_' | '`' => {},
+            '!' if chars.get(i + 1) == Some(&'[') => {},
+            '[' => {
+                // [text](url) -> text
+                if let Some(close) = chars[i..].iter().position(|c| *c == ']') {
+                    let close = i + close;
+                    if chars.get(close + 1) == Some(&'(') {
+                        if let Some(end) = chars[close..].iter().position(|c| *c == ')') {
+                            out.extend(&chars[i + 1..close]);
+                            i = close + end + 1;
+                            continue;
+                        }
+                    }
+                }
+                out.push('[');
+            },
+            c => out.push(c),
+        }
+        i += 1;
+    }
+    out
+}
+
+/// HTML: `<title>`/`<h1>` as title, `lang` attribute, `<h1>`–`<h3>` as sections
+fn parse_html(html: &str) -> ParsedParts {
+    let lower = html.to_ascii_lowercase();
+    let mut title = None;
+    let mut language = None;
+    let mut sections = Vec::new();
+    let mut heading: Option<String> = None;
+    let mut current = String::new();
+    let mut heading_text: Option<String> = None;
+    let mut title_text: Option<String> = None;
+
+    let mut pos = 0;
+    while pos < html.len() {
+        let Some(offset) = html[pos..].find('<') else {
+            append_text(
+                &html[pos..],
+                &mut current,
+                &mut heading_text,
+                &mut title_text,
+            );
+            break;
+        };
+        append_text(
+            &html[pos..pos + offset],
+            &mut current,
+            &mut heading_text,
+            &mut title_text,
+        );
+        let start = pos + offset;
+
+        if lower[start..].starts_with("<!--") {
+            pos = lower[start..]
+                .find("-->")
+                .map(|e| start + e + 3)
+                .unwrap_or(html.len());
+            continue;
+        }
+        let Some(end) = html[start..].find('>').map(|e| start + e) else {
+            break;
+        };
+        let tag = &lower[start + 1..end];
+        let closing = tag.starts_with('/');
+        let name: String = tag
+            .trim_start_matches('/')
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .collect();
+        pos = end + 1;
+
+        match (name.as_str(), closing) {
+            ("script" | "style" | "noscript", false) => {
+                let close = format!("</{}", name);
+                pos = lower[pos..]
+                    .find(&close)
+                    .map(|e| pos + e)
+                    .unwrap_or(html.len());
+            },
+            ("html", false) => {
+                language = attribute(&html[start + 1..end], "lang");
+            },
+            ("title", false) => title_text = Some(String::new()),
+            ("title", true) => {
+                if let Some(text) = title_text.take() {
+                    let text = collapse_whitespace(&text);
+                    if !text.is_empty() {
+                        title = Some(text);
+                    }
+                }
+            },
+            ("h1" | "h2" | "h3", false) => {
+                push_section(&mut sections, heading.take(), &mut current, None);
+                heading_text = Some(String::new());
+            },
+            ("h1" | "h2" | "h3", true) => {
+                if let Some(text) = heading_text.take() {
+                    let text = collapse_whitespace(&text);
+                    if name == "h1" && title.is_none() {
+                        title = Some(text.clone());
+                    }
+                    if !text.is_empty() {
+                        heading = Some(text);
+                    }
+                }
+            },
+            (
+                "p" | "div" | "br" | "li" | "tr" | "ul" | "ol" | "table" | "section" | "article"
+                | "h4" | "h5" | "h6",
+                _,
+            ) => {
+                if name == "li" && !closing {
+                    current.push_str("\n- ");
+                } else {
+                    current.push('\n');
+                }
+            },
+            ("td" | "th", true) => current.push_str(" | "),
+            _ => {},
+        }
+    }
+    push_section(&mut sections, heading, &mut current, None);
+
+    for section in &mut sections {
+        section.text = section
+            .text
+            .lines()
+            .map(collapse_whitespace)
+            .filter(|l| !l.is_empty() && l != "-")
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+    (title, language, sections)
+}
+
+fn append_text(
+    raw: &str,
+    current: &mut String,
+    heading: &mut Option<String>,
+    title: &mut Option<String>,
+) {
+    let text = decode_entities(raw);
+    if let Some(title) = title {
+        title.push_str(&text);
+    } else if let Some(heading) = heading {
+        heading.push_str(&text);
+    } else {
+        current.push_str(&text);
+    }
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let lower = tag.to_ascii_lowercase();
+    let start = lower.find(&format!("{}=", name))? + name.len() + 1;
+    let value = &tag[start..];
+    let value = match value.chars().next()? {
+        quote @ ('"' | '\'') => value[1..].split(quote).next()?,
+        _ => value
+            .split(|c: char| c.is_whitespace() || c == '>')
+            .next()?,
+    };
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let decoded = rest.find(';').filter(|&end| end <= 10).and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" | "#39" => Some('\''),
+                "nbsp" => Some(' '),
+                "rsquo" | "lsquo" => Some('\''),
+                "rupee" => Some('₹'),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+            c.map(|c| (c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            },
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            },
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn push_section(
+    sections: &mut Vec<DocumentSection>,
+    heading: Option<String>,
+    text: &mut String,
+    page: Option<u32>,
+) {
+    let body = std::mem::take(text);
+    if body.trim().is_empty() {
+        return;
+    }
+    sections.push(DocumentSection {
+        heading,
+        text: body.trim().to_string(),
+        page,
+    });
+}
+
+/// PDF: one section per page (pages are separated by form feeds)
+#[cfg(feature = "pdf")]
+fn parse_pdf(bytes: &[u8]) -> Result<Vec<DocumentSection>, RagError> {
+    let text = pdf_extract::extract_text_from_mem(bytes)
+        .map_err(|e| RagError::Index(format!("PDF parse error: {}", e)))?;
+    Ok(text
+        .split('\u{c}')
+        .enumerate()
+        .filter(|(_, page)| !page.trim().is_empty())
+        .map(|(i, page)| DocumentSection {
+            heading: None,
+            text: page.trim().to_string(),
+            page: Some(i as u32 + 1),
+        })
+        .collect())
+}
+
+#[cfg(not(feature = "pdf"))]
+fn parse_pdf(_bytes: &[u8]) -> Result<Vec<DocumentSection>, RagError> {
+    Err(RagError::Index(
+        "PDF ingestion requires the `pdf` feature".to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::knowledge_base::KnowledgeBaseConfig;
+
+    #[test]
+    fn test_parse_markdown_sections() {
+        let md = "---\nlanguage: hi\n---\n# Gold Loan Rates\nIntro **text**.\n\n## Rate Tiers\n| Amount | Rate |\n|---|---|\n| Up to 1 lakh | 11.5% |\nSee [the FAQ](https://example.com).\n";
+        let (title, language, sections) = parse_markdown(md);
+        assert_eq!(title.as_deref(), Some("Gold Loan Rates"));
+        assert_eq!(language.as_deref(), Some("hi"));
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].text, "Intro text.");
+        assert_eq!(sections[1].heading.as_deref(), Some("Rate Tiers"));
+        assert!(sections[1].text.contains("Up to 1 lakh | 11.5%"));
+        assert!(sections[1].text.contains("See the FAQ."));
+    }
+
+    #[test]
+    fn test_parse_html_sections() {
+        let html = r#"<html lang="en"><head><title>Rates &amp; Fees</title><style>p{}</style></head>
+            <body><h1>Gold Loan</h1><p>Processing fee is 1%.</p><script>var x = "<p>";</script>
+            <h2>Tenure</h2><ul><li>6 months</li><li>12 months</li></ul></body></html>"#;
+        let (title, language, sections) = parse_html(html);
+        assert_eq!(title.as_deref(), Some("Rates & Fees"));
+        assert_eq!(language.as_deref(), Some("en"));
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].heading.as_deref(), Some("Gold Loan"));
+        assert_eq!(sections[0].text, "Processing fee is 1%.");
+        assert_eq!(sections[1].text, "- 6 months\n- 12 months");
+    }
+
+    #[test]
+    fn test_language_from_file_name() {
+        assert_eq!(
+            language_from_source("kb/rates.hi.md").as_deref(),
+            Some("hi")
+        );
+        assert_eq!(language_from_source("kb/rates.md"), None);
+        assert_eq!(language_from_source("kb/v1.2.md"), None);
+    }
+
+    #[test]
+    fn test_ingest_directory_per_domain() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("faq.md"),
+            "# FAQ\n## Documents\nBring Aadhaar and PAN for KYC.\n",
+        )
+        .unwrap();
+        std::fs::create_dir(dir.path().join("car_loan")).unwrap();
+        std::fs::write(
+            dir.path().join("car_loan").join("rates.hi.html"),
+            "<h1>Car Loan</h1><p>Byaj dar 8.5% se shuru.</p>",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("manifest.yaml"), "files: []\n").unwrap();
+
+        let kb = KnowledgeBase::new(KnowledgeBaseConfig::default());
+        let pipeline = IngestionPipeline::new(IngestionConfig::default());
+        let report = pipeline
+            .ingest_directory(&kb, dir.path(), "gold_loan")
+            .unwrap();
+        assert_eq!(report.documents, 2);
+        assert!(report.failed.is_empty());
+        assert_eq!(kb.domains(), vec!["car_loan", "gold_loan"]);
+
+        let hits = kb
+            .retrieve("gold_loan", "which documents for KYC", Some("en"), 3)
+            .unwrap();
+        assert_eq!(hits.len(), 1);
+        let citation = &hits[0].chunk.citation;
+        assert_eq!(citation.document_id, "gold_loan/faq");
+        assert_eq!(citation.section.as_deref(), Some("Documents"));
+
+        let hits = kb.retrieve("car_loan", "byaj dar", None, 3).unwrap();
+        assert_eq!(hits[0].chunk.language, "hi");
+    }
+}
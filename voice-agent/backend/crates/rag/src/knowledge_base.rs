@@ -0,0 +1,494 @@
+//! Knowledge Base
+//!
+//! Per-domain indices over ingested product sheets, policies and FAQs.
+//! Each chunk carries citation metadata (document, section, page) so the
+//! agent can ground answers and the transcript can show where a fact came
+//! from.
+//!
+//! Retrieval blends BM25 (Tantivy) with dense cosine similarity when an
+//! embedder is available, and prefers chunks in the caller's language.
+
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::embeddings::{cosine_similarity, Embedder};
+use crate::sparse_search::{SparseConfig, SparseIndex};
+use crate::vector_store::Document;
+use crate::RagError;
+
+/// Where a chunk came from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Citation {
+    /// Source document ID
+    pub document_id: String,
+    /// Document title
+    pub title: String,
+    /// File path or URL the document was ingested from
+    pub source: String,
+    /// Section heading, if the document had headings
+    pub section: Option<String>,
+    /// Page number (PDF only, 1-based)
+    pub page: Option<u32>,
+    /// Position of the chunk within the document
+    pub chunk_index: usize,
+}
+
+impl Citation {
+    /// Short human-readable label, e.g. `Gold Loan Rates › Rate Tiers, p. 2`
+    pub fn label(&self) -> String {
+        let mut label = self.title.clone();
+        if let Some(ref section) = self.section {
+            if section != &self.title {
+                label.push_str(" › ");
+                label.push_str(section);
+            }
+        }
+        if let Some(page) = self.page {
+            label.push_str(&format!(", p. {}", page));
+        }
+        label
+    }
+}
+
+/// A retrievable unit of knowledge
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KnowledgeChunk {
+    /// Unique chunk ID (`<document_id>#<chunk_index>`)
+    pub id: String,
+    /// Language code (e.g., "en", "hi")
+    pub language: String,
+    /// Chunk text (with title/section prefix)
+    pub text: String,
+    /// Citation metadata
+    pub citation: Citation,
+}
+
+/// A retrieved chunk with its blended score
+#[derive(Debug, Clone)]
+pub struct KnowledgeHit {
+    pub chunk: KnowledgeChunk,
+    /// Blended relevance score (0.0 - 1.0)
+    pub score: f32,
+}
+
+/// Knowledge base retrieval configuration
+#[derive(Debug, Clone)]
+pub struct KnowledgeBaseConfig {
+    /// Weight of dense similarity vs BM25 (0.0 = BM25 only)
+    pub dense_weight: f32,
+    /// Minimum blended score to return a hit
+    pub min_score: f32,
+    /// Results returned when the caller passes `top_k = 0`
+    pub default_top_k: usize,
+    /// Score multiplier for chunks not in the requested language
+    pub language_mismatch_penalty: f32,
+}
+
+impl Default for KnowledgeBaseConfig {
+    fn default() -> Self {
+        Self {
+            dense_weight: 0.6,
+            min_score: 0.05,
+            default_top_k: 4,
+            language_mismatch_penalty: 0.85,
+        }
+    }
+}
+
+struct StoredChunk {
+    chunk: KnowledgeChunk,
+    embedding: Option<Vec<f32>>,
+}
+
+/// Index for a single domain
+struct DomainIndex {
+    chunks: HashMap<String, StoredChunk>,
+    sparse: SparseIndex,
+}
+
+impl DomainIndex {
+    fn new() -> Result<Self, RagError> {
+        Ok(Self {
+            chunks: HashMap::new(),
+            sparse: SparseIndex::new(SparseConfig::default())?,
+        })
+    }
+
+    fn remove_document(&mut self, document_id: &str) -> Result<usize, RagError> {
+        let ids: Vec<String> = self
+            .chunks
+            .values()
+            .filter(|c| c.chunk.citation.document_id == document_id)
+            .map(|c| c.chunk.id.clone())
+            .collect();
+        if ids.is_empty() {
+            return Ok(0);
+        }
+        self.sparse.delete(&ids)?;
+        for id in &ids {
+            self.chunks.remove(id);
+        }
+        Ok(ids.len())
+    }
+}
+
+/// Knowledge base with one index per domain
+pub struct KnowledgeBase {
+    config: KnowledgeBaseConfig,
+    embedder: Option<Arc<Embedder>>,
+    domains: RwLock<HashMap<String, DomainIndex>>,
+}
+
+impl KnowledgeBase {
+    /// Create an empty knowledge base (BM25 only until an embedder is set)
+    pub fn new(config: KnowledgeBaseConfig) -> Self {
+        Self {
+            config,
+            embedder: None,
+            domains: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Use an embedder for dense retrieval
+    pub fn with_embedder(mut self, embedder: Arc<Embedder>) -> Self {
+        self.embedder = Some(embedder);
+        self
+    }
+
+    /// Add chunks to a domain index
+    ///
+    /// Chunks of a document that is already indexed replace its previous
+    /// chunks, so re-ingesting an edited sheet does not leave stale text.
+    pub fn add_chunks(&self, domain: &str, chunks: Vec<KnowledgeChunk>) -> Result<usize, RagError> {
+        if chunks.is_empty() {
+            return Ok(0);
+        }
+
+        let embeddings: Vec<Option<Vec<f32>>> = match self.embedder {
+            Some(ref embedder) => {
+                let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+                embedder
+                    .embed_batch(&texts)?
+                    .into_iter()
+                    .map(Some)
+                    .collect()
+            },
+            None => vec![None; chunks.len()],
+        };
+
+        let mut domains = self.domains.write();
+        let index = match domains.entry(domain.to_string()) {
+            std::collections::hash_map::Entry::Occupied(e) => e.into_mut(),
+            std::collections::hash_map::Entry::Vacant(e) => e.insert(DomainIndex::new()?),
+        };
+
+        let mut document_ids: Vec<&str> = chunks
+            .iter()
+            .map(|c| c.citation.document_id.as_str())
+            .collect();
+        document_ids.sort_unstable();
+        document_ids.dedup();
+        for document_id in document_ids {
+            index.remove_document(document_id)?;
+        }
+
+        let documents: Vec<Document> = chunks
+            .iter()
+            .map(|c| Document {
+                id: c.id.clone(),
+                content: c.text.clone(),
+                title: Some(c.citation.title.clone()),
+                category: c.citation.section.clone(),
+                language: Some(c.language.clone()),
+                metadata: HashMap::new(),
+            })
+            .collect();
+        index.sparse.index_documents(&documents)?;
+
+        let count = chunks.len();
+        for (chunk, embedding) in chunks.into_iter().zip(embeddings) {
+            index
+                .chunks
+                .insert(chunk.id.clone(), StoredChunk { chunk, embedding });
+        }
+        Ok(count)
+    }
+
+    /// Remove a document from a domain index, returning the chunks removed
+    pub fn remove_document(&self, domain: &str, document_id: &str) -> Result<usize, RagError> {
+        match self.domains.write().get_mut(domain) {
+            Some(index) => index.remove_document(document_id),
+            None => Ok(0),
+        }
+    }
+
+    /// Domains with at least one index
+    pub fn domains(&self) -> Vec<String> {
+        let mut domains: Vec<String> = self.domains.read().keys().cloned().collect();
+        domains.sort();
+        domains
+    }
+
+    /// Number of chunks indexed for a domain
+    pub fn chunk_count(&self, domain: &str) -> usize {
+        self.domains
+            .read()
+            .get(domain)
+            .map(|index| index.chunks.len())
+            .unwrap_or(0)
+    }
+
+    /// Whether nothing has been ingested
+    pub fn is_empty(&self) -> bool {
+        self.domains
+            .read()
+            .values()
+            .all(|index| index.chunks.is_empty())
+    }
+
+    /// Retrieve the most relevant chunks for a query
+    ///
+    /// `language` is a preference, not a filter: chunks in other languages
+    /// are still returned when nothing better matches. Unknown domains
+    /// return no hits.
+    pub fn retrieve(
+        &self,
+        domain: &str,
+        query: &str,
+        language: Option<&str>,
+        top_k: usize,
+    ) -> Result<Vec<KnowledgeHit>, RagError> {
+        let top_k = if top_k == 0 {
+            self.config.default_top_k
+        } else {
+            top_k
+        };
+        let query = query.trim();
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let domains = self.domains.read();
+        let Some(index) = domains.get(domain) else {
+            return Ok(Vec::new());
+        };
+        if index.chunks.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Over-fetch so language preference and blending can reorder
+        let candidates = (top_k * 4).max(20);
+
+        let mut sparse_scores: HashMap<String, f32> = HashMap::new();
+        let sanitized = sanitize_query(query);
+        if !sanitized.is_empty() {
+            let results = index.sparse.search(&sanitized, Some(candidates))?;
+            let max = results.iter().map(|r| r.score).fold(0.0f32, f32::max);
+            if max > 0.0 {
+                for result in results {
+                    sparse_scores.insert(result.id, result.score / max);
+                }
+            }
+        }
+
+        let query_embedding = match self.embedder {
+            Some(ref embedder) => Some(embedder.embed(query)?),
+            None => None,
+        };
+        let dense_weight = if query_embedding.is_some() {
+            self.config.dense_weight.clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        let mut hits: Vec<KnowledgeHit> = index
+            .chunks
+            .values()
+            .filter_map(|stored| {
+                let sparse = sparse_scores.get(&stored.chunk.id).copied().unwrap_or(0.0);
+                let dense = match (&query_embedding, &stored.embedding) {
+                    (Some(q), Some(e)) => cosine_similarity(q, e).max(0.0),
+                    _ => 0.0,
+                };
+                let mut score = dense_weight * dense + (1.0 - dense_weight) * sparse;
+                if let Some(lang) = language {
+                    if !stored.chunk.language.eq_ignore_ascii_case(lang) {
+                        score *= self.config.language_mismatch_penalty;
+                    }
+                }
+                (score >= self.config.min_score && score > 0.0).then(|| KnowledgeHit {
+                    chunk: stored.chunk.clone(),
+                    score,
+                })
+            })
+            .collect();
+
+        hits.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.chunk.id.cmp(&b.chunk.id))
+        });
+        hits.truncate(top_k);
+        Ok(hits)
+    }
+}
+
+/// Format hits as a prompt context block with numbered citations
+///
+/// ```text
+/// - [1] Rates are 9.5% to 11.5% per annum.
+///
+/// Sources:
+/// [1] Gold Loan Rates › Rate Tiers (knowledge/gold_loan/rates.md)
+/// ```
+pub fn format_knowledge_context(hits: &[KnowledgeHit]) -> String {
+    if hits.is_empty() {
+        return String::new();
+    }
+
+    let mut sources: Vec<&Citation> = Vec::new();
+    let mut lines = Vec::with_capacity(hits.len());
+    for hit in hits {
+        let citation = &hit.chunk.citation;
+        let number = match sources.iter().position(|c| {
+            c.document_id == citation.document_id
+                && c.section == citation.section
+                && c.page == citation.page
+        }) {
+            Some(pos) => pos + 1,
+            None => {
+                sources.push(citation);
+                sources.len()
+            },
+        };
+        lines.push(format!("- [{}] {}", number, hit.chunk.text.trim()));
+    }
+
+    let mut context = lines.join("\n");
+    context.push_str("\n\nSources:");
+    for (i, citation) in sources.iter().enumerate() {
+        context.push_str(&format!(
+            "\n[{}] {} ({})",
+            i + 1,
+            citation.label(),
+            citation.source
+        ));
+    }
+    context
+}
+
+/// Strip query-parser syntax so free-form user text never fails to parse
+fn sanitize_query(query: &str) -> String {
+    query
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c.is_whitespace() {
+                c
+            } else {
+                ' '
+            }
+        })
+        .collect::<String>()
+        .split_whitespace()
+        .filter(|w| !matches!(*w, "AND" | "OR" | "NOT"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk(doc: &str, index: usize, language: &str, text: &str) -> KnowledgeChunk {
+        KnowledgeChunk {
+            id: format!("{}#{}", doc, index),
+            language: language.to_string(),
+            text: text.to_string(),
+            citation: Citation {
+                document_id: doc.to_string(),
+                title: "Gold Loan Rates".to_string(),
+                source: format!("knowledge/gold_loan/{}.md", doc),
+                section: Some("Rate Tiers".to_string()),
+                page: None,
+                chunk_index: index,
+            },
+        }
+    }
+
+    #[test]
+    fn test_retrieve_prefers_requested_language() {
+        let kb = KnowledgeBase::new(KnowledgeBaseConfig::default());
+        kb.add_chunks(
+            "gold_loan",
+            vec![
+                chunk(
+                    "rates",
+                    0,
+                    "en",
+                    "Interest rate for gold loan is 9.5 percent",
+                ),
+                chunk(
+                    "rates_hi",
+                    0,
+                    "hi",
+                    "gold loan interest rate 9.5 percent hai",
+                ),
+                chunk("process", 0, "en", "Bring your KYC documents to the branch"),
+            ],
+        )
+        .unwrap();
+
+        let hits = kb
+            .retrieve("gold_loan", "gold loan interest rate?", Some("hi"), 2)
+            .unwrap();
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].chunk.id, "rates_hi#0");
+
+        assert!(kb
+            .retrieve("unknown", "interest", None, 2)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_reingest_replaces_document() {
+        let kb = KnowledgeBase::new(KnowledgeBaseConfig::default());
+        kb.add_chunks(
+            "gold_loan",
+            vec![
+                chunk("rates", 0, "en", "old rate"),
+                chunk("rates", 1, "en", "more"),
+            ],
+        )
+        .unwrap();
+        kb.add_chunks("gold_loan", vec![chunk("rates", 0, "en", "new rate")])
+            .unwrap();
+        assert_eq!(kb.chunk_count("gold_loan"), 1);
+
+        let hits = kb.retrieve("gold_loan", "rate", None, 5).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].chunk.text, "new rate");
+    }
+
+    #[test]
+    fn test_format_knowledge_context_numbers_sources() {
+        let hits = vec![
+            KnowledgeHit {
+                chunk: chunk("rates", 0, "en", "Rate is 9.5%"),
+                score: 0.9,
+            },
+            KnowledgeHit {
+                chunk: chunk("rates", 1, "en", "Women get 9%"),
+                score: 0.8,
+            },
+        ];
+        let context = format_knowledge_context(&hits);
+        assert!(context.contains("- [1] Rate is 9.5%"));
+        assert!(context.contains("- [1] Women get 9%"));
+        assert!(context.contains("[1] Gold Loan Rates › Rate Tiers (knowledge/gold_loan/rates.md)"));
+        assert!(!context.contains("[2]"));
+    }
+}
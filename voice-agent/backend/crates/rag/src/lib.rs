@@ -13,6 +13,7 @@
 //! - Domain-specific term boosting
 //! - Cross-lingual query normalization (Hindi/Hinglish/English)
 //! - Core Retriever trait implementation
+//! - Knowledge base ingestion (Markdown/HTML/PDF) with per-domain indices and citations
 
 pub mod adapter;
 pub mod agentic;
@@ -34,6 +35,9 @@ pub mod knowledge_loader;
 pub mod compressor;
 // Phase 4: Semantic chunking for improved RAG
 pub mod chunker;
+// Knowledge base: document ingestion and per-domain retrieval with citations
+pub mod ingest;
+pub mod knowledge_base;
 
 pub use adapter::{EnhancedRetriever, EnhancedRetrieverConfig};
 pub use agentic::{
@@ -64,7 +68,7 @@ pub use domain_boost::{
     BoostResult, DomainBoostConfig, DomainBooster, DomainTerm, MatchedTerm, QueryIntent,
     TermCategory,
 };
-pub use embeddings::{Embedder, EmbeddingConfig, SimpleEmbedder};
+pub use embeddings::{cosine_similarity, Embedder, EmbeddingConfig, SimpleEmbedder};
 pub use knowledge_loader::{KnowledgeDocument, KnowledgeFile, KnowledgeLoader};
 pub use query_expansion::{
     ExpandedQuery, ExpansionStats, QueryExpander, QueryExpansionConfig, TermSource, WeightedTerm,
@@ -79,6 +83,15 @@ pub use compressor::{
 };
 // Semantic chunking exports
 pub use chunker::{Chunk, ChunkConfig, ChunkStrategy, SemanticChunker};
+// Knowledge base exports
+pub use ingest::{
+    parse_document, parse_knowledge_file, DocumentFormat, DocumentSection, IngestionConfig,
+    IngestionPipeline, IngestionReport, ParsedDocument,
+};
+pub use knowledge_base::{
+    format_knowledge_context, Citation, KnowledgeBase, KnowledgeBaseConfig, KnowledgeChunk,
+    KnowledgeHit,
+};
 
 use thiserror::Error;
 
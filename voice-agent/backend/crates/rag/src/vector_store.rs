@@ -5,8 +5,8 @@
 use qdrant_client::{
     qdrant::{
         value::Kind, Condition, CreateCollectionBuilder, DeletePointsBuilder, Distance,
-        FieldCondition, Filter, Match, PointId, PointStruct, PointsIdsList, SearchPointsBuilder,
-        UpsertPointsBuilder, VectorParamsBuilder,
+        FieldCondition, Filter, Match, PointId, PointStruct, PointsIdsList, ScrollPointsBuilder,
+        SearchPointsBuilder, UpsertPointsBuilder, VectorParamsBuilder,
     },
     Qdrant,
 };
@@ -235,33 +235,9 @@ impl VectorStore {
             .result
             .into_iter()
             .map(|point| {
-                let mut metadata = HashMap::new();
-                let mut content = String::new();
-
-                for (k, v) in point.payload {
-                    // P2-2 FIX: "text" key in Qdrant maps to `content` field
-                    if k == "text" {
-                        if let Some(Kind::StringValue(s)) = v.kind {
-                            content = s;
-                        }
-                    } else if let Some(Kind::StringValue(s)) = v.kind {
-                        metadata.insert(k, s);
-                    }
-                }
-
-                let id = point
-                    .id
-                    .map(|pid| match pid.point_id_options {
-                        Some(qdrant_client::qdrant::point_id::PointIdOptions::Uuid(u)) => u,
-                        Some(qdrant_client::qdrant::point_id::PointIdOptions::Num(n)) => {
-                            n.to_string()
-                        },
-                        None => String::new(),
-                    })
-                    .unwrap_or_default();
-
+                let (content, metadata) = parse_payload(point.payload);
                 VectorSearchResult {
-                    id,
+                    id: point_id_string(point.id),
                     score: point.score,
                     content,
                     metadata,
@@ -272,6 +248,44 @@ impl VectorStore {
         Ok(search_results)
     }
 
+    /// List points matching a filter, without scoring
+    ///
+    /// Returned results carry a score of 0.0. Used to reload stored
+    /// documents (e.g. archival memories) after a restart.
+    pub async fn scroll(
+        &self,
+        filter: Option<SearchFilter>,
+        limit: usize,
+    ) -> Result<Vec<VectorSearchResult>, RagError> {
+        let mut scroll_builder = ScrollPointsBuilder::new(&self.config.collection)
+            .limit(limit as u32)
+            .with_payload(true);
+
+        if let Some(f) = filter {
+            scroll_builder = scroll_builder.filter(f.into_qdrant());
+        }
+
+        let response = self
+            .client
+            .scroll(scroll_builder)
+            .await
+            .map_err(|e| RagError::Search(e.to_string()))?;
+
+        Ok(response
+            .result
+            .into_iter()
+            .map(|point| {
+                let (content, metadata) = parse_payload(point.payload);
+                VectorSearchResult {
+                    id: point_id_string(point.id),
+                    score: 0.0,
+                    content,
+                    metadata,
+                }
+            })
+            .collect())
+    }
+
     /// Delete by IDs
     pub async fn delete(&self, ids: &[String]) -> Result<(), RagError> {
         let points: Vec<PointId> = ids.iter().map(|id| PointId::from(id.clone())).collect();
@@ -308,6 +322,37 @@ impl VectorStore {
     }
 }
 
+/// Split a Qdrant payload into content and string metadata
+fn parse_payload(
+    payload: HashMap<String, qdrant_client::qdrant::Value>,
+) -> (String, HashMap<String, String>) {
+    let mut metadata = HashMap::new();
+    let mut content = String::new();
+
+    for (k, v) in payload {
+        // P2-2 FIX: "text" key in Qdrant maps to `content` field
+        if k == "text" {
+            if let Some(Kind::StringValue(s)) = v.kind {
+                content = s;
+            }
+        } else if let Some(Kind::StringValue(s)) = v.kind {
+            metadata.insert(k, s);
+        }
+    }
+
+    (content, metadata)
+}
+
+/// Render a Qdrant point ID as a string
+fn point_id_string(id: Option<PointId>) -> String {
+    id.map(|pid| match pid.point_id_options {
+        Some(qdrant_client::qdrant::point_id::PointIdOptions::Uuid(u)) => u,
+        Some(qdrant_client::qdrant::point_id::PointIdOptions::Num(n)) => n.to_string(),
+        None => String::new(),
+    })
+    .unwrap_or_default()
+}
+
 /// Collection info
 #[derive(Debug, Clone)]
 pub struct CollectionInfo {
@@ -343,9 +388,31 @@ impl SearchFilter {
         self
     }
 
+    /// Require an exact match on a payload field
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
     fn into_qdrant(self) -> Filter {
         let mut conditions = Vec::new();
 
+        for (key, value) in self.metadata {
+            conditions.push(Condition {
+                condition_one_of: Some(qdrant_client::qdrant::condition::ConditionOneOf::Field(
+                    FieldCondition {
+                        key,
+                        r#match: Some(Match {
+                            match_value: Some(qdrant_client::qdrant::r#match::MatchValue::Keyword(
+                                value,
+                            )),
+                        }),
+                        ..Default::default()
+                    },
+                )),
+            });
+        }
+
         if let Some(category) = self.category {
             conditions.push(Condition {
                 condition_one_of: Some(qdrant_client::qdrant::condition::ConditionOneOf::Field(
@@ -408,5 +475,8 @@ mod tests {
 
         assert_eq!(filter.category, Some("product".to_string()));
         assert_eq!(filter.language, Some("hi".to_string()));
+
+        let filter = SearchFilter::new().metadata("session_id", "s1");
+        assert_eq!(filter.metadata.get("session_id").map(String::as_str), Some("s1"));
     }
 }
@@ -0,0 +1,590 @@
+//! Admin API
+//!
+//! Operator views over the persisted entities, so routine lookups and fixes
+//! don't need hand-written CQL:
+//! - `GET /admin/sessions`, `GET|DELETE /admin/sessions/:id`
+//! - `GET /admin/appointments?date=...` or `?phone=...`, and
+//!   `POST /admin/appointments/:phone/:id/status`
+//! - `GET /admin/sms?phone=...`
+//! - `GET /admin/webhooks/deliveries?date=...`
+//! - `GET /admin/audit` (filtered) and `GET /admin/audit/:session_id/verify`
+//! - `GET /admin/branches`, `GET|POST /admin/branches/:id/slots`
+//!
+//! Competitor rates live under `/admin/competitor-rates` (see `http.rs`).
+//! All `/admin` routes require `server.auth.admin_api_key` and are refused
+//! when it is not set; changes are written to the audit trail.
+
+use axum::{
+    extract::{Json, Path, Query, State},
+    http::StatusCode,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use voice_agent_persistence::{AppointmentStatus, AuditEventType, AuditQuery};
+use voice_agent_tools::configured_time_slots;
+
+use crate::state::AppState;
+
+/// Rows returned when `limit` is omitted
+const DEFAULT_LIMIT: usize = 50;
+/// Most rows one request may ask for
+const MAX_LIMIT: usize = 500;
+
+type ApiResponse = (StatusCode, Json<serde_json::Value>);
+
+fn error(status: StatusCode, message: impl ToString) -> ApiResponse {
+    (
+        status,
+        Json(serde_json::json!({ "status": "error", "message": message.to_string() })),
+    )
+}
+
+fn disabled(what: &str) -> ApiResponse {
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(serde_json::json!({
+            "status": "disabled",
+            "message": format!("{} requires persistence", what)
+        })),
+    )
+}
+
+fn limit(requested: Option<usize>) -> usize {
+    requested.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT)
+}
+
+/// Record an admin change in the audit trail
+async fn audit(
+    state: &AppState,
+    resource_type: &str,
+    resource_id: &str,
+    action: &str,
+    details: serde_json::Value,
+) {
+    let Some(ref logger) = state.audit_logger else {
+        return;
+    };
+    if let Err(e) = logger
+        .log_admin_action(resource_type, resource_id, action, details)
+        .await
+    {
+        tracing::warn!(resource_type, resource_id, action, error = %e, "Failed to audit admin action");
+    }
+}
+
+/// List query parameters
+#[derive(Debug, Default, Deserialize)]
+pub struct ListQuery {
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+/// Stored sessions, marked live if this instance is serving them
+///
+/// GET /admin/sessions?limit=...
+pub async fn list_sessions(
+    State(state): State<AppState>,
+    Query(query): Query<ListQuery>,
+) -> ApiResponse {
+    let ids = match state.session_store.list_ids().await {
+        Ok(ids) => ids,
+        Err(e) => {
+            tracing::error!("Failed to list sessions: {}", e);
+            return error(StatusCode::INTERNAL_SERVER_ERROR, e);
+        },
+    };
+
+    let total = ids.len();
+    let mut sessions = Vec::new();
+    for id in ids.into_iter().take(limit(query.limit)) {
+        match state.session_store.get_metadata(&id).await {
+            Ok(Some(metadata)) => sessions.push(serde_json::json!({
+                "live": state.sessions.get(&id).is_some(),
+                "session": metadata,
+            })),
+            Ok(None) => {},
+            Err(e) => tracing::warn!(session_id = %id, error = %e, "Failed to load session"),
+        }
+    }
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({ "sessions": sessions, "total": total })),
+    )
+}
+
+/// A session's stored metadata and, if live, its current state
+///
+/// GET /admin/sessions/:id
+pub async fn get_session(State(state): State<AppState>, Path(id): Path<String>) -> ApiResponse {
+    let metadata = match state.session_store.get_metadata(&id).await {
+        Ok(metadata) => metadata,
+        Err(e) => {
+            tracing::error!(session_id = %id, "Failed to load session: {}", e);
+            return error(StatusCode::INTERNAL_SERVER_ERROR, e);
+        },
+    };
+    let live = state.sessions.get(&id).map(|session| {
+        serde_json::json!({
+            "active": session.is_active(),
+            "stage": session.agent.stage().display_name(),
+            "turn_count": session.agent.conversation().turn_count(),
+            "usage": session.agent.session_usage(),
+        })
+    });
+    if metadata.is_none() && live.is_none() {
+        return error(StatusCode::NOT_FOUND, "Session not found");
+    }
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({ "session": metadata, "live": live })),
+    )
+}
+
+/// End a session if live and delete its stored metadata
+///
+/// DELETE /admin/sessions/:id
+pub async fn delete_session(State(state): State<AppState>, Path(id): Path<String>) -> ApiResponse {
+    let live = state.sessions.get(&id).is_some();
+    // Removal closes the session and runs the usual finalization
+    state.sessions.remove(&id);
+    if let Err(e) = state.session_store.delete_metadata(&id).await {
+        tracing::error!(session_id = %id, "Failed to delete session: {}", e);
+        return error(StatusCode::INTERNAL_SERVER_ERROR, e);
+    }
+    audit(
+        &state,
+        "session",
+        &id,
+        "delete_session",
+        serde_json::json!({ "was_live": live }),
+    )
+    .await;
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({ "status": "success", "was_live": live })),
+    )
+}
+
+/// Appointment query parameters; one of `date` or `phone` is required
+#[derive(Debug, Deserialize)]
+pub struct AppointmentQuery {
+    #[serde(default)]
+    date: Option<NaiveDate>,
+    #[serde(default)]
+    phone: Option<String>,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+/// Appointments on a date or for a customer
+///
+/// GET /admin/appointments?date=YYYY-MM-DD or ?phone=...
+pub async fn list_appointments(
+    State(state): State<AppState>,
+    Query(query): Query<AppointmentQuery>,
+) -> ApiResponse {
+    let Some(ref store) = state.appointments else {
+        return disabled("Appointment lookup");
+    };
+
+    let appointments = match (query.date, query.phone.as_deref()) {
+        (Some(date), _) => store.list_for_date(date).await,
+        (None, Some(phone)) => {
+            store
+                .list_for_customer(phone, limit(query.limit) as i32)
+                .await
+        },
+        (None, None) => {
+            return error(StatusCode::BAD_REQUEST, "Pass a date or a phone number");
+        },
+    };
+
+    match appointments {
+        Ok(appointments) => (
+            StatusCode::OK,
+            Json(serde_json::json!({
+                "count": appointments.len(),
+                "appointments": appointments,
+            })),
+        ),
+        Err(e) => {
+            tracing::error!("Failed to list appointments: {}", e);
+            error(StatusCode::INTERNAL_SERVER_ERROR, e)
+        },
+    }
+}
+
+/// Appointment status change
+#[derive(Debug, Deserialize)]
+pub struct AppointmentStatusRequest {
+    status: AppointmentStatus,
+}
+
+/// Change an appointment's status (confirm, cancel, mark completed or no-show)
+///
+/// POST /admin/appointments/:phone/:id/status
+pub async fn update_appointment_status(
+    State(state): State<AppState>,
+    Path((phone, id)): Path<(String, Uuid)>,
+    Json(request): Json<AppointmentStatusRequest>,
+) -> ApiResponse {
+    let Some(ref store) = state.appointments else {
+        return disabled("Appointment lookup");
+    };
+
+    let previous = match store.get(&phone, id).await {
+        Ok(Some(appointment)) => appointment.status,
+        Ok(None) => return error(StatusCode::NOT_FOUND, "Appointment not found"),
+        Err(e) => {
+            tracing::error!(appointment_id = %id, "Failed to load appointment: {}", e);
+            return error(StatusCode::INTERNAL_SERVER_ERROR, e);
+        },
+    };
+    if let Err(e) = store.update_status(&phone, id, request.status).await {
+        tracing::error!(appointment_id = %id, "Failed to update appointment: {}", e);
+        return error(StatusCode::INTERNAL_SERVER_ERROR, e);
+    }
+    audit(
+        &state,
+        "appointment",
+        &id.to_string(),
+        "update_appointment_status",
+        serde_json::json!({
+            "from": previous.as_str(),
+            "to": request.status.as_str(),
+        }),
+    )
+    .await;
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({ "status": "success", "appointment_status": request.status })),
+    )
+}
+
+/// SMS history query parameters
+#[derive(Debug, Deserialize)]
+pub struct SmsQuery {
+    phone: String,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+/// Messages sent to a customer, newest first
+///
+/// GET /admin/sms?phone=...&limit=...
+pub async fn list_sms(State(state): State<AppState>, Query(query): Query<SmsQuery>) -> ApiResponse {
+    let Some(ref sms) = state.sms else {
+        return disabled("SMS history");
+    };
+
+    match sms
+        .get_messages_for_phone(&query.phone, limit(query.limit) as i32)
+        .await
+    {
+        Ok(messages) => (
+            StatusCode::OK,
+            Json(serde_json::json!({ "count": messages.len(), "messages": messages })),
+        ),
+        Err(e) => {
+            tracing::error!("Failed to load SMS history: {}", e);
+            error(StatusCode::INTERNAL_SERVER_ERROR, e)
+        },
+    }
+}
+
+/// Webhook delivery log query parameters
+#[derive(Debug, Deserialize)]
+pub struct WebhookDeliveryQuery {
+    /// Day (UTC) the deliveries were created; today when omitted
+    #[serde(default)]
+    date: Option<NaiveDate>,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+/// Webhook deliveries of a day with their status and attempts, newest first
+///
+/// GET /admin/webhooks/deliveries?date=YYYY-MM-DD&limit=...
+pub async fn list_webhook_deliveries(
+    State(state): State<AppState>,
+    Query(query): Query<WebhookDeliveryQuery>,
+) -> ApiResponse {
+    let Some(ref log) = state.webhook_deliveries else {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(serde_json::json!({
+                "status": "disabled",
+                "message": "Webhooks are disabled"
+            })),
+        );
+    };
+
+    let date = query.date.unwrap_or_else(|| Utc::now().date_naive());
+    match log.list_for_day(date, limit(query.limit)).await {
+        Ok(deliveries) => (
+            StatusCode::OK,
+            Json(serde_json::json!({
+                "date": date,
+                "count": deliveries.len(),
+                "deliveries": deliveries,
+            })),
+        ),
+        Err(e) => {
+            tracing::error!("Failed to list webhook deliveries: {}", e);
+            error(StatusCode::INTERNAL_SERVER_ERROR, e)
+        },
+    }
+}
+
+/// Audit query parameters
+#[derive(Debug, Default, Deserialize)]
+pub struct AuditQueryParams {
+    #[serde(default)]
+    session_id: Option<String>,
+    #[serde(default)]
+    event_type: Option<String>,
+    #[serde(default)]
+    resource_type: Option<String>,
+    #[serde(default)]
+    resource_id: Option<String>,
+    #[serde(default)]
+    from: Option<DateTime<Utc>>,
+    #[serde(default)]
+    to: Option<DateTime<Utc>>,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+/// Audit entries matching the filters, newest first
+///
+/// GET /admin/audit?session_id=...&event_type=...&resource_type=...&resource_id=...&from=...&to=...
+pub async fn query_audit(
+    State(state): State<AppState>,
+    Query(params): Query<AuditQueryParams>,
+) -> ApiResponse {
+    let Some(ref log) = state.audit_log else {
+        return disabled("Audit queries");
+    };
+
+    let event_type = match params.event_type.as_deref() {
+        Some(name) => {
+            let event_type = AuditEventType::from_str(name);
+            // Unknown names parse to a fallback type rather than failing
+            if event_type.as_str() != name {
+                return error(
+                    StatusCode::BAD_REQUEST,
+                    format!("Unknown event type: {}", name),
+                );
+            }
+            Some(event_type)
+        },
+        None => None,
+    };
+    let query = AuditQuery {
+        session_id: params.session_id,
+        event_type,
+        resource_type: params.resource_type,
+        resource_id: params.resource_id,
+        from: params.from,
+        to: params.to,
+        limit: Some(limit(params.limit) as i32),
+    };
+
+    match log.query(query).await {
+        Ok(entries) => (
+            StatusCode::OK,
+            Json(serde_json::json!({ "count": entries.len(), "entries": entries })),
+        ),
+        Err(e) => {
+            tracing::error!("Failed to query audit log: {}", e);
+            error(StatusCode::INTERNAL_SERVER_ERROR, e)
+        },
+    }
+}
+
+/// Check a session's audit hash chain for tampering
+///
+/// GET /admin/audit/:session_id/verify
+pub async fn verify_audit_chain(
+    State(state): State<AppState>,
+    Path(session_id): Path<String>,
+) -> ApiResponse {
+    let Some(ref log) = state.audit_log else {
+        return disabled("Audit queries");
+    };
+
+    match log.verify_chain(&session_id).await {
+        Ok(valid) => (
+            StatusCode::OK,
+            Json(serde_json::json!({ "session_id": session_id, "valid": valid })),
+        ),
+        Err(e) => {
+            tracing::error!(session_id = %session_id, "Failed to verify audit chain: {}", e);
+            error(StatusCode::INTERNAL_SERVER_ERROR, e)
+        },
+    }
+}
+
+/// Configured branches with their default slot capacity
+///
+/// GET /admin/branches
+pub async fn list_branches(State(state): State<AppState>) -> ApiResponse {
+    let tools_view = state.get_tools_view();
+    let branches: Vec<serde_json::Value> = tools_view
+        .all_branches()
+        .iter()
+        .map(|branch| {
+            serde_json::json!({
+                "branch": branch,
+                "slot_capacity": tools_view.branch_slot_capacity(&branch.branch_id),
+            })
+        })
+        .collect();
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({ "count": branches.len(), "branches": branches })),
+    )
+}
+
+/// Slot query parameters
+#[derive(Debug, Deserialize)]
+pub struct SlotQuery {
+    date: NaiveDate,
+}
+
+/// Capacity, bookings and holds for each configured slot at a branch on a date
+///
+/// GET /admin/branches/:id/slots?date=YYYY-MM-DD
+pub async fn branch_slots(
+    State(state): State<AppState>,
+    Path(branch_id): Path<String>,
+    Query(query): Query<SlotQuery>,
+) -> ApiResponse {
+    let Some(ref slots) = state.slots else {
+        return disabled("Slot capacity");
+    };
+    let tools_view = state.get_tools_view();
+    if tools_view.get_branch(&branch_id).is_none() {
+        return error(StatusCode::NOT_FOUND, "Branch not found");
+    }
+
+    let times = configured_time_slots(Some(tools_view.as_ref()));
+    let capacity = tools_view.branch_slot_capacity(&branch_id);
+    match slots
+        .availability(&branch_id, query.date, &times, capacity)
+        .await
+    {
+        Ok(availability) => {
+            let slots: Vec<serde_json::Value> = availability
+                .iter()
+                .map(|slot| serde_json::json!({ "slot": slot, "remaining": slot.remaining() }))
+                .collect();
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "branch_id": branch_id,
+                    "date": query.date,
+                    "slots": slots,
+                })),
+            )
+        },
+        Err(e) => {
+            tracing::error!(branch_id = %branch_id, "Failed to load slots: {}", e);
+            error(StatusCode::INTERNAL_SERVER_ERROR, e)
+        },
+    }
+}
+
+/// Slot capacity override
+#[derive(Debug, Deserialize)]
+pub struct SlotCapacityRequest {
+    date: NaiveDate,
+    time: String,
+    capacity: u32,
+}
+
+/// Override one slot's capacity (extra staff, half days, holidays)
+///
+/// POST /admin/branches/:id/slots
+pub async fn set_slot_capacity(
+    State(state): State<AppState>,
+    Path(branch_id): Path<String>,
+    Json(request): Json<SlotCapacityRequest>,
+) -> ApiResponse {
+    let Some(ref slots) = state.slots else {
+        return disabled("Slot capacity");
+    };
+    let tools_view = state.get_tools_view();
+    if tools_view.get_branch(&branch_id).is_none() {
+        return error(StatusCode::NOT_FOUND, "Branch not found");
+    }
+    if !configured_time_slots(Some(tools_view.as_ref())).contains(&request.time) {
+        return error(
+            StatusCode::BAD_REQUEST,
+            format!("Unknown slot time: {}", request.time),
+        );
+    }
+
+    if let Err(e) = slots
+        .set_capacity(&branch_id, request.date, &request.time, request.capacity)
+        .await
+    {
+        tracing::error!(branch_id = %branch_id, "Failed to set slot capacity: {}", e);
+        return error(StatusCode::INTERNAL_SERVER_ERROR, e);
+    }
+    audit(
+        &state,
+        "branch_slot",
+        &branch_id,
+        "set_slot_capacity",
+        serde_json::json!({
+            "date": request.date,
+            "time": request.time,
+            "capacity": request.capacity,
+        }),
+    )
+    .await;
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({ "status": "success" })),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use voice_agent_config::Settings;
+
+    #[tokio::test]
+    async fn test_admin_lists_and_deletes_sessions() {
+        let state = AppState::new(Settings::default());
+        let session = state
+            .sessions
+            .create(Default::default(), state.master_domain_config.clone())
+            .unwrap();
+        state.persist_session(&session).await.unwrap();
+
+        let (status, Json(body)) =
+            list_sessions(State(state.clone()), Query(ListQuery::default())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["total"], 1);
+        assert_eq!(body["sessions"][0]["live"], true);
+
+        let (status, Json(body)) =
+            delete_session(State(state.clone()), Path(session.id.clone())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["was_live"], true);
+        assert!(state.sessions.get(&session.id).is_none());
+
+        let (status, _) = get_session(State(state), Path(session.id.clone())).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
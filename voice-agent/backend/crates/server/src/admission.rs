@@ -0,0 +1,466 @@
+//! Session admission control
+//!
+//! STT and TTS are CPU/GPU bound; once their workers saturate, every extra
+//! session slows down all calls in progress. New sessions are checked against
+//! active-session, model queue-depth and host utilization limits. Over a
+//! limit they wait up to `admission.queue_timeout_ms` for capacity and are
+//! then refused with a "busy, please retry" response. Sessions already
+//! admitted are never cut off.
+//!
+//! Queue depth is the number of in-flight jobs per model component, counted
+//! by the `InFlight` guards handlers hold while waiting for and running a
+//! model.
+
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use parking_lot::RwLock;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use voice_agent_config::{AdmissionConfig, PipelineComponent};
+
+use crate::metrics::{record_admission_queued, record_admission_rejected};
+
+/// How often a waiting session re-checks for capacity
+const WAIT_POLL: Duration = Duration::from_millis(50);
+
+/// Why a new session was refused
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum Rejection {
+    #[error("{active} active sessions (limit {limit})")]
+    Sessions { active: usize, limit: usize },
+    #[error("{} queue depth {depth} (limit {limit})", .component.as_str())]
+    QueueDepth {
+        component: PipelineComponent,
+        depth: usize,
+        limit: usize,
+    },
+    #[error("CPU at {percent:.0}% (limit {limit:.0}%)")]
+    Cpu { percent: f32, limit: f32 },
+    #[error("GPU at {percent:.0}% (limit {limit:.0}%)")]
+    Gpu { percent: f32, limit: f32 },
+    #[error("{waiting} sessions already waiting for capacity")]
+    QueueFull { waiting: usize },
+}
+
+impl Rejection {
+    /// Metric label for the limit that was hit
+    pub fn reason(&self) -> &'static str {
+        match self {
+            Self::Sessions { .. } => "sessions",
+            Self::QueueDepth { .. } => "queue_depth",
+            Self::Cpu { .. } => "cpu",
+            Self::Gpu { .. } => "gpu",
+            Self::QueueFull { .. } => "queue_full",
+        }
+    }
+}
+
+/// Latest host utilization sample; `None` until sampled or when unavailable
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct Utilization {
+    pub cpu_percent: Option<f32>,
+    pub gpu_percent: Option<f32>,
+}
+
+/// Admission state for `/health` and metrics
+#[derive(Debug, Clone, Serialize)]
+pub struct AdmissionSnapshot {
+    pub enabled: bool,
+    pub active_sessions: usize,
+    pub waiting: usize,
+    pub queue_depth: HashMap<&'static str, usize>,
+    pub utilization: Utilization,
+}
+
+/// Counts one in-flight job (or waiting session) until dropped
+pub struct InFlight(Arc<AtomicUsize>);
+
+impl InFlight {
+    fn new(counter: &Arc<AtomicUsize>) -> Self {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Self(counter.clone())
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Admits or refuses new sessions based on current load
+#[derive(Debug)]
+pub struct AdmissionController {
+    config: AdmissionConfig,
+    in_flight: HashMap<PipelineComponent, Arc<AtomicUsize>>,
+    waiting: Arc<AtomicUsize>,
+    utilization: RwLock<Utilization>,
+}
+
+impl AdmissionController {
+    pub fn new(config: AdmissionConfig) -> Self {
+        let in_flight = PipelineComponent::ALL
+            .into_iter()
+            .map(|c| (c, Arc::new(AtomicUsize::new(0))))
+            .collect();
+        Self {
+            config,
+            in_flight,
+            waiting: Arc::new(AtomicUsize::new(0)),
+            utilization: RwLock::new(Utilization::default()),
+        }
+    }
+
+    pub fn config(&self) -> &AdmissionConfig {
+        &self.config
+    }
+
+    /// Count a model job until the returned guard is dropped
+    ///
+    /// Hold it across both waiting for a worker and running it, so queued
+    /// work shows up in the depth.
+    pub fn begin(&self, component: PipelineComponent) -> InFlight {
+        InFlight::new(&self.in_flight[&component])
+    }
+
+    pub fn queue_depth(&self, component: PipelineComponent) -> usize {
+        self.in_flight[&component].load(Ordering::SeqCst)
+    }
+
+    pub fn utilization(&self) -> Utilization {
+        *self.utilization.read()
+    }
+
+    /// Record a utilization sample (normally done by the sampler task)
+    pub fn set_utilization(&self, utilization: Utilization) {
+        *self.utilization.write() = utilization;
+    }
+
+    /// Check the limits once, without waiting
+    pub fn check(&self, active_sessions: usize) -> Result<(), Rejection> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if active_sessions >= self.config.max_active_sessions {
+            return Err(Rejection::Sessions {
+                active: active_sessions,
+                limit: self.config.max_active_sessions,
+            });
+        }
+
+        for component in PipelineComponent::ALL {
+            if let Some(&limit) = self.config.max_queue_depth.get(&component) {
+                let depth = self.queue_depth(component);
+                if depth >= limit {
+                    return Err(Rejection::QueueDepth {
+                        component,
+                        depth,
+                        limit,
+                    });
+                }
+            }
+        }
+
+        let utilization = self.utilization();
+        if let Some(percent) = utilization.cpu_percent {
+            if percent >= self.config.max_cpu_percent {
+                return Err(Rejection::Cpu {
+                    percent,
+                    limit: self.config.max_cpu_percent,
+                });
+            }
+        }
+        if let Some(percent) = utilization.gpu_percent {
+            if percent >= self.config.max_gpu_percent {
+                return Err(Rejection::Gpu {
+                    percent,
+                    limit: self.config.max_gpu_percent,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Admit a new session, waiting up to `queue_timeout_ms` for capacity
+    ///
+    /// `active_sessions` is re-read on every check since sessions end while
+    /// this one waits.
+    pub async fn admit(&self, active_sessions: impl Fn() -> usize) -> Result<(), Rejection> {
+        let rejection = match self.check(active_sessions()) {
+            Ok(()) => return Ok(()),
+            Err(rejection) => rejection,
+        };
+
+        let result = if self.config.queue_timeout_ms == 0 {
+            Err(rejection)
+        } else if self.waiting.load(Ordering::SeqCst) >= self.config.max_waiting {
+            Err(Rejection::QueueFull {
+                waiting: self.waiting.load(Ordering::SeqCst),
+            })
+        } else {
+            let _waiting = InFlight::new(&self.waiting);
+            record_admission_queued();
+            let deadline = Instant::now() + Duration::from_millis(self.config.queue_timeout_ms);
+            loop {
+                tokio::time::sleep(WAIT_POLL).await;
+                match self.check(active_sessions()) {
+                    Ok(()) => break Ok(()),
+                    Err(rejection) if Instant::now() >= deadline => break Err(rejection),
+                    Err(_) => {},
+                }
+            }
+        };
+
+        if let Err(ref rejection) = result {
+            tracing::warn!(
+                reason = rejection.reason(),
+                "Session refused: {}",
+                rejection
+            );
+            record_admission_rejected(rejection.reason());
+        }
+        result
+    }
+
+    pub fn snapshot(&self, active_sessions: usize) -> AdmissionSnapshot {
+        AdmissionSnapshot {
+            enabled: self.config.enabled,
+            active_sessions,
+            waiting: self.waiting.load(Ordering::SeqCst),
+            queue_depth: PipelineComponent::ALL
+                .into_iter()
+                .map(|c| (c.as_str(), self.queue_depth(c)))
+                .collect(),
+            utilization: self.utilization(),
+        }
+    }
+
+    /// JSON body of a "busy, please retry" response
+    pub fn busy_body(&self, rejection: &Rejection) -> serde_json::Value {
+        serde_json::json!({
+            "error": "busy",
+            "message": "The service is busy, please retry shortly",
+            "reason": rejection.reason(),
+            "retry_after_secs": self.config.retry_after_secs,
+        })
+    }
+
+    /// 503 with a `Retry-After` header and `busy_body`
+    pub fn busy_response(&self, rejection: &Rejection) -> Response {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            [(
+                header::RETRY_AFTER,
+                self.config.retry_after_secs.to_string(),
+            )],
+            axum::Json(self.busy_body(rejection)),
+        )
+            .into_response()
+    }
+
+    /// Sample CPU (and GPU, with `gpu_probe`) utilization in the background
+    pub fn spawn_sampler(self: &Arc<Self>) -> tokio::task::JoinHandle<()> {
+        let controller = self.clone();
+        tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval(Duration::from_millis(controller.config.sample_interval_ms));
+            let mut previous_cpu: Option<(u64, u64)> = None;
+            loop {
+                interval.tick().await;
+
+                let cpu_times = tokio::fs::read_to_string("/proc/stat")
+                    .await
+                    .ok()
+                    .and_then(|stat| parse_cpu_times(&stat));
+                let cpu_percent = match (previous_cpu, cpu_times) {
+                    (Some(prev), Some(now)) => cpu_percent_between(prev, now),
+                    _ => None,
+                };
+                previous_cpu = cpu_times;
+
+                let gpu_percent = if controller.config.gpu_probe {
+                    sample_gpu_percent().await
+                } else {
+                    None
+                };
+
+                controller.set_utilization(Utilization {
+                    cpu_percent,
+                    gpu_percent,
+                });
+            }
+        })
+    }
+}
+
+impl Default for AdmissionController {
+    fn default() -> Self {
+        Self::new(AdmissionConfig::default())
+    }
+}
+
+/// (idle, total) jiffies from the aggregate `cpu` line of `/proc/stat`
+fn parse_cpu_times(stat: &str) -> Option<(u64, u64)> {
+    let line = stat.lines().find(|l| l.starts_with("cpu "))?;
+    let values: Vec<u64> = line
+        .split_whitespace()
+        .skip(1)
+        .filter_map(|v| v.parse().ok())
+        .collect();
+    if values.len() < 4 {
+        return None;
+    }
+    // idle + iowait
+    let idle = values[3] + values.get(4).copied().unwrap_or(0);
+    Some((idle, values.iter().sum()))
+}
+
+fn cpu_percent_between(previous: (u64, u64), now: (u64, u64)) -> Option<f32> {
+    let total = now.1.checked_sub(previous.1)?;
+    let idle = now.0.checked_sub(previous.0)?;
+    if total == 0 {
+        return None;
+    }
+    Some((total.saturating_sub(idle)) as f32 / total as f32 * 100.0)
+}
+
+/// Busiest GPU's utilization from `nvidia-smi` CSV output
+fn parse_gpu_utilization(output: &str) -> Option<f32> {
+    output
+        .lines()
+        .filter_map(|l| l.trim().parse::<f32>().ok())
+        .reduce(f32::max)
+}
+
+async fn sample_gpu_percent() -> Option<f32> {
+    let output = tokio::process::Command::new("nvidia-smi")
+        .args([
+            "--query-gpu=utilization.gpu",
+            "--format=csv,noheader,nounits",
+        ])
+        .output()
+        .await
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_gpu_utilization(&String::from_utf8_lossy(&output.stdout))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn controller(config: AdmissionConfig) -> AdmissionController {
+        AdmissionController::new(config)
+    }
+
+    #[test]
+    fn test_session_limit() {
+        let admission = controller(AdmissionConfig {
+            max_active_sessions: 2,
+            ..Default::default()
+        });
+        assert!(admission.check(1).is_ok());
+        assert_eq!(
+            admission.check(2),
+            Err(Rejection::Sessions {
+                active: 2,
+                limit: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_queue_depth_tracks_guards() {
+        let admission = controller(AdmissionConfig {
+            max_queue_depth: HashMap::from([(PipelineComponent::Stt, 2)]),
+            ..Default::default()
+        });
+        let first = admission.begin(PipelineComponent::Stt);
+        let _second = admission.begin(PipelineComponent::Stt);
+        assert_eq!(admission.queue_depth(PipelineComponent::Stt), 2);
+        assert_eq!(admission.check(0).unwrap_err().reason(), "queue_depth");
+
+        drop(first);
+        assert_eq!(admission.queue_depth(PipelineComponent::Stt), 1);
+        assert!(admission.check(0).is_ok());
+    }
+
+    #[test]
+    fn test_utilization_limits() {
+        let admission = controller(AdmissionConfig::default());
+        admission.set_utilization(Utilization {
+            cpu_percent: Some(97.0),
+            gpu_percent: None,
+        });
+        assert_eq!(admission.check(0).unwrap_err().reason(), "cpu");
+
+        admission.set_utilization(Utilization {
+            cpu_percent: Some(20.0),
+            gpu_percent: Some(99.0),
+        });
+        assert_eq!(admission.check(0).unwrap_err().reason(), "gpu");
+    }
+
+    #[test]
+    fn test_disabled_admits_everything() {
+        let admission = controller(AdmissionConfig {
+            enabled: false,
+            max_active_sessions: 1,
+            ..Default::default()
+        });
+        assert!(admission.check(50).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_admit_waits_for_capacity() {
+        let admission = Arc::new(controller(AdmissionConfig {
+            max_queue_depth: HashMap::from([(PipelineComponent::Tts, 1)]),
+            queue_timeout_ms: 1000,
+            ..Default::default()
+        }));
+        let job = admission.begin(PipelineComponent::Tts);
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            drop(job);
+        });
+        assert!(admission.admit(|| 0).await.is_ok());
+        assert_eq!(admission.snapshot(0).waiting, 0);
+    }
+
+    #[tokio::test]
+    async fn test_admit_times_out() {
+        let admission = controller(AdmissionConfig {
+            max_active_sessions: 1,
+            queue_timeout_ms: 100,
+            ..Default::default()
+        });
+        let rejection = admission.admit(|| 1).await.unwrap_err();
+        assert_eq!(rejection.reason(), "sessions");
+
+        let body = admission.busy_body(&rejection);
+        assert_eq!(body["error"], "busy");
+        assert_eq!(body["retry_after_secs"], 5);
+    }
+
+    #[test]
+    fn test_parse_cpu_times() {
+        let stat = "cpu  100 0 50 800 50 0 0 0 0 0\ncpu0 50 0 25 400 25 0 0 0 0 0\n";
+        assert_eq!(parse_cpu_times(stat), Some((850, 1000)));
+        assert_eq!(cpu_percent_between((850, 1000), (900, 1100)), Some(50.0));
+        assert_eq!(parse_cpu_times("intr 1 2 3"), None);
+    }
+
+    #[test]
+    fn test_parse_gpu_utilization() {
+        assert_eq!(parse_gpu_utilization("35\n82\n"), Some(82.0));
+        assert_eq!(parse_gpu_utilization(""), None);
+    }
+}
@@ -0,0 +1,266 @@
+//! Conversation Analytics API
+//!
+//! Read-only views over the daily rollups built from finished sessions:
+//! - `GET /api/analytics/daily` returns one row per day
+//! - `GET /api/analytics/funnel` returns stage-to-stage conversion
+//! - `GET /api/analytics/offers` returns mentions and acceptance per offer
+//!
+//! All accept `from` and `to` (`YYYY-MM-DD`, default the last 7 days); the
+//! daily and funnel views also take `language` (default `all`). The funnel
+//! takes comma-separated `steps`, each a stage or a tool name; without it
+//! the configured steps are used.
+
+use axum::{
+    extract::{Json, Query, State},
+    http::StatusCode,
+};
+use chrono::{NaiveDate, Utc};
+use serde::Deserialize;
+
+use voice_agent_persistence::analytics::{funnel as build_funnel, ALL_LANGUAGES};
+use voice_agent_persistence::offers::offer_report;
+use voice_agent_persistence::{DailyRollup, PersistenceError};
+
+use crate::state::AppState;
+
+/// Days covered when `from` is omitted
+const DEFAULT_RANGE_DAYS: u64 = 7;
+
+/// Analytics query parameters
+#[derive(Debug, Deserialize)]
+pub struct AnalyticsQuery {
+    #[serde(default)]
+    from: Option<NaiveDate>,
+    #[serde(default)]
+    to: Option<NaiveDate>,
+    #[serde(default)]
+    language: Option<String>,
+    /// Funnel steps, comma-separated
+    #[serde(default)]
+    steps: Option<String>,
+}
+
+type ApiResponse = (StatusCode, Json<serde_json::Value>);
+
+fn error(status: StatusCode, message: impl ToString) -> ApiResponse {
+    (
+        status,
+        Json(serde_json::json!({ "status": "error", "message": message.to_string() })),
+    )
+}
+
+/// Date range a query covers
+fn query_range(query: &AnalyticsQuery) -> (NaiveDate, NaiveDate) {
+    let to = query.to.unwrap_or_else(|| Utc::now().date_naive());
+    let from = query
+        .from
+        .unwrap_or_else(|| to - chrono::Days::new(DEFAULT_RANGE_DAYS - 1));
+    (from, to)
+}
+
+/// Load the rollups a query covers
+async fn load_rollups(
+    state: &AppState,
+    query: &AnalyticsQuery,
+) -> Result<(NaiveDate, NaiveDate, String, Vec<DailyRollup>), ApiResponse> {
+    let Some(ref store) = state.analytics else {
+        return Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(serde_json::json!({
+                "status": "disabled",
+                "message": "Analytics requires persistence"
+            })),
+        ));
+    };
+
+    let (from, to) = query_range(query);
+    let language = query
+        .language
+        .clone()
+        .filter(|l| !l.is_empty())
+        .unwrap_or_else(|| ALL_LANGUAGES.to_string());
+
+    match store.rollups(from, to, &language).await {
+        Ok(rollups) => Ok((from, to, language, rollups)),
+        Err(PersistenceError::InvalidData(message)) => {
+            Err(error(StatusCode::BAD_REQUEST, message))
+        },
+        Err(e) => {
+            tracing::error!("Failed to load analytics rollups: {}", e);
+            Err(error(StatusCode::INTERNAL_SERVER_ERROR, e))
+        },
+    }
+}
+
+/// Daily rollups
+///
+/// GET /api/analytics/daily?from=...&to=...&language=...
+pub async fn daily(
+    State(state): State<AppState>,
+    Query(query): Query<AnalyticsQuery>,
+) -> ApiResponse {
+    let (from, to, language, rollups) = match load_rollups(&state, &query).await {
+        Ok(loaded) => loaded,
+        Err(response) => return response,
+    };
+
+    let days: Vec<serde_json::Value> = rollups
+        .iter()
+        .map(|r| {
+            serde_json::json!({
+                "day": r.day,
+                "sessions": r.sessions,
+                "goals_reached": r.goals_reached,
+                "average_duration_secs": r.average_duration_secs(),
+                "interruptions": r.interruptions,
+                "stage_reached": r.stage_reached,
+                "drop_off": r.drop_off,
+                "tool_sessions": r.tool_sessions,
+                "tool_calls": r.tool_calls,
+            })
+        })
+        .collect();
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "from": from,
+            "to": to,
+            "language": language,
+            "days": days,
+        })),
+    )
+}
+
+/// Conversion funnel
+///
+/// GET /api/analytics/funnel?from=...&to=...&language=...&steps=discovery,presentation
+pub async fn funnel(
+    State(state): State<AppState>,
+    Query(query): Query<AnalyticsQuery>,
+) -> ApiResponse {
+    let steps: Vec<String> = match query.steps.as_deref() {
+        Some(steps) => steps
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect(),
+        None => state.get_config().analytics.funnel_steps.clone(),
+    };
+    if steps.is_empty() {
+        return error(
+            StatusCode::BAD_REQUEST,
+            "At least one funnel step is required",
+        );
+    }
+
+    let (from, to, language, rollups) = match load_rollups(&state, &query).await {
+        Ok(loaded) => loaded,
+        Err(response) => return response,
+    };
+    let sessions: u64 = rollups.iter().map(|r| r.sessions).sum();
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "from": from,
+            "to": to,
+            "language": language,
+            "sessions": sessions,
+            "steps": build_funnel(&rollups, &steps),
+        })),
+    )
+}
+
+/// Offer mentions and acceptance
+///
+/// GET /api/analytics/offers?from=...&to=...
+///
+/// Counts sessions per offer: mentioned by the agent, accepted and declined.
+pub async fn offers(
+    State(state): State<AppState>,
+    Query(query): Query<AnalyticsQuery>,
+) -> ApiResponse {
+    let Some(ref store) = state.offer_events else {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(serde_json::json!({
+                "status": "disabled",
+                "message": "Offer tracking requires persistence"
+            })),
+        );
+    };
+
+    let (from, to) = query_range(&query);
+    let report = match offer_report(store.as_ref(), from, to).await {
+        Ok(report) => report,
+        Err(PersistenceError::InvalidData(message)) => {
+            return error(StatusCode::BAD_REQUEST, message)
+        },
+        Err(e) => {
+            tracing::error!("Failed to load offer events: {}", e);
+            return error(StatusCode::INTERNAL_SERVER_ERROR, e);
+        },
+    };
+
+    let tools_view = state.get_tools_view();
+    let offers: Vec<serde_json::Value> = report
+        .iter()
+        .map(|stats| {
+            serde_json::json!({
+                "offer_id": stats.offer_id,
+                "name": tools_view.get_offer(&stats.offer_id).map(|o| o.name.as_str()),
+                "mentioned": stats.mentioned,
+                "accepted": stats.accepted,
+                "declined": stats.declined,
+                "acceptance_rate": stats.acceptance_rate(),
+            })
+        })
+        .collect();
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "from": from,
+            "to": to,
+            "offers": offers,
+        })),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use voice_agent_config::Settings;
+    use voice_agent_persistence::{AnalyticsStore, InMemoryAnalyticsStore, SessionOutcome};
+
+    #[tokio::test]
+    async fn test_funnel_from_recorded_sessions() {
+        let store = Arc::new(InMemoryAnalyticsStore::new());
+        let state = AppState::new(Settings::default()).with_analytics_store(store.clone());
+
+        let session = state
+            .sessions
+            .create(Default::default(), state.master_domain_config.clone())
+            .unwrap();
+        let mut outcome: SessionOutcome = session.outcome();
+        outcome.stages_reached.push("discovery".to_string());
+        store.record_outcome(&outcome).await.unwrap();
+        voice_agent_persistence::analytics::roll_up_day(store.as_ref(), outcome.day())
+            .await
+            .unwrap();
+
+        let query = AnalyticsQuery {
+            from: None,
+            to: None,
+            language: None,
+            steps: Some("greeting, discovery,presentation".to_string()),
+        };
+        let (status, Json(body)) = funnel(State(state), Query(query)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["sessions"], 1);
+        assert_eq!(body["steps"][1]["sessions"], 1);
+        assert_eq!(body["steps"][2]["sessions"], 0);
+    }
+}
@@ -14,9 +14,14 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use voice_agent_config::Settings;
 
+use crate::sms_inbound::SMS_INBOUND_PATH;
+
 /// P1 FIX: Track if we've warned about auth being disabled (warn once only)
 static AUTH_DISABLED_WARNED: AtomicBool = AtomicBool::new(false);
 
+/// Routes guarded by `admin_api_key`; refused outright when it is unset
+const ADMIN_PATH_PREFIX: &str = "/admin/";
+
 /// Authentication result after checking config
 enum AuthCheck {
     /// Authentication disabled, pass through
@@ -25,6 +30,8 @@ enum AuthCheck {
     PublicPath,
     /// Config error
     ConfigError(&'static str),
+    /// Route closed by config
+    Forbidden(&'static str),
     /// Need to check API key with this expected key
     CheckKey(String),
 }
@@ -37,6 +44,19 @@ fn check_auth_config(config: &Arc<RwLock<Settings>>, path: &str) -> AuthCheck {
     let config_guard = config.read();
     let auth_config = &config_guard.server.auth;
 
+    // The SMS provider authenticates with the inbound token, checked by the handler
+    if path == SMS_INBOUND_PATH {
+        return AuthCheck::PublicPath;
+    }
+
+    // Admin routes use their own key and stay closed without one
+    if path.starts_with(ADMIN_PATH_PREFIX) {
+        return match auth_config.admin_api_key.as_ref().filter(|k| !k.is_empty()) {
+            Some(key) => AuthCheck::CheckKey(key.clone()),
+            None => AuthCheck::Forbidden("Admin API is disabled: no admin API key is configured"),
+        };
+    }
+
     // P1 FIX: Log warning when auth is disabled (only once)
     // This is a security risk in production environments
     if !auth_config.enabled {
@@ -66,6 +86,8 @@ fn check_auth_config(config: &Arc<RwLock<Settings>>, path: &str) -> AuthCheck {
 /// # Authorization
 /// - Checks for `Authorization: Bearer <api_key>` header
 /// - Skips authentication for public paths (health, metrics)
+/// - `/admin/` routes require `admin_api_key` instead, and return 403 Forbidden
+///   when it is not set
 /// - Returns 401 Unauthorized if auth is enabled but key is missing/invalid
 ///
 /// # Configuration
@@ -100,6 +122,10 @@ pub async fn auth_middleware(request: Request, next: Next) -> Response {
             )
                 .into_response()
         },
+        AuthCheck::Forbidden(msg) => {
+            tracing::warn!(path = %path, "{}", msg);
+            (StatusCode::FORBIDDEN, msg).into_response()
+        },
         AuthCheck::CheckKey(expected_key) => {
             // Extract Authorization header
             let auth_header = request
@@ -136,7 +162,7 @@ pub async fn auth_middleware(request: Request, next: Next) -> Response {
 }
 
 /// Constant-time comparison to prevent timing attacks
-fn constant_time_compare(a: &[u8], b: &[u8]) -> bool {
+pub(crate) fn constant_time_compare(a: &[u8], b: &[u8]) -> bool {
     if a.len() != b.len() {
         return false;
     }
@@ -159,4 +185,56 @@ mod tests {
         assert!(!constant_time_compare(b"secret", b"secreT"));
         assert!(!constant_time_compare(b"abc", b"xyz"));
     }
+
+    #[test]
+    fn test_admin_paths_require_admin_key() {
+        let mut settings = Settings::default();
+        settings.server.auth.api_key = Some("api-key".to_string());
+        settings.server.auth.admin_api_key = Some("admin-key".to_string());
+        let config = Arc::new(RwLock::new(settings));
+
+        // Even with auth disabled, admin routes need the admin key
+        assert!(matches!(
+            check_auth_config(&config, "/admin/sessions"),
+            AuthCheck::CheckKey(key) if key == "admin-key"
+        ));
+        assert!(matches!(
+            check_auth_config(&config, "/api/sessions"),
+            AuthCheck::Disabled
+        ));
+
+        config.write().server.auth.enabled = true;
+        assert!(matches!(
+            check_auth_config(&config, "/api/sessions"),
+            AuthCheck::CheckKey(key) if key == "api-key"
+        ));
+        assert!(matches!(
+            check_auth_config(&config, "/sms/inbound"),
+            AuthCheck::PublicPath
+        ));
+    }
+
+    #[test]
+    fn test_admin_paths_closed_without_admin_key() {
+        let mut settings = Settings::default();
+        settings.server.auth.api_key = Some("api-key".to_string());
+        let config = Arc::new(RwLock::new(settings));
+
+        // Neither disabled auth nor the regular API key opens admin routes
+        assert!(matches!(
+            check_auth_config(&config, "/admin/sessions"),
+            AuthCheck::Forbidden(_)
+        ));
+        config.write().server.auth.enabled = true;
+        assert!(matches!(
+            check_auth_config(&config, "/admin/competitor-rates"),
+            AuthCheck::Forbidden(_)
+        ));
+
+        config.write().server.auth.admin_api_key = Some(String::new());
+        assert!(matches!(
+            check_auth_config(&config, "/admin/sessions"),
+            AuthCheck::Forbidden(_)
+        ));
+    }
 }
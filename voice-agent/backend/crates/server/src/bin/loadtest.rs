@@ -0,0 +1,822 @@
+//! loadtest: concurrent session load generator for capacity sizing
+//!
+//! Drives a running server through a series of concurrency steps. At each
+//! step, N sessions are created over HTTP, connected over WebSocket and taken
+//! through scripted text or audio turns, and per-stage latency percentiles
+//! are reported. The largest step whose p95 response latency and error rate
+//! stay within budget is reported as the capacity limit. With `--scylla`,
+//! session-store throughput is measured at the same concurrency steps.
+//!
+//! ```text
+//! loadtest [--url URL] [--sessions N,N,...] [--turns N] [--mode text|audio]
+//!          [--think-ms MS] [--ramp-ms MS] [--timeout-ms MS] [--budget-ms MS]
+//!          [--max-error-rate R] [--api-key KEY] [--scylla HOSTS]
+//!          [--scylla-ops N] [--json]
+//! ```
+//!
+//! Stages, all measured client-side in milliseconds:
+//! - `create`: `POST /api/sessions` round trip
+//! - `connect`: WebSocket handshake until `session_info` arrives
+//! - `transcript`: end of caller speech until the final transcript (audio mode)
+//! - `response`: end of caller input until the first agent response
+//! - `first_audio`: end of caller speech until the first response audio (audio mode)
+//!
+//! Exit codes: 0 = every step within budget, 1 = capacity limit reached
+//! below the largest step, 2 = usage error.
+
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use futures::{SinkExt, StreamExt};
+use serde::Serialize;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::{header::AUTHORIZATION, HeaderValue};
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use voice_agent_core::stats::percentile;
+use voice_agent_persistence::{
+    ScyllaClient, ScyllaConfig, ScyllaSessionStore, SessionData, SessionStore,
+};
+use voice_agent_server::websocket::WsMessage;
+
+const USAGE: &str = "Usage: loadtest [--url URL] [--sessions N,N,...] [--turns N] \
+[--mode text|audio] [--think-ms MS] [--ramp-ms MS] [--timeout-ms MS] [--budget-ms MS] \
+[--max-error-rate R] [--api-key KEY] [--scylla HOSTS] [--scylla-ops N] [--json]
+
+Options:
+  --url URL             Server base URL (default: http://127.0.0.1:8080)
+  --sessions N,N,...    Concurrency steps (default: 1,5,10,25,50)
+  --turns N             Turns per session (default: 5)
+  --mode text|audio     Send scripted text or synthetic speech (default: text)
+  --think-ms MS         Pause between turns (default: 1000)
+  --ramp-ms MS          Spread session starts over this window (default: 1000)
+  --timeout-ms MS       Give up on a turn after this long (default: 15000)
+  --budget-ms MS        p95 response latency budget (default: 1500)
+  --max-error-rate R    Tolerated failed-turn ratio (default: 0.01)
+  --api-key KEY         Bearer token when server auth is enabled
+  --scylla HOSTS        Also measure session-store throughput (comma-separated)
+  --scylla-ops N        Store operations per worker (default: 200)
+  --json                Print the report as JSON";
+
+/// Caller lines cycled through in text mode
+const UTTERANCES: &[&str] = &[
+    "Hello, I want to know about a gold loan",
+    "I have about 50 grams of gold jewellery",
+    "What interest rate can you offer?",
+    "I need around two lakh rupees",
+    "Which branch is closest to me?",
+    "Thank you, that is all",
+];
+
+/// Sample rate the server expects for inbound PCM
+const SAMPLE_RATE: usize = 16000;
+/// Client audio frame length
+const FRAME_MS: usize = 20;
+/// Synthetic speech per audio turn
+const SPEECH_MS: usize = 1500;
+/// Trailing silence so the turn detector closes the turn
+const TRAILING_SILENCE_MS: usize = 1000;
+
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Text,
+    Audio,
+}
+
+struct Args {
+    url: String,
+    steps: Vec<usize>,
+    turns: usize,
+    mode: Mode,
+    think: Duration,
+    ramp: Duration,
+    timeout: Duration,
+    budget_ms: f64,
+    max_error_rate: f64,
+    api_key: Option<String>,
+    scylla_hosts: Option<Vec<String>>,
+    scylla_ops: usize,
+    json: bool,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        url: "http://127.0.0.1:8080".to_string(),
+        steps: vec![1, 5, 10, 25, 50],
+        turns: 5,
+        mode: Mode::Text,
+        think: Duration::from_millis(1000),
+        ramp: Duration::from_millis(1000),
+        timeout: Duration::from_millis(15000),
+        budget_ms: 1500.0,
+        max_error_rate: 0.01,
+        api_key: None,
+        scylla_hosts: None,
+        scylla_ops: 200,
+        json: false,
+    };
+
+    fn value(iter: &mut impl Iterator<Item = String>, flag: &str) -> Result<String, String> {
+        iter.next().ok_or(format!("{} requires a value", flag))
+    }
+    fn number<T: std::str::FromStr>(raw: String, flag: &str) -> Result<T, String> {
+        raw.parse()
+            .map_err(|_| format!("{} expects a number, got '{}'", flag, raw))
+    }
+
+    let mut iter = std::env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        let flag = arg.as_str();
+        match flag {
+            "--url" => args.url = value(&mut iter, flag)?.trim_end_matches('/').to_string(),
+            "--sessions" => {
+                args.steps = value(&mut iter, flag)?
+                    .split(',')
+                    .map(|s| number(s.trim().to_string(), flag))
+                    .collect::<Result<_, _>>()?;
+            },
+            "--turns" => args.turns = number(value(&mut iter, flag)?, flag)?,
+            "--mode" => {
+                args.mode = match value(&mut iter, flag)?.as_str() {
+                    "text" => Mode::Text,
+                    "audio" => Mode::Audio,
+                    other => return Err(format!("Unknown mode: {}", other)),
+                }
+            },
+            "--think-ms" => {
+                args.think = Duration::from_millis(number(value(&mut iter, flag)?, flag)?)
+            },
+            "--ramp-ms" => {
+                args.ramp = Duration::from_millis(number(value(&mut iter, flag)?, flag)?)
+            },
+            "--timeout-ms" => {
+                args.timeout = Duration::from_millis(number(value(&mut iter, flag)?, flag)?)
+            },
+            "--budget-ms" => args.budget_ms = number(value(&mut iter, flag)?, flag)?,
+            "--max-error-rate" => args.max_error_rate = number(value(&mut iter, flag)?, flag)?,
+            "--api-key" => args.api_key = Some(value(&mut iter, flag)?),
+            "--scylla" => {
+                let hosts = value(&mut iter, flag)?;
+                args.scylla_hosts = Some(hosts.split(',').map(|h| h.trim().to_string()).collect());
+            },
+            "--scylla-ops" => args.scylla_ops = number(value(&mut iter, flag)?, flag)?,
+            "--json" => args.json = true,
+            "-h" | "--help" => return Err(String::new()),
+            other => return Err(format!("Unknown option: {}", other)),
+        }
+    }
+
+    if args.steps.is_empty() || args.steps.contains(&0) {
+        return Err("--sessions needs one or more counts above zero".to_string());
+    }
+    if args.turns == 0 {
+        return Err("--turns must be above zero".to_string());
+    }
+    args.steps.sort_unstable();
+    args.steps.dedup();
+
+    Ok(args)
+}
+
+/// Latency samples per stage, in milliseconds
+#[derive(Default)]
+struct Samples {
+    create: Vec<f64>,
+    connect: Vec<f64>,
+    transcript: Vec<f64>,
+    response: Vec<f64>,
+    first_audio: Vec<f64>,
+}
+
+impl Samples {
+    fn merge(&mut self, other: Samples) {
+        self.create.extend(other.create);
+        self.connect.extend(other.connect);
+        self.transcript.extend(other.transcript);
+        self.response.extend(other.response);
+        self.first_audio.extend(other.first_audio);
+    }
+}
+
+/// Percentile summary of one stage
+#[derive(Debug, Serialize)]
+struct StageSummary {
+    stage: &'static str,
+    count: usize,
+    p50_ms: f64,
+    p95_ms: f64,
+    p99_ms: f64,
+    max_ms: f64,
+}
+
+impl StageSummary {
+    fn from_samples(stage: &'static str, samples: &[f64]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        Self {
+            stage,
+            count: sorted.len(),
+            p50_ms: percentile(&sorted, 0.50),
+            p95_ms: percentile(&sorted, 0.95),
+            p99_ms: percentile(&sorted, 0.99),
+            max_ms: sorted.last().copied().unwrap_or(0.0),
+        }
+    }
+}
+
+/// Result of one concurrency step
+#[derive(Debug, Serialize)]
+struct StepReport {
+    sessions: usize,
+    turns_ok: usize,
+    turns_failed: usize,
+    error_rate: f64,
+    turns_per_sec: f64,
+    stages: Vec<StageSummary>,
+    /// First few distinct errors, for diagnosis
+    errors: Vec<String>,
+    within_budget: bool,
+}
+
+/// Session-store throughput at one concurrency step
+#[derive(Debug, Serialize)]
+struct StoreReport {
+    workers: usize,
+    ops: usize,
+    failed: usize,
+    ops_per_sec: f64,
+    latency: StageSummary,
+}
+
+#[derive(Debug, Serialize)]
+struct Report {
+    mode: &'static str,
+    budget_ms: f64,
+    steps: Vec<StepReport>,
+    /// Largest step within budget; `None` if even the smallest step failed
+    capacity: Option<usize>,
+    store: Vec<StoreReport>,
+}
+
+#[derive(Default)]
+struct SessionResult {
+    samples: Samples,
+    turns_ok: usize,
+    errors: Vec<String>,
+}
+
+fn elapsed_ms(since: Instant) -> f64 {
+    since.elapsed().as_secs_f64() * 1000.0
+}
+
+fn to_ws_url(base: &str) -> String {
+    if let Some(rest) = base.strip_prefix("https://") {
+        format!("wss://{}", rest)
+    } else if let Some(rest) = base.strip_prefix("http://") {
+        format!("ws://{}", rest)
+    } else {
+        base.to_string()
+    }
+}
+
+/// 16-bit PCM frame: a voiced harmonic stack for speech, zeros for silence
+fn pcm_frame(start_sample: usize, voiced: bool) -> Vec<u8> {
+    let len = SAMPLE_RATE * FRAME_MS / 1000;
+    (start_sample..start_sample + len)
+        .flat_map(|n| {
+            let sample = if voiced {
+                let t = n as f32 / SAMPLE_RATE as f32;
+                (1..=4)
+                    .map(|h| (2.0 * std::f32::consts::PI * 140.0 * h as f32 * t).sin() / h as f32)
+                    .sum::<f32>()
+                    * 0.15
+            } else {
+                0.0
+            };
+            ((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16).to_le_bytes()
+        })
+        .collect()
+}
+
+async fn send(ws: &mut WsStream, msg: &WsMessage) -> Result<(), String> {
+    let json = serde_json::to_string(msg).map_err(|e| e.to_string())?;
+    ws.send(Message::Text(json))
+        .await
+        .map_err(|e| format!("send failed: {}", e))
+}
+
+/// Next server message, skipping frames that are not protocol messages
+async fn recv(
+    ws: &mut WsStream,
+    deadline: Instant,
+    waiting_for: &str,
+) -> Result<WsMessage, String> {
+    loop {
+        let next = tokio::time::timeout_at(deadline.into(), ws.next())
+            .await
+            .map_err(|_| format!("timed out waiting for {}", waiting_for))?;
+        match next {
+            Some(Ok(Message::Text(text))) => {
+                if let Ok(msg) = serde_json::from_str::<WsMessage>(&text) {
+                    return Ok(msg);
+                }
+            },
+            Some(Ok(Message::Close(_))) | None => {
+                return Err(format!("connection closed waiting for {}", waiting_for))
+            },
+            Some(Ok(_)) => {},
+            Some(Err(e)) => return Err(format!("receive failed: {}", e)),
+        }
+    }
+}
+
+async fn create_session(client: &reqwest::Client, args: &Args) -> Result<String, String> {
+    let mut request = client.post(format!("{}/api/sessions", args.url));
+    if let Some(ref key) = args.api_key {
+        request = request.bearer_auth(key);
+    }
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("create session failed: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("create session returned {}", response.status()));
+    }
+    let body: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("create session body: {}", e))?;
+    body.get("session_id")
+        .and_then(|id| id.as_str())
+        .map(str::to_string)
+        .ok_or_else(|| "create session response has no session_id".to_string())
+}
+
+async fn connect(args: &Args, session_id: &str) -> Result<WsStream, String> {
+    let url = format!("{}/ws/{}", to_ws_url(&args.url), session_id);
+    let mut request = url
+        .into_client_request()
+        .map_err(|e| format!("invalid WebSocket URL: {}", e))?;
+    if let Some(ref key) = args.api_key {
+        let value = HeaderValue::from_str(&format!("Bearer {}", key))
+            .map_err(|e| format!("invalid API key: {}", e))?;
+        request.headers_mut().insert(AUTHORIZATION, value);
+    }
+    let (mut ws, _) = tokio_tungstenite::connect_async(request)
+        .await
+        .map_err(|e| format!("WebSocket connect failed: {}", e))?;
+
+    let deadline = Instant::now() + args.timeout;
+    while !matches!(
+        recv(&mut ws, deadline, "session_info").await?,
+        WsMessage::SessionInfo { .. }
+    ) {}
+    Ok(ws)
+}
+
+async fn text_turn(
+    ws: &mut WsStream,
+    args: &Args,
+    turn: usize,
+    samples: &mut Samples,
+) -> Result<(), String> {
+    let content = UTTERANCES[turn % UTTERANCES.len()].to_string();
+    let sent = Instant::now();
+    send(ws, &WsMessage::Text { content }).await?;
+
+    let deadline = sent + args.timeout;
+    loop {
+        match recv(ws, deadline, "response").await? {
+            WsMessage::Response { .. } => {
+                samples.response.push(elapsed_ms(sent));
+                return Ok(());
+            },
+            WsMessage::Error { message } => return Err(format!("server error: {}", message)),
+            _ => {},
+        }
+    }
+}
+
+async fn audio_turn(
+    ws: &mut WsStream,
+    args: &Args,
+    seq: &mut u64,
+    samples: &mut Samples,
+) -> Result<(), String> {
+    // Stream speech then silence at real-time pace
+    let speech_frames = SPEECH_MS / FRAME_MS;
+    let total_frames = speech_frames + TRAILING_SILENCE_MS / FRAME_MS;
+    let mut ticker = tokio::time::interval(Duration::from_millis(FRAME_MS as u64));
+    let mut speech_end = Instant::now();
+    for i in 0..total_frames {
+        ticker.tick().await;
+        let pcm = pcm_frame(i * SAMPLE_RATE * FRAME_MS / 1000, i < speech_frames);
+        send(
+            ws,
+            &WsMessage::Audio {
+                data: BASE64.encode(pcm),
+                seq: Some(*seq),
+                speaker: None,
+            },
+        )
+        .await?;
+        *seq += 1;
+        if i + 1 == speech_frames {
+            speech_end = Instant::now();
+        }
+    }
+
+    // Stages arrive in order; anything left over from the previous turn
+    // lands before this turn's final transcript and is skipped
+    let deadline = speech_end + args.timeout;
+    loop {
+        match recv(ws, deadline, "final transcript").await? {
+            WsMessage::Transcript { is_final: true, .. } => break,
+            WsMessage::Error { message } => return Err(format!("server error: {}", message)),
+            _ => {},
+        }
+    }
+    samples.transcript.push(elapsed_ms(speech_end));
+
+    let mut responded = false;
+    let mut spoke = false;
+    while !(responded && spoke) {
+        let waiting_for = if responded {
+            "response audio"
+        } else {
+            "response"
+        };
+        match recv(ws, deadline, waiting_for).await? {
+            WsMessage::Response { .. } if !responded => {
+                samples.response.push(elapsed_ms(speech_end));
+                responded = true;
+            },
+            WsMessage::ResponseAudio { .. } if !spoke => {
+                samples.first_audio.push(elapsed_ms(speech_end));
+                spoke = true;
+            },
+            WsMessage::Error { message } => return Err(format!("server error: {}", message)),
+            _ => {},
+        }
+    }
+    Ok(())
+}
+
+async fn run_session(
+    client: reqwest::Client,
+    args: Arc<Args>,
+    start_delay: Duration,
+) -> SessionResult {
+    let mut result = SessionResult::default();
+    tokio::time::sleep(start_delay).await;
+
+    let started = Instant::now();
+    let session_id = match create_session(&client, &args).await {
+        Ok(id) => id,
+        Err(e) => {
+            result.errors.push(e);
+            return result;
+        },
+    };
+    result.samples.create.push(elapsed_ms(started));
+
+    let started = Instant::now();
+    let mut ws = match connect(&args, &session_id).await {
+        Ok(ws) => ws,
+        Err(e) => {
+            result.errors.push(e);
+            return result;
+        },
+    };
+    result.samples.connect.push(elapsed_ms(started));
+
+    let mut seq = 0;
+    for turn in 0..args.turns {
+        if turn > 0 {
+            tokio::time::sleep(args.think).await;
+        }
+        let outcome = match args.mode {
+            Mode::Text => text_turn(&mut ws, &args, turn, &mut result.samples).await,
+            Mode::Audio => audio_turn(&mut ws, &args, &mut seq, &mut result.samples).await,
+        };
+        match outcome {
+            Ok(()) => result.turns_ok += 1,
+            Err(e) => {
+                // A failed turn leaves the socket in an unknown state
+                result.errors.push(e);
+                break;
+            },
+        }
+    }
+
+    let _ = send(&mut ws, &WsMessage::EndSession).await;
+    let _ = ws.close(None).await;
+    result
+}
+
+async fn run_step(client: &reqwest::Client, args: &Arc<Args>, sessions: usize) -> StepReport {
+    let spacing = args.ramp / sessions as u32;
+    let started = Instant::now();
+    let handles: Vec<_> = (0..sessions)
+        .map(|i| {
+            tokio::spawn(run_session(
+                client.clone(),
+                args.clone(),
+                spacing * i as u32,
+            ))
+        })
+        .collect();
+
+    let mut samples = Samples::default();
+    let mut turns_ok = 0;
+    let mut errors = Vec::new();
+    for handle in handles {
+        match handle.await {
+            Ok(result) => {
+                samples.merge(result.samples);
+                turns_ok += result.turns_ok;
+                errors.extend(result.errors);
+            },
+            Err(e) => errors.push(format!("session task panicked: {}", e)),
+        }
+    }
+    let wall_secs = started.elapsed().as_secs_f64();
+
+    // A session that errored out skipped the rest of its turns
+    let planned = sessions * args.turns;
+    let turns_failed = planned - turns_ok.min(planned);
+    let error_rate = turns_failed as f64 / planned as f64;
+
+    let mut stages = vec![
+        StageSummary::from_samples("create", &samples.create),
+        StageSummary::from_samples("connect", &samples.connect),
+    ];
+    if args.mode == Mode::Audio {
+        stages.push(StageSummary::from_samples(
+            "transcript",
+            &samples.transcript,
+        ));
+    }
+    stages.push(StageSummary::from_samples("response", &samples.response));
+    if args.mode == Mode::Audio {
+        stages.push(StageSummary::from_samples(
+            "first_audio",
+            &samples.first_audio,
+        ));
+    }
+
+    let response_p95 = percentile_of(&stages, "response");
+    let within_budget =
+        turns_ok > 0 && error_rate <= args.max_error_rate && response_p95 <= args.budget_ms;
+
+    errors.sort();
+    errors.dedup();
+    errors.truncate(5);
+
+    StepReport {
+        sessions,
+        turns_ok,
+        turns_failed,
+        error_rate,
+        turns_per_sec: if wall_secs > 0.0 {
+            turns_ok as f64 / wall_secs
+        } else {
+            0.0
+        },
+        stages,
+        errors,
+        within_budget,
+    }
+}
+
+fn percentile_of(stages: &[StageSummary], name: &str) -> f64 {
+    stages
+        .iter()
+        .find(|s| s.stage == name)
+        .map_or(0.0, |s| s.p95_ms)
+}
+
+/// Create, touch, read and delete session rows from `workers` concurrent tasks
+async fn run_store_step(store: &ScyllaSessionStore, workers: usize, ops: usize) -> StoreReport {
+    let started = Instant::now();
+    let handles: Vec<_> = (0..workers)
+        .map(|_| {
+            let store = store.clone();
+            tokio::spawn(async move {
+                let mut latencies = Vec::with_capacity(ops);
+                let mut failed = 0;
+                let mut op = 0;
+                while op < ops {
+                    let id = format!("loadtest-{}", uuid::Uuid::new_v4());
+                    let data = SessionData::new(&id);
+                    for step in 0..4 {
+                        if op == ops {
+                            break;
+                        }
+                        let t = Instant::now();
+                        let ok = match step {
+                            0 => store.create(&data).await.is_ok(),
+                            1 => store.touch(&id).await.is_ok(),
+                            2 => store.get(&id).await.is_ok(),
+                            _ => store.delete(&id).await.is_ok(),
+                        };
+                        latencies.push(elapsed_ms(t));
+                        if !ok {
+                            failed += 1;
+                        }
+                        op += 1;
+                    }
+                }
+                (latencies, failed)
+            })
+        })
+        .collect();
+
+    let mut latencies = Vec::with_capacity(workers * ops);
+    let mut failed = 0;
+    for handle in handles {
+        match handle.await {
+            Ok((l, f)) => {
+                latencies.extend(l);
+                failed += f;
+            },
+            Err(_) => failed += ops,
+        }
+    }
+    let wall_secs = started.elapsed().as_secs_f64();
+    let completed = latencies.len() - failed.min(latencies.len());
+
+    StoreReport {
+        workers,
+        ops: latencies.len(),
+        failed,
+        ops_per_sec: if wall_secs > 0.0 {
+            completed as f64 / wall_secs
+        } else {
+            0.0
+        },
+        latency: StageSummary::from_samples("store_op", &latencies),
+    }
+}
+
+fn print_report(report: &Report) {
+    for step in &report.steps {
+        println!(
+            "{} sessions: {} turns ok, {} failed ({:.1}%), {:.1} turns/s{}",
+            step.sessions,
+            step.turns_ok,
+            step.turns_failed,
+            step.error_rate * 100.0,
+            step.turns_per_sec,
+            if step.within_budget {
+                ""
+            } else {
+                "  OVER BUDGET"
+            }
+        );
+        for stage in &step.stages {
+            if stage.count == 0 {
+                continue;
+            }
+            println!(
+                "  {:<12} n={:<5} p50={:>7.0}ms p95={:>7.0}ms p99={:>7.0}ms max={:>7.0}ms",
+                stage.stage, stage.count, stage.p50_ms, stage.p95_ms, stage.p99_ms, stage.max_ms
+            );
+        }
+        for error in &step.errors {
+            println!("  error: {}", error);
+        }
+    }
+
+    for store in &report.store {
+        println!(
+            "store {} workers: {} ops, {} failed, {:.0} ops/s, p95={:.1}ms p99={:.1}ms",
+            store.workers,
+            store.ops,
+            store.failed,
+            store.ops_per_sec,
+            store.latency.p95_ms,
+            store.latency.p99_ms
+        );
+    }
+
+    match report.capacity {
+        Some(sessions) => println!(
+            "Capacity: {} concurrent sessions within {:.0}ms p95 ({} mode)",
+            sessions, report.budget_ms, report.mode
+        ),
+        None => println!(
+            "Capacity: below {} sessions; the smallest step exceeded the budget",
+            report.steps.first().map_or(0, |s| s.sessions)
+        ),
+    }
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => Arc::new(args),
+        Err(msg) => {
+            if !msg.is_empty() {
+                eprintln!("{}\n", msg);
+            }
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        },
+    };
+
+    let client = reqwest::Client::new();
+    let mut steps = Vec::new();
+    for &sessions in &args.steps {
+        if !args.json {
+            eprintln!("Running {} concurrent sessions...", sessions);
+        }
+        let step = run_step(&client, &args, sessions).await;
+        let over = !step.within_budget;
+        steps.push(step);
+        // Past the limit, larger steps only add noise and load
+        if over {
+            break;
+        }
+    }
+
+    let mut store = Vec::new();
+    if let Some(ref hosts) = args.scylla_hosts {
+        let config = ScyllaConfig {
+            hosts: hosts.clone(),
+            ..ScyllaConfig::default()
+        };
+        match ScyllaClient::connect(config).await {
+            Ok(client) => {
+                if let Err(e) = client.ensure_schema().await {
+                    eprintln!("Failed to prepare ScyllaDB schema: {}", e);
+                } else {
+                    let session_store = ScyllaSessionStore::new(client);
+                    for &workers in &args.steps {
+                        if !args.json {
+                            eprintln!("Measuring session store with {} workers...", workers);
+                        }
+                        store.push(run_store_step(&session_store, workers, args.scylla_ops).await);
+                    }
+                }
+            },
+            Err(e) => eprintln!("Failed to connect to ScyllaDB: {}", e),
+        }
+    }
+
+    let capacity = steps
+        .iter()
+        .take_while(|s| s.within_budget)
+        .last()
+        .map(|s| s.sessions);
+    let report = Report {
+        mode: match args.mode {
+            Mode::Text => "text",
+            Mode::Audio => "audio",
+        },
+        budget_ms: args.budget_ms,
+        steps,
+        capacity,
+        store,
+    };
+
+    if args.json {
+        match serde_json::to_string_pretty(&report) {
+            Ok(json) => println!("{}", json),
+            Err(e) => eprintln!("Failed to serialize report: {}", e),
+        }
+    } else {
+        print_report(&report);
+    }
+
+    if report.capacity == args.steps.last().copied() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ws_url() {
+        assert_eq!(to_ws_url("http://localhost:8080"), "ws://localhost:8080");
+        assert_eq!(to_ws_url("https://agent.example"), "wss://agent.example");
+    }
+
+    #[test]
+    fn test_pcm_frame_length() {
+        let voiced = pcm_frame(0, true);
+        assert_eq!(voiced.len(), SAMPLE_RATE * FRAME_MS / 1000 * 2);
+        assert!(voiced.iter().any(|&b| b != 0));
+        assert!(pcm_frame(0, false).iter().all(|&b| b == 0));
+    }
+}
@@ -0,0 +1,502 @@
+//! voicectl: maintenance commands for operators
+//!
+//! Runs routine maintenance through the same library code the server uses:
+//! settings and domain validation, schema migrations, audit export, session
+//! purge, a test SMS, a test TTS phrase, transcription of a WAV file and
+//! scripted conversation scenarios.
+//!
+//! ```text
+//! voicectl COMMAND [--env ENV] [--config-dir DIR] [--domain ID] [OPTIONS]
+//! ```
+//!
+//! Settings load like the server's (`config/default.yaml`, then
+//! `config/{env}.yaml`, then `VOICE_AGENT__*` variables); `--env` defaults to
+//! `VOICE_AGENT_ENV`. Persistence commands connect to the configured backend
+//! whether or not `persistence.enabled` is set.
+//!
+//! Exit codes: 0 = success, 1 = the command failed, 2 = usage or load error.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Arc;
+
+use chrono::{NaiveDate, NaiveTime, Utc};
+
+use voice_agent_agent::simulator::{load_scenarios, Simulator};
+use voice_agent_agent::AgentConfig;
+use voice_agent_config::{load_settings, ConfigValidator, MasterDomainConfig, Settings};
+use voice_agent_persistence::{AuditQuery, PersistenceLayer, SmsType};
+use voice_agent_pipeline::BatchEngine;
+use voice_agent_server::models::{load_stt_engine, load_tts_engine};
+use voice_agent_server::storage::init_persistence;
+
+const USAGE: &str = "Usage: voicectl COMMAND [--env ENV] [--config-dir DIR] [--domain ID] [OPTIONS]
+
+Commands:
+  validate-config                 Check settings and the domain config
+  migrate                         Create or update the persistence schema
+  export-audit --from DATE [--to DATE] [--session ID] [--limit N] [--out FILE]
+                                  Write audit entries as JSON lines (default: stdout)
+  purge-sessions                  Delete expired sessions
+  send-sms --phone PHONE --message TEXT
+                                  Send a test SMS through the SMS service
+  synthesize --text TEXT [--out FILE]
+                                  Speak a test phrase to a WAV file (default: voicectl.wav)
+  transcribe FILE                 Transcribe a 16 kHz WAV file
+  simulate [--json] [PATH...]     Run scripted conversation scenarios
+                                  (default: {config-dir}/domains/{domain}/scenarios)
+
+Options:
+  --env ENV           Settings overlay config/{ENV}.yaml (default: VOICE_AGENT_ENV)
+  --config-dir DIR    Config root containing domains/ (default: config)
+  --domain ID         Domain to load (default: DOMAIN_ID or the default domain)";
+
+/// Audit entries exported when `--limit` is omitted
+const DEFAULT_AUDIT_LIMIT: i32 = 100_000;
+/// Sample rate the STT models expect
+const STT_SAMPLE_RATE: u32 = 16000;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Command {
+    ValidateConfig,
+    Migrate,
+    ExportAudit,
+    PurgeSessions,
+    SendSms,
+    Synthesize,
+    Transcribe,
+    Simulate,
+}
+
+struct Args {
+    command: Command,
+    env: Option<String>,
+    config_dir: PathBuf,
+    domain: Option<String>,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+    session: Option<String>,
+    limit: i32,
+    out: Option<PathBuf>,
+    phone: Option<String>,
+    message: Option<String>,
+    text: Option<String>,
+    json: bool,
+    paths: Vec<PathBuf>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    fn value(iter: &mut impl Iterator<Item = String>, flag: &str) -> Result<String, String> {
+        iter.next().ok_or(format!("{} requires a value", flag))
+    }
+    fn date(raw: String, flag: &str) -> Result<NaiveDate, String> {
+        raw.parse()
+            .map_err(|_| format!("{} expects YYYY-MM-DD, got '{}'", flag, raw))
+    }
+
+    let mut iter = std::env::args().skip(1);
+    let command = match iter.next().as_deref() {
+        Some("validate-config") => Command::ValidateConfig,
+        Some("migrate") => Command::Migrate,
+        Some("export-audit") => Command::ExportAudit,
+        Some("purge-sessions") => Command::PurgeSessions,
+        Some("send-sms") => Command::SendSms,
+        Some("synthesize") => Command::Synthesize,
+        Some("transcribe") => Command::Transcribe,
+        Some("simulate") => Command::Simulate,
+        Some("-h") | Some("--help") | None => return Err(String::new()),
+        Some(other) => return Err(format!("Unknown command: {}", other)),
+    };
+
+    let mut args = Args {
+        command,
+        env: std::env::var("VOICE_AGENT_ENV").ok(),
+        config_dir: PathBuf::from("config"),
+        domain: None,
+        from: None,
+        to: None,
+        session: None,
+        limit: DEFAULT_AUDIT_LIMIT,
+        out: None,
+        phone: None,
+        message: None,
+        text: None,
+        json: false,
+        paths: Vec::new(),
+    };
+
+    while let Some(arg) = iter.next() {
+        let flag = arg.as_str();
+        match flag {
+            "--env" => args.env = Some(value(&mut iter, flag)?),
+            "--config-dir" => args.config_dir = PathBuf::from(value(&mut iter, flag)?),
+            "--domain" => args.domain = Some(value(&mut iter, flag)?),
+            "--from" => args.from = Some(date(value(&mut iter, flag)?, flag)?),
+            "--to" => args.to = Some(date(value(&mut iter, flag)?, flag)?),
+            "--session" => args.session = Some(value(&mut iter, flag)?),
+            "--limit" => {
+                let raw = value(&mut iter, flag)?;
+                args.limit = raw
+                    .parse()
+                    .map_err(|_| format!("{} expects a number, got '{}'", flag, raw))?;
+            },
+            "--out" => args.out = Some(PathBuf::from(value(&mut iter, flag)?)),
+            "--phone" => args.phone = Some(value(&mut iter, flag)?),
+            "--message" => args.message = Some(value(&mut iter, flag)?),
+            "--text" => args.text = Some(value(&mut iter, flag)?),
+            "--json" => args.json = true,
+            "-h" | "--help" => return Err(String::new()),
+            other if other.starts_with('-') => return Err(format!("Unknown option: {}", other)),
+            path => args.paths.push(PathBuf::from(path)),
+        }
+    }
+
+    let required = |present: bool, what: &str| {
+        if present {
+            Ok(())
+        } else {
+            Err(format!("{} requires {}", arg_name(command), what))
+        }
+    };
+    match command {
+        Command::ExportAudit => required(args.from.is_some(), "--from")?,
+        Command::SendSms => required(
+            args.phone.is_some() && args.message.is_some(),
+            "--phone and --message",
+        )?,
+        Command::Synthesize => required(args.text.is_some(), "--text")?,
+        Command::Transcribe => required(args.paths.len() == 1, "one WAV file")?,
+        _ => {},
+    }
+
+    Ok(args)
+}
+
+fn arg_name(command: Command) -> &'static str {
+    match command {
+        Command::ValidateConfig => "validate-config",
+        Command::Migrate => "migrate",
+        Command::ExportAudit => "export-audit",
+        Command::PurgeSessions => "purge-sessions",
+        Command::SendSms => "send-sms",
+        Command::Synthesize => "synthesize",
+        Command::Transcribe => "transcribe",
+        Command::Simulate => "simulate",
+    }
+}
+
+fn load_domain(args: &Args) -> Result<Arc<MasterDomainConfig>, String> {
+    let loaded = match args.domain {
+        Some(ref domain) => MasterDomainConfig::load(domain, &args.config_dir),
+        None => MasterDomainConfig::load_from_env(&args.config_dir),
+    };
+    loaded
+        .map(Arc::new)
+        .map_err(|e| format!("Failed to load domain config: {}", e))
+}
+
+async fn connect(
+    settings: &Settings,
+    domain: Arc<MasterDomainConfig>,
+) -> Result<PersistenceLayer, String> {
+    init_persistence(settings, domain).await.map_err(|e| {
+        format!(
+            "Failed to connect to {} persistence: {}",
+            settings.persistence.backend.as_str(),
+            e
+        )
+    })
+}
+
+/// Outcome of a command: `Ok(false)` when it ran but found problems
+type CommandResult = Result<bool, String>;
+
+fn validate_config(domain: &MasterDomainConfig) -> CommandResult {
+    // Settings were validated when they loaded
+    println!("Settings: ok");
+    let result = ConfigValidator::new().validate(&domain.domain_id, domain);
+    for error in &result.errors {
+        println!("  {}", error);
+    }
+    println!("{}", result.summary());
+    Ok(result.is_ok())
+}
+
+async fn migrate(settings: &Settings, domain: Arc<MasterDomainConfig>) -> CommandResult {
+    // Connecting creates any missing keyspace, tables and indexes
+    connect(settings, domain).await?;
+    println!(
+        "Schema up to date ({} backend)",
+        settings.persistence.backend.as_str()
+    );
+    Ok(true)
+}
+
+async fn export_audit(
+    args: &Args,
+    settings: &Settings,
+    domain: Arc<MasterDomainConfig>,
+) -> CommandResult {
+    let persistence = connect(settings, domain).await?;
+    let from = args.from.unwrap_or_else(|| Utc::now().date_naive());
+    let to = args.to.unwrap_or_else(|| Utc::now().date_naive());
+    let query = AuditQuery {
+        session_id: args.session.clone(),
+        from: Some(from.and_time(NaiveTime::MIN).and_utc()),
+        to: to
+            .and_hms_milli_opt(23, 59, 59, 999)
+            .map(|end| end.and_utc()),
+        limit: Some(args.limit),
+        ..Default::default()
+    };
+    let entries = persistence
+        .audit
+        .query(query)
+        .await
+        .map_err(|e| format!("Audit query failed: {}", e))?;
+
+    let mut lines = String::new();
+    for entry in &entries {
+        let line = serde_json::to_string(entry)
+            .map_err(|e| format!("Failed to serialize audit entry: {}", e))?;
+        lines.push_str(&line);
+        lines.push('\n');
+    }
+    match args.out {
+        Some(ref path) => {
+            std::fs::write(path, lines)
+                .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+            eprintln!(
+                "Exported {} audit entries to {}",
+                entries.len(),
+                path.display()
+            );
+        },
+        None => print!("{}", lines),
+    }
+    Ok(true)
+}
+
+async fn purge_sessions(settings: &Settings, domain: Arc<MasterDomainConfig>) -> CommandResult {
+    let persistence = connect(settings, domain).await?;
+    let purged = persistence
+        .sessions
+        .purge_expired()
+        .await
+        .map_err(|e| format!("Session purge failed: {}", e))?;
+    println!("Purged {} expired sessions", purged);
+    Ok(true)
+}
+
+async fn send_sms(
+    args: &Args,
+    settings: &Settings,
+    domain: Arc<MasterDomainConfig>,
+) -> CommandResult {
+    let persistence = connect(settings, domain).await?;
+    let phone = args.phone.as_deref().unwrap_or_default();
+    let message = args.message.as_deref().unwrap_or_default();
+    let result = persistence
+        .sms
+        .send_sms(phone, message, SmsType::FollowUp, None)
+        .await
+        .map_err(|e| format!("SMS failed: {}", e))?;
+    println!(
+        "SMS {} {:?}{}",
+        result.message_id,
+        result.status,
+        if result.simulated { " (simulated)" } else { "" }
+    );
+    Ok(true)
+}
+
+async fn synthesize(args: &Args, settings: &Settings) -> CommandResult {
+    let engine = load_tts_engine(settings)?;
+    let backend = engine.backend();
+    let text = args.text.as_deref().unwrap_or_default();
+    let samples = backend
+        .synthesize(text)
+        .await
+        .map_err(|e| format!("Synthesis failed: {}", e))?;
+
+    let path = args
+        .out
+        .clone()
+        .unwrap_or_else(|| PathBuf::from("voicectl.wav"));
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: backend.sample_rate(),
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let write = || -> Result<(), hound::Error> {
+        let mut writer = hound::WavWriter::create(&path, spec)?;
+        for sample in &samples {
+            writer.write_sample((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)?;
+        }
+        writer.finalize()
+    };
+    write().map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+    println!(
+        "Wrote {:.2}s of audio to {}",
+        samples.len() as f32 / spec.sample_rate as f32,
+        path.display()
+    );
+    Ok(true)
+}
+
+/// Mono samples in [-1, 1] from a WAV file, channels averaged
+fn read_wav(path: &PathBuf) -> Result<(Vec<f32>, u32), String> {
+    let mut reader = hound::WavReader::open(path)
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    let spec = reader.spec();
+    let samples: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => reader.samples::<f32>().filter_map(Result::ok).collect(),
+        hound::SampleFormat::Int => {
+            let max = (1i64 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .filter_map(Result::ok)
+                .map(|s| s as f32 / max)
+                .collect()
+        },
+    };
+    let channels = spec.channels.max(1) as usize;
+    let mono = samples
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+        .collect();
+    Ok((mono, spec.sample_rate))
+}
+
+async fn transcribe(args: &Args) -> CommandResult {
+    let (audio, sample_rate) = read_wav(&args.paths[0])?;
+    if sample_rate != STT_SAMPLE_RATE {
+        return Err(format!(
+            "Expected {} Hz audio, got {} Hz",
+            STT_SAMPLE_RATE, sample_rate
+        ));
+    }
+
+    let engine = load_stt_engine(1)?;
+    let result = engine
+        .run_batch(vec![audio])
+        .await
+        .pop()
+        .ok_or("STT returned no result")?
+        .map_err(|e| format!("Transcription failed: {}", e))?;
+    println!("{}", result.text);
+    eprintln!("confidence {:.2}", result.confidence);
+    Ok(true)
+}
+
+async fn simulate(args: &Args, domain: Arc<MasterDomainConfig>) -> CommandResult {
+    let paths = if args.paths.is_empty() {
+        vec![args
+            .config_dir
+            .join("domains")
+            .join(&domain.domain_id)
+            .join("scenarios")]
+    } else {
+        args.paths.clone()
+    };
+    let mut scenarios = Vec::new();
+    for path in &paths {
+        scenarios.extend(load_scenarios(path).map_err(|e| e.to_string())?);
+    }
+    if scenarios.is_empty() {
+        return Err("No scenarios to run".to_string());
+    }
+
+    let reports = Simulator::new(domain, AgentConfig::default())
+        .run_all(&scenarios)
+        .await;
+    let failed = reports.iter().filter(|r| !r.passed()).count();
+    if args.json {
+        let json = serde_json::to_string_pretty(&reports)
+            .map_err(|e| format!("Failed to serialize reports: {}", e))?;
+        println!("{}", json);
+    } else {
+        for report in &reports {
+            let status = if report.passed() { "PASS" } else { "FAIL" };
+            println!("{} {} ({} turns)", status, report.name, report.turns);
+            for failure in &report.failures {
+                println!("  turn {}: {}", failure.turn, failure.message);
+            }
+        }
+        println!(
+            "{}/{} scenarios passed",
+            reports.len() - failed,
+            reports.len()
+        );
+    }
+    Ok(failed == 0)
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(msg) => {
+            if !msg.is_empty() {
+                eprintln!("{}\n", msg);
+            }
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        },
+    };
+
+    tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("warn")),
+        )
+        .init();
+
+    let settings = match load_settings(args.env.as_deref()) {
+        Ok(settings) => settings,
+        Err(e) => {
+            eprintln!("Invalid settings: {}", e);
+            return ExitCode::from(if args.command == Command::ValidateConfig {
+                1
+            } else {
+                2
+            });
+        },
+    };
+    let needs_domain = !matches!(args.command, Command::Synthesize | Command::Transcribe);
+    let domain = if needs_domain {
+        match load_domain(&args) {
+            Ok(domain) => Some(domain),
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::from(2);
+            },
+        }
+    } else {
+        None
+    };
+    let domain = || domain.clone().expect("domain config loaded");
+
+    let result = match args.command {
+        Command::ValidateConfig => validate_config(&domain()),
+        Command::Migrate => migrate(&settings, domain()).await,
+        Command::ExportAudit => export_audit(&args, &settings, domain()).await,
+        Command::PurgeSessions => purge_sessions(&settings, domain()).await,
+        Command::SendSms => send_sms(&args, &settings, domain()).await,
+        Command::Synthesize => synthesize(&args, &settings).await,
+        Command::Transcribe => transcribe(&args).await,
+        Command::Simulate => simulate(&args, domain()).await,
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::from(1)
+        },
+    }
+}
@@ -0,0 +1,152 @@
+//! Live Captions
+//!
+//! Web clients render live captions from the WebSocket stream:
+//! - STT partials arrive as `transcript` messages numbered by `utterance`.
+//!   Each replaces the last one of its utterance, and its `words` carry
+//!   timings and a `stable` flag for the leading words that have stopped
+//!   changing
+//! - Agent replies stream as `response` chunks numbered by `turn` (the
+//!   utterance they answer), closed by a `response_end` with the full text
+
+use serde::{Deserialize, Serialize};
+use voice_agent_core::TranscriptResult;
+
+use crate::websocket::WsMessage;
+
+/// Partials a word must survive unchanged before it is marked stable
+const STABLE_AFTER: u32 = 2;
+
+/// A captioned word
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CaptionWord {
+    pub word: String,
+    /// Offset from the start of the stream (ms), when the STT reports it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_ms: Option<u64>,
+    /// Unchanged over the last partials; not expected to change again
+    pub stable: bool,
+}
+
+/// Numbers utterances and marks the stable prefix of their partials
+#[derive(Debug, Default)]
+pub struct CaptionTracker {
+    /// Utterance in progress
+    utterance: u64,
+    /// Words of the last partial
+    previous: Vec<String>,
+    /// Partials in a row each word has been unchanged for
+    agreed: Vec<u32>,
+}
+
+impl CaptionTracker {
+    /// Caption a transcript; a final one closes the utterance
+    ///
+    /// Returns the message for the client and the utterance it belongs to.
+    pub fn transcript(&mut self, transcript: &TranscriptResult) -> (WsMessage, u64) {
+        let mut words: Vec<CaptionWord> = if transcript.words.is_empty() {
+            transcript
+                .text
+                .split_whitespace()
+                .map(|word| CaptionWord {
+                    word: word.to_string(),
+                    start_ms: None,
+                    end_ms: None,
+                    stable: false,
+                })
+                .collect()
+        } else {
+            transcript
+                .words
+                .iter()
+                .map(|word| CaptionWord {
+                    word: word.word.clone(),
+                    start_ms: Some(word.start_ms),
+                    end_ms: Some(word.end_ms),
+                    stable: false,
+                })
+                .collect()
+        };
+
+        let utterance = self.utterance;
+        if transcript.is_final {
+            words.iter_mut().for_each(|word| word.stable = true);
+            self.utterance += 1;
+            self.previous.clear();
+            self.agreed.clear();
+        } else {
+            let agreed: Vec<u32> = words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| match self.previous.get(i) {
+                    Some(previous) if *previous == word.word => self.agreed[i] + 1,
+                    _ => 1,
+                })
+                .collect();
+            // Only a prefix is stable: a revised word unsettles those after it
+            for (word, &count) in words.iter_mut().zip(&agreed) {
+                if count < STABLE_AFTER {
+                    break;
+                }
+                word.stable = true;
+            }
+            self.previous = words.iter().map(|word| word.word.clone()).collect();
+            self.agreed = agreed;
+        }
+
+        let message = WsMessage::Transcript {
+            text: transcript.text.clone(),
+            is_final: transcript.is_final,
+            utterance: Some(utterance),
+            words,
+        };
+        (message, utterance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stable_words(message: &WsMessage) -> Vec<bool> {
+        match message {
+            WsMessage::Transcript { words, .. } => words.iter().map(|w| w.stable).collect(),
+            _ => panic!("not a transcript"),
+        }
+    }
+
+    #[test]
+    fn test_partials_settle_into_stable_prefix() {
+        let mut captions = CaptionTracker::default();
+        let partial = |text: &str| TranscriptResult::new(text.to_string(), false, 0.9);
+
+        let (message, utterance) = captions.transcript(&partial("mujhe gold"));
+        assert_eq!(utterance, 0);
+        assert_eq!(stable_words(&message), vec![false, false]);
+
+        let (message, _) = captions.transcript(&partial("mujhe gold loan"));
+        assert_eq!(stable_words(&message), vec![true, true, false]);
+
+        // A revised word unsettles the words after it
+        let (message, _) = captions.transcript(&partial("mujhe kold loan chahiye"));
+        assert_eq!(stable_words(&message), vec![true, false, false, false]);
+
+        let (message, utterance) = captions.transcript(&TranscriptResult::new(
+            "mujhe gold loan chahiye".to_string(),
+            true,
+            0.9,
+        ));
+        assert_eq!(utterance, 0);
+        assert_eq!(stable_words(&message), vec![true; 4]);
+
+        let json = serde_json::to_value(&message).unwrap();
+        assert_eq!(json["utterance"], 0);
+        assert_eq!(json["words"][0]["word"], "mujhe");
+        assert!(json["words"][0].get("start_ms").is_none());
+
+        // The next utterance starts over
+        let (_, utterance) = captions.transcript(&partial("haan"));
+        assert_eq!(utterance, 1);
+    }
+}
@@ -0,0 +1,406 @@
+//! Graceful degradation manager
+//!
+//! Models that fail to load fall back to stubs (silent TTS, empty STT,
+//! pass-through translation) and ScyllaDB falls back to in-memory sessions.
+//! Without tracking, such a run looks healthy while doing nothing useful.
+//!
+//! Every fallback is reported here. Components listed in
+//! `degradation.required` abort startup when not healthy; the rest degrade
+//! with a warning. `/health` reports per-component status.
+
+use parking_lot::RwLock;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::time::SystemTime;
+
+use voice_agent_config::agent::LlmProvider;
+use voice_agent_config::{DegradationConfig, PipelineComponent};
+
+use crate::state::AppState;
+
+/// STT model directory the voice pipeline loads (onnx builds)
+pub const STT_MODEL_DIR: &str = "models/stt/indicconformer";
+
+/// TTS model directory the voice pipeline loads (onnx + candle builds)
+pub const TTS_MODEL_DIR: &str = "models/tts/IndicF5";
+
+/// Health of one component
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentStatus {
+    /// Not reported yet
+    Unknown,
+    /// Loaded and working
+    Healthy,
+    /// Running on a fallback (stub backend, in-memory store, pass-through)
+    Degraded,
+    /// Unavailable with no fallback
+    Failed,
+    /// Turned off in config
+    Disabled,
+}
+
+impl ComponentStatus {
+    /// Whether a required component in this state is acceptable
+    fn satisfies_required(&self) -> bool {
+        matches!(self, Self::Healthy)
+    }
+}
+
+/// Latest report for a component
+#[derive(Debug, Clone, Serialize)]
+pub struct ComponentHealth {
+    pub status: ComponentStatus,
+    /// What was loaded, or why it fell back
+    pub detail: String,
+    pub required: bool,
+    #[serde(with = "unix_seconds")]
+    pub updated_at: SystemTime,
+}
+
+/// Overall service health derived from component reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverallHealth {
+    Healthy,
+    /// Optional components are running on fallbacks
+    Degraded,
+    /// A required component is not healthy
+    Unhealthy,
+}
+
+/// Tracks component health and enforces the required/optional policy
+#[derive(Debug)]
+pub struct DegradationManager {
+    config: DegradationConfig,
+    components: RwLock<HashMap<PipelineComponent, ComponentHealth>>,
+}
+
+impl DegradationManager {
+    pub fn new(config: DegradationConfig) -> Self {
+        Self {
+            config,
+            components: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Record a component's status
+    ///
+    /// Degraded and failed reports are logged at warn level, or error level
+    /// for required components.
+    pub fn report(
+        &self,
+        component: PipelineComponent,
+        status: ComponentStatus,
+        detail: impl Into<String>,
+    ) {
+        let detail = detail.into();
+        let required = self.config.is_required(component);
+
+        match status {
+            ComponentStatus::Degraded | ComponentStatus::Failed if required => {
+                tracing::error!(
+                    component = component.as_str(),
+                    ?status,
+                    "Required component unavailable: {}",
+                    detail
+                );
+            },
+            ComponentStatus::Degraded | ComponentStatus::Failed => {
+                tracing::warn!(
+                    component = component.as_str(),
+                    ?status,
+                    "Component degraded: {}",
+                    detail
+                );
+            },
+            _ => {
+                tracing::info!(component = component.as_str(), ?status, "{}", detail);
+            },
+        }
+
+        self.components.write().insert(
+            component,
+            ComponentHealth {
+                status,
+                detail,
+                required,
+                updated_at: SystemTime::now(),
+            },
+        );
+    }
+
+    pub fn healthy(&self, component: PipelineComponent, detail: impl Into<String>) {
+        self.report(component, ComponentStatus::Healthy, detail);
+    }
+
+    pub fn degraded(&self, component: PipelineComponent, detail: impl Into<String>) {
+        self.report(component, ComponentStatus::Degraded, detail);
+    }
+
+    pub fn failed(&self, component: PipelineComponent, detail: impl Into<String>) {
+        self.report(component, ComponentStatus::Failed, detail);
+    }
+
+    pub fn disabled(&self, component: PipelineComponent, detail: impl Into<String>) {
+        self.report(component, ComponentStatus::Disabled, detail);
+    }
+
+    /// Current status of a component
+    pub fn status(&self, component: PipelineComponent) -> ComponentStatus {
+        self.components
+            .read()
+            .get(&component)
+            .map(|health| health.status)
+            .unwrap_or(ComponentStatus::Unknown)
+    }
+
+    /// Every tracked component, including unreported ones
+    pub fn snapshot(&self) -> Vec<(PipelineComponent, ComponentHealth)> {
+        let components = self.components.read();
+        PipelineComponent::ALL
+            .iter()
+            .map(|component| {
+                let health =
+                    components
+                        .get(component)
+                        .cloned()
+                        .unwrap_or_else(|| ComponentHealth {
+                            status: ComponentStatus::Unknown,
+                            detail: "not reported".to_string(),
+                            required: self.config.is_required(*component),
+                            updated_at: SystemTime::UNIX_EPOCH,
+                        });
+                (*component, health)
+            })
+            .collect()
+    }
+
+    /// Required components that are not healthy, with the reason
+    pub fn unmet_requirements(&self) -> Vec<(PipelineComponent, String)> {
+        self.snapshot()
+            .into_iter()
+            .filter(|(_, health)| health.required && !health.status.satisfies_required())
+            .map(|(component, health)| (component, health.detail))
+            .collect()
+    }
+
+    /// Fail startup if a required component is not healthy
+    pub fn check_startup(&self) -> Result<(), String> {
+        let unmet = self.unmet_requirements();
+        if unmet.is_empty() {
+            return Ok(());
+        }
+        Err(unmet
+            .iter()
+            .map(|(component, detail)| format!("{}: {}", component.as_str(), detail))
+            .collect::<Vec<_>>()
+            .join("; "))
+    }
+
+    pub fn overall(&self) -> OverallHealth {
+        let snapshot = self.snapshot();
+        if snapshot
+            .iter()
+            .any(|(_, health)| health.required && !health.status.satisfies_required())
+        {
+            OverallHealth::Unhealthy
+        } else if snapshot.iter().any(|(_, health)| {
+            matches!(
+                health.status,
+                ComponentStatus::Degraded | ComponentStatus::Failed
+            )
+        }) {
+            OverallHealth::Degraded
+        } else {
+            OverallHealth::Healthy
+        }
+    }
+
+    /// Per-component status for the health endpoint
+    pub fn to_json(&self) -> serde_json::Value {
+        let components: serde_json::Map<String, serde_json::Value> = self
+            .snapshot()
+            .into_iter()
+            .map(|(component, health)| {
+                (
+                    component.as_str().to_string(),
+                    serde_json::to_value(health).unwrap_or_default(),
+                )
+            })
+            .collect();
+        serde_json::Value::Object(components)
+    }
+}
+
+impl Default for DegradationManager {
+    fn default() -> Self {
+        Self::new(DegradationConfig::default())
+    }
+}
+
+/// Report STT, TTS, LLM and translation health at startup
+///
+/// ScyllaDB is reported where persistence is initialized.
+pub async fn probe_components(state: &AppState) {
+    let degradation = &state.degradation;
+    let (llm, router_backends) = {
+        let config = state.get_config();
+        (
+            config.agent.llm.clone(),
+            state
+                .models
+                .active()
+                .models
+                .llm_router
+                .as_ref()
+                .map(|router| router.backend_names()),
+        )
+    };
+
+    // STT: IndicConformer needs the onnx build; otherwise sessions get the stub
+    if !cfg!(feature = "onnx") {
+        degradation.degraded(
+            PipelineComponent::Stt,
+            "built without the onnx feature; voice sessions use the stub STT",
+        );
+    } else if std::path::Path::new(STT_MODEL_DIR).exists() {
+        degradation.healthy(
+            PipelineComponent::Stt,
+            format!("IndicConformer at {}", STT_MODEL_DIR),
+        );
+    } else {
+        degradation.failed(
+            PipelineComponent::Stt,
+            format!("IndicConformer model not found at {}", STT_MODEL_DIR),
+        );
+    }
+
+    // TTS: IndicF5 needs onnx (real pipeline) and candle (model); else silence
+    if !cfg!(all(feature = "onnx", feature = "candle")) {
+        degradation.degraded(
+            PipelineComponent::Tts,
+            "built without the onnx and candle features; TTS output is silence",
+        );
+    } else if std::path::Path::new(TTS_MODEL_DIR).exists() {
+        degradation.healthy(
+            PipelineComponent::Tts,
+            format!("IndicF5 at {}", TTS_MODEL_DIR),
+        );
+    } else {
+        degradation.degraded(
+            PipelineComponent::Tts,
+            format!(
+                "IndicF5 model not found at {}; TTS output is silence",
+                TTS_MODEL_DIR
+            ),
+        );
+    }
+
+    // LLM: the router health-checks its own backends; otherwise probe Ollama
+    match router_backends {
+        Some(backends) => degradation.healthy(
+            PipelineComponent::Llm,
+            format!("LLM router with backends {:?}", backends),
+        ),
+        None if llm.provider == LlmProvider::Ollama => {
+            let url = format!("{}/api/tags", llm.endpoint);
+            let probe =
+                tokio::time::timeout(std::time::Duration::from_secs(2), reqwest::get(&url)).await;
+            match probe {
+                Ok(Ok(resp)) if resp.status().is_success() => degradation.healthy(
+                    PipelineComponent::Llm,
+                    format!("Ollama at {}", llm.endpoint),
+                ),
+                Ok(Ok(resp)) => degradation.failed(
+                    PipelineComponent::Llm,
+                    format!("Ollama at {} returned {}", llm.endpoint, resp.status()),
+                ),
+                Ok(Err(e)) => degradation.failed(
+                    PipelineComponent::Llm,
+                    format!("Ollama at {} unreachable: {}", llm.endpoint, e),
+                ),
+                Err(_) => degradation.failed(
+                    PipelineComponent::Llm,
+                    format!("Ollama at {} timed out", llm.endpoint),
+                ),
+            }
+        },
+        None if llm.api_key.as_deref().is_some_and(|key| !key.is_empty()) => degradation.healthy(
+            PipelineComponent::Llm,
+            format!("{:?} API configured", llm.provider),
+        ),
+        None => degradation.failed(
+            PipelineComponent::Llm,
+            format!("{:?} selected but no API key configured", llm.provider),
+        ),
+    }
+
+    // Translation: a provider that fails to load leaves the pass-through translator
+    match state.translator.name() {
+        "noop" => degradation.degraded(
+            PipelineComponent::Translation,
+            "no translation model loaded; text passes through untranslated",
+        ),
+        name => degradation.healthy(
+            PipelineComponent::Translation,
+            format!("{} translator", name),
+        ),
+    }
+}
+
+mod unix_seconds {
+    use serde::Serializer;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    pub fn serialize<S: Serializer>(time: &SystemTime, serializer: S) -> Result<S::Ok, S::Error> {
+        let seconds = time
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        serializer.serialize_u64(seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_required(required: Vec<PipelineComponent>) -> DegradationManager {
+        DegradationManager::new(DegradationConfig { required })
+    }
+
+    #[test]
+    fn test_optional_component_degrades() {
+        let manager = with_required(vec![]);
+        manager.healthy(PipelineComponent::Llm, "ollama reachable");
+        manager.degraded(PipelineComponent::Tts, "model missing, using stub");
+
+        assert!(manager.check_startup().is_ok());
+        assert_eq!(manager.overall(), OverallHealth::Degraded);
+        assert_eq!(
+            manager.status(PipelineComponent::Tts),
+            ComponentStatus::Degraded
+        );
+        assert_eq!(manager.to_json()["tts"]["status"], "degraded");
+    }
+
+    #[test]
+    fn test_required_component_fails_startup() {
+        let manager = with_required(vec![PipelineComponent::Stt, PipelineComponent::Scylla]);
+        manager.healthy(PipelineComponent::Stt, "IndicConformer loaded");
+        manager.degraded(
+            PipelineComponent::Scylla,
+            "connection refused, using in-memory",
+        );
+
+        let err = manager.check_startup().unwrap_err();
+        assert!(err.contains("scylla"));
+        assert!(!err.contains("stt"));
+        assert_eq!(manager.overall(), OverallHealth::Unhealthy);
+
+        // Never reported counts as unmet too
+        let manager = with_required(vec![PipelineComponent::Translation]);
+        assert!(manager.check_startup().is_err());
+    }
+}
@@ -0,0 +1,80 @@
+//! Kafka sink (requires the `kafka` feature)
+//!
+//! For at-least-once delivery the producer is idempotent with `acks=all`, so
+//! librdkafka's own retries do not duplicate messages; otherwise it sends
+//! with `acks=0` and does not wait.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use rdkafka::config::ClientConfig;
+use rdkafka::message::{Header, OwnedHeaders};
+use rdkafka::producer::{FutureProducer, FutureRecord};
+
+use super::{EventSink, EventSinkError, SinkMessage};
+
+/// Publishes events to Kafka topics, keyed by session ID
+pub struct KafkaSink {
+    producer: FutureProducer,
+    timeout: Duration,
+}
+
+impl KafkaSink {
+    /// Create a producer for the comma-separated `bootstrap_servers`
+    pub fn new(
+        bootstrap_servers: &str,
+        acknowledged: bool,
+        timeout: Duration,
+    ) -> Result<Self, EventSinkError> {
+        if bootstrap_servers.trim().is_empty() {
+            return Err(EventSinkError::Config(
+                "Kafka sink requires bootstrap servers".to_string(),
+            ));
+        }
+
+        let mut config = ClientConfig::new();
+        config
+            .set("bootstrap.servers", bootstrap_servers.trim())
+            .set("client.id", "voice-agent")
+            .set("message.timeout.ms", timeout.as_millis().to_string());
+        if acknowledged {
+            config.set("acks", "all").set("enable.idempotence", "true");
+        } else {
+            config.set("acks", "0");
+        }
+
+        let producer = config
+            .create()
+            .map_err(|e| EventSinkError::Config(e.to_string()))?;
+        Ok(Self { producer, timeout })
+    }
+}
+
+#[async_trait]
+impl EventSink for KafkaSink {
+    fn name(&self) -> &'static str {
+        "kafka"
+    }
+
+    async fn send(&self, message: &SinkMessage) -> Result<(), EventSinkError> {
+        let headers = message
+            .headers
+            .iter()
+            .fold(OwnedHeaders::new(), |headers, (key, value)| {
+                headers.insert(Header {
+                    key,
+                    value: Some(value.as_str()),
+                })
+            });
+        let record = FutureRecord::to(&message.topic)
+            .key(&message.key)
+            .payload(&message.payload)
+            .headers(headers);
+
+        self.producer
+            .send(record, self.timeout)
+            .await
+            .map(|_| ())
+            .map_err(|(e, _)| EventSinkError::Rejected(e.to_string()))
+    }
+}
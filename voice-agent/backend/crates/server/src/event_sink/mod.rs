@@ -0,0 +1,252 @@
+//! Conversation Event Sink
+//!
+//! Publishes domain events from the shared `EventBus` to a message broker
+//! (NATS or Kafka) for downstream data platforms. `EventSinkSubscriber`
+//! encodes each event (see `schema`) into a bounded buffer, and a background
+//! worker publishes it. With at-least-once delivery the worker waits for the
+//! broker's acknowledgement and retries with backoff; consumers deduplicate
+//! by `event_id`. When the buffer is full newer events are dropped rather
+//! than slowing the conversation.
+
+pub mod nats;
+pub mod schema;
+
+#[cfg(feature = "kafka")]
+pub mod kafka;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use thiserror::Error;
+use tokio::sync::mpsc;
+use voice_agent_config::{DeliveryGuarantee, EventSinkConfig, EventSinkKind};
+use voice_agent_core::{EventEnvelope, EventSubscriber};
+
+pub use nats::NatsSink;
+pub use schema::{encode_event, SinkMessage, AVRO_SCHEMA, SCHEMA_NAME, SCHEMA_VERSION};
+
+/// Delay before the first retry (doubled per attempt)
+const INITIAL_BACKOFF: Duration = Duration::from_millis(200);
+
+/// Upper bound on the retry delay
+const MAX_BACKOFF: Duration = Duration::from_secs(10);
+
+/// Event sink errors
+#[derive(Error, Debug)]
+pub enum EventSinkError {
+    #[error("Connection failed: {0}")]
+    Connection(String),
+
+    #[error("Rejected by broker: {0}")]
+    Rejected(String),
+
+    #[error("Timed out after {0:?}")]
+    Timeout(Duration),
+
+    #[error("Invalid configuration: {0}")]
+    Config(String),
+}
+
+impl EventSinkError {
+    /// Whether publishing again may succeed
+    pub fn is_retryable(&self) -> bool {
+        !matches!(self, Self::Config(_))
+    }
+}
+
+/// Message broker the events are published to
+#[async_trait]
+pub trait EventSink: Send + Sync {
+    /// Name used in logs
+    fn name(&self) -> &'static str;
+
+    /// Publish one message; returns once the broker acknowledged it when the
+    /// sink was built for at-least-once delivery
+    async fn send(&self, message: &SinkMessage) -> Result<(), EventSinkError>;
+}
+
+/// Build the configured sink, or `None` when events stay in-process
+pub fn event_sink_from_config(
+    config: &EventSinkConfig,
+) -> Result<Option<Arc<dyn EventSink>>, EventSinkError> {
+    let endpoint = config.endpoint.as_deref().unwrap_or_default();
+    let timeout = Duration::from_millis(config.timeout_ms);
+    let acknowledged = config.delivery == DeliveryGuarantee::AtLeastOnce;
+    match config.kind {
+        EventSinkKind::None => Ok(None),
+        EventSinkKind::Nats => Ok(Some(Arc::new(NatsSink::new(
+            endpoint,
+            acknowledged,
+            timeout,
+        )?))),
+        #[cfg(feature = "kafka")]
+        EventSinkKind::Kafka => Ok(Some(Arc::new(kafka::KafkaSink::new(
+            endpoint,
+            acknowledged,
+            timeout,
+        )?))),
+        #[cfg(not(feature = "kafka"))]
+        EventSinkKind::Kafka => Err(EventSinkError::Config(
+            "Kafka sink requires the server built with the `kafka` feature".to_string(),
+        )),
+    }
+}
+
+/// Forwards bus events to an `EventSink` through a bounded buffer
+pub struct EventSinkSubscriber {
+    config: EventSinkConfig,
+    tx: mpsc::Sender<SinkMessage>,
+}
+
+impl EventSinkSubscriber {
+    /// Create the subscriber and start its publishing worker
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn spawn(sink: Arc<dyn EventSink>, config: EventSinkConfig) -> Self {
+        let (tx, mut rx) = mpsc::channel::<SinkMessage>(config.buffer_size.max(1));
+        let max_attempts = match config.delivery {
+            DeliveryGuarantee::AtMostOnce => 1,
+            DeliveryGuarantee::AtLeastOnce => config.max_attempts.max(1),
+        };
+
+        tokio::spawn(async move {
+            while let Some(message) = rx.recv().await {
+                publish_with_retry(sink.as_ref(), &message, max_attempts).await;
+            }
+        });
+
+        Self { config, tx }
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for EventSinkSubscriber {
+    fn name(&self) -> &str {
+        "event_sink"
+    }
+
+    async fn handle(&self, envelope: &EventEnvelope) {
+        let topic = self.config.topic_for(envelope.event.name());
+        let message = encode_event(envelope, topic, self.config.encoding);
+        if self.tx.try_send(message).is_err() {
+            crate::metrics::record_event_sink("dropped");
+            tracing::warn!(
+                session_id = %envelope.session_id,
+                event = envelope.event.name(),
+                "Event sink buffer full, event dropped"
+            );
+        }
+    }
+}
+
+/// Publish one message, retrying retryable failures with backoff
+async fn publish_with_retry(sink: &dyn EventSink, message: &SinkMessage, max_attempts: u32) {
+    for attempt in 1..=max_attempts {
+        match sink.send(message).await {
+            Ok(()) => {
+                crate::metrics::record_event_sink("published");
+                return;
+            },
+            Err(e) if e.is_retryable() && attempt < max_attempts => {
+                let backoff = INITIAL_BACKOFF
+                    .saturating_mul(1 << (attempt - 1).min(16))
+                    .min(MAX_BACKOFF);
+                tracing::debug!(
+                    sink = sink.name(),
+                    topic = %message.topic,
+                    attempt,
+                    error = %e,
+                    "Event publish failed, retrying"
+                );
+                tokio::time::sleep(backoff).await;
+            },
+            Err(e) => {
+                crate::metrics::record_event_sink("failed");
+                tracing::warn!(
+                    sink = sink.name(),
+                    topic = %message.topic,
+                    attempts = attempt,
+                    error = %e,
+                    "Event publish failed, event dropped"
+                );
+                return;
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parking_lot::Mutex;
+    use voice_agent_config::EventEncoding;
+    use voice_agent_core::{DomainEvent, EventBus};
+
+    /// Fails the first `failures` sends, then records topics
+    #[derive(Default)]
+    struct RecordingSink {
+        failures: Mutex<u32>,
+        topics: Mutex<Vec<String>>,
+        done: tokio::sync::Notify,
+    }
+
+    #[async_trait]
+    impl EventSink for RecordingSink {
+        fn name(&self) -> &'static str {
+            "recording"
+        }
+
+        async fn send(&self, message: &SinkMessage) -> Result<(), EventSinkError> {
+            {
+                let mut failures = self.failures.lock();
+                if *failures > 0 {
+                    *failures -= 1;
+                    return Err(EventSinkError::Connection("broker down".to_string()));
+                }
+            }
+            self.topics.lock().push(message.topic.clone());
+            self.done.notify_one();
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscriber_publishes_with_retry() {
+        let sink = Arc::new(RecordingSink::default());
+        *sink.failures.lock() = 1;
+        let config = EventSinkConfig {
+            encoding: EventEncoding::Avro,
+            ..EventSinkConfig::default()
+        };
+
+        let bus = EventBus::default();
+        bus.attach(Arc::new(EventSinkSubscriber::spawn(sink.clone(), config)));
+        bus.publish(
+            "s1",
+            DomainEvent::GoalReached {
+                goal: "balance_transfer".to_string(),
+            },
+        );
+
+        tokio::time::timeout(Duration::from_secs(5), sink.done.notified())
+            .await
+            .expect("event published");
+        assert_eq!(
+            sink.topics.lock().as_slice(),
+            ["voice_agent.events.goal_reached"]
+        );
+    }
+
+    #[test]
+    fn test_kafka_requires_feature_or_endpoint() {
+        let config = EventSinkConfig {
+            kind: EventSinkKind::Kafka,
+            ..EventSinkConfig::default()
+        };
+        assert!(event_sink_from_config(&config).is_err());
+        assert!(event_sink_from_config(&EventSinkConfig::default())
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,203 @@
+//! Event payload schema
+//!
+//! Every published event carries `schema` and `schema-version` headers;
+//! bump `SCHEMA_VERSION` on any incompatible change to either encoding.
+//!
+//! JSON: `{ "schema": "voice_agent.conversation_event", "schema_version": 1,
+//! "event_id": "...", "session_id": "...", "occurred_at": "<RFC 3339>",
+//! "event": { "type": "slot_filled", "slot": "...", "value": "..." } }`.
+//!
+//! Avro: a binary `ConversationEvent` record (`AVRO_SCHEMA`) with the event
+//! fields flattened into the `attributes` string map.
+
+use std::collections::BTreeMap;
+
+use serde_json::{json, Value};
+use uuid::Uuid;
+use voice_agent_config::EventEncoding;
+use voice_agent_core::EventEnvelope;
+
+/// Schema name, sent in the `schema` header
+pub const SCHEMA_NAME: &str = "voice_agent.conversation_event";
+
+/// Schema version, sent in the `schema-version` header and the payload
+pub const SCHEMA_VERSION: i32 = 1;
+
+/// Avro schema of the binary encoding
+pub const AVRO_SCHEMA: &str = r#"{
+  "type": "record",
+  "name": "ConversationEvent",
+  "namespace": "voice_agent",
+  "fields": [
+    {"name": "schema_version", "type": "int"},
+    {"name": "event_id", "type": "string"},
+    {"name": "event_type", "type": "string"},
+    {"name": "session_id", "type": "string"},
+    {"name": "occurred_at", "type": {"type": "long", "logicalType": "timestamp-millis"}},
+    {"name": "attributes", "type": {"type": "map", "values": "string"}}
+  ]
+}"#;
+
+/// An encoded event ready for the broker
+#[derive(Debug, Clone)]
+pub struct SinkMessage {
+    /// Topic (Kafka) or subject (NATS)
+    pub topic: String,
+    /// Partition key; the session ID keeps a session's events in order
+    pub key: String,
+    pub payload: Vec<u8>,
+    pub headers: Vec<(&'static str, String)>,
+}
+
+/// Encode an event for publishing to `topic`
+pub fn encode_event(
+    envelope: &EventEnvelope,
+    topic: String,
+    encoding: EventEncoding,
+) -> SinkMessage {
+    let event_id = Uuid::new_v4().to_string();
+    let event_type = envelope.event.name();
+
+    let (content_type, payload) = match encoding {
+        EventEncoding::Json => {
+            let payload = json!({
+                "schema": SCHEMA_NAME,
+                "schema_version": SCHEMA_VERSION,
+                "event_id": event_id,
+                "session_id": envelope.session_id,
+                "occurred_at": envelope.occurred_at.to_rfc3339(),
+                "event": envelope.event,
+            });
+            ("application/json", payload.to_string().into_bytes())
+        },
+        EventEncoding::Avro => {
+            let mut payload = Vec::new();
+            write_long(&mut payload, SCHEMA_VERSION as i64);
+            write_string(&mut payload, &event_id);
+            write_string(&mut payload, event_type);
+            write_string(&mut payload, &envelope.session_id);
+            write_long(&mut payload, envelope.occurred_at.timestamp_millis());
+            write_map(&mut payload, &attributes(envelope));
+            ("avro/binary", payload)
+        },
+    };
+
+    SinkMessage {
+        topic,
+        key: envelope.session_id.clone(),
+        payload,
+        headers: vec![
+            ("content-type", content_type.to_string()),
+            ("schema", SCHEMA_NAME.to_string()),
+            ("schema-version", SCHEMA_VERSION.to_string()),
+            ("event-id", event_id),
+            ("event-type", event_type.to_string()),
+        ],
+    }
+}
+
+/// Event fields as strings (the `type` tag is carried as `event_type`)
+fn attributes(envelope: &EventEnvelope) -> BTreeMap<String, String> {
+    let Ok(Value::Object(fields)) = serde_json::to_value(&envelope.event) else {
+        return BTreeMap::new();
+    };
+    fields
+        .into_iter()
+        .filter(|(key, _)| key != "type")
+        .map(|(key, value)| match value {
+            Value::String(s) => (key, s),
+            other => (key, other.to_string()),
+        })
+        .collect()
+}
+
+/// Avro `int`/`long`: zigzag varint
+fn write_long(buf: &mut Vec<u8>, value: i64) {
+    let mut n = ((value << 1) ^ (value >> 63)) as u64;
+    while n >= 0x80 {
+        buf.push((n as u8) | 0x80);
+        n >>= 7;
+    }
+    buf.push(n as u8);
+}
+
+/// Avro `string`: length then UTF-8 bytes
+fn write_string(buf: &mut Vec<u8>, value: &str) {
+    write_long(buf, value.len() as i64);
+    buf.extend_from_slice(value.as_bytes());
+}
+
+/// Avro `map<string>`: one block of entries, then the empty block
+fn write_map(buf: &mut Vec<u8>, map: &BTreeMap<String, String>) {
+    if !map.is_empty() {
+        write_long(buf, map.len() as i64);
+        for (key, value) in map {
+            write_string(buf, key);
+            write_string(buf, value);
+        }
+    }
+    write_long(buf, 0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use voice_agent_core::DomainEvent;
+
+    fn envelope(event: DomainEvent) -> EventEnvelope {
+        EventEnvelope {
+            session_id: "s1".to_string(),
+            occurred_at: Utc::now(),
+            event,
+        }
+    }
+
+    #[test]
+    fn test_zigzag_encoding() {
+        for (value, expected) in [
+            (0, vec![0x00]),
+            (-1, vec![0x01]),
+            (1, vec![0x02]),
+            (64, vec![0x80, 0x01]),
+        ] {
+            let mut buf = Vec::new();
+            write_long(&mut buf, value);
+            assert_eq!(buf, expected, "value {}", value);
+        }
+    }
+
+    #[test]
+    fn test_json_payload_is_versioned() {
+        let message = encode_event(
+            &envelope(DomainEvent::SlotFilled {
+                slot: "city".to_string(),
+                value: "Pune".to_string(),
+            }),
+            "t".to_string(),
+            EventEncoding::Json,
+        );
+        let payload: Value = serde_json::from_slice(&message.payload).unwrap();
+        assert_eq!(payload["schema_version"], SCHEMA_VERSION);
+        assert_eq!(payload["event"]["type"], "slot_filled");
+        assert_eq!(payload["event"]["value"], "Pune");
+        assert_eq!(message.key, "s1");
+    }
+
+    #[test]
+    fn test_avro_attributes_flattened() {
+        let envelope = envelope(DomainEvent::ToolExecuted {
+            tool: "capture_lead".to_string(),
+            success: true,
+        });
+        let attributes = attributes(&envelope);
+        assert_eq!(attributes["tool"], "capture_lead");
+        assert_eq!(attributes["success"], "true");
+        assert!(!attributes.contains_key("type"));
+
+        let message = encode_event(&envelope, "t".to_string(), EventEncoding::Avro);
+        // schema_version 1 zigzag-encodes to 2, then the 36-char event ID
+        assert_eq!(&message.payload[..2], &[0x02, 72]);
+        assert_eq!(*message.payload.last().unwrap(), 0);
+    }
+}
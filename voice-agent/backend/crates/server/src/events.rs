@@ -0,0 +1,84 @@
+//! Domain Event Subscribers
+//!
+//! Cross-cutting consumers of the shared `EventBus`: Prometheus counters for
+//! every event and the audit trail for conversation start and end and for
+//! budget enforcement.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use voice_agent_core::{DomainEvent, EventEnvelope, EventSubscriber};
+use voice_agent_persistence::AuditLogger;
+
+/// Counts domain events by name
+pub struct MetricsSubscriber;
+
+#[async_trait]
+impl EventSubscriber for MetricsSubscriber {
+    fn name(&self) -> &str {
+        "metrics"
+    }
+
+    async fn handle(&self, envelope: &EventEnvelope) {
+        crate::metrics::record_domain_event(envelope.event.name());
+    }
+}
+
+/// Writes conversation start and end and enforced budgets to the audit log
+pub struct AuditSubscriber {
+    logger: Arc<AuditLogger>,
+}
+
+impl AuditSubscriber {
+    pub fn new(logger: Arc<AuditLogger>) -> Self {
+        Self { logger }
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for AuditSubscriber {
+    fn name(&self) -> &str {
+        "audit"
+    }
+
+    fn accepts(&self, event: &DomainEvent) -> bool {
+        matches!(
+            event,
+            DomainEvent::SessionStarted { .. }
+                | DomainEvent::SessionEnded { .. }
+                | DomainEvent::BudgetExceeded { .. }
+        )
+    }
+
+    async fn handle(&self, envelope: &EventEnvelope) {
+        let session_id = envelope.session_id.as_str();
+        let result = match &envelope.event {
+            DomainEvent::SessionStarted { language } => {
+                self.logger
+                    .log_conversation_start(session_id, language)
+                    .await
+            },
+            DomainEvent::SessionEnded {
+                reason,
+                duration_secs,
+            } => {
+                self.logger
+                    .log_conversation_end(session_id, reason, *duration_secs)
+                    .await
+            },
+            DomainEvent::BudgetExceeded {
+                budget,
+                limit,
+                used,
+            } => {
+                self.logger
+                    .log_budget_enforcement(session_id, budget, *limit, *used, "end_session")
+                    .await
+            },
+            _ => Ok(()),
+        };
+        if let Err(e) = result {
+            tracing::warn!(session_id, error = %e, "Failed to audit conversation event");
+        }
+    }
+}
@@ -3,27 +3,50 @@
 //! REST API for the voice agent.
 
 use axum::{
-    extract::{Json, Path, State},
+    extract::{Json, Path, Query, State},
     http::{HeaderValue, Method, StatusCode},
     response::IntoResponse,
     routing::{delete, get, post},
     Extension, Router,
 };
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use tower_http::compression::CompressionLayer;
 use tower_http::cors::{Any, CorsLayer};
 use tower_http::trace::TraceLayer;
 
+use crate::admin;
+use crate::analytics;
 use crate::auth::auth_middleware;
-use crate::mcp_server::handle_mcp_request;
+use crate::degradation::OverallHealth;
+use crate::kiosk;
+use crate::mcp_server::{
+    handle_mcp_request, handle_mcp_sse, handle_mcp_sse_message, McpSseSessions,
+};
 use crate::metrics::metrics_handler;
+use crate::models::{ModelComponent, ModelStatus};
 use crate::ptt;
+use crate::sms_inbound;
 use crate::state::AppState;
+use crate::supervisor;
+use crate::usage;
 #[cfg(feature = "webrtc")]
 use crate::webrtc;
 use crate::websocket::{create_session, WebSocketHandler};
+use voice_agent_config::RateCard;
+use voice_agent_persistence::{CallbackStatus, CompetitorRate};
 use voice_agent_tools::ToolExecutor;
 
+/// Tool whose cached answers quote competitor rates
+const COMPARISON_TOOL: &str = "compare_lenders";
+
+/// Tools whose cached answers quote our LTV and rates
+const RATE_CARD_TOOLS: &[&str] = &[
+    "check_eligibility",
+    "calculate_savings",
+    "check_top_up_eligibility",
+];
+
 /// Create the application router
 pub fn create_router(state: AppState) -> Router {
     // P0 FIX: Build CORS layer from configured origins instead of wildcard Any
@@ -45,6 +68,8 @@ pub fn create_router(state: AppState) -> Router {
         .route("/api/tools/:name", post(call_tool))
         // MCP JSON-RPC endpoint
         .route("/mcp", post(handle_mcp_request))
+        .route("/mcp/sse", get(handle_mcp_sse))
+        .route("/mcp/messages", post(handle_mcp_sse_message))
         // Health check
         .route("/health", get(health_check))
         .route("/ready", get(readiness_check))
@@ -52,8 +77,65 @@ pub fn create_router(state: AppState) -> Router {
         .route("/metrics", get(metrics_handler))
         // Admin endpoints
         .route("/admin/reload-config", post(reload_config))
+        .route("/admin/cache/invalidate", post(invalidate_cache))
+        .route("/admin/models", get(model_status))
+        .route("/admin/models/reload", post(reload_models))
+        .route("/admin/competitor-rates", get(list_competitor_rates))
+        .route("/admin/competitor-rates", post(update_competitor_rate))
+        .route(
+            "/admin/competitor-rates/:product/:lender_id",
+            delete(delete_competitor_rate),
+        )
+        .route("/admin/rate-cards", get(list_rate_cards))
+        .route("/admin/rate-cards", post(publish_rate_card))
+        // Persisted entities for operators
+        .route("/admin/sessions", get(admin::list_sessions))
+        .route("/admin/sessions/:id", get(admin::get_session))
+        .route("/admin/sessions/:id", delete(admin::delete_session))
+        .route("/admin/appointments", get(admin::list_appointments))
+        .route(
+            "/admin/appointments/:phone/:id/status",
+            post(admin::update_appointment_status),
+        )
+        .route("/admin/sms", get(admin::list_sms))
+        .route(
+            "/admin/webhooks/deliveries",
+            get(admin::list_webhook_deliveries),
+        )
+        .route("/admin/audit", get(admin::query_audit))
+        .route("/admin/audit/:session_id/verify", get(admin::verify_audit_chain))
+        .route("/admin/branches", get(admin::list_branches))
+        .route("/admin/branches/:id/slots", get(admin::branch_slots))
+        .route("/admin/branches/:id/slots", post(admin::set_slot_capacity))
+        // Customer SMS replies forwarded by the provider (inbound token, not API key)
+        .route(
+            sms_inbound::SMS_INBOUND_PATH,
+            post(sms_inbound::receive),
+        )
+        // Callback desk
+        .route("/api/callbacks", get(list_callbacks))
+        .route("/api/callbacks/:id/assign", post(assign_callback))
+        .route("/api/callbacks/:id/outcome", post(record_callback_outcome))
+        // Human handoff (agent desk signals warm transfer progress)
+        .route("/api/handoffs/:session_id/transfer", post(signal_handoff))
+        // Conversation analytics (daily rollups and funnels)
+        .route("/api/analytics/daily", get(analytics::daily))
+        .route("/api/analytics/funnel", get(analytics::funnel))
+        .route("/api/analytics/offers", get(analytics::offers))
+        // Usage accounting (per session, and per day and tenant)
+        .route("/api/usage/daily", get(usage::daily))
+        .route("/api/sessions/:id/usage", get(usage::session))
+        // Supervisor monitoring and whisper coaching
+        .route("/api/supervisor/sessions", get(supervisor::list_active_sessions))
+        .route(
+            "/api/supervisor/sessions/:session_id/whisper",
+            post(supervisor::whisper).delete(supervisor::clear_whispers),
+        )
+        .route("/ws/supervisor/:session_id", get(supervisor::monitor))
         // P12 FIX: Removed reload-domain-config (MasterDomainConfig loaded at startup)
         .route("/api/domain/info", get(domain_info))
+        // Kiosk devices listening for the wake word
+        .route("/ws/kiosk", get(kiosk::listen))
         // WebSocket
         .route("/ws/:session_id", get(ws_handler))
         // Push-to-talk
@@ -82,6 +164,7 @@ pub fn create_router(state: AppState) -> Router {
             },
         ))
         .layer(Extension(state.config.clone()))
+        .layer(Extension(Arc::new(McpSseSessions::new())))
         .layer(TraceLayer::new_for_http())
         .layer(CompressionLayer::new())
         .layer(cors_layer)
@@ -148,11 +231,25 @@ async fn get_session(
         "active": session.is_active(),
         "stage": session.agent.stage().display_name(),
         "turn_count": session.agent.conversation().turn_count(),
+        "abuse_incidents": session.agent.abuse_incidents(),
+        "flagged_abusive": session.agent.is_flagged_abusive(),
+        "disposition": session.agent.disposition(),
+        "audio_quality": session.agent.line_quality(),
+        "line_degradations": session.agent.line_degradations(),
+        "model_versions": session.model_versions(),
     })))
 }
 
 /// Delete session
 async fn delete_session(State(state): State<AppState>, Path(id): Path<String>) -> StatusCode {
+    if let Some(session) = state.sessions.get(&id) {
+        if let Err(e) = state.save_customer(&session).await {
+            tracing::warn!(session_id = %id, error = %e, "Failed to save customer identity");
+        }
+    }
+    if let Some(ref cache) = state.response_cache {
+        cache.invalidate_session(&id);
+    }
     state.sessions.remove(&id);
     StatusCode::NO_CONTENT
 }
@@ -201,7 +298,15 @@ async fn chat(
         })),
         Err(e) => {
             tracing::error!("Chat error: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            // Failed turns are answered with an apology the client can show or speak
+            match session.agent.failure_reply(&e) {
+                Some(response) => Ok(Json(ChatResponse {
+                    response,
+                    stage: session.agent.stage().display_name().to_string(),
+                    turn_count: session.agent.conversation().turn_count(),
+                })),
+                None => Err(StatusCode::INTERNAL_SERVER_ERROR),
+            }
         },
     }
 }
@@ -335,8 +440,16 @@ async fn health_check(State(state): State<AppState>) -> (StatusCode, Json<serde_
 
     drop(config);
 
-    let status = if all_healthy { "healthy" } else { "degraded" };
-    let status_code = if all_healthy {
+    // Per-component status: a required component that is down makes the
+    // service unhealthy; optional ones running on fallbacks only degrade it
+    let overall = state.degradation.overall();
+    let status = match overall {
+        OverallHealth::Unhealthy => "unhealthy",
+        OverallHealth::Degraded => "degraded",
+        OverallHealth::Healthy if !all_healthy => "degraded",
+        OverallHealth::Healthy => "healthy",
+    };
+    let status_code = if all_healthy && overall != OverallHealth::Unhealthy {
         StatusCode::OK
     } else {
         StatusCode::SERVICE_UNAVAILABLE
@@ -347,7 +460,8 @@ async fn health_check(State(state): State<AppState>) -> (StatusCode, Json<serde_
         Json(serde_json::json!({
             "status": status,
             "version": env!("CARGO_PKG_VERSION"),
-            "checks": checks
+            "checks": checks,
+            "components": state.degradation.to_json()
         })),
     )
 }
@@ -374,7 +488,23 @@ async fn readiness_check(State(state): State<AppState>) -> (StatusCode, Json<ser
         }),
     );
 
-    // Check 2: LLM backend (Ollama) connectivity
+    // Check 2: Admission - a saturated instance should get no new calls
+    let admission = state.admission.snapshot(session_count);
+    let admission_status = match state.admission.check(session_count) {
+        Ok(()) => serde_json::json!({ "status": "ok", "load": admission }),
+        Err(rejection) => {
+            ready = false;
+            serde_json::json!({
+                "status": "busy",
+                "reason": rejection.reason(),
+                "detail": rejection.to_string(),
+                "load": admission
+            })
+        },
+    };
+    checks.insert("admission".to_string(), admission_status);
+
+    // Check 3: LLM backend (Ollama) connectivity
     let llm_url = format!("{}/api/tags", llm_endpoint);
 
     let llm_status =
@@ -427,24 +557,629 @@ async fn readiness_check(State(state): State<AppState>) -> (StatusCode, Json<ser
 /// Note: Some settings (like CORS) are only applied at startup.
 async fn reload_config(State(state): State<AppState>) -> impl IntoResponse {
     match state.reload_config() {
+        Ok(()) => {
+            // Cached answers may quote the old rates and policies
+            if let Some(ref cache) = state.response_cache {
+                cache.clear();
+            }
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "status": "success",
+                    "message": "Configuration reloaded successfully"
+                })),
+            )
+        },
+        Err(e) => {
+            tracing::error!("Config reload failed: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "status": "error",
+                    "message": e
+                })),
+            )
+        },
+    }
+}
+
+/// Cache invalidation request
+#[derive(Debug, Deserialize)]
+struct InvalidateCacheRequest {
+    /// Tool whose underlying data changed; omit to clear the whole cache
+    #[serde(default)]
+    tool: Option<String>,
+}
+
+/// Invalidate cached responses
+///
+/// POST /admin/cache/invalidate
+///
+/// Call when a tool's data changes (price feed update, branch list or
+/// document policy edit) so customers don't hear stale answers.
+async fn invalidate_cache(
+    State(state): State<AppState>,
+    Json(request): Json<InvalidateCacheRequest>,
+) -> Json<serde_json::Value> {
+    let Some(ref cache) = state.response_cache else {
+        return Json(serde_json::json!({ "status": "disabled" }));
+    };
+
+    let removed = match request.tool {
+        Some(ref tool) => cache.invalidate_tool(tool),
+        None => {
+            let stats = cache.stats();
+            cache.clear();
+            stats.responses + stats.tool_results
+        },
+    };
+
+    Json(serde_json::json!({
+        "status": "success",
+        "tool": request.tool,
+        "removed": removed
+    }))
+}
+
+/// Model reload request
+#[derive(Debug, Default, Deserialize)]
+struct ReloadModelsRequest {
+    /// Models to reload; omit to reload STT, TTS and the LLM router
+    #[serde(default)]
+    components: Vec<ModelComponent>,
+}
+
+/// Reload models without dropping sessions
+///
+/// POST /admin/models/reload
+///
+/// Loads new versions alongside the models in service. Once loaded, new
+/// sessions use them and the old ones are unloaded when their last session
+/// ends. Poll `GET /admin/models` for progress.
+async fn reload_models(
+    State(state): State<AppState>,
+    request: Option<Json<ReloadModelsRequest>>,
+) -> impl IntoResponse {
+    let Json(request) = request.unwrap_or_default();
+    let components = if request.components.is_empty() {
+        ModelComponent::ALL.to_vec()
+    } else {
+        request.components
+    };
+
+    match state.reload_models(components.clone()) {
         Ok(()) => (
-            StatusCode::OK,
+            StatusCode::ACCEPTED,
+            Json(serde_json::json!({
+                "status": "reloading",
+                "components": components
+            })),
+        ),
+        Err(e) => (
+            StatusCode::CONFLICT,
             Json(serde_json::json!({
-                "status": "success",
-                "message": "Configuration reloaded successfully"
+                "status": "error",
+                "message": e
             })),
         ),
+    }
+}
+
+/// Active and draining model generations
+///
+/// GET /admin/models
+async fn model_status(State(state): State<AppState>) -> Json<ModelStatus> {
+    Json(state.models.status())
+}
+
+/// Competitor rate update request
+#[derive(Debug, Deserialize)]
+struct CompetitorRateRequest {
+    lender_id: String,
+    /// Defaults to the competitors.yaml display name, else the lender ID
+    #[serde(default)]
+    lender_name: Option<String>,
+    /// Defaults to the domain's configured rate product
+    #[serde(default)]
+    product: Option<String>,
+    rate_min: f64,
+    rate_max: f64,
+    #[serde(default)]
+    typical_rate: Option<f64>,
+    #[serde(default)]
+    processing_fee_percent: Option<f64>,
+    #[serde(default)]
+    source: Option<String>,
+}
+
+fn competitor_rates_disabled() -> (StatusCode, Json<serde_json::Value>) {
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(serde_json::json!({
+            "status": "disabled",
+            "message": "Competitor rate table requires persistence"
+        })),
+    )
+}
+
+/// List competitor rates
+///
+/// GET /admin/competitor-rates
+///
+/// Returns the stored rates for the configured product, with their age and
+/// whether comparisons currently flag them as outdated.
+async fn list_competitor_rates(
+    State(state): State<AppState>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let Some(ref store) = state.competitor_rates else {
+        return competitor_rates_disabled();
+    };
+    let tools_view = state.get_tools_view();
+    let max_age_days = tools_view.competitor_rate_max_age_days();
+
+    match store.list(tools_view.competitor_rate_product()).await {
+        Ok(rates) => {
+            let now = chrono::Utc::now();
+            let rates: Vec<serde_json::Value> = rates
+                .iter()
+                .map(|rate| {
+                    serde_json::json!({
+                        "rate": rate,
+                        "age_days": rate.age_days(now),
+                        "stale": rate.is_stale(max_age_days, now)
+                    })
+                })
+                .collect();
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "rates": rates,
+                    "max_age_days": max_age_days
+                })),
+            )
+        },
         Err(e) => {
-            tracing::error!("Config reload failed: {}", e);
+            tracing::error!("Failed to list competitor rates: {}", e);
             (
                 StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({ "status": "error", "message": e.to_string() })),
+            )
+        },
+    }
+}
+
+/// Update a competitor's rates
+///
+/// POST /admin/competitor-rates
+///
+/// Inserts or replaces one lender's rates, stamped with the current time,
+/// and drops cached comparison answers that quoted the old figures.
+async fn update_competitor_rate(
+    State(state): State<AppState>,
+    Json(request): Json<CompetitorRateRequest>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let Some(ref store) = state.competitor_rates else {
+        return competitor_rates_disabled();
+    };
+    let tools_view = state.get_tools_view();
+
+    let lender_name = request.lender_name.clone().unwrap_or_else(|| {
+        tools_view
+            .get_competitor_extended(&request.lender_id)
+            .map(|entry| entry.display_name.clone())
+            .unwrap_or_else(|| request.lender_id.clone())
+    });
+    let product = request
+        .product
+        .clone()
+        .or_else(|| tools_view.competitor_rate_product().map(str::to_string))
+        .unwrap_or_default();
+
+    let mut rate = CompetitorRate::new(
+        &request.lender_id,
+        lender_name,
+        product,
+        request.rate_min,
+        request.rate_max,
+    );
+    rate.typical_rate = request.typical_rate;
+    rate.processing_fee_percent = request.processing_fee_percent;
+    rate.source = request.source;
+
+    if let Err(e) = rate.validate() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "status": "error", "message": e.to_string() })),
+        );
+    }
+
+    match store.upsert(&rate).await {
+        Ok(()) => {
+            if let Some(ref cache) = state.response_cache {
+                cache.invalidate_tool(COMPARISON_TOOL);
+            }
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({ "status": "success", "rate": rate })),
+            )
+        },
+        Err(e) => {
+            tracing::error!("Failed to update competitor rate: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({ "status": "error", "message": e.to_string() })),
+            )
+        },
+    }
+}
+
+/// Remove a competitor's rates
+///
+/// DELETE /admin/competitor-rates/:product/:lender_id
+///
+/// Comparisons fall back to the competitors.yaml figures for the lender.
+async fn delete_competitor_rate(
+    State(state): State<AppState>,
+    Path((product, lender_id)): Path<(String, String)>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let Some(ref store) = state.competitor_rates else {
+        return competitor_rates_disabled();
+    };
+
+    match store.remove(&product, &lender_id).await {
+        Ok(true) => {
+            if let Some(ref cache) = state.response_cache {
+                cache.invalidate_tool(COMPARISON_TOOL);
+            }
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({ "status": "success" })),
+            )
+        },
+        Ok(false) => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({ "status": "not_found" })),
+        ),
+        Err(e) => {
+            tracing::error!("Failed to remove competitor rate: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({ "status": "error", "message": e.to_string() })),
+            )
+        },
+    }
+}
+
+/// Rate card publish request
+#[derive(Debug, Deserialize)]
+struct PublishRateCardRequest {
+    card: RateCard,
+    /// Who is publishing, kept with the card
+    #[serde(default)]
+    published_by: Option<String>,
+}
+
+fn rate_cards_disabled() -> (StatusCode, Json<serde_json::Value>) {
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(serde_json::json!({
+            "status": "disabled",
+            "message": "Publishing rate cards requires persistence"
+        })),
+    )
+}
+
+/// List rate cards
+///
+/// GET /admin/rate-cards
+///
+/// Returns the card in force today, the cards in rate_card.yaml and those
+/// published through this API.
+async fn list_rate_cards(State(state): State<AppState>) -> Json<serde_json::Value> {
+    let tools_view = state.get_tools_view();
+    let today = chrono::Utc::now().date_naive();
+    let (current, published) = match state.rate_cards {
+        Some(ref engine) => (engine.card_on(today), engine.published()),
+        None => (tools_view.rate_card_on(today), Vec::new()),
+    };
+
+    Json(serde_json::json!({
+        "current": current,
+        "config": tools_view.rate_cards(),
+        "published": published
+    }))
+}
+
+/// Publish a rate card
+///
+/// POST /admin/rate-cards
+///
+/// Stores a new card version, which applies from its `effective_from` date,
+/// and drops cached answers that quoted the old rates. Versions cannot be
+/// replaced; publish a new version to change rates.
+async fn publish_rate_card(
+    State(state): State<AppState>,
+    Json(request): Json<PublishRateCardRequest>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let Some(ref engine) = state.rate_cards else {
+        return rate_cards_disabled();
+    };
+
+    if let Err(e) = request.card.validate() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "status": "error", "message": e.to_string() })),
+        );
+    }
+
+    match engine
+        .publish(&request.card, request.published_by.as_deref())
+        .await
+    {
+        Ok(true) => {
+            if let Some(ref cache) = state.response_cache {
+                for tool in RATE_CARD_TOOLS {
+                    cache.invalidate_tool(tool);
+                }
+            }
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({ "status": "success", "card": request.card })),
+            )
+        },
+        Ok(false) => (
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({
+                "status": "error",
+                "message": format!("Rate card version '{}' already exists", request.card.version)
+            })),
+        ),
+        Err(e) => {
+            tracing::error!("Failed to publish rate card: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({ "status": "error", "message": e.to_string() })),
+            )
+        },
+    }
+}
+
+/// Callback list filters
+#[derive(Debug, Deserialize)]
+struct CallbackQuery {
+    #[serde(default)]
+    agent_id: Option<String>,
+    #[serde(default)]
+    status: Option<CallbackStatus>,
+}
+
+/// Callback assignment request
+#[derive(Debug, Deserialize)]
+struct AssignCallbackRequest {
+    agent_id: String,
+}
+
+/// Callback outcome reported by the dialer or an agent
+#[derive(Debug, Deserialize)]
+struct CallbackOutcomeRequest {
+    status: CallbackStatus,
+    #[serde(default)]
+    notes: Option<String>,
+}
+
+fn callbacks_disabled() -> (StatusCode, Json<serde_json::Value>) {
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(serde_json::json!({
+            "status": "disabled",
+            "message": "Callbacks require persistence"
+        })),
+    )
+}
+
+fn callback_error(
+    status: StatusCode,
+    message: impl ToString,
+) -> (StatusCode, Json<serde_json::Value>) {
+    (
+        status,
+        Json(serde_json::json!({ "status": "error", "message": message.to_string() })),
+    )
+}
+
+/// Load a callback by its path ID, mapping failures to responses
+async fn load_callback(
+    store: &dyn voice_agent_persistence::CallbackStore,
+    id: &str,
+) -> Result<voice_agent_persistence::Callback, (StatusCode, Json<serde_json::Value>)> {
+    let id = uuid::Uuid::parse_str(id)
+        .map_err(|_| callback_error(StatusCode::BAD_REQUEST, "Invalid callback ID"))?;
+    match store.get(id).await {
+        Ok(Some(callback)) => Ok(callback),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({ "status": "not_found" })),
+        )),
+        Err(e) => {
+            tracing::error!("Failed to load callback: {}", e);
+            Err(callback_error(StatusCode::INTERNAL_SERVER_ERROR, e))
+        },
+    }
+}
+
+/// Save a changed callback and return it
+async fn save_callback(
+    store: &dyn voice_agent_persistence::CallbackStore,
+    callback: voice_agent_persistence::Callback,
+) -> (StatusCode, Json<serde_json::Value>) {
+    match store.update(&callback).await {
+        Ok(()) => (
+            StatusCode::OK,
+            Json(serde_json::json!({ "status": "success", "callback": callback })),
+        ),
+        Err(e) => {
+            tracing::error!("Failed to update callback: {}", e);
+            callback_error(StatusCode::INTERNAL_SERVER_ERROR, e)
+        },
+    }
+}
+
+/// List open callbacks
+///
+/// GET /api/callbacks?agent_id=...&status=...
+///
+/// Returns callbacks that are not yet completed, expired or cancelled,
+/// earliest window first.
+async fn list_callbacks(
+    State(state): State<AppState>,
+    Query(query): Query<CallbackQuery>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let Some(ref store) = state.callbacks else {
+        return callbacks_disabled();
+    };
+
+    match store.list_open().await {
+        Ok(callbacks) => {
+            let callbacks: Vec<_> = callbacks
+                .into_iter()
+                .filter(|c| {
+                    query
+                        .agent_id
+                        .as_deref()
+                        .map_or(true, |agent| c.assigned_agent.as_deref() == Some(agent))
+                })
+                .filter(|c| query.status.map_or(true, |status| c.status == status))
+                .collect();
+            (
+                StatusCode::OK,
                 Json(serde_json::json!({
-                    "status": "error",
-                    "message": e
+                    "count": callbacks.len(),
+                    "callbacks": callbacks
                 })),
             )
         },
+        Err(e) => {
+            tracing::error!("Failed to list callbacks: {}", e);
+            callback_error(StatusCode::INTERNAL_SERVER_ERROR, e)
+        },
+    }
+}
+
+/// Assign a callback to a human agent
+///
+/// POST /api/callbacks/:id/assign
+///
+/// The dialer connects the call to this agent when the window opens.
+async fn assign_callback(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(request): Json<AssignCallbackRequest>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let Some(ref store) = state.callbacks else {
+        return callbacks_disabled();
+    };
+    let mut callback = match load_callback(store.as_ref(), &id).await {
+        Ok(callback) => callback,
+        Err(response) => return response,
+    };
+
+    if request.agent_id.trim().is_empty() {
+        return callback_error(StatusCode::BAD_REQUEST, "agent_id is required");
     }
+    if let Err(e) = callback.assign(request.agent_id.trim()) {
+        return callback_error(StatusCode::CONFLICT, e);
+    }
+
+    save_callback(store.as_ref(), callback).await
+}
+
+/// Record the outcome of a callback call
+///
+/// POST /api/callbacks/:id/outcome
+///
+/// An unanswered call is retried after the configured delay until the
+/// attempts run out or the window closes; then the callback expires.
+async fn record_callback_outcome(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(request): Json<CallbackOutcomeRequest>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let Some(ref store) = state.callbacks else {
+        return callbacks_disabled();
+    };
+    let mut callback = match load_callback(store.as_ref(), &id).await {
+        Ok(callback) => callback,
+        Err(response) => return response,
+    };
+
+    let policy = voice_agent_tools::DispatchPolicy::from_config(&state.config.read().dialer);
+    if let Err(e) = policy.apply_outcome(&mut callback, request.status, chrono::Utc::now()) {
+        return callback_error(StatusCode::CONFLICT, e);
+    }
+    if let Some(notes) = request.notes.filter(|n| !n.trim().is_empty()) {
+        callback.notes = Some(notes);
+    }
+
+    save_callback(store.as_ref(), callback).await
+}
+
+/// Warm transfer signal from the agent desk
+#[derive(Debug, Deserialize)]
+struct HandoffSignalRequest {
+    handoff_id: String,
+    /// agent_assigned, ready, connected, cancelled or failed
+    status: String,
+    #[serde(default)]
+    agent_id: Option<String>,
+    #[serde(default)]
+    transfer_to: Option<String>,
+}
+
+/// Report warm transfer progress for an escalated session
+///
+/// POST /api/handoffs/:session_id/transfer
+///
+/// Forwarded to the caller's WebSocket as a `handoff` message. Once the
+/// human agent is connected the bot stops answering; a cancelled or failed
+/// transfer hands the conversation back to the bot.
+async fn signal_handoff(
+    State(state): State<AppState>,
+    Path(session_id): Path<String>,
+    Json(request): Json<HandoffSignalRequest>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let Some(session) = state.sessions.get(&session_id) else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({ "status": "not_found" })),
+        );
+    };
+    let bad_request = |message: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "status": "error", "message": message })),
+        )
+    };
+    let Some(status) = voice_agent_agent::HandoffStatus::parse(&request.status) else {
+        return bad_request(format!("Unknown handoff status '{}'", request.status));
+    };
+    if request.handoff_id.trim().is_empty() {
+        return bad_request("handoff_id is required".to_string());
+    }
+
+    session.agent.signal_handoff(
+        request.handoff_id.trim(),
+        status,
+        request.agent_id,
+        request.transfer_to,
+    );
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "status": "ok",
+            "session_id": session_id,
+            "handoff_status": status.as_str(),
+        })),
+    )
 }
 
 /// P12 FIX: Domain config info endpoint
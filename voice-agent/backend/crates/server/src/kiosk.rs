@@ -0,0 +1,195 @@
+//! Kiosk Wake Word Endpoint
+//!
+//! Always-listening kiosk devices stream their microphone to `GET /ws/kiosk`
+//! instead of holding a session open. Audio is only run through the wake
+//! word detector (see `pipeline.wake_word`); when a customer says the
+//! activation phrase:
+//! 1. A session is created and announced with a `wake` message
+//! 2. The device holds the conversation on the session's own socket
+//! 3. Kiosk audio is ignored until that session closes or expires, then a
+//!    `session_ended` message is sent and listening resumes after the cooldown
+
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        State,
+    },
+    http::StatusCode,
+    response::Response,
+};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use futures::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use voice_agent_core::{AudioFrame, Channels, SampleRate};
+use voice_agent_pipeline::WakeWordDetector;
+
+use crate::session::Session;
+use crate::state::AppState;
+use crate::websocket::{open_session, CreateSessionRequest};
+
+/// Messages on the kiosk stream
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum KioskMessage {
+    /// Microphone audio (base64 16-bit PCM, 16kHz mono); binary frames
+    /// are accepted too
+    Audio { data: String },
+    /// Waiting for an activation phrase
+    Listening { phrases: Vec<String> },
+    /// Activation phrase heard; the conversation continues on the session
+    Wake {
+        phrase: String,
+        session_id: String,
+        websocket_url: String,
+    },
+    /// The woken session ended
+    SessionEnded { session_id: String },
+    /// Error
+    Error { message: String },
+}
+
+impl KioskMessage {
+    fn to_message(&self) -> Message {
+        Message::Text(serde_json::to_string(self).unwrap_or_default())
+    }
+}
+
+/// Open a kiosk stream
+///
+/// GET /ws/kiosk (404 unless `pipeline.wake_word` is enabled)
+pub async fn listen(
+    ws: WebSocketUpgrade,
+    State(state): State<AppState>,
+) -> Result<Response, StatusCode> {
+    let config = state.config.read().pipeline.wake_word.clone();
+    if !config.enabled {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    let detector = WakeWordDetector::new(config).map_err(|e| {
+        tracing::error!(error = %e, "Wake word detector unavailable");
+        StatusCode::SERVICE_UNAVAILABLE
+    })?;
+    Ok(ws.on_upgrade(move |socket| listen_socket(socket, state, detector)))
+}
+
+async fn listen_socket(socket: WebSocket, state: AppState, detector: WakeWordDetector) {
+    let (mut sender, mut receiver) = socket.split();
+    let listening = KioskMessage::Listening {
+        phrases: detector.phrases().map(str::to_string).collect(),
+    };
+    if sender.send(listening.to_message()).await.is_err() {
+        return;
+    }
+    tracing::info!("Kiosk listening for wake word");
+
+    // Session started by the last wake, while it is running
+    let mut woken: Option<Arc<Session>> = None;
+
+    while let Some(Ok(message)) = receiver.next().await {
+        let pcm = match message {
+            Message::Binary(data) => data,
+            Message::Text(text) => match serde_json::from_str::<KioskMessage>(&text) {
+                Ok(KioskMessage::Audio { data }) => match BASE64.decode(&data) {
+                    Ok(pcm) => pcm,
+                    Err(e) => {
+                        tracing::warn!("Failed to decode kiosk audio: {}", e);
+                        continue;
+                    },
+                },
+                _ => {
+                    let error = KioskMessage::Error {
+                        message: "Only audio messages are accepted".to_string(),
+                    };
+                    let _ = sender.send(error.to_message()).await;
+                    continue;
+                },
+            },
+            Message::Close(_) => break,
+            _ => continue,
+        };
+
+        if let Some(session) = woken.take() {
+            let running = session.is_active() && state.sessions.get(&session.id).is_some();
+            if running {
+                woken = Some(session);
+                continue;
+            }
+            tracing::info!(session_id = %session.id, "Kiosk session ended, listening again");
+            detector.rearm();
+            let ended = KioskMessage::SessionEnded {
+                session_id: session.id.clone(),
+            };
+            if sender.send(ended.to_message()).await.is_err() {
+                break;
+            }
+            continue;
+        }
+
+        let samples: Vec<f32> = pcm
+            .chunks_exact(2)
+            .map(|chunk| i16::from_le_bytes([chunk[0], chunk[1]]) as f32 / 32768.0)
+            .collect();
+        if samples.is_empty() {
+            continue;
+        }
+        let frame = AudioFrame::new(samples, SampleRate::Hz16000, Channels::Mono, 0);
+        let heard = match detector.process(&frame) {
+            Ok(Some(heard)) => heard,
+            Ok(None) => continue,
+            Err(e) => {
+                tracing::debug!("Wake word detection error: {}", e);
+                continue;
+            },
+        };
+
+        tracing::info!(phrase = %heard.phrase, score = heard.score, "Kiosk wake word heard");
+        match open_session(&state, CreateSessionRequest::default()).await {
+            Ok((session, _)) => {
+                let wake = KioskMessage::Wake {
+                    phrase: heard.phrase,
+                    session_id: session.id.clone(),
+                    websocket_url: format!("/ws/{}", session.id),
+                };
+                woken = Some(session);
+                if sender.send(wake.to_message()).await.is_err() {
+                    break;
+                }
+            },
+            Err(response) => {
+                tracing::warn!(status = %response.status(), "Kiosk session could not be started");
+                let error = KioskMessage::Error {
+                    message: "Session could not be started, please try again".to_string(),
+                };
+                if sender.send(error.to_message()).await.is_err() {
+                    break;
+                }
+            },
+        }
+    }
+
+    // A session woken for a device that went away is left to expire
+    tracing::info!("Kiosk stream closed");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kiosk_message_format() {
+        let wake = KioskMessage::Wake {
+            phrase: "Hello Kotak".to_string(),
+            session_id: "abc".to_string(),
+            websocket_url: "/ws/abc".to_string(),
+        };
+        let json = serde_json::to_value(&wake).unwrap();
+        assert_eq!(json["type"], "wake");
+        assert_eq!(json["websocket_url"], "/ws/abc");
+
+        let audio: KioskMessage =
+            serde_json::from_str(r#"{"type":"audio","data":"AAA="}"#).unwrap();
+        assert!(matches!(audio, KioskMessage::Audio { .. }));
+    }
+}
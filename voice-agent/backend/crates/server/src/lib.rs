@@ -2,24 +2,43 @@
 //!
 //! Provides WebSocket, WebRTC, and HTTP endpoints for the voice agent.
 
+pub mod admin;
+pub mod admission;
+pub mod analytics;
 pub mod auth;
+pub mod captions;
+pub mod degradation;
+pub mod event_sink;
+pub mod events;
 pub mod http;
+pub mod kiosk;
 pub mod mcp_server;
 pub mod metrics;
+pub mod model_registry;
+pub mod models;
 pub mod ptt;
 pub mod rate_limit;
 pub mod session;
+pub mod sms_inbound;
 pub mod state;
+pub mod storage;
+pub mod supervisor;
+pub mod usage;
+pub mod webhooks;
 #[cfg(feature = "webrtc")]
 pub mod webrtc;
 pub mod websocket;
 
+pub use admission::{AdmissionController, Rejection};
 pub use auth::auth_middleware;
+pub use degradation::{ComponentHealth, ComponentStatus, DegradationManager, OverallHealth};
 pub use http::create_router;
 pub use metrics::{
     init_metrics, record_error, record_llm_latency, record_request, record_stt_latency,
     record_total_latency, record_tts_latency,
 };
+pub use model_registry::{ModelManifest, ModelRegistry, RegistryError};
+pub use models::{ModelComponent, ModelManager, ModelStatus};
 pub use rate_limit::{RateLimitError, RateLimiter};
 pub use session::{
     InMemorySessionStore, RecoverableSession, ScyllaSessionStore, Session, SessionManager,
@@ -61,6 +80,26 @@ pub enum ServerError {
     Persistence(String),
 }
 
+impl voice_agent_core::ErrorClass for ServerError {
+    fn kind(&self) -> voice_agent_core::ErrorKind {
+        use voice_agent_core::ErrorKind;
+        match self {
+            ServerError::Session(_) => ErrorKind::NotFound,
+            ServerError::WebSocket(_) | ServerError::WebRtc(_) | ServerError::Persistence(_) => {
+                ErrorKind::Unavailable
+            },
+            ServerError::Auth(_) => ErrorKind::Unauthorized,
+            ServerError::RateLimit => ErrorKind::RateLimited,
+            ServerError::InvalidRequest(_) => ErrorKind::InvalidInput,
+            ServerError::Internal(_) => ErrorKind::Internal,
+        }
+    }
+
+    fn is_user_visible(&self) -> bool {
+        !matches!(self, ServerError::Persistence(_))
+    }
+}
+
 impl From<ServerError> for axum::http::StatusCode {
     fn from(err: ServerError) -> Self {
         match err {
@@ -6,13 +6,29 @@
 use std::net::SocketAddr;
 use std::path::Path;
 use std::sync::Arc;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, Layer};
+use tracing_subscriber::{
+    fmt::writer::BoxMakeWriter, layer::SubscriberExt, util::SubscriberInitExt, Layer,
+};
 
-use voice_agent_config::{load_settings, MasterDomainConfig, Settings};
-use voice_agent_server::{create_router, init_metrics, session::ScyllaSessionStore, AppState};
+use voice_agent_config::{
+    load_settings, ArchivalBackendKind, MasterDomainConfig, PersistenceBackend, PipelineComponent,
+    Settings, ToolsDomainView,
+};
+use voice_agent_pipeline::PronunciationLexicon;
+use voice_agent_server::degradation::probe_components;
+use voice_agent_server::model_registry::{describe_failures, ModelRegistry};
+use voice_agent_server::models::{load_llm_router, load_stt_pool, load_tts_pool, SttPool, TtsPool};
+use voice_agent_server::storage::init_persistence;
+use voice_agent_server::{
+    create_router, init_metrics, session::ScyllaSessionStore, AdmissionController, AppState,
+    DegradationManager,
+};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Serve tools over MCP stdio instead of HTTP (for external agent frameworks)
+    let mcp_stdio = std::env::args().any(|arg| arg == "--mcp-stdio");
+
     // P0 FIX: Load configuration from files and environment
     // Priority: env vars > config/{env}.yaml > config/default.yaml > defaults
     let env = std::env::var("VOICE_AGENT_ENV").ok();
@@ -35,7 +51,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
 
     // P5 FIX: Initialize tracing with optional OpenTelemetry
-    init_tracing(&config);
+    init_tracing(&config, mcp_stdio);
 
     tracing::info!("Starting Voice Agent Server v{}", env!("CARGO_PKG_VERSION"));
     tracing::info!(
@@ -55,27 +71,105 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let _metrics_handle = init_metrics();
     tracing::info!("Initialized Prometheus metrics at /metrics");
 
-    // Optionally initialize ScyllaDB persistence with config-driven tiers
-    let mut state = if config.persistence.enabled {
-        tracing::info!("Initializing ScyllaDB persistence layer...");
+    // Component health: required components abort startup, the rest degrade
+    let degradation = Arc::new(DegradationManager::new(config.degradation.clone()));
+
+    // Optionally initialize persistence (ScyllaDB, SQLite or Postgres) with config-driven tiers
+    let mut archival_store: Option<Arc<dyn voice_agent_persistence::ArchivalStore>> = None;
+    let mut analytics_store: Option<Arc<dyn voice_agent_persistence::AnalyticsStore>> = None;
+    let mut usage_store: Option<Arc<dyn voice_agent_persistence::UsageStore>> = None;
+    let mut webhook_log: Option<Arc<dyn voice_agent_persistence::WebhookDeliveryStore>> = None;
+    let state = if config.persistence.enabled {
+        let backend = config.persistence.backend.as_str();
+        tracing::info!(backend, "Initializing persistence layer...");
         match init_persistence(&config, master_domain_config.clone()).await {
             Ok(persistence) => {
-                tracing::info!(
-                    hosts = ?config.persistence.scylla_hosts,
-                    keyspace = %config.persistence.keyspace,
-                    "ScyllaDB persistence initialized"
+                let target = match config.persistence.backend {
+                    PersistenceBackend::Scylla => format!(
+                        "{:?}/{}",
+                        config.persistence.scylla_hosts, config.persistence.keyspace
+                    ),
+                    PersistenceBackend::Sqlite | PersistenceBackend::Postgres => {
+                        redact_url_password(&config.persistence.sql.url)
+                    },
+                };
+                tracing::info!(backend, target = %target, "Persistence initialized");
+                degradation.healthy(
+                    PipelineComponent::Scylla,
+                    format!("{} connected to {}", backend, target),
                 );
-                let scylla_store = ScyllaSessionStore::new(persistence.sessions);
+                let scylla_store = ScyllaSessionStore::new(persistence.sessions.clone());
                 // P2 FIX: Wire audit logging for RBI compliance
-                let audit_log: Arc<dyn voice_agent_persistence::AuditLog> =
-                    Arc::new(persistence.audit);
+                let audit_log = persistence.audit;
                 // P1-4 FIX: Wire SMS and AssetPrice services into tools
-                let sms_service: Arc<dyn voice_agent_persistence::SmsService> =
-                    Arc::new(persistence.sms);
+                let mut sms_service = persistence.sms;
                 // P16 FIX: Use generic AssetPriceService (GoldPriceService is an alias)
-                let gold_price_service: Arc<dyn voice_agent_persistence::AssetPriceService> =
-                    Arc::new(persistence.asset_price);
-                tracing::info!("SMS and AssetPrice services wired into tools");
+                let gold_price_service = persistence.asset_price;
+                let slot_store = persistence.slots;
+                let competitor_rates = persistence.competitor_rates;
+                let callbacks = persistence.callbacks;
+                tracing::info!("SMS, AssetPrice, slot, rate and callback services wired in");
+                // Dial callbacks as their windows open and expire missed ones
+                init_callback_dispatcher(&config, callbacks.clone());
+                // Deliver captured leads to the configured CRM (status tracked in persistence)
+                let mut crm = init_crm_connector(&config);
+                if config.outbox.enabled {
+                    // Record SMS and leads first; the outbox dispatcher delivers them
+                    (sms_service, crm) = init_outbox(&config, persistence.outbox, sms_service, crm);
+                } else {
+                    crm = crm
+                        .map(|connector| init_crm(&config, connector, persistence.crm_deliveries));
+                }
+                // Cap SMS per customer per day ahead of the outbox
+                if config.budgets.enabled && config.budgets.max_sms_per_customer_per_day > 0 {
+                    let logger =
+                        Arc::new(voice_agent_persistence::AuditLogger::new(audit_log.clone()));
+                    sms_service = Arc::new(
+                        voice_agent_tools::QuotaSmsService::new(
+                            sms_service,
+                            config.budgets.max_sms_per_customer_per_day,
+                        )
+                        .with_audit_logger(logger),
+                    );
+                }
+                let handoff = init_handoff_queue(&config);
+                // Store bookings and let customers confirm them by SMS reply
+                let confirmations = config.sms_confirmation.enabled.then(|| {
+                    let view = ToolsDomainView::new(master_domain_config.clone());
+                    Arc::new(
+                        voice_agent_tools::AppointmentConfirmations::new(
+                            persistence.appointments.clone(),
+                            sms_service.clone(),
+                        )
+                        .with_session_store(persistence.sessions.clone())
+                        .with_view(Arc::new(view)),
+                    )
+                });
+                // Text customers when the price crosses their alert threshold
+                let price_alerts = config.price_alerts.enabled.then(|| {
+                    init_price_alert_monitor(
+                        &config,
+                        persistence.price_alerts.clone(),
+                        gold_price_service.clone(),
+                        sms_service.clone(),
+                        master_domain_config.clone(),
+                    );
+                    persistence.price_alerts.clone()
+                });
+                // LTV and rate cards, including those published through the admin API
+                let rate_cards =
+                    init_rate_cards(persistence.rate_cards.clone(), master_domain_config.clone())
+                        .await;
+                archival_store = Some(persistence.archival);
+                if config.analytics.enabled {
+                    analytics_store = Some(persistence.analytics);
+                }
+                if config.usage.enabled {
+                    usage_store = Some(persistence.usage);
+                }
+                if config.webhooks.enabled {
+                    webhook_log = Some(persistence.webhook_deliveries);
+                }
                 // P12 FIX: Use new method that only accepts MasterDomainConfig
                 AppState::with_full_persistence(
                     config.clone(),
@@ -83,13 +177,25 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     master_domain_config.clone(),
                     sms_service,
                     gold_price_service,
+                    slot_store,
+                    competitor_rates,
+                    callbacks,
+                    crm,
+                    handoff,
+                    confirmations,
+                    price_alerts,
+                    rate_cards,
+                    persistence.offer_events,
                 )
                 .with_audit_logger(audit_log)
+                .with_appointment_store(persistence.appointments)
+                .with_identity_store(persistence.customers)
+                .with_session_journal(persistence.journal)
             },
             Err(e) => {
-                tracing::error!(
-                    "Failed to initialize ScyllaDB: {}. Falling back to in-memory.",
-                    e
+                degradation.degraded(
+                    PipelineComponent::Scylla,
+                    format!("failed to initialize ({}); sessions are in-memory", e),
                 );
                 // P12 FIX: Use new method that only accepts MasterDomainConfig
                 AppState::with_master_domain_config(config.clone(), master_domain_config.clone())
@@ -97,10 +203,53 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     } else {
         tracing::info!("Persistence disabled, using in-memory session store");
+        degradation.disabled(PipelineComponent::Scylla, "persistence disabled");
         // P12 FIX: Use new method that only accepts MasterDomainConfig
         AppState::with_master_domain_config(config.clone(), master_domain_config.clone())
     };
 
+    // Refuse new sessions gracefully once model workers or the host saturate
+    let admission = Arc::new(AdmissionController::new(config.admission.clone()));
+    if config.admission.enabled {
+        admission.spawn_sampler();
+    }
+
+    // Verify (and fetch) pinned model artifacts before any model loads
+    let model_registry = init_model_registry(&config).await?;
+
+    // Shared STT/TTS workers that batch requests across sessions
+    let (stt_pool, tts_pool) = init_worker_pools(&config);
+
+    let mut state = state
+        .with_degradation(degradation.clone())
+        .with_admission(admission)
+        .with_worker_pools(stt_pool, tts_pool)
+        .with_tool_execution(config.tool_execution.clone());
+    if let Some(registry) = model_registry {
+        state = state.with_model_registry(registry);
+    }
+    if let Some(store) = analytics_store {
+        state = state.with_analytics_store(store);
+    }
+    if let Some(store) = usage_store {
+        state = state.with_usage_store(store);
+    }
+    if config.webhooks.enabled {
+        // Without persistence the delivery log is kept in memory
+        let log = webhook_log.unwrap_or_else(|| {
+            Arc::new(voice_agent_persistence::InMemoryWebhookDeliveryStore::new())
+        });
+        state = state.with_webhook_log(log);
+    }
+
+    // Pronunciation overrides, re-read when lexicon.yaml is edited
+    let lexicon = master_domain_config.lexicon.clone();
+    let lexicon = match master_domain_config.lexicon_path {
+        Some(ref path) => PronunciationLexicon::watching(lexicon, path),
+        None => PronunciationLexicon::new(lexicon),
+    };
+    state = state.with_lexicon(Arc::new(lexicon));
+
     // P0 FIX: Optionally initialize VectorStore for RAG
     if config.rag.enabled {
         tracing::info!("Initializing VectorStore for RAG...");
@@ -122,12 +271,123 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    // Archival memory: embeddings + durable vector backend
+    let (archival_embedder, archival_backend) = init_archival_memory(&config, archival_store).await;
+    state = state.with_archival_memory(archival_embedder.clone(), archival_backend);
+
+    // Knowledge base: chunk and index product sheets / policies per domain
+    if config.knowledge.enabled {
+        let knowledge_base =
+            init_knowledge_base(&config, &master_domain_config.domain_id, archival_embedder).await;
+        state = state.with_knowledge_base(Arc::new(knowledge_base));
+    }
+
+    // Response cache: reuse deterministic tool and FAQ answers across sessions
+    if config.response_cache.enabled {
+        state = state.with_response_cache(Arc::new(voice_agent_agent::ResponseCache::new(
+            config.response_cache.clone(),
+        )));
+    }
+
+    // Output guardrails: enforce the domain's compliance rules on LLM answers
+    if config.guardrails.enabled {
+        let mut guardrails = voice_agent_agent::Guardrails::new(
+            &master_domain_config.compliance,
+            config.guardrails.action,
+        );
+        if config.guardrails.audit {
+            if let Some(ref logger) = state.audit_logger {
+                guardrails = guardrails.with_audit_logger(logger.clone());
+            }
+        }
+        state = state.with_guardrails(Arc::new(guardrails));
+    }
+
+    // Response length: keep spoken answers within the duration budget
+    if config.response_length.enabled {
+        state = state.with_response_governor(Arc::new(voice_agent_agent::ResponseGovernor::new(
+            config.response_length.clone(),
+        )));
+    }
+
+    // Abusive-speech policy: warn, de-escalate or hand off abusive callers
+    if config.abuse.enabled {
+        state = state.with_abuse_policy(Arc::new(voice_agent_agent::AbusePolicy::new(
+            config.abuse.clone(),
+        )));
+    }
+
+    // Code-switch rendering: keep spoken answers in the session language's script
+    if config.code_switch.enabled {
+        use voice_agent_text_processing::{CodeSwitchConfig, CodeSwitchLevel, CodeSwitchRenderer};
+        let level = match config.code_switch.level {
+            voice_agent_config::CodeSwitchLevel::Strict => CodeSwitchLevel::Strict,
+            voice_agent_config::CodeSwitchLevel::Loanwords => CodeSwitchLevel::Loanwords,
+            voice_agent_config::CodeSwitchLevel::Free => CodeSwitchLevel::Free,
+        };
+        let mut renderer = CodeSwitchRenderer::new(CodeSwitchConfig {
+            level,
+            loanwords: config.code_switch.loanwords.clone(),
+        });
+        if config.code_switch.translate {
+            renderer = renderer.with_translator(state.translator.clone());
+        }
+        state = state.with_code_switch(Arc::new(renderer));
+    }
+
+    // Disposition coding: tag finished calls for reporting
+    if config.disposition.enabled {
+        state = state.with_disposition_classifier(Arc::new(
+            voice_agent_agent::DispositionClassifier::new(config.disposition.clone()),
+        ));
+    }
+
+    // LLM router: per-task backends with health checks and failover
+    match load_llm_router(&config) {
+        Ok(Some(router)) => state = state.with_llm_router(router),
+        Ok(None) => {},
+        Err(e) => tracing::warn!("{}. Using agent.llm.", e),
+    }
+
+    // Fail fast instead of serving calls on stubs a required component fell back to
+    probe_components(&state).await;
+    if let Err(unmet) = degradation.check_startup() {
+        tracing::error!("Required components unavailable: {}", unmet);
+        return Err(format!("required components unavailable: {}", unmet).into());
+    }
+
     tracing::info!(
+        overall = ?degradation.overall(),
         distributed = state.is_distributed_sessions(),
         rag_enabled = state.vector_store.is_some(),
+        knowledge_base = state.knowledge_base.is_some(),
+        llm_router = state.models.active().models.llm_router.is_some(),
         "Initialized application state"
     );
 
+    if mcp_stdio {
+        let server = voice_agent_server::mcp_server::McpServer::new(state.tools.clone());
+        voice_agent_server::mcp_server::serve_stdio(server).await?;
+        return Ok(());
+    }
+
+    // Domain events: metrics and audit subscribe to the shared bus
+    init_event_subscribers(&config, &state);
+
+    // Finished sessions: assign a disposition code, record the outcome and
+    // publish the session end
+    init_session_finalizer(&state);
+
+    // Conversation analytics: roll finished sessions up per day
+    if state.analytics.is_some() {
+        init_analytics(&config, &state);
+    }
+
+    // Usage accounting: roll per-turn usage up per day and tenant
+    if state.usage.is_some() {
+        init_usage(&config, &state);
+    }
+
     // P2 FIX: Attempt to recover sessions from previous run
     if state.is_distributed_sessions() {
         match state.recover_sessions().await {
@@ -192,7 +452,7 @@ async fn shutdown_signal() {
 
 /// Initialize tracing (with optional OpenTelemetry when feature enabled)
 #[cfg(feature = "telemetry")]
-fn init_tracing(config: &Settings) {
+fn init_tracing(config: &Settings, log_to_stderr: bool) {
     use opentelemetry_otlp::WithExportConfig;
 
     let env_filter = tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| {
@@ -201,10 +461,19 @@ fn init_tracing(config: &Settings) {
     });
 
     let subscriber = tracing_subscriber::registry().with(env_filter);
+    // stdout carries protocol messages in MCP stdio mode
+    let writer = if log_to_stderr {
+        BoxMakeWriter::new(std::io::stderr)
+    } else {
+        BoxMakeWriter::new(std::io::stdout)
+    };
     let fmt_layer = if config.observability.log_json {
-        tracing_subscriber::fmt::layer().json().boxed()
+        tracing_subscriber::fmt::layer()
+            .json()
+            .with_writer(writer)
+            .boxed()
     } else {
-        tracing_subscriber::fmt::layer().boxed()
+        tracing_subscriber::fmt::layer().with_writer(writer).boxed()
     };
 
     if let Some(otlp_endpoint) = &config.observability.otlp_endpoint {
@@ -239,46 +508,337 @@ fn init_tracing(config: &Settings) {
 
 /// Initialize tracing (console only - telemetry feature disabled)
 #[cfg(not(feature = "telemetry"))]
-fn init_tracing(config: &Settings) {
+fn init_tracing(config: &Settings, log_to_stderr: bool) {
     let env_filter = tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| {
         let level = &config.observability.log_level;
         format!("voice_agent={},tower_http=debug", level).into()
     });
 
     let subscriber = tracing_subscriber::registry().with(env_filter);
+    // stdout carries protocol messages in MCP stdio mode
+    let writer = if log_to_stderr {
+        BoxMakeWriter::new(std::io::stderr)
+    } else {
+        BoxMakeWriter::new(std::io::stdout)
+    };
     let fmt_layer = if config.observability.log_json {
-        tracing_subscriber::fmt::layer().json().boxed()
+        tracing_subscriber::fmt::layer()
+            .json()
+            .with_writer(writer)
+            .boxed()
     } else {
-        tracing_subscriber::fmt::layer().boxed()
+        tracing_subscriber::fmt::layer().with_writer(writer).boxed()
     };
     subscriber.with(fmt_layer).init();
 }
 
-/// Initialize ScyllaDB persistence layer with config-driven tier definitions
-async fn init_persistence(
+/// Strip the password from a connection URL before logging it
+fn redact_url_password(url: &str) -> String {
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return url.to_string();
+    };
+    match rest.split_once('@') {
+        Some((credentials, host)) if credentials.contains(':') => {
+            let user = credentials.split(':').next().unwrap_or_default();
+            format!("{}://{}:***@{}", scheme, user, host)
+        },
+        _ => url.to_string(),
+    }
+}
+
+/// Initialize the CRM connector, or `None` when none is configured
+fn init_crm_connector(config: &Settings) -> Option<Arc<dyn voice_agent_tools::CrmIntegration>> {
+    match voice_agent_tools::connector_from_config(&config.crm) {
+        Ok(connector) => {
+            if connector.is_some() {
+                let name = voice_agent_tools::crm::connector_name(config.crm.connector);
+                tracing::info!(connector = name, "CRM connector initialized");
+            }
+            connector
+        },
+        Err(e) => {
+            tracing::error!(
+                "Failed to initialize CRM connector: {}. Leads stay local.",
+                e
+            );
+            None
+        },
+    }
+}
+
+/// Wrap the CRM connector in the delivery queue
+fn init_crm(
+    config: &Settings,
+    connector: Arc<dyn voice_agent_tools::CrmIntegration>,
+    store: Arc<dyn voice_agent_persistence::CrmDeliveryStore>,
+) -> Arc<dyn voice_agent_tools::CrmIntegration> {
+    Arc::new(voice_agent_tools::CrmDeliveryQueue::spawn(
+        connector,
+        voice_agent_tools::crm::connector_name(config.crm.connector),
+        voice_agent_tools::RetryPolicy::from_config(&config.crm),
+        config.crm.queue_capacity,
+        Some(store),
+    ))
+}
+
+/// Route SMS and CRM leads through the outbox and start its dispatcher
+///
+/// Returns the wrapped services to hand to the tools.
+fn init_outbox(
+    config: &Settings,
+    store: Arc<dyn voice_agent_persistence::OutboxStore>,
+    sms: Arc<dyn voice_agent_persistence::SmsService>,
+    crm: Option<Arc<dyn voice_agent_tools::CrmIntegration>>,
+) -> (
+    Arc<dyn voice_agent_persistence::SmsService>,
+    Option<Arc<dyn voice_agent_tools::CrmIntegration>>,
+) {
+    use voice_agent_tools::{Outbox, OutboxCrm, OutboxDispatcher, OutboxSmsService};
+
+    let mut dispatcher =
+        OutboxDispatcher::from_config(store.clone(), &config.outbox).with_sms(sms.clone());
+    if let Some(connector) = &crm {
+        dispatcher = dispatcher.with_crm(connector.clone());
+    }
+    dispatcher.spawn();
+    tracing::info!(crm = crm.is_some(), "Outbox dispatcher started");
+
+    let outbox = Outbox::new(store);
+    let crm = crm.map(|connector| {
+        Arc::new(OutboxCrm::new(outbox.clone(), connector))
+            as Arc<dyn voice_agent_tools::CrmIntegration>
+    });
+    (Arc::new(OutboxSmsService::new(outbox, sms)), crm)
+}
+
+/// Initialize the human handoff queue, or `None` when escalations are not queued
+fn init_handoff_queue(config: &Settings) -> Option<Arc<dyn voice_agent_tools::HandoffQueue>> {
+    match voice_agent_tools::handoff_queue_from_config(&config.handoff) {
+        Ok(queue) => {
+            if queue.is_some() {
+                tracing::info!(queue = ?config.handoff.queue, "Human handoff queue initialized");
+            }
+            queue
+        },
+        Err(e) => {
+            tracing::error!("Failed to initialize handoff queue: {}. Not queuing.", e);
+            None
+        },
+    }
+}
+
+/// Start the background callback dispatcher
+///
+/// Without a dialer endpoint it only expires callbacks whose window closed.
+fn init_callback_dispatcher(
+    config: &Settings,
+    store: Arc<dyn voice_agent_persistence::CallbackStore>,
+) {
+    let dialer = match voice_agent_tools::dialer_from_config(&config.dialer) {
+        Ok(dialer) => dialer,
+        Err(e) => {
+            tracing::error!("Failed to initialize outbound dialer: {}. Not dialing.", e);
+            None
+        },
+    };
+    tracing::info!(dialer = dialer.is_some(), "Callback dispatcher started");
+    voice_agent_tools::CallbackDispatcher::new(
+        store,
+        dialer,
+        voice_agent_tools::DispatchPolicy::from_config(&config.dialer),
+    )
+    .spawn();
+}
+
+/// Start the monitor that texts customers when their price alerts trigger
+fn init_price_alert_monitor(
+    config: &Settings,
+    store: Arc<dyn voice_agent_persistence::PriceAlertStore>,
+    prices: Arc<dyn voice_agent_persistence::AssetPriceService>,
+    sms: Arc<dyn voice_agent_persistence::SmsService>,
+    master_domain_config: Arc<MasterDomainConfig>,
+) {
+    tracing::info!(
+        interval_secs = config.price_alerts.check_interval_secs,
+        "Price alert monitor started"
+    );
+    voice_agent_tools::PriceAlertMonitor::new(store, prices, sms, &config.price_alerts)
+        .with_view(Arc::new(ToolsDomainView::new(master_domain_config)))
+        .spawn();
+}
+
+/// Load published rate cards and re-read them in the background
+///
+/// Until the store can be read, rates come from the domain's rate_card.yaml.
+async fn init_rate_cards(
+    store: Arc<dyn voice_agent_persistence::RateCardStore>,
+    master_domain_config: Arc<MasterDomainConfig>,
+) -> Arc<voice_agent_tools::RateCardEngine> {
+    let view = Arc::new(ToolsDomainView::new(master_domain_config));
+    let engine = Arc::new(voice_agent_tools::RateCardEngine::new(view).with_store(store));
+    match engine.refresh().await {
+        Ok(published) => tracing::info!(
+            published,
+            version = %engine.current().version,
+            "Rate cards loaded"
+        ),
+        Err(e) => tracing::warn!("Published rate cards unavailable, using config: {}", e),
+    }
+    engine.clone().spawn();
+    engine
+}
+
+/// Verify the artifacts in the model manifest, downloading missing ones
+///
+/// Startup fails if an artifact is missing or does not match its checksum.
+async fn init_model_registry(
     config: &Settings,
-    domain_config: Arc<voice_agent_config::domain::MasterDomainConfig>,
-) -> Result<voice_agent_persistence::PersistenceLayer, voice_agent_persistence::PersistenceError> {
-    let scylla_config = voice_agent_persistence::ScyllaConfig {
-        hosts: config.persistence.scylla_hosts.clone(),
-        keyspace: config.persistence.keyspace.clone(),
-        replication_factor: config.persistence.replication_factor,
+) -> Result<Option<Arc<ModelRegistry>>, Box<dyn std::error::Error>> {
+    if !config.model_registry.enabled {
+        return Ok(None);
+    }
+    let registry = ModelRegistry::new(config.model_registry.clone())?;
+    registry.prepare().await.map_err(|failures| {
+        format!("model artifacts rejected: {}", describe_failures(&failures))
+    })?;
+    Ok(Some(Arc::new(registry)))
+}
+
+/// Load the shared model worker pools enabled in `pipeline.workers`
+///
+/// A pool that fails to load is skipped; sessions then load their own model.
+fn init_worker_pools(config: &Settings) -> (Option<SttPool>, Option<TtsPool>) {
+    let stt_pool = load_stt_pool(config).unwrap_or_else(|e| {
+        tracing::warn!("{}. Using per-session STT models.", e);
+        None
+    });
+    let tts_pool = load_tts_pool(config).unwrap_or_else(|e| {
+        tracing::warn!("{}. Using per-session TTS models.", e);
+        None
+    });
+    (stt_pool, tts_pool)
+}
+
+/// Attach the built-in domain event subscribers, the broker sink and webhooks
+fn init_event_subscribers(config: &Settings, state: &AppState) {
+    use voice_agent_server::event_sink::{event_sink_from_config, EventSinkSubscriber};
+    use voice_agent_server::events::{AuditSubscriber, MetricsSubscriber};
+    use voice_agent_server::webhooks::{HttpWebhookTransport, WebhookSubscriber};
+
+    state.events.attach(Arc::new(MetricsSubscriber));
+    if let Some(ref logger) = state.audit_logger {
+        state
+            .events
+            .attach(Arc::new(AuditSubscriber::new(logger.clone())));
+    }
+    match event_sink_from_config(&config.event_sink) {
+        Ok(Some(sink)) => {
+            tracing::info!(
+                sink = sink.name(),
+                delivery = ?config.event_sink.delivery,
+                "Publishing conversation events to broker"
+            );
+            state.events.attach(Arc::new(EventSinkSubscriber::spawn(
+                sink,
+                config.event_sink.clone(),
+            )));
+        },
+        Ok(None) => {},
+        Err(e) => tracing::error!(error = %e, "Event sink disabled"),
+    }
+    if let Some(ref log) = state.webhook_deliveries {
+        let timeout = std::time::Duration::from_millis(config.webhooks.timeout_ms);
+        match HttpWebhookTransport::new(timeout) {
+            Ok(transport) => {
+                tracing::info!(
+                    endpoints = config.webhooks.endpoints.len(),
+                    "Sending business events to webhooks"
+                );
+                state.events.attach(Arc::new(WebhookSubscriber::spawn(
+                    &config.webhooks,
+                    Arc::new(transport),
+                    log.clone(),
+                )));
+            },
+            Err(e) => tracing::error!(error = %e, "Webhooks disabled"),
+        }
+    }
+    tracing::info!(
+        subscribers = state.events.subscriber_count(),
+        "Domain event subscribers attached"
+    );
+}
+
+/// Code and record sessions as they are removed or expire
+fn init_session_finalizer(state: &AppState) {
+    let mut closed = state.sessions.subscribe_closed();
+    let finalizer = state.clone();
+    tokio::spawn(async move {
+        while let Some(session) = closed.recv().await {
+            finalizer.finalize_session(&session).await;
+        }
+    });
+}
+
+/// Start the daily analytics rollup job
+///
+/// Rollups for today and yesterday are rebuilt on every pass, since a
+/// session is counted under the day it started and may end after midnight.
+fn init_analytics(config: &Settings, state: &AppState) {
+    let Some(store) = state.analytics.clone() else {
+        return;
+    };
+
+    let interval = std::time::Duration::from_secs(config.analytics.rollup_interval_secs);
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        loop {
+            ticker.tick().await;
+            let today = chrono::Utc::now().date_naive();
+            for day in [today.pred_opt().unwrap_or(today), today] {
+                match voice_agent_persistence::analytics::roll_up_day(store.as_ref(), day).await {
+                    Ok(sessions) => tracing::debug!(%day, sessions, "Analytics rollup updated"),
+                    Err(e) => tracing::error!(%day, error = %e, "Analytics rollup failed"),
+                }
+            }
+        }
+    });
+    tracing::info!(
+        interval_secs = config.analytics.rollup_interval_secs,
+        "Conversation analytics started"
+    );
+}
+
+/// Start the daily usage rollup job
+///
+/// Like the analytics rollup, today and yesterday are rebuilt on every
+/// pass: the last turns of a call that crosses midnight land on the new day.
+fn init_usage(config: &Settings, state: &AppState) {
+    let Some(store) = state.usage.clone() else {
+        return;
     };
 
-    // Extract tier definitions from domain config via ToolsDomainView
-    let tools_view = voice_agent_config::ToolsDomainView::new(domain_config);
-    let base_price = tools_view.asset_price_per_unit();
-    let tier_data = tools_view.quality_tiers_full();
-    let tiers: Vec<voice_agent_persistence::TierDefinition> = tier_data
-        .into_iter()
-        .map(|(code, factor, description)| voice_agent_persistence::TierDefinition {
-            code,
-            factor,
-            description,
-        })
-        .collect();
-
-    voice_agent_persistence::init(scylla_config, base_price, tiers).await
+    let interval = std::time::Duration::from_secs(config.usage.rollup_interval_secs);
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        loop {
+            ticker.tick().await;
+            let today = chrono::Utc::now().date_naive();
+            for day in [today.pred_opt().unwrap_or(today), today] {
+                match voice_agent_persistence::usage::roll_up_usage_day(store.as_ref(), day).await {
+                    Ok(turns) => tracing::debug!(%day, turns, "Usage rollup updated"),
+                    Err(e) => tracing::error!(%day, error = %e, "Usage rollup failed"),
+                }
+            }
+        }
+    });
+    tracing::info!(
+        interval_secs = config.usage.rollup_interval_secs,
+        default_tenant = %config.usage.default_tenant,
+        "Usage accounting started"
+    );
 }
 
 /// P0 FIX: Initialize VectorStore for RAG retrieval
@@ -297,6 +857,153 @@ async fn init_vector_store(
     Ok(store)
 }
 
+/// Ingest the knowledge directory into per-domain indices
+///
+/// Top-level files belong to the active domain. Files that fail to parse
+/// are logged and skipped; the knowledge base may end up empty.
+async fn init_knowledge_base(
+    config: &Settings,
+    root_domain: &str,
+    embedder: Option<Arc<voice_agent_rag::Embedder>>,
+) -> voice_agent_rag::KnowledgeBase {
+    let knowledge = config.knowledge.clone();
+    let root_domain = root_domain.to_string();
+
+    let kb_config = voice_agent_rag::KnowledgeBaseConfig {
+        dense_weight: knowledge.dense_weight,
+        min_score: knowledge.min_score,
+        default_top_k: knowledge.top_k,
+        language_mismatch_penalty: knowledge.language_mismatch_penalty,
+    };
+    let mut knowledge_base = voice_agent_rag::KnowledgeBase::new(kb_config);
+    if let Some(embedder) = embedder {
+        knowledge_base = knowledge_base.with_embedder(embedder);
+    }
+
+    let pipeline = voice_agent_rag::IngestionPipeline::new(voice_agent_rag::IngestionConfig {
+        chunk: voice_agent_rag::ChunkConfig {
+            target_chunk_size: knowledge.chunk_target_tokens,
+            max_chunk_size: knowledge.chunk_max_tokens,
+            min_chunk_size: (knowledge.chunk_target_tokens / 4).max(1),
+            overlap_percent: knowledge.chunk_overlap,
+            ..Default::default()
+        },
+        default_language: knowledge.default_language.clone(),
+    });
+
+    // Parsing and embedding are CPU-bound
+    let result = tokio::task::spawn_blocking(move || {
+        let report =
+            pipeline.ingest_directory(&knowledge_base, Path::new(&knowledge.directory), &root_domain);
+        (knowledge_base, report)
+    })
+    .await;
+
+    match result {
+        Ok((knowledge_base, Ok(report))) => {
+            if !report.failed.is_empty() {
+                tracing::warn!(failed = report.failed.len(), "Some knowledge files were not ingested");
+            }
+            knowledge_base
+        },
+        Ok((knowledge_base, Err(e))) => {
+            tracing::warn!("Knowledge ingestion failed: {}. Knowledge base is empty.", e);
+            knowledge_base
+        },
+        Err(e) => {
+            tracing::warn!("Knowledge ingestion task failed: {}. Knowledge base is empty.", e);
+            voice_agent_rag::KnowledgeBase::new(voice_agent_rag::KnowledgeBaseConfig::default())
+        },
+    }
+}
+
+/// Initialize the archival memory embedder and durable backend
+///
+/// Any failure degrades to in-process archival memory rather than aborting startup.
+async fn init_archival_memory(
+    config: &Settings,
+    scylla_store: Option<Arc<dyn voice_agent_persistence::ArchivalStore>>,
+) -> (
+    Option<Arc<voice_agent_rag::Embedder>>,
+    Option<Arc<dyn voice_agent_agent::ArchivalVectorBackend>>,
+) {
+    let archival = &config.archival;
+
+    let model_path = archival
+        .embedding_model
+        .clone()
+        .unwrap_or_else(|| config.models.embeddings.clone());
+    let embedding_config = voice_agent_rag::EmbeddingConfig {
+        embedding_dim: archival.embedding_dim,
+        output_name: archival.embedding_output.clone(),
+        ..Default::default()
+    };
+    let embedder = match voice_agent_rag::Embedder::new(
+        &model_path,
+        &archival.embedding_tokenizer,
+        embedding_config,
+    ) {
+        Ok(embedder) => Some(Arc::new(embedder)),
+        Err(e) => {
+            tracing::warn!(model = %model_path, "Archival embedder unavailable: {}. Using BM25-only search.", e);
+            None
+        },
+    };
+
+    let backend: Option<Arc<dyn voice_agent_agent::ArchivalVectorBackend>> = match archival.backend {
+        ArchivalBackendKind::InMemory => None,
+        ArchivalBackendKind::Qdrant => {
+            let vs_config = voice_agent_rag::VectorStoreConfig {
+                endpoint: config.rag.qdrant_endpoint.clone(),
+                collection: archival.collection.clone(),
+                vector_dim: archival.embedding_dim,
+                distance: voice_agent_rag::VectorDistance::Cosine,
+                api_key: config.rag.qdrant_api_key.clone(),
+            };
+            let store = match voice_agent_rag::VectorStore::new(vs_config).await {
+                Ok(store) => store.ensure_collection().await.map(|_| store),
+                Err(e) => Err(e),
+            };
+            match store {
+                Ok(store) => Some(Arc::new(voice_agent_agent::QdrantArchivalBackend::new(
+                    Arc::new(store),
+                ))),
+                Err(e) => {
+                    tracing::warn!("Failed to initialize Qdrant archival backend: {}. Archival memory is in-process only.", e);
+                    None
+                },
+            }
+        },
+        ArchivalBackendKind::Scylla => match scylla_store {
+            Some(store) => Some(Arc::new(voice_agent_agent::ScyllaArchivalBackend::new(
+                store,
+                archival.collection.clone(),
+            ))),
+            None => {
+                tracing::warn!("ScyllaDB unavailable. Archival memory is in-process only.");
+                None
+            },
+        },
+    };
+
+    let persistent = backend.is_some();
+    if persistent && embedder.is_none() {
+        tracing::warn!("Archival backend configured without an embedder; notes will not be persisted");
+    }
+    tracing::info!(
+        backend = ?archival.backend,
+        embeddings = embedder.is_some(),
+        persistent,
+        "Archival memory initialized"
+    );
+
+    // Without a durable backend, share notes in process so customer memory
+    // still carries over between sessions until restart
+    let backend = backend.unwrap_or_else(|| Arc::new(voice_agent_agent::InMemoryArchivalBackend::new()));
+
+    (embedder, Some(backend))
+}
+
 /// P12 FIX: Load hierarchical domain configuration from YAML files
 ///
 /// Loads the new MasterDomainConfig from config/domains/{domain_id}/ directory.
@@ -348,22 +1055,19 @@ fn load_master_domain_config(config_dir: &str) -> Arc<MasterDomainConfig> {
                 match error.severity {
                     voice_agent_config::ValidationSeverity::Warning => {
                         tracing::warn!(
-                            source = %error.source,
-                            field = ?error.field,
+                            path = %error.path(),
                             "Config warning: {}", error.message
                         );
                     }
                     voice_agent_config::ValidationSeverity::Error => {
                         tracing::error!(
-                            source = %error.source,
-                            field = ?error.field,
+                            path = %error.path(),
                             "Config error: {}", error.message
                         );
                     }
                     voice_agent_config::ValidationSeverity::Critical => {
                         tracing::error!(
-                            source = %error.source,
-                            field = ?error.field,
+                            path = %error.path(),
                             "Critical config error: {}", error.message
                         );
                     }
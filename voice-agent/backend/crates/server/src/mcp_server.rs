@@ -1,175 +1,418 @@
-//! MCP JSON-RPC Server Endpoint
+//! MCP Server
 //!
 //! P2 FIX: Exposes tools via standard MCP JSON-RPC 2.0 protocol.
 //! This allows external MCP clients to interact with the voice agent's tools.
+//!
+//! Transports:
+//! - `POST /mcp`: one JSON-RPC request per HTTP request
+//! - SSE: `GET /mcp/sse` opens an event stream whose first `endpoint` event
+//!   names the URL to POST requests to; responses arrive as `message` events
+//! - stdio: newline-delimited JSON-RPC (`voice-agent-server --mcp-stdio`)
 
-use axum::{extract::State, Json};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::sse::{Event, KeepAlive, Sse},
+    Extension, Json,
+};
+use futures::{stream, Stream, StreamExt};
+use parking_lot::RwLock;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::Arc;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::sync::mpsc;
 use voice_agent_tools::{
-    mcp::{methods, JsonRpcError, JsonRpcRequest, JsonRpcResponse, ToolCallParams},
+    mcp::{
+        methods, ContentBlock, JsonRpcError, JsonRpcRequest, JsonRpcResponse, RequestId,
+        ServerCapabilities, ToolCallParams,
+    },
     ToolExecutor,
 };
 
 use crate::state::AppState;
 
-/// MCP JSON-RPC endpoint handler
-///
-/// POST /mcp
-///
-/// Handles MCP protocol requests:
-/// - tools/list: List available tools with schemas
-/// - tools/call: Execute a tool with arguments
-pub async fn handle_mcp_request(
-    State(state): State<AppState>,
-    Json(request): Json<JsonRpcRequest>,
-) -> Json<JsonRpcResponse> {
-    // Validate JSON-RPC version
-    if request.jsonrpc != "2.0" {
-        return Json(JsonRpcResponse::error(
-            request.id,
-            JsonRpcError {
-                code: -32600,
-                message: "Invalid Request: jsonrpc must be \"2.0\"".to_string(),
-                data: None,
-            },
-        ));
-    }
-
-    let response = match request.method.as_str() {
-        methods::TOOLS_LIST => handle_tools_list(&state, &request),
-        methods::TOOLS_CALL => handle_tools_call(&state, &request).await,
-        _ => JsonRpcResponse::error(
-            request.id.clone(),
-            JsonRpcError {
-                code: -32601,
-                message: format!("Method not found: {}", request.method),
-                data: None,
-            },
-        ),
-    };
+/// MCP protocol revision implemented
+pub const PROTOCOL_VERSION: &str = "2024-11-05";
 
-    Json(response)
+/// Responses buffered per SSE client before requests are rejected
+const SSE_CHANNEL_CAPACITY: usize = 32;
+
+/// Transport-independent MCP request handler
+#[derive(Clone)]
+pub struct McpServer {
+    tools: Arc<dyn ToolExecutor>,
 }
 
-/// Handle tools/list request
-fn handle_tools_list(state: &AppState, request: &JsonRpcRequest) -> JsonRpcResponse {
-    let tools = state.tools.list_tools();
+impl McpServer {
+    pub fn new(tools: Arc<dyn ToolExecutor>) -> Self {
+        Self { tools }
+    }
+
+    /// Handle one request; notifications get no response
+    pub async fn handle(&self, request: JsonRpcRequest) -> Option<JsonRpcResponse> {
+        // Validate JSON-RPC version
+        if request.jsonrpc != "2.0" {
+            return Some(JsonRpcResponse::error(
+                request.id,
+                JsonRpcError {
+                    code: -32600,
+                    message: "Invalid Request: jsonrpc must be \"2.0\"".to_string(),
+                    data: None,
+                },
+            ));
+        }
+
+        if request.is_notification() {
+            tracing::debug!(method = %request.method, "MCP notification");
+            return None;
+        }
+
+        let response = match request.method.as_str() {
+            methods::INITIALIZE => self.handle_initialize(&request),
+            methods::PING => JsonRpcResponse::success(response_id(&request), serde_json::json!({})),
+            methods::TOOLS_LIST => self.handle_tools_list(&request),
+            methods::TOOLS_CALL => self.handle_tools_call(&request).await,
+            _ => JsonRpcResponse::error(
+                request.id.clone(),
+                JsonRpcError {
+                    code: -32601,
+                    message: format!("Method not found: {}", request.method),
+                    data: None,
+                },
+            ),
+        };
+
+        Some(response)
+    }
+
+    /// Handle one raw JSON-RPC message (stdio and other text transports)
+    pub async fn handle_message(&self, message: &str) -> Option<JsonRpcResponse> {
+        match serde_json::from_str::<JsonRpcRequest>(message) {
+            Ok(request) => self.handle(request).await,
+            Err(e) => Some(JsonRpcResponse::error(
+                None,
+                JsonRpcError {
+                    code: -32700,
+                    message: format!("Parse error: {}", e),
+                    data: None,
+                },
+            )),
+        }
+    }
 
-    let tool_schemas: Vec<serde_json::Value> = tools
-        .into_iter()
-        .map(|tool| {
+    /// Handle initialize request
+    fn handle_initialize(&self, request: &JsonRpcRequest) -> JsonRpcResponse {
+        JsonRpcResponse::success(
+            response_id(request),
             serde_json::json!({
-                "name": tool.name,
-                "description": tool.description,
-                "inputSchema": tool.input_schema
+                "protocolVersion": PROTOCOL_VERSION,
+                "capabilities": ServerCapabilities::with_tools(),
+                "serverInfo": {
+                    "name": "voice-agent",
+                    "version": env!("CARGO_PKG_VERSION")
+                }
+            }),
+        )
+    }
+
+    /// Handle tools/list request
+    fn handle_tools_list(&self, request: &JsonRpcRequest) -> JsonRpcResponse {
+        let tools = self.tools.list_tools();
+
+        let tool_schemas: Vec<serde_json::Value> = tools
+            .into_iter()
+            .map(|tool| {
+                serde_json::json!({
+                    "name": tool.name,
+                    "description": tool.description,
+                    "inputSchema": tool.input_schema
+                })
             })
-        })
-        .collect();
-
-    JsonRpcResponse::success(
-        request
-            .id
-            .clone()
-            .unwrap_or(voice_agent_tools::mcp::RequestId::Number(0)),
-        serde_json::json!({
-            "tools": tool_schemas
-        }),
-    )
-}
+            .collect();
 
-/// Handle tools/call request
-async fn handle_tools_call(state: &AppState, request: &JsonRpcRequest) -> JsonRpcResponse {
-    // Parse call params
-    let params: ToolCallParams = match &request.params {
-        Some(p) => match serde_json::from_value(p.clone()) {
-            Ok(params) => params,
-            Err(e) => {
+        JsonRpcResponse::success(
+            response_id(request),
+            serde_json::json!({
+                "tools": tool_schemas
+            }),
+        )
+    }
+
+    /// Handle tools/call request
+    async fn handle_tools_call(&self, request: &JsonRpcRequest) -> JsonRpcResponse {
+        // Parse call params
+        let params: ToolCallParams = match &request.params {
+            Some(p) => match serde_json::from_value(p.clone()) {
+                Ok(params) => params,
+                Err(e) => {
+                    return JsonRpcResponse::error(
+                        request.id.clone(),
+                        JsonRpcError {
+                            code: -32602,
+                            message: format!("Invalid params: {}", e),
+                            data: None,
+                        },
+                    );
+                },
+            },
+            None => {
                 return JsonRpcResponse::error(
                     request.id.clone(),
                     JsonRpcError {
                         code: -32602,
-                        message: format!("Invalid params: {}", e),
+                        message: "Missing params for tools/call".to_string(),
                         data: None,
                     },
                 );
             },
+        };
+
+        // Execute the tool
+        match self.tools.execute(&params.name, params.arguments).await {
+            Ok(output) => {
+                // Convert ToolOutput to MCP response format
+                let content: Vec<serde_json::Value> =
+                    output.content.into_iter().map(content_to_json).collect();
+
+                JsonRpcResponse::success(
+                    response_id(request),
+                    serde_json::json!({
+                        "content": content,
+                        "isError": output.is_error
+                    }),
+                )
+            },
+            Err(tool_error) => JsonRpcResponse::from_tool_error(request.id.clone(), tool_error),
+        }
+    }
+}
+
+fn response_id(request: &JsonRpcRequest) -> RequestId {
+    request.id.clone().unwrap_or(RequestId::Number(0))
+}
+
+/// Convert a content block to MCP wire format
+fn content_to_json(block: ContentBlock) -> serde_json::Value {
+    match block {
+        ContentBlock::Text { text } => {
+            serde_json::json!({
+                "type": "text",
+                "text": text
+            })
         },
-        None => {
-            return JsonRpcResponse::error(
-                request.id.clone(),
-                JsonRpcError {
-                    code: -32602,
-                    message: "Missing params for tools/call".to_string(),
-                    data: None,
-                },
-            );
+        ContentBlock::Image { data, mime_type } => {
+            serde_json::json!({
+                "type": "image",
+                "data": data,
+                "mimeType": mime_type
+            })
+        },
+        ContentBlock::Resource { uri, mime_type } => {
+            serde_json::json!({
+                "type": "resource",
+                "resource": {
+                    "uri": uri,
+                    "mimeType": mime_type
+                }
+            })
         },
+        ContentBlock::Audio {
+            data,
+            mime_type,
+            sample_rate,
+            duration_ms,
+        } => {
+            serde_json::json!({
+                "type": "audio",
+                "data": data,
+                "mimeType": mime_type,
+                "sampleRate": sample_rate,
+                "durationMs": duration_ms
+            })
+        },
+    }
+}
+
+/// MCP JSON-RPC endpoint handler
+///
+/// POST /mcp
+///
+/// Handles MCP protocol requests:
+/// - initialize / ping
+/// - tools/list: List available tools with schemas
+/// - tools/call: Execute a tool with arguments
+pub async fn handle_mcp_request(
+    State(state): State<AppState>,
+    Json(request): Json<JsonRpcRequest>,
+) -> Result<Json<JsonRpcResponse>, StatusCode> {
+    McpServer::new(state.tools.clone())
+        .handle(request)
+        .await
+        .map(Json)
+        .ok_or(StatusCode::ACCEPTED)
+}
+
+/// Open SSE clients, keyed by session ID
+#[derive(Default)]
+pub struct McpSseSessions {
+    senders: RwLock<HashMap<String, mpsc::Sender<JsonRpcResponse>>>,
+}
+
+impl McpSseSessions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of connected SSE clients
+    pub fn len(&self) -> usize {
+        self.senders.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.senders.read().is_empty()
+    }
+}
+
+/// Removes an SSE session when its stream is dropped (client disconnected)
+struct SseSessionGuard {
+    id: String,
+    sessions: Arc<McpSseSessions>,
+}
+
+impl Drop for SseSessionGuard {
+    fn drop(&mut self) {
+        self.sessions.senders.write().remove(&self.id);
+        tracing::debug!(session_id = %self.id, "MCP SSE client disconnected");
+    }
+}
+
+/// MCP SSE stream
+///
+/// GET /mcp/sse
+pub async fn handle_mcp_sse(
+    Extension(sessions): Extension<Arc<McpSseSessions>>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let id = uuid::Uuid::new_v4().to_string();
+    let (tx, rx) = mpsc::channel(SSE_CHANNEL_CAPACITY);
+    sessions.senders.write().insert(id.clone(), tx);
+    tracing::debug!(session_id = %id, "MCP SSE client connected");
+
+    let endpoint = Event::default()
+        .event("endpoint")
+        .data(format!("/mcp/messages?sessionId={}", id));
+    let guard = SseSessionGuard { id, sessions };
+
+    let responses = stream::unfold((rx, guard), |(mut rx, guard)| async move {
+        let response = rx.recv().await?;
+        let event = Event::default()
+            .event("message")
+            .json_data(&response)
+            .unwrap_or_else(|e| Event::default().event("error").data(e.to_string()));
+        Some((Ok::<_, Infallible>(event), (rx, guard)))
+    });
+
+    Sse::new(stream::once(async { Ok::<_, Infallible>(endpoint) }).chain(responses))
+        .keep_alive(KeepAlive::default())
+}
+
+/// SSE message query
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SseMessageQuery {
+    session_id: String,
+}
+
+/// MCP request for an SSE client; the response is sent on its stream
+///
+/// POST /mcp/messages?sessionId=...
+pub async fn handle_mcp_sse_message(
+    State(state): State<AppState>,
+    Extension(sessions): Extension<Arc<McpSseSessions>>,
+    Query(query): Query<SseMessageQuery>,
+    Json(request): Json<JsonRpcRequest>,
+) -> StatusCode {
+    let Some(tx) = sessions.senders.read().get(&query.session_id).cloned() else {
+        return StatusCode::NOT_FOUND;
     };
 
-    // Execute the tool
-    match state.tools.execute(&params.name, params.arguments).await {
-        Ok(output) => {
-            // Convert ToolOutput to MCP response format
-            let content: Vec<serde_json::Value> = output
-                .content
-                .into_iter()
-                .map(|block| match block {
-                    voice_agent_tools::mcp::ContentBlock::Text { text } => {
-                        serde_json::json!({
-                            "type": "text",
-                            "text": text
-                        })
-                    },
-                    voice_agent_tools::mcp::ContentBlock::Image { data, mime_type } => {
-                        serde_json::json!({
-                            "type": "image",
-                            "data": data,
-                            "mimeType": mime_type
-                        })
-                    },
-                    voice_agent_tools::mcp::ContentBlock::Resource { uri, mime_type } => {
-                        serde_json::json!({
-                            "type": "resource",
-                            "resource": {
-                                "uri": uri,
-                                "mimeType": mime_type
-                            }
-                        })
-                    },
-                    voice_agent_tools::mcp::ContentBlock::Audio {
-                        data,
-                        mime_type,
-                        sample_rate,
-                        duration_ms,
-                    } => {
-                        serde_json::json!({
-                            "type": "audio",
-                            "data": data,
-                            "mimeType": mime_type,
-                            "sampleRate": sample_rate,
-                            "durationMs": duration_ms
-                        })
-                    },
-                })
-                .collect();
+    let server = McpServer::new(state.tools.clone());
+    tokio::spawn(async move {
+        if let Some(response) = server.handle(request).await {
+            if tx.send(response).await.is_err() {
+                tracing::debug!("MCP SSE client gone before response was sent");
+            }
+        }
+    });
 
-            JsonRpcResponse::success(
-                request
-                    .id
-                    .clone()
-                    .unwrap_or(voice_agent_tools::mcp::RequestId::Number(0)),
-                serde_json::json!({
-                    "content": content,
-                    "isError": output.is_error
-                }),
-            )
-        },
-        Err(tool_error) => JsonRpcResponse::from_tool_error(request.id.clone(), tool_error),
+    StatusCode::ACCEPTED
+}
+
+/// Serve MCP over newline-delimited JSON-RPC until `reader` closes
+pub async fn serve_lines<R, W>(server: &McpServer, reader: R, mut writer: W) -> std::io::Result<()>
+where
+    R: AsyncBufRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let mut lines = reader.lines();
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = server.handle_message(&line).await {
+            let mut out = serde_json::to_vec(&response).map_err(std::io::Error::other)?;
+            out.push(b'\n');
+            writer.write_all(&out).await?;
+            writer.flush().await?;
+        }
     }
+    Ok(())
+}
+
+/// Serve MCP over stdin/stdout
+///
+/// Logs must go to stderr in this mode; stdout carries protocol messages only.
+pub async fn serve_stdio(server: McpServer) -> std::io::Result<()> {
+    tracing::info!("Serving MCP over stdio");
+    let stdin = tokio::io::BufReader::new(tokio::io::stdin());
+    serve_lines(&server, stdin, tokio::io::stdout()).await
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use async_trait::async_trait;
+    use voice_agent_tools::mcp::{InputSchema, ToolError, ToolOutput, ToolSchema};
+
+    /// Single tool that echoes its arguments
+    struct EchoTools;
+
+    #[async_trait]
+    impl ToolExecutor for EchoTools {
+        async fn execute(
+            &self,
+            name: &str,
+            arguments: serde_json::Value,
+        ) -> Result<ToolOutput, ToolError> {
+            match name {
+                "echo" => Ok(ToolOutput::text(arguments.to_string())),
+                _ => Err(ToolError::not_found(format!("Tool not found: {}", name))),
+            }
+        }
+
+        fn list_tools(&self) -> Vec<ToolSchema> {
+            vec![ToolSchema {
+                name: "echo".to_string(),
+                description: "Echo arguments".to_string(),
+                input_schema: InputSchema::object(),
+            }]
+        }
+
+        fn get_tool(&self, name: &str) -> Option<ToolSchema> {
+            self.list_tools().into_iter().find(|t| t.name == name)
+        }
+    }
 
     #[test]
     fn test_json_rpc_request_parsing() {
@@ -197,4 +440,38 @@ mod tests {
         let params: ToolCallParams = serde_json::from_str(json).unwrap();
         assert_eq!(params.name, "calculate_loan_eligibility");
     }
+
+    #[tokio::test]
+    async fn test_stdio_session() {
+        let server = McpServer::new(Arc::new(EchoTools));
+        let input = [
+            r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}"#,
+            r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#,
+            r#"{"jsonrpc":"2.0","id":2,"method":"tools/list"}"#,
+            r#"{"jsonrpc":"2.0","id":3,"method":"tools/call","params":{"name":"echo","arguments":{"grams":40}}}"#,
+            "not json",
+        ]
+        .join("\n");
+
+        let mut output = Vec::new();
+        serve_lines(&server, input.as_bytes(), &mut output)
+            .await
+            .unwrap();
+
+        let responses: Vec<serde_json::Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        // The notification gets no response
+        assert_eq!(responses.len(), 4);
+        assert_eq!(responses[0]["result"]["protocolVersion"], PROTOCOL_VERSION);
+        assert_eq!(responses[1]["result"]["tools"][0]["name"], "echo");
+        assert_eq!(
+            responses[2]["result"]["content"][0]["text"],
+            r#"{"grams":40}"#
+        );
+        assert_eq!(responses[3]["error"]["code"], -32700);
+    }
 }
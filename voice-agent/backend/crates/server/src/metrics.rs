@@ -10,6 +10,11 @@ use axum::{
 use metrics::{counter, gauge, histogram};
 use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
 use std::sync::OnceLock;
+use voice_agent_agent::ResponseCacheStats;
+use voice_agent_llm::BackendStats;
+use voice_agent_pipeline::WorkerPoolStats;
+
+use crate::admission::AdmissionSnapshot;
 
 /// Global Prometheus handle
 static METRICS_HANDLE: OnceLock<PrometheusHandle> = OnceLock::new();
@@ -49,6 +54,7 @@ fn register_default_metrics() {
     histogram!("voice_agent_stt_duration_seconds").record(0.0);
     histogram!("voice_agent_llm_duration_seconds").record(0.0);
     histogram!("voice_agent_tts_duration_seconds").record(0.0);
+    histogram!("voice_agent_denoise_duration_seconds").record(0.0);
     histogram!("voice_agent_total_latency_seconds").record(0.0);
 
     // Error metrics
@@ -88,6 +94,11 @@ pub fn record_tts_latency(duration_secs: f64) {
     histogram!("voice_agent_tts_duration_seconds").record(duration_secs);
 }
 
+/// Record a session's average per-frame noise suppression latency
+pub fn record_denoise_latency(duration_secs: f64) {
+    histogram!("voice_agent_denoise_duration_seconds").record(duration_secs);
+}
+
 /// Record total pipeline latency
 pub fn record_total_latency(duration_secs: f64) {
     histogram!("voice_agent_total_latency_seconds").record(duration_secs);
@@ -98,6 +109,90 @@ pub fn record_error(error_type: &'static str) {
     counter!("voice_agent_errors_total", "type" => error_type).increment(1);
 }
 
+/// Record a domain event published on the event bus
+pub fn record_domain_event(event: &'static str) {
+    counter!("voice_agent_domain_events_total", "event" => event).increment(1);
+}
+
+/// Record an event sink outcome (published, failed, dropped)
+pub fn record_event_sink(outcome: &'static str) {
+    counter!("voice_agent_event_sink_total", "outcome" => outcome).increment(1);
+}
+
+/// Record a webhook delivery outcome (delivered, failed, dropped)
+pub fn record_webhook(outcome: &'static str) {
+    counter!("voice_agent_webhooks_total", "outcome" => outcome).increment(1);
+}
+
+/// Record an inbound SMS reply outcome (updated, no_appointment, unrecognized, ...)
+pub fn record_sms_reply(outcome: &'static str) {
+    counter!("voice_agent_sms_replies_total", "outcome" => outcome).increment(1);
+}
+
+/// Record a new session refused by admission control
+pub fn record_admission_rejected(reason: &'static str) {
+    counter!("voice_agent_admission_rejected_total", "reason" => reason).increment(1);
+}
+
+/// Record a new session waiting for capacity
+pub fn record_admission_queued() {
+    counter!("voice_agent_admission_queued_total").increment(1);
+}
+
+/// Record model queue depths, waiting sessions and host utilization
+pub fn record_admission_snapshot(snapshot: &AdmissionSnapshot) {
+    gauge!("voice_agent_admission_waiting").set(snapshot.waiting as f64);
+    for (&component, &depth) in &snapshot.queue_depth {
+        gauge!("voice_agent_model_queue_depth", "component" => component).set(depth as f64);
+    }
+    if let Some(cpu) = snapshot.utilization.cpu_percent {
+        gauge!("voice_agent_cpu_utilization_percent").set(cpu as f64);
+    }
+    if let Some(gpu) = snapshot.utilization.gpu_percent {
+        gauge!("voice_agent_gpu_utilization_percent").set(gpu as f64);
+    }
+}
+
+/// Record shared model worker pool throughput and batching
+pub fn record_worker_pool_stats(component: &'static str, stats: &WorkerPoolStats) {
+    counter!("voice_agent_worker_requests_total", "component" => component)
+        .absolute(stats.submitted);
+    counter!("voice_agent_worker_failures_total", "component" => component).absolute(stats.failed);
+    counter!("voice_agent_worker_rejected_total", "component" => component)
+        .absolute(stats.rejected);
+    counter!("voice_agent_worker_batches_total", "component" => component).absolute(stats.batches);
+    counter!("voice_agent_worker_slo_misses_total", "component" => component)
+        .absolute(stats.slo_misses);
+    gauge!("voice_agent_worker_queued", "component" => component).set(stats.queued as f64);
+    gauge!("voice_agent_worker_avg_batch_size", "component" => component).set(stats.avg_batch_size);
+}
+
+/// Record per-backend LLM router health and usage
+pub fn record_llm_backend_stats(stats: &[BackendStats]) {
+    for backend in stats {
+        let name = backend.name.clone();
+        gauge!("voice_agent_llm_backend_healthy", "backend" => name.clone())
+            .set(if backend.healthy { 1.0 } else { 0.0 });
+        counter!("voice_agent_llm_backend_requests_total", "backend" => name.clone())
+            .absolute(backend.requests);
+        counter!("voice_agent_llm_backend_failures_total", "backend" => name.clone())
+            .absolute(backend.failures);
+        gauge!("voice_agent_llm_backend_avg_latency_ms", "backend" => name.clone())
+            .set(backend.avg_latency_ms);
+        counter!("voice_agent_llm_backend_tokens_total", "backend" => name.clone())
+            .absolute(backend.tokens);
+        gauge!("voice_agent_llm_backend_cost_total", "backend" => name).set(backend.estimated_cost);
+    }
+}
+
+/// Record response cache hit/miss counters and size
+pub fn record_response_cache_stats(stats: &ResponseCacheStats) {
+    counter!("voice_agent_response_cache_hits_total").absolute(stats.hits);
+    counter!("voice_agent_response_cache_misses_total").absolute(stats.misses);
+    counter!("voice_agent_response_cache_tool_hits_total").absolute(stats.tool_hits);
+    gauge!("voice_agent_response_cache_entries").set(stats.responses as f64);
+}
+
 use crate::state::AppState;
 
 /// Metrics endpoint handler
@@ -107,6 +202,20 @@ pub async fn metrics_handler(State(state): State<AppState>) -> impl IntoResponse
     // Update active sessions gauge
     let session_count = state.sessions.count();
     record_active_sessions(session_count);
+    record_admission_snapshot(&state.admission.snapshot(session_count));
+    let models = state.models.active();
+    if let Some(ref pool) = models.models.stt_pool {
+        record_worker_pool_stats("stt", &pool.stats());
+    }
+    if let Some(ref pool) = models.models.tts_pool {
+        record_worker_pool_stats("tts", &pool.stats());
+    }
+    if let Some(ref router) = models.models.llm_router {
+        record_llm_backend_stats(&router.stats());
+    }
+    if let Some(ref cache) = state.response_cache {
+        record_response_cache_stats(&cache.stats());
+    }
 
     match get_metrics_handle() {
         Some(handle) => {
@@ -0,0 +1,347 @@
+//! Model Artifact Registry
+//!
+//! Model files are referenced by raw paths, so nothing guarantees the bytes
+//! on disk are the version that was tested. The registry manifest
+//! (`model_registry.manifest_path`) pins every artifact:
+//!
+//! ```yaml
+//! models:
+//!   silero_vad:
+//!     version: "v5.1"
+//!     path: models/vad/silero_vad.onnx
+//!     source: https://github.com/snakers4/silero-vad/raw/v5.1/src/silero_vad/data/silero_vad.onnx
+//!     sha256: "…"
+//! ```
+//!
+//! At startup and before a model reload every artifact is checked against
+//! its SHA-256. With `download_missing` set, artifacts missing on disk are
+//! fetched from their source first; downloads go to a `.part` file that is
+//! moved into place only once its checksum matches. `s3://bucket/key`
+//! sources are fetched from the bucket's HTTPS endpoint, so the object must
+//! be readable without credentials (use a presigned URL otherwise).
+//!
+//! The verified versions are recorded with each session, so a call can be
+//! reproduced against the exact models that served it.
+
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::Duration;
+
+use futures::StreamExt;
+use tokio::io::AsyncWriteExt;
+use voice_agent_config::ModelRegistryConfig;
+
+/// One pinned model artifact
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelArtifact {
+    pub version: String,
+    /// Local path the model is loaded from
+    pub path: String,
+    /// Download source (HTTP(S) URL or `s3://bucket/key`)
+    #[serde(default)]
+    pub source: Option<String>,
+    /// Expected SHA-256 (hex)
+    pub sha256: String,
+}
+
+/// Model name -> pinned artifact
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModelManifest {
+    #[serde(default)]
+    pub models: BTreeMap<String, ModelArtifact>,
+}
+
+impl ModelManifest {
+    pub fn from_file(path: &str) -> Result<Self, RegistryError> {
+        let manifest_error = |message: String| RegistryError::Manifest {
+            path: path.to_string(),
+            message,
+        };
+        let contents = std::fs::read_to_string(path).map_err(|e| manifest_error(e.to_string()))?;
+        serde_yaml::from_str(&contents).map_err(|e| manifest_error(e.to_string()))
+    }
+}
+
+/// Why an artifact could not be verified
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum RegistryError {
+    #[error("model manifest {path}: {message}")]
+    Manifest { path: String, message: String },
+    #[error("{name}: {path} is missing")]
+    Missing { name: String, path: String },
+    #[error("{name}: checksum mismatch for {path} (expected {expected}, got {actual})")]
+    Checksum {
+        name: String,
+        path: String,
+        expected: String,
+        actual: String,
+    },
+    #[error("{name}: download failed: {message}")]
+    Download { name: String, message: String },
+    #[error("{name}: {message}")]
+    Io { name: String, message: String },
+}
+
+/// Verifies (and fetches) the artifacts listed in the model manifest
+pub struct ModelRegistry {
+    config: ModelRegistryConfig,
+    client: reqwest::Client,
+    manifest: RwLock<ModelManifest>,
+    /// Model name -> version, as of the last successful `prepare`
+    verified: RwLock<BTreeMap<String, String>>,
+}
+
+impl ModelRegistry {
+    /// Read the manifest; call `prepare` before loading models
+    pub fn new(config: ModelRegistryConfig) -> Result<Self, RegistryError> {
+        let manifest = ModelManifest::from_file(&config.manifest_path)?;
+        Ok(Self {
+            config,
+            client: reqwest::Client::new(),
+            manifest: RwLock::new(manifest),
+            verified: RwLock::new(BTreeMap::new()),
+        })
+    }
+
+    /// Download missing artifacts (if enabled) and verify every checksum
+    ///
+    /// Returns the verified model versions, or every artifact that failed.
+    pub async fn prepare(&self) -> Result<BTreeMap<String, String>, Vec<RegistryError>> {
+        let manifest = self.manifest.read().clone();
+        let mut failures = Vec::new();
+        for (name, artifact) in &manifest.models {
+            if let Err(e) = self.prepare_artifact(name, artifact).await {
+                tracing::error!(
+                    model = %name,
+                    version = %artifact.version,
+                    error = %e,
+                    "Model artifact rejected"
+                );
+                failures.push(e);
+            }
+        }
+        if !failures.is_empty() {
+            return Err(failures);
+        }
+
+        let versions: BTreeMap<String, String> = manifest
+            .models
+            .into_iter()
+            .map(|(name, artifact)| (name, artifact.version))
+            .collect();
+        tracing::info!(models = ?versions, "Model artifacts verified");
+        *self.verified.write() = versions.clone();
+        Ok(versions)
+    }
+
+    /// Re-read the manifest and prepare its artifacts, e.g. before a model
+    /// reload after a version was bumped
+    pub async fn refresh(&self) -> Result<BTreeMap<String, String>, String> {
+        let manifest =
+            ModelManifest::from_file(&self.config.manifest_path).map_err(|e| e.to_string())?;
+        *self.manifest.write() = manifest;
+        self.prepare()
+            .await
+            .map_err(|failures| describe_failures(&failures))
+    }
+
+    /// Model versions verified by the last successful `prepare`
+    pub fn versions(&self) -> BTreeMap<String, String> {
+        self.verified.read().clone()
+    }
+
+    async fn prepare_artifact(
+        &self,
+        name: &str,
+        artifact: &ModelArtifact,
+    ) -> Result<(), RegistryError> {
+        if !Path::new(&artifact.path).exists() {
+            return match artifact.source {
+                Some(ref source) if self.config.download_missing => {
+                    self.download(name, artifact, source).await
+                },
+                _ => Err(RegistryError::Missing {
+                    name: name.to_string(),
+                    path: artifact.path.clone(),
+                }),
+            };
+        }
+
+        let path = artifact.path.clone();
+        let actual = tokio::task::spawn_blocking(move || sha256_file(Path::new(&path)))
+            .await
+            .map_err(|e| e.to_string())
+            .and_then(|hashed| hashed.map_err(|e| e.to_string()))
+            .map_err(|message| RegistryError::Io {
+                name: name.to_string(),
+                message,
+            })?;
+        check_sha256(name, artifact, actual)
+    }
+
+    /// Fetch an artifact, moving it into place only if its checksum matches
+    async fn download(
+        &self,
+        name: &str,
+        artifact: &ModelArtifact,
+        source: &str,
+    ) -> Result<(), RegistryError> {
+        let failed = |message: String| RegistryError::Download {
+            name: name.to_string(),
+            message,
+        };
+        let url = source_url(source);
+        tracing::info!(
+            model = %name,
+            version = %artifact.version,
+            url = %url,
+            "Downloading model artifact"
+        );
+
+        let path = Path::new(&artifact.path);
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir)
+                .await
+                .map_err(|e| failed(e.to_string()))?;
+        }
+        let response = self
+            .client
+            .get(&url)
+            .timeout(Duration::from_secs(self.config.download_timeout_secs))
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| failed(e.to_string()))?;
+
+        let partial = format!("{}.part", artifact.path);
+        let mut file = tokio::fs::File::create(&partial)
+            .await
+            .map_err(|e| failed(e.to_string()))?;
+        let mut hasher = Sha256::new();
+        let mut body = response.bytes_stream();
+        while let Some(chunk) = body.next().await {
+            let chunk = chunk.map_err(|e| failed(e.to_string()))?;
+            hasher.update(&chunk);
+            file.write_all(&chunk)
+                .await
+                .map_err(|e| failed(e.to_string()))?;
+        }
+        file.flush().await.map_err(|e| failed(e.to_string()))?;
+        drop(file);
+
+        if let Err(e) = check_sha256(name, artifact, format!("{:x}", hasher.finalize())) {
+            let _ = tokio::fs::remove_file(&partial).await;
+            return Err(e);
+        }
+        tokio::fs::rename(&partial, path)
+            .await
+            .map_err(|e| failed(e.to_string()))?;
+        tracing::info!(model = %name, path = %artifact.path, "Model artifact downloaded");
+        Ok(())
+    }
+}
+
+fn check_sha256(name: &str, artifact: &ModelArtifact, actual: String) -> Result<(), RegistryError> {
+    if actual.eq_ignore_ascii_case(artifact.sha256.trim()) {
+        return Ok(());
+    }
+    Err(RegistryError::Checksum {
+        name: name.to_string(),
+        path: artifact.path.clone(),
+        expected: artifact.sha256.clone(),
+        actual,
+    })
+}
+
+/// One line listing every rejected artifact
+pub fn describe_failures(failures: &[RegistryError]) -> String {
+    failures
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// SHA-256 of a file (hex)
+pub fn sha256_file(path: &Path) -> std::io::Result<String> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// HTTP(S) URL for a manifest source
+fn source_url(source: &str) -> String {
+    match source
+        .strip_prefix("s3://")
+        .and_then(|rest| rest.split_once('/'))
+    {
+        Some((bucket, key)) => format!("https://{}.s3.amazonaws.com/{}", bucket, key),
+        None => source.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry_for(artifacts: &[(&str, &str, &str)]) -> ModelRegistry {
+        let dir = std::env::temp_dir().join(format!("model-registry-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut manifest = ModelManifest::default();
+        for (name, contents, sha256) in artifacts {
+            let path = dir.join(format!("{}.onnx", name));
+            std::fs::write(&path, contents).unwrap();
+            manifest.models.insert(
+                name.to_string(),
+                ModelArtifact {
+                    version: "v1".to_string(),
+                    path: path.to_string_lossy().into_owned(),
+                    source: None,
+                    sha256: sha256.to_string(),
+                },
+            );
+        }
+        let manifest_path = dir.join("models.yaml");
+        std::fs::write(&manifest_path, serde_yaml::to_string(&manifest).unwrap()).unwrap();
+
+        ModelRegistry::new(ModelRegistryConfig {
+            enabled: true,
+            manifest_path: manifest_path.to_string_lossy().into_owned(),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    // SHA-256 of "abc"
+    const ABC_SHA256: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+    #[tokio::test]
+    async fn test_prepare_verifies_checksums() {
+        let registry = registry_for(&[("vad", "abc", ABC_SHA256)]);
+        let versions = registry.prepare().await.unwrap();
+        assert_eq!(versions.get("vad").map(String::as_str), Some("v1"));
+        assert_eq!(registry.versions(), versions);
+
+        let registry = registry_for(&[("vad", "abc", ABC_SHA256), ("stt", "tampered", ABC_SHA256)]);
+        let failures = registry.prepare().await.unwrap_err();
+        assert_eq!(failures.len(), 1);
+        assert!(matches!(&failures[0], RegistryError::Checksum { name, .. } if name == "stt"));
+        assert!(registry.versions().is_empty());
+    }
+
+    #[test]
+    fn test_s3_source_url() {
+        assert_eq!(
+            source_url("s3://voice-models/stt/indicconformer-v2.onnx"),
+            "https://voice-models.s3.amazonaws.com/stt/indicconformer-v2.onnx"
+        );
+        assert_eq!(
+            source_url("https://example.com/model.onnx"),
+            "https://example.com/model.onnx"
+        );
+    }
+}
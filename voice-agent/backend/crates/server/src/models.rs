@@ -0,0 +1,398 @@
+//! Blue/Green Model Reload
+//!
+//! The shared STT/TTS worker pools and the LLM router are grouped into a
+//! model generation. Reloading loads a new generation alongside the one in
+//! service; once it is ready it becomes active and new sessions are pinned
+//! to it, while sessions already in a call keep the generation they started
+//! on. The old generation drains and is unloaded when its last session ends.
+//!
+//! Only one reload runs at a time. A reload that fails to load leaves the
+//! active generation in service and is reported in the status.
+
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use voice_agent_config::Settings;
+use voice_agent_llm::{LlmFactory, LlmRouter};
+use voice_agent_pipeline::{
+    PipelineConfig, SttBatchEngine, TtsBatchEngine, VoicePipeline, WorkerPool,
+};
+
+pub type SttPool = Arc<WorkerPool<SttBatchEngine>>;
+pub type TtsPool = Arc<WorkerPool<TtsBatchEngine>>;
+
+/// A model that can be reloaded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ModelComponent {
+    Stt,
+    Tts,
+    Llm,
+}
+
+impl ModelComponent {
+    pub const ALL: [ModelComponent; 3] = [Self::Stt, Self::Tts, Self::Llm];
+}
+
+/// Shared models sessions are wired to (None = per-session model)
+#[derive(Clone, Default)]
+pub struct ModelSet {
+    pub stt_pool: Option<SttPool>,
+    pub tts_pool: Option<TtsPool>,
+    pub llm_router: Option<Arc<LlmRouter>>,
+    /// Model artifact versions verified by the registry (empty = unversioned)
+    pub versions: BTreeMap<String, String>,
+}
+
+impl ModelSet {
+    /// Load `components` from the current settings, keeping `base`'s models
+    /// for the rest
+    ///
+    /// Loads model files, so call it off the async runtime.
+    pub fn load(
+        config: &Settings,
+        components: &[ModelComponent],
+        base: &ModelSet,
+    ) -> Result<ModelSet, String> {
+        let mut models = base.clone();
+        for component in components {
+            match component {
+                ModelComponent::Stt => models.stt_pool = load_stt_pool(config)?,
+                ModelComponent::Tts => models.tts_pool = load_tts_pool(config)?,
+                ModelComponent::Llm => models.llm_router = load_llm_router(config)?,
+            }
+        }
+        Ok(models)
+    }
+}
+
+/// Load the shared STT pool if `pipeline.workers.stt` enables it
+pub fn load_stt_pool(config: &Settings) -> Result<Option<SttPool>, String> {
+    let workers = &config.pipeline.workers;
+    if !workers.stt.enabled {
+        return Ok(None);
+    }
+
+    let engine = load_stt_engine(workers.stt.workers)?;
+    tracing::info!(
+        instances = engine.instances(),
+        max_batch_size = workers.stt.max_batch_size,
+        "Shared STT worker pool initialized"
+    );
+    Ok(Some(WorkerPool::new(Arc::new(engine), workers.stt.clone())))
+}
+
+/// Load the shared TTS pool if `pipeline.workers.tts` enables it
+pub fn load_tts_pool(config: &Settings) -> Result<Option<TtsPool>, String> {
+    let workers = &config.pipeline.workers;
+    if !workers.tts.enabled {
+        return Ok(None);
+    }
+
+    let engine = load_tts_engine(config)?;
+    tracing::info!(
+        workers = workers.tts.workers,
+        max_batch_size = workers.tts.max_batch_size,
+        "Shared TTS worker pool initialized"
+    );
+    Ok(Some(WorkerPool::new(Arc::new(engine), workers.tts.clone())))
+}
+
+/// Load `instances` IndicConformer models for batch transcription
+pub fn load_stt_engine(instances: usize) -> Result<SttBatchEngine, String> {
+    if !cfg!(feature = "onnx") {
+        return Err("STT models require the onnx feature".to_string());
+    }
+    SttBatchEngine::indicconformer(
+        crate::degradation::STT_MODEL_DIR,
+        VoicePipeline::indicconformer_config(&PipelineConfig::default().stt),
+        instances,
+    )
+    .map_err(|e| format!("failed to load STT model: {}", e))
+}
+
+/// Load the IndicF5 model at the configured precision
+pub fn load_tts_engine(config: &Settings) -> Result<TtsBatchEngine, String> {
+    let mut fallback = PipelineConfig::default().tts;
+    fallback.quantization = config.pipeline.tts.quantization;
+    let tts_config = VoicePipeline::indicf5_tts_config(&fallback);
+    TtsBatchEngine::from_config(&tts_config).map_err(|e| format!("failed to load TTS model: {}", e))
+}
+
+/// Build the LLM router if `llm_router` enables it and start its health checks
+pub fn load_llm_router(config: &Settings) -> Result<Option<Arc<LlmRouter>>, String> {
+    if !config.llm_router.enabled {
+        return Ok(None);
+    }
+
+    let router = LlmFactory::create_router(&config.llm_router)
+        .map_err(|e| format!("failed to initialize LLM router: {}", e))?;
+    let router = Arc::new(router);
+    router.spawn_health_checks();
+    tracing::info!(backends = ?router.backend_names(), "LLM router initialized");
+    Ok(Some(router))
+}
+
+/// One loaded set of models
+pub struct ModelGeneration {
+    pub id: u64,
+    pub models: ModelSet,
+    /// Components loaded for this generation (the rest are shared with the
+    /// generation it replaced)
+    pub reloaded: Vec<ModelComponent>,
+    pub loaded_at: DateTime<Utc>,
+}
+
+/// Generation status for the admin API
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GenerationStatus {
+    pub id: u64,
+    pub reloaded: Vec<ModelComponent>,
+    pub loaded_at: DateTime<Utc>,
+    /// Sessions pinned to this generation
+    pub sessions: usize,
+    pub stt_pool: bool,
+    pub tts_pool: bool,
+    pub llm_router: bool,
+    pub versions: BTreeMap<String, String>,
+}
+
+/// Model manager status for the admin API
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ModelStatus {
+    pub active: GenerationStatus,
+    /// Replaced generations still serving sessions
+    pub draining: Vec<GenerationStatus>,
+    /// Components of the reload in progress (None = idle)
+    pub reloading: Option<Vec<ModelComponent>>,
+    /// Why the last reload failed (cleared by a successful reload)
+    pub last_error: Option<String>,
+}
+
+struct Generations {
+    active: Arc<ModelGeneration>,
+    draining: Vec<Arc<ModelGeneration>>,
+    /// Session ID -> generation ID
+    pins: HashMap<String, u64>,
+    reloading: Option<Vec<ModelComponent>>,
+    last_error: Option<String>,
+}
+
+impl Generations {
+    fn sessions_on(&self, generation: u64) -> usize {
+        self.pins.values().filter(|&&id| id == generation).count()
+    }
+
+    fn status(&self, generation: &ModelGeneration) -> GenerationStatus {
+        GenerationStatus {
+            id: generation.id,
+            reloaded: generation.reloaded.clone(),
+            loaded_at: generation.loaded_at,
+            sessions: self.sessions_on(generation.id),
+            stt_pool: generation.models.stt_pool.is_some(),
+            tts_pool: generation.models.tts_pool.is_some(),
+            llm_router: generation.models.llm_router.is_some(),
+            versions: generation.models.versions.clone(),
+        }
+    }
+
+    /// Unload draining generations no session is pinned to
+    fn reap(&mut self) {
+        let drained: Vec<u64> = self
+            .draining
+            .iter()
+            .map(|generation| generation.id)
+            .filter(|&id| self.sessions_on(id) == 0)
+            .collect();
+        for id in drained {
+            self.draining.retain(|generation| generation.id != id);
+            tracing::info!(generation = id, "Drained model generation unloaded");
+        }
+    }
+}
+
+/// Active and draining model generations and the sessions pinned to them
+pub struct ModelManager {
+    inner: Mutex<Generations>,
+}
+
+impl Default for ModelManager {
+    fn default() -> Self {
+        Self::new(ModelSet::default())
+    }
+}
+
+impl ModelManager {
+    /// Start with `models` as generation 0
+    pub fn new(models: ModelSet) -> Self {
+        let active = Arc::new(ModelGeneration {
+            id: 0,
+            models,
+            reloaded: Vec::new(),
+            loaded_at: Utc::now(),
+        });
+        Self {
+            inner: Mutex::new(Generations {
+                active,
+                draining: Vec::new(),
+                pins: HashMap::new(),
+                reloading: None,
+                last_error: None,
+            }),
+        }
+    }
+
+    /// Generation new sessions start on
+    pub fn active(&self) -> Arc<ModelGeneration> {
+        self.inner.lock().active.clone()
+    }
+
+    /// Replace the startup models in place, before any session is pinned
+    pub fn set_initial(&self, update: impl FnOnce(&mut ModelSet)) {
+        let mut inner = self.inner.lock();
+        let mut models = inner.active.models.clone();
+        update(&mut models);
+        inner.active = Arc::new(ModelGeneration {
+            id: inner.active.id,
+            models,
+            reloaded: inner.active.reloaded.clone(),
+            loaded_at: inner.active.loaded_at,
+        });
+    }
+
+    /// Generation a session uses, pinning it to the active one on first use
+    ///
+    /// A session keeps its generation for the rest of the call, so a
+    /// reconnect mid-reload does not switch models under it.
+    pub fn pin(&self, session_id: &str) -> Arc<ModelGeneration> {
+        let mut inner = self.inner.lock();
+        if let Some(&id) = inner.pins.get(session_id) {
+            if let Some(generation) = inner.draining.iter().find(|g| g.id == id) {
+                return generation.clone();
+            }
+        }
+        let active = inner.active.clone();
+        inner.pins.insert(session_id.to_string(), active.id);
+        active
+    }
+
+    /// A session ended; unload its generation if that drained it
+    pub fn release(&self, session_id: &str) {
+        let mut inner = self.inner.lock();
+        if inner.pins.remove(session_id).is_some() {
+            inner.reap();
+        }
+    }
+
+    /// Claim the reload slot, failing if a reload is already running
+    pub fn begin_reload(&self, components: &[ModelComponent]) -> Result<(), String> {
+        let mut inner = self.inner.lock();
+        if let Some(ref running) = inner.reloading {
+            return Err(format!("reload of {:?} already in progress", running));
+        }
+        inner.reloading = Some(components.to_vec());
+        Ok(())
+    }
+
+    /// Finish a reload: on success the new models become the active
+    /// generation and the previous one starts draining
+    ///
+    /// Returns the new generation's ID.
+    pub fn finish_reload(&self, loaded: Result<ModelSet, String>) -> Result<u64, String> {
+        let mut inner = self.inner.lock();
+        let components = inner.reloading.take().unwrap_or_default();
+        let models = match loaded {
+            Ok(models) => models,
+            Err(e) => {
+                tracing::error!(components = ?components, error = %e, "Model reload failed");
+                inner.last_error = Some(e.clone());
+                return Err(e);
+            },
+        };
+
+        let generation = Arc::new(ModelGeneration {
+            id: inner.active.id + 1,
+            models,
+            reloaded: components,
+            loaded_at: Utc::now(),
+        });
+        let previous = std::mem::replace(&mut inner.active, generation.clone());
+        tracing::info!(
+            generation = generation.id,
+            previous = previous.id,
+            reloaded = ?generation.reloaded,
+            sessions = inner.sessions_on(previous.id),
+            "Model generation activated, draining previous"
+        );
+        inner.draining.push(previous);
+        inner.last_error = None;
+        inner.reap();
+        Ok(generation.id)
+    }
+
+    pub fn status(&self) -> ModelStatus {
+        let inner = self.inner.lock();
+        ModelStatus {
+            active: inner.status(&inner.active),
+            draining: inner
+                .draining
+                .iter()
+                .map(|generation| inner.status(generation))
+                .collect(),
+            reloading: inner.reloading.clone(),
+            last_error: inner.last_error.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reload(manager: &ModelManager) -> u64 {
+        manager.begin_reload(&[ModelComponent::Llm]).unwrap();
+        manager.finish_reload(Ok(ModelSet::default())).unwrap()
+    }
+
+    #[test]
+    fn test_sessions_keep_generation_until_drained() {
+        let manager = ModelManager::default();
+        assert_eq!(manager.pin("old").id, 0);
+
+        assert_eq!(reload(&manager), 1);
+        assert_eq!(manager.pin("new").id, 1);
+        assert_eq!(manager.pin("old").id, 0);
+
+        let status = manager.status();
+        assert_eq!(status.active.sessions, 1);
+        assert_eq!(status.draining.len(), 1);
+        assert_eq!(status.draining[0].sessions, 1);
+
+        manager.release("old");
+        assert!(manager.status().draining.is_empty());
+
+        // Nothing pinned to generation 1, so it is unloaded as soon as it is replaced
+        manager.release("new");
+        assert_eq!(reload(&manager), 2);
+        assert!(manager.status().draining.is_empty());
+    }
+
+    #[test]
+    fn test_failed_reload_keeps_active_generation() {
+        let manager = ModelManager::default();
+        manager.begin_reload(&ModelComponent::ALL).unwrap();
+        assert!(manager.begin_reload(&[ModelComponent::Stt]).is_err());
+
+        assert!(manager
+            .finish_reload(Err("model file missing".to_string()))
+            .is_err());
+        let status = manager.status();
+        assert_eq!(status.active.id, 0);
+        assert_eq!(status.reloading, None);
+        assert_eq!(status.last_error.as_deref(), Some("model file missing"));
+        assert!(manager.begin_reload(&[ModelComponent::Stt]).is_ok());
+    }
+}
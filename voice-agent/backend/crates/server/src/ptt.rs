@@ -24,6 +24,8 @@ use std::convert::Infallible;
 use once_cell::sync::Lazy;
 use parking_lot::Mutex;
 
+use voice_agent_config::PipelineComponent;
+
 use crate::state::AppState;
 
 // Pre-compiled regex patterns for markdown stripping (compiled once at startup)
@@ -342,6 +344,16 @@ pub async fn handle_ptt(
         request.audio.len()
     );
 
+    // New conversations are subject to admission control
+    if request.session_id.is_none() {
+        if let Err(rejection) = state.admission.admit(|| state.sessions.count()).await {
+            return (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(state.admission.busy_body(&rejection)),
+            );
+        }
+    }
+
     // 1. Decode audio from base64
     let audio_bytes = match BASE64.decode(&request.audio) {
         Ok(bytes) => bytes,
@@ -369,6 +381,7 @@ pub async fn handle_ptt(
     // 3. Initialize STT and run based on language
     let stt_start = std::time::Instant::now();
     let use_english = is_english(&request.language);
+    let stt_job = state.admission.begin(PipelineComponent::Stt);
 
     let stt_text = if use_english {
         // Use faster-whisper HTTP service for English
@@ -430,6 +443,7 @@ pub async fn handle_ptt(
 
         final_result.text
     };
+    drop(stt_job);
     metrics.stt_ms = stt_start.elapsed().as_millis() as u64;
 
     tracing::info!("STT result ({}): '{}'", if use_english { "faster-whisper" } else { "IndicConformer" }, stt_text);
@@ -479,6 +493,7 @@ pub async fn handle_ptt(
 
     // 5. Call LLM via Agent pipeline (with RAG + tools)
     let llm_start = std::time::Instant::now();
+    let llm_job = state.admission.begin(PipelineComponent::Llm);
     let (llm_response, session_id) = match process_with_agent(
         &state,
         text_for_llm,
@@ -493,12 +508,14 @@ pub async fn handle_ptt(
             (format_fallback_response(text_for_llm, &request.language), fallback_sid)
         }
     };
+    drop(llm_job);
     metrics.llm_ms = llm_start.elapsed().as_millis() as u64;
 
     tracing::info!("LLM response: '{}'", llm_response);
 
     // 6. Generate TTS via IndicF5 service
     let tts_start = std::time::Instant::now();
+    let tts_job = state.admission.begin(PipelineComponent::Tts);
     let audio_response = match synthesize_with_tts(&llm_response, &request.language).await {
         Ok((audio_b64, format)) => {
             tracing::info!("TTS generated {} bytes of {} audio", audio_b64.len(), format);
@@ -509,6 +526,7 @@ pub async fn handle_ptt(
             None
         }
     };
+    drop(tts_job);
     let audio_format = if audio_response.is_some() { Some("wav".to_string()) } else { None };
     metrics.tts_ms = tts_start.elapsed().as_millis() as u64;
 
@@ -693,12 +711,17 @@ async fn process_with_agent(
         "Processing PTT request"
     );
 
-    // Process through agent pipeline
-    let response = session
-        .agent
-        .process(user_text)
-        .await
-        .map_err(|e| format!("Agent processing failed: {}", e))?;
+    // Process through agent pipeline; a failed turn is answered with an apology
+    let response = match session.agent.process(user_text).await {
+        Ok(response) => response,
+        Err(e) => {
+            tracing::warn!(session_id = %session_id, error = %e, "PTT turn failed");
+            session
+                .agent
+                .failure_reply(&e)
+                .ok_or_else(|| format!("Agent processing failed: {}", e))?
+        },
+    };
 
     // Don't remove session - keep it for conversation continuity
     // Sessions will be cleaned up by timeout/explicit end
@@ -726,6 +749,16 @@ fn create_new_session(
             state.master_domain_config.clone(),
         )
         .map_err(|e| format!("Failed to create session: {}", e))?;
+    state.attach_archival_memory(&session);
+    state.attach_knowledge_base(&session);
+    state.attach_llm_router(&session);
+    state.attach_response_cache(&session);
+    state.attach_guardrails(&session);
+    state.attach_response_governor(&session);
+    state.attach_abuse_policy(&session);
+    state.attach_event_bus(&session);
+    state.attach_journal(&session);
+    state.attach_model_versions(&session);
 
     tracing::info!(
         session_id = %session.id,
@@ -1236,6 +1269,17 @@ pub async fn handle_ptt_stream(
             request.audio.len()
         );
 
+        // New conversations are subject to admission control
+        if request.session_id.is_none() {
+            if state.admission.admit(|| state.sessions.count()).await.is_err() {
+                let retry_after = state.admission.config().retry_after_secs;
+                send_event(&tx, PttEvent::Error {
+                    message: format!("The service is busy, please retry in {}s", retry_after),
+                });
+                return;
+            }
+        }
+
         // 1. Decode audio from base64
         let audio_bytes = match BASE64.decode(&request.audio) {
             Ok(bytes) => bytes,
@@ -1257,6 +1301,7 @@ pub async fn handle_ptt_stream(
         // 3. STT
         let stt_start = std::time::Instant::now();
         let use_english = is_english(&request.language);
+        let stt_job = state.admission.begin(PipelineComponent::Stt);
 
         let stt_text = if use_english {
             match transcribe_with_whisper(&pcm_f32, &request.language).await {
@@ -1295,6 +1340,7 @@ pub async fn handle_ptt_stream(
 
             final_result.text
         };
+        drop(stt_job);
         metrics.stt_ms = stt_start.elapsed().as_millis() as u64;
 
         if stt_text.is_empty() {
@@ -1344,6 +1390,7 @@ pub async fn handle_ptt_stream(
 
         // 5. LLM processing
         let llm_start = std::time::Instant::now();
+        let llm_job = state.admission.begin(PipelineComponent::Llm);
         let llm_response = match process_with_agent(
             &state,
             text_for_llm,
@@ -1356,6 +1403,7 @@ pub async fn handle_ptt_stream(
                 format_fallback_response(text_for_llm, &request.language)
             }
         };
+        drop(llm_job);
         metrics.llm_ms = llm_start.elapsed().as_millis() as u64;
 
         // Send assistant text
@@ -1365,12 +1413,14 @@ pub async fn handle_ptt_stream(
 
         // 6. TTS
         let tts_start = std::time::Instant::now();
+        let tts_job = state.admission.begin(PipelineComponent::Tts);
         if let Ok((audio_b64, format)) = synthesize_with_tts(&llm_response, &request.language).await {
             send_event(&tx, PttEvent::AudioReady {
                 audio: audio_b64,
                 format,
             });
         }
+        drop(tts_job);
         metrics.tts_ms = tts_start.elapsed().as_millis() as u64;
 
         metrics.total_ms = start.elapsed().as_millis() as u64;
@@ -8,19 +8,21 @@
 //! allowing different backends to be used.
 //!
 //! - `InMemorySessionStore` - Default, uses HashMap
-//! - `ScyllaSessionStore` - Production persistence using ScyllaDB
+//! - `ScyllaSessionStore` - Production persistence using ScyllaDB (or the
+//!   SQLite/Postgres backend when configured)
 //!
 //! P3-1 FIX: Removed deprecated RedisSessionStore stub.
 //! Use ScyllaSessionStore for distributed session persistence.
 
 use async_trait::async_trait;
 use parking_lot::RwLock;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::watch;
+use tokio::sync::{mpsc, watch};
 
 use voice_agent_agent::{AgentConfig, DomainAgent};
+use voice_agent_config::{SessionDomainView, SessionOverrides};
 
 use crate::ServerError;
 
@@ -44,6 +46,18 @@ pub struct SessionMetadata {
     pub turn_count: usize,
     /// Instance ID that owns this session (for affinity)
     pub instance_id: Option<String>,
+    /// Abusive caller turns so far (non-zero = flagged)
+    #[serde(default)]
+    pub abuse_incidents: u32,
+    /// Disposition code assigned when the call ended
+    #[serde(default)]
+    pub disposition: Option<String>,
+    /// Latest quality of the caller's line
+    #[serde(default)]
+    pub audio_quality: Option<voice_agent_core::AudioQuality>,
+    /// Model artifact versions that served the session
+    #[serde(default)]
+    pub model_versions: BTreeMap<String, String>,
 }
 
 /// P2 FIX: Session data for recovery (matches persistence layer)
@@ -111,6 +125,10 @@ impl SessionStore for InMemorySessionStore {
             stage: session.agent.stage().display_name().to_string(),
             turn_count: session.agent.conversation().turn_count(),
             instance_id: None,
+            abuse_incidents: session.agent.abuse_incidents(),
+            disposition: session.agent.disposition().map(|d| d.code),
+            audio_quality: session.agent.line_quality(),
+            model_versions: session.model_versions(),
         };
         self.metadata.write().insert(session.id.clone(), metadata);
         Ok(())
@@ -158,15 +176,16 @@ impl SessionStore for InMemorySessionStore {
 /// P1 FIX: ScyllaDB session store for production persistence
 ///
 /// Uses the voice-agent-persistence crate for durable session storage.
-/// Sessions are persisted to ScyllaDB and survive server restarts.
+/// Sessions are persisted to the configured backend (ScyllaDB, SQLite or
+/// Postgres) and survive server restarts.
 pub struct ScyllaSessionStore {
-    store: voice_agent_persistence::ScyllaSessionStore,
+    store: Arc<dyn voice_agent_persistence::SessionStore>,
     instance_id: String,
 }
 
 impl ScyllaSessionStore {
-    /// Create a new ScyllaDB session store
-    pub fn new(store: voice_agent_persistence::ScyllaSessionStore) -> Self {
+    /// Create a new persistent session store
+    pub fn new(store: Arc<dyn voice_agent_persistence::SessionStore>) -> Self {
         Self {
             store,
             instance_id: uuid::Uuid::new_v4().to_string(),
@@ -175,7 +194,7 @@ impl ScyllaSessionStore {
 
     /// Create with a specific instance ID (for session affinity)
     pub fn with_instance_id(
-        store: voice_agent_persistence::ScyllaSessionStore,
+        store: Arc<dyn voice_agent_persistence::SessionStore>,
         instance_id: String,
     ) -> Self {
         Self { store, instance_id }
@@ -191,22 +210,21 @@ impl ScyllaSessionStore {
 impl SessionStore for ScyllaSessionStore {
     async fn store_metadata(&self, session: &Session) -> Result<(), ServerError> {
         use chrono::Utc;
-        use voice_agent_persistence::sessions::{
-            SessionData, SessionStore as PersistenceSessionStore,
-        };
+        use voice_agent_persistence::sessions::SessionData;
 
         let now = Utc::now();
         let expires_at = now + chrono::Duration::hours(1);
 
         // Get memory context from agent if available
         let memory_json = serde_json::to_string(&session.agent.conversation().get_context()).ok();
+        let lead_score = session.agent.last_lead_score();
 
         let data = SessionData {
             session_id: session.id.clone(),
             created_at: now,
             updated_at: now,
             expires_at,
-            customer_phone: None, // Will be set when customer provides phone
+            customer_phone: session.customer_id(),
             customer_name: None,
             customer_segment: None,
             language: session.agent.config().language.clone(),
@@ -215,41 +233,65 @@ impl SessionStore for ScyllaSessionStore {
             memory_json,
             metadata_json: Some(
                 serde_json::json!({
-                    "instance_id": self.instance_id
+                    "instance_id": self.instance_id,
+                    "abuse_incidents": session.agent.abuse_incidents(),
+                    "disposition": session.agent.disposition(),
+                    "audio_quality": session.agent.line_quality(),
+                    "model_versions": session.model_versions(),
                 })
                 .to_string(),
             ),
+            lead_score: lead_score.as_ref().map(|score| score.total as i32),
+            lead_qualification: lead_score.map(|score| format!("{:?}", score.qualification)),
         };
 
         self.store
             .create(&data)
             .await
-            .map_err(|e| ServerError::Session(format!("ScyllaDB error: {}", e)))?;
+            .map_err(|e| ServerError::Session(format!("Persistence error: {}", e)))?;
 
         tracing::debug!(
             session_id = %session.id,
             stage = %data.conversation_stage,
-            "Session persisted to ScyllaDB"
+            "Session persisted"
         );
 
         Ok(())
     }
 
     async fn get_metadata(&self, id: &str) -> Result<Option<SessionMetadata>, ServerError> {
-        use voice_agent_persistence::sessions::SessionStore as PersistenceSessionStore;
-
         match self.store.get(id).await {
             Ok(Some(data)) => {
-                // Extract instance_id from metadata_json if present
-                let instance_id = data
+                // Extract instance_id, abuse flag, disposition, line quality and model versions
+                // from metadata_json if present
+                let metadata = data
                     .metadata_json
                     .as_ref()
-                    .and_then(|json| serde_json::from_str::<serde_json::Value>(json).ok())
-                    .and_then(|v| {
-                        v.get("instance_id")
-                            .and_then(|i| i.as_str())
-                            .map(String::from)
-                    });
+                    .and_then(|json| serde_json::from_str::<serde_json::Value>(json).ok());
+                let instance_id = metadata.as_ref().and_then(|v| {
+                    v.get("instance_id")
+                        .and_then(|i| i.as_str())
+                        .map(String::from)
+                });
+                let abuse_incidents = metadata
+                    .as_ref()
+                    .and_then(|v| v.get("abuse_incidents"))
+                    .and_then(|n| n.as_u64())
+                    .unwrap_or(0) as u32;
+                let disposition = metadata
+                    .as_ref()
+                    .and_then(|v| v.pointer("/disposition/code"))
+                    .and_then(|c| c.as_str())
+                    .map(String::from);
+                let audio_quality = metadata
+                    .as_ref()
+                    .and_then(|v| v.get("audio_quality"))
+                    .and_then(|q| serde_json::from_value(q.clone()).ok());
+                let model_versions = metadata
+                    .as_ref()
+                    .and_then(|v| v.get("model_versions"))
+                    .and_then(|m| serde_json::from_value(m.clone()).ok())
+                    .unwrap_or_default();
 
                 Ok(Some(SessionMetadata {
                     id: data.session_id,
@@ -259,44 +301,42 @@ impl SessionStore for ScyllaSessionStore {
                     stage: data.conversation_stage,
                     turn_count: data.turn_count as usize,
                     instance_id,
+                    abuse_incidents,
+                    disposition,
+                    audio_quality,
+                    model_versions,
                 }))
             },
             Ok(None) => Ok(None),
-            Err(e) => Err(ServerError::Session(format!("ScyllaDB error: {}", e))),
+            Err(e) => Err(ServerError::Session(format!("Persistence error: {}", e))),
         }
     }
 
     async fn delete_metadata(&self, id: &str) -> Result<(), ServerError> {
-        use voice_agent_persistence::sessions::SessionStore as PersistenceSessionStore;
-
         self.store
             .delete(id)
             .await
-            .map_err(|e| ServerError::Session(format!("ScyllaDB error: {}", e)))?;
-        tracing::debug!(session_id = %id, "Session deleted from ScyllaDB");
+            .map_err(|e| ServerError::Session(format!("Persistence error: {}", e)))?;
+        tracing::debug!(session_id = %id, "Session deleted from persistent store");
         Ok(())
     }
 
     async fn list_ids(&self) -> Result<Vec<String>, ServerError> {
-        use voice_agent_persistence::sessions::SessionStore as PersistenceSessionStore;
-
-        // P2-3 FIX: Actually list sessions from ScyllaDB
+        // P2-3 FIX: Actually list sessions from the persistent store
         let sessions = self
             .store
             .list_active(100)
             .await
-            .map_err(|e| ServerError::Session(format!("ScyllaDB list error: {}", e)))?;
+            .map_err(|e| ServerError::Session(format!("Persistence list error: {}", e)))?;
 
         Ok(sessions.into_iter().map(|s| s.session_id).collect())
     }
 
     async fn touch(&self, id: &str) -> Result<(), ServerError> {
-        use voice_agent_persistence::sessions::SessionStore as PersistenceSessionStore;
-
         self.store
             .touch(id)
             .await
-            .map_err(|e| ServerError::Session(format!("ScyllaDB error: {}", e)))?;
+            .map_err(|e| ServerError::Session(format!("Persistence error: {}", e)))?;
         Ok(())
     }
 
@@ -308,13 +348,11 @@ impl SessionStore for ScyllaSessionStore {
         &self,
         limit: i32,
     ) -> Result<Vec<RecoverableSession>, ServerError> {
-        use voice_agent_persistence::sessions::SessionStore as PersistenceSessionStore;
-
         let sessions = self
             .store
             .list_active(limit)
             .await
-            .map_err(|e| ServerError::Session(format!("ScyllaDB list error: {}", e)))?;
+            .map_err(|e| ServerError::Session(format!("Persistence list error: {}", e)))?;
 
         Ok(sessions
             .into_iter()
@@ -342,6 +380,12 @@ pub struct Session {
     pub last_activity: RwLock<Instant>,
     /// Is active
     pub active: RwLock<bool>,
+    /// Domain config with this session's runtime overrides applied
+    domain: SessionDomainView,
+    /// Resolved customer identity (normalized phone), if known
+    customer_id: RwLock<Option<String>>,
+    /// Model artifact versions serving this session (empty = unversioned)
+    model_versions: RwLock<BTreeMap<String, String>>,
     #[cfg(feature = "webrtc")]
     webrtc: RwLock<Option<crate::webrtc::WebRtcSession>>,
 }
@@ -356,12 +400,16 @@ impl Session {
         domain_config: Arc<voice_agent_config::MasterDomainConfig>,
     ) -> Self {
         let id = id.into();
+        let domain = SessionDomainView::new(domain_config.clone(), SessionOverrides::default());
         Self {
             agent: Arc::new(DomainAgent::new(&id, config, domain_config)),
             id,
             created_at: Instant::now(),
             last_activity: RwLock::new(Instant::now()),
             active: RwLock::new(true),
+            domain,
+            customer_id: RwLock::new(None),
+            model_versions: RwLock::new(BTreeMap::new()),
             #[cfg(feature = "webrtc")]
             webrtc: RwLock::new(None),
         }
@@ -377,6 +425,7 @@ impl Session {
         domain_config: Arc<voice_agent_config::MasterDomainConfig>,
     ) -> Self {
         let id = id.into();
+        let domain = SessionDomainView::new(domain_config.clone(), SessionOverrides::default());
         let agent = DomainAgent::new(&id, config, domain_config).with_vector_store(vector_store);
         Self {
             agent: Arc::new(agent),
@@ -384,6 +433,9 @@ impl Session {
             created_at: Instant::now(),
             last_activity: RwLock::new(Instant::now()),
             active: RwLock::new(true),
+            domain,
+            customer_id: RwLock::new(None),
+            model_versions: RwLock::new(BTreeMap::new()),
             #[cfg(feature = "webrtc")]
             webrtc: RwLock::new(None),
         }
@@ -396,10 +448,11 @@ impl Session {
         id: impl Into<String>,
         config: AgentConfig,
         vector_store: Option<Arc<voice_agent_rag::VectorStore>>,
-        tools: Arc<voice_agent_tools::ToolRegistry>,
+        tools: Arc<dyn voice_agent_tools::ToolExecutor>,
         domain_config: Arc<voice_agent_config::MasterDomainConfig>,
     ) -> Self {
         let id = id.into();
+        let domain = SessionDomainView::new(domain_config.clone(), SessionOverrides::default());
         let mut agent = DomainAgent::new(&id, config, domain_config).with_tools(tools);
         if let Some(vs) = vector_store {
             agent = agent.with_vector_store(vs);
@@ -410,11 +463,53 @@ impl Session {
             created_at: Instant::now(),
             last_activity: RwLock::new(Instant::now()),
             active: RwLock::new(true),
+            domain,
+            customer_id: RwLock::new(None),
+            model_versions: RwLock::new(BTreeMap::new()),
             #[cfg(feature = "webrtc")]
             webrtc: RwLock::new(None),
         }
     }
 
+    /// Record the runtime overrides this session was started with
+    ///
+    /// The agent config must already have them applied
+    /// (see `AgentConfig::with_overrides`); this only updates the view.
+    pub fn with_overrides(mut self, overrides: SessionOverrides) -> Self {
+        self.domain = self.domain.with_overrides(overrides);
+        self
+    }
+
+    /// Runtime overrides for this session
+    pub fn overrides(&self) -> &SessionOverrides {
+        self.domain.overrides()
+    }
+
+    /// Domain config as seen by this session (overrides applied)
+    pub fn domain_view(&self) -> &SessionDomainView {
+        &self.domain
+    }
+
+    /// Link this session to a resolved customer identity
+    pub fn set_customer_id(&self, customer_id: impl Into<String>) {
+        *self.customer_id.write() = Some(customer_id.into());
+    }
+
+    /// Customer identity this session is linked to, if any
+    pub fn customer_id(&self) -> Option<String> {
+        self.customer_id.read().clone()
+    }
+
+    /// Record the model versions this session runs on
+    pub fn set_model_versions(&self, versions: BTreeMap<String, String>) {
+        *self.model_versions.write() = versions;
+    }
+
+    /// Model artifact versions serving this session
+    pub fn model_versions(&self) -> BTreeMap<String, String> {
+        self.model_versions.read().clone()
+    }
+
     #[cfg(feature = "webrtc")]
     pub fn set_webrtc_transport(&self, session: crate::webrtc::WebRtcSession) {
         *self.webrtc.write() = Some(session);
@@ -451,6 +546,28 @@ impl Session {
     pub fn is_active(&self) -> bool {
         *self.active.read()
     }
+
+    /// Summarize how the session went, for conversation analytics
+    pub fn outcome(&self) -> voice_agent_persistence::SessionOutcome {
+        let agent = &self.agent;
+        let elapsed = self.created_at.elapsed();
+        let started_at = chrono::Utc::now()
+            - chrono::Duration::from_std(elapsed).unwrap_or_else(|_| chrono::Duration::zero());
+        let goal = agent.dialogue_goal();
+        voice_agent_persistence::SessionOutcome {
+            session_id: self.id.clone(),
+            started_at,
+            duration_secs: elapsed.as_secs(),
+            language: agent.user_language().code().to_string(),
+            stages_reached: agent.stages_reached(),
+            final_stage: agent.stage().as_str().to_string(),
+            goal: (!goal.is_empty()).then_some(goal),
+            goal_reached: agent.goal_reached(),
+            tools_called: agent.tools_called(),
+            interruptions: agent.interruption_count(),
+            turns: agent.conversation().turn_count() as u32,
+        }
+    }
 }
 
 /// Session manager
@@ -460,6 +577,8 @@ pub struct SessionManager {
     session_timeout: Duration,
     /// P2 FIX: Cleanup interval for passive session cleanup
     cleanup_interval: Duration,
+    /// Receives sessions as they are removed or expire
+    closed_tx: RwLock<Option<mpsc::UnboundedSender<Arc<Session>>>>,
 }
 
 impl SessionManager {
@@ -470,6 +589,7 @@ impl SessionManager {
             max_sessions,
             session_timeout: Duration::from_secs(3600), // 1 hour
             cleanup_interval: Duration::from_secs(300), // 5 minutes
+            closed_tx: RwLock::new(None),
         }
     }
 
@@ -484,6 +604,7 @@ impl SessionManager {
             max_sessions,
             session_timeout,
             cleanup_interval,
+            closed_tx: RwLock::new(None),
         }
     }
 
@@ -528,6 +649,21 @@ impl SessionManager {
         shutdown_tx
     }
 
+    /// Receive every session as it is removed or expires
+    ///
+    /// Only one listener is kept; subscribing again replaces it.
+    pub fn subscribe_closed(&self) -> mpsc::UnboundedReceiver<Arc<Session>> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        *self.closed_tx.write() = Some(tx);
+        rx
+    }
+
+    fn notify_closed(&self, session: Arc<Session>) {
+        if let Some(tx) = self.closed_tx.read().as_ref() {
+            let _ = tx.send(session);
+        }
+    }
+
     /// Create a new session with domain configuration
     ///
     /// # P21 FIX: Accept domain config to pass through to agent
@@ -565,9 +701,36 @@ impl SessionManager {
         &self,
         config: AgentConfig,
         vector_store: Option<Arc<voice_agent_rag::VectorStore>>,
-        tools: Option<Arc<voice_agent_tools::ToolRegistry>>,
+        tools: Option<Arc<dyn voice_agent_tools::ToolExecutor>>,
         domain_config: Arc<voice_agent_config::MasterDomainConfig>,
     ) -> Result<Arc<Session>, ServerError> {
+        self.create_with_overrides(
+            config,
+            vector_store,
+            tools,
+            domain_config,
+            SessionOverrides::default(),
+        )
+    }
+
+    /// Create a session with per-session runtime overrides
+    ///
+    /// Overrides are validated against the domain config and applied to this
+    /// session's agent config only; the shared `MasterDomainConfig` is untouched.
+    pub fn create_with_overrides(
+        &self,
+        config: AgentConfig,
+        vector_store: Option<Arc<voice_agent_rag::VectorStore>>,
+        tools: Option<Arc<dyn voice_agent_tools::ToolExecutor>>,
+        domain_config: Arc<voice_agent_config::MasterDomainConfig>,
+        overrides: SessionOverrides,
+    ) -> Result<Arc<Session>, ServerError> {
+        overrides
+            .validate(&domain_config)
+            .map_err(|e| ServerError::InvalidRequest(format!("Invalid session overrides: {}", e)))?;
+        let config = config.with_overrides(&overrides);
+        let has_overrides = !overrides.is_empty();
+
         let mut sessions = self.sessions.write();
 
         // Check capacity
@@ -584,27 +747,66 @@ impl SessionManager {
         let rag_enabled = vector_store.is_some();
         let tools_wired = tools.is_some();
 
-        // P21 FIX: Pass domain_config to all Session constructors
-        let session = match (vector_store, tools) {
-            (Some(vs), Some(t)) => {
-                Arc::new(Session::with_full_integration(&id, config, Some(vs), t, domain_config))
-            },
-            (Some(vs), None) => Arc::new(Session::with_vector_store(&id, config, vs, domain_config)),
-            (None, Some(t)) => Arc::new(Session::with_full_integration(&id, config, None, t, domain_config)),
-            (None, None) => Arc::new(Session::new(&id, config, domain_config)),
-        };
+        let session = Self::build(&id, config, vector_store, tools, domain_config);
+        let session = Arc::new(session.with_overrides(overrides));
         sessions.insert(id.clone(), session.clone());
 
         tracing::info!(
             session_id = %id,
             rag_enabled = rag_enabled,
             tools_wired = tools_wired,
+            has_overrides = has_overrides,
             "Created session"
         );
 
         Ok(session)
     }
 
+    /// Recreate a session under the ID it had before a restart
+    ///
+    /// The caller replays its journal into the new agent. Returns the
+    /// existing session if the ID is already live.
+    pub fn restore(
+        &self,
+        id: &str,
+        config: AgentConfig,
+        vector_store: Option<Arc<voice_agent_rag::VectorStore>>,
+        tools: Option<Arc<dyn voice_agent_tools::ToolExecutor>>,
+        domain_config: Arc<voice_agent_config::MasterDomainConfig>,
+    ) -> Result<Arc<Session>, ServerError> {
+        let mut sessions = self.sessions.write();
+        if let Some(session) = sessions.get(id) {
+            return Ok(session.clone());
+        }
+        if sessions.len() >= self.max_sessions {
+            return Err(ServerError::Session("Max sessions reached".to_string()));
+        }
+
+        let session = Arc::new(Self::build(id, config, vector_store, tools, domain_config));
+        sessions.insert(id.to_string(), session.clone());
+        tracing::info!(session_id = %id, "Restored session");
+
+        Ok(session)
+    }
+
+    fn build(
+        id: &str,
+        config: AgentConfig,
+        vector_store: Option<Arc<voice_agent_rag::VectorStore>>,
+        tools: Option<Arc<dyn voice_agent_tools::ToolExecutor>>,
+        domain_config: Arc<voice_agent_config::MasterDomainConfig>,
+    ) -> Session {
+        // P21 FIX: Pass domain_config to all Session constructors
+        match (vector_store, tools) {
+            (Some(vs), Some(t)) => {
+                Session::with_full_integration(id, config, Some(vs), t, domain_config)
+            },
+            (Some(vs), None) => Session::with_vector_store(id, config, vs, domain_config),
+            (None, Some(t)) => Session::with_full_integration(id, config, None, t, domain_config),
+            (None, None) => Session::new(id, config, domain_config),
+        }
+    }
+
     /// Get a session by ID
     pub fn get(&self, id: &str) -> Option<Arc<Session>> {
         let sessions = self.sessions.read();
@@ -617,6 +819,7 @@ impl SessionManager {
         if let Some(session) = sessions.remove(id) {
             session.close();
             tracing::info!("Removed session: {}", id);
+            self.notify_closed(session);
         }
     }
 
@@ -643,6 +846,7 @@ impl SessionManager {
             if let Some(session) = sessions.remove(&id) {
                 session.close();
                 tracing::info!("Expired session: {}", id);
+                self.notify_closed(session);
             }
         }
     }
@@ -692,6 +896,52 @@ mod tests {
         assert!(manager.get(&id).is_none());
     }
 
+    #[test]
+    fn test_closed_sessions_are_reported() {
+        let manager = SessionManager::new(10);
+        let mut closed = manager.subscribe_closed();
+        let session = manager.create(AgentConfig::default(), test_domain_config()).unwrap();
+        let id = session.id.clone();
+
+        manager.remove(&id);
+        let reported = closed.try_recv().unwrap();
+        assert_eq!(reported.id, id);
+        let outcome = reported.outcome();
+        assert_eq!(outcome.session_id, id);
+        assert_eq!(outcome.final_stage, "greeting");
+        assert!(closed.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_session_overrides() {
+        let manager = SessionManager::new(10);
+        let overrides = SessionOverrides {
+            persona: Some("Asha".to_string()),
+            llm_temperature: Some(0.1),
+            ..Default::default()
+        };
+        let session = manager
+            .create_with_overrides(AgentConfig::default(), None, None, test_domain_config(), overrides)
+            .unwrap();
+
+        assert_eq!(session.agent.config().name(), "Asha");
+        assert_eq!(session.agent.config().llm_provider.temperature, 0.1);
+        assert_eq!(session.domain_view().agent_name(), "Asha");
+
+        // Other sessions keep domain defaults
+        let plain = manager.create(AgentConfig::default(), test_domain_config()).unwrap();
+        assert!(plain.overrides().is_empty());
+
+        let invalid = SessionOverrides {
+            llm_temperature: Some(5.0),
+            ..Default::default()
+        };
+        assert!(matches!(
+            manager.create_with_overrides(AgentConfig::default(), None, None, test_domain_config(), invalid),
+            Err(ServerError::InvalidRequest(_))
+        ));
+    }
+
     #[tokio::test]
     async fn test_in_memory_session_store() {
         let store = InMemorySessionStore::new();
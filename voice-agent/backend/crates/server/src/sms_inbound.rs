@@ -0,0 +1,139 @@
+//! Inbound SMS Webhook
+//!
+//! The SMS provider forwards customer replies to `POST /sms/inbound`, as a
+//! form (Twilio-style `From`/`Body`) or JSON (`from`/`body`). Replies to
+//! appointment confirmation texts (YES, NO or RESCHEDULE, optionally with
+//! the code) update the appointment; see `sms_confirmation` in the config.
+//!
+//! The provider cannot send the API key, so the route bypasses API auth and
+//! checks `sms_confirmation.inbound_token` instead (`X-Inbound-Token` header
+//! or `?token=`).
+
+use axum::{
+    extract::{FromRequest, Query, Request, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Form, Json,
+};
+use serde::Deserialize;
+use voice_agent_tools::ReplyOutcome;
+
+use crate::auth::constant_time_compare;
+use crate::state::AppState;
+
+/// Path the provider posts replies to
+pub const SMS_INBOUND_PATH: &str = "/sms/inbound";
+
+/// An inbound message
+#[derive(Debug, Deserialize)]
+pub struct InboundSms {
+    #[serde(alias = "From")]
+    from: String,
+    #[serde(alias = "Body")]
+    body: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenQuery {
+    token: Option<String>,
+}
+
+/// Whether the request carries the configured inbound token
+fn authorized(request: &Request, expected: &str) -> bool {
+    let provided = request
+        .headers()
+        .get("x-inbound-token")
+        .and_then(|v| v.to_str().ok())
+        .map(String::from)
+        .or_else(|| {
+            Query::<TokenQuery>::try_from_uri(request.uri())
+                .ok()
+                .and_then(|Query(q)| q.token)
+        });
+    provided.is_some_and(|token| constant_time_compare(token.as_bytes(), expected.as_bytes()))
+}
+
+/// Apply a customer's SMS reply
+///
+/// POST /sms/inbound
+pub async fn receive(State(state): State<AppState>, request: Request) -> Response {
+    let Some(confirmations) = state.appointment_confirmations.clone() else {
+        return (StatusCode::NOT_FOUND, "SMS confirmation is disabled").into_response();
+    };
+    let token = state.config.read().sms_confirmation.inbound_token.clone();
+    if !token.is_some_and(|t| authorized(&request, &t)) {
+        crate::metrics::record_sms_reply("unauthorized");
+        return (StatusCode::UNAUTHORIZED, "Invalid inbound token").into_response();
+    }
+
+    let is_json = request
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| ct.starts_with("application/json"));
+    let sms = if is_json {
+        Json::<InboundSms>::from_request(request, &state)
+            .await
+            .map(|Json(sms)| sms)
+            .map_err(IntoResponse::into_response)
+    } else {
+        Form::<InboundSms>::from_request(request, &state)
+            .await
+            .map(|Form(sms)| sms)
+            .map_err(IntoResponse::into_response)
+    };
+    let sms = match sms {
+        Ok(sms) => sms,
+        Err(rejection) => return rejection,
+    };
+
+    match confirmations.handle_reply(&sms.from, &sms.body).await {
+        Ok(outcome) => {
+            let label = match outcome {
+                ReplyOutcome::Updated { .. } => "updated",
+                ReplyOutcome::NoAppointment => "no_appointment",
+                ReplyOutcome::Unrecognized => "unrecognized",
+            };
+            crate::metrics::record_sms_reply(label);
+            (StatusCode::OK, Json(outcome)).into_response()
+        },
+        Err(e) => {
+            tracing::error!("Failed to apply SMS reply: {}", e);
+            crate::metrics::record_sms_reply("failed");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({ "error": e.to_string() })),
+            )
+                .into_response()
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+
+    #[test]
+    fn test_inbound_token_from_header_or_query() {
+        let request = Request::builder()
+            .uri("/sms/inbound")
+            .header("x-inbound-token", "t0ken")
+            .body(Body::empty())
+            .unwrap();
+        assert!(authorized(&request, "t0ken"));
+        assert!(!authorized(&request, "other"));
+
+        let request = Request::builder()
+            .uri("/sms/inbound?token=t0ken")
+            .body(Body::empty())
+            .unwrap();
+        assert!(authorized(&request, "t0ken"));
+
+        let request = Request::builder()
+            .uri("/sms/inbound")
+            .body(Body::empty())
+            .unwrap();
+        assert!(!authorized(&request, "t0ken"));
+    }
+}
@@ -8,20 +8,52 @@
 use parking_lot::RwLock;
 use std::sync::Arc;
 
-use voice_agent_config::{load_settings, MasterDomainConfig, Settings};
+use voice_agent_config::{
+    load_settings, MasterDomainConfig, SessionOverrides, Settings, ToolExecutionConfig,
+};
 use voice_agent_config::domain::{AgentDomainView, LlmDomainView, ToolsDomainView};
-use voice_agent_rag::VectorStore;
-use voice_agent_tools::ToolRegistry;
+use voice_agent_rag::{Embedder, KnowledgeBase, VectorStore};
+use voice_agent_llm::LlmRouter;
+use voice_agent_pipeline::{PipelineConfig, PronunciationLexicon, SpeechStyle};
+use voice_agent_agent::{
+    AbusePolicy, ArchivalVectorBackend, DispositionClassifier, Guardrails, ResponseCache,
+    ResponseGovernor,
+};
+use voice_agent_tools::{
+    AppointmentConfirmations, RateCardEngine, ResilientToolExecutor, ToolExecutor,
+};
 // P2 FIX: Text processing pipeline for grammar, PII, compliance
-use voice_agent_text_processing::{TextProcessingConfig, TextProcessingPipeline, TextSimplifier};
+use voice_agent_text_processing::{CodeSwitchRenderer, TextProcessingConfig, TextProcessingPipeline, TextSimplifier};
 // Deterministic phonetic error correction
 use voice_agent_text_processing::grammar::PhoneticCorrector;
 // Translation
 use voice_agent_text_processing::translation::{TranslationConfig, create_translator};
-use voice_agent_core::Translator;
+use voice_agent_core::{DomainEvent, EventBus, Translator};
 // P2 FIX: Audit logging for RBI compliance
 use voice_agent_persistence::{AuditLog, AuditLogger};
+// Cross-channel customer identity
+use voice_agent_persistence::{CustomerIdentityStore, InMemoryCustomerIdentityStore};
+// Admin-maintained competitor rates
+use voice_agent_persistence::CompetitorRateStore;
+// Requested callbacks
+use voice_agent_persistence::CallbackStore;
+// Appointments, slots and SMS history for the admin API
+use voice_agent_persistence::{AppointmentStore, SlotStore, SmsService};
+// Conversation analytics
+use voice_agent_persistence::AnalyticsStore;
+// Promotional offer mentions and acceptance
+use voice_agent_persistence::OfferEventStore;
+// Write-ahead journal of conversation turns
+use voice_agent_persistence::SessionJournal;
+// Per-turn usage accounting
+use voice_agent_persistence::UsageStore;
+// Webhook delivery log
+use voice_agent_persistence::WebhookDeliveryStore;
 
+use crate::admission::AdmissionController;
+use crate::degradation::DegradationManager;
+use crate::model_registry::ModelRegistry;
+use crate::models::{ModelComponent, ModelManager, ModelSet, SttPool, TtsPool};
 use crate::session::{InMemorySessionStore, SessionManager, SessionStore};
 
 /// Application state
@@ -40,7 +72,7 @@ pub struct AppState {
     /// Session manager
     pub sessions: Arc<SessionManager>,
     /// Tool registry
-    pub tools: Arc<ToolRegistry>,
+    pub tools: Arc<dyn ToolExecutor>,
     /// P2-3 FIX: Session store for persistence (ScyllaDB or in-memory)
     pub session_store: Arc<dyn SessionStore>,
     /// P0 FIX: Vector store for RAG retrieval (optional - initialized if Qdrant is available)
@@ -55,6 +87,64 @@ pub struct AppState {
     pub translator: Arc<dyn Translator>,
     /// P2 FIX: Audit logger for RBI compliance (wrapped in Arc for Clone)
     pub audit_logger: Option<Arc<AuditLogger>>,
+    /// Customer identities keyed by normalized phone (ScyllaDB or in-memory)
+    pub identity_store: Arc<dyn CustomerIdentityStore>,
+    /// Admin-maintained competitor rates (None = comparisons use config only)
+    pub competitor_rates: Option<Arc<dyn CompetitorRateStore>>,
+    /// LTV and rate cards shared with the tools (None = config cards only, no publishing)
+    pub rate_cards: Option<Arc<RateCardEngine>>,
+    /// Offer mentions and acceptance per session (None = offer report disabled)
+    pub offer_events: Option<Arc<dyn OfferEventStore>>,
+    /// Requested callbacks (None = callback API disabled)
+    pub callbacks: Option<Arc<dyn CallbackStore>>,
+    /// Booked branch visits, for the admin API
+    pub appointments: Option<Arc<dyn AppointmentStore>>,
+    /// Branch slot capacity, for the admin API
+    pub slots: Option<Arc<dyn SlotStore>>,
+    /// SMS history, for the admin API
+    pub sms: Option<Arc<dyn SmsService>>,
+    /// Applies SMS replies to booked appointments (None = SMS confirmation disabled)
+    pub appointment_confirmations: Option<Arc<AppointmentConfirmations>>,
+    /// Audit trail queried by the admin API
+    pub audit_log: Option<Arc<dyn AuditLog>>,
+    /// Webhook delivery log, for the admin API (None = webhooks disabled)
+    pub webhook_deliveries: Option<Arc<dyn WebhookDeliveryStore>>,
+    /// Session outcomes and daily rollups (None = analytics disabled)
+    pub analytics: Option<Arc<dyn AnalyticsStore>>,
+    /// Disposition coding for finished sessions (None = not coded)
+    pub disposition: Option<Arc<DispositionClassifier>>,
+    /// Embedder for archival memory (None = BM25-only archival search)
+    pub archival_embedder: Option<Arc<Embedder>>,
+    /// Archival memory backend (Qdrant, ScyllaDB or shared in-process)
+    pub archival_backend: Option<Arc<dyn ArchivalVectorBackend>>,
+    /// Ingested knowledge base shared by all sessions
+    pub knowledge_base: Option<Arc<KnowledgeBase>>,
+    /// Cache of deterministic tool and FAQ answers shared by all sessions
+    pub response_cache: Option<Arc<ResponseCache>>,
+    /// Per-turn journal sessions are recovered from after a restart (None = not journaled)
+    pub journal: Option<Arc<dyn SessionJournal>>,
+    /// Per-turn LLM, speech, tool and translation usage (None = not accounted)
+    pub usage: Option<Arc<dyn UsageStore>>,
+    /// Compliance guardrails applied to every session's LLM output
+    pub guardrails: Option<Arc<Guardrails>>,
+    /// Spoken-duration budget applied to every session's LLM answers
+    pub response_governor: Option<Arc<ResponseGovernor>>,
+    /// Abusive-speech policy applied to every session's caller turns
+    pub abuse_policy: Option<Arc<AbusePolicy>>,
+    /// Script-consistency rewrite of responses before TTS (None = spoken as generated)
+    pub code_switch: Option<Arc<CodeSwitchRenderer>>,
+    /// Component health and required/optional startup policy
+    pub degradation: Arc<DegradationManager>,
+    /// Admission control for new sessions under load
+    pub admission: Arc<AdmissionController>,
+    /// Shared STT/TTS worker pools and LLM router, reloadable without a restart
+    pub models: Arc<ModelManager>,
+    /// Checksummed model artifacts verified before models load (None = unverified paths)
+    pub model_registry: Option<Arc<ModelRegistry>>,
+    /// Pronunciation overrides shared by every session's TTS (None = G2P rules only)
+    pub lexicon: Option<Arc<PronunciationLexicon>>,
+    /// Domain events from all sessions (audit, metrics and other subscribers)
+    pub events: EventBus,
     /// Environment name for config reload
     env: Option<String>,
 }
@@ -152,6 +242,35 @@ impl AppState {
             phonetic_corrector,
             translator,
             audit_logger: None,
+            identity_store: Arc::new(InMemoryCustomerIdentityStore::new()),
+            competitor_rates: None,
+            rate_cards: None,
+            offer_events: None,
+            callbacks: None,
+            appointments: None,
+            slots: None,
+            sms: None,
+            appointment_confirmations: None,
+            audit_log: None,
+            webhook_deliveries: None,
+            analytics: None,
+            disposition: None,
+            archival_embedder: None,
+            archival_backend: None,
+            knowledge_base: None,
+            response_cache: None,
+            journal: None,
+            usage: None,
+            guardrails: None,
+            response_governor: None,
+            abuse_policy: None,
+            code_switch: None,
+            degradation: Arc::new(DegradationManager::default()),
+            admission: Arc::new(AdmissionController::default()),
+            models: Arc::new(ModelManager::default()),
+            model_registry: None,
+            lexicon: None,
+            events: EventBus::default(),
             env: None,
         }
     }
@@ -181,6 +300,35 @@ impl AppState {
             phonetic_corrector,
             translator,
             audit_logger: None,
+            identity_store: Arc::new(InMemoryCustomerIdentityStore::new()),
+            competitor_rates: None,
+            rate_cards: None,
+            offer_events: None,
+            callbacks: None,
+            appointments: None,
+            slots: None,
+            sms: None,
+            appointment_confirmations: None,
+            audit_log: None,
+            webhook_deliveries: None,
+            analytics: None,
+            disposition: None,
+            archival_embedder: None,
+            archival_backend: None,
+            knowledge_base: None,
+            response_cache: None,
+            journal: None,
+            usage: None,
+            guardrails: None,
+            response_governor: None,
+            abuse_policy: None,
+            code_switch: None,
+            degradation: Arc::new(DegradationManager::default()),
+            admission: Arc::new(AdmissionController::default()),
+            models: Arc::new(ModelManager::default()),
+            model_registry: None,
+            lexicon: None,
+            events: EventBus::default(),
             env: None,
         }
     }
@@ -207,6 +355,35 @@ impl AppState {
             phonetic_corrector,
             translator,
             audit_logger: None,
+            identity_store: Arc::new(InMemoryCustomerIdentityStore::new()),
+            competitor_rates: None,
+            rate_cards: None,
+            offer_events: None,
+            callbacks: None,
+            appointments: None,
+            slots: None,
+            sms: None,
+            appointment_confirmations: None,
+            audit_log: None,
+            webhook_deliveries: None,
+            analytics: None,
+            disposition: None,
+            archival_embedder: None,
+            archival_backend: None,
+            knowledge_base: None,
+            response_cache: None,
+            journal: None,
+            usage: None,
+            guardrails: None,
+            response_governor: None,
+            abuse_policy: None,
+            code_switch: None,
+            degradation: Arc::new(DegradationManager::default()),
+            admission: Arc::new(AdmissionController::default()),
+            models: Arc::new(ModelManager::default()),
+            model_registry: None,
+            lexicon: None,
+            events: EventBus::default(),
             env,
         }
     }
@@ -233,6 +410,35 @@ impl AppState {
             phonetic_corrector,
             translator,
             audit_logger: None,
+            identity_store: Arc::new(InMemoryCustomerIdentityStore::new()),
+            competitor_rates: None,
+            rate_cards: None,
+            offer_events: None,
+            callbacks: None,
+            appointments: None,
+            slots: None,
+            sms: None,
+            appointment_confirmations: None,
+            audit_log: None,
+            webhook_deliveries: None,
+            analytics: None,
+            disposition: None,
+            archival_embedder: None,
+            archival_backend: None,
+            knowledge_base: None,
+            response_cache: None,
+            journal: None,
+            usage: None,
+            guardrails: None,
+            response_governor: None,
+            abuse_policy: None,
+            code_switch: None,
+            degradation: Arc::new(DegradationManager::default()),
+            admission: Arc::new(AdmissionController::default()),
+            models: Arc::new(ModelManager::default()),
+            model_registry: None,
+            lexicon: None,
+            events: EventBus::default(),
             env: None,
         }
     }
@@ -245,12 +451,30 @@ impl AppState {
     ///
     /// All business config (rates, LTV, etc.) now comes from ToolsDomainView.
     /// P16 FIX: Accept AssetPriceService (generic) instead of GoldPriceService
+    ///
+    /// `crm` is the lead delivery target for `capture_lead`; `None` keeps leads local.
+    /// `slot_store` holds branch visit capacity for the appointment tools and
+    /// `competitor_rates` the admin-maintained rates used by comparisons, and
+    /// `callbacks` the callbacks booked by `schedule_callback`.
+    /// `confirmations` stores booked appointments and confirms them by SMS reply.
+    /// `rate_cards` serves LTV and rates to the tools and publishes new cards.
+    /// `offer_events` records the offers presented in each session and the answers.
+    #[allow(clippy::too_many_arguments)]
     pub fn with_full_persistence(
         config: Settings,
         store: Arc<dyn SessionStore>,
         master_domain_config: Arc<MasterDomainConfig>,
         sms_service: Arc<dyn voice_agent_persistence::SmsService>,
         gold_price_service: Arc<dyn voice_agent_persistence::AssetPriceService>,
+        slot_store: Arc<dyn voice_agent_persistence::SlotStore>,
+        competitor_rates: Arc<dyn CompetitorRateStore>,
+        callbacks: Arc<dyn CallbackStore>,
+        crm: Option<Arc<dyn voice_agent_tools::CrmIntegration>>,
+        handoff: Option<Arc<dyn voice_agent_tools::HandoffQueue>>,
+        confirmations: Option<Arc<AppointmentConfirmations>>,
+        price_alerts: Option<Arc<dyn voice_agent_persistence::PriceAlertStore>>,
+        rate_cards: Arc<RateCardEngine>,
+        offer_events: Arc<dyn OfferEventStore>,
     ) -> Self {
         // P16 FIX: Use config-driven phonetic corrector
         let (text_processing, text_simplifier, phonetic_corrector, translator) = Self::create_text_processing_with_domain(&master_domain_config);
@@ -258,8 +482,29 @@ impl AppState {
 
         // P15 FIX: Create tool registry with REQUIRED tools_view and persistence services
         let integration_config = voice_agent_tools::FullIntegrationConfig::new(tools_view.clone())
-            .with_sms_service(sms_service)
-            .with_gold_price_service(gold_price_service);
+            .with_sms_service(sms_service.clone())
+            .with_gold_price_service(gold_price_service)
+            .with_slot_store(slot_store.clone())
+            .with_competitor_rate_store(competitor_rates.clone())
+            .with_rate_cards(rate_cards.clone())
+            .with_offer_events(offer_events.clone())
+            .with_callback_store(callbacks.clone());
+        let integration_config = match crm {
+            Some(crm) => integration_config.with_crm(crm),
+            None => integration_config,
+        };
+        let integration_config = match handoff {
+            Some(queue) => integration_config.with_handoff_queue(queue, config.handoff.clone()),
+            None => integration_config,
+        };
+        let integration_config = match confirmations.clone() {
+            Some(confirmations) => integration_config.with_appointment_confirmations(confirmations),
+            None => integration_config,
+        };
+        let integration_config = match price_alerts {
+            Some(store) => integration_config.with_price_alerts(store, config.price_alerts.clone()),
+            None => integration_config,
+        };
         let tools = voice_agent_tools::create_registry_with_persistence(integration_config);
 
         Self {
@@ -277,6 +522,35 @@ impl AppState {
             phonetic_corrector,
             translator,
             audit_logger: None,
+            identity_store: Arc::new(InMemoryCustomerIdentityStore::new()),
+            competitor_rates: Some(competitor_rates),
+            rate_cards: Some(rate_cards),
+            offer_events: Some(offer_events),
+            callbacks: Some(callbacks),
+            appointments: None,
+            slots: Some(slot_store.clone()),
+            sms: Some(sms_service.clone()),
+            appointment_confirmations: confirmations,
+            audit_log: None,
+            webhook_deliveries: None,
+            analytics: None,
+            disposition: None,
+            archival_embedder: None,
+            archival_backend: None,
+            knowledge_base: None,
+            response_cache: None,
+            journal: None,
+            usage: None,
+            guardrails: None,
+            response_governor: None,
+            abuse_policy: None,
+            code_switch: None,
+            degradation: Arc::new(DegradationManager::default()),
+            admission: Arc::new(AdmissionController::default()),
+            models: Arc::new(ModelManager::default()),
+            model_registry: None,
+            lexicon: None,
+            events: EventBus::default(),
             env: None,
         }
     }
@@ -289,10 +563,459 @@ impl AppState {
 
     /// P2 FIX: Set audit logger for RBI compliance logging
     pub fn with_audit_logger(mut self, audit_log: Arc<dyn AuditLog>) -> Self {
-        self.audit_logger = Some(Arc::new(AuditLogger::new(audit_log)));
+        self.audit_logger = Some(Arc::new(AuditLogger::new(audit_log.clone())));
+        self.audit_log = Some(audit_log);
+        self
+    }
+
+    /// Set the appointment store the admin API reads and updates
+    pub fn with_appointment_store(mut self, store: Arc<dyn AppointmentStore>) -> Self {
+        self.appointments = Some(store);
+        self
+    }
+
+    /// Set the webhook delivery log the admin API reads
+    pub fn with_webhook_log(mut self, store: Arc<dyn WebhookDeliveryStore>) -> Self {
+        self.webhook_deliveries = Some(store);
+        self
+    }
+
+    /// Set the analytics store sessions are recorded to
+    pub fn with_analytics_store(mut self, store: Arc<dyn AnalyticsStore>) -> Self {
+        self.analytics = Some(store);
+        self
+    }
+
+    /// Set the classifier that codes finished sessions
+    pub fn with_disposition_classifier(mut self, classifier: Arc<DispositionClassifier>) -> Self {
+        self.disposition = Some(classifier);
+        self
+    }
+
+    /// Set the customer identity store (e.g. ScyllaDB)
+    pub fn with_identity_store(mut self, store: Arc<dyn CustomerIdentityStore>) -> Self {
+        self.identity_store = store;
+        self
+    }
+
+    /// Set the archival memory embedder and durable backend
+    pub fn with_archival_memory(
+        mut self,
+        embedder: Option<Arc<Embedder>>,
+        backend: Option<Arc<dyn ArchivalVectorBackend>>,
+    ) -> Self {
+        self.archival_embedder = embedder;
+        self.archival_backend = backend;
+        self
+    }
+
+    /// Set the ingested knowledge base
+    pub fn with_knowledge_base(mut self, knowledge_base: Arc<KnowledgeBase>) -> Self {
+        self.knowledge_base = Some(knowledge_base);
+        self
+    }
+
+    /// Give a session's agent access to the knowledge base
+    pub fn attach_knowledge_base(&self, session: &crate::session::Session) {
+        if let Some(ref knowledge_base) = self.knowledge_base {
+            session.agent.set_knowledge_base(knowledge_base.clone());
+        }
+    }
+
+    /// Set the shared LLM router
+    pub fn with_llm_router(self, router: Arc<LlmRouter>) -> Self {
+        self.models
+            .set_initial(|models| models.llm_router = Some(router));
+        self
+    }
+
+    /// Set the shared response cache
+    pub fn with_response_cache(mut self, cache: Arc<ResponseCache>) -> Self {
+        self.response_cache = Some(cache);
+        self
+    }
+
+    /// Give a session's agent access to the response cache
+    pub fn attach_response_cache(&self, session: &crate::session::Session) {
+        if let Some(ref cache) = self.response_cache {
+            session.agent.set_response_cache(cache.clone());
+        }
+    }
+
+    /// Set the journal sessions write their turns to
+    pub fn with_session_journal(mut self, journal: Arc<dyn SessionJournal>) -> Self {
+        self.journal = Some(journal);
+        self
+    }
+
+    /// Journal a session's turns so it survives a restart
+    pub fn attach_journal(&self, session: &crate::session::Session) {
+        if let Some(ref journal) = self.journal {
+            session.agent.set_journal(journal.clone());
+        }
+    }
+
+    /// Set the store sessions record their per-turn usage to
+    pub fn with_usage_store(mut self, store: Arc<dyn UsageStore>) -> Self {
+        self.usage = Some(store);
+        self
+    }
+
+    /// `tenant`, or the configured default tenant
+    fn tenant_or_default(&self, tenant: Option<&str>) -> String {
+        tenant
+            .map(str::to_string)
+            .unwrap_or_else(|| self.config.read().usage.default_tenant.clone())
+    }
+
+    /// Account a session's usage to `tenant`, or the configured default tenant
+    pub fn attach_usage(&self, session: &crate::session::Session, tenant: Option<&str>) {
+        if let Some(ref store) = self.usage {
+            let tenant = self.tenant_or_default(tenant);
+            session.agent.set_usage_store(store.clone(), tenant);
+        }
+    }
+
+    /// Apply the tenant's session budgets (no-op when budgets are disabled)
+    pub fn attach_budget(&self, session: &crate::session::Session, tenant: Option<&str>) {
+        let tenant = self.tenant_or_default(tenant);
+        let config = self.config.read();
+        if config.budgets.enabled {
+            session
+                .agent
+                .set_session_budget(config.budgets.session_budget(&tenant));
+        }
+    }
+
+    /// Set the output guardrails
+    pub fn with_guardrails(mut self, guardrails: Arc<Guardrails>) -> Self {
+        self.guardrails = Some(guardrails);
+        self
+    }
+
+    /// Apply the output guardrails to a session's agent
+    pub fn attach_guardrails(&self, session: &crate::session::Session) {
+        if let Some(ref guardrails) = self.guardrails {
+            session.agent.set_guardrails(guardrails.clone());
+        }
+    }
+
+    /// Set the spoken-duration budget for answers
+    pub fn with_response_governor(mut self, governor: Arc<ResponseGovernor>) -> Self {
+        self.response_governor = Some(governor);
+        self
+    }
+
+    /// Apply the spoken-duration budget to a session's agent
+    pub fn attach_response_governor(&self, session: &crate::session::Session) {
+        if let Some(ref governor) = self.response_governor {
+            session.agent.set_response_governor(governor.clone());
+        }
+    }
+
+    /// Set the abusive-speech policy
+    pub fn with_abuse_policy(mut self, policy: Arc<AbusePolicy>) -> Self {
+        self.abuse_policy = Some(policy);
+        self
+    }
+
+    /// Set the code-switch renderer applied to responses before TTS
+    pub fn with_code_switch(mut self, renderer: Arc<CodeSwitchRenderer>) -> Self {
+        self.code_switch = Some(renderer);
+        self
+    }
+
+    /// Run tool calls through timeouts, retries and circuit breakers
+    ///
+    /// Call after `with_audit_logger` so session tool calls are audited.
+    pub fn with_tool_execution(mut self, config: ToolExecutionConfig) -> Self {
+        let mut executor = ResilientToolExecutor::new(self.tools.clone(), config);
+        if let Some(ref logger) = self.audit_logger {
+            executor = executor.with_audit_logger(logger.clone());
+        }
+        self.tools = Arc::new(executor);
+        self
+    }
+
+    /// Set the admission controller
+    pub fn with_admission(mut self, admission: Arc<AdmissionController>) -> Self {
+        self.admission = admission;
+        self
+    }
+
+    /// Share STT/TTS worker pools across sessions
+    pub fn with_worker_pools(self, stt_pool: Option<SttPool>, tts_pool: Option<TtsPool>) -> Self {
+        self.models.set_initial(|models| {
+            models.stt_pool = stt_pool;
+            models.tts_pool = tts_pool;
+        });
+        self
+    }
+
+    /// Set the model registry the startup models were verified against
+    pub fn with_model_registry(mut self, registry: Arc<ModelRegistry>) -> Self {
+        self.models
+            .set_initial(|models| models.versions = registry.versions());
+        self.model_registry = Some(registry);
         self
     }
 
+    /// Record the model versions a session runs on with its metadata
+    pub fn attach_model_versions(&self, session: &crate::session::Session) {
+        session.set_model_versions(self.models.pin(&session.id).models.versions.clone());
+    }
+
+    /// Share a pronunciation lexicon across sessions
+    pub fn with_lexicon(mut self, lexicon: Arc<PronunciationLexicon>) -> Self {
+        self.lexicon = Some(lexicon);
+        self
+    }
+
+    /// Voice pipeline config for a session, wired to the shared pools of
+    /// the model generation it is pinned to
+    pub fn pipeline_config(&self, session_id: &str) -> PipelineConfig {
+        let models = self.models.pin(session_id);
+        let style = self.speech_style(&SessionOverrides::default());
+        let settings = self.config.read();
+        let mut config = PipelineConfig {
+            stt_pool: models.models.stt_pool.clone(),
+            tts_pool: models.models.tts_pool.clone(),
+            lexicon: self.lexicon.clone(),
+            denoise_budget_ms: settings.pipeline.audio.denoiser.latency_budget_ms,
+            ..Default::default()
+        };
+        config.tts.speaking_rate = style.rate;
+        config.tts.pitch = style.pitch;
+        config.tts.sentence_pause_ms = style.sentence_pause_ms;
+        let tts = &settings.pipeline.tts;
+        config.tts.quantization = tts.quantization;
+        let tts_processor = &mut config.processors.tts_processor;
+        tts_processor.avatar_cues = tts.avatar_cues;
+        tts_processor.parallel_synthesis = tts.lookahead_sentences > 0;
+        tts_processor.max_queue_size = tts.lookahead_sentences.max(1);
+        config
+    }
+
+    /// Speaking rate, pitch and sentence pause for a session
+    ///
+    /// Session overrides win over the deployment's TTS settings.
+    pub fn speech_style(&self, overrides: &SessionOverrides) -> SpeechStyle {
+        let tts = &self.config.read().pipeline.tts;
+        SpeechStyle {
+            rate: overrides.tts_rate.unwrap_or(tts.rate),
+            pitch: SpeechStyle::pitch_from_semitones(overrides.tts_pitch.unwrap_or(tts.pitch)),
+            sentence_pause_ms: overrides.tts_sentence_pause_ms.unwrap_or(tts.sentence_pause_ms),
+        }
+    }
+
+    /// Set the degradation manager
+    pub fn with_degradation(mut self, degradation: Arc<DegradationManager>) -> Self {
+        self.degradation = degradation;
+        self
+    }
+
+    /// Apply the abusive-speech policy to a session's agent
+    pub fn attach_abuse_policy(&self, session: &crate::session::Session) {
+        if let Some(ref policy) = self.abuse_policy {
+            session.agent.set_abuse_policy(policy.clone());
+        }
+    }
+
+    /// Publish a session's domain events to the shared bus
+    pub fn attach_event_bus(&self, session: &crate::session::Session) {
+        session.agent.set_event_bus(self.events.clone());
+        self.events.publish(
+            &session.id,
+            DomainEvent::SessionStarted {
+                language: session.agent.user_language().code().to_string(),
+            },
+        );
+    }
+
+    /// Route a session's LLM calls through the shared router
+    pub fn attach_llm_router(&self, session: &crate::session::Session) {
+        if let Some(ref router) = self.models.pin(&session.id).models.llm_router {
+            session.agent.set_language_model(router.clone());
+        }
+    }
+
+    /// Wire the shared embedder and backend into a session's archival memory
+    ///
+    /// Restores the session's notes from the backend in the background if
+    /// this session ID was active before a restart.
+    pub fn attach_archival_memory(&self, session: &crate::session::Session) {
+        let memory = session.agent.conversation().agentic_memory().clone();
+        if let Some(ref embedder) = self.archival_embedder {
+            memory.archival.set_embedder(embedder.clone());
+        }
+        let Some(ref backend) = self.archival_backend else {
+            return;
+        };
+        memory.archival.set_backend(backend.clone());
+
+        let session_id = session.id.clone();
+        let limit = self.config.read().archival.hydrate_limit;
+        tokio::spawn(async move {
+            match memory.archival.hydrate(&session_id, limit).await {
+                Ok(0) => {},
+                Ok(loaded) => {
+                    tracing::info!(session_id = %session_id, loaded, "Restored archival memory");
+                },
+                Err(e) => {
+                    tracing::warn!(session_id = %session_id, error = %e, "Failed to restore archival memory");
+                },
+            }
+        });
+    }
+
+    /// Resolve the caller's identity and preload prior-session facts into the agent
+    ///
+    /// Links the session to the customer's identity so later sessions on any
+    /// channel can pick up from here. Returns whether the customer is returning.
+    pub async fn resolve_customer(
+        &self,
+        session: &crate::session::Session,
+        phone: &str,
+        channel: voice_agent_core::Channel,
+    ) -> Result<bool, crate::ServerError> {
+        let identity = self
+            .identity_store
+            .resolve(phone, &session.id, channel)
+            .await
+            .map_err(|e| crate::ServerError::Persistence(e.to_string()))?
+            .ok_or_else(|| {
+                crate::ServerError::InvalidRequest("Invalid customer phone number".to_string())
+            })?;
+
+        session.set_customer_id(identity.customer_id.clone());
+        let returning = identity.is_returning(&session.id);
+        if returning {
+            session.agent.preload_customer(&identity);
+
+            let limit = self.config.read().archival.hydrate_limit;
+            match session
+                .agent
+                .load_customer_memory(&identity.customer_id, limit)
+                .await
+            {
+                Ok(restored) => {
+                    tracing::debug!(session_id = %session.id, restored, "Loaded customer memory");
+                },
+                Err(e) => {
+                    tracing::warn!(session_id = %session.id, error = %e, "Failed to load customer memory");
+                },
+            }
+        }
+        Ok(returning)
+    }
+
+    /// Save what was learned in this session back to the customer's identity
+    ///
+    /// Also consolidates durable facts into the customer's archival memory.
+    /// No-op for sessions without a resolved customer.
+    pub async fn save_customer(
+        &self,
+        session: &crate::session::Session,
+    ) -> Result<(), crate::ServerError> {
+        let Some(customer_id) = session.customer_id() else {
+            return Ok(());
+        };
+
+        // Promote durable facts into the customer's long-term memory
+        session.agent.consolidate_customer_memory(&customer_id);
+
+        let store_err = |e: voice_agent_persistence::PersistenceError| {
+            crate::ServerError::Persistence(e.to_string())
+        };
+        let identity = self.identity_store.get(&customer_id).await.map_err(store_err)?;
+        let Some(mut identity) = identity else {
+            return Ok(());
+        };
+
+        let agent = &session.agent;
+        if let Some(name) = agent.personalization_context().customer_name {
+            identity.name = Some(name);
+        }
+        identity.preferred_language = Some(agent.config().language.clone());
+        identity.merge_facts(agent.customer_facts());
+        identity.last_goal = Some(agent.dialogue_goal());
+        identity.last_seen = chrono::Utc::now();
+
+        self.identity_store.upsert(&identity).await.map_err(store_err)
+    }
+
+    /// Wrap up a session that was removed or expired
+    ///
+    /// Assigns its disposition code and saves it with the session metadata,
+    /// records the session's outcome for analytics, writes its last turn's
+    /// usage, drops its journal, releases its model generation and publishes `SessionEnded` to the
+    /// event bus.
+    pub async fn finalize_session(&self, session: &crate::session::Session) {
+        if let Some(ref classifier) = self.disposition {
+            session.agent.classify_disposition(classifier).await;
+            if let Err(e) = self.persist_session(session).await {
+                tracing::warn!(
+                    session_id = %session.id,
+                    error = %e,
+                    "Failed to save disposition"
+                );
+            }
+        }
+        if let Err(e) = self.record_outcome(session).await {
+            tracing::warn!(
+                session_id = %session.id,
+                error = %e,
+                "Failed to record session outcome"
+            );
+        }
+        if let Err(e) = session.agent.flush_usage().await {
+            tracing::warn!(
+                session_id = %session.id,
+                error = %e,
+                "Failed to record session usage"
+            );
+        }
+        // The call ended normally; nothing left to recover
+        if let Some(ref journal) = self.journal {
+            if let Err(e) = journal.truncate(&session.id).await {
+                tracing::warn!(
+                    session_id = %session.id,
+                    error = %e,
+                    "Failed to drop session journal"
+                );
+            }
+        }
+        self.models.release(&session.id);
+        let reason = session
+            .agent
+            .disposition()
+            .map(|d| d.code)
+            .unwrap_or_else(|| "closed".to_string());
+        self.events.publish(
+            &session.id,
+            DomainEvent::SessionEnded {
+                reason,
+                duration_secs: session.created_at.elapsed().as_secs(),
+            },
+        );
+    }
+
+    /// Record how a finished session went for conversation analytics
+    ///
+    /// No-op when analytics is disabled.
+    pub async fn record_outcome(
+        &self,
+        session: &crate::session::Session,
+    ) -> Result<(), crate::ServerError> {
+        let Some(ref analytics) = self.analytics else {
+            return Ok(());
+        };
+        analytics
+            .record_outcome(&session.outcome())
+            .await
+            .map_err(|e| crate::ServerError::Persistence(e.to_string()))
+    }
+
     /// P2 FIX: Log an audit event for RBI compliance
     ///
     /// Returns Ok(()) if logger is not configured (noop).
@@ -342,6 +1065,38 @@ impl AppState {
         Ok(())
     }
 
+    /// Load new versions of `components` alongside the models in service
+    ///
+    /// With a model registry, the manifest is re-read and its artifacts
+    /// fetched and verified first. Returns once the reload has started. When
+    /// loading succeeds the new generation takes new sessions and the old
+    /// one drains; follow progress with `models.status()`.
+    pub fn reload_models(&self, components: Vec<ModelComponent>) -> Result<(), String> {
+        self.models.begin_reload(&components)?;
+        let config = self.config.read().clone();
+        let models = self.models.clone();
+        let registry = self.model_registry.clone();
+        tokio::spawn(async move {
+            let mut base = models.active().models.clone();
+            if let Some(ref registry) = registry {
+                match registry.refresh().await {
+                    Ok(versions) => base.versions = versions,
+                    Err(e) => {
+                        let _ = models.finish_reload(Err(e));
+                        return;
+                    },
+                }
+            }
+            let loaded =
+                tokio::task::spawn_blocking(move || ModelSet::load(&config, &components, &base))
+                    .await
+                    .unwrap_or_else(|e| Err(format!("model loading panicked: {}", e)));
+            // Failures are recorded in the model status
+            let _ = models.finish_reload(loaded);
+        });
+        Ok(())
+    }
+
     /// Get a read guard to the current configuration
     pub fn get_config(&self) -> parking_lot::RwLockReadGuard<'_, Settings> {
         self.config.read()
@@ -385,12 +1140,13 @@ impl AppState {
 
     /// P2 FIX: Recover active sessions on server restart
     ///
-    /// Loads session metadata from persistent storage and logs recoverable sessions.
-    /// Note: Full agent state recovery requires conversation history serialization
-    /// which is not implemented. This method provides visibility into sessions
-    /// that were active before restart.
+    /// Sessions that were active before the restart are recreated under
+    /// their old IDs and their journals replayed, so callers reconnecting
+    /// continue the conversation where it stopped. Without a journal the
+    /// sessions are only logged. Runtime overrides are not journaled; a
+    /// restored session runs with the defaults.
     ///
-    /// Returns the count of sessions found (not fully restored).
+    /// Returns the count of sessions restored (or found, without a journal).
     pub async fn recover_sessions(&self) -> Result<usize, crate::ServerError> {
         if !self.is_distributed_sessions() {
             tracing::debug!("Session recovery skipped: not using distributed session store");
@@ -407,12 +1163,14 @@ impl AppState {
 
                 if active_sessions.is_empty() {
                     tracing::info!("No active sessions to recover");
-                } else {
-                    tracing::info!(
-                        count = active_sessions.len(),
-                        "Found recoverable sessions from previous run"
-                    );
+                    return Ok(0);
+                }
+                tracing::info!(
+                    count = active_sessions.len(),
+                    "Found recoverable sessions from previous run"
+                );
 
+                if self.journal.is_none() {
                     // Log details of each recoverable session
                     for session in &active_sessions {
                         tracing::info!(
@@ -424,9 +1182,16 @@ impl AppState {
                             "Recoverable session found"
                         );
                     }
+                    return Ok(active_sessions.len());
                 }
 
-                Ok(active_sessions.len())
+                let mut restored = 0;
+                for recoverable in &active_sessions {
+                    if self.restore_session(recoverable).await {
+                        restored += 1;
+                    }
+                }
+                Ok(restored)
             },
             Err(e) => {
                 tracing::warn!(error = %e, "Failed to query active sessions for recovery");
@@ -434,4 +1199,64 @@ impl AppState {
             },
         }
     }
+
+    /// Recreate one session and replay its journal, returning whether any
+    /// turns were restored
+    async fn restore_session(&self, recoverable: &crate::session::RecoverableSession) -> bool {
+        let mut config = voice_agent_agent::AgentConfig::default();
+        config.language = recoverable.language.clone();
+
+        let session = match self.sessions.restore(
+            &recoverable.session_id,
+            config,
+            self.vector_store.clone(),
+            Some(self.tools.clone()),
+            self.master_domain_config.clone(),
+        ) {
+            Ok(session) => session,
+            Err(e) => {
+                tracing::warn!(
+                    session_id = %recoverable.session_id,
+                    error = %e,
+                    "Session not restored"
+                );
+                return false;
+            },
+        };
+        self.attach_archival_memory(&session);
+        self.attach_knowledge_base(&session);
+        self.attach_llm_router(&session);
+        self.attach_response_cache(&session);
+        self.attach_guardrails(&session);
+        self.attach_response_governor(&session);
+        self.attach_abuse_policy(&session);
+        self.attach_journal(&session);
+        self.attach_usage(&session, None);
+        self.attach_budget(&session, None);
+        self.attach_model_versions(&session);
+        session.agent.set_event_bus(self.events.clone());
+
+        // The session stays registered either way, so a caller reconnecting
+        // with its ID is not turned away
+        match session.agent.recover_from_journal().await {
+            Ok(0) => false,
+            Ok(turns) => {
+                tracing::info!(
+                    session_id = %session.id,
+                    turns,
+                    stage = session.agent.stage().as_str(),
+                    "Session recovered from journal"
+                );
+                true
+            },
+            Err(e) => {
+                tracing::warn!(
+                    session_id = %session.id,
+                    error = %e,
+                    "Failed to replay session journal"
+                );
+                false
+            },
+        }
+    }
 }
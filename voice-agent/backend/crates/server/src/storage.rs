@@ -0,0 +1,86 @@
+//! Persistence Initialization
+//!
+//! Connects the persistence layer selected by `persistence.backend`, with
+//! asset tiers from the domain config. Shared by the server and `voicectl`.
+
+use std::sync::Arc;
+
+use voice_agent_config::{PersistenceBackend, ScyllaConsistency, Settings};
+
+fn scylla_consistency(consistency: ScyllaConsistency) -> voice_agent_persistence::Consistency {
+    use voice_agent_persistence::Consistency;
+    match consistency {
+        ScyllaConsistency::One => Consistency::One,
+        ScyllaConsistency::LocalOne => Consistency::LocalOne,
+        ScyllaConsistency::Quorum => Consistency::Quorum,
+        ScyllaConsistency::LocalQuorum => Consistency::LocalQuorum,
+        ScyllaConsistency::EachQuorum => Consistency::EachQuorum,
+        ScyllaConsistency::All => Consistency::All,
+    }
+}
+
+/// Initialize the persistence layer with config-driven tier definitions
+///
+/// Connects to ScyllaDB or, when the server is built with the `sqlite` or
+/// `postgres` feature, to the configured SQL database.
+pub async fn init_persistence(
+    config: &Settings,
+    domain_config: Arc<voice_agent_config::domain::MasterDomainConfig>,
+) -> Result<voice_agent_persistence::PersistenceLayer, voice_agent_persistence::PersistenceError> {
+    // Extract tier definitions from domain config via ToolsDomainView
+    let tools_view = voice_agent_config::ToolsDomainView::new(domain_config);
+    let base_price = tools_view.asset_price_per_unit();
+    let tier_data = tools_view.quality_tiers_full();
+    let tiers: Vec<voice_agent_persistence::TierDefinition> = tier_data
+        .into_iter()
+        .map(
+            |(code, factor, description)| voice_agent_persistence::TierDefinition {
+                code,
+                factor,
+                description,
+            },
+        )
+        .collect();
+
+    let persistence = &config.persistence;
+    match persistence.backend {
+        PersistenceBackend::Scylla => {},
+        #[cfg(any(feature = "sqlite", feature = "postgres"))]
+        PersistenceBackend::Sqlite | PersistenceBackend::Postgres => {
+            let sql_config = voice_agent_persistence::sql::SqlConfig {
+                url: persistence.sql.url.clone(),
+                max_connections: persistence.sql.max_connections,
+            };
+            return voice_agent_persistence::init_sql(sql_config, base_price, tiers).await;
+        },
+        #[cfg(not(any(feature = "sqlite", feature = "postgres")))]
+        PersistenceBackend::Sqlite | PersistenceBackend::Postgres => {
+            use voice_agent_persistence::PersistenceError;
+            let backend = persistence.backend.as_str();
+            return Err(PersistenceError::Connection(format!(
+                "{} backend requires the server built with the `{}` feature",
+                backend, backend
+            )));
+        },
+    }
+
+    let retry = &persistence.speculative_retry;
+    let speculative_retry = retry
+        .enabled
+        .then(|| voice_agent_persistence::SpeculativeRetry {
+            max_retries: retry.max_retries,
+            delay: std::time::Duration::from_millis(retry.delay_ms),
+        });
+    let scylla_config = voice_agent_persistence::ScyllaConfig {
+        hosts: persistence.scylla_hosts.clone(),
+        keyspace: persistence.keyspace.clone(),
+        replication_factor: persistence.replication_factor,
+        read_consistency: scylla_consistency(persistence.read_consistency),
+        write_consistency: scylla_consistency(persistence.write_consistency),
+        speculative_retry,
+        connections_per_shard: persistence.pool.connections_for(&persistence.keyspace),
+        prepared_cache_size: persistence.prepared_cache_size,
+    };
+
+    voice_agent_persistence::init(scylla_config, base_price, tiers).await
+}
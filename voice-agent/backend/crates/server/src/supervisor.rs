@@ -0,0 +1,344 @@
+//! Supervisor Monitoring
+//!
+//! Lets a human supervisor watch live calls and coach the agent:
+//! - `GET /api/supervisor/sessions` lists active sessions
+//! - `GET /ws/supervisor/:session_id` streams the live transcript
+//! - `POST /api/supervisor/sessions/:session_id/whisper` adds guidance to the
+//!   agent's prompt (also accepted as a `whisper` message on the stream)
+//!
+//! Whispers are never spoken to the customer.
+
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Json, Path, State,
+    },
+    http::StatusCode,
+    response::Response,
+};
+use futures::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use voice_agent_agent::{AgentEvent, ConversationEvent, ConversationState};
+
+use crate::session::Session;
+use crate::state::AppState;
+
+/// Messages on the supervisor stream
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SupervisorMessage {
+    /// Conversation so far, sent when the stream opens
+    Snapshot {
+        session_id: String,
+        stage: String,
+        turns: Vec<TranscriptTurn>,
+    },
+    /// A turn was added to the conversation
+    Transcript {
+        role: String,
+        content: String,
+    },
+    /// The conversation moved to another stage
+    Stage {
+        stage: String,
+    },
+    /// Tool the agent called
+    ToolResult {
+        name: String,
+        success: bool,
+    },
+    /// Lead scoring asked for a human
+    Escalation {
+        trigger: String,
+        recommendation: String,
+    },
+    /// Warm transfer progress
+    Handoff {
+        handoff_id: String,
+        status: String,
+    },
+    /// Guidance added by a supervisor (sent by the client to whisper)
+    Whisper {
+        text: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        supervisor: Option<String>,
+    },
+    /// The conversation ended
+    Ended {
+        reason: String,
+    },
+    Error {
+        message: String,
+    },
+}
+
+/// One turn of the transcript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscriptTurn {
+    pub role: String,
+    pub content: String,
+}
+
+impl SupervisorMessage {
+    fn from_agent_event(event: AgentEvent) -> Option<Self> {
+        match event {
+            AgentEvent::ToolResult { name, success } => {
+                Some(SupervisorMessage::ToolResult { name, success })
+            },
+            AgentEvent::EscalationTriggered {
+                trigger,
+                recommendation,
+            } => Some(SupervisorMessage::Escalation {
+                trigger,
+                recommendation,
+            }),
+            AgentEvent::Handoff {
+                handoff_id, status, ..
+            } => Some(SupervisorMessage::Handoff {
+                handoff_id,
+                status: status.as_str().to_string(),
+            }),
+            AgentEvent::Whisper { text, supervisor } => {
+                Some(SupervisorMessage::Whisper { text, supervisor })
+            },
+            _ => None,
+        }
+    }
+
+    fn from_conversation_event(event: ConversationEvent) -> Option<Self> {
+        match event {
+            ConversationEvent::TurnAdded { role, content } => Some(SupervisorMessage::Transcript {
+                role: role.as_str().to_string(),
+                content,
+            }),
+            ConversationEvent::StageChanged { to, .. } => Some(SupervisorMessage::Stage {
+                stage: to.display_name().to_string(),
+            }),
+            ConversationEvent::Ended { reason } => Some(SupervisorMessage::Ended {
+                reason: format!("{:?}", reason),
+            }),
+            _ => None,
+        }
+    }
+
+    fn to_message(&self) -> Message {
+        Message::Text(serde_json::to_string(self).unwrap_or_default())
+    }
+}
+
+/// List active sessions
+///
+/// GET /api/supervisor/sessions
+pub async fn list_active_sessions(State(state): State<AppState>) -> Json<serde_json::Value> {
+    let sessions: Vec<serde_json::Value> = state
+        .sessions
+        .list()
+        .into_iter()
+        .filter_map(|id| state.sessions.get(&id))
+        .filter(|session| session.is_active())
+        .map(|session| {
+            let agent = &session.agent;
+            let lead_score = agent.last_lead_score();
+            let paused = agent.conversation().state() == ConversationState::Paused;
+            serde_json::json!({
+                "session_id": session.id,
+                "stage": agent.stage().display_name(),
+                "turn_count": agent.conversation().turn_count(),
+                "duration_secs": session.created_at.elapsed().as_secs(),
+                "idle_secs": session.last_activity.read().elapsed().as_secs(),
+                "language": agent.user_language().code(),
+                "lead_score": lead_score.as_ref().map(|s| s.total),
+                "lead_qualification": lead_score.map(|s| format!("{:?}", s.qualification)),
+                "paused": paused,
+                "flagged_abusive": agent.is_flagged_abusive(),
+                "whispers": agent.whispers().len(),
+            })
+        })
+        .collect();
+
+    Json(serde_json::json!({
+        "sessions": sessions,
+        "count": sessions.len(),
+    }))
+}
+
+/// Whisper request
+#[derive(Debug, Deserialize)]
+pub struct WhisperRequest {
+    text: String,
+    #[serde(default)]
+    supervisor: Option<String>,
+}
+
+/// Add guidance to a session's prompt
+///
+/// POST /api/supervisor/sessions/:session_id/whisper
+pub async fn whisper(
+    State(state): State<AppState>,
+    Path(session_id): Path<String>,
+    Json(request): Json<WhisperRequest>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let Some(session) = state.sessions.get(&session_id) else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({ "status": "not_found" })),
+        );
+    };
+    if request.text.trim().is_empty() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "status": "error", "message": "text is required" })),
+        );
+    }
+
+    session.agent.whisper(&request.text, request.supervisor);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "status": "ok",
+            "whispers": session.agent.whispers().len(),
+        })),
+    )
+}
+
+/// Drop all guidance for a session
+///
+/// DELETE /api/supervisor/sessions/:session_id/whisper
+pub async fn clear_whispers(
+    State(state): State<AppState>,
+    Path(session_id): Path<String>,
+) -> StatusCode {
+    match state.sessions.get(&session_id) {
+        Some(session) => {
+            session.agent.clear_whispers();
+            StatusCode::NO_CONTENT
+        },
+        None => StatusCode::NOT_FOUND,
+    }
+}
+
+/// Stream a session's live transcript
+///
+/// GET /ws/supervisor/:session_id
+pub async fn monitor(
+    ws: WebSocketUpgrade,
+    State(state): State<AppState>,
+    Path(session_id): Path<String>,
+) -> Result<Response, StatusCode> {
+    let session = state
+        .sessions
+        .get(&session_id)
+        .ok_or(StatusCode::NOT_FOUND)?;
+    Ok(ws.on_upgrade(move |socket| monitor_socket(socket, session)))
+}
+
+async fn monitor_socket(socket: WebSocket, session: Arc<Session>) {
+    let (sender, mut receiver) = socket.split();
+    let sender = Arc::new(tokio::sync::Mutex::new(sender));
+
+    // Subscribe before the snapshot so no turn falls in between
+    let mut agent_events = session.agent.subscribe();
+    let mut conversation_events = session.agent.conversation().subscribe();
+
+    let snapshot = SupervisorMessage::Snapshot {
+        session_id: session.id.clone(),
+        stage: session.agent.stage().display_name().to_string(),
+        turns: session
+            .agent
+            .conversation()
+            .get_messages()
+            .into_iter()
+            .map(|(role, content)| TranscriptTurn { role, content })
+            .collect(),
+    };
+    if sender
+        .lock()
+        .await
+        .send(snapshot.to_message())
+        .await
+        .is_err()
+    {
+        return;
+    }
+    tracing::info!(session_id = %session.id, "Supervisor monitoring started");
+
+    let forward_sender = sender.clone();
+    let forward_task = tokio::spawn(async move {
+        loop {
+            let message = tokio::select! {
+                event = agent_events.recv() => match event {
+                    Ok(event) => SupervisorMessage::from_agent_event(event),
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => None,
+                    Err(_) => break,
+                },
+                event = conversation_events.recv() => match event {
+                    Ok(event) => SupervisorMessage::from_conversation_event(event),
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => None,
+                    Err(_) => break,
+                },
+            };
+            if let Some(message) = message {
+                if forward_sender
+                    .lock()
+                    .await
+                    .send(message.to_message())
+                    .await
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        }
+    });
+
+    while let Some(Ok(message)) = receiver.next().await {
+        match message {
+            Message::Text(text) => match serde_json::from_str::<SupervisorMessage>(&text) {
+                // Echoed back through the agent's Whisper event
+                Ok(SupervisorMessage::Whisper { text, supervisor }) => {
+                    session.agent.whisper(&text, supervisor);
+                },
+                _ => {
+                    let error = SupervisorMessage::Error {
+                        message: "Only whisper messages are accepted".to_string(),
+                    };
+                    let _ = sender.lock().await.send(error.to_message()).await;
+                },
+            },
+            Message::Close(_) => break,
+            _ => {},
+        }
+    }
+
+    forward_task.abort();
+    tracing::info!(session_id = %session.id, "Supervisor monitoring stopped");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_supervisor_message_format() {
+        let message = SupervisorMessage::Transcript {
+            role: "user".to_string(),
+            content: "What is the rate?".to_string(),
+        };
+        let json = serde_json::to_value(&message).unwrap();
+        assert_eq!(json["type"], "transcript");
+        assert_eq!(json["role"], "user");
+
+        let inbound: SupervisorMessage =
+            serde_json::from_str(r#"{"type":"whisper","text":"Offer the doorstep option"}"#)
+                .unwrap();
+        assert!(matches!(
+            inbound,
+            SupervisorMessage::Whisper {
+                supervisor: None,
+                ..
+            }
+        ));
+    }
+}
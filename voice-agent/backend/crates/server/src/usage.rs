@@ -0,0 +1,192 @@
+//! Usage Accounting API
+//!
+//! Read-only views over per-turn usage (LLM tokens, STT seconds, TTS
+//! characters, tool and translation calls):
+//! - `GET /api/usage/daily` returns one row per day for a tenant; it takes
+//!   `from` and `to` (`YYYY-MM-DD`, default the last 7 days) and `tenant`
+//!   (default `all`)
+//! - `GET /api/sessions/:id/usage` returns a session's turns and totals
+
+use axum::{
+    extract::{Json, Path, Query, State},
+    http::StatusCode,
+};
+use chrono::{NaiveDate, Utc};
+use serde::Deserialize;
+
+use voice_agent_persistence::usage::ALL_TENANTS;
+use voice_agent_persistence::{UsageCounts, UsageStore};
+
+use crate::state::AppState;
+
+/// Days covered when `from` is omitted
+const DEFAULT_RANGE_DAYS: u64 = 7;
+
+/// Daily usage query parameters
+#[derive(Debug, Deserialize)]
+pub struct UsageQuery {
+    #[serde(default)]
+    from: Option<NaiveDate>,
+    #[serde(default)]
+    to: Option<NaiveDate>,
+    #[serde(default)]
+    tenant: Option<String>,
+}
+
+type ApiResponse = (StatusCode, Json<serde_json::Value>);
+
+fn error(status: StatusCode, message: impl ToString) -> ApiResponse {
+    (
+        status,
+        Json(serde_json::json!({ "status": "error", "message": message.to_string() })),
+    )
+}
+
+fn usage_store(state: &AppState) -> Result<&dyn UsageStore, ApiResponse> {
+    state.usage.as_deref().ok_or_else(|| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(serde_json::json!({
+                "status": "disabled",
+                "message": "Usage accounting requires persistence"
+            })),
+        )
+    })
+}
+
+/// Daily usage for a tenant
+///
+/// GET /api/usage/daily?from=...&to=...&tenant=...
+pub async fn daily(State(state): State<AppState>, Query(query): Query<UsageQuery>) -> ApiResponse {
+    let store = match usage_store(&state) {
+        Ok(store) => store,
+        Err(response) => return response,
+    };
+
+    let to = query.to.unwrap_or_else(|| Utc::now().date_naive());
+    let from = query
+        .from
+        .unwrap_or_else(|| to - chrono::Days::new(DEFAULT_RANGE_DAYS - 1));
+    let tenant = query
+        .tenant
+        .filter(|t| !t.is_empty())
+        .unwrap_or_else(|| ALL_TENANTS.to_string());
+
+    let days = match store.daily_usage(from, to, &tenant).await {
+        Ok(days) => days,
+        Err(voice_agent_persistence::PersistenceError::InvalidData(message)) => {
+            return error(StatusCode::BAD_REQUEST, message);
+        },
+        Err(e) => {
+            tracing::error!("Failed to load daily usage: {}", e);
+            return error(StatusCode::INTERNAL_SERVER_ERROR, e);
+        },
+    };
+    let mut total = UsageCounts::default();
+    for day in &days {
+        total.add(&day.counts);
+    }
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "from": from,
+            "to": to,
+            "tenant": tenant,
+            "total": total,
+            "days": days,
+        })),
+    )
+}
+
+/// A session's recorded turns and totals
+///
+/// GET /api/sessions/:id/usage
+///
+/// For a session still in progress, `live` also counts the turn that has
+/// not been written yet.
+pub async fn session(State(state): State<AppState>, Path(id): Path<String>) -> ApiResponse {
+    let store = match usage_store(&state) {
+        Ok(store) => store,
+        Err(response) => return response,
+    };
+
+    let turns = match store.session_usage(&id).await {
+        Ok(turns) => turns,
+        Err(e) => {
+            tracing::error!(session_id = %id, "Failed to load session usage: {}", e);
+            return error(StatusCode::INTERNAL_SERVER_ERROR, e);
+        },
+    };
+    let live = state.sessions.get(&id).map(|s| s.agent.session_usage());
+    if turns.is_empty() && live.is_none() {
+        return error(StatusCode::NOT_FOUND, "Session not found");
+    }
+    let mut total = UsageCounts::default();
+    for turn in &turns {
+        total.add(&turn.counts);
+    }
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "session_id": id,
+            "tenant": turns.first().map(|t| t.tenant.clone()),
+            "total": total,
+            "live": live,
+            "turns": turns,
+        })),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use voice_agent_config::Settings;
+    use voice_agent_persistence::InMemoryUsageStore;
+
+    #[tokio::test]
+    async fn test_usage_recorded_per_turn_and_tenant() {
+        let store = Arc::new(InMemoryUsageStore::new());
+        let state = AppState::new(Settings::default()).with_usage_store(store.clone());
+
+        let session = state
+            .sessions
+            .create(Default::default(), state.master_domain_config.clone())
+            .unwrap();
+        state.attach_usage(&session, Some("acme"));
+        session.agent.record_stt_seconds(2.5);
+        session
+            .agent
+            .process("What is the gold rate today?")
+            .await
+            .unwrap();
+        session
+            .agent
+            .record_tts_characters("Today's rate is ₹7,200 a gram.");
+        session.agent.flush_usage().await.unwrap();
+
+        let (status, Json(body)) =
+            super::session(State(state.clone()), Path(session.id.clone())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["tenant"], "acme");
+        assert_eq!(body["turns"].as_array().unwrap().len(), 1);
+        assert_eq!(body["total"]["stt_seconds"], 2.5);
+        assert_eq!(body["total"]["tts_characters"], 30);
+
+        let day = Utc::now().date_naive();
+        voice_agent_persistence::usage::roll_up_usage_day(store.as_ref(), day)
+            .await
+            .unwrap();
+        let query = UsageQuery {
+            from: None,
+            to: None,
+            tenant: Some("acme".to_string()),
+        };
+        let (status, Json(body)) = daily(State(state), Query(query)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["days"][0]["sessions"], 1);
+        assert_eq!(body["total"]["stt_seconds"], 2.5);
+    }
+}
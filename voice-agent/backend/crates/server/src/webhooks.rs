@@ -0,0 +1,435 @@
+//! Webhook Notifications
+//!
+//! Turns business events from the shared `EventBus` into signed JSON POSTs,
+//! for banks that integrate over HTTP rather than consuming the event sink:
+//! lead captured, appointment booked, escalation requested and call
+//! completed (session ended). `WebhookSubscriber` queues one delivery per
+//! subscribed endpoint in a bounded buffer; a background worker sends each
+//! with retries and backoff and records every attempt in the delivery log.
+//!
+//! Body: `{ "event_id": "...", "event_type": "lead_captured", "session_id":
+//! "...", "occurred_at": "<RFC 3339>", "data": { ... } }`, where `data` is
+//! the tool's JSON result (or the end reason and duration of a call).
+//! Headers: `X-Webhook-Event`, `X-Webhook-Id` (the event ID, shared by every
+//! endpoint and retry), `X-Webhook-Timestamp` (Unix seconds) and
+//! `X-Webhook-Signature: sha256=<hex>`, the HMAC-SHA256 of
+//! `{timestamp}.{body}` keyed with the endpoint's secret. Receivers should
+//! reject stale timestamps and deduplicate by event ID.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use serde_json::{json, Value};
+use sha2::Sha256;
+use tokio::sync::{mpsc, Semaphore};
+use uuid::Uuid;
+use voice_agent_config::{WebhookConfig, WebhookEndpoint};
+use voice_agent_core::{DomainEvent, EventEnvelope, EventSubscriber};
+use voice_agent_persistence::{WebhookDelivery, WebhookDeliveryStatus, WebhookDeliveryStore};
+
+/// Delay before the first retry (doubled per attempt)
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Upper bound on the retry delay
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Deliveries in flight at once, so one slow endpoint does not hold up the rest
+const MAX_CONCURRENT_DELIVERIES: usize = 16;
+
+/// Webhook event name and `data` for a domain event, or `None` when the
+/// event is not delivered to webhooks
+pub fn webhook_event(event: &DomainEvent) -> Option<(&'static str, Value)> {
+    match event {
+        DomainEvent::LeadCaptured { details } => Some(("lead_captured", details.clone())),
+        DomainEvent::AppointmentBooked { details } => Some(("appointment_booked", details.clone())),
+        DomainEvent::EscalationRequested { details } => {
+            Some(("escalation_requested", details.clone()))
+        },
+        DomainEvent::SessionEnded {
+            reason,
+            duration_secs,
+        } => Some((
+            "call_completed",
+            json!({ "reason": reason, "duration_secs": duration_secs }),
+        )),
+        _ => None,
+    }
+}
+
+/// `X-Webhook-Signature` value for a body sent at `timestamp`
+pub fn sign(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC takes keys of any size");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body.as_bytes());
+    format!("sha256={:x}", mac.finalize().into_bytes())
+}
+
+/// Sends webhook requests
+#[async_trait]
+pub trait WebhookTransport: Send + Sync {
+    /// POST `body` with `headers`, returning the HTTP status, or an error
+    /// when no response arrived
+    async fn post(
+        &self,
+        url: &str,
+        headers: &[(&'static str, String)],
+        body: &str,
+    ) -> Result<u16, String>;
+}
+
+/// `WebhookTransport` over reqwest
+pub struct HttpWebhookTransport {
+    client: reqwest::Client,
+}
+
+impl HttpWebhookTransport {
+    pub fn new(timeout: Duration) -> Result<Self, String> {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .map_err(|e| e.to_string())?;
+        Ok(Self { client })
+    }
+}
+
+#[async_trait]
+impl WebhookTransport for HttpWebhookTransport {
+    async fn post(
+        &self,
+        url: &str,
+        headers: &[(&'static str, String)],
+        body: &str,
+    ) -> Result<u16, String> {
+        let mut request = self
+            .client
+            .post(url)
+            .header("content-type", "application/json")
+            .body(body.to_string());
+        for (name, value) in headers {
+            request = request.header(*name, value);
+        }
+        let response = request.send().await.map_err(|e| e.to_string())?;
+        Ok(response.status().as_u16())
+    }
+}
+
+/// A delivery waiting for the worker
+struct Job {
+    endpoint: Arc<WebhookEndpoint>,
+    delivery: WebhookDelivery,
+}
+
+/// Queues business events for the configured webhook endpoints
+pub struct WebhookSubscriber {
+    endpoints: Vec<Arc<WebhookEndpoint>>,
+    log: Arc<dyn WebhookDeliveryStore>,
+    tx: mpsc::Sender<Job>,
+}
+
+impl WebhookSubscriber {
+    /// Create the subscriber and start its delivery worker
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn spawn(
+        config: &WebhookConfig,
+        transport: Arc<dyn WebhookTransport>,
+        log: Arc<dyn WebhookDeliveryStore>,
+    ) -> Self {
+        let (tx, mut rx) = mpsc::channel::<Job>(config.buffer_size.max(1));
+        let max_attempts = config.max_attempts.max(1);
+        let worker_log = log.clone();
+
+        tokio::spawn(async move {
+            let in_flight = Arc::new(Semaphore::new(MAX_CONCURRENT_DELIVERIES));
+            while let Some(job) = rx.recv().await {
+                let Ok(permit) = in_flight.clone().acquire_owned().await else {
+                    break;
+                };
+                let transport = transport.clone();
+                let log = worker_log.clone();
+                tokio::spawn(async move {
+                    deliver_with_retry(transport.as_ref(), log.as_ref(), job, max_attempts).await;
+                    drop(permit);
+                });
+            }
+        });
+
+        Self {
+            endpoints: config.endpoints.iter().cloned().map(Arc::new).collect(),
+            log,
+            tx,
+        }
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for WebhookSubscriber {
+    fn name(&self) -> &str {
+        "webhooks"
+    }
+
+    fn accepts(&self, event: &DomainEvent) -> bool {
+        webhook_event(event).is_some()
+    }
+
+    async fn handle(&self, envelope: &EventEnvelope) {
+        let Some((event_type, data)) = webhook_event(&envelope.event) else {
+            return;
+        };
+        let event_id = Uuid::new_v4().to_string();
+        let body = json!({
+            "event_id": event_id,
+            "event_type": event_type,
+            "session_id": envelope.session_id,
+            "occurred_at": envelope.occurred_at.to_rfc3339(),
+            "data": data,
+        })
+        .to_string();
+
+        for endpoint in self.endpoints.iter().filter(|e| e.wants(event_type)) {
+            let delivery = WebhookDelivery::new(
+                &endpoint.name,
+                event_type,
+                &event_id,
+                &envelope.session_id,
+                body.clone(),
+            );
+            persist(self.log.as_ref(), &delivery).await;
+            let job = Job {
+                endpoint: endpoint.clone(),
+                delivery,
+            };
+            if let Err(mpsc::error::TrySendError::Full(job)) = self.tx.try_send(job) {
+                crate::metrics::record_webhook("dropped");
+                tracing::warn!(
+                    endpoint = %endpoint.name,
+                    event = event_type,
+                    session_id = %envelope.session_id,
+                    "Webhook buffer full, delivery dropped"
+                );
+                let mut delivery = job.delivery;
+                delivery.status = WebhookDeliveryStatus::Failed;
+                delivery.last_error = Some("delivery buffer full".to_string());
+                delivery.updated_at = Utc::now();
+                persist(self.log.as_ref(), &delivery).await;
+            }
+        }
+    }
+}
+
+/// Send one delivery, retrying network errors, 408, 429 and 5xx responses
+/// with backoff, and log the outcome of every attempt
+async fn deliver_with_retry(
+    transport: &dyn WebhookTransport,
+    log: &dyn WebhookDeliveryStore,
+    job: Job,
+    max_attempts: u32,
+) {
+    let Job {
+        endpoint,
+        mut delivery,
+    } = job;
+
+    for attempt in 1..=max_attempts {
+        let timestamp = Utc::now().timestamp();
+        let headers = [
+            ("x-webhook-event", delivery.event_type.clone()),
+            ("x-webhook-id", delivery.event_id.clone()),
+            ("x-webhook-timestamp", timestamp.to_string()),
+            (
+                "x-webhook-signature",
+                sign(&endpoint.secret, timestamp, &delivery.payload_json),
+            ),
+        ];
+        let result = transport
+            .post(&endpoint.url, &headers, &delivery.payload_json)
+            .await;
+
+        delivery.attempts = attempt as i32;
+        delivery.updated_at = Utc::now();
+        let retryable = match result {
+            Ok(status) => {
+                delivery.response_status = Some(status as i32);
+                if (200..300).contains(&status) {
+                    delivery.status = WebhookDeliveryStatus::Delivered;
+                    delivery.last_error = None;
+                    crate::metrics::record_webhook("delivered");
+                    persist(log, &delivery).await;
+                    return;
+                }
+                delivery.last_error = Some(format!("HTTP {}", status));
+                status == 408 || status == 429 || status >= 500
+            },
+            Err(e) => {
+                delivery.response_status = None;
+                delivery.last_error = Some(e);
+                true
+            },
+        };
+
+        if !retryable || attempt == max_attempts {
+            break;
+        }
+        delivery.status = WebhookDeliveryStatus::Retrying;
+        persist(log, &delivery).await;
+        let backoff = INITIAL_BACKOFF
+            .saturating_mul(1 << (attempt - 1).min(16))
+            .min(MAX_BACKOFF);
+        tracing::debug!(
+            endpoint = %endpoint.name,
+            event = %delivery.event_type,
+            attempt,
+            error = ?delivery.last_error,
+            "Webhook delivery failed, retrying"
+        );
+        tokio::time::sleep(backoff).await;
+    }
+
+    delivery.status = WebhookDeliveryStatus::Failed;
+    crate::metrics::record_webhook("failed");
+    tracing::warn!(
+        endpoint = %endpoint.name,
+        event = %delivery.event_type,
+        attempts = delivery.attempts,
+        error = ?delivery.last_error,
+        "Webhook delivery failed"
+    );
+    persist(log, &delivery).await;
+}
+
+/// Record a delivery's latest state; a logging failure does not stop delivery
+async fn persist(log: &dyn WebhookDeliveryStore, delivery: &WebhookDelivery) {
+    if let Err(e) = log.upsert(delivery).await {
+        tracing::warn!(
+            delivery_id = %delivery.delivery_id,
+            error = %e,
+            "Failed to log webhook delivery"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parking_lot::Mutex;
+    use voice_agent_core::EventBus;
+    use voice_agent_persistence::InMemoryWebhookDeliveryStore;
+
+    /// Answers with `statuses` in turn (200 once they run out) and records requests
+    #[derive(Default)]
+    struct RecordingTransport {
+        statuses: Mutex<Vec<u16>>,
+        requests: Mutex<Vec<(Vec<(&'static str, String)>, String)>>,
+        done: tokio::sync::Notify,
+    }
+
+    #[async_trait]
+    impl WebhookTransport for RecordingTransport {
+        async fn post(
+            &self,
+            _url: &str,
+            headers: &[(&'static str, String)],
+            body: &str,
+        ) -> Result<u16, String> {
+            self.requests
+                .lock()
+                .push((headers.to_vec(), body.to_string()));
+            let status = self.statuses.lock().pop().unwrap_or(200);
+            if status == 200 {
+                self.done.notify_one();
+            }
+            Ok(status)
+        }
+    }
+
+    fn config() -> WebhookConfig {
+        WebhookConfig {
+            enabled: true,
+            endpoints: vec![WebhookEndpoint {
+                name: "bank".to_string(),
+                url: "https://bank.example.com/hooks".to_string(),
+                secret: "s3cret".to_string(),
+                events: vec!["lead_captured".to_string()],
+            }],
+            ..WebhookConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_signature_is_hmac_of_timestamp_and_body() {
+        let signature = sign("key", 1700000000, "{}");
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"key").unwrap();
+        mac.update(b"1700000000.{}");
+        assert_eq!(
+            signature,
+            format!("sha256={:x}", mac.finalize().into_bytes())
+        );
+        assert_ne!(signature, sign("other", 1700000000, "{}"));
+        assert_eq!(signature.len(), "sha256=".len() + 64);
+    }
+
+    #[tokio::test]
+    async fn test_delivers_subscribed_events_with_retry() {
+        let transport = Arc::new(RecordingTransport::default());
+        transport.statuses.lock().push(503);
+        let log = Arc::new(InMemoryWebhookDeliveryStore::new());
+
+        let bus = EventBus::default();
+        bus.attach(Arc::new(WebhookSubscriber::spawn(
+            &config(),
+            transport.clone(),
+            log.clone(),
+        )));
+        bus.publish(
+            "s1",
+            DomainEvent::SessionEnded {
+                reason: "hangup".to_string(),
+                duration_secs: 42,
+            },
+        );
+        bus.publish(
+            "s1",
+            DomainEvent::LeadCaptured {
+                details: json!({ "success": true, "lead_id": "L1" }),
+            },
+        );
+
+        tokio::time::timeout(Duration::from_secs(5), transport.done.notified())
+            .await
+            .expect("webhook delivered");
+        let requests = transport.requests.lock().clone();
+        assert_eq!(requests.len(), 2);
+        let (headers, body) = &requests[1];
+        let body: Value = serde_json::from_str(body).unwrap();
+        assert_eq!(body["event_type"], "lead_captured");
+        assert_eq!(body["data"]["lead_id"], "L1");
+        let header = |name: &str| headers.iter().find(|(h, _)| *h == name).unwrap().1.clone();
+        let timestamp: i64 = header("x-webhook-timestamp").parse().unwrap();
+        assert_eq!(
+            header("x-webhook-signature"),
+            sign("s3cret", timestamp, &requests[1].1)
+        );
+
+        // The delivered status is logged just after the response
+        let mut delivered = false;
+        for _ in 0..50 {
+            let logged = log.list_for_day(Utc::now().date_naive(), 10).await.unwrap();
+            if logged
+                .first()
+                .is_some_and(|d| d.status == WebhookDeliveryStatus::Delivered)
+            {
+                assert_eq!(logged[0].attempts, 2);
+                assert_eq!(logged[0].response_status, Some(200));
+                delivered = true;
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        assert!(delivered);
+    }
+}
@@ -27,7 +27,7 @@ use std::sync::Arc;
 use tokio::sync::{mpsc, Mutex, RwLock};
 
 use voice_agent_core::{AudioFrame, Channels, SampleRate};
-use voice_agent_pipeline::{create_noise_suppressor, PipelineConfig, PipelineEvent, VoicePipeline};
+use voice_agent_pipeline::{create_denoiser, PipelineEvent, VoicePipeline};
 use voice_agent_transport::{
     IceCandidate, IceServer, Transport, TransportEvent, WebRtcConfig, WebRtcTransport,
 };
@@ -175,14 +175,16 @@ pub async fn handle_offer(
 
     // P1 FIX: Create voice pipeline for WebRTC audio processing
     // P0 FIX: Wire text processing (grammar, PII, compliance) to pipeline
-    // P2 FIX: Wire noise suppression for cleaner audio input
+    // Denoise inbound audio with the deployment's configured backend
+    let audio_config = state.config.read().pipeline.audio.clone();
     let noise_suppressor: Arc<dyn voice_agent_core::AudioProcessor> =
-        Arc::from(create_noise_suppressor(16000)); // 16kHz input
-    let pipeline = match VoicePipeline::simple(PipelineConfig::default()) {
+        Arc::from(create_denoiser(&audio_config));
+    let pipeline = match VoicePipeline::simple(state.pipeline_config(&session_id)) {
         Ok(p) => {
             let p = p
                 .with_text_processor(state.text_processing.clone())
                 .with_noise_suppressor(noise_suppressor);
+            p.set_speech_style(state.speech_style(session.overrides()));
             tracing::info!("Created voice pipeline with text processing and noise suppression for WebRTC session {}", session_id);
             Some(Arc::new(Mutex::new(p)))
         },
@@ -530,6 +532,17 @@ async fn spawn_webrtc_audio_processor(
                         "WebRTC final transcript, processing with agent"
                     );
 
+                    // Repeatedly mis-heard turns switch answers to the keypad
+                    session_for_pipeline
+                        .agent
+                        .record_stt_confidence(transcript.confidence);
+                    let audio_ms = transcript
+                        .end_time_ms
+                        .saturating_sub(transcript.start_time_ms);
+                    session_for_pipeline
+                        .agent
+                        .record_stt_seconds(audio_ms as f64 / 1000.0);
+
                     // Process through agent
                     if !text.trim().is_empty() {
                         match session_for_pipeline.agent.process(&text).await {
@@ -565,6 +578,14 @@ async fn spawn_webrtc_audio_processor(
                         "WebRTC turn state changed"
                     );
                 },
+                PipelineEvent::EndOfTurn(prediction) => {
+                    tracing::debug!(
+                        session_id = %session_id_for_pipeline,
+                        probability = prediction.probability,
+                        silence_ms = prediction.silence.as_millis() as u64,
+                        "WebRTC end of turn"
+                    );
+                },
                 PipelineEvent::TtsAudio {
                     samples,
                     text: _,
@@ -614,6 +635,34 @@ async fn spawn_webrtc_audio_processor(
                         let _ = sink.flush().await;
                     }
                 },
+                PipelineEvent::SpokenUpTo { text, position_ms } => {
+                    tracing::debug!(
+                        session_id = %session_id_for_pipeline,
+                        position_ms,
+                        "WebRTC response interrupted"
+                    );
+                    // Keep only what the caller heard in dialogue history
+                    session_for_pipeline.agent.record_interruption(&text);
+                },
+                PipelineEvent::AudioQuality(quality) => {
+                    session_for_pipeline.agent.set_line_quality(quality);
+                },
+                PipelineEvent::AvatarCues(_) => {
+                    // Audio-only transport; avatars are served over WebSocket
+                },
+                PipelineEvent::Dtmf { key } => {
+                    // A completed keypad answer is processed as the customer's turn
+                    let Some(text) = session_for_pipeline.agent.press_key(key) else {
+                        continue;
+                    };
+                    if let Err(e) = session_for_pipeline.agent.process(&text).await {
+                        tracing::error!(
+                            session_id = %session_id_for_pipeline,
+                            error = %e,
+                            "Agent processing failed for WebRTC keypad input"
+                        );
+                    }
+                },
                 PipelineEvent::Error(e) => {
                     tracing::error!(
                         session_id = %session_id_for_pipeline,
@@ -7,7 +7,7 @@ use axum::{
         ws::{Message, WebSocket, WebSocketUpgrade},
         Path, State,
     },
-    response::Response,
+    response::{IntoResponse, Response},
 };
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 use futures::{SinkExt, StreamExt};
@@ -15,10 +15,16 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tokio::sync::mpsc;
 
-use voice_agent_core::{AudioFrame, Channels, Frame, LanguageModel, SampleRate};
+use voice_agent_config::PipelineComponent;
+use voice_agent_core::{
+    AudioFrame, AvatarCues, Channels, ConferenceMixer, ConferenceMixerConfig, Frame, JitterBuffer,
+    JitterBufferConfig, JitterOutput, JitterStats, LanguageModel, SampleRate, Speaker,
+    SpeakerTally,
+};
 use voice_agent_llm::{LlmFactory, LlmProviderConfig};
-use voice_agent_pipeline::{create_noise_suppressor, PipelineConfig, PipelineEvent, VoicePipeline};
+use voice_agent_pipeline::{create_denoiser, PipelineEvent, VoicePipeline};
 
+use crate::captions::{CaptionTracker, CaptionWord};
 use crate::rate_limit::RateLimiter;
 use crate::session::Session;
 use crate::state::AppState;
@@ -30,24 +36,67 @@ pub enum WsMessage {
     /// Audio data (base64 encoded)
     Audio {
         data: String,
+        /// Frame sequence number; enables reordering and gap concealment
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        seq: Option<u64>,
+        /// Party on whose channel the frame was captured
+        ///
+        /// Tagged frames put the session in conference mode: each party's
+        /// channel is numbered separately and the two are mixed.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        speaker: Option<Speaker>,
+    },
+    /// Audio numbering restarts at `next_seq`
+    ///
+    /// Sent by the client after reconnecting, and by the server when it
+    /// skipped a gap too long to conceal.
+    Resync {
+        next_seq: u64,
+        /// Conference channel the numbering belongs to
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        speaker: Option<Speaker>,
+    },
+    /// Reply to a client resync with the transcript of the turn in progress
+    TranscriptSync {
+        next_seq: u64,
+        transcript: String,
     },
     /// Text input
     Text {
         content: String,
     },
+    /// Key pressed on the phone keypad (DTMF: 0-9, *, #, A-D)
+    Dtmf {
+        key: char,
+    },
     /// Transcript update
+    ///
+    /// Partials of an utterance replace each other; see [`crate::captions`].
     Transcript {
         text: String,
         is_final: bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        utterance: Option<u64>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        words: Vec<CaptionWord>,
     },
-    /// Agent response
+    /// Agent response, or a chunk of one streaming for `turn`
     Response {
         text: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        turn: Option<u64>,
+    },
+    /// A streamed response is complete
+    ResponseEnd {
+        turn: u64,
+        text: String,
     },
     /// Agent audio response
     ResponseAudio {
         data: String,
     },
+    /// Viseme and gesture cues for lip-syncing an avatar to `ResponseAudio`
+    AvatarCues(AvatarCues),
     /// Status update
     Status {
         state: String,
@@ -64,10 +113,34 @@ pub enum WsMessage {
     SessionInfo {
         session_id: String,
     },
+    /// Warm transfer progress of an escalated call
+    Handoff {
+        handoff_id: String,
+        status: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        agent_id: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        transfer_to: Option<String>,
+    },
     /// End session
     EndSession,
 }
 
+/// Inbound audio for the processor task
+enum InboundAudio {
+    /// 16-bit PCM with the client's sequence number and conference channel
+    Frame {
+        speaker: Option<Speaker>,
+        sequence: Option<u64>,
+        pcm: Vec<u8>,
+    },
+    /// Client restarted its numbering
+    Resync {
+        speaker: Option<Speaker>,
+        next_seq: u64,
+    },
+}
+
 /// WebSocket handler
 pub struct WebSocketHandler;
 
@@ -102,6 +175,7 @@ impl WebSocketHandler {
         // P2 FIX: Get text processing components from state
         let text_processing = state.text_processing.clone();
         let text_simplifier = state.text_simplifier.clone();
+        let code_switch = state.code_switch.clone();
         let (sender, mut receiver) = socket.split();
 
         // Wrap sender in Arc<Mutex> for sharing across tasks
@@ -134,13 +208,14 @@ impl WebSocketHandler {
         let mut agent_events = session.agent.subscribe();
 
         // Create channels for audio processing
-        let (audio_tx, mut audio_rx) = mpsc::channel::<Vec<u8>>(100);
+        let (audio_tx, mut audio_rx) = mpsc::channel::<InboundAudio>(100);
 
         // Create voice pipeline for audio processing
         // P0 FIX: Wire text processing (grammar, PII, compliance) to pipeline
-        // P2 FIX: Wire noise suppression for cleaner audio input
+        // Denoise inbound audio with the deployment's configured backend
+        let audio_config = state.config.read().pipeline.audio.clone();
         let noise_suppressor: Arc<dyn voice_agent_core::AudioProcessor> =
-            Arc::from(create_noise_suppressor(16000)); // 16kHz input
+            Arc::from(create_denoiser(&audio_config));
 
         // P0 FIX: Create LLM backend (Ollama with qwen3) for response generation
         let llm: Option<Arc<dyn LanguageModel>> = {
@@ -159,18 +234,19 @@ impl WebSocketHandler {
 
         // Create voice pipeline (use IndicConformer if onnx feature enabled, otherwise simple)
         #[cfg(feature = "onnx")]
-        let pipeline_result = {
-            let indicconformer_model_path = "models/stt/indicconformer";
-            VoicePipeline::with_indicconformer(indicconformer_model_path, PipelineConfig::default())
-        };
+        let pipeline_result = VoicePipeline::with_indicconformer(
+            crate::degradation::STT_MODEL_DIR,
+            state.pipeline_config(&session.id),
+        );
         #[cfg(not(feature = "onnx"))]
-        let pipeline_result = VoicePipeline::simple(PipelineConfig::default());
+        let pipeline_result = VoicePipeline::simple(state.pipeline_config(&session.id));
 
         let pipeline = match pipeline_result {
             Ok(p) => {
                 let mut p = p
                     .with_text_processor(text_processing.clone())
                     .with_noise_suppressor(noise_suppressor);
+                p.set_speech_style(state.speech_style(session.overrides()));
                 // Wire LLM for automatic response generation
                 if let Some(llm) = llm {
                     p = p.with_llm(llm);
@@ -179,9 +255,9 @@ impl WebSocketHandler {
                 Some(Arc::new(tokio::sync::Mutex::new(p)))
             },
             Err(e) => {
-                tracing::warn!(
-                    "Failed to create voice pipeline: {}, using text-only mode",
-                    e
+                state.degradation.degraded(
+                    PipelineComponent::Stt,
+                    format!("voice pipeline unavailable ({}); text-only mode", e),
                 );
                 None
             },
@@ -190,15 +266,59 @@ impl WebSocketHandler {
         // Spawn audio processor task - receives audio and feeds to pipeline
         let session_clone = session.clone();
         let pipeline_clone = pipeline.clone();
+        let sender_for_audio = sender.clone();
+        // Who spoke the utterance being transcribed, in conference mode
+        let speaker_tally = Arc::new(parking_lot::Mutex::new(SpeakerTally::default()));
+        let tally_for_audio = speaker_tally.clone();
 
         let audio_task = tokio::spawn(async move {
             let mut frame_count: u64 = 0;
+            // Reorders sequenced frames and conceals short drops so STT windows
+            // and barge-in timestamps stay aligned with the caller's audio
+            let mut jitter = JitterBuffer::new(JitterBufferConfig::default());
+            // Conference mode: the co-applicant's channel, mixed with the
+            // customer's once the first tagged frame arrives
+            let mut co_applicant_jitter = JitterBuffer::new(JitterBufferConfig::default());
+            let mut mixer: Option<ConferenceMixer> = None;
+            let mut mixed_seq: u64 = 0;
+            // Frames released and concealed on either channel, for line quality
+            let mut line_stats = JitterStats::default();
 
             tracing::info!("WebSocket audio processor task started");
 
-            while let Some(audio_data) = audio_rx.recv().await {
+            while let Some(inbound) = audio_rx.recv().await {
                 session_clone.touch();
 
+                let (speaker, sequence, audio_data) = match inbound {
+                    InboundAudio::Frame {
+                        speaker,
+                        sequence,
+                        pcm,
+                    } => (speaker, sequence, pcm),
+                    InboundAudio::Resync { speaker, next_seq } => {
+                        // Client reconnected: restart numbering and send back the
+                        // transcript of the turn in progress
+                        match speaker {
+                            Some(Speaker::CoApplicant) => co_applicant_jitter.resync(next_seq),
+                            _ => jitter.resync(next_seq),
+                        }
+                        let transcript = match pipeline_clone {
+                            Some(ref pipeline) => pipeline.lock().await.current_transcript(),
+                            None => String::new(),
+                        };
+                        tracing::info!(next_seq, "WebSocket audio resync requested by client");
+                        let msg = WsMessage::TranscriptSync {
+                            next_seq,
+                            transcript,
+                        };
+                        let mut s = sender_for_audio.lock().await;
+                        let _ = s
+                            .send(Message::Text(serde_json::to_string(&msg).unwrap()))
+                            .await;
+                        continue;
+                    },
+                };
+
                 if frame_count % 100 == 0 {
                     tracing::debug!("WebSocket audio frame {} received, {} bytes", frame_count, audio_data.len());
                 }
@@ -216,37 +336,117 @@ impl WebSocketHandler {
                     continue;
                 }
 
-                // Create audio frame
-                let frame =
-                    AudioFrame::new(samples.clone(), SampleRate::Hz16000, Channels::Mono, frame_count);
-                frame_count += 1;
+                if speaker.is_some() && mixer.is_none() {
+                    tracing::info!("WebSocket audio switched to conference mode");
+                    mixer = Some(ConferenceMixer::new(ConferenceMixerConfig::default()));
+                }
+                let jitter = match speaker {
+                    Some(Speaker::CoApplicant) => &mut co_applicant_jitter,
+                    _ => &mut jitter,
+                };
 
-                // Process through pipeline if available
-                if let Some(ref pipeline) = pipeline_clone {
-                    // DIAGNOSTIC: Log before lock
-                    if frame_count % 10 == 0 {
-                        tracing::debug!("Audio task: Acquiring pipeline lock for frame {}", frame_count);
-                    }
-                    let pipeline_guard = pipeline.lock().await;
-                    if frame_count % 10 == 0 {
-                        tracing::debug!("Audio task: Got pipeline lock, processing frame {}", frame_count);
-                    }
+                match sequence {
+                    Some(sequence) => {
+                        jitter.push(sequence, samples);
+                    },
+                    None => {
+                        jitter.push_unsequenced(samples);
+                    },
+                }
 
-                    if let Err(e) = pipeline_guard.process_audio(frame).await {
-                        tracing::debug!("Pipeline processing error: {}", e);
+                let mut ready = Vec::new();
+                for output in jitter.drain() {
+                    match output {
+                        JitterOutput::Frame { sequence, samples } => {
+                            line_stats.received += 1;
+                            ready.push((sequence, samples));
+                        },
+                        JitterOutput::Concealed { sequence, samples } => {
+                            line_stats.concealed += 1;
+                            ready.push((sequence, samples));
+                        },
+                        JitterOutput::Resync {
+                            expected,
+                            resumed_at,
+                        } => {
+                            tracing::warn!(
+                                expected,
+                                resumed_at,
+                                "WebSocket audio gap too long to conceal, resyncing"
+                            );
+                            let msg = WsMessage::Resync {
+                                next_seq: resumed_at,
+                                speaker,
+                            };
+                            let mut s = sender_for_audio.lock().await;
+                            let _ = s
+                                .send(Message::Text(serde_json::to_string(&msg).unwrap()))
+                                .await;
+                        },
                     }
+                }
 
-                    if frame_count % 10 == 0 {
-                        tracing::debug!("Audio task: Finished processing frame {}", frame_count);
+                // In conference mode both channels become one stream, numbered
+                // by the mixer, and each mixed frame counts for its speaker
+                if let Some(ref mut mixer) = mixer {
+                    for (_, samples) in ready {
+                        mixer.push(speaker.unwrap_or_default(), samples);
                     }
-                } else {
-                    if frame_count == 1 {
-                        tracing::warn!("No pipeline available for audio processing");
+                    let mixed = mixer.drain();
+                    let mut tally = tally_for_audio.lock();
+                    ready = mixed
+                        .into_iter()
+                        .map(|frame| {
+                            tally.record(frame.speaker);
+                            mixed_seq += 1;
+                            (mixed_seq - 1, frame.samples)
+                        })
+                        .collect();
+                }
+
+                if let Some(ref pipeline) = pipeline_clone {
+                    pipeline.lock().await.record_jitter(&line_stats);
+                }
+
+                for (sequence, samples) in ready {
+                    // Sequence numbers keep the frame timeline continuous across drops
+                    let frame = AudioFrame::new(samples, SampleRate::Hz16000, Channels::Mono, sequence);
+                    frame_count += 1;
+
+                    // Process through pipeline if available
+                    if let Some(ref pipeline) = pipeline_clone {
+                        // DIAGNOSTIC: Log before lock
+                        if frame_count % 10 == 0 {
+                            tracing::debug!("Audio task: Acquiring pipeline lock for frame {}", frame_count);
+                        }
+                        let pipeline_guard = pipeline.lock().await;
+                        if frame_count % 10 == 0 {
+                            tracing::debug!("Audio task: Got pipeline lock, processing frame {}", frame_count);
+                        }
+
+                        if let Err(e) = pipeline_guard.process_audio(frame).await {
+                            tracing::debug!("Pipeline processing error: {}", e);
+                        }
+
+                        if frame_count % 10 == 0 {
+                            tracing::debug!("Audio task: Finished processing frame {}", frame_count);
+                        }
+                    } else {
+                        if frame_count == 1 {
+                            tracing::warn!("No pipeline available for audio processing");
+                        }
                     }
                 }
             }
 
-            tracing::info!("WebSocket audio processor task ended after {} frames", frame_count);
+            let stats = jitter.stats();
+            tracing::info!(
+                concealed = stats.concealed,
+                late = stats.late,
+                resyncs = stats.resyncs,
+                "WebSocket audio processor task ended after {} frames",
+                frame_count
+            );
         });
 
         // Spawn pipeline event handler task
@@ -256,12 +456,14 @@ impl WebSocketHandler {
                                                  // P2 FIX: Clone text processing for pipeline event handler
         let text_processing_for_pipeline = text_processing.clone();
         let text_simplifier_for_pipeline = text_simplifier.clone();
+        let code_switch_for_pipeline = code_switch.clone();
 
         #[allow(unused_mut)]
         let pipeline_event_task = if let Some(ref pipeline) = pipeline {
             let mut pipeline_events = pipeline.lock().await.subscribe();
             tracing::info!("Pipeline event handler task started, listening for events");
             Some(tokio::spawn(async move {
+                let mut captions = CaptionTracker::default();
                 loop {
                     let event = match pipeline_events.recv().await {
                         Ok(event) => event,
@@ -279,11 +481,8 @@ impl WebSocketHandler {
                     match event {
                         PipelineEvent::PartialTranscript(transcript) => {
                             tracing::debug!("Sending partial transcript to client: {}", transcript.text);
-                            // Send partial transcript to client
-                            let msg = WsMessage::Transcript {
-                                text: transcript.text,
-                                is_final: false,
-                            };
+                            // Send partial transcript to client as a live caption
+                            let (msg, _) = captions.transcript(&transcript);
                             let json = serde_json::to_string(&msg).unwrap();
                             let mut s = sender_for_pipeline.lock().await;
                             let _ = s.send(Message::Text(json)).await;
@@ -291,16 +490,22 @@ impl WebSocketHandler {
                         PipelineEvent::FinalTranscript(transcript) => {
                             let text = transcript.text.clone();
 
-                            // Send final transcript to client
-                            let msg = WsMessage::Transcript {
-                                text: text.clone(),
-                                is_final: true,
-                            };
+                            // Send final transcript to client; the reply is captioned
+                            // as the turn answering this utterance
+                            let (msg, turn) = captions.transcript(&transcript);
                             let json = serde_json::to_string(&msg).unwrap();
                             let mut s = sender_for_pipeline.lock().await;
                             let _ = s.send(Message::Text(json)).await;
                             drop(s); // Release lock before async operations
 
+                            // The utterance's audio counts toward the turn it starts
+                            let audio_ms = transcript
+                                .end_time_ms
+                                .saturating_sub(transcript.start_time_ms);
+                            session_for_pipeline
+                                .agent
+                                .record_stt_seconds(audio_ms as f64 / 1000.0);
+
                             // Process through agent
                             if !text.trim().is_empty() {
                                 // P2 FIX: Process user input through text processing pipeline
@@ -330,10 +535,21 @@ impl WebSocketHandler {
                                 let session = session_for_pipeline.clone();
                                 let sender = sender_for_pipeline.clone();
                                 let text_simplifier = text_simplifier_for_pipeline.clone();
+                                let code_switch = code_switch_for_pipeline.clone();
                                 let pipeline = pipeline_for_tts.clone();
 
+                                // In conference mode, whoever spoke most of the utterance
+                                let speaker = speaker_tally.lock().take();
+                                // Repeatedly mis-heard turns switch answers to the keypad
+                                session_for_pipeline
+                                    .agent
+                                    .record_stt_confidence(transcript.confidence);
+
                                 tokio::spawn(async move {
                                     let user_language = session.agent.user_language();
+                                    if speaker.is_some() {
+                                        session.agent.set_speaker(speaker);
+                                    }
 
                                     match session.agent.process_stream(&processed_input).await {
                                         Ok(mut chunk_rx) => {
@@ -405,12 +621,16 @@ impl WebSocketHandler {
                                                         });
 
                                                         // Forward chunks to client and TTS
+                                                        let mut reply = String::new();
+                                                        let mut pending = String::new();
                                                         while let Some(chunk) =
                                                             chunk_rx.recv().await
                                                         {
                                                             // Send to client
+                                                            reply.push_str(&chunk);
                                                             let resp = WsMessage::Response {
                                                                 text: chunk.clone(),
+                                                                turn: Some(turn),
                                                             };
                                                             let json = serde_json::to_string(&resp)
                                                                 .unwrap();
@@ -419,12 +639,40 @@ impl WebSocketHandler {
                                                                 s.send(Message::Text(json)).await;
                                                             drop(s);
 
-                                                            // Simplify and send to TTS
-                                                            let simplified =
-                                                                text_simplifier.simplify(&chunk);
-                                                            let _ = tts_tx.send(simplified).await;
+                                                            // Render whole words in the session's script, simplify and send to TTS
+                                                            pending.push_str(&chunk);
+                                                            if let Some(words) =
+                                                                take_complete_words(&mut pending)
+                                                            {
+                                                                let spoken = speakable_text(
+                                                                    &words,
+                                                                    user_language,
+                                                                    code_switch.as_deref(),
+                                                                    &text_simplifier,
+                                                                )
+                                                                .await;
+                                                                session
+                                                                    .agent
+                                                                    .record_tts_characters(&spoken);
+                                                                let _ = tts_tx.send(spoken).await;
+                                                            }
+                                                        }
+                                                        if !pending.is_empty() {
+                                                            let spoken = speakable_text(
+                                                                &pending,
+                                                                user_language,
+                                                                code_switch.as_deref(),
+                                                                &text_simplifier,
+                                                            )
+                                                            .await;
+                                                            session
+                                                                .agent
+                                                                .record_tts_characters(&spoken);
+                                                            let _ = tts_tx.send(spoken).await;
                                                         }
 
+                                                        send_response_end(&sender, turn, reply)
+                                                            .await;
                                                         tracing::debug!(
                                                             "Streaming response complete"
                                                         );
@@ -434,32 +682,67 @@ impl WebSocketHandler {
                                                         tracing::warn!("speak_streaming failed: {}, using text-only", e);
 
                                                         // Fallback: just stream text
+                                                        let mut reply = String::new();
                                                         while let Some(chunk) =
                                                             chunk_rx.recv().await
                                                         {
-                                                            let resp =
-                                                                WsMessage::Response { text: chunk };
+                                                            reply.push_str(&chunk);
+                                                            let resp = WsMessage::Response {
+                                                                text: chunk,
+                                                                turn: Some(turn),
+                                                            };
                                                             let json = serde_json::to_string(&resp)
                                                                 .unwrap();
                                                             let mut s = sender.lock().await;
                                                             let _ =
                                                                 s.send(Message::Text(json)).await;
                                                         }
+                                                        send_response_end(&sender, turn, reply)
+                                                            .await;
                                                     },
                                                 }
                                             } else {
                                                 // No pipeline - just stream text responses
+                                                let mut reply = String::new();
                                                 while let Some(chunk) = chunk_rx.recv().await {
-                                                    let resp = WsMessage::Response { text: chunk };
+                                                    reply.push_str(&chunk);
+                                                    let resp = WsMessage::Response {
+                                                        text: chunk,
+                                                        turn: Some(turn),
+                                                    };
                                                     let json =
                                                         serde_json::to_string(&resp).unwrap();
                                                     let mut s = sender.lock().await;
                                                     let _ = s.send(Message::Text(json)).await;
                                                 }
+                                                send_response_end(&sender, turn, reply).await;
                                             }
                                         },
                                         Err(e) => {
                                             tracing::error!("Agent streaming error: {}", e);
+                                            // The caller hears an apology instead of silence
+                                            let Some(apology) = session.agent.failure_reply(&e)
+                                            else {
+                                                return;
+                                            };
+                                            if let Some(ref pipeline) = pipeline {
+                                                session.agent.record_tts_characters(&apology);
+                                                if let Err(e) =
+                                                    pipeline.lock().await.speak(&apology).await
+                                                {
+                                                    tracing::warn!("Apology TTS failed: {}", e);
+                                                }
+                                            }
+                                            let resp = WsMessage::Response {
+                                                text: apology.clone(),
+                                                turn: Some(turn),
+                                            };
+                                            let json = serde_json::to_string(&resp).unwrap();
+                                            {
+                                                let mut s = sender.lock().await;
+                                                let _ = s.send(Message::Text(json)).await;
+                                            }
+                                            send_response_end(&sender, turn, apology).await;
                                         },
                                     }
                                 });
@@ -484,11 +767,68 @@ impl WebSocketHandler {
                         PipelineEvent::Error(e) => {
                             tracing::error!("Pipeline error: {}", e);
                         },
+                        PipelineEvent::SpokenUpTo { text, .. } => {
+                            // Keep only what the caller heard in dialogue history
+                            session_for_pipeline.agent.record_interruption(&text);
+                        },
+                        PipelineEvent::AudioQuality(quality) => {
+                            session_for_pipeline.agent.set_line_quality(quality);
+                            let msg = WsMessage::Status {
+                                state: "line_quality".to_string(),
+                                stage: quality
+                                    .issue
+                                    .map_or("good", |issue| issue.as_str())
+                                    .to_string(),
+                            };
+                            let json = serde_json::to_string(&msg).unwrap();
+                            let mut s = sender_for_pipeline.lock().await;
+                            let _ = s.send(Message::Text(json)).await;
+                        },
+                        PipelineEvent::Dtmf { key } => {
+                            // A completed keypad answer is processed as the customer's turn
+                            let Some(text) = session_for_pipeline.agent.press_key(key) else {
+                                continue;
+                            };
+                            let session = session_for_pipeline.clone();
+                            let sender = sender_for_pipeline.clone();
+                            let pipeline = pipeline_for_tts.clone();
+                            tokio::spawn(async move {
+                                // A failed turn is answered with an apology
+                                let reply = match session.agent.process(&text).await {
+                                    Ok(response) => Ok(response),
+                                    Err(e) => session.agent.failure_reply(&e).ok_or(e),
+                                };
+                                let msg = match reply {
+                                    Ok(response) => {
+                                        if let Some(ref pipeline) = pipeline {
+                                            session.agent.record_tts_characters(&response);
+                                            if let Err(e) =
+                                                pipeline.lock().await.speak(&response).await
+                                            {
+                                                tracing::warn!("Keypad reply TTS failed: {}", e);
+                                            }
+                                        }
+                                        WsMessage::Response {
+                                            text: response,
+                                            turn: None,
+                                        }
+                                    },
+                                    Err(e) => WsMessage::Error {
+                                        message: e.to_string(),
+                                    },
+                                };
+                                let mut s = sender.lock().await;
+                                let _ = s
+                                    .send(Message::Text(serde_json::to_string(&msg).unwrap()))
+                                    .await;
+                            });
+                        },
                         PipelineEvent::Response { text, is_final } => {
                             // P0 FIX: Send text response to client (before TTS audio)
                             if is_final && !text.is_empty() {
                                 let msg = WsMessage::Response {
                                     text: text.clone(),
+                                    turn: None,
                                 };
                                 let json = serde_json::to_string(&msg).unwrap();
                                 let mut s = sender_for_pipeline.lock().await;
@@ -496,6 +836,11 @@ impl WebSocketHandler {
                                 tracing::info!("Sent response to client: {} chars", text.len());
                             }
                         },
+                        PipelineEvent::AvatarCues(cues) => {
+                            let json = serde_json::to_string(&WsMessage::AvatarCues(cues)).unwrap();
+                            let mut s = sender_for_pipeline.lock().await;
+                            let _ = s.send(Message::Text(json)).await;
+                        },
                         PipelineEvent::TtsAudio {
                             samples,
                             text: _,
@@ -537,7 +882,7 @@ impl WebSocketHandler {
             while let Ok(event) = agent_events.recv().await {
                 let msg = match event {
                     voice_agent_agent::AgentEvent::Response(text) => {
-                        Some(WsMessage::Response { text })
+                        Some(WsMessage::Response { text, turn: None })
                     },
                     voice_agent_agent::AgentEvent::Thinking => Some(WsMessage::Status {
                         state: "thinking".to_string(),
@@ -546,6 +891,17 @@ impl WebSocketHandler {
                     voice_agent_agent::AgentEvent::Error(e) => {
                         Some(WsMessage::Error { message: e })
                     },
+                    voice_agent_agent::AgentEvent::Handoff {
+                        handoff_id,
+                        status,
+                        agent_id,
+                        transfer_to,
+                    } => Some(WsMessage::Handoff {
+                        handoff_id,
+                        status: status.as_str().to_string(),
+                        agent_id,
+                        transfer_to,
+                    }),
                     _ => None,
                 };
 
@@ -605,10 +961,17 @@ impl WebSocketHandler {
                                     },
                                 };
 
-                                // Process text input
-                                match session.agent.process(&processed_input).await {
+                                // Process text input; a failed turn is answered with an apology
+                                let reply = match session.agent.process(&processed_input).await {
+                                    Ok(response) => Ok(response),
+                                    Err(e) => session.agent.failure_reply(&e).ok_or(e),
+                                };
+                                match reply {
                                     Ok(response) => {
-                                        let resp = WsMessage::Response { text: response };
+                                        let resp = WsMessage::Response {
+                                            text: response,
+                                            turn: None,
+                                        };
                                         let json = serde_json::to_string(&resp).unwrap();
                                         let mut s = sender.lock().await;
                                         let _ = s.send(Message::Text(json)).await;
@@ -644,7 +1007,7 @@ impl WebSocketHandler {
                                     .send(Message::Text(serde_json::to_string(&pong).unwrap()))
                                     .await;
                             },
-                            WsMessage::Audio { data } => {
+                            WsMessage::Audio { data, seq, speaker } => {
                                 // Decode base64 audio data and send to processor
                                 match BASE64.decode(&data) {
                                     Ok(audio_bytes) => {
@@ -667,13 +1030,32 @@ impl WebSocketHandler {
                                             continue;
                                         }
                                         drop(limiter); // Release lock before sending
-                                        let _ = audio_tx.send(audio_bytes).await;
+                                        let _ = audio_tx
+                                            .send(InboundAudio::Frame {
+                                                speaker,
+                                                sequence: seq,
+                                                pcm: audio_bytes,
+                                            })
+                                            .await;
                                     },
                                     Err(e) => {
                                         tracing::warn!("Failed to decode audio data: {}", e);
                                     },
                                 }
                             },
+                            WsMessage::Dtmf { key } => match pipeline {
+                                Some(ref pipeline) => {
+                                    if let Err(e) = pipeline.lock().await.process_dtmf(key) {
+                                        tracing::debug!("Ignoring keypad input: {}", e);
+                                    }
+                                },
+                                None => tracing::debug!("No pipeline for keypad input"),
+                            },
+                            WsMessage::Resync { next_seq, speaker } => {
+                                let _ = audio_tx
+                                    .send(InboundAudio::Resync { speaker, next_seq })
+                                    .await;
+                            },
                             WsMessage::EndSession => {
                                 session.close();
                                 break;
@@ -702,7 +1084,12 @@ impl WebSocketHandler {
                     }
 
                     // Raw binary audio data (PCM)
-                    if let Err(e) = audio_tx.send(data).await {
+                    let frame = InboundAudio::Frame {
+                        speaker: None,
+                        sequence: None,
+                        pcm: data,
+                    };
+                    if let Err(e) = audio_tx.send(frame).await {
                         tracing::warn!("Failed to send audio to pipeline: {}", e);
                     }
                 },
@@ -725,28 +1112,189 @@ impl WebSocketHandler {
         if let Some(task) = pipeline_event_task {
             task.abort();
         }
+        if let Some(ref pipeline) = pipeline {
+            if let Some(ms) = pipeline.lock().await.denoise_latency_ms() {
+                crate::metrics::record_denoise_latency(ms as f64 / 1000.0);
+            }
+        }
+
+        if let Err(e) = state.save_customer(&session).await {
+            tracing::warn!(session_id = %session.id, error = %e, "Failed to save customer identity");
+        }
 
         tracing::info!("WebSocket closed for session: {}", session.id);
     }
 }
 
+/// Split off the complete words of `pending`, leaving a trailing partial word
+fn take_complete_words(pending: &mut String) -> Option<String> {
+    let end = pending
+        .char_indices()
+        .rev()
+        .find(|(_, c)| c.is_whitespace())
+        .map(|(i, c)| i + c.len_utf8())?;
+    let rest = pending.split_off(end);
+    Some(std::mem::replace(pending, rest))
+}
+
+/// Prepare response text for TTS: keep it in the session language's script,
+/// then expand numbers and abbreviations
+///
+/// A trailing space is kept so consecutive pieces don't run words together.
+async fn speakable_text(
+    text: &str,
+    language: voice_agent_core::Language,
+    code_switch: Option<&voice_agent_text_processing::CodeSwitchRenderer>,
+    text_simplifier: &voice_agent_text_processing::TextSimplifier,
+) -> String {
+    let rendered = match code_switch {
+        Some(renderer) => renderer.render_translated(text, language).await,
+        None => text.to_string(),
+    };
+    let mut spoken = text_simplifier.simplify(&rendered);
+    if text.ends_with(char::is_whitespace) && !spoken.is_empty() {
+        spoken.push(' ');
+    }
+    spoken
+}
+
+/// Close a streamed response with its full text
+async fn send_response_end(
+    sender: &tokio::sync::Mutex<futures::stream::SplitSink<WebSocket, Message>>,
+    turn: u64,
+    text: String,
+) {
+    let msg = WsMessage::ResponseEnd { turn, text };
+    let json = serde_json::to_string(&msg).unwrap();
+    let _ = sender.lock().await.send(Message::Text(json)).await;
+}
+
+/// Optional body for `POST /api/sessions`
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CreateSessionRequest {
+    /// Per-session runtime overrides (language, persona, voice, temperature, tools)
+    #[serde(default)]
+    pub overrides: voice_agent_config::SessionOverrides,
+    /// Caller's phone number; links this session to the customer's identity
+    #[serde(default)]
+    pub customer_phone: Option<String>,
+    /// Channel the session arrived on (voice, whatsapp, web)
+    #[serde(default)]
+    pub channel: voice_agent_core::Channel,
+    /// Tenant the session's usage and budgets belong to (default: `usage.default_tenant`)
+    #[serde(default)]
+    pub tenant: Option<String>,
+}
+
 /// Create new session endpoint
+///
+/// Accepts an empty body or a `CreateSessionRequest` JSON body. Under load,
+/// admission control may answer 503 with a `Retry-After` header instead.
 pub async fn create_session(
     State(state): State<AppState>,
-) -> Result<axum::Json<serde_json::Value>, axum::http::StatusCode> {
+    body: axum::body::Bytes,
+) -> Result<axum::Json<serde_json::Value>, Response> {
+    let request: CreateSessionRequest = if body.iter().all(u8::is_ascii_whitespace) {
+        CreateSessionRequest::default()
+    } else {
+        serde_json::from_slice(&body).map_err(|e| {
+            tracing::warn!(error = %e, "Invalid create session request body");
+            axum::http::StatusCode::BAD_REQUEST.into_response()
+        })?
+    };
+
+    let (session, returning_customer) = open_session(&state, request).await?;
+
+    // Build ICE servers from config for frontend
+    let config = state.config.read();
+    let mut ice_servers: Vec<serde_json::Value> = config
+        .server
+        .stun_servers
+        .iter()
+        .map(|url| serde_json::json!({ "urls": url }))
+        .collect();
+
+    // Add TURN servers with credentials
+    for turn in &config.server.turn_servers {
+        ice_servers.push(serde_json::json!({
+            "urls": turn.url,
+            "username": turn.username,
+            "credential": turn.credential
+        }));
+    }
+    drop(config);
+
+    Ok(axum::Json(serde_json::json!({
+        "session_id": session.id,
+        "websocket_url": format!("/ws/{}", session.id),
+        "rag_enabled": state.vector_store.is_some(),
+        "tools_wired": true,
+        "overrides": session.overrides(),
+        "returning_customer": returning_customer,
+        "ice_servers": ice_servers
+    })))
+}
+
+/// Admit, create and wire up a session
+///
+/// Returns the session and whether the caller is a returning customer.
+pub(crate) async fn open_session(
+    state: &AppState,
+    request: CreateSessionRequest,
+) -> Result<(Arc<Session>, bool), Response> {
+    if let Some(ref tenant) = request.tenant {
+        if tenant.trim().is_empty() || tenant == voice_agent_persistence::usage::ALL_TENANTS {
+            tracing::warn!(tenant = %tenant, "Invalid usage tenant");
+            return Err(axum::http::StatusCode::BAD_REQUEST.into_response());
+        }
+    }
+    if let Err(rejection) = state.admission.admit(|| state.sessions.count()).await {
+        return Err(state.admission.busy_response(&rejection));
+    }
     let config = voice_agent_agent::AgentConfig::default();
 
     // P0 FIX: Pass vector store AND tools to enable full integration in agent
     // This ensures the agent uses the persistence-wired tool registry from AppState
     // instead of creating its own default registry without persistence.
     // P21 FIX: Pass domain config to ensure agent uses loaded domain configuration
-    match state.sessions.create_with_full_integration(
+    match state.sessions.create_with_overrides(
         config,
         state.vector_store.clone(),
         Some(state.tools.clone()),
         state.master_domain_config.clone(),
+        request.overrides,
     ) {
         Ok(session) => {
+            state.attach_archival_memory(&session);
+            state.attach_knowledge_base(&session);
+            state.attach_llm_router(&session);
+            state.attach_response_cache(&session);
+            state.attach_guardrails(&session);
+            state.attach_response_governor(&session);
+            state.attach_abuse_policy(&session);
+            state.attach_event_bus(&session);
+            state.attach_journal(&session);
+            state.attach_usage(&session, request.tenant.as_deref());
+            state.attach_budget(&session, request.tenant.as_deref());
+            state.attach_model_versions(&session);
+
+            // Link to the customer's identity and preload facts from prior sessions
+            let mut returning_customer = false;
+            if let Some(ref phone) = request.customer_phone {
+                match state.resolve_customer(&session, phone, request.channel).await {
+                    Ok(returning) => returning_customer = returning,
+                    Err(crate::ServerError::InvalidRequest(msg)) => {
+                        tracing::warn!(error = %msg, "Rejected customer phone");
+                        state.sessions.remove(&session.id);
+                        return Err(axum::http::StatusCode::BAD_REQUEST.into_response());
+                    },
+                    Err(e) => {
+                        tracing::warn!(session_id = %session.id, error = %e, "Customer identity resolution failed");
+                    },
+                }
+            }
+
             // P2-3 FIX: Persist session metadata to configured store
             if let Err(e) = state.persist_session(&session).await {
                 tracing::warn!(session_id = %session.id, error = %e, "Failed to persist session metadata");
@@ -761,33 +1309,12 @@ pub async fn create_session(
                 );
             }
 
-            // Build ICE servers from config for frontend
-            let config = state.config.read();
-            let mut ice_servers: Vec<serde_json::Value> = config
-                .server
-                .stun_servers
-                .iter()
-                .map(|url| serde_json::json!({ "urls": url }))
-                .collect();
-
-            // Add TURN servers with credentials
-            for turn in &config.server.turn_servers {
-                ice_servers.push(serde_json::json!({
-                    "urls": turn.url,
-                    "username": turn.username,
-                    "credential": turn.credential
-                }));
-            }
-            drop(config);
-
-            Ok(axum::Json(serde_json::json!({
-                "session_id": session.id,
-                "websocket_url": format!("/ws/{}", session.id),
-                "rag_enabled": state.vector_store.is_some(),
-                "tools_wired": true,
-                "ice_servers": ice_servers
-            })))
+            Ok((session, returning_customer))
+        },
+        Err(crate::ServerError::InvalidRequest(msg)) => {
+            tracing::warn!(error = %msg, "Rejected session overrides");
+            Err(axum::http::StatusCode::BAD_REQUEST.into_response())
         },
-        Err(_) => Err(axum::http::StatusCode::SERVICE_UNAVAILABLE),
+        Err(_) => Err(axum::http::StatusCode::SERVICE_UNAVAILABLE.into_response()),
     }
 }
@@ -0,0 +1,408 @@
+//! Abusive Speech Detection
+//!
+//! Screens caller transcripts for profanity and abuse in English, Hindi
+//! (Devanagari) and romanized Hinglish.
+//!
+//! Matching is token-based on normalized text: lowercased, common
+//! character substitutions undone (`@` -> `a`, `0` -> `o`, ...) and repeated
+//! letters collapsed, so "fuuuck", "b@stard" and "paagal" / "pagal" all match.
+//! Multi-word phrases ("shut up", "chup kar") match on whole-token runs.
+//!
+//! An optional [`AbuseClassifier`] can be plugged in to catch abuse the
+//! lexicons miss; it can raise a turn to [`AbuseSeverity::Mild`] but only the
+//! severe lexicon marks a turn [`AbuseSeverity::Severe`].
+//!
+//! # Example
+//!
+//! ```
+//! use voice_agent_text_processing::abuse::{AbuseDetector, AbuseSeverity};
+//!
+//! let detector = AbuseDetector::new();
+//! assert_eq!(detector.detect("kitna byaj lagega?").severity, AbuseSeverity::None);
+//! assert_eq!(detector.detect("kya bakwas hai yeh").severity, AbuseSeverity::Mild);
+//! ```
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// How abusive a caller turn is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Default)]
+pub enum AbuseSeverity {
+    /// No abuse detected
+    #[default]
+    None,
+    /// Profanity or rude venting ("damn", "bakwas", "pagal")
+    Mild,
+    /// Slurs, sexual abuse or threats
+    Severe,
+}
+
+/// Result of screening one transcript
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AbuseDetection {
+    /// Overall severity
+    pub severity: AbuseSeverity,
+    /// Lexicon terms that matched (normalized)
+    pub matched_terms: Vec<String>,
+    /// Classifier score, if a classifier is configured
+    pub classifier_score: Option<f32>,
+}
+
+impl AbuseDetection {
+    /// Whether the turn counts as an abuse incident
+    pub fn is_abusive(&self) -> bool {
+        self.severity != AbuseSeverity::None
+    }
+}
+
+/// Pluggable abuse classifier (e.g. an ONNX toxicity model)
+pub trait AbuseClassifier: Send + Sync {
+    /// Probability (0.0 - 1.0) that `text` is abusive
+    fn score(&self, text: &str) -> f32;
+}
+
+// ============================================================================
+// Lexicons
+// ============================================================================
+
+static SEVERE_TERMS: Lazy<Vec<&'static str>> = Lazy::new(|| {
+    vec![
+        // English
+        "fuck",
+        "fucking",
+        "fucker",
+        "motherfucker",
+        "bitch",
+        "bastard",
+        "asshole",
+        "cunt",
+        "dick",
+        "whore",
+        "slut",
+        "kill you",
+        "i will kill",
+        // Hinglish
+        "madarchod",
+        "maderchod",
+        "behenchod",
+        "bhenchod",
+        "benchod",
+        "chutiya",
+        "chutiye",
+        "chodu",
+        "bhosdike",
+        "bhosadike",
+        "bhosdi",
+        "gaandu",
+        "gandu",
+        "randi",
+        "harami",
+        "haramkhor",
+        "teri maa ki",
+        "jaan se maar",
+        // Hindi (Devanagari)
+        "मादरचोद",
+        "बहनचोद",
+        "भेनचोद",
+        "चूतिया",
+        "चुतिया",
+        "भोसडीके",
+        "भोसड़ीके",
+        "गांडू",
+        "गाँडू",
+        "रंडी",
+        "हरामी",
+        "हरामखोर",
+        "तेरी माँ की",
+        "जान से मार",
+    ]
+});
+
+static MILD_TERMS: Lazy<Vec<&'static str>> = Lazy::new(|| {
+    vec![
+        // English
+        "damn",
+        "shit",
+        "bullshit",
+        "crap",
+        "bloody",
+        "stupid",
+        "idiot",
+        "moron",
+        "dumb",
+        "shut up",
+        // Hinglish
+        "bakwas",
+        "bewakoof",
+        "bevkoof",
+        "pagal",
+        "ullu",
+        "kamina",
+        "kamine",
+        "saala",
+        "sala",
+        "nalayak",
+        "gadha",
+        "chup kar",
+        "dimag kharab",
+        // Hindi (Devanagari)
+        "बकवास",
+        "बेवकूफ",
+        "बेवकूफ़",
+        "पागल",
+        "उल्लू",
+        "कमीना",
+        "कमीने",
+        "साला",
+        "नालायक",
+        "गधा",
+        "चुप कर",
+    ]
+});
+
+/// Compiled lexicon for one severity
+#[derive(Debug, Default)]
+struct Lexicon {
+    /// Single-token terms
+    words: HashSet<String>,
+    /// Multi-token phrases, space-joined
+    phrases: Vec<String>,
+}
+
+impl Lexicon {
+    fn new<'a>(terms: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut lexicon = Self::default();
+        for term in terms {
+            let tokens = tokenize(term);
+            match tokens.len() {
+                0 => {},
+                1 => {
+                    lexicon
+                        .words
+                        .insert(tokens.into_iter().next().unwrap_or_default());
+                },
+                _ => lexicon.phrases.push(tokens.join(" ")),
+            }
+        }
+        lexicon
+    }
+
+    fn matches(&self, tokens: &[String], joined: &str) -> Vec<String> {
+        let mut matched: Vec<String> = tokens
+            .iter()
+            .filter(|t| self.words.contains(*t))
+            .cloned()
+            .collect();
+        matched.extend(
+            self.phrases
+                .iter()
+                .filter(|p| joined.contains(&format!(" {} ", p)))
+                .cloned(),
+        );
+        matched.dedup();
+        matched
+    }
+}
+
+/// Abusive speech detector
+pub struct AbuseDetector {
+    severe: Lexicon,
+    mild: Lexicon,
+    classifier: Option<Arc<dyn AbuseClassifier>>,
+    classifier_threshold: f32,
+}
+
+impl AbuseDetector {
+    /// Create a detector with the built-in lexicons
+    pub fn new() -> Self {
+        Self::with_terms(&[], &[])
+    }
+
+    /// Create a detector with extra terms on top of the built-in lexicons
+    pub fn with_terms(extra_mild: &[String], extra_severe: &[String]) -> Self {
+        Self {
+            severe: Lexicon::new(
+                SEVERE_TERMS
+                    .iter()
+                    .copied()
+                    .chain(extra_severe.iter().map(|s| s.as_str())),
+            ),
+            mild: Lexicon::new(
+                MILD_TERMS
+                    .iter()
+                    .copied()
+                    .chain(extra_mild.iter().map(|s| s.as_str())),
+            ),
+            classifier: None,
+            classifier_threshold: 0.8,
+        }
+    }
+
+    /// Consult a classifier for abuse the lexicons miss
+    pub fn with_classifier(mut self, classifier: Arc<dyn AbuseClassifier>, threshold: f32) -> Self {
+        self.classifier = Some(classifier);
+        self.classifier_threshold = threshold;
+        self
+    }
+
+    /// Screen a transcript
+    pub fn detect(&self, text: &str) -> AbuseDetection {
+        let tokens = tokenize(text);
+        let joined = format!(" {} ", tokens.join(" "));
+
+        let severe = self.severe.matches(&tokens, &joined);
+        let mild = self.mild.matches(&tokens, &joined);
+        let classifier_score = self.classifier.as_ref().map(|c| c.score(text));
+
+        let severity = if !severe.is_empty() {
+            AbuseSeverity::Severe
+        } else if !mild.is_empty()
+            || classifier_score.is_some_and(|score| score >= self.classifier_threshold)
+        {
+            AbuseSeverity::Mild
+        } else {
+            AbuseSeverity::None
+        };
+
+        let mut matched_terms = severe;
+        matched_terms.extend(mild);
+
+        AbuseDetection {
+            severity,
+            matched_terms,
+            classifier_score,
+        }
+    }
+}
+
+impl Default for AbuseDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Lowercase, undo common character substitutions, split into tokens and
+/// collapse repeated letters
+fn tokenize(text: &str) -> Vec<String> {
+    let substituted: String = text
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            '@' | '4' => 'a',
+            '0' => 'o',
+            '1' => 'i',
+            '3' => 'e',
+            '$' | '5' => 's',
+            _ => c,
+        })
+        .collect();
+
+    substituted
+        .split(|c: char| c.is_whitespace() || c.is_ascii_punctuation() || c == '।' || c == '॥')
+        .filter(|t| !t.is_empty())
+        .map(collapse_repeats)
+        .collect()
+}
+
+fn collapse_repeats(token: &str) -> String {
+    let mut out = String::with_capacity(token.len());
+    let mut last = None;
+    for c in token.chars() {
+        if Some(c) != last {
+            out.push(c);
+        }
+        last = Some(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedClassifier(f32);
+
+    impl AbuseClassifier for FixedClassifier {
+        fn score(&self, _text: &str) -> f32 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_clean_input() {
+        let detector = AbuseDetector::new();
+        for text in [
+            "What is the interest rate for 50 grams?",
+            "mujhe 2 lakh ka loan chahiye",
+            "मुझे गोल्ड लोन चाहिए",
+            "maa ki tabiyat kharab hai, loan chahiye",
+            "I need this by Monday, skill assessment pending",
+        ] {
+            assert!(!detector.detect(text).is_abusive(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_severity_levels() {
+        let detector = AbuseDetector::new();
+
+        assert_eq!(
+            detector.detect("This is bullshit").severity,
+            AbuseSeverity::Mild
+        );
+        assert_eq!(
+            detector.detect("tum log pagal ho kya").severity,
+            AbuseSeverity::Mild
+        );
+        assert_eq!(
+            detector.detect("chutiya bana rahe ho").severity,
+            AbuseSeverity::Severe
+        );
+        assert_eq!(
+            detector.detect("तुम हरामी हो").severity,
+            AbuseSeverity::Severe
+        );
+    }
+
+    #[test]
+    fn test_obfuscation_and_phrases() {
+        let detector = AbuseDetector::new();
+
+        assert_eq!(
+            detector.detect("fuuuck this").severity,
+            AbuseSeverity::Severe
+        );
+        assert_eq!(
+            detector.detect("you b@stard").severity,
+            AbuseSeverity::Severe
+        );
+        assert_eq!(
+            detector.detect("paagal hai kya").severity,
+            AbuseSeverity::Mild
+        );
+        assert_eq!(detector.detect("Shut   up!").severity, AbuseSeverity::Mild);
+        // "up" alone or "shut" alone is fine
+        assert!(!detector
+            .detect("shut the account, set up a new one")
+            .is_abusive());
+    }
+
+    #[test]
+    fn test_extra_terms_and_classifier() {
+        let detector = AbuseDetector::with_terms(&["nikamma".to_string()], &[]);
+        assert_eq!(
+            detector.detect("nikamma service").severity,
+            AbuseSeverity::Mild
+        );
+
+        let detector = AbuseDetector::new().with_classifier(Arc::new(FixedClassifier(0.9)), 0.8);
+        let detection = detector.detect("you people are the worst");
+        assert_eq!(detection.severity, AbuseSeverity::Mild);
+        assert_eq!(detection.classifier_score, Some(0.9));
+
+        let detector = AbuseDetector::new().with_classifier(Arc::new(FixedClassifier(0.2)), 0.8);
+        assert!(!detector.detect("you people are the worst").is_abusive());
+    }
+}
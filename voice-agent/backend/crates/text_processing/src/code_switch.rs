@@ -0,0 +1,468 @@
+//! Script-Consistent Response Rendering
+//!
+//! LLM answers for a Hindi session occasionally slip into English words, a
+//! romanized phrase or even a word in another Indic script. The TTS voice for
+//! the session reads one script, so stray words come out garbled. Before a
+//! response is chunked for TTS every word is checked against the target
+//! script and, unless the configured [`CodeSwitchLevel`] allows it:
+//!
+//! 1. Indic words in another Brahmic script are transliterated letter by
+//!    letter (the Unicode blocks share one layout).
+//! 2. Hindi domain words are transliterated through the
+//!    [`transliteration`](crate::transliteration) lexicon in either direction.
+//! 3. Anything left is a stray segment; adjacent stray words are grouped so
+//!    [`CodeSwitchRenderer::render_translated`] can translate them as phrases.
+//!
+//! Whitespace is kept exactly, so streamed chunks can be rendered one at a
+//! time as long as they end on a word boundary.
+//!
+//! # Example
+//!
+//! ```
+//! use voice_agent_core::Language;
+//! use voice_agent_text_processing::code_switch::{CodeSwitchConfig, CodeSwitchRenderer};
+//!
+//! let renderer = CodeSwitchRenderer::new(CodeSwitchConfig::default());
+//! assert_eq!(
+//!     renderer.render("आपका loan मंज़ूर हो गया", Language::Hindi),
+//!     "आपका loan मंज़ूर हो गया"
+//! );
+//! assert_eq!(renderer.render("Your लोन is approved", Language::English), "Your loan is approved");
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+use voice_agent_core::{Language, Script, Translator};
+
+use crate::translation::ScriptDetector;
+use crate::transliteration;
+
+/// English words commonly spoken as-is in Indian-language banking calls
+const DEFAULT_LOANWORDS: &[&str] = &[
+    "loan",
+    "gold",
+    "bank",
+    "branch",
+    "account",
+    "online",
+    "app",
+    "ok",
+    "okay",
+    "interest",
+    "rate",
+    "form",
+    "document",
+    "documents",
+    "mobile",
+    "number",
+    "process",
+    "offer",
+];
+
+/// Longest all-caps word treated as an acronym (EMI, KYC, PAN)
+const MAX_ACRONYM_LEN: usize = 5;
+
+/// How much code-switching a response may keep
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CodeSwitchLevel {
+    /// Every word in the target script
+    Strict,
+    /// English loanwords and acronyms may stay in Latin script
+    #[default]
+    Loanwords,
+    /// Any English may stay; only words in a third script are rewritten
+    Free,
+}
+
+/// Configuration for [`CodeSwitchRenderer`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeSwitchConfig {
+    /// How much mixing is left alone
+    #[serde(default)]
+    pub level: CodeSwitchLevel,
+    /// Loanwords kept in Latin script, on top of the built-in list
+    #[serde(default)]
+    pub loanwords: Vec<String>,
+}
+
+impl Default for CodeSwitchConfig {
+    fn default() -> Self {
+        Self {
+            level: CodeSwitchLevel::Loanwords,
+            loanwords: Vec::new(),
+        }
+    }
+}
+
+/// A rendered span of the response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Segment {
+    /// Text already in the target script (or allowed to stay as it is)
+    Kept(String),
+    /// Words that couldn't be transliterated, with the language they look like
+    Stray { text: String, language: Language },
+}
+
+/// Rewrites stray-script words in a response for the session's TTS voice
+pub struct CodeSwitchRenderer {
+    level: CodeSwitchLevel,
+    loanwords: HashSet<String>,
+    detector: ScriptDetector,
+    translator: Option<Arc<dyn Translator>>,
+}
+
+impl CodeSwitchRenderer {
+    /// Create a renderer
+    pub fn new(config: CodeSwitchConfig) -> Self {
+        let loanwords = DEFAULT_LOANWORDS
+            .iter()
+            .map(|word| word.to_string())
+            .chain(config.loanwords.iter().map(|word| word.to_lowercase()))
+            .collect();
+        Self {
+            level: config.level,
+            loanwords,
+            detector: ScriptDetector::new(),
+            translator: None,
+        }
+    }
+
+    /// Translate stray segments the lexicon can't transliterate
+    pub fn with_translator(mut self, translator: Arc<dyn Translator>) -> Self {
+        self.translator = Some(translator);
+        self
+    }
+
+    /// Render `text` for `target`, leaving untransliterable words as they are
+    pub fn render(&self, text: &str, target: Language) -> String {
+        self.segments(text, target)
+            .into_iter()
+            .map(|segment| match segment {
+                Segment::Kept(text) | Segment::Stray { text, .. } => text,
+            })
+            .collect()
+    }
+
+    /// Render `text` for `target`, translating stray segments
+    ///
+    /// Without a translator this is [`render`](Self::render). A segment the
+    /// translator fails on is spoken as it was written.
+    pub async fn render_translated(&self, text: &str, target: Language) -> String {
+        let Some(ref translator) = self.translator else {
+            return self.render(text, target);
+        };
+        let mut out = String::with_capacity(text.len());
+        for segment in self.segments(text, target) {
+            match segment {
+                Segment::Kept(text) => out.push_str(&text),
+                Segment::Stray { text, language } if translator.supports_pair(language, target) => {
+                    match translator.translate(&text, language, target).await {
+                        Ok(translated) if !translated.trim().is_empty() => {
+                            out.push_str(translated.trim())
+                        },
+                        Ok(_) => out.push_str(&text),
+                        Err(e) => {
+                            tracing::debug!(error = %e, segment = %text, "Stray segment kept untranslated");
+                            out.push_str(&text);
+                        },
+                    }
+                },
+                Segment::Stray { text, .. } => out.push_str(&text),
+            }
+        }
+        out
+    }
+
+    /// Split `text` into kept and stray segments for `target`
+    ///
+    /// Concatenating the segments gives back the rendered text; whitespace
+    /// between two stray words belongs to the stray segment.
+    pub fn segments(&self, text: &str, target: Language) -> Vec<Segment> {
+        let target_script = target.script();
+        let mut segments: Vec<Segment> = Vec::new();
+
+        let mut rest = text;
+        while !rest.is_empty() {
+            let space_len = rest.len() - rest.trim_start().len();
+            let (space, after) = rest.split_at(space_len);
+            let word_len = after.find(char::is_whitespace).unwrap_or(after.len());
+            let (word, after) = after.split_at(word_len);
+            rest = after;
+
+            // Whitespace joins a stray run only if another stray word follows
+            let rendered = if word.is_empty() {
+                None
+            } else {
+                self.render_word(word, target, target_script)
+            };
+            match (rendered, segments.last_mut()) {
+                (None, Some(Segment::Stray { text, language })) if !word.is_empty() => {
+                    let word_language = self.detector.detect(word);
+                    if *language == word_language {
+                        text.push_str(space);
+                        text.push_str(word);
+                    } else {
+                        segments.push(Segment::Kept(space.to_string()));
+                        segments.push(Segment::Stray {
+                            text: word.to_string(),
+                            language: word_language,
+                        });
+                    }
+                },
+                (None, _) if !word.is_empty() => {
+                    push_kept(&mut segments, space);
+                    segments.push(Segment::Stray {
+                        text: word.to_string(),
+                        language: self.detector.detect(word),
+                    });
+                },
+                (rendered, _) => {
+                    push_kept(&mut segments, space);
+                    push_kept(&mut segments, rendered.as_deref().unwrap_or(word));
+                },
+            }
+        }
+
+        segments
+    }
+
+    /// The word as it should be spoken, or `None` if it is a stray word
+    fn render_word(&self, word: &str, target: Language, target_script: Script) -> Option<String> {
+        let Some(script) = self.word_script(word) else {
+            // Digits, punctuation and symbols read the same in any script
+            return Some(word.to_string());
+        };
+        if script == target_script {
+            return Some(word.to_string());
+        }
+
+        if script == Script::Latin {
+            let allowed = match self.level {
+                CodeSwitchLevel::Strict => false,
+                CodeSwitchLevel::Loanwords => self.is_loanword(word),
+                CodeSwitchLevel::Free => true,
+            };
+            if allowed {
+                return Some(word.to_string());
+            }
+            if target_script == Script::Devanagari {
+                let devanagari = transliteration::to_devanagari(word);
+                if devanagari != word {
+                    return Some(devanagari);
+                }
+            }
+            return None;
+        }
+
+        if let Some(shifted) = shift_brahmic(word, script, target_script) {
+            return Some(shifted);
+        }
+        if script == Script::Devanagari && target == Language::English {
+            let roman = transliteration::to_roman(word);
+            if roman != word {
+                return Some(roman);
+            }
+        }
+        None
+    }
+
+    /// Script of a word's letters, `None` for words without letters
+    fn word_script(&self, word: &str) -> Option<Script> {
+        let letters: String = word
+            .chars()
+            .filter(|c| c.is_alphabetic() || is_mark(*c))
+            .collect();
+        if letters.is_empty() {
+            return None;
+        }
+        Some(self.detector.detect_script(&letters))
+    }
+
+    fn is_loanword(&self, word: &str) -> bool {
+        let core = word.trim_matches(|c: char| !c.is_alphanumeric());
+        let is_acronym = core.len() <= MAX_ACRONYM_LEN
+            && core.chars().any(|c| c.is_ascii_alphabetic())
+            && core
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+        is_acronym || self.loanwords.contains(&core.to_lowercase())
+    }
+}
+
+fn push_kept(segments: &mut Vec<Segment>, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    match segments.last_mut() {
+        Some(Segment::Kept(kept)) => kept.push_str(text),
+        _ => segments.push(Segment::Kept(text.to_string())),
+    }
+}
+
+/// Indic vowel signs and viramas aren't alphabetic but belong to the word
+fn is_mark(c: char) -> bool {
+    matches!(c as u32, 0x0900..=0x0D7F) && !c.is_alphabetic()
+}
+
+/// First code point of each Brahmic block sharing the ISCII-derived layout
+///
+/// Tamil is left out: it has no letters for most aspirated and voiced
+/// consonants, so a letter-by-letter copy would produce unassigned code points.
+fn brahmic_base(script: Script) -> Option<u32> {
+    match script {
+        Script::Devanagari => Some(0x0900),
+        Script::Bengali => Some(0x0980),
+        Script::Gurmukhi => Some(0x0A00),
+        Script::Gujarati => Some(0x0A80),
+        Script::Odia => Some(0x0B00),
+        Script::Telugu => Some(0x0C00),
+        Script::Kannada => Some(0x0C80),
+        Script::Malayalam => Some(0x0D00),
+        _ => None,
+    }
+}
+
+/// Transliterate a word between two Brahmic scripts by block offset
+fn shift_brahmic(word: &str, from: Script, to: Script) -> Option<String> {
+    let from_base = brahmic_base(from)?;
+    let to_base = brahmic_base(to)?;
+    word.chars()
+        .map(|c| {
+            let code = c as u32;
+            if (from_base..from_base + 0x80).contains(&code) {
+                char::from_u32(code - from_base + to_base)
+            } else {
+                Some(c)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use futures::Stream;
+    use std::pin::Pin;
+
+    fn renderer(level: CodeSwitchLevel) -> CodeSwitchRenderer {
+        CodeSwitchRenderer::new(CodeSwitchConfig {
+            level,
+            loanwords: vec!["Processing".to_string()],
+        })
+    }
+
+    #[test]
+    fn test_levels() {
+        let text = "आपका EMI और loan status तैयार है";
+
+        let free = renderer(CodeSwitchLevel::Free);
+        assert_eq!(free.render(text, Language::Hindi), text);
+
+        // Acronyms and loanwords stay, other English is stray
+        let loanwords = renderer(CodeSwitchLevel::Loanwords);
+        let segments = loanwords.segments(text, Language::Hindi);
+        assert_eq!(
+            segments,
+            vec![
+                Segment::Kept("आपका EMI और loan ".to_string()),
+                Segment::Stray {
+                    text: "status".to_string(),
+                    language: Language::English
+                },
+                Segment::Kept(" तैयार है".to_string()),
+            ]
+        );
+        assert!(loanwords
+            .render("processing जारी है", Language::Hindi)
+            .starts_with("processing"));
+
+        // Lexicon words are transliterated instead of kept
+        let strict = renderer(CodeSwitchLevel::Strict);
+        assert_eq!(
+            strict.render("आपका gold loan तैयार है", Language::Hindi),
+            "आपका गोल्ड लोन तैयार है"
+        );
+    }
+
+    #[test]
+    fn test_stray_words_grouped() {
+        let strict = renderer(CodeSwitchLevel::Strict);
+        let segments = strict.segments("कृपया  please wait कीजिए।", Language::Hindi);
+        assert_eq!(
+            segments,
+            vec![
+                Segment::Kept("कृपया  ".to_string()),
+                Segment::Stray {
+                    text: "please wait".to_string(),
+                    language: Language::English
+                },
+                Segment::Kept(" कीजिए।".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_other_scripts() {
+        let renderer = renderer(CodeSwitchLevel::Loanwords);
+        // Bengali letters in a Hindi answer move to Devanagari
+        assert_eq!(renderer.render("আমি ठीक हूँ", Language::Hindi), "आमि ठीक हूँ");
+        // Devanagari lexicon words in an English answer are romanized
+        assert_eq!(
+            renderer.render("The ब्याज is 9%", Language::English),
+            "The byaj is 9%"
+        );
+        // Whitespace and digits are untouched, so chunks can be rendered separately
+        assert_eq!(renderer.render(" 5 लाख, ", Language::Hindi), " 5 लाख, ");
+        assert_eq!(renderer.render("", Language::Hindi), "");
+    }
+
+    struct UppercaseTranslator;
+
+    #[async_trait]
+    impl Translator for UppercaseTranslator {
+        async fn translate(
+            &self,
+            text: &str,
+            _from: Language,
+            _to: Language,
+        ) -> voice_agent_core::Result<String> {
+            Ok(format!("[{}]", text.to_uppercase()))
+        }
+
+        async fn detect_language(&self, _text: &str) -> voice_agent_core::Result<Language> {
+            Ok(Language::English)
+        }
+
+        fn translate_stream<'a>(
+            &'a self,
+            text_stream: Pin<Box<dyn Stream<Item = String> + Send + 'a>>,
+            _from: Language,
+            _to: Language,
+        ) -> Pin<Box<dyn Stream<Item = voice_agent_core::Result<String>> + Send + 'a>> {
+            use futures::StreamExt;
+            Box::pin(text_stream.map(Ok))
+        }
+
+        fn supports_pair(&self, _from: Language, _to: Language) -> bool {
+            true
+        }
+
+        fn name(&self) -> &str {
+            "uppercase"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_render_translated() {
+        let text = "कृपया please wait कीजिए";
+        let strict = renderer(CodeSwitchLevel::Strict);
+        assert_eq!(strict.render_translated(text, Language::Hindi).await, text);
+
+        let strict = strict.with_translator(Arc::new(UppercaseTranslator));
+        let rendered = strict.render_translated(text, Language::Hindi).await;
+        assert_eq!(rendered, "कृपया [PLEASE WAIT] कीजिए");
+    }
+}
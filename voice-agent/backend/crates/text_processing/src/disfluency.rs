@@ -0,0 +1,207 @@
+//! Disfluency Cleanup
+//!
+//! ASR transcripts keep everything a caller says: hesitations ("umm",
+//! "hmm"), filler words ("matlab", "you know"), stutters ("mujhe mujhe") and
+//! false starts ("a pers- a gold loan"). These break the extraction regexes
+//! and distract the LLM, so [`clean`] removes them before intent and entity
+//! extraction:
+//!
+//! - hesitation sounds are dropped wherever they occur;
+//! - filler phrases of the caller's language (plus English, since callers
+//!   code-mix) are dropped when set off by a pause or when they open a longer
+//!   utterance, so "iska matlab kya hai" keeps its meaning;
+//! - words cut off mid-way ("pers-") are dropped;
+//! - immediately repeated words and phrases collapse to one, except numbers,
+//!   which callers repeat on purpose ("98 98 ...").
+//!
+//! # Example
+//!
+//! ```
+//! use voice_agent_text_processing::disfluency::clean;
+//!
+//! assert_eq!(clean("umm mujhe mujhe gold loan chahiye", "hi"), "mujhe gold loan chahiye");
+//! assert_eq!(clean("I want a pers- a gold loan", "en"), "I want a gold loan");
+//! ```
+
+/// Hesitation sounds, with repeated letters folded ("ummm" -> "um")
+const HESITATIONS: &str = "um uh uhm hm mm er erm ah eh अं उम्म हम्म";
+
+/// Filler phrases by language code, one per line
+const FILLERS: &[(&str, &str)] = &[
+    ("en", "you know\ni mean\nlike i said\nkind of\nsort of"),
+    (
+        "hi",
+        "matlab\nyaani\nwo kya hai\nkya kehte hain\nमतलब\nयानी\nवो क्या है\nक्या कहते हैं",
+    ),
+];
+
+/// A filler opening the utterance is dropped only if this many words follow
+const MIN_WORDS_AFTER_LEADING_FILLER: usize = 3;
+
+/// Longest phrase (in words) checked for repetition
+const MAX_REPEATED_PHRASE: usize = 3;
+
+struct Word<'a> {
+    raw: &'a str,
+    /// Lowercased, without surrounding punctuation
+    key: String,
+    /// Followed by a pause (comma, ellipsis, sentence end)
+    pause_after: bool,
+}
+
+impl<'a> Word<'a> {
+    fn parse(raw: &'a str) -> Self {
+        Self {
+            raw,
+            key: raw.trim_matches(is_punctuation).to_lowercase(),
+            pause_after: raw.ends_with([',', '.', '…', ';', '!', '?', '।']),
+        }
+    }
+
+    fn is_hesitation(&self) -> bool {
+        let mut chars: Vec<char> = self.key.chars().collect();
+        chars.dedup();
+        let folded: String = chars.into_iter().collect();
+        // "mmm" folds to "m", which on its own could be an initial
+        HESITATIONS
+            .split_whitespace()
+            .any(|h| h == folded || h == self.key)
+            || (folded == "m" && self.key.len() > 1)
+    }
+
+    /// Cut off mid-word, as ASR marks false starts ("pers-")
+    fn is_truncated(&self) -> bool {
+        self.raw.ends_with(['-', '—'])
+    }
+
+    fn is_number(&self) -> bool {
+        !self.key.is_empty() && self.key.chars().all(|c| c.is_ascii_digit())
+    }
+}
+
+/// Remove hesitations, fillers, false starts and repetitions
+///
+/// Returns the utterance unchanged (trimmed) if nothing else would be left,
+/// so a bare "hmm" still reaches the agent.
+pub fn clean(text: &str, language: &str) -> String {
+    let words: Vec<Word> = text.split_whitespace().map(Word::parse).collect();
+    let fillers: Vec<Vec<&str>> = FILLERS
+        .iter()
+        .filter(|(code, _)| *code == "en" || *code == language)
+        .flat_map(|(_, phrases)| phrases.lines())
+        .map(|phrase| phrase.split_whitespace().collect())
+        .collect();
+
+    let mut kept: Vec<&Word> = Vec::with_capacity(words.len());
+    let mut i = 0;
+    while i < words.len() {
+        let word = &words[i];
+        if word.is_hesitation() || word.is_truncated() {
+            i += 1;
+            continue;
+        }
+        if let Some(len) = filler_at(&words, i, &fillers, kept.is_empty()) {
+            i += len;
+            continue;
+        }
+        kept.push(word);
+        collapse_repetition(&mut kept);
+        i += 1;
+    }
+
+    if kept.is_empty() {
+        return text.trim().to_string();
+    }
+    kept.iter().map(|w| w.raw).collect::<Vec<_>>().join(" ")
+}
+
+/// Length of the filler phrase starting at `i`, if it should be dropped
+fn filler_at(words: &[Word], i: usize, fillers: &[Vec<&str>], at_start: bool) -> Option<usize> {
+    let phrase = fillers.iter().find(|phrase| {
+        words.len() >= i + phrase.len()
+            && phrase
+                .iter()
+                .zip(&words[i..])
+                .all(|(filler, word)| word.key == *filler)
+    })?;
+    let end = i + phrase.len();
+
+    let set_off = words[end - 1].pause_after || (i > 0 && words[i - 1].pause_after);
+    let leading = at_start && words.len() - end >= MIN_WORDS_AFTER_LEADING_FILLER;
+    (set_off || leading).then_some(phrase.len())
+}
+
+/// Drop the earlier copy of a phrase repeated at the end of `kept`
+fn collapse_repetition(kept: &mut Vec<&Word>) {
+    for n in (1..=MAX_REPEATED_PHRASE).rev() {
+        let len = kept.len();
+        if len < 2 * n {
+            continue;
+        }
+        let (earlier, later) = (&kept[len - 2 * n..len - n], &kept[len - n..]);
+        let repeated = earlier.iter().zip(later).all(|(a, b)| a.key == b.key);
+        if repeated && !later.iter().any(|w| w.is_number()) {
+            kept.drain(len - 2 * n..len - n);
+            return;
+        }
+    }
+}
+
+fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation() || matches!(c, '…' | '।' | '—')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(cases: &[(&str, &str, &str)]) {
+        for (input, language, expected) in cases {
+            assert_eq!(clean(input, language), *expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_hesitations_and_stutters() {
+        check(&[
+            ("ummm I I want uh a loan", "en", "I want a loan"),
+            ("hmm", "en", "hmm"),
+            (
+                "mujhe gold loan gold loan chahiye",
+                "hi",
+                "mujhe gold loan chahiye",
+            ),
+            // The later copy keeps its punctuation
+            ("mera, mera naam Rahul hai", "hi", "mera naam Rahul hai"),
+            ("I want a pers- a gold loan", "en", "I want a gold loan"),
+        ]);
+    }
+
+    #[test]
+    fn test_fillers_need_pause_or_lead() {
+        check(&[
+            ("matlab mujhe loan chahiye", "hi", "mujhe loan chahiye"),
+            (
+                "mujhe, matlab, 5 lakh chahiye",
+                "hi",
+                "mujhe, 5 lakh chahiye",
+            ),
+            ("iska matlab kya hai", "hi", "iska matlab kya hai"),
+            // Hindi fillers only apply to Hindi turns
+            (
+                "matlab mujhe loan chahiye",
+                "en",
+                "matlab mujhe loan chahiye",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_numbers_not_collapsed() {
+        check(&[(
+            "my number is 98 98 76 54 32",
+            "en",
+            "my number is 98 98 76 54 32",
+        )]);
+    }
+}
@@ -334,7 +334,12 @@ impl EntityExtractor {
     }
 
     /// Extract all entities from text
+    ///
+    /// Romanized and Devanagari number words are normalized first
+    /// (see [`crate::transliteration::normalize`]).
     pub fn extract(&self, text: &str) -> ExtractedEntities {
+        let text = crate::transliteration::normalize(text);
+        let text = text.as_str();
         ExtractedEntities {
             amount: self.extract_amount(text),
             collateral_weight: self.extract_weight(text),
@@ -663,6 +668,20 @@ mod tests {
         assert_eq!(amount.rupees(), 500000.0);
     }
 
+    #[test]
+    fn test_mixed_script_amount() {
+        let extractor = EntityExtractor::new();
+
+        for text in [
+            "mujhe paanch lakh ka loan chahiye",
+            "मुझे पांच lakh चाहिए",
+            "mujhe ५ लाख chahiye",
+        ] {
+            let entities = extractor.extract(text);
+            assert_eq!(entities.amount.map(|a| a.rupees()), Some(500000.0), "{}", text);
+        }
+    }
+
     #[test]
     fn test_merge_entities() {
         let mut entities1 = ExtractedEntities::default();
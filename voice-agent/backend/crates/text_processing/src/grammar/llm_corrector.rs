@@ -5,7 +5,8 @@ use futures::Stream;
 use std::pin::Pin;
 use std::sync::Arc;
 use voice_agent_core::{
-    DomainContext, GenerateRequest, GrammarCorrector, LanguageModel, Message, Result, Role,
+    DomainContext, GenerateRequest, GrammarCorrector, LanguageModel, LlmTask, Message, Result,
+    Role,
 };
 
 /// Grammar corrector using LLM
@@ -106,6 +107,7 @@ impl GrammarCorrector for LLMGrammarCorrector {
             max_tokens: Some(256),
             temperature: Some(self.temperature),
             stream: false,
+            task: Some(LlmTask::Extraction),
             ..Default::default()
         };
 
@@ -6,7 +6,7 @@
 /// Convert Hindi number word (Devanagari script) to numeric value
 ///
 /// Handles common Hindi number words in Devanagari script.
-/// For romanized Hindi (ek, do, teen), see vocabulary.yaml config.
+/// For romanized Hindi (ek, do, teen), see [`roman_word_to_number`].
 ///
 /// # Examples
 /// ```
@@ -46,6 +46,54 @@ pub fn word_to_number(word: &str) -> Option<f64> {
     }
 }
 
+/// Convert romanized Hindi number word to numeric value
+///
+/// Accepts the common spelling variants heard from STT ("paanch" / "panch",
+/// "chaar" / "char") and the fractional words used with lakh/crore
+/// ("dedh" = 1.5, "dhai" = 2.5). Input must already be lowercase.
+///
+/// # Examples
+/// ```
+/// use voice_agent_text_processing::hindi::roman_word_to_number;
+/// assert_eq!(roman_word_to_number("paanch"), Some(5.0));
+/// assert_eq!(roman_word_to_number("dedh"), Some(1.5));
+/// assert_eq!(roman_word_to_number("loan"), None);
+/// ```
+pub fn roman_word_to_number(word: &str) -> Option<f64> {
+    match word {
+        // Basic numbers 1-10
+        "ek" => Some(1.0),
+        "do" => Some(2.0),
+        "teen" | "tin" => Some(3.0),
+        "char" | "chaar" | "chār" => Some(4.0),
+        "paanch" | "panch" | "paach" | "panc" => Some(5.0),
+        "chhe" | "chheh" | "chhah" | "chah" | "che" => Some(6.0),
+        "saat" | "sat" => Some(7.0),
+        "aath" | "ath" => Some(8.0),
+        "nau" | "nao" => Some(9.0),
+        "das" | "dus" => Some(10.0),
+
+        // Tens
+        "bees" | "bis" => Some(20.0),
+        "pachchees" | "pachees" | "pachis" => Some(25.0),
+        "tees" | "tis" => Some(30.0),
+        "paintees" | "paintis" => Some(35.0),
+        "chaalis" | "chalis" | "chalees" => Some(40.0),
+        "pachaas" | "pachas" => Some(50.0),
+        "saath" | "sath" => Some(60.0),
+        "sattar" | "sattur" => Some(70.0),
+        "assi" | "asi" => Some(80.0),
+        "nabbe" | "nabe" => Some(90.0),
+        "sau" => Some(100.0),
+
+        // Fractions
+        "dedh" | "derh" => Some(1.5),
+        "dhai" | "dhaai" | "adhai" => Some(2.5),
+
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,6 +121,14 @@ mod tests {
         assert_eq!(word_to_number("सौ"), Some(100.0));
     }
 
+    #[test]
+    fn test_roman_numbers() {
+        assert_eq!(roman_word_to_number("ek"), Some(1.0));
+        assert_eq!(roman_word_to_number("panch"), Some(5.0));
+        assert_eq!(roman_word_to_number("pachaas"), Some(50.0));
+        assert_eq!(roman_word_to_number("dhai"), Some(2.5));
+    }
+
     #[test]
     fn test_unknown() {
         assert_eq!(word_to_number("unknown"), None);
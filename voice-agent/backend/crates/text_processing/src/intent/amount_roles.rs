@@ -0,0 +1,146 @@
+//! Amount Roles
+//!
+//! "I have 2 lakh loan at Muthoot, want 5 lakh from you" names two amounts:
+//! the loan the customer already has and the one they want. [`assign_roles`]
+//! tells them apart by the cue words around each amount ("outstanding",
+//! "baki" vs "want", "chahiye") and by a lender named next to it.
+//!
+//! Each cue belongs to one amount: words before the first amount belong to
+//! it, words after the last amount to the last, and words between two amounts
+//! to whichever side of the first clause break ("," / "but" / "aur") they are
+//! on, or to the nearer amount when there is no break.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Role of an amount in the utterance
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AmountRole {
+    /// A loan the customer already has
+    Current,
+    /// The amount the customer asks for
+    Wanted,
+}
+
+/// Words marking an existing loan
+const CURRENT_CUES: &str = "current outstanding existing already pending balance remaining \
+     have has had took taken running old baki baaki bakaya liya chal rakha pehle \
+     मौजूदा बाकी बकाया लिया चल रखा पहले पुराना";
+
+/// Words marking the amount asked for
+const WANT_CUES: &str = "want need require looking apply more extra new additional \
+     chahiye chahie chaiye chahta chahti chahte lena zarurat jarurat \
+     चाहिए चाहता चाहती ज़रूरत जरूरत लेना नया";
+
+/// Words starting a new clause
+const CLAUSE_WORDS: &str = "but and aur lekin magar और लेकिन";
+
+/// Characters ending a clause
+const CLAUSE_MARKS: &[char] = &[',', '.', ';', '।', '?', '!'];
+
+/// Role of each amount, `None` where the cues are missing or tied
+///
+/// `amounts` and `lenders` are byte spans in `text`, with `amounts` in order.
+/// A lender named next to an amount counts as a cue for [`AmountRole::Current`].
+pub(crate) fn assign_roles(
+    text: &str,
+    amounts: &[(usize, usize)],
+    lenders: &[(usize, usize)],
+) -> Vec<Option<AmountRole>> {
+    if amounts.is_empty() {
+        return Vec::new();
+    }
+    let breaks = clause_breaks(text);
+    // (current, wanted) cue counts per amount
+    let mut scores = vec![(0u32, 0u32); amounts.len()];
+
+    for (start, word) in text.unicode_word_indices() {
+        let word = word.to_lowercase();
+        let Some(i) = owner(start, amounts, &breaks) else {
+            continue;
+        };
+        if has_word(CURRENT_CUES, &word) {
+            scores[i].0 += 1;
+        } else if has_word(WANT_CUES, &word) {
+            scores[i].1 += 1;
+        }
+    }
+    for &(start, _) in lenders {
+        if let Some(i) = owner(start, amounts, &breaks) {
+            scores[i].0 += 1;
+        }
+    }
+
+    scores
+        .into_iter()
+        .map(|(current, wanted)| match current.cmp(&wanted) {
+            std::cmp::Ordering::Greater => Some(AmountRole::Current),
+            std::cmp::Ordering::Less => Some(AmountRole::Wanted),
+            std::cmp::Ordering::Equal => None,
+        })
+        .collect()
+}
+
+/// Byte offsets where a clause ends
+fn clause_breaks(text: &str) -> Vec<usize> {
+    let marks = text.match_indices(CLAUSE_MARKS).map(|(i, _)| i);
+    let words = text
+        .unicode_word_indices()
+        .filter(|(_, word)| has_word(CLAUSE_WORDS, &word.to_lowercase()))
+        .map(|(i, _)| i);
+    let mut breaks: Vec<usize> = marks.chain(words).collect();
+    breaks.sort_unstable();
+    breaks
+}
+
+/// Index of the amount a word at `pos` belongs to (none inside an amount)
+fn owner(pos: usize, amounts: &[(usize, usize)], breaks: &[usize]) -> Option<usize> {
+    let inside = |&(start, end): &(usize, usize)| (start..end).contains(&pos);
+    if amounts.iter().any(inside) {
+        return None;
+    }
+    let next = amounts.iter().position(|&(start, _)| start > pos);
+    let i = match next {
+        None => amounts.len() - 1,
+        Some(0) => 0,
+        Some(next) => {
+            let gap = amounts[next - 1].1..amounts[next].0;
+            match breaks.iter().find(|&&b| gap.contains(&b)) {
+                Some(&split) if pos < split => next - 1,
+                Some(_) => next,
+                None if pos - gap.start <= gap.end - pos => next - 1,
+                None => next,
+            }
+        },
+    };
+    Some(i)
+}
+
+fn has_word(words: &str, word: &str) -> bool {
+    words.split_whitespace().any(|w| w == word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(text: &str, part: &str) -> (usize, usize) {
+        let start = text.find(part).unwrap();
+        (start, start + part.len())
+    }
+
+    #[test]
+    fn test_cues_split_at_clause_break() {
+        let text = "5 lakh chahiye, abhi 2 lakh outstanding hai";
+        let amounts = [span(text, "5 lakh"), span(text, "2 lakh")];
+        let roles = assign_roles(text, &amounts, &[]);
+        assert_eq!(roles, [Some(AmountRole::Wanted), Some(AmountRole::Current)]);
+    }
+
+    #[test]
+    fn test_lender_marks_current() {
+        let text = "Muthoot 2 lakh 5 lakh";
+        let amounts = [span(text, "2 lakh"), span(text, "5 lakh")];
+        let roles = assign_roles(text, &amounts, &[span(text, "Muthoot")]);
+        assert_eq!(roles, [Some(AmountRole::Current), None]);
+    }
+}
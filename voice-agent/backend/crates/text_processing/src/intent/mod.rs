@@ -21,11 +21,20 @@
 //! assert_eq!(result.intent, "eligibility_check");
 //! ```
 
+use once_cell::sync::Lazy;
 use parking_lot::RwLock;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use unicode_segmentation::UnicodeSegmentation;
+use voice_agent_core::{Gazetteer, LocationGazetteer};
+
+use crate::keywords::{plain_keywords, KeywordMatcher};
+use crate::transliteration;
+
+mod amount_roles;
+
+use amount_roles::{assign_roles, AmountRole};
 
 /// Intent definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -79,9 +88,13 @@ pub struct DetectedIntent {
     pub slots: HashMap<String, Slot>,
     /// Alternative intents
     pub alternatives: Vec<(String, f32)>,
+    /// Utterance text the slots were extracted from, verbatim (see
+    /// [`IntentDetector::slot_spans`])
+    pub slot_spans: Vec<String>,
 }
 
 /// Compiled slot pattern with its regex
+#[derive(Clone)]
 struct CompiledSlotPattern {
     name: String,
     regex: Regex,
@@ -90,13 +103,46 @@ struct CompiledSlotPattern {
     multiplier: Option<f64>,
 }
 
+/// Built-in slot patterns, compiled on first use and cloned into each detector
+static BUILTIN_SLOT_PATTERNS: Lazy<HashMap<String, Vec<CompiledSlotPattern>>> =
+    Lazy::new(IntentDetector::builtin_slot_patterns);
+
+/// Most distinct config patterns kept compiled
+const MAX_CACHED_CONFIG_PATTERNS: usize = 1024;
+
+/// Config-driven patterns compiled so far, keyed by pattern source
+///
+/// Every session of a domain wires the same competitor, variant and location
+/// patterns, so each distinct pattern is compiled once per process.
+static CONFIG_PATTERNS: Lazy<RwLock<HashMap<String, Regex>>> = Lazy::new(Default::default);
+
+fn compile_cached(pattern: &str) -> Result<Regex, regex::Error> {
+    if let Some(regex) = CONFIG_PATTERNS.read().get(pattern) {
+        return Ok(regex.clone());
+    }
+    let regex = Regex::new(pattern)?;
+    let mut cache = CONFIG_PATTERNS.write();
+    if cache.len() < MAX_CACHED_CONFIG_PATTERNS {
+        cache.insert(pattern.to_string(), regex.clone());
+    }
+    Ok(regex)
+}
+
 /// Intent detector
 pub struct IntentDetector {
     intents: RwLock<Vec<Intent>>,
     /// P0 FIX: Compiled regex patterns for slot extraction
     compiled_patterns: HashMap<String, Vec<CompiledSlotPattern>>,
+    /// Competitor names and aliases, when config supplies them as plain keywords
+    competitor_keywords: Option<KeywordMatcher>,
+    /// Names fuzzy-matched for slots the patterns leave empty, by slot name
+    gazetteers: Vec<(String, Gazetteer)>,
 }
 
+/// Confidence of a gazetteer match at similarity 1.0, below the exact
+/// patterns since a near miss may be the wrong name
+const GAZETTEER_CONFIDENCE: f32 = 0.8;
+
 impl IntentDetector {
     /// Create a new intent detector with minimal generic intents
     ///
@@ -105,6 +151,8 @@ impl IntentDetector {
         let mut detector = Self {
             intents: RwLock::new(Vec::new()),
             compiled_patterns: HashMap::new(),
+            competitor_keywords: None,
+            gazetteers: Vec::new(),
         };
 
         detector.register_core_intents();
@@ -121,6 +169,8 @@ impl IntentDetector {
         let mut detector = Self {
             intents: RwLock::new(intents),
             compiled_patterns: HashMap::new(),
+            competitor_keywords: None,
+            gazetteers: Vec::new(),
         };
         detector.compile_slot_patterns();
         detector
@@ -164,9 +214,26 @@ impl IntentDetector {
     /// ]);
     /// ```
     pub fn add_competitor_patterns(&mut self, competitors: Vec<(&str, &str, &str)>) {
+        // Name lists generated from config are matched in one pass instead of
+        // one regex per competitor
+        let keywords: Option<Vec<_>> = competitors
+            .iter()
+            .map(|(id, _, pattern)| plain_keywords(pattern).map(|keywords| (*id, keywords)))
+            .collect();
+        if let Some(Ok(matcher)) = keywords
+            .filter(|keywords| !keywords.is_empty())
+            .map(KeywordMatcher::whole_words)
+        {
+            tracing::debug!("Added {} competitor keywords from config", matcher.len());
+            self.competitor_keywords = Some(matcher);
+            self.compiled_patterns
+                .insert("current_lender".to_string(), Vec::new());
+            return;
+        }
+
         let mut patterns = Vec::new();
         for (id, _display_name, pattern) in competitors {
-            if let Ok(regex) = Regex::new(pattern) {
+            if let Ok(regex) = compile_cached(pattern) {
                 patterns.push(CompiledSlotPattern {
                     name: id.to_string(),
                     regex,
@@ -178,11 +245,25 @@ impl IntentDetector {
             }
         }
         if !patterns.is_empty() {
+            self.competitor_keywords = None;
             self.compiled_patterns.insert("current_lender".to_string(), patterns);
             tracing::debug!("Added {} competitor patterns from config", self.compiled_patterns.get("current_lender").map(|p| p.len()).unwrap_or(0));
         }
     }
 
+    /// Fuzzy-match `gazetteer` names for `slot` when its patterns find nothing
+    ///
+    /// Near-miss transcriptions ("mana puram" for Manappuram) still fill the
+    /// slot, with confidence scaled by the match similarity.
+    pub fn add_gazetteer(&mut self, slot: &str, gazetteer: Gazetteer) {
+        if gazetteer.is_empty() {
+            return;
+        }
+        tracing::debug!(slot = slot, names = gazetteer.len(), "Added gazetteer");
+        self.gazetteers.retain(|(name, _)| name != slot);
+        self.gazetteers.push((slot.to_string(), gazetteer));
+    }
+
     /// Add collateral variant patterns from domain config
     ///
     /// This allows loading variant patterns (e.g., purity grades) from config.
@@ -192,7 +273,7 @@ impl IntentDetector {
     pub fn add_variant_patterns(&mut self, variants: Vec<(&str, &str)>) {
         let mut valid_variants = Vec::new();
         for (id, pattern) in &variants {
-            if let Ok(regex) = Regex::new(pattern) {
+            if let Ok(regex) = compile_cached(pattern) {
                 valid_variants.push(id.to_uppercase());
                 // We'll use a combined pattern for now
                 let _ = regex; // Pattern validated
@@ -206,7 +287,7 @@ impl IntentDetector {
                 .collect::<Vec<_>>()
                 .join("|");
 
-            if let Ok(regex) = Regex::new(&format!("(?i){}", pattern_str)) {
+            if let Ok(regex) = compile_cached(&format!("(?i){}", pattern_str)) {
                 self.compiled_patterns.insert(
                     "collateral_variant".to_string(),
                     vec![CompiledSlotPattern {
@@ -229,7 +310,7 @@ impl IntentDetector {
     /// # Arguments
     /// * `pattern` - Combined regex pattern for all city names
     pub fn set_location_pattern(&mut self, pattern: &str) {
-        if let Ok(regex) = Regex::new(pattern) {
+        if let Ok(regex) = compile_cached(pattern) {
             self.compiled_patterns.insert(
                 "location".to_string(),
                 vec![CompiledSlotPattern {
@@ -420,8 +501,9 @@ impl IntentDetector {
     /// P0 FIX: Compile slot patterns into regex at startup
     ///
     /// This replaces the old register_slot_patterns() which stored patterns
-    /// as strings but never used them. Now patterns are compiled once and
-    /// reused for all extractions.
+    /// as strings but never used them. Now patterns are compiled once per
+    /// process and shared by every detector; cloning a `Regex` only bumps a
+    /// reference count.
     ///
     /// P0 FIX (Dec 2025): Added Devanagari script support for Hindi users.
     /// Includes:
@@ -429,6 +511,13 @@ impl IntentDetector {
     /// - Hindi number words (पांच, दस, बीस, etc.)
     /// - Hindi multiplier words (लाख, करोड़, हज़ार)
     fn compile_slot_patterns(&mut self) {
+        self.compiled_patterns = BUILTIN_SLOT_PATTERNS.clone();
+    }
+
+    /// Built-in slot patterns, compiled once into [`BUILTIN_SLOT_PATTERNS`]
+    fn builtin_slot_patterns() -> HashMap<String, Vec<CompiledSlotPattern>> {
+        let mut patterns = HashMap::new();
+
         // Loan amount patterns - P0 FIX: Added Devanagari support
         let loan_patterns = vec![
             // === DEVANAGARI PATTERNS (Hindi) - Check first for proper Hindi support ===
@@ -659,8 +748,7 @@ impl IntentDetector {
                 multiplier: None,
             },
         ];
-        self.compiled_patterns
-            .insert("loan_amount".to_string(), loan_patterns);
+        patterns.insert("loan_amount".to_string(), loan_patterns);
 
         // Gold weight patterns
         let weight_patterns = vec![
@@ -677,8 +765,7 @@ impl IntentDetector {
                 multiplier: Some(11.66), // 1 tola = 11.66 grams
             },
         ];
-        self.compiled_patterns
-            .insert("gold_weight".to_string(), weight_patterns);
+        patterns.insert("gold_weight".to_string(), weight_patterns);
 
         // Phone patterns - using phone_number to match DST slot naming
         let phone_patterns = vec![CompiledSlotPattern {
@@ -687,19 +774,16 @@ impl IntentDetector {
             slot_type: SlotType::Phone,
             multiplier: None,
         }];
-        self.compiled_patterns
-            .insert("phone_number".to_string(), phone_patterns);
+        patterns.insert("phone_number".to_string(), phone_patterns);
 
         // Current provider patterns (empty by default - domain-agnostic)
         //
         // P18 FIX: No hardcoded competitors. For production use, load competitor patterns
         // from domain config using add_competitor_patterns() after construction.
         // Example: detector.add_competitor_patterns(view.competitor_intent_patterns());
-        self.compiled_patterns
-            .insert("current_provider".to_string(), vec![]);
+        patterns.insert("current_provider".to_string(), vec![]);
         // Legacy alias for backwards compatibility
-        self.compiled_patterns
-            .insert("current_lender".to_string(), vec![]);
+        patterns.insert("current_lender".to_string(), vec![]);
 
         // Collateral variant patterns (DEFAULT - override with add_variant_patterns())
         //
@@ -711,34 +795,36 @@ impl IntentDetector {
             slot_type: SlotType::Enum(vec!["18K".into(), "22K".into(), "24K".into()]),
             multiplier: None,
         }];
-        self.compiled_patterns
-            .insert("collateral_variant".to_string(), variant_patterns);
+        patterns.insert("collateral_variant".to_string(), variant_patterns);
         // Legacy alias for backwards compatibility
-        self.compiled_patterns
-            .insert("gold_purity".to_string(), vec![CompiledSlotPattern {
+        patterns.insert(
+            "gold_purity".to_string(),
+            vec![CompiledSlotPattern {
                 name: "karat".to_string(),
                 regex: Regex::new(r"(?i)(22|24|18)\s*(?:k|karat|carat|kt)").unwrap(),
                 slot_type: SlotType::Enum(vec!["18K".into(), "22K".into(), "24K".into()]),
                 multiplier: None,
-            }]);
+            }],
+        );
 
-        // Location/City patterns
+        // Location/City patterns from the built-in gazetteer, until
+        // set_location_pattern() installs the domain's cities
+        let cities: Vec<String> = LocationGazetteer::builtin()
+            .cities()
+            .iter()
+            .flat_map(|city| city.names().map(regex::escape))
+            .collect();
         let location_patterns = vec![CompiledSlotPattern {
             name: "city".to_string(),
-            regex: Regex::new(
-                r"(?i)\b(mumbai|delhi|bangalore|chennai|hyderabad|kolkata|pune|ahmedabad|jaipur)\b",
-            )
-            .unwrap(),
+            regex: Regex::new(&format!(r"(?i)\b({})\b", cities.join("|"))).unwrap(),
             slot_type: SlotType::Location,
             multiplier: None,
         }];
-        self.compiled_patterns
-            .insert("location".to_string(), location_patterns);
+        patterns.insert("location".to_string(), location_patterns);
 
-        tracing::debug!(
-            "Compiled {} slot pattern groups",
-            self.compiled_patterns.len()
-        );
+        tracing::debug!("Compiled {} slot pattern groups", patterns.len());
+
+        patterns
     }
 
     /// Detect intent from text
@@ -746,10 +832,22 @@ impl IntentDetector {
         let intents = self.intents.read();
         let text_lower = text.to_lowercase();
 
+        // Score the utterance as spoken and transliterated into each script,
+        // so romanized input matches Devanagari examples and vice versa
+        let mut forms = vec![
+            transliteration::to_roman(&text_lower),
+            transliteration::to_devanagari(&text_lower),
+        ];
+        forms.retain(|form| *form != text_lower);
+        forms.insert(0, text_lower);
+
         let mut scores: Vec<(String, f32)> = intents
             .iter()
             .map(|intent| {
-                let score = self.calculate_intent_score(&text_lower, intent);
+                let score = forms
+                    .iter()
+                    .map(|form| self.calculate_intent_score(form, intent))
+                    .fold(0.0, f32::max);
                 (intent.name.clone(), score)
             })
             .collect();
@@ -770,9 +868,36 @@ impl IntentDetector {
             confidence: best_score,
             slots,
             alternatives: scores.into_iter().skip(1).take(3).collect(),
+            slot_spans: self.slot_spans(text),
         }
     }
 
+    /// Parts of `text` the slot patterns and lender names match, as written
+    ///
+    /// Slot values are normalized ("paanch lakh" is stored as "500000"), so
+    /// they rarely appear in the utterance itself; these spans do.
+    pub fn slot_spans(&self, text: &str) -> Vec<String> {
+        let mut spans: Vec<String> = self
+            .compiled_patterns
+            .values()
+            .flatten()
+            .flat_map(|pattern| pattern.regex.captures_iter(text))
+            .filter_map(|captures| captures.get(1))
+            .map(|matched| matched.as_str().trim().to_string())
+            .collect();
+        if let Some(ref matcher) = self.competitor_keywords {
+            spans.extend(
+                matcher
+                    .matches(text)
+                    .map(|m| text[m.start..m.end].to_string()),
+            );
+        }
+        spans.retain(|span| !span.is_empty());
+        spans.sort_unstable();
+        spans.dedup();
+        spans
+    }
+
     /// Calculate intent match score
     ///
     /// P2 FIX: Uses unicode_segmentation for proper Hindi/Devanagari word boundaries
@@ -813,37 +938,21 @@ impl IntentDetector {
     ///
     /// Iterates through all pattern groups and extracts matching slots
     /// with proper type inference and confidence scoring.
+    ///
+    /// Text is first normalized with [`transliteration::normalize`] so that
+    /// "paanch lakh", "पांच लाख" and "पांच lakh" all read "5 lakh".
     pub fn extract_slots(&self, text: &str) -> HashMap<String, Slot> {
         let mut slots = HashMap::new();
+        let text = transliteration::normalize(text);
+        let text = text.as_str();
 
         for (slot_name, patterns) in &self.compiled_patterns {
             if let Some((value, slot_type, confidence)) =
                 self.extract_slot_with_patterns(text, patterns)
             {
                 // Validate loan_amount to exclude phone-number-like values
-                if slot_name == "loan_amount" {
-                    // Skip if value looks like a phone number (10 digits starting with 6-9)
-                    if value.len() == 10 {
-                        if let Some(first_char) = value.chars().next() {
-                            if first_char >= '6' && first_char <= '9' && value.chars().all(|c| c.is_ascii_digit()) {
-                                tracing::debug!(
-                                    value = %value,
-                                    "Skipping loan_amount extraction - looks like phone number"
-                                );
-                                continue;
-                            }
-                        }
-                    }
-                    // Skip if value is unreasonably large (> 100 crore = 1 billion)
-                    if let Ok(amount) = value.parse::<f64>() {
-                        if amount > 1_000_000_000.0 {
-                            tracing::debug!(
-                                value = %value,
-                                "Skipping loan_amount extraction - unreasonably large"
-                            );
-                            continue;
-                        }
-                    }
+                if slot_name == "loan_amount" && !Self::is_plausible_amount(&value) {
+                    continue;
                 }
 
                 slots.insert(
@@ -858,9 +967,170 @@ impl IntentDetector {
             }
         }
 
+        if let Some(found) = self
+            .competitor_keywords
+            .as_ref()
+            .and_then(|matcher| matcher.find(text))
+        {
+            slots.insert(
+                "current_lender".to_string(),
+                Slot {
+                    name: "current_lender".to_string(),
+                    slot_type: SlotType::Text,
+                    value: Some(title_case(&text[found.start..found.end])),
+                    confidence: 0.85,
+                },
+            );
+        }
+
+        for (slot_name, gazetteer) in &self.gazetteers {
+            if slots.contains_key(slot_name) {
+                continue;
+            }
+            if let Some(found) = gazetteer.find(text) {
+                let slot_type = self
+                    .compiled_patterns
+                    .get(slot_name)
+                    .and_then(|patterns| patterns.first())
+                    .map_or(SlotType::Text, |pattern| pattern.slot_type.clone());
+                tracing::debug!(
+                    slot = %slot_name,
+                    value = found.value,
+                    similarity = found.similarity,
+                    "Gazetteer match"
+                );
+                slots.insert(
+                    slot_name.clone(),
+                    Slot {
+                        name: slot_name.clone(),
+                        slot_type,
+                        value: Some(found.value.to_string()),
+                        confidence: GAZETTEER_CONFIDENCE * found.similarity,
+                    },
+                );
+            }
+        }
+
+        self.assign_amount_roles(text, &mut slots);
+
         slots
     }
 
+    /// Whether a loan_amount value can be a loan amount at all
+    fn is_plausible_amount(value: &str) -> bool {
+        // Skip if value looks like a phone number (10 digits starting with 6-9)
+        if value.len() == 10 {
+            if let Some(first_char) = value.chars().next() {
+                if ('6'..='9').contains(&first_char) && value.chars().all(|c| c.is_ascii_digit()) {
+                    tracing::debug!(
+                        value = %value,
+                        "Skipping loan_amount extraction - looks like phone number"
+                    );
+                    return false;
+                }
+            }
+        }
+        // Skip if value is unreasonably large (> 100 crore = 1 billion)
+        if let Ok(amount) = value.parse::<f64>() {
+            if amount > 1_000_000_000.0 {
+                tracing::debug!(
+                    value = %value,
+                    "Skipping loan_amount extraction - unreasonably large"
+                );
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Route several amounts in one utterance to loan_amount and current_outstanding
+    ///
+    /// "I have 2 lakh loan at Muthoot, want 5 lakh from you" names the
+    /// existing loan and the one asked for. With two or more distinct amounts,
+    /// the one with "want"/"chahiye" cues becomes `loan_amount` and the one
+    /// with "outstanding"/"baki" cues or a lender next to it becomes
+    /// `current_outstanding`. See [`assign_roles`].
+    fn assign_amount_roles(&self, text: &str, slots: &mut HashMap<String, Slot>) {
+        let Some(patterns) = self.compiled_patterns.get("loan_amount") else {
+            return;
+        };
+
+        // Non-overlapping mentions, earlier patterns taking precedence
+        let mut mentions: Vec<(usize, usize, String, f32)> = Vec::new();
+        for pattern in patterns {
+            for captures in pattern.regex.captures_iter(text) {
+                let (Some(whole), Some(matched)) = (captures.get(0), captures.get(1)) else {
+                    continue;
+                };
+                let overlaps = mentions
+                    .iter()
+                    .any(|m| whole.start() < m.1 && m.0 < whole.end());
+                let value = Self::pattern_value(pattern, matched.as_str());
+                if !overlaps && Self::is_plausible_amount(&value) {
+                    let confidence = Self::pattern_confidence(pattern);
+                    mentions.push((whole.start(), whole.end(), value, confidence));
+                }
+            }
+        }
+        mentions.sort_by_key(|m| m.0);
+        let mut values: Vec<&str> = mentions.iter().map(|m| m.2.as_str()).collect();
+        values.sort_unstable();
+        values.dedup();
+        if values.len() < 2 {
+            return;
+        }
+
+        let lenders: Vec<(usize, usize)> = match &self.competitor_keywords {
+            Some(matcher) => matcher.matches(text).map(|m| (m.start, m.end)).collect(),
+            None => self
+                .compiled_patterns
+                .get("current_lender")
+                .into_iter()
+                .flatten()
+                .flat_map(|p| p.regex.find_iter(text).map(|m| (m.start(), m.end())))
+                .collect(),
+        };
+        let spans: Vec<(usize, usize)> = mentions.iter().map(|m| (m.0, m.1)).collect();
+        let roles = assign_roles(text, &spans, &lenders);
+
+        let with_role = |role| roles.iter().position(|r| *r == Some(role));
+        let wanted = with_role(AmountRole::Wanted);
+        let current = with_role(AmountRole::Current);
+        if wanted.is_none() && current.is_none() {
+            return;
+        }
+        // An amount without cues takes whichever role the others leave open
+        let open = |other: Option<usize>| {
+            roles.iter().zip(&mentions).position(|(role, mention)| {
+                role.is_none() && other.map_or(true, |o| mentions[o].2 != mention.2)
+            })
+        };
+        let (wanted, current) = (
+            wanted.or_else(|| open(current)),
+            current.or_else(|| open(wanted)),
+        );
+
+        let slot = |name: &str, i: usize| Slot {
+            name: name.to_string(),
+            slot_type: SlotType::Currency,
+            value: Some(mentions[i].2.clone()),
+            confidence: mentions[i].3,
+        };
+        match wanted {
+            Some(i) => {
+                slots.insert("loan_amount".to_string(), slot("loan_amount", i));
+            },
+            // Every amount is an existing loan
+            None => {
+                slots.remove("loan_amount");
+            },
+        }
+        if let Some(i) = current {
+            let name = "current_outstanding";
+            slots.insert(name.to_string(), slot(name, i));
+        }
+    }
+
     /// P3 FIX: Convert all Indic script numerals to ASCII digits
     ///
     /// Supports all 11 major Indic scripts:
@@ -1073,92 +1343,8 @@ impl IntentDetector {
             if let Some(captures) = pattern.regex.captures(text) {
                 // Get the first capturing group (the value)
                 if let Some(matched) = captures.get(1) {
-                    let raw_value = matched.as_str();
-
-                    // Compute final value based on multiplier
-                    let value = if let Some(multiplier) = pattern.multiplier {
-                        // P3 FIX: Handle all Indic script numerals and number words
-                        let numeric_value = if pattern.name.starts_with("hindi_word") {
-                            // P2.2 FIX: Use shared Hindi module
-                            crate::hindi::word_to_number(raw_value).unwrap_or(1.0)
-                        } else if raw_value.chars().any(Self::is_indic_numeral) {
-                            // P3 FIX: Contains any Indic script numerals - convert to ASCII first
-                            let ascii_value = Self::indic_numerals_to_ascii(raw_value);
-                            ascii_value.replace(",", "").parse::<f64>().unwrap_or(0.0)
-                        } else {
-                            // Regular ASCII number
-                            raw_value.replace(",", "").parse::<f64>().unwrap_or(0.0)
-                        };
-
-                        if numeric_value > 0.0 {
-                            format!("{}", (numeric_value * multiplier) as i64)
-                        } else {
-                            raw_value.to_string()
-                        }
-                    } else {
-                        // Remove commas for currency, keep as-is for others
-                        match pattern.slot_type {
-                            SlotType::Currency => {
-                                // P3 FIX: Also convert all Indic script numerals for direct amounts
-                                let converted = Self::indic_numerals_to_ascii(raw_value);
-                                converted.replace(",", "")
-                            },
-                            SlotType::Text => {
-                                // Capitalize first letter for proper nouns (competitor names, etc.)
-                                // NOTE: Specific capitalization rules should come from domain config
-                                let trimmed = raw_value.trim();
-                                if trimmed.is_empty() {
-                                    raw_value.to_string()
-                                } else {
-                                    // Title case: capitalize first letter of each word
-                                    trimmed
-                                        .split_whitespace()
-                                        .map(|word| {
-                                            let mut chars = word.chars();
-                                            match chars.next() {
-                                                None => String::new(),
-                                                Some(c) => c.to_uppercase().chain(chars).collect(),
-                                            }
-                                        })
-                                        .collect::<Vec<_>>()
-                                        .join(" ")
-                                }
-                            },
-                            SlotType::Enum(_) => {
-                                // Normalize karat values
-                                format!("{}K", raw_value)
-                            },
-                            _ => raw_value.to_string(),
-                        }
-                    };
-
-                    // Calculate confidence based on pattern specificity
-                    // P3 FIX: All Indic language patterns get same high confidence
-                    let confidence = match pattern.name.as_str() {
-                        // English patterns
-                        "crore" | "lakh" | "rs_amount" => 0.95,
-                        // Hindi patterns
-                        "hindi_crore_devanagari" | "hindi_crore_ascii" => 0.95,
-                        "hindi_lakh_devanagari" | "hindi_lakh_ascii" => 0.95,
-                        "hindi_word_lakh" | "hindi_word_crore" => 0.93,
-                        "hindi_hazar_devanagari" | "hindi_hazar_ascii" => 0.90,
-                        "hindi_rupees" => 0.92,
-                        // P3 FIX: Other Indic language patterns
-                        "tamil_lakh" | "tamil_crore" => 0.95,
-                        "telugu_lakh" | "telugu_crore" => 0.95,
-                        "bengali_lakh" | "bengali_crore" => 0.95,
-                        "gujarati_lakh" | "gujarati_crore" => 0.95,
-                        "kannada_lakh" | "kannada_crore" => 0.95,
-                        "malayalam_lakh" | "malayalam_crore" => 0.95,
-                        "marathi_crore" => 0.95,
-                        "odia_lakh" | "odia_crore" => 0.95,
-                        "punjabi_lakh" | "punjabi_crore" => 0.95,
-                        // General patterns
-                        "thousand" | "grams" | "karat" => 0.90,
-                        "plain_number" => 0.70,
-                        _ => 0.85,
-                    };
-
+                    let value = Self::pattern_value(pattern, matched.as_str());
+                    let confidence = Self::pattern_confidence(pattern);
                     return Some((value, pattern.slot_type.clone(), confidence));
                 }
             }
@@ -1167,6 +1353,85 @@ impl IntentDetector {
         None
     }
 
+    /// Slot value of a pattern's captured text, applying its multiplier
+    fn pattern_value(pattern: &CompiledSlotPattern, raw_value: &str) -> String {
+        // Compute final value based on multiplier
+        if let Some(multiplier) = pattern.multiplier {
+            // P3 FIX: Handle all Indic script numerals and number words
+            let numeric_value = if pattern.name.starts_with("hindi_word") {
+                // P2.2 FIX: Use shared Hindi module
+                crate::hindi::word_to_number(raw_value).unwrap_or(1.0)
+            } else if raw_value.chars().any(Self::is_indic_numeral) {
+                // P3 FIX: Contains any Indic script numerals - convert to ASCII first
+                let ascii_value = Self::indic_numerals_to_ascii(raw_value);
+                ascii_value.replace(",", "").parse::<f64>().unwrap_or(0.0)
+            } else {
+                // Regular ASCII number
+                raw_value.replace(",", "").parse::<f64>().unwrap_or(0.0)
+            };
+
+            if numeric_value > 0.0 {
+                format!("{}", (numeric_value * multiplier) as i64)
+            } else {
+                raw_value.to_string()
+            }
+        } else {
+            // Remove commas for currency, keep as-is for others
+            match pattern.slot_type {
+                SlotType::Currency => {
+                    // P3 FIX: Also convert all Indic script numerals for direct amounts
+                    let converted = Self::indic_numerals_to_ascii(raw_value);
+                    converted.replace(",", "")
+                },
+                SlotType::Text => {
+                    // Capitalize first letter for proper nouns (competitor names, etc.)
+                    // NOTE: Specific capitalization rules should come from domain config
+                    let trimmed = raw_value.trim();
+                    if trimmed.is_empty() {
+                        raw_value.to_string()
+                    } else {
+                        title_case(trimmed)
+                    }
+                },
+                SlotType::Enum(_) => {
+                    // Normalize karat values
+                    format!("{}K", raw_value)
+                },
+                _ => raw_value.to_string(),
+            }
+        }
+    }
+
+    /// Confidence of a pattern match, by pattern specificity
+    fn pattern_confidence(pattern: &CompiledSlotPattern) -> f32 {
+        // Calculate confidence based on pattern specificity
+        // P3 FIX: All Indic language patterns get same high confidence
+        match pattern.name.as_str() {
+            // English patterns
+            "crore" | "lakh" | "rs_amount" => 0.95,
+            // Hindi patterns
+            "hindi_crore_devanagari" | "hindi_crore_ascii" => 0.95,
+            "hindi_lakh_devanagari" | "hindi_lakh_ascii" => 0.95,
+            "hindi_word_lakh" | "hindi_word_crore" => 0.93,
+            "hindi_hazar_devanagari" | "hindi_hazar_ascii" => 0.90,
+            "hindi_rupees" => 0.92,
+            // P3 FIX: Other Indic language patterns
+            "tamil_lakh" | "tamil_crore" => 0.95,
+            "telugu_lakh" | "telugu_crore" => 0.95,
+            "bengali_lakh" | "bengali_crore" => 0.95,
+            "gujarati_lakh" | "gujarati_crore" => 0.95,
+            "kannada_lakh" | "kannada_crore" => 0.95,
+            "malayalam_lakh" | "malayalam_crore" => 0.95,
+            "marathi_crore" => 0.95,
+            "odia_lakh" | "odia_crore" => 0.95,
+            "punjabi_lakh" | "punjabi_crore" => 0.95,
+            // General patterns
+            "thousand" | "grams" | "karat" => 0.90,
+            "plain_number" => 0.70,
+            _ => 0.85,
+        }
+    }
+
     /// Get intent by name
     pub fn get_intent(&self, name: &str) -> Option<Intent> {
         self.intents.read().iter().find(|i| i.name == name).cloned()
@@ -1184,6 +1449,20 @@ impl Default for IntentDetector {
     }
 }
 
+/// Capitalize the first letter of each word
+fn title_case(text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                None => String::new(),
+                Some(c) => c.to_uppercase().chain(chars).collect(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1222,6 +1501,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_slot_spans_are_verbatim() {
+        let mut detector = IntentDetector::new();
+        detector.add_competitor_patterns(vec![
+            ("muthoot", "Muthoot Finance", r"(?i)\b(muthoot)\b"),
+        ]);
+
+        let text = "muthoot se 2.5 lakh chahiye";
+        let result = detector.detect(text);
+        let amount = result.slots["loan_amount"].value.as_deref();
+        assert_eq!(amount, Some("250000"));
+        assert!(result.slot_spans.iter().all(|span| text.contains(span)));
+        assert!(result.slot_spans.contains(&"2.5".to_string()));
+        assert!(result.slot_spans.contains(&"muthoot".to_string()));
+    }
+
     #[test]
     fn test_greeting() {
         let detector = IntentDetector::new();
@@ -1325,6 +1620,107 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_multiple_amounts_by_role() {
+        let mut detector = IntentDetector::new();
+        detector.add_competitor_patterns(vec![("muthoot", "Muthoot", r"(?i)\b(muthoot)\b")]);
+
+        for text in [
+            "I have 2 lakh loan at Muthoot, want 5 lakh from you",
+            "Muthoot mein 2 lakh baki hai, mujhe 5 lakh chahiye",
+            "5 lakh chahiye, abhi 2 lakh outstanding hai",
+            // Only the existing loan has a cue
+            "2 lakh outstanding hai aur 5 lakh",
+        ] {
+            let slots = detector.extract_slots(text);
+            let value = |name: &str| slots.get(name).and_then(|s| s.value.clone());
+            assert_eq!(value("loan_amount"), Some("500000".to_string()), "{}", text);
+            assert_eq!(
+                value("current_outstanding"),
+                Some("200000".to_string()),
+                "{}",
+                text
+            );
+        }
+
+        // A single amount stays the requested one
+        let slots = detector.extract_slots("Muthoot mein 2 lakh ka loan hai");
+        assert!(slots.contains_key("loan_amount"));
+        assert!(!slots.contains_key("current_outstanding"));
+    }
+
+    #[test]
+    fn test_config_competitors_use_keyword_matcher() {
+        let mut detector = IntentDetector::new();
+        // Shape generated by AgentDomainView::competitor_intent_patterns
+        detector.add_competitor_patterns(vec![
+            (
+                "muthoot",
+                "Muthoot Finance",
+                r"(?i)\b(muthoot|muthoot finance)\b",
+            ),
+            ("manappuram", "Manappuram", r"(?i)\b(manappuram)\b"),
+        ]);
+        assert!(detector.competitor_keywords.is_some());
+
+        let slots = detector.extract_slots("Mera loan muthoot finance se hai");
+        assert_eq!(
+            slots.get("current_lender").unwrap().value,
+            Some("Muthoot Finance".to_string())
+        );
+        assert!(!detector
+            .extract_slots("muthootgold scheme")
+            .contains_key("current_lender"));
+    }
+
+    #[test]
+    fn test_gazetteer_fills_near_misses() {
+        let mut detector = IntentDetector::new();
+        detector.add_competitor_patterns(vec![(
+            "manappuram",
+            "Manappuram Finance",
+            r"(?i)\b(manappuram)\b",
+        )]);
+        let mut lenders = Gazetteer::new();
+        lenders.insert("Manappuram Finance", ["manappuram", "Manappuram Finance"]);
+        detector.add_gazetteer("current_lender", lenders);
+
+        // Exact mentions keep the pattern's value and confidence
+        let exact = detector.extract_slots("loan from manappuram");
+        assert_eq!(exact["current_lender"].confidence, 0.85);
+
+        let slots = detector.extract_slots("mera loan mana puram mein hai");
+        let lender = &slots["current_lender"];
+        assert_eq!(lender.value.as_deref(), Some("Manappuram Finance"));
+        assert!(lender.confidence < 0.85);
+    }
+
+    #[test]
+    fn test_detectors_share_compiled_patterns() {
+        let pattern = r"(?i)\b(rupeek|ru\s*peek)\b";
+        let mut first = IntentDetector::new();
+        first.add_competitor_patterns(vec![("rupeek", "Rupeek", pattern)]);
+        assert!(CONFIG_PATTERNS.read().contains_key(pattern));
+
+        // A second session of the same domain reuses both pattern sets
+        let mut second = IntentDetector::new();
+        second.add_competitor_patterns(vec![("rupeek", "Rupeek", pattern)]);
+        assert_eq!(second.compiled_patterns.len(), BUILTIN_SLOT_PATTERNS.len());
+        let slots = second.extract_slots("mera loan Rupeek se hai, 2 lakh ka");
+        assert_eq!(
+            slots.get("current_lender").unwrap().value,
+            Some("Rupeek".to_string())
+        );
+        assert_eq!(
+            slots.get("loan_amount").unwrap().value,
+            Some("200000".to_string())
+        );
+
+        // Invalid patterns are rejected, not cached
+        second.add_competitor_patterns(vec![("broken", "Broken", r"(unclosed")]);
+        assert!(!CONFIG_PATTERNS.read().contains_key(r"(unclosed"));
+    }
+
     #[test]
     fn test_tola_to_grams() {
         let detector = IntentDetector::new();
@@ -1388,6 +1784,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mixed_script_utterances() {
+        let detector = IntentDetector::new();
+
+        for text in [
+            "mujhe paanch lakh ka loan chahiye",
+            "मुझे पांच lakh का loan चाहिए",
+            "mujhe पाँच लाख chahiye",
+        ] {
+            let slots = detector.extract_slots(text);
+            assert_eq!(
+                slots.get("loan_amount").and_then(|s| s.value.clone()),
+                Some("500000".to_string()),
+                "{}",
+                text
+            );
+        }
+
+        // Devanagari input still scores against romanized examples
+        let detected = detector.detect("गोल्ड लोन चाहिए");
+        let romanized = detector.detect("gold loan chahiye");
+        assert_eq!(detected.intent, romanized.intent);
+        assert!((detected.confidence - romanized.confidence).abs() < f32::EPSILON);
+    }
+
     #[test]
     fn test_hindi_hazar() {
         let detector = IntentDetector::new();
@@ -0,0 +1,244 @@
+//! Multi-Keyword Matching
+//!
+//! Lender names, competitor aliases and per-language intent keywords are
+//! plain strings, yet matching them used to mean one regex or substring scan
+//! per keyword per utterance. [`KeywordMatcher`] compiles every keyword into a
+//! single Aho-Corasick automaton and finds all of them in one pass over the
+//! text. Regexes stay reserved for structured entities (amounts, phone
+//! numbers, dates).
+//!
+//! Matching is ASCII case-insensitive. Keywords containing non-ASCII cased
+//! letters (accented Latin, Cyrillic) are rejected by [`plain_keywords`] so a
+//! regex fallback keeps full Unicode case folding; Indic scripts have no case
+//! and match exactly.
+
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, BuildError, MatchKind};
+
+/// One keyword occurrence
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeywordMatch<'a> {
+    /// Label the keyword was registered under
+    pub label: &'a str,
+    /// Byte offset where the match starts in the searched text
+    pub start: usize,
+    /// Byte offset where the match ends in the searched text
+    pub end: usize,
+}
+
+/// Labelled keyword set matched in a single pass
+#[derive(Debug, Clone)]
+pub struct KeywordMatcher {
+    automaton: AhoCorasick,
+    /// Label index per keyword, in automaton pattern order
+    keyword_labels: Vec<usize>,
+    labels: Vec<String>,
+    whole_words: bool,
+}
+
+impl KeywordMatcher {
+    /// Match keywords anywhere in the text, like `str::contains`
+    ///
+    /// `entries` maps each label to its keywords; empty keywords are ignored.
+    pub fn new<L, I, K>(entries: impl IntoIterator<Item = (L, I)>) -> Result<Self, BuildError>
+    where
+        L: Into<String>,
+        I: IntoIterator<Item = K>,
+        K: AsRef<str>,
+    {
+        Self::build(entries, false)
+    }
+
+    /// Match keywords only between word boundaries, like regex `\b...\b`
+    ///
+    /// Non-ASCII letters, digits and combining marks count as word
+    /// characters, so a Devanagari keyword does not match inside a longer
+    /// word.
+    pub fn whole_words<L, I, K>(
+        entries: impl IntoIterator<Item = (L, I)>,
+    ) -> Result<Self, BuildError>
+    where
+        L: Into<String>,
+        I: IntoIterator<Item = K>,
+        K: AsRef<str>,
+    {
+        Self::build(entries, true)
+    }
+
+    fn build<L, I, K>(
+        entries: impl IntoIterator<Item = (L, I)>,
+        whole_words: bool,
+    ) -> Result<Self, BuildError>
+    where
+        L: Into<String>,
+        I: IntoIterator<Item = K>,
+        K: AsRef<str>,
+    {
+        let mut labels = Vec::new();
+        let mut keywords = Vec::new();
+        let mut keyword_labels = Vec::new();
+        for (label, label_keywords) in entries {
+            let index = labels.len();
+            labels.push(label.into());
+            for keyword in label_keywords {
+                let keyword = keyword.as_ref().trim();
+                if !keyword.is_empty() {
+                    keywords.push(keyword.to_lowercase());
+                    keyword_labels.push(index);
+                }
+            }
+        }
+
+        let automaton = AhoCorasickBuilder::new()
+            .ascii_case_insensitive(true)
+            .match_kind(MatchKind::Standard)
+            .build(&keywords)?;
+
+        Ok(Self {
+            automaton,
+            keyword_labels,
+            labels,
+            whole_words,
+        })
+    }
+
+    /// Number of keywords across all labels
+    pub fn len(&self) -> usize {
+        self.keyword_labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keyword_labels.is_empty()
+    }
+
+    /// Every keyword occurrence, including overlapping ones
+    pub fn matches<'a>(&'a self, text: &'a str) -> impl Iterator<Item = KeywordMatch<'a>> + 'a {
+        self.automaton
+            .find_overlapping_iter(text)
+            .filter(move |m| !self.whole_words || is_whole_word(text, m.start(), m.end()))
+            .map(move |m| KeywordMatch {
+                label: &self.labels[self.keyword_labels[m.pattern().as_usize()]],
+                start: m.start(),
+                end: m.end(),
+            })
+    }
+
+    /// Leftmost occurrence, preferring the longest keyword at that position
+    pub fn find<'a>(&'a self, text: &'a str) -> Option<KeywordMatch<'a>> {
+        self.matches(text)
+            .min_by_key(|m| (m.start, std::cmp::Reverse(m.end)))
+    }
+
+    /// Whether any keyword occurs in the text
+    pub fn is_match(&self, text: &str) -> bool {
+        self.matches(text).next().is_some()
+    }
+}
+
+/// Keywords of a case-insensitive, word-bounded alternation regex
+///
+/// Recognizes the `(?i)\b(name|alias|...)\b` shape generated from domain
+/// config, with `regex::escape`d alternatives. Returns `None` for anything
+/// else, including alternatives that need regex features or Unicode case
+/// folding, so callers can fall back to the regex.
+pub fn plain_keywords(pattern: &str) -> Option<Vec<String>> {
+    let body = pattern.strip_prefix(r"(?i)\b(")?.strip_suffix(r")\b")?;
+
+    let mut keywords = Vec::new();
+    let mut current = String::new();
+    let mut chars = body.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let escaped = chars.next()?;
+                if escaped.is_alphanumeric() {
+                    // \s, \d, \b, ... are classes, not literals
+                    return None;
+                }
+                current.push(escaped);
+            },
+            '|' => keywords.push(std::mem::take(&mut current)),
+            '.' | '^' | '$' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' => return None,
+            c if !c.is_ascii() && (c.is_lowercase() || c.is_uppercase()) => return None,
+            c => current.push(c),
+        }
+    }
+    keywords.push(current);
+
+    if keywords.iter().any(|k| k.trim().is_empty()) {
+        return None;
+    }
+    Some(keywords)
+}
+
+fn is_whole_word(text: &str, start: usize, end: usize) -> bool {
+    let before = text[..start].chars().next_back();
+    let after = text[end..].chars().next();
+    !before.is_some_and(is_word_char) && !after.is_some_and(is_word_char)
+}
+
+/// Approximates regex `\w` without Unicode tables
+fn is_word_char(c: char) -> bool {
+    if c.is_ascii() {
+        return c.is_ascii_alphanumeric() || c == '_';
+    }
+    !(c.is_whitespace()
+        || ('\u{2000}'..='\u{206F}').contains(&c) // General Punctuation
+        || matches!(c, '।' | '॥'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lenders() -> KeywordMatcher {
+        KeywordMatcher::whole_words([
+            ("muthoot", vec!["muthoot", "muthoot finance", "मुथूट"]),
+            ("manappuram", vec!["manappuram"]),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_leftmost_longest_match() {
+        let matcher = lenders();
+        let text = "Loan Muthoot Finance se hai, Manappuram bhi dekha";
+        let found = matcher.find(text).unwrap();
+        assert_eq!(found.label, "muthoot");
+        assert_eq!(&text[found.start..found.end], "Muthoot Finance");
+
+        let labels: Vec<_> = matcher.matches(text).map(|m| m.label).collect();
+        assert!(labels.contains(&"manappuram"));
+    }
+
+    #[test]
+    fn test_whole_words() {
+        let matcher = lenders();
+        assert!(matcher.is_match("मेरा लोन मुथूट से है"));
+        assert!(!matcher.is_match("muthootgold"));
+        assert!(!matcher.is_match("मुथूटी"));
+        assert!(matcher.is_match("“Muthoot”"));
+
+        let substring = KeywordMatcher::new([("muthoot", ["muthoot"])]).unwrap();
+        assert!(substring.is_match("muthootgold"));
+    }
+
+    #[test]
+    fn test_plain_keywords() {
+        assert_eq!(
+            plain_keywords(r"(?i)\b(muthoot|muthoot\ finance|iifl\.)\b"),
+            Some(vec![
+                "muthoot".to_string(),
+                "muthoot finance".to_string(),
+                "iifl.".to_string(),
+            ])
+        );
+        assert_eq!(
+            plain_keywords(r"(?i)\b(मुथूट)\b"),
+            Some(vec!["मुथूट".to_string()])
+        );
+        assert_eq!(plain_keywords(r"(?i)\b(ii\s*fl)\b"), None);
+        assert_eq!(plain_keywords(r"\b(muthoot)\b"), None);
+        assert_eq!(plain_keywords(r"(?i)\b(a||b)\b"), None);
+        assert_eq!(plain_keywords(r"(?i)\b(émile)\b"), None);
+    }
+}
@@ -6,6 +6,10 @@
 //! - **PII Detection**: Detect and redact sensitive Indian data (Aadhaar, PAN, etc.)
 //! - **Compliance Checking**: Ensure banking regulatory compliance
 //! - **Intent Detection**: Detect user intents and extract slots (P1-2 FIX: moved from agent)
+//! - **Transliteration**: Normalize romanized Hindi and Devanagari before extraction
+//! - **Disfluency Cleanup**: Strip fillers, stutters and false starts from ASR text
+//! - **Code-Switch Rendering**: Keep spoken responses in the session language's script
+//! - **Gazetteer Lookup**: Match lender, city and branch names despite ASR misspellings
 //!
 //! # Example
 //!
@@ -20,16 +24,21 @@
 //! println!("Processed: {}", result.text);
 //! ```
 
+pub mod abuse; // Abusive speech detection on caller transcripts
+pub mod code_switch; // Script-consistent rendering of responses before TTS
 pub mod compliance;
+pub mod disfluency; // Filler, repetition and false-start removal from ASR transcripts
 pub mod entities;
 pub mod grammar;
 pub mod hindi; // P2.2 FIX: Shared Hindi language utilities
 pub mod intent; // P1-2 FIX: Intent detection moved from agent crate
+pub mod keywords; // Single-pass keyword matching for lenders and intent keywords
 pub mod pii;
 pub mod sentiment; // P2-1 FIX: Sentiment analysis for customer emotion detection
 pub mod simplifier; // P2 FIX: Text simplifier for TTS
 pub mod slot_extraction; // P3-3 FIX: Slot extraction moved from agent/dst
 pub mod translation; // P2-5 FIX: Loan entity extraction
+pub mod transliteration; // Romanized Hindi / Devanagari normalization before extraction
 
 mod error;
 mod pipeline;
@@ -45,9 +54,16 @@ pub use simplifier::{AbbreviationExpander, NumberToWords, TextSimplifier, TextSi
 pub use translation::{ScriptDetector, TranslationConfig, TranslationProvider};
 // P1-2 FIX: Intent detection exports
 pub use intent::{DetectedIntent, Intent, IntentDetector, Slot, SlotType};
+pub use keywords::{KeywordMatch, KeywordMatcher};
+// Fuzzy gazetteer exports (shared with tools and pipeline via core)
+pub use voice_agent_core::gazetteer::{Gazetteer, GazetteerMatch, LocationGazetteer};
+// Abusive speech detection exports
+pub use abuse::{AbuseClassifier, AbuseDetection, AbuseDetector, AbuseSeverity};
+// Script-consistent response rendering exports
+pub use code_switch::{CodeSwitchConfig, CodeSwitchLevel, CodeSwitchRenderer};
 // P2-1 FIX: Sentiment analysis exports
 pub use sentiment::{Sentiment, SentimentAnalyzer, SentimentConfig, SentimentResult};
 // P2-5 FIX: Loan entity extraction exports
 pub use entities::{Currency, Duration, EntityExtractor, ExtractedEntities, Percentage, Weight};
 // P3-3 FIX: Slot extraction exports (moved from agent/dst)
-pub use slot_extraction::SlotExtractor;
+pub use slot_extraction::{LanguagePack, SlotExtractor};
@@ -0,0 +1,122 @@
+//! Per-Language Slot Extraction Packs
+//!
+//! The static amount/weight patterns understand digits with Hindi/English
+//! units. A [`LanguagePack`] lets another language reuse them: spoken number
+//! words and units are rewritten to digits and canonical unit names first, so
+//! Tamil "ஐந்து லட்சம்" or Telugu "ఐదు లక్షలు" read "5 lakh".
+//!
+//! Packs are populated from domain config
+//! (`extraction_patterns.yaml` -> `language_packs`).
+
+use std::collections::HashMap;
+
+/// Slot extraction vocabulary for one language
+#[derive(Debug, Clone, Default)]
+pub struct LanguagePack {
+    /// Number words and their values
+    pub number_words: HashMap<String, f64>,
+    /// Canonical amount unit ("lakh", "crore", "thousand") -> spoken forms
+    pub amount_units: HashMap<String, Vec<String>>,
+    /// Canonical weight unit ("gram", "tola") -> spoken forms
+    pub weight_units: HashMap<String, Vec<String>>,
+    /// Intent name -> keywords
+    pub intent_keywords: HashMap<String, Vec<String>>,
+}
+
+impl LanguagePack {
+    /// Rewrite spoken numbers and units to "<digits> <canonical unit>"
+    ///
+    /// Units are only rewritten after a number, so words that merely start
+    /// like a unit (Telugu "లక్ష్యం", goal) are left alone.
+    pub fn normalize(&self, text: &str) -> String {
+        let tokens: Vec<&str> = text.split_whitespace().collect();
+        let mut out: Vec<String> = Vec::with_capacity(tokens.len());
+
+        let mut i = 0;
+        while i < tokens.len() {
+            // Run of number words followed by a unit
+            let mut value = 0.0;
+            let mut end = i;
+            while let Some(n) = tokens.get(end).and_then(|t| self.number_value(t)) {
+                value = if n == 100.0 && value > 0.0 {
+                    value * 100.0
+                } else {
+                    value + n
+                };
+                end += 1;
+            }
+            if end > i {
+                if let Some(unit) = tokens.get(end).and_then(|t| self.unit(t)) {
+                    out.push(format!("{}", value));
+                    out.push(unit);
+                    i = end + 1;
+                    continue;
+                }
+            }
+
+            // Digits followed by a unit
+            if is_numeric(tokens[i]) {
+                if let Some(unit) = tokens.get(i + 1).and_then(|t| self.unit(t)) {
+                    out.push(tokens[i].to_string());
+                    out.push(unit);
+                    i += 2;
+                    continue;
+                }
+            }
+
+            out.push(tokens[i].to_string());
+            i += 1;
+        }
+
+        out.join(" ")
+    }
+
+    /// Intent whose keywords appear in the utterance
+    ///
+    /// Intents are checked in name order so the result is deterministic.
+    pub fn detect_intent(&self, utterance: &str) -> Option<&str> {
+        let lower = utterance.to_lowercase();
+        let mut intents: Vec<_> = self.intent_keywords.iter().collect();
+        intents.sort_by(|a, b| a.0.cmp(b.0));
+        intents
+            .into_iter()
+            .find(|(_, keywords)| {
+                keywords
+                    .iter()
+                    .any(|k| !k.is_empty() && lower.contains(&k.to_lowercase()))
+            })
+            .map(|(name, _)| name.as_str())
+    }
+
+    fn number_value(&self, token: &str) -> Option<f64> {
+        self.number_words
+            .get(&strip_punctuation(token).to_lowercase())
+            .copied()
+    }
+
+    /// Canonical unit for a token, keeping trailing punctuation
+    fn unit(&self, token: &str) -> Option<String> {
+        let word = strip_punctuation(token);
+        let lower = word.to_lowercase();
+        self.amount_units
+            .iter()
+            .chain(self.weight_units.iter())
+            .find(|(_, forms)| {
+                forms
+                    .iter()
+                    .any(|f| !f.is_empty() && lower.starts_with(&f.to_lowercase()))
+            })
+            .map(|(canonical, _)| format!("{}{}", canonical, &token[word.len()..]))
+    }
+}
+
+fn strip_punctuation(token: &str) -> &str {
+    token.trim_end_matches(|c: char| c.is_ascii_punctuation() || c == '।')
+}
+
+/// Digits in any script, with optional decimal point or grouping commas
+fn is_numeric(token: &str) -> bool {
+    let word = strip_punctuation(token);
+    word.chars().next().is_some_and(char::is_numeric)
+        && word.chars().all(|c| c.is_numeric() || c == '.' || c == ',')
+}
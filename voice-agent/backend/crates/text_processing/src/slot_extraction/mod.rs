@@ -3,7 +3,9 @@
 //! Moved from agent/dst/extractor.rs as part of Phase 3.3 crate boundary fix.
 //!
 //! Implements rule-based and pattern-based slot extraction from user utterances.
-//! Supports Hindi, Hinglish, and English utterances.
+//! Supports Hindi, Hinglish, and English utterances out of the box; other
+//! languages (Marathi, Tamil, Telugu, ...) through config-driven
+//! [`LanguagePack`]s.
 //!
 //! ## Config-Driven Slot Extraction (P16 FIX)
 //!
@@ -14,12 +16,22 @@
 //!
 //! Static patterns are compiled once at program start using `once_cell::sync::Lazy`.
 //! These serve as fallbacks when config-driven patterns are not available.
+//! Callers without domain config use [`SlotExtractor::shared`] instead of
+//! constructing an extractor per utterance.
 
 use once_cell::sync::Lazy;
 use regex::Regex;
 use std::collections::HashMap;
 
+use std::sync::Arc;
+use voice_agent_core::LocationGazetteer;
+
 use crate::intent::{Slot, SlotType};
+use crate::keywords::KeywordMatcher;
+
+mod language_pack;
+
+pub use language_pack::LanguagePack;
 
 /// P16 FIX: Slot extraction configuration from domain config
 /// This mirrors the structure in slots.yaml
@@ -46,6 +58,12 @@ pub struct SlotExtractionConfig {
     /// P2.1 FIX: Purpose patterns from extraction_patterns.yaml
     /// Loaded from domain config extraction_patterns.purposes.categories
     pub purpose_patterns: Vec<PurposePattern>,
+    /// Per-language number words, units and intent keywords by language code
+    /// Loaded from domain config extraction_patterns.language_packs
+    pub language_packs: HashMap<String, LanguagePack>,
+    /// Cities, PIN code ranges and branch localities of the domain
+    /// Without it, the built-in list of major Indian cities is used
+    pub locations: Option<Arc<LocationGazetteer>>,
 }
 
 /// P1.1 FIX: Compiled quality tier pattern for domain-agnostic extraction
@@ -182,11 +200,9 @@ static REPAYMENT_PATTERNS: Lazy<Vec<(Regex, &'static str)>> = Lazy::new(|| vec![
     (Regex::new(r"(?i)(?:interest\s+only|sirf\s+byaaj|only\s+interest)").unwrap(), "interest_only"),
 ]);
 
-// City patterns (major Indian cities)
+// City after a place word; known city names come from the location gazetteer
 static CITY_PATTERNS: Lazy<Vec<Regex>> = Lazy::new(|| vec![
     Regex::new(r"(?i)(?:from|in|at|near|city|sheher)\s+([A-Z][a-zA-Z]+(?:\s+[A-Z][a-zA-Z]+)?)").unwrap(),
-    Regex::new(r"(?i)\b(Mumbai|Delhi|Bangalore|Bengaluru|Chennai|Hyderabad|Kolkata|Pune|Ahmedabad|Jaipur|Lucknow|Kanpur|Nagpur|Indore|Thane|Bhopal|Visakhapatnam|Patna|Vadodara|Ghaziabad|Ludhiana|Agra|Nashik|Faridabad|Meerut|Rajkot|Kalyan|Vasai|Varanasi|Srinagar|Aurangabad|Dhanbad|Amritsar|Navi Mumbai|Allahabad|Ranchi|Howrah|Coimbatore|Jabalpur|Gwalior|Vijayawada|Jodhpur|Madurai|Raipur|Kota|Guwahati|Chandigarh|Solapur|Hubli|Mysore|Tiruchirappalli|Bareilly|Aligarh|Tiruppur|Gurgaon|Noida|NCR)\b").unwrap(),
-    Regex::new(r"(?i)\b(Dilli|Mumbay|Calcutta|Madras|Bangaluru)\b").unwrap(),
 ]);
 
 // Intent detection patterns (order matters - more specific first)
@@ -278,6 +294,14 @@ pub struct SlotExtractor {
     city_patterns: Vec<CityPattern>,
     /// P2.1 FIX: Compiled purpose patterns from config
     purpose_patterns: Vec<PurposePattern>,
+    /// Language packs by language code
+    language_packs: HashMap<String, LanguagePack>,
+    /// Config lender variants, matched in one pass
+    lender_matcher: Option<KeywordMatcher>,
+    /// Language pack intent keywords by language code
+    pack_intents: HashMap<String, KeywordMatcher>,
+    /// Domain location gazetteer (None = built-in city list)
+    locations: Option<Arc<LocationGazetteer>>,
 }
 
 impl SlotExtractor {
@@ -297,9 +321,22 @@ impl SlotExtractor {
             quality_tiers: Vec::new(), // Empty = use static fallback patterns
             city_patterns: Vec::new(), // Empty = use static fallback patterns
             purpose_patterns: Vec::new(), // Empty = use static fallback patterns
+            language_packs: HashMap::new(),
+            lender_matcher: None,
+            pack_intents: HashMap::new(),
+            locations: None,
         }
     }
 
+    /// Process-wide extractor with static fallback patterns only
+    ///
+    /// For per-utterance callers without domain config; avoids building an
+    /// extractor per turn.
+    pub fn shared() -> &'static SlotExtractor {
+        static SHARED: Lazy<SlotExtractor> = Lazy::new(SlotExtractor::new);
+        &SHARED
+    }
+
     /// P16 FIX: Create a slot extractor with domain-specific configuration
     ///
     /// This allows loading extraction patterns from slots.yaml config file
@@ -318,6 +355,27 @@ impl SlotExtractor {
         let quality_tiers = config.quality_tiers.clone();
         let city_patterns = config.city_patterns.clone();
         let purpose_patterns = config.purpose_patterns.clone();
+        let language_packs = config.language_packs.clone();
+        let locations = config.locations.clone();
+        let lender_matcher = if config_lenders.is_empty() {
+            None
+        } else {
+            KeywordMatcher::new(&config_lenders)
+                .map_err(|e| tracing::warn!("Failed to build lender matcher: {}", e))
+                .ok()
+        };
+        let pack_intents = language_packs
+            .iter()
+            .filter(|(_, pack)| !pack.intent_keywords.is_empty())
+            .filter_map(|(language, pack)| {
+                KeywordMatcher::new(&pack.intent_keywords)
+                    .map(|matcher| (language.clone(), matcher))
+                    .map_err(|e| {
+                        tracing::warn!(language = %language, "Failed to build intent matcher: {}", e)
+                    })
+                    .ok()
+            })
+            .collect();
         Self {
             config: Some(config),
             config_lenders,
@@ -325,6 +383,10 @@ impl SlotExtractor {
             quality_tiers,
             city_patterns,
             purpose_patterns,
+            language_packs,
+            lender_matcher,
+            pack_intents,
+            locations,
         }
     }
 
@@ -355,6 +417,8 @@ impl SlotExtractor {
             quality_tiers: Vec::new(),
             city_patterns: Vec::new(),
             purpose_patterns: Vec::new(),
+            language_packs: HashMap::new(),
+            locations: None,
         })
     }
 
@@ -373,6 +437,8 @@ impl SlotExtractor {
             quality_tiers: Vec::new(),
             city_patterns: Vec::new(),
             purpose_patterns: Vec::new(),
+            language_packs: HashMap::new(),
+            locations: None,
         })
     }
 
@@ -401,12 +467,107 @@ impl SlotExtractor {
             quality_tiers,
             city_patterns: Vec::new(),
             purpose_patterns: Vec::new(),
+            language_packs: HashMap::new(),
+            locations: None,
         })
     }
 
-    /// Extract all slots from an utterance
-    pub fn extract(&self, utterance: &str) -> HashMap<String, Slot> {
+    /// Create with language packs for non-Hindi Indic languages
+    ///
+    /// Example usage:
+    /// ```ignore
+    /// let packs = patterns_config
+    ///     .language_packs
+    ///     .iter()
+    ///     .map(|(lang, pack)| (lang.clone(), LanguagePack {
+    ///         number_words: pack.number_words.clone(),
+    ///         amount_units: pack.amount_units.clone(),
+    ///         weight_units: pack.weight_units.clone(),
+    ///         intent_keywords: pack.intent_keywords.clone(),
+    ///     }))
+    ///     .collect();
+    /// let extractor = SlotExtractor::with_language_packs(packs);
+    /// ```
+    pub fn with_language_packs(language_packs: HashMap<String, LanguagePack>) -> Self {
+        Self::from_config(SlotExtractionConfig {
+            language_packs,
+            ..Default::default()
+        })
+    }
+
+    /// Create with the domain's location gazetteer for city and locality lookup
+    ///
+    /// Example usage with voice_agent_config:
+    /// ```ignore
+    /// let extractor = SlotExtractor::with_location_gazetteer(view.location_gazetteer());
+    /// ```
+    pub fn with_location_gazetteer(locations: Arc<LocationGazetteer>) -> Self {
+        Self::from_config(SlotExtractionConfig {
+            locations: Some(locations),
+            ..Default::default()
+        })
+    }
+
+    /// Configured location gazetteer, else the built-in city list
+    fn locations(&self) -> &LocationGazetteer {
+        match &self.locations {
+            Some(locations) => locations,
+            None => LocationGazetteer::builtin(),
+        }
+    }
+
+    /// City named (or misheard), given by PIN code, or holding a named locality
+    ///
+    /// Returns the canonical city name and a confidence for the lookup.
+    fn find_city(&self, utterance: &str) -> Option<(String, f32)> {
+        let locations = self.locations();
+        if let Some(found) = locations.find_city(utterance) {
+            return Some((found.value.to_string(), found.similarity));
+        }
+        // Only a PIN code the caller calls one; bare 6-digit numbers are often amounts
+        let pincode = self
+            .extract_pincode(utterance)
+            .filter(|(_, confidence)| *confidence > 0.9);
+        if let Some(city) = pincode.and_then(|(pin, _)| locations.city_for_pincode(&pin)) {
+            return Some((city.name.clone(), 0.9));
+        }
+        let (locality, similarity) = locations.find_locality(utterance)?;
+        Some((locality.city.clone(), 0.8 * similarity))
+    }
+
+    /// Language pack for a language code ("ta" or "ta-IN")
+    pub fn language_pack(&self, language: &str) -> Option<&LanguagePack> {
+        by_language(&self.language_packs, language)
+    }
+
+    /// Language pack intent whose keywords appear in the utterance
+    ///
+    /// Same result as [`LanguagePack::detect_intent`], in one pass over the
+    /// utterance instead of one scan per keyword.
+    fn pack_intent(&self, language: &str, utterance: &str) -> Option<String> {
+        let matcher = by_language(&self.pack_intents, language)?;
+        let lower = utterance.to_lowercase();
+        matcher
+            .matches(&lower)
+            .map(|found| found.label)
+            .min()
+            .map(str::to_string)
+    }
+
+    /// Extract all slots from an utterance in the given language
+    ///
+    /// The utterance is normalized first so number words reach the amount,
+    /// weight and tenure patterns as digits: through the language's pack if
+    /// one is configured, then romanized Hindi / Devanagari normalization.
+    pub fn extract(&self, utterance: &str, language: &str) -> HashMap<String, Slot> {
         let mut slots = HashMap::new();
+        let pack = self.language_pack(language);
+        let utterance = match pack {
+            Some(pack) => pack.normalize(utterance),
+            None => utterance.to_string(),
+        };
+        let utterance = crate::transliteration::normalize(&utterance);
+        let utterance = utterance.as_str();
 
         // Extract amount
         if let Some((amount, confidence)) = self.extract_amount(utterance) {
@@ -559,7 +720,10 @@ impl SlotExtractor {
         }
 
         // Extract detected intent (helps LLM understand what user wants)
-        if let Some((intent, confidence)) = self.extract_intent(utterance) {
+        let pack_intent = self
+            .pack_intent(language, utterance)
+            .map(|intent| (intent, 0.8));
+        if let Some((intent, confidence)) = pack_intent.or_else(|| self.extract_intent(utterance)) {
             slots.insert("detected_intent".to_string(), Slot {
                 name: "detected_intent".to_string(),
                 value: Some(intent),
@@ -721,36 +885,20 @@ impl SlotExtractor {
     pub fn extract_lender(&self, utterance: &str) -> Option<(String, f32)> {
         let lower = utterance.to_lowercase();
 
-        // P16 FIX: Try config-driven lenders first
-        if !self.config_lenders.is_empty() {
-            for (canonical, variants) in &self.config_lenders {
-                for variant in variants {
-                    if lower.contains(&variant.to_lowercase()) {
-                        let confidence = if lower.contains("from") || lower.contains("with")
-                            || lower.contains("se") || lower.contains("current")
-                        {
-                            0.9
-                        } else {
-                            0.7
-                        };
-                        return Some((canonical.clone(), confidence));
-                    }
-                }
-            }
+        // P16 FIX: Try config-driven lenders first; the earliest mention wins
+        if let Some(found) = self
+            .lender_matcher
+            .as_ref()
+            .and_then(|matcher| matcher.find(&lower))
+        {
+            return Some((found.label.to_string(), lender_confidence(&lower)));
         }
 
         // Fallback to static patterns
         for (canonical, variants) in LENDER_PATTERNS.iter() {
             for variant in variants.iter() {
                 if lower.contains(variant) {
-                    let confidence = if lower.contains("from") || lower.contains("with")
-                        || lower.contains("se") || lower.contains("current")
-                    {
-                        0.9
-                    } else {
-                        0.7
-                    };
-                    return Some(((*canonical).to_string(), confidence));
+                    return Some(((*canonical).to_string(), lender_confidence(&lower)));
                 }
             }
         }
@@ -860,19 +1008,23 @@ impl SlotExtractor {
 
     /// Extract location from utterance
     ///
-    /// P2.1 FIX: Uses config-driven patterns when available, falls back to hardcoded patterns.
+    /// P2.1 FIX: Uses config-driven patterns when available, then the location
+    /// gazetteer (near-miss city names, PIN codes, branch localities).
     pub fn extract_location(&self, utterance: &str) -> Option<(String, f32)> {
         let lower = utterance.to_lowercase();
+        let has_context = lower.contains("in ")
+            || lower.contains("at ")
+            || lower.contains("from ")
+            || lower.contains("near ")
+            || lower.contains("mein")
+            || lower.contains("में");
 
         // P2.1 FIX: Use config-driven city patterns if available
         if !self.city_patterns.is_empty() {
             for pattern in &self.city_patterns {
                 if pattern.pattern.is_match(&lower) {
                     // Boost confidence if location context keywords present
-                    let confidence = if lower.contains("in ") || lower.contains("at ")
-                        || lower.contains("from ") || lower.contains("near ")
-                        || lower.contains("mein") || lower.contains("में")
-                    {
+                    let confidence = if has_context {
                         (pattern.confidence + 0.1).min(1.0)
                     } else {
                         pattern.confidence
@@ -880,36 +1032,12 @@ impl SlotExtractor {
                     return Some((pattern.name.clone(), confidence));
                 }
             }
-            // Fall through to generic location pattern if no config city matched
-        } else {
-            // Fallback: hardcoded city list (deprecated - prefer config)
-            let cities = [
-                "mumbai", "delhi", "bangalore", "bengaluru", "chennai", "hyderabad",
-                "kolkata", "pune", "ahmedabad", "jaipur", "surat", "lucknow",
-                "kanpur", "nagpur", "indore", "thane", "bhopal", "visakhapatnam",
-                "patna", "vadodara", "ghaziabad", "ludhiana", "agra", "nashik",
-                "faridabad", "meerut", "rajkot", "kalyan", "vasai", "varanasi",
-                "aurangabad", "dhanbad", "amritsar", "allahabad", "ranchi", "gwalior",
-                "jodhpur", "coimbatore", "vijayawada", "madurai", "raipur", "kota",
-            ];
-
-            for city in &cities {
-                if lower.contains(city) {
-                    let confidence = if lower.contains("in ") || lower.contains("at ")
-                        || lower.contains("from ") || lower.contains("near ")
-                        || lower.contains("mein") || lower.contains("में")
-                    {
-                        0.9
-                    } else {
-                        0.7
-                    };
+            // Fall through to the gazetteer if no config city matched
+        }
 
-                    // Capitalize city name
-                    let capitalized = city.chars().next().unwrap().to_uppercase().to_string()
-                        + &city[1..];
-                    return Some((capitalized, confidence));
-                }
-            }
+        if let Some((city, similarity)) = self.find_city(utterance) {
+            let confidence = if has_context { 0.9 } else { 0.7 };
+            return Some((city, confidence * similarity));
         }
 
         // Try to extract location after keywords using static pattern
@@ -1073,7 +1201,12 @@ impl SlotExtractor {
 
     /// Extract city from utterance
     pub fn extract_city(&self, utterance: &str) -> Option<(String, f32)> {
-        // First try direct city patterns
+        // Known cities first, by canonical name
+        if let Some((city, similarity)) = self.find_city(utterance) {
+            return Some((city, 0.85 * similarity));
+        }
+
+        // Then any capitalized place after a place word
         for pattern in CITY_PATTERNS.iter() {
             if let Some(caps) = pattern.captures(utterance) {
                 if let Some(m) = caps.get(1) {
@@ -1109,6 +1242,25 @@ impl SlotExtractor {
     // P3.1 FIX: Removed duplicate extract_loan_purpose() - use extract_purpose() instead
 }
 
+/// Higher confidence when the lender is named as the current one
+fn lender_confidence(lower: &str) -> f32 {
+    if lower.contains("from")
+        || lower.contains("with")
+        || lower.contains("se")
+        || lower.contains("current")
+    {
+        0.9
+    } else {
+        0.7
+    }
+}
+
+/// Entry for a language code, falling back to its base ("ta-IN" -> "ta")
+fn by_language<'a, V>(map: &'a HashMap<String, V>, language: &str) -> Option<&'a V> {
+    map.get(language)
+        .or_else(|| language.split('-').next().and_then(|base| map.get(base)))
+}
+
 impl Default for SlotExtractor {
     /// Creates a slot extractor with static fallback patterns only.
     /// For domain-agnostic operation, use `from_slots_config()` instead.
@@ -1126,7 +1278,7 @@ mod tests {
 
     #[test]
     fn test_amount_extraction() {
-        let extractor = SlotExtractor::new();
+        let extractor = SlotExtractor::shared();
 
         // Lakh amounts
         let (amount, _) = extractor.extract_amount("I need a loan of 5 lakh").unwrap();
@@ -1144,9 +1296,18 @@ mod tests {
         assert!((amount - 50_000.0).abs() < 1.0);
     }
 
+    #[test]
+    fn test_shared_extractor_is_process_wide() {
+        let (first, second) = (SlotExtractor::shared(), SlotExtractor::shared());
+        assert!(std::ptr::eq(first, second));
+
+        let slots = SlotExtractor::shared().extract("mujhe 5 lakh chahiye", "hi");
+        assert_eq!(slots["loan_amount"].value.as_deref(), Some("500000"));
+    }
+
     #[test]
     fn test_weight_extraction() {
-        let extractor = SlotExtractor::new();
+        let extractor = SlotExtractor::shared();
 
         // Gram weights
         let (weight, _) = extractor.extract_weight("I have 50 grams of gold").unwrap();
@@ -1162,7 +1323,7 @@ mod tests {
 
     #[test]
     fn test_phone_extraction() {
-        let extractor = SlotExtractor::new();
+        let extractor = SlotExtractor::shared();
 
         let (phone, _) = extractor.extract_phone("my number is 9876543210").unwrap();
         assert_eq!(phone, "9876543210");
@@ -1173,7 +1334,7 @@ mod tests {
 
     #[test]
     fn test_pincode_extraction() {
-        let extractor = SlotExtractor::new();
+        let extractor = SlotExtractor::shared();
 
         let (pincode, _) = extractor.extract_pincode("pincode is 400001").unwrap();
         assert_eq!(pincode, "400001");
@@ -1197,6 +1358,12 @@ mod tests {
         let (lender, _) = extractor.extract_lender("with Bank B").unwrap();
         assert_eq!(lender, "bank_b");
 
+        // Earliest mention wins regardless of map order
+        let (lender, _) = extractor
+            .extract_lender("bank b se shift karke lender a mein jaana hai")
+            .unwrap();
+        assert_eq!(lender, "bank_b");
+
         // Test that unrecognized lenders return None
         let empty_extractor = SlotExtractor::new();
         assert!(empty_extractor.extract_lender("from unknown provider").is_none());
@@ -1204,7 +1371,7 @@ mod tests {
 
     #[test]
     fn test_purity_extraction() {
-        let extractor = SlotExtractor::new();
+        let extractor = SlotExtractor::shared();
 
         let (purity, _) = extractor.extract_purity("24k gold").unwrap();
         assert_eq!(purity, "24");
@@ -1215,7 +1382,7 @@ mod tests {
 
     #[test]
     fn test_purpose_extraction() {
-        let extractor = SlotExtractor::new();
+        let extractor = SlotExtractor::shared();
 
         let (purpose, _) = extractor.extract_purpose("for medical treatment").unwrap();
         assert_eq!(purpose, "medical");
@@ -1229,7 +1396,7 @@ mod tests {
 
     #[test]
     fn test_location_extraction() {
-        let extractor = SlotExtractor::new();
+        let extractor = SlotExtractor::shared();
 
         let (location, _) = extractor.extract_location("I'm in Mumbai").unwrap();
         assert_eq!(location, "Mumbai");
@@ -1238,9 +1405,41 @@ mod tests {
         assert_eq!(location, "Bangalore");
     }
 
+    #[test]
+    fn test_location_from_gazetteer() {
+        use voice_agent_core::{City, Locality};
+
+        let mut locations = LocationGazetteer::new();
+        locations.add_city(City {
+            name: "Bangalore".to_string(),
+            aliases: vec!["Bengaluru".to_string()],
+            ..City::default()
+        });
+        locations.add_locality(Locality {
+            name: "Koramangala".to_string(),
+            city: "Bangalore".to_string(),
+            branch_id: Some("KMBL007".to_string()),
+            pincode: Some("560034".to_string()),
+        });
+        let extractor = SlotExtractor::with_location_gazetteer(Arc::new(locations));
+
+        for text in [
+            "main bengluru mein rehta hoon",
+            "my pincode is 560034",
+            "Koramangala ke paas",
+        ] {
+            let (city, _) = extractor.extract_city(text).unwrap();
+            assert_eq!(city, "Bangalore", "{}", text);
+        }
+        // A misheard name is less certain than an exact one
+        let (_, exact) = extractor.extract_location("I live in Bengaluru").unwrap();
+        let (_, misheard) = extractor.extract_location("I live in Bengluru").unwrap();
+        assert!(misheard < exact);
+    }
+
     #[test]
     fn test_tenure_extraction() {
-        let extractor = SlotExtractor::new();
+        let extractor = SlotExtractor::shared();
 
         let (tenure, _) = extractor.extract_tenure("for 12 months").unwrap();
         assert_eq!(tenure, 12);
@@ -1251,10 +1450,10 @@ mod tests {
 
     #[test]
     fn test_combined_extraction() {
-        let extractor = SlotExtractor::new();
+        let extractor = SlotExtractor::shared();
 
         let utterance = "I want a gold loan of 5 lakh for my 50 grams of 22k gold";
-        let slots = extractor.extract(utterance);
+        let slots = extractor.extract(utterance, "en");
 
         assert!(slots.contains_key("loan_amount"));
         assert!(slots.contains_key("gold_weight"));
@@ -1263,7 +1462,7 @@ mod tests {
 
     #[test]
     fn test_hindi_extraction() {
-        let extractor = SlotExtractor::new();
+        let extractor = SlotExtractor::shared();
 
         let (amount, _) = extractor.extract_amount("mujhe 5 lakh chahiye").unwrap();
         assert!((amount - 500_000.0).abs() < 1.0);
@@ -1272,9 +1471,131 @@ mod tests {
         assert!((weight - 50.0).abs() < 0.1);
     }
 
+    #[test]
+    fn test_mixed_script_extraction() {
+        let extractor = SlotExtractor::shared();
+
+        let slots = extractor.extract("mujhe dhai lakh chahiye, do saal ke liye", "hi");
+        assert_eq!(slots["loan_amount"].value.as_deref(), Some("250000"));
+        assert_eq!(slots["tenure_months"].value.as_deref(), Some("24"));
+
+        let slots = extractor.extract("मेरे पास बीस ग्राम सोना है, पांच lakh चाहिए", "hi");
+        assert_eq!(slots["loan_amount"].value.as_deref(), Some("500000"));
+        assert_eq!(slots["gold_weight"].value.as_deref(), Some("20"));
+    }
+
+    fn pack(
+        numbers: &[(&str, f64)],
+        amount_units: &[(&str, &[&str])],
+        weight_units: &[(&str, &[&str])],
+        intents: &[(&str, &[&str])],
+    ) -> LanguagePack {
+        let units = |entries: &[(&str, &[&str])]| {
+            entries
+                .iter()
+                .map(|(unit, forms)| {
+                    (unit.to_string(), forms.iter().map(|f| f.to_string()).collect())
+                })
+                .collect()
+        };
+        LanguagePack {
+            number_words: numbers.iter().map(|(w, n)| (w.to_string(), *n)).collect(),
+            amount_units: units(amount_units),
+            weight_units: units(weight_units),
+            intent_keywords: units(intents),
+        }
+    }
+
+    fn indic_extractor() -> SlotExtractor {
+        let mut packs = HashMap::new();
+        packs.insert(
+            "mr".to_string(),
+            pack(
+                &[("दोन", 2.0), ("दहा", 10.0), ("दीड", 1.5)],
+                &[("lakh", &["लाख"]), ("thousand", &["हजार"])],
+                &[("gram", &["ग्रॅम"]), ("tola", &["तोळ"])],
+                &[("rate_inquiry", &["व्याज"])],
+            ),
+        );
+        packs.insert(
+            "ta".to_string(),
+            pack(
+                &[("ஐந்து", 5.0), ("இருபது", 20.0)],
+                &[("lakh", &["லட்ச"]), ("thousand", &["ஆயிர"])],
+                &[("gram", &["கிராம்"])],
+                &[("rate_inquiry", &["வட்டி"])],
+            ),
+        );
+        packs.insert(
+            "te".to_string(),
+            pack(
+                &[("రెండు", 2.0), ("యాభై", 50.0)],
+                &[("lakh", &["లక్ష"]), ("crore", &["కోటి"])],
+                &[("gram", &["గ్రాము"]), ("tola", &["తులం"])],
+                &[("branch_inquiry", &["శాఖ"])],
+            ),
+        );
+        SlotExtractor::with_language_packs(packs)
+    }
+
+    #[test]
+    fn test_marathi_pack() {
+        let extractor = indic_extractor();
+
+        let slots = extractor.extract("मला दीड लाख हवे आहेत", "mr");
+        assert_eq!(slots["loan_amount"].value.as_deref(), Some("150000"));
+
+        let slots = extractor.extract("माझ्याकडे दहा तोळे सोने आहे", "mr");
+        let weight: f64 = slots["gold_weight"].value.as_deref().unwrap().parse().unwrap();
+        assert!((weight - 116.6).abs() < 0.1);
+
+        let slots = extractor.extract("व्याज दर किती आहे?", "mr");
+        assert_eq!(slots["detected_intent"].value.as_deref(), Some("rate_inquiry"));
+    }
+
+    #[test]
+    fn test_tamil_pack() {
+        let extractor = indic_extractor();
+
+        let slots = extractor.extract("எனக்கு ஐந்து லட்சம் கடன் வேண்டும்", "ta");
+        assert_eq!(slots["loan_amount"].value.as_deref(), Some("500000"));
+
+        // Inflected unit and Tamil digits
+        let slots = extractor.extract("௫ லட்சத்துக்கு எவ்வளவு வட்டி?", "ta-IN");
+        assert_eq!(slots["loan_amount"].value.as_deref(), Some("500000"));
+        assert_eq!(slots["detected_intent"].value.as_deref(), Some("rate_inquiry"));
+
+        let slots = extractor.extract("என்னிடம் இருபது கிராம் தங்கம் உள்ளது", "ta");
+        assert_eq!(slots["gold_weight"].value.as_deref(), Some("20"));
+    }
+
+    #[test]
+    fn test_telugu_pack() {
+        let extractor = indic_extractor();
+
+        let slots = extractor.extract("నాకు రెండు లక్షలు కావాలి", "te");
+        assert_eq!(slots["loan_amount"].value.as_deref(), Some("200000"));
+
+        let slots = extractor.extract("నా దగ్గర యాభై గ్రాముల బంగారం ఉంది", "te");
+        assert_eq!(slots["gold_weight"].value.as_deref(), Some("50"));
+
+        // A unit-like word without a number is left alone
+        let slots = extractor.extract("మా లక్ష్యం దగ్గరలో శాఖ ఎక్కడ ఉంది", "te");
+        assert!(!slots.contains_key("loan_amount"));
+        assert_eq!(slots["detected_intent"].value.as_deref(), Some("branch_inquiry"));
+    }
+
+    #[test]
+    fn test_language_pack_not_applied_to_other_languages() {
+        let extractor = indic_extractor();
+
+        let slots = extractor.extract("ஐந்து லட்சம்", "hi");
+        assert!(!slots.contains_key("loan_amount"));
+    }
+
     #[test]
     fn test_intent_extraction() {
-        let extractor = SlotExtractor::new();
+        let extractor = SlotExtractor::shared();
 
         let (intent, _) = extractor.extract_intent("I want balance transfer").unwrap();
         assert_eq!(intent, "balance_transfer");
@@ -0,0 +1,183 @@
+//! Fallback translator chain
+//!
+//! Tries translators in order, e.g. on-device ONNX first and Candle when the
+//! ONNX model is unavailable or fails on a request.
+
+use async_trait::async_trait;
+use futures::Stream;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use voice_agent_core::{Error, Language, Result, Translator};
+
+use super::ScriptDetector;
+
+/// Translator that falls through a chain of translators
+pub struct FallbackTranslator {
+    translators: Vec<Arc<dyn Translator>>,
+    detector: ScriptDetector,
+}
+
+impl FallbackTranslator {
+    /// Create a chain; earlier translators are preferred
+    pub fn new(translators: Vec<Arc<dyn Translator>>) -> Self {
+        Self {
+            translators,
+            detector: ScriptDetector::new(),
+        }
+    }
+
+    /// Names of the chained translators, in order
+    pub fn chain(&self) -> Vec<&str> {
+        self.translators.iter().map(|t| t.name()).collect()
+    }
+}
+
+#[async_trait]
+impl Translator for FallbackTranslator {
+    /// Translate with the first translator that supports the pair and succeeds
+    ///
+    /// Text passes through unchanged when no translator supports the pair; if
+    /// every supporting translator fails, the last error is returned.
+    async fn translate(&self, text: &str, from: Language, to: Language) -> Result<String> {
+        if from == to {
+            return Ok(text.to_string());
+        }
+
+        let mut last_error: Option<Error> = None;
+        for translator in self
+            .translators
+            .iter()
+            .filter(|t| t.supports_pair(from, to))
+        {
+            match translator.translate(text, from, to).await {
+                Ok(translation) => return Ok(translation),
+                Err(e) => {
+                    tracing::warn!(
+                        translator = translator.name(),
+                        error = %e,
+                        "Translation failed, trying next translator"
+                    );
+                    last_error = Some(e);
+                },
+            }
+        }
+
+        match last_error {
+            Some(e) => Err(e),
+            None => Ok(text.to_string()),
+        }
+    }
+
+    async fn detect_language(&self, text: &str) -> Result<Language> {
+        Ok(self.detector.detect(text))
+    }
+
+    fn translate_stream<'a>(
+        &'a self,
+        text_stream: Pin<Box<dyn Stream<Item = String> + Send + 'a>>,
+        from: Language,
+        to: Language,
+    ) -> Pin<Box<dyn Stream<Item = Result<String>> + Send + 'a>> {
+        use futures::StreamExt;
+
+        Box::pin(text_stream.then(move |text| async move { self.translate(&text, from, to).await }))
+    }
+
+    fn supports_pair(&self, from: Language, to: Language) -> bool {
+        self.translators.iter().any(|t| t.supports_pair(from, to))
+    }
+
+    fn name(&self) -> &str {
+        "fallback"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::translation::NoopTranslator;
+
+    /// Test translator that either fails or tags its output
+    struct MockTranslator {
+        name: &'static str,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl Translator for MockTranslator {
+        async fn translate(&self, text: &str, _from: Language, _to: Language) -> Result<String> {
+            if self.fail {
+                Err(Error::other(format!("{} unavailable", self.name)))
+            } else {
+                Ok(format!("[{}] {}", self.name, text))
+            }
+        }
+
+        async fn detect_language(&self, _text: &str) -> Result<Language> {
+            Ok(Language::English)
+        }
+
+        fn translate_stream<'a>(
+            &'a self,
+            text_stream: Pin<Box<dyn Stream<Item = String> + Send + 'a>>,
+            _from: Language,
+            _to: Language,
+        ) -> Pin<Box<dyn Stream<Item = Result<String>> + Send + 'a>> {
+            use futures::StreamExt;
+            Box::pin(text_stream.map(Ok))
+        }
+
+        fn supports_pair(&self, _from: Language, _to: Language) -> bool {
+            true
+        }
+
+        fn name(&self) -> &str {
+            self.name
+        }
+    }
+
+    fn mock(name: &'static str, fail: bool) -> Arc<dyn Translator> {
+        Arc::new(MockTranslator { name, fail })
+    }
+
+    #[tokio::test]
+    async fn test_falls_through_on_error() {
+        let chain = FallbackTranslator::new(vec![mock("onnx", true), mock("candle", false)]);
+        assert_eq!(chain.chain(), vec!["onnx", "candle"]);
+
+        let out = chain
+            .translate("hello", Language::English, Language::Hindi)
+            .await
+            .unwrap();
+        assert_eq!(out, "[candle] hello");
+    }
+
+    #[tokio::test]
+    async fn test_prefers_first_translator() {
+        let chain = FallbackTranslator::new(vec![mock("onnx", false), mock("candle", false)]);
+        let out = chain
+            .translate("hello", Language::English, Language::Hindi)
+            .await
+            .unwrap();
+        assert_eq!(out, "[onnx] hello");
+    }
+
+    #[tokio::test]
+    async fn test_all_failed_and_unsupported() {
+        let chain = FallbackTranslator::new(vec![mock("onnx", true), mock("candle", true)]);
+        assert!(chain
+            .translate("hello", Language::English, Language::Hindi)
+            .await
+            .is_err());
+
+        // Noop supports no pairs, so the text passes through
+        let chain = FallbackTranslator::new(vec![Arc::new(NoopTranslator::new())]);
+        assert!(!chain.supports_pair(Language::English, Language::Hindi));
+        let out = chain
+            .translate("hello", Language::English, Language::Hindi)
+            .await
+            .unwrap();
+        assert_eq!(out, "hello");
+    }
+}
@@ -61,7 +61,7 @@ impl Default for IndicTrans2Config {
 /// Language code mapping for IndicTrans2
 ///
 /// IndicTrans2 uses ISO 639-1 codes with script suffixes
-fn language_to_indictrans_code(lang: Language) -> &'static str {
+pub(super) fn language_to_indictrans_code(lang: Language) -> &'static str {
     match lang {
         Language::Hindi => "hin_Deva",
         Language::English => "eng_Latn",
@@ -97,13 +97,13 @@ struct CacheEntry {
 }
 
 /// Translation cache
-struct TranslationCache {
+pub(super) struct TranslationCache {
     entries: std::collections::HashMap<String, CacheEntry>,
     max_size: usize,
 }
 
 impl TranslationCache {
-    fn new(max_size: usize) -> Self {
+    pub(super) fn new(max_size: usize) -> Self {
         Self {
             entries: std::collections::HashMap::new(),
             max_size,
@@ -114,12 +114,12 @@ impl TranslationCache {
         format!("{}:{}:{}", from, to, text)
     }
 
-    fn get(&self, text: &str, from: Language, to: Language) -> Option<&str> {
+    pub(super) fn get(&self, text: &str, from: Language, to: Language) -> Option<&str> {
         let key = Self::make_key(text, from, to);
         self.entries.get(&key).map(|e| e.translation.as_str())
     }
 
-    fn insert(&mut self, text: &str, from: Language, to: Language, translation: String) {
+    pub(super) fn insert(&mut self, text: &str, from: Language, to: Language, translation: String) {
         // Simple eviction: clear half when full
         if self.entries.len() >= self.max_size {
             let keys_to_remove: Vec<_> = self
@@ -5,16 +5,24 @@
 //! Uses IndicTrans2 models for translation between English and 22 Indian languages:
 //! - indictrans2-en-indic-dist-200M: English → Indic languages
 //! - indictrans2-indic-en-dist-200M: Indic languages → English
+//!
+//! Providers can be chained: with `fallback` set, a request the primary
+//! provider fails on (or a primary that fails to load) is handed to the
+//! fallback, e.g. on-device ONNX first and Candle second.
 
 mod candle_indictrans2;
 mod detect;
+mod fallback;
 mod indictrans2;
 mod noop;
+mod onnx;
 
 pub use candle_indictrans2::{CandleIndicTrans2Config, CandleIndicTrans2Translator};
 pub use detect::ScriptDetector;
+pub use fallback::FallbackTranslator;
 pub use indictrans2::{IndicTrans2Config, IndicTrans2Translator};
 pub use noop::NoopTranslator;
+pub use onnx::{ModelDirection, OnnxTranslator, OnnxTranslatorConfig};
 
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -32,9 +40,21 @@ pub struct TranslationConfig {
     /// Path to Indic→English model (for Candle provider)
     #[serde(default = "default_indic_en_path")]
     pub indic_en_model_path: PathBuf,
-    /// Legacy: IndicTrans2 model path (for ONNX provider)
+    /// Legacy: IndicTrans2 model path (for IndicTrans2 provider)
     #[serde(default)]
     pub indictrans2_model_path: Option<PathBuf>,
+    /// English→Indic ONNX model directory (for Onnx provider)
+    #[serde(default = "default_onnx_en_indic_path")]
+    pub onnx_en_indic_path: PathBuf,
+    /// Indic→English ONNX model directory (for Onnx provider)
+    #[serde(default = "default_onnx_indic_en_path")]
+    pub onnx_indic_en_path: PathBuf,
+    /// Maximum sentences per ONNX inference batch
+    #[serde(default = "default_max_batch_size")]
+    pub max_batch_size: usize,
+    /// Provider to fall back to when the primary fails
+    #[serde(default)]
+    pub fallback: Option<TranslationProvider>,
 }
 
 fn default_en_indic_path() -> PathBuf {
@@ -45,16 +65,29 @@ fn default_indic_en_path() -> PathBuf {
     PathBuf::from("models/translation/indictrans2-indic-en")
 }
 
+fn default_onnx_en_indic_path() -> PathBuf {
+    PathBuf::from("models/translation/indictrans2-en-indic-onnx")
+}
+
+fn default_onnx_indic_en_path() -> PathBuf {
+    PathBuf::from("models/translation/indictrans2-indic-en-onnx")
+}
+
+fn default_max_batch_size() -> usize {
+    8
+}
+
 /// Translation providers
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum TranslationProvider {
     /// Candle-based IndicTrans2 translation (native Rust, recommended)
     #[default]
     #[serde(alias = "native")]
     Candle,
+    /// On-device ONNX IndicTrans2 with batching and per-direction models
+    Onnx,
     /// Legacy ONNX-based IndicTrans2 translation
-    #[serde(alias = "onnx")]
     IndicTrans2,
     /// Disabled (pass-through)
     Disabled,
@@ -67,13 +100,46 @@ impl Default for TranslationConfig {
             en_indic_model_path: default_en_indic_path(),
             indic_en_model_path: default_indic_en_path(),
             indictrans2_model_path: None,
+            onnx_en_indic_path: default_onnx_en_indic_path(),
+            onnx_indic_en_path: default_onnx_indic_en_path(),
+            max_batch_size: default_max_batch_size(),
+            fallback: None,
         }
     }
 }
 
 /// Create translator based on config
+///
+/// With a `fallback` provider, both are chained in a [`FallbackTranslator`].
+/// A provider that fails to load is skipped; if none load, translation is
+/// disabled (pass-through).
 pub fn create_translator(config: &TranslationConfig) -> Arc<dyn Translator> {
-    match config.provider {
+    let primary = build_provider(config, config.provider);
+    let fallback = config
+        .fallback
+        .filter(|provider| *provider != config.provider)
+        .and_then(|provider| build_provider(config, provider));
+
+    match (primary, fallback) {
+        (Some(primary), Some(fallback)) => {
+            tracing::info!(
+                primary = primary.name(),
+                fallback = fallback.name(),
+                "Using translator with fallback"
+            );
+            Arc::new(FallbackTranslator::new(vec![primary, fallback]))
+        },
+        (Some(translator), None) | (None, Some(translator)) => translator,
+        (None, None) => Arc::new(NoopTranslator::new()),
+    }
+}
+
+/// Build a single provider, `None` if it is disabled or fails to load
+fn build_provider(
+    config: &TranslationConfig,
+    provider: TranslationProvider,
+) -> Option<Arc<dyn Translator>> {
+    match provider {
         TranslationProvider::Candle => {
             // Create Candle-based IndicTrans2 translator with both models
             let candle_config = CandleIndicTrans2Config {
@@ -85,14 +151,30 @@ pub fn create_translator(config: &TranslationConfig) -> Arc<dyn Translator> {
             match CandleIndicTrans2Translator::new(candle_config) {
                 Ok(translator) => {
                     tracing::info!("Using Candle IndicTrans2 translator");
-                    Arc::new(translator)
+                    Some(Arc::new(translator))
                 },
                 Err(e) => {
-                    tracing::warn!(
-                        error = %e,
-                        "Failed to load Candle IndicTrans2, using noop translator"
-                    );
-                    Arc::new(NoopTranslator::new())
+                    tracing::warn!(error = %e, "Failed to load Candle IndicTrans2");
+                    None
+                },
+            }
+        },
+        TranslationProvider::Onnx => {
+            let onnx_config = OnnxTranslatorConfig {
+                en_indic_dir: config.onnx_en_indic_path.clone(),
+                indic_en_dir: config.onnx_indic_en_path.clone(),
+                max_batch_size: config.max_batch_size,
+                ..Default::default()
+            };
+
+            match OnnxTranslator::new(onnx_config) {
+                Ok(translator) => {
+                    tracing::info!("Using on-device ONNX IndicTrans2 translator");
+                    Some(Arc::new(translator))
+                },
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to load ONNX IndicTrans2 models");
+                    None
                 },
             }
         },
@@ -112,18 +194,15 @@ pub fn create_translator(config: &TranslationConfig) -> Arc<dyn Translator> {
             match IndicTrans2Translator::new(indictrans2_config) {
                 Ok(translator) => {
                     tracing::info!("Using ONNX IndicTrans2 translator");
-                    Arc::new(translator)
+                    Some(Arc::new(translator))
                 },
                 Err(e) => {
-                    tracing::warn!(
-                        error = %e,
-                        "Failed to load ONNX IndicTrans2, using noop translator"
-                    );
-                    Arc::new(NoopTranslator::new())
+                    tracing::warn!(error = %e, "Failed to load ONNX IndicTrans2");
+                    None
                 },
             }
         },
-        TranslationProvider::Disabled => Arc::new(NoopTranslator::new()),
+        TranslationProvider::Disabled => None,
     }
 }
 
@@ -234,6 +313,29 @@ mod tests {
     fn test_default_config() {
         let config = TranslationConfig::default();
         assert!(matches!(config.provider, TranslationProvider::Candle));
+        assert_eq!(config.max_batch_size, 8);
+        assert!(config.fallback.is_none());
+    }
+
+    #[test]
+    fn test_provider_config() {
+        let config: TranslationConfig =
+            serde_json::from_str(r#"{"provider": "onnx", "fallback": "native"}"#).unwrap();
+        assert_eq!(config.provider, TranslationProvider::Onnx);
+        assert_eq!(config.fallback, Some(TranslationProvider::Candle));
+        assert_eq!(config.onnx_en_indic_path, default_onnx_en_indic_path());
+    }
+
+    #[test]
+    fn test_create_translator_without_models() {
+        let config = TranslationConfig {
+            provider: TranslationProvider::Onnx,
+            onnx_en_indic_path: PathBuf::from("/nonexistent/en-indic"),
+            onnx_indic_en_path: PathBuf::from("/nonexistent/indic-en"),
+            fallback: Some(TranslationProvider::Disabled),
+            ..Default::default()
+        };
+        assert_eq!(create_translator(&config).name(), "noop");
     }
 
     #[test]
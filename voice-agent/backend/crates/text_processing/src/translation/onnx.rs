@@ -0,0 +1,570 @@
+//! On-Device ONNX Translator
+//!
+//! Runs the distilled IndicTrans2 models (200M) exported to ONNX, e.g. with
+//! `optimum-cli export onnx --model ai4bharat/indictrans2-en-indic-dist-200M`.
+//! Each translation direction is a separate model directory:
+//! - `en_indic_dir`: English → Indic languages
+//! - `indic_en_dir`: Indic languages → English
+//!
+//! A directory holds `encoder_model.onnx`, `decoder_model.onnx`,
+//! `tokenizer.json` and optionally `config.json` (special token IDs).
+//!
+//! Models are loaded on first use (or at startup with `preload`) and can be
+//! unloaded to free memory. Requests are translated in padded batches of up
+//! to `max_batch_size` sentences with greedy decoding.
+//!
+//! Unlike the pass-through stubs, this translator returns an error when it
+//! cannot translate, so a [`super::FallbackTranslator`] can hand the request
+//! to the next provider.
+
+use async_trait::async_trait;
+use futures::Stream;
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::Arc;
+
+use voice_agent_core::{Error, Language, Result, Translator};
+
+use super::indictrans2::{language_to_indictrans_code, TranslationCache};
+use super::{is_pair_supported, ScriptDetector};
+
+/// ONNX translator configuration
+#[derive(Debug, Clone)]
+pub struct OnnxTranslatorConfig {
+    /// English → Indic model directory
+    pub en_indic_dir: PathBuf,
+    /// Indic → English model directory
+    pub indic_en_dir: PathBuf,
+    /// Maximum source and output length in tokens
+    pub max_seq_length: usize,
+    /// Maximum sentences per inference batch
+    pub max_batch_size: usize,
+    /// Intra-op threads per ONNX session
+    pub num_threads: usize,
+    /// Load both models at startup instead of on first use
+    pub preload: bool,
+    /// Enable translation caching
+    pub cache_enabled: bool,
+    /// Maximum cache entries
+    pub cache_size: usize,
+}
+
+impl Default for OnnxTranslatorConfig {
+    fn default() -> Self {
+        Self {
+            en_indic_dir: PathBuf::from("models/translation/indictrans2-en-indic-onnx"),
+            indic_en_dir: PathBuf::from("models/translation/indictrans2-indic-en-onnx"),
+            max_seq_length: 256,
+            max_batch_size: 8,
+            num_threads: 2,
+            preload: true,
+            cache_enabled: true,
+            cache_size: 1000,
+        }
+    }
+}
+
+/// Translation direction; IndicTrans2 ships one model per direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModelDirection {
+    /// English → Indic
+    EnIndic,
+    /// Indic → English
+    IndicEn,
+}
+
+impl ModelDirection {
+    /// Model direction for a language pair (`None` for Indic ↔ Indic or same language)
+    pub fn for_pair(from: Language, to: Language) -> Option<Self> {
+        match (from == Language::English, to == Language::English) {
+            (true, false) => Some(Self::EnIndic),
+            (false, true) => Some(Self::IndicEn),
+            _ => None,
+        }
+    }
+}
+
+/// On-device IndicTrans2 translator using ONNX Runtime
+pub struct OnnxTranslator {
+    config: OnnxTranslatorConfig,
+    models: RwLock<HashMap<ModelDirection, Arc<model::PairModel>>>,
+    cache: RwLock<TranslationCache>,
+    detector: ScriptDetector,
+}
+
+impl OnnxTranslator {
+    /// Create a translator, loading both models now if `preload` is set
+    pub fn new(config: OnnxTranslatorConfig) -> Result<Self> {
+        let translator = Self {
+            cache: RwLock::new(TranslationCache::new(config.cache_size)),
+            config,
+            models: RwLock::new(HashMap::new()),
+            detector: ScriptDetector::new(),
+        };
+
+        if translator.config.preload {
+            translator.model(ModelDirection::EnIndic)?;
+            translator.model(ModelDirection::IndicEn)?;
+        }
+
+        Ok(translator)
+    }
+
+    /// Model directory for a direction
+    pub fn model_dir(&self, direction: ModelDirection) -> &Path {
+        match direction {
+            ModelDirection::EnIndic => &self.config.en_indic_dir,
+            ModelDirection::IndicEn => &self.config.indic_en_dir,
+        }
+    }
+
+    /// Directions whose model is currently loaded
+    pub fn loaded_models(&self) -> Vec<ModelDirection> {
+        self.models.read().keys().copied().collect()
+    }
+
+    /// Unload a direction's model; it is reloaded on next use
+    pub fn unload(&self, direction: ModelDirection) -> bool {
+        self.models.write().remove(&direction).is_some()
+    }
+
+    /// Get a direction's model, loading it if needed
+    fn model(&self, direction: ModelDirection) -> Result<Arc<model::PairModel>> {
+        if let Some(model) = self.models.read().get(&direction) {
+            return Ok(model.clone());
+        }
+
+        let mut models = self.models.write();
+        // Another request may have loaded it while we waited for the lock
+        if let Some(model) = models.get(&direction) {
+            return Ok(model.clone());
+        }
+
+        let dir = self.model_dir(direction);
+        tracing::info!(direction = ?direction, path = ?dir, "Loading ONNX translation model");
+        let model = Arc::new(model::PairModel::load(dir, self.config.num_threads)?);
+        models.insert(direction, model.clone());
+        Ok(model)
+    }
+
+    /// Translate several sentences in one go
+    ///
+    /// Cached sentences are served from the cache; the rest run through the
+    /// model in batches of up to `max_batch_size`. Output order matches input.
+    pub async fn translate_batch(
+        &self,
+        texts: &[String],
+        from: Language,
+        to: Language,
+    ) -> Result<Vec<String>> {
+        if from == to {
+            return Ok(texts.to_vec());
+        }
+
+        let direction = ModelDirection::for_pair(from, to)
+            .filter(|_| is_pair_supported(from, to))
+            .ok_or_else(|| {
+                Error::other(format!(
+                    "ONNX translator does not support {} -> {}",
+                    from, to
+                ))
+            })?;
+
+        let mut results: Vec<Option<String>> = if self.config.cache_enabled {
+            let cache = self.cache.read();
+            texts
+                .iter()
+                .map(|text| cache.get(text, from, to).map(str::to_string))
+                .collect()
+        } else {
+            vec![None; texts.len()]
+        };
+
+        let pending: Vec<usize> = (0..texts.len()).filter(|&i| results[i].is_none()).collect();
+        if !pending.is_empty() {
+            let model = self.model(direction)?;
+            let src_code = language_to_indictrans_code(from);
+            let tgt_code = language_to_indictrans_code(to);
+
+            for batch in pending.chunks(self.config.max_batch_size.max(1)) {
+                let inputs: Vec<&str> = batch.iter().map(|&i| texts[i].as_str()).collect();
+                let outputs =
+                    model.translate(&inputs, src_code, tgt_code, self.config.max_seq_length)?;
+
+                for (&i, output) in batch.iter().zip(outputs) {
+                    if self.config.cache_enabled {
+                        self.cache
+                            .write()
+                            .insert(&texts[i], from, to, output.clone());
+                    }
+                    results[i] = Some(output);
+                }
+            }
+        }
+
+        Ok(results.into_iter().map(Option::unwrap_or_default).collect())
+    }
+}
+
+#[async_trait]
+impl Translator for OnnxTranslator {
+    async fn translate(&self, text: &str, from: Language, to: Language) -> Result<String> {
+        if from == to {
+            return Ok(text.to_string());
+        }
+
+        let mut outputs = self.translate_batch(&[text.to_string()], from, to).await?;
+        Ok(outputs.pop().unwrap_or_default())
+    }
+
+    async fn detect_language(&self, text: &str) -> Result<Language> {
+        Ok(self.detector.detect(text))
+    }
+
+    fn translate_stream<'a>(
+        &'a self,
+        text_stream: Pin<Box<dyn Stream<Item = String> + Send + 'a>>,
+        from: Language,
+        to: Language,
+    ) -> Pin<Box<dyn Stream<Item = Result<String>> + Send + 'a>> {
+        use futures::StreamExt;
+
+        Box::pin(text_stream.then(move |text| async move { self.translate(&text, from, to).await }))
+    }
+
+    fn supports_pair(&self, from: Language, to: Language) -> bool {
+        ModelDirection::for_pair(from, to).is_some() && is_pair_supported(from, to)
+    }
+
+    fn name(&self) -> &str {
+        "indictrans2-onnx-native"
+    }
+}
+
+// ============================================================================
+// ONNX Model (feature-gated)
+// ============================================================================
+
+/// Special token IDs, read from the model's `config.json` when present
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(not(feature = "onnx"), allow(dead_code))]
+struct SpecialTokens {
+    pad: i64,
+    eos: i64,
+    decoder_start: i64,
+}
+
+#[cfg_attr(not(feature = "onnx"), allow(dead_code))]
+impl SpecialTokens {
+    fn load(dir: &Path) -> Self {
+        let json: serde_json::Value = std::fs::read_to_string(dir.join("config.json"))
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+        let id = |key: &str, default: i64| json[key].as_i64().unwrap_or(default);
+        Self {
+            pad: id("pad_token_id", 1),
+            eos: id("eos_token_id", 2),
+            decoder_start: id("decoder_start_token_id", 2),
+        }
+    }
+}
+
+#[cfg(feature = "onnx")]
+mod model {
+    use super::*;
+    use ndarray::{Array2, Array3};
+    use ort::{session::builder::GraphOptimizationLevel, session::Session, value::Tensor};
+    use parking_lot::Mutex;
+    use tokenizers::Tokenizer;
+
+    fn onnx_error(context: &str, e: impl std::fmt::Display) -> Error {
+        Error::other(format!("ONNX translation {}: {}", context, e))
+    }
+
+    fn load_session(path: &Path, num_threads: usize) -> Result<Session> {
+        Session::builder()
+            .map_err(|e| onnx_error("session builder", e))?
+            .with_optimization_level(GraphOptimizationLevel::Level3)
+            .map_err(|e| onnx_error("optimization level", e))?
+            .with_intra_threads(num_threads)
+            .map_err(|e| onnx_error("thread count", e))?
+            .commit_from_file(path)
+            .map_err(|e| onnx_error(&format!("loading {}", path.display()), e))
+    }
+
+    /// Encoder, decoder and tokenizer for one direction
+    pub(super) struct PairModel {
+        encoder: Mutex<Session>,
+        decoder: Mutex<Session>,
+        tokenizer: Tokenizer,
+        tokens: SpecialTokens,
+    }
+
+    impl PairModel {
+        pub(super) fn load(dir: &Path, num_threads: usize) -> Result<Self> {
+            let encoder = load_session(&dir.join("encoder_model.onnx"), num_threads)?;
+            let decoder = load_session(&dir.join("decoder_model.onnx"), num_threads)?;
+            let tokenizer = Tokenizer::from_file(dir.join("tokenizer.json"))
+                .map_err(|e| onnx_error("loading tokenizer", e))?;
+
+            Ok(Self {
+                encoder: Mutex::new(encoder),
+                decoder: Mutex::new(decoder),
+                tokenizer,
+                tokens: SpecialTokens::load(dir),
+            })
+        }
+
+        /// Translate a batch with greedy decoding
+        pub(super) fn translate(
+            &self,
+            texts: &[&str],
+            src_code: &str,
+            tgt_code: &str,
+            max_len: usize,
+        ) -> Result<Vec<String>> {
+            let batch = texts.len();
+            if batch == 0 {
+                return Ok(Vec::new());
+            }
+
+            // Tokenize with IndicTrans2 language tags, pad to the longest input
+            let mut sources = Vec::with_capacity(batch);
+            for text in texts {
+                let encoding = self
+                    .tokenizer
+                    .encode(format!("{} {} {}", src_code, tgt_code, text), true)
+                    .map_err(|e| onnx_error("tokenization", e))?;
+                let mut ids: Vec<i64> = encoding.get_ids().iter().map(|&id| id as i64).collect();
+                ids.truncate(max_len);
+                sources.push(ids);
+            }
+            let width = sources.iter().map(Vec::len).max().unwrap_or(0).max(1);
+
+            let mut input_ids = Array2::from_elem((batch, width), self.tokens.pad);
+            let mut attention_mask = Array2::<i64>::zeros((batch, width));
+            for (row, ids) in sources.iter().enumerate() {
+                for (col, &id) in ids.iter().enumerate() {
+                    input_ids[[row, col]] = id;
+                    attention_mask[[row, col]] = 1;
+                }
+            }
+
+            // Encode
+            let hidden = {
+                let mut encoder = self.encoder.lock();
+                let outputs = encoder
+                    .run(ort::inputs![
+                        "input_ids" => Tensor::from_array(input_ids).map_err(|e| onnx_error("input tensor", e))?,
+                        "attention_mask" => Tensor::from_array(attention_mask.clone()).map_err(|e| onnx_error("mask tensor", e))?,
+                    ])
+                    .map_err(|e| onnx_error("encoder", e))?;
+                let (shape, data) = outputs
+                    .get("last_hidden_state")
+                    .ok_or_else(|| onnx_error("encoder", "missing last_hidden_state"))?
+                    .try_extract_tensor::<f32>()
+                    .map_err(|e| onnx_error("encoder output", e))?;
+                let dims: Vec<usize> = shape.iter().map(|&d| d as usize).collect();
+                if dims.len() != 3 {
+                    return Err(onnx_error(
+                        "encoder output",
+                        format!("unexpected shape {:?}", dims),
+                    ));
+                }
+                Array3::from_shape_vec((dims[0], dims[1], dims[2]), data.to_vec())
+                    .map_err(|e| onnx_error("encoder output", e))?
+            };
+
+            // Greedy decode every row in lockstep; finished rows are fed padding
+            let mut decoded: Vec<Vec<i64>> = vec![vec![self.tokens.decoder_start]; batch];
+            let mut finished = vec![false; batch];
+            let mut decoder = self.decoder.lock();
+
+            for _ in 0..max_len {
+                let step = decoded[0].len();
+                let flat: Vec<i64> = decoded.iter().flatten().copied().collect();
+                let decoder_ids = Array2::from_shape_vec((batch, step), flat)
+                    .map_err(|e| onnx_error("decoder input", e))?;
+
+                let outputs = decoder
+                    .run(ort::inputs![
+                        "input_ids" => Tensor::from_array(decoder_ids).map_err(|e| onnx_error("decoder tensor", e))?,
+                        "encoder_hidden_states" => Tensor::from_array(hidden.clone()).map_err(|e| onnx_error("hidden tensor", e))?,
+                        "encoder_attention_mask" => Tensor::from_array(attention_mask.clone()).map_err(|e| onnx_error("mask tensor", e))?,
+                    ])
+                    .map_err(|e| onnx_error("decoder", e))?;
+                let (shape, logits) = outputs
+                    .get("logits")
+                    .ok_or_else(|| onnx_error("decoder", "missing logits"))?
+                    .try_extract_tensor::<f32>()
+                    .map_err(|e| onnx_error("decoder output", e))?;
+
+                // Logits: [batch, step, vocab]; take the last position of each row
+                let vocab = shape.last().copied().unwrap_or(0) as usize;
+                if vocab == 0 || logits.len() < batch * step * vocab {
+                    return Err(onnx_error("decoder output", "unexpected logits shape"));
+                }
+                for row in 0..batch {
+                    if finished[row] {
+                        decoded[row].push(self.tokens.pad);
+                        continue;
+                    }
+                    let start = (row * step + step - 1) * vocab;
+                    let next = logits[start..start + vocab]
+                        .iter()
+                        .enumerate()
+                        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+                        .map(|(id, _)| id as i64)
+                        .unwrap_or(self.tokens.eos);
+                    if next == self.tokens.eos {
+                        finished[row] = true;
+                    }
+                    decoded[row].push(next);
+                }
+
+                if finished.iter().all(|&f| f) {
+                    break;
+                }
+            }
+
+            decoded
+                .iter()
+                .map(|ids| {
+                    let tokens: Vec<u32> = ids
+                        .iter()
+                        .skip(1)
+                        .take_while(|&&id| id != self.tokens.eos)
+                        .filter(|&&id| id != self.tokens.pad)
+                        .map(|&id| id as u32)
+                        .collect();
+                    self.tokenizer
+                        .decode(&tokens, true)
+                        .map(|text| text.trim().to_string())
+                        .map_err(|e| onnx_error("decoding", e))
+                })
+                .collect()
+        }
+    }
+}
+
+#[cfg(not(feature = "onnx"))]
+mod model {
+    use super::*;
+
+    /// Placeholder when the `onnx` feature is disabled; loading always fails
+    pub(super) struct PairModel;
+
+    impl PairModel {
+        pub(super) fn load(_dir: &Path, _num_threads: usize) -> Result<Self> {
+            Err(Error::other("ONNX translation requires the `onnx` feature"))
+        }
+
+        pub(super) fn translate(
+            &self,
+            _texts: &[&str],
+            _src_code: &str,
+            _tgt_code: &str,
+            _max_len: usize,
+        ) -> Result<Vec<String>> {
+            Err(Error::other("ONNX translation requires the `onnx` feature"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lazy_config() -> OnnxTranslatorConfig {
+        OnnxTranslatorConfig {
+            en_indic_dir: PathBuf::from("/nonexistent/en-indic"),
+            indic_en_dir: PathBuf::from("/nonexistent/indic-en"),
+            preload: false,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_model_direction() {
+        assert_eq!(
+            ModelDirection::for_pair(Language::English, Language::Tamil),
+            Some(ModelDirection::EnIndic)
+        );
+        assert_eq!(
+            ModelDirection::for_pair(Language::Hindi, Language::English),
+            Some(ModelDirection::IndicEn)
+        );
+        assert_eq!(
+            ModelDirection::for_pair(Language::Hindi, Language::Tamil),
+            None
+        );
+    }
+
+    #[test]
+    fn test_preload_fails_without_models() {
+        let config = OnnxTranslatorConfig {
+            preload: true,
+            ..lazy_config()
+        };
+        assert!(OnnxTranslator::new(config).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_lazy_loading_and_errors() {
+        let translator = OnnxTranslator::new(lazy_config()).unwrap();
+        assert!(translator.loaded_models().is_empty());
+        assert_eq!(
+            translator.model_dir(ModelDirection::IndicEn),
+            Path::new("/nonexistent/indic-en")
+        );
+
+        // Same language never touches a model
+        let out = translator
+            .translate("hello", Language::English, Language::English)
+            .await
+            .unwrap();
+        assert_eq!(out, "hello");
+
+        // Unsupported pair and missing model are errors, not pass-through
+        assert!(!translator.supports_pair(Language::Hindi, Language::Tamil));
+        assert!(translator
+            .translate("नमस्ते", Language::Hindi, Language::Tamil)
+            .await
+            .is_err());
+        assert!(translator
+            .translate("नमस्ते", Language::Hindi, Language::English)
+            .await
+            .is_err());
+        assert!(translator.loaded_models().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_batch_served_from_cache() {
+        let translator = OnnxTranslator::new(lazy_config()).unwrap();
+        {
+            let mut cache = translator.cache.write();
+            cache.insert("hello", Language::English, Language::Hindi, "नमस्ते".into());
+            cache.insert(
+                "thanks",
+                Language::English,
+                Language::Hindi,
+                "धन्यवाद".into(),
+            );
+        }
+
+        // Every sentence is cached, so no model is needed
+        let out = translator
+            .translate_batch(
+                &["thanks".to_string(), "hello".to_string()],
+                Language::English,
+                Language::Hindi,
+            )
+            .await
+            .unwrap();
+        assert_eq!(out, vec!["धन्यवाद", "नमस्ते"]);
+        assert!(translator.loaded_models().is_empty());
+    }
+}
@@ -0,0 +1,363 @@
+//! Romanized Hindi / Devanagari Normalization
+//!
+//! Callers switch freely between romanized Hindi ("mujhe paanch lakh ka loan
+//! chahiye"), Devanagari ("मुझे पांच लाख चाहिए") and mixtures of both
+//! ("मुझे पांच lakh चाहिए"). The extraction regexes only cover some of these
+//! combinations, so text is normalized before intent and entity extraction:
+//!
+//! - [`normalize`] rewrites amounts, weights and durations into one canonical
+//!   form: ASCII digits followed by a romanized unit ("5 lakh", "250 gram",
+//!   "2 saal"). Number words are only converted when a unit follows, so
+//!   "do you have a branch" is left alone.
+//! - [`to_roman`] / [`to_devanagari`] transliterate common domain words
+//!   through a small lexicon, so an utterance in one script can be matched
+//!   against intent examples written in the other.
+//!
+//! # Example
+//!
+//! ```
+//! use voice_agent_text_processing::transliteration::{normalize, to_roman};
+//!
+//! assert_eq!(normalize("mujhe paanch lakh ka loan chahiye"), "mujhe 5 lakh ka loan chahiye");
+//! assert_eq!(normalize("मुझे पांच lakh चाहिए"), "मुझे 5 lakh चाहिए");
+//! assert_eq!(to_roman("मुझे गोल्ड लोन चाहिए"), "mujhe gold loan chahiye");
+//! ```
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+use crate::hindi;
+use crate::intent::IntentDetector;
+
+/// Word lexicon: (Devanagari spellings, romanized spellings)
+///
+/// The first spelling on each side is the canonical form.
+static LEXICON: &[(&[&str], &[&str])] = &[
+    // Units
+    (&["लाख", "लख"], &["lakh", "lac", "lakhs", "laakh"]),
+    (
+        &["करोड़", "करोड"],
+        &["crore", "crores", "karod", "karor", "karode"],
+    ),
+    (&["हज़ार", "हजार"], &["hazar", "hazaar", "hajar", "hajaar"]),
+    (&["सौ"], &["sau"]),
+    (&["ग्राम"], &["gram", "grams", "graam"]),
+    (&["तोला", "तोले"], &["tola", "tole"]),
+    (&["साल"], &["saal", "sal"]),
+    (
+        &["महीने", "महीना"],
+        &["mahine", "mahina", "maheene", "mahinay"],
+    ),
+    (
+        &["रुपये", "रुपए", "रूपये"],
+        &["rupaye", "rupees", "rupay", "rupaiye"],
+    ),
+    // Number words
+    (&["एक"], &["ek"]),
+    (&["दो"], &["do"]),
+    (&["तीन"], &["teen"]),
+    (&["चार"], &["char", "chaar"]),
+    (&["पांच", "पाँच"], &["paanch", "panch"]),
+    (&["छह", "छः", "छे"], &["chhe", "chheh", "chhah"]),
+    (&["सात"], &["saat"]),
+    (&["आठ"], &["aath"]),
+    (&["नौ"], &["nau"]),
+    (&["दस"], &["das", "dus"]),
+    (&["बीस"], &["bees"]),
+    (&["पचास"], &["pachaas", "pachas"]),
+    (&["डेढ़", "डेढ"], &["dedh", "derh"]),
+    (&["ढाई"], &["dhai", "dhaai", "adhai"]),
+    // Domain words
+    (&["लोन"], &["loan", "lon", "lone"]),
+    (&["गोल्ड"], &["gold", "gould"]),
+    (&["सोना"], &["sona"]),
+    (&["सोने"], &["sone"]),
+    (&["ब्याज"], &["byaj", "byaaj", "biyaj"]),
+    (&["दर"], &["dar"]),
+    (&["रेट"], &["rate"]),
+    (&["ब्रांच"], &["branch"]),
+    (&["ईएमआई"], &["emi"]),
+    // Function words
+    (&["मुझे"], &["mujhe", "mujhey", "mujhay"]),
+    (&["मेरा"], &["mera"]),
+    (
+        &["चाहिए", "चाहिये"],
+        &["chahiye", "chahie", "chaiye", "chahiyeh"],
+    ),
+    (&["कितना"], &["kitna", "kitnaa"]),
+    (&["कितनी"], &["kitni"]),
+    (&["क्या"], &["kya", "kyaa"]),
+    (&["कहाँ", "कहां"], &["kahan", "kahaan"]),
+    (&["है"], &["hai", "hae"]),
+    (&["हैं"], &["hain"]),
+    (&["का"], &["ka"]),
+    (&["की"], &["ki"]),
+    (&["के"], &["ke"]),
+    (&["में"], &["mein"]),
+    (&["नहीं"], &["nahi", "nahin", "nahee"]),
+    (&["हाँ", "हां"], &["haan", "han"]),
+];
+
+/// Canonical romanized units that trigger number-word conversion
+const UNITS: &[&str] = &["lakh", "crore", "hazar", "gram", "tola", "saal", "mahine"];
+
+/// Folded Devanagari spelling -> canonical romanized form
+static DEVANAGARI_TO_ROMAN: Lazy<HashMap<String, &'static str>> = Lazy::new(|| {
+    let mut map = HashMap::new();
+    for (devanagari, roman) in LEXICON {
+        for spelling in *devanagari {
+            map.entry(fold(spelling)).or_insert(roman[0]);
+        }
+    }
+    map
+});
+
+/// Romanized spelling -> canonical Devanagari form
+static ROMAN_TO_DEVANAGARI: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
+    let mut map = HashMap::new();
+    for (devanagari, roman) in LEXICON {
+        for spelling in *roman {
+            map.entry(*spelling).or_insert(devanagari[0]);
+        }
+    }
+    map
+});
+
+/// Romanized spelling -> canonical romanized form
+static ROMAN_CANONICAL: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
+    let mut map = HashMap::new();
+    for (_, roman) in LEXICON {
+        for spelling in *roman {
+            map.entry(*spelling).or_insert(roman[0]);
+        }
+    }
+    map
+});
+
+/// One whitespace-separated token, with surrounding punctuation kept aside
+struct Token<'a> {
+    prefix: &'a str,
+    core: &'a str,
+    suffix: &'a str,
+    /// Lookup key: lowercased, ASCII digits, folded Devanagari
+    key: String,
+}
+
+impl<'a> Token<'a> {
+    fn parse(raw: &'a str) -> Self {
+        let start = raw.find(|c: char| !is_punctuation(c)).unwrap_or(raw.len());
+        let end = raw
+            .rfind(|c: char| !is_punctuation(c))
+            .map(|i| i + raw[i..].chars().next().map_or(1, char::len_utf8))
+            .unwrap_or(start)
+            .max(start);
+        let core = &raw[start..end];
+        Self {
+            prefix: &raw[..start],
+            core,
+            suffix: &raw[end..],
+            key: fold(&IntentDetector::indic_numerals_to_ascii(
+                &core.to_lowercase(),
+            )),
+        }
+    }
+
+    /// Canonical romanized form of this token, if it is a lexicon word
+    fn canonical_roman(&self) -> Option<&'static str> {
+        DEVANAGARI_TO_ROMAN
+            .get(&self.key)
+            .copied()
+            .or_else(|| ROMAN_CANONICAL.get(self.key.as_str()).copied())
+    }
+
+    fn is_unit(&self) -> bool {
+        self.canonical_roman().is_some_and(|w| UNITS.contains(&w))
+    }
+
+    /// Value of a number word in either script
+    fn number_value(&self) -> Option<f64> {
+        hindi::roman_word_to_number(&self.key)
+            .or_else(|| hindi::word_to_number(self.core))
+            .or_else(|| self.canonical_roman().and_then(hindi::roman_word_to_number))
+    }
+
+    fn render(&self, word: &str) -> String {
+        format!("{}{}{}", self.prefix, word, self.suffix)
+    }
+}
+
+/// Normalize amounts, weights and durations to "<digits> <romanized unit>"
+///
+/// Number words in either script become ASCII digits when followed by a unit
+/// ("paanch lakh", "पांच लाख", "पांच lakh" -> "5 lakh"; "do sau pachaas
+/// gram" -> "250 gram"), units become their canonical romanized spelling
+/// ("5 लाख", "5 lac" -> "5 lakh") and Indic digits become ASCII. Everything
+/// else is left as spoken. Normalizing twice gives the same result.
+pub fn normalize(text: &str) -> String {
+    let tokens: Vec<Token> = text.split_whitespace().map(Token::parse).collect();
+    let mut out = Vec::with_capacity(tokens.len());
+
+    let mut i = 0;
+    while i < tokens.len() {
+        // Longest run of number words starting here
+        let mut value = 0.0;
+        let mut end = i;
+        while let Some(n) = tokens.get(end).and_then(Token::number_value) {
+            value = if n == 100.0 && value > 0.0 {
+                value * 100.0
+            } else {
+                value + n
+            };
+            end += 1;
+        }
+
+        if end > i && tokens.get(end).is_some_and(Token::is_unit) {
+            let digits = format!("{}", value);
+            out.push(format!(
+                "{}{}{}",
+                tokens[i].prefix,
+                digits,
+                tokens[end - 1].suffix
+            ));
+            i = end;
+            continue;
+        }
+
+        let token = &tokens[i];
+        if token.is_unit() {
+            out.push(token.render(token.canonical_roman().unwrap_or(token.core)));
+        } else {
+            out.push(token.render(&IntentDetector::indic_numerals_to_ascii(token.core)));
+        }
+        i += 1;
+    }
+
+    out.join(" ")
+}
+
+/// Transliterate known Devanagari words to their canonical romanized form
+///
+/// Words outside the lexicon are left unchanged.
+pub fn to_roman(text: &str) -> String {
+    text.split_whitespace()
+        .map(Token::parse)
+        .map(|token| match DEVANAGARI_TO_ROMAN.get(&token.key) {
+            Some(roman) => token.render(roman),
+            None => token.render(token.core),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Transliterate known romanized words (any listed spelling) to Devanagari
+///
+/// Words outside the lexicon are left unchanged.
+pub fn to_devanagari(text: &str) -> String {
+    text.split_whitespace()
+        .map(Token::parse)
+        .map(|token| match ROMAN_TO_DEVANAGARI.get(token.key.as_str()) {
+            Some(devanagari) => token.render(devanagari),
+            None => token.render(token.core),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation() || c == '।' || c == '॥'
+}
+
+/// Fold Devanagari spelling variants: precomposed nukta letters are
+/// decomposed and chandrabindu is treated as anusvara (पाँच == पांच)
+fn fold(word: &str) -> String {
+    let mut out = String::with_capacity(word.len());
+    for c in word.chars() {
+        match c {
+            '\u{0958}'..='\u{095F}' => {
+                let base = match c {
+                    '\u{0958}' => 'क',
+                    '\u{0959}' => 'ख',
+                    '\u{095A}' => 'ग',
+                    '\u{095B}' => 'ज',
+                    '\u{095C}' => 'ड',
+                    '\u{095D}' => 'ढ',
+                    '\u{095E}' => 'फ',
+                    _ => 'य',
+                };
+                out.push(base);
+                out.push('\u{093C}');
+            },
+            'ँ' => out.push('ं'),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_mixed_script_amounts() {
+        for (input, expected) in [
+            (
+                "mujhe paanch lakh ka loan chahiye",
+                "mujhe 5 lakh ka loan chahiye",
+            ),
+            ("मुझे पांच लाख का लोन चाहिए", "मुझे 5 lakh का लोन चाहिए"),
+            ("मुझे पाँच lakh चाहिए", "मुझे 5 lakh चाहिए"),
+            ("mujhe ५ लाख chahiye", "mujhe 5 lakh chahiye"),
+            ("dedh crore tak milega?", "1.5 crore tak milega?"),
+            ("das hazaar ka EMI", "10 hazar ka EMI"),
+            ("dhai lac", "2.5 lakh"),
+        ] {
+            assert_eq!(normalize(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_normalize_weights_and_durations() {
+        assert_eq!(normalize("do sau pachaas gram sona"), "250 gram sona");
+        assert_eq!(normalize("मेरे पास बीस ग्राम सोना है"), "मेरे पास 20 gram सोना है");
+        assert_eq!(normalize("do saal ke liye"), "2 saal ke liye");
+        assert_eq!(normalize("तीन महीने"), "3 mahine");
+    }
+
+    #[test]
+    fn test_normalize_leaves_plain_speech_alone() {
+        for text in [
+            "do you have a branch?",
+            "ek baat batao",
+            "What is the interest rate for 50 grams?",
+            "",
+        ] {
+            let expected = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            assert_eq!(
+                normalize(text),
+                expected.replace("grams", "gram"),
+                "{}",
+                text
+            );
+        }
+        // Idempotent
+        let once = normalize("मुझे पांच lakh चाहिए");
+        assert_eq!(normalize(&once), once);
+    }
+
+    #[test]
+    fn test_word_transliteration() {
+        assert_eq!(to_roman("मुझे गोल्ड लोन चाहिए।"), "mujhe gold loan chahiye।");
+        assert_eq!(to_roman("ब्याज दर कितनी है?"), "byaj dar kitni hai?");
+        assert_eq!(
+            to_devanagari("mujhey gold lone chaiye"),
+            "मुझे गोल्ड लोन चाहिए"
+        );
+        // Mixed script: each word converted on its own
+        assert_eq!(
+            to_roman("gold loan का rate क्या है"),
+            "gold loan ka rate kya hai"
+        );
+        assert_eq!(to_devanagari("gold loan का rate"), "गोल्ड लोन का रेट");
+    }
+}
@@ -0,0 +1,530 @@
+//! Appointment confirmation by two-way SMS
+//!
+//! `AppointmentConfirmations` stores a booked appointment and texts the
+//! customer its confirmation code, asking them to reply YES, NO or
+//! RESCHEDULE. Replies forwarded by the SMS provider are matched to the
+//! customer's open appointment (by code when quoted, else the latest one),
+//! the `AppointmentStatus` is updated, the customer gets an acknowledgement
+//! and the reply is noted in the metadata of the session that booked it.
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde::Serialize;
+use serde_json::json;
+use uuid::Uuid;
+use voice_agent_config::ToolsDomainView;
+use voice_agent_core::normalize_phone;
+use voice_agent_persistence::{
+    Appointment, AppointmentStatus, AppointmentStore, PersistenceError, SessionStore, SmsService,
+    SmsType,
+};
+
+/// Appointments looked at when matching a reply
+const REPLY_LOOKBACK: i32 = 20;
+
+/// What the customer asked for in their reply
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReplyAction {
+    Confirm,
+    Cancel,
+    Reschedule,
+}
+
+impl ReplyAction {
+    /// Status the appointment moves to
+    pub fn status(&self) -> AppointmentStatus {
+        match self {
+            Self::Confirm => AppointmentStatus::Confirmed,
+            Self::Cancel => AppointmentStatus::Cancelled,
+            Self::Reschedule => AppointmentStatus::RescheduleRequested,
+        }
+    }
+}
+
+/// Parse a reply such as "yes", "NO 1A2B3C4D" or "Reschedule please"
+///
+/// Returns the action and the confirmation code, if one was quoted.
+pub fn parse_reply(body: &str) -> Option<(ReplyAction, Option<String>)> {
+    let mut words = body
+        .split(|c: char| c.is_whitespace() || c == ',' || c == '.' || c == '!')
+        .filter(|w| !w.is_empty());
+    let action = match words.next()?.to_uppercase().as_str() {
+        "YES" | "Y" | "CONFIRM" | "HAAN" => ReplyAction::Confirm,
+        "NO" | "N" | "CANCEL" | "NAHI" => ReplyAction::Cancel,
+        "RESCHEDULE" | "CHANGE" => ReplyAction::Reschedule,
+        _ => return None,
+    };
+    let code = words
+        .map(|w| w.to_uppercase())
+        .find(|w| w.len() == 8 && w.chars().all(|c| c.is_ascii_hexdigit()));
+    Some((action, code))
+}
+
+/// Confirmation requested for a new booking
+#[derive(Debug, Clone)]
+pub struct ConfirmationRequest {
+    pub appointment_id: Uuid,
+    pub code: String,
+    /// Confirmation SMS, if it could be sent
+    pub sms_id: Option<Uuid>,
+}
+
+/// Result of handling an inbound reply
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum ReplyOutcome {
+    /// Appointment status changed
+    Updated {
+        appointment_id: Uuid,
+        action: ReplyAction,
+        status: AppointmentStatus,
+        session_id: Option<String>,
+    },
+    /// No open appointment for this number (or code)
+    NoAppointment,
+    /// Not a YES/NO/RESCHEDULE reply
+    Unrecognized,
+}
+
+/// Sends confirmation requests and applies the customer's replies
+pub struct AppointmentConfirmations {
+    appointments: Arc<dyn AppointmentStore>,
+    sms: Arc<dyn SmsService>,
+    sessions: Option<Arc<dyn SessionStore>>,
+    view: Option<Arc<ToolsDomainView>>,
+}
+
+impl AppointmentConfirmations {
+    pub fn new(appointments: Arc<dyn AppointmentStore>, sms: Arc<dyn SmsService>) -> Self {
+        Self {
+            appointments,
+            sms,
+            sessions: None,
+            view: None,
+        }
+    }
+
+    /// Note replies on the session that booked the appointment
+    pub fn with_session_store(mut self, sessions: Arc<dyn SessionStore>) -> Self {
+        self.sessions = Some(sessions);
+        self
+    }
+
+    /// Use the domain's brand and SMS templates
+    pub fn with_view(mut self, view: Arc<ToolsDomainView>) -> Self {
+        self.view = Some(view);
+        self
+    }
+
+    /// Store a booked appointment and text the customer its confirmation code
+    ///
+    /// The appointment is kept even if the SMS cannot be sent (e.g. the
+    /// customer's daily SMS limit is reached); `sms_id` is then `None`.
+    pub async fn request(
+        &self,
+        mut appointment: Appointment,
+    ) -> Result<ConfirmationRequest, PersistenceError> {
+        if let Some(phone) = normalize_phone(&appointment.customer_phone) {
+            appointment.customer_phone = phone;
+        }
+        self.appointments.create(&appointment).await?;
+
+        let code = appointment.confirmation_code();
+        let message = self.request_message(&appointment, &code);
+        let sms_id = match self
+            .sms
+            .send_sms(
+                &appointment.customer_phone,
+                &message,
+                SmsType::AppointmentConfirmation,
+                appointment.session_id.as_deref(),
+            )
+            .await
+        {
+            Ok(sent) => Some(sent.message_id),
+            Err(e) => {
+                tracing::warn!(
+                    appointment_id = %appointment.appointment_id,
+                    error = %e,
+                    "Confirmation SMS not sent"
+                );
+                None
+            },
+        };
+        if let Some(sms_id) = sms_id {
+            self.appointments
+                .set_confirmation_sms(
+                    &appointment.customer_phone,
+                    appointment.appointment_id,
+                    sms_id,
+                )
+                .await?;
+        }
+
+        Ok(ConfirmationRequest {
+            appointment_id: appointment.appointment_id,
+            code,
+            sms_id,
+        })
+    }
+
+    /// Apply an inbound SMS from `from` to the customer's open appointment
+    pub async fn handle_reply(
+        &self,
+        from: &str,
+        body: &str,
+    ) -> Result<ReplyOutcome, PersistenceError> {
+        let Some((action, code)) = parse_reply(body) else {
+            return Ok(ReplyOutcome::Unrecognized);
+        };
+        let phone = normalize_phone(from).unwrap_or_else(|| from.trim().to_string());
+
+        let today = Utc::now().date_naive();
+        let mut open: Vec<Appointment> = self
+            .appointments
+            .list_for_customer(&phone, REPLY_LOOKBACK)
+            .await?
+            .into_iter()
+            .filter(|a| a.is_open(today))
+            .collect();
+        open.sort_by_key(|a| Reverse(a.created_at));
+        let appointment = match code {
+            Some(ref code) => open.into_iter().find(|a| &a.confirmation_code() == code),
+            None => open.into_iter().next(),
+        };
+        let Some(appointment) = appointment else {
+            return Ok(ReplyOutcome::NoAppointment);
+        };
+
+        let status = action.status();
+        self.appointments
+            .update_status(&phone, appointment.appointment_id, status)
+            .await?;
+        tracing::info!(
+            appointment_id = %appointment.appointment_id,
+            status = status.as_str(),
+            "Appointment updated from SMS reply"
+        );
+
+        let ack = self.ack_message(&appointment, action);
+        if let Err(e) = self
+            .sms
+            .send_sms(
+                &phone,
+                &ack,
+                SmsType::AppointmentConfirmation,
+                appointment.session_id.as_deref(),
+            )
+            .await
+        {
+            tracing::warn!(error = %e, "Reply acknowledgement SMS not sent");
+        }
+
+        if let Some(ref session_id) = appointment.session_id {
+            self.note_on_session(session_id, &appointment, action, body)
+                .await;
+        }
+
+        Ok(ReplyOutcome::Updated {
+            appointment_id: appointment.appointment_id,
+            action,
+            status,
+            session_id: appointment.session_id,
+        })
+    }
+
+    /// Record the reply under `appointment_reply` in the session's metadata
+    async fn note_on_session(
+        &self,
+        session_id: &str,
+        appointment: &Appointment,
+        action: ReplyAction,
+        body: &str,
+    ) {
+        let Some(ref sessions) = self.sessions else {
+            return;
+        };
+        let mut session = match sessions.get(session_id).await {
+            Ok(Some(session)) => session,
+            Ok(None) => return,
+            Err(e) => {
+                tracing::warn!(session_id, error = %e, "Session not updated with SMS reply");
+                return;
+            },
+        };
+
+        let mut metadata = session
+            .metadata_json
+            .as_deref()
+            .and_then(|json| serde_json::from_str::<serde_json::Value>(json).ok())
+            .filter(|v| v.is_object())
+            .unwrap_or_else(|| json!({}));
+        metadata["appointment_reply"] = json!({
+            "appointment_id": appointment.appointment_id,
+            "action": action,
+            "status": action.status(),
+            "reply": body.trim(),
+            "received_at": Utc::now(),
+        });
+        session.metadata_json = Some(metadata.to_string());
+
+        if let Err(e) = sessions.update(&session).await {
+            tracing::warn!(session_id, error = %e, "Session not updated with SMS reply");
+        }
+    }
+
+    fn company(&self) -> String {
+        self.view
+            .as_ref()
+            .map(|v| v.company_name().to_string())
+            .unwrap_or_else(|| "Service Provider".to_string())
+    }
+
+    /// Placeholders for the `appointment_confirmation_request` template
+    fn placeholders(&self, appointment: &Appointment, code: &str) -> HashMap<String, String> {
+        let mut placeholders = HashMap::new();
+        placeholders.insert(
+            "customer_name".to_string(),
+            appointment.customer_name.clone().unwrap_or_default(),
+        );
+        placeholders.insert("branch".to_string(), appointment.branch_name.clone());
+        placeholders.insert(
+            "date".to_string(),
+            appointment.appointment_date.format("%d %b %Y").to_string(),
+        );
+        placeholders.insert("time".to_string(), appointment.appointment_time.clone());
+        placeholders.insert("code".to_string(), code.to_string());
+        placeholders.insert("brand.company_name".to_string(), self.company());
+        placeholders
+    }
+
+    fn request_message(&self, appointment: &Appointment, code: &str) -> String {
+        if let Some(ref view) = self.view {
+            let placeholders = self.placeholders(appointment, code);
+            if let Some(message) =
+                view.build_sms_message("appointment_confirmation_request", "en", &placeholders)
+            {
+                return message;
+            }
+        }
+
+        format!(
+            "Your visit to {} on {} at {} is booked. Reply YES {code} to confirm, NO {code} to \
+             cancel or RESCHEDULE {code} to change it. - {}",
+            appointment.branch_name,
+            appointment.appointment_date.format("%d %b %Y"),
+            appointment.appointment_time,
+            self.company(),
+            code = code,
+        )
+    }
+
+    fn ack_message(&self, appointment: &Appointment, action: ReplyAction) -> String {
+        let visit = format!(
+            "your visit to {} on {} at {}",
+            appointment.branch_name,
+            appointment.appointment_date.format("%d %b %Y"),
+            appointment.appointment_time
+        );
+        let text = match action {
+            ReplyAction::Confirm => format!("Thank you, {} is confirmed.", visit),
+            ReplyAction::Cancel => format!("As requested, {} has been cancelled.", visit),
+            ReplyAction::Reschedule => {
+                format!("We will call you to find a new time for {}.", visit)
+            },
+        };
+        format!("{} - {}", text, self.company())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::{Duration, NaiveDate};
+    use parking_lot::Mutex;
+    use voice_agent_persistence::{SmsMessage, SmsResult, SmsStatus};
+
+    #[derive(Default)]
+    struct MemoryAppointments {
+        appointments: Mutex<Vec<Appointment>>,
+    }
+
+    #[async_trait]
+    impl AppointmentStore for MemoryAppointments {
+        async fn create(&self, appointment: &Appointment) -> Result<(), PersistenceError> {
+            self.appointments.lock().push(appointment.clone());
+            Ok(())
+        }
+
+        async fn get(
+            &self,
+            phone: &str,
+            appointment_id: Uuid,
+        ) -> Result<Option<Appointment>, PersistenceError> {
+            Ok(self
+                .appointments
+                .lock()
+                .iter()
+                .find(|a| a.customer_phone == phone && a.appointment_id == appointment_id)
+                .cloned())
+        }
+
+        async fn update_status(
+            &self,
+            _phone: &str,
+            appointment_id: Uuid,
+            status: AppointmentStatus,
+        ) -> Result<(), PersistenceError> {
+            for a in self.appointments.lock().iter_mut() {
+                if a.appointment_id == appointment_id {
+                    a.status = status;
+                }
+            }
+            Ok(())
+        }
+
+        async fn set_confirmation_sms(
+            &self,
+            _phone: &str,
+            appointment_id: Uuid,
+            sms_id: Uuid,
+        ) -> Result<(), PersistenceError> {
+            for a in self.appointments.lock().iter_mut() {
+                if a.appointment_id == appointment_id {
+                    a.confirmation_sms_id = Some(sms_id);
+                }
+            }
+            Ok(())
+        }
+
+        async fn list_for_customer(
+            &self,
+            phone: &str,
+            _limit: i32,
+        ) -> Result<Vec<Appointment>, PersistenceError> {
+            Ok(self
+                .appointments
+                .lock()
+                .iter()
+                .filter(|a| a.customer_phone == phone)
+                .cloned()
+                .collect())
+        }
+
+        async fn list_for_date(
+            &self,
+            _date: NaiveDate,
+        ) -> Result<Vec<Appointment>, PersistenceError> {
+            Ok(Vec::new())
+        }
+    }
+
+    /// Records message bodies
+    #[derive(Default)]
+    struct RecordingSms {
+        sent: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl SmsService for RecordingSms {
+        async fn send_sms(
+            &self,
+            _phone: &str,
+            message: &str,
+            _msg_type: SmsType,
+            _session_id: Option<&str>,
+        ) -> Result<SmsResult, PersistenceError> {
+            self.sent.lock().push(message.to_string());
+            Ok(SmsResult {
+                message_id: Uuid::new_v4(),
+                status: SmsStatus::SimulatedSent,
+                sent_at: Utc::now(),
+                simulated: true,
+            })
+        }
+
+        async fn get_messages_for_phone(
+            &self,
+            _phone: &str,
+            _limit: i32,
+        ) -> Result<Vec<SmsMessage>, PersistenceError> {
+            Ok(Vec::new())
+        }
+
+        async fn get_message(
+            &self,
+            _phone: &str,
+            _message_id: Uuid,
+        ) -> Result<Option<SmsMessage>, PersistenceError> {
+            Ok(None)
+        }
+    }
+
+    #[test]
+    fn test_parse_reply() {
+        assert_eq!(parse_reply(" yes "), Some((ReplyAction::Confirm, None)));
+        assert_eq!(
+            parse_reply("No, 1a2b3c4d"),
+            Some((ReplyAction::Cancel, Some("1A2B3C4D".to_string())))
+        );
+        assert_eq!(
+            parse_reply("RESCHEDULE please"),
+            Some((ReplyAction::Reschedule, None))
+        );
+        assert_eq!(parse_reply("who is this?"), None);
+        assert_eq!(parse_reply(""), None);
+    }
+
+    #[tokio::test]
+    async fn test_request_and_reply_round_trip() {
+        let store = Arc::new(MemoryAppointments::default());
+        let sms = Arc::new(RecordingSms::default());
+        let confirmations = AppointmentConfirmations::new(store.clone(), sms.clone());
+
+        let date = Utc::now().date_naive() + Duration::days(2);
+        let mut appointment = Appointment::new(
+            "+91 98765 43210",
+            "b1",
+            "Andheri",
+            "Link Road",
+            date,
+            "11:00",
+        );
+        appointment.session_id = Some("s1".to_string());
+        let request = confirmations.request(appointment).await.unwrap();
+        assert!(request.sms_id.is_some());
+        assert!(sms.sent.lock()[0].contains(&format!("YES {}", request.code)));
+
+        // Reply from the provider's E.164 form of the number
+        let outcome = confirmations
+            .handle_reply("+919876543210", &format!("yes {}", request.code))
+            .await
+            .unwrap();
+        assert!(matches!(
+            outcome,
+            ReplyOutcome::Updated {
+                status: AppointmentStatus::Confirmed,
+                ..
+            }
+        ));
+        let stored = store
+            .get("9876543210", request.appointment_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.status, AppointmentStatus::Confirmed);
+        assert_eq!(stored.confirmation_sms_id, request.sms_id);
+
+        // A wrong code matches nothing
+        let outcome = confirmations
+            .handle_reply("9876543210", "NO 00000000")
+            .await
+            .unwrap();
+        assert_eq!(outcome, ReplyOutcome::NoAppointment);
+    }
+}
@@ -0,0 +1,144 @@
+//! Config-driven tool overrides
+//!
+//! Compiled-in tools ship with a default schema. When the domain's
+//! `tools/schemas.yaml` has an entry for a tool, its description, parameters
+//! and timeout replace the defaults in what the LLM sees. Parameters the
+//! implementation requires stay required so an override cannot produce calls
+//! the tool will reject.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use voice_agent_config::ToolSchema as ConfigToolSchema;
+
+use crate::mcp::{Tool, ToolError, ToolOutput, ToolSchema};
+
+/// Compiled-in tool with its schema overlaid from domain config
+pub struct ConfiguredTool {
+    inner: Arc<dyn Tool>,
+    schema: ToolSchema,
+    timeout_secs: u64,
+}
+
+impl ConfiguredTool {
+    pub fn new(inner: Arc<dyn Tool>, config: &ConfigToolSchema) -> Self {
+        let base = inner.schema();
+        let configured = config.to_core_schema();
+
+        let mut input_schema = base.input_schema;
+        input_schema
+            .properties
+            .extend(configured.input_schema.properties);
+        for name in configured.input_schema.required {
+            if !input_schema.required.contains(&name) {
+                input_schema.required.push(name);
+            }
+        }
+
+        let description = if configured.description.trim().is_empty() {
+            base.description
+        } else {
+            configured.description
+        };
+        let timeout_secs = config
+            .metadata
+            .as_ref()
+            .map(|m| m.timeout_secs)
+            .unwrap_or_else(|| inner.timeout_secs());
+
+        Self {
+            schema: ToolSchema {
+                name: base.name,
+                description,
+                input_schema,
+            },
+            timeout_secs,
+            inner,
+        }
+    }
+}
+
+#[async_trait]
+impl Tool for ConfiguredTool {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn description(&self) -> &str {
+        &self.schema.description
+    }
+
+    fn schema(&self) -> ToolSchema {
+        self.schema.clone()
+    }
+
+    async fn execute(&self, input: Value) -> Result<ToolOutput, ToolError> {
+        self.inner.execute(input).await
+    }
+
+    fn timeout_secs(&self) -> u64 {
+        self.timeout_secs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mcp::{InputSchema, PropertySchema};
+    use serde_json::json;
+
+    struct FixedTool;
+
+    #[async_trait]
+    impl Tool for FixedTool {
+        fn name(&self) -> &str {
+            "find_locations"
+        }
+
+        fn description(&self) -> &str {
+            "Built-in description"
+        }
+
+        fn schema(&self) -> ToolSchema {
+            ToolSchema {
+                name: self.name().to_string(),
+                description: self.description().to_string(),
+                input_schema: InputSchema::object()
+                    .property("city", PropertySchema::string("City"), true)
+                    .property("pincode", PropertySchema::string("Pincode"), false),
+            }
+        }
+
+        async fn execute(&self, _input: Value) -> Result<ToolOutput, ToolError> {
+            Ok(ToolOutput::text("ok"))
+        }
+    }
+
+    #[test]
+    fn test_config_overlays_schema() {
+        let config: ConfigToolSchema = serde_json::from_value(json!({
+            "name": "find_locations",
+            "description": "Find the nearest branch",
+            "metadata": { "timeout_secs": 5 },
+            "parameters": [
+                { "name": "city", "type": "string", "description": "City", "required": false },
+                { "name": "area", "type": "string", "description": "Locality", "required": true }
+            ]
+        }))
+        .unwrap();
+
+        let tool = ConfiguredTool::new(Arc::new(FixedTool), &config);
+        let schema = tool.schema();
+
+        assert_eq!(tool.name(), "find_locations");
+        assert_eq!(schema.description, "Find the nearest branch");
+        assert_eq!(tool.timeout_secs(), 5);
+        assert_eq!(schema.input_schema.properties.len(), 3);
+        // Still required by the implementation
+        assert!(schema.input_schema.required.contains(&"city".to_string()));
+        assert!(schema.input_schema.required.contains(&"area".to_string()));
+        assert!(tool.validate(&json!({ "city": "Pune" })).is_err());
+    }
+}
@@ -0,0 +1,323 @@
+//! HubSpot connector
+//!
+//! Creates and updates contacts via the HubSpot CRM v3 API using a private
+//! app access token. Idempotent creates use batch upsert keyed on the
+//! `voice_agent_key` contact property; the portal must define the custom
+//! properties `voice_agent_key` (unique), `voice_agent_lead_score` and
+//! `voice_agent_qualification`.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde_json::{json, Map, Value};
+
+use super::{check_response, http_client, split_name, transport_error};
+use crate::integrations::{
+    CrmIntegration, CrmLead, IntegrationError, InterestLevel, LeadSource, LeadStatus,
+};
+
+const DEFAULT_BASE_URL: &str = "https://api.hubapi.com";
+const IDEMPOTENCY_PROPERTY: &str = "voice_agent_key";
+/// HubSpot-defined association type: note -> contact
+const NOTE_TO_CONTACT_ASSOCIATION: u32 = 202;
+const CONTACT_PROPERTIES: &str =
+    "firstname,lastname,mobilephone,phone,email,city,hs_lead_status,hubspot_owner_id";
+
+/// HubSpot CRM v3 connector
+pub struct HubSpotCrm {
+    client: reqwest::Client,
+    base_url: String,
+    access_token: String,
+}
+
+impl HubSpotCrm {
+    pub fn new(access_token: &str, timeout: Duration) -> Result<Self, IntegrationError> {
+        if access_token.trim().is_empty() {
+            return Err(IntegrationError::AuthFailed(
+                "HubSpot access token is required".to_string(),
+            ));
+        }
+        Ok(Self {
+            client: http_client(timeout)?,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            access_token: access_token.to_string(),
+        })
+    }
+
+    /// Override the API base URL (sandboxes, proxies)
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/crm/v3/{}", self.base_url, path)
+    }
+
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<Value, IntegrationError> {
+        let response = request
+            .bearer_auth(&self.access_token)
+            .send()
+            .await
+            .map_err(transport_error)?;
+        let response = check_response(response).await?;
+        if response.status() == reqwest::StatusCode::NO_CONTENT {
+            return Ok(Value::Null);
+        }
+        response
+            .json()
+            .await
+            .map_err(|e| IntegrationError::Internal(e.to_string()))
+    }
+
+    async fn patch_contact(&self, id: &str, properties: Value) -> Result<(), IntegrationError> {
+        self.send(
+            self.client
+                .patch(self.url(&format!("objects/contacts/{}", id)))
+                .json(&json!({ "properties": properties })),
+        )
+        .await?;
+        Ok(())
+    }
+
+    fn properties(lead: &CrmLead) -> Value {
+        let (first_name, last_name) = split_name(&lead.name);
+        let mut props = Map::new();
+        props.insert("lastname".into(), json!(last_name));
+        if let Some(first) = first_name {
+            props.insert("firstname".into(), json!(first));
+        }
+        props.insert("mobilephone".into(), json!(lead.phone));
+        props.insert("lifecyclestage".into(), json!("lead"));
+        props.insert(
+            "hs_lead_status".into(),
+            json!(Self::lead_status(lead.status)),
+        );
+        props.insert(
+            "hs_analytics_source".into(),
+            json!(Self::source_label(lead.source)),
+        );
+        if let Some(email) = &lead.email {
+            props.insert("email".into(), json!(email));
+        }
+        if let Some(city) = &lead.city {
+            props.insert("city".into(), json!(city));
+        }
+        if let Some(owner) = &lead.assigned_to {
+            props.insert("hubspot_owner_id".into(), json!(owner));
+        }
+        if let Some(score) = lead.lead_score {
+            props.insert("voice_agent_lead_score".into(), json!(score));
+        }
+        if let Some(qualification) = &lead.lead_qualification {
+            props.insert("voice_agent_qualification".into(), json!(qualification));
+        }
+        Value::Object(props)
+    }
+
+    fn source_label(source: LeadSource) -> &'static str {
+        match source {
+            LeadSource::VoiceAgent => "OFFLINE",
+            LeadSource::Website => "DIRECT_TRAFFIC",
+            LeadSource::Branch => "OFFLINE",
+            LeadSource::Referral => "REFERRALS",
+            LeadSource::Campaign => "PAID_SEARCH",
+        }
+    }
+
+    fn lead_status(status: LeadStatus) -> &'static str {
+        match status {
+            LeadStatus::New => "NEW",
+            LeadStatus::Contacted => "CONNECTED",
+            LeadStatus::Qualified | LeadStatus::Proposal | LeadStatus::Negotiation => "IN_PROGRESS",
+            LeadStatus::Won => "OPEN_DEAL",
+            LeadStatus::Lost => "UNQUALIFIED",
+        }
+    }
+
+    fn parse_contact(record: &Value) -> CrmLead {
+        let props = record.get("properties").cloned().unwrap_or(Value::Null);
+        let text = |field: &str| {
+            props
+                .get(field)
+                .and_then(|v| v.as_str())
+                .filter(|s| !s.is_empty())
+                .map(String::from)
+        };
+        let name = [text("firstname"), text("lastname")]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ");
+        let status = match text("hs_lead_status").as_deref() {
+            Some("CONNECTED") => LeadStatus::Contacted,
+            Some("IN_PROGRESS") => LeadStatus::Qualified,
+            Some("OPEN_DEAL") => LeadStatus::Won,
+            Some("UNQUALIFIED") => LeadStatus::Lost,
+            _ => LeadStatus::New,
+        };
+        CrmLead {
+            id: record.get("id").and_then(|v| v.as_str()).map(String::from),
+            name,
+            phone: text("mobilephone")
+                .or_else(|| text("phone"))
+                .unwrap_or_default(),
+            email: text("email"),
+            city: text("city"),
+            source: LeadSource::VoiceAgent,
+            interest_level: InterestLevel::Medium,
+            estimated_asset_value: None,
+            current_provider: None,
+            notes: None,
+            assigned_to: text("hubspot_owner_id"),
+            status,
+            lead_score: None,
+            lead_qualification: None,
+        }
+    }
+
+    fn result_id(body: &Value) -> Result<String, IntegrationError> {
+        body.get("id")
+            .or_else(|| body.pointer("/results/0/id"))
+            .and_then(|v| v.as_str())
+            .map(String::from)
+            .ok_or_else(|| IntegrationError::Internal("HubSpot response missing id".into()))
+    }
+}
+
+#[async_trait]
+impl CrmIntegration for HubSpotCrm {
+    async fn create_lead(&self, lead: CrmLead) -> Result<String, IntegrationError> {
+        let body = self
+            .send(
+                self.client
+                    .post(self.url("objects/contacts"))
+                    .json(&json!({ "properties": Self::properties(&lead) })),
+            )
+            .await?;
+        let id = Self::result_id(&body)?;
+        tracing::info!(contact_id = %id, "HubSpot: Created contact");
+        Ok(id)
+    }
+
+    async fn create_lead_idempotent(
+        &self,
+        lead: CrmLead,
+        idempotency_key: &str,
+    ) -> Result<String, IntegrationError> {
+        let mut properties = Self::properties(&lead);
+        properties[IDEMPOTENCY_PROPERTY] = json!(idempotency_key);
+        let body = self
+            .send(
+                self.client
+                    .post(self.url("objects/contacts/batch/upsert"))
+                    .json(&json!({
+                        "inputs": [{
+                            "idProperty": IDEMPOTENCY_PROPERTY,
+                            "id": idempotency_key,
+                            "properties": properties,
+                        }]
+                    })),
+            )
+            .await?;
+        let id = Self::result_id(&body)?;
+        tracing::info!(contact_id = %id, "HubSpot: Upserted contact");
+        Ok(id)
+    }
+
+    async fn update_lead(&self, id: &str, lead: CrmLead) -> Result<(), IntegrationError> {
+        self.patch_contact(id, Self::properties(&lead)).await
+    }
+
+    async fn get_lead(&self, id: &str) -> Result<CrmLead, IntegrationError> {
+        let record = self
+            .send(
+                self.client
+                    .get(self.url(&format!("objects/contacts/{}", id)))
+                    .query(&[("properties", CONTACT_PROPERTIES)]),
+            )
+            .await?;
+        Ok(Self::parse_contact(&record))
+    }
+
+    async fn find_by_phone(&self, phone: &str) -> Result<Vec<CrmLead>, IntegrationError> {
+        let properties: Vec<&str> = CONTACT_PROPERTIES.split(',').collect();
+        let body = self
+            .send(
+                self.client
+                    .post(self.url("objects/contacts/search"))
+                    .json(&json!({
+                        "filterGroups": [
+                            { "filters": [{ "propertyName": "mobilephone", "operator": "EQ", "value": phone }] },
+                            { "filters": [{ "propertyName": "phone", "operator": "EQ", "value": phone }] }
+                        ],
+                        "properties": properties,
+                    })),
+            )
+            .await?;
+        Ok(body
+            .get("results")
+            .and_then(|v| v.as_array())
+            .map(|results| results.iter().map(Self::parse_contact).collect())
+            .unwrap_or_default())
+    }
+
+    async fn assign_lead(&self, lead_id: &str, rep_id: &str) -> Result<(), IntegrationError> {
+        self.patch_contact(lead_id, json!({ "hubspot_owner_id": rep_id }))
+            .await
+    }
+
+    async fn add_note(&self, lead_id: &str, note: &str) -> Result<(), IntegrationError> {
+        self.send(self.client.post(self.url("objects/notes")).json(&json!({
+            "properties": {
+                "hs_note_body": note,
+                "hs_timestamp": chrono::Utc::now().to_rfc3339(),
+            },
+            "associations": [{
+                "to": { "id": lead_id },
+                "types": [{
+                    "associationCategory": "HUBSPOT_DEFINED",
+                    "associationTypeId": NOTE_TO_CONTACT_ASSOCIATION,
+                }]
+            }]
+        })))
+        .await?;
+        Ok(())
+    }
+
+    async fn update_status(
+        &self,
+        lead_id: &str,
+        status: LeadStatus,
+    ) -> Result<(), IntegrationError> {
+        self.patch_contact(
+            lead_id,
+            json!({ "hs_lead_status": Self::lead_status(status) }),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crm::tests::sample_lead;
+
+    #[test]
+    fn test_properties_and_parse_roundtrip() {
+        let props = HubSpotCrm::properties(&sample_lead("9876543210"));
+        assert_eq!(props["lastname"], "Sharma");
+        assert_eq!(props["voice_agent_lead_score"], 65);
+
+        let record = json!({ "id": "101", "properties": props });
+        let lead = HubSpotCrm::parse_contact(&record);
+        assert_eq!(lead.id.as_deref(), Some("101"));
+        assert_eq!(lead.name, "Rahul Kumar Sharma");
+        assert_eq!(lead.phone, "9876543210");
+    }
+
+    #[test]
+    fn test_missing_token_rejected() {
+        assert!(HubSpotCrm::new("", Duration::from_secs(5)).is_err());
+    }
+}
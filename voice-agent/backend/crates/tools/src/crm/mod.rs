@@ -0,0 +1,213 @@
+//! CRM Connectors
+//!
+//! Delivers captured leads to external CRM systems. Each connector implements
+//! `CrmIntegration`; `CrmDeliveryQueue` wraps any connector with an async
+//! delivery queue, exponential backoff retry, idempotency keys and
+//! delivery-status tracking (persisted via `CrmDeliveryStore`).
+//!
+//! ```ignore
+//! let connector = crm::connector_from_config(&settings.crm)?;
+//! if let Some(connector) = connector {
+//!     let store: Arc<dyn CrmDeliveryStore> = persistence.crm_deliveries.clone();
+//!     let queue = CrmDeliveryQueue::spawn(
+//!         connector,
+//!         "webhook",
+//!         RetryPolicy::from_config(&settings.crm),
+//!         settings.crm.queue_capacity,
+//!         Some(store),
+//!     );
+//!     integrations = integrations.with_crm(Arc::new(queue));
+//! }
+//! ```
+
+mod hubspot;
+mod queue;
+mod salesforce;
+mod webhook;
+
+pub use hubspot::HubSpotCrm;
+pub use queue::{CrmDeliveryQueue, RetryPolicy};
+pub use salesforce::SalesforceCrm;
+pub use webhook::WebhookCrm;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use voice_agent_config::{CrmConfig, CrmConnectorKind};
+
+use crate::integrations::{CrmIntegration, CrmLead, IntegrationError, LeadStatus};
+
+/// Build the configured connector, or `None` when no CRM is configured
+pub fn connector_from_config(
+    config: &CrmConfig,
+) -> Result<Option<Arc<dyn CrmIntegration>>, IntegrationError> {
+    let timeout = Duration::from_secs(config.timeout_secs);
+    let api_key = config.api_key.clone().unwrap_or_default();
+    let endpoint = config.endpoint.clone().unwrap_or_default();
+
+    let connector: Arc<dyn CrmIntegration> = match config.connector {
+        CrmConnectorKind::None => return Ok(None),
+        CrmConnectorKind::Salesforce => Arc::new(SalesforceCrm::new(&endpoint, &api_key, timeout)?),
+        CrmConnectorKind::Hubspot => {
+            let hubspot = HubSpotCrm::new(&api_key, timeout)?;
+            if endpoint.is_empty() {
+                Arc::new(hubspot)
+            } else {
+                Arc::new(hubspot.with_base_url(&endpoint))
+            }
+        },
+        CrmConnectorKind::Webhook => {
+            let token = config.api_key.as_deref();
+            Arc::new(WebhookCrm::new(&endpoint, token, timeout)?)
+        },
+    };
+
+    Ok(Some(connector))
+}
+
+/// Connector name used for logging and delivery records
+pub fn connector_name(kind: CrmConnectorKind) -> &'static str {
+    match kind {
+        CrmConnectorKind::None => "none",
+        CrmConnectorKind::Salesforce => "salesforce",
+        CrmConnectorKind::Hubspot => "hubspot",
+        CrmConnectorKind::Webhook => "webhook",
+    }
+}
+
+/// Idempotency key for a lead
+///
+/// One lead per customer phone per day: repeated captures in the same call
+/// (or a callback later that day) update the same CRM record instead of
+/// creating duplicates. An explicit lead ID always wins.
+pub fn idempotency_key(lead: &CrmLead) -> String {
+    if let Some(id) = lead.id.as_deref().filter(|id| !id.is_empty()) {
+        return id.to_string();
+    }
+    let digits: String = lead.phone.chars().filter(|c| c.is_ascii_digit()).collect();
+    // Normalize +91 / 0 prefixed Indian numbers to the 10-digit form
+    let phone = if digits.len() > 10 {
+        &digits[digits.len() - 10..]
+    } else {
+        digits.as_str()
+    };
+    format!("lead-{}-{}", phone, Utc::now().format("%Y%m%d"))
+}
+
+/// Build a reqwest client with the connector timeout
+pub(crate) fn http_client(timeout: Duration) -> Result<reqwest::Client, IntegrationError> {
+    reqwest::Client::builder()
+        .timeout(timeout)
+        .build()
+        .map_err(|e| IntegrationError::Internal(e.to_string()))
+}
+
+/// Map a non-success HTTP response to an IntegrationError
+///
+/// 5xx, 408 and 429 are retryable; other 4xx are not.
+pub(crate) async fn check_response(
+    response: reqwest::Response,
+) -> Result<reqwest::Response, IntegrationError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let body = response.text().await.unwrap_or_default();
+    let message = format!("HTTP {}: {}", status, body);
+    Err(match status.as_u16() {
+        401 | 403 => IntegrationError::AuthFailed(message),
+        404 => IntegrationError::NotFound(message),
+        408 => IntegrationError::ConnectionFailed(message),
+        429 => IntegrationError::RateLimited,
+        400..=499 => IntegrationError::InvalidRequest(message),
+        _ => IntegrationError::Internal(message),
+    })
+}
+
+/// Map reqwest transport errors (timeouts, DNS, refused) to retryable errors
+pub(crate) fn transport_error(err: reqwest::Error) -> IntegrationError {
+    IntegrationError::ConnectionFailed(err.to_string())
+}
+
+/// Split a full name into (first, last); CRMs usually require a last name
+pub(crate) fn split_name(name: &str) -> (Option<String>, String) {
+    let name = name.trim();
+    match name.rsplit_once(' ') {
+        Some((first, last)) => (Some(first.trim().to_string()), last.to_string()),
+        None => (None, name.to_string()),
+    }
+}
+
+/// Status label shared by the CRM mappings
+pub(crate) fn status_label(status: LeadStatus) -> &'static str {
+    match status {
+        LeadStatus::New => "new",
+        LeadStatus::Contacted => "contacted",
+        LeadStatus::Qualified => "qualified",
+        LeadStatus::Proposal => "proposal",
+        LeadStatus::Negotiation => "negotiation",
+        LeadStatus::Won => "won",
+        LeadStatus::Lost => "lost",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::{InterestLevel, LeadSource};
+
+    pub(super) fn sample_lead(phone: &str) -> CrmLead {
+        CrmLead {
+            id: None,
+            name: "Rahul Kumar Sharma".to_string(),
+            phone: phone.to_string(),
+            email: None,
+            city: Some("Mumbai".to_string()),
+            source: LeadSource::VoiceAgent,
+            interest_level: InterestLevel::High,
+            estimated_asset_value: Some(50.0),
+            current_provider: None,
+            notes: None,
+            assigned_to: None,
+            status: LeadStatus::New,
+            lead_score: Some(65),
+            lead_qualification: Some("Hot".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_idempotency_key_normalizes_phone() {
+        let a = idempotency_key(&sample_lead("9876543210"));
+        let b = idempotency_key(&sample_lead("+91 98765 43210"));
+        assert_eq!(a, b);
+        assert!(a.starts_with("lead-9876543210-"));
+
+        let mut lead = sample_lead("9876543210");
+        lead.id = Some("LEAD-ABC".to_string());
+        assert_eq!(idempotency_key(&lead), "LEAD-ABC");
+    }
+
+    #[test]
+    fn test_split_name() {
+        assert_eq!(
+            split_name("Rahul Kumar Sharma"),
+            (Some("Rahul Kumar".to_string()), "Sharma".to_string())
+        );
+        assert_eq!(split_name("Rahul"), (None, "Rahul".to_string()));
+    }
+
+    #[test]
+    fn test_connector_from_config() {
+        let config = CrmConfig::default();
+        assert!(connector_from_config(&config).unwrap().is_none());
+
+        let config = CrmConfig {
+            connector: CrmConnectorKind::Webhook,
+            endpoint: Some("https://crm.example.com/leads".to_string()),
+            ..CrmConfig::default()
+        };
+        assert!(connector_from_config(&config).unwrap().is_some());
+    }
+}
@@ -0,0 +1,479 @@
+//! Async CRM delivery queue
+//!
+//! Lead capture must not block the conversation on a slow or unavailable CRM.
+//! `CrmDeliveryQueue` accepts leads immediately, delivers them in the
+//! background with exponential backoff, deduplicates by idempotency key and
+//! records every status change (in memory and, when configured, in ScyllaDB).
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use parking_lot::RwLock;
+use tokio::sync::{mpsc, Semaphore};
+use voice_agent_config::CrmConfig;
+use voice_agent_persistence::{CrmDelivery, CrmDeliveryStatus, CrmDeliveryStore};
+
+use super::idempotency_key;
+use crate::integrations::{CrmIntegration, CrmLead, IntegrationError, LeadStatus};
+
+/// Deliveries in flight at once (each may be sleeping between retries)
+const MAX_CONCURRENT_DELIVERIES: usize = 4;
+
+/// Terminal deliveries are kept in memory this long for deduplication
+const STATUS_RETENTION_HOURS: i64 = 24;
+
+/// Retry policy with exponential backoff
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Total attempts including the first
+    pub max_attempts: u32,
+    /// Delay after the first failure
+    pub initial_backoff: Duration,
+    /// Upper bound on the delay
+    pub max_backoff: Duration,
+    /// Growth factor per attempt
+    pub multiplier: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn from_config(config: &CrmConfig) -> Self {
+        Self {
+            max_attempts: config.max_attempts.max(1),
+            initial_backoff: Duration::from_millis(config.initial_backoff_ms),
+            max_backoff: Duration::from_millis(config.max_backoff_ms),
+            ..Self::default()
+        }
+    }
+
+    /// Delay before retrying after the given (1-based) failed attempt
+    pub fn backoff_for(&self, attempt: u32) -> Duration {
+        let factor = self.multiplier.powi(attempt.saturating_sub(1) as i32);
+        let delay = self.initial_backoff.as_secs_f64() * factor;
+        Duration::from_secs_f64(delay.min(self.max_backoff.as_secs_f64()))
+    }
+}
+
+struct DeliveryJob {
+    key: String,
+    lead: CrmLead,
+}
+
+struct QueueInner {
+    connector: Arc<dyn CrmIntegration>,
+    connector_name: String,
+    policy: RetryPolicy,
+    store: Option<Arc<dyn CrmDeliveryStore>>,
+    deliveries: RwLock<HashMap<String, CrmDelivery>>,
+}
+
+impl QueueInner {
+    /// Record a status change in memory and (best effort) in the store
+    async fn record(&self, delivery: &CrmDelivery) {
+        self.deliveries
+            .write()
+            .insert(delivery.idempotency_key.clone(), delivery.clone());
+        if let Some(store) = &self.store {
+            if let Err(e) = store.upsert(delivery).await {
+                tracing::warn!(
+                    idempotency_key = %delivery.idempotency_key,
+                    error = %e,
+                    "Failed to persist CRM delivery status"
+                );
+            }
+        }
+    }
+
+    async fn deliver(&self, job: DeliveryJob) {
+        let mut delivery = match self.deliveries.read().get(&job.key) {
+            Some(delivery) => delivery.clone(),
+            None => return,
+        };
+
+        for attempt in 1..=self.policy.max_attempts {
+            delivery.attempts = attempt as i32;
+            delivery.updated_at = Utc::now();
+
+            match self
+                .connector
+                .create_lead_idempotent(job.lead.clone(), &job.key)
+                .await
+            {
+                Ok(external_id) => {
+                    delivery.status = CrmDeliveryStatus::Delivered;
+                    delivery.external_id = Some(external_id);
+                    delivery.last_error = None;
+                    self.record(&delivery).await;
+                    tracing::info!(
+                        idempotency_key = %job.key,
+                        connector = %self.connector_name,
+                        attempts = attempt,
+                        "CRM lead delivered"
+                    );
+                    return;
+                },
+                Err(e) if e.is_retryable() && attempt < self.policy.max_attempts => {
+                    let backoff = self.policy.backoff_for(attempt);
+                    delivery.status = CrmDeliveryStatus::Retrying;
+                    delivery.last_error = Some(e.to_string());
+                    self.record(&delivery).await;
+                    tracing::warn!(
+                        idempotency_key = %job.key,
+                        connector = %self.connector_name,
+                        attempt,
+                        backoff_ms = backoff.as_millis() as u64,
+                        error = %e,
+                        "CRM delivery failed, retrying"
+                    );
+                    tokio::time::sleep(backoff).await;
+                },
+                Err(e) => {
+                    delivery.status = CrmDeliveryStatus::Failed;
+                    delivery.last_error = Some(e.to_string());
+                    self.record(&delivery).await;
+                    tracing::error!(
+                        idempotency_key = %job.key,
+                        connector = %self.connector_name,
+                        attempts = attempt,
+                        error = %e,
+                        "CRM delivery failed permanently"
+                    );
+                    return;
+                },
+            }
+        }
+    }
+}
+
+/// Queued, retrying, idempotent CRM delivery
+///
+/// Implements `CrmIntegration` so it can be handed to `LeadCaptureTool`
+/// in place of the raw connector: `create_lead` enqueues and returns the
+/// idempotency key (or the CRM lead ID if already delivered). Other
+/// operations pass straight through to the connector.
+pub struct CrmDeliveryQueue {
+    inner: Arc<QueueInner>,
+    sender: mpsc::Sender<DeliveryJob>,
+}
+
+impl CrmDeliveryQueue {
+    /// Start the queue and its background worker
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn spawn(
+        connector: Arc<dyn CrmIntegration>,
+        connector_name: &str,
+        policy: RetryPolicy,
+        capacity: usize,
+        store: Option<Arc<dyn CrmDeliveryStore>>,
+    ) -> Self {
+        let inner = Arc::new(QueueInner {
+            connector,
+            connector_name: connector_name.to_string(),
+            policy,
+            store,
+            deliveries: RwLock::new(HashMap::new()),
+        });
+        let (sender, mut receiver) = mpsc::channel::<DeliveryJob>(capacity.max(1));
+
+        let worker = inner.clone();
+        tokio::spawn(async move {
+            let permits = Arc::new(Semaphore::new(MAX_CONCURRENT_DELIVERIES));
+            while let Some(job) = receiver.recv().await {
+                let permit = match permits.clone().acquire_owned().await {
+                    Ok(permit) => permit,
+                    Err(_) => break,
+                };
+                let worker = worker.clone();
+                tokio::spawn(async move {
+                    worker.deliver(job).await;
+                    drop(permit);
+                });
+            }
+            tracing::debug!("CRM delivery queue closed");
+        });
+
+        Self { inner, sender }
+    }
+
+    /// Queue a lead for delivery under its derived idempotency key
+    pub async fn enqueue(&self, lead: CrmLead) -> Result<String, IntegrationError> {
+        let key = idempotency_key(&lead);
+        self.enqueue_with_key(lead, &key).await
+    }
+
+    /// Queue a lead for delivery under an explicit idempotency key
+    ///
+    /// Returns the CRM lead ID if this key was already delivered, otherwise
+    /// the idempotency key. Duplicate keys that are pending or retrying are
+    /// not queued again.
+    pub async fn enqueue_with_key(
+        &self,
+        lead: CrmLead,
+        key: &str,
+    ) -> Result<String, IntegrationError> {
+        self.prune();
+
+        if let Some(existing) = self.delivery(key).await {
+            if existing.status != CrmDeliveryStatus::Failed {
+                tracing::debug!(
+                    idempotency_key = %key,
+                    status = existing.status.as_str(),
+                    "Duplicate CRM delivery skipped"
+                );
+                return Ok(existing.external_id.unwrap_or_else(|| key.to_string()));
+            }
+        }
+
+        let payload =
+            serde_json::to_string(&lead).map_err(|e| IntegrationError::Internal(e.to_string()))?;
+        let mut delivery = CrmDelivery::new(key, &self.inner.connector_name, &lead.phone, payload);
+        self.inner.record(&delivery).await;
+
+        let job = DeliveryJob {
+            key: key.to_string(),
+            lead,
+        };
+        if let Err(e) = self.sender.try_send(job) {
+            delivery.status = CrmDeliveryStatus::Failed;
+            delivery.last_error = Some(format!("Delivery queue unavailable: {}", e));
+            delivery.updated_at = Utc::now();
+            self.inner.record(&delivery).await;
+            return Err(IntegrationError::RateLimited);
+        }
+
+        Ok(key.to_string())
+    }
+
+    /// Current delivery status (memory first, then the store)
+    pub async fn delivery(&self, key: &str) -> Option<CrmDelivery> {
+        if let Some(delivery) = self.inner.deliveries.read().get(key).cloned() {
+            return Some(delivery);
+        }
+        let store = self.inner.store.as_ref()?;
+        match store.get(key).await {
+            Ok(found) => found,
+            Err(e) => {
+                tracing::warn!(idempotency_key = %key, error = %e, "Failed to load CRM delivery status");
+                None
+            },
+        }
+    }
+
+    /// In-memory status for a key, if known
+    pub fn status(&self, key: &str) -> Option<CrmDeliveryStatus> {
+        self.inner.deliveries.read().get(key).map(|d| d.status)
+    }
+
+    /// Drop terminal deliveries older than the retention window
+    fn prune(&self) {
+        let cutoff = Utc::now() - chrono::Duration::hours(STATUS_RETENTION_HOURS);
+        self.inner
+            .deliveries
+            .write()
+            .retain(|_, d| !d.status.is_terminal() || d.updated_at > cutoff);
+    }
+}
+
+#[async_trait]
+impl CrmIntegration for CrmDeliveryQueue {
+    async fn create_lead(&self, lead: CrmLead) -> Result<String, IntegrationError> {
+        self.enqueue(lead).await
+    }
+
+    async fn create_lead_idempotent(
+        &self,
+        lead: CrmLead,
+        idempotency_key: &str,
+    ) -> Result<String, IntegrationError> {
+        self.enqueue_with_key(lead, idempotency_key).await
+    }
+
+    async fn update_lead(&self, id: &str, lead: CrmLead) -> Result<(), IntegrationError> {
+        self.inner.connector.update_lead(id, lead).await
+    }
+
+    async fn get_lead(&self, id: &str) -> Result<CrmLead, IntegrationError> {
+        self.inner.connector.get_lead(id).await
+    }
+
+    async fn find_by_phone(&self, phone: &str) -> Result<Vec<CrmLead>, IntegrationError> {
+        self.inner.connector.find_by_phone(phone).await
+    }
+
+    async fn assign_lead(&self, lead_id: &str, rep_id: &str) -> Result<(), IntegrationError> {
+        self.inner.connector.assign_lead(lead_id, rep_id).await
+    }
+
+    async fn add_note(&self, lead_id: &str, note: &str) -> Result<(), IntegrationError> {
+        self.inner.connector.add_note(lead_id, note).await
+    }
+
+    async fn update_status(
+        &self,
+        lead_id: &str,
+        status: LeadStatus,
+    ) -> Result<(), IntegrationError> {
+        self.inner.connector.update_status(lead_id, status).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crm::tests::sample_lead;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use voice_agent_persistence::PersistenceError;
+
+    /// Fails the first `failures` calls with the given error kind
+    struct FlakyCrm {
+        calls: AtomicU32,
+        failures: u32,
+        retryable: bool,
+    }
+
+    impl FlakyCrm {
+        fn new(failures: u32, retryable: bool) -> Self {
+            Self {
+                calls: AtomicU32::new(0),
+                failures,
+                retryable,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl CrmIntegration for FlakyCrm {
+        async fn create_lead(&self, _lead: CrmLead) -> Result<String, IntegrationError> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            if call <= self.failures {
+                return Err(if self.retryable {
+                    IntegrationError::ConnectionFailed("timeout".into())
+                } else {
+                    IntegrationError::InvalidRequest("bad lead".into())
+                });
+            }
+            Ok("CRM-1".to_string())
+        }
+        async fn update_lead(&self, _: &str, _: CrmLead) -> Result<(), IntegrationError> {
+            Ok(())
+        }
+        async fn get_lead(&self, id: &str) -> Result<CrmLead, IntegrationError> {
+            Err(IntegrationError::NotFound(id.to_string()))
+        }
+        async fn find_by_phone(&self, _: &str) -> Result<Vec<CrmLead>, IntegrationError> {
+            Ok(vec![])
+        }
+        async fn assign_lead(&self, _: &str, _: &str) -> Result<(), IntegrationError> {
+            Ok(())
+        }
+        async fn add_note(&self, _: &str, _: &str) -> Result<(), IntegrationError> {
+            Ok(())
+        }
+        async fn update_status(&self, _: &str, _: LeadStatus) -> Result<(), IntegrationError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct MemoryStore {
+        records: RwLock<HashMap<String, CrmDelivery>>,
+    }
+
+    #[async_trait]
+    impl CrmDeliveryStore for MemoryStore {
+        async fn upsert(&self, delivery: &CrmDelivery) -> Result<(), PersistenceError> {
+            self.records
+                .write()
+                .insert(delivery.idempotency_key.clone(), delivery.clone());
+            Ok(())
+        }
+        async fn get(&self, key: &str) -> Result<Option<CrmDelivery>, PersistenceError> {
+            Ok(self.records.read().get(key).cloned())
+        }
+    }
+
+    fn fast_policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(5),
+            multiplier: 2.0,
+        }
+    }
+
+    async fn wait_terminal(queue: &CrmDeliveryQueue, key: &str) -> CrmDeliveryStatus {
+        for _ in 0..200 {
+            if let Some(status) = queue.status(key).filter(|s| s.is_terminal()) {
+                return status;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        panic!("delivery did not finish");
+    }
+
+    #[test]
+    fn test_backoff_growth_and_cap() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.backoff_for(1), Duration::from_millis(500));
+        assert_eq!(policy.backoff_for(2), Duration::from_millis(1000));
+        assert_eq!(policy.backoff_for(3), Duration::from_millis(2000));
+        assert_eq!(policy.backoff_for(20), Duration::from_secs(30));
+    }
+
+    #[tokio::test]
+    async fn test_retries_until_delivered() {
+        let crm = Arc::new(FlakyCrm::new(2, true));
+        let store = Arc::new(MemoryStore::default());
+        let queue =
+            CrmDeliveryQueue::spawn(crm.clone(), "test", fast_policy(5), 10, Some(store.clone()));
+
+        let key = queue.enqueue(sample_lead("9876543210")).await.unwrap();
+        assert_eq!(
+            wait_terminal(&queue, &key).await,
+            CrmDeliveryStatus::Delivered
+        );
+        assert_eq!(crm.calls.load(Ordering::SeqCst), 3);
+
+        let persisted = store.get(&key).await.unwrap().unwrap();
+        assert_eq!(persisted.status, CrmDeliveryStatus::Delivered);
+        assert_eq!(persisted.attempts, 3);
+        assert_eq!(persisted.external_id.as_deref(), Some("CRM-1"));
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_lead_delivered_once() {
+        let crm = Arc::new(FlakyCrm::new(0, true));
+        let queue = CrmDeliveryQueue::spawn(crm.clone(), "test", fast_policy(3), 10, None);
+
+        let key = queue.enqueue(sample_lead("9876543210")).await.unwrap();
+        wait_terminal(&queue, &key).await;
+        let again = queue.enqueue(sample_lead("+919876543210")).await.unwrap();
+
+        assert_eq!(again, "CRM-1");
+        assert_eq!(crm.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_non_retryable_error_fails_fast() {
+        let crm = Arc::new(FlakyCrm::new(10, false));
+        let queue = CrmDeliveryQueue::spawn(crm.clone(), "test", fast_policy(5), 10, None);
+
+        let key = queue.enqueue(sample_lead("9876543210")).await.unwrap();
+        assert_eq!(wait_terminal(&queue, &key).await, CrmDeliveryStatus::Failed);
+        assert_eq!(crm.calls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,340 @@
+//! Salesforce connector
+//!
+//! Creates and updates `Lead` sObjects via the Salesforce REST API.
+//! Idempotent creates use upsert-by-external-ID on a custom text field
+//! (`Voice_Agent_Key__c` by default, marked External ID + Unique in the org).
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde_json::{json, Map, Value};
+
+use super::{check_response, http_client, split_name, status_label, transport_error};
+use crate::integrations::{
+    CrmIntegration, CrmLead, IntegrationError, InterestLevel, LeadSource, LeadStatus,
+};
+
+const API_VERSION: &str = "v59.0";
+const DEFAULT_EXTERNAL_ID_FIELD: &str = "Voice_Agent_Key__c";
+
+/// Salesforce REST API connector
+pub struct SalesforceCrm {
+    client: reqwest::Client,
+    instance_url: String,
+    access_token: String,
+    external_id_field: String,
+}
+
+impl SalesforceCrm {
+    /// Create a connector for an org instance URL (e.g. `https://acme.my.salesforce.com`)
+    pub fn new(
+        instance_url: &str,
+        access_token: &str,
+        timeout: Duration,
+    ) -> Result<Self, IntegrationError> {
+        if instance_url.trim().is_empty() {
+            return Err(IntegrationError::InvalidRequest(
+                "Salesforce instance URL is required".to_string(),
+            ));
+        }
+        Ok(Self {
+            client: http_client(timeout)?,
+            instance_url: instance_url.trim_end_matches('/').to_string(),
+            access_token: access_token.to_string(),
+            external_id_field: DEFAULT_EXTERNAL_ID_FIELD.to_string(),
+        })
+    }
+
+    /// Use a different external ID field for idempotent upserts
+    pub fn with_external_id_field(mut self, field: impl Into<String>) -> Self {
+        self.external_id_field = field.into();
+        self
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!(
+            "{}/services/data/{}/{}",
+            self.instance_url, API_VERSION, path
+        )
+    }
+
+    async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, IntegrationError> {
+        let response = request
+            .bearer_auth(&self.access_token)
+            .send()
+            .await
+            .map_err(transport_error)?;
+        check_response(response).await
+    }
+
+    async fn patch_lead(&self, id: &str, fields: Value) -> Result<(), IntegrationError> {
+        self.send(
+            self.client
+                .patch(self.url(&format!("sobjects/Lead/{}", id)))
+                .json(&fields),
+        )
+        .await?;
+        Ok(())
+    }
+
+    fn lead_fields(lead: &CrmLead) -> Value {
+        let (first_name, last_name) = split_name(&lead.name);
+        let mut fields = Map::new();
+        fields.insert("LastName".into(), json!(last_name));
+        if let Some(first) = first_name {
+            fields.insert("FirstName".into(), json!(first));
+        }
+        // Company is mandatory on Lead; B2C orgs conventionally use the person's name
+        fields.insert("Company".into(), json!(lead.name));
+        fields.insert("MobilePhone".into(), json!(lead.phone));
+        fields.insert("LeadSource".into(), json!(Self::source_label(lead.source)));
+        fields.insert("Rating".into(), json!(Self::rating(lead.interest_level)));
+        fields.insert("Status".into(), json!(Self::status(lead.status)));
+        if let Some(email) = &lead.email {
+            fields.insert("Email".into(), json!(email));
+        }
+        if let Some(city) = &lead.city {
+            fields.insert("City".into(), json!(city));
+        }
+        let mut description = Vec::new();
+        if let Some(value) = lead.estimated_asset_value {
+            description.push(format!("Estimated asset value: {}", value));
+        }
+        if let Some(provider) = &lead.current_provider {
+            description.push(format!("Current provider: {}", provider));
+        }
+        if let Some(score) = lead.lead_score {
+            description.push(format!("Lead score: {}", score));
+        }
+        if let Some(qualification) = &lead.lead_qualification {
+            description.push(format!("Qualification: {}", qualification));
+        }
+        if let Some(notes) = &lead.notes {
+            description.push(notes.clone());
+        }
+        if !description.is_empty() {
+            fields.insert("Description".into(), json!(description.join("\n")));
+        }
+        if let Some(owner) = &lead.assigned_to {
+            fields.insert("OwnerId".into(), json!(owner));
+        }
+        Value::Object(fields)
+    }
+
+    fn source_label(source: LeadSource) -> &'static str {
+        match source {
+            LeadSource::VoiceAgent => "Phone Inquiry",
+            LeadSource::Website => "Web",
+            LeadSource::Branch => "Other",
+            LeadSource::Referral => "Partner Referral",
+            LeadSource::Campaign => "Purchased List",
+        }
+    }
+
+    fn rating(level: InterestLevel) -> &'static str {
+        match level {
+            InterestLevel::High => "Hot",
+            InterestLevel::Medium => "Warm",
+            InterestLevel::Low => "Cold",
+        }
+    }
+
+    fn status(status: LeadStatus) -> &'static str {
+        match status {
+            LeadStatus::New => "Open - Not Contacted",
+            LeadStatus::Contacted => "Working - Contacted",
+            LeadStatus::Won => "Closed - Converted",
+            LeadStatus::Lost => "Closed - Not Converted",
+            LeadStatus::Qualified | LeadStatus::Proposal | LeadStatus::Negotiation => {
+                "Working - Contacted"
+            },
+        }
+    }
+
+    fn parse_lead(record: &Value) -> CrmLead {
+        let text = |field: &str| record.get(field).and_then(|v| v.as_str()).map(String::from);
+        let name = [text("FirstName"), text("LastName")]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ");
+        let status = match text("Status").as_deref() {
+            Some("Working - Contacted") => LeadStatus::Contacted,
+            Some("Closed - Converted") => LeadStatus::Won,
+            Some("Closed - Not Converted") => LeadStatus::Lost,
+            _ => LeadStatus::New,
+        };
+        let interest_level = match text("Rating").as_deref() {
+            Some("Hot") => InterestLevel::High,
+            Some("Cold") => InterestLevel::Low,
+            _ => InterestLevel::Medium,
+        };
+        CrmLead {
+            id: text("Id"),
+            name,
+            phone: text("MobilePhone")
+                .or_else(|| text("Phone"))
+                .unwrap_or_default(),
+            email: text("Email"),
+            city: text("City"),
+            source: LeadSource::VoiceAgent,
+            interest_level,
+            estimated_asset_value: None,
+            current_provider: None,
+            notes: text("Description"),
+            assigned_to: text("OwnerId"),
+            status,
+            lead_score: None,
+            lead_qualification: None,
+        }
+    }
+}
+
+#[async_trait]
+impl CrmIntegration for SalesforceCrm {
+    async fn create_lead(&self, lead: CrmLead) -> Result<String, IntegrationError> {
+        let response = self
+            .send(
+                self.client
+                    .post(self.url("sobjects/Lead"))
+                    .json(&Self::lead_fields(&lead)),
+            )
+            .await?;
+        let body: Value = response
+            .json()
+            .await
+            .map_err(|e| IntegrationError::Internal(e.to_string()))?;
+        let id = body
+            .get("id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| IntegrationError::Internal("Salesforce response missing id".into()))?;
+        tracing::info!(lead_id = %id, "Salesforce: Created lead");
+        Ok(id.to_string())
+    }
+
+    async fn create_lead_idempotent(
+        &self,
+        lead: CrmLead,
+        idempotency_key: &str,
+    ) -> Result<String, IntegrationError> {
+        let path = format!(
+            "sobjects/Lead/{}/{}",
+            self.external_id_field, idempotency_key
+        );
+        let response = self
+            .send(
+                self.client
+                    .patch(self.url(&path))
+                    .json(&Self::lead_fields(&lead)),
+            )
+            .await?;
+
+        // 201 = created (returns id); 204 = existing record updated (no body)
+        if response.status() == reqwest::StatusCode::CREATED {
+            let body: Value = response
+                .json()
+                .await
+                .map_err(|e| IntegrationError::Internal(e.to_string()))?;
+            if let Some(id) = body.get("id").and_then(|v| v.as_str()) {
+                tracing::info!(lead_id = %id, "Salesforce: Created lead");
+                return Ok(id.to_string());
+            }
+        }
+
+        let existing: Value = self
+            .send(self.client.get(self.url(&format!("{}?fields=Id", path))))
+            .await?
+            .json()
+            .await
+            .map_err(|e| IntegrationError::Internal(e.to_string()))?;
+        let id = existing
+            .get("Id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| IntegrationError::Internal("Salesforce response missing Id".into()))?;
+        tracing::info!(lead_id = %id, "Salesforce: Upserted existing lead");
+        Ok(id.to_string())
+    }
+
+    async fn update_lead(&self, id: &str, lead: CrmLead) -> Result<(), IntegrationError> {
+        self.patch_lead(id, Self::lead_fields(&lead)).await
+    }
+
+    async fn get_lead(&self, id: &str) -> Result<CrmLead, IntegrationError> {
+        let record: Value = self
+            .send(self.client.get(self.url(&format!("sobjects/Lead/{}", id))))
+            .await?
+            .json()
+            .await
+            .map_err(|e| IntegrationError::Internal(e.to_string()))?;
+        Ok(Self::parse_lead(&record))
+    }
+
+    async fn find_by_phone(&self, phone: &str) -> Result<Vec<CrmLead>, IntegrationError> {
+        // Only digits and '+' reach the SOQL literal
+        let phone: String = phone
+            .chars()
+            .filter(|c| c.is_ascii_digit() || *c == '+')
+            .collect();
+        let soql = format!(
+            "SELECT Id, FirstName, LastName, MobilePhone, Phone, Email, City, Rating, Status, \
+             Description, OwnerId FROM Lead WHERE MobilePhone = '{0}' OR Phone = '{0}'",
+            phone
+        );
+        let body: Value = self
+            .send(self.client.get(self.url("query")).query(&[("q", soql)]))
+            .await?
+            .json()
+            .await
+            .map_err(|e| IntegrationError::Internal(e.to_string()))?;
+        Ok(body
+            .get("records")
+            .and_then(|v| v.as_array())
+            .map(|records| records.iter().map(Self::parse_lead).collect())
+            .unwrap_or_default())
+    }
+
+    async fn assign_lead(&self, lead_id: &str, rep_id: &str) -> Result<(), IntegrationError> {
+        self.patch_lead(lead_id, json!({ "OwnerId": rep_id })).await
+    }
+
+    async fn add_note(&self, lead_id: &str, note: &str) -> Result<(), IntegrationError> {
+        self.send(self.client.post(self.url("sobjects/Note")).json(&json!({
+            "ParentId": lead_id,
+            "Title": "Voice agent note",
+            "Body": note,
+        })))
+        .await?;
+        Ok(())
+    }
+
+    async fn update_status(
+        &self,
+        lead_id: &str,
+        status: LeadStatus,
+    ) -> Result<(), IntegrationError> {
+        tracing::debug!(lead_id = %lead_id, status = status_label(status), "Salesforce: Updating status");
+        self.patch_lead(lead_id, json!({ "Status": Self::status(status) }))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crm::tests::sample_lead;
+
+    #[test]
+    fn test_lead_fields() {
+        let fields = SalesforceCrm::lead_fields(&sample_lead("9876543210"));
+        assert_eq!(fields["LastName"], "Sharma");
+        assert_eq!(fields["FirstName"], "Rahul Kumar");
+        assert_eq!(fields["Rating"], "Hot");
+        assert!(fields["Description"]
+            .as_str()
+            .unwrap()
+            .contains("Lead score: 65"));
+    }
+}
@@ -0,0 +1,191 @@
+//! Generic webhook connector
+//!
+//! POSTs lead events as JSON to a single URL. Every request carries an
+//! `Idempotency-Key` header so the receiver can drop duplicate deliveries.
+//!
+//! Payload: `{ "event": "lead.created", "idempotency_key": "...", "lead_id": ..., "data": {...} }`.
+//! A JSON response with `id` (or `lead_id`) is used as the external lead ID.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+
+use super::{check_response, http_client, status_label, transport_error};
+use crate::integrations::{CrmIntegration, CrmLead, IntegrationError, LeadStatus};
+
+/// Generic JSON webhook connector
+pub struct WebhookCrm {
+    client: reqwest::Client,
+    url: String,
+    bearer_token: Option<String>,
+}
+
+impl WebhookCrm {
+    pub fn new(
+        url: &str,
+        bearer_token: Option<&str>,
+        timeout: Duration,
+    ) -> Result<Self, IntegrationError> {
+        if url.trim().is_empty() {
+            return Err(IntegrationError::InvalidRequest(
+                "Webhook URL is required".to_string(),
+            ));
+        }
+        Ok(Self {
+            client: http_client(timeout)?,
+            url: url.to_string(),
+            bearer_token: bearer_token.filter(|t| !t.is_empty()).map(String::from),
+        })
+    }
+
+    async fn post_event(
+        &self,
+        event: &str,
+        idempotency_key: &str,
+        lead_id: Option<&str>,
+        data: Value,
+    ) -> Result<Value, IntegrationError> {
+        let payload = json!({
+            "event": event,
+            "idempotency_key": idempotency_key,
+            "lead_id": lead_id,
+            "data": data,
+            "sent_at": chrono::Utc::now().to_rfc3339(),
+        });
+
+        let mut request = self
+            .client
+            .post(&self.url)
+            .header("Idempotency-Key", idempotency_key)
+            .json(&payload);
+        if let Some(token) = &self.bearer_token {
+            request = request.bearer_auth(token);
+        }
+
+        let response = request.send().await.map_err(transport_error)?;
+        let response = check_response(response).await?;
+        // Receivers are free to reply with an empty body
+        Ok(response.json().await.unwrap_or(Value::Null))
+    }
+
+    fn event_key(lead_id: &str, event: &str) -> String {
+        format!("{}-{}-{}", lead_id, event, uuid::Uuid::new_v4())
+    }
+}
+
+#[async_trait]
+impl CrmIntegration for WebhookCrm {
+    async fn create_lead(&self, lead: CrmLead) -> Result<String, IntegrationError> {
+        let key = super::idempotency_key(&lead);
+        self.create_lead_idempotent(lead, &key).await
+    }
+
+    async fn create_lead_idempotent(
+        &self,
+        lead: CrmLead,
+        idempotency_key: &str,
+    ) -> Result<String, IntegrationError> {
+        let data =
+            serde_json::to_value(&lead).map_err(|e| IntegrationError::Internal(e.to_string()))?;
+        let body = self
+            .post_event("lead.created", idempotency_key, None, data)
+            .await?;
+        let id = body
+            .get("id")
+            .or_else(|| body.get("lead_id"))
+            .and_then(|v| v.as_str())
+            .unwrap_or(idempotency_key)
+            .to_string();
+        tracing::info!(lead_id = %id, "Webhook CRM: Delivered lead");
+        Ok(id)
+    }
+
+    async fn update_lead(&self, id: &str, lead: CrmLead) -> Result<(), IntegrationError> {
+        let data =
+            serde_json::to_value(&lead).map_err(|e| IntegrationError::Internal(e.to_string()))?;
+        let key = Self::event_key(id, "updated");
+        self.post_event("lead.updated", &key, Some(id), data)
+            .await?;
+        Ok(())
+    }
+
+    async fn get_lead(&self, id: &str) -> Result<CrmLead, IntegrationError> {
+        // Webhooks are write-only
+        Err(IntegrationError::NotFound(format!(
+            "Lead lookup not supported by webhook connector: {}",
+            id
+        )))
+    }
+
+    async fn find_by_phone(&self, _phone: &str) -> Result<Vec<CrmLead>, IntegrationError> {
+        Ok(vec![])
+    }
+
+    async fn assign_lead(&self, lead_id: &str, rep_id: &str) -> Result<(), IntegrationError> {
+        let key = Self::event_key(lead_id, "assigned");
+        self.post_event(
+            "lead.assigned",
+            &key,
+            Some(lead_id),
+            json!({ "rep_id": rep_id }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn add_note(&self, lead_id: &str, note: &str) -> Result<(), IntegrationError> {
+        let key = Self::event_key(lead_id, "note");
+        self.post_event(
+            "lead.note_added",
+            &key,
+            Some(lead_id),
+            json!({ "note": note }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_status(
+        &self,
+        lead_id: &str,
+        status: LeadStatus,
+    ) -> Result<(), IntegrationError> {
+        let key = Self::event_key(lead_id, "status");
+        self.post_event(
+            "lead.status_changed",
+            &key,
+            Some(lead_id),
+            json!({ "status": status_label(status) }),
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requires_url() {
+        assert!(WebhookCrm::new("", None, Duration::from_secs(5)).is_err());
+        let crm = WebhookCrm::new(
+            "https://crm.example.com/hook",
+            Some(""),
+            Duration::from_secs(5),
+        )
+        .unwrap();
+        assert!(crm.bearer_token.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_endpoint_is_retryable() {
+        let crm = WebhookCrm::new("http://127.0.0.1:9/hook", None, Duration::from_secs(2)).unwrap();
+        let err = crm
+            .create_lead_idempotent(crate::crm::tests::sample_lead("9876543210"), "key-1")
+            .await
+            .unwrap_err();
+        assert!(err.is_retryable());
+    }
+}
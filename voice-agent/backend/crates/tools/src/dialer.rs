@@ -0,0 +1,381 @@
+//! Outbound Dialer
+//!
+//! Turns stored callbacks into calls. `CallbackDispatcher` polls the
+//! `CallbackStore`, hands callbacks whose window is open to the configured
+//! `OutboundDialer` and expires those whose window closed unanswered. The
+//! dialer (or the agent desk) reports each call's outcome back, which
+//! `DispatchPolicy::apply_outcome` turns into the next status: completed,
+//! retry after a delay, or expired once attempts run out.
+//!
+//! Webhook payload: `{ "event": "callback.dial", "callback_id": "...", "phone": "...",
+//! "agent_id": ..., "attempt": 1, "window_end": "..." }`. A JSON response with
+//! `call_id` (or `id`) is kept as the dialer call ID.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use voice_agent_config::DialerConfig;
+use voice_agent_persistence::{Callback, CallbackStatus, CallbackStore, PersistenceError};
+
+use crate::crm::{check_response, http_client, transport_error};
+use crate::integrations::IntegrationError;
+
+/// A call for the dialer to place
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DialRequest {
+    pub callback_id: String,
+    pub customer_phone: String,
+    pub customer_name: Option<String>,
+    /// Agent the call should be connected to (any available agent when None)
+    pub agent_id: Option<String>,
+    /// Attempt number, starting at 1
+    pub attempt: i32,
+    /// Latest time the customer asked to be called
+    pub window_end: DateTime<Utc>,
+    pub notes: Option<String>,
+}
+
+impl DialRequest {
+    fn for_callback(callback: &Callback) -> Self {
+        Self {
+            callback_id: callback.callback_id.to_string(),
+            customer_phone: callback.customer_phone.clone(),
+            customer_name: callback.customer_name.clone(),
+            agent_id: callback.assigned_agent.clone(),
+            attempt: callback.attempts + 1,
+            window_end: callback.window_end,
+            notes: callback.notes.clone(),
+        }
+    }
+}
+
+/// Outbound dialer integration
+#[async_trait]
+pub trait OutboundDialer: Send + Sync {
+    /// Place (or queue) a call; returns the dialer's call ID
+    async fn dial(&self, request: &DialRequest) -> Result<String, IntegrationError>;
+}
+
+/// Dialer reached through a JSON webhook
+pub struct WebhookDialer {
+    client: reqwest::Client,
+    url: String,
+    bearer_token: Option<String>,
+}
+
+impl WebhookDialer {
+    pub fn new(
+        url: &str,
+        bearer_token: Option<&str>,
+        timeout: Duration,
+    ) -> Result<Self, IntegrationError> {
+        if url.trim().is_empty() {
+            return Err(IntegrationError::InvalidRequest(
+                "Dialer URL is required".to_string(),
+            ));
+        }
+        Ok(Self {
+            client: http_client(timeout)?,
+            url: url.to_string(),
+            bearer_token: bearer_token.filter(|t| !t.is_empty()).map(String::from),
+        })
+    }
+}
+
+#[async_trait]
+impl OutboundDialer for WebhookDialer {
+    async fn dial(&self, request: &DialRequest) -> Result<String, IntegrationError> {
+        let payload = json!({
+            "event": "callback.dial",
+            "callback_id": request.callback_id,
+            "phone": request.customer_phone,
+            "customer_name": request.customer_name,
+            "agent_id": request.agent_id,
+            "attempt": request.attempt,
+            "window_end": request.window_end.to_rfc3339(),
+            "notes": request.notes,
+        });
+
+        let mut http = self
+            .client
+            .post(&self.url)
+            .header(
+                "Idempotency-Key",
+                format!("{}-{}", request.callback_id, request.attempt),
+            )
+            .json(&payload);
+        if let Some(token) = &self.bearer_token {
+            http = http.bearer_auth(token);
+        }
+
+        let response = http.send().await.map_err(transport_error)?;
+        let response = check_response(response).await?;
+        let body: Value = response.json().await.unwrap_or(Value::Null);
+        Ok(body
+            .get("call_id")
+            .or_else(|| body.get("id"))
+            .and_then(|v| v.as_str())
+            .map(String::from)
+            .unwrap_or_else(|| request.callback_id.clone()))
+    }
+}
+
+/// Build the configured dialer, or `None` when no endpoint is set
+pub fn dialer_from_config(
+    config: &DialerConfig,
+) -> Result<Option<Arc<dyn OutboundDialer>>, IntegrationError> {
+    let Some(endpoint) = config.endpoint.as_deref().filter(|e| !e.trim().is_empty()) else {
+        return Ok(None);
+    };
+    let dialer = WebhookDialer::new(
+        endpoint,
+        config.api_key.as_deref(),
+        Duration::from_secs(config.timeout_secs),
+    )?;
+    Ok(Some(Arc::new(dialer)))
+}
+
+/// Retry and polling rules for callbacks
+#[derive(Debug, Clone)]
+pub struct DispatchPolicy {
+    /// Dial attempts before a callback is expired
+    pub max_attempts: i32,
+    /// Wait after an unanswered call (or a dialer error) before dialing again
+    pub retry_delay: chrono::Duration,
+    /// How often the dispatcher checks for due callbacks
+    pub poll_interval: Duration,
+}
+
+impl DispatchPolicy {
+    pub fn from_config(config: &DialerConfig) -> Self {
+        Self {
+            max_attempts: config.max_attempts.max(1) as i32,
+            retry_delay: chrono::Duration::minutes(config.retry_delay_mins as i64),
+            poll_interval: Duration::from_secs(config.poll_interval_secs.max(1)),
+        }
+    }
+
+    /// Record a call outcome reported by the dialer or an agent
+    ///
+    /// An unanswered call is retried after `retry_delay`, or expired once
+    /// `max_attempts` calls went unanswered or the window has closed.
+    pub fn apply_outcome(
+        &self,
+        callback: &mut Callback,
+        status: CallbackStatus,
+        now: DateTime<Utc>,
+    ) -> Result<(), PersistenceError> {
+        callback.transition(status)?;
+        if status == CallbackStatus::NoAnswer {
+            let retry_at = now + self.retry_delay;
+            if callback.attempts >= self.max_attempts || retry_at >= callback.window_end {
+                callback.transition(CallbackStatus::Expired)?;
+            } else {
+                callback.next_attempt_at = Some(retry_at);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for DispatchPolicy {
+    fn default() -> Self {
+        Self::from_config(&DialerConfig::default())
+    }
+}
+
+/// What one dispatch pass did
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DispatchSummary {
+    pub dialed: usize,
+    pub expired: usize,
+    pub failed: usize,
+}
+
+/// Hands due callbacks to the dialer and expires missed ones
+pub struct CallbackDispatcher {
+    store: Arc<dyn CallbackStore>,
+    dialer: Option<Arc<dyn OutboundDialer>>,
+    policy: DispatchPolicy,
+}
+
+impl CallbackDispatcher {
+    /// Create a dispatcher; without a dialer it only expires missed callbacks
+    pub fn new(
+        store: Arc<dyn CallbackStore>,
+        dialer: Option<Arc<dyn OutboundDialer>>,
+        policy: DispatchPolicy,
+    ) -> Self {
+        Self {
+            store,
+            dialer,
+            policy,
+        }
+    }
+
+    /// Run one pass over the open callbacks
+    pub async fn run_once(&self, now: DateTime<Utc>) -> Result<DispatchSummary, PersistenceError> {
+        let mut summary = DispatchSummary::default();
+
+        for mut callback in self.store.list_open().await? {
+            if callback.is_missed(now) {
+                callback.transition(CallbackStatus::Expired)?;
+                self.store.update(&callback).await?;
+                summary.expired += 1;
+                continue;
+            }
+
+            let Some(ref dialer) = self.dialer else {
+                continue;
+            };
+            if !callback.is_due(now) {
+                continue;
+            }
+
+            match dialer.dial(&DialRequest::for_callback(&callback)).await {
+                Ok(call_id) => {
+                    callback.transition(CallbackStatus::Dialing)?;
+                    callback.attempts += 1;
+                    callback.dialer_call_id = Some(call_id);
+                    callback.next_attempt_at = None;
+                    summary.dialed += 1;
+                },
+                Err(e) => {
+                    tracing::warn!(
+                        callback_id = %callback.callback_id,
+                        error = %e,
+                        "Dialer rejected callback, will retry"
+                    );
+                    callback.next_attempt_at = Some(now + self.policy.retry_delay);
+                    summary.failed += 1;
+                },
+            }
+            self.store.update(&callback).await?;
+        }
+
+        Ok(summary)
+    }
+
+    /// Poll in the background
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn spawn(self) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(self.policy.poll_interval);
+            loop {
+                interval.tick().await;
+                match self.run_once(Utc::now()).await {
+                    Ok(summary) if summary != DispatchSummary::default() => {
+                        tracing::info!(
+                            dialed = summary.dialed,
+                            expired = summary.expired,
+                            failed = summary.failed,
+                            "Callback dispatch pass"
+                        );
+                    },
+                    Ok(_) => {},
+                    Err(e) => tracing::error!("Callback dispatch failed: {}", e),
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration as ChronoDuration;
+    use parking_lot::Mutex;
+    use voice_agent_persistence::InMemoryCallbackStore;
+
+    #[derive(Default)]
+    struct RecordingDialer {
+        calls: Mutex<Vec<DialRequest>>,
+    }
+
+    #[async_trait]
+    impl OutboundDialer for RecordingDialer {
+        async fn dial(&self, request: &DialRequest) -> Result<String, IntegrationError> {
+            self.calls.lock().push(request.clone());
+            Ok(format!("call-{}", request.attempt))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_dials_due_and_expires_missed() {
+        let store = Arc::new(InMemoryCallbackStore::new());
+        let now = Utc::now();
+        let mut due = Callback::new(
+            "9876543210",
+            now - ChronoDuration::minutes(5),
+            now + ChronoDuration::hours(2),
+        );
+        due.assign("agent-7").unwrap();
+        let missed = Callback::new(
+            "9876500000",
+            now - ChronoDuration::hours(3),
+            now - ChronoDuration::hours(1),
+        );
+        let later = Callback::new(
+            "9876511111",
+            now + ChronoDuration::hours(1),
+            now + ChronoDuration::hours(2),
+        );
+        for callback in [&due, &missed, &later] {
+            store.create(callback).await.unwrap();
+        }
+
+        let dialer = Arc::new(RecordingDialer::default());
+        let dispatcher = CallbackDispatcher::new(
+            store.clone(),
+            Some(dialer.clone()),
+            DispatchPolicy::default(),
+        );
+        let summary = dispatcher.run_once(now).await.unwrap();
+        assert_eq!(
+            summary,
+            DispatchSummary {
+                dialed: 1,
+                expired: 1,
+                failed: 0
+            }
+        );
+
+        {
+            let calls = dialer.calls.lock();
+            assert_eq!(calls.len(), 1);
+            assert_eq!(calls[0].agent_id.as_deref(), Some("agent-7"));
+        }
+        let dialed = store.get(due.callback_id).await.unwrap().unwrap();
+        assert_eq!(dialed.status, CallbackStatus::Dialing);
+        assert_eq!(dialed.dialer_call_id.as_deref(), Some("call-1"));
+        let expired = store.get(missed.callback_id).await.unwrap().unwrap();
+        assert_eq!(expired.status, CallbackStatus::Expired);
+    }
+
+    #[test]
+    fn test_no_answer_retries_until_attempts_run_out() {
+        let policy = DispatchPolicy::default();
+        let now = Utc::now();
+        let mut callback = Callback::new("9876543210", now, now + ChronoDuration::hours(4));
+
+        callback.transition(CallbackStatus::Dialing).unwrap();
+        callback.attempts = 1;
+        policy
+            .apply_outcome(&mut callback, CallbackStatus::NoAnswer, now)
+            .unwrap();
+        assert_eq!(callback.status, CallbackStatus::NoAnswer);
+        assert_eq!(callback.next_attempt_at, Some(now + policy.retry_delay));
+
+        callback.transition(CallbackStatus::Dialing).unwrap();
+        callback.attempts = policy.max_attempts;
+        policy
+            .apply_outcome(&mut callback, CallbackStatus::NoAnswer, now)
+            .unwrap();
+        assert_eq!(callback.status, CallbackStatus::Expired);
+    }
+}
@@ -178,7 +178,7 @@ impl ToolFactory for DomainToolFactory {
 
             // Tools that require config for location data
             "find_locations" | "find_branches" => {
-                Ok(Arc::new(BranchLocatorTool::new(self.view.clone())))
+                Ok(Arc::new(BranchLocatorTool::with_view(self.view.clone())))
             }
 
             // Tools that don't need domain config but may use integrations
@@ -6,15 +6,20 @@
 //! This module is organized into:
 //! - `utils`: Financial calculations (EMI, interest)
 //! - `locations`: Location/branch data management
+//! - `scheduling`: Visit date/time parsing and branch resolution
 //! - `tools`: MCP tool implementations
 
 mod locations;
+mod scheduling;
 mod tools;
 mod utils;
 
 // Re-export utilities
 pub use utils::{calculate_emi, calculate_total_interest};
 
+// Re-export the configured visit slot times
+pub use scheduling::configured_time_slots;
+
 // Re-export location management
 pub use locations::{
     get_branches, find_locations, load_branches_from_file, reload_branches, BranchData,
@@ -22,7 +27,12 @@ pub use locations::{
 
 // Re-export all tools
 pub use tools::{
-    AppointmentSchedulerTool, BranchLocatorTool, CompetitorComparisonTool, DocumentChecklistTool,
-    EligibilityCheckTool, EscalateToHumanTool, GetGoldPriceTool, LeadCaptureTool,
-    SavingsCalculatorTool, SendSmsTool,
+    AppointmentSchedulerTool, BranchLocatorTool, CheckOffersTool, CompetitorComparisonTool,
+    DocumentChecklistTool, EligibilityCheckTool, EscalateToHumanTool, GetGoldPriceTool,
+    LeadCaptureTool, OfferResponseTool, PriceAlertTool, SavingsCalculatorTool,
+    ScheduleCallbackTool, SendSmsTool, SlotAvailabilityTool, SlotHoldTool, TopUpEligibilityTool,
+    ESCALATION_TOOL, OFFER_TOOLS,
 };
+
+// Trend wording shared with the price alert SMS
+pub(crate) use tools::{describe_trend, DEFAULT_TREND_DAYS};
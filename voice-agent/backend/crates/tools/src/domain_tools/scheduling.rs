@@ -0,0 +1,397 @@
+//! Visit Scheduling Helpers
+//!
+//! Turns what callers say ("tomorrow", "kal", "Monday", "15/01/2025",
+//! "3 baje", "10 am") into calendar dates and configured slot times,
+//! resolves a spoken place or branch ID to a configured branch, and turns a
+//! requested callback time ("tomorrow after 6pm", "kal shaam") into a window.
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc, Weekday};
+use voice_agent_config::{BranchEntry, ToolsDomainView};
+
+/// Time slots from the `schedule_appointment.preferred_time` enum, or defaults
+pub fn configured_time_slots(view: Option<&ToolsDomainView>) -> Vec<String> {
+    if let Some(view) = view {
+        if let Some(tool) = view.get_tool("schedule_appointment") {
+            // Find the preferred_time parameter and get its enum values
+            for param in &tool.parameters {
+                if param.name == "preferred_time" {
+                    if let Some(ref values) = param.enum_values {
+                        if !values.is_empty() {
+                            return values.clone();
+                        }
+                    }
+                }
+            }
+        }
+    }
+    // Default time slots (generic)
+    vec![
+        "10:00 AM".to_string(),
+        "11:00 AM".to_string(),
+        "12:00 PM".to_string(),
+        "2:00 PM".to_string(),
+        "3:00 PM".to_string(),
+        "4:00 PM".to_string(),
+        "5:00 PM".to_string(),
+    ]
+}
+
+/// Current date and time at the branches (UTC shifted by the configured offset)
+pub fn branch_now(view: Option<&ToolsDomainView>) -> NaiveDateTime {
+    let offset = view
+        .map(|v| v.appointment_slots().utc_offset_minutes)
+        .unwrap_or(0);
+    Utc::now().naive_utc() + Duration::minutes(offset as i64)
+}
+
+/// Parse a spoken or written visit date relative to `today`
+///
+/// Accepts today/tomorrow/day after tomorrow (and aaj/kal/parso), weekday
+/// names (the next such day, never today) and YYYY-MM-DD, DD-MM-YYYY or
+/// DD/MM/YYYY dates.
+pub fn parse_visit_date(text: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let text = text.trim().to_lowercase();
+    let relative = match text.as_str() {
+        "today" | "aaj" => Some(0),
+        "tomorrow" | "kal" => Some(1),
+        "day after tomorrow" | "parso" | "parson" => Some(2),
+        _ => None,
+    };
+    if let Some(days) = relative {
+        return Some(today + Duration::days(days));
+    }
+
+    let day_name = text
+        .trim_start_matches("next ")
+        .trim_start_matches("this ")
+        .trim();
+    if let Ok(weekday) = day_name.parse::<Weekday>() {
+        let ahead = (weekday.num_days_from_monday() as i64
+            - today.weekday().num_days_from_monday() as i64)
+            .rem_euclid(7);
+        return Some(today + Duration::days(if ahead == 0 { 7 } else { ahead }));
+    }
+
+    NaiveDate::parse_from_str(&text, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(&text, "%d-%m-%Y"))
+        .or_else(|_| NaiveDate::parse_from_str(&text, "%d/%m/%Y"))
+        .ok()
+}
+
+/// Start time of a configured slot label such as "10:00 AM"
+pub fn slot_start(slot: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(&slot.trim().to_uppercase(), "%I:%M %p").ok()
+}
+
+/// Parse a spoken time ("10 am", "2:30pm", "14:00", "3 baje")
+///
+/// Bare hours from 1 to 7 are taken as afternoon, matching branch hours.
+pub fn parse_time(text: &str) -> Option<NaiveTime> {
+    let text = text
+        .trim()
+        .to_uppercase()
+        .replace("BAJE", "")
+        .replace('.', "")
+        .trim()
+        .to_string();
+
+    for format in ["%I:%M %p", "%I:%M%p", "%H:%M"] {
+        if let Ok(time) = NaiveTime::parse_from_str(&text, format) {
+            return Some(time);
+        }
+    }
+
+    let (hour, meridiem) = match text.strip_suffix("AM").or_else(|| text.strip_suffix("PM")) {
+        Some(hour) => (hour.trim(), Some(text.ends_with("PM"))),
+        None => (text.as_str(), None),
+    };
+    let hour: u32 = hour.parse().ok()?;
+    let hour = match (hour, meridiem) {
+        (1..=11, Some(true)) => hour + 12,
+        (12, Some(false)) => 0,
+        (1..=7, None) => hour + 12,
+        (0..=23, _) => hour,
+        _ => return None,
+    };
+    NaiveTime::from_hms_opt(hour, 0, 0)
+}
+
+/// Match a spoken time against the configured slot labels
+pub fn match_slot_time(text: &str, slots: &[String]) -> Option<String> {
+    if let Some(slot) = slots.iter().find(|s| s.eq_ignore_ascii_case(text.trim())) {
+        return Some(slot.clone());
+    }
+    let time = parse_time(text)?;
+    slots
+        .iter()
+        .find(|slot| slot_start(slot) == Some(time))
+        .cloned()
+}
+
+/// Resolve a branch ID or place name to a configured branch
+pub fn resolve_branch<'a>(view: &'a ToolsDomainView, text: &str) -> Option<&'a BranchEntry> {
+    view.get_branch(text.trim())
+        .or_else(|| view.find_branches_near(text).into_iter().next())
+}
+
+/// Hours callbacks can be made in, from the `schedule_callback` tool defaults
+#[derive(Debug, Clone)]
+pub struct CallbackHours {
+    /// Earliest time of day to call
+    pub start: NaiveTime,
+    /// Latest time of day to call
+    pub end: NaiveTime,
+    /// Window length when the caller names a single time ("6 pm")
+    pub window_minutes: i64,
+    /// Named parts of the day ("evening", "shaam") and their hours
+    pub periods: Vec<(String, NaiveTime, NaiveTime)>,
+}
+
+impl Default for CallbackHours {
+    fn default() -> Self {
+        let hour = |h| NaiveTime::from_hms_opt(h, 0, 0).unwrap_or_default();
+        let periods = [
+            ("morning", 9, 12),
+            ("subah", 9, 12),
+            ("afternoon", 12, 16),
+            ("dopahar", 12, 16),
+            ("evening", 16, 20),
+            ("shaam", 16, 20),
+        ];
+        Self {
+            start: hour(9),
+            end: hour(20),
+            window_minutes: 60,
+            periods: periods
+                .iter()
+                .map(|(name, from, to)| (name.to_string(), hour(*from), hour(*to)))
+                .collect(),
+        }
+    }
+}
+
+impl CallbackHours {
+    /// Read `call_hours_start`, `call_hours_end`, `window_minutes` and `periods`
+    pub fn from_view(view: Option<&ToolsDomainView>) -> Self {
+        let mut hours = Self::default();
+        let Some(defaults) =
+            view.and_then(|v| v.tools_config().get_tool_defaults("schedule_callback"))
+        else {
+            return hours;
+        };
+        let time = |key: &str| {
+            defaults
+                .get(key)
+                .and_then(|v| v.as_str())
+                .and_then(|s| NaiveTime::parse_from_str(s, "%H:%M").ok())
+        };
+        if let Some(start) = time("call_hours_start") {
+            hours.start = start;
+        }
+        if let Some(end) = time("call_hours_end") {
+            hours.end = end;
+        }
+        if let Some(minutes) = defaults.get("window_minutes").and_then(|v| v.as_i64()) {
+            hours.window_minutes = minutes.max(1);
+        }
+        if let Some(periods) = defaults.get("periods").and_then(|v| v.as_object()) {
+            let parse = |v: &serde_json::Value| {
+                v.as_str()
+                    .and_then(|s| NaiveTime::parse_from_str(s, "%H:%M").ok())
+            };
+            hours.periods = periods
+                .iter()
+                .filter_map(|(name, range)| {
+                    let range = range.as_array()?;
+                    Some((
+                        name.to_lowercase(),
+                        parse(range.first()?)?,
+                        parse(range.get(1)?)?,
+                    ))
+                })
+                .collect();
+        }
+        hours
+    }
+}
+
+/// Times mentioned in a phrase ("6", "6pm", "6:30 pm", "6 baje")
+fn find_times(words: &[&str], afternoon: bool) -> Vec<NaiveTime> {
+    let mut times = Vec::new();
+    for (i, word) in words.iter().enumerate() {
+        if !word.starts_with(|c: char| c.is_ascii_digit()) {
+            continue;
+        }
+        let mut text = word.to_string();
+        if let Some(next) = words
+            .get(i + 1)
+            .filter(|w| matches!(**w, "am" | "pm" | "baje"))
+        {
+            text = format!("{} {}", text, next);
+        }
+        if let Some(time) = parse_time(&text) {
+            let explicit = text.ends_with("am") || text.ends_with("pm");
+            let time = match time.hour() {
+                h @ 1..=11 if afternoon && !explicit => time.with_hour(h + 12).unwrap_or(time),
+                _ => time,
+            };
+            times.push(time);
+        }
+    }
+    times
+}
+
+/// Parse a requested callback time into a window in branch local time
+///
+/// Understands a day (today/tomorrow/kal/a weekday, else `date`, else the
+/// next day the window is still open), a part of the day ("evening",
+/// "shaam"), and a time with "after"/"baad", "before"/"pehle" or a range
+/// ("between 4 and 6"). A single time gets a `window_minutes` window;
+/// "now"/"abhi" starts immediately. Windows are kept within call hours.
+pub fn parse_callback_window(
+    text: &str,
+    date: Option<NaiveDate>,
+    hours: &CallbackHours,
+    now: NaiveDateTime,
+) -> Option<(NaiveDateTime, NaiveDateTime)> {
+    let text = text.to_lowercase().replace([',', '-', '?', '!'], " ");
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let has = |options: &[&str]| words.iter().any(|w| options.contains(w));
+    let today = now.date();
+    let window = Duration::minutes(hours.window_minutes);
+
+    if has(&["now", "abhi", "asap", "immediately"]) {
+        return Some((now, now + window));
+    }
+
+    let spoken_date = if text.contains("day after tomorrow") {
+        Some(today + Duration::days(2))
+    } else {
+        words.iter().find_map(|w| match *w {
+            "next" | "this" => None,
+            w if w.starts_with(|c: char| c.is_ascii_digit()) && !w.contains('/') => None,
+            w => parse_visit_date(w, today),
+        })
+    };
+
+    let period = hours
+        .periods
+        .iter()
+        .find(|(name, _, _)| words.contains(&name.as_str()));
+    let afternoon = period.is_some_and(|(_, from, _)| from.hour() >= 12);
+    let times = find_times(&words, afternoon);
+    let (period_start, period_end) = period
+        .map(|(_, from, to)| (*from, *to))
+        .unwrap_or((hours.start, hours.end));
+
+    let (start, end) = match times.as_slice() {
+        [first, second, ..] => (*first.min(second), *first.max(second)),
+        [time] if has(&["after", "baad"]) => (*time, period_end.max(*time)),
+        [time] if has(&["before", "pehle"]) => (period_start.min(*time), *time),
+        [time] => (*time, *time + window),
+        [] => (period_start, period_end),
+    };
+    let (start, end) = (start.max(hours.start), end.min(hours.end));
+    if end <= start {
+        return None;
+    }
+
+    let day = match spoken_date.or(date) {
+        Some(day) => day,
+        None if now.time() >= end => today + Duration::days(1),
+        None => today,
+    };
+    let start = day.and_time(start).max(now);
+    let end = day.and_time(end);
+    (end > start).then_some((start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slots() -> Vec<String> {
+        configured_time_slots(None)
+    }
+
+    #[test]
+    fn test_parse_visit_date() {
+        // A Wednesday
+        let today = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+        let day = |d| NaiveDate::from_ymd_opt(2025, 1, d).unwrap();
+
+        assert_eq!(parse_visit_date("Tomorrow", today), Some(day(16)));
+        assert_eq!(parse_visit_date("kal", today), Some(day(16)));
+        assert_eq!(parse_visit_date("parso", today), Some(day(17)));
+        assert_eq!(parse_visit_date("friday", today), Some(day(17)));
+        assert_eq!(parse_visit_date("next Wednesday", today), Some(day(22)));
+        assert_eq!(parse_visit_date("2025-01-20", today), Some(day(20)));
+        assert_eq!(parse_visit_date("20/01/2025", today), Some(day(20)));
+        assert_eq!(parse_visit_date("someday", today), None);
+    }
+
+    #[test]
+    fn test_match_slot_time() {
+        let slots = slots();
+        assert_eq!(
+            match_slot_time("10:00 am", &slots),
+            Some("10:00 AM".to_string())
+        );
+        assert_eq!(
+            match_slot_time("10 am", &slots),
+            Some("10:00 AM".to_string())
+        );
+        assert_eq!(
+            match_slot_time("3 baje", &slots),
+            Some("3:00 PM".to_string())
+        );
+        assert_eq!(
+            match_slot_time("14:00", &slots),
+            Some("2:00 PM".to_string())
+        );
+        assert_eq!(
+            match_slot_time("12 pm", &slots),
+            Some("12:00 PM".to_string())
+        );
+        assert_eq!(match_slot_time("1 pm", &slots), None);
+        assert_eq!(match_slot_time("evening", &slots), None);
+    }
+
+    #[test]
+    fn test_parse_callback_window() {
+        let hours = CallbackHours::default();
+        // Wednesday 15 Jan, 11:30 local
+        let now = NaiveDate::from_ymd_opt(2025, 1, 15)
+            .unwrap()
+            .and_hms_opt(11, 30, 0)
+            .unwrap();
+        let at = |d, h, m| {
+            NaiveDate::from_ymd_opt(2025, 1, d)
+                .unwrap()
+                .and_hms_opt(h, m, 0)
+                .unwrap()
+        };
+        let window = |text| parse_callback_window(text, None, &hours, now);
+
+        assert_eq!(
+            window("call me tomorrow after 6pm"),
+            Some((at(16, 18, 0), at(16, 20, 0)))
+        );
+        assert_eq!(
+            window("kal shaam 5 baje ke baad"),
+            Some((at(16, 17, 0), at(16, 20, 0)))
+        );
+        assert_eq!(
+            window("friday between 4 and 6"),
+            Some((at(17, 16, 0), at(17, 18, 0)))
+        );
+        assert_eq!(window("3 pm"), Some((at(15, 15, 0), at(15, 16, 0))));
+        // Already past today, so tomorrow
+        assert_eq!(window("before 11"), Some((at(16, 9, 0), at(16, 11, 0))));
+        // Open windows start now rather than in the past
+        assert_eq!(window("morning"), Some((now, at(15, 12, 0))));
+        assert_eq!(window("today"), Some((now, at(15, 20, 0))));
+        assert_eq!(window("abhi"), Some((now, at(15, 12, 30))));
+        assert_eq!(window("today at 10 am"), None);
+    }
+}
@@ -2,14 +2,27 @@
 //!
 //! Schedule branch visit appointments.
 //! P16 FIX: Purposes and time slots are now config-driven via ToolsDomainView.
+//!
+//! With a slot store, booking confirms the caller's slot hold (or holds and
+//! confirms in one go), so capacity is decremented and full slots are refused.
+//!
+//! With SMS confirmations wired in, the booking is stored and the customer is
+//! texted a code to reply YES, NO or RESCHEDULE to.
 
 use async_trait::async_trait;
-use chrono::{NaiveDate, Utc};
+use chrono::{Duration, NaiveDate};
 use serde_json::{json, Value};
 use std::sync::Arc;
 
 use voice_agent_config::ToolsDomainView;
+use voice_agent_persistence::{
+    Appointment as StoredAppointment, PersistenceError, SlotConfirmation, SlotHold, SlotStore,
+};
 
+use super::super::scheduling::{
+    branch_now, configured_time_slots, match_slot_time, parse_visit_date, resolve_branch,
+};
+use crate::appointment_confirmation::{AppointmentConfirmations, ConfirmationRequest};
 use crate::integrations::{
     Appointment, AppointmentPurpose, AppointmentStatus, CalendarIntegration,
 };
@@ -24,6 +37,10 @@ pub struct AppointmentSchedulerTool {
     calendar: Option<Arc<dyn CalendarIntegration>>,
     /// P16 FIX: Domain view for config-driven values
     view: Option<Arc<ToolsDomainView>>,
+    /// Branch slot capacity; used together with the view
+    slots: Option<Arc<dyn SlotStore>>,
+    /// Stores bookings and confirms them by SMS reply
+    confirmations: Option<Arc<AppointmentConfirmations>>,
 }
 
 impl AppointmentSchedulerTool {
@@ -31,6 +48,8 @@ impl AppointmentSchedulerTool {
         Self {
             calendar: None,
             view: None,
+            slots: None,
+            confirmations: None,
         }
     }
 
@@ -39,6 +58,8 @@ impl AppointmentSchedulerTool {
         Self {
             calendar: None,
             view: Some(view),
+            slots: None,
+            confirmations: None,
         }
     }
 
@@ -46,6 +67,8 @@ impl AppointmentSchedulerTool {
         Self {
             calendar: Some(calendar),
             view: None,
+            slots: None,
+            confirmations: None,
         }
     }
 
@@ -57,35 +80,134 @@ impl AppointmentSchedulerTool {
         Self {
             calendar: Some(calendar),
             view: Some(view),
+            slots: None,
+            confirmations: None,
         }
     }
 
+    /// Book against branch slot capacity (requires a domain view)
+    pub fn with_slot_store(mut self, slots: Arc<dyn SlotStore>) -> Self {
+        self.slots = Some(slots);
+        self
+    }
+
+    /// Store bookings and text the customer a code to confirm by reply
+    pub fn with_confirmations(mut self, confirmations: Arc<AppointmentConfirmations>) -> Self {
+        self.confirmations = Some(confirmations);
+        self
+    }
+
     /// Get time slots from config or defaults
     fn time_slots(&self) -> Vec<String> {
-        if let Some(ref view) = self.view {
-            if let Some(tool) = view.get_tool("schedule_appointment") {
-                // Find the preferred_time parameter and get its enum values
-                for param in &tool.parameters {
-                    if param.name == "preferred_time" {
-                        if let Some(ref values) = param.enum_values {
-                            if !values.is_empty() {
-                                return values.clone();
-                            }
-                        }
-                    }
-                }
+        configured_time_slots(self.view.as_deref())
+    }
+
+    /// Confirm the caller's `hold_id`, or hold and confirm the requested slot
+    ///
+    /// Returns `Ok(None)` when the slot is fully booked.
+    async fn book_slot(
+        slots: &dyn SlotStore,
+        view: &ToolsDomainView,
+        input: &Value,
+        branch_id: &str,
+        date: NaiveDate,
+        time: &str,
+    ) -> Result<Option<SlotHold>, ToolError> {
+        let store_error =
+            |e: PersistenceError| ToolError::internal(format!("Slot booking failed: {}", e));
+        let hold_id = input
+            .get("hold_id")
+            .and_then(|v| v.as_str())
+            .and_then(|id| uuid::Uuid::parse_str(id).ok());
+        let session_id = input
+            .get("session_id")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default();
+        let capacity = view.branch_slot_capacity(branch_id);
+
+        if let Some(hold_id) = hold_id {
+            let confirmation = slots
+                .confirm(hold_id, capacity)
+                .await
+                .map_err(store_error)?;
+            match confirmation {
+                SlotConfirmation::Confirmed(hold) => return Ok(Some(hold)),
+                SlotConfirmation::Full => return Ok(None),
+                // Hold lapsed; try to book the slot directly
+                SlotConfirmation::Expired => {},
             }
         }
-        // Default time slots (generic)
-        vec![
-            "10:00 AM".to_string(),
-            "11:00 AM".to_string(),
-            "12:00 PM".to_string(),
-            "2:00 PM".to_string(),
-            "3:00 PM".to_string(),
-            "4:00 PM".to_string(),
-            "5:00 PM".to_string(),
-        ]
+
+        let ttl = Duration::seconds(view.appointment_slots().hold_ttl_secs as i64);
+        let Some(hold) = slots
+            .hold(branch_id, date, time, session_id, capacity, ttl)
+            .await
+            .map_err(store_error)?
+        else {
+            return Ok(None);
+        };
+        let confirmation = slots
+            .confirm(hold.hold_id, capacity)
+            .await
+            .map_err(store_error)?;
+        match confirmation {
+            SlotConfirmation::Confirmed(hold) => Ok(Some(hold)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Store the booking and send the confirmation SMS
+    ///
+    /// Returns `None` (the team confirms by phone instead) if it cannot be stored.
+    #[allow(clippy::too_many_arguments)]
+    async fn request_confirmation(
+        &self,
+        confirmations: &AppointmentConfirmations,
+        input: &Value,
+        name: &str,
+        phone: &str,
+        branch_id: &str,
+        date: NaiveDate,
+        time: &str,
+        purpose: &str,
+    ) -> Option<ConfirmationRequest> {
+        let branch = self.view.as_ref().and_then(|v| v.get_branch(branch_id));
+        let mut appointment = StoredAppointment::new(
+            phone,
+            branch_id,
+            branch.map(|b| b.name.as_str()).unwrap_or(branch_id),
+            branch.map(|b| b.address.as_str()).unwrap_or_default(),
+            date,
+            time,
+        );
+        appointment.customer_name = Some(name.to_string());
+        appointment.session_id = input
+            .get("session_id")
+            .and_then(|v| v.as_str())
+            .map(String::from);
+        appointment.notes = Some(purpose.to_string());
+
+        match confirmations.request(appointment).await {
+            Ok(request) => Some(request),
+            Err(e) => {
+                tracing::warn!("Appointment not stored for SMS confirmation: {}", e);
+                None
+            },
+        }
+    }
+
+    /// Report an SMS confirmation request in the tool result
+    fn apply_sms_confirmation(result: &mut Value, request: &ConfirmationRequest, message: String) {
+        if request.sms_id.is_none() {
+            return;
+        }
+        result["confirmation_sent"] = json!(true);
+        result["confirmation_method"] = json!("sms_reply");
+        result["confirmation_code"] = json!(request.code);
+        result["next_action"] = json!(
+            "Tell the customer to reply YES to the SMS to confirm, NO to cancel or RESCHEDULE to change the time"
+        );
+        result["message"] = json!(message);
     }
 
     /// Get appointment purposes from config or defaults
@@ -181,6 +303,11 @@ impl Tool for AppointmentSchedulerTool {
                     "purpose",
                     PropertySchema::enum_type("Purpose of visit", purposes),
                     false,
+                )
+                .property(
+                    "hold_id",
+                    PropertySchema::string("Slot hold ID from hold_appointment_slot"),
+                    false,
                 ),
         }
     }
@@ -196,38 +323,90 @@ impl Tool for AppointmentSchedulerTool {
             .and_then(|v| v.as_str())
             .ok_or_else(|| ToolError::invalid_params("phone_number is required"))?;
 
-        let branch = input
+        let branch_text = input
             .get("branch_id")
             .and_then(|v| v.as_str())
             .ok_or_else(|| ToolError::invalid_params("branch_id is required"))?;
+        let mut branch = self
+            .view
+            .as_deref()
+            .and_then(|view| resolve_branch(view, branch_text))
+            .map(|b| b.branch_id.clone())
+            .unwrap_or_else(|| branch_text.to_string());
 
         let date_str = input
             .get("preferred_date")
             .and_then(|v| v.as_str())
             .ok_or_else(|| ToolError::invalid_params("preferred_date is required"))?;
 
-        let parsed_date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d")
-            .or_else(|_| NaiveDate::parse_from_str(date_str, "%d-%m-%Y"))
-            .or_else(|_| NaiveDate::parse_from_str(date_str, "%d/%m/%Y"))
-            .map_err(|_| {
-                ToolError::invalid_params(
-                    "preferred_date must be in format YYYY-MM-DD, DD-MM-YYYY, or DD/MM/YYYY",
-                )
-            })?;
+        let today = branch_now(self.view.as_deref()).date();
+        let mut parsed_date = parse_visit_date(date_str, today).ok_or_else(|| {
+            ToolError::invalid_params(
+                "preferred_date must be today, tomorrow, a weekday, YYYY-MM-DD, DD-MM-YYYY or DD/MM/YYYY",
+            )
+        })?;
 
-        let today = Utc::now().date_naive();
         if parsed_date < today {
             return Err(ToolError::invalid_params(
                 "preferred_date cannot be in the past",
             ));
         }
+        if let Some(ref view) = self.view {
+            if view
+                .appointment_slots()
+                .is_closed_day(&parsed_date.format("%a").to_string())
+            {
+                return Err(ToolError::invalid_params(format!(
+                    "Branches are closed on {}",
+                    parsed_date.format("%A")
+                )));
+            }
+        }
 
-        let date = parsed_date.format("%Y-%m-%d").to_string();
-
-        let time = input
+        let time_slots = self.time_slots();
+        let time_str = input
             .get("preferred_time")
             .and_then(|v| v.as_str())
             .ok_or_else(|| ToolError::invalid_params("preferred_time is required"))?;
+        let mut time = match_slot_time(time_str, &time_slots).ok_or_else(|| {
+            ToolError::invalid_params(format!(
+                "preferred_time must be one of: {}",
+                time_slots.join(", ")
+            ))
+        })?;
+
+        // Book against branch capacity when a slot store is wired in
+        let mut slot_confirmed = false;
+        if let (Some(slots), Some(view)) = (&self.slots, &self.view) {
+            let booked =
+                Self::book_slot(slots.as_ref(), view, &input, &branch, parsed_date, &time).await?;
+            match booked {
+                Some(hold) => {
+                    branch = hold.branch_id;
+                    parsed_date = hold.date;
+                    time = hold.time;
+                    slot_confirmed = true;
+                },
+                None => {
+                    let result = json!({
+                        "success": false,
+                        "reason": "slot_full",
+                        "branch_id": branch,
+                        "date": parsed_date.format("%Y-%m-%d").to_string(),
+                        "time": time,
+                        "next_action": "Offer other free slots with check_slot_availability",
+                        "message": format!(
+                            "{} on {} is fully booked at this branch. Let me find another slot.",
+                            time,
+                            parsed_date.format("%a %-d %b")
+                        )
+                    });
+                    return Ok(ToolOutput::json(result));
+                },
+            }
+        }
+
+        let date = parsed_date.format("%Y-%m-%d").to_string();
 
         let default_purpose = self.default_purpose();
         let purpose_str = input
@@ -240,14 +419,35 @@ impl Tool for AppointmentSchedulerTool {
 
         let product = self.product_name();
 
+        let sms_confirmation = match self.confirmations {
+            Some(ref confirmations) => {
+                self.request_confirmation(
+                    confirmations,
+                    &input,
+                    name,
+                    phone,
+                    &branch,
+                    parsed_date,
+                    &time,
+                    purpose_str,
+                )
+                .await
+            },
+            None => None,
+        };
+        let sms_message = format!(
+            "{} appointment scheduled for {} on {} at {}. Please reply YES to the SMS sent to {} to confirm.",
+            product, name, date, time, phone
+        );
+
         if let Some(ref calendar) = self.calendar {
             let appointment = Appointment {
                 id: None,
                 customer_name: name.to_string(),
                 customer_phone: phone.to_string(),
-                branch_id: branch.to_string(),
+                branch_id: branch.clone(),
                 date: date.clone(),
-                time_slot: time.to_string(),
+                time_slot: time.clone(),
                 purpose,
                 notes: None,
                 status: AppointmentStatus::Scheduled,
@@ -259,7 +459,7 @@ impl Tool for AppointmentSchedulerTool {
                     let confirmation_sent =
                         calendar.send_confirmation(&appointment_id).await.is_ok();
 
-                    let result = json!({
+                    let mut result = json!({
                         "success": true,
                         "appointment_id": appointment_id,
                         "customer_name": name,
@@ -270,6 +470,7 @@ impl Tool for AppointmentSchedulerTool {
                         "purpose": purpose_str,
                         "confirmation_sent": confirmation_sent,
                         "calendar_integrated": true,
+                        "slot_confirmed": slot_confirmed,
                         "status": "pending_confirmation",
                         "confirmation_method": "agent_will_call_to_confirm",
                         "next_action": "Agent will call customer to confirm appointment",
@@ -285,6 +486,9 @@ impl Tool for AppointmentSchedulerTool {
                             )
                         }
                     });
+                    if let Some(ref request) = sms_confirmation {
+                        Self::apply_sms_confirmation(&mut result, request, sms_message);
+                    }
                     return Ok(ToolOutput::json(result));
                 }
                 Err(e) => {
@@ -293,12 +497,15 @@ impl Tool for AppointmentSchedulerTool {
             }
         }
 
-        let appointment_id = format!(
-            "APT{}",
-            uuid::Uuid::new_v4().to_string()[..8].to_uppercase()
-        );
+        let appointment_id = match sms_confirmation {
+            Some(ref request) => format!("APT{}", request.code),
+            None => format!(
+                "APT{}",
+                uuid::Uuid::new_v4().to_string()[..8].to_uppercase()
+            ),
+        };
 
-        let result = json!({
+        let mut result = json!({
             "success": true,
             "appointment_id": appointment_id,
             "customer_name": name,
@@ -309,6 +516,7 @@ impl Tool for AppointmentSchedulerTool {
             "purpose": purpose_str,
             "confirmation_sent": false,
             "calendar_integrated": false,
+            "slot_confirmed": slot_confirmed,
             "status": "pending_confirmation",
             "confirmation_method": "agent_will_call_to_confirm",
             "next_action": "Agent will call customer to confirm appointment",
@@ -317,6 +525,9 @@ impl Tool for AppointmentSchedulerTool {
                 product, name, date, time
             )
         });
+        if let Some(ref request) = sms_confirmation {
+            Self::apply_sms_confirmation(&mut result, request, sms_message);
+        }
 
         Ok(ToolOutput::json(result))
     }
@@ -1,9 +1,17 @@
 //! Location Finder Tool
 //!
 //! Find nearby service locations/branches.
+//!
+//! The caller's city is resolved through the location gazetteer first, so
+//! "Bengaluru", a misheard "bengluru" or just a PIN code all find the
+//! Bangalore branches.
 
 use async_trait::async_trait;
 use serde_json::{json, Value};
+use std::sync::Arc;
+
+use voice_agent_config::ToolsDomainView;
+use voice_agent_core::LocationGazetteer;
 
 use crate::mcp::{InputSchema, PropertySchema, Tool, ToolError, ToolOutput, ToolSchema};
 
@@ -13,11 +21,37 @@ use super::super::locations::{get_branches, BranchData};
 ///
 /// Finds service locations based on city, area, or pincode.
 /// This is domain-agnostic - actual locations come from domain config.
-pub struct BranchLocatorTool;
+pub struct BranchLocatorTool {
+    /// Cities and localities of the domain (None = built-in city list)
+    locations: Option<Arc<LocationGazetteer>>,
+}
 
 impl BranchLocatorTool {
     pub fn new() -> Self {
-        Self
+        Self { locations: None }
+    }
+
+    /// Create with the domain's location gazetteer
+    pub fn with_view(view: Arc<ToolsDomainView>) -> Self {
+        Self {
+            locations: Some(view.location_gazetteer()),
+        }
+    }
+
+    /// Canonical name of the caller's city, else the city of the PIN code
+    fn resolve_city(&self, city: &str, pincode: Option<&str>) -> Option<String> {
+        let locations: &LocationGazetteer = match &self.locations {
+            Some(locations) => locations,
+            None => LocationGazetteer::builtin(),
+        };
+        if let Some(known) = locations.city(city) {
+            return Some(known.name.clone());
+        }
+        if let Some(found) = locations.find_city(city) {
+            return Some(found.value.to_string());
+        }
+        let by_pincode = pincode.and_then(|pin| locations.city_for_pincode(pin));
+        by_pincode.map(|known| known.name.clone())
     }
 }
 
@@ -60,6 +94,8 @@ impl Tool for BranchLocatorTool {
             .and_then(|v| v.as_i64())
             .unwrap_or(5) as usize;
 
+        let resolved = self.resolve_city(city, pincode);
+        let city = resolved.as_deref().unwrap_or(city);
         let locations = filter_locations_json(city, area, pincode, max_results);
 
         let result = json!({
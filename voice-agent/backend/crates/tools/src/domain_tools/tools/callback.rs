@@ -0,0 +1,228 @@
+//! Callback Scheduling Tool
+//!
+//! Turns "call me tomorrow after 6pm" into a stored callback with a concrete
+//! window. Call hours, the default window length and the named parts of the
+//! day come from the `schedule_callback` tool defaults; the outbound dialer
+//! picks the callback up once its window opens.
+
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDateTime};
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+use voice_agent_config::ToolsDomainView;
+use voice_agent_persistence::{Callback, CallbackStore};
+
+use super::super::scheduling::{
+    branch_now, parse_callback_window, parse_visit_date, CallbackHours,
+};
+use crate::mcp::{InputSchema, PropertySchema, Tool, ToolError, ToolOutput, ToolSchema};
+
+/// Spoken form of a window, e.g. "Thu 16 Jan, 6:00 PM to 8:00 PM"
+fn spoken_window(start: NaiveDateTime, end: NaiveDateTime) -> String {
+    format!(
+        "{}, {} to {}",
+        start.format("%a %-d %b"),
+        start.format("%-I:%M %p"),
+        end.format("%-I:%M %p")
+    )
+}
+
+/// Schedule a callback from a human agent within the caller's requested window
+pub struct ScheduleCallbackTool {
+    store: Arc<dyn CallbackStore>,
+    view: Arc<ToolsDomainView>,
+}
+
+impl ScheduleCallbackTool {
+    pub fn new(store: Arc<dyn CallbackStore>, view: Arc<ToolsDomainView>) -> Self {
+        Self { store, view }
+    }
+}
+
+#[async_trait]
+impl Tool for ScheduleCallbackTool {
+    fn name(&self) -> &str {
+        "schedule_callback"
+    }
+
+    fn description(&self) -> &str {
+        "Schedule a callback from our team at the time the customer asks for"
+    }
+
+    fn schema(&self) -> ToolSchema {
+        ToolSchema {
+            name: self.name().to_string(),
+            description: self.description().to_string(),
+            input_schema: InputSchema::object()
+                .property(
+                    "phone",
+                    PropertySchema::string("Customer phone number (10 digits)"),
+                    true,
+                )
+                .property(
+                    "preferred_time",
+                    PropertySchema::string(
+                        "When to call, as the customer said it, e.g. 'tomorrow after 6pm'",
+                    ),
+                    false,
+                )
+                .property(
+                    "preferred_date",
+                    PropertySchema::string("Callback date if given separately"),
+                    false,
+                )
+                .property(
+                    "customer_name",
+                    PropertySchema::string("Customer's name"),
+                    false,
+                )
+                .property(
+                    "session_id",
+                    PropertySchema::string("Conversation session ID"),
+                    false,
+                )
+                .property(
+                    "notes",
+                    PropertySchema::string("What the customer wants to discuss"),
+                    false,
+                ),
+        }
+    }
+
+    async fn execute(&self, input: Value) -> Result<ToolOutput, ToolError> {
+        let tools_config = self.view.tools_config();
+        let phone: String = tools_config
+            .get_string_param_with_aliases(&input, "phone")
+            .ok_or_else(|| ToolError::invalid_params("phone is required"))?
+            .chars()
+            .filter(|c| c.is_ascii_digit())
+            .collect();
+        if phone.len() != 10 {
+            return Err(ToolError::invalid_params("phone must be 10 digits"));
+        }
+
+        // The schema names these preferred_*; "date"/"time" aliases cover slot names
+        let param = |name: &str, generic: &str| {
+            input
+                .get(name)
+                .and_then(|v| v.as_str())
+                .map(String::from)
+                .or_else(|| tools_config.get_string_param_with_aliases(&input, generic))
+        };
+        let now = branch_now(Some(self.view.as_ref()));
+        let date = match param("preferred_date", "date") {
+            Some(text) => Some(parse_visit_date(&text, now.date()).ok_or_else(|| {
+                ToolError::invalid_params(
+                    "preferred_date must be today, tomorrow, a weekday or YYYY-MM-DD",
+                )
+            })?),
+            None => None,
+        };
+        let requested = param("preferred_time", "time");
+        let hours = CallbackHours::from_view(Some(self.view.as_ref()));
+        let (start, end) =
+            parse_callback_window(requested.as_deref().unwrap_or("now"), date, &hours, now)
+                .ok_or_else(|| {
+                    ToolError::invalid_params(format!(
+                        "We can call between {} and {}; please pick a time within those hours",
+                        hours.start.format("%-I:%M %p"),
+                        hours.end.format("%-I:%M %p")
+                    ))
+                })?;
+
+        let offset = Duration::minutes(self.view.appointment_slots().utc_offset_minutes as i64);
+        let mut callback =
+            Callback::new(&phone, (start - offset).and_utc(), (end - offset).and_utc());
+        let text = |key: &str| {
+            input
+                .get(key)
+                .and_then(|v| v.as_str())
+                .filter(|s| !s.trim().is_empty())
+                .map(String::from)
+        };
+        callback.customer_name = text("customer_name");
+        callback.session_id = text("session_id");
+        callback.notes = text("notes");
+        callback.requested_time = requested;
+
+        self.store
+            .create(&callback)
+            .await
+            .map_err(|e| ToolError::internal(format!("Failed to schedule callback: {}", e)))?;
+
+        let window = spoken_window(start, end);
+        let customer_name = callback.customer_name.clone().unwrap_or_default();
+        let fallback = format!(
+            "I've scheduled a callback for you{}. Our team will call you on {}.",
+            if customer_name.is_empty() {
+                String::new()
+            } else {
+                format!(", {}", customer_name)
+            },
+            window
+        );
+        let message = if self.view.has_response_templates(self.name()) {
+            let mut vars = self.view.default_template_vars();
+            vars.insert("customer_name".to_string(), customer_name);
+            vars.insert("callback_window".to_string(), window.clone());
+            self.view
+                .render_response("schedule_callback", "scheduled", "en", &vars)
+                .unwrap_or(fallback)
+        } else {
+            fallback
+        };
+
+        let result = json!({
+            "scheduled": true,
+            "callback_id": callback.callback_id.to_string(),
+            "phone": callback.customer_phone,
+            "window": window,
+            "window_start": start.format("%Y-%m-%d %H:%M").to_string(),
+            "window_end": end.format("%Y-%m-%d %H:%M").to_string(),
+            "window_start_utc": callback.window_start.to_rfc3339(),
+            "window_end_utc": callback.window_end.to_rfc3339(),
+            "status": callback.status.as_str(),
+            "message": message
+        });
+
+        Ok(ToolOutput::json(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain_tools::tools::test_support::{output_json, view_with};
+    use voice_agent_persistence::InMemoryCallbackStore;
+
+    #[tokio::test]
+    async fn test_callback_is_stored_with_window() {
+        let store = Arc::new(InMemoryCallbackStore::new());
+        let view = view_with(|_| {});
+        let tool = ScheduleCallbackTool::new(store.clone(), view);
+
+        let result = output_json(
+            tool.execute(json!({
+                "phone": "98765-43210",
+                "preferred_time": "tomorrow evening",
+                "customer_name": "Asha",
+                "session_id": "call-1"
+            }))
+            .await
+            .unwrap(),
+        );
+        assert_eq!(result["scheduled"], true);
+        assert_eq!(result["status"], "pending");
+        assert!(result["window_start"].as_str().unwrap().ends_with("16:00"));
+        assert!(result["window_end"].as_str().unwrap().ends_with("20:00"));
+
+        let open = store.list_open().await.unwrap();
+        assert_eq!(open.len(), 1);
+        assert_eq!(open[0].customer_phone, "9876543210");
+        assert_eq!(open[0].customer_name.as_deref(), Some("Asha"));
+        assert_eq!(open[0].requested_time.as_deref(), Some("tomorrow evening"));
+
+        assert!(tool.execute(json!({ "phone": "12345" })).await.is_err());
+    }
+}
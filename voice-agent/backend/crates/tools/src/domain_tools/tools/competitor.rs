@@ -2,26 +2,43 @@
 //!
 //! Compare loan offerings with other major lenders.
 //! P21 FIX: Made domain-agnostic (was gold loan specific).
+//!
+//! Rates come from competitors.yaml unless the admin-maintained rate table
+//! has an entry for the lender; table entries older than
+//! `defaults.rate_max_age_days` are flagged in the output.
 
 use async_trait::async_trait;
+use chrono::Utc;
 use serde_json::{json, Value};
+use std::collections::HashMap;
 use std::sync::Arc;
 use voice_agent_config::ToolsDomainView;
+use voice_agent_persistence::{CompetitorRate, CompetitorRateStore};
 
 use crate::mcp::{InputSchema, PropertySchema, Tool, ToolError, ToolOutput, ToolSchema};
 
+/// Lender ID, name, rate, LTV (unknown for table-only lenders) and features
+type LenderRow = (String, String, f64, Option<f64>, Vec<String>);
+
 /// Competitor comparison tool
 ///
 /// P13 FIX: Uses ToolsDomainView instead of GoldLoanConfig
 /// P15 FIX: ToolsDomainView is now REQUIRED - no more hardcoded fallbacks
 pub struct CompetitorComparisonTool {
     view: Arc<ToolsDomainView>,
+    rates: Option<Arc<dyn CompetitorRateStore>>,
 }
 
 impl CompetitorComparisonTool {
     /// Create with required ToolsDomainView - domain config is mandatory
     pub fn new(view: Arc<ToolsDomainView>) -> Self {
-        Self { view }
+        Self { view, rates: None }
+    }
+
+    /// Prefer rates from the competitor rate table over config
+    pub fn with_rate_store(mut self, rates: Arc<dyn CompetitorRateStore>) -> Self {
+        self.rates = Some(rates);
+        self
     }
 
     /// Alias for new() for backwards compatibility during migration
@@ -67,6 +84,23 @@ impl CompetitorComparisonTool {
     fn get_our_features(&self) -> Vec<String> {
         self.view.our_features().to_vec()
     }
+
+    /// Stored rates keyed by lender ID (empty when no table or on error)
+    async fn stored_rates(&self) -> HashMap<String, CompetitorRate> {
+        let Some(ref store) = self.rates else {
+            return HashMap::new();
+        };
+        match store.list(self.view.competitor_rate_product()).await {
+            Ok(rates) => rates
+                .into_iter()
+                .map(|rate| (rate.lender_id.clone(), rate))
+                .collect(),
+            Err(e) => {
+                tracing::warn!("Failed to load competitor rates, using config: {}", e);
+                HashMap::new()
+            },
+        }
+    }
 }
 
 #[async_trait]
@@ -144,7 +178,26 @@ impl Tool for CompetitorComparisonTool {
             .unwrap_or(default_tenure);
 
         // P15 FIX: All values from config, no hardcoded fallbacks
-        let competitors = self.get_competitors();
+        // (lenders only present in the rate table are compared too)
+        let mut stored = self.stored_rates().await;
+        let mut competitors: Vec<LenderRow> = self
+            .get_competitors()
+            .into_iter()
+            .map(|(id, name, rate, ltv, features)| (id, name, rate, Some(ltv), features))
+            .collect();
+        let mut table_only: Vec<LenderRow> = stored
+            .values()
+            .filter(|r| !competitors.iter().any(|(id, ..)| *id == r.lender_id))
+            .map(|r| {
+                let name = r.lender_name.clone();
+                (r.lender_id.clone(), name, r.comparison_rate(), None, vec![])
+            })
+            .collect();
+        table_only.sort_by(|a, b| a.0.cmp(&b.0));
+        competitors.extend(table_only);
+        let max_age_days = self.view.competitor_rate_max_age_days();
+        let now = Utc::now();
+        let mut stale_lenders: Vec<String> = vec![];
         let our_rate = self.get_our_rate();
         let our_ltv = self.get_our_ltv();
         let company_name = self.company_name();
@@ -166,6 +219,28 @@ impl Tool for CompetitorComparisonTool {
         let mut our_advantages: Vec<String> = vec![];
 
         for (id, name, rate, ltv, features) in selected_competitors {
+            let (rate, rate_data) = match stored.remove(&id) {
+                Some(entry) => {
+                    let age_days = entry.age_days(now);
+                    let stale = entry.is_stale(max_age_days, now);
+                    if stale {
+                        stale_lenders.push(format!("{} ({} days old)", name, age_days));
+                    }
+                    (
+                        entry.comparison_rate(),
+                        json!({
+                            "source": "rate_table",
+                            "rate_min": entry.rate_min,
+                            "rate_max": entry.rate_max,
+                            "processing_fee_percent": entry.processing_fee_percent,
+                            "updated_at": entry.updated_at.to_rfc3339(),
+                            "age_days": age_days,
+                            "stale": stale
+                        }),
+                    )
+                },
+                None => (rate, json!({ "source": "config" })),
+            };
             let competitor_monthly = loan_amount * rate / 100.0 / 12.0;
             let competitor_annual = loan_amount * rate / 100.0;
             let monthly_savings = competitor_monthly - our_monthly_interest;
@@ -177,6 +252,7 @@ impl Tool for CompetitorComparisonTool {
                 "interest_rate": rate,
                 "ltv_percent": ltv,
                 "features": features,
+                "rate_data": rate_data,
                 "monthly_interest": competitor_monthly,
                 "annual_interest": competitor_annual,
                 "vs_us": {
@@ -207,7 +283,7 @@ impl Tool for CompetitorComparisonTool {
 
         // P3.2 FIX: Use config-driven currency symbol
         let currency = self.view.currency_symbol();
-        let summary = format!(
+        let mut summary = format!(
             "For a loan of {}{:.0}, {} offers {}% p.a. with monthly interest of {}{:.0}. {}",
             currency,
             loan_amount,
@@ -222,6 +298,18 @@ impl Tool for CompetitorComparisonTool {
             }
         );
 
+        // Outdated competitor figures must not be quoted as current
+        let mut warnings: Vec<String> = vec![];
+        if !stale_lenders.is_empty() {
+            let warning = format!(
+                "Competitor rates for {} were last updated more than {} days ago and may have changed.",
+                stale_lenders.join(", "),
+                max_age_days
+            );
+            summary = format!("{} Note: {}", summary, warning);
+            warnings.push(warning);
+        }
+
         let result = json!({
             "comparison_for": {
                 "loan_amount": loan_amount,
@@ -237,9 +325,77 @@ impl Tool for CompetitorComparisonTool {
             },
             "competitors": comparisons,
             "our_advantages": our_advantages,
+            "warnings": warnings,
             "summary": summary
         });
 
         Ok(ToolOutput::json(result))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain_tools::tools::test_support::{output_json, view_with};
+    use chrono::Duration;
+    use voice_agent_persistence::InMemoryCompetitorRateStore;
+
+    fn view() -> Arc<ToolsDomainView> {
+        view_with(|config| {
+            config.competitors_config = serde_json::from_value(json!({
+                "competitors": {
+                    "muthoot": {
+                        "display_name": "Muthoot Finance",
+                        "typical_rate": 12.0,
+                        "ltv_percent": 75.0
+                    }
+                },
+                "defaults": { "rate_max_age_days": 30, "rate_product": "gold_loan" }
+            }))
+            .unwrap();
+        })
+    }
+
+    #[tokio::test]
+    async fn test_rate_table_overrides_config_and_flags_stale_rates() {
+        let store = Arc::new(InMemoryCompetitorRateStore::new());
+        let mut stale = CompetitorRate::new("muthoot", "Muthoot Finance", "gold_loan", 11.0, 24.0)
+            .with_typical_rate(14.0);
+        stale.updated_at = Utc::now() - Duration::days(45);
+        store.upsert(&stale).await.unwrap();
+        let rupeek = CompetitorRate::new("rupeek", "Rupeek", "gold_loan", 9.0, 19.0);
+        store.upsert(&rupeek).await.unwrap();
+
+        let tool = CompetitorComparisonTool::new(view()).with_rate_store(store);
+        let result = output_json(tool.execute(json!({})).await.unwrap());
+
+        let competitors = result["competitors"].as_array().unwrap();
+        assert_eq!(competitors.len(), 2);
+        let muthoot = &competitors[0];
+        assert_eq!(muthoot["interest_rate"], 14.0);
+        assert_eq!(muthoot["rate_data"]["source"], "rate_table");
+        assert_eq!(muthoot["rate_data"]["stale"], true);
+        let rupeek = &competitors[1];
+        assert_eq!(rupeek["lender_id"], "rupeek");
+        assert_eq!(rupeek["interest_rate"], 14.0);
+        assert_eq!(rupeek["rate_data"]["stale"], false);
+
+        assert_eq!(result["warnings"].as_array().unwrap().len(), 1);
+        assert!(result["summary"]
+            .as_str()
+            .unwrap()
+            .contains("Muthoot Finance (45 days old)"));
+    }
+
+    #[tokio::test]
+    async fn test_config_rates_without_table() {
+        let tool = CompetitorComparisonTool::new(view());
+        let input = json!({ "competitor": "muthoot" });
+        let result = output_json(tool.execute(input).await.unwrap());
+
+        let muthoot = &result["competitors"][0];
+        assert_eq!(muthoot["interest_rate"], 12.0);
+        assert_eq!(muthoot["rate_data"]["source"], "config");
+        assert!(result["warnings"].as_array().unwrap().is_empty());
+    }
+}
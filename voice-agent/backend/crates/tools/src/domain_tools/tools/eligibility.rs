@@ -3,6 +3,7 @@
 //! Check customer eligibility based on collateral weight and variant.
 //! All schema content (names, descriptions, parameters) comes from YAML config.
 //! Domain-specific parameter names (e.g., "gold_weight_grams") should be defined in config.
+//! LTV, rate and processing fee come from the rate card in force (`RateCardEngine`).
 
 use async_trait::async_trait;
 use serde_json::{json, Value};
@@ -10,6 +11,7 @@ use std::sync::Arc;
 use voice_agent_config::ToolsDomainView;
 
 use crate::mcp::{InputSchema, PropertySchema, Tool, ToolError, ToolOutput, ToolSchema};
+use crate::rate_cards::RateCardEngine;
 
 /// Tool name as defined in config - used to look up schema
 const TOOL_NAME: &str = "check_eligibility";
@@ -20,12 +22,14 @@ const TOOL_NAME: &str = "check_eligibility";
 /// P15 FIX: ToolsDomainView is now REQUIRED - no more hardcoded fallbacks
 pub struct EligibilityCheckTool {
     view: Arc<ToolsDomainView>,
+    rates: Arc<RateCardEngine>,
 }
 
 impl EligibilityCheckTool {
     /// Create with required ToolsDomainView - domain config is mandatory
     pub fn new(view: Arc<ToolsDomainView>) -> Self {
-        Self { view }
+        let rates = Arc::new(RateCardEngine::new(view.clone()));
+        Self { view, rates }
     }
 
     /// Alias for new() for backwards compatibility during migration
@@ -33,29 +37,23 @@ impl EligibilityCheckTool {
         Self::new(view)
     }
 
-    fn get_rate(&self, amount: f64) -> f64 {
-        self.view.get_rate_for_amount(amount)
-    }
-
-    fn get_ltv(&self) -> f64 {
-        self.view.ltv_percent()
+    /// Use published rate cards as well as those in config
+    pub fn with_rate_cards(mut self, rates: Arc<RateCardEngine>) -> Self {
+        self.rates = rates;
+        self
     }
 
     fn get_min_loan(&self) -> f64 {
         self.view.min_loan_amount()
     }
 
-    fn get_processing_fee(&self) -> f64 {
-        self.view.processing_fee_percent()
-    }
-
     fn calculate_collateral_value(&self, weight: f64, variant: &str) -> f64 {
         // P20 FIX: Uses domain-agnostic method from config
         self.view.calculate_asset_value(weight, variant)
     }
 
-    fn calculate_max_loan(&self, collateral_value: f64) -> f64 {
-        self.view.calculate_max_loan(collateral_value)
+    fn calculate_max_loan(&self, collateral_value: f64, variant: &str) -> f64 {
+        self.rates.max_loan(collateral_value, Some(variant))
     }
 }
 
@@ -123,13 +121,26 @@ impl Tool for EligibilityCheckTool {
             .and_then(|v| v.as_f64())
             .unwrap_or(0.0);
 
+        let tenure_months = input
+            .get("tenure_months")
+            .and_then(|v| v.as_u64())
+            .map(|t| t as u32);
+
         // Calculate eligibility using domain config
         let collateral_value = self.calculate_collateral_value(weight, variant);
-        let max_loan = self.calculate_max_loan(collateral_value);
+        let max_loan = self.calculate_max_loan(collateral_value, variant);
         let available_loan = max_loan - existing_loan;
 
-        // Use tiered interest rates based on loan amount
-        let interest_rate = self.get_rate(available_loan.max(0.0));
+        // Rate from the rate card slab for the loan amount (and tenure, if given)
+        let quote = self
+            .rates
+            .quote(available_loan.max(0.0), tenure_months, Some(variant));
+        let interest_rate = quote.rate_percent;
+        let rate_tier = if quote.slab.is_empty() {
+            self.view.get_rate_tier_name(available_loan).to_string()
+        } else {
+            quote.slab.clone()
+        };
         let min_loan = self.get_min_loan();
 
         // P16 FIX: Use config-driven response templates
@@ -142,7 +153,7 @@ impl Tool for EligibilityCheckTool {
                 vars.insert("max_amount".to_string(), format!("{:.0}", available_loan));
                 vars.insert("interest_rate".to_string(), format!("{:.1}", interest_rate));
                 vars.insert("rate_description".to_string(),
-                    self.view.get_rate_description(self.view.get_rate_tier_name(available_loan)).to_string());
+                    self.view.get_rate_description(&rate_tier).to_string());
                 // P18 FIX: Use config-driven product name instead of hardcoded "gold"
                 vars.insert("collateral_type".to_string(), self.view.product_name().to_string());
                 vars.insert("currency".to_string(), currency.to_string());
@@ -190,10 +201,11 @@ impl Tool for EligibilityCheckTool {
             format!("max_loan_amount_{}", suffix): max_loan.round(),
             format!("existing_loan_{}", suffix): existing_loan,
             format!("available_loan_{}", suffix): available_loan.max(0.0).round(),
-            "ltv_percent": self.get_ltv(),
+            "ltv_percent": quote.ltv_percent,
             "interest_rate_percent": interest_rate,
-            "processing_fee_percent": self.get_processing_fee(),
-            "rate_tier": self.view.get_rate_tier_name(available_loan),
+            "processing_fee_percent": quote.processing_fee_percent,
+            "rate_tier": rate_tier,
+            "rate_card_version": quote.card_version,
             "message": message
         });
 
@@ -1,22 +1,39 @@
 //! Human Escalation Tool
 //!
-//! Escalate the conversation to a human agent.
+//! Escalate the conversation to a human agent. With a handoff queue
+//! configured, the session context the agent attaches (`handoff_context`)
+//! is packaged and pushed to the queue for a warm transfer.
 
 use async_trait::async_trait;
 use chrono::Utc;
 use serde_json::{json, Value};
 use std::sync::Arc;
 
+use voice_agent_config::HandoffConfig;
+
+use crate::handoff::{HandoffContext, HandoffQueue, HandoffRequest};
 use crate::mcp::{InputSchema, PropertySchema, Tool, ToolError, ToolOutput, ToolSchema};
 
+/// Registered name of the human escalation tool
+pub const ESCALATION_TOOL: &str = "escalate_to_human";
+
 /// Human escalation tool
 pub struct EscalateToHumanTool {
     on_escalate: Option<Arc<dyn Fn(String, String, String) + Send + Sync>>,
+    handoff: Option<Arc<dyn HandoffQueue>>,
+    recent_turns: usize,
+    recording_url_template: Option<String>,
 }
 
 impl EscalateToHumanTool {
     pub fn new() -> Self {
-        Self { on_escalate: None }
+        let config = HandoffConfig::default();
+        Self {
+            on_escalate: None,
+            handoff: None,
+            recent_turns: config.recent_turns,
+            recording_url_template: None,
+        }
     }
 
     pub fn with_callback<F>(callback: F) -> Self
@@ -25,14 +42,27 @@ impl EscalateToHumanTool {
     {
         Self {
             on_escalate: Some(Arc::new(callback)),
+            ..Self::new()
         }
     }
+
+    /// Push escalations to a handoff queue, packaged per `config`
+    pub fn with_handoff_queue(
+        mut self,
+        queue: Arc<dyn HandoffQueue>,
+        config: &HandoffConfig,
+    ) -> Self {
+        self.handoff = Some(queue);
+        self.recent_turns = config.recent_turns;
+        self.recording_url_template = config.recording_url_template.clone();
+        self
+    }
 }
 
 #[async_trait]
 impl Tool for EscalateToHumanTool {
     fn name(&self) -> &str {
-        "escalate_to_human"
+        ESCALATION_TOOL
     }
 
     fn description(&self) -> &str {
@@ -116,6 +146,35 @@ impl Tool for EscalateToHumanTool {
             uuid::Uuid::new_v4().to_string()[..8].to_uppercase()
         );
 
+        let mut context = HandoffContext::from_arguments(&input);
+        context.truncate_turns(self.recent_turns);
+        if let Some(ref template) = self.recording_url_template {
+            context.audio_url = Some(template.replace("{session_id}", session_id));
+        }
+        let turns_shared = context.recent_turns.len();
+        if let Some(ref queue) = self.handoff {
+            let request = HandoffRequest {
+                handoff_id: escalation_id.clone(),
+                session_id: session_id.to_string(),
+                reason: reason.to_string(),
+                priority: priority.to_string(),
+                customer_phone: input
+                    .get("customer_phone")
+                    .and_then(|v| v.as_str())
+                    .map(String::from),
+                summary: input
+                    .get("summary")
+                    .and_then(|v| v.as_str())
+                    .map(String::from),
+                context,
+                created_at: Utc::now(),
+            };
+            queue.push(&request).await.map_err(|e| {
+                tracing::error!(escalation_id = %escalation_id, error = %e, "Handoff push failed");
+                ToolError::from(e)
+            })?;
+        }
+
         let estimated_wait = match priority {
             "urgent" => "1-2 minutes",
             "high" => "2-5 minutes",
@@ -149,6 +208,9 @@ impl Tool for EscalateToHumanTool {
             "status": "queued",
             "estimated_wait": estimated_wait,
             "queue_position": 1,
+            "handoff_id": escalation_id,
+            "transfer": if self.handoff.is_some() { "warm" } else { "none" },
+            "context_turns": turns_shared,
             "created_at": Utc::now().to_rfc3339(),
             "message": format!(
                 "Your request has been escalated to a human agent. Escalation ID: {}. Estimated wait time: {}. Please hold.",
@@ -170,3 +232,56 @@ impl Default for EscalateToHumanTool {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::IntegrationError;
+    use parking_lot::Mutex;
+
+    #[derive(Default)]
+    struct RecordingQueue {
+        pushed: Mutex<Vec<HandoffRequest>>,
+    }
+
+    #[async_trait]
+    impl HandoffQueue for RecordingQueue {
+        async fn push(&self, request: &HandoffRequest) -> Result<(), IntegrationError> {
+            self.pushed.lock().push(request.clone());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_escalation_pushes_packaged_context() {
+        let queue = Arc::new(RecordingQueue::default());
+        let config = HandoffConfig {
+            recent_turns: 1,
+            recording_url_template: Some("s3://calls/{session_id}.wav".to_string()),
+            ..HandoffConfig::default()
+        };
+        let tool = EscalateToHumanTool::new().with_handoff_queue(queue.clone(), &config);
+
+        tool.execute(json!({
+            "reason": "customer_request",
+            "session_id": "call-9",
+            "handoff_context": {
+                "recent_turns": [
+                    { "role": "assistant", "content": "How can I help?" },
+                    { "role": "user", "content": "Let me talk to a person" }
+                ],
+                "lead_score": 64
+            }
+        }))
+        .await
+        .unwrap();
+
+        let pushed = queue.pushed.lock();
+        assert_eq!(pushed.len(), 1);
+        let context = &pushed[0].context;
+        assert_eq!(context.recent_turns.len(), 1);
+        assert_eq!(context.recent_turns[0].role, "user");
+        assert_eq!(context.lead_score, Some(64));
+        assert_eq!(context.audio_url.as_deref(), Some("s3://calls/call-9.wav"));
+    }
+}
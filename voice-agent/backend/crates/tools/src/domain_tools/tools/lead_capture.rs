@@ -73,6 +73,16 @@ impl Tool for LeadCaptureTool {
                     "notes",
                     PropertySchema::string("Additional notes from conversation"),
                     false,
+                )
+                .property(
+                    "lead_score",
+                    PropertySchema::number("Lead score (0-100) from the conversation"),
+                    false,
+                )
+                .property(
+                    "lead_qualification",
+                    PropertySchema::string("Lead qualification level (Cold/Warm/Hot/Qualified)"),
+                    false,
                 ),
         }
     }
@@ -103,6 +113,15 @@ impl Tool for LeadCaptureTool {
             .and_then(|v| v.as_str())
             .unwrap_or("Medium");
 
+        let lead_score = input
+            .get("lead_score")
+            .and_then(|v| v.as_f64())
+            .map(|v| v.clamp(0.0, 100.0).round() as u32);
+        let lead_qualification = input
+            .get("lead_qualification")
+            .and_then(|v| v.as_str())
+            .map(String::from);
+
         let interest_level = match interest_str.to_lowercase().as_str() {
             "high" => InterestLevel::High,
             "low" => InterestLevel::Low,
@@ -123,6 +142,8 @@ impl Tool for LeadCaptureTool {
                 notes,
                 assigned_to: None,
                 status: LeadStatus::New,
+                lead_score,
+                lead_qualification: lead_qualification.clone(),
             };
 
             match crm.create_lead(lead).await {
@@ -135,6 +156,8 @@ impl Tool for LeadCaptureTool {
                         "city": input.get("city").and_then(|v| v.as_str()),
                         "interest_level": interest_str,
                         "estimated_value": estimated_value,
+                        "lead_score": lead_score,
+                        "lead_qualification": lead_qualification,
                         "created_at": Utc::now().to_rfc3339(),
                         "crm_integrated": true,
                         "message": format!("Lead captured successfully! A representative will contact {} shortly.", name)
@@ -159,6 +182,8 @@ impl Tool for LeadCaptureTool {
             "estimated_value": estimated_value,
             "interest_level": interest_str,
             "notes": input.get("notes").and_then(|v| v.as_str()),
+            "lead_score": lead_score,
+            "lead_qualification": lead_qualification,
             "created_at": Utc::now().to_rfc3339(),
             "crm_integrated": false,
             "message": format!("Lead captured successfully! A representative will contact {} shortly.", name)
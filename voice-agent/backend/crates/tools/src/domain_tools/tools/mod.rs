@@ -7,25 +7,64 @@
 
 mod appointment;
 mod branch_locator;
+mod callback;
 mod competitor;
 mod document_checklist;
 mod eligibility;
 mod escalate;
 mod lead_capture;
+mod offers;
 mod price;
+mod price_alert;
 mod savings;
+mod slots;
 mod sms;
+mod top_up;
 
 // Re-export all tools
 pub use appointment::AppointmentSchedulerTool;
 pub use branch_locator::BranchLocatorTool;
+pub use callback::ScheduleCallbackTool;
 pub use competitor::CompetitorComparisonTool;
 pub use document_checklist::DocumentChecklistTool;
 pub use eligibility::EligibilityCheckTool;
-pub use escalate::EscalateToHumanTool;
+pub use escalate::{EscalateToHumanTool, ESCALATION_TOOL};
 pub use lead_capture::LeadCaptureTool;
+pub use offers::{CheckOffersTool, OfferResponseTool, OFFER_TOOLS};
 pub use price::GetPriceTool;
+pub(crate) use price::{describe_trend, DEFAULT_TREND_DAYS};
 /// Legacy alias for backwards compatibility
 pub type GetGoldPriceTool = GetPriceTool;
+pub use price_alert::PriceAlertTool;
 pub use savings::SavingsCalculatorTool;
+pub use slots::{SlotAvailabilityTool, SlotHoldTool};
 pub use sms::SendSmsTool;
+pub use top_up::TopUpEligibilityTool;
+
+#[cfg(test)]
+mod test_support {
+    //! Helpers shared by the tool tests
+
+    use serde_json::Value;
+    use std::sync::Arc;
+    use voice_agent_config::{MasterDomainConfig, ToolsDomainView};
+
+    use crate::mcp::{ContentBlock, ToolOutput};
+
+    /// Tools view over the default domain config, adjusted by `configure`
+    pub(super) fn view_with(
+        configure: impl FnOnce(&mut MasterDomainConfig),
+    ) -> Arc<ToolsDomainView> {
+        let mut config = MasterDomainConfig::default();
+        configure(&mut config);
+        Arc::new(ToolsDomainView::new(Arc::new(config)))
+    }
+
+    /// JSON a tool returned as text
+    pub(super) fn output_json(output: ToolOutput) -> Value {
+        match output.content.first() {
+            Some(ContentBlock::Text { text }) => serde_json::from_str(text).unwrap(),
+            _ => panic!("expected text output"),
+        }
+    }
+}
@@ -0,0 +1,374 @@
+//! Promotional Offer Tools
+//!
+//! `check_offers` finds the running promotions the customer qualifies for,
+//! from what is known so far (new customer, current lender, loan amount,
+//! city). Offers it returns are the ones the agent presents, so each is
+//! recorded as mentioned for the session. Offers that might apply but need
+//! a fact the agent has not collected are listed separately, with the facts
+//! to ask about. `record_offer_response` records whether the customer took
+//! an offer up.
+
+use async_trait::async_trait;
+use chrono::Utc;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+use voice_agent_config::{Offer, OfferCheck, OfferContext, ToolsDomainView};
+use voice_agent_persistence::{OfferEvent, OfferEventKind, OfferEventStore};
+
+use crate::mcp::{InputSchema, PropertySchema, Tool, ToolError, ToolOutput, ToolSchema};
+
+/// Registered name of the offer lookup tool
+pub const CHECK_OFFERS_TOOL: &str = "check_offers";
+
+/// Registered name of the offer response tool
+pub const OFFER_RESPONSE_TOOL: &str = "record_offer_response";
+
+/// Tools that record offer events against the calling session
+pub const OFFER_TOOLS: [&str; 2] = [CHECK_OFFERS_TOOL, OFFER_RESPONSE_TOOL];
+
+/// Non-empty string input
+fn text(input: &Value, key: &str) -> Option<String> {
+    input
+        .get(key)
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+}
+
+fn offer_json(offer: &Offer) -> Value {
+    json!({
+        "offer_id": offer.id,
+        "name": offer.name,
+        "pitch": offer.pitch,
+        "terms": offer.terms,
+        "rate_discount": offer.rate_discount,
+        "processing_fee_waived": offer.processing_fee_waived,
+        "valid_until": offer.valid_until,
+    })
+}
+
+/// Find the promotional offers a customer qualifies for
+pub struct CheckOffersTool {
+    view: Arc<ToolsDomainView>,
+    events: Option<Arc<dyn OfferEventStore>>,
+}
+
+impl CheckOffersTool {
+    pub fn new(view: Arc<ToolsDomainView>) -> Self {
+        Self { view, events: None }
+    }
+
+    /// Record offers presented to each session
+    pub fn with_event_store(mut self, events: Arc<dyn OfferEventStore>) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    fn context(input: &Value) -> OfferContext {
+        OfferContext {
+            new_customer: input.get("new_customer").and_then(|v| v.as_bool()),
+            current_lender: text(input, "current_lender"),
+            loan_amount: input
+                .get("loan_amount")
+                .and_then(|v| v.as_f64())
+                .filter(|a| *a > 0.0),
+            city: text(input, "city"),
+        }
+    }
+}
+
+#[async_trait]
+impl Tool for CheckOffersTool {
+    fn name(&self) -> &str {
+        CHECK_OFFERS_TOOL
+    }
+
+    fn description(&self) -> &str {
+        "Find running promotional offers the customer qualifies for"
+    }
+
+    fn schema(&self) -> ToolSchema {
+        ToolSchema {
+            name: self.name().to_string(),
+            description: self.description().to_string(),
+            input_schema: InputSchema::object()
+                .property(
+                    "new_customer",
+                    PropertySchema::boolean("Whether the customer is new to us"),
+                    false,
+                )
+                .property(
+                    "current_lender",
+                    PropertySchema::string("Lender the customer would transfer the loan from"),
+                    false,
+                )
+                .property(
+                    "loan_amount",
+                    PropertySchema::number("Loan amount the customer wants"),
+                    false,
+                )
+                .property("city", PropertySchema::string("Customer's city"), false)
+                .property(
+                    "session_id",
+                    PropertySchema::string("Conversation session ID"),
+                    false,
+                ),
+        }
+    }
+
+    async fn execute(&self, input: Value) -> Result<ToolOutput, ToolError> {
+        let context = Self::context(&input);
+        let today = Utc::now().date_naive();
+        let max_presented = self.view.offers_config().max_presented.max(1);
+
+        let mut eligible = Vec::new();
+        let mut to_confirm = Vec::new();
+        for (offer, check) in self.view.offers_for(&context, today) {
+            match check {
+                OfferCheck::Eligible if eligible.len() < max_presented => eligible.push(offer),
+                OfferCheck::Undecided(missing) => to_confirm.push(json!({
+                    "offer_id": offer.id,
+                    "name": offer.name,
+                    "ask_about": missing,
+                })),
+                _ => {},
+            }
+        }
+
+        if let (Some(events), Some(session_id)) = (&self.events, text(&input, "session_id")) {
+            for offer in &eligible {
+                let event = OfferEvent::new(&session_id, &offer.id, OfferEventKind::Mentioned);
+                if let Err(e) = events.record(&event).await {
+                    tracing::warn!(offer = %offer.id, error = %e, "Failed to record offer mention");
+                }
+            }
+        }
+
+        let fallback = match eligible.as_slice() {
+            [] => "There are no special offers running for you right now.".to_string(),
+            offers => offers
+                .iter()
+                .map(|o| format!("{}: {}", o.name, o.pitch))
+                .collect::<Vec<_>>()
+                .join(" "),
+        };
+        let message = if self.view.has_response_templates(self.name()) {
+            let mut vars = self.view.default_template_vars();
+            let key = match eligible.first() {
+                Some(offer) => {
+                    vars.insert("offer_name".to_string(), offer.name.clone());
+                    vars.insert("offer_pitch".to_string(), offer.pitch.clone());
+                    "eligible"
+                },
+                None => "none",
+            };
+            self.view
+                .render_response("check_offers", key, "en", &vars)
+                .unwrap_or(fallback)
+        } else {
+            fallback
+        };
+
+        let offers: Vec<Value> = eligible.iter().copied().map(offer_json).collect();
+        Ok(ToolOutput::json(json!({
+            "eligible": !offers.is_empty(),
+            "offers": offers,
+            "to_confirm": to_confirm,
+            "message": message
+        })))
+    }
+}
+
+/// Record whether the customer accepted or declined an offer
+pub struct OfferResponseTool {
+    view: Arc<ToolsDomainView>,
+    events: Arc<dyn OfferEventStore>,
+}
+
+impl OfferResponseTool {
+    pub fn new(view: Arc<ToolsDomainView>, events: Arc<dyn OfferEventStore>) -> Self {
+        Self { view, events }
+    }
+}
+
+#[async_trait]
+impl Tool for OfferResponseTool {
+    fn name(&self) -> &str {
+        OFFER_RESPONSE_TOOL
+    }
+
+    fn description(&self) -> &str {
+        "Record whether the customer accepted or declined a promotional offer"
+    }
+
+    fn schema(&self) -> ToolSchema {
+        ToolSchema {
+            name: self.name().to_string(),
+            description: self.description().to_string(),
+            input_schema: InputSchema::object()
+                .property(
+                    "offer_id",
+                    PropertySchema::string("ID of the offer, as returned by check_offers"),
+                    true,
+                )
+                .property(
+                    "accepted",
+                    PropertySchema::boolean("Whether the customer accepted the offer"),
+                    true,
+                )
+                .property(
+                    "session_id",
+                    PropertySchema::string("Conversation session ID"),
+                    true,
+                ),
+        }
+    }
+
+    async fn execute(&self, input: Value) -> Result<ToolOutput, ToolError> {
+        let session_id = text(&input, "session_id")
+            .ok_or_else(|| ToolError::invalid_params("session_id is required"))?;
+        let offer_id = text(&input, "offer_id")
+            .ok_or_else(|| ToolError::invalid_params("offer_id is required"))?;
+        let accepted = input
+            .get("accepted")
+            .and_then(|v| v.as_bool())
+            .ok_or_else(|| ToolError::invalid_params("accepted must be true or false"))?;
+        let offer = self
+            .view
+            .get_offer(&offer_id)
+            .ok_or_else(|| ToolError::invalid_params(format!("Unknown offer '{}'", offer_id)))?;
+
+        let kind = if accepted {
+            OfferEventKind::Accepted
+        } else {
+            OfferEventKind::Declined
+        };
+        let recorded = self
+            .events
+            .record(&OfferEvent::new(&session_id, &offer.id, kind))
+            .await
+            .map_err(|e| ToolError::internal(format!("Failed to record offer response: {}", e)))?;
+
+        let mut result = json!({
+            "recorded": recorded,
+            "offer_id": offer.id,
+            "response": kind.as_str(),
+        });
+        if accepted {
+            result["offer"] = offer_json(offer);
+        }
+
+        Ok(ToolOutput::json(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain_tools::tools::test_support::{output_json, view_with};
+    use chrono::Duration;
+    use voice_agent_config::OfferEligibility;
+    use voice_agent_persistence::InMemoryOfferEventStore;
+
+    fn view() -> Arc<ToolsDomainView> {
+        let today = Utc::now().date_naive();
+        let offer = |id: &str, priority, eligibility| Offer {
+            id: id.to_string(),
+            name: id.to_string(),
+            pitch: format!("{} pitch", id),
+            terms: None,
+            rate_discount: Some(0.5),
+            processing_fee_waived: false,
+            valid_from: today - Duration::days(1),
+            valid_until: None,
+            enabled: true,
+            priority,
+            eligibility,
+        };
+        view_with(|config| {
+            config.offers.offers = vec![
+                offer(
+                    "switch",
+                    10,
+                    OfferEligibility {
+                        balance_transfer_from: vec!["muthoot".to_string()],
+                        ..Default::default()
+                    },
+                ),
+                offer(
+                    "first_loan",
+                    5,
+                    OfferEligibility {
+                        new_customers_only: true,
+                        min_amount: Some(100_000.0),
+                        ..Default::default()
+                    },
+                ),
+            ];
+        })
+    }
+
+    #[tokio::test]
+    async fn test_check_offers_records_mentions() {
+        let events = Arc::new(InMemoryOfferEventStore::new());
+        let tool = CheckOffersTool::new(view()).with_event_store(events.clone());
+
+        let result = output_json(
+            tool.execute(json!({ "loan_amount": 200000, "session_id": "s1" }))
+                .await
+                .unwrap(),
+        );
+        assert_eq!(result["eligible"], false);
+        let to_confirm: Vec<&str> = result["to_confirm"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|o| o["offer_id"].as_str().unwrap())
+            .collect();
+        assert_eq!(to_confirm, vec!["switch", "first_loan"]);
+        assert_eq!(
+            result["to_confirm"][1]["ask_about"],
+            json!(["new_customer"])
+        );
+
+        let result = output_json(
+            tool.execute(json!({
+                "new_customer": true,
+                "current_lender": "muthoot",
+                "loan_amount": 200000,
+                "session_id": "s1"
+            }))
+            .await
+            .unwrap(),
+        );
+        assert_eq!(result["offers"][0]["offer_id"], "switch");
+        assert_eq!(result["offers"].as_array().unwrap().len(), 2);
+
+        let recorded = events.session_events("s1").await.unwrap();
+        assert_eq!(recorded.len(), 2);
+        assert!(recorded.iter().all(|e| e.kind == OfferEventKind::Mentioned));
+    }
+
+    #[tokio::test]
+    async fn test_offer_response_is_recorded() {
+        let events = Arc::new(InMemoryOfferEventStore::new());
+        let tool = OfferResponseTool::new(view(), events.clone());
+
+        let result = output_json(
+            tool.execute(json!({ "offer_id": "switch", "accepted": true, "session_id": "s1" }))
+                .await
+                .unwrap(),
+        );
+        assert_eq!(result["recorded"], true);
+        assert_eq!(result["response"], "accepted");
+        assert_eq!(result["offer"]["rate_discount"], 0.5);
+
+        assert!(tool
+            .execute(json!({ "offer_id": "nope", "accepted": true, "session_id": "s1" }))
+            .await
+            .is_err());
+        assert_eq!(events.session_events("s1").await.unwrap().len(), 1);
+    }
+}
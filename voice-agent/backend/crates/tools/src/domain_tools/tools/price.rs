@@ -1,6 +1,7 @@
 //! Price Tool
 //!
-//! Get current asset prices per gram for different purities/variants.
+//! Get current asset prices per gram for different purities/variants,
+//! with the recent trend when the price service keeps history.
 //! All schema content (names, descriptions, parameters) comes from YAML config.
 
 use async_trait::async_trait;
@@ -8,6 +9,7 @@ use chrono::Utc;
 use serde_json::{json, Value};
 use std::sync::Arc;
 use voice_agent_config::ToolsDomainView;
+use voice_agent_persistence::PriceTrend;
 
 use crate::mcp::{InputSchema, PropertySchema, Tool, ToolError, ToolOutput, ToolSchema};
 
@@ -15,6 +17,30 @@ use crate::mcp::{InputSchema, PropertySchema, Tool, ToolError, ToolOutput, ToolS
 /// Note: Must match the key in schemas.yaml for this domain
 const TOOL_NAME: &str = "get_price";
 
+/// Trend window when none is asked for ("has gold gone up this week?")
+pub(crate) const DEFAULT_TREND_DAYS: u32 = 7;
+const MAX_TREND_DAYS: u64 = 90;
+/// Moves within this percentage are described as steady
+const FLAT_TREND_PERCENT: f64 = 0.25;
+
+/// Short trend description for messages, e.g. "up 1.2% this week"
+pub(crate) fn describe_trend(trend: &PriceTrend, days: u32) -> String {
+    let period = match days {
+        1 => "since yesterday".to_string(),
+        7 => "this week".to_string(),
+        n => format!("over the last {} days", n),
+    };
+    match trend.direction(FLAT_TREND_PERCENT) {
+        "flat" => format!("steady {}", period),
+        direction => format!(
+            "{} {:.1}% {}",
+            direction,
+            trend.change_percent.abs(),
+            period
+        ),
+    }
+}
+
 /// Get asset price tool (generic name for domain-agnostic code)
 ///
 /// P15 FIX: ToolsDomainView is now REQUIRED - no more hardcoded fallbacks
@@ -105,6 +131,11 @@ impl Tool for GetPriceTool {
                         "weight_grams",
                         PropertySchema::number("Weight to calculate total value"),
                         false,
+                    )
+                    .property(
+                        "trend_days",
+                        PropertySchema::integer("Days to compare the price against"),
+                        false,
                     ),
             }
         }
@@ -125,6 +156,12 @@ impl Tool for GetPriceTool {
             .get_numeric_param_with_aliases(&input, "collateral_weight")
             .or_else(|| input.get("weight_grams").and_then(|v| v.as_f64()));
 
+        let trend_days = input
+            .get("trend_days")
+            .and_then(|v| v.as_u64())
+            .map(|d| d.clamp(1, MAX_TREND_DAYS) as u32)
+            .unwrap_or(DEFAULT_TREND_DAYS);
+
         // P20 FIX: Get quality tiers from config dynamically
         let tiers = self.view.quality_tiers_full();
         let base_price = self.fallback_base_price();
@@ -217,6 +254,38 @@ impl Tool for GetPriceTool {
 
         // P20 FIX: Build message dynamically from config tiers
         let default_tier = self.view.default_quality_tier_display();
+
+        // Trend for the requested tier, or the default one
+        let trend_tier = purity
+            .filter(|p| tier_prices.contains_key(*p))
+            .unwrap_or(default_tier.as_str());
+        let trend = match self.price_service {
+            Some(ref service) => match service.get_trend(trend_days, Some(trend_tier)).await {
+                Ok(trend) => trend,
+                Err(e) => {
+                    tracing::warn!("Failed to get price trend from service: {}", e);
+                    None
+                },
+            },
+            None => None,
+        };
+        let trend_summary = trend.as_ref().map(|t| describe_trend(t, trend_days));
+        if let (Some(t), Some(summary)) = (&trend, &trend_summary) {
+            result["trend"] = json!({
+                "purity": trend_tier,
+                "days": trend_days,
+                "direction": t.direction(FLAT_TREND_PERCENT),
+                "change": t.change.round(),
+                "change_percent": (t.change_percent * 100.0).round() / 100.0,
+                "start_price": t.start_price.round(),
+                "end_price": t.end_price.round(),
+                "high": t.high.round(),
+                "low": t.low.round(),
+                "volatility_percent": (t.volatility_percent * 100.0).round() / 100.0,
+                "summary": summary
+            });
+        }
+
         if let Some(p) = purity {
             let price = tier_prices
                 .get(p)
@@ -230,33 +299,38 @@ impl Tool for GetPriceTool {
             // P20 FIX: Use config template keys (single_variant) with correct variable names
             // P3.2 FIX: Use config-driven currency symbol
             let currency = self.view.currency_symbol();
+            // The trend only describes this purity if it was found
+            let trend_summary = trend_summary.filter(|_| trend_tier == p);
+            let fallback = format!(
+                "Current {} {} price is {}{:.0} per {}{}.",
+                p,
+                self.view.product_name(),
+                currency,
+                price,
+                self.view.asset_unit(),
+                trend_summary
+                    .as_ref()
+                    .map(|summary| format!(", {}", summary))
+                    .unwrap_or_default()
+            );
             let message = if self.view.has_response_templates("get_price") {
                 let mut vars = self.view.default_template_vars();
                 vars.insert("variant_name".to_string(), p.to_string());
                 vars.insert("price".to_string(), format!("{:.0}", price));
                 vars.insert("unit".to_string(), self.view.asset_unit().to_string());
                 vars.insert("currency".to_string(), currency.to_string());
+                let key = match trend_summary {
+                    Some(summary) => {
+                        vars.insert("trend".to_string(), summary);
+                        "price_trend"
+                    },
+                    None => "single_variant",
+                };
                 self.view
-                    .render_response("get_price", "single_variant", "en", &vars)
-                    .unwrap_or_else(|| {
-                        format!(
-                            "Current {} {} price is {}{:.0} per {}.",
-                            p,
-                            self.view.product_name(),
-                            currency,
-                            price,
-                            self.view.asset_unit()
-                        )
-                    })
+                    .render_response("get_price", key, "en", &vars)
+                    .unwrap_or(fallback)
             } else {
-                format!(
-                    "Current {} {} price is {}{:.0} per {}.",
-                    p,
-                    self.view.product_name(),
-                    currency,
-                    price,
-                    self.view.asset_unit()
-                )
+                fallback
             };
             result["message"] = json!(message);
         } else {
@@ -314,3 +388,80 @@ impl GetPriceTool {
         format!("Current {} prices - {}", product, price_parts.join(", "))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain_tools::tools::test_support::{output_json, view_with};
+    use chrono::{Duration, NaiveDate};
+    use voice_agent_persistence::{
+        AssetPrice, AssetPriceService, PersistenceError, PriceBucket, PricePoint,
+    };
+
+    /// Current price plus a daily history that rose 2% over the week
+    struct RisingPrice;
+
+    #[async_trait]
+    impl AssetPriceService for RisingPrice {
+        async fn get_current_price(&self) -> Result<AssetPrice, PersistenceError> {
+            Ok(AssetPrice::new(7140.0, "test").with_tier("22K", 7140.0))
+        }
+
+        async fn get_historical_price(
+            &self,
+            _date: NaiveDate,
+        ) -> Result<Option<AssetPrice>, PersistenceError> {
+            Ok(None)
+        }
+
+        async fn refresh_price(&self) -> Result<AssetPrice, PersistenceError> {
+            self.get_current_price().await
+        }
+
+        async fn get_history(
+            &self,
+            _from: NaiveDate,
+            _to: NaiveDate,
+            _bucket: PriceBucket,
+            _tier: Option<&str>,
+        ) -> Result<Vec<PricePoint>, PersistenceError> {
+            let now = Utc::now();
+            Ok([(7, 7000.0), (3, 7070.0), (0, 7140.0)]
+                .into_iter()
+                .map(|(days_ago, close)| PricePoint {
+                    at: now - Duration::days(days_ago),
+                    open: close,
+                    high: close,
+                    low: close,
+                    close,
+                    samples: 1,
+                })
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_price_includes_weekly_trend() {
+        let view = view_with(|_| {});
+        let tool = GetPriceTool::with_price_service(Arc::new(RisingPrice), view.clone());
+
+        let result = output_json(tool.execute(json!({ "purity": "22K" })).await.unwrap());
+        assert_eq!(result["trend"]["direction"], "up");
+        assert_eq!(result["trend"]["days"], 7);
+        assert_eq!(result["trend"]["change_percent"], 2.0);
+        assert_eq!(result["trend"]["summary"], "up 2.0% this week");
+        assert!(result["message"]
+            .as_str()
+            .unwrap()
+            .contains("up 2.0% this week"));
+
+        // No history to compare without a price service
+        let result = output_json(
+            GetPriceTool::new(view)
+                .execute(json!({ "purity": "22K" }))
+                .await
+                .unwrap(),
+        );
+        assert!(result.get("trend").is_none());
+    }
+}
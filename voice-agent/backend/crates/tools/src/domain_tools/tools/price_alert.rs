@@ -0,0 +1,337 @@
+//! Price Alert Tool
+//!
+//! Subscribes a customer to an SMS when the asset price crosses a threshold
+//! ("tell me when gold crosses ₹7600"). The direction is taken from the
+//! customer's words or, when not given, from where the price is now; the
+//! `PriceAlertMonitor` sends the SMS once the alert triggers.
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+use voice_agent_config::{PriceAlertConfig, ToolsDomainView};
+use voice_agent_persistence::{AlertDirection, AssetPriceService, PriceAlert, PriceAlertStore};
+
+use crate::mcp::{InputSchema, PropertySchema, Tool, ToolError, ToolOutput, ToolSchema};
+
+/// Threshold given as a number or as text such as "₹7,600"
+fn parse_threshold(value: &Value) -> Option<f64> {
+    let threshold = match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s
+            .chars()
+            .filter(|c| c.is_ascii_digit() || *c == '.')
+            .collect::<String>()
+            .parse()
+            .ok(),
+        _ => None,
+    };
+    threshold.filter(|t| *t > 0.0)
+}
+
+/// Create a price alert subscription for the customer
+pub struct PriceAlertTool {
+    store: Arc<dyn PriceAlertStore>,
+    view: Arc<ToolsDomainView>,
+    prices: Option<Arc<dyn AssetPriceService>>,
+    config: PriceAlertConfig,
+}
+
+impl PriceAlertTool {
+    pub fn new(
+        store: Arc<dyn PriceAlertStore>,
+        view: Arc<ToolsDomainView>,
+        config: PriceAlertConfig,
+    ) -> Self {
+        Self {
+            store,
+            view,
+            prices: None,
+            config,
+        }
+    }
+
+    /// Infer the direction from the current price when the customer gives none
+    pub fn with_price_service(mut self, prices: Arc<dyn AssetPriceService>) -> Self {
+        self.prices = Some(prices);
+        self
+    }
+
+    /// Tier code from config matching the customer's purity, e.g. "22k" -> "22K"
+    fn resolve_tier(&self, purity: &str) -> Result<String, ToolError> {
+        let wanted: String = purity.chars().filter(|c| !c.is_whitespace()).collect();
+        let tiers = self.view.quality_tiers_full();
+        tiers
+            .iter()
+            .find(|(code, _, _)| code.eq_ignore_ascii_case(&wanted))
+            .map(|(code, _, _)| code.clone())
+            .ok_or_else(|| {
+                let codes: Vec<&str> = tiers.iter().map(|(code, _, _)| code.as_str()).collect();
+                ToolError::invalid_params(format!("purity must be one of {}", codes.join(", ")))
+            })
+    }
+}
+
+#[async_trait]
+impl Tool for PriceAlertTool {
+    fn name(&self) -> &str {
+        "create_price_alert"
+    }
+
+    fn description(&self) -> &str {
+        "Text the customer when the price per gram crosses the amount they name"
+    }
+
+    fn schema(&self) -> ToolSchema {
+        ToolSchema {
+            name: self.name().to_string(),
+            description: self.description().to_string(),
+            input_schema: InputSchema::object()
+                .property(
+                    "phone",
+                    PropertySchema::string("Customer phone number (10 digits)"),
+                    true,
+                )
+                .property(
+                    "threshold",
+                    PropertySchema::number("Price per gram that triggers the alert"),
+                    true,
+                )
+                .property(
+                    "direction",
+                    PropertySchema::enum_type(
+                        "Alert when the price goes above or below the threshold",
+                        vec!["above".into(), "below".into()],
+                    ),
+                    false,
+                )
+                .property(
+                    "purity",
+                    PropertySchema::string("Purity the threshold is for, e.g. 22K"),
+                    false,
+                )
+                .property(
+                    "expiry_days",
+                    PropertySchema::integer("Days to keep watching the price"),
+                    false,
+                )
+                .property(
+                    "customer_name",
+                    PropertySchema::string("Customer's name"),
+                    false,
+                )
+                .property(
+                    "session_id",
+                    PropertySchema::string("Conversation session ID"),
+                    false,
+                ),
+        }
+    }
+
+    async fn execute(&self, input: Value) -> Result<ToolOutput, ToolError> {
+        let tools_config = self.view.tools_config();
+        let phone: String = tools_config
+            .get_string_param_with_aliases(&input, "phone")
+            .ok_or_else(|| ToolError::invalid_params("phone is required"))?
+            .chars()
+            .filter(|c| c.is_ascii_digit())
+            .collect();
+        if phone.len() != 10 {
+            return Err(ToolError::invalid_params("phone must be 10 digits"));
+        }
+        let threshold = tools_config
+            .get_param_with_aliases(&input, "threshold")
+            .as_ref()
+            .and_then(parse_threshold)
+            .ok_or_else(|| ToolError::invalid_params("threshold must be a positive amount"))?;
+
+        let tier = match tools_config
+            .get_string_param_with_aliases(&input, "collateral_variant")
+            .or_else(|| {
+                input
+                    .get("purity")
+                    .and_then(|v| v.as_str())
+                    .map(String::from)
+            })
+            .filter(|p| !p.trim().is_empty())
+        {
+            Some(purity) => Some(self.resolve_tier(&purity)?),
+            None => None,
+        };
+
+        let current = match self.prices {
+            Some(ref prices) => match prices.get_current_price().await {
+                Ok(price) => Some(match tier {
+                    Some(ref tier) => price.price_for_tier(tier),
+                    None => price.base_price_per_unit(),
+                }),
+                Err(e) => {
+                    tracing::warn!(error = %e, "Current price unavailable for price alert");
+                    None
+                },
+            },
+            None => None,
+        };
+        let direction = match input.get("direction").and_then(|v| v.as_str()) {
+            Some("above") => AlertDirection::Above,
+            Some("below") => AlertDirection::Below,
+            Some(other) => {
+                return Err(ToolError::invalid_params(format!(
+                    "direction must be above or below, got '{}'",
+                    other
+                )))
+            },
+            None => match current {
+                Some(price) if price > threshold => AlertDirection::Below,
+                _ => AlertDirection::Above,
+            },
+        };
+
+        let max_days = self.config.max_expiry_days.max(1);
+        let days = input
+            .get("expiry_days")
+            .and_then(|v| v.as_u64())
+            .map(|d| d.clamp(1, max_days as u64) as u32)
+            .unwrap_or(self.config.default_expiry_days.clamp(1, max_days));
+
+        let mut alert = PriceAlert::new(
+            &phone,
+            threshold,
+            direction,
+            Utc::now() + Duration::days(days as i64),
+        );
+        let text = |key: &str| {
+            input
+                .get(key)
+                .and_then(|v| v.as_str())
+                .filter(|s| !s.trim().is_empty())
+                .map(String::from)
+        };
+        alert.customer_name = text("customer_name");
+        alert.session_id = text("session_id");
+        alert.tier = tier;
+
+        let active: Vec<PriceAlert> = self
+            .store
+            .list_active()
+            .await
+            .map_err(|e| ToolError::internal(format!("Failed to load price alerts: {}", e)))?
+            .into_iter()
+            .filter(|a| a.customer_phone == phone)
+            .collect();
+        let existing = active.iter().find(|a| a.same_subscription(&alert)).cloned();
+        let already_subscribed = existing.is_some();
+        let alert = match existing {
+            Some(existing) => existing,
+            None => {
+                if active.len() >= self.config.max_active_per_customer {
+                    return Err(ToolError::invalid_params(format!(
+                        "Customer already has {} active price alerts, the most allowed",
+                        active.len()
+                    )));
+                }
+                self.store.create(&alert).await.map_err(|e| {
+                    ToolError::internal(format!("Failed to create price alert: {}", e))
+                })?;
+                alert
+            },
+        };
+
+        let currency = self.view.currency_symbol();
+        let threshold_text = format!("{}{:.0}", currency, alert.threshold);
+        let expires = alert.expires_at.format("%-d %b %Y").to_string();
+        let fallback = format!(
+            "Done. We'll send you an SMS when the price goes {} {} per {}. The alert stays on until {}.",
+            alert.direction.as_str(),
+            threshold_text,
+            self.view.asset_unit(),
+            expires
+        );
+        let message = if self.view.has_response_templates(self.name()) {
+            let mut vars = self.view.default_template_vars();
+            vars.insert(
+                "customer_name".to_string(),
+                alert.customer_name.clone().unwrap_or_default(),
+            );
+            vars.insert(
+                "direction".to_string(),
+                alert.direction.as_str().to_string(),
+            );
+            vars.insert("threshold".to_string(), threshold_text.clone());
+            vars.insert("unit".to_string(), self.view.asset_unit().to_string());
+            vars.insert("expires".to_string(), expires.clone());
+            self.view
+                .render_response("create_price_alert", "created", "en", &vars)
+                .unwrap_or(fallback)
+        } else {
+            fallback
+        };
+
+        let mut result = json!({
+            "created": !already_subscribed,
+            "already_subscribed": already_subscribed,
+            "alert_id": alert.alert_id.to_string(),
+            "phone": alert.customer_phone,
+            "threshold": alert.threshold,
+            "direction": alert.direction.as_str(),
+            "purity": alert.tier,
+            "expires_at": alert.expires_at.to_rfc3339(),
+            "status": alert.status.as_str(),
+            "message": message
+        });
+        if let Some(price) = current {
+            result["current_price"] = json!(price.round());
+        }
+
+        Ok(ToolOutput::json(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain_tools::tools::test_support::{output_json, view_with};
+    use voice_agent_persistence::InMemoryPriceAlertStore;
+
+    #[tokio::test]
+    async fn test_alert_is_stored_once_and_limited() {
+        let store = Arc::new(InMemoryPriceAlertStore::new());
+        let view = view_with(|_| {});
+        let config = PriceAlertConfig {
+            max_active_per_customer: 2,
+            ..Default::default()
+        };
+        let tool = PriceAlertTool::new(store.clone(), view, config);
+
+        let input = json!({
+            "phone": "98765-43210",
+            "threshold": "₹7,600",
+            "customer_name": "Asha"
+        });
+        let result = output_json(tool.execute(input.clone()).await.unwrap());
+        assert_eq!(result["created"], true);
+        assert_eq!(result["direction"], "above");
+        assert_eq!(result["threshold"], 7600.0);
+
+        let again = output_json(tool.execute(input).await.unwrap());
+        assert_eq!(again["already_subscribed"], true);
+        assert_eq!(again["alert_id"], result["alert_id"]);
+
+        let active = store.list_active().await.unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].customer_phone, "9876543210");
+
+        tool.execute(json!({ "phone": "9876543210", "threshold": 7000, "direction": "below" }))
+            .await
+            .unwrap();
+        assert!(tool
+            .execute(json!({ "phone": "9876543210", "threshold": 8000 }))
+            .await
+            .is_err());
+        assert!(tool
+            .execute(json!({ "phone": "9876543210", "threshold": "soon" }))
+            .await
+            .is_err());
+    }
+}
@@ -2,13 +2,23 @@
 //!
 //! Calculate potential savings when switching from another lender to our loan.
 //! P21 FIX: Made domain-agnostic (was gold loan specific).
+//!
+//! Net savings account for our processing fee and the current lender's
+//! prepayment penalty (competitors.yaml); the current rate comes from the
+//! competitor rate table when one is wired in. With the collateral weight,
+//! the extra funds our LTV would release are reported too. Output carries a
+//! one-line voice summary (`message`) and a detailed `sms_breakdown`.
+//! Our rate, LTV and processing fee come from the rate card in force, with
+//! the rate slab chosen by the loan amount and remaining tenure.
 
 use async_trait::async_trait;
 use serde_json::{json, Value};
 use std::sync::Arc;
 use voice_agent_config::ToolsDomainView;
+use voice_agent_persistence::CompetitorRateStore;
 
 use crate::mcp::{InputSchema, PropertySchema, Tool, ToolError, ToolOutput, ToolSchema};
+use crate::rate_cards::RateCardEngine;
 use super::super::utils::{calculate_emi, calculate_total_interest};
 
 /// Savings calculator tool
@@ -17,12 +27,19 @@ use super::super::utils::{calculate_emi, calculate_total_interest};
 /// P15 FIX: ToolsDomainView is now REQUIRED - no more hardcoded fallbacks
 pub struct SavingsCalculatorTool {
     view: Arc<ToolsDomainView>,
+    rates: Option<Arc<dyn CompetitorRateStore>>,
+    rate_cards: Arc<RateCardEngine>,
 }
 
 impl SavingsCalculatorTool {
     /// Create with required ToolsDomainView - domain config is mandatory
     pub fn new(view: Arc<ToolsDomainView>) -> Self {
-        Self { view }
+        let rate_cards = Arc::new(RateCardEngine::new(view.clone()));
+        Self {
+            view,
+            rates: None,
+            rate_cards,
+        }
     }
 
     /// Alias for new() for backwards compatibility during migration
@@ -30,21 +47,52 @@ impl SavingsCalculatorTool {
         Self::new(view)
     }
 
-    fn get_rate(&self, amount: f64) -> f64 {
-        self.view.get_rate_for_amount(amount)
+    /// Prefer the competitor rate table over config for the current rate
+    pub fn with_rate_store(mut self, rates: Arc<dyn CompetitorRateStore>) -> Self {
+        self.rates = Some(rates);
+        self
     }
 
-    fn get_competitor_rate(&self, lender: &str) -> f64 {
-        self.view.get_competitor_rate(lender)
+    /// Use published rate cards as well as those in config for our rates
+    pub fn with_rate_cards(mut self, rate_cards: Arc<RateCardEngine>) -> Self {
+        self.rate_cards = rate_cards;
+        self
     }
 
-    fn get_rate_tier_name(&self, amount: f64) -> &str {
-        self.view.get_rate_tier_name(amount)
+    /// Current lender's rate: rate table first, then config
+    async fn get_competitor_rate(&self, lender: &str) -> (f64, &'static str) {
+        if let Some(ref store) = self.rates {
+            let lender_id = self
+                .view
+                .find_competitor_by_name(lender)
+                .map(|(id, _)| id.to_string())
+                .unwrap_or_else(|| lender.to_lowercase());
+            match store.list(self.view.competitor_rate_product()).await {
+                Ok(rates) => {
+                    if let Some(rate) = rates.iter().find(|r| {
+                        r.lender_id == lender_id || r.lender_name.eq_ignore_ascii_case(lender)
+                    }) {
+                        return (rate.comparison_rate(), "rate_table");
+                    }
+                },
+                Err(e) => tracing::warn!("Failed to load competitor rates, using config: {}", e),
+            }
+        }
+        (self.view.get_competitor_rate(lender), "config")
     }
 
     fn company_name(&self) -> &str {
         self.view.company_name()
     }
+
+    /// Net savings below this share of the loan amount count as minimal
+    fn minimal_savings_percent(&self) -> f64 {
+        self.view
+            .tools_config()
+            .get_tool_default("calculate_savings", "minimal_savings_percent")
+            .and_then(|v| v.as_f64())
+            .unwrap_or(1.0)
+    }
 }
 
 #[async_trait]
@@ -104,6 +152,22 @@ impl Tool for SavingsCalculatorTool {
                         },
                     ),
                     false,
+                )
+                .property(
+                    "prepayment_penalty_percent",
+                    PropertySchema::number("Current lender's foreclosure charge (%)")
+                        .with_range(0.0, 10.0),
+                    false,
+                )
+                .property(
+                    "collateral_weight",
+                    PropertySchema::number("Weight/quantity of pledged collateral"),
+                    false,
+                )
+                .property(
+                    "collateral_variant",
+                    PropertySchema::string("Variant/grade of collateral"),
+                    false,
                 ),
         }
     }
@@ -126,19 +190,34 @@ impl Tool for SavingsCalculatorTool {
             .unwrap_or(&default_lender);
 
         // P13 FIX: Use ToolsDomainView for competitor rates
-        let current_rate: f64 = input
-            .get("current_interest_rate")
-            .and_then(|v| v.as_f64())
-            .unwrap_or_else(|| self.get_competitor_rate(current_lender));
+        let (current_rate, rate_source) =
+            match input.get("current_interest_rate").and_then(|v| v.as_f64()) {
+                Some(rate) => (rate, "customer"),
+                None => self.get_competitor_rate(current_lender).await,
+            };
 
         let tenure_months: i64 = input
             .get("remaining_tenure_months")
             .and_then(|v| v.as_i64())
             .ok_or_else(|| ToolError::invalid_params("remaining_tenure_months is required"))?;
 
+        let tools_config = self.view.tools_config();
+        let variant = tools_config
+            .get_string_param_with_aliases(&input, "collateral_variant")
+            .unwrap_or_else(|| self.view.default_quality_tier_display());
+
         // P15 FIX: Use config-driven rates and bank name
-        let our_rate = self.get_rate(loan_amount);
-        let rate_tier = self.get_rate_tier_name(loan_amount);
+        let quote = self.rate_cards.quote(
+            loan_amount,
+            u32::try_from(tenure_months).ok(),
+            Some(&variant),
+        );
+        let our_rate = quote.rate_percent;
+        let rate_tier = if quote.slab.is_empty() {
+            self.view.get_rate_tier_name(loan_amount).to_string()
+        } else {
+            quote.slab.clone()
+        };
         let company_name = self.company_name();
 
         let current_emi = calculate_emi(loan_amount, current_rate, tenure_months);
@@ -154,38 +233,127 @@ impl Tool for SavingsCalculatorTool {
             calculate_total_interest(loan_amount, current_rate, tenure_months)
                 - calculate_total_interest(loan_amount, our_rate, tenure_months);
 
+        // Switching costs: our processing fee and the current lender's foreclosure charge
+        let processing_fee_percent = quote.processing_fee_percent;
+        let processing_fee = loan_amount * processing_fee_percent / 100.0;
+        let default_penalty = self.view.competitor_prepayment_penalty(current_lender);
+        let penalty_percent = input
+            .get("prepayment_penalty_percent")
+            .and_then(|v| v.as_f64())
+            .unwrap_or(default_penalty);
+        let prepayment_penalty = loan_amount * penalty_percent / 100.0;
+        let switching_costs = processing_fee + prepayment_penalty;
+        let net_savings = total_interest_savings - switching_costs;
+        let break_even_months = if switching_costs <= 0.0 {
+            Some(0)
+        } else if emi_savings > 0.0 {
+            Some((switching_costs / emi_savings).ceil() as i64).filter(|m| *m <= tenure_months)
+        } else {
+            None
+        };
+
+        // Differential LTV: extra funds the same collateral would release with us
+        let our_ltv = quote.ltv_percent;
+        let current_ltv = self.view.competitor_ltv_percent(current_lender);
+        let additional_funds = tools_config
+            .get_numeric_param_with_aliases(&input, "collateral_weight")
+            .filter(|weight| *weight > 0.0)
+            .map(|weight| {
+                let collateral_value = self.view.calculate_asset_value(weight, &variant);
+                let max_loan = self.rate_cards.max_loan(collateral_value, Some(&variant));
+                (max_loan - loan_amount).max(0.0)
+            });
+
+        let scenario = if net_savings <= 0.0 {
+            "no_savings"
+        } else if net_savings < loan_amount * self.minimal_savings_percent() / 100.0 {
+            "minimal_savings"
+        } else {
+            "savings_found"
+        };
+
         // P16 FIX: Use config-driven response templates
         // P23 FIX: Use config-driven currency symbol instead of hardcoded "₹"
         let currency = self.view.currency_symbol();
+        let fallback = match scenario {
+            "savings_found" => format!(
+                "Switching from {} to {} at {}% saves you about {}{:.0} a month and {}{:.0} in total after fees over the remaining {} months.",
+                current_lender, company_name, our_rate, currency, emi_savings, currency, net_savings, tenure_months
+            ),
+            "minimal_savings" => format!(
+                "Switching from {} to {} would save only about {}{:.0} in total after fees over the remaining {} months.",
+                current_lender, company_name, currency, net_savings, tenure_months
+            ),
+            _ => format!(
+                "After fees and charges, switching from {} wouldn't save you money over the remaining {} months.",
+                current_lender, tenure_months
+            ),
+        };
         let message = if self.view.has_response_templates("calculate_savings") {
             let mut vars = self.view.default_template_vars();
             vars.insert("company_name".to_string(), company_name.to_string());
-            vars.insert("rate_tier".to_string(), rate_tier.to_string());
+            vars.insert("rate_tier".to_string(), rate_tier.clone());
+            vars.insert("rate_description".to_string(), rate_tier.to_lowercase());
             vars.insert("our_rate".to_string(), format!("{:.1}", our_rate));
+            vars.insert("current_rate".to_string(), format!("{:.1}", current_rate));
             vars.insert("emi_savings".to_string(), format!("{:.0}", emi_savings));
+            vars.insert("monthly_savings".to_string(), format!("{:.0}", emi_savings));
             vars.insert("interest_savings".to_string(), format!("{:.0}", monthly_interest_savings));
             vars.insert("total_savings".to_string(), format!("{:.0}", total_emi_savings));
+            vars.insert("net_savings".to_string(), format!("{:.0}", net_savings));
+            let fees = format!("{:.0}", switching_costs);
+            vars.insert("switching_fees".to_string(), fees);
             vars.insert("tenure_months".to_string(), tenure_months.to_string());
             vars.insert("current_lender".to_string(), current_lender.to_string());
             vars.insert("rate_reduction".to_string(), format!("{:.1}", current_rate - our_rate));
+            vars.insert("features".to_string(), self.view.our_features().join(", "));
             vars.insert("currency".to_string(), currency.to_string());
-            self.view.render_response("calculate_savings", "savings_available", "en", &vars)
-                .unwrap_or_else(|| format!(
-                    "By switching to {} at our {} rate of {}%, you can save {}{:.0} per month on EMI (or {}{:.0} on interest-only) and {}{:.0} total over the remaining {} months!",
-                    company_name, rate_tier, our_rate, currency, emi_savings, currency, monthly_interest_savings, currency, total_emi_savings, tenure_months
-                ))
+            self.view
+                .render_response("calculate_savings", scenario, "en", &vars)
+                .unwrap_or(fallback)
         } else {
-            format!(
-                "By switching to {} at our {} rate of {}%, you can save {}{:.0} per month on EMI (or {}{:.0} on interest-only) and {}{:.0} total over the remaining {} months!",
-                company_name, rate_tier, our_rate, currency, emi_savings, currency, monthly_interest_savings, currency, total_emi_savings, tenure_months
-            )
+            fallback
         };
 
+        // Detailed breakdown for SMS follow-up (send_sms custom_message)
+        let mut sms_lines = vec![
+            format!(
+                "{} vs {}: {}{:.0} for {} months",
+                company_name, current_lender, currency, loan_amount, tenure_months
+            ),
+            format!("Rate: {:.2}% -> {:.2}%", current_rate, our_rate),
+            format!(
+                "EMI: {}{:.0} -> {}{:.0} (save {}{:.0}/month)",
+                currency, current_emi, currency, our_emi, currency, emi_savings
+            ),
+            format!("Interest saved: {}{:.0}", currency, total_interest_savings),
+            format!(
+                "Processing fee ({}%): -{}{:.0}",
+                processing_fee_percent, currency, processing_fee
+            ),
+            format!(
+                "Prepayment charge ({}%): -{}{:.0}",
+                penalty_percent, currency, prepayment_penalty
+            ),
+            format!("Net savings: {}{:.0}", currency, net_savings),
+        ];
+        if let Some(months) = break_even_months.filter(|m| *m > 0) {
+            sms_lines.push(format!("Fees recovered in {} months", months));
+        }
+        if let Some(extra) = additional_funds.filter(|extra| *extra > 0.0) {
+            sms_lines.push(format!(
+                "Extra funds at our {}% LTV: {}{:.0}",
+                our_ltv, currency, extra
+            ));
+        }
+        let sms_breakdown = sms_lines.join("\n");
+
         // P2.6 FIX: Use config-driven currency field suffix instead of hardcoded "_inr"
         let suffix = self.view.currency_field_suffix();
         let result = json!({
             "current_lender": current_lender,
             "current_interest_rate_percent": current_rate,
+            "current_rate_source": rate_source,
             "our_interest_rate_percent": our_rate,
             "rate_reduction_percent": current_rate - our_rate,
             format!("current_emi_{}", suffix): current_emi.round(),
@@ -196,12 +364,109 @@ impl Tool for SavingsCalculatorTool {
             format!("our_monthly_interest_{}", suffix): our_monthly_interest.round(),
             format!("monthly_interest_savings_{}", suffix): monthly_interest_savings.round(),
             format!("total_interest_savings_{}", suffix): total_interest_savings.round(),
+            format!("processing_fee_{}", suffix): processing_fee.round(),
+            format!("prepayment_penalty_{}", suffix): prepayment_penalty.round(),
+            format!("switching_costs_{}", suffix): switching_costs.round(),
+            format!("net_savings_{}", suffix): net_savings.round(),
+            format!("additional_funds_available_{}", suffix): additional_funds.map(f64::round),
+            "processing_fee_percent": processing_fee_percent,
+            "prepayment_penalty_percent": penalty_percent,
+            "break_even_months": break_even_months,
+            "our_ltv_percent": our_ltv,
+            "current_ltv_percent": current_ltv,
             "tenure_months": tenure_months,
             "rate_tier": rate_tier,
+            "rate_card_version": quote.card_version,
             "company_name": company_name,
-            "message": message
+            "scenario": scenario,
+            "message": message,
+            "sms_breakdown": sms_breakdown
         });
 
         Ok(ToolOutput::json(result))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain_tools::tools::test_support::{output_json, view_with};
+
+    fn view() -> Arc<ToolsDomainView> {
+        view_with(|config| {
+            config.constants = serde_json::from_value(json!({
+                "interest_rates": { "base_rate": 10.0 },
+                "ltv_percent": 75.0,
+                "loan_limits": { "min": 10000.0, "max": 10000000.0 },
+                "processing_fee_percent": 1.0,
+                "asset_price_per_unit": 7000.0,
+                "variant_factors": { "22K": 0.916 }
+            }))
+            .unwrap();
+            config.competitors_config = serde_json::from_value(json!({
+                "competitors": {
+                    "muthoot": {
+                        "display_name": "Muthoot Finance",
+                        "typical_rate": 18.0,
+                        "ltv_percent": 70.0,
+                        "prepayment_penalty_percent": 0.5
+                    }
+                }
+            }))
+            .unwrap();
+        })
+    }
+
+    #[tokio::test]
+    async fn test_net_savings_after_fees_and_extra_funds() {
+        let tool = SavingsCalculatorTool::new(view());
+        let result = output_json(
+            tool.execute(json!({
+                "current_lender": "muthoot",
+                "current_loan_amount": 200000.0,
+                "remaining_tenure_months": 12,
+                "collateral_weight": 100.0,
+                "collateral_variant": "22K"
+            }))
+            .await
+            .unwrap(),
+        );
+
+        // 1% processing fee plus Muthoot's 0.5% foreclosure charge
+        assert_eq!(result["switching_costs_inr"], 3000.0);
+        assert_eq!(result["current_rate_source"], "config");
+        let gross = result["total_interest_savings_inr"].as_f64().unwrap();
+        let net = result["net_savings_inr"].as_f64().unwrap();
+        assert!((gross - net - 3000.0).abs() <= 1.0);
+        assert_eq!(result["scenario"], "savings_found");
+        assert_eq!(result["break_even_months"], 4);
+        assert_eq!(result["current_ltv_percent"], 70.0);
+
+        // 100g of 22K at 7000/g and 75% LTV releases about 480,900
+        let extra = result["additional_funds_available_inr"].as_f64().unwrap();
+        assert!((extra - 280900.0).abs() <= 1.0);
+        assert!(result["sms_breakdown"]
+            .as_str()
+            .unwrap()
+            .contains("Net savings"));
+    }
+
+    #[tokio::test]
+    async fn test_fees_outweigh_small_rate_gap() {
+        let tool = SavingsCalculatorTool::new(view());
+        let result = output_json(
+            tool.execute(json!({
+                "current_lender": "muthoot",
+                "current_interest_rate": 10.5,
+                "current_loan_amount": 200000.0,
+                "remaining_tenure_months": 6
+            }))
+            .await
+            .unwrap(),
+        );
+
+        assert_eq!(result["scenario"], "no_savings");
+        assert!(result["net_savings_inr"].as_f64().unwrap() < 0.0);
+        assert!(result["additional_funds_available_inr"].is_null());
+    }
+}
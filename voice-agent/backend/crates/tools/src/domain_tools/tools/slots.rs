@@ -0,0 +1,448 @@
+//! Branch Visit Slot Tools
+//!
+//! Real slot availability for branch visits: find the next free slots near
+//! a place, and hold one for the caller while the booking details are
+//! collected. Capacity per branch and slot, hold expiry, closed days and
+//! the search window come from `appointments` in tools/branches.yaml; time
+//! slots come from the `schedule_appointment.preferred_time` enum.
+
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDate};
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+use voice_agent_config::{BranchEntry, ToolsDomainView};
+use voice_agent_persistence::SlotStore;
+
+use super::super::scheduling::{
+    branch_now, configured_time_slots, match_slot_time, parse_time, parse_visit_date,
+    resolve_branch, slot_start,
+};
+use crate::mcp::{InputSchema, PropertySchema, Tool, ToolError, ToolOutput, ToolSchema};
+
+/// Most free slots returned by one availability query
+const MAX_SLOTS_OFFERED: usize = 10;
+
+/// Spoken form of a date, e.g. "Thu 16 Jan"
+fn spoken_date(date: NaiveDate) -> String {
+    date.format("%a %-d %b").to_string()
+}
+
+/// Parse a visit date that must not be in the past
+fn visit_date(text: &str, today: NaiveDate) -> Result<NaiveDate, ToolError> {
+    let date = parse_visit_date(text, today).ok_or_else(|| {
+        ToolError::invalid_params(
+            "date must be today, tomorrow, a weekday, YYYY-MM-DD, DD-MM-YYYY or DD/MM/YYYY",
+        )
+    })?;
+    if date < today {
+        return Err(ToolError::invalid_params("date cannot be in the past"));
+    }
+    Ok(date)
+}
+
+/// Find the next free branch visit slots near a place
+pub struct SlotAvailabilityTool {
+    store: Arc<dyn SlotStore>,
+    view: Arc<ToolsDomainView>,
+}
+
+impl SlotAvailabilityTool {
+    pub fn new(store: Arc<dyn SlotStore>, view: Arc<ToolsDomainView>) -> Self {
+        Self { store, view }
+    }
+
+    /// Branches to search: an exact branch ID, else those matching the place
+    fn branches(&self, location: &str) -> Vec<&BranchEntry> {
+        match self.view.get_branch(location.trim()) {
+            Some(branch) => vec![branch],
+            None => {
+                let mut branches = self.view.find_branches_near(location);
+                branches.truncate(self.view.branch_search_max_results());
+                branches
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl Tool for SlotAvailabilityTool {
+    fn name(&self) -> &str {
+        "check_slot_availability"
+    }
+
+    fn description(&self) -> &str {
+        "Find the next free branch visit slots near a place"
+    }
+
+    fn schema(&self) -> ToolSchema {
+        ToolSchema {
+            name: self.name().to_string(),
+            description: self.description().to_string(),
+            input_schema: InputSchema::object()
+                .property(
+                    "location",
+                    PropertySchema::string("Area, city, pincode or branch ID"),
+                    true,
+                )
+                .property(
+                    "date",
+                    PropertySchema::string(
+                        "Earliest visit date: today, tomorrow, a weekday or YYYY-MM-DD",
+                    ),
+                    false,
+                )
+                .property(
+                    "preferred_time",
+                    PropertySchema::string("Earliest time of day, e.g. '2 pm'"),
+                    false,
+                )
+                .property(
+                    "count",
+                    PropertySchema::integer("Number of free slots to offer"),
+                    false,
+                ),
+        }
+    }
+
+    async fn execute(&self, input: Value) -> Result<ToolOutput, ToolError> {
+        let tools_config = self.view.tools_config();
+        let location = tools_config
+            .get_string_param_with_aliases(&input, "location")
+            .ok_or_else(|| ToolError::invalid_params("location is required"))?;
+        let location = location.as_str();
+
+        let branches = self.branches(location);
+        if branches.is_empty() {
+            return Err(ToolError::invalid_params(format!(
+                "No branch found near '{}'",
+                location
+            )));
+        }
+
+        let settings = self.view.appointment_slots();
+        let now = branch_now(Some(self.view.as_ref()));
+        let today = now.date();
+        let start = match tools_config.get_string_param_with_aliases(&input, "date") {
+            Some(text) => visit_date(&text, today)?,
+            None => today,
+        };
+        let earliest = input
+            .get("preferred_time")
+            .and_then(|v| v.as_str())
+            .and_then(parse_time);
+        let count = input
+            .get("count")
+            .and_then(|v| v.as_u64())
+            .map(|c| (c as usize).clamp(1, MAX_SLOTS_OFFERED))
+            .unwrap_or(settings.slots_offered);
+        let times = configured_time_slots(Some(self.view.as_ref()));
+
+        let mut found = Vec::new();
+        for offset in 0..settings.search_days {
+            let date = start + Duration::days(offset as i64);
+            if found.len() >= count {
+                break;
+            }
+            if settings.is_closed_day(&date.format("%a").to_string()) {
+                continue;
+            }
+
+            let day_times: Vec<String> = times
+                .iter()
+                .filter(|slot| match slot_start(slot) {
+                    Some(start_time) => {
+                        (date > today || start_time > now.time())
+                            && earliest.map_or(true, |t| start_time >= t)
+                    },
+                    None => date > today && earliest.is_none(),
+                })
+                .cloned()
+                .collect();
+            if day_times.is_empty() {
+                continue;
+            }
+
+            let mut day_slots = Vec::new();
+            for branch in &branches {
+                let capacity = self.view.branch_slot_capacity(&branch.branch_id);
+                let slots = self
+                    .store
+                    .availability(&branch.branch_id, date, &day_times, capacity)
+                    .await
+                    .map_err(|e| ToolError::internal(format!("Slot lookup failed: {}", e)))?;
+                day_slots.extend(
+                    slots
+                        .into_iter()
+                        .filter(|s| s.remaining() > 0)
+                        .map(|s| (*branch, s)),
+                );
+            }
+            day_slots.sort_by_key(|(_, s)| slot_start(&s.time));
+            found.extend(day_slots.into_iter().take(count - found.len()));
+        }
+
+        let slots: Vec<Value> = found
+            .iter()
+            .map(|(branch, slot)| {
+                json!({
+                    "branch_id": branch.branch_id,
+                    "branch_name": branch.name,
+                    "address": branch.address,
+                    "date": slot.date.format("%Y-%m-%d").to_string(),
+                    "day": slot.date.format("%A").to_string(),
+                    "time": slot.time,
+                    "places_left": slot.remaining(),
+                })
+            })
+            .collect();
+
+        let message = if found.is_empty() {
+            format!(
+                "There are no free slots near {} in the next {} days.",
+                location, settings.search_days
+            )
+        } else {
+            let options: Vec<String> = found
+                .iter()
+                .map(|(branch, slot)| {
+                    format!(
+                        "{} at {} ({})",
+                        spoken_date(slot.date),
+                        slot.time,
+                        branch.area
+                    )
+                })
+                .collect();
+            format!("Free slots near {}: {}.", location, options.join(", "))
+        };
+
+        let result = json!({
+            "location": location,
+            "slots_found": slots.len(),
+            "slots": slots,
+            "hold_ttl_secs": settings.hold_ttl_secs,
+            "message": message
+        });
+
+        Ok(ToolOutput::json(result))
+    }
+}
+
+/// Hold a branch visit slot for the caller while the booking is completed
+pub struct SlotHoldTool {
+    store: Arc<dyn SlotStore>,
+    view: Arc<ToolsDomainView>,
+}
+
+impl SlotHoldTool {
+    pub fn new(store: Arc<dyn SlotStore>, view: Arc<ToolsDomainView>) -> Self {
+        Self { store, view }
+    }
+}
+
+#[async_trait]
+impl Tool for SlotHoldTool {
+    fn name(&self) -> &str {
+        "hold_appointment_slot"
+    }
+
+    fn description(&self) -> &str {
+        "Reserve a branch visit slot for the caller while booking details are collected"
+    }
+
+    fn schema(&self) -> ToolSchema {
+        ToolSchema {
+            name: self.name().to_string(),
+            description: self.description().to_string(),
+            input_schema: InputSchema::object()
+                .property(
+                    "branch_id",
+                    PropertySchema::string("Branch ID from check_slot_availability"),
+                    true,
+                )
+                .property(
+                    "date",
+                    PropertySchema::string("Visit date (YYYY-MM-DD)"),
+                    true,
+                )
+                .property(
+                    "time",
+                    PropertySchema::enum_type(
+                        "Time slot",
+                        configured_time_slots(Some(self.view.as_ref())),
+                    ),
+                    true,
+                )
+                .property(
+                    "session_id",
+                    PropertySchema::string("Conversation session ID"),
+                    false,
+                ),
+        }
+    }
+
+    async fn execute(&self, input: Value) -> Result<ToolOutput, ToolError> {
+        let branch_text = input
+            .get("branch_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| ToolError::invalid_params("branch_id is required"))?;
+        let branch = resolve_branch(&self.view, branch_text).ok_or_else(|| {
+            ToolError::invalid_params(format!("Unknown branch '{}'", branch_text))
+        })?;
+
+        let settings = self.view.appointment_slots();
+        let today = branch_now(Some(self.view.as_ref())).date();
+        let tools_config = self.view.tools_config();
+        let date_text = tools_config
+            .get_string_param_with_aliases(&input, "date")
+            .ok_or_else(|| ToolError::invalid_params("date is required"))?;
+        let date = visit_date(&date_text, today)?;
+        if settings.is_closed_day(&date.format("%a").to_string()) {
+            return Err(ToolError::invalid_params(format!(
+                "Branches are closed on {}",
+                date.format("%A")
+            )));
+        }
+
+        let times = configured_time_slots(Some(self.view.as_ref()));
+        let time_text = tools_config
+            .get_string_param_with_aliases(&input, "time")
+            .ok_or_else(|| ToolError::invalid_params("time is required"))?;
+        let time = match_slot_time(&time_text, &times).ok_or_else(|| {
+            ToolError::invalid_params(format!("time must be one of: {}", times.join(", ")))
+        })?;
+        let session_id = input
+            .get("session_id")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default();
+
+        let hold = self
+            .store
+            .hold(
+                &branch.branch_id,
+                date,
+                &time,
+                session_id,
+                self.view.branch_slot_capacity(&branch.branch_id),
+                Duration::seconds(settings.hold_ttl_secs as i64),
+            )
+            .await
+            .map_err(|e| ToolError::internal(format!("Slot hold failed: {}", e)))?;
+
+        let result = match hold {
+            Some(hold) => json!({
+                "held": true,
+                "hold_id": hold.hold_id.to_string(),
+                "branch_id": branch.branch_id,
+                "branch_name": branch.name,
+                "date": date.format("%Y-%m-%d").to_string(),
+                "time": time,
+                "expires_at": hold.expires_at.to_rfc3339(),
+                "hold_ttl_secs": settings.hold_ttl_secs,
+                "message": format!(
+                    "I've reserved {} at {} at our {} branch for the next {} minutes.",
+                    spoken_date(date),
+                    time,
+                    branch.area,
+                    (settings.hold_ttl_secs / 60).max(1)
+                )
+            }),
+            None => json!({
+                "held": false,
+                "branch_id": branch.branch_id,
+                "branch_name": branch.name,
+                "date": date.format("%Y-%m-%d").to_string(),
+                "time": time,
+                "message": format!(
+                    "{} at {} is fully booked at our {} branch. Let me find another slot.",
+                    spoken_date(date),
+                    time,
+                    branch.area
+                )
+            }),
+        };
+
+        Ok(ToolOutput::json(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain_tools::tools::test_support::{output_json, view_with};
+    use voice_agent_persistence::InMemorySlotStore;
+
+    fn view() -> Arc<ToolsDomainView> {
+        view_with(|config| {
+            config.branches = serde_json::from_value(json!({
+                "branches": [{
+                    "branch_id": "B1",
+                    "name": "Andheri West",
+                    "city": "Mumbai",
+                    "area": "Andheri West",
+                    "address": "S.V. Road",
+                    "phone": "1"
+                }],
+                "appointments": { "slot_capacity": 1 }
+            }))
+            .unwrap();
+        })
+    }
+
+    #[tokio::test]
+    async fn test_held_slot_is_not_offered_again() {
+        let view = view();
+        let store: Arc<dyn SlotStore> = Arc::new(InMemorySlotStore::new());
+        let availability = SlotAvailabilityTool::new(store.clone(), view.clone());
+        let hold = SlotHoldTool::new(store, view);
+
+        let first = output_json(
+            availability
+                .execute(json!({ "location": "andheri", "date": "tomorrow", "count": 2 }))
+                .await
+                .unwrap(),
+        );
+        assert_eq!(first["slots_found"], 2);
+        let slot = &first["slots"][0];
+        assert_eq!(slot["branch_id"], "B1");
+
+        let held = output_json(
+            hold.execute(json!({
+                "branch_id": "B1",
+                "date": slot["date"],
+                "time": slot["time"],
+                "session_id": "call-1"
+            }))
+            .await
+            .unwrap(),
+        );
+        assert_eq!(held["held"], true);
+
+        // Capacity is one, so the held slot is no longer offered
+        let second = output_json(
+            availability
+                .execute(json!({ "location": "andheri", "date": "tomorrow", "count": 2 }))
+                .await
+                .unwrap(),
+        );
+        assert_ne!(second["slots"][0]["time"], slot["time"]);
+
+        let again = output_json(
+            hold.execute(json!({ "branch_id": "B1", "date": slot["date"], "time": slot["time"] }))
+                .await
+                .unwrap(),
+        );
+        assert_eq!(again["held"], false);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_location_is_rejected() {
+        let store: Arc<dyn SlotStore> = Arc::new(InMemorySlotStore::new());
+        let tool = SlotAvailabilityTool::new(store, view());
+        assert!(tool
+            .execute(json!({ "location": "Chennai" }))
+            .await
+            .is_err());
+    }
+}
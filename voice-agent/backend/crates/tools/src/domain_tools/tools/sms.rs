@@ -267,6 +267,18 @@ impl Tool for SendSmsTool {
                     result.status.as_str().to_string(),
                     result.simulated,
                 ),
+                Err(voice_agent_persistence::PersistenceError::QuotaExceeded(reason)) => {
+                    return Ok(ToolOutput::json(json!({
+                        "success": false,
+                        "phone_number": phone,
+                        "message_type": msg_type_str,
+                        "status": "limit_reached",
+                        "message": format!(
+                            "SMS not sent: {}. Share the details on the call instead.",
+                            reason
+                        )
+                    })));
+                }
                 Err(e) => {
                     tracing::warn!("SMS service failed: {}", e);
                     let id = format!(
@@ -0,0 +1,269 @@
+//! Top-up Eligibility Tool
+//!
+//! For existing customers: how much more can be borrowed on the current
+//! pledge at today's collateral price, and what renewing would look like.
+//! LTV cap, minimum top-up and renewal tenures come from `constants.top_up`
+//! in domain config, with the cap held to the rate card's LTV for the
+//! collateral's tier; rates and fees come from the rate card in force. The
+//! collateral price comes from the AssetPriceService when one is wired in.
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::sync::Arc;
+use voice_agent_config::ToolsDomainView;
+
+use crate::mcp::{InputSchema, PropertySchema, Tool, ToolError, ToolOutput, ToolSchema};
+use crate::rate_cards::RateCardEngine;
+
+/// Tool name as defined in config - used to look up schema
+const TOOL_NAME: &str = "check_top_up_eligibility";
+
+/// Top-up and renewal eligibility tool
+pub struct TopUpEligibilityTool {
+    price_service: Option<Arc<dyn voice_agent_persistence::AssetPriceService>>,
+    view: Arc<ToolsDomainView>,
+    rates: Arc<RateCardEngine>,
+}
+
+impl TopUpEligibilityTool {
+    /// Create with required ToolsDomainView (prices from config)
+    pub fn new(view: Arc<ToolsDomainView>) -> Self {
+        let rates = Arc::new(RateCardEngine::new(view.clone()));
+        Self {
+            price_service: None,
+            view,
+            rates,
+        }
+    }
+
+    /// Create with live prices from the asset price service
+    pub fn with_price_service(
+        service: Arc<dyn voice_agent_persistence::AssetPriceService>,
+        view: Arc<ToolsDomainView>,
+    ) -> Self {
+        let rates = Arc::new(RateCardEngine::new(view.clone()));
+        Self {
+            price_service: Some(service),
+            view,
+            rates,
+        }
+    }
+
+    /// Use published rate cards as well as those in config
+    pub fn with_rate_cards(mut self, rates: Arc<RateCardEngine>) -> Self {
+        self.rates = rates;
+        self
+    }
+
+    /// Collateral value at today's price, with the price source
+    async fn collateral_value(&self, weight: f64, variant: &str) -> (f64, String) {
+        if let Some(ref service) = self.price_service {
+            match service.get_current_price().await {
+                Ok(price) => {
+                    let per_unit = price.tier_prices.get(variant).copied().unwrap_or_else(|| {
+                        price.base_price_per_unit * self.view.purity_factor(variant)
+                    });
+                    return (weight * per_unit, price.source);
+                },
+                Err(e) => {
+                    tracing::warn!("Failed to get price from service: {}", e);
+                },
+            }
+        }
+        (
+            self.view.calculate_asset_value(weight, variant),
+            "fallback".to_string(),
+        )
+    }
+
+    /// Renewal at the full eligible limit, one option per configured tenure
+    ///
+    /// Each tenure is priced from its own rate card slab.
+    fn renewal_options(&self, renewal_limit: f64, outstanding: f64) -> Vec<Value> {
+        let option = |tenure_months: Option<u32>| {
+            let quote = self.rates.quote(renewal_limit, tenure_months, None);
+            let rate = quote.rate_percent;
+            let processing_fee = renewal_limit * quote.processing_fee_percent / 100.0;
+            let net_disbursal = (renewal_limit - outstanding - processing_fee).max(0.0);
+            let monthly_interest = renewal_limit * rate / 1200.0;
+            json!({
+                "tenure_months": tenure_months,
+                "loan_amount": renewal_limit.round(),
+                "interest_rate_percent": rate,
+                "processing_fee": processing_fee.round(),
+                "net_disbursal": net_disbursal.round(),
+                "monthly_interest": monthly_interest.round(),
+            })
+        };
+
+        let tenures = self.view.renewal_tenures_months();
+        if tenures.is_empty() {
+            vec![option(None)]
+        } else {
+            tenures.iter().map(|t| option(Some(*t))).collect()
+        }
+    }
+
+    fn message(&self, scenario: &str, vars_extra: &[(&str, String)]) -> String {
+        let currency = self.view.currency_symbol();
+        let product = self.view.product_name();
+        let value = |key: &str| {
+            vars_extra
+                .iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v.clone())
+                .unwrap_or_default()
+        };
+
+        if self.view.has_response_templates(TOOL_NAME) {
+            let mut vars = self.view.default_template_vars();
+            vars.insert("collateral_type".to_string(), product.to_string());
+            vars.insert("currency".to_string(), currency.to_string());
+            for (key, val) in vars_extra {
+                vars.insert(key.to_string(), val.clone());
+            }
+            if let Some(message) = self.view.render_response(TOOL_NAME, scenario, "en", &vars) {
+                return message;
+            }
+        }
+
+        match scenario {
+            "top_up_available" => format!(
+                "You can get a top-up of up to {}{} on your current pledge at {}% interest.",
+                currency,
+                value("top_up_amount"),
+                value("interest_rate")
+            ),
+            "renewal_only" => format!(
+                "A top-up isn't available right now, but you can renew your loan at {}% interest.",
+                value("interest_rate")
+            ),
+            _ => format!(
+                "Your outstanding is above the current limit; a part-payment of {}{} is needed to renew.",
+                currency,
+                value("shortfall_amount")
+            ),
+        }
+    }
+}
+
+#[async_trait]
+impl Tool for TopUpEligibilityTool {
+    fn name(&self) -> &str {
+        self.view
+            .tools_config()
+            .get_tool(TOOL_NAME)
+            .map(|t| t.name.as_str())
+            .unwrap_or(TOOL_NAME)
+    }
+
+    fn description(&self) -> &str {
+        "Check top-up amount and renewal options on an existing pledge"
+    }
+
+    fn schema(&self) -> ToolSchema {
+        if let Some(core_schema) = self.view.tools_config().get_core_schema(TOOL_NAME) {
+            core_schema
+        } else {
+            tracing::warn!(
+                "Tool schema not found in config for {}, using generic fallback",
+                TOOL_NAME
+            );
+            ToolSchema {
+                name: TOOL_NAME.to_string(),
+                description: self.description().to_string(),
+                input_schema: InputSchema::object()
+                    .property(
+                        "current_outstanding",
+                        PropertySchema::number("Outstanding principal on the existing loan"),
+                        true,
+                    )
+                    .property(
+                        "collateral_weight",
+                        PropertySchema::number("Weight/quantity of pledged collateral"),
+                        true,
+                    )
+                    .property(
+                        "collateral_variant",
+                        PropertySchema::string("Variant/grade of collateral"),
+                        false,
+                    ),
+            }
+        }
+    }
+
+    async fn execute(&self, input: Value) -> Result<ToolOutput, ToolError> {
+        let tools_config = self.view.tools_config();
+        let outstanding = tools_config
+            .get_numeric_param_with_aliases(&input, "current_outstanding")
+            .ok_or_else(|| ToolError::invalid_params("current_outstanding is required"))?;
+        if outstanding < 0.0 {
+            return Err(ToolError::invalid_params(
+                "current_outstanding cannot be negative",
+            ));
+        }
+        let weight = tools_config
+            .get_numeric_param_with_aliases(&input, "collateral_weight")
+            .ok_or_else(|| ToolError::invalid_params("collateral_weight is required"))?;
+        let variant = tools_config
+            .get_string_param_with_aliases(&input, "collateral_variant")
+            .unwrap_or_else(|| self.view.default_quality_tier_display());
+
+        let (collateral_value, price_source) = self.collateral_value(weight, &variant).await;
+        let ltv_cap = self
+            .view
+            .top_up_ltv_percent()
+            .min(self.rates.ltv_percent(Some(&variant)));
+        let renewal_limit = (collateral_value * ltv_cap / 100.0).min(self.view.max_loan_amount());
+        let headroom = renewal_limit - outstanding;
+        let min_top_up = self.view.top_up_min_amount();
+        let current_ltv = if collateral_value > 0.0 {
+            outstanding / collateral_value * 100.0
+        } else {
+            0.0
+        };
+
+        let top_up_available = headroom >= min_top_up;
+        let top_up_amount = if top_up_available { headroom } else { 0.0 };
+        let shortfall = (-headroom).max(0.0);
+        let quote = self.rates.quote(renewal_limit, None, Some(&variant));
+        let interest_rate = quote.rate_percent;
+
+        let scenario = if top_up_available {
+            "top_up_available"
+        } else if headroom >= 0.0 {
+            "renewal_only"
+        } else {
+            "part_payment_required"
+        };
+        let message = self.message(
+            scenario,
+            &[
+                ("top_up_amount", format!("{:.0}", top_up_amount)),
+                ("shortfall_amount", format!("{:.0}", shortfall)),
+                ("interest_rate", format!("{:.1}", interest_rate)),
+            ],
+        );
+
+        let suffix = self.view.currency_field_suffix();
+        let result = json!({
+            "top_up_available": top_up_available,
+            "scenario": scenario,
+            format!("collateral_value_{}", suffix): collateral_value.round(),
+            format!("current_outstanding_{}", suffix): outstanding.round(),
+            format!("max_loan_amount_{}", suffix): renewal_limit.round(),
+            format!("top_up_amount_{}", suffix): top_up_amount.round(),
+            format!("min_top_up_{}", suffix): min_top_up,
+            format!("part_payment_required_{}", suffix): shortfall.round(),
+            "current_ltv_percent": (current_ltv * 10.0).round() / 10.0,
+            "max_ltv_percent": ltv_cap,
+            "interest_rate_percent": interest_rate,
+            "rate_card_version": quote.card_version,
+            "renewal_options": self.renewal_options(renewal_limit, outstanding),
+            "price_source": price_source,
+            "message": message
+        });
+
+        Ok(ToolOutput::json(result))
+    }
+}
@@ -0,0 +1,675 @@
+//! Resilient Tool Execution
+//!
+//! [`ResilientToolExecutor`] wraps any [`ToolExecutor`] with the
+//! `tool_execution` policy:
+//! - Per-tool timeouts (tightening the tool's own timeout)
+//! - Retries with backoff for transient failures (timeouts, backend errors)
+//! - Circuit breakers per backend, so a ScyllaDB outage makes the tools that
+//!   depend on it answer with a friendly fallback instead of timing out on
+//!   every turn
+//! - Duplicate suppression for side-effecting tools: a session's repeat of
+//!   the same call (same idempotency key) gets the first call's result, so a
+//!   lead or SMS is never created twice
+//!
+//! Every call is counted in metrics, and written to the audit log when it is
+//! made for a session and an audit logger is attached.
+
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use serde_json::Value;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use voice_agent_config::{ToolExecutionConfig, ToolPolicyConfig};
+use voice_agent_core::ErrorClass;
+use voice_agent_persistence::AuditLogger;
+
+use crate::crm::RetryPolicy;
+use crate::mcp::{ToolError, ToolOutput, ToolSchema};
+use crate::registry::ToolExecutor;
+
+/// Upper bound on the delay between retries
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(2);
+
+/// Circuit breaker state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Calls flow normally
+    Closed,
+    /// Calls are short-circuited to the fallback reply
+    Open,
+    /// Cool-down elapsed; one trial call is let through
+    HalfOpen,
+}
+
+/// How a tool call ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionOutcome {
+    Success,
+    /// Transient failure that survived all retries
+    Failed,
+    /// Caller error (unknown tool, invalid arguments); never retried
+    Rejected,
+    /// Circuit open; answered with the fallback reply
+    ShortCircuited,
+}
+
+impl ExecutionOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Failed => "failed",
+            Self::Rejected => "rejected",
+            Self::ShortCircuited => "short_circuited",
+        }
+    }
+}
+
+/// Consecutive-failure breaker for one backend
+#[derive(Debug, Default)]
+struct CircuitBreaker {
+    failures: u32,
+    opened_at: Option<Instant>,
+    trial_in_flight: bool,
+}
+
+impl CircuitBreaker {
+    fn state(&self, open_for: Duration) -> CircuitState {
+        match self.opened_at {
+            None => CircuitState::Closed,
+            Some(at) if at.elapsed() < open_for => CircuitState::Open,
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+
+    fn admits(&self, open_for: Duration) -> bool {
+        match self.state(open_for) {
+            CircuitState::Closed => true,
+            CircuitState::Open => false,
+            CircuitState::HalfOpen => !self.trial_in_flight,
+        }
+    }
+
+    /// Record a failed call; returns true if it opened the circuit
+    fn record_failure(&mut self, threshold: u32) -> bool {
+        self.failures += 1;
+        let trial_failed = std::mem::take(&mut self.trial_in_flight);
+        if trial_failed || (self.opened_at.is_none() && self.failures >= threshold) {
+            self.opened_at = Some(Instant::now());
+            return true;
+        }
+        false
+    }
+}
+
+/// Result of a deduplicated call, shared by every call with its idempotency
+/// key; the async lock makes a concurrent repeat wait for the first call
+type Invocation = Arc<tokio::sync::Mutex<Option<(Instant, ToolOutput)>>>;
+
+/// Tool executor enforcing timeouts, retries and circuit breakers
+pub struct ResilientToolExecutor {
+    inner: Arc<dyn ToolExecutor>,
+    config: ToolExecutionConfig,
+    circuits: Mutex<HashMap<String, CircuitBreaker>>,
+    /// Recent invocations of dedupe tools, by idempotency key
+    invocations: Mutex<HashMap<String, Invocation>>,
+    audit_logger: Option<Arc<AuditLogger>>,
+}
+
+impl ResilientToolExecutor {
+    pub fn new(inner: Arc<dyn ToolExecutor>, config: ToolExecutionConfig) -> Self {
+        Self {
+            inner,
+            config,
+            circuits: Mutex::new(HashMap::new()),
+            invocations: Mutex::new(HashMap::new()),
+            audit_logger: None,
+        }
+    }
+
+    /// Write every session tool call to the audit log
+    pub fn with_audit_logger(mut self, logger: Arc<AuditLogger>) -> Self {
+        self.audit_logger = Some(logger);
+        self
+    }
+
+    /// State of a backend's circuit (`Closed` if never used)
+    pub fn circuit_state(&self, circuit: &str) -> CircuitState {
+        self.circuits
+            .lock()
+            .get(circuit)
+            .map(|breaker| breaker.state(self.open_for()))
+            .unwrap_or(CircuitState::Closed)
+    }
+
+    fn policy(&self, name: &str) -> Option<&ToolPolicyConfig> {
+        self.config.tools.get(name)
+    }
+
+    /// Circuits guarding a tool: its declared backends, else the tool itself
+    fn circuit_keys(&self, name: &str) -> Vec<String> {
+        match self.policy(name) {
+            Some(policy) if !policy.depends_on.is_empty() => policy.depends_on.clone(),
+            _ => vec![name.to_string()],
+        }
+    }
+
+    fn retry_policy(&self, name: &str) -> RetryPolicy {
+        let max_attempts = self
+            .policy(name)
+            .and_then(|policy| policy.max_attempts)
+            .unwrap_or(self.config.max_attempts);
+        RetryPolicy {
+            max_attempts: max_attempts.max(1),
+            initial_backoff: Duration::from_millis(self.config.retry_backoff_ms),
+            max_backoff: MAX_RETRY_BACKOFF,
+            ..RetryPolicy::default()
+        }
+    }
+
+    fn fallback_message(&self, name: &str) -> String {
+        self.policy(name)
+            .and_then(|policy| policy.fallback_message.clone())
+            .unwrap_or_else(|| self.config.fallback_message.clone())
+    }
+
+    fn open_for(&self) -> Duration {
+        Duration::from_secs(self.config.open_secs)
+    }
+
+    fn dedupe_window(&self) -> Duration {
+        Duration::from_secs(self.config.dedupe_window_secs)
+    }
+
+    /// Run a side-effecting tool at most once per idempotency key
+    ///
+    /// Only successful results are kept, so a failed call can be retried by
+    /// the next repeat.
+    async fn run_once(
+        &self,
+        session_id: &str,
+        name: &str,
+        arguments: Value,
+        ignore: &[String],
+    ) -> Result<ToolOutput, ToolError> {
+        let key = idempotency_key(session_id, name, &arguments, ignore);
+        let window = self.dedupe_window();
+        let invocation = {
+            let mut invocations = self.invocations.lock();
+            // In-flight calls hold their lock and are kept
+            invocations.retain(|_, invocation| match invocation.try_lock() {
+                Ok(result) => result.as_ref().is_some_and(|(at, _)| at.elapsed() < window),
+                Err(_) => true,
+            });
+            invocations.entry(key.clone()).or_default().clone()
+        };
+
+        let mut result = invocation.lock().await;
+        if let Some((at, output)) = result.as_ref() {
+            if at.elapsed() < window {
+                tracing::info!(
+                    session_id,
+                    tool = name,
+                    key = %key,
+                    "Duplicate tool call suppressed"
+                );
+                metrics::counter!("voice_agent_tool_duplicates_total", "tool" => name.to_string())
+                    .increment(1);
+                return Ok(output.clone());
+            }
+        }
+
+        let output = self.run(Some(session_id), name, arguments).await?;
+        if !output.is_error {
+            *result = Some((Instant::now(), output.clone()));
+        }
+        Ok(output)
+    }
+
+    /// Admit a call if every circuit allows it, claiming half-open trial slots
+    fn acquire(&self, keys: &[String]) -> bool {
+        let open_for = self.open_for();
+        let mut circuits = self.circuits.lock();
+        let admitted = keys.iter().all(|key| {
+            circuits
+                .get(key)
+                .map(|breaker| breaker.admits(open_for))
+                .unwrap_or(true)
+        });
+        if admitted {
+            for key in keys {
+                let breaker = circuits.entry(key.clone()).or_default();
+                if breaker.state(open_for) == CircuitState::HalfOpen {
+                    breaker.trial_in_flight = true;
+                }
+            }
+        }
+        admitted
+    }
+
+    /// Update circuits with a call's outcome
+    fn settle(&self, keys: &[String], outcome: ExecutionOutcome) {
+        let mut circuits = self.circuits.lock();
+        for key in keys {
+            let breaker = circuits.entry(key.clone()).or_default();
+            match outcome {
+                ExecutionOutcome::Success => {
+                    if breaker.opened_at.is_some() {
+                        tracing::info!(circuit = %key, "Tool circuit closed");
+                        metrics::gauge!("voice_agent_tool_circuit_open", "circuit" => key.clone())
+                            .set(0.0);
+                    }
+                    *breaker = CircuitBreaker::default();
+                },
+                ExecutionOutcome::Failed => {
+                    if breaker.record_failure(self.config.failure_threshold) {
+                        tracing::warn!(
+                            circuit = %key,
+                            failures = breaker.failures,
+                            open_secs = self.config.open_secs,
+                            "Tool circuit opened"
+                        );
+                        metrics::gauge!("voice_agent_tool_circuit_open", "circuit" => key.clone())
+                            .set(1.0);
+                    }
+                },
+                // A caller error says nothing about the backend; free the trial slot
+                ExecutionOutcome::Rejected | ExecutionOutcome::ShortCircuited => {
+                    breaker.trial_in_flight = false;
+                },
+            }
+        }
+    }
+
+    async fn attempt(
+        &self,
+        name: &str,
+        arguments: Value,
+        timeout_secs: Option<u64>,
+    ) -> Result<ToolOutput, ToolError> {
+        let Some(secs) = timeout_secs else {
+            return self.inner.execute(name, arguments).await;
+        };
+        tokio::time::timeout(
+            Duration::from_secs(secs),
+            self.inner.execute(name, arguments),
+        )
+        .await
+        .unwrap_or_else(|_| Err(ToolError::timeout(name, secs)))
+    }
+
+    async fn run(
+        &self,
+        session_id: Option<&str>,
+        name: &str,
+        arguments: Value,
+    ) -> Result<ToolOutput, ToolError> {
+        let started = Instant::now();
+        let keys = self.circuit_keys(name);
+
+        if !self.acquire(&keys) {
+            tracing::debug!(tool = name, circuits = ?keys, "Tool call short-circuited");
+            self.record(
+                session_id,
+                name,
+                ExecutionOutcome::ShortCircuited,
+                0,
+                started.elapsed(),
+                None,
+            );
+            return Ok(ToolOutput::error(self.fallback_message(name)));
+        }
+
+        let retry = self.retry_policy(name);
+        let timeout_secs = self.policy(name).and_then(|policy| policy.timeout_secs);
+        let mut attempts = 0;
+        let result = loop {
+            attempts += 1;
+            let result = self.attempt(name, arguments.clone(), timeout_secs).await;
+            match result {
+                Err(ref e) if is_transient(e) && attempts < retry.max_attempts => {
+                    tracing::debug!(tool = name, attempt = attempts, error = %e, "Retrying tool");
+                    tokio::time::sleep(retry.backoff_for(attempts)).await;
+                },
+                _ => break result,
+            }
+        };
+
+        let outcome = match &result {
+            Ok(_) => ExecutionOutcome::Success,
+            Err(e) if is_transient(e) => ExecutionOutcome::Failed,
+            Err(_) => ExecutionOutcome::Rejected,
+        };
+        self.settle(&keys, outcome);
+        self.record(
+            session_id,
+            name,
+            outcome,
+            attempts,
+            started.elapsed(),
+            result.as_ref().err(),
+        );
+        result
+    }
+
+    /// Metrics for every call; audit entry for session calls
+    fn record(
+        &self,
+        session_id: Option<&str>,
+        name: &str,
+        outcome: ExecutionOutcome,
+        attempts: u32,
+        elapsed: Duration,
+        error: Option<&ToolError>,
+    ) {
+        metrics::counter!(
+            "voice_agent_tool_executions_total",
+            "tool" => name.to_string(),
+            "outcome" => outcome.as_str()
+        )
+        .increment(1);
+        metrics::histogram!("voice_agent_tool_duration_seconds", "tool" => name.to_string())
+            .record(elapsed.as_secs_f64());
+        if attempts > 1 {
+            metrics::counter!("voice_agent_tool_retries_total", "tool" => name.to_string())
+                .increment(u64::from(attempts - 1));
+        }
+
+        let (Some(logger), Some(session_id)) = (&self.audit_logger, session_id) else {
+            return;
+        };
+        let logger = logger.clone();
+        let session_id = session_id.to_string();
+        let tool = name.to_string();
+        let details = serde_json::json!({
+            "outcome": outcome.as_str(),
+            "attempts": attempts,
+            "duration_ms": elapsed.as_millis() as u64,
+            "error": error.map(|e| e.message.clone()),
+        });
+        // Off the response path: chaining the entry costs a ScyllaDB round trip
+        tokio::spawn(async move {
+            let success = outcome == ExecutionOutcome::Success;
+            if let Err(e) = logger
+                .log_tool_execution(&session_id, &tool, success, details)
+                .await
+            {
+                tracing::warn!(tool = %tool, error = %e, "Failed to audit tool execution");
+            }
+        });
+    }
+}
+
+/// Timeouts and backend errors are worth retrying; bad calls are not
+fn is_transient(error: &ToolError) -> bool {
+    error.is_retryable()
+}
+
+/// Per-session idempotency key for a call: session, tool and a hash of the
+/// canonicalized arguments
+///
+/// Object keys are sorted, strings trimmed and lowercased and numbers compared
+/// by value (`5` and `5.0` match); top-level arguments in `ignore` are left out.
+pub fn idempotency_key(
+    session_id: &str,
+    tool: &str,
+    arguments: &Value,
+    ignore: &[String],
+) -> String {
+    let arguments = match arguments {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .filter(|(key, _)| !ignore.contains(key))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+        ),
+        other => other.clone(),
+    };
+    let mut hasher = DefaultHasher::new();
+    canonical(&arguments).to_string().hash(&mut hasher);
+    format!("{}:{}:{:016x}", session_id, tool, hasher.finish())
+}
+
+fn canonical(value: &Value) -> Value {
+    match value {
+        Value::String(s) => Value::String(s.trim().to_lowercase()),
+        Value::Number(n) => n
+            .as_f64()
+            .and_then(serde_json::Number::from_f64)
+            .map(Value::Number)
+            .unwrap_or_else(|| value.clone()),
+        Value::Array(items) => Value::Array(items.iter().map(canonical).collect()),
+        // serde_json maps are ordered by key
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| (key.clone(), canonical(value)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+#[async_trait]
+impl ToolExecutor for ResilientToolExecutor {
+    async fn execute(&self, name: &str, arguments: Value) -> Result<ToolOutput, ToolError> {
+        self.run(None, name, arguments).await
+    }
+
+    fn list_tools(&self) -> Vec<ToolSchema> {
+        self.inner.list_tools()
+    }
+
+    fn get_tool(&self, name: &str) -> Option<ToolSchema> {
+        self.inner.get_tool(name)
+    }
+
+    async fn execute_for_session(
+        &self,
+        session_id: &str,
+        name: &str,
+        arguments: Value,
+    ) -> Result<ToolOutput, ToolError> {
+        match self.policy(name).filter(|policy| policy.dedupe) {
+            Some(policy) => {
+                self.run_once(session_id, name, arguments, &policy.dedupe_ignore)
+                    .await
+            },
+            None => self.run(Some(session_id), name, arguments).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mcp::ErrorCode;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// Fails the first `failures` calls with `code`, then succeeds
+    struct FlakyBackend {
+        calls: AtomicU32,
+        failures: u32,
+        code: ErrorCode,
+    }
+
+    impl FlakyBackend {
+        fn new(failures: u32, code: ErrorCode) -> Arc<Self> {
+            Arc::new(Self {
+                calls: AtomicU32::new(0),
+                failures,
+                code,
+            })
+        }
+
+        fn calls(&self) -> u32 {
+            self.calls.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl ToolExecutor for FlakyBackend {
+        async fn execute(&self, _name: &str, _arguments: Value) -> Result<ToolOutput, ToolError> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            if call <= self.failures {
+                Err(ToolError {
+                    code: self.code,
+                    message: "backend unavailable".to_string(),
+                    data: None,
+                })
+            } else {
+                Ok(ToolOutput::text("ok"))
+            }
+        }
+
+        fn list_tools(&self) -> Vec<ToolSchema> {
+            Vec::new()
+        }
+
+        fn get_tool(&self, _name: &str) -> Option<ToolSchema> {
+            None
+        }
+    }
+
+    fn scylla_tools(open_secs: u64) -> ToolExecutionConfig {
+        let depends_on_scylla = ToolPolicyConfig {
+            depends_on: vec!["scylla".to_string()],
+            ..Default::default()
+        };
+        ToolExecutionConfig {
+            max_attempts: 1,
+            retry_backoff_ms: 0,
+            failure_threshold: 2,
+            open_secs,
+            tools: HashMap::from([
+                ("check_eligibility".to_string(), depends_on_scylla.clone()),
+                ("schedule_appointment".to_string(), depends_on_scylla),
+            ]),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retries_transient_errors_only() {
+        let config = ToolExecutionConfig {
+            max_attempts: 3,
+            retry_backoff_ms: 0,
+            ..Default::default()
+        };
+
+        let backend = FlakyBackend::new(2, ErrorCode::InternalError);
+        let executor = ResilientToolExecutor::new(backend.clone(), config.clone());
+        assert!(executor.execute("get_price", Value::Null).await.is_ok());
+        assert_eq!(backend.calls(), 3);
+
+        let backend = FlakyBackend::new(1, ErrorCode::InvalidParams);
+        let executor = ResilientToolExecutor::new(backend.clone(), config);
+        assert!(executor.execute("get_price", Value::Null).await.is_err());
+        assert_eq!(backend.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_shared_dependency_short_circuits() {
+        let backend = FlakyBackend::new(u32::MAX, ErrorCode::InternalError);
+        let executor = ResilientToolExecutor::new(backend.clone(), scylla_tools(60));
+
+        for _ in 0..2 {
+            assert!(executor
+                .execute("check_eligibility", Value::Null)
+                .await
+                .is_err());
+        }
+        assert_eq!(executor.circuit_state("scylla"), CircuitState::Open);
+
+        // A different tool on the same backend gets the fallback without a call
+        let output = executor
+            .execute("schedule_appointment", Value::Null)
+            .await
+            .unwrap();
+        assert!(output.is_error);
+        assert_eq!(backend.calls(), 2);
+
+        // Tools without the dependency are unaffected
+        assert!(executor
+            .execute("find_locations", Value::Null)
+            .await
+            .is_err());
+        assert_eq!(backend.calls(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_dedupe_runs_side_effect_once_per_session() {
+        let backend = FlakyBackend::new(0, ErrorCode::InternalError);
+        let capture_lead = ToolPolicyConfig {
+            dedupe: true,
+            dedupe_ignore: vec!["lead_score".to_string()],
+            ..Default::default()
+        };
+        let config = ToolExecutionConfig {
+            tools: HashMap::from([("capture_lead".to_string(), capture_lead)]),
+            ..Default::default()
+        };
+        let executor = ResilientToolExecutor::new(backend.clone(), config);
+
+        let lead = |score: u32| {
+            serde_json::json!({"name": "Priya ", "phone": 9876543210u64, "lead_score": score})
+        };
+        for score in [40, 55] {
+            let output = executor
+                .execute_for_session("s1", "capture_lead", lead(score))
+                .await
+                .unwrap();
+            assert!(!output.is_error);
+        }
+        assert_eq!(backend.calls(), 1);
+
+        // Another session, or a tool that is not deduplicated, runs again
+        executor
+            .execute_for_session("s2", "capture_lead", lead(40))
+            .await
+            .unwrap();
+        executor
+            .execute_for_session("s1", "get_price", Value::Null)
+            .await
+            .unwrap();
+        executor
+            .execute_for_session("s1", "get_price", Value::Null)
+            .await
+            .unwrap();
+        assert_eq!(backend.calls(), 4);
+    }
+
+    #[test]
+    fn test_idempotency_key_canonicalizes_arguments() {
+        let key = |arguments: Value| idempotency_key("s1", "send_sms", &arguments, &[]);
+        assert_eq!(
+            key(serde_json::json!({"phone": "9876543210", "amount": 5})),
+            key(serde_json::json!({"amount": 5.0, "phone": " 9876543210"}))
+        );
+        assert_ne!(
+            key(serde_json::json!({"phone": "9876543210"})),
+            key(serde_json::json!({"phone": "9876543211"}))
+        );
+        assert!(key(Value::Null).starts_with("s1:send_sms:"));
+    }
+
+    #[tokio::test]
+    async fn test_half_open_trial_closes_circuit() {
+        let backend = FlakyBackend::new(2, ErrorCode::InternalError);
+        // Zero cool-down: the circuit is half-open as soon as it opens
+        let executor = ResilientToolExecutor::new(backend.clone(), scylla_tools(0));
+
+        for _ in 0..2 {
+            let _ = executor.execute("check_eligibility", Value::Null).await;
+        }
+        assert_eq!(executor.circuit_state("scylla"), CircuitState::HalfOpen);
+
+        assert!(executor
+            .execute("check_eligibility", Value::Null)
+            .await
+            .is_ok());
+        assert_eq!(executor.circuit_state("scylla"), CircuitState::Closed);
+    }
+}
@@ -16,11 +16,22 @@
 
 use std::sync::Arc;
 
-use voice_agent_config::{MasterDomainConfig, ToolsDomainView};
+use voice_agent_config::{
+    HandoffConfig, MasterDomainConfig, PriceAlertConfig, ToolSchema as ConfigToolSchema,
+    ToolsDomainView,
+};
 use voice_agent_core::traits::{Tool, ToolFactory, ToolFactoryError, ToolMetadata};
+use voice_agent_persistence::{
+    CallbackStore, CompetitorRateStore, InMemoryCallbackStore, InMemorySlotStore, OfferEventStore,
+    PriceAlertStore, SlotStore,
+};
 
+use crate::configured::ConfiguredTool;
 use crate::domain_tools;
+use crate::handoff::HandoffQueue;
+use crate::http_tool::HttpTool;
 use crate::integrations::{CalendarIntegration, CrmIntegration};
+use crate::rate_cards::RateCardEngine;
 
 /// External integrations that some tools may need
 #[derive(Default)]
@@ -33,6 +44,24 @@ pub struct ToolIntegrations {
     pub sms_service: Option<Arc<dyn voice_agent_persistence::SmsService>>,
     /// Asset price service for price lookups
     pub price_service: Option<Arc<dyn voice_agent_persistence::AssetPriceService>>,
+    /// Branch visit slot capacity (in-memory when not set)
+    pub slot_store: Option<Arc<dyn SlotStore>>,
+    /// Admin-maintained competitor rates (config rates only when not set)
+    pub competitor_rates: Option<Arc<dyn CompetitorRateStore>>,
+    /// LTV and rate cards, including published ones (config cards only when not set)
+    pub rate_cards: Option<Arc<RateCardEngine>>,
+    /// Requested callbacks (in-memory when not set)
+    pub callbacks: Option<Arc<dyn CallbackStore>>,
+    /// Queue escalations are handed to human agents through
+    pub handoff_queue: Option<Arc<dyn HandoffQueue>>,
+    /// Handoff packaging settings
+    pub handoff: HandoffConfig,
+    /// Price alert subscriptions (create_price_alert unavailable when not set)
+    pub price_alerts: Option<Arc<dyn PriceAlertStore>>,
+    /// Price alert limits
+    pub price_alert_config: PriceAlertConfig,
+    /// Offer mention and acceptance tracking (record_offer_response unavailable when not set)
+    pub offer_events: Option<Arc<dyn OfferEventStore>>,
 }
 
 impl ToolIntegrations {
@@ -48,6 +77,15 @@ impl ToolIntegrations {
             calendar: Some(Arc::new(crate::integrations::StubCalendarIntegration::new())),
             sms_service: None,
             price_service: None,
+            slot_store: None,
+            competitor_rates: None,
+            rate_cards: None,
+            callbacks: None,
+            handoff_queue: None,
+            handoff: HandoffConfig::default(),
+            price_alerts: None,
+            price_alert_config: PriceAlertConfig::default(),
+            offer_events: None,
         }
     }
 
@@ -78,18 +116,74 @@ impl ToolIntegrations {
         self
     }
 
+    /// Set branch visit slot store
+    pub fn with_slot_store(mut self, slots: Arc<dyn SlotStore>) -> Self {
+        self.slot_store = Some(slots);
+        self
+    }
+
+    /// Set competitor rate store
+    pub fn with_competitor_rate_store(mut self, rates: Arc<dyn CompetitorRateStore>) -> Self {
+        self.competitor_rates = Some(rates);
+        self
+    }
+
+    /// Set the rate card engine shared by the rate and LTV tools
+    pub fn with_rate_cards(mut self, rate_cards: Arc<RateCardEngine>) -> Self {
+        self.rate_cards = Some(rate_cards);
+        self
+    }
+
+    /// Set callback store
+    pub fn with_callback_store(mut self, callbacks: Arc<dyn CallbackStore>) -> Self {
+        self.callbacks = Some(callbacks);
+        self
+    }
+
+    /// Set the human handoff queue
+    pub fn with_handoff_queue(
+        mut self,
+        queue: Arc<dyn HandoffQueue>,
+        handoff: HandoffConfig,
+    ) -> Self {
+        self.handoff_queue = Some(queue);
+        self.handoff = handoff;
+        self
+    }
+
+    /// Set the price alert store
+    pub fn with_price_alerts(
+        mut self,
+        store: Arc<dyn PriceAlertStore>,
+        config: PriceAlertConfig,
+    ) -> Self {
+        self.price_alerts = Some(store);
+        self.price_alert_config = config;
+        self
+    }
+
+    /// Set the offer event store
+    pub fn with_offer_events(mut self, events: Arc<dyn OfferEventStore>) -> Self {
+        self.offer_events = Some(events);
+        self
+    }
+
     /// Create from persistence layer
     pub fn from_persistence(persistence: &voice_agent_persistence::PersistenceLayer) -> Self {
         Self {
             crm: Some(Arc::new(crate::integrations::StubCrmIntegration::new())),
             calendar: Some(Arc::new(crate::integrations::StubCalendarIntegration::new())),
-            sms_service: Some(
-                Arc::new(persistence.sms.clone()) as Arc<dyn voice_agent_persistence::SmsService>
-            ),
-            price_service: Some(
-                Arc::new(persistence.asset_price.clone())
-                    as Arc<dyn voice_agent_persistence::AssetPriceService>,
-            ),
+            sms_service: Some(persistence.sms.clone()),
+            price_service: Some(persistence.asset_price.clone()),
+            slot_store: Some(persistence.slots.clone()),
+            competitor_rates: Some(persistence.competitor_rates.clone()),
+            rate_cards: None,
+            callbacks: Some(persistence.callbacks.clone()),
+            handoff_queue: None,
+            handoff: HandoffConfig::default(),
+            price_alerts: None,
+            price_alert_config: PriceAlertConfig::default(),
+            offer_events: Some(persistence.offer_events.clone()),
         }
     }
 }
@@ -109,28 +203,23 @@ pub struct DomainToolFactory {
     view: Arc<ToolsDomainView>,
     domain_id: String,
     integrations: ToolIntegrations,
+    /// Shared by the slot and appointment tools so holds and bookings agree
+    slot_store: Arc<dyn SlotStore>,
+    /// Callback store, kept so every schedule_callback instance shares it
+    callbacks: Arc<dyn CallbackStore>,
+    /// Shared by the eligibility, savings and top-up tools
+    rate_cards: Arc<RateCardEngine>,
 }
 
 impl DomainToolFactory {
     /// Create a new factory with required domain config
     pub fn new(config: Arc<MasterDomainConfig>) -> Self {
-        let domain_id = config.domain_id.clone();
-        let view = Arc::new(ToolsDomainView::new(config));
-        Self {
-            view,
-            domain_id,
-            integrations: ToolIntegrations::default(),
-        }
+        Self::with_integrations(config, ToolIntegrations::default())
     }
 
     /// Create a new factory with view (for compatibility)
     pub fn with_view(view: Arc<ToolsDomainView>) -> Self {
-        let domain_id = view.domain_id().to_string();
-        Self {
-            view,
-            domain_id,
-            integrations: ToolIntegrations::default(),
-        }
+        Self::with_view_and_integrations(view, ToolIntegrations::default())
     }
 
     /// Create a factory with integrations
@@ -138,13 +227,8 @@ impl DomainToolFactory {
         config: Arc<MasterDomainConfig>,
         integrations: ToolIntegrations,
     ) -> Self {
-        let domain_id = config.domain_id.clone();
         let view = Arc::new(ToolsDomainView::new(config));
-        Self {
-            view,
-            domain_id,
-            integrations,
-        }
+        Self::with_view_and_integrations(view, integrations)
     }
 
     /// Create a factory with view and integrations
@@ -153,10 +237,25 @@ impl DomainToolFactory {
         integrations: ToolIntegrations,
     ) -> Self {
         let domain_id = view.domain_id().to_string();
+        let slot_store = integrations
+            .slot_store
+            .clone()
+            .unwrap_or_else(|| Arc::new(InMemorySlotStore::new()));
+        let callbacks = integrations
+            .callbacks
+            .clone()
+            .unwrap_or_else(|| Arc::new(InMemoryCallbackStore::new()));
+        let rate_cards = integrations
+            .rate_cards
+            .clone()
+            .unwrap_or_else(|| Arc::new(RateCardEngine::new(view.clone())));
         Self {
             view,
             domain_id,
             integrations,
+            slot_store,
+            callbacks,
+            rate_cards,
         }
     }
 
@@ -167,23 +266,33 @@ impl DomainToolFactory {
 
     /// Create a tool based on its name from config
     ///
-    /// This maps tool names to their implementations. New tools can be added
-    /// to config and registered here to be available without registry changes.
+    /// Tools with an `http` block are served by their endpoint; compiled-in
+    /// tools get their configured description and parameters overlaid.
     fn create_tool_by_name(&self, name: &str) -> Result<Arc<dyn Tool>, ToolFactoryError> {
-        // Get tool config to check category and metadata
-        let tool_config = self
-            .view
-            .tools_config()
-            .get_tool(name)
-            .or_else(|| {
-                // Check aliases
-                self.view
-                    .tools_config()
-                    .tools
-                    .values()
-                    .find(|t| t.matches_name(name))
-            });
+        let tool_config = self.view.tools_config().find_tool(name);
+
+        if let Some(schema) = tool_config.filter(|t| t.http.is_some()) {
+            let tool = HttpTool::from_config(schema)
+                .map_err(|e| ToolFactoryError::for_tool(name, e))?;
+            return Ok(Arc::new(tool));
+        }
+
+        let tool = self.create_builtin_tool(name, tool_config)?;
+        Ok(match tool_config {
+            Some(schema) => Arc::new(ConfiguredTool::new(tool, schema)),
+            None => tool,
+        })
+    }
 
+    /// Create a compiled-in tool
+    ///
+    /// This maps tool names to their implementations. New tools can be added
+    /// to config and registered here to be available without registry changes.
+    fn create_builtin_tool(
+        &self,
+        name: &str,
+        tool_config: Option<&ConfigToolSchema>,
+    ) -> Result<Arc<dyn Tool>, ToolFactoryError> {
         let category = tool_config
             .and_then(|t| t.category.as_deref())
             .unwrap_or("generic");
@@ -191,12 +300,29 @@ impl DomainToolFactory {
         // Create the appropriate tool based on name and category
         match name {
             // Calculation tools
-            "check_eligibility" => Ok(Arc::new(domain_tools::EligibilityCheckTool::new(
-                self.view.clone(),
-            ))),
-            "calculate_savings" => Ok(Arc::new(domain_tools::SavingsCalculatorTool::new(
-                self.view.clone(),
-            ))),
+            "check_eligibility" => Ok(Arc::new(
+                domain_tools::EligibilityCheckTool::new(self.view.clone())
+                    .with_rate_cards(self.rate_cards.clone()),
+            )),
+            "calculate_savings" => {
+                let tool = domain_tools::SavingsCalculatorTool::new(self.view.clone())
+                    .with_rate_cards(self.rate_cards.clone());
+                match self.integrations.competitor_rates {
+                    Some(ref rates) => Ok(Arc::new(tool.with_rate_store(rates.clone()))),
+                    None => Ok(Arc::new(tool)),
+                }
+            },
+            "check_top_up_eligibility" | "top_up_eligibility" => {
+                let tool = if let Some(ref service) = self.integrations.price_service {
+                    domain_tools::TopUpEligibilityTool::with_price_service(
+                        service.clone(),
+                        self.view.clone(),
+                    )
+                } else {
+                    domain_tools::TopUpEligibilityTool::new(self.view.clone())
+                };
+                Ok(Arc::new(tool.with_rate_cards(self.rate_cards.clone())))
+            }
 
             // Location tools
             "find_locations" | "find_branches" => {
@@ -218,9 +344,13 @@ impl DomainToolFactory {
             }
 
             // Comparison tools
-            "compare_providers" | "compare_lenders" => Ok(Arc::new(
-                domain_tools::CompetitorComparisonTool::new(self.view.clone()),
-            )),
+            "compare_providers" | "compare_lenders" => {
+                let tool = domain_tools::CompetitorComparisonTool::new(self.view.clone());
+                match self.integrations.competitor_rates {
+                    Some(ref rates) => Ok(Arc::new(tool.with_rate_store(rates.clone()))),
+                    None => Ok(Arc::new(tool)),
+                }
+            },
 
             // Communication tools
             "send_sms" => {
@@ -246,19 +376,64 @@ impl DomainToolFactory {
             }
 
             // Scheduling tools
-            "schedule_appointment" | "schedule_callback" | "book_appointment" => {
-                if let Some(ref calendar) = self.integrations.calendar {
-                    Ok(Arc::new(
-                        domain_tools::AppointmentSchedulerTool::with_calendar_and_view(
-                            calendar.clone(),
-                            self.view.clone(),
-                        ),
-                    ))
-                } else {
-                    Ok(Arc::new(domain_tools::AppointmentSchedulerTool::with_view(
+            "schedule_appointment" | "book_appointment" => {
+                let scheduler = if let Some(ref calendar) = self.integrations.calendar {
+                    domain_tools::AppointmentSchedulerTool::with_calendar_and_view(
+                        calendar.clone(),
                         self.view.clone(),
-                    )))
-                }
+                    )
+                } else {
+                    domain_tools::AppointmentSchedulerTool::with_view(self.view.clone())
+                };
+                Ok(Arc::new(scheduler.with_slot_store(self.slot_store.clone())))
+            }
+            "schedule_callback" | "request_callback" => Ok(Arc::new(
+                domain_tools::ScheduleCallbackTool::new(self.callbacks.clone(), self.view.clone()),
+            )),
+            "create_price_alert" | "price_alert" => {
+                let Some(ref store) = self.integrations.price_alerts else {
+                    return Err(ToolFactoryError::for_tool(
+                        name,
+                        "Price alerts are not enabled",
+                    ));
+                };
+                let tool = domain_tools::PriceAlertTool::new(
+                    store.clone(),
+                    self.view.clone(),
+                    self.integrations.price_alert_config.clone(),
+                );
+                Ok(Arc::new(match self.integrations.price_service {
+                    Some(ref prices) => tool.with_price_service(prices.clone()),
+                    None => tool,
+                }))
+            }
+            "check_slot_availability" | "find_free_slots" => Ok(Arc::new(
+                domain_tools::SlotAvailabilityTool::new(self.slot_store.clone(), self.view.clone()),
+            )),
+            "hold_appointment_slot" | "hold_slot" => Ok(Arc::new(domain_tools::SlotHoldTool::new(
+                self.slot_store.clone(),
+                self.view.clone(),
+            ))),
+
+            // Promotional offers
+            "check_offers" | "get_offers" => {
+                let tool = domain_tools::CheckOffersTool::new(self.view.clone());
+                Ok(Arc::new(match self.integrations.offer_events {
+                    Some(ref events) => tool.with_event_store(events.clone()),
+                    None => tool,
+                }))
+            }
+            "record_offer_response" => {
+                let Some(ref events) = self.integrations.offer_events else {
+                    return Err(ToolFactoryError::for_tool(
+                        name,
+                        "Offer tracking is not enabled",
+                    ));
+                };
+                Ok(Arc::new(domain_tools::OfferResponseTool::new(
+                    self.view.clone(),
+                    events.clone(),
+                )))
             }
 
             // Document tools
@@ -268,7 +443,13 @@ impl DomainToolFactory {
 
             // Escalation tools
             "escalate_to_human" | "escalate" | "human_agent" => {
-                Ok(Arc::new(domain_tools::EscalateToHumanTool::new()))
+                let tool = domain_tools::EscalateToHumanTool::new();
+                Ok(Arc::new(match self.integrations.handoff_queue {
+                    Some(ref queue) => {
+                        tool.with_handoff_queue(queue.clone(), &self.integrations.handoff)
+                    }
+                    None => tool,
+                }))
             }
 
             // Unknown tool - check if it's in config but not implemented
@@ -299,7 +480,7 @@ impl ToolFactory for DomainToolFactory {
             .tools_config()
             .tools
             .values()
-            .filter(|t| t.enabled.unwrap_or(true))
+            .filter(|t| t.is_enabled())
             .map(|t| ToolMetadata {
                 name: t.name.clone(),
                 display_name: t.display_name().to_string(),
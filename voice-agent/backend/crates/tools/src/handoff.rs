@@ -0,0 +1,343 @@
+//! Human Handoff
+//!
+//! Packages an escalated call for the human agent who takes it over: the
+//! dialogue state summary, the last few turns, the lead score and where the
+//! call recording will be. `EscalateToHumanTool` pushes the package to the
+//! configured `HandoffQueue` (a JSON webhook or a Redis stream); the agent
+//! desk then signals the warm transfer back through the handoff API.
+//!
+//! Webhook payload: `{ "event": "handoff.requested", "handoff_id": "...",
+//! "session_id": "...", "reason": "...", "priority": "...", "context": {...} }`.
+//! Redis entries carry `handoff_id`, `session_id`, `priority` and the same
+//! JSON as `payload`.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use voice_agent_config::{HandoffConfig, HandoffQueueKind};
+
+use crate::crm::{check_response, http_client, transport_error};
+use crate::integrations::IntegrationError;
+
+/// Approximate cap on Redis stream length (older handoffs are trimmed)
+const STREAM_MAX_LEN: &str = "10000";
+
+/// One conversation turn in a handoff package
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HandoffTurn {
+    pub role: String,
+    pub content: String,
+}
+
+/// What the human agent needs to pick up the call
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HandoffContext {
+    /// Dialogue state summary ("Customer: Asha, Phone: ..., Intent: ...")
+    #[serde(default)]
+    pub dialogue_state: Option<String>,
+    /// Slots collected so far
+    #[serde(default)]
+    pub slots: HashMap<String, String>,
+    /// Most recent turns, oldest first
+    #[serde(default)]
+    pub recent_turns: Vec<HandoffTurn>,
+    #[serde(default)]
+    pub lead_score: Option<u32>,
+    #[serde(default)]
+    pub lead_qualification: Option<String>,
+    /// Conversation stage when the call was escalated
+    #[serde(default)]
+    pub stage: Option<String>,
+    /// Language the caller is speaking
+    #[serde(default)]
+    pub language: Option<String>,
+    /// Where the call recording can be fetched
+    #[serde(default)]
+    pub audio_url: Option<String>,
+}
+
+impl HandoffContext {
+    /// Read the context the agent attached to the tool arguments
+    pub fn from_arguments(input: &Value) -> Self {
+        input
+            .get("handoff_context")
+            .cloned()
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_default()
+    }
+
+    /// Keep only the last `count` turns
+    pub fn truncate_turns(&mut self, count: usize) {
+        let excess = self.recent_turns.len().saturating_sub(count);
+        self.recent_turns.drain(..excess);
+    }
+}
+
+/// An escalated call waiting for a human agent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HandoffRequest {
+    pub handoff_id: String,
+    pub session_id: String,
+    pub reason: String,
+    pub priority: String,
+    pub customer_phone: Option<String>,
+    /// Summary written by the model, if any
+    pub summary: Option<String>,
+    pub context: HandoffContext,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Queue human agents pick escalated calls from
+#[async_trait]
+pub trait HandoffQueue: Send + Sync {
+    /// Publish a handoff
+    async fn push(&self, request: &HandoffRequest) -> Result<(), IntegrationError>;
+}
+
+/// Handoffs posted to a JSON webhook
+pub struct WebhookHandoffQueue {
+    client: reqwest::Client,
+    url: String,
+    bearer_token: Option<String>,
+}
+
+impl WebhookHandoffQueue {
+    pub fn new(
+        url: &str,
+        bearer_token: Option<&str>,
+        timeout: Duration,
+    ) -> Result<Self, IntegrationError> {
+        if url.trim().is_empty() {
+            return Err(IntegrationError::InvalidRequest(
+                "Handoff webhook URL is required".to_string(),
+            ));
+        }
+        Ok(Self {
+            client: http_client(timeout)?,
+            url: url.to_string(),
+            bearer_token: bearer_token.filter(|t| !t.is_empty()).map(String::from),
+        })
+    }
+}
+
+#[async_trait]
+impl HandoffQueue for WebhookHandoffQueue {
+    async fn push(&self, request: &HandoffRequest) -> Result<(), IntegrationError> {
+        let mut payload =
+            serde_json::to_value(request).map_err(|e| IntegrationError::Internal(e.to_string()))?;
+        payload["event"] = Value::from("handoff.requested");
+
+        let mut http = self
+            .client
+            .post(&self.url)
+            .header("Idempotency-Key", request.handoff_id.as_str())
+            .json(&payload);
+        if let Some(token) = &self.bearer_token {
+            http = http.bearer_auth(token);
+        }
+
+        let response = http.send().await.map_err(transport_error)?;
+        check_response(response).await?;
+        Ok(())
+    }
+}
+
+/// Handoffs appended to a Redis stream with XADD
+///
+/// Opens a connection per handoff; escalations are rare enough that a
+/// pool is not worth it.
+pub struct RedisStreamHandoffQueue {
+    address: String,
+    password: Option<String>,
+    stream: String,
+    timeout: Duration,
+}
+
+impl RedisStreamHandoffQueue {
+    /// Create from `redis://[:password@]host:port` (a trailing `/db` is ignored)
+    pub fn new(url: &str, stream: &str, timeout: Duration) -> Result<Self, IntegrationError> {
+        let rest = url.trim().trim_start_matches("redis://");
+        let (auth, host) = match rest.rsplit_once('@') {
+            Some((auth, host)) => (Some(auth), host),
+            None => (None, rest),
+        };
+        let address = host.split('/').next().unwrap_or_default().to_string();
+        if address.is_empty() || stream.trim().is_empty() {
+            return Err(IntegrationError::InvalidRequest(
+                "Redis address and stream are required".to_string(),
+            ));
+        }
+        let password = auth
+            .map(|a| a.rsplit_once(':').map_or(a, |(_, p)| p))
+            .filter(|p| !p.is_empty())
+            .map(String::from);
+        Ok(Self {
+            address: if address.contains(':') {
+                address
+            } else {
+                format!("{}:6379", address)
+            },
+            password,
+            stream: stream.to_string(),
+            timeout,
+        })
+    }
+
+    async fn xadd(&self, fields: &[(&str, &str)]) -> Result<(), IntegrationError> {
+        let stream = TcpStream::connect(&self.address)
+            .await
+            .map_err(|e| IntegrationError::ConnectionFailed(e.to_string()))?;
+        let mut stream = BufReader::new(stream);
+
+        if let Some(ref password) = self.password {
+            send_command(&mut stream, &["AUTH", password.as_str()]).await?;
+        }
+
+        let mut command = vec![
+            "XADD",
+            self.stream.as_str(),
+            "MAXLEN",
+            "~",
+            STREAM_MAX_LEN,
+            "*",
+        ];
+        for &(key, value) in fields {
+            command.push(key);
+            command.push(value);
+        }
+        send_command(&mut stream, &command).await
+    }
+}
+
+/// Write one RESP command and check the reply is not an error
+async fn send_command(
+    stream: &mut BufReader<TcpStream>,
+    args: &[&str],
+) -> Result<(), IntegrationError> {
+    let mut command = format!("*{}\r\n", args.len());
+    for arg in args {
+        command.push_str(&format!("${}\r\n{}\r\n", arg.len(), arg));
+    }
+    let io_error = |e: std::io::Error| IntegrationError::ConnectionFailed(e.to_string());
+    stream
+        .get_mut()
+        .write_all(command.as_bytes())
+        .await
+        .map_err(io_error)?;
+
+    let mut reply = String::new();
+    stream.read_line(&mut reply).await.map_err(io_error)?;
+    match reply.strip_prefix('-') {
+        Some(error) => Err(IntegrationError::InvalidRequest(format!(
+            "Redis error: {}",
+            error.trim()
+        ))),
+        None if reply.is_empty() => Err(IntegrationError::ConnectionFailed(
+            "Redis closed the connection".to_string(),
+        )),
+        None => {
+            // Bulk replies ($len) carry their value on the next line
+            if reply.starts_with('$') && !reply.starts_with("$-1") {
+                let mut value = String::new();
+                stream.read_line(&mut value).await.map_err(io_error)?;
+            }
+            Ok(())
+        },
+    }
+}
+
+#[async_trait]
+impl HandoffQueue for RedisStreamHandoffQueue {
+    async fn push(&self, request: &HandoffRequest) -> Result<(), IntegrationError> {
+        let payload = serde_json::to_string(request)
+            .map_err(|e| IntegrationError::Internal(e.to_string()))?;
+        let fields = [
+            ("handoff_id", request.handoff_id.as_str()),
+            ("session_id", request.session_id.as_str()),
+            ("priority", request.priority.as_str()),
+            ("payload", payload.as_str()),
+        ];
+        tokio::time::timeout(self.timeout, self.xadd(&fields))
+            .await
+            .map_err(|_| IntegrationError::ConnectionFailed("Redis timed out".to_string()))?
+    }
+}
+
+/// Build the configured handoff queue, or `None` when handoffs are not queued
+pub fn handoff_queue_from_config(
+    config: &HandoffConfig,
+) -> Result<Option<Arc<dyn HandoffQueue>>, IntegrationError> {
+    let endpoint = config.endpoint.as_deref().unwrap_or_default();
+    let timeout = Duration::from_secs(config.timeout_secs);
+    match config.queue {
+        HandoffQueueKind::None => Ok(None),
+        HandoffQueueKind::Webhook => Ok(Some(Arc::new(WebhookHandoffQueue::new(
+            endpoint,
+            config.api_key.as_deref(),
+            timeout,
+        )?))),
+        HandoffQueueKind::RedisStream => Ok(Some(Arc::new(RedisStreamHandoffQueue::new(
+            endpoint,
+            &config.stream,
+            timeout,
+        )?))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_context_from_arguments_keeps_last_turns() {
+        let input = json!({
+            "reason": "customer_request",
+            "handoff_context": {
+                "dialogue_state": "Customer: Asha",
+                "slots": { "phone_number": "9876543210" },
+                "recent_turns": [
+                    { "role": "user", "content": "hi" },
+                    { "role": "assistant", "content": "hello" },
+                    { "role": "user", "content": "agent please" }
+                ],
+                "lead_score": 72
+            }
+        });
+
+        let mut context = HandoffContext::from_arguments(&input);
+        assert_eq!(context.lead_score, Some(72));
+        assert_eq!(context.slots["phone_number"], "9876543210");
+        context.truncate_turns(2);
+        assert_eq!(context.recent_turns.len(), 2);
+        assert_eq!(context.recent_turns[1].content, "agent please");
+
+        // Missing context is not an error
+        assert!(HandoffContext::from_arguments(&json!({}))
+            .recent_turns
+            .is_empty());
+    }
+
+    #[test]
+    fn test_redis_url_parsing() {
+        let timeout = Duration::from_secs(1);
+        let queue =
+            RedisStreamHandoffQueue::new("redis://:secret@cache.local:6380/0", "h", timeout)
+                .unwrap();
+        assert_eq!(queue.address, "cache.local:6380");
+        assert_eq!(queue.password.as_deref(), Some("secret"));
+
+        let queue = RedisStreamHandoffQueue::new("localhost", "h", timeout).unwrap();
+        assert_eq!(queue.address, "localhost:6379");
+        assert!(queue.password.is_none());
+
+        assert!(RedisStreamHandoffQueue::new("redis://", "h", timeout).is_err());
+    }
+}
@@ -0,0 +1,256 @@
+//! Declarative HTTP-backed tools
+//!
+//! Tools whose `tools/schemas.yaml` entry has an `http` block are served by
+//! an external endpoint instead of a compiled-in implementation:
+//!
+//! ```yaml
+//! get_loan_status:
+//!   name: get_loan_status
+//!   description: "Look up the status of an existing loan application"
+//!   metadata:
+//!     timeout_secs: 10
+//!   http:
+//!     url: "https://los.example.com/api/applications/status"
+//!     method: GET
+//!     headers:
+//!       Authorization: "Bearer ${LOS_API_TOKEN}"
+//!     response_path: "data.status"
+//!   parameters:
+//!     - name: application_id
+//!       type: string
+//!       required: true
+//! ```
+//!
+//! 5xx, 408, 429 and transport failures surface as internal errors so the
+//! resilient executor retries them; other 4xx responses are invalid params.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde_json::Value;
+
+use voice_agent_config::{HttpToolConfig, ToolSchema as ConfigToolSchema};
+
+use crate::mcp::{Tool, ToolError, ToolOutput, ToolSchema};
+
+/// Longest response body echoed back in an error message
+const MAX_ERROR_BODY_CHARS: usize = 200;
+
+/// Tool that forwards its arguments to an HTTP endpoint
+pub struct HttpTool {
+    schema: ToolSchema,
+    config: HttpToolConfig,
+    method: Method,
+    timeout_secs: u64,
+    client: reqwest::Client,
+}
+
+impl HttpTool {
+    /// Create from a config schema with an `http` block
+    pub fn from_config(schema: &ConfigToolSchema) -> Result<Self, String> {
+        let config = schema
+            .http
+            .clone()
+            .ok_or_else(|| format!("tool '{}' has no http block", schema.name))?;
+        if config.url.trim().is_empty() {
+            return Err(format!("tool '{}' has an empty http url", schema.name));
+        }
+        let method = match config.method.to_ascii_uppercase().as_str() {
+            "GET" => Method::GET,
+            "POST" => Method::POST,
+            "PUT" => Method::PUT,
+            "PATCH" => Method::PATCH,
+            other => {
+                return Err(format!(
+                    "tool '{}' has unsupported http method '{}'",
+                    schema.name, other
+                ))
+            },
+        };
+        let timeout_secs = schema.timeout_secs();
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(timeout_secs))
+            .build()
+            .map_err(|e| e.to_string())?;
+
+        Ok(Self {
+            schema: schema.to_core_schema(),
+            config,
+            method,
+            timeout_secs,
+            client,
+        })
+    }
+
+    fn build_request(&self, input: &Value) -> reqwest::RequestBuilder {
+        let url = expand_env(&self.config.url);
+        let mut request = if self.method == Method::GET {
+            self.client.get(url).query(&query_pairs(input))
+        } else {
+            self.client.request(self.method.clone(), url).json(input)
+        };
+        for (name, value) in &self.config.headers {
+            request = request.header(name.as_str(), expand_env(value));
+        }
+        request
+    }
+}
+
+#[async_trait]
+impl Tool for HttpTool {
+    fn name(&self) -> &str {
+        &self.schema.name
+    }
+
+    fn description(&self) -> &str {
+        &self.schema.description
+    }
+
+    fn schema(&self) -> ToolSchema {
+        self.schema.clone()
+    }
+
+    async fn execute(&self, input: Value) -> Result<ToolOutput, ToolError> {
+        let response = self.build_request(&input).send().await.map_err(|e| {
+            ToolError::internal(format!("{} request failed: {}", self.schema.name, e))
+        })?;
+
+        let status = response.status();
+        let body = response.text().await.map_err(|e| {
+            ToolError::internal(format!("{} response unreadable: {}", self.schema.name, e))
+        })?;
+
+        if !status.is_success() {
+            let snippet: String = body.chars().take(MAX_ERROR_BODY_CHARS).collect();
+            let message = format!("{} returned HTTP {}: {}", self.schema.name, status, snippet);
+            return Err(match status.as_u16() {
+                408 | 429 => ToolError::internal(message),
+                400..=499 => ToolError::invalid_params(message),
+                _ => ToolError::internal(message),
+            });
+        }
+
+        let value = serde_json::from_str::<Value>(&body).unwrap_or(Value::String(body));
+        let value = match &self.config.response_path {
+            Some(path) => select_path(&value, path).cloned().ok_or_else(|| {
+                ToolError::internal(format!(
+                    "{} response has no '{}' field",
+                    self.schema.name, path
+                ))
+            })?,
+            None => value,
+        };
+
+        Ok(match value {
+            Value::String(text) => ToolOutput::text(text),
+            other => ToolOutput::json(other),
+        })
+    }
+
+    fn timeout_secs(&self) -> u64 {
+        self.timeout_secs
+    }
+}
+
+/// Expand `${VAR}` references from the environment; unset variables become empty
+fn expand_env(template: &str) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("${") {
+        let Some(len) = rest[start + 2..].find('}') else {
+            break;
+        };
+        result.push_str(&rest[..start]);
+        let var = &rest[start + 2..start + 2 + len];
+        result.push_str(&std::env::var(var).unwrap_or_default());
+        rest = &rest[start + 3 + len..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Flatten top-level arguments into query parameters
+fn query_pairs(input: &Value) -> Vec<(String, String)> {
+    input
+        .as_object()
+        .map(|obj| {
+            obj.iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, v)| match v {
+                    Value::String(s) => (k.clone(), s.clone()),
+                    other => (k.clone(), other.to_string()),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Walk a dotted path (`data.items.0.name`) into a JSON value
+fn select_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .filter(|segment| !segment.is_empty())
+        .try_fold(value, |current, segment| match current {
+            Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => current.get(segment),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn http_schema(method: &str) -> ConfigToolSchema {
+        serde_json::from_value(json!({
+            "name": "get_loan_status",
+            "description": "Look up a loan application",
+            "parameters": [
+                { "name": "application_id", "type": "string", "description": "Application ID", "required": true }
+            ],
+            "http": { "url": "http://localhost:9/status", "method": method }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_from_config() {
+        let tool = HttpTool::from_config(&http_schema("get")).unwrap();
+        assert_eq!(tool.name(), "get_loan_status");
+        assert_eq!(tool.method, Method::GET);
+        assert_eq!(tool.timeout_secs(), 30);
+        assert!(tool
+            .schema()
+            .input_schema
+            .required
+            .contains(&"application_id".to_string()));
+
+        assert!(HttpTool::from_config(&http_schema("DELETE")).is_err());
+    }
+
+    #[test]
+    fn test_expand_env_and_select_path() {
+        std::env::set_var("HTTP_TOOL_TEST_TOKEN", "secret");
+        assert_eq!(
+            expand_env("Bearer ${HTTP_TOOL_TEST_TOKEN}"),
+            "Bearer secret"
+        );
+        assert_eq!(expand_env("${HTTP_TOOL_TEST_UNSET}/x"), "/x");
+        assert_eq!(expand_env("no vars ${"), "no vars ${");
+
+        let body = json!({ "data": { "items": [{ "status": "approved" }] } });
+        assert_eq!(
+            select_path(&body, "data.items.0.status"),
+            Some(&json!("approved"))
+        );
+        assert_eq!(select_path(&body, "data.missing"), None);
+    }
+
+    #[test]
+    fn test_query_pairs() {
+        let pairs = query_pairs(&json!({ "id": "A1", "limit": 5, "skip": null }));
+        assert_eq!(pairs.len(), 2);
+        assert!(pairs.contains(&("id".to_string(), "A1".to_string())));
+        assert!(pairs.contains(&("limit".to_string(), "5".to_string())));
+    }
+}
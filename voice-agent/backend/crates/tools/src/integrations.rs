@@ -1,7 +1,7 @@
 //! External System Integrations
 //!
 //! P0 FIX: Traits and stubs for CRM and Calendar integrations.
-//! These will be implemented when actual systems are available.
+//! Real CRM connectors (Salesforce, HubSpot, webhook) live in `crate::crm`.
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
@@ -29,6 +29,16 @@ pub enum IntegrationError {
     Internal(String),
 }
 
+impl IntegrationError {
+    /// Whether the operation may succeed if retried later
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::ConnectionFailed(_) | Self::RateLimited | Self::Internal(_)
+        )
+    }
+}
+
 /// P2 FIX: Convert IntegrationError to ToolError for unified error handling
 impl From<IntegrationError> for crate::mcp::ToolError {
     fn from(err: IntegrationError) -> Self {
@@ -74,6 +84,12 @@ pub struct CrmLead {
     pub assigned_to: Option<String>,
     /// Lead status
     pub status: LeadStatus,
+    /// Lead score (0-100) from the conversation's lead scoring engine
+    #[serde(default)]
+    pub lead_score: Option<u32>,
+    /// Lead qualification level (Cold/Warm/Hot/Qualified)
+    #[serde(default)]
+    pub lead_qualification: Option<String>,
 }
 
 /// Lead source
@@ -121,6 +137,19 @@ pub trait CrmIntegration: Send + Sync {
     /// Create a new lead
     async fn create_lead(&self, lead: CrmLead) -> Result<String, IntegrationError>;
 
+    /// Create a lead, deduplicated by `idempotency_key`
+    ///
+    /// Connectors that can upsert by an external key should override this so
+    /// that retried deliveries never create the same lead twice.
+    async fn create_lead_idempotent(
+        &self,
+        lead: CrmLead,
+        idempotency_key: &str,
+    ) -> Result<String, IntegrationError> {
+        let _ = idempotency_key;
+        self.create_lead(lead).await
+    }
+
     /// Update an existing lead
     async fn update_lead(&self, id: &str, lead: CrmLead) -> Result<(), IntegrationError>;
 
@@ -194,6 +223,8 @@ impl CrmIntegration for StubCrmIntegration {
             notes: None,
             assigned_to: None,
             status: LeadStatus::New,
+            lead_score: None,
+            lead_qualification: None,
         })
     }
 
@@ -490,6 +521,8 @@ mod tests {
             notes: None,
             assigned_to: None,
             status: LeadStatus::New,
+            lead_score: Some(72),
+            lead_qualification: Some("Hot".to_string()),
         };
 
         let id = crm.create_lead(lead).await.unwrap();
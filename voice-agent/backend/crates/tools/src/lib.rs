@@ -16,21 +16,52 @@
 //! let registry = create_registry_from_factory(factory)?;
 //! ```
 
+pub mod appointment_confirmation;
+pub mod configured;
+pub mod crm;
+pub mod dialer;
 pub mod domain_tools;
+pub mod execution;
 pub mod factory;
+pub mod handoff;
+pub mod http_tool;
 pub mod integrations;
 pub mod mcp;
+pub mod outbox;
+pub mod price_alerts;
+pub mod rate_cards;
 pub mod registry;
+pub mod sms_quota;
 
 pub use domain_tools::{
     // Location data management
     get_branches, find_locations, load_branches_from_file, reload_branches, BranchData,
     // Utility functions
     calculate_emi, calculate_total_interest,
+    // Visit slot times
+    configured_time_slots,
     // Tool implementations
-    AppointmentSchedulerTool, BranchLocatorTool, CompetitorComparisonTool, DocumentChecklistTool,
-    EligibilityCheckTool, EscalateToHumanTool, GetGoldPriceTool, LeadCaptureTool,
-    SavingsCalculatorTool, SendSmsTool,
+    AppointmentSchedulerTool, BranchLocatorTool, CheckOffersTool, CompetitorComparisonTool,
+    DocumentChecklistTool, EligibilityCheckTool, EscalateToHumanTool, GetGoldPriceTool,
+    LeadCaptureTool, OfferResponseTool, PriceAlertTool, SavingsCalculatorTool,
+    ScheduleCallbackTool, SendSmsTool, SlotAvailabilityTool, SlotHoldTool, TopUpEligibilityTool,
+    ESCALATION_TOOL, OFFER_TOOLS,
+};
+pub use appointment_confirmation::{
+    parse_reply, AppointmentConfirmations, ConfirmationRequest, ReplyAction, ReplyOutcome,
+};
+pub use configured::ConfiguredTool;
+pub use crm::{
+    connector_from_config, CrmDeliveryQueue, HubSpotCrm, RetryPolicy, SalesforceCrm, WebhookCrm,
+};
+pub use dialer::{
+    dialer_from_config, CallbackDispatcher, DialRequest, DispatchPolicy, DispatchSummary,
+    OutboundDialer, WebhookDialer,
+};
+pub use execution::{idempotency_key, CircuitState, ExecutionOutcome, ResilientToolExecutor};
+pub use handoff::{
+    handoff_queue_from_config, HandoffContext, HandoffQueue, HandoffRequest, HandoffTurn,
+    RedisStreamHandoffQueue, WebhookHandoffQueue,
 };
 pub use integrations::{
     Appointment, AppointmentPurpose, AppointmentStatus, CalendarIntegration, CrmIntegration,
@@ -65,6 +96,13 @@ pub use mcp::{
     ToolSchema,
 };
 pub use factory::{DomainToolFactory, ToolIntegrations};
+pub use http_tool::HttpTool;
+pub use outbox::{
+    Outbox, OutboxCalendar, OutboxCrm, OutboxDispatcher, OutboxSmsService, OutboxSummary,
+    SmsPayload,
+};
+pub use price_alerts::{AlertSummary, PriceAlertMonitor};
+pub use rate_cards::{RateCardEngine, RateQuote};
 pub use registry::{
     // P22 FIX: Factory-based tool creation (preferred)
     create_registry_from_factory,
@@ -78,6 +116,7 @@ pub use registry::{
     ToolExecutor,
     ToolRegistry,
 };
+pub use sms_quota::QuotaSmsService;
 
 // P2 FIX: Removed redundant ToolsError enum.
 // Use mcp::ToolError for tool execution errors instead.
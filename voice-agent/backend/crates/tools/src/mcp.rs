@@ -172,6 +172,12 @@ impl From<i32> for RequestId {
 
 /// MCP Method names
 pub mod methods {
+    /// Open a session and negotiate capabilities
+    pub const INITIALIZE: &str = "initialize";
+    /// Client finished initialization (notification)
+    pub const INITIALIZED: &str = "notifications/initialized";
+    /// Liveness check
+    pub const PING: &str = "ping";
     /// List available tools
     pub const TOOLS_LIST: &str = "tools/list";
     /// Call a tool
@@ -378,6 +384,7 @@ pub struct ServerCapabilities {
 
 /// Tool capabilities
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ToolCapabilities {
     /// Server supports listing tools that have changed
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -386,6 +393,7 @@ pub struct ToolCapabilities {
 
 /// Resource capabilities
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ResourceCapabilities {
     /// Server supports subscriptions
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -0,0 +1,580 @@
+//! Outbox delivery for tool side-effects
+//!
+//! `OutboxSmsService`, `OutboxCrm` and `OutboxCalendar` wrap the real
+//! services and only record the action in the `OutboxStore`, so the tool
+//! returns as soon as the entry is durable. `OutboxDispatcher` polls the
+//! store, performs the action against the real service and retries failures
+//! with `RetryPolicy` backoff. Delivery is at-least-once: an entry may be
+//! retried after a crash mid-send, which CRM connectors absorb through the
+//! idempotency key.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use voice_agent_config::OutboxConfig;
+use voice_agent_persistence::{
+    OutboxEntry, OutboxKind, OutboxStatus, OutboxStore, PersistenceError, SmsMessage, SmsResult,
+    SmsService, SmsStatus, SmsType,
+};
+
+use crate::crm::{idempotency_key, RetryPolicy};
+use crate::integrations::{
+    Appointment, CalendarIntegration, CrmIntegration, CrmLead, IntegrationError, LeadStatus,
+    TimeSlot,
+};
+
+/// SMS as recorded in the outbox
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmsPayload {
+    /// Message ID already returned to the caller
+    pub message_id: Uuid,
+    pub phone: String,
+    pub message: String,
+    pub msg_type: SmsType,
+    pub session_id: Option<String>,
+}
+
+/// Records side-effects in the outbox store
+#[derive(Clone)]
+pub struct Outbox {
+    store: Arc<dyn OutboxStore>,
+}
+
+impl Outbox {
+    pub fn new(store: Arc<dyn OutboxStore>) -> Self {
+        Self { store }
+    }
+
+    /// Record an action under `key`
+    ///
+    /// Recording a key that is already pending or delivered returns the
+    /// existing entry; a failed entry is replaced so the action is retried.
+    pub async fn record<T: Serialize>(
+        &self,
+        kind: OutboxKind,
+        key: &str,
+        session_id: Option<&str>,
+        payload: &T,
+    ) -> Result<OutboxEntry, PersistenceError> {
+        if let Some(existing) = self.store.get(key).await? {
+            if existing.status != OutboxStatus::Failed {
+                return Ok(existing);
+            }
+        }
+
+        let entry = OutboxEntry::new(key, kind, session_id, serde_json::to_string(payload)?);
+        self.store.create(&entry).await?;
+        tracing::debug!(idempotency_key = %key, kind = kind.as_str(), "Outbox entry recorded");
+        Ok(entry)
+    }
+}
+
+fn record_error(e: PersistenceError) -> IntegrationError {
+    IntegrationError::Internal(format!("Outbox record failed: {}", e))
+}
+
+/// SMS service that records messages for the dispatcher to send
+///
+/// `send_sms` returns `SmsStatus::Queued`; lookups pass through.
+pub struct OutboxSmsService {
+    outbox: Outbox,
+    inner: Arc<dyn SmsService>,
+}
+
+impl OutboxSmsService {
+    pub fn new(outbox: Outbox, inner: Arc<dyn SmsService>) -> Self {
+        Self { outbox, inner }
+    }
+}
+
+#[async_trait]
+impl SmsService for OutboxSmsService {
+    async fn send_sms(
+        &self,
+        phone: &str,
+        message: &str,
+        msg_type: SmsType,
+        session_id: Option<&str>,
+    ) -> Result<SmsResult, PersistenceError> {
+        let payload = SmsPayload {
+            message_id: Uuid::new_v4(),
+            phone: phone.to_string(),
+            message: message.to_string(),
+            msg_type,
+            session_id: session_id.map(String::from),
+        };
+        let key = format!("sms-{}", payload.message_id);
+        let entry = self
+            .outbox
+            .record(OutboxKind::Sms, &key, session_id, &payload)
+            .await?;
+
+        Ok(SmsResult {
+            message_id: payload.message_id,
+            status: SmsStatus::Queued,
+            sent_at: entry.created_at,
+            simulated: false,
+        })
+    }
+
+    async fn get_messages_for_phone(
+        &self,
+        phone: &str,
+        limit: i32,
+    ) -> Result<Vec<SmsMessage>, PersistenceError> {
+        self.inner.get_messages_for_phone(phone, limit).await
+    }
+
+    async fn get_message(
+        &self,
+        phone: &str,
+        message_id: Uuid,
+    ) -> Result<Option<SmsMessage>, PersistenceError> {
+        self.inner.get_message(phone, message_id).await
+    }
+}
+
+/// CRM connector that records leads for the dispatcher to push
+///
+/// Like `CrmDeliveryQueue`, `create_lead` returns the idempotency key (or
+/// the CRM lead ID once delivered); other operations pass through.
+pub struct OutboxCrm {
+    outbox: Outbox,
+    inner: Arc<dyn CrmIntegration>,
+}
+
+impl OutboxCrm {
+    pub fn new(outbox: Outbox, inner: Arc<dyn CrmIntegration>) -> Self {
+        Self { outbox, inner }
+    }
+}
+
+#[async_trait]
+impl CrmIntegration for OutboxCrm {
+    async fn create_lead(&self, lead: CrmLead) -> Result<String, IntegrationError> {
+        let key = idempotency_key(&lead);
+        self.create_lead_idempotent(lead, &key).await
+    }
+
+    async fn create_lead_idempotent(
+        &self,
+        lead: CrmLead,
+        idempotency_key: &str,
+    ) -> Result<String, IntegrationError> {
+        let entry = self
+            .outbox
+            .record(OutboxKind::CrmLead, idempotency_key, None, &lead)
+            .await
+            .map_err(record_error)?;
+        Ok(entry.external_id.unwrap_or(entry.idempotency_key))
+    }
+
+    async fn update_lead(&self, id: &str, lead: CrmLead) -> Result<(), IntegrationError> {
+        self.inner.update_lead(id, lead).await
+    }
+
+    async fn get_lead(&self, id: &str) -> Result<CrmLead, IntegrationError> {
+        self.inner.get_lead(id).await
+    }
+
+    async fn find_by_phone(&self, phone: &str) -> Result<Vec<CrmLead>, IntegrationError> {
+        self.inner.find_by_phone(phone).await
+    }
+
+    async fn assign_lead(&self, lead_id: &str, rep_id: &str) -> Result<(), IntegrationError> {
+        self.inner.assign_lead(lead_id, rep_id).await
+    }
+
+    async fn add_note(&self, lead_id: &str, note: &str) -> Result<(), IntegrationError> {
+        self.inner.add_note(lead_id, note).await
+    }
+
+    async fn update_status(
+        &self,
+        lead_id: &str,
+        status: LeadStatus,
+    ) -> Result<(), IntegrationError> {
+        self.inner.update_status(lead_id, status).await
+    }
+}
+
+/// Calendar that records bookings for the dispatcher to make
+///
+/// `schedule_appointment` assigns the appointment ID up front (unless the
+/// caller set one) and returns it; other operations pass through.
+pub struct OutboxCalendar {
+    outbox: Outbox,
+    inner: Arc<dyn CalendarIntegration>,
+}
+
+impl OutboxCalendar {
+    pub fn new(outbox: Outbox, inner: Arc<dyn CalendarIntegration>) -> Self {
+        Self { outbox, inner }
+    }
+}
+
+#[async_trait]
+impl CalendarIntegration for OutboxCalendar {
+    async fn get_available_slots(
+        &self,
+        branch_id: &str,
+        date: &str,
+    ) -> Result<Vec<TimeSlot>, IntegrationError> {
+        self.inner.get_available_slots(branch_id, date).await
+    }
+
+    async fn schedule_appointment(
+        &self,
+        mut appointment: Appointment,
+    ) -> Result<String, IntegrationError> {
+        let id = appointment
+            .id
+            .clone()
+            .unwrap_or_else(|| format!("APT-{}", Uuid::new_v4().to_string()[..8].to_uppercase()));
+        appointment.id = Some(id.clone());
+        self.outbox
+            .record(OutboxKind::Appointment, &id, None, &appointment)
+            .await
+            .map_err(record_error)?;
+        Ok(id)
+    }
+
+    async fn cancel_appointment(&self, id: &str) -> Result<(), IntegrationError> {
+        self.inner.cancel_appointment(id).await
+    }
+
+    async fn reschedule_appointment(
+        &self,
+        id: &str,
+        new_date: &str,
+        new_time: &str,
+    ) -> Result<(), IntegrationError> {
+        self.inner
+            .reschedule_appointment(id, new_date, new_time)
+            .await
+    }
+
+    async fn get_appointment(&self, id: &str) -> Result<Appointment, IntegrationError> {
+        self.inner.get_appointment(id).await
+    }
+
+    async fn send_confirmation(&self, id: &str) -> Result<(), IntegrationError> {
+        self.inner.send_confirmation(id).await
+    }
+}
+
+/// What one outbox pass did
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OutboxSummary {
+    pub delivered: usize,
+    pub retried: usize,
+    pub failed: usize,
+}
+
+/// Delivers due outbox entries to the real services
+pub struct OutboxDispatcher {
+    store: Arc<dyn OutboxStore>,
+    sms: Option<Arc<dyn SmsService>>,
+    crm: Option<Arc<dyn CrmIntegration>>,
+    calendar: Option<Arc<dyn CalendarIntegration>>,
+    policy: RetryPolicy,
+    poll_interval: Duration,
+}
+
+impl OutboxDispatcher {
+    /// Create a dispatcher; entries whose target is not set fail permanently
+    pub fn new(store: Arc<dyn OutboxStore>, policy: RetryPolicy, poll_interval: Duration) -> Self {
+        Self {
+            store,
+            sms: None,
+            crm: None,
+            calendar: None,
+            policy,
+            poll_interval,
+        }
+    }
+
+    pub fn from_config(store: Arc<dyn OutboxStore>, config: &OutboxConfig) -> Self {
+        let policy = RetryPolicy {
+            max_attempts: config.max_attempts.max(1),
+            initial_backoff: Duration::from_millis(config.initial_backoff_ms),
+            max_backoff: Duration::from_millis(config.max_backoff_ms),
+            ..RetryPolicy::default()
+        };
+        Self::new(
+            store,
+            policy,
+            Duration::from_millis(config.poll_interval_ms.max(1)),
+        )
+    }
+
+    pub fn with_sms(mut self, sms: Arc<dyn SmsService>) -> Self {
+        self.sms = Some(sms);
+        self
+    }
+
+    pub fn with_crm(mut self, crm: Arc<dyn CrmIntegration>) -> Self {
+        self.crm = Some(crm);
+        self
+    }
+
+    pub fn with_calendar(mut self, calendar: Arc<dyn CalendarIntegration>) -> Self {
+        self.calendar = Some(calendar);
+        self
+    }
+
+    /// Perform the entry's action; returns the target's ID for it
+    async fn deliver(&self, entry: &OutboxEntry) -> Result<String, IntegrationError> {
+        let missing = || {
+            IntegrationError::InvalidRequest(format!(
+                "No {} target configured",
+                entry.kind.as_str()
+            ))
+        };
+        let invalid = |e: serde_json::Error| IntegrationError::InvalidRequest(e.to_string());
+
+        match entry.kind {
+            OutboxKind::Sms => {
+                let sms = self.sms.as_ref().ok_or_else(missing)?;
+                let payload: SmsPayload =
+                    serde_json::from_str(&entry.payload_json).map_err(invalid)?;
+                let result = sms
+                    .send_sms(
+                        &payload.phone,
+                        &payload.message,
+                        payload.msg_type,
+                        payload.session_id.as_deref(),
+                    )
+                    .await
+                    .map_err(|e| IntegrationError::ConnectionFailed(e.to_string()))?;
+                Ok(result.message_id.to_string())
+            },
+            OutboxKind::CrmLead => {
+                let crm = self.crm.as_ref().ok_or_else(missing)?;
+                let lead: CrmLead = serde_json::from_str(&entry.payload_json).map_err(invalid)?;
+                crm.create_lead_idempotent(lead, &entry.idempotency_key)
+                    .await
+            },
+            OutboxKind::Appointment => {
+                let calendar = self.calendar.as_ref().ok_or_else(missing)?;
+                let appointment: Appointment =
+                    serde_json::from_str(&entry.payload_json).map_err(invalid)?;
+                calendar.schedule_appointment(appointment).await
+            },
+        }
+    }
+
+    /// Run one pass over the pending entries
+    pub async fn run_once(&self, now: DateTime<Utc>) -> Result<OutboxSummary, PersistenceError> {
+        let mut summary = OutboxSummary::default();
+
+        for mut entry in self.store.list_pending().await? {
+            if !entry.is_due(now) {
+                continue;
+            }
+
+            entry.attempts += 1;
+            entry.updated_at = now;
+            match self.deliver(&entry).await {
+                Ok(external_id) => {
+                    entry.status = OutboxStatus::Delivered;
+                    entry.external_id = Some(external_id);
+                    entry.last_error = None;
+                    summary.delivered += 1;
+                },
+                Err(e)
+                    if e.is_retryable() && (entry.attempts as u32) < self.policy.max_attempts =>
+                {
+                    let backoff = self.policy.backoff_for(entry.attempts as u32);
+                    entry.status = OutboxStatus::Retrying;
+                    entry.next_attempt_at = now
+                        + chrono::Duration::from_std(backoff)
+                            .unwrap_or_else(|_| chrono::Duration::zero());
+                    entry.last_error = Some(e.to_string());
+                    tracing::warn!(
+                        idempotency_key = %entry.idempotency_key,
+                        kind = entry.kind.as_str(),
+                        attempt = entry.attempts,
+                        backoff_ms = backoff.as_millis() as u64,
+                        error = %e,
+                        "Outbox delivery failed, retrying"
+                    );
+                    summary.retried += 1;
+                },
+                Err(e) => {
+                    entry.status = OutboxStatus::Failed;
+                    entry.last_error = Some(e.to_string());
+                    tracing::error!(
+                        idempotency_key = %entry.idempotency_key,
+                        kind = entry.kind.as_str(),
+                        attempts = entry.attempts,
+                        error = %e,
+                        "Outbox delivery failed permanently"
+                    );
+                    summary.failed += 1;
+                },
+            }
+            self.store.update(&entry).await?;
+        }
+
+        Ok(summary)
+    }
+
+    /// Poll in the background
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn spawn(self) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(self.poll_interval);
+            loop {
+                interval.tick().await;
+                match self.run_once(Utc::now()).await {
+                    Ok(summary) if summary != OutboxSummary::default() => {
+                        tracing::info!(
+                            delivered = summary.delivered,
+                            retried = summary.retried,
+                            failed = summary.failed,
+                            "Outbox dispatch pass"
+                        );
+                    },
+                    Ok(_) => {},
+                    Err(e) => tracing::error!("Outbox dispatch failed: {}", e),
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parking_lot::Mutex;
+    use voice_agent_persistence::InMemoryOutboxStore;
+
+    /// Records sends; fails the first `failures` of them
+    #[derive(Default)]
+    struct RecordingSms {
+        sent: Mutex<Vec<String>>,
+        failures: Mutex<u32>,
+    }
+
+    #[async_trait]
+    impl SmsService for RecordingSms {
+        async fn send_sms(
+            &self,
+            phone: &str,
+            _message: &str,
+            _msg_type: SmsType,
+            _session_id: Option<&str>,
+        ) -> Result<SmsResult, PersistenceError> {
+            let mut failures = self.failures.lock();
+            if *failures > 0 {
+                *failures -= 1;
+                return Err(PersistenceError::Connection("gateway down".into()));
+            }
+            self.sent.lock().push(phone.to_string());
+            Ok(SmsResult {
+                message_id: Uuid::new_v4(),
+                status: SmsStatus::SimulatedSent,
+                sent_at: Utc::now(),
+                simulated: true,
+            })
+        }
+
+        async fn get_messages_for_phone(
+            &self,
+            _phone: &str,
+            _limit: i32,
+        ) -> Result<Vec<SmsMessage>, PersistenceError> {
+            Ok(Vec::new())
+        }
+
+        async fn get_message(
+            &self,
+            _phone: &str,
+            _message_id: Uuid,
+        ) -> Result<Option<SmsMessage>, PersistenceError> {
+            Ok(None)
+        }
+    }
+
+    fn policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(10),
+            multiplier: 2.0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sms_recorded_then_delivered() {
+        let store: Arc<dyn OutboxStore> = Arc::new(InMemoryOutboxStore::new());
+        let gateway = Arc::new(RecordingSms::default());
+        let sms = OutboxSmsService::new(Outbox::new(store.clone()), gateway.clone());
+
+        let result = sms
+            .send_sms("9876543210", "Hello", SmsType::FollowUp, Some("s1"))
+            .await
+            .unwrap();
+        assert_eq!(result.status, SmsStatus::Queued);
+        assert!(gateway.sent.lock().is_empty());
+
+        let dispatcher = OutboxDispatcher::new(store.clone(), policy(3), Duration::from_secs(1))
+            .with_sms(gateway.clone());
+        let summary = dispatcher.run_once(Utc::now()).await.unwrap();
+        assert_eq!(summary.delivered, 1);
+        assert_eq!(gateway.sent.lock().as_slice(), ["9876543210"]);
+
+        let key = format!("sms-{}", result.message_id);
+        let entry = store.get(&key).await.unwrap().unwrap();
+        assert_eq!(entry.status, OutboxStatus::Delivered);
+        assert!(store.list_pending().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_backoff_then_fail() {
+        let store: Arc<dyn OutboxStore> = Arc::new(InMemoryOutboxStore::new());
+        let gateway = Arc::new(RecordingSms::default());
+        *gateway.failures.lock() = 5;
+        let sms = OutboxSmsService::new(Outbox::new(store.clone()), gateway.clone());
+        sms.send_sms("9876543210", "Hello", SmsType::FollowUp, None)
+            .await
+            .unwrap();
+
+        let dispatcher = OutboxDispatcher::new(store.clone(), policy(2), Duration::from_secs(1))
+            .with_sms(gateway.clone());
+        let now = Utc::now();
+        assert_eq!(dispatcher.run_once(now).await.unwrap().retried, 1);
+        // Not due again until the backoff has passed
+        assert_eq!(
+            dispatcher.run_once(now).await.unwrap(),
+            OutboxSummary::default()
+        );
+
+        let later = now + chrono::Duration::seconds(2);
+        assert_eq!(dispatcher.run_once(later).await.unwrap().failed, 1);
+        assert!(store.list_pending().await.unwrap().is_empty());
+        assert!(gateway.sent.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_crm_record_is_idempotent() {
+        let store: Arc<dyn OutboxStore> = Arc::new(InMemoryOutboxStore::new());
+        let outbox = Outbox::new(store.clone());
+        let first = outbox
+            .record(OutboxKind::CrmLead, "lead-1", None, &"a")
+            .await
+            .unwrap();
+        let second = outbox
+            .record(OutboxKind::CrmLead, "lead-1", None, &"b")
+            .await
+            .unwrap();
+        assert_eq!(first.payload_json, second.payload_json);
+        assert_eq!(store.list_pending().await.unwrap().len(), 1);
+    }
+}
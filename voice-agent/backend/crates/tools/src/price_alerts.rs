@@ -0,0 +1,377 @@
+//! Price alert notifications
+//!
+//! `PriceAlertMonitor` polls the `PriceAlertStore`, checks each active alert
+//! against the current `AssetPriceService` price (the alert's tier, or the
+//! base price) and texts the customer once the threshold is crossed.
+//! Alerts past their expiry are closed without a message.
+//!
+//! The SMS also says how the price has moved this week when there is enough
+//! price history.
+//!
+//! A customer gets at most one SMS per pass, however many of their alerts
+//! trigger together; the alerts are marked triggered before the SMS goes out
+//! and put back to active if it cannot be sent, so the next pass retries.
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use voice_agent_config::{PriceAlertConfig, ToolsDomainView};
+use voice_agent_persistence::{
+    AssetPriceService, PersistenceError, PriceAlert, PriceAlertStatus, PriceAlertStore, SmsService,
+    SmsType,
+};
+
+use crate::domain_tools::{describe_trend, DEFAULT_TREND_DAYS};
+
+/// What one monitor pass did
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AlertSummary {
+    /// Alerts whose threshold was crossed
+    pub triggered: usize,
+    /// Notification SMS sent
+    pub notified: usize,
+    pub expired: usize,
+    /// Notification SMS that could not be sent
+    pub failed: usize,
+}
+
+/// Checks active price alerts and notifies customers
+pub struct PriceAlertMonitor {
+    store: Arc<dyn PriceAlertStore>,
+    prices: Arc<dyn AssetPriceService>,
+    sms: Arc<dyn SmsService>,
+    view: Option<Arc<ToolsDomainView>>,
+    poll_interval: Duration,
+}
+
+impl PriceAlertMonitor {
+    pub fn new(
+        store: Arc<dyn PriceAlertStore>,
+        prices: Arc<dyn AssetPriceService>,
+        sms: Arc<dyn SmsService>,
+        config: &PriceAlertConfig,
+    ) -> Self {
+        Self {
+            store,
+            prices,
+            sms,
+            view: None,
+            poll_interval: Duration::from_secs(config.check_interval_secs.max(1)),
+        }
+    }
+
+    /// Use the domain's brand, currency and SMS templates
+    pub fn with_view(mut self, view: Arc<ToolsDomainView>) -> Self {
+        self.view = Some(view);
+        self
+    }
+
+    /// Run one pass over the active alerts
+    pub async fn run_once(&self, now: DateTime<Utc>) -> Result<AlertSummary, PersistenceError> {
+        let mut summary = AlertSummary::default();
+        let alerts = self.store.list_active().await?;
+        if alerts.is_empty() {
+            return Ok(summary);
+        }
+        let price = self.prices.get_current_price().await?;
+
+        let mut crossed: HashMap<String, Vec<PriceAlert>> = HashMap::new();
+        for mut alert in alerts {
+            if alert.is_expired(now) {
+                alert.status = PriceAlertStatus::Expired;
+                alert.updated_at = now;
+                self.store.update(&alert).await?;
+                summary.expired += 1;
+                continue;
+            }
+
+            let current = match alert.tier {
+                Some(ref tier) => price.price_for_tier(tier),
+                None => price.base_price_per_unit(),
+            };
+            if alert.is_crossed(current) {
+                alert.status = PriceAlertStatus::Triggered;
+                alert.triggered_at = Some(now);
+                alert.triggered_price = Some(current);
+                alert.updated_at = now;
+                crossed
+                    .entry(alert.customer_phone.clone())
+                    .or_default()
+                    .push(alert);
+            }
+        }
+
+        for (phone, mut group) in crossed {
+            for alert in &group {
+                self.store.update(alert).await?;
+            }
+            summary.triggered += group.len();
+
+            // One message per customer, about their most recent request
+            group.sort_by_key(|a| Reverse(a.created_at));
+            let trend = match self
+                .prices
+                .get_trend(DEFAULT_TREND_DAYS, group[0].tier.as_deref())
+                .await
+            {
+                Ok(trend) => trend.map(|t| describe_trend(&t, DEFAULT_TREND_DAYS)),
+                Err(e) => {
+                    tracing::warn!(error = %e, "Price trend unavailable for price alert");
+                    None
+                },
+            };
+            let message = self.message(&group[0], trend.as_deref());
+            match self
+                .sms
+                .send_sms(
+                    &phone,
+                    &message,
+                    SmsType::PriceAlert,
+                    group[0].session_id.as_deref(),
+                )
+                .await
+            {
+                Ok(sent) => {
+                    for alert in &mut group {
+                        alert.sms_id = Some(sent.message_id);
+                        self.store.update(alert).await?;
+                    }
+                    summary.notified += 1;
+                },
+                Err(e) => {
+                    tracing::warn!(
+                        customer_phone = %phone,
+                        error = %e,
+                        "Price alert SMS not sent, will retry"
+                    );
+                    for alert in &mut group {
+                        alert.status = PriceAlertStatus::Active;
+                        alert.triggered_at = None;
+                        alert.triggered_price = None;
+                        self.store.update(alert).await?;
+                    }
+                    summary.failed += 1;
+                },
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Notification text, from the `price_alert` SMS template when configured
+    fn message(&self, alert: &PriceAlert, trend: Option<&str>) -> String {
+        let price = alert.triggered_price.unwrap_or(alert.threshold);
+        let direction = alert.direction.as_str();
+        let (company, helpline, currency, unit) = match self.view {
+            Some(ref view) => (
+                view.company_name().to_string(),
+                view.helpline().to_string(),
+                view.currency_symbol().to_string(),
+                view.asset_unit().to_string(),
+            ),
+            None => (
+                "Service Provider".to_string(),
+                String::new(),
+                "₹".to_string(),
+                "gram".to_string(),
+            ),
+        };
+        let tier = alert.tier.clone().unwrap_or_default();
+        // Bracketed so the template reads the same with or without it
+        let trend = trend.map(|t| format!(" ({})", t)).unwrap_or_default();
+
+        if let Some(ref view) = self.view {
+            let mut placeholders = HashMap::new();
+            placeholders.insert(
+                "customer_name".to_string(),
+                alert.customer_name.clone().unwrap_or_default(),
+            );
+            placeholders.insert("tier".to_string(), tier.clone());
+            placeholders.insert("price".to_string(), format!("{}{:.0}", currency, price));
+            placeholders.insert(
+                "threshold".to_string(),
+                format!("{}{:.0}", currency, alert.threshold),
+            );
+            placeholders.insert("direction".to_string(), direction.to_string());
+            placeholders.insert("unit".to_string(), unit.clone());
+            placeholders.insert("trend".to_string(), trend.clone());
+            placeholders.insert("brand.bank_name".to_string(), company.clone());
+            placeholders.insert("brand.helpline".to_string(), helpline);
+            if let Some(message) = view.build_sms_message("price_alert", "en", &placeholders) {
+                // An empty tier or name leaves doubled spaces behind
+                return message.split_whitespace().collect::<Vec<_>>().join(" ");
+            }
+        }
+
+        format!(
+            "Price alert: {}price is now {}{:.0} per {}{}, {} your alert of {}{:.0}. - {}",
+            if tier.is_empty() {
+                String::new()
+            } else {
+                format!("{} ", tier)
+            },
+            currency,
+            price,
+            unit,
+            trend,
+            direction,
+            currency,
+            alert.threshold,
+            company
+        )
+    }
+
+    /// Poll in the background
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn spawn(self) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(self.poll_interval);
+            loop {
+                interval.tick().await;
+                match self.run_once(Utc::now()).await {
+                    Ok(summary) if summary != AlertSummary::default() => {
+                        tracing::info!(
+                            triggered = summary.triggered,
+                            notified = summary.notified,
+                            expired = summary.expired,
+                            failed = summary.failed,
+                            "Price alert pass"
+                        );
+                    },
+                    Ok(_) => {},
+                    Err(e) => tracing::error!("Price alert check failed: {}", e),
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::{Duration as ChronoDuration, NaiveDate};
+    use parking_lot::Mutex;
+    use uuid::Uuid;
+    use voice_agent_persistence::{
+        AlertDirection, AssetPrice, InMemoryPriceAlertStore, SmsMessage, SmsResult, SmsStatus,
+    };
+
+    struct FixedPrice(f64);
+
+    #[async_trait]
+    impl AssetPriceService for FixedPrice {
+        async fn get_current_price(&self) -> Result<AssetPrice, PersistenceError> {
+            Ok(AssetPrice::new(self.0, "test").with_tier("22K", self.0 * 0.916))
+        }
+
+        async fn get_historical_price(
+            &self,
+            _date: NaiveDate,
+        ) -> Result<Option<AssetPrice>, PersistenceError> {
+            Ok(None)
+        }
+
+        async fn refresh_price(&self) -> Result<AssetPrice, PersistenceError> {
+            self.get_current_price().await
+        }
+    }
+
+    /// Records recipients
+    #[derive(Default)]
+    struct RecordingSms {
+        sent: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl SmsService for RecordingSms {
+        async fn send_sms(
+            &self,
+            phone: &str,
+            _message: &str,
+            _msg_type: SmsType,
+            _session_id: Option<&str>,
+        ) -> Result<SmsResult, PersistenceError> {
+            self.sent.lock().push(phone.to_string());
+            Ok(SmsResult {
+                message_id: Uuid::new_v4(),
+                status: SmsStatus::SimulatedSent,
+                sent_at: Utc::now(),
+                simulated: true,
+            })
+        }
+
+        async fn get_messages_for_phone(
+            &self,
+            _phone: &str,
+            _limit: i32,
+        ) -> Result<Vec<SmsMessage>, PersistenceError> {
+            Ok(Vec::new())
+        }
+
+        async fn get_message(
+            &self,
+            _phone: &str,
+            _message_id: Uuid,
+        ) -> Result<Option<SmsMessage>, PersistenceError> {
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_monitor_notifies_once_per_customer_and_expires() {
+        let store = Arc::new(InMemoryPriceAlertStore::new());
+        let now = Utc::now();
+        let expires = now + ChronoDuration::days(30);
+        let first = PriceAlert::new("9876543210", 7600.0, AlertDirection::Above, expires);
+        let second = PriceAlert::new("9876543210", 7500.0, AlertDirection::Above, expires);
+        let mut tier = PriceAlert::new("9123456780", 7100.0, AlertDirection::Above, expires);
+        tier.tier = Some("22K".to_string());
+        let lapsed = PriceAlert::new(
+            "9000000000",
+            9000.0,
+            AlertDirection::Above,
+            now - ChronoDuration::hours(1),
+        );
+        for alert in [&first, &second, &tier, &lapsed] {
+            store.create(alert).await.unwrap();
+        }
+
+        let sms = Arc::new(RecordingSms::default());
+        let monitor = PriceAlertMonitor::new(
+            store.clone(),
+            Arc::new(FixedPrice(7650.0)),
+            sms.clone(),
+            &PriceAlertConfig::default(),
+        );
+        let summary = monitor.run_once(now).await.unwrap();
+        assert_eq!(
+            summary,
+            AlertSummary {
+                triggered: 2,
+                notified: 1,
+                expired: 1,
+                failed: 0
+            }
+        );
+        assert_eq!(*sms.sent.lock(), vec!["9876543210".to_string()]);
+
+        let triggered = store.get(first.alert_id).await.unwrap().unwrap();
+        assert_eq!(triggered.status, PriceAlertStatus::Triggered);
+        assert_eq!(triggered.triggered_price, Some(7650.0));
+        assert!(triggered.sms_id.is_some());
+        // 22K at 7650 * 0.916 has not reached 7100
+        let active = store.list_active().await.unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].alert_id, tier.alert_id);
+
+        assert_eq!(
+            monitor.run_once(now).await.unwrap(),
+            AlertSummary::default()
+        );
+    }
+}
@@ -0,0 +1,304 @@
+//! Rate card engine
+//!
+//! `RateCardEngine` answers the LTV and interest rate questions of the
+//! eligibility, savings and top-up tools. The card in force on a date is the
+//! latest to start among the cards published to the `RateCardStore` and those
+//! in rate_card.yaml (a published card wins a tie); without either, the card
+//! implied by domain constants applies.
+//!
+//! Published cards are read from the store by `refresh`, which `spawn` runs
+//! periodically so a card published on one instance reaches the others.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{NaiveDate, Utc};
+use parking_lot::RwLock;
+use serde::Serialize;
+use voice_agent_config::{effective_rate_card, RateCard, ToolsDomainView};
+use voice_agent_persistence::{PersistenceError, PublishedRateCard, RateCardStore};
+
+/// How often published cards are re-read by default
+const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(300);
+
+/// LTV and rate for one loan, with the card they came from
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RateQuote {
+    pub card_version: String,
+    /// Quality tier short code the LTV is for, when a variant was given
+    pub tier: Option<String>,
+    pub ltv_percent: f64,
+    pub rate_percent: f64,
+    /// Name of the slab the rate came from (empty when unnamed)
+    pub slab: String,
+    pub processing_fee_percent: f64,
+}
+
+/// Resolves rate cards for the tools
+pub struct RateCardEngine {
+    view: Arc<ToolsDomainView>,
+    store: Option<Arc<dyn RateCardStore>>,
+    published: RwLock<Vec<RateCard>>,
+    refresh_interval: Duration,
+}
+
+impl RateCardEngine {
+    /// Cards from domain config only
+    pub fn new(view: Arc<ToolsDomainView>) -> Self {
+        Self {
+            view,
+            store: None,
+            published: RwLock::new(Vec::new()),
+            refresh_interval: DEFAULT_REFRESH_INTERVAL,
+        }
+    }
+
+    /// Also use cards published to the store
+    pub fn with_store(mut self, store: Arc<dyn RateCardStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// How often `spawn` re-reads published cards
+    pub fn with_refresh_interval(mut self, interval: Duration) -> Self {
+        self.refresh_interval = interval;
+        self
+    }
+
+    /// Product key for published cards (the domain id)
+    pub fn product(&self) -> &str {
+        self.view.domain_id()
+    }
+
+    /// Re-read published cards; returns how many are usable
+    ///
+    /// Cards that fail to parse or validate are skipped with a warning.
+    pub async fn refresh(&self) -> Result<usize, PersistenceError> {
+        let Some(ref store) = self.store else {
+            return Ok(0);
+        };
+        let stored = store.list(self.product()).await?;
+
+        let mut cards = Vec::with_capacity(stored.len());
+        for record in stored {
+            let card = serde_json::from_str::<RateCard>(&record.card_json)
+                .map_err(|e| e.to_string())
+                .and_then(|card| card.validate().map(|_| card).map_err(|e| e.to_string()));
+            match card {
+                Ok(card) => cards.push(card),
+                Err(e) => tracing::warn!(
+                    version = %record.version,
+                    error = %e,
+                    "Skipping unusable published rate card"
+                ),
+            }
+        }
+
+        let count = cards.len();
+        *self.published.write() = cards;
+        Ok(count)
+    }
+
+    /// Published cards as last read from the store
+    pub fn published(&self) -> Vec<RateCard> {
+        self.published.read().clone()
+    }
+
+    /// The card in force on `date`
+    pub fn card_on(&self, date: NaiveDate) -> RateCard {
+        let published = self.published.read();
+        let candidates: Vec<RateCard> = self
+            .view
+            .rate_cards()
+            .iter()
+            .chain(published.iter())
+            .cloned()
+            .collect();
+        effective_rate_card(&candidates, date)
+            .cloned()
+            .unwrap_or_else(|| self.view.rate_card_on(date))
+    }
+
+    /// The card in force today
+    pub fn current(&self) -> RateCard {
+        self.card_on(Utc::now().date_naive())
+    }
+
+    /// Tier short code for a variant, or the variant as given when unknown
+    fn tier_code(&self, variant: &str) -> String {
+        self.view
+            .quality_tier_code(variant)
+            .unwrap_or_else(|| variant.trim().to_string())
+    }
+
+    /// LTV (%) today for a collateral variant (tier id, name or short code)
+    pub fn ltv_percent(&self, variant: Option<&str>) -> f64 {
+        let tier = variant.map(|v| self.tier_code(v));
+        self.current().ltv_for(tier.as_deref())
+    }
+
+    /// Interest rate (% p.a.) today for a loan amount and, if known, tenure
+    pub fn rate_for(&self, amount: f64, tenure_months: Option<u32>) -> f64 {
+        self.quote(amount, tenure_months, None).rate_percent
+    }
+
+    /// LTV, rate and fee today for a loan
+    ///
+    /// When no slab covers the loan the headline base rate applies.
+    pub fn quote(&self, amount: f64, tenure_months: Option<u32>, variant: Option<&str>) -> RateQuote {
+        let card = self.current();
+        let tier = variant.map(|v| self.tier_code(v));
+        let slab = card.slab_for(amount, tenure_months);
+
+        RateQuote {
+            ltv_percent: card.ltv_for(tier.as_deref()),
+            rate_percent: slab
+                .map(|s| s.rate)
+                .unwrap_or_else(|| self.view.base_interest_rate()),
+            slab: slab.map(|s| s.name.clone()).unwrap_or_default(),
+            processing_fee_percent: card
+                .processing_fee_percent
+                .unwrap_or_else(|| self.view.processing_fee_percent()),
+            card_version: card.version,
+            tier,
+        }
+    }
+
+    /// Largest loan on collateral worth `asset_value`, within the loan limit
+    pub fn max_loan(&self, asset_value: f64, variant: Option<&str>) -> f64 {
+        let ltv = self.ltv_percent(variant);
+        (asset_value * ltv / 100.0).min(self.view.max_loan_amount())
+    }
+
+    /// Publish a new card version; returns false if the version is taken
+    ///
+    /// The card is checked first, and published cards are re-read on
+    /// success so it applies here straight away.
+    pub async fn publish(
+        &self,
+        card: &RateCard,
+        published_by: Option<&str>,
+    ) -> Result<bool, PersistenceError> {
+        let Some(ref store) = self.store else {
+            return Err(PersistenceError::InvalidData(
+                "rate card publishing is not configured".to_string(),
+            ));
+        };
+        card.validate()
+            .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+        if self.view.rate_cards().iter().any(|c| c.version == card.version) {
+            return Ok(false);
+        }
+
+        let mut record = PublishedRateCard::new(
+            self.product(),
+            &card.version,
+            card.effective_from,
+            serde_json::to_string(card)?,
+        );
+        if let Some(by) = published_by {
+            record = record.with_published_by(by);
+        }
+        let published = store.publish(&record).await?;
+        if published {
+            self.refresh().await?;
+        }
+        Ok(published)
+    }
+
+    /// Re-read published cards in the background
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn spawn(self: Arc<Self>) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(self.refresh_interval);
+            loop {
+                interval.tick().await;
+                if let Err(e) = self.refresh().await {
+                    tracing::warn!("Rate card refresh failed: {}", e);
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use voice_agent_config::{MasterDomainConfig, RateSlab};
+    use voice_agent_persistence::InMemoryRateCardStore;
+
+    fn card(version: &str, from: NaiveDate, ltv: f64, rate: f64) -> RateCard {
+        RateCard {
+            version: version.to_string(),
+            effective_from: from,
+            effective_until: None,
+            ltv_percent: ltv,
+            ltv_by_tier: [("18K".to_string(), ltv - 5.0)].into_iter().collect(),
+            slabs: vec![
+                RateSlab {
+                    name: "Short".to_string(),
+                    min_amount: 0.0,
+                    max_amount: None,
+                    min_tenure_months: 0,
+                    max_tenure_months: Some(6),
+                    rate: rate - 0.5,
+                },
+                RateSlab {
+                    name: "Standard".to_string(),
+                    min_amount: 0.0,
+                    max_amount: None,
+                    min_tenure_months: 7,
+                    max_tenure_months: None,
+                    rate,
+                },
+            ],
+            processing_fee_percent: Some(0.5),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_published_card_takes_over() {
+        let mut config = MasterDomainConfig {
+            domain_id: "gold_loan".to_string(),
+            ..Default::default()
+        };
+        config.constants.ltv_percent = 75.0;
+        config.constants.loan_limits.max = 1_000_000.0;
+        let today = Utc::now().date_naive();
+        config
+            .rate_card
+            .cards
+            .push(card("config", today - chrono::Duration::days(30), 75.0, 11.0));
+        let view = Arc::new(ToolsDomainView::new(Arc::new(config)));
+
+        let engine = RateCardEngine::new(view.clone())
+            .with_store(Arc::new(InMemoryRateCardStore::new()));
+        let quote = engine.quote(50_000.0, Some(12), Some("18K"));
+        assert_eq!(quote.card_version, "config");
+        assert_eq!(quote.ltv_percent, 70.0);
+        assert_eq!(quote.rate_percent, 11.0);
+        assert_eq!(engine.rate_for(50_000.0, Some(3)), 10.5);
+
+        // Versions already in config cannot be published again
+        let published = card("config", today, 72.0, 10.0);
+        assert!(!engine.publish(&published, Some("ops")).await.unwrap());
+
+        let published = card("2026-10", today, 72.0, 10.0);
+        assert!(engine.publish(&published, Some("ops")).await.unwrap());
+        assert!(!engine.publish(&published, None).await.unwrap());
+        assert_eq!(engine.current().version, "2026-10");
+        assert_eq!(engine.ltv_percent(Some("22K")), 72.0);
+        assert_eq!(engine.max_loan(2_000_000.0, None), 1_000_000.0);
+
+        // Invalid cards, and publishing without a store, are refused
+        let mut invalid = published.clone();
+        invalid.version = "bad".to_string();
+        invalid.ltv_percent = 0.0;
+        assert!(engine.publish(&invalid, None).await.is_err());
+        assert!(RateCardEngine::new(view)
+            .publish(&published, None)
+            .await
+            .is_err());
+    }
+}
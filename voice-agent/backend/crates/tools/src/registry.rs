@@ -8,6 +8,8 @@ use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use std::time::Duration;
 
+use crate::configured::ConfiguredTool;
+use crate::http_tool::HttpTool;
 use crate::mcp::{Tool, ToolError, ToolOutput, ToolSchema};
 
 /// Default timeout for tool execution (30 seconds)
@@ -24,6 +26,18 @@ pub trait ToolExecutor: Send + Sync {
 
     /// Get tool schema by name
     fn get_tool(&self, name: &str) -> Option<ToolSchema>;
+
+    /// Execute a tool on behalf of a session
+    ///
+    /// Executors that audit calls override this; the default ignores the session.
+    async fn execute_for_session(
+        &self,
+        _session_id: &str,
+        name: &str,
+        arguments: Value,
+    ) -> Result<ToolOutput, ToolError> {
+        self.execute(name, arguments).await
+    }
 }
 
 /// Tool registry
@@ -80,6 +94,45 @@ impl ToolRegistry {
     pub fn tool_names(&self) -> Vec<String> {
         self.tools.keys().cloned().collect()
     }
+
+    /// Apply the domain's `tools/schemas.yaml`
+    ///
+    /// Tools disabled in config are removed, configured descriptions and
+    /// parameters are overlaid on compiled-in tools, and tools with an `http`
+    /// block are registered as HTTP-backed tools (replacing any compiled-in
+    /// tool of the same name). Tools without a config entry are left as is.
+    pub fn apply_domain_config(&mut self, config: &voice_agent_config::ToolsConfig) {
+        for name in self.tool_names() {
+            let Some(schema) = config.find_tool(&name) else {
+                continue;
+            };
+            if !schema.is_enabled() {
+                self.tools.remove(&name);
+                tracing::info!(tool = %name, "Tool disabled by domain config");
+            } else if schema.http.is_some() {
+                self.tools.remove(&name);
+            } else if let Some(tool) = self.tools.remove(&name) {
+                self.tools
+                    .insert(name, Arc::new(ConfiguredTool::new(tool, schema)));
+            }
+        }
+
+        for schema in config
+            .tools
+            .values()
+            .filter(|t| t.is_enabled() && t.http.is_some())
+        {
+            match HttpTool::from_config(schema) {
+                Ok(tool) => {
+                    tracing::info!(tool = %schema.name, "Registered HTTP tool from domain config");
+                    self.register(tool);
+                },
+                Err(e) => {
+                    tracing::warn!(tool = %schema.name, error = %e, "Skipping invalid HTTP tool");
+                },
+            }
+        }
+    }
 }
 
 impl Default for ToolRegistry {
@@ -278,6 +331,7 @@ pub fn create_registry_with_view(
 
     // P15: Register gold loan tools - ALL require ToolsDomainView
     registry.register(crate::domain_tools::EligibilityCheckTool::new(view.clone()));
+    registry.register(crate::domain_tools::TopUpEligibilityTool::new(view.clone()));
     registry.register(crate::domain_tools::SavingsCalculatorTool::new(view.clone()));
     registry.register(crate::domain_tools::GetGoldPriceTool::new(view.clone()));
     registry.register(crate::domain_tools::CompetitorComparisonTool::new(view.clone()));
@@ -285,12 +339,24 @@ pub fn create_registry_with_view(
     // Tools that don't need domain config (CRM/calendar integrations only)
     registry.register(crate::domain_tools::LeadCaptureTool::new());
     // P16 FIX: Appointment tool uses view for config-driven purposes/times
-    registry.register(crate::domain_tools::AppointmentSchedulerTool::with_view(view.clone()));
+    let slots: Arc<dyn voice_agent_persistence::SlotStore> =
+        Arc::new(voice_agent_persistence::InMemorySlotStore::new());
+    registry.register(
+        crate::domain_tools::AppointmentSchedulerTool::with_view(view.clone())
+            .with_slot_store(slots.clone()),
+    );
+    registry.register(crate::domain_tools::SlotAvailabilityTool::new(slots.clone(), view.clone()));
+    registry.register(crate::domain_tools::SlotHoldTool::new(slots, view.clone()));
+    registry.register(crate::domain_tools::ScheduleCallbackTool::new(
+        Arc::new(voice_agent_persistence::InMemoryCallbackStore::new()),
+        view.clone(),
+    ));
     registry.register(crate::domain_tools::BranchLocatorTool::new());
     registry.register(crate::domain_tools::EscalateToHumanTool::new());
     // P16 FIX: SMS and Document tools now use view for config-driven content
     registry.register(crate::domain_tools::SendSmsTool::with_view(view.clone()));
     registry.register(crate::domain_tools::DocumentChecklistTool::with_view(view.clone()));
+    registry.apply_domain_config(view.tools_config());
 
     tracing::info!(
         bank_name = view.company_name(),
@@ -474,6 +540,7 @@ pub fn create_registry_with_integrations(config: IntegrationConfig) -> ToolRegis
 
     // P15: All tools that need domain config use the REQUIRED view
     registry.register(crate::domain_tools::EligibilityCheckTool::new(config.view.clone()));
+    registry.register(crate::domain_tools::TopUpEligibilityTool::new(config.view.clone()));
     registry.register(crate::domain_tools::SavingsCalculatorTool::new(config.view.clone()));
     registry.register(crate::domain_tools::GetGoldPriceTool::new(config.view.clone()));
     registry.register(crate::domain_tools::CompetitorComparisonTool::new(config.view.clone()));
@@ -487,19 +554,32 @@ pub fn create_registry_with_integrations(config: IntegrationConfig) -> ToolRegis
     }
 
     // P16 FIX: AppointmentSchedulerTool with optional calendar integration and view
-    if let Some(calendar) = config.calendar {
-        registry.register(crate::domain_tools::AppointmentSchedulerTool::with_calendar_and_view(
+    let slots: Arc<dyn voice_agent_persistence::SlotStore> =
+        Arc::new(voice_agent_persistence::InMemorySlotStore::new());
+    let scheduler = if let Some(calendar) = config.calendar {
+        crate::domain_tools::AppointmentSchedulerTool::with_calendar_and_view(
             calendar,
             config.view.clone(),
-        ));
+        )
     } else {
-        registry.register(crate::domain_tools::AppointmentSchedulerTool::with_view(config.view.clone()));
-    }
+        crate::domain_tools::AppointmentSchedulerTool::with_view(config.view.clone())
+    };
+    registry.register(scheduler.with_slot_store(slots.clone()));
+    registry.register(crate::domain_tools::SlotAvailabilityTool::new(
+        slots.clone(),
+        config.view.clone(),
+    ));
+    registry.register(crate::domain_tools::SlotHoldTool::new(slots, config.view.clone()));
+    registry.register(crate::domain_tools::ScheduleCallbackTool::new(
+        Arc::new(voice_agent_persistence::InMemoryCallbackStore::new()),
+        config.view.clone(),
+    ));
 
     registry.register(crate::domain_tools::EscalateToHumanTool::new());
     // P16 FIX: SMS and Document tools now use view for config-driven content
     registry.register(crate::domain_tools::SendSmsTool::with_view(config.view.clone()));
     registry.register(crate::domain_tools::DocumentChecklistTool::with_view(config.view.clone()));
+    registry.apply_domain_config(config.view.tools_config());
 
     tracing::info!(
         bank_name = config.view.company_name(),
@@ -527,6 +607,27 @@ pub struct FullIntegrationConfig {
     pub sms_service: Option<Arc<dyn voice_agent_persistence::SmsService>>,
     /// P16 FIX: Asset price service (generic, gold_price_service for backwards compatibility)
     pub gold_price_service: Option<Arc<dyn voice_agent_persistence::AssetPriceService>>,
+    /// Branch visit slot capacity (in-memory when not set)
+    pub slot_store: Option<Arc<dyn voice_agent_persistence::SlotStore>>,
+    /// Admin-maintained competitor rates (config rates only when not set)
+    pub competitor_rates: Option<Arc<dyn voice_agent_persistence::CompetitorRateStore>>,
+    /// LTV and rate cards, including published ones (config cards only when not set)
+    pub rate_cards: Option<Arc<crate::rate_cards::RateCardEngine>>,
+    /// Requested callbacks (in-memory when not set)
+    pub callbacks: Option<Arc<dyn voice_agent_persistence::CallbackStore>>,
+    /// Queue escalations are handed to human agents through
+    pub handoff_queue: Option<Arc<dyn crate::handoff::HandoffQueue>>,
+    /// Handoff packaging settings
+    pub handoff: voice_agent_config::HandoffConfig,
+    /// Two-way SMS confirmation of booked appointments
+    pub appointment_confirmations:
+        Option<Arc<crate::appointment_confirmation::AppointmentConfirmations>>,
+    /// Price alert subscriptions (tool not registered when not set)
+    pub price_alerts: Option<Arc<dyn voice_agent_persistence::PriceAlertStore>>,
+    /// Price alert limits
+    pub price_alert_config: voice_agent_config::PriceAlertConfig,
+    /// Offer mention and acceptance tracking (offer tools not registered when not set)
+    pub offer_events: Option<Arc<dyn voice_agent_persistence::OfferEventStore>>,
 }
 
 impl FullIntegrationConfig {
@@ -538,6 +639,16 @@ impl FullIntegrationConfig {
             calendar: None,
             sms_service: None,
             gold_price_service: None,
+            slot_store: None,
+            competitor_rates: None,
+            rate_cards: None,
+            callbacks: None,
+            handoff_queue: None,
+            handoff: Default::default(),
+            appointment_confirmations: None,
+            price_alerts: None,
+            price_alert_config: Default::default(),
+            offer_events: None,
         }
     }
 
@@ -550,12 +661,19 @@ impl FullIntegrationConfig {
             view,
             crm: Some(Arc::new(crate::integrations::StubCrmIntegration::new())),
             calendar: Some(Arc::new(crate::integrations::StubCalendarIntegration::new())),
-            sms_service: Some(
-                Arc::new(persistence.sms.clone()) as Arc<dyn voice_agent_persistence::SmsService>
-            ),
+            sms_service: Some(persistence.sms.clone()),
             // P16 FIX: Use generic asset_price field (AssetPriceService)
-            gold_price_service: Some(Arc::new(persistence.asset_price.clone())
-                as Arc<dyn voice_agent_persistence::AssetPriceService>),
+            gold_price_service: Some(persistence.asset_price.clone()),
+            slot_store: Some(persistence.slots.clone()),
+            competitor_rates: Some(persistence.competitor_rates.clone()),
+            rate_cards: None,
+            callbacks: Some(persistence.callbacks.clone()),
+            handoff_queue: None,
+            handoff: Default::default(),
+            appointment_confirmations: None,
+            price_alerts: None,
+            price_alert_config: Default::default(),
+            offer_events: Some(persistence.offer_events.clone()),
         }
     }
 
@@ -597,6 +715,76 @@ impl FullIntegrationConfig {
         self.gold_price_service = Some(price);
         self
     }
+
+    /// Set branch visit slot store
+    pub fn with_slot_store(mut self, slots: Arc<dyn voice_agent_persistence::SlotStore>) -> Self {
+        self.slot_store = Some(slots);
+        self
+    }
+
+    /// Set competitor rate store
+    pub fn with_competitor_rate_store(
+        mut self,
+        rates: Arc<dyn voice_agent_persistence::CompetitorRateStore>,
+    ) -> Self {
+        self.competitor_rates = Some(rates);
+        self
+    }
+
+    /// Set the rate card engine shared by the rate and LTV tools
+    pub fn with_rate_cards(mut self, rate_cards: Arc<crate::rate_cards::RateCardEngine>) -> Self {
+        self.rate_cards = Some(rate_cards);
+        self
+    }
+
+    /// Set callback store
+    pub fn with_callback_store(
+        mut self,
+        callbacks: Arc<dyn voice_agent_persistence::CallbackStore>,
+    ) -> Self {
+        self.callbacks = Some(callbacks);
+        self
+    }
+
+    /// Set the human handoff queue
+    pub fn with_handoff_queue(
+        mut self,
+        queue: Arc<dyn crate::handoff::HandoffQueue>,
+        handoff: voice_agent_config::HandoffConfig,
+    ) -> Self {
+        self.handoff_queue = Some(queue);
+        self.handoff = handoff;
+        self
+    }
+
+    /// Store booked appointments and confirm them by SMS reply
+    pub fn with_appointment_confirmations(
+        mut self,
+        confirmations: Arc<crate::appointment_confirmation::AppointmentConfirmations>,
+    ) -> Self {
+        self.appointment_confirmations = Some(confirmations);
+        self
+    }
+
+    /// Let customers subscribe to price alerts
+    pub fn with_price_alerts(
+        mut self,
+        store: Arc<dyn voice_agent_persistence::PriceAlertStore>,
+        config: voice_agent_config::PriceAlertConfig,
+    ) -> Self {
+        self.price_alerts = Some(store);
+        self.price_alert_config = config;
+        self
+    }
+
+    /// Let the agent present promotional offers, tracking them per session
+    pub fn with_offer_events(
+        mut self,
+        events: Arc<dyn voice_agent_persistence::OfferEventStore>,
+    ) -> Self {
+        self.offer_events = Some(events);
+        self
+    }
 }
 
 /// P15 FIX: Create registry with full persistence support - view is REQUIRED
@@ -609,9 +797,24 @@ pub fn create_registry_with_persistence(config: FullIntegrationConfig) -> ToolRe
     let mut registry = ToolRegistry::new();
 
     // P15: All tools that need domain config use the REQUIRED view
-    registry.register(crate::domain_tools::EligibilityCheckTool::new(config.view.clone()));
-    registry.register(crate::domain_tools::SavingsCalculatorTool::new(config.view.clone()));
-    registry.register(crate::domain_tools::CompetitorComparisonTool::new(config.view.clone()));
+    let rate_cards = config
+        .rate_cards
+        .unwrap_or_else(|| Arc::new(crate::rate_cards::RateCardEngine::new(config.view.clone())));
+    registry.register(
+        crate::domain_tools::EligibilityCheckTool::new(config.view.clone())
+            .with_rate_cards(rate_cards.clone()),
+    );
+    let savings = crate::domain_tools::SavingsCalculatorTool::new(config.view.clone())
+        .with_rate_cards(rate_cards.clone());
+    registry.register(match config.competitor_rates.clone() {
+        Some(rates) => savings.with_rate_store(rates),
+        None => savings,
+    });
+    let comparison = crate::domain_tools::CompetitorComparisonTool::new(config.view.clone());
+    registry.register(match config.competitor_rates {
+        Some(rates) => comparison.with_rate_store(rates),
+        None => comparison,
+    });
     registry.register(crate::domain_tools::BranchLocatorTool::new());
 
     // LeadCaptureTool with optional CRM integration
@@ -621,28 +824,88 @@ pub fn create_registry_with_persistence(config: FullIntegrationConfig) -> ToolRe
         registry.register(crate::domain_tools::LeadCaptureTool::new());
     }
 
-    // P16 FIX: AppointmentSchedulerTool with optional calendar integration and view
-    if let Some(calendar) = config.calendar {
-        registry.register(crate::domain_tools::AppointmentSchedulerTool::with_calendar_and_view(
+    // P16 FIX: AppointmentSchedulerTool with optional calendar integration and view,
+    // booking against the persisted slot store (in-memory when not configured)
+    let slots = config
+        .slot_store
+        .unwrap_or_else(|| Arc::new(voice_agent_persistence::InMemorySlotStore::new()));
+    let scheduler = if let Some(calendar) = config.calendar {
+        crate::domain_tools::AppointmentSchedulerTool::with_calendar_and_view(
             calendar,
             config.view.clone(),
-        ));
+        )
     } else {
-        registry.register(crate::domain_tools::AppointmentSchedulerTool::with_view(config.view.clone()));
+        crate::domain_tools::AppointmentSchedulerTool::with_view(config.view.clone())
+    };
+    let scheduler = scheduler.with_slot_store(slots.clone());
+    registry.register(match config.appointment_confirmations {
+        Some(confirmations) => scheduler.with_confirmations(confirmations),
+        None => scheduler,
+    });
+    registry.register(crate::domain_tools::SlotAvailabilityTool::new(
+        slots.clone(),
+        config.view.clone(),
+    ));
+    registry.register(crate::domain_tools::SlotHoldTool::new(slots, config.view.clone()));
+    registry.register(crate::domain_tools::ScheduleCallbackTool::new(
+        config
+            .callbacks
+            .unwrap_or_else(|| Arc::new(voice_agent_persistence::InMemoryCallbackStore::new())),
+        config.view.clone(),
+    ));
+
+    // Price alerts infer the direction from the current price when available
+    if let Some(store) = config.price_alerts {
+        let alerts = crate::domain_tools::PriceAlertTool::new(
+            store,
+            config.view.clone(),
+            config.price_alert_config,
+        );
+        registry.register(match config.gold_price_service.clone() {
+            Some(service) => alerts.with_price_service(service),
+            None => alerts,
+        });
     }
 
-    // GetGoldPriceTool with REQUIRED view and optional price service
+    // Promotional offers, with mentions and answers recorded per session
+    if let Some(events) = config.offer_events {
+        registry.register(
+            crate::domain_tools::CheckOffersTool::new(config.view.clone())
+                .with_event_store(events.clone()),
+        );
+        registry.register(crate::domain_tools::OfferResponseTool::new(
+            config.view.clone(),
+            events,
+        ));
+    }
+
+    // GetGoldPriceTool and TopUpEligibilityTool with REQUIRED view and optional price service
     if let Some(service) = config.gold_price_service {
+        registry.register(
+            crate::domain_tools::TopUpEligibilityTool::with_price_service(
+                service.clone(),
+                config.view.clone(),
+            )
+            .with_rate_cards(rate_cards),
+        );
         registry.register(crate::domain_tools::GetGoldPriceTool::with_price_service(
             service,
             config.view.clone(),
         ));
     } else {
+        registry.register(
+            crate::domain_tools::TopUpEligibilityTool::new(config.view.clone())
+                .with_rate_cards(rate_cards),
+        );
         registry.register(crate::domain_tools::GetGoldPriceTool::new(config.view.clone()));
     }
 
     // EscalateToHumanTool (no domain config needed)
-    registry.register(crate::domain_tools::EscalateToHumanTool::new());
+    let escalate = crate::domain_tools::EscalateToHumanTool::new();
+    registry.register(match config.handoff_queue {
+        Some(queue) => escalate.with_handoff_queue(queue, &config.handoff),
+        None => escalate,
+    });
 
     // P16 FIX: SendSmsTool with view and optional persistence service
     if let Some(sms_service) = config.sms_service {
@@ -656,6 +919,7 @@ pub fn create_registry_with_persistence(config: FullIntegrationConfig) -> ToolRe
 
     // P16 FIX: Document tool uses view for config-driven content
     registry.register(crate::domain_tools::DocumentChecklistTool::with_view(config.view.clone()));
+    registry.apply_domain_config(config.view.tools_config());
 
     tracing::info!(
         tools = registry.len(),
@@ -741,12 +1005,16 @@ mod tests {
         let registry = create_registry_with_integrations(config);
 
         // P20 FIX: Tool names now come from config (domain-agnostic)
-        // Should have all 10 tools
-        assert_eq!(registry.len(), 10);
+        // Should have all 14 tools
+        assert_eq!(registry.len(), 14);
         assert!(registry.has("check_eligibility"));
+        assert!(registry.has("check_top_up_eligibility"));
         assert!(registry.has("calculate_savings"));
         assert!(registry.has("capture_lead"));
         assert!(registry.has("schedule_appointment"));
+        assert!(registry.has("check_slot_availability"));
+        assert!(registry.has("hold_appointment_slot"));
+        assert!(registry.has("schedule_callback"));
         assert!(registry.has("find_locations")); // Config-driven name (was find_branches)
         assert!(registry.has("get_price")); // Config-driven name (was get_gold_price)
         assert!(registry.has("escalate_to_human"));
@@ -762,8 +1030,8 @@ mod tests {
         let registry = create_registry_with_integrations(config);
 
         // P20 FIX: Tool names now come from config (domain-agnostic)
-        // Should still have all 10 tools (just without integrations)
-        assert_eq!(registry.len(), 10);
+        // Should still have all 14 tools (just without integrations)
+        assert_eq!(registry.len(), 14);
         assert!(registry.has("capture_lead"));
         assert!(registry.has("schedule_appointment"));
         assert!(registry.has("get_price")); // Config-driven name (was get_gold_price)
@@ -779,12 +1047,16 @@ mod tests {
         let registry = create_registry_with_view(view);
 
         // P20 FIX: Tool names now come from config (domain-agnostic)
-        // Registry should have all 10 tools
-        assert_eq!(registry.len(), 10);
+        // Registry should have all 14 tools
+        assert_eq!(registry.len(), 14);
         assert!(registry.has("check_eligibility"));
+        assert!(registry.has("check_top_up_eligibility"));
         assert!(registry.has("calculate_savings"));
         assert!(registry.has("capture_lead"));
         assert!(registry.has("schedule_appointment"));
+        assert!(registry.has("check_slot_availability"));
+        assert!(registry.has("hold_appointment_slot"));
+        assert!(registry.has("schedule_callback"));
         assert!(registry.has("find_locations")); // Config-driven name (was find_branches)
         assert!(registry.has("get_price")); // Config-driven name (was get_gold_price)
         assert!(registry.has("escalate_to_human"));
@@ -792,4 +1064,68 @@ mod tests {
         assert!(registry.has("get_document_checklist"));
         assert!(registry.has("compare_lenders"));
     }
+
+    #[test]
+    fn test_registry_applies_domain_tool_config() {
+        let config = voice_agent_config::MasterDomainConfig {
+            tools: serde_json::from_value(serde_json::json!({
+                "tools": {
+                    "send_sms": {
+                        "name": "send_sms",
+                        "description": "Send an SMS",
+                        "enabled": false
+                    },
+                    "capture_lead": {
+                        "name": "capture_lead",
+                        "description": "Save the caller's contact details"
+                    },
+                    "get_loan_status": {
+                        "name": "get_loan_status",
+                        "description": "Look up a loan application",
+                        "http": { "url": "http://localhost:9/status", "method": "GET" }
+                    }
+                }
+            }))
+            .unwrap(),
+            ..Default::default()
+        };
+        let view = Arc::new(voice_agent_config::ToolsDomainView::new(Arc::new(config)));
+        let registry = create_registry_with_integrations(IntegrationConfig::new(view));
+
+        assert!(!registry.has("send_sms"));
+        assert!(registry.has("get_loan_status"));
+        assert_eq!(registry.len(), 14);
+        assert_eq!(
+            registry.get_tool("capture_lead").unwrap().description,
+            "Save the caller's contact details"
+        );
+        assert!(!registry
+            .list_tools()
+            .iter()
+            .any(|schema| schema.name == "send_sms"));
+    }
+
+    #[test]
+    fn test_persistence_registry_registers_price_alerts_when_set() {
+        let registry = create_registry_with_persistence(FullIntegrationConfig::new(test_view()));
+        assert!(!registry.has("create_price_alert"));
+
+        let store = Arc::new(voice_agent_persistence::InMemoryPriceAlertStore::new());
+        let config = FullIntegrationConfig::new(test_view())
+            .with_price_alerts(store, voice_agent_config::PriceAlertConfig::default());
+        let registry = create_registry_with_persistence(config);
+        assert!(registry.has("create_price_alert"));
+    }
+
+    #[test]
+    fn test_persistence_registry_registers_offer_tools_when_set() {
+        let registry = create_registry_with_persistence(FullIntegrationConfig::new(test_view()));
+        assert!(!registry.has("check_offers"));
+
+        let events = Arc::new(voice_agent_persistence::InMemoryOfferEventStore::new());
+        let config = FullIntegrationConfig::new(test_view()).with_offer_events(events);
+        let registry = create_registry_with_persistence(config);
+        assert!(registry.has("check_offers"));
+        assert!(registry.has("record_offer_response"));
+    }
 }
@@ -0,0 +1,218 @@
+//! Daily SMS limit per customer
+//!
+//! `QuotaSmsService` wraps the SMS service the tools use and refuses a
+//! message once the phone number has had `max_per_day` that day (UTC),
+//! returning `PersistenceError::QuotaExceeded`. The first send to a number
+//! each day counts what the store already holds for it, so the limit
+//! survives a restart. Refusals are written to the audit log.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use parking_lot::Mutex;
+use uuid::Uuid;
+use voice_agent_persistence::{
+    AuditLogger, PersistenceError, SmsMessage, SmsResult, SmsService, SmsType,
+};
+
+/// SMS service that enforces a daily limit per phone number
+pub struct QuotaSmsService {
+    inner: Arc<dyn SmsService>,
+    max_per_day: u32,
+    /// Messages sent per phone number on the day recorded with them
+    sent: Mutex<HashMap<String, (NaiveDate, u32)>>,
+    audit_logger: Option<Arc<AuditLogger>>,
+}
+
+impl QuotaSmsService {
+    pub fn new(inner: Arc<dyn SmsService>, max_per_day: u32) -> Self {
+        Self {
+            inner,
+            max_per_day,
+            sent: Mutex::new(HashMap::new()),
+            audit_logger: None,
+        }
+    }
+
+    /// Audit refused messages
+    pub fn with_audit_logger(mut self, logger: Arc<AuditLogger>) -> Self {
+        self.audit_logger = Some(logger);
+        self
+    }
+
+    /// Messages the store already holds for `phone` today
+    async fn stored_today(&self, phone: &str, today: NaiveDate) -> u32 {
+        match self
+            .inner
+            .get_messages_for_phone(phone, self.max_per_day as i32 + 1)
+            .await
+        {
+            Ok(messages) => messages
+                .iter()
+                .filter(|m| m.created_at.date_naive() == today)
+                .count() as u32,
+            Err(e) => {
+                tracing::warn!(error = %e, "SMS history unavailable, counting from zero");
+                0
+            },
+        }
+    }
+
+    /// Count a message for `phone`, or return the count if the limit is reached
+    async fn reserve(&self, phone: &str) -> Result<(), u32> {
+        let today = Utc::now().date_naive();
+        let known = matches!(self.sent.lock().get(phone), Some((day, _)) if *day == today);
+        let stored = if known {
+            0
+        } else {
+            self.stored_today(phone, today).await
+        };
+
+        let mut sent = self.sent.lock();
+        let entry = sent.entry(phone.to_string()).or_insert((today, stored));
+        if entry.0 != today {
+            *entry = (today, stored);
+        }
+        if entry.1 >= self.max_per_day {
+            return Err(entry.1);
+        }
+        entry.1 += 1;
+        Ok(())
+    }
+
+    /// Give back a reservation whose send failed
+    fn release(&self, phone: &str) {
+        if let Some((_, count)) = self.sent.lock().get_mut(phone) {
+            *count = count.saturating_sub(1);
+        }
+    }
+}
+
+#[async_trait]
+impl SmsService for QuotaSmsService {
+    async fn send_sms(
+        &self,
+        phone: &str,
+        message: &str,
+        msg_type: SmsType,
+        session_id: Option<&str>,
+    ) -> Result<SmsResult, PersistenceError> {
+        if self.max_per_day > 0 {
+            if let Err(used) = self.reserve(phone).await {
+                tracing::warn!(
+                    session_id = session_id.unwrap_or_default(),
+                    limit = self.max_per_day,
+                    "Daily SMS limit reached for customer, message refused"
+                );
+                if let Some(ref logger) = self.audit_logger {
+                    let audited = logger
+                        .log_budget_enforcement(
+                            session_id.unwrap_or("sms"),
+                            "sms_per_day",
+                            self.max_per_day as u64,
+                            used as u64,
+                            "refuse_sms",
+                        )
+                        .await;
+                    if let Err(e) = audited {
+                        tracing::warn!(error = %e, "Failed to audit SMS limit");
+                    }
+                }
+                return Err(PersistenceError::QuotaExceeded(format!(
+                    "daily SMS limit of {} reached",
+                    self.max_per_day
+                )));
+            }
+        }
+
+        let result = self
+            .inner
+            .send_sms(phone, message, msg_type, session_id)
+            .await;
+        if result.is_err() && self.max_per_day > 0 {
+            self.release(phone);
+        }
+        result
+    }
+
+    async fn get_messages_for_phone(
+        &self,
+        phone: &str,
+        limit: i32,
+    ) -> Result<Vec<SmsMessage>, PersistenceError> {
+        self.inner.get_messages_for_phone(phone, limit).await
+    }
+
+    async fn get_message(
+        &self,
+        phone: &str,
+        message_id: Uuid,
+    ) -> Result<Option<SmsMessage>, PersistenceError> {
+        self.inner.get_message(phone, message_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use voice_agent_persistence::SmsStatus;
+
+    /// Accepts every message and stores nothing
+    struct NullSms;
+
+    #[async_trait]
+    impl SmsService for NullSms {
+        async fn send_sms(
+            &self,
+            _phone: &str,
+            _message: &str,
+            _msg_type: SmsType,
+            _session_id: Option<&str>,
+        ) -> Result<SmsResult, PersistenceError> {
+            Ok(SmsResult {
+                message_id: Uuid::new_v4(),
+                status: SmsStatus::SimulatedSent,
+                sent_at: Utc::now(),
+                simulated: true,
+            })
+        }
+
+        async fn get_messages_for_phone(
+            &self,
+            _phone: &str,
+            _limit: i32,
+        ) -> Result<Vec<SmsMessage>, PersistenceError> {
+            Ok(Vec::new())
+        }
+
+        async fn get_message(
+            &self,
+            _phone: &str,
+            _message_id: Uuid,
+        ) -> Result<Option<SmsMessage>, PersistenceError> {
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_daily_limit_per_phone() {
+        let sms = QuotaSmsService::new(Arc::new(NullSms), 2);
+        for _ in 0..2 {
+            sms.send_sms("9876543210", "Hi", SmsType::FollowUp, Some("s1"))
+                .await
+                .unwrap();
+        }
+        let refused = sms
+            .send_sms("9876543210", "Hi", SmsType::FollowUp, Some("s1"))
+            .await;
+        assert!(matches!(refused, Err(PersistenceError::QuotaExceeded(_))));
+
+        // Other customers have their own allowance
+        assert!(sms
+            .send_sms("9123456780", "Hi", SmsType::FollowUp, None)
+            .await
+            .is_ok());
+    }
+}
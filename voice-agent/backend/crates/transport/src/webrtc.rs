@@ -15,6 +15,7 @@ use parking_lot::RwLock;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use tokio::sync::{mpsc, oneshot};
+use voice_agent_core::{JitterBuffer, JitterBufferConfig, JitterOutput};
 use webrtc::api::interceptor_registry::register_default_interceptors;
 use webrtc::api::media_engine::MediaEngine;
 use webrtc::api::setting_engine::SettingEngine;
@@ -564,6 +565,15 @@ impl Transport for WebRtcTransport {
             let event_tx = event_tx_clone.clone();
 
             Box::pin(async move {
+                // RTP reorders and drops packets; sequence numbers restore order
+                // and keep the timeline continuous across short losses
+                let mut jitter = JitterBuffer::new(JitterBufferConfig::default());
+                let mut highest_sequence: Option<u64> = None;
+                let mut start_ms: Option<u64> = None;
+                let mut released_samples: u64 = 0;
+                let samples_per_ms =
+                    (decoder.sample_rate() as u64 * decoder.channels() as u64 / 1000).max(1);
+
                 loop {
                     match track.read_rtp().await {
                         Ok((rtp_packet, _)) => {
@@ -572,6 +582,14 @@ impl Transport for WebRtcTransport {
                                 continue;
                             }
 
+                            let sequence = extend_rtp_sequence(
+                                highest_sequence,
+                                rtp_packet.header.sequence_number,
+                            );
+                            highest_sequence = highest_sequence.max(Some(sequence));
+                            let base_ms = *start_ms
+                                .get_or_insert((rtp_packet.header.timestamp as u64 * 1000) / 48000);
+
                             // Decode Opus to PCM
                             let samples = match decoder.decode(payload) {
                                 Ok(s) => s,
@@ -585,25 +603,54 @@ impl Transport for WebRtcTransport {
                                 },
                             };
 
-                            let timestamp_ms = (rtp_packet.header.timestamp as u64 * 1000) / 48000;
-
-                            // Send to audio channel
-                            if audio_tx
-                                .send((samples.clone(), timestamp_ms))
-                                .await
-                                .is_err()
-                            {
-                                break;
+                            let frame_len = samples.len() as u64;
+                            jitter.push(sequence, samples);
+
+                            let mut closed = false;
+                            for output in jitter.drain() {
+                                let samples = match output {
+                                    JitterOutput::Frame { samples, .. }
+                                    | JitterOutput::Concealed { samples, .. } => samples,
+                                    JitterOutput::Resync {
+                                        expected,
+                                        resumed_at,
+                                    } => {
+                                        tracing::warn!(
+                                            expected,
+                                            resumed_at,
+                                            "RTP gap too long to conceal, resyncing"
+                                        );
+                                        // Skip the timeline past the lost audio
+                                        released_samples += (resumed_at - expected) * frame_len;
+                                        continue;
+                                    },
+                                };
+
+                                let timestamp_ms = base_ms + released_samples / samples_per_ms;
+                                released_samples += samples.len() as u64;
+
+                                // Send to audio channel
+                                if audio_tx
+                                    .send((samples.clone(), timestamp_ms))
+                                    .await
+                                    .is_err()
+                                {
+                                    closed = true;
+                                    break;
+                                }
+
+                                // Also send as event
+                                if let Some(tx) = &event_tx {
+                                    let _ = tx
+                                        .send(TransportEvent::AudioReceived {
+                                            samples,
+                                            timestamp_ms,
+                                        })
+                                        .await;
+                                }
                             }
-
-                            // Also send as event
-                            if let Some(tx) = &event_tx {
-                                let _ = tx
-                                    .send(TransportEvent::AudioReceived {
-                                        samples,
-                                        timestamp_ms,
-                                    })
-                                    .await;
+                            if closed {
+                                break;
                             }
                         },
                         Err(e) => {
@@ -812,10 +859,42 @@ impl AudioSource for DummyAudioSource {
     }
 }
 
+/// Extend a 16-bit RTP sequence number to 64 bits across wraparound
+///
+/// Picks the value closest to the highest sequence seen so far. Numbering
+/// starts one cycle in so a packet reordered before the first one doesn't
+/// underflow.
+fn extend_rtp_sequence(highest: Option<u64>, sequence: u16) -> u64 {
+    const CYCLE: u64 = 1 << 16;
+    let Some(highest) = highest else {
+        return CYCLE + sequence as u64;
+    };
+
+    let candidate = (highest & !(CYCLE - 1)) | sequence as u64;
+    [
+        candidate.saturating_sub(CYCLE),
+        candidate,
+        candidate + CYCLE,
+    ]
+    .into_iter()
+    .min_by_key(|c| c.abs_diff(highest))
+    .unwrap_or(candidate)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_extend_rtp_sequence() {
+        let first = extend_rtp_sequence(None, 65534);
+        assert_eq!(extend_rtp_sequence(Some(first), 65535), first + 1);
+        // Wraps forward
+        assert_eq!(extend_rtp_sequence(Some(first + 1), 0), first + 2);
+        // Late packet from before the wrap
+        assert_eq!(extend_rtp_sequence(Some(first + 2), 65533), first - 1);
+    }
+
     #[test]
     fn test_webrtc_config_default() {
         let config = WebRtcConfig::default();